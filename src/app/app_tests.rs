@@ -1,18 +1,26 @@
 #[cfg(test)]
 mod tests {
   use anyhow::anyhow;
+  use indoc::indoc;
   use pretty_assertions::{assert_eq, assert_str_eq};
   use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
-  use serde_json::Value;
+  use serde_json::{Value, json};
   use serial_test::serial;
   use tokio::sync::mpsc;
 
-  use crate::app::{App, AppConfig, Data, ServarrConfig, interpolate_env_vars};
+  use crate::app::key_binding::{KeyBindingOverride, KeyBindingOverrides};
+  use crate::app::{
+    App, AppConfig, ConfigValidationError, ConfigValidationErrors, Data, ServarrConfig,
+    interpolate_env_vars,
+  };
+  use crate::event::Key;
   use crate::models::servarr_data::lidarr::lidarr_data::LidarrData;
+  use crate::models::servarr_data::prowlarr::prowlarr_data::{ActiveProwlarrBlock, ProwlarrData};
   use crate::models::servarr_data::radarr::radarr_data::{ActiveRadarrBlock, RadarrData};
+  use crate::models::servarr_data::readarr::readarr_data::{ActiveReadarrBlock, ReadarrData};
   use crate::models::servarr_data::sonarr::sonarr_data::{ActiveSonarrBlock, SonarrData};
   use crate::models::{HorizontallyScrollableText, TabRoute};
-  use crate::network::NetworkEvent;
+  use crate::network::QueuedNetworkEvent;
   use crate::network::radarr_network::RadarrEvent;
   use tokio_util::sync::CancellationToken;
 
@@ -32,11 +40,22 @@ mod tests {
       ..ServarrConfig::default()
     };
     let sonarr_config_2 = ServarrConfig::default();
+    let prowlarr_config = ServarrConfig {
+      weight: Some(2),
+      ..ServarrConfig::default()
+    };
+    let readarr_config = ServarrConfig {
+      weight: Some(4),
+      ..ServarrConfig::default()
+    };
     let config = AppConfig {
       theme: None,
+      keybindings: None,
       radarr: Some(vec![radarr_config_1.clone(), radarr_config_2.clone()]),
       sonarr: Some(vec![sonarr_config_1.clone(), sonarr_config_2.clone()]),
       lidarr: None,
+      prowlarr: Some(vec![prowlarr_config.clone()]),
+      readarr: Some(vec![readarr_config.clone()]),
     };
     let expected_tab_routes = vec![
       TabRoute {
@@ -45,12 +64,24 @@ mod tests {
         contextual_help: None,
         config: Some(sonarr_config_1),
       },
+      TabRoute {
+        title: "Prowlarr 1".to_owned(),
+        route: ActiveProwlarrBlock::default().into(),
+        contextual_help: None,
+        config: Some(prowlarr_config),
+      },
       TabRoute {
         title: "Radarr 1".to_owned(),
         route: ActiveRadarrBlock::default().into(),
         contextual_help: None,
         config: Some(radarr_config_2),
       },
+      TabRoute {
+        title: "Readarr 1".to_owned(),
+        route: ActiveReadarrBlock::default().into(),
+        contextual_help: None,
+        config: Some(readarr_config),
+      },
       TabRoute {
         title: "Radarr Test".to_owned(),
         route: ActiveRadarrBlock::default().into(),
@@ -66,10 +97,11 @@ mod tests {
     ];
 
     let app = App::new(
-      mpsc::channel::<NetworkEvent>(500).0,
+      mpsc::channel::<QueuedNetworkEvent>(500).0,
       config,
       CancellationToken::new(),
-    );
+    )
+    .unwrap();
 
     assert_is_empty!(app.navigation_stack);
     assert_eq!(app.get_current_route(), ActiveSonarrBlock::default().into());
@@ -79,6 +111,9 @@ mod tests {
     assert_eq!(app.error, HorizontallyScrollableText::default());
     assert_eq!(app.server_tabs.index, 0);
     assert_eq!(app.server_tabs.tabs, expected_tab_routes);
+    for tab in &expected_tab_routes {
+      assert_ok!(app.network_clients.get(&tab.instance()));
+    }
     assert_eq!(app.tick_until_poll, 400);
     assert_eq!(app.ticks_until_scroll, 64);
     assert_eq!(app.tick_count, 0);
@@ -187,7 +222,9 @@ mod tests {
     };
     let data = Data {
       lidarr_data: LidarrData::default(),
+      prowlarr_data: ProwlarrData::default(),
       radarr_data,
+      readarr_data: ReadarrData::default(),
       sonarr_data,
     };
     let mut app = App {
@@ -207,6 +244,161 @@ mod tests {
     assert!(app.data.sonarr_data.version.is_empty());
   }
 
+  #[test]
+  fn test_reload_config_keeps_current_tab() {
+    let config = AppConfig {
+      radarr: Some(vec![ServarrConfig {
+        name: Some("Radarr".to_owned()),
+        ..ServarrConfig::default()
+      }]),
+      sonarr: Some(vec![
+        ServarrConfig {
+          name: Some("New Sonarr".to_owned()),
+          weight: Some(1),
+          ..ServarrConfig::default()
+        },
+        ServarrConfig {
+          name: Some("Sonarr".to_owned()),
+          api_token: Some("newToken".to_owned()),
+          ..ServarrConfig::default()
+        },
+      ]),
+      ..AppConfig::default()
+    };
+    let mut app = App::test_default();
+    app.data.sonarr_data.version = "test".into();
+    app.server_tabs.set_index(1);
+    app.push_navigation_stack(ActiveSonarrBlock::Series.into());
+    app.push_navigation_stack(ActiveSonarrBlock::SeriesDetails.into());
+
+    assert_ok!(app.reload_config(config));
+
+    assert_eq!(app.server_tabs.tabs.len(), 3);
+    assert_str_eq!(app.server_tabs.tabs[app.server_tabs.index].title, "Sonarr");
+    assert_some_eq_x!(
+      &app
+        .server_tabs
+        .get_active_config()
+        .as_ref()
+        .unwrap()
+        .api_token,
+      "newToken"
+    );
+    assert_eq!(
+      app.get_current_route(),
+      ActiveSonarrBlock::SeriesDetails.into()
+    );
+    assert!(app.should_refresh);
+    assert!(!app.cancellation_token.is_cancelled());
+    assert_str_eq!(app.data.sonarr_data.version, "test");
+  }
+
+  #[test]
+  fn test_reload_config_resets_to_first_tab_when_current_tab_is_removed() {
+    let config = AppConfig {
+      radarr: Some(vec![ServarrConfig {
+        name: Some("Radarr".to_owned()),
+        ..ServarrConfig::default()
+      }]),
+      ..AppConfig::default()
+    };
+    let mut app = App::test_default();
+    app.data.sonarr_data.version = "test".into();
+    app.server_tabs.set_index(1);
+    app.push_navigation_stack(ActiveSonarrBlock::Series.into());
+    app.push_navigation_stack(ActiveSonarrBlock::SeriesDetails.into());
+
+    assert_ok!(app.reload_config(config));
+
+    assert_eq!(app.server_tabs.tabs.len(), 1);
+    assert_eq!(app.server_tabs.index, 0);
+    assert_eq!(app.get_current_route(), ActiveRadarrBlock::Movies.into());
+    app.pop_navigation_stack();
+    assert_eq!(app.get_current_route(), ActiveRadarrBlock::Movies.into());
+    assert!(app.cancellation_token.is_cancelled());
+    assert!(app.data.sonarr_data.version.is_empty());
+  }
+
+  #[test]
+  fn test_reload_config_does_not_keep_tab_with_same_title_for_different_servarr() {
+    let config = AppConfig {
+      lidarr: Some(vec![ServarrConfig {
+        name: Some("Sonarr".to_owned()),
+        ..ServarrConfig::default()
+      }]),
+      radarr: Some(vec![ServarrConfig {
+        name: Some("Radarr".to_owned()),
+        weight: Some(1),
+        ..ServarrConfig::default()
+      }]),
+      ..AppConfig::default()
+    };
+    let mut app = App::test_default();
+    app.server_tabs.set_index(1);
+    app.push_navigation_stack(ActiveSonarrBlock::Series.into());
+
+    assert_ok!(app.reload_config(config));
+
+    assert_eq!(app.server_tabs.index, 0);
+    assert_eq!(app.get_current_route(), ActiveRadarrBlock::Movies.into());
+  }
+
+  #[test]
+  fn test_reload_config_replaces_network_clients() {
+    let config = AppConfig {
+      radarr: Some(vec![ServarrConfig {
+        name: Some("Radarr".to_owned()),
+        ..ServarrConfig::default()
+      }]),
+      readarr: Some(vec![ServarrConfig {
+        name: Some("Readarr".to_owned()),
+        ..ServarrConfig::default()
+      }]),
+      ..AppConfig::default()
+    };
+    let mut app = App::new(
+      mpsc::channel::<QueuedNetworkEvent>(500).0,
+      AppConfig {
+        sonarr: Some(vec![ServarrConfig::default()]),
+        ..config.clone()
+      },
+      CancellationToken::new(),
+    )
+    .unwrap();
+    let sonarr_instance = app
+      .server_tabs
+      .tabs
+      .iter()
+      .find(|tab| tab.title == "Sonarr 1")
+      .unwrap()
+      .instance();
+
+    assert_ok!(app.reload_config(config));
+
+    assert_ok!(app.network_clients.get(&app.server_tabs.tabs[0].instance()));
+    assert_ok!(app.network_clients.get(&app.server_tabs.tabs[1].instance()));
+    assert_err!(app.network_clients.get(&sonarr_instance));
+  }
+
+  #[test]
+  fn test_reload_config_keeps_the_current_config_when_a_client_cannot_be_built() {
+    let config = AppConfig {
+      radarr: Some(vec![ServarrConfig {
+        name: Some("Radarr".to_owned()),
+        ssl_cert_path: Some("/nonexistent/radarr.crt".to_owned()),
+        ..ServarrConfig::default()
+      }]),
+      ..AppConfig::default()
+    };
+    let mut app = App::test_default();
+    let expected_tabs = app.server_tabs.tabs.clone();
+
+    assert_err!(app.reload_config(config));
+
+    assert_eq!(app.server_tabs.tabs, expected_tabs);
+    assert!(!app.cancellation_token.is_cancelled());
+  }
+
   #[test]
   fn test_handle_error() {
     let mut app = App::test_default();
@@ -223,7 +415,7 @@ mod tests {
 
   #[tokio::test]
   async fn test_dispatch_network_event() {
-    let (sync_network_tx, mut sync_network_rx) = mpsc::channel::<NetworkEvent>(500);
+    let (sync_network_tx, mut sync_network_rx) = mpsc::channel::<QueuedNetworkEvent>(500);
 
     let mut app = App {
       tick_until_poll: 2,
@@ -238,7 +430,7 @@ mod tests {
       .await;
 
     assert_eq!(
-      sync_network_rx.recv().await.unwrap(),
+      sync_network_rx.recv().await.unwrap().network_event,
       RadarrEvent::GetStatus.into()
     );
     assert_eq!(app.tick_count, 0);
@@ -267,7 +459,7 @@ mod tests {
 
   #[tokio::test]
   async fn test_on_tick_first_render() {
-    let (sync_network_tx, mut sync_network_rx) = mpsc::channel::<NetworkEvent>(500);
+    let (sync_network_tx, mut sync_network_rx) = mpsc::channel::<QueuedNetworkEvent>(500);
 
     let mut app = App {
       tick_until_poll: 2,
@@ -281,39 +473,39 @@ mod tests {
     app.on_tick().await;
 
     assert_eq!(
-      sync_network_rx.recv().await.unwrap(),
+      sync_network_rx.recv().await.unwrap().network_event,
       RadarrEvent::GetQualityProfiles.into()
     );
     assert_eq!(
-      sync_network_rx.recv().await.unwrap(),
+      sync_network_rx.recv().await.unwrap().network_event,
       RadarrEvent::GetTags.into()
     );
     assert_eq!(
-      sync_network_rx.recv().await.unwrap(),
+      sync_network_rx.recv().await.unwrap().network_event,
       RadarrEvent::GetRootFolders.into()
     );
     assert_eq!(
-      sync_network_rx.recv().await.unwrap(),
+      sync_network_rx.recv().await.unwrap().network_event,
       RadarrEvent::GetDownloads(500).into()
     );
     assert_eq!(
-      sync_network_rx.recv().await.unwrap(),
+      sync_network_rx.recv().await.unwrap().network_event,
       RadarrEvent::GetDiskSpace.into()
     );
     assert_eq!(
-      sync_network_rx.recv().await.unwrap(),
+      sync_network_rx.recv().await.unwrap().network_event,
       RadarrEvent::GetStatus.into()
     );
     assert_eq!(
-      sync_network_rx.recv().await.unwrap(),
+      sync_network_rx.recv().await.unwrap().network_event,
       RadarrEvent::GetQualityProfiles.into()
     );
     assert_eq!(
-      sync_network_rx.recv().await.unwrap(),
+      sync_network_rx.recv().await.unwrap().network_event,
       RadarrEvent::GetTags.into()
     );
     assert_eq!(
-      sync_network_rx.recv().await.unwrap(),
+      sync_network_rx.recv().await.unwrap().network_event,
       RadarrEvent::GetMovies.into()
     );
     assert!(!app.is_routing);
@@ -347,12 +539,264 @@ mod tests {
     assert!(!app.should_refresh);
   }
 
+  #[tokio::test]
+  async fn test_on_tick_polls_less_often_when_push_connected() {
+    let (sync_network_tx, mut sync_network_rx) = mpsc::channel::<QueuedNetworkEvent>(500);
+    let mut app = App {
+      tick_until_poll: 2,
+      tick_count: 2,
+      is_first_render: false,
+      network_tx: Some(sync_network_tx),
+      ..App::test_default()
+    };
+    let instance = app.server_tabs.get_active_instance().unwrap();
+    app.push_connected_instances.insert(instance);
+
+    app.on_tick().await;
+
+    assert!(sync_network_rx.try_recv().is_err());
+    assert_eq!(app.tick_count, 3);
+
+    app.tick_count = 20;
+    app.on_tick().await;
+
+    assert!(sync_network_rx.try_recv().is_ok());
+  }
+
+  #[test]
+  fn test_should_poll() {
+    let mut app = App {
+      tick_until_poll: 2,
+      ..App::test_default()
+    };
+
+    assert!(app.should_poll());
+
+    app.tick_count = 1;
+
+    assert!(!app.should_poll());
+  }
+
+  #[test]
+  fn test_should_poll_less_often_when_active_instance_is_push_connected() {
+    let mut app = App {
+      tick_until_poll: 2,
+      tick_count: 2,
+      ..App::test_default()
+    };
+    let config = ServarrConfig {
+      push_updates: Some(true),
+      ..ServarrConfig::default()
+    };
+    app.server_tabs.tabs[0].config = Some(config.clone());
+    app.server_tabs.tabs[1].config = Some(config);
+    let instance = app.server_tabs.tabs[0].instance();
+    app.push_connected_instances.insert(instance);
+
+    assert!(app.is_push_connected());
+    assert!(!app.should_poll());
+
+    app.tick_count = 20;
+
+    assert!(app.should_poll());
+
+    app.tick_count = 2;
+    app.server_tabs.next();
+
+    assert!(!app.is_push_connected());
+    assert!(app.should_poll());
+  }
+
   #[test]
   fn test_app_config_default() {
     let app_config = AppConfig::default();
 
     assert_none!(app_config.radarr);
     assert_none!(app_config.sonarr);
+    assert_none!(app_config.prowlarr);
+    assert_none!(app_config.readarr);
+  }
+
+  #[test]
+  fn test_app_config_try_validate() {
+    let app_config = AppConfig {
+      sonarr: Some(vec![ServarrConfig::default()]),
+      ..AppConfig::default()
+    };
+
+    assert_ok!(app_config.try_validate("/path/to/config.yml"));
+  }
+
+  #[test]
+  fn test_app_config_try_validate_no_servarrs_configured() {
+    let result = AppConfig::default().try_validate("/path/to/config.yml");
+
+    assert_err!(&result);
+    assert_str_eq!(
+      result.unwrap_err().to_string(),
+      "No Servarrs are configured in the file: /path/to/config.yml"
+    );
+  }
+
+  #[test]
+  fn test_app_config_try_validate_invalid_servarr_config() {
+    let app_config = AppConfig {
+      radarr: Some(vec![ServarrConfig::default()]),
+      readarr: Some(vec![
+        ServarrConfig {
+          name: Some("Books".to_owned()),
+          ..ServarrConfig::default()
+        },
+        ServarrConfig {
+          host: None,
+          api_token: None,
+          ..ServarrConfig::default()
+        },
+      ]),
+      ..AppConfig::default()
+    };
+    let expected_errors = ConfigValidationErrors(vec![
+      ConfigValidationError {
+        instance: Some("Readarr 1".to_owned()),
+        field: "readarr[1].host".to_owned(),
+        message: "'host' or 'uri' is required for configuration".to_owned(),
+      },
+      ConfigValidationError {
+        instance: Some("Readarr 1".to_owned()),
+        field: "readarr[1].api_token".to_owned(),
+        message: "'api_token' or 'api_token_file' is required for configuration".to_owned(),
+      },
+    ]);
+
+    let result = app_config.try_validate("/path/to/config.yml");
+
+    assert_eq!(result, Err(expected_errors));
+    assert_str_eq!(
+      app_config
+        .try_validate("/path/to/config.yml")
+        .unwrap_err()
+        .to_string(),
+      indoc! {"
+        readarr[1].host (Readarr 1): 'host' or 'uri' is required for configuration
+        readarr[1].api_token (Readarr 1): 'api_token' or 'api_token_file' is required for configuration"}
+    );
+  }
+
+  #[test]
+  fn test_app_config_try_validate_collects_every_error() {
+    let app_config = AppConfig {
+      keybindings: Some(KeyBindingOverrides {
+        refresh: Some(KeyBindingOverride {
+          key: Some(Key::Char('q')),
+          alt: None,
+        }),
+        ..KeyBindingOverrides::default()
+      }),
+      sonarr: Some(vec![ServarrConfig {
+        api_token_file: Some("/path/to/nonexistent/token".to_owned()),
+        ssl_cert_path: Some("/path/to/nonexistent/cert.pem".to_owned()),
+        ..ServarrConfig::default()
+      }]),
+      ..AppConfig::default()
+    };
+    let expected_errors = ConfigValidationErrors(vec![
+      ConfigValidationError {
+        instance: None,
+        field: "keybindings".to_owned(),
+        message: "Keybindings 'quit' and 'refresh' are both bound to 'q'".to_owned(),
+      },
+      ConfigValidationError {
+        instance: Some("Sonarr 1".to_owned()),
+        field: "sonarr[0].api_token_file".to_owned(),
+        message: "The specified /path/to/nonexistent/token API token file does not exist"
+          .to_owned(),
+      },
+      ConfigValidationError {
+        instance: Some("Sonarr 1".to_owned()),
+        field: "sonarr[0].ssl_cert_path".to_owned(),
+        message: "The specified /path/to/nonexistent/cert.pem SSL certificate does not exist"
+          .to_owned(),
+      },
+    ]);
+
+    assert_eq!(
+      app_config.try_validate("/path/to/config.yml"),
+      Err(expected_errors)
+    );
+  }
+
+  #[test]
+  fn test_config_validation_errors_serialize_to_json() {
+    let errors = ConfigValidationErrors(vec![
+      ConfigValidationError {
+        instance: None,
+        field: String::new(),
+        message: "No Servarrs are configured in the file: /path/to/config.yml".to_owned(),
+      },
+      ConfigValidationError {
+        instance: Some("Radarr 1".to_owned()),
+        field: "radarr[0].port".to_owned(),
+        message: "Invalid value 'hi': invalid digit found in string".to_owned(),
+      },
+    ]);
+
+    assert_eq!(
+      serde_json::to_value(errors).unwrap(),
+      json!([
+        {
+          "field": "",
+          "message": "No Servarrs are configured in the file: /path/to/config.yml"
+        },
+        {
+          "instance": "Radarr 1",
+          "field": "radarr[0].port",
+          "message": "Invalid value 'hi': invalid digit found in string"
+        }
+      ])
+    );
+  }
+
+  #[test]
+  fn test_app_config_try_post_process_initialization_missing_api_token_file() {
+    let mut app_config = AppConfig {
+      lidarr: Some(vec![ServarrConfig {
+        api_token_file: Some("/path/to/nonexistent/token".to_owned()),
+        ..ServarrConfig::default()
+      }]),
+      ..AppConfig::default()
+    };
+
+    let result = app_config.try_post_process_initialization();
+
+    assert_err!(&result);
+    assert_str_eq!(
+      result.unwrap_err().to_string(),
+      "lidarr[0].api_token_file (Lidarr 1): The specified /path/to/nonexistent/token API token file does not exist"
+    );
+  }
+
+  #[test]
+  fn test_app_config_servarr_configs() {
+    let servarr_config = |name: &str| ServarrConfig {
+      name: Some(name.to_owned()),
+      ..ServarrConfig::default()
+    };
+    let app_config = AppConfig {
+      lidarr: Some(vec![servarr_config("Lidarr")]),
+      prowlarr: Some(vec![servarr_config("Prowlarr")]),
+      radarr: Some(vec![servarr_config("Radarr 1"), servarr_config("Radarr 2")]),
+      readarr: None,
+      sonarr: Some(vec![servarr_config("Sonarr")]),
+      ..AppConfig::default()
+    };
+
+    assert_eq!(
+      app_config
+        .servarr_configs()
+        .map(|config| config.name.clone().unwrap())
+        .collect::<Vec<_>>(),
+      vec!["Radarr 1", "Radarr 2", "Sonarr", "Lidarr", "Prowlarr"]
+    );
   }
 
   #[test]
@@ -663,13 +1107,107 @@ mod tests {
   fn test_deserialize_optional_u16_env_var_invalid_number() {
     let yaml_data = r#"
       port: "hi"
+      weight: "-1"
       api_token: "test123"
     "#;
-    let result: Result<ServarrConfig, _> = serde_yaml::from_str(yaml_data);
+    let expected_errors = vec![
+      ConfigValidationError {
+        instance: Some("Radarr 1".to_owned()),
+        field: "radarr[0].port".to_owned(),
+        message: "Invalid value 'hi': invalid digit found in string".to_owned(),
+      },
+      ConfigValidationError {
+        instance: Some("Radarr 1".to_owned()),
+        field: "radarr[0].weight".to_owned(),
+        message: "Invalid value '-1': invalid digit found in string".to_owned(),
+      },
+      ConfigValidationError {
+        instance: Some("Radarr 1".to_owned()),
+        field: "radarr[0].host".to_owned(),
+        message: "'host' or 'uri' is required for configuration".to_owned(),
+      },
+    ];
 
-    assert_err!(&result);
-    let err = result.unwrap_err().to_string();
-    assert_contains!(err, "invalid digit found in string");
+    let config: ServarrConfig = serde_yaml::from_str(yaml_data).unwrap();
+
+    assert_none!(config.port);
+    assert_none!(config.weight);
+    assert_eq!(config.validate("radarr[0]", "Radarr 1"), expected_errors);
+  }
+
+  #[test]
+  fn test_deserialize_connection_settings() {
+    let yaml_data = r#"
+      host: localhost
+      api_token: "test123"
+      timeout_secs: 10
+      retries: "3"
+      retry_backoff: 250
+    "#;
+
+    let config: ServarrConfig = serde_yaml::from_str(yaml_data).unwrap();
+
+    assert_some_eq_x!(config.timeout_secs, 10);
+    assert_some_eq_x!(config.retries, 3);
+    assert_some_eq_x!(config.retry_backoff, 250);
+    assert!(config.validate("radarr[0]", "Radarr 1").is_empty());
+  }
+
+  #[test]
+  fn test_validate_connection_settings() {
+    let yaml_data = r#"
+      host: localhost
+      api_token: "test123"
+      timeout_secs: 0
+      retries: "-1"
+    "#;
+    let expected_errors = vec![
+      ConfigValidationError {
+        instance: Some("Radarr 1".to_owned()),
+        field: "radarr[0].retries".to_owned(),
+        message: "Invalid value '-1': invalid digit found in string".to_owned(),
+      },
+      ConfigValidationError {
+        instance: Some("Radarr 1".to_owned()),
+        field: "radarr[0].timeout_secs".to_owned(),
+        message: "'timeout_secs' must be greater than 0".to_owned(),
+      },
+    ];
+
+    let config: ServarrConfig = serde_yaml::from_str(yaml_data).unwrap();
+
+    assert_none!(config.retries);
+    assert_eq!(config.validate("radarr[0]", "Radarr 1"), expected_errors);
+  }
+
+  #[test]
+  fn test_validate_tls_and_proxy_settings() {
+    let yaml_data = r#"
+      host: localhost
+      api_token: "test123"
+      client_cert_path: /nonexistent/client.crt
+      proxy: "not a proxy"
+    "#;
+    let expected_errors = vec![
+      ConfigValidationError {
+        instance: Some("Radarr 1".to_owned()),
+        field: "radarr[0].client_key_path".to_owned(),
+        message: "'client_key_path' is required when 'client_cert_path' is specified".to_owned(),
+      },
+      ConfigValidationError {
+        instance: Some("Radarr 1".to_owned()),
+        field: "radarr[0].client_cert_path".to_owned(),
+        message: "The specified /nonexistent/client.crt client certificate does not exist"
+          .to_owned(),
+      },
+    ];
+
+    let config: ServarrConfig = serde_yaml::from_str(yaml_data).unwrap();
+    let errors = config.validate("radarr[0]", "Radarr 1");
+
+    assert_eq!(errors[..2], expected_errors);
+    assert_str_eq!(errors[2].field, "radarr[0].proxy");
+    assert!(errors[2].message.starts_with("Invalid proxy 'not a proxy'"));
   }
 
   #[test]
@@ -742,11 +1280,14 @@ mod tests {
     let api_token = "thisisatest".to_owned();
     let api_token_file = "/root/.config/api_token".to_owned();
     let ssl_cert_path = "/some/path".to_owned();
+    let client_cert_path = "/some/client.crt".to_owned();
+    let client_key_path = "/some/client.key".to_owned();
+    let proxy = "socks5://localhost:1080".to_owned();
     let monitored_storage = vec!["/path1".to_owned(), "/path2".to_owned()];
     let mut custom_headers = HeaderMap::new();
     custom_headers.insert("X-Custom-Header", "value".parse().unwrap());
     let expected_str = format!(
-      "ServarrConfig {{ name: Some(\"{name}\"), host: Some(\"{host}\"), port: Some({port}), uri: Some(\"{uri}\"), weight: Some({weight}), api_token: Some(\"***********\"), api_token_file: Some(\"{api_token_file}\"), ssl: Some(true), ssl_cert_path: Some(\"{ssl_cert_path}\"), custom_headers: Some({{\"x-custom-header\": \"value\"}}), monitored_storage_paths: Some([\"/path1\", \"/path2\"]) }}"
+      "ServarrConfig {{ name: Some(\"{name}\"), host: Some(\"{host}\"), port: Some({port}), uri: Some(\"{uri}\"), weight: Some({weight}), api_token: Some(\"***********\"), api_token_file: Some(\"{api_token_file}\"), ssl: Some(true), ssl_cert_path: Some(\"{ssl_cert_path}\"), accept_invalid_certs: Some(true), client_cert_path: Some(\"{client_cert_path}\"), client_key_path: Some(\"{client_key_path}\"), proxy: Some(\"{proxy}\"), custom_headers: Some({{\"x-custom-header\": \"value\"}}), monitored_storage_paths: Some([\"/path1\", \"/path2\"]), push_updates: Some(true), timeout_secs: Some(30), retries: Some(2), retry_backoff: Some(500), invalid_values: [] }}"
     );
     let servarr_config = ServarrConfig {
       name: Some(name),
@@ -758,8 +1299,17 @@ mod tests {
       api_token_file: Some(api_token_file),
       ssl_cert_path: Some(ssl_cert_path),
       ssl: Some(true),
+      accept_invalid_certs: Some(true),
+      client_cert_path: Some(client_cert_path),
+      client_key_path: Some(client_key_path),
+      proxy: Some(proxy),
       custom_headers: Some(custom_headers),
       monitored_storage_paths: Some(monitored_storage),
+      push_updates: Some(true),
+      timeout_secs: Some(30),
+      retries: Some(2),
+      retry_backoff: Some(500),
+      invalid_values: Vec::new(),
     };
 
     assert_str_eq!(format!("{servarr_config:?}"), expected_str);
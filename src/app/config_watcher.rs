@@ -0,0 +1,114 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime};
+
+use anyhow::{Context, Result};
+
+use crate::app::AppConfig;
+
+#[cfg(test)]
+#[path = "config_watcher_tests.rs"]
+mod config_watcher_tests;
+
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Watches the Managarr config file, and any `api_token_file`s it references, for changes so
+/// the TUI can reload its configuration without being restarted
+pub struct ConfigWatcher {
+  config_path: PathBuf,
+  watched_files: Vec<(PathBuf, Option<SystemTime>)>,
+  last_poll: Instant,
+}
+
+impl ConfigWatcher {
+  pub fn new(config_path: impl Into<PathBuf>, config: &AppConfig) -> Self {
+    let mut watcher = ConfigWatcher {
+      config_path: config_path.into(),
+      watched_files: Vec::new(),
+      last_poll: Instant::now(),
+    };
+    watcher.watch(config);
+
+    watcher
+  }
+
+  /// Returns true when any of the watched files have been modified, created, or removed since the
+  /// last time they were checked. The filesystem is only polled once every [POLL_INTERVAL].
+  pub fn poll(&mut self) -> bool {
+    if self.last_poll.elapsed() < POLL_INTERVAL {
+      return false;
+    }
+
+    self.last_poll = Instant::now();
+    self.has_changed()
+  }
+
+  /// Loads, validates, and initializes the config from disk. The watched files are refreshed
+  /// even when the config is invalid, so the same broken edit isn't reported repeatedly.
+  pub fn reload(&mut self) -> Result<AppConfig> {
+    let config_path = self.config_path.display().to_string();
+    let result = fs::read_to_string(&self.config_path)
+      .with_context(|| format!("Unable to open config file '{config_path}'"))
+      .and_then(|contents| {
+        serde_yaml::from_str::<AppConfig>(&contents)
+          .with_context(|| format!("Config file at '{config_path}' is invalid"))
+      })
+      .and_then(|mut config| {
+        config.try_validate(&config_path)?;
+        self.watch(&config);
+        config.try_post_process_initialization()?;
+
+        Ok(config)
+      });
+
+    if result.is_err() {
+      self.refresh_modified_times();
+    }
+
+    result
+  }
+
+  fn has_changed(&mut self) -> bool {
+    let mut changed = false;
+
+    for (path, modified) in &mut self.watched_files {
+      let current_modified = modified_time(path);
+      if current_modified != *modified {
+        *modified = current_modified;
+        changed = true;
+      }
+    }
+
+    changed
+  }
+
+  fn watch(&mut self, config: &AppConfig) {
+    let mut paths = vec![self.config_path.clone()];
+    paths.extend(
+      config
+        .servarr_configs()
+        .filter_map(|servarr_config| servarr_config.api_token_file.as_ref())
+        .map(PathBuf::from),
+    );
+
+    self.watched_files = paths
+      .into_iter()
+      .map(|path| {
+        let modified = modified_time(&path);
+        (path, modified)
+      })
+      .collect();
+  }
+
+  fn refresh_modified_times(&mut self) {
+    for (path, modified) in &mut self.watched_files {
+      *modified = modified_time(path);
+    }
+  }
+}
+
+fn modified_time(path: &Path) -> Option<SystemTime> {
+  fs::metadata(path)
+    .and_then(|metadata| metadata.modified())
+    .ok()
+}
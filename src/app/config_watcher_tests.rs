@@ -0,0 +1,145 @@
+#[cfg(test)]
+mod tests {
+  use std::fs::{self, File};
+  use std::path::PathBuf;
+  use std::time::{Duration, SystemTime};
+
+  use indoc::{formatdoc, indoc};
+  use pretty_assertions::{assert_eq, assert_str_eq};
+
+  use crate::app::config_watcher::ConfigWatcher;
+  use crate::app::{AppConfig, ServarrConfig};
+
+  fn write_file(name: &str, contents: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(name);
+    fs::write(&path, contents).unwrap();
+
+    path
+  }
+
+  fn touch(path: &PathBuf) {
+    File::options()
+      .write(true)
+      .open(path)
+      .unwrap()
+      .set_modified(SystemTime::now() + Duration::from_secs(60))
+      .unwrap();
+  }
+
+  #[test]
+  fn test_config_watcher_new_watches_config_and_api_token_files() {
+    let config = AppConfig {
+      radarr: Some(vec![ServarrConfig {
+        api_token_file: Some("/path/to/radarr_token".to_owned()),
+        ..ServarrConfig::default()
+      }]),
+      sonarr: Some(vec![ServarrConfig::default()]),
+      ..AppConfig::default()
+    };
+
+    let config_watcher = ConfigWatcher::new("/path/to/config.yml", &config);
+
+    assert_eq!(
+      config_watcher
+        .watched_files
+        .iter()
+        .map(|(path, _)| path.clone())
+        .collect::<Vec<_>>(),
+      vec![
+        PathBuf::from("/path/to/config.yml"),
+        PathBuf::from("/path/to/radarr_token")
+      ]
+    );
+  }
+
+  #[test]
+  fn test_config_watcher_poll_waits_for_poll_interval() {
+    let config_path = write_file("managarr_test_watcher_poll.yml", "");
+    let mut config_watcher = ConfigWatcher::new(&config_path, &AppConfig::default());
+    touch(&config_path);
+
+    assert!(!config_watcher.poll());
+  }
+
+  #[test]
+  fn test_config_watcher_has_changed() {
+    let config_path = write_file("managarr_test_watcher_changed.yml", "");
+    let mut config_watcher = ConfigWatcher::new(&config_path, &AppConfig::default());
+
+    assert!(!config_watcher.has_changed());
+
+    touch(&config_path);
+
+    assert!(config_watcher.has_changed());
+    assert!(!config_watcher.has_changed());
+  }
+
+  #[test]
+  fn test_config_watcher_reload() {
+    let token_path = write_file("managarr_test_watcher_reload_token", "newToken\n");
+    let config_path = write_file(
+      "managarr_test_watcher_reload.yml",
+      &formatdoc! {"
+        radarr:
+          - host: 192.168.0.78
+            api_token_file: {}
+      ", token_path.display()},
+    );
+    let mut config_watcher = ConfigWatcher::new(&config_path, &AppConfig::default());
+
+    let config = config_watcher.reload().unwrap();
+
+    assert_str_eq!(
+      config.radarr.unwrap()[0].api_token.as_ref().unwrap(),
+      "newToken"
+    );
+    assert_eq!(config_watcher.watched_files.len(), 2);
+    assert_eq!(config_watcher.watched_files[1].0, token_path);
+  }
+
+  #[test]
+  fn test_config_watcher_reload_invalid_yaml() {
+    let config_path = write_file("managarr_test_watcher_invalid_yaml.yml", "radarr: [");
+    let mut config_watcher = ConfigWatcher::new(&config_path, &AppConfig::default());
+
+    let result = config_watcher.reload();
+
+    assert_err!(&result);
+    assert_str_eq!(
+      result.unwrap_err().to_string(),
+      format!("Config file at '{}' is invalid", config_path.display())
+    );
+  }
+
+  #[test]
+  fn test_config_watcher_reload_failed_validation() {
+    let config_path = write_file(
+      "managarr_test_watcher_failed_validation.yml",
+      indoc! {"
+        sonarr:
+          - host: 192.168.0.89
+      "},
+    );
+    let mut config_watcher = ConfigWatcher::new(&config_path, &AppConfig::default());
+    touch(&config_path);
+
+    let result = config_watcher.reload();
+
+    assert_err!(&result);
+    assert_str_eq!(
+      result.unwrap_err().to_string(),
+      "sonarr[0].api_token (Sonarr 1): 'api_token' or 'api_token_file' is required for configuration"
+    );
+    assert!(!config_watcher.has_changed());
+  }
+
+  #[test]
+  fn test_config_watcher_reload_missing_file() {
+    let mut config_watcher = ConfigWatcher::new(
+      "/path/to/nonexistent/managarr_config.yml",
+      &AppConfig::default(),
+    );
+
+    assert_err!(config_watcher.reload());
+  }
+}
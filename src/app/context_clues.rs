@@ -1,8 +1,31 @@
 use crate::app::App;
 use crate::app::key_binding::{DEFAULT_KEYBINDINGS, KeyBinding};
-use crate::app::lidarr::lidarr_context_clues::LidarrContextClueProvider;
-use crate::app::radarr::radarr_context_clues::RadarrContextClueProvider;
-use crate::app::sonarr::sonarr_context_clues::SonarrContextClueProvider;
+use crate::app::lidarr::lidarr_context_clues::{
+  ADD_ARTIST_SEARCH_RESULTS_CONTEXT_CLUES, ALBUM_DETAILS_CONTEXT_CLUES,
+  ALBUM_HISTORY_CONTEXT_CLUES, ARTIST_DETAILS_CONTEXT_CLUES, ARTIST_HISTORY_CONTEXT_CLUES,
+  ARTISTS_CONTEXT_CLUES, LidarrContextClueProvider, MANUAL_ALBUM_SEARCH_CONTEXT_CLUES,
+  MANUAL_ARTIST_SEARCH_CONTEXT_CLUES, TRACK_DETAILS_CONTEXT_CLUES, TRACK_HISTORY_CONTEXT_CLUES,
+};
+use crate::app::prowlarr::prowlarr_context_clues::{
+  APPLICATIONS_CONTEXT_CLUES, INDEXER_PROXIES_CONTEXT_CLUES, PROWLARR_INDEXERS_CONTEXT_CLUES,
+  ProwlarrContextClueProvider, SEARCH_RELEASES_CONTEXT_CLUES,
+};
+use crate::app::radarr::radarr_context_clues::{
+  ADD_MOVIE_SEARCH_RESULTS_CONTEXT_CLUES, COLLECTION_DETAILS_CONTEXT_CLUES,
+  COLLECTIONS_CONTEXT_CLUES, LIBRARY_CONTEXT_CLUES, MANUAL_MOVIE_SEARCH_CONTEXT_CLUES,
+  MOVIE_DETAILS_CONTEXT_CLUES, RadarrContextClueProvider,
+};
+use crate::app::readarr::readarr_context_clues::{
+  ADD_AUTHOR_SEARCH_RESULTS_CONTEXT_CLUES, AUTHOR_DETAILS_CONTEXT_CLUES, AUTHORS_CONTEXT_CLUES,
+  BOOK_DETAILS_CONTEXT_CLUES, ReadarrContextClueProvider,
+};
+use crate::app::sonarr::sonarr_context_clues::{
+  ADD_SERIES_SEARCH_RESULTS_CONTEXT_CLUES, EPISODE_DETAILS_CONTEXT_CLUES,
+  MANUAL_EPISODE_SEARCH_CONTEXT_CLUES, MANUAL_SEASON_SEARCH_CONTEXT_CLUES,
+  SEASON_DETAILS_CONTEXT_CLUES, SEASON_HISTORY_CONTEXT_CLUES,
+  SELECTABLE_EPISODE_DETAILS_CONTEXT_CLUES, SERIES_CONTEXT_CLUES, SERIES_DETAILS_CONTEXT_CLUES,
+  SERIES_HISTORY_CONTEXT_CLUES, SonarrContextClueProvider,
+};
 use crate::models::Route;
 
 #[cfg(test)]
@@ -23,6 +46,8 @@ impl ContextClueProvider for ServarrContextClueProvider {
       Route::Radarr(_, _) => RadarrContextClueProvider::get_context_clues(app),
       Route::Sonarr(_, _) => SonarrContextClueProvider::get_context_clues(app),
       Route::Lidarr(_, _) => LidarrContextClueProvider::get_context_clues(app),
+      Route::Prowlarr(_, _) => ProwlarrContextClueProvider::get_context_clues(app),
+      Route::Readarr(_, _) => ReadarrContextClueProvider::get_context_clues(app),
       _ => None,
     }
   }
@@ -64,30 +89,66 @@ pub static BLOCKLIST_CONTEXT_CLUES: [ContextClue; 5] = [
   (DEFAULT_KEYBINDINGS.clear, "clear blocklist"),
 ];
 
+pub static CALENDAR_CONTEXT_CLUES: [ContextClue; 2] = [
+  (
+    DEFAULT_KEYBINDINGS.toggle_view,
+    DEFAULT_KEYBINDINGS.toggle_view.desc,
+  ),
+  (
+    DEFAULT_KEYBINDINGS.refresh,
+    DEFAULT_KEYBINDINGS.refresh.desc,
+  ),
+];
+
+pub static WANTED_CONTEXT_CLUES: [ContextClue; 4] = [
+  (DEFAULT_KEYBINDINGS.toggle_view, "missing/cutoff unmet"),
+  (DEFAULT_KEYBINDINGS.submit, "search selected"),
+  (DEFAULT_KEYBINDINGS.auto_search, "search all"),
+  (
+    DEFAULT_KEYBINDINGS.refresh,
+    DEFAULT_KEYBINDINGS.refresh.desc,
+  ),
+];
+
 pub static CONFIRMATION_PROMPT_CONTEXT_CLUES: [ContextClue; 2] = [
   (DEFAULT_KEYBINDINGS.confirm, "submit"),
   (DEFAULT_KEYBINDINGS.esc, "cancel"),
 ];
 
-pub static DOWNLOADS_CONTEXT_CLUES: [ContextClue; 3] = [
+pub static DOWNLOADS_CONTEXT_CLUES: [ContextClue; 6] = [
   (
     DEFAULT_KEYBINDINGS.refresh,
     DEFAULT_KEYBINDINGS.refresh.desc,
   ),
   (DEFAULT_KEYBINDINGS.delete, DEFAULT_KEYBINDINGS.delete.desc),
+  (DEFAULT_KEYBINDINGS.select, DEFAULT_KEYBINDINGS.select.desc),
   (DEFAULT_KEYBINDINGS.update, "update downloads"),
+  (DEFAULT_KEYBINDINGS.import, "manual import"),
+  (DEFAULT_KEYBINDINGS.grab, "grab pending"),
+];
+
+pub static MANUAL_IMPORT_CONTEXT_CLUES: [ContextClue; 4] = [
+  (DEFAULT_KEYBINDINGS.submit, "change mapping"),
+  (DEFAULT_KEYBINDINGS.import, DEFAULT_KEYBINDINGS.import.desc),
+  (
+    DEFAULT_KEYBINDINGS.refresh,
+    DEFAULT_KEYBINDINGS.refresh.desc,
+  ),
+  (DEFAULT_KEYBINDINGS.esc, DEFAULT_KEYBINDINGS.esc.desc),
 ];
 
-pub static ROOT_FOLDERS_CONTEXT_CLUES: [ContextClue; 3] = [
+pub static ROOT_FOLDERS_CONTEXT_CLUES: [ContextClue; 4] = [
   (DEFAULT_KEYBINDINGS.add, DEFAULT_KEYBINDINGS.add.desc),
   (DEFAULT_KEYBINDINGS.delete, DEFAULT_KEYBINDINGS.delete.desc),
+  (DEFAULT_KEYBINDINGS.settings, "media management"),
   (
     DEFAULT_KEYBINDINGS.refresh,
     DEFAULT_KEYBINDINGS.refresh.desc,
   ),
 ];
 
-pub static INDEXERS_CONTEXT_CLUES: [ContextClue; 6] = [
+pub static INDEXERS_CONTEXT_CLUES: [ContextClue; 7] = [
+  (DEFAULT_KEYBINDINGS.add, DEFAULT_KEYBINDINGS.add.desc),
   (DEFAULT_KEYBINDINGS.submit, "edit indexer"),
   (
     DEFAULT_KEYBINDINGS.settings,
@@ -102,6 +163,68 @@ pub static INDEXERS_CONTEXT_CLUES: [ContextClue; 6] = [
   ),
 ];
 
+pub static DOWNLOAD_CLIENTS_CONTEXT_CLUES: [ContextClue; 6] = [
+  (DEFAULT_KEYBINDINGS.add, DEFAULT_KEYBINDINGS.add.desc),
+  (DEFAULT_KEYBINDINGS.submit, "edit download client"),
+  (DEFAULT_KEYBINDINGS.delete, DEFAULT_KEYBINDINGS.delete.desc),
+  (DEFAULT_KEYBINDINGS.test, "test download client"),
+  (DEFAULT_KEYBINDINGS.test_all, "test all download clients"),
+  (
+    DEFAULT_KEYBINDINGS.refresh,
+    DEFAULT_KEYBINDINGS.refresh.desc,
+  ),
+];
+
+pub static QUALITY_PROFILES_CONTEXT_CLUES: [ContextClue; 2] = [
+  (DEFAULT_KEYBINDINGS.submit, "edit quality profile"),
+  (
+    DEFAULT_KEYBINDINGS.refresh,
+    DEFAULT_KEYBINDINGS.refresh.desc,
+  ),
+];
+
+pub static CUSTOM_FORMATS_CONTEXT_CLUES: [ContextClue; 6] = [
+  (DEFAULT_KEYBINDINGS.submit, "specifications"),
+  (DEFAULT_KEYBINDINGS.edit, "edit custom format"),
+  (DEFAULT_KEYBINDINGS.delete, DEFAULT_KEYBINDINGS.delete.desc),
+  (DEFAULT_KEYBINDINGS.import, DEFAULT_KEYBINDINGS.import.desc),
+  (DEFAULT_KEYBINDINGS.export, DEFAULT_KEYBINDINGS.export.desc),
+  (
+    DEFAULT_KEYBINDINGS.refresh,
+    DEFAULT_KEYBINDINGS.refresh.desc,
+  ),
+];
+
+pub static EDIT_QUALITY_PROFILE_CONTEXT_CLUES: [ContextClue; 7] = [
+  (DEFAULT_KEYBINDINGS.select, "toggle allowed"),
+  (
+    DEFAULT_KEYBINDINGS.move_up,
+    DEFAULT_KEYBINDINGS.move_up.desc,
+  ),
+  (
+    DEFAULT_KEYBINDINGS.move_down,
+    DEFAULT_KEYBINDINGS.move_down.desc,
+  ),
+  (DEFAULT_KEYBINDINGS.group, DEFAULT_KEYBINDINGS.group.desc),
+  (DEFAULT_KEYBINDINGS.cutoff, DEFAULT_KEYBINDINGS.cutoff.desc),
+  (DEFAULT_KEYBINDINGS.confirm, "save"),
+  (DEFAULT_KEYBINDINGS.esc, "cancel"),
+];
+
+/// Shown in the forms that are generated from a Servarr schema (e.g. adding an indexer)
+pub static SCHEMA_FORM_CONTEXT_CLUES: [ContextClue; 3] = [
+  (DEFAULT_KEYBINDINGS.submit, "edit field"),
+  (DEFAULT_KEYBINDINGS.confirm, "test and save"),
+  (DEFAULT_KEYBINDINGS.esc, "cancel"),
+];
+
+/// Shown in the forms for editing the settings of a Servarr (e.g. media management)
+pub static SETTINGS_FORM_CONTEXT_CLUES: [ContextClue; 3] = [
+  (DEFAULT_KEYBINDINGS.submit, "edit field"),
+  (DEFAULT_KEYBINDINGS.confirm, "save"),
+  (DEFAULT_KEYBINDINGS.esc, "cancel"),
+];
+
 pub static HISTORY_CONTEXT_CLUES: [ContextClue; 6] = [
   (DEFAULT_KEYBINDINGS.submit, "details"),
   (DEFAULT_KEYBINDINGS.sort, DEFAULT_KEYBINDINGS.sort.desc),
@@ -125,7 +248,87 @@ pub static SYSTEM_CONTEXT_CLUES: [ContextClue; 5] = [
   ),
 ];
 
+pub static SYSTEM_WITH_BACKUPS_CONTEXT_CLUES: [ContextClue; 6] = [
+  (DEFAULT_KEYBINDINGS.tasks, "open tasks"),
+  (DEFAULT_KEYBINDINGS.events, "open events"),
+  (DEFAULT_KEYBINDINGS.logs, "open logs"),
+  (DEFAULT_KEYBINDINGS.backups, "open backups"),
+  (DEFAULT_KEYBINDINGS.update, "open updates"),
+  (
+    DEFAULT_KEYBINDINGS.refresh,
+    DEFAULT_KEYBINDINGS.refresh.desc,
+  ),
+];
+
+pub static SYSTEM_BACKUPS_CONTEXT_CLUES: [ContextClue; 3] = [
+  (DEFAULT_KEYBINDINGS.delete, "delete backup"),
+  (
+    DEFAULT_KEYBINDINGS.refresh,
+    DEFAULT_KEYBINDINGS.refresh.desc,
+  ),
+  (DEFAULT_KEYBINDINGS.esc, DEFAULT_KEYBINDINGS.esc.desc),
+];
+
 pub static SYSTEM_TASKS_CONTEXT_CLUES: [ContextClue; 2] = [
   (DEFAULT_KEYBINDINGS.submit, "start task"),
   (DEFAULT_KEYBINDINGS.esc, DEFAULT_KEYBINDINGS.esc.desc),
 ];
+
+/// Every set of context clues that is displayed for a single route; used to detect keybindings
+/// that conflict with one another once user overrides have been applied
+pub static ROUTE_CONTEXT_CLUES: [&[ContextClue]; 54] = [
+  &BARE_POPUP_CONTEXT_CLUES,
+  &BLOCKLIST_CONTEXT_CLUES,
+  &CALENDAR_CONTEXT_CLUES,
+  &WANTED_CONTEXT_CLUES,
+  &CONFIRMATION_PROMPT_CONTEXT_CLUES,
+  &DOWNLOADS_CONTEXT_CLUES,
+  &MANUAL_IMPORT_CONTEXT_CLUES,
+  &ROOT_FOLDERS_CONTEXT_CLUES,
+  &INDEXERS_CONTEXT_CLUES,
+  &SCHEMA_FORM_CONTEXT_CLUES,
+  &SETTINGS_FORM_CONTEXT_CLUES,
+  &DOWNLOAD_CLIENTS_CONTEXT_CLUES,
+  &QUALITY_PROFILES_CONTEXT_CLUES,
+  &EDIT_QUALITY_PROFILE_CONTEXT_CLUES,
+  &CUSTOM_FORMATS_CONTEXT_CLUES,
+  &HISTORY_CONTEXT_CLUES,
+  &SYSTEM_CONTEXT_CLUES,
+  &SYSTEM_WITH_BACKUPS_CONTEXT_CLUES,
+  &SYSTEM_BACKUPS_CONTEXT_CLUES,
+  &SYSTEM_TASKS_CONTEXT_CLUES,
+  &LIBRARY_CONTEXT_CLUES,
+  &COLLECTIONS_CONTEXT_CLUES,
+  &MOVIE_DETAILS_CONTEXT_CLUES,
+  &MANUAL_MOVIE_SEARCH_CONTEXT_CLUES,
+  &ADD_MOVIE_SEARCH_RESULTS_CONTEXT_CLUES,
+  &COLLECTION_DETAILS_CONTEXT_CLUES,
+  &ADD_SERIES_SEARCH_RESULTS_CONTEXT_CLUES,
+  &SERIES_CONTEXT_CLUES,
+  &SERIES_DETAILS_CONTEXT_CLUES,
+  &SERIES_HISTORY_CONTEXT_CLUES,
+  &SEASON_DETAILS_CONTEXT_CLUES,
+  &SEASON_HISTORY_CONTEXT_CLUES,
+  &MANUAL_SEASON_SEARCH_CONTEXT_CLUES,
+  &MANUAL_EPISODE_SEARCH_CONTEXT_CLUES,
+  &EPISODE_DETAILS_CONTEXT_CLUES,
+  &SELECTABLE_EPISODE_DETAILS_CONTEXT_CLUES,
+  &ARTISTS_CONTEXT_CLUES,
+  &ADD_ARTIST_SEARCH_RESULTS_CONTEXT_CLUES,
+  &ARTIST_DETAILS_CONTEXT_CLUES,
+  &ARTIST_HISTORY_CONTEXT_CLUES,
+  &MANUAL_ARTIST_SEARCH_CONTEXT_CLUES,
+  &ALBUM_DETAILS_CONTEXT_CLUES,
+  &ALBUM_HISTORY_CONTEXT_CLUES,
+  &MANUAL_ALBUM_SEARCH_CONTEXT_CLUES,
+  &TRACK_DETAILS_CONTEXT_CLUES,
+  &TRACK_HISTORY_CONTEXT_CLUES,
+  &AUTHORS_CONTEXT_CLUES,
+  &ADD_AUTHOR_SEARCH_RESULTS_CONTEXT_CLUES,
+  &AUTHOR_DETAILS_CONTEXT_CLUES,
+  &BOOK_DETAILS_CONTEXT_CLUES,
+  &PROWLARR_INDEXERS_CONTEXT_CLUES,
+  &SEARCH_RELEASES_CONTEXT_CLUES,
+  &APPLICATIONS_CONTEXT_CLUES,
+  &INDEXER_PROXIES_CONTEXT_CLUES,
+];
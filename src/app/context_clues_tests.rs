@@ -1,14 +1,20 @@
 #[cfg(test)]
 mod test {
   use crate::app::context_clues::{
-    BARE_POPUP_CONTEXT_CLUES, BLOCKLIST_CONTEXT_CLUES, CONFIRMATION_PROMPT_CONTEXT_CLUES,
-    ContextClueProvider, DOWNLOADS_CONTEXT_CLUES, HISTORY_CONTEXT_CLUES, INDEXERS_CONTEXT_CLUES,
-    ROOT_FOLDERS_CONTEXT_CLUES, SERVARR_CONTEXT_CLUES, SYSTEM_CONTEXT_CLUES,
-    SYSTEM_TASKS_CONTEXT_CLUES, ServarrContextClueProvider,
+    BARE_POPUP_CONTEXT_CLUES, BLOCKLIST_CONTEXT_CLUES, CALENDAR_CONTEXT_CLUES,
+    CONFIRMATION_PROMPT_CONTEXT_CLUES, CUSTOM_FORMATS_CONTEXT_CLUES, ContextClueProvider,
+    DOWNLOAD_CLIENTS_CONTEXT_CLUES, DOWNLOADS_CONTEXT_CLUES, EDIT_QUALITY_PROFILE_CONTEXT_CLUES,
+    HISTORY_CONTEXT_CLUES, INDEXERS_CONTEXT_CLUES, MANUAL_IMPORT_CONTEXT_CLUES,
+    QUALITY_PROFILES_CONTEXT_CLUES, ROOT_FOLDERS_CONTEXT_CLUES, SCHEMA_FORM_CONTEXT_CLUES,
+    SERVARR_CONTEXT_CLUES, SETTINGS_FORM_CONTEXT_CLUES, SYSTEM_BACKUPS_CONTEXT_CLUES,
+    SYSTEM_CONTEXT_CLUES, SYSTEM_TASKS_CONTEXT_CLUES, SYSTEM_WITH_BACKUPS_CONTEXT_CLUES,
+    ServarrContextClueProvider, WANTED_CONTEXT_CLUES,
   };
   use crate::app::{App, key_binding::DEFAULT_KEYBINDINGS};
   use crate::models::servarr_data::ActiveKeybindingBlock;
+  use crate::models::servarr_data::prowlarr::prowlarr_data::ActiveProwlarrBlock;
   use crate::models::servarr_data::radarr::radarr_data::ActiveRadarrBlock;
+  use crate::models::servarr_data::readarr::readarr_data::ActiveReadarrBlock;
   use crate::models::servarr_data::sonarr::sonarr_data::ActiveSonarrBlock;
 
   #[test]
@@ -93,13 +99,51 @@ mod test {
       downloads_context_clues_iter.next(),
       &(DEFAULT_KEYBINDINGS.delete, DEFAULT_KEYBINDINGS.delete.desc)
     );
+    assert_some_eq_x!(
+      downloads_context_clues_iter.next(),
+      &(DEFAULT_KEYBINDINGS.select, DEFAULT_KEYBINDINGS.select.desc)
+    );
     assert_some_eq_x!(
       downloads_context_clues_iter.next(),
       &(DEFAULT_KEYBINDINGS.update, "update downloads")
     );
+    assert_some_eq_x!(
+      downloads_context_clues_iter.next(),
+      &(DEFAULT_KEYBINDINGS.import, "manual import")
+    );
+    assert_some_eq_x!(
+      downloads_context_clues_iter.next(),
+      &(DEFAULT_KEYBINDINGS.grab, "grab pending")
+    );
     assert_none!(downloads_context_clues_iter.next());
   }
 
+  #[test]
+  fn test_manual_import_context_clues() {
+    let mut manual_import_context_clues_iter = MANUAL_IMPORT_CONTEXT_CLUES.iter();
+
+    assert_some_eq_x!(
+      manual_import_context_clues_iter.next(),
+      &(DEFAULT_KEYBINDINGS.submit, "change mapping")
+    );
+    assert_some_eq_x!(
+      manual_import_context_clues_iter.next(),
+      &(DEFAULT_KEYBINDINGS.import, DEFAULT_KEYBINDINGS.import.desc)
+    );
+    assert_some_eq_x!(
+      manual_import_context_clues_iter.next(),
+      &(
+        DEFAULT_KEYBINDINGS.refresh,
+        DEFAULT_KEYBINDINGS.refresh.desc
+      )
+    );
+    assert_some_eq_x!(
+      manual_import_context_clues_iter.next(),
+      &(DEFAULT_KEYBINDINGS.esc, DEFAULT_KEYBINDINGS.esc.desc)
+    );
+    assert_none!(manual_import_context_clues_iter.next());
+  }
+
   #[test]
   fn test_blocklist_context_clues() {
     let mut blocklist_context_clues_iter = BLOCKLIST_CONTEXT_CLUES.iter();
@@ -130,6 +174,53 @@ mod test {
     assert_none!(blocklist_context_clues_iter.next());
   }
 
+  #[test]
+  fn test_calendar_context_clues() {
+    let mut calendar_context_clues_iter = CALENDAR_CONTEXT_CLUES.iter();
+
+    assert_some_eq_x!(
+      calendar_context_clues_iter.next(),
+      &(
+        DEFAULT_KEYBINDINGS.toggle_view,
+        DEFAULT_KEYBINDINGS.toggle_view.desc
+      )
+    );
+    assert_some_eq_x!(
+      calendar_context_clues_iter.next(),
+      &(
+        DEFAULT_KEYBINDINGS.refresh,
+        DEFAULT_KEYBINDINGS.refresh.desc
+      )
+    );
+    assert_none!(calendar_context_clues_iter.next());
+  }
+
+  #[test]
+  fn test_wanted_context_clues() {
+    let mut wanted_context_clues_iter = WANTED_CONTEXT_CLUES.iter();
+
+    assert_some_eq_x!(
+      wanted_context_clues_iter.next(),
+      &(DEFAULT_KEYBINDINGS.toggle_view, "missing/cutoff unmet")
+    );
+    assert_some_eq_x!(
+      wanted_context_clues_iter.next(),
+      &(DEFAULT_KEYBINDINGS.submit, "search selected")
+    );
+    assert_some_eq_x!(
+      wanted_context_clues_iter.next(),
+      &(DEFAULT_KEYBINDINGS.auto_search, "search all")
+    );
+    assert_some_eq_x!(
+      wanted_context_clues_iter.next(),
+      &(
+        DEFAULT_KEYBINDINGS.refresh,
+        DEFAULT_KEYBINDINGS.refresh.desc
+      )
+    );
+    assert_none!(wanted_context_clues_iter.next());
+  }
+
   #[test]
   fn test_confirmation_prompt_context_clues() {
     let mut confirmation_prompt_context_clues_iter = CONFIRMATION_PROMPT_CONTEXT_CLUES.iter();
@@ -145,6 +236,44 @@ mod test {
     assert_none!(confirmation_prompt_context_clues_iter.next());
   }
 
+  #[test]
+  fn test_schema_form_context_clues() {
+    let mut schema_form_context_clues_iter = SCHEMA_FORM_CONTEXT_CLUES.iter();
+
+    assert_some_eq_x!(
+      schema_form_context_clues_iter.next(),
+      &(DEFAULT_KEYBINDINGS.submit, "edit field")
+    );
+    assert_some_eq_x!(
+      schema_form_context_clues_iter.next(),
+      &(DEFAULT_KEYBINDINGS.confirm, "test and save")
+    );
+    assert_some_eq_x!(
+      schema_form_context_clues_iter.next(),
+      &(DEFAULT_KEYBINDINGS.esc, "cancel")
+    );
+    assert_none!(schema_form_context_clues_iter.next());
+  }
+
+  #[test]
+  fn test_settings_form_context_clues() {
+    let mut settings_form_context_clues_iter = SETTINGS_FORM_CONTEXT_CLUES.iter();
+
+    assert_some_eq_x!(
+      settings_form_context_clues_iter.next(),
+      &(DEFAULT_KEYBINDINGS.submit, "edit field")
+    );
+    assert_some_eq_x!(
+      settings_form_context_clues_iter.next(),
+      &(DEFAULT_KEYBINDINGS.confirm, "save")
+    );
+    assert_some_eq_x!(
+      settings_form_context_clues_iter.next(),
+      &(DEFAULT_KEYBINDINGS.esc, "cancel")
+    );
+    assert_none!(settings_form_context_clues_iter.next());
+  }
+
   #[test]
   fn test_root_folders_context_clues() {
     let mut root_folders_context_clues_iter = ROOT_FOLDERS_CONTEXT_CLUES.iter();
@@ -157,6 +286,10 @@ mod test {
       root_folders_context_clues_iter.next(),
       &(DEFAULT_KEYBINDINGS.delete, DEFAULT_KEYBINDINGS.delete.desc)
     );
+    assert_some_eq_x!(
+      root_folders_context_clues_iter.next(),
+      &(DEFAULT_KEYBINDINGS.settings, "media management")
+    );
     assert_some_eq_x!(
       root_folders_context_clues_iter.next(),
       &(
@@ -171,6 +304,10 @@ mod test {
   fn test_indexers_context_clues() {
     let mut indexers_context_clues_iter = INDEXERS_CONTEXT_CLUES.iter();
 
+    assert_some_eq_x!(
+      indexers_context_clues_iter.next(),
+      &(DEFAULT_KEYBINDINGS.add, DEFAULT_KEYBINDINGS.add.desc)
+    );
     assert_some_eq_x!(
       indexers_context_clues_iter.next(),
       &(DEFAULT_KEYBINDINGS.submit, "edit indexer")
@@ -204,6 +341,133 @@ mod test {
     assert_none!(indexers_context_clues_iter.next());
   }
 
+  #[test]
+  fn test_download_clients_context_clues() {
+    let mut download_clients_context_clues_iter = DOWNLOAD_CLIENTS_CONTEXT_CLUES.iter();
+
+    assert_some_eq_x!(
+      download_clients_context_clues_iter.next(),
+      &(DEFAULT_KEYBINDINGS.add, DEFAULT_KEYBINDINGS.add.desc)
+    );
+    assert_some_eq_x!(
+      download_clients_context_clues_iter.next(),
+      &(DEFAULT_KEYBINDINGS.submit, "edit download client")
+    );
+    assert_some_eq_x!(
+      download_clients_context_clues_iter.next(),
+      &(DEFAULT_KEYBINDINGS.delete, DEFAULT_KEYBINDINGS.delete.desc)
+    );
+    assert_some_eq_x!(
+      download_clients_context_clues_iter.next(),
+      &(DEFAULT_KEYBINDINGS.test, "test download client")
+    );
+    assert_some_eq_x!(
+      download_clients_context_clues_iter.next(),
+      &(DEFAULT_KEYBINDINGS.test_all, "test all download clients")
+    );
+    assert_some_eq_x!(
+      download_clients_context_clues_iter.next(),
+      &(
+        DEFAULT_KEYBINDINGS.refresh,
+        DEFAULT_KEYBINDINGS.refresh.desc
+      )
+    );
+    assert_none!(download_clients_context_clues_iter.next());
+  }
+
+  #[test]
+  fn test_quality_profiles_context_clues() {
+    let mut quality_profiles_context_clues_iter = QUALITY_PROFILES_CONTEXT_CLUES.iter();
+
+    assert_some_eq_x!(
+      quality_profiles_context_clues_iter.next(),
+      &(DEFAULT_KEYBINDINGS.submit, "edit quality profile")
+    );
+    assert_some_eq_x!(
+      quality_profiles_context_clues_iter.next(),
+      &(
+        DEFAULT_KEYBINDINGS.refresh,
+        DEFAULT_KEYBINDINGS.refresh.desc
+      )
+    );
+    assert_none!(quality_profiles_context_clues_iter.next());
+  }
+
+  #[test]
+  fn test_edit_quality_profile_context_clues() {
+    let mut edit_quality_profile_context_clues_iter = EDIT_QUALITY_PROFILE_CONTEXT_CLUES.iter();
+
+    assert_some_eq_x!(
+      edit_quality_profile_context_clues_iter.next(),
+      &(DEFAULT_KEYBINDINGS.select, "toggle allowed")
+    );
+    assert_some_eq_x!(
+      edit_quality_profile_context_clues_iter.next(),
+      &(
+        DEFAULT_KEYBINDINGS.move_up,
+        DEFAULT_KEYBINDINGS.move_up.desc
+      )
+    );
+    assert_some_eq_x!(
+      edit_quality_profile_context_clues_iter.next(),
+      &(
+        DEFAULT_KEYBINDINGS.move_down,
+        DEFAULT_KEYBINDINGS.move_down.desc
+      )
+    );
+    assert_some_eq_x!(
+      edit_quality_profile_context_clues_iter.next(),
+      &(DEFAULT_KEYBINDINGS.group, DEFAULT_KEYBINDINGS.group.desc)
+    );
+    assert_some_eq_x!(
+      edit_quality_profile_context_clues_iter.next(),
+      &(DEFAULT_KEYBINDINGS.cutoff, DEFAULT_KEYBINDINGS.cutoff.desc)
+    );
+    assert_some_eq_x!(
+      edit_quality_profile_context_clues_iter.next(),
+      &(DEFAULT_KEYBINDINGS.confirm, "save")
+    );
+    assert_some_eq_x!(
+      edit_quality_profile_context_clues_iter.next(),
+      &(DEFAULT_KEYBINDINGS.esc, "cancel")
+    );
+    assert_none!(edit_quality_profile_context_clues_iter.next());
+  }
+
+  #[test]
+  fn test_custom_formats_context_clues() {
+    let mut custom_formats_context_clues_iter = CUSTOM_FORMATS_CONTEXT_CLUES.iter();
+
+    assert_some_eq_x!(
+      custom_formats_context_clues_iter.next(),
+      &(DEFAULT_KEYBINDINGS.submit, "specifications")
+    );
+    assert_some_eq_x!(
+      custom_formats_context_clues_iter.next(),
+      &(DEFAULT_KEYBINDINGS.edit, "edit custom format")
+    );
+    assert_some_eq_x!(
+      custom_formats_context_clues_iter.next(),
+      &(DEFAULT_KEYBINDINGS.delete, DEFAULT_KEYBINDINGS.delete.desc)
+    );
+    assert_some_eq_x!(
+      custom_formats_context_clues_iter.next(),
+      &(DEFAULT_KEYBINDINGS.import, DEFAULT_KEYBINDINGS.import.desc)
+    );
+    assert_some_eq_x!(
+      custom_formats_context_clues_iter.next(),
+      &(DEFAULT_KEYBINDINGS.export, DEFAULT_KEYBINDINGS.export.desc)
+    );
+    assert_some_eq_x!(
+      custom_formats_context_clues_iter.next(),
+      &(
+        DEFAULT_KEYBINDINGS.refresh,
+        DEFAULT_KEYBINDINGS.refresh.desc
+      )
+    );
+    assert_none!(custom_formats_context_clues_iter.next());
+  }
+
   #[test]
   fn test_history_context_clues() {
     let mut history_context_clues_iter = HISTORY_CONTEXT_CLUES.iter();
@@ -268,6 +532,62 @@ mod test {
     assert_none!(system_context_clues_iter.next());
   }
 
+  #[test]
+  fn test_system_with_backups_context_clues() {
+    let mut system_context_clues_iter = SYSTEM_WITH_BACKUPS_CONTEXT_CLUES.iter();
+
+    assert_some_eq_x!(
+      system_context_clues_iter.next(),
+      &(DEFAULT_KEYBINDINGS.tasks, "open tasks")
+    );
+    assert_some_eq_x!(
+      system_context_clues_iter.next(),
+      &(DEFAULT_KEYBINDINGS.events, "open events")
+    );
+    assert_some_eq_x!(
+      system_context_clues_iter.next(),
+      &(DEFAULT_KEYBINDINGS.logs, "open logs")
+    );
+    assert_some_eq_x!(
+      system_context_clues_iter.next(),
+      &(DEFAULT_KEYBINDINGS.backups, "open backups")
+    );
+    assert_some_eq_x!(
+      system_context_clues_iter.next(),
+      &(DEFAULT_KEYBINDINGS.update, "open updates")
+    );
+    assert_some_eq_x!(
+      system_context_clues_iter.next(),
+      &(
+        DEFAULT_KEYBINDINGS.refresh,
+        DEFAULT_KEYBINDINGS.refresh.desc
+      )
+    );
+    assert_none!(system_context_clues_iter.next());
+  }
+
+  #[test]
+  fn test_system_backups_context_clues() {
+    let mut system_backups_context_clues_iter = SYSTEM_BACKUPS_CONTEXT_CLUES.iter();
+
+    assert_some_eq_x!(
+      system_backups_context_clues_iter.next(),
+      &(DEFAULT_KEYBINDINGS.delete, "delete backup")
+    );
+    assert_some_eq_x!(
+      system_backups_context_clues_iter.next(),
+      &(
+        DEFAULT_KEYBINDINGS.refresh,
+        DEFAULT_KEYBINDINGS.refresh.desc
+      )
+    );
+    assert_some_eq_x!(
+      system_backups_context_clues_iter.next(),
+      &(DEFAULT_KEYBINDINGS.esc, DEFAULT_KEYBINDINGS.esc.desc)
+    );
+    assert_none!(system_backups_context_clues_iter.next());
+  }
+
   #[test]
   fn test_system_tasks_context_clues() {
     let mut system_tasks_context_clues_iter = SYSTEM_TASKS_CONTEXT_CLUES.iter();
@@ -303,6 +623,26 @@ mod test {
     assert_some_eq_x!(context_clues, &SYSTEM_TASKS_CONTEXT_CLUES,);
   }
 
+  #[test]
+  fn test_servarr_context_clue_provider_delegates_to_prowlarr_provider() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveProwlarrBlock::SystemTasks.into());
+
+    let context_clues = ServarrContextClueProvider::get_context_clues(&mut app);
+
+    assert_some_eq_x!(context_clues, &SYSTEM_TASKS_CONTEXT_CLUES,);
+  }
+
+  #[test]
+  fn test_servarr_context_clue_provider_delegates_to_readarr_provider() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveReadarrBlock::SystemTasks.into());
+
+    let context_clues = ServarrContextClueProvider::get_context_clues(&mut app);
+
+    assert_some_eq_x!(context_clues, &SYSTEM_TASKS_CONTEXT_CLUES,);
+  }
+
   #[test]
   fn test_servarr_context_clue_provider_unsupported_route_returns_none() {
     let mut app = App::test_default();
@@ -1,10 +1,42 @@
+use std::collections::BTreeSet;
+use std::sync::{PoisonError, RwLock};
+
+use serde::{Deserialize, Serialize};
+
+use crate::app::context_clues::{ROUTE_CONTEXT_CLUES, SERVARR_CONTEXT_CLUES};
 use crate::event::Key;
+use crate::handlers::HANDLER_KEYBINDINGS;
 
 macro_rules! generate_keybindings {
     ($($field:ident),+) => {
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
         pub struct KeyBindings {
             $(pub $field: KeyBinding),+
         }
+
+        #[derive(Default, Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+        #[serde(deny_unknown_fields)]
+        pub struct KeyBindingOverrides {
+            $(
+              #[serde(default, skip_serializing_if = "Option::is_none")]
+              pub $field: Option<KeyBindingOverride>
+            ),+
+        }
+
+        impl KeyBindings {
+            pub fn with_overrides(mut self, overrides: &KeyBindingOverrides) -> Self {
+                $(
+                  if let Some(binding_override) = &overrides.$field {
+                    binding_override.apply(&mut self.$field);
+                  }
+                )+
+                self
+            }
+
+            fn named_bindings(&self) -> Vec<(&'static str, KeyBinding)> {
+                vec![$((stringify!($field), self.$field)),+]
+            }
+        }
     };
 }
 
@@ -32,9 +64,21 @@ generate_keybindings! {
   test,
   test_all,
   toggle_monitoring,
+  toggle_view,
+  move_up,
+  move_down,
+  group,
+  cutoff,
+  select,
+  select_all,
+  visual_select,
   refresh,
   update,
+  import,
+  export,
+  grab,
   events,
+  backups,
   home,
   end,
   delete,
@@ -51,6 +95,117 @@ pub struct KeyBinding {
   pub desc: &'static str,
 }
 
+impl KeyBinding {
+  fn keys(&self) -> Vec<Key> {
+    std::iter::once(self.key).chain(self.alt).collect()
+  }
+}
+
+#[derive(Default, Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct KeyBindingOverride {
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub key: Option<Key>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub alt: Option<Key>,
+}
+
+impl KeyBindingOverride {
+  fn apply(&self, binding: &mut KeyBinding) {
+    if let Some(key) = self.key {
+      binding.key = key;
+    }
+
+    if let Some(alt) = self.alt {
+      binding.alt = Some(alt);
+    }
+  }
+}
+
+/// The active keybindings, shared by every thread so the overrides loaded from the config apply
+/// no matter which thread resolves a key
+static KEYBINDINGS: RwLock<KeyBindings> = RwLock::new(DEFAULT_KEYBINDINGS);
+
+pub fn keybindings() -> KeyBindings {
+  *KEYBINDINGS.read().unwrap_or_else(PoisonError::into_inner)
+}
+
+pub fn set_keybindings(keybindings: KeyBindings) {
+  *KEYBINDINGS.write().unwrap_or_else(PoisonError::into_inner) = keybindings;
+}
+
+impl KeyBindings {
+  /// Maps a binding taken from [DEFAULT_KEYBINDINGS] (e.g. one stored in a static list of
+  /// context clues) to the corresponding binding in `self`.
+  pub fn resolve(&self, default_binding: &KeyBinding) -> KeyBinding {
+    DEFAULT_KEYBINDINGS
+      .named_bindings()
+      .into_iter()
+      .zip(self.named_bindings())
+      .find(|((_, default), _)| default == default_binding)
+      .map_or(*default_binding, |(_, (_, binding))| binding)
+  }
+
+  /// Returns a description of every pair of bindings that share a key while both being active
+  /// within the same route.
+  pub fn conflicts(&self) -> Vec<String> {
+    let mut conflicts = BTreeSet::new();
+
+    for route_context_clues in ROUTE_CONTEXT_CLUES {
+      let route_bindings = self.named_bindings_in(
+        route_context_clues
+          .iter()
+          .chain(SERVARR_CONTEXT_CLUES.iter())
+          .map(|(binding, _)| binding)
+          .chain(HANDLER_KEYBINDINGS.iter()),
+      );
+
+      for (i, (name, binding)) in route_bindings.iter().enumerate() {
+        for (other_name, other_binding) in &route_bindings[i + 1..] {
+          for key in binding.keys() {
+            if other_binding.keys().contains(&key) {
+              let (first, second) = if name < other_name {
+                (name, other_name)
+              } else {
+                (other_name, name)
+              };
+              conflicts.insert(format!(
+                "Keybindings '{first}' and '{second}' are both bound to '{key}'"
+              ));
+            }
+          }
+        }
+      }
+    }
+
+    conflicts.into_iter().collect()
+  }
+
+  fn named_bindings_in<'a>(
+    &self,
+    default_bindings: impl Iterator<Item = &'a KeyBinding>,
+  ) -> Vec<(&'static str, KeyBinding)> {
+    let defaults = DEFAULT_KEYBINDINGS.named_bindings();
+    let mut names = Vec::new();
+
+    for default_binding in default_bindings {
+      if let Some((name, _)) = defaults
+        .iter()
+        .find(|(_, default)| default == default_binding)
+        && !names.contains(name)
+      {
+        names.push(*name);
+      }
+    }
+
+    self
+      .named_bindings()
+      .into_iter()
+      .filter(|(name, _)| names.contains(name))
+      .collect()
+  }
+}
+
 pub const DEFAULT_KEYBINDINGS: KeyBindings = KeyBindings {
   add: KeyBinding {
     key: Key::Char('a'),
@@ -147,6 +302,11 @@ pub const DEFAULT_KEYBINDINGS: KeyBindings = KeyBindings {
     alt: None,
     desc: "events",
   },
+  backups: KeyBinding {
+    key: Key::Char('b'),
+    alt: None,
+    desc: "backups",
+  },
   logs: KeyBinding {
     key: Key::Char('L'),
     alt: None,
@@ -172,6 +332,46 @@ pub const DEFAULT_KEYBINDINGS: KeyBindings = KeyBindings {
     alt: None,
     desc: "toggle monitoring",
   },
+  toggle_view: KeyBinding {
+    key: Key::Char('v'),
+    alt: None,
+    desc: "toggle view",
+  },
+  move_up: KeyBinding {
+    key: Key::Char('K'),
+    alt: None,
+    desc: "move up",
+  },
+  move_down: KeyBinding {
+    key: Key::Char('J'),
+    alt: None,
+    desc: "move down",
+  },
+  group: KeyBinding {
+    key: Key::Char('G'),
+    alt: None,
+    desc: "group/ungroup",
+  },
+  cutoff: KeyBinding {
+    key: Key::Char('C'),
+    alt: None,
+    desc: "set cutoff",
+  },
+  select: KeyBinding {
+    key: Key::Char(' '),
+    alt: None,
+    desc: "toggle select",
+  },
+  select_all: KeyBinding {
+    key: Key::Ctrl('a'),
+    alt: None,
+    desc: "select all",
+  },
+  visual_select: KeyBinding {
+    key: Key::Char('V'),
+    alt: None,
+    desc: "visual select",
+  },
   refresh: KeyBinding {
     key: Key::Ctrl('r'),
     alt: None,
@@ -182,6 +382,21 @@ pub const DEFAULT_KEYBINDINGS: KeyBindings = KeyBindings {
     alt: None,
     desc: "update",
   },
+  import: KeyBinding {
+    key: Key::Char('i'),
+    alt: None,
+    desc: "import",
+  },
+  export: KeyBinding {
+    key: Key::Char('x'),
+    alt: None,
+    desc: "export",
+  },
+  grab: KeyBinding {
+    key: Key::Char('g'),
+    alt: None,
+    desc: "grab",
+  },
   home: KeyBinding {
     key: Key::Home,
     alt: None,
@@ -221,29 +436,12 @@ pub const DEFAULT_KEYBINDINGS: KeyBindings = KeyBindings {
 
 #[macro_export]
 macro_rules! matches_key {
-  ($binding:ident, $key:expr) => {
-    $crate::app::key_binding::DEFAULT_KEYBINDINGS.$binding.key == $key
-      || ($crate::app::key_binding::DEFAULT_KEYBINDINGS
-        .$binding
-        .alt
-        .is_some()
-        && $crate::app::key_binding::DEFAULT_KEYBINDINGS
-          .$binding
-          .alt
-          .unwrap()
-          == $key)
-  };
-  ($binding:ident, $key:expr, $ignore_special_keys:expr) => {
-    $crate::app::key_binding::DEFAULT_KEYBINDINGS.$binding.key == $key
-      || !$ignore_special_keys
-        && ($crate::app::key_binding::DEFAULT_KEYBINDINGS
-          .$binding
-          .alt
-          .is_some()
-          && $crate::app::key_binding::DEFAULT_KEYBINDINGS
-            .$binding
-            .alt
-            .unwrap()
-            == $key)
-  };
+  ($binding:ident, $key:expr) => {{
+    let binding = $crate::app::key_binding::keybindings().$binding;
+    binding.key == $key || binding.alt == Some($key)
+  }};
+  ($binding:ident, $key:expr, $ignore_special_keys:expr) => {{
+    let binding = $crate::app::key_binding::keybindings().$binding;
+    binding.key == $key || !$ignore_special_keys && binding.alt == Some($key)
+  }};
 }
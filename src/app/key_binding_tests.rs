@@ -1,9 +1,15 @@
 #[cfg(test)]
 mod test {
+  use indoc::indoc;
   use pretty_assertions::{assert_eq, assert_str_eq};
   use rstest::rstest;
+  use serial_test::serial;
+  use std::thread;
 
-  use crate::app::key_binding::{DEFAULT_KEYBINDINGS, KeyBinding};
+  use crate::app::key_binding::{
+    DEFAULT_KEYBINDINGS, KeyBinding, KeyBindingOverride, KeyBindingOverrides, keybindings,
+    set_keybindings,
+  };
   use crate::event::Key;
   use crate::matches_key;
 
@@ -27,13 +33,25 @@ mod test {
   #[case(DEFAULT_KEYBINDINGS.sort, Key::Char('o'), None, "sort")]
   #[case(DEFAULT_KEYBINDINGS.edit, Key::Char('e'), None, "edit")]
   #[case(DEFAULT_KEYBINDINGS.events, Key::Char('e'), None, "events")]
+  #[case(DEFAULT_KEYBINDINGS.backups, Key::Char('b'), None, "backups")]
   #[case(DEFAULT_KEYBINDINGS.logs, Key::Char('L'), None, "logs")]
   #[case(DEFAULT_KEYBINDINGS.tasks, Key::Char('t'), None, "tasks")]
   #[case(DEFAULT_KEYBINDINGS.test, Key::Char('t'), None, "test")]
   #[case(DEFAULT_KEYBINDINGS.test_all, Key::Char('T'), None, "test all")]
   #[case(DEFAULT_KEYBINDINGS.toggle_monitoring, Key::Char('m'), None, "toggle monitoring")]
+  #[case(DEFAULT_KEYBINDINGS.toggle_view, Key::Char('v'), None, "toggle view")]
+  #[case(DEFAULT_KEYBINDINGS.move_up, Key::Char('K'), None, "move up")]
+  #[case(DEFAULT_KEYBINDINGS.move_down, Key::Char('J'), None, "move down")]
+  #[case(DEFAULT_KEYBINDINGS.group, Key::Char('G'), None, "group/ungroup")]
+  #[case(DEFAULT_KEYBINDINGS.cutoff, Key::Char('C'), None, "set cutoff")]
+  #[case(DEFAULT_KEYBINDINGS.select, Key::Char(' '), None, "toggle select")]
+  #[case(DEFAULT_KEYBINDINGS.select_all, Key::Ctrl('a'), None, "select all")]
+  #[case(DEFAULT_KEYBINDINGS.visual_select, Key::Char('V'), None, "visual select")]
   #[case(DEFAULT_KEYBINDINGS.refresh, Key::Ctrl('r'), None, "refresh")]
   #[case(DEFAULT_KEYBINDINGS.update, Key::Char('u'), None, "update")]
+  #[case(DEFAULT_KEYBINDINGS.import, Key::Char('i'), None, "import")]
+  #[case(DEFAULT_KEYBINDINGS.export, Key::Char('x'), None, "export")]
+  #[case(DEFAULT_KEYBINDINGS.grab, Key::Char('g'), None, "grab")]
   #[case(DEFAULT_KEYBINDINGS.home, Key::Home, None, "home")]
   #[case(DEFAULT_KEYBINDINGS.end, Key::End, None, "end")]
   #[case(DEFAULT_KEYBINDINGS.delete, Key::Delete, None, "delete")]
@@ -89,4 +107,212 @@ mod test {
     assert!(!matches_key!(up, alt_key, true));
     assert!(!matches_key!(up, Key::Char('t'), true));
   }
+
+  // The keybindings are shared by every test thread, so the tests that override them only add an
+  // alt key that no other test sends, and run serially with the tests that compare them in full.
+  #[test]
+  #[serial]
+  fn test_matches_key_macro_uses_overridden_keybindings() {
+    let overrides = KeyBindingOverrides {
+      test: Some(KeyBindingOverride {
+        key: None,
+        alt: Some(Key::Ctrl('x')),
+      }),
+      ..KeyBindingOverrides::default()
+    };
+    set_keybindings(DEFAULT_KEYBINDINGS.with_overrides(&overrides));
+
+    assert!(matches_key!(test, Key::Char('t')));
+    assert!(matches_key!(test, Key::Ctrl('x')));
+    assert!(!matches_key!(test, Key::Ctrl('x'), true));
+    assert!(!matches_key!(test, Key::Char('x')));
+
+    set_keybindings(DEFAULT_KEYBINDINGS);
+  }
+
+  #[test]
+  #[serial]
+  fn test_keybindings_are_shared_across_threads() {
+    let overrides = KeyBindingOverrides {
+      test: Some(KeyBindingOverride {
+        key: None,
+        alt: Some(Key::Ctrl('x')),
+      }),
+      ..KeyBindingOverrides::default()
+    };
+    set_keybindings(DEFAULT_KEYBINDINGS.with_overrides(&overrides));
+
+    let resolved_binding = thread::spawn(|| keybindings().resolve(&DEFAULT_KEYBINDINGS.test))
+      .join()
+      .unwrap();
+    set_keybindings(DEFAULT_KEYBINDINGS);
+
+    assert_eq!(resolved_binding.key, Key::Char('t'));
+    assert_some_eq_x!(resolved_binding.alt, Key::Ctrl('x'));
+  }
+
+  #[test]
+  #[serial]
+  fn test_keybindings_defaults_to_default_keybindings() {
+    assert_eq!(keybindings(), DEFAULT_KEYBINDINGS);
+  }
+
+  #[test]
+  fn test_with_overrides() {
+    let overrides = KeyBindingOverrides {
+      up: Some(KeyBindingOverride {
+        key: None,
+        alt: Some(Key::Char('i')),
+      }),
+      quit: Some(KeyBindingOverride {
+        key: Some(Key::Ctrl('q')),
+        alt: None,
+      }),
+      ..KeyBindingOverrides::default()
+    };
+    let expected_keybindings = {
+      let mut keybindings = DEFAULT_KEYBINDINGS;
+      keybindings.up.alt = Some(Key::Char('i'));
+      keybindings.quit.key = Key::Ctrl('q');
+      keybindings
+    };
+
+    let keybindings = DEFAULT_KEYBINDINGS.with_overrides(&overrides);
+
+    assert_eq!(keybindings, expected_keybindings);
+    assert_str_eq!(keybindings.quit.desc, DEFAULT_KEYBINDINGS.quit.desc);
+  }
+
+  #[test]
+  fn test_with_overrides_empty_overrides() {
+    assert_eq!(
+      DEFAULT_KEYBINDINGS.with_overrides(&KeyBindingOverrides::default()),
+      DEFAULT_KEYBINDINGS
+    );
+  }
+
+  #[test]
+  fn test_resolve() {
+    let overrides = KeyBindingOverrides {
+      edit: Some(KeyBindingOverride {
+        key: Some(Key::Char('E')),
+        alt: None,
+      }),
+      ..KeyBindingOverrides::default()
+    };
+    let keybindings = DEFAULT_KEYBINDINGS.with_overrides(&overrides);
+
+    assert_eq!(
+      keybindings.resolve(&DEFAULT_KEYBINDINGS.edit),
+      keybindings.edit
+    );
+    assert_eq!(
+      keybindings.resolve(&DEFAULT_KEYBINDINGS.events),
+      DEFAULT_KEYBINDINGS.events
+    );
+  }
+
+  #[test]
+  fn test_default_keybindings_have_no_conflicts() {
+    assert_is_empty!(DEFAULT_KEYBINDINGS.conflicts());
+  }
+
+  #[test]
+  fn test_conflicts() {
+    let overrides = KeyBindingOverrides {
+      refresh: Some(KeyBindingOverride {
+        key: Some(Key::Char('q')),
+        alt: None,
+      }),
+      sort: Some(KeyBindingOverride {
+        key: None,
+        alt: Some(Key::Char('f')),
+      }),
+      ..KeyBindingOverrides::default()
+    };
+
+    let conflicts = DEFAULT_KEYBINDINGS.with_overrides(&overrides).conflicts();
+
+    assert_eq!(
+      conflicts,
+      vec![
+        "Keybindings 'filter' and 'sort' are both bound to 'f'".to_owned(),
+        "Keybindings 'quit' and 'refresh' are both bound to 'q'".to_owned(),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_conflicts_ignores_bindings_in_different_routes() {
+    let overrides = KeyBindingOverrides {
+      test_all: Some(KeyBindingOverride {
+        key: Some(Key::Char('L')),
+        alt: None,
+      }),
+      ..KeyBindingOverrides::default()
+    };
+
+    assert_is_empty!(DEFAULT_KEYBINDINGS.with_overrides(&overrides).conflicts());
+  }
+
+  #[test]
+  fn test_conflicts_includes_bindings_not_in_context_clues() {
+    let overrides = KeyBindingOverrides {
+      visual_select: Some(KeyBindingOverride {
+        key: Some(Key::Char('a')),
+        alt: None,
+      }),
+      home: Some(KeyBindingOverride {
+        key: None,
+        alt: Some(Key::Enter),
+      }),
+      ..KeyBindingOverrides::default()
+    };
+
+    let conflicts = DEFAULT_KEYBINDINGS.with_overrides(&overrides).conflicts();
+
+    assert_eq!(
+      conflicts,
+      vec![
+        "Keybindings 'add' and 'visual_select' are both bound to 'a'".to_owned(),
+        "Keybindings 'home' and 'submit' are both bound to 'enter'".to_owned(),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_deserialize_keybinding_overrides() {
+    let yaml = indoc! {"
+      up:
+        key: k
+        alt: ctrl-p
+      quit:
+        key: ctrl-q
+    "};
+    let expected_overrides = KeyBindingOverrides {
+      up: Some(KeyBindingOverride {
+        key: Some(Key::Char('k')),
+        alt: Some(Key::Ctrl('p')),
+      }),
+      quit: Some(KeyBindingOverride {
+        key: Some(Key::Ctrl('q')),
+        alt: None,
+      }),
+      ..KeyBindingOverrides::default()
+    };
+
+    let overrides: KeyBindingOverrides = serde_yaml::from_str(yaml).unwrap();
+
+    assert_eq!(overrides, expected_overrides);
+  }
+
+  #[test]
+  fn test_deserialize_keybinding_overrides_rejects_unknown_keybindings() {
+    let yaml = indoc! {"
+      not_a_keybinding:
+        key: k
+    "};
+
+    assert_err!(serde_yaml::from_str::<KeyBindingOverrides>(yaml));
+  }
 }
@@ -1,21 +1,22 @@
 use crate::app::App;
 use crate::app::context_clues::{
   BARE_POPUP_CONTEXT_CLUES, CONFIRMATION_PROMPT_CONTEXT_CLUES, ContextClue, ContextClueProvider,
-  SYSTEM_TASKS_CONTEXT_CLUES,
+  EDIT_QUALITY_PROFILE_CONTEXT_CLUES, MANUAL_IMPORT_CONTEXT_CLUES, SCHEMA_FORM_CONTEXT_CLUES,
+  SETTINGS_FORM_CONTEXT_CLUES, SYSTEM_BACKUPS_CONTEXT_CLUES, SYSTEM_TASKS_CONTEXT_CLUES,
 };
 use crate::app::key_binding::DEFAULT_KEYBINDINGS;
 use crate::models::Route;
 use crate::models::servarr_data::lidarr::lidarr_data::{
   ADD_ARTIST_BLOCKS, ADD_ROOT_FOLDER_BLOCKS, ALBUM_DETAILS_BLOCKS, ARTIST_DETAILS_BLOCKS,
-  ActiveLidarrBlock, EDIT_ARTIST_BLOCKS, EDIT_INDEXER_BLOCKS, INDEXER_SETTINGS_BLOCKS,
-  TRACK_DETAILS_BLOCKS,
+  ActiveLidarrBlock, BULK_EDIT_ARTISTS_BLOCKS, EDIT_ARTIST_BLOCKS, EDIT_INDEXER_BLOCKS,
+  INDEXER_SETTINGS_BLOCKS, TRACK_DETAILS_BLOCKS,
 };
 
 #[cfg(test)]
 #[path = "lidarr_context_clues_tests.rs"]
 mod lidarr_context_clues_tests;
 
-pub static ARTISTS_CONTEXT_CLUES: [ContextClue; 10] = [
+pub static ARTISTS_CONTEXT_CLUES: [ContextClue; 11] = [
   (DEFAULT_KEYBINDINGS.add, DEFAULT_KEYBINDINGS.add.desc),
   (
     DEFAULT_KEYBINDINGS.toggle_monitoring,
@@ -26,6 +27,7 @@ pub static ARTISTS_CONTEXT_CLUES: [ContextClue; 10] = [
   (DEFAULT_KEYBINDINGS.delete, DEFAULT_KEYBINDINGS.delete.desc),
   (DEFAULT_KEYBINDINGS.search, DEFAULT_KEYBINDINGS.search.desc),
   (DEFAULT_KEYBINDINGS.filter, DEFAULT_KEYBINDINGS.filter.desc),
+  (DEFAULT_KEYBINDINGS.select, DEFAULT_KEYBINDINGS.select.desc),
   (
     DEFAULT_KEYBINDINGS.refresh,
     DEFAULT_KEYBINDINGS.refresh.desc,
@@ -191,12 +193,24 @@ impl ContextClueProvider for LidarrContextClueProvider {
         .expect("track_details_modal is empty")
         .track_details_tabs
         .get_active_route_contextual_help(),
+      ActiveLidarrBlock::EditQualityProfile => Some(&EDIT_QUALITY_PROFILE_CONTEXT_CLUES),
+      ActiveLidarrBlock::AddIndexerPrompt | ActiveLidarrBlock::DownloadClientPrompt => {
+        Some(&SCHEMA_FORM_CONTEXT_CLUES)
+      }
+      ActiveLidarrBlock::MediaManagementSettingsPrompt => Some(&SETTINGS_FORM_CONTEXT_CLUES),
+      ActiveLidarrBlock::AddIndexerSelectImplementation
+      | ActiveLidarrBlock::AddIndexerFieldInput
+      | ActiveLidarrBlock::AddDownloadClientSelectImplementation
+      | ActiveLidarrBlock::DownloadClientFieldInput
+      | ActiveLidarrBlock::MediaManagementFieldInput => Some(&CONFIRMATION_PROMPT_CONTEXT_CLUES),
       ActiveLidarrBlock::AddArtistSearchInput
       | ActiveLidarrBlock::AddArtistEmptySearchResults
       | ActiveLidarrBlock::TestAllIndexers
+      | ActiveLidarrBlock::TestAllDownloadClients
       | ActiveLidarrBlock::SystemLogs
       | ActiveLidarrBlock::SystemUpdates => Some(&BARE_POPUP_CONTEXT_CLUES),
       _ if EDIT_ARTIST_BLOCKS.contains(&active_lidarr_block)
+        || BULK_EDIT_ARTISTS_BLOCKS.contains(&active_lidarr_block)
         || EDIT_INDEXER_BLOCKS.contains(&active_lidarr_block)
         || INDEXER_SETTINGS_BLOCKS.contains(&active_lidarr_block)
         || ADD_ROOT_FOLDER_BLOCKS.contains(&active_lidarr_block) =>
@@ -210,11 +224,15 @@ impl ContextClueProvider for LidarrContextClueProvider {
       | ActiveLidarrBlock::AddArtistSelectMetadataProfile
       | ActiveLidarrBlock::AddArtistSelectRootFolder
       | ActiveLidarrBlock::AddArtistTagsInput
-      | ActiveLidarrBlock::AddArtistAlreadyInLibrary => Some(&CONFIRMATION_PROMPT_CONTEXT_CLUES),
+      | ActiveLidarrBlock::AddArtistAlreadyInLibrary
+      | ActiveLidarrBlock::ManualImportConfirmPrompt
+      | ActiveLidarrBlock::ManualImportSelectArtist => Some(&CONFIRMATION_PROMPT_CONTEXT_CLUES),
       _ if ADD_ARTIST_BLOCKS.contains(&active_lidarr_block) => {
         Some(&ADD_ARTIST_SEARCH_RESULTS_CONTEXT_CLUES)
       }
+      ActiveLidarrBlock::SystemBackups => Some(&SYSTEM_BACKUPS_CONTEXT_CLUES),
       ActiveLidarrBlock::SystemTasks => Some(&SYSTEM_TASKS_CONTEXT_CLUES),
+      ActiveLidarrBlock::ManualImport => Some(&MANUAL_IMPORT_CONTEXT_CLUES),
       _ => app
         .data
         .lidarr_data
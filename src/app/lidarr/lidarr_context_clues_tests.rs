@@ -3,7 +3,8 @@ mod tests {
   use crate::app::App;
   use crate::app::context_clues::{
     BARE_POPUP_CONTEXT_CLUES, CONFIRMATION_PROMPT_CONTEXT_CLUES, ContextClue, ContextClueProvider,
-    SYSTEM_TASKS_CONTEXT_CLUES,
+    EDIT_QUALITY_PROFILE_CONTEXT_CLUES, MANUAL_IMPORT_CONTEXT_CLUES, SCHEMA_FORM_CONTEXT_CLUES,
+    SETTINGS_FORM_CONTEXT_CLUES, SYSTEM_BACKUPS_CONTEXT_CLUES, SYSTEM_TASKS_CONTEXT_CLUES,
   };
   use crate::app::key_binding::DEFAULT_KEYBINDINGS;
   use crate::app::lidarr::lidarr_context_clues::{
@@ -13,8 +14,8 @@ mod tests {
     MANUAL_ARTIST_SEARCH_CONTEXT_CLUES, TRACK_DETAILS_CONTEXT_CLUES, TRACK_HISTORY_CONTEXT_CLUES,
   };
   use crate::models::servarr_data::lidarr::lidarr_data::{
-    ADD_ROOT_FOLDER_BLOCKS, ActiveLidarrBlock, EDIT_ARTIST_BLOCKS, EDIT_INDEXER_BLOCKS,
-    INDEXER_SETTINGS_BLOCKS, LidarrData,
+    ADD_ROOT_FOLDER_BLOCKS, ActiveLidarrBlock, BULK_EDIT_ARTISTS_BLOCKS, EDIT_ARTIST_BLOCKS,
+    EDIT_INDEXER_BLOCKS, INDEXER_SETTINGS_BLOCKS, LidarrData,
   };
   use crate::models::servarr_data::lidarr::modals::{AlbumDetailsModal, TrackDetailsModal};
   use crate::models::servarr_data::radarr::radarr_data::ActiveRadarrBlock;
@@ -55,6 +56,10 @@ mod tests {
       artists_context_clues_iter.next(),
       &(DEFAULT_KEYBINDINGS.filter, DEFAULT_KEYBINDINGS.filter.desc)
     );
+    assert_some_eq_x!(
+      artists_context_clues_iter.next(),
+      &(DEFAULT_KEYBINDINGS.select, DEFAULT_KEYBINDINGS.select.desc)
+    );
     assert_some_eq_x!(
       artists_context_clues_iter.next(),
       &(
@@ -516,7 +521,8 @@ mod tests {
     #[values(
       ActiveLidarrBlock::AddArtistSearchInput,
       ActiveLidarrBlock::AddArtistEmptySearchResults,
-      ActiveLidarrBlock::TestAllIndexers
+      ActiveLidarrBlock::TestAllIndexers,
+      ActiveLidarrBlock::TestAllDownloadClients
     )]
     active_lidarr_block: ActiveLidarrBlock,
   ) {
@@ -531,6 +537,7 @@ mod tests {
   #[test]
   fn test_lidarr_context_clue_provider_confirmation_prompt_popup_clues_edit_indexer_blocks() {
     let mut blocks = EDIT_ARTIST_BLOCKS.to_vec();
+    blocks.extend(BULK_EDIT_ARTISTS_BLOCKS);
     blocks.extend(ADD_ROOT_FOLDER_BLOCKS);
     blocks.extend(INDEXER_SETTINGS_BLOCKS);
     blocks.extend(EDIT_INDEXER_BLOCKS);
@@ -565,7 +572,9 @@ mod tests {
       ActiveLidarrBlock::AddArtistSelectMetadataProfile,
       ActiveLidarrBlock::AddArtistSelectRootFolder,
       ActiveLidarrBlock::AddArtistTagsInput,
-      ActiveLidarrBlock::AddArtistAlreadyInLibrary
+      ActiveLidarrBlock::AddArtistAlreadyInLibrary,
+      ActiveLidarrBlock::ManualImportConfirmPrompt,
+      ActiveLidarrBlock::ManualImportSelectArtist
     )]
     active_lidarr_block: ActiveLidarrBlock,
   ) {
@@ -586,4 +595,78 @@ mod tests {
 
     assert_some_eq_x!(context_clues, &SYSTEM_TASKS_CONTEXT_CLUES);
   }
+
+  #[test]
+  fn test_lidarr_context_clue_provider_system_backups_clues() {
+    let mut app = App::test_default();
+
+    app.push_navigation_stack(ActiveLidarrBlock::SystemBackups.into());
+    let context_clues = LidarrContextClueProvider::get_context_clues(&mut app);
+
+    assert_some_eq_x!(context_clues, &SYSTEM_BACKUPS_CONTEXT_CLUES);
+  }
+
+  #[test]
+  fn test_lidarr_context_clue_provider_manual_import_clues() {
+    let mut app = App::test_default();
+
+    app.push_navigation_stack(ActiveLidarrBlock::ManualImport.into());
+    let context_clues = LidarrContextClueProvider::get_context_clues(&mut app);
+
+    assert_some_eq_x!(context_clues, &MANUAL_IMPORT_CONTEXT_CLUES);
+  }
+  #[test]
+  fn test_lidarr_context_clue_provider_edit_quality_profile_context_clues() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveLidarrBlock::EditQualityProfile.into());
+
+    let context_clues = LidarrContextClueProvider::get_context_clues(&mut app);
+
+    assert_some_eq_x!(context_clues, &EDIT_QUALITY_PROFILE_CONTEXT_CLUES);
+  }
+
+  #[test]
+  fn test_lidarr_context_clue_provider_media_management_settings_context_clues() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveLidarrBlock::MediaManagementSettingsPrompt.into());
+
+    let context_clues = LidarrContextClueProvider::get_context_clues(&mut app);
+
+    assert_some_eq_x!(context_clues, &SETTINGS_FORM_CONTEXT_CLUES);
+  }
+
+  #[rstest]
+  fn test_lidarr_context_clue_provider_schema_form_prompt_context_clues(
+    #[values(
+      ActiveLidarrBlock::AddIndexerPrompt,
+      ActiveLidarrBlock::DownloadClientPrompt
+    )]
+    active_lidarr_block: ActiveLidarrBlock,
+  ) {
+    let mut app = App::test_default();
+    app.push_navigation_stack(active_lidarr_block.into());
+
+    let context_clues = LidarrContextClueProvider::get_context_clues(&mut app);
+
+    assert_some_eq_x!(context_clues, &SCHEMA_FORM_CONTEXT_CLUES);
+  }
+
+  #[rstest]
+  fn test_lidarr_context_clue_provider_confirmation_prompt_context_clues_schema_form_blocks(
+    #[values(
+      ActiveLidarrBlock::AddIndexerSelectImplementation,
+      ActiveLidarrBlock::AddIndexerFieldInput,
+      ActiveLidarrBlock::AddDownloadClientSelectImplementation,
+      ActiveLidarrBlock::DownloadClientFieldInput,
+      ActiveLidarrBlock::MediaManagementFieldInput
+    )]
+    active_lidarr_block: ActiveLidarrBlock,
+  ) {
+    let mut app = App::test_default();
+    app.push_navigation_stack(active_lidarr_block.into());
+
+    let context_clues = LidarrContextClueProvider::get_context_clues(&mut app);
+
+    assert_some_eq_x!(context_clues, &CONFIRMATION_PROMPT_CONTEXT_CLUES);
+  }
 }
@@ -1,21 +1,26 @@
 #[cfg(test)]
 mod tests {
   use crate::app::App;
-  use crate::models::lidarr_models::{Album, Artist, LidarrRelease};
-  use crate::models::servarr_data::lidarr::lidarr_data::ActiveLidarrBlock;
-  use crate::models::servarr_data::lidarr::modals::AlbumDetailsModal;
-  use crate::models::servarr_models::Indexer;
-  use crate::network::NetworkEvent;
+  use crate::models::lidarr_models::{Album, Artist, LidarrRelease, NamingConfig};
+  use crate::models::servarr_data::lidarr::lidarr_data::{
+    ActiveLidarrBlock, MEDIA_MANAGEMENT_NAMING_FIELDS,
+  };
+  use crate::models::servarr_data::lidarr::modals::{AlbumDetailsModal, ManualImportModal};
+  use crate::models::servarr_data::modals::MediaManagementModal;
+  use crate::models::servarr_models::{DownloadClient, Indexer};
+  use crate::network::QueuedNetworkEvent;
   use crate::network::lidarr_network::LidarrEvent;
   use crate::network::lidarr_network::lidarr_network_test_utils::test_utils::{
-    album, artist, track,
+    album, artist, media_management_settings, naming_config, track,
   };
+  use crate::utils::calendar_range;
+  use chrono::Local;
   use pretty_assertions::{assert_eq, assert_str_eq};
   use tokio::sync::mpsc;
 
   #[tokio::test]
   async fn test_dispatch_by_lidarr_block_artists() {
-    let (tx, mut rx) = mpsc::channel::<NetworkEvent>(500);
+    let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
     let mut app = App::test_default();
     app.data.lidarr_data.prompt_confirm = true;
     app.network_tx = Some(tx);
@@ -26,22 +31,28 @@ mod tests {
 
     assert!(app.is_loading);
     assert_eq!(
-      rx.recv().await.unwrap(),
+      rx.recv().await.unwrap().network_event,
       LidarrEvent::GetQualityProfiles.into()
     );
     assert_eq!(
-      rx.recv().await.unwrap(),
+      rx.recv().await.unwrap().network_event,
       LidarrEvent::GetMetadataProfiles.into()
     );
-    assert_eq!(rx.recv().await.unwrap(), LidarrEvent::GetTags.into());
-    assert_eq!(rx.recv().await.unwrap(), LidarrEvent::ListArtists.into());
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      LidarrEvent::GetTags.into()
+    );
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      LidarrEvent::ListArtists.into()
+    );
     assert!(!app.data.lidarr_data.prompt_confirm);
     assert_eq!(app.tick_count, 0);
   }
 
   #[tokio::test]
   async fn test_dispatch_by_lidarr_block_artist_details() {
-    let (tx, mut rx) = mpsc::channel::<NetworkEvent>(500);
+    let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
     let mut app = App::test_default();
     app.data.lidarr_data.prompt_confirm = true;
     app.data.lidarr_data.artists.set_items(vec![artist()]);
@@ -52,14 +63,17 @@ mod tests {
       .await;
 
     assert!(app.is_loading);
-    assert_eq!(rx.recv().await.unwrap(), LidarrEvent::GetAlbums(1).into());
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      LidarrEvent::GetAlbums(1).into()
+    );
     assert!(!app.data.lidarr_data.prompt_confirm);
     assert_eq!(app.tick_count, 0);
   }
 
   #[tokio::test]
   async fn test_dispatch_by_blocklist_block() {
-    let (tx, mut rx) = mpsc::channel::<NetworkEvent>(500);
+    let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
     let mut app = App::test_default();
     app.data.lidarr_data.prompt_confirm = true;
     app.data.lidarr_data.artists.set_items(vec![artist()]);
@@ -70,14 +84,93 @@ mod tests {
       .await;
 
     assert!(app.is_loading);
-    assert_eq!(rx.recv().await.unwrap(), LidarrEvent::GetBlocklist.into());
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      LidarrEvent::GetBlocklist.into()
+    );
     assert!(!app.data.sonarr_data.prompt_confirm);
     assert_eq!(app.tick_count, 0);
   }
 
+  #[tokio::test]
+  async fn test_dispatch_by_calendar_blocks() {
+    for active_lidarr_block in [
+      ActiveLidarrBlock::Calendar,
+      ActiveLidarrBlock::CalendarAgenda,
+    ] {
+      let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
+      let mut app = App::test_default();
+      app.data.lidarr_data.prompt_confirm = true;
+      app.network_tx = Some(tx);
+      let (start, end) = calendar_range(Local::now().date_naive());
+
+      app.dispatch_by_lidarr_block(&active_lidarr_block).await;
+
+      assert!(app.is_loading);
+      assert_eq!(
+        rx.recv().await.unwrap().network_event,
+        LidarrEvent::ListArtists.into()
+      );
+      assert_eq!(
+        rx.recv().await.unwrap().network_event,
+        LidarrEvent::GetCalendar(start, end).into()
+      );
+      assert!(!app.data.lidarr_data.prompt_confirm);
+      assert_eq!(app.tick_count, 0);
+    }
+  }
+
+  #[tokio::test]
+  async fn test_dispatch_by_wanted_missing_block() {
+    let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
+    let mut app = App::test_default();
+    app.data.lidarr_data.prompt_confirm = true;
+    app.network_tx = Some(tx);
+
+    app
+      .dispatch_by_lidarr_block(&ActiveLidarrBlock::WantedMissing)
+      .await;
+
+    assert!(app.is_loading);
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      LidarrEvent::ListArtists.into()
+    );
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      LidarrEvent::GetWantedMissing(500).into()
+    );
+    assert!(!app.data.lidarr_data.prompt_confirm);
+    assert_eq!(app.tick_count, 0);
+  }
+
+  #[tokio::test]
+  async fn test_dispatch_by_wanted_cutoff_unmet_block() {
+    let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
+    let mut app = App::test_default();
+    app.data.lidarr_data.prompt_confirm = true;
+    app.network_tx = Some(tx);
+
+    app
+      .dispatch_by_lidarr_block(&ActiveLidarrBlock::WantedCutoffUnmet)
+      .await;
+
+    assert!(app.is_loading);
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      LidarrEvent::ListArtists.into()
+    );
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      LidarrEvent::GetCutoffUnmet(500).into()
+    );
+    assert!(!app.data.lidarr_data.prompt_confirm);
+    assert_eq!(app.tick_count, 0);
+  }
+
   #[tokio::test]
   async fn test_dispatch_by_artist_history_block() {
-    let (tx, mut rx) = mpsc::channel::<NetworkEvent>(500);
+    let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
     let mut app = App::test_default();
     app.data.lidarr_data.prompt_confirm = true;
     app.network_tx = Some(tx);
@@ -92,7 +185,7 @@ mod tests {
 
     assert!(app.is_loading);
     assert_eq!(
-      rx.recv().await.unwrap(),
+      rx.recv().await.unwrap().network_event,
       LidarrEvent::GetArtistHistory(1).into()
     );
     assert!(!app.data.lidarr_data.prompt_confirm);
@@ -101,7 +194,7 @@ mod tests {
 
   #[tokio::test]
   async fn test_dispatch_by_manual_artist_search_block() {
-    let (tx, mut rx) = mpsc::channel::<NetworkEvent>(500);
+    let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
     let mut app = App::test_default();
     app.data.lidarr_data.prompt_confirm = true;
     app.network_tx = Some(tx);
@@ -116,7 +209,7 @@ mod tests {
 
     assert!(app.is_loading);
     assert_eq!(
-      rx.recv().await.unwrap(),
+      rx.recv().await.unwrap().network_event,
       LidarrEvent::GetDiscographyReleases(1).into()
     );
     assert!(!app.data.lidarr_data.prompt_confirm);
@@ -143,7 +236,7 @@ mod tests {
 
   #[tokio::test]
   async fn test_dispatch_by_album_details_block() {
-    let (tx, mut rx) = mpsc::channel::<NetworkEvent>(500);
+    let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
     let mut app = App::test_default();
     app.data.lidarr_data.prompt_confirm = true;
     app.network_tx = Some(tx);
@@ -162,15 +255,15 @@ mod tests {
 
     assert!(app.is_loading);
     assert_eq!(
-      rx.recv().await.unwrap(),
+      rx.recv().await.unwrap().network_event,
       LidarrEvent::GetTracks(1, 1).into()
     );
     assert_eq!(
-      rx.recv().await.unwrap(),
+      rx.recv().await.unwrap().network_event,
       LidarrEvent::GetTrackFiles(1).into()
     );
     assert_eq!(
-      rx.recv().await.unwrap(),
+      rx.recv().await.unwrap().network_event,
       LidarrEvent::GetDownloads(500).into()
     );
     assert!(!app.data.lidarr_data.prompt_confirm);
@@ -179,7 +272,7 @@ mod tests {
 
   #[tokio::test]
   async fn test_dispatch_by_album_history_block() {
-    let (tx, mut rx) = mpsc::channel::<NetworkEvent>(500);
+    let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
     let mut app = App::test_default();
     app.data.lidarr_data.prompt_confirm = true;
     app.network_tx = Some(tx);
@@ -198,7 +291,7 @@ mod tests {
 
     assert!(app.is_loading);
     assert_eq!(
-      rx.recv().await.unwrap(),
+      rx.recv().await.unwrap().network_event,
       LidarrEvent::GetAlbumHistory(1, 1).into()
     );
     assert!(!app.data.lidarr_data.prompt_confirm);
@@ -207,7 +300,7 @@ mod tests {
 
   #[tokio::test]
   async fn test_dispatch_by_album_history_block_no_op_when_albums_table_is_empty() {
-    let (tx, _) = mpsc::channel::<NetworkEvent>(500);
+    let (tx, _) = mpsc::channel::<QueuedNetworkEvent>(500);
     let mut app = App::test_default();
     app.data.lidarr_data.prompt_confirm = true;
     app.network_tx = Some(tx);
@@ -227,7 +320,7 @@ mod tests {
 
   #[tokio::test]
   async fn test_dispatch_by_manual_album_search_block() {
-    let (tx, mut rx) = mpsc::channel::<NetworkEvent>(500);
+    let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
     let mut app = App::test_default();
     app.data.lidarr_data.prompt_confirm = true;
     app.network_tx = Some(tx);
@@ -247,7 +340,7 @@ mod tests {
 
     assert!(app.is_loading);
     assert_eq!(
-      rx.recv().await.unwrap(),
+      rx.recv().await.unwrap().network_event,
       LidarrEvent::GetAlbumReleases(1, 1).into()
     );
     assert!(!app.data.lidarr_data.prompt_confirm);
@@ -290,7 +383,7 @@ mod tests {
 
   #[tokio::test]
   async fn test_dispatch_by_downloads_block() {
-    let (tx, mut rx) = mpsc::channel::<NetworkEvent>(500);
+    let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
     let mut app = App::test_default();
     app.data.lidarr_data.prompt_confirm = true;
     app.network_tx = Some(tx);
@@ -301,16 +394,68 @@ mod tests {
 
     assert!(app.is_loading);
     assert_eq!(
-      rx.recv().await.unwrap(),
+      rx.recv().await.unwrap().network_event,
       LidarrEvent::GetDownloads(500).into()
     );
     assert!(!app.data.lidarr_data.prompt_confirm);
     assert_eq!(app.tick_count, 0);
   }
 
+  #[tokio::test]
+  async fn test_dispatch_by_manual_import_block() {
+    let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
+    let mut app = App::test_default();
+    app.data.lidarr_data.prompt_confirm = true;
+    app.network_tx = Some(tx);
+    app.data.lidarr_data.manual_import_modal = Some(ManualImportModal {
+      download_id: "abc123".to_owned(),
+      artist_id: Some(1),
+      ..ManualImportModal::default()
+    });
+
+    app
+      .dispatch_by_lidarr_block(&ActiveLidarrBlock::ManualImport)
+      .await;
+
+    assert!(app.is_loading);
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      LidarrEvent::ListArtists.into()
+    );
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      LidarrEvent::GetManualImport("abc123".to_owned(), Some(1)).into()
+    );
+    assert!(!app.data.lidarr_data.prompt_confirm);
+    assert_eq!(app.tick_count, 0);
+  }
+
+  #[tokio::test]
+  async fn test_dispatch_by_manual_import_block_artists_non_empty() {
+    let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
+    let mut app = App::test_default();
+    app.network_tx = Some(tx);
+    app.data.lidarr_data.artists.set_items(vec![artist()]);
+    app.data.lidarr_data.manual_import_modal = Some(ManualImportModal {
+      download_id: "abc123".to_owned(),
+      ..ManualImportModal::default()
+    });
+
+    app
+      .dispatch_by_lidarr_block(&ActiveLidarrBlock::ManualImport)
+      .await;
+
+    assert!(app.is_loading);
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      LidarrEvent::GetManualImport("abc123".to_owned(), None).into()
+    );
+    assert_eq!(app.tick_count, 0);
+  }
+
   #[tokio::test]
   async fn test_dispatch_by_lidarr_block_add_artist_search_results() {
-    let (tx, mut rx) = mpsc::channel::<NetworkEvent>(500);
+    let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
     let mut app = App::test_default();
     app.data.lidarr_data.prompt_confirm = true;
     app.network_tx = Some(tx);
@@ -322,7 +467,7 @@ mod tests {
 
     assert!(app.is_loading);
     assert_eq!(
-      rx.recv().await.unwrap(),
+      rx.recv().await.unwrap().network_event,
       LidarrEvent::SearchNewArtist("test artist".to_owned()).into()
     );
     assert!(!app.data.lidarr_data.prompt_confirm);
@@ -331,7 +476,7 @@ mod tests {
 
   #[tokio::test]
   async fn test_dispatch_by_history_block() {
-    let (tx, mut rx) = mpsc::channel::<NetworkEvent>(500);
+    let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
     let mut app = App::test_default();
     app.data.lidarr_data.prompt_confirm = true;
     app.network_tx = Some(tx);
@@ -342,7 +487,7 @@ mod tests {
 
     assert!(app.is_loading);
     assert_eq!(
-      rx.recv().await.unwrap(),
+      rx.recv().await.unwrap().network_event,
       LidarrEvent::GetHistory(500).into()
     );
     assert!(!app.data.lidarr_data.prompt_confirm);
@@ -351,7 +496,7 @@ mod tests {
 
   #[tokio::test]
   async fn test_dispatch_by_root_folders_block() {
-    let (tx, mut rx) = mpsc::channel::<NetworkEvent>(500);
+    let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
     let mut app = App::test_default();
     app.data.lidarr_data.prompt_confirm = true;
     app.network_tx = Some(tx);
@@ -361,14 +506,66 @@ mod tests {
       .await;
 
     assert!(app.is_loading);
-    assert_eq!(rx.recv().await.unwrap(), LidarrEvent::GetRootFolders.into());
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      LidarrEvent::GetRootFolders.into()
+    );
+    assert!(!app.data.lidarr_data.prompt_confirm);
+    assert_eq!(app.tick_count, 0);
+  }
+
+  #[tokio::test]
+  async fn test_dispatch_by_media_management_settings_prompt_block() {
+    let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
+    let mut app = App::test_default();
+    app.data.lidarr_data.prompt_confirm = true;
+    app.network_tx = Some(tx);
+
+    app
+      .dispatch_by_lidarr_block(&ActiveLidarrBlock::MediaManagementSettingsPrompt)
+      .await;
+
+    assert!(app.is_loading);
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      LidarrEvent::GetMediaManagementSettings.into()
+    );
     assert!(!app.data.lidarr_data.prompt_confirm);
     assert_eq!(app.tick_count, 0);
   }
 
+  #[tokio::test]
+  async fn test_dispatch_by_media_management_settings_prompt_block_fetches_naming_examples_for_edits()
+   {
+    let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
+    let mut app = App::test_default();
+    let mut media_management_modal = MediaManagementModal::new(
+      &media_management_settings(),
+      &MEDIA_MANAGEMENT_NAMING_FIELDS,
+    );
+    media_management_modal.toggle_current_field();
+    app.data.lidarr_data.media_management_modal = Some(media_management_modal);
+    app.network_tx = Some(tx);
+    let expected_naming_config = NamingConfig {
+      rename_tracks: false,
+      ..naming_config()
+    };
+
+    app
+      .dispatch_by_lidarr_block(&ActiveLidarrBlock::MediaManagementSettingsPrompt)
+      .await;
+
+    assert!(app.is_loading);
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      LidarrEvent::GetNamingExamples(expected_naming_config).into()
+    );
+    assert_eq!(app.tick_count, 0);
+  }
+
   #[tokio::test]
   async fn test_dispatch_by_indexers_block() {
-    let (tx, mut rx) = mpsc::channel::<NetworkEvent>(500);
+    let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
     let mut app = App::test_default();
     app.data.lidarr_data.prompt_confirm = true;
     app.network_tx = Some(tx);
@@ -378,15 +575,39 @@ mod tests {
       .await;
 
     assert!(app.is_loading);
-    assert_eq!(rx.recv().await.unwrap(), LidarrEvent::GetTags.into());
-    assert_eq!(rx.recv().await.unwrap(), LidarrEvent::GetIndexers.into());
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      LidarrEvent::GetTags.into()
+    );
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      LidarrEvent::GetIndexers.into()
+    );
     assert!(!app.data.lidarr_data.prompt_confirm);
     assert_eq!(app.tick_count, 0);
   }
 
+  #[tokio::test]
+  async fn test_dispatch_by_add_indexer_select_implementation_block() {
+    let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
+    let mut app = App::test_default();
+    app.network_tx = Some(tx);
+
+    app
+      .dispatch_by_lidarr_block(&ActiveLidarrBlock::AddIndexerSelectImplementation)
+      .await;
+
+    assert!(app.is_loading);
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      LidarrEvent::GetIndexerSchema.into()
+    );
+    assert_eq!(app.tick_count, 0);
+  }
+
   #[tokio::test]
   async fn test_dispatch_by_all_indexer_settings_block() {
-    let (tx, mut rx) = mpsc::channel::<NetworkEvent>(500);
+    let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
     let mut app = App::test_default();
     app.data.lidarr_data.prompt_confirm = true;
     app.network_tx = Some(tx);
@@ -397,7 +618,7 @@ mod tests {
 
     assert!(app.is_loading);
     assert_eq!(
-      rx.recv().await.unwrap(),
+      rx.recv().await.unwrap().network_event,
       LidarrEvent::GetAllIndexerSettings.into()
     );
     assert!(!app.data.lidarr_data.prompt_confirm);
@@ -406,7 +627,7 @@ mod tests {
 
   #[tokio::test]
   async fn test_dispatch_by_test_indexer_block() {
-    let (tx, mut rx) = mpsc::channel::<NetworkEvent>(500);
+    let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
     let mut app = App::test_default();
     app.data.lidarr_data.prompt_confirm = true;
     app.network_tx = Some(tx);
@@ -421,13 +642,16 @@ mod tests {
 
     assert!(app.is_loading);
     assert!(!app.data.lidarr_data.prompt_confirm);
-    assert_eq!(rx.recv().await.unwrap(), LidarrEvent::TestIndexer(1).into());
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      LidarrEvent::TestIndexer(1).into()
+    );
     assert_eq!(app.tick_count, 0);
   }
 
   #[tokio::test]
   async fn test_dispatch_by_test_all_indexers_block() {
-    let (tx, mut rx) = mpsc::channel::<NetworkEvent>(500);
+    let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
     let mut app = App::test_default();
     app.data.lidarr_data.prompt_confirm = true;
     app.network_tx = Some(tx);
@@ -439,15 +663,121 @@ mod tests {
     assert!(app.is_loading);
     assert!(!app.data.lidarr_data.prompt_confirm);
     assert_eq!(
-      rx.recv().await.unwrap(),
+      rx.recv().await.unwrap().network_event,
       LidarrEvent::TestAllIndexers.into()
     );
     assert_eq!(app.tick_count, 0);
   }
 
+  #[tokio::test]
+  async fn test_dispatch_by_quality_profiles_block() {
+    let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
+    let mut app = App::test_default();
+    app.data.lidarr_data.prompt_confirm = true;
+    app.network_tx = Some(tx);
+
+    app
+      .dispatch_by_lidarr_block(&ActiveLidarrBlock::QualityProfiles)
+      .await;
+
+    assert!(app.is_loading);
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      LidarrEvent::GetQualityProfiles.into()
+    );
+    assert!(!app.data.lidarr_data.prompt_confirm);
+    assert_eq!(app.tick_count, 0);
+  }
+
+  #[tokio::test]
+  async fn test_dispatch_by_download_clients_block() {
+    let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
+    let mut app = App::test_default();
+    app.data.lidarr_data.prompt_confirm = true;
+    app.network_tx = Some(tx);
+
+    app
+      .dispatch_by_lidarr_block(&ActiveLidarrBlock::DownloadClients)
+      .await;
+
+    assert!(app.is_loading);
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      LidarrEvent::GetTags.into()
+    );
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      LidarrEvent::GetDownloadClients.into()
+    );
+    assert!(!app.data.lidarr_data.prompt_confirm);
+    assert_eq!(app.tick_count, 0);
+  }
+
+  #[tokio::test]
+  async fn test_dispatch_by_add_download_client_select_implementation_block() {
+    let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
+    let mut app = App::test_default();
+    app.network_tx = Some(tx);
+
+    app
+      .dispatch_by_lidarr_block(&ActiveLidarrBlock::AddDownloadClientSelectImplementation)
+      .await;
+
+    assert!(app.is_loading);
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      LidarrEvent::GetDownloadClientSchema.into()
+    );
+    assert_eq!(app.tick_count, 0);
+  }
+
+  #[tokio::test]
+  async fn test_dispatch_by_test_download_client_block() {
+    let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
+    let mut app = App::test_default();
+    app.network_tx = Some(tx);
+    app
+      .data
+      .lidarr_data
+      .download_clients
+      .set_items(vec![DownloadClient {
+        id: 1,
+        ..DownloadClient::default()
+      }]);
+
+    app
+      .dispatch_by_lidarr_block(&ActiveLidarrBlock::TestDownloadClient)
+      .await;
+
+    assert!(app.is_loading);
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      LidarrEvent::TestDownloadClient(1).into()
+    );
+    assert_eq!(app.tick_count, 0);
+  }
+
+  #[tokio::test]
+  async fn test_dispatch_by_test_all_download_clients_block() {
+    let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
+    let mut app = App::test_default();
+    app.network_tx = Some(tx);
+
+    app
+      .dispatch_by_lidarr_block(&ActiveLidarrBlock::TestAllDownloadClients)
+      .await;
+
+    assert!(app.is_loading);
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      LidarrEvent::TestAllDownloadClients.into()
+    );
+    assert_eq!(app.tick_count, 0);
+  }
+
   #[tokio::test]
   async fn test_dispatch_by_system_block() {
-    let (tx, mut rx) = mpsc::channel::<NetworkEvent>(500);
+    let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
     let mut app = App::test_default();
     app.data.lidarr_data.prompt_confirm = true;
     app.network_tx = Some(tx);
@@ -457,19 +787,45 @@ mod tests {
       .await;
 
     assert!(app.is_loading);
-    assert_eq!(rx.recv().await.unwrap(), LidarrEvent::GetTasks.into());
     assert_eq!(
-      rx.recv().await.unwrap(),
+      rx.recv().await.unwrap().network_event,
+      LidarrEvent::GetTasks.into()
+    );
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
       LidarrEvent::GetQueuedEvents.into()
     );
-    assert_eq!(rx.recv().await.unwrap(), LidarrEvent::GetLogs(500).into());
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      LidarrEvent::GetLogs(500).into()
+    );
+    assert!(!app.data.lidarr_data.prompt_confirm);
+    assert_eq!(app.tick_count, 0);
+  }
+
+  #[tokio::test]
+  async fn test_dispatch_by_system_backups_block() {
+    let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
+    let mut app = App::test_default();
+    app.data.lidarr_data.prompt_confirm = true;
+    app.network_tx = Some(tx);
+
+    app
+      .dispatch_by_lidarr_block(&ActiveLidarrBlock::SystemBackups)
+      .await;
+
+    assert!(app.is_loading);
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      LidarrEvent::GetBackups.into()
+    );
     assert!(!app.data.lidarr_data.prompt_confirm);
     assert_eq!(app.tick_count, 0);
   }
 
   #[tokio::test]
   async fn test_dispatch_by_system_updates_block() {
-    let (tx, mut rx) = mpsc::channel::<NetworkEvent>(500);
+    let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
     let mut app = App::test_default();
     app.data.lidarr_data.prompt_confirm = true;
     app.network_tx = Some(tx);
@@ -479,14 +835,17 @@ mod tests {
       .await;
 
     assert!(app.is_loading);
-    assert_eq!(rx.recv().await.unwrap(), LidarrEvent::GetUpdates.into());
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      LidarrEvent::GetUpdates.into()
+    );
     assert!(!app.data.lidarr_data.prompt_confirm);
     assert_eq!(app.tick_count, 0);
   }
 
   #[tokio::test]
   async fn test_dispatch_by_track_details_block() {
-    let (tx, mut rx) = mpsc::channel::<NetworkEvent>(500);
+    let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
     let mut app = App::test_default_fully_populated();
     app.data.lidarr_data.prompt_confirm = true;
     app.network_tx = Some(tx);
@@ -497,7 +856,7 @@ mod tests {
 
     assert!(app.is_loading);
     assert_eq!(
-      rx.recv().await.unwrap(),
+      rx.recv().await.unwrap().network_event,
       LidarrEvent::GetTrackDetails(1).into()
     );
     assert!(!app.data.lidarr_data.prompt_confirm);
@@ -506,7 +865,7 @@ mod tests {
 
   #[tokio::test]
   async fn test_dispatch_by_track_history_block() {
-    let (tx, mut rx) = mpsc::channel::<NetworkEvent>(500);
+    let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
     let mut app = App::test_default_fully_populated();
     app.data.lidarr_data.prompt_confirm = true;
     app.network_tx = Some(tx);
@@ -517,7 +876,7 @@ mod tests {
 
     assert!(app.is_loading);
     assert_eq!(
-      rx.recv().await.unwrap(),
+      rx.recv().await.unwrap().network_event,
       LidarrEvent::GetTrackHistory(1, 1, 1).into()
     );
     assert!(!app.data.lidarr_data.prompt_confirm);
@@ -537,7 +896,7 @@ mod tests {
 
   #[tokio::test]
   async fn test_check_for_lidarr_prompt_action() {
-    let (tx, mut rx) = mpsc::channel::<NetworkEvent>(500);
+    let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
     let mut app = App::test_default();
     app.data.lidarr_data.prompt_confirm = true;
     app.network_tx = Some(tx);
@@ -546,14 +905,17 @@ mod tests {
     app.check_for_lidarr_prompt_action().await;
 
     assert!(!app.data.lidarr_data.prompt_confirm);
-    assert_eq!(rx.recv().await.unwrap(), LidarrEvent::GetStatus.into());
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      LidarrEvent::GetStatus.into()
+    );
     assert!(app.should_refresh);
     assert_eq!(app.data.lidarr_data.prompt_confirm_action, None);
   }
 
   #[tokio::test]
   async fn test_lidarr_refresh_metadata() {
-    let (tx, mut rx) = mpsc::channel::<NetworkEvent>(500);
+    let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
     let mut app = App::test_default();
     app.data.lidarr_data.prompt_confirm = true;
     app.network_tx = Some(tx);
@@ -562,27 +924,39 @@ mod tests {
     app.refresh_lidarr_metadata().await;
 
     assert_eq!(
-      rx.recv().await.unwrap(),
+      rx.recv().await.unwrap().network_event,
       LidarrEvent::GetQualityProfiles.into()
     );
     assert_eq!(
-      rx.recv().await.unwrap(),
+      rx.recv().await.unwrap().network_event,
       LidarrEvent::GetMetadataProfiles.into()
     );
-    assert_eq!(rx.recv().await.unwrap(), LidarrEvent::GetTags.into());
-    assert_eq!(rx.recv().await.unwrap(), LidarrEvent::GetRootFolders.into());
     assert_eq!(
-      rx.recv().await.unwrap(),
+      rx.recv().await.unwrap().network_event,
+      LidarrEvent::GetTags.into()
+    );
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      LidarrEvent::GetRootFolders.into()
+    );
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
       LidarrEvent::GetDownloads(500).into()
     );
-    assert_eq!(rx.recv().await.unwrap(), LidarrEvent::GetDiskSpace.into());
-    assert_eq!(rx.recv().await.unwrap(), LidarrEvent::GetStatus.into());
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      LidarrEvent::GetDiskSpace.into()
+    );
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      LidarrEvent::GetStatus.into()
+    );
     assert!(app.is_loading);
   }
 
   #[tokio::test]
   async fn test_lidarr_on_tick_first_render() {
-    let (tx, mut rx) = mpsc::channel::<NetworkEvent>(500);
+    let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
     let mut app = App::test_default();
     app.data.lidarr_data.prompt_confirm = true;
     app.network_tx = Some(tx);
@@ -591,21 +965,33 @@ mod tests {
     app.lidarr_on_tick(ActiveLidarrBlock::Downloads).await;
 
     assert_eq!(
-      rx.recv().await.unwrap(),
+      rx.recv().await.unwrap().network_event,
       LidarrEvent::GetQualityProfiles.into()
     );
     assert_eq!(
-      rx.recv().await.unwrap(),
+      rx.recv().await.unwrap().network_event,
       LidarrEvent::GetMetadataProfiles.into()
     );
-    assert_eq!(rx.recv().await.unwrap(), LidarrEvent::GetTags.into());
-    assert_eq!(rx.recv().await.unwrap(), LidarrEvent::GetRootFolders.into());
     assert_eq!(
-      rx.recv().await.unwrap(),
+      rx.recv().await.unwrap().network_event,
+      LidarrEvent::GetTags.into()
+    );
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      LidarrEvent::GetRootFolders.into()
+    );
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
       LidarrEvent::GetDownloads(500).into()
     );
-    assert_eq!(rx.recv().await.unwrap(), LidarrEvent::GetDiskSpace.into());
-    assert_eq!(rx.recv().await.unwrap(), LidarrEvent::GetStatus.into());
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      LidarrEvent::GetDiskSpace.into()
+    );
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      LidarrEvent::GetStatus.into()
+    );
     assert!(app.is_loading);
     assert!(!app.data.lidarr_data.prompt_confirm);
     assert!(!app.is_first_render);
@@ -613,7 +999,7 @@ mod tests {
 
   #[tokio::test]
   async fn test_lidarr_on_tick_routing() {
-    let (tx, mut rx) = mpsc::channel::<NetworkEvent>(500);
+    let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
     let mut app = App::test_default();
     app.data.lidarr_data.prompt_confirm = true;
     app.network_tx = Some(tx);
@@ -625,7 +1011,7 @@ mod tests {
     app.lidarr_on_tick(ActiveLidarrBlock::Downloads).await;
 
     assert_eq!(
-      rx.recv().await.unwrap(),
+      rx.recv().await.unwrap().network_event,
       LidarrEvent::GetDownloads(500).into()
     );
     assert!(!app.data.lidarr_data.prompt_confirm);
@@ -633,7 +1019,7 @@ mod tests {
 
   #[tokio::test]
   async fn test_lidarr_on_tick_routing_while_long_request_is_running_should_cancel_request() {
-    let (tx, _) = mpsc::channel::<NetworkEvent>(500);
+    let (tx, _) = mpsc::channel::<QueuedNetworkEvent>(500);
     let mut app = App::test_default();
     app.data.lidarr_data.prompt_confirm = true;
     app.network_tx = Some(tx);
@@ -649,7 +1035,7 @@ mod tests {
 
   #[tokio::test]
   async fn test_lidarr_on_tick_should_refresh() {
-    let (tx, mut rx) = mpsc::channel::<NetworkEvent>(500);
+    let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
     let mut app = App::test_default();
     app.data.lidarr_data.prompt_confirm = true;
     app.network_tx = Some(tx);
@@ -660,7 +1046,7 @@ mod tests {
     app.lidarr_on_tick(ActiveLidarrBlock::Downloads).await;
 
     assert_eq!(
-      rx.recv().await.unwrap(),
+      rx.recv().await.unwrap().network_event,
       LidarrEvent::GetDownloads(500).into()
     );
     assert!(app.should_refresh);
@@ -669,7 +1055,7 @@ mod tests {
 
   #[tokio::test]
   async fn test_lidarr_on_tick_should_refresh_does_not_cancel_prompt_requests() {
-    let (tx, mut rx) = mpsc::channel::<NetworkEvent>(500);
+    let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
     let mut app = App::test_default();
     app.data.lidarr_data.prompt_confirm = true;
     app.network_tx = Some(tx);
@@ -682,7 +1068,7 @@ mod tests {
     app.lidarr_on_tick(ActiveLidarrBlock::Downloads).await;
 
     assert_eq!(
-      rx.recv().await.unwrap(),
+      rx.recv().await.unwrap().network_event,
       LidarrEvent::GetDownloads(500).into()
     );
     assert!(app.is_loading);
@@ -693,7 +1079,7 @@ mod tests {
 
   #[tokio::test]
   async fn test_lidarr_on_tick_network_tick_frequency() {
-    let (tx, mut rx) = mpsc::channel::<NetworkEvent>(500);
+    let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
     let mut app = App::test_default();
     app.data.lidarr_data.prompt_confirm = true;
     app.network_tx = Some(tx);
@@ -703,17 +1089,23 @@ mod tests {
     app.lidarr_on_tick(ActiveLidarrBlock::Downloads).await;
 
     assert_eq!(
-      rx.recv().await.unwrap(),
+      rx.recv().await.unwrap().network_event,
       LidarrEvent::GetQualityProfiles.into()
     );
     assert_eq!(
-      rx.recv().await.unwrap(),
+      rx.recv().await.unwrap().network_event,
       LidarrEvent::GetMetadataProfiles.into()
     );
-    assert_eq!(rx.recv().await.unwrap(), LidarrEvent::GetTags.into());
-    assert_eq!(rx.recv().await.unwrap(), LidarrEvent::GetRootFolders.into());
     assert_eq!(
-      rx.recv().await.unwrap(),
+      rx.recv().await.unwrap().network_event,
+      LidarrEvent::GetTags.into()
+    );
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      LidarrEvent::GetRootFolders.into()
+    );
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
       LidarrEvent::GetDownloads(500).into()
     );
     assert!(app.is_loading);
@@ -780,4 +1172,19 @@ mod tests {
 
     assert_eq!(app.extract_lidarr_indexer_id().await, 1);
   }
+
+  #[tokio::test]
+  async fn test_extract_lidarr_download_client_id() {
+    let mut app = App::test_default();
+    app
+      .data
+      .lidarr_data
+      .download_clients
+      .set_items(vec![DownloadClient {
+        id: 1,
+        ..DownloadClient::default()
+      }]);
+
+    assert_eq!(app.extract_lidarr_download_client_id().await, 1);
+  }
 }
@@ -1,7 +1,9 @@
+use chrono::Local;
+
 use super::App;
 use crate::{
   models::servarr_data::lidarr::lidarr_data::ActiveLidarrBlock,
-  network::lidarr_network::LidarrEvent,
+  network::lidarr_network::LidarrEvent, utils::calendar_range,
 };
 
 pub mod lidarr_context_clues;
@@ -32,11 +34,51 @@ impl App<'_> {
           .dispatch_network_event(LidarrEvent::GetBlocklist.into())
           .await;
       }
+      ActiveLidarrBlock::Calendar | ActiveLidarrBlock::CalendarAgenda => {
+        let (start, end) = calendar_range(Local::now().date_naive());
+        self
+          .dispatch_network_event(LidarrEvent::ListArtists.into())
+          .await;
+        self
+          .dispatch_network_event(LidarrEvent::GetCalendar(start, end).into())
+          .await;
+      }
+      ActiveLidarrBlock::WantedMissing => {
+        self
+          .dispatch_network_event(LidarrEvent::ListArtists.into())
+          .await;
+        self
+          .dispatch_network_event(LidarrEvent::GetWantedMissing(500).into())
+          .await;
+      }
+      ActiveLidarrBlock::WantedCutoffUnmet => {
+        self
+          .dispatch_network_event(LidarrEvent::ListArtists.into())
+          .await;
+        self
+          .dispatch_network_event(LidarrEvent::GetCutoffUnmet(500).into())
+          .await;
+      }
       ActiveLidarrBlock::Downloads => {
         self
           .dispatch_network_event(LidarrEvent::GetDownloads(500).into())
           .await;
       }
+      ActiveLidarrBlock::ManualImport => {
+        if self.data.lidarr_data.artists.is_empty() {
+          self
+            .dispatch_network_event(LidarrEvent::ListArtists.into())
+            .await;
+        }
+
+        if let Some(manual_import_modal) = self.data.lidarr_data.manual_import_modal.as_ref() {
+          let event = LidarrEvent::GetManualImport(
+            manual_import_modal.download_id.clone(),
+            manual_import_modal.artist_id,
+          );
+          self.dispatch_network_event(event.into()).await;
+        }
+      }
       ActiveLidarrBlock::ArtistDetails => {
         self
           .dispatch_network_event(LidarrEvent::GetAlbums(self.extract_artist_id().await).into())
@@ -117,6 +159,15 @@ impl App<'_> {
           .dispatch_network_event(LidarrEvent::GetRootFolders.into())
           .await;
       }
+      ActiveLidarrBlock::MediaManagementSettingsPrompt => {
+        let event = match self.data.lidarr_data.media_management_modal.as_ref() {
+          Some(media_management_modal) => {
+            LidarrEvent::GetNamingExamples(media_management_modal.settings().naming)
+          }
+          None => LidarrEvent::GetMediaManagementSettings,
+        };
+        self.dispatch_network_event(event.into()).await;
+      }
       ActiveLidarrBlock::Indexers => {
         self
           .dispatch_network_event(LidarrEvent::GetTags.into())
@@ -137,11 +188,46 @@ impl App<'_> {
           )
           .await;
       }
+      ActiveLidarrBlock::AddIndexerSelectImplementation => {
+        self
+          .dispatch_network_event(LidarrEvent::GetIndexerSchema.into())
+          .await;
+      }
       ActiveLidarrBlock::TestAllIndexers => {
         self
           .dispatch_network_event(LidarrEvent::TestAllIndexers.into())
           .await;
       }
+      ActiveLidarrBlock::QualityProfiles => {
+        self
+          .dispatch_network_event(LidarrEvent::GetQualityProfiles.into())
+          .await;
+      }
+      ActiveLidarrBlock::DownloadClients => {
+        self
+          .dispatch_network_event(LidarrEvent::GetTags.into())
+          .await;
+        self
+          .dispatch_network_event(LidarrEvent::GetDownloadClients.into())
+          .await;
+      }
+      ActiveLidarrBlock::TestDownloadClient => {
+        self
+          .dispatch_network_event(
+            LidarrEvent::TestDownloadClient(self.extract_lidarr_download_client_id().await).into(),
+          )
+          .await;
+      }
+      ActiveLidarrBlock::AddDownloadClientSelectImplementation => {
+        self
+          .dispatch_network_event(LidarrEvent::GetDownloadClientSchema.into())
+          .await;
+      }
+      ActiveLidarrBlock::TestAllDownloadClients => {
+        self
+          .dispatch_network_event(LidarrEvent::TestAllDownloadClients.into())
+          .await;
+      }
       ActiveLidarrBlock::System => {
         self
           .dispatch_network_event(LidarrEvent::GetTasks.into())
@@ -153,6 +239,11 @@ impl App<'_> {
           .dispatch_network_event(LidarrEvent::GetLogs(500).into())
           .await;
       }
+      ActiveLidarrBlock::SystemBackups => {
+        self
+          .dispatch_network_event(LidarrEvent::GetBackups.into())
+          .await;
+      }
       ActiveLidarrBlock::SystemUpdates => {
         self
           .dispatch_network_event(LidarrEvent::GetUpdates.into())
@@ -217,6 +308,15 @@ impl App<'_> {
     self.data.lidarr_data.indexers.current_selection().id
   }
 
+  async fn extract_lidarr_download_client_id(&self) -> i64 {
+    self
+      .data
+      .lidarr_data
+      .download_clients
+      .current_selection()
+      .id
+  }
+
   async fn check_for_lidarr_prompt_action(&mut self) {
     if self.data.lidarr_data.prompt_confirm {
       self.data.lidarr_data.prompt_confirm = false;
@@ -248,7 +348,7 @@ impl App<'_> {
       }
     }
 
-    if self.tick_count.is_multiple_of(self.tick_until_poll) {
+    if self.should_poll() {
       self.refresh_lidarr_metadata().await;
     }
   }
@@ -3,37 +3,50 @@ use colored::Colorize;
 use itertools::Itertools;
 use log::{debug, error};
 use regex::Regex;
+use reqwest::Proxy;
 use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::fmt::{self, Display, Formatter};
 use std::path::PathBuf;
+use std::str::FromStr;
 use std::{fs, process};
 use tokio::sync::mpsc::Sender;
 use tokio_util::sync::CancellationToken;
 use veil::Redact;
 
+use crate::app::key_binding::{DEFAULT_KEYBINDINGS, KeyBindingOverrides, KeyBindings};
 use crate::cli::Command;
 use crate::models::servarr_data::Notification;
 use crate::models::servarr_data::lidarr::lidarr_data::{ActiveLidarrBlock, LidarrData};
+use crate::models::servarr_data::prowlarr::prowlarr_data::{ActiveProwlarrBlock, ProwlarrData};
 use crate::models::servarr_data::radarr::radarr_data::{ActiveRadarrBlock, RadarrData};
+use crate::models::servarr_data::readarr::readarr_data::{ActiveReadarrBlock, ReadarrData};
 use crate::models::servarr_data::sonarr::sonarr_data::{ActiveSonarrBlock, SonarrData};
 use crate::models::servarr_models::KeybindingItem;
 use crate::models::stateful_table::StatefulTable;
-use crate::models::{HorizontallyScrollableText, Route, TabRoute, TabState};
-use crate::network::NetworkEvent;
+use crate::models::{HorizontallyScrollableText, Route, ServarrInstance, TabRoute, TabState};
+use crate::network::{NetworkClients, NetworkEvent, QueuedNetworkEvent};
+use crate::utils::try_build_network_clients;
 
 #[cfg(test)]
 mod app_tests;
+pub mod config_watcher;
 pub mod context_clues;
 pub mod key_binding;
 mod key_binding_tests;
 pub mod lidarr;
+pub mod prowlarr;
 pub mod radarr;
+pub mod readarr;
 pub mod sonarr;
 
+/// How many times less often an instance is polled while its SignalR hub is connected
+const PUSH_CONNECTED_POLL_INTERVAL_MULTIPLIER: u64 = 10;
+
 pub struct App<'a> {
   navigation_stack: Vec<Route>,
-  network_tx: Option<Sender<NetworkEvent>>,
+  network_tx: Option<Sender<QueuedNetworkEvent>>,
   pub cancellation_token: CancellationToken,
   pub is_first_render: bool,
   pub server_tabs: TabState,
@@ -43,6 +56,9 @@ pub struct App<'a> {
   pub tick_until_poll: u64,
   pub ticks_until_scroll: u64,
   pub tick_count: u64,
+  pub network_clients: NetworkClients,
+  pub push_connected_instances: HashSet<ServarrInstance>,
+  pub retry_attempts: HashMap<u64, (u16, u16)>,
   pub ui_scroll_tick_count: u64,
   pub is_routing: bool,
   pub is_loading: bool,
@@ -54,10 +70,49 @@ pub struct App<'a> {
 
 impl App<'_> {
   pub fn new(
-    network_tx: Sender<NetworkEvent>,
+    network_tx: Sender<QueuedNetworkEvent>,
     config: AppConfig,
     cancellation_token: CancellationToken,
-  ) -> Self {
+  ) -> Result<Self> {
+    let server_tabs = App::build_server_tabs(config);
+    let network_clients = try_build_network_clients(&server_tabs)?;
+
+    Ok(App {
+      network_tx: Some(network_tx),
+      cancellation_token,
+      server_tabs: TabState::new(server_tabs),
+      network_clients,
+      ..App::default()
+    })
+  }
+
+  /// Replaces the configured Servarr tabs, and the clients used to contact them, with those of the
+  /// given (already validated) config. The user stays on their current tab when an instance with
+  /// the same name still exists; otherwise they're sent to the first tab.
+  pub fn reload_config(&mut self, config: AppConfig) -> Result<()> {
+    let current_instance = self.server_tabs.get_active_instance();
+    let tabs = App::build_server_tabs(config);
+    let network_clients = try_build_network_clients(&tabs)?;
+    let current_tab_index = tabs
+      .iter()
+      .position(|tab| Some(tab.instance()) == current_instance);
+    self.server_tabs = TabState::new(tabs);
+    self.network_clients = network_clients;
+
+    if let Some(index) = current_tab_index {
+      self.server_tabs.index = index;
+      self.should_refresh = true;
+    } else {
+      self.reset();
+      self.navigation_stack.clear();
+      self.push_navigation_stack(self.server_tabs.get_active_route());
+      self.cancellation_token.cancel();
+    }
+
+    Ok(())
+  }
+
+  fn build_server_tabs(config: AppConfig) -> Vec<TabRoute> {
     let mut server_tabs = Vec::new();
 
     if let Some(radarr_configs) = config.radarr {
@@ -120,7 +175,47 @@ impl App<'_> {
       server_tabs.extend(lidarr_tabs);
     }
 
-    let weight_sorted_tabs = server_tabs
+    if let Some(prowlarr_configs) = config.prowlarr {
+      let mut unnamed_idx = 0;
+      let prowlarr_tabs = prowlarr_configs.into_iter().map(|prowlarr_config| {
+        let name = if let Some(name) = prowlarr_config.name.clone() {
+          name
+        } else {
+          unnamed_idx += 1;
+          format!("Prowlarr {unnamed_idx}")
+        };
+
+        TabRoute {
+          title: name,
+          route: ActiveProwlarrBlock::Indexers.into(),
+          contextual_help: None,
+          config: Some(prowlarr_config),
+        }
+      });
+      server_tabs.extend(prowlarr_tabs);
+    }
+
+    if let Some(readarr_configs) = config.readarr {
+      let mut unnamed_idx = 0;
+      let readarr_tabs = readarr_configs.into_iter().map(|readarr_config| {
+        let name = if let Some(name) = readarr_config.name.clone() {
+          name
+        } else {
+          unnamed_idx += 1;
+          format!("Readarr {unnamed_idx}")
+        };
+
+        TabRoute {
+          title: name,
+          route: ActiveReadarrBlock::Authors.into(),
+          contextual_help: None,
+          config: Some(readarr_config),
+        }
+      });
+      server_tabs.extend(readarr_tabs);
+    }
+
+    server_tabs
       .into_iter()
       .sorted_by(|tab1, tab2| {
         Ord::cmp(
@@ -140,25 +235,29 @@ impl App<'_> {
             .unwrap_or(&1000),
         )
       })
-      .collect();
-
-    App {
-      network_tx: Some(network_tx),
-      cancellation_token,
-      server_tabs: TabState::new(weight_sorted_tabs),
-      ..App::default()
-    }
+      .collect()
   }
 
+  /// Queues the event for the active Servarr instance
   pub async fn dispatch_network_event(&mut self, action: NetworkEvent) {
     debug!("Dispatching network event: {action:?}");
 
+    let Some(instance) = self.server_tabs.get_active_instance() else {
+      error!("No Servarr is configured to handle the network event: {action:?}");
+      return;
+    };
+
     if !self.should_refresh {
       self.is_loading = true;
     }
 
     if let Some(network_tx) = &self.network_tx
-      && let Err(e) = network_tx.send(action).await
+      && let Err(e) = network_tx
+        .send(QueuedNetworkEvent {
+          instance,
+          network_event: action,
+        })
+        .await
     {
       self.is_loading = false;
       error!("Failed to send event. {e:?}");
@@ -193,14 +292,15 @@ impl App<'_> {
   }
 
   pub async fn on_tick(&mut self) {
-    if self.tick_count.is_multiple_of(self.tick_until_poll)
-      || self.is_routing
-      || self.should_refresh
-    {
+    if self.should_poll() || self.is_first_render || self.is_routing || self.should_refresh {
       match self.get_current_route() {
         Route::Radarr(active_radarr_block, _) => self.radarr_on_tick(active_radarr_block).await,
         Route::Sonarr(active_sonarr_block, _) => self.sonarr_on_tick(active_sonarr_block).await,
         Route::Lidarr(active_lidarr_block, _) => self.lidarr_on_tick(active_lidarr_block).await,
+        Route::Prowlarr(active_prowlarr_block, _) => {
+          self.prowlarr_on_tick(active_prowlarr_block).await
+        }
+        Route::Readarr(active_readarr_block, _) => self.readarr_on_tick(active_readarr_block).await,
         _ => (),
       }
 
@@ -211,6 +311,26 @@ impl App<'_> {
     self.tick_count += 1;
   }
 
+  /// Whether the periodic refresh of the active Servarr is due. Instances with a connected
+  /// SignalR hub get push updates for their library, downloads, and queued events, so they're
+  /// only polled every so often to keep the data the hub doesn't cover (e.g. disk space) current.
+  pub fn should_poll(&self) -> bool {
+    let tick_until_poll = if self.is_push_connected() {
+      self.tick_until_poll * PUSH_CONNECTED_POLL_INTERVAL_MULTIPLIER
+    } else {
+      self.tick_until_poll
+    };
+
+    self.tick_count.is_multiple_of(tick_until_poll)
+  }
+
+  pub fn is_push_connected(&self) -> bool {
+    self
+      .server_tabs
+      .get_active_instance()
+      .is_some_and(|instance| self.push_connected_instances.contains(&instance))
+  }
+
   pub fn push_navigation_stack(&mut self, route: Route) {
     self.navigation_stack.push(route);
     self.is_routing = true;
@@ -262,6 +382,9 @@ impl Default for App<'_> {
       tick_until_poll: 400,
       ticks_until_scroll: 64,
       tick_count: 0,
+      network_clients: NetworkClients::default(),
+      push_connected_instances: HashSet::new(),
+      retry_attempts: HashMap::new(),
       ui_scroll_tick_count: 0,
       is_loading: false,
       is_routing: false,
@@ -305,7 +428,9 @@ impl App<'_> {
     App {
       data: Data {
         lidarr_data: LidarrData::test_default_fully_populated(),
+        prowlarr_data: ProwlarrData::test_default_fully_populated(),
         radarr_data: RadarrData::test_default_fully_populated(),
+        readarr_data: ReadarrData::test_default_fully_populated(),
         sonarr_data: SonarrData::test_default_fully_populated(),
       },
       server_tabs: TabState::new(vec![
@@ -336,37 +461,72 @@ impl App<'_> {
 #[derive(Default)]
 pub struct Data<'a> {
   pub lidarr_data: LidarrData<'a>,
+  pub prowlarr_data: ProwlarrData,
   pub radarr_data: RadarrData<'a>,
+  pub readarr_data: ReadarrData<'a>,
   pub sonarr_data: SonarrData<'a>,
 }
 
 #[derive(Debug, Deserialize, Serialize, Default, Clone)]
 pub struct AppConfig {
   pub theme: Option<String>,
+  pub keybindings: Option<KeyBindingOverrides>,
   pub lidarr: Option<Vec<ServarrConfig>>,
+  pub prowlarr: Option<Vec<ServarrConfig>>,
   pub radarr: Option<Vec<ServarrConfig>>,
+  pub readarr: Option<Vec<ServarrConfig>>,
   pub sonarr: Option<Vec<ServarrConfig>>,
 }
 
 impl AppConfig {
   pub fn validate(&self, config_path: &str) {
-    if self.lidarr.is_none() && self.radarr.is_none() && self.sonarr.is_none() {
-      log_and_print_error(format!(
-        "No Servarrs are configured in the file: {config_path}"
-      ));
+    if let Err(e) = self.try_validate(config_path) {
+      log_and_print_error(e.to_string());
       process::exit(1);
     }
+  }
+
+  /// Validates the entire config, collecting every problem that's found rather than stopping
+  /// at the first one
+  pub fn try_validate(&self, config_path: &str) -> Result<(), ConfigValidationErrors> {
+    let mut errors = Vec::new();
+
+    if self.lidarr.is_none()
+      && self.prowlarr.is_none()
+      && self.radarr.is_none()
+      && self.readarr.is_none()
+      && self.sonarr.is_none()
+    {
+      errors.push(ConfigValidationError {
+        instance: None,
+        field: String::new(),
+        message: format!("No Servarrs are configured in the file: {config_path}"),
+      });
+    }
 
-    if let Some(radarr_configs) = &self.radarr {
-      radarr_configs.iter().for_each(|config| config.validate());
+    errors.extend(self.keybindings().conflicts().into_iter().map(|conflict| {
+      ConfigValidationError {
+        instance: None,
+        field: "keybindings".to_owned(),
+        message: conflict,
+      }
+    }));
+
+    for (path, instance, config) in self.servarr_instances() {
+      errors.extend(config.validate(&path, &instance));
     }
 
-    if let Some(sonarr_configs) = &self.sonarr {
-      sonarr_configs.iter().for_each(|config| config.validate());
+    if errors.is_empty() {
+      Ok(())
+    } else {
+      Err(ConfigValidationErrors(errors))
     }
+  }
 
-    if let Some(lidarr_configs) = &self.lidarr {
-      lidarr_configs.iter().for_each(|config| config.validate());
+  pub fn keybindings(&self) -> KeyBindings {
+    match &self.keybindings {
+      Some(overrides) => DEFAULT_KEYBINDINGS.with_overrides(overrides),
+      None => DEFAULT_KEYBINDINGS,
     }
   }
 
@@ -389,91 +549,372 @@ impl AppConfig {
         msg("Lidarr");
         process::exit(1);
       }
+      Command::Prowlarr(_) if self.prowlarr.is_none() => {
+        msg("Prowlarr");
+        process::exit(1);
+      }
+      Command::Readarr(_) if self.readarr.is_none() => {
+        msg("Readarr");
+        process::exit(1);
+      }
       _ => (),
     }
   }
 
   pub fn post_process_initialization(&mut self) {
-    if let Some(radarr_configs) = self.radarr.as_mut() {
-      for radarr_config in radarr_configs {
-        radarr_config.post_process_initialization();
-      }
+    if let Err(e) = self.try_post_process_initialization() {
+      log_and_print_error(e.to_string());
+      process::exit(1);
     }
+  }
 
-    if let Some(sonarr_configs) = self.sonarr.as_mut() {
-      for sonarr_config in sonarr_configs {
-        sonarr_config.post_process_initialization();
-      }
+  pub fn try_post_process_initialization(&mut self) -> Result<(), ConfigValidationErrors> {
+    let instances = self
+      .servarr_instances()
+      .map(|(path, instance, _)| (path, instance))
+      .collect::<Vec<_>>();
+    let errors = self
+      .servarr_configs_mut()
+      .zip(instances)
+      .filter_map(|(config, (path, instance))| {
+        config
+          .post_process_initialization()
+          .err()
+          .map(|e| ConfigValidationError {
+            instance: Some(instance),
+            field: format!("{path}.api_token_file"),
+            message: e.to_string(),
+          })
+      })
+      .collect::<Vec<_>>();
+
+    if errors.is_empty() {
+      Ok(())
+    } else {
+      Err(ConfigValidationErrors(errors))
     }
+  }
 
-    if let Some(lidarr_configs) = self.lidarr.as_mut() {
-      for lidarr_config in lidarr_configs {
-        lidarr_config.post_process_initialization();
-      }
+  /// Returns every configured Servarr instance along with its path in the config file (e.g.
+  /// `radarr[0]`) and its display name
+  pub fn servarr_instances(&self) -> impl Iterator<Item = (String, String, &ServarrConfig)> {
+    [
+      ("radarr", "Radarr", &self.radarr),
+      ("sonarr", "Sonarr", &self.sonarr),
+      ("lidarr", "Lidarr", &self.lidarr),
+      ("prowlarr", "Prowlarr", &self.prowlarr),
+      ("readarr", "Readarr", &self.readarr),
+    ]
+    .into_iter()
+    .filter_map(|(key, servarr, configs)| Some((key, servarr, configs.as_ref()?)))
+    .flat_map(|(key, servarr, configs)| {
+      let mut unnamed_idx = 0;
+      configs.iter().enumerate().map(move |(idx, config)| {
+        let instance = config.name.clone().unwrap_or_else(|| {
+          unnamed_idx += 1;
+          format!("{servarr} {unnamed_idx}")
+        });
+
+        (format!("{key}[{idx}]"), instance, config)
+      })
+    })
+  }
+
+  pub fn servarr_configs(&self) -> impl Iterator<Item = &ServarrConfig> {
+    [
+      &self.radarr,
+      &self.sonarr,
+      &self.lidarr,
+      &self.prowlarr,
+      &self.readarr,
+    ]
+    .into_iter()
+    .flatten()
+    .flatten()
+  }
+
+  fn servarr_configs_mut(&mut self) -> impl Iterator<Item = &mut ServarrConfig> {
+    [
+      &mut self.radarr,
+      &mut self.sonarr,
+      &mut self.lidarr,
+      &mut self.prowlarr,
+      &mut self.readarr,
+    ]
+    .into_iter()
+    .flatten()
+    .flatten()
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ConfigValidationError {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub instance: Option<String>,
+  pub field: String,
+  pub message: String,
+}
+
+impl Display for ConfigValidationError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    match &self.instance {
+      Some(instance) => write!(f, "{} ({instance}): {}", self.field, self.message),
+      None if self.field.is_empty() => write!(f, "{}", self.message),
+      None => write!(f, "{}: {}", self.field, self.message),
     }
   }
 }
 
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+#[serde(transparent)]
+pub struct ConfigValidationErrors(pub Vec<ConfigValidationError>);
+
+impl Display for ConfigValidationErrors {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    write!(f, "{}", self.0.iter().join("\n"))
+  }
+}
+
+impl std::error::Error for ConfigValidationErrors {}
+
 #[derive(Redact, Deserialize, Serialize, Clone, PartialEq, Eq)]
+#[serde(from = "ServarrConfigFields")]
 pub struct ServarrConfig {
-  #[serde(default, deserialize_with = "deserialize_optional_env_var")]
   pub name: Option<String>,
-  #[serde(default, deserialize_with = "deserialize_optional_env_var")]
   pub host: Option<String>,
-  #[serde(default, deserialize_with = "deserialize_u16_env_var")]
   pub port: Option<u16>,
-  #[serde(default, deserialize_with = "deserialize_optional_env_var")]
   pub uri: Option<String>,
-  #[serde(default, deserialize_with = "deserialize_u16_env_var")]
   pub weight: Option<u16>,
-  #[serde(default, deserialize_with = "deserialize_optional_env_var")]
   #[redact]
   pub api_token: Option<String>,
-  #[serde(default, deserialize_with = "deserialize_optional_env_var")]
   pub api_token_file: Option<String>,
-  #[serde(default, deserialize_with = "deserialize_optional_env_var_bool")]
   pub ssl: Option<bool>,
-  #[serde(default, deserialize_with = "deserialize_optional_env_var")]
   pub ssl_cert_path: Option<String>,
-  #[serde(
-    default,
-    deserialize_with = "deserialize_optional_env_var_header_map",
-    serialize_with = "serialize_header_map"
-  )]
+  pub accept_invalid_certs: Option<bool>,
+  pub client_cert_path: Option<String>,
+  pub client_key_path: Option<String>,
+  pub proxy: Option<String>,
+  #[serde(serialize_with = "serialize_header_map")]
   pub custom_headers: Option<HeaderMap>,
-  #[serde(default, deserialize_with = "deserialize_optional_env_var_string_vec")]
   pub monitored_storage_paths: Option<Vec<String>>,
+  pub push_updates: Option<bool>,
+  pub timeout_secs: Option<u64>,
+  pub retries: Option<u16>,
+  pub retry_backoff: Option<u64>,
+  #[serde(skip)]
+  pub invalid_values: Vec<InvalidConfigValue>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidConfigValue {
+  pub field: &'static str,
+  pub message: String,
+}
+
+/// The raw fields of a [ServarrConfig] as they appear in the config file. Numeric fields are
+/// parsed separately so a single bad value is reported during validation instead of failing
+/// to load the entire file.
+#[derive(Deserialize)]
+struct ServarrConfigFields {
+  #[serde(default, deserialize_with = "deserialize_optional_env_var")]
+  name: Option<String>,
+  #[serde(default, deserialize_with = "deserialize_optional_env_var")]
+  host: Option<String>,
+  #[serde(default, deserialize_with = "deserialize_optional_env_var")]
+  port: Option<String>,
+  #[serde(default, deserialize_with = "deserialize_optional_env_var")]
+  uri: Option<String>,
+  #[serde(default, deserialize_with = "deserialize_optional_env_var")]
+  weight: Option<String>,
+  #[serde(default, deserialize_with = "deserialize_optional_env_var")]
+  api_token: Option<String>,
+  #[serde(default, deserialize_with = "deserialize_optional_env_var")]
+  api_token_file: Option<String>,
+  #[serde(default, deserialize_with = "deserialize_optional_env_var_bool")]
+  ssl: Option<bool>,
+  #[serde(default, deserialize_with = "deserialize_optional_env_var")]
+  ssl_cert_path: Option<String>,
+  #[serde(default, deserialize_with = "deserialize_optional_env_var_bool")]
+  accept_invalid_certs: Option<bool>,
+  #[serde(default, deserialize_with = "deserialize_optional_env_var")]
+  client_cert_path: Option<String>,
+  #[serde(default, deserialize_with = "deserialize_optional_env_var")]
+  client_key_path: Option<String>,
+  #[serde(default, deserialize_with = "deserialize_optional_env_var")]
+  proxy: Option<String>,
+  #[serde(default, deserialize_with = "deserialize_optional_env_var_header_map")]
+  custom_headers: Option<HeaderMap>,
+  #[serde(default, deserialize_with = "deserialize_optional_env_var_string_vec")]
+  monitored_storage_paths: Option<Vec<String>>,
+  #[serde(default, deserialize_with = "deserialize_optional_env_var_bool")]
+  push_updates: Option<bool>,
+  #[serde(default, deserialize_with = "deserialize_optional_env_var")]
+  timeout_secs: Option<String>,
+  #[serde(default, deserialize_with = "deserialize_optional_env_var")]
+  retries: Option<String>,
+  #[serde(default, deserialize_with = "deserialize_optional_env_var")]
+  retry_backoff: Option<String>,
+}
+
+impl From<ServarrConfigFields> for ServarrConfig {
+  fn from(fields: ServarrConfigFields) -> Self {
+    let mut invalid_values = Vec::new();
+    let port = parse_number("port", fields.port, &mut invalid_values);
+    let weight = parse_number("weight", fields.weight, &mut invalid_values);
+    let timeout_secs = parse_number("timeout_secs", fields.timeout_secs, &mut invalid_values);
+    let retries = parse_number("retries", fields.retries, &mut invalid_values);
+    let retry_backoff = parse_number("retry_backoff", fields.retry_backoff, &mut invalid_values);
+
+    ServarrConfig {
+      name: fields.name,
+      host: fields.host,
+      port,
+      uri: fields.uri,
+      weight,
+      api_token: fields.api_token,
+      api_token_file: fields.api_token_file,
+      ssl: fields.ssl,
+      ssl_cert_path: fields.ssl_cert_path,
+      accept_invalid_certs: fields.accept_invalid_certs,
+      client_cert_path: fields.client_cert_path,
+      client_key_path: fields.client_key_path,
+      proxy: fields.proxy,
+      custom_headers: fields.custom_headers,
+      monitored_storage_paths: fields.monitored_storage_paths,
+      push_updates: fields.push_updates,
+      timeout_secs,
+      retries,
+      retry_backoff,
+      invalid_values,
+    }
+  }
+}
+
+fn parse_number<T>(
+  field: &'static str,
+  value: Option<String>,
+  invalid_values: &mut Vec<InvalidConfigValue>,
+) -> Option<T>
+where
+  T: FromStr,
+  T::Err: Display,
+{
+  value.and_then(|value| match value.parse::<T>() {
+    Ok(number) => Some(number),
+    Err(e) => {
+      invalid_values.push(InvalidConfigValue {
+        field,
+        message: format!("Invalid value '{value}': {e}"),
+      });
+      None
+    }
+  })
 }
 
 impl ServarrConfig {
-  fn validate(&self) {
+  fn validate(&self, path: &str, instance: &str) -> Vec<ConfigValidationError> {
+    let error = |field: &str, message: String| ConfigValidationError {
+      instance: Some(instance.to_owned()),
+      field: format!("{path}.{field}"),
+      message,
+    };
+    let mut errors: Vec<ConfigValidationError> = self
+      .invalid_values
+      .iter()
+      .map(|invalid_value| error(invalid_value.field, invalid_value.message.clone()))
+      .collect();
+
     if self.host.is_none() && self.uri.is_none() {
-      log_and_print_error("'host' or 'uri' is required for configuration".to_owned());
-      process::exit(1);
+      errors.push(error(
+        "host",
+        "'host' or 'uri' is required for configuration".to_owned(),
+      ));
     }
 
     if self.api_token_file.is_none() && self.api_token.is_none() {
-      log_and_print_error(
-        "'api_token' or 'api_token_path' is required for configuration".to_owned(),
-      );
-      process::exit(1);
+      errors.push(error(
+        "api_token",
+        "'api_token' or 'api_token_file' is required for configuration".to_owned(),
+      ));
+    }
+
+    if let Some(api_token_file) = &self.api_token_file
+      && !PathBuf::from(api_token_file).exists()
+    {
+      errors.push(error(
+        "api_token_file",
+        format!("The specified {api_token_file} API token file does not exist"),
+      ));
+    }
+
+    if self.timeout_secs == Some(0) {
+      errors.push(error(
+        "timeout_secs",
+        "'timeout_secs' must be greater than 0".to_owned(),
+      ));
+    }
+
+    if let Some(ssl_cert_path) = &self.ssl_cert_path
+      && !PathBuf::from(ssl_cert_path).exists()
+    {
+      errors.push(error(
+        "ssl_cert_path",
+        format!("The specified {ssl_cert_path} SSL certificate does not exist"),
+      ));
+    }
+
+    match (&self.client_cert_path, &self.client_key_path) {
+      (Some(_), None) => errors.push(error(
+        "client_key_path",
+        "'client_key_path' is required when 'client_cert_path' is specified".to_owned(),
+      )),
+      (None, Some(_)) => errors.push(error(
+        "client_cert_path",
+        "'client_cert_path' is required when 'client_key_path' is specified".to_owned(),
+      )),
+      _ => (),
+    }
+
+    if let Some(client_cert_path) = &self.client_cert_path
+      && !PathBuf::from(client_cert_path).exists()
+    {
+      errors.push(error(
+        "client_cert_path",
+        format!("The specified {client_cert_path} client certificate does not exist"),
+      ));
+    }
+
+    if let Some(client_key_path) = &self.client_key_path
+      && !PathBuf::from(client_key_path).exists()
+    {
+      errors.push(error(
+        "client_key_path",
+        format!("The specified {client_key_path} client key does not exist"),
+      ));
+    }
+
+    if let Some(proxy) = &self.proxy
+      && let Err(e) = Proxy::all(proxy)
+    {
+      errors.push(error("proxy", format!("Invalid proxy '{proxy}': {e}")));
     }
+
+    errors
   }
 
-  pub fn post_process_initialization(&mut self) {
+  pub fn post_process_initialization(&mut self) -> Result<()> {
     if let Some(api_token_file) = self.api_token_file.as_ref() {
       if !PathBuf::from(api_token_file).exists() {
-        log_and_print_error(format!(
+        return Err(anyhow!(
           "The specified {api_token_file} API token file does not exist"
         ));
-        process::exit(1);
       }
 
-      let api_token = fs::read_to_string(api_token_file)
-        .map_err(|e| anyhow!(e))
-        .unwrap();
+      let api_token = fs::read_to_string(api_token_file)?;
       self.api_token = Some(api_token.trim().to_owned());
     }
+
+    Ok(())
   }
 }
 
@@ -489,8 +930,17 @@ impl Default for ServarrConfig {
       api_token_file: None,
       ssl_cert_path: None,
       ssl: None,
+      accept_invalid_certs: None,
+      client_cert_path: None,
+      client_key_path: None,
+      proxy: None,
       custom_headers: None,
       monitored_storage_paths: None,
+      push_updates: None,
+      timeout_secs: None,
+      retries: None,
+      retry_backoff: None,
+      invalid_values: Vec::new(),
     }
   }
 }
@@ -598,23 +1048,6 @@ where
   }
 }
 
-fn deserialize_u16_env_var<'de, D>(deserializer: D) -> Result<Option<u16>, D::Error>
-where
-  D: serde::Deserializer<'de>,
-{
-  let s: Option<String> = Option::deserialize(deserializer)?;
-  match s {
-    Some(value) => {
-      let interpolated = interpolate_env_vars(&value);
-      interpolated
-        .parse::<u16>()
-        .map(Some)
-        .map_err(serde::de::Error::custom)
-    }
-    None => Ok(None),
-  }
-}
-
 fn interpolate_env_vars(s: &str) -> String {
   let result = s.to_string();
   let scrubbing_regex = Regex::new(r#"[\s{}!$^()\[\]\\|`'"]+"#).unwrap();
@@ -0,0 +1,173 @@
+use super::App;
+use crate::{
+  models::{
+    prowlarr_models::ProwlarrSearchParams,
+    servarr_data::prowlarr::prowlarr_data::ActiveProwlarrBlock,
+  },
+  network::prowlarr_network::ProwlarrEvent,
+};
+
+pub mod prowlarr_context_clues;
+
+#[cfg(test)]
+#[path = "prowlarr_tests.rs"]
+mod prowlarr_tests;
+
+impl App<'_> {
+  pub(super) async fn dispatch_by_prowlarr_block(
+    &mut self,
+    active_prowlarr_block: &ActiveProwlarrBlock,
+  ) {
+    match active_prowlarr_block {
+      ActiveProwlarrBlock::Indexers => {
+        self
+          .dispatch_network_event(ProwlarrEvent::GetTags.into())
+          .await;
+        self
+          .dispatch_network_event(ProwlarrEvent::GetIndexers.into())
+          .await;
+      }
+      ActiveProwlarrBlock::SearchReleases => {
+        if self.data.prowlarr_data.search_results.is_empty()
+          && let Some(query) = self.extract_prowlarr_search_query().await
+        {
+          self
+            .dispatch_network_event(
+              ProwlarrEvent::SearchReleases(ProwlarrSearchParams {
+                query,
+                ..ProwlarrSearchParams::default()
+              })
+              .into(),
+            )
+            .await;
+        }
+      }
+      ActiveProwlarrBlock::Applications => {
+        self
+          .dispatch_network_event(ProwlarrEvent::GetTags.into())
+          .await;
+        self
+          .dispatch_network_event(ProwlarrEvent::GetApplications.into())
+          .await;
+      }
+      ActiveProwlarrBlock::IndexerProxies => {
+        self
+          .dispatch_network_event(ProwlarrEvent::GetTags.into())
+          .await;
+        self
+          .dispatch_network_event(ProwlarrEvent::GetIndexerProxies.into())
+          .await;
+      }
+      ActiveProwlarrBlock::History => {
+        self
+          .dispatch_network_event(ProwlarrEvent::GetHistory(500).into())
+          .await;
+      }
+      ActiveProwlarrBlock::AddIndexerSelectImplementation => {
+        self
+          .dispatch_network_event(ProwlarrEvent::GetIndexerSchema.into())
+          .await;
+      }
+      ActiveProwlarrBlock::TestIndexer => {
+        self
+          .dispatch_network_event(
+            ProwlarrEvent::TestIndexer(self.extract_prowlarr_indexer_id().await).into(),
+          )
+          .await;
+      }
+      ActiveProwlarrBlock::TestAllIndexers => {
+        self
+          .dispatch_network_event(ProwlarrEvent::TestAllIndexers.into())
+          .await;
+      }
+      ActiveProwlarrBlock::System => {
+        self
+          .dispatch_network_event(ProwlarrEvent::GetTasks.into())
+          .await;
+        self
+          .dispatch_network_event(ProwlarrEvent::GetQueuedEvents.into())
+          .await;
+        self
+          .dispatch_network_event(ProwlarrEvent::GetLogs(500).into())
+          .await;
+      }
+      ActiveProwlarrBlock::SystemUpdates => {
+        self
+          .dispatch_network_event(ProwlarrEvent::GetUpdates.into())
+          .await;
+      }
+      _ => (),
+    }
+
+    self.check_for_prowlarr_prompt_action().await;
+    self.reset_tick_count();
+  }
+
+  async fn extract_prowlarr_search_query(&self) -> Option<String> {
+    self
+      .data
+      .prowlarr_data
+      .search_query
+      .as_ref()
+      .map(|query| query.text.trim().to_owned())
+      .filter(|query| !query.is_empty())
+  }
+
+  async fn extract_prowlarr_indexer_id(&self) -> i64 {
+    self.data.prowlarr_data.indexers.current_selection().id
+  }
+
+  async fn check_for_prowlarr_prompt_action(&mut self) {
+    if self.data.prowlarr_data.prompt_confirm {
+      self.data.prowlarr_data.prompt_confirm = false;
+      if let Some(prowlarr_event) = self.data.prowlarr_data.prompt_confirm_action.take() {
+        self.dispatch_network_event(prowlarr_event.into()).await;
+        self.should_refresh = true;
+      }
+    }
+  }
+
+  pub(super) async fn prowlarr_on_tick(&mut self, active_prowlarr_block: ActiveProwlarrBlock) {
+    if self.is_first_render {
+      self.refresh_prowlarr_metadata().await;
+      self
+        .dispatch_by_prowlarr_block(&active_prowlarr_block)
+        .await;
+      self.is_first_render = false;
+      return;
+    }
+
+    if self.should_refresh {
+      self
+        .dispatch_by_prowlarr_block(&active_prowlarr_block)
+        .await;
+      self.refresh_prowlarr_metadata().await;
+    }
+
+    if self.is_routing {
+      if !self.should_refresh {
+        self.cancellation_token.cancel();
+      } else {
+        self
+          .dispatch_by_prowlarr_block(&active_prowlarr_block)
+          .await;
+      }
+    }
+
+    if self.should_poll() {
+      self.refresh_prowlarr_metadata().await;
+    }
+  }
+
+  async fn refresh_prowlarr_metadata(&mut self) {
+    self
+      .dispatch_network_event(ProwlarrEvent::GetTags.into())
+      .await;
+    self
+      .dispatch_network_event(ProwlarrEvent::GetIndexers.into())
+      .await;
+    self
+      .dispatch_network_event(ProwlarrEvent::GetStatus.into())
+      .await;
+  }
+}
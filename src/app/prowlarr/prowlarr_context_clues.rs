@@ -0,0 +1,73 @@
+use crate::app::App;
+use crate::app::context_clues::{
+  BARE_POPUP_CONTEXT_CLUES, CONFIRMATION_PROMPT_CONTEXT_CLUES, ContextClue, ContextClueProvider,
+  SCHEMA_FORM_CONTEXT_CLUES, SYSTEM_TASKS_CONTEXT_CLUES,
+};
+use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+use crate::models::Route;
+use crate::models::servarr_data::prowlarr::prowlarr_data::ActiveProwlarrBlock;
+
+#[cfg(test)]
+#[path = "prowlarr_context_clues_tests.rs"]
+mod prowlarr_context_clues_tests;
+
+pub static PROWLARR_INDEXERS_CONTEXT_CLUES: [ContextClue; 5] = [
+  (DEFAULT_KEYBINDINGS.add, DEFAULT_KEYBINDINGS.add.desc),
+  (DEFAULT_KEYBINDINGS.delete, DEFAULT_KEYBINDINGS.delete.desc),
+  (DEFAULT_KEYBINDINGS.test, "test indexer"),
+  (DEFAULT_KEYBINDINGS.test_all, "test all indexers"),
+  (
+    DEFAULT_KEYBINDINGS.refresh,
+    DEFAULT_KEYBINDINGS.refresh.desc,
+  ),
+];
+
+pub static SEARCH_RELEASES_CONTEXT_CLUES: [ContextClue; 3] = [
+  (DEFAULT_KEYBINDINGS.search, DEFAULT_KEYBINDINGS.search.desc),
+  (DEFAULT_KEYBINDINGS.sort, DEFAULT_KEYBINDINGS.sort.desc),
+  (DEFAULT_KEYBINDINGS.submit, "grab"),
+];
+
+pub static APPLICATIONS_CONTEXT_CLUES: [ContextClue; 3] = [
+  (DEFAULT_KEYBINDINGS.delete, DEFAULT_KEYBINDINGS.delete.desc),
+  (DEFAULT_KEYBINDINGS.update, "sync app indexers"),
+  (
+    DEFAULT_KEYBINDINGS.refresh,
+    DEFAULT_KEYBINDINGS.refresh.desc,
+  ),
+];
+
+pub static INDEXER_PROXIES_CONTEXT_CLUES: [ContextClue; 2] = [
+  (DEFAULT_KEYBINDINGS.delete, DEFAULT_KEYBINDINGS.delete.desc),
+  (
+    DEFAULT_KEYBINDINGS.refresh,
+    DEFAULT_KEYBINDINGS.refresh.desc,
+  ),
+];
+
+pub(in crate::app) struct ProwlarrContextClueProvider;
+
+impl ContextClueProvider for ProwlarrContextClueProvider {
+  fn get_context_clues(app: &mut App<'_>) -> Option<&'static [ContextClue]> {
+    let Route::Prowlarr(active_prowlarr_block, _context_option) = app.get_current_route() else {
+      panic!("ProwlarrContextClueProvider::get_context_clues called with non-Prowlarr route");
+    };
+
+    match active_prowlarr_block {
+      ActiveProwlarrBlock::TestAllIndexers
+      | ActiveProwlarrBlock::HistoryItemDetails
+      | ActiveProwlarrBlock::SystemLogs
+      | ActiveProwlarrBlock::SystemUpdates => Some(&BARE_POPUP_CONTEXT_CLUES),
+      ActiveProwlarrBlock::AddIndexerPrompt => Some(&SCHEMA_FORM_CONTEXT_CLUES),
+      ActiveProwlarrBlock::AddIndexerSelectImplementation
+      | ActiveProwlarrBlock::AddIndexerFieldInput
+      | ActiveProwlarrBlock::SearchReleasesInput => Some(&CONFIRMATION_PROMPT_CONTEXT_CLUES),
+      ActiveProwlarrBlock::SystemTasks => Some(&SYSTEM_TASKS_CONTEXT_CLUES),
+      _ => app
+        .data
+        .prowlarr_data
+        .main_tabs
+        .get_active_route_contextual_help(),
+    }
+  }
+}
@@ -0,0 +1,193 @@
+#[cfg(test)]
+mod tests {
+  use crate::app::App;
+  use crate::app::context_clues::{
+    BARE_POPUP_CONTEXT_CLUES, CONFIRMATION_PROMPT_CONTEXT_CLUES, ContextClue, ContextClueProvider,
+    HISTORY_CONTEXT_CLUES, SCHEMA_FORM_CONTEXT_CLUES, SYSTEM_CONTEXT_CLUES,
+    SYSTEM_TASKS_CONTEXT_CLUES,
+  };
+  use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+  use crate::app::prowlarr::prowlarr_context_clues::{
+    APPLICATIONS_CONTEXT_CLUES, INDEXER_PROXIES_CONTEXT_CLUES, PROWLARR_INDEXERS_CONTEXT_CLUES,
+    ProwlarrContextClueProvider, SEARCH_RELEASES_CONTEXT_CLUES,
+  };
+  use crate::models::servarr_data::prowlarr::prowlarr_data::ActiveProwlarrBlock;
+  use crate::models::servarr_data::radarr::radarr_data::ActiveRadarrBlock;
+  use rstest::rstest;
+
+  #[test]
+  fn test_prowlarr_indexers_context_clues() {
+    let mut indexers_context_clues_iter = PROWLARR_INDEXERS_CONTEXT_CLUES.iter();
+
+    assert_some_eq_x!(
+      indexers_context_clues_iter.next(),
+      &(DEFAULT_KEYBINDINGS.add, DEFAULT_KEYBINDINGS.add.desc)
+    );
+    assert_some_eq_x!(
+      indexers_context_clues_iter.next(),
+      &(DEFAULT_KEYBINDINGS.delete, DEFAULT_KEYBINDINGS.delete.desc)
+    );
+    assert_some_eq_x!(
+      indexers_context_clues_iter.next(),
+      &(DEFAULT_KEYBINDINGS.test, "test indexer")
+    );
+    assert_some_eq_x!(
+      indexers_context_clues_iter.next(),
+      &(DEFAULT_KEYBINDINGS.test_all, "test all indexers")
+    );
+    assert_some_eq_x!(
+      indexers_context_clues_iter.next(),
+      &(
+        DEFAULT_KEYBINDINGS.refresh,
+        DEFAULT_KEYBINDINGS.refresh.desc
+      )
+    );
+    assert_none!(indexers_context_clues_iter.next());
+  }
+
+  #[test]
+  fn test_search_releases_context_clues() {
+    let mut search_releases_context_clues_iter = SEARCH_RELEASES_CONTEXT_CLUES.iter();
+
+    assert_some_eq_x!(
+      search_releases_context_clues_iter.next(),
+      &(DEFAULT_KEYBINDINGS.search, DEFAULT_KEYBINDINGS.search.desc)
+    );
+    assert_some_eq_x!(
+      search_releases_context_clues_iter.next(),
+      &(DEFAULT_KEYBINDINGS.sort, DEFAULT_KEYBINDINGS.sort.desc)
+    );
+    assert_some_eq_x!(
+      search_releases_context_clues_iter.next(),
+      &(DEFAULT_KEYBINDINGS.submit, "grab")
+    );
+    assert_none!(search_releases_context_clues_iter.next());
+  }
+
+  #[test]
+  fn test_applications_context_clues() {
+    let mut applications_context_clues_iter = APPLICATIONS_CONTEXT_CLUES.iter();
+
+    assert_some_eq_x!(
+      applications_context_clues_iter.next(),
+      &(DEFAULT_KEYBINDINGS.delete, DEFAULT_KEYBINDINGS.delete.desc)
+    );
+    assert_some_eq_x!(
+      applications_context_clues_iter.next(),
+      &(DEFAULT_KEYBINDINGS.update, "sync app indexers")
+    );
+    assert_some_eq_x!(
+      applications_context_clues_iter.next(),
+      &(
+        DEFAULT_KEYBINDINGS.refresh,
+        DEFAULT_KEYBINDINGS.refresh.desc
+      )
+    );
+    assert_none!(applications_context_clues_iter.next());
+  }
+
+  #[test]
+  fn test_indexer_proxies_context_clues() {
+    let mut indexer_proxies_context_clues_iter = INDEXER_PROXIES_CONTEXT_CLUES.iter();
+
+    assert_some_eq_x!(
+      indexer_proxies_context_clues_iter.next(),
+      &(DEFAULT_KEYBINDINGS.delete, DEFAULT_KEYBINDINGS.delete.desc)
+    );
+    assert_some_eq_x!(
+      indexer_proxies_context_clues_iter.next(),
+      &(
+        DEFAULT_KEYBINDINGS.refresh,
+        DEFAULT_KEYBINDINGS.refresh.desc
+      )
+    );
+    assert_none!(indexer_proxies_context_clues_iter.next());
+  }
+
+  #[test]
+  #[should_panic(
+    expected = "ProwlarrContextClueProvider::get_context_clues called with non-Prowlarr route"
+  )]
+  fn test_prowlarr_context_clue_provider_get_context_clues_non_prowlarr_route() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveRadarrBlock::default().into());
+
+    ProwlarrContextClueProvider::get_context_clues(&mut app);
+  }
+
+  #[rstest]
+  fn test_prowlarr_context_clue_provider_bare_popup_context_clues(
+    #[values(
+      ActiveProwlarrBlock::TestAllIndexers,
+      ActiveProwlarrBlock::HistoryItemDetails,
+      ActiveProwlarrBlock::SystemLogs,
+      ActiveProwlarrBlock::SystemUpdates
+    )]
+    active_prowlarr_block: ActiveProwlarrBlock,
+  ) {
+    let mut app = App::test_default();
+    app.push_navigation_stack(active_prowlarr_block.into());
+
+    let context_clues = ProwlarrContextClueProvider::get_context_clues(&mut app);
+
+    assert_some_eq_x!(context_clues, &BARE_POPUP_CONTEXT_CLUES);
+  }
+
+  #[test]
+  fn test_prowlarr_context_clue_provider_add_indexer_prompt_context_clues() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveProwlarrBlock::AddIndexerPrompt.into());
+
+    let context_clues = ProwlarrContextClueProvider::get_context_clues(&mut app);
+
+    assert_some_eq_x!(context_clues, &SCHEMA_FORM_CONTEXT_CLUES);
+  }
+
+  #[rstest]
+  fn test_prowlarr_context_clue_provider_confirmation_prompt_context_clues(
+    #[values(
+      ActiveProwlarrBlock::AddIndexerSelectImplementation,
+      ActiveProwlarrBlock::AddIndexerFieldInput,
+      ActiveProwlarrBlock::SearchReleasesInput
+    )]
+    active_prowlarr_block: ActiveProwlarrBlock,
+  ) {
+    let mut app = App::test_default();
+    app.push_navigation_stack(active_prowlarr_block.into());
+
+    let context_clues = ProwlarrContextClueProvider::get_context_clues(&mut app);
+
+    assert_some_eq_x!(context_clues, &CONFIRMATION_PROMPT_CONTEXT_CLUES);
+  }
+
+  #[test]
+  fn test_prowlarr_context_clue_provider_system_tasks() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveProwlarrBlock::SystemTasks.into());
+
+    let context_clues = ProwlarrContextClueProvider::get_context_clues(&mut app);
+
+    assert_some_eq_x!(context_clues, &SYSTEM_TASKS_CONTEXT_CLUES);
+  }
+
+  #[rstest]
+  #[case(0, ActiveProwlarrBlock::Indexers, &PROWLARR_INDEXERS_CONTEXT_CLUES)]
+  #[case(1, ActiveProwlarrBlock::SearchReleases, &SEARCH_RELEASES_CONTEXT_CLUES)]
+  #[case(2, ActiveProwlarrBlock::Applications, &APPLICATIONS_CONTEXT_CLUES)]
+  #[case(3, ActiveProwlarrBlock::IndexerProxies, &INDEXER_PROXIES_CONTEXT_CLUES)]
+  #[case(4, ActiveProwlarrBlock::History, &HISTORY_CONTEXT_CLUES)]
+  #[case(5, ActiveProwlarrBlock::System, &SYSTEM_CONTEXT_CLUES)]
+  fn test_prowlarr_context_clue_provider_prowlarr_blocks_context_clues(
+    #[case] index: usize,
+    #[case] active_prowlarr_block: ActiveProwlarrBlock,
+    #[case] expected_context_clues: &[ContextClue],
+  ) {
+    let mut app = App::test_default();
+    app.push_navigation_stack(active_prowlarr_block.into());
+    app.data.prowlarr_data.main_tabs.set_index(index);
+
+    let context_clues = ProwlarrContextClueProvider::get_context_clues(&mut app);
+
+    assert_some_eq_x!(context_clues, expected_context_clues);
+  }
+}
@@ -0,0 +1,410 @@
+#[cfg(test)]
+mod tests {
+  use crate::app::App;
+  use crate::models::prowlarr_models::{ProwlarrSearchParams, ProwlarrTaskName};
+  use crate::models::servarr_data::prowlarr::prowlarr_data::ActiveProwlarrBlock;
+  use crate::network::QueuedNetworkEvent;
+  use crate::network::prowlarr_network::ProwlarrEvent;
+  use crate::network::prowlarr_network::prowlarr_network_test_utils::test_utils::{
+    indexer, release,
+  };
+  use pretty_assertions::assert_eq;
+  use tokio::sync::mpsc;
+
+  #[tokio::test]
+  async fn test_dispatch_by_prowlarr_block_indexers() {
+    let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
+    let mut app = App::test_default();
+    app.data.prowlarr_data.prompt_confirm = true;
+    app.network_tx = Some(tx);
+
+    app
+      .dispatch_by_prowlarr_block(&ActiveProwlarrBlock::Indexers)
+      .await;
+
+    assert!(app.is_loading);
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      ProwlarrEvent::GetTags.into()
+    );
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      ProwlarrEvent::GetIndexers.into()
+    );
+    assert!(!app.data.prowlarr_data.prompt_confirm);
+    assert_eq!(app.tick_count, 0);
+  }
+
+  #[tokio::test]
+  async fn test_dispatch_by_prowlarr_block_add_indexer_select_implementation() {
+    let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
+    let mut app = App::test_default();
+    app.network_tx = Some(tx);
+
+    app
+      .dispatch_by_prowlarr_block(&ActiveProwlarrBlock::AddIndexerSelectImplementation)
+      .await;
+
+    assert!(app.is_loading);
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      ProwlarrEvent::GetIndexerSchema.into()
+    );
+    assert_eq!(app.tick_count, 0);
+  }
+
+  #[tokio::test]
+  async fn test_dispatch_by_prowlarr_block_search_releases() {
+    let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
+    let mut app = App::test_default();
+    app.data.prowlarr_data.search_query = Some(" Test Release ".into());
+    app.network_tx = Some(tx);
+
+    app
+      .dispatch_by_prowlarr_block(&ActiveProwlarrBlock::SearchReleases)
+      .await;
+
+    assert!(app.is_loading);
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      ProwlarrEvent::SearchReleases(ProwlarrSearchParams {
+        query: "Test Release".to_owned(),
+        ..ProwlarrSearchParams::default()
+      })
+      .into()
+    );
+    assert_eq!(app.tick_count, 0);
+  }
+
+  #[tokio::test]
+  async fn test_dispatch_by_prowlarr_block_search_releases_no_op_when_results_are_present() {
+    let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
+    let mut app = App::test_default();
+    app.data.prowlarr_data.search_query = Some("Test Release".into());
+    app
+      .data
+      .prowlarr_data
+      .search_results
+      .set_items(vec![release()]);
+    app.network_tx = Some(tx);
+
+    app
+      .dispatch_by_prowlarr_block(&ActiveProwlarrBlock::SearchReleases)
+      .await;
+
+    assert!(!app.is_loading);
+    assert!(rx.try_recv().is_err());
+  }
+
+  #[tokio::test]
+  async fn test_dispatch_by_prowlarr_block_search_releases_no_op_when_query_is_empty() {
+    let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
+    let mut app = App::test_default();
+    app.data.prowlarr_data.search_query = Some("   ".into());
+    app.network_tx = Some(tx);
+
+    app
+      .dispatch_by_prowlarr_block(&ActiveProwlarrBlock::SearchReleases)
+      .await;
+
+    assert!(!app.is_loading);
+    assert!(rx.try_recv().is_err());
+  }
+
+  #[tokio::test]
+  async fn test_dispatch_by_prowlarr_block_applications() {
+    let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
+    let mut app = App::test_default();
+    app.network_tx = Some(tx);
+
+    app
+      .dispatch_by_prowlarr_block(&ActiveProwlarrBlock::Applications)
+      .await;
+
+    assert!(app.is_loading);
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      ProwlarrEvent::GetTags.into()
+    );
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      ProwlarrEvent::GetApplications.into()
+    );
+    assert_eq!(app.tick_count, 0);
+  }
+
+  #[tokio::test]
+  async fn test_dispatch_by_prowlarr_block_indexer_proxies() {
+    let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
+    let mut app = App::test_default();
+    app.network_tx = Some(tx);
+
+    app
+      .dispatch_by_prowlarr_block(&ActiveProwlarrBlock::IndexerProxies)
+      .await;
+
+    assert!(app.is_loading);
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      ProwlarrEvent::GetTags.into()
+    );
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      ProwlarrEvent::GetIndexerProxies.into()
+    );
+    assert_eq!(app.tick_count, 0);
+  }
+
+  #[tokio::test]
+  async fn test_dispatch_by_prowlarr_block_history() {
+    let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
+    let mut app = App::test_default();
+    app.network_tx = Some(tx);
+
+    app
+      .dispatch_by_prowlarr_block(&ActiveProwlarrBlock::History)
+      .await;
+
+    assert!(app.is_loading);
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      ProwlarrEvent::GetHistory(500).into()
+    );
+    assert_eq!(app.tick_count, 0);
+  }
+
+  #[tokio::test]
+  async fn test_dispatch_by_prowlarr_block_test_indexer() {
+    let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
+    let mut app = App::test_default();
+    app.data.prowlarr_data.indexers.set_items(vec![indexer()]);
+    app.network_tx = Some(tx);
+
+    app
+      .dispatch_by_prowlarr_block(&ActiveProwlarrBlock::TestIndexer)
+      .await;
+
+    assert!(app.is_loading);
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      ProwlarrEvent::TestIndexer(1).into()
+    );
+    assert_eq!(app.tick_count, 0);
+  }
+
+  #[tokio::test]
+  async fn test_dispatch_by_prowlarr_block_test_all_indexers() {
+    let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
+    let mut app = App::test_default();
+    app.network_tx = Some(tx);
+
+    app
+      .dispatch_by_prowlarr_block(&ActiveProwlarrBlock::TestAllIndexers)
+      .await;
+
+    assert!(app.is_loading);
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      ProwlarrEvent::TestAllIndexers.into()
+    );
+    assert_eq!(app.tick_count, 0);
+  }
+
+  #[tokio::test]
+  async fn test_dispatch_by_prowlarr_block_system() {
+    let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
+    let mut app = App::test_default();
+    app.network_tx = Some(tx);
+
+    app
+      .dispatch_by_prowlarr_block(&ActiveProwlarrBlock::System)
+      .await;
+
+    assert!(app.is_loading);
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      ProwlarrEvent::GetTasks.into()
+    );
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      ProwlarrEvent::GetQueuedEvents.into()
+    );
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      ProwlarrEvent::GetLogs(500).into()
+    );
+    assert_eq!(app.tick_count, 0);
+  }
+
+  #[tokio::test]
+  async fn test_dispatch_by_prowlarr_block_system_updates() {
+    let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
+    let mut app = App::test_default();
+    app.network_tx = Some(tx);
+
+    app
+      .dispatch_by_prowlarr_block(&ActiveProwlarrBlock::SystemUpdates)
+      .await;
+
+    assert!(app.is_loading);
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      ProwlarrEvent::GetUpdates.into()
+    );
+    assert_eq!(app.tick_count, 0);
+  }
+
+  #[tokio::test]
+  async fn test_check_for_prowlarr_prompt_action_no_prompt_confirm() {
+    let mut app = App::test_default();
+    app.data.prowlarr_data.prompt_confirm = false;
+
+    app.check_for_prowlarr_prompt_action().await;
+
+    assert!(!app.data.prowlarr_data.prompt_confirm);
+    assert!(!app.should_refresh);
+  }
+
+  #[tokio::test]
+  async fn test_check_for_prowlarr_prompt_action() {
+    let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
+    let mut app = App::test_default();
+    app.data.prowlarr_data.prompt_confirm = true;
+    app.network_tx = Some(tx);
+    app.data.prowlarr_data.prompt_confirm_action = Some(ProwlarrEvent::StartTask(
+      ProwlarrTaskName::ApplicationIndexerSync,
+    ));
+
+    app.check_for_prowlarr_prompt_action().await;
+
+    assert!(!app.data.prowlarr_data.prompt_confirm);
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      ProwlarrEvent::StartTask(ProwlarrTaskName::ApplicationIndexerSync).into()
+    );
+    assert!(app.should_refresh);
+    assert_eq!(app.data.prowlarr_data.prompt_confirm_action, None);
+  }
+
+  #[tokio::test]
+  async fn test_prowlarr_refresh_metadata() {
+    let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
+    let mut app = App::test_default();
+    app.network_tx = Some(tx);
+    app.is_routing = true;
+
+    app.refresh_prowlarr_metadata().await;
+
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      ProwlarrEvent::GetTags.into()
+    );
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      ProwlarrEvent::GetIndexers.into()
+    );
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      ProwlarrEvent::GetStatus.into()
+    );
+    assert!(app.is_loading);
+  }
+
+  #[tokio::test]
+  async fn test_prowlarr_on_tick_first_render() {
+    let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
+    let mut app = App::test_default();
+    app.data.prowlarr_data.prompt_confirm = true;
+    app.network_tx = Some(tx);
+    app.is_first_render = true;
+
+    app.prowlarr_on_tick(ActiveProwlarrBlock::History).await;
+
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      ProwlarrEvent::GetTags.into()
+    );
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      ProwlarrEvent::GetIndexers.into()
+    );
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      ProwlarrEvent::GetStatus.into()
+    );
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      ProwlarrEvent::GetHistory(500).into()
+    );
+    assert!(app.is_loading);
+    assert!(!app.data.prowlarr_data.prompt_confirm);
+    assert!(!app.is_first_render);
+  }
+
+  #[tokio::test]
+  async fn test_prowlarr_on_tick_routing() {
+    let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
+    let mut app = App::test_default();
+    app.network_tx = Some(tx);
+    app.is_routing = true;
+    app.should_refresh = true;
+    app.is_first_render = false;
+    app.tick_count = 1;
+
+    app.prowlarr_on_tick(ActiveProwlarrBlock::History).await;
+
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      ProwlarrEvent::GetHistory(500).into()
+    );
+  }
+
+  #[tokio::test]
+  async fn test_prowlarr_on_tick_routing_while_long_request_is_running_should_cancel_request() {
+    let (tx, _) = mpsc::channel::<QueuedNetworkEvent>(500);
+    let mut app = App::test_default();
+    app.network_tx = Some(tx);
+    app.is_routing = true;
+    app.should_refresh = false;
+    app.is_first_render = false;
+    app.tick_count = 1;
+
+    app.prowlarr_on_tick(ActiveProwlarrBlock::History).await;
+
+    assert!(app.cancellation_token.is_cancelled());
+  }
+
+  #[tokio::test]
+  async fn test_prowlarr_on_tick_network_tick_frequency() {
+    let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
+    let mut app = App::test_default();
+    app.network_tx = Some(tx);
+    app.is_first_render = false;
+    app.tick_count = 2;
+    app.tick_until_poll = 2;
+
+    app.prowlarr_on_tick(ActiveProwlarrBlock::History).await;
+
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      ProwlarrEvent::GetTags.into()
+    );
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      ProwlarrEvent::GetIndexers.into()
+    );
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      ProwlarrEvent::GetStatus.into()
+    );
+    assert!(app.is_loading);
+  }
+
+  #[tokio::test]
+  async fn test_extract_prowlarr_indexer_id() {
+    let mut app = App::test_default();
+    app.data.prowlarr_data.indexers.set_items(vec![indexer()]);
+
+    assert_eq!(app.extract_prowlarr_indexer_id().await, 1);
+  }
+}
@@ -1,6 +1,9 @@
+use chrono::Local;
+
 use crate::app::App;
 use crate::models::servarr_data::radarr::radarr_data::ActiveRadarrBlock;
 use crate::network::radarr_network::RadarrEvent;
+use crate::utils::calendar_range;
 
 pub mod radarr_context_clues;
 
@@ -16,6 +19,28 @@ impl App<'_> {
           .dispatch_network_event(RadarrEvent::GetBlocklist.into())
           .await;
       }
+      ActiveRadarrBlock::Calendar | ActiveRadarrBlock::CalendarAgenda => {
+        let (start, end) = calendar_range(Local::now().date_naive());
+        self
+          .dispatch_network_event(RadarrEvent::GetCalendar(start, end).into())
+          .await;
+      }
+      ActiveRadarrBlock::WantedMissing => {
+        self
+          .dispatch_network_event(RadarrEvent::GetQualityProfiles.into())
+          .await;
+        self
+          .dispatch_network_event(RadarrEvent::GetWantedMissing(500).into())
+          .await;
+      }
+      ActiveRadarrBlock::WantedCutoffUnmet => {
+        self
+          .dispatch_network_event(RadarrEvent::GetQualityProfiles.into())
+          .await;
+        self
+          .dispatch_network_event(RadarrEvent::GetCutoffUnmet(500).into())
+          .await;
+      }
       ActiveRadarrBlock::Collections => {
         self
           .dispatch_network_event(RadarrEvent::GetQualityProfiles.into())
@@ -43,11 +68,35 @@ impl App<'_> {
           .dispatch_network_event(RadarrEvent::GetDownloads(500).into())
           .await;
       }
+      ActiveRadarrBlock::ManualImport => {
+        if self.data.radarr_data.movies.is_empty() {
+          self
+            .dispatch_network_event(RadarrEvent::GetMovies.into())
+            .await;
+        }
+
+        if let Some(manual_import_modal) = self.data.radarr_data.manual_import_modal.as_ref() {
+          let event = RadarrEvent::GetManualImport(
+            manual_import_modal.download_id.clone(),
+            manual_import_modal.movie_id,
+          );
+          self.dispatch_network_event(event.into()).await;
+        }
+      }
       ActiveRadarrBlock::RootFolders => {
         self
           .dispatch_network_event(RadarrEvent::GetRootFolders.into())
           .await;
       }
+      ActiveRadarrBlock::MediaManagementSettingsPrompt => {
+        let event = match self.data.radarr_data.media_management_modal.as_ref() {
+          Some(media_management_modal) => {
+            RadarrEvent::GetNamingExamples(media_management_modal.settings().naming)
+          }
+          None => RadarrEvent::GetMediaManagementSettings,
+        };
+        self.dispatch_network_event(event.into()).await;
+      }
       ActiveRadarrBlock::Movies => {
         self
           .dispatch_network_event(RadarrEvent::GetQualityProfiles.into())
@@ -87,11 +136,51 @@ impl App<'_> {
           )
           .await;
       }
+      ActiveRadarrBlock::AddIndexerSelectImplementation => {
+        self
+          .dispatch_network_event(RadarrEvent::GetIndexerSchema.into())
+          .await;
+      }
       ActiveRadarrBlock::TestAllIndexers => {
         self
           .dispatch_network_event(RadarrEvent::TestAllIndexers.into())
           .await;
       }
+      ActiveRadarrBlock::QualityProfiles => {
+        self
+          .dispatch_network_event(RadarrEvent::GetQualityProfiles.into())
+          .await;
+      }
+      ActiveRadarrBlock::CustomFormats => {
+        self
+          .dispatch_network_event(RadarrEvent::GetCustomFormats.into())
+          .await;
+      }
+      ActiveRadarrBlock::DownloadClients => {
+        self
+          .dispatch_network_event(RadarrEvent::GetTags.into())
+          .await;
+        self
+          .dispatch_network_event(RadarrEvent::GetDownloadClients.into())
+          .await;
+      }
+      ActiveRadarrBlock::TestDownloadClient => {
+        self
+          .dispatch_network_event(
+            RadarrEvent::TestDownloadClient(self.extract_radarr_download_client_id().await).into(),
+          )
+          .await;
+      }
+      ActiveRadarrBlock::AddDownloadClientSelectImplementation => {
+        self
+          .dispatch_network_event(RadarrEvent::GetDownloadClientSchema.into())
+          .await;
+      }
+      ActiveRadarrBlock::TestAllDownloadClients => {
+        self
+          .dispatch_network_event(RadarrEvent::TestAllDownloadClients.into())
+          .await;
+      }
       ActiveRadarrBlock::System => {
         self
           .dispatch_network_event(RadarrEvent::GetTasks.into())
@@ -103,6 +192,11 @@ impl App<'_> {
           .dispatch_network_event(RadarrEvent::GetLogs(500).into())
           .await;
       }
+      ActiveRadarrBlock::SystemBackups => {
+        self
+          .dispatch_network_event(RadarrEvent::GetBackups.into())
+          .await;
+      }
       ActiveRadarrBlock::SystemUpdates => {
         self
           .dispatch_network_event(RadarrEvent::GetUpdates.into())
@@ -190,7 +284,7 @@ impl App<'_> {
       }
     }
 
-    if self.tick_count.is_multiple_of(self.tick_until_poll) {
+    if self.should_poll() {
       self.refresh_radarr_metadata().await;
     }
   }
@@ -250,4 +344,13 @@ impl App<'_> {
   async fn extract_radarr_indexer_id(&self) -> i64 {
     self.data.radarr_data.indexers.current_selection().id
   }
+
+  async fn extract_radarr_download_client_id(&self) -> i64 {
+    self
+      .data
+      .radarr_data
+      .download_clients
+      .current_selection()
+      .id
+  }
 }
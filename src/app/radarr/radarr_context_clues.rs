@@ -1,20 +1,21 @@
 use crate::app::App;
 use crate::app::context_clues::{
   BARE_POPUP_CONTEXT_CLUES, CONFIRMATION_PROMPT_CONTEXT_CLUES, ContextClue, ContextClueProvider,
-  SYSTEM_TASKS_CONTEXT_CLUES,
+  EDIT_QUALITY_PROFILE_CONTEXT_CLUES, MANUAL_IMPORT_CONTEXT_CLUES, SCHEMA_FORM_CONTEXT_CLUES,
+  SETTINGS_FORM_CONTEXT_CLUES, SYSTEM_BACKUPS_CONTEXT_CLUES, SYSTEM_TASKS_CONTEXT_CLUES,
 };
 use crate::app::key_binding::DEFAULT_KEYBINDINGS;
 use crate::models::Route;
 use crate::models::servarr_data::radarr::radarr_data::{
-  ADD_MOVIE_BLOCKS, ActiveRadarrBlock, EDIT_COLLECTION_BLOCKS, EDIT_INDEXER_BLOCKS,
-  EDIT_MOVIE_BLOCKS, INDEXER_SETTINGS_BLOCKS, MOVIE_DETAILS_BLOCKS,
+  ADD_MOVIE_BLOCKS, ActiveRadarrBlock, BULK_EDIT_MOVIES_BLOCKS, EDIT_COLLECTION_BLOCKS,
+  EDIT_INDEXER_BLOCKS, EDIT_MOVIE_BLOCKS, INDEXER_SETTINGS_BLOCKS, MOVIE_DETAILS_BLOCKS,
 };
 
 #[cfg(test)]
 #[path = "radarr_context_clues_tests.rs"]
 mod radarr_context_clues_tests;
 
-pub static LIBRARY_CONTEXT_CLUES: [ContextClue; 11] = [
+pub static LIBRARY_CONTEXT_CLUES: [ContextClue; 12] = [
   (DEFAULT_KEYBINDINGS.add, DEFAULT_KEYBINDINGS.add.desc),
   (DEFAULT_KEYBINDINGS.edit, DEFAULT_KEYBINDINGS.edit.desc),
   (
@@ -25,6 +26,7 @@ pub static LIBRARY_CONTEXT_CLUES: [ContextClue; 11] = [
   (DEFAULT_KEYBINDINGS.delete, DEFAULT_KEYBINDINGS.delete.desc),
   (DEFAULT_KEYBINDINGS.search, DEFAULT_KEYBINDINGS.search.desc),
   (DEFAULT_KEYBINDINGS.filter, DEFAULT_KEYBINDINGS.filter.desc),
+  (DEFAULT_KEYBINDINGS.select, DEFAULT_KEYBINDINGS.select.desc),
   (
     DEFAULT_KEYBINDINGS.refresh,
     DEFAULT_KEYBINDINGS.refresh.desc,
@@ -102,7 +104,22 @@ impl ContextClueProvider for RadarrContextClueProvider {
         .radarr_data
         .movie_info_tabs
         .get_active_route_contextual_help(),
+      ActiveRadarrBlock::EditQualityProfile => Some(&EDIT_QUALITY_PROFILE_CONTEXT_CLUES),
+      ActiveRadarrBlock::AddIndexerPrompt
+      | ActiveRadarrBlock::DownloadClientPrompt
+      | ActiveRadarrBlock::EditCustomFormatPrompt => Some(&SCHEMA_FORM_CONTEXT_CLUES),
+      ActiveRadarrBlock::MediaManagementSettingsPrompt => Some(&SETTINGS_FORM_CONTEXT_CLUES),
+      ActiveRadarrBlock::AddIndexerSelectImplementation
+      | ActiveRadarrBlock::AddIndexerFieldInput
+      | ActiveRadarrBlock::AddDownloadClientSelectImplementation
+      | ActiveRadarrBlock::DownloadClientFieldInput
+      | ActiveRadarrBlock::CustomFormatFieldInput
+      | ActiveRadarrBlock::MediaManagementFieldInput
+      | ActiveRadarrBlock::ImportCustomFormatPrompt
+      | ActiveRadarrBlock::ExportCustomFormatPrompt => Some(&CONFIRMATION_PROMPT_CONTEXT_CLUES),
       ActiveRadarrBlock::TestAllIndexers
+      | ActiveRadarrBlock::TestAllDownloadClients
+      | ActiveRadarrBlock::CustomFormatDetails
       | ActiveRadarrBlock::AddMovieSearchInput
       | ActiveRadarrBlock::AddMovieEmptySearchResults
       | ActiveRadarrBlock::SystemLogs
@@ -112,11 +129,14 @@ impl ContextClueProvider for RadarrContextClueProvider {
       {
         Some(&BARE_POPUP_CONTEXT_CLUES)
       }
+      ActiveRadarrBlock::SystemBackups => Some(&SYSTEM_BACKUPS_CONTEXT_CLUES),
       ActiveRadarrBlock::SystemTasks => Some(&SYSTEM_TASKS_CONTEXT_CLUES),
+      ActiveRadarrBlock::ManualImport => Some(&MANUAL_IMPORT_CONTEXT_CLUES),
       _ if EDIT_COLLECTION_BLOCKS.contains(&active_radarr_block)
         || EDIT_INDEXER_BLOCKS.contains(&active_radarr_block)
         || INDEXER_SETTINGS_BLOCKS.contains(&active_radarr_block)
-        || EDIT_MOVIE_BLOCKS.contains(&active_radarr_block) =>
+        || EDIT_MOVIE_BLOCKS.contains(&active_radarr_block)
+        || BULK_EDIT_MOVIES_BLOCKS.contains(&active_radarr_block) =>
       {
         Some(&CONFIRMATION_PROMPT_CONTEXT_CLUES)
       }
@@ -126,6 +146,8 @@ impl ContextClueProvider for RadarrContextClueProvider {
       | ActiveRadarrBlock::AddMovieSelectQualityProfile
       | ActiveRadarrBlock::AddMovieSelectRootFolder
       | ActiveRadarrBlock::AddMovieTagsInput
+      | ActiveRadarrBlock::ManualImportConfirmPrompt
+      | ActiveRadarrBlock::ManualImportSelectMovie
       | ActiveRadarrBlock::SystemTaskStartConfirmPrompt => Some(&CONFIRMATION_PROMPT_CONTEXT_CLUES),
       _ if ADD_MOVIE_BLOCKS.contains(&active_radarr_block) => {
         Some(&ADD_MOVIE_SEARCH_RESULTS_CONTEXT_CLUES)
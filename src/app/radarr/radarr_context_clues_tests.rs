@@ -3,9 +3,11 @@ mod tests {
   use crate::app::App;
   use crate::app::context_clues::{
     BARE_POPUP_CONTEXT_CLUES, BLOCKLIST_CONTEXT_CLUES, CONFIRMATION_PROMPT_CONTEXT_CLUES,
-    ContextClue, ContextClueProvider, DOWNLOADS_CONTEXT_CLUES, HISTORY_CONTEXT_CLUES,
-    INDEXERS_CONTEXT_CLUES, ROOT_FOLDERS_CONTEXT_CLUES, SYSTEM_CONTEXT_CLUES,
-    SYSTEM_TASKS_CONTEXT_CLUES,
+    CUSTOM_FORMATS_CONTEXT_CLUES, ContextClue, ContextClueProvider, DOWNLOAD_CLIENTS_CONTEXT_CLUES,
+    DOWNLOADS_CONTEXT_CLUES, EDIT_QUALITY_PROFILE_CONTEXT_CLUES, HISTORY_CONTEXT_CLUES,
+    INDEXERS_CONTEXT_CLUES, MANUAL_IMPORT_CONTEXT_CLUES, QUALITY_PROFILES_CONTEXT_CLUES,
+    ROOT_FOLDERS_CONTEXT_CLUES, SCHEMA_FORM_CONTEXT_CLUES, SETTINGS_FORM_CONTEXT_CLUES,
+    SYSTEM_BACKUPS_CONTEXT_CLUES, SYSTEM_TASKS_CONTEXT_CLUES, SYSTEM_WITH_BACKUPS_CONTEXT_CLUES,
   };
   use crate::app::key_binding::DEFAULT_KEYBINDINGS;
   use crate::app::radarr::radarr_context_clues::{
@@ -53,6 +55,10 @@ mod tests {
       library_context_clues_iter.next(),
       &(DEFAULT_KEYBINDINGS.filter, DEFAULT_KEYBINDINGS.filter.desc)
     );
+    assert_some_eq_x!(
+      library_context_clues_iter.next(),
+      &(DEFAULT_KEYBINDINGS.select, DEFAULT_KEYBINDINGS.select.desc)
+    );
     assert_some_eq_x!(
       library_context_clues_iter.next(),
       &(
@@ -255,6 +261,8 @@ mod tests {
 
   #[rstest]
   #[case(ActiveRadarrBlock::TestAllIndexers, None)]
+  #[case(ActiveRadarrBlock::TestAllDownloadClients, None)]
+  #[case(ActiveRadarrBlock::CustomFormatDetails, None)]
   #[case(ActiveRadarrBlock::AddMovieSearchInput, None)]
   #[case(ActiveRadarrBlock::AddMovieEmptySearchResults, None)]
   #[case(ActiveRadarrBlock::SystemLogs, None)]
@@ -307,6 +315,8 @@ mod tests {
       ActiveRadarrBlock::AddMovieSelectQualityProfile,
       ActiveRadarrBlock::AddMovieSelectRootFolder,
       ActiveRadarrBlock::AddMovieTagsInput,
+      ActiveRadarrBlock::ManualImportConfirmPrompt,
+      ActiveRadarrBlock::ManualImportSelectMovie,
       ActiveRadarrBlock::SystemTaskStartConfirmPrompt
     )]
     active_radarr_block: ActiveRadarrBlock,
@@ -320,6 +330,17 @@ mod tests {
     assert_some_eq_x!(context_clues, &CONFIRMATION_PROMPT_CONTEXT_CLUES);
   }
 
+  #[test]
+  fn test_radarr_context_clue_provider_manual_import_context_clues() {
+    let mut app = App::test_default();
+    app.data.radarr_data = RadarrData::default();
+    app.push_navigation_stack(ActiveRadarrBlock::ManualImport.into());
+
+    let context_clues = RadarrContextClueProvider::get_context_clues(&mut app);
+
+    assert_some_eq_x!(context_clues, &MANUAL_IMPORT_CONTEXT_CLUES);
+  }
+
   #[rstest]
   fn test_radarr_context_clue_provider_confirmation_prompt_context_clues_edit_collection_blocks(
     #[values(
@@ -415,6 +436,29 @@ mod tests {
     assert_some_eq_x!(context_clues, &CONFIRMATION_PROMPT_CONTEXT_CLUES);
   }
 
+  #[rstest]
+  fn test_radarr_context_clue_provider_confirmation_prompt_context_clues_bulk_edit_movies_blocks(
+    #[values(
+      ActiveRadarrBlock::BulkEditMoviesPrompt,
+      ActiveRadarrBlock::BulkEditMoviesConfirmPrompt,
+      ActiveRadarrBlock::BulkEditMoviesSelectApplyTags,
+      ActiveRadarrBlock::BulkEditMoviesSelectMonitored,
+      ActiveRadarrBlock::BulkEditMoviesSelectQualityProfile,
+      ActiveRadarrBlock::BulkEditMoviesSelectRootFolder,
+      ActiveRadarrBlock::BulkEditMoviesTagsInput,
+      ActiveRadarrBlock::BulkEditMoviesToggleMoveFiles
+    )]
+    active_radarr_block: ActiveRadarrBlock,
+  ) {
+    let mut app = App::test_default();
+    app.data.radarr_data = RadarrData::default();
+    app.push_navigation_stack(active_radarr_block.into());
+
+    let context_clues = RadarrContextClueProvider::get_context_clues(&mut app);
+
+    assert_some_eq_x!(context_clues, &CONFIRMATION_PROMPT_CONTEXT_CLUES);
+  }
+
   #[rstest]
   fn test_radarr_context_clue_provider_add_movie_search_results_context_clues(
     #[values(
@@ -455,6 +499,18 @@ mod tests {
     assert_some_eq_x!(context_clues, &SYSTEM_TASKS_CONTEXT_CLUES);
   }
 
+  #[test]
+  fn test_radarr_context_clue_provider_system_backups_context_clues() {
+    let mut app = App::test_default();
+    app.data.radarr_data = RadarrData::default();
+
+    app.push_navigation_stack(ActiveRadarrBlock::SystemBackups.into());
+
+    let context_clues = RadarrContextClueProvider::get_context_clues(&mut app);
+
+    assert_some_eq_x!(context_clues, &SYSTEM_BACKUPS_CONTEXT_CLUES);
+  }
+
   #[rstest]
   #[case(0, ActiveRadarrBlock::Movies, &LIBRARY_CONTEXT_CLUES)]
   #[case(1, ActiveRadarrBlock::Collections, &COLLECTIONS_CONTEXT_CLUES)]
@@ -463,7 +519,10 @@ mod tests {
   #[case(4, ActiveRadarrBlock::History, &HISTORY_CONTEXT_CLUES)]
   #[case(5, ActiveRadarrBlock::RootFolders, &ROOT_FOLDERS_CONTEXT_CLUES)]
   #[case(6, ActiveRadarrBlock::Indexers, &INDEXERS_CONTEXT_CLUES)]
-  #[case(7, ActiveRadarrBlock::System, &SYSTEM_CONTEXT_CLUES)]
+  #[case(7, ActiveRadarrBlock::DownloadClients, &DOWNLOAD_CLIENTS_CONTEXT_CLUES)]
+  #[case(8, ActiveRadarrBlock::QualityProfiles, &QUALITY_PROFILES_CONTEXT_CLUES)]
+  #[case(9, ActiveRadarrBlock::CustomFormats, &CUSTOM_FORMATS_CONTEXT_CLUES)]
+  #[case(10, ActiveRadarrBlock::System, &SYSTEM_WITH_BACKUPS_CONTEXT_CLUES)]
   fn test_radarr_context_clue_provider_radarr_blocks_context_clues(
     #[case] index: usize,
     #[case] active_radarr_block: ActiveRadarrBlock,
@@ -478,4 +537,62 @@ mod tests {
 
     assert_some_eq_x!(context_clues, expected_context_clues);
   }
+  #[test]
+  fn test_radarr_context_clue_provider_edit_quality_profile_context_clues() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveRadarrBlock::EditQualityProfile.into());
+
+    let context_clues = RadarrContextClueProvider::get_context_clues(&mut app);
+
+    assert_some_eq_x!(context_clues, &EDIT_QUALITY_PROFILE_CONTEXT_CLUES);
+  }
+
+  #[test]
+  fn test_radarr_context_clue_provider_media_management_settings_context_clues() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveRadarrBlock::MediaManagementSettingsPrompt.into());
+
+    let context_clues = RadarrContextClueProvider::get_context_clues(&mut app);
+
+    assert_some_eq_x!(context_clues, &SETTINGS_FORM_CONTEXT_CLUES);
+  }
+
+  #[rstest]
+  fn test_radarr_context_clue_provider_schema_form_prompt_context_clues(
+    #[values(
+      ActiveRadarrBlock::AddIndexerPrompt,
+      ActiveRadarrBlock::DownloadClientPrompt,
+      ActiveRadarrBlock::EditCustomFormatPrompt
+    )]
+    active_radarr_block: ActiveRadarrBlock,
+  ) {
+    let mut app = App::test_default();
+    app.push_navigation_stack(active_radarr_block.into());
+
+    let context_clues = RadarrContextClueProvider::get_context_clues(&mut app);
+
+    assert_some_eq_x!(context_clues, &SCHEMA_FORM_CONTEXT_CLUES);
+  }
+
+  #[rstest]
+  fn test_radarr_context_clue_provider_confirmation_prompt_context_clues_schema_form_blocks(
+    #[values(
+      ActiveRadarrBlock::AddIndexerSelectImplementation,
+      ActiveRadarrBlock::AddIndexerFieldInput,
+      ActiveRadarrBlock::AddDownloadClientSelectImplementation,
+      ActiveRadarrBlock::DownloadClientFieldInput,
+      ActiveRadarrBlock::CustomFormatFieldInput,
+      ActiveRadarrBlock::MediaManagementFieldInput,
+      ActiveRadarrBlock::ImportCustomFormatPrompt,
+      ActiveRadarrBlock::ExportCustomFormatPrompt
+    )]
+    active_radarr_block: ActiveRadarrBlock,
+  ) {
+    let mut app = App::test_default();
+    app.push_navigation_stack(active_radarr_block.into());
+
+    let context_clues = RadarrContextClueProvider::get_context_clues(&mut app);
+
+    assert_some_eq_x!(context_clues, &CONFIRMATION_PROMPT_CONTEXT_CLUES);
+  }
 }
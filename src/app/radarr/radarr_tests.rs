@@ -1,5 +1,6 @@
 #[cfg(test)]
 mod tests {
+  use chrono::Local;
   use pretty_assertions::assert_eq;
   use tokio::sync::mpsc;
 
@@ -7,12 +8,18 @@ mod tests {
   use crate::app::radarr::ActiveRadarrBlock;
   use crate::models::radarr_models::{
     AddMovieBody, AddMovieOptions, Collection, CollectionMovie, Credit, MinimumAvailability, Movie,
-    MovieMonitor, RadarrRelease,
+    MovieMonitor, NamingConfig, RadarrRelease,
   };
-  use crate::models::servarr_data::radarr::modals::MovieDetailsModal;
-  use crate::models::servarr_models::Indexer;
-  use crate::network::NetworkEvent;
+  use crate::models::servarr_data::modals::MediaManagementModal;
+  use crate::models::servarr_data::radarr::modals::{ManualImportModal, MovieDetailsModal};
+  use crate::models::servarr_data::radarr::radarr_data::MEDIA_MANAGEMENT_NAMING_FIELDS;
+  use crate::models::servarr_models::{DownloadClient, Indexer};
+  use crate::network::QueuedNetworkEvent;
   use crate::network::radarr_network::RadarrEvent;
+  use crate::network::radarr_network::radarr_network_test_utils::test_utils::{
+    media_management_settings, naming_config,
+  };
+  use crate::utils::calendar_range;
 
   #[tokio::test]
   async fn test_dispatch_by_blocklist_block() {
@@ -24,13 +31,76 @@ mod tests {
 
     assert!(app.is_loading);
     assert_eq!(
-      sync_network_rx.recv().await.unwrap(),
+      sync_network_rx.recv().await.unwrap().network_event,
       RadarrEvent::GetBlocklist.into()
     );
     assert!(!app.data.radarr_data.prompt_confirm);
     assert_eq!(app.tick_count, 0);
   }
 
+  #[tokio::test]
+  async fn test_dispatch_by_calendar_blocks() {
+    for active_radarr_block in [
+      ActiveRadarrBlock::Calendar,
+      ActiveRadarrBlock::CalendarAgenda,
+    ] {
+      let (mut app, mut sync_network_rx) = construct_app_unit();
+      let (start, end) = calendar_range(Local::now().date_naive());
+
+      app.dispatch_by_radarr_block(&active_radarr_block).await;
+
+      assert!(app.is_loading);
+      assert_eq!(
+        sync_network_rx.recv().await.unwrap().network_event,
+        RadarrEvent::GetCalendar(start, end).into()
+      );
+      assert!(!app.data.radarr_data.prompt_confirm);
+      assert_eq!(app.tick_count, 0);
+    }
+  }
+
+  #[tokio::test]
+  async fn test_dispatch_by_wanted_missing_block() {
+    let (mut app, mut sync_network_rx) = construct_app_unit();
+
+    app
+      .dispatch_by_radarr_block(&ActiveRadarrBlock::WantedMissing)
+      .await;
+
+    assert!(app.is_loading);
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap().network_event,
+      RadarrEvent::GetQualityProfiles.into()
+    );
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap().network_event,
+      RadarrEvent::GetWantedMissing(500).into()
+    );
+    assert!(!app.data.radarr_data.prompt_confirm);
+    assert_eq!(app.tick_count, 0);
+  }
+
+  #[tokio::test]
+  async fn test_dispatch_by_wanted_cutoff_unmet_block() {
+    let (mut app, mut sync_network_rx) = construct_app_unit();
+
+    app
+      .dispatch_by_radarr_block(&ActiveRadarrBlock::WantedCutoffUnmet)
+      .await;
+
+    assert!(app.is_loading);
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap().network_event,
+      RadarrEvent::GetQualityProfiles.into()
+    );
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap().network_event,
+      RadarrEvent::GetCutoffUnmet(500).into()
+    );
+    assert!(!app.data.radarr_data.prompt_confirm);
+    assert_eq!(app.tick_count, 0);
+  }
+
   #[tokio::test]
   async fn test_dispatch_by_collections_block() {
     let (mut app, mut sync_network_rx) = construct_app_unit();
@@ -41,15 +111,15 @@ mod tests {
 
     assert!(app.is_loading);
     assert_eq!(
-      sync_network_rx.recv().await.unwrap(),
+      sync_network_rx.recv().await.unwrap().network_event,
       RadarrEvent::GetQualityProfiles.into()
     );
     assert_eq!(
-      sync_network_rx.recv().await.unwrap(),
+      sync_network_rx.recv().await.unwrap().network_event,
       RadarrEvent::GetCollections.into()
     );
     assert_eq!(
-      sync_network_rx.recv().await.unwrap(),
+      sync_network_rx.recv().await.unwrap().network_event,
       RadarrEvent::GetMovies.into()
     );
     assert!(!app.data.radarr_data.prompt_confirm);
@@ -71,11 +141,11 @@ mod tests {
 
     assert!(!app.is_loading);
     assert_eq!(
-      sync_network_rx.recv().await.unwrap(),
+      sync_network_rx.recv().await.unwrap().network_event,
       RadarrEvent::GetQualityProfiles.into()
     );
     assert_eq!(
-      sync_network_rx.recv().await.unwrap(),
+      sync_network_rx.recv().await.unwrap().network_event,
       RadarrEvent::GetTags.into()
     );
     assert!(!app.data.radarr_data.collection_movies.items.is_empty());
@@ -114,15 +184,15 @@ mod tests {
 
     assert!(app.is_loading);
     assert_eq!(
-      sync_network_rx.recv().await.unwrap(),
+      sync_network_rx.recv().await.unwrap().network_event,
       RadarrEvent::GetQualityProfiles.into()
     );
     assert_eq!(
-      sync_network_rx.recv().await.unwrap(),
+      sync_network_rx.recv().await.unwrap().network_event,
       RadarrEvent::GetTags.into()
     );
     assert_eq!(
-      sync_network_rx.recv().await.unwrap(),
+      sync_network_rx.recv().await.unwrap().network_event,
       RadarrEvent::AddMovie(add_movie_body).into()
     );
     assert!(!app.data.radarr_data.collection_movies.items.is_empty());
@@ -140,13 +210,65 @@ mod tests {
 
     assert!(app.is_loading);
     assert_eq!(
-      sync_network_rx.recv().await.unwrap(),
+      sync_network_rx.recv().await.unwrap().network_event,
       RadarrEvent::GetDownloads(500).into()
     );
     assert!(!app.data.radarr_data.prompt_confirm);
     assert_eq!(app.tick_count, 0);
   }
 
+  #[tokio::test]
+  async fn test_dispatch_by_manual_import_block() {
+    let (mut app, mut sync_network_rx) = construct_app_unit();
+    app.data.radarr_data.manual_import_modal = Some(ManualImportModal {
+      download_id: "abc123".to_owned(),
+      movie_id: Some(1),
+      ..ManualImportModal::default()
+    });
+
+    app
+      .dispatch_by_radarr_block(&ActiveRadarrBlock::ManualImport)
+      .await;
+
+    assert!(app.is_loading);
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap().network_event,
+      RadarrEvent::GetMovies.into()
+    );
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap().network_event,
+      RadarrEvent::GetManualImport("abc123".to_owned(), Some(1)).into()
+    );
+    assert!(!app.data.radarr_data.prompt_confirm);
+    assert_eq!(app.tick_count, 0);
+  }
+
+  #[tokio::test]
+  async fn test_dispatch_by_manual_import_block_movies_non_empty() {
+    let (mut app, mut sync_network_rx) = construct_app_unit();
+    app
+      .data
+      .radarr_data
+      .movies
+      .set_items(vec![Movie::default()]);
+    app.data.radarr_data.manual_import_modal = Some(ManualImportModal {
+      download_id: "abc123".to_owned(),
+      ..ManualImportModal::default()
+    });
+
+    app
+      .dispatch_by_radarr_block(&ActiveRadarrBlock::ManualImport)
+      .await;
+
+    assert!(app.is_loading);
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap().network_event,
+      RadarrEvent::GetManualImport("abc123".to_owned(), None).into()
+    );
+    assert!(!app.data.radarr_data.prompt_confirm);
+    assert_eq!(app.tick_count, 0);
+  }
+
   #[tokio::test]
   async fn test_dispatch_by_history_block() {
     let (mut app, mut sync_network_rx) = construct_app_unit();
@@ -157,7 +279,7 @@ mod tests {
 
     assert!(app.is_loading);
     assert_eq!(
-      sync_network_rx.recv().await.unwrap(),
+      sync_network_rx.recv().await.unwrap().network_event,
       RadarrEvent::GetHistory(500).into()
     );
     assert!(!app.data.radarr_data.prompt_confirm);
@@ -174,13 +296,58 @@ mod tests {
 
     assert!(app.is_loading);
     assert_eq!(
-      sync_network_rx.recv().await.unwrap(),
+      sync_network_rx.recv().await.unwrap().network_event,
       RadarrEvent::GetRootFolders.into()
     );
     assert!(!app.data.radarr_data.prompt_confirm);
     assert_eq!(app.tick_count, 0);
   }
 
+  #[tokio::test]
+  async fn test_dispatch_by_media_management_settings_prompt_block() {
+    let (mut app, mut sync_network_rx) = construct_app_unit();
+
+    app
+      .dispatch_by_radarr_block(&ActiveRadarrBlock::MediaManagementSettingsPrompt)
+      .await;
+
+    assert!(app.is_loading);
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap().network_event,
+      RadarrEvent::GetMediaManagementSettings.into()
+    );
+    assert!(!app.data.radarr_data.prompt_confirm);
+    assert_eq!(app.tick_count, 0);
+  }
+
+  #[tokio::test]
+  async fn test_dispatch_by_media_management_settings_prompt_block_fetches_naming_examples_for_edits()
+   {
+    let (mut app, mut sync_network_rx) = construct_app_unit();
+    let mut media_management_modal = MediaManagementModal::new(
+      &media_management_settings(),
+      &MEDIA_MANAGEMENT_NAMING_FIELDS,
+    );
+    media_management_modal.toggle_current_field();
+    app.data.radarr_data.media_management_modal = Some(media_management_modal);
+    let expected_naming_config = NamingConfig {
+      rename_movies: false,
+      ..naming_config()
+    };
+
+    app
+      .dispatch_by_radarr_block(&ActiveRadarrBlock::MediaManagementSettingsPrompt)
+      .await;
+
+    assert!(app.is_loading);
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap().network_event,
+      RadarrEvent::GetNamingExamples(expected_naming_config).into()
+    );
+    assert!(!app.data.radarr_data.prompt_confirm);
+    assert_eq!(app.tick_count, 0);
+  }
+
   #[tokio::test]
   async fn test_dispatch_by_movies_block() {
     let (mut app, mut sync_network_rx) = construct_app_unit();
@@ -191,19 +358,19 @@ mod tests {
 
     assert!(app.is_loading);
     assert_eq!(
-      sync_network_rx.recv().await.unwrap(),
+      sync_network_rx.recv().await.unwrap().network_event,
       RadarrEvent::GetQualityProfiles.into()
     );
     assert_eq!(
-      sync_network_rx.recv().await.unwrap(),
+      sync_network_rx.recv().await.unwrap().network_event,
       RadarrEvent::GetTags.into()
     );
     assert_eq!(
-      sync_network_rx.recv().await.unwrap(),
+      sync_network_rx.recv().await.unwrap().network_event,
       RadarrEvent::GetMovies.into()
     );
     assert_eq!(
-      sync_network_rx.recv().await.unwrap(),
+      sync_network_rx.recv().await.unwrap().network_event,
       RadarrEvent::GetDownloads(500).into()
     );
     assert!(!app.data.radarr_data.prompt_confirm);
@@ -220,17 +387,33 @@ mod tests {
 
     assert!(app.is_loading);
     assert_eq!(
-      sync_network_rx.recv().await.unwrap(),
+      sync_network_rx.recv().await.unwrap().network_event,
       RadarrEvent::GetTags.into()
     );
     assert_eq!(
-      sync_network_rx.recv().await.unwrap(),
+      sync_network_rx.recv().await.unwrap().network_event,
       RadarrEvent::GetIndexers.into()
     );
     assert!(!app.data.radarr_data.prompt_confirm);
     assert_eq!(app.tick_count, 0);
   }
 
+  #[tokio::test]
+  async fn test_dispatch_by_add_indexer_select_implementation_block() {
+    let (mut app, mut sync_network_rx) = construct_app_unit();
+
+    app
+      .dispatch_by_radarr_block(&ActiveRadarrBlock::AddIndexerSelectImplementation)
+      .await;
+
+    assert!(app.is_loading);
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap().network_event,
+      RadarrEvent::GetIndexerSchema.into()
+    );
+    assert_eq!(app.tick_count, 0);
+  }
+
   #[tokio::test]
   async fn test_dispatch_by_all_indexer_settings_block() {
     let (mut app, mut sync_network_rx) = construct_app_unit();
@@ -241,7 +424,7 @@ mod tests {
 
     assert!(app.is_loading);
     assert_eq!(
-      sync_network_rx.recv().await.unwrap(),
+      sync_network_rx.recv().await.unwrap().network_event,
       RadarrEvent::GetAllIndexerSettings.into()
     );
     assert!(!app.data.radarr_data.prompt_confirm);
@@ -262,7 +445,7 @@ mod tests {
 
     assert!(app.is_loading);
     assert_eq!(
-      sync_network_rx.recv().await.unwrap(),
+      sync_network_rx.recv().await.unwrap().network_event,
       RadarrEvent::TestIndexer(1).into()
     );
     assert_eq!(app.tick_count, 0);
@@ -278,12 +461,123 @@ mod tests {
 
     assert!(app.is_loading);
     assert_eq!(
-      sync_network_rx.recv().await.unwrap(),
+      sync_network_rx.recv().await.unwrap().network_event,
       RadarrEvent::TestAllIndexers.into()
     );
     assert_eq!(app.tick_count, 0);
   }
 
+  #[tokio::test]
+  async fn test_dispatch_by_quality_profiles_block() {
+    let (mut app, mut sync_network_rx) = construct_app_unit();
+
+    app
+      .dispatch_by_radarr_block(&ActiveRadarrBlock::QualityProfiles)
+      .await;
+
+    assert!(app.is_loading);
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap().network_event,
+      RadarrEvent::GetQualityProfiles.into()
+    );
+    assert!(!app.data.radarr_data.prompt_confirm);
+    assert_eq!(app.tick_count, 0);
+  }
+
+  #[tokio::test]
+  async fn test_dispatch_by_custom_formats_block() {
+    let (mut app, mut sync_network_rx) = construct_app_unit();
+
+    app
+      .dispatch_by_radarr_block(&ActiveRadarrBlock::CustomFormats)
+      .await;
+
+    assert!(app.is_loading);
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap().network_event,
+      RadarrEvent::GetCustomFormats.into()
+    );
+    assert!(!app.data.radarr_data.prompt_confirm);
+    assert_eq!(app.tick_count, 0);
+  }
+
+  #[tokio::test]
+  async fn test_dispatch_by_download_clients_block() {
+    let (mut app, mut sync_network_rx) = construct_app_unit();
+
+    app
+      .dispatch_by_radarr_block(&ActiveRadarrBlock::DownloadClients)
+      .await;
+
+    assert!(app.is_loading);
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap().network_event,
+      RadarrEvent::GetTags.into()
+    );
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap().network_event,
+      RadarrEvent::GetDownloadClients.into()
+    );
+    assert!(!app.data.radarr_data.prompt_confirm);
+    assert_eq!(app.tick_count, 0);
+  }
+
+  #[tokio::test]
+  async fn test_dispatch_by_add_download_client_select_implementation_block() {
+    let (mut app, mut sync_network_rx) = construct_app_unit();
+
+    app
+      .dispatch_by_radarr_block(&ActiveRadarrBlock::AddDownloadClientSelectImplementation)
+      .await;
+
+    assert!(app.is_loading);
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap().network_event,
+      RadarrEvent::GetDownloadClientSchema.into()
+    );
+    assert_eq!(app.tick_count, 0);
+  }
+
+  #[tokio::test]
+  async fn test_dispatch_by_test_download_client_block() {
+    let (mut app, mut sync_network_rx) = construct_app_unit();
+    app
+      .data
+      .radarr_data
+      .download_clients
+      .set_items(vec![DownloadClient {
+        id: 1,
+        ..DownloadClient::default()
+      }]);
+
+    app
+      .dispatch_by_radarr_block(&ActiveRadarrBlock::TestDownloadClient)
+      .await;
+
+    assert!(app.is_loading);
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap().network_event,
+      RadarrEvent::TestDownloadClient(1).into()
+    );
+    assert_eq!(app.tick_count, 0);
+  }
+
+  #[tokio::test]
+  async fn test_dispatch_by_test_all_download_clients_block() {
+    let (mut app, mut sync_network_rx) = construct_app_unit();
+
+    app
+      .dispatch_by_radarr_block(&ActiveRadarrBlock::TestAllDownloadClients)
+      .await;
+
+    assert!(app.is_loading);
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap().network_event,
+      RadarrEvent::TestAllDownloadClients.into()
+    );
+    assert_eq!(app.tick_count, 0);
+  }
+
   #[tokio::test]
   async fn test_dispatch_by_system_block() {
     let (mut app, mut sync_network_rx) = construct_app_unit();
@@ -294,21 +588,38 @@ mod tests {
 
     assert!(app.is_loading);
     assert_eq!(
-      sync_network_rx.recv().await.unwrap(),
+      sync_network_rx.recv().await.unwrap().network_event,
       RadarrEvent::GetTasks.into()
     );
     assert_eq!(
-      sync_network_rx.recv().await.unwrap(),
+      sync_network_rx.recv().await.unwrap().network_event,
       RadarrEvent::GetQueuedEvents.into()
     );
     assert_eq!(
-      sync_network_rx.recv().await.unwrap(),
+      sync_network_rx.recv().await.unwrap().network_event,
       RadarrEvent::GetLogs(500).into()
     );
     assert!(!app.data.radarr_data.prompt_confirm);
     assert_eq!(app.tick_count, 0);
   }
 
+  #[tokio::test]
+  async fn test_dispatch_by_system_backups_block() {
+    let (mut app, mut sync_network_rx) = construct_app_unit();
+
+    app
+      .dispatch_by_radarr_block(&ActiveRadarrBlock::SystemBackups)
+      .await;
+
+    assert!(app.is_loading);
+    assert_eq!(
+      sync_network_rx.recv().await.unwrap().network_event,
+      RadarrEvent::GetBackups.into()
+    );
+    assert!(!app.data.radarr_data.prompt_confirm);
+    assert_eq!(app.tick_count, 0);
+  }
+
   #[tokio::test]
   async fn test_dispatch_by_system_updates_block() {
     let (mut app, mut sync_network_rx) = construct_app_unit();
@@ -319,7 +630,7 @@ mod tests {
 
     assert!(app.is_loading);
     assert_eq!(
-      sync_network_rx.recv().await.unwrap(),
+      sync_network_rx.recv().await.unwrap().network_event,
       RadarrEvent::GetUpdates.into()
     );
     assert!(!app.data.radarr_data.prompt_confirm);
@@ -337,7 +648,7 @@ mod tests {
 
     assert!(app.is_loading);
     assert_eq!(
-      sync_network_rx.recv().await.unwrap(),
+      sync_network_rx.recv().await.unwrap().network_event,
       RadarrEvent::SearchNewMovie("test".into()).into()
     );
     assert!(!app.data.radarr_data.prompt_confirm);
@@ -358,7 +669,7 @@ mod tests {
 
     assert!(app.is_loading);
     assert_eq!(
-      sync_network_rx.recv().await.unwrap(),
+      sync_network_rx.recv().await.unwrap().network_event,
       RadarrEvent::GetMovieDetails(1).into()
     );
     assert!(!app.data.radarr_data.prompt_confirm);
@@ -379,7 +690,7 @@ mod tests {
 
     assert!(app.is_loading);
     assert_eq!(
-      sync_network_rx.recv().await.unwrap(),
+      sync_network_rx.recv().await.unwrap().network_event,
       RadarrEvent::GetMovieDetails(1).into()
     );
     assert!(!app.data.radarr_data.prompt_confirm);
@@ -400,7 +711,7 @@ mod tests {
 
     assert!(app.is_loading);
     assert_eq!(
-      sync_network_rx.recv().await.unwrap(),
+      sync_network_rx.recv().await.unwrap().network_event,
       RadarrEvent::GetMovieHistory(1).into()
     );
     assert!(!app.data.radarr_data.prompt_confirm);
@@ -421,7 +732,7 @@ mod tests {
 
       assert!(app.is_loading);
       assert_eq!(
-        sync_network_rx.recv().await.unwrap(),
+        sync_network_rx.recv().await.unwrap().network_event,
         RadarrEvent::GetMovieCredits(1).into()
       );
       assert!(!app.data.radarr_data.prompt_confirm);
@@ -448,7 +759,7 @@ mod tests {
 
       assert!(app.is_loading);
       assert_eq!(
-        sync_network_rx.recv().await.unwrap(),
+        sync_network_rx.recv().await.unwrap().network_event,
         RadarrEvent::GetMovieCredits(1).into()
       );
       assert!(!app.data.radarr_data.prompt_confirm);
@@ -475,7 +786,7 @@ mod tests {
 
       assert!(app.is_loading);
       assert_eq!(
-        sync_network_rx.recv().await.unwrap(),
+        sync_network_rx.recv().await.unwrap().network_event,
         RadarrEvent::GetMovieCredits(1).into()
       );
       assert!(!app.data.radarr_data.prompt_confirm);
@@ -520,7 +831,7 @@ mod tests {
 
     assert!(app.is_loading);
     assert_eq!(
-      sync_network_rx.recv().await.unwrap(),
+      sync_network_rx.recv().await.unwrap().network_event,
       RadarrEvent::GetReleases(1).into()
     );
     assert!(!app.data.radarr_data.prompt_confirm);
@@ -581,7 +892,7 @@ mod tests {
 
     assert!(!app.data.radarr_data.prompt_confirm);
     assert_eq!(
-      sync_network_rx.recv().await.unwrap(),
+      sync_network_rx.recv().await.unwrap().network_event,
       RadarrEvent::GetStatus.into()
     );
     assert!(app.should_refresh);
@@ -596,27 +907,27 @@ mod tests {
     app.refresh_radarr_metadata().await;
 
     assert_eq!(
-      sync_network_rx.recv().await.unwrap(),
+      sync_network_rx.recv().await.unwrap().network_event,
       RadarrEvent::GetQualityProfiles.into()
     );
     assert_eq!(
-      sync_network_rx.recv().await.unwrap(),
+      sync_network_rx.recv().await.unwrap().network_event,
       RadarrEvent::GetTags.into()
     );
     assert_eq!(
-      sync_network_rx.recv().await.unwrap(),
+      sync_network_rx.recv().await.unwrap().network_event,
       RadarrEvent::GetRootFolders.into()
     );
     assert_eq!(
-      sync_network_rx.recv().await.unwrap(),
+      sync_network_rx.recv().await.unwrap().network_event,
       RadarrEvent::GetDownloads(500).into()
     );
     assert_eq!(
-      sync_network_rx.recv().await.unwrap(),
+      sync_network_rx.recv().await.unwrap().network_event,
       RadarrEvent::GetDiskSpace.into()
     );
     assert_eq!(
-      sync_network_rx.recv().await.unwrap(),
+      sync_network_rx.recv().await.unwrap().network_event,
       RadarrEvent::GetStatus.into()
     );
     assert!(app.is_loading);
@@ -630,27 +941,27 @@ mod tests {
     app.radarr_on_tick(ActiveRadarrBlock::Downloads).await;
 
     assert_eq!(
-      sync_network_rx.recv().await.unwrap(),
+      sync_network_rx.recv().await.unwrap().network_event,
       RadarrEvent::GetQualityProfiles.into()
     );
     assert_eq!(
-      sync_network_rx.recv().await.unwrap(),
+      sync_network_rx.recv().await.unwrap().network_event,
       RadarrEvent::GetTags.into()
     );
     assert_eq!(
-      sync_network_rx.recv().await.unwrap(),
+      sync_network_rx.recv().await.unwrap().network_event,
       RadarrEvent::GetRootFolders.into()
     );
     assert_eq!(
-      sync_network_rx.recv().await.unwrap(),
+      sync_network_rx.recv().await.unwrap().network_event,
       RadarrEvent::GetDownloads(500).into()
     );
     assert_eq!(
-      sync_network_rx.recv().await.unwrap(),
+      sync_network_rx.recv().await.unwrap().network_event,
       RadarrEvent::GetDiskSpace.into()
     );
     assert_eq!(
-      sync_network_rx.recv().await.unwrap(),
+      sync_network_rx.recv().await.unwrap().network_event,
       RadarrEvent::GetStatus.into()
     );
     assert!(app.is_loading);
@@ -667,7 +978,7 @@ mod tests {
     app.radarr_on_tick(ActiveRadarrBlock::Downloads).await;
 
     assert_eq!(
-      sync_network_rx.recv().await.unwrap(),
+      sync_network_rx.recv().await.unwrap().network_event,
       RadarrEvent::GetDownloads(500).into()
     );
     assert!(!app.data.radarr_data.prompt_confirm);
@@ -692,7 +1003,7 @@ mod tests {
     app.radarr_on_tick(ActiveRadarrBlock::Downloads).await;
 
     assert_eq!(
-      sync_network_rx.recv().await.unwrap(),
+      sync_network_rx.recv().await.unwrap().network_event,
       RadarrEvent::GetDownloads(500).into()
     );
     assert!(app.should_refresh);
@@ -709,7 +1020,7 @@ mod tests {
     app.radarr_on_tick(ActiveRadarrBlock::Downloads).await;
 
     assert_eq!(
-      sync_network_rx.recv().await.unwrap(),
+      sync_network_rx.recv().await.unwrap().network_event,
       RadarrEvent::GetDownloads(500).into()
     );
     assert!(app.is_loading);
@@ -727,19 +1038,19 @@ mod tests {
     app.radarr_on_tick(ActiveRadarrBlock::Downloads).await;
 
     assert_eq!(
-      sync_network_rx.recv().await.unwrap(),
+      sync_network_rx.recv().await.unwrap().network_event,
       RadarrEvent::GetQualityProfiles.into()
     );
     assert_eq!(
-      sync_network_rx.recv().await.unwrap(),
+      sync_network_rx.recv().await.unwrap().network_event,
       RadarrEvent::GetTags.into()
     );
     assert_eq!(
-      sync_network_rx.recv().await.unwrap(),
+      sync_network_rx.recv().await.unwrap().network_event,
       RadarrEvent::GetRootFolders.into()
     );
     assert_eq!(
-      sync_network_rx.recv().await.unwrap(),
+      sync_network_rx.recv().await.unwrap().network_event,
       RadarrEvent::GetDownloads(500).into()
     );
     assert!(app.is_loading);
@@ -797,8 +1108,23 @@ mod tests {
     assert_eq!(app.extract_radarr_indexer_id().await, 1);
   }
 
-  fn construct_app_unit<'a>() -> (App<'a>, mpsc::Receiver<NetworkEvent>) {
-    let (sync_network_tx, sync_network_rx) = mpsc::channel::<NetworkEvent>(500);
+  #[tokio::test]
+  async fn test_extract_radarr_download_client_id() {
+    let mut app = App::test_default();
+    app
+      .data
+      .radarr_data
+      .download_clients
+      .set_items(vec![DownloadClient {
+        id: 1,
+        ..DownloadClient::default()
+      }]);
+
+    assert_eq!(app.extract_radarr_download_client_id().await, 1);
+  }
+
+  fn construct_app_unit<'a>() -> (App<'a>, mpsc::Receiver<QueuedNetworkEvent>) {
+    let (sync_network_tx, sync_network_rx) = mpsc::channel::<QueuedNetworkEvent>(500);
     let mut app = App {
       network_tx: Some(sync_network_tx),
       tick_count: 1,
@@ -0,0 +1,148 @@
+use super::App;
+use crate::{
+  models::servarr_data::readarr::readarr_data::ActiveReadarrBlock,
+  network::readarr_network::ReadarrEvent,
+};
+
+pub mod readarr_context_clues;
+
+#[cfg(test)]
+#[path = "readarr_tests.rs"]
+mod readarr_tests;
+
+impl App<'_> {
+  pub(super) async fn dispatch_by_readarr_block(
+    &mut self,
+    active_readarr_block: &ActiveReadarrBlock,
+  ) {
+    match active_readarr_block {
+      ActiveReadarrBlock::Authors => {
+        self
+          .dispatch_network_event(ReadarrEvent::GetQualityProfiles.into())
+          .await;
+        self
+          .dispatch_network_event(ReadarrEvent::GetMetadataProfiles.into())
+          .await;
+        self
+          .dispatch_network_event(ReadarrEvent::GetTags.into())
+          .await;
+        self
+          .dispatch_network_event(ReadarrEvent::ListAuthors.into())
+          .await;
+      }
+      ActiveReadarrBlock::AuthorDetails => {
+        self
+          .dispatch_network_event(ReadarrEvent::GetBooks(self.extract_author_id().await).into())
+          .await;
+      }
+      ActiveReadarrBlock::BookDetails => {
+        self
+          .dispatch_network_event(ReadarrEvent::GetEditions(self.extract_book_id().await).into())
+          .await;
+      }
+      ActiveReadarrBlock::AddAuthorSearchResults => {
+        self
+          .dispatch_network_event(
+            ReadarrEvent::SearchNewAuthor(self.extract_add_new_author_search_query().await).into(),
+          )
+          .await;
+      }
+      ActiveReadarrBlock::System => {
+        self
+          .dispatch_network_event(ReadarrEvent::GetTasks.into())
+          .await;
+        self
+          .dispatch_network_event(ReadarrEvent::GetQueuedEvents.into())
+          .await;
+        self
+          .dispatch_network_event(ReadarrEvent::GetLogs(500).into())
+          .await;
+      }
+      ActiveReadarrBlock::SystemUpdates => {
+        self
+          .dispatch_network_event(ReadarrEvent::GetUpdates.into())
+          .await;
+      }
+      _ => (),
+    }
+
+    self.check_for_readarr_prompt_action().await;
+    self.reset_tick_count();
+  }
+
+  async fn extract_add_new_author_search_query(&self) -> String {
+    self
+      .data
+      .readarr_data
+      .add_author_search
+      .as_ref()
+      .expect("Add author search is empty")
+      .text
+      .clone()
+  }
+
+  async fn extract_author_id(&self) -> i64 {
+    self.data.readarr_data.authors.current_selection().id
+  }
+
+  async fn extract_book_id(&self) -> i64 {
+    self.data.readarr_data.books.current_selection().id
+  }
+
+  async fn check_for_readarr_prompt_action(&mut self) {
+    if self.data.readarr_data.prompt_confirm {
+      self.data.readarr_data.prompt_confirm = false;
+      if let Some(readarr_event) = self.data.readarr_data.prompt_confirm_action.take() {
+        self.dispatch_network_event(readarr_event.into()).await;
+        self.should_refresh = true;
+      }
+    }
+  }
+
+  pub(super) async fn readarr_on_tick(&mut self, active_readarr_block: ActiveReadarrBlock) {
+    if self.is_first_render {
+      self.refresh_readarr_metadata().await;
+      self.dispatch_by_readarr_block(&active_readarr_block).await;
+      self.is_first_render = false;
+      return;
+    }
+
+    if self.should_refresh {
+      self.dispatch_by_readarr_block(&active_readarr_block).await;
+      self.refresh_readarr_metadata().await;
+    }
+
+    if self.is_routing {
+      if !self.should_refresh {
+        self.cancellation_token.cancel();
+      } else {
+        self.dispatch_by_readarr_block(&active_readarr_block).await;
+      }
+    }
+
+    if self.should_poll() {
+      self.refresh_readarr_metadata().await;
+    }
+  }
+
+  async fn refresh_readarr_metadata(&mut self) {
+    self
+      .dispatch_network_event(ReadarrEvent::GetQualityProfiles.into())
+      .await;
+    self
+      .dispatch_network_event(ReadarrEvent::GetMetadataProfiles.into())
+      .await;
+    self
+      .dispatch_network_event(ReadarrEvent::GetTags.into())
+      .await;
+    self
+      .dispatch_network_event(ReadarrEvent::GetRootFolders.into())
+      .await;
+    self
+      .dispatch_network_event(ReadarrEvent::GetDiskSpace.into())
+      .await;
+    self
+      .dispatch_network_event(ReadarrEvent::GetStatus.into())
+      .await;
+  }
+}
@@ -0,0 +1,382 @@
+#[cfg(test)]
+mod tests {
+  use crate::app::App;
+  use crate::models::servarr_data::readarr::readarr_data::ActiveReadarrBlock;
+  use crate::network::QueuedNetworkEvent;
+  use crate::network::readarr_network::ReadarrEvent;
+  use crate::network::readarr_network::readarr_network_test_utils::test_utils::{author, book};
+  use pretty_assertions::{assert_eq, assert_str_eq};
+  use tokio::sync::mpsc;
+
+  #[tokio::test]
+  async fn test_dispatch_by_readarr_block_authors() {
+    let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
+    let mut app = App::test_default();
+    app.data.readarr_data.prompt_confirm = true;
+    app.network_tx = Some(tx);
+
+    app
+      .dispatch_by_readarr_block(&ActiveReadarrBlock::Authors)
+      .await;
+
+    assert!(app.is_loading);
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      ReadarrEvent::GetQualityProfiles.into()
+    );
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      ReadarrEvent::GetMetadataProfiles.into()
+    );
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      ReadarrEvent::GetTags.into()
+    );
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      ReadarrEvent::ListAuthors.into()
+    );
+    assert!(!app.data.readarr_data.prompt_confirm);
+    assert_eq!(app.tick_count, 0);
+  }
+
+  #[tokio::test]
+  async fn test_dispatch_by_readarr_block_author_details() {
+    let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
+    let mut app = App::test_default();
+    app.data.readarr_data.prompt_confirm = true;
+    app.data.readarr_data.authors.set_items(vec![author()]);
+    app.network_tx = Some(tx);
+
+    app
+      .dispatch_by_readarr_block(&ActiveReadarrBlock::AuthorDetails)
+      .await;
+
+    assert!(app.is_loading);
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      ReadarrEvent::GetBooks(1).into()
+    );
+    assert!(!app.data.readarr_data.prompt_confirm);
+    assert_eq!(app.tick_count, 0);
+  }
+
+  #[tokio::test]
+  async fn test_dispatch_by_readarr_block_book_details() {
+    let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
+    let mut app = App::test_default();
+    app.data.readarr_data.prompt_confirm = true;
+    app.data.readarr_data.books.set_items(vec![book()]);
+    app.network_tx = Some(tx);
+
+    app
+      .dispatch_by_readarr_block(&ActiveReadarrBlock::BookDetails)
+      .await;
+
+    assert!(app.is_loading);
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      ReadarrEvent::GetEditions(1).into()
+    );
+    assert!(!app.data.readarr_data.prompt_confirm);
+    assert_eq!(app.tick_count, 0);
+  }
+
+  #[tokio::test]
+  async fn test_dispatch_by_readarr_block_add_author_search_results() {
+    let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
+    let mut app = App::test_default();
+    app.data.readarr_data.prompt_confirm = true;
+    app.data.readarr_data.add_author_search = Some("test search".into());
+    app.network_tx = Some(tx);
+
+    app
+      .dispatch_by_readarr_block(&ActiveReadarrBlock::AddAuthorSearchResults)
+      .await;
+
+    assert!(app.is_loading);
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      ReadarrEvent::SearchNewAuthor("test search".to_owned()).into()
+    );
+    assert!(!app.data.readarr_data.prompt_confirm);
+    assert_eq!(app.tick_count, 0);
+  }
+
+  #[tokio::test]
+  async fn test_dispatch_by_system_block() {
+    let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
+    let mut app = App::test_default();
+    app.data.readarr_data.prompt_confirm = true;
+    app.network_tx = Some(tx);
+
+    app
+      .dispatch_by_readarr_block(&ActiveReadarrBlock::System)
+      .await;
+
+    assert!(app.is_loading);
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      ReadarrEvent::GetTasks.into()
+    );
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      ReadarrEvent::GetQueuedEvents.into()
+    );
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      ReadarrEvent::GetLogs(500).into()
+    );
+    assert!(!app.data.readarr_data.prompt_confirm);
+    assert_eq!(app.tick_count, 0);
+  }
+
+  #[tokio::test]
+  async fn test_dispatch_by_system_updates_block() {
+    let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
+    let mut app = App::test_default();
+    app.data.readarr_data.prompt_confirm = true;
+    app.network_tx = Some(tx);
+
+    app
+      .dispatch_by_readarr_block(&ActiveReadarrBlock::SystemUpdates)
+      .await;
+
+    assert!(app.is_loading);
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      ReadarrEvent::GetUpdates.into()
+    );
+    assert!(!app.data.readarr_data.prompt_confirm);
+    assert_eq!(app.tick_count, 0);
+  }
+
+  #[tokio::test]
+  async fn test_check_for_readarr_prompt_action_no_prompt_confirm() {
+    let mut app = App::test_default();
+    app.data.readarr_data.prompt_confirm = false;
+
+    app.check_for_readarr_prompt_action().await;
+
+    assert!(!app.data.readarr_data.prompt_confirm);
+    assert!(!app.should_refresh);
+  }
+
+  #[tokio::test]
+  async fn test_check_for_readarr_prompt_action() {
+    let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
+    let mut app = App::test_default();
+    app.data.readarr_data.prompt_confirm = true;
+    app.network_tx = Some(tx);
+    app.data.readarr_data.prompt_confirm_action = Some(ReadarrEvent::GetStatus);
+
+    app.check_for_readarr_prompt_action().await;
+
+    assert!(!app.data.readarr_data.prompt_confirm);
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      ReadarrEvent::GetStatus.into()
+    );
+    assert!(app.should_refresh);
+    assert_eq!(app.data.readarr_data.prompt_confirm_action, None);
+  }
+
+  #[tokio::test]
+  async fn test_readarr_refresh_metadata() {
+    let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
+    let mut app = App::test_default();
+    app.data.readarr_data.prompt_confirm = true;
+    app.network_tx = Some(tx);
+    app.is_routing = true;
+
+    app.refresh_readarr_metadata().await;
+
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      ReadarrEvent::GetQualityProfiles.into()
+    );
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      ReadarrEvent::GetMetadataProfiles.into()
+    );
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      ReadarrEvent::GetTags.into()
+    );
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      ReadarrEvent::GetRootFolders.into()
+    );
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      ReadarrEvent::GetDiskSpace.into()
+    );
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      ReadarrEvent::GetStatus.into()
+    );
+    assert!(app.is_loading);
+  }
+
+  #[tokio::test]
+  async fn test_readarr_on_tick_first_render() {
+    let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
+    let mut app = App::test_default();
+    app.data.readarr_data.prompt_confirm = true;
+    app.network_tx = Some(tx);
+    app.is_first_render = true;
+
+    app.readarr_on_tick(ActiveReadarrBlock::SystemUpdates).await;
+
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      ReadarrEvent::GetQualityProfiles.into()
+    );
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      ReadarrEvent::GetMetadataProfiles.into()
+    );
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      ReadarrEvent::GetTags.into()
+    );
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      ReadarrEvent::GetRootFolders.into()
+    );
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      ReadarrEvent::GetDiskSpace.into()
+    );
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      ReadarrEvent::GetStatus.into()
+    );
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      ReadarrEvent::GetUpdates.into()
+    );
+    assert!(app.is_loading);
+    assert!(!app.data.readarr_data.prompt_confirm);
+    assert!(!app.is_first_render);
+  }
+
+  #[tokio::test]
+  async fn test_readarr_on_tick_routing() {
+    let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
+    let mut app = App::test_default();
+    app.data.readarr_data.prompt_confirm = true;
+    app.network_tx = Some(tx);
+    app.is_routing = true;
+    app.should_refresh = true;
+    app.is_first_render = false;
+    app.tick_count = 1;
+
+    app.readarr_on_tick(ActiveReadarrBlock::SystemUpdates).await;
+
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      ReadarrEvent::GetUpdates.into()
+    );
+    assert!(!app.data.readarr_data.prompt_confirm);
+  }
+
+  #[tokio::test]
+  async fn test_readarr_on_tick_routing_while_long_request_is_running_should_cancel_request() {
+    let (tx, _) = mpsc::channel::<QueuedNetworkEvent>(500);
+    let mut app = App::test_default();
+    app.data.readarr_data.prompt_confirm = true;
+    app.network_tx = Some(tx);
+    app.is_routing = true;
+    app.should_refresh = false;
+    app.is_first_render = false;
+    app.tick_count = 1;
+
+    app.readarr_on_tick(ActiveReadarrBlock::SystemUpdates).await;
+
+    assert!(app.cancellation_token.is_cancelled());
+  }
+
+  #[tokio::test]
+  async fn test_readarr_on_tick_should_refresh() {
+    let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
+    let mut app = App::test_default();
+    app.data.readarr_data.prompt_confirm = true;
+    app.network_tx = Some(tx);
+    app.should_refresh = true;
+    app.is_first_render = false;
+    app.tick_count = 1;
+
+    app.readarr_on_tick(ActiveReadarrBlock::SystemUpdates).await;
+
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      ReadarrEvent::GetUpdates.into()
+    );
+    assert!(app.should_refresh);
+    assert!(!app.data.readarr_data.prompt_confirm);
+  }
+
+  #[tokio::test]
+  async fn test_readarr_on_tick_network_tick_frequency() {
+    let (tx, mut rx) = mpsc::channel::<QueuedNetworkEvent>(500);
+    let mut app = App::test_default();
+    app.data.readarr_data.prompt_confirm = true;
+    app.network_tx = Some(tx);
+    app.tick_count = 2;
+    app.tick_until_poll = 2;
+
+    app.readarr_on_tick(ActiveReadarrBlock::SystemUpdates).await;
+
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      ReadarrEvent::GetQualityProfiles.into()
+    );
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      ReadarrEvent::GetMetadataProfiles.into()
+    );
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      ReadarrEvent::GetTags.into()
+    );
+    assert_eq!(
+      rx.recv().await.unwrap().network_event,
+      ReadarrEvent::GetRootFolders.into()
+    );
+    assert!(app.is_loading);
+  }
+
+  #[tokio::test]
+  async fn test_extract_add_new_author_search_query() {
+    let app = App::test_default_fully_populated();
+
+    let query = app.extract_add_new_author_search_query().await;
+
+    assert_str_eq!(query, "Test Author");
+  }
+
+  #[tokio::test]
+  #[should_panic(expected = "Add author search is empty")]
+  async fn test_extract_add_new_author_search_query_panics_when_the_query_is_not_set() {
+    let app = App::test_default();
+
+    app.extract_add_new_author_search_query().await;
+  }
+
+  #[tokio::test]
+  async fn test_extract_author_id() {
+    let mut app = App::test_default();
+    app.data.readarr_data.authors.set_items(vec![author()]);
+
+    assert_eq!(app.extract_author_id().await, 1);
+  }
+
+  #[tokio::test]
+  async fn test_extract_book_id() {
+    let mut app = App::test_default();
+    app.data.readarr_data.books.set_items(vec![book()]);
+
+    assert_eq!(app.extract_book_id().await, 1);
+  }
+}
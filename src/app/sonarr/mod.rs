@@ -1,6 +1,8 @@
+use chrono::Local;
+
 use crate::{
   models::servarr_data::sonarr::sonarr_data::ActiveSonarrBlock,
-  network::sonarr_network::SonarrEvent,
+  network::sonarr_network::SonarrEvent, utils::calendar_range,
 };
 
 use super::App;
@@ -108,6 +110,21 @@ impl App<'_> {
           .dispatch_network_event(SonarrEvent::GetDownloads(500).into())
           .await;
       }
+      ActiveSonarrBlock::ManualImport => {
+        if self.data.sonarr_data.series.is_empty() {
+          self
+            .dispatch_network_event(SonarrEvent::ListSeries.into())
+            .await;
+        }
+
+        if let Some(manual_import_modal) = self.data.sonarr_data.manual_import_modal.as_ref() {
+          let event = SonarrEvent::GetManualImport(
+            manual_import_modal.download_id.clone(),
+            manual_import_modal.series_id,
+          );
+          self.dispatch_network_event(event.into()).await;
+        }
+      }
       ActiveSonarrBlock::Blocklist => {
         self
           .dispatch_network_event(SonarrEvent::ListSeries.into())
@@ -121,11 +138,45 @@ impl App<'_> {
           .dispatch_network_event(SonarrEvent::GetHistory(500).into())
           .await;
       }
+      ActiveSonarrBlock::Calendar | ActiveSonarrBlock::CalendarAgenda => {
+        let (start, end) = calendar_range(Local::now().date_naive());
+        self
+          .dispatch_network_event(SonarrEvent::ListSeries.into())
+          .await;
+        self
+          .dispatch_network_event(SonarrEvent::GetCalendar(start, end).into())
+          .await;
+      }
+      ActiveSonarrBlock::WantedMissing => {
+        self
+          .dispatch_network_event(SonarrEvent::ListSeries.into())
+          .await;
+        self
+          .dispatch_network_event(SonarrEvent::GetWantedMissing(500).into())
+          .await;
+      }
+      ActiveSonarrBlock::WantedCutoffUnmet => {
+        self
+          .dispatch_network_event(SonarrEvent::ListSeries.into())
+          .await;
+        self
+          .dispatch_network_event(SonarrEvent::GetCutoffUnmet(500).into())
+          .await;
+      }
       ActiveSonarrBlock::RootFolders => {
         self
           .dispatch_network_event(SonarrEvent::GetRootFolders.into())
           .await;
       }
+      ActiveSonarrBlock::MediaManagementSettingsPrompt => {
+        let event = match self.data.sonarr_data.media_management_modal.as_ref() {
+          Some(media_management_modal) => {
+            SonarrEvent::GetNamingExamples(media_management_modal.settings().naming)
+          }
+          None => SonarrEvent::GetMediaManagementSettings,
+        };
+        self.dispatch_network_event(event.into()).await;
+      }
       ActiveSonarrBlock::Indexers => {
         self
           .dispatch_network_event(SonarrEvent::GetTags.into())
@@ -146,11 +197,51 @@ impl App<'_> {
           )
           .await;
       }
+      ActiveSonarrBlock::AddIndexerSelectImplementation => {
+        self
+          .dispatch_network_event(SonarrEvent::GetIndexerSchema.into())
+          .await;
+      }
       ActiveSonarrBlock::TestAllIndexers => {
         self
           .dispatch_network_event(SonarrEvent::TestAllIndexers.into())
           .await;
       }
+      ActiveSonarrBlock::QualityProfiles => {
+        self
+          .dispatch_network_event(SonarrEvent::GetQualityProfiles.into())
+          .await;
+      }
+      ActiveSonarrBlock::CustomFormats => {
+        self
+          .dispatch_network_event(SonarrEvent::GetCustomFormats.into())
+          .await;
+      }
+      ActiveSonarrBlock::DownloadClients => {
+        self
+          .dispatch_network_event(SonarrEvent::GetTags.into())
+          .await;
+        self
+          .dispatch_network_event(SonarrEvent::GetDownloadClients.into())
+          .await;
+      }
+      ActiveSonarrBlock::TestDownloadClient => {
+        self
+          .dispatch_network_event(
+            SonarrEvent::TestDownloadClient(self.extract_sonarr_download_client_id().await).into(),
+          )
+          .await;
+      }
+      ActiveSonarrBlock::AddDownloadClientSelectImplementation => {
+        self
+          .dispatch_network_event(SonarrEvent::GetDownloadClientSchema.into())
+          .await;
+      }
+      ActiveSonarrBlock::TestAllDownloadClients => {
+        self
+          .dispatch_network_event(SonarrEvent::TestAllDownloadClients.into())
+          .await;
+      }
       ActiveSonarrBlock::System => {
         self
           .dispatch_network_event(SonarrEvent::GetTasks.into())
@@ -169,6 +260,11 @@ impl App<'_> {
           )
           .await;
       }
+      ActiveSonarrBlock::SystemBackups => {
+        self
+          .dispatch_network_event(SonarrEvent::GetBackups.into())
+          .await;
+      }
       ActiveSonarrBlock::SystemUpdates => {
         self
           .dispatch_network_event(SonarrEvent::GetUpdates.into())
@@ -212,7 +308,7 @@ impl App<'_> {
       }
     }
 
-    if self.tick_count.is_multiple_of(self.tick_until_poll) {
+    if self.should_poll() {
       self.refresh_sonarr_metadata().await;
     }
   }
@@ -300,4 +396,13 @@ impl App<'_> {
   async fn extract_sonarr_indexer_id(&self) -> i64 {
     self.data.sonarr_data.indexers.current_selection().id
   }
+
+  async fn extract_sonarr_download_client_id(&self) -> i64 {
+    self
+      .data
+      .sonarr_data
+      .download_clients
+      .current_selection()
+      .id
+  }
 }
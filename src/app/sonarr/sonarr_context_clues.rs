@@ -1,12 +1,14 @@
 use crate::app::context_clues::{
   BARE_POPUP_CONTEXT_CLUES, CONFIRMATION_PROMPT_CONTEXT_CLUES, ContextClueProvider,
-  SYSTEM_TASKS_CONTEXT_CLUES,
+  EDIT_QUALITY_PROFILE_CONTEXT_CLUES, MANUAL_IMPORT_CONTEXT_CLUES, SCHEMA_FORM_CONTEXT_CLUES,
+  SETTINGS_FORM_CONTEXT_CLUES, SYSTEM_BACKUPS_CONTEXT_CLUES, SYSTEM_TASKS_CONTEXT_CLUES,
 };
 use crate::app::{App, context_clues::ContextClue, key_binding::DEFAULT_KEYBINDINGS};
 use crate::models::Route;
 use crate::models::servarr_data::sonarr::sonarr_data::{
-  ADD_SERIES_BLOCKS, ActiveSonarrBlock, EDIT_INDEXER_BLOCKS, EDIT_SERIES_BLOCKS,
-  EPISODE_DETAILS_BLOCKS, INDEXER_SETTINGS_BLOCKS, SEASON_DETAILS_BLOCKS, SERIES_DETAILS_BLOCKS,
+  ADD_SERIES_BLOCKS, ActiveSonarrBlock, BULK_EDIT_SERIES_BLOCKS, EDIT_INDEXER_BLOCKS,
+  EDIT_SERIES_BLOCKS, EPISODE_DETAILS_BLOCKS, INDEXER_SETTINGS_BLOCKS, SEASON_DETAILS_BLOCKS,
+  SERIES_DETAILS_BLOCKS,
 };
 
 #[cfg(test)]
@@ -18,7 +20,7 @@ pub static ADD_SERIES_SEARCH_RESULTS_CONTEXT_CLUES: [ContextClue; 2] = [
   (DEFAULT_KEYBINDINGS.esc, "edit search"),
 ];
 
-pub static SERIES_CONTEXT_CLUES: [ContextClue; 11] = [
+pub static SERIES_CONTEXT_CLUES: [ContextClue; 12] = [
   (DEFAULT_KEYBINDINGS.add, DEFAULT_KEYBINDINGS.add.desc),
   (DEFAULT_KEYBINDINGS.edit, DEFAULT_KEYBINDINGS.edit.desc),
   (
@@ -29,6 +31,7 @@ pub static SERIES_CONTEXT_CLUES: [ContextClue; 11] = [
   (DEFAULT_KEYBINDINGS.delete, DEFAULT_KEYBINDINGS.delete.desc),
   (DEFAULT_KEYBINDINGS.search, DEFAULT_KEYBINDINGS.search.desc),
   (DEFAULT_KEYBINDINGS.filter, DEFAULT_KEYBINDINGS.filter.desc),
+  (DEFAULT_KEYBINDINGS.select, DEFAULT_KEYBINDINGS.select.desc),
   (
     DEFAULT_KEYBINDINGS.refresh,
     DEFAULT_KEYBINDINGS.refresh.desc,
@@ -196,14 +199,30 @@ impl ContextClueProvider for SonarrContextClueProvider {
         .unwrap()
         .episode_details_tabs
         .get_active_route_contextual_help(),
+      ActiveSonarrBlock::EditQualityProfile => Some(&EDIT_QUALITY_PROFILE_CONTEXT_CLUES),
+      ActiveSonarrBlock::AddIndexerPrompt
+      | ActiveSonarrBlock::DownloadClientPrompt
+      | ActiveSonarrBlock::EditCustomFormatPrompt => Some(&SCHEMA_FORM_CONTEXT_CLUES),
+      ActiveSonarrBlock::MediaManagementSettingsPrompt => Some(&SETTINGS_FORM_CONTEXT_CLUES),
+      ActiveSonarrBlock::AddIndexerSelectImplementation
+      | ActiveSonarrBlock::AddIndexerFieldInput
+      | ActiveSonarrBlock::AddDownloadClientSelectImplementation
+      | ActiveSonarrBlock::DownloadClientFieldInput
+      | ActiveSonarrBlock::CustomFormatFieldInput
+      | ActiveSonarrBlock::MediaManagementFieldInput
+      | ActiveSonarrBlock::ImportCustomFormatPrompt
+      | ActiveSonarrBlock::ExportCustomFormatPrompt => Some(&CONFIRMATION_PROMPT_CONTEXT_CLUES),
       ActiveSonarrBlock::TestAllIndexers
+      | ActiveSonarrBlock::TestAllDownloadClients
+      | ActiveSonarrBlock::CustomFormatDetails
       | ActiveSonarrBlock::AddSeriesSearchInput
       | ActiveSonarrBlock::AddSeriesEmptySearchResults
       | ActiveSonarrBlock::SystemLogs
       | ActiveSonarrBlock::SystemUpdates => Some(&BARE_POPUP_CONTEXT_CLUES),
       _ if EDIT_INDEXER_BLOCKS.contains(&active_sonarr_block)
         || INDEXER_SETTINGS_BLOCKS.contains(&active_sonarr_block)
-        || EDIT_SERIES_BLOCKS.contains(&active_sonarr_block) =>
+        || EDIT_SERIES_BLOCKS.contains(&active_sonarr_block)
+        || BULK_EDIT_SERIES_BLOCKS.contains(&active_sonarr_block) =>
       {
         Some(&CONFIRMATION_PROMPT_CONTEXT_CLUES)
       }
@@ -214,11 +233,15 @@ impl ContextClueProvider for SonarrContextClueProvider {
       | ActiveSonarrBlock::AddSeriesSelectLanguageProfile
       | ActiveSonarrBlock::AddSeriesSelectRootFolder
       | ActiveSonarrBlock::AddSeriesTagsInput
+      | ActiveSonarrBlock::ManualImportConfirmPrompt
+      | ActiveSonarrBlock::ManualImportSelectSeries
       | ActiveSonarrBlock::SystemTaskStartConfirmPrompt => Some(&CONFIRMATION_PROMPT_CONTEXT_CLUES),
       _ if ADD_SERIES_BLOCKS.contains(&active_sonarr_block) => {
         Some(&ADD_SERIES_SEARCH_RESULTS_CONTEXT_CLUES)
       }
+      ActiveSonarrBlock::SystemBackups => Some(&SYSTEM_BACKUPS_CONTEXT_CLUES),
       ActiveSonarrBlock::SystemTasks => Some(&SYSTEM_TASKS_CONTEXT_CLUES),
+      ActiveSonarrBlock::ManualImport => Some(&MANUAL_IMPORT_CONTEXT_CLUES),
       _ => app
         .data
         .sonarr_data
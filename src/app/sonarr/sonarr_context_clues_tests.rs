@@ -2,9 +2,11 @@
 mod tests {
   use crate::app::context_clues::{
     BARE_POPUP_CONTEXT_CLUES, BLOCKLIST_CONTEXT_CLUES, CONFIRMATION_PROMPT_CONTEXT_CLUES,
-    ContextClue, ContextClueProvider, DOWNLOADS_CONTEXT_CLUES, HISTORY_CONTEXT_CLUES,
-    INDEXERS_CONTEXT_CLUES, ROOT_FOLDERS_CONTEXT_CLUES, SYSTEM_CONTEXT_CLUES,
-    SYSTEM_TASKS_CONTEXT_CLUES,
+    CUSTOM_FORMATS_CONTEXT_CLUES, ContextClue, ContextClueProvider, DOWNLOAD_CLIENTS_CONTEXT_CLUES,
+    DOWNLOADS_CONTEXT_CLUES, EDIT_QUALITY_PROFILE_CONTEXT_CLUES, HISTORY_CONTEXT_CLUES,
+    INDEXERS_CONTEXT_CLUES, MANUAL_IMPORT_CONTEXT_CLUES, QUALITY_PROFILES_CONTEXT_CLUES,
+    ROOT_FOLDERS_CONTEXT_CLUES, SCHEMA_FORM_CONTEXT_CLUES, SETTINGS_FORM_CONTEXT_CLUES,
+    SYSTEM_BACKUPS_CONTEXT_CLUES, SYSTEM_TASKS_CONTEXT_CLUES, SYSTEM_WITH_BACKUPS_CONTEXT_CLUES,
   };
   use crate::app::sonarr::sonarr_context_clues::{
     SELECTABLE_EPISODE_DETAILS_CONTEXT_CLUES, SonarrContextClueProvider,
@@ -75,6 +77,10 @@ mod tests {
       series_context_clues_iter.next(),
       &(DEFAULT_KEYBINDINGS.filter, DEFAULT_KEYBINDINGS.filter.desc)
     );
+    assert_some_eq_x!(
+      series_context_clues_iter.next(),
+      &(DEFAULT_KEYBINDINGS.select, DEFAULT_KEYBINDINGS.select.desc)
+    );
     assert_some_eq_x!(
       series_context_clues_iter.next(),
       &(
@@ -498,6 +504,8 @@ mod tests {
   fn test_sonarr_context_clue_provider_bare_popup_context_clues(
     #[values(
       ActiveSonarrBlock::TestAllIndexers,
+      ActiveSonarrBlock::TestAllDownloadClients,
+      ActiveSonarrBlock::CustomFormatDetails,
       ActiveSonarrBlock::AddSeriesSearchInput,
       ActiveSonarrBlock::AddSeriesEmptySearchResults,
       ActiveSonarrBlock::SystemLogs,
@@ -523,6 +531,8 @@ mod tests {
       ActiveSonarrBlock::AddSeriesSelectLanguageProfile,
       ActiveSonarrBlock::AddSeriesSelectRootFolder,
       ActiveSonarrBlock::AddSeriesTagsInput,
+      ActiveSonarrBlock::ManualImportConfirmPrompt,
+      ActiveSonarrBlock::ManualImportSelectSeries,
       ActiveSonarrBlock::SystemTaskStartConfirmPrompt
     )]
     active_sonarr_block: ActiveSonarrBlock,
@@ -580,6 +590,28 @@ mod tests {
     assert_some_eq_x!(context_clues, &CONFIRMATION_PROMPT_CONTEXT_CLUES);
   }
 
+  #[rstest]
+  fn test_sonarr_context_clue_provider_confirmation_prompt_popup_clues_bulk_edit_series_blocks(
+    #[values(
+      ActiveSonarrBlock::BulkEditSeriesPrompt,
+      ActiveSonarrBlock::BulkEditSeriesConfirmPrompt,
+      ActiveSonarrBlock::BulkEditSeriesSelectApplyTags,
+      ActiveSonarrBlock::BulkEditSeriesSelectMonitored,
+      ActiveSonarrBlock::BulkEditSeriesSelectQualityProfile,
+      ActiveSonarrBlock::BulkEditSeriesSelectRootFolder,
+      ActiveSonarrBlock::BulkEditSeriesTagsInput,
+      ActiveSonarrBlock::BulkEditSeriesToggleMoveFiles
+    )]
+    active_sonarr_block: ActiveSonarrBlock,
+  ) {
+    let mut app = App::test_default();
+    app.push_navigation_stack(active_sonarr_block.into());
+
+    let context_clues = SonarrContextClueProvider::get_context_clues(&mut app);
+
+    assert_some_eq_x!(context_clues, &CONFIRMATION_PROMPT_CONTEXT_CLUES);
+  }
+
   #[rstest]
   fn test_sonarr_context_clue_provider_confirmation_prompt_popup_clues_edit_series_blocks(
     #[values(
@@ -629,6 +661,26 @@ mod tests {
     assert_some_eq_x!(context_clues, &SYSTEM_TASKS_CONTEXT_CLUES);
   }
 
+  #[test]
+  fn test_sonarr_context_clue_provider_system_backups_clues() {
+    let mut app = App::test_default();
+
+    app.push_navigation_stack(ActiveSonarrBlock::SystemBackups.into());
+    let context_clues = SonarrContextClueProvider::get_context_clues(&mut app);
+
+    assert_some_eq_x!(context_clues, &SYSTEM_BACKUPS_CONTEXT_CLUES);
+  }
+
+  #[test]
+  fn test_sonarr_context_clue_provider_manual_import_clues() {
+    let mut app = App::test_default();
+
+    app.push_navigation_stack(ActiveSonarrBlock::ManualImport.into());
+    let context_clues = SonarrContextClueProvider::get_context_clues(&mut app);
+
+    assert_some_eq_x!(context_clues, &MANUAL_IMPORT_CONTEXT_CLUES);
+  }
+
   #[rstest]
   #[case(0, ActiveSonarrBlock::Series, &SERIES_CONTEXT_CLUES)]
   #[case(1, ActiveSonarrBlock::Downloads, &DOWNLOADS_CONTEXT_CLUES)]
@@ -636,7 +688,10 @@ mod tests {
   #[case(3, ActiveSonarrBlock::History, &HISTORY_CONTEXT_CLUES)]
   #[case(4, ActiveSonarrBlock::RootFolders, &ROOT_FOLDERS_CONTEXT_CLUES)]
   #[case(5, ActiveSonarrBlock::Indexers, &INDEXERS_CONTEXT_CLUES)]
-  #[case(6, ActiveSonarrBlock::System, &SYSTEM_CONTEXT_CLUES)]
+  #[case(6, ActiveSonarrBlock::DownloadClients, &DOWNLOAD_CLIENTS_CONTEXT_CLUES)]
+  #[case(7, ActiveSonarrBlock::QualityProfiles, &QUALITY_PROFILES_CONTEXT_CLUES)]
+  #[case(8, ActiveSonarrBlock::CustomFormats, &CUSTOM_FORMATS_CONTEXT_CLUES)]
+  #[case(9, ActiveSonarrBlock::System, &SYSTEM_WITH_BACKUPS_CONTEXT_CLUES)]
   fn test_sonarr_context_clue_provider_sonarr_tabs(
     #[case] index: usize,
     #[case] active_sonarr_block: ActiveSonarrBlock,
@@ -651,4 +706,62 @@ mod tests {
 
     assert_some_eq_x!(context_clues, expected_context_clues);
   }
+  #[test]
+  fn test_sonarr_context_clue_provider_edit_quality_profile_context_clues() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveSonarrBlock::EditQualityProfile.into());
+
+    let context_clues = SonarrContextClueProvider::get_context_clues(&mut app);
+
+    assert_some_eq_x!(context_clues, &EDIT_QUALITY_PROFILE_CONTEXT_CLUES);
+  }
+
+  #[test]
+  fn test_sonarr_context_clue_provider_media_management_settings_context_clues() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveSonarrBlock::MediaManagementSettingsPrompt.into());
+
+    let context_clues = SonarrContextClueProvider::get_context_clues(&mut app);
+
+    assert_some_eq_x!(context_clues, &SETTINGS_FORM_CONTEXT_CLUES);
+  }
+
+  #[rstest]
+  fn test_sonarr_context_clue_provider_schema_form_prompt_context_clues(
+    #[values(
+      ActiveSonarrBlock::AddIndexerPrompt,
+      ActiveSonarrBlock::DownloadClientPrompt,
+      ActiveSonarrBlock::EditCustomFormatPrompt
+    )]
+    active_sonarr_block: ActiveSonarrBlock,
+  ) {
+    let mut app = App::test_default();
+    app.push_navigation_stack(active_sonarr_block.into());
+
+    let context_clues = SonarrContextClueProvider::get_context_clues(&mut app);
+
+    assert_some_eq_x!(context_clues, &SCHEMA_FORM_CONTEXT_CLUES);
+  }
+
+  #[rstest]
+  fn test_sonarr_context_clue_provider_confirmation_prompt_context_clues_schema_form_blocks(
+    #[values(
+      ActiveSonarrBlock::AddIndexerSelectImplementation,
+      ActiveSonarrBlock::AddIndexerFieldInput,
+      ActiveSonarrBlock::AddDownloadClientSelectImplementation,
+      ActiveSonarrBlock::DownloadClientFieldInput,
+      ActiveSonarrBlock::CustomFormatFieldInput,
+      ActiveSonarrBlock::MediaManagementFieldInput,
+      ActiveSonarrBlock::ImportCustomFormatPrompt,
+      ActiveSonarrBlock::ExportCustomFormatPrompt
+    )]
+    active_sonarr_block: ActiveSonarrBlock,
+  ) {
+    let mut app = App::test_default();
+    app.push_navigation_stack(active_sonarr_block.into());
+
+    let context_clues = SonarrContextClueProvider::get_context_clues(&mut app);
+
+    assert_some_eq_x!(context_clues, &CONFIRMATION_PROMPT_CONTEXT_CLUES);
+  }
 }
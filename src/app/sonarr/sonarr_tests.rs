@@ -1,22 +1,29 @@
 #[cfg(test)]
 mod tests {
   mod sonarr_tests {
+    use chrono::Local;
     use pretty_assertions::{assert_eq, assert_str_eq};
     use tokio::sync::mpsc;
 
+    use crate::models::servarr_data::modals::MediaManagementModal;
+    use crate::models::servarr_data::sonarr::sonarr_data::MEDIA_MANAGEMENT_NAMING_FIELDS;
     use crate::models::servarr_data::sonarr::sonarr_data::sonarr_test_utils::utils::create_test_sonarr_data;
-    use crate::models::servarr_models::Indexer;
-    use crate::models::sonarr_models::Episode;
+    use crate::models::servarr_models::{DownloadClient, Indexer};
+    use crate::models::sonarr_models::{Episode, NamingConfig};
+    use crate::network::sonarr_network::sonarr_network_test_utils::test_utils::{
+      media_management_settings, naming_config,
+    };
     use crate::{
       app::App,
       models::{
         servarr_data::sonarr::{
-          modals::{EpisodeDetailsModal, SeasonDetailsModal},
+          modals::{EpisodeDetailsModal, ManualImportModal, SeasonDetailsModal},
           sonarr_data::ActiveSonarrBlock,
         },
         sonarr_models::{Season, Series, SonarrRelease},
       },
-      network::{NetworkEvent, sonarr_network::SonarrEvent},
+      network::{QueuedNetworkEvent, sonarr_network::SonarrEvent},
+      utils::calendar_range,
     };
 
     #[tokio::test]
@@ -29,17 +36,84 @@ mod tests {
 
       assert!(app.is_loading);
       assert_eq!(
-        sync_network_rx.recv().await.unwrap(),
+        sync_network_rx.recv().await.unwrap().network_event,
         SonarrEvent::ListSeries.into()
       );
       assert_eq!(
-        sync_network_rx.recv().await.unwrap(),
+        sync_network_rx.recv().await.unwrap().network_event,
         SonarrEvent::GetBlocklist.into()
       );
       assert!(!app.data.sonarr_data.prompt_confirm);
       assert_eq!(app.tick_count, 0);
     }
 
+    #[tokio::test]
+    async fn test_dispatch_by_calendar_blocks() {
+      for active_sonarr_block in [
+        ActiveSonarrBlock::Calendar,
+        ActiveSonarrBlock::CalendarAgenda,
+      ] {
+        let (mut app, mut sync_network_rx) = construct_app_unit();
+        let (start, end) = calendar_range(Local::now().date_naive());
+
+        app.dispatch_by_sonarr_block(&active_sonarr_block).await;
+
+        assert!(app.is_loading);
+        assert_eq!(
+          sync_network_rx.recv().await.unwrap().network_event,
+          SonarrEvent::ListSeries.into()
+        );
+        assert_eq!(
+          sync_network_rx.recv().await.unwrap().network_event,
+          SonarrEvent::GetCalendar(start, end).into()
+        );
+        assert!(!app.data.sonarr_data.prompt_confirm);
+        assert_eq!(app.tick_count, 0);
+      }
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_by_wanted_missing_block() {
+      let (mut app, mut sync_network_rx) = construct_app_unit();
+
+      app
+        .dispatch_by_sonarr_block(&ActiveSonarrBlock::WantedMissing)
+        .await;
+
+      assert!(app.is_loading);
+      assert_eq!(
+        sync_network_rx.recv().await.unwrap().network_event,
+        SonarrEvent::ListSeries.into()
+      );
+      assert_eq!(
+        sync_network_rx.recv().await.unwrap().network_event,
+        SonarrEvent::GetWantedMissing(500).into()
+      );
+      assert!(!app.data.sonarr_data.prompt_confirm);
+      assert_eq!(app.tick_count, 0);
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_by_wanted_cutoff_unmet_block() {
+      let (mut app, mut sync_network_rx) = construct_app_unit();
+
+      app
+        .dispatch_by_sonarr_block(&ActiveSonarrBlock::WantedCutoffUnmet)
+        .await;
+
+      assert!(app.is_loading);
+      assert_eq!(
+        sync_network_rx.recv().await.unwrap().network_event,
+        SonarrEvent::ListSeries.into()
+      );
+      assert_eq!(
+        sync_network_rx.recv().await.unwrap().network_event,
+        SonarrEvent::GetCutoffUnmet(500).into()
+      );
+      assert!(!app.data.sonarr_data.prompt_confirm);
+      assert_eq!(app.tick_count, 0);
+    }
+
     #[tokio::test]
     async fn test_dispatch_by_series_history_block() {
       let (mut app, mut sync_network_rx) = construct_app_unit();
@@ -54,7 +128,7 @@ mod tests {
 
       assert!(app.is_loading);
       assert_eq!(
-        sync_network_rx.recv().await.unwrap(),
+        sync_network_rx.recv().await.unwrap().network_event,
         SonarrEvent::GetSeriesHistory(1).into()
       );
       assert!(!app.data.sonarr_data.prompt_confirm);
@@ -76,7 +150,7 @@ mod tests {
 
       assert!(!app.is_loading);
       assert_eq!(
-        sync_network_rx.recv().await.unwrap(),
+        sync_network_rx.recv().await.unwrap().network_event,
         SonarrEvent::ListSeries.into()
       );
       assert!(!app.data.sonarr_data.seasons.items.is_empty());
@@ -98,15 +172,15 @@ mod tests {
 
       assert!(app.is_loading);
       assert_eq!(
-        sync_network_rx.recv().await.unwrap(),
+        sync_network_rx.recv().await.unwrap().network_event,
         SonarrEvent::GetEpisodes(1).into()
       );
       assert_eq!(
-        sync_network_rx.recv().await.unwrap(),
+        sync_network_rx.recv().await.unwrap().network_event,
         SonarrEvent::GetEpisodeFiles(1).into()
       );
       assert_eq!(
-        sync_network_rx.recv().await.unwrap(),
+        sync_network_rx.recv().await.unwrap().network_event,
         SonarrEvent::GetDownloads(500).into()
       );
       assert!(!app.data.sonarr_data.prompt_confirm);
@@ -131,7 +205,7 @@ mod tests {
 
       assert!(app.is_loading);
       assert_eq!(
-        sync_network_rx.recv().await.unwrap(),
+        sync_network_rx.recv().await.unwrap().network_event,
         SonarrEvent::GetSeasonHistory(1, 1).into()
       );
       assert!(!app.data.sonarr_data.prompt_confirm);
@@ -174,7 +248,7 @@ mod tests {
 
       assert!(app.is_loading);
       assert_eq!(
-        sync_network_rx.recv().await.unwrap(),
+        sync_network_rx.recv().await.unwrap().network_event,
         SonarrEvent::GetSeasonReleases(1, 1).into()
       );
       assert!(!app.data.sonarr_data.prompt_confirm);
@@ -226,7 +300,7 @@ mod tests {
 
       assert!(app.is_loading);
       assert_eq!(
-        sync_network_rx.recv().await.unwrap(),
+        sync_network_rx.recv().await.unwrap().network_event,
         SonarrEvent::GetEpisodeDetails(0).into()
       );
       assert!(!app.data.sonarr_data.prompt_confirm);
@@ -244,7 +318,7 @@ mod tests {
 
       assert!(app.is_loading);
       assert_eq!(
-        sync_network_rx.recv().await.unwrap(),
+        sync_network_rx.recv().await.unwrap().network_event,
         SonarrEvent::GetEpisodeDetails(0).into()
       );
       assert!(!app.data.sonarr_data.prompt_confirm);
@@ -267,7 +341,7 @@ mod tests {
 
       assert!(app.is_loading);
       assert_eq!(
-        sync_network_rx.recv().await.unwrap(),
+        sync_network_rx.recv().await.unwrap().network_event,
         SonarrEvent::GetEpisodeHistory(1).into()
       );
       assert!(!app.data.sonarr_data.prompt_confirm);
@@ -293,7 +367,7 @@ mod tests {
 
       assert!(app.is_loading);
       assert_eq!(
-        sync_network_rx.recv().await.unwrap(),
+        sync_network_rx.recv().await.unwrap().network_event,
         SonarrEvent::GetEpisodeReleases(1).into()
       );
       assert!(!app.data.sonarr_data.prompt_confirm);
@@ -348,7 +422,7 @@ mod tests {
 
       assert!(app.is_loading);
       assert_eq!(
-        sync_network_rx.recv().await.unwrap(),
+        sync_network_rx.recv().await.unwrap().network_event,
         SonarrEvent::GetHistory(500).into()
       );
       assert!(!app.data.sonarr_data.prompt_confirm);
@@ -365,13 +439,65 @@ mod tests {
 
       assert!(app.is_loading);
       assert_eq!(
-        sync_network_rx.recv().await.unwrap(),
+        sync_network_rx.recv().await.unwrap().network_event,
         SonarrEvent::GetDownloads(500).into()
       );
       assert!(!app.data.sonarr_data.prompt_confirm);
       assert_eq!(app.tick_count, 0);
     }
 
+    #[tokio::test]
+    async fn test_dispatch_by_manual_import_block() {
+      let (mut app, mut sync_network_rx) = construct_app_unit();
+      app.data.sonarr_data.manual_import_modal = Some(ManualImportModal {
+        download_id: "abc123".to_owned(),
+        series_id: Some(1),
+        ..ManualImportModal::default()
+      });
+
+      app
+        .dispatch_by_sonarr_block(&ActiveSonarrBlock::ManualImport)
+        .await;
+
+      assert!(app.is_loading);
+      assert_eq!(
+        sync_network_rx.recv().await.unwrap().network_event,
+        SonarrEvent::ListSeries.into()
+      );
+      assert_eq!(
+        sync_network_rx.recv().await.unwrap().network_event,
+        SonarrEvent::GetManualImport("abc123".to_owned(), Some(1)).into()
+      );
+      assert!(!app.data.sonarr_data.prompt_confirm);
+      assert_eq!(app.tick_count, 0);
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_by_manual_import_block_series_non_empty() {
+      let (mut app, mut sync_network_rx) = construct_app_unit();
+      app
+        .data
+        .sonarr_data
+        .series
+        .set_items(vec![Series::default()]);
+      app.data.sonarr_data.manual_import_modal = Some(ManualImportModal {
+        download_id: "abc123".to_owned(),
+        ..ManualImportModal::default()
+      });
+
+      app
+        .dispatch_by_sonarr_block(&ActiveSonarrBlock::ManualImport)
+        .await;
+
+      assert!(app.is_loading);
+      assert_eq!(
+        sync_network_rx.recv().await.unwrap().network_event,
+        SonarrEvent::GetManualImport("abc123".to_owned(), None).into()
+      );
+      assert!(!app.data.sonarr_data.prompt_confirm);
+      assert_eq!(app.tick_count, 0);
+    }
+
     #[tokio::test]
     async fn test_dispatch_by_root_folders_block() {
       let (mut app, mut sync_network_rx) = construct_app_unit();
@@ -382,13 +508,58 @@ mod tests {
 
       assert!(app.is_loading);
       assert_eq!(
-        sync_network_rx.recv().await.unwrap(),
+        sync_network_rx.recv().await.unwrap().network_event,
         SonarrEvent::GetRootFolders.into()
       );
       assert!(!app.data.sonarr_data.prompt_confirm);
       assert_eq!(app.tick_count, 0);
     }
 
+    #[tokio::test]
+    async fn test_dispatch_by_media_management_settings_prompt_block() {
+      let (mut app, mut sync_network_rx) = construct_app_unit();
+
+      app
+        .dispatch_by_sonarr_block(&ActiveSonarrBlock::MediaManagementSettingsPrompt)
+        .await;
+
+      assert!(app.is_loading);
+      assert_eq!(
+        sync_network_rx.recv().await.unwrap().network_event,
+        SonarrEvent::GetMediaManagementSettings.into()
+      );
+      assert!(!app.data.sonarr_data.prompt_confirm);
+      assert_eq!(app.tick_count, 0);
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_by_media_management_settings_prompt_block_fetches_naming_examples_for_edits()
+     {
+      let (mut app, mut sync_network_rx) = construct_app_unit();
+      let mut media_management_modal = MediaManagementModal::new(
+        &media_management_settings(),
+        &MEDIA_MANAGEMENT_NAMING_FIELDS,
+      );
+      media_management_modal.toggle_current_field();
+      app.data.sonarr_data.media_management_modal = Some(media_management_modal);
+      let expected_naming_config = NamingConfig {
+        rename_episodes: false,
+        ..naming_config()
+      };
+
+      app
+        .dispatch_by_sonarr_block(&ActiveSonarrBlock::MediaManagementSettingsPrompt)
+        .await;
+
+      assert!(app.is_loading);
+      assert_eq!(
+        sync_network_rx.recv().await.unwrap().network_event,
+        SonarrEvent::GetNamingExamples(expected_naming_config).into()
+      );
+      assert!(!app.data.sonarr_data.prompt_confirm);
+      assert_eq!(app.tick_count, 0);
+    }
+
     #[tokio::test]
     async fn test_dispatch_by_series_block() {
       let (mut app, mut sync_network_rx) = construct_app_unit();
@@ -399,19 +570,19 @@ mod tests {
 
       assert!(app.is_loading);
       assert_eq!(
-        sync_network_rx.recv().await.unwrap(),
+        sync_network_rx.recv().await.unwrap().network_event,
         SonarrEvent::GetQualityProfiles.into()
       );
       assert_eq!(
-        sync_network_rx.recv().await.unwrap(),
+        sync_network_rx.recv().await.unwrap().network_event,
         SonarrEvent::GetLanguageProfiles.into()
       );
       assert_eq!(
-        sync_network_rx.recv().await.unwrap(),
+        sync_network_rx.recv().await.unwrap().network_event,
         SonarrEvent::GetTags.into()
       );
       assert_eq!(
-        sync_network_rx.recv().await.unwrap(),
+        sync_network_rx.recv().await.unwrap().network_event,
         SonarrEvent::ListSeries.into()
       );
       assert!(!app.data.sonarr_data.prompt_confirm);
@@ -428,17 +599,33 @@ mod tests {
 
       assert!(app.is_loading);
       assert_eq!(
-        sync_network_rx.recv().await.unwrap(),
+        sync_network_rx.recv().await.unwrap().network_event,
         SonarrEvent::GetTags.into()
       );
       assert_eq!(
-        sync_network_rx.recv().await.unwrap(),
+        sync_network_rx.recv().await.unwrap().network_event,
         SonarrEvent::GetIndexers.into()
       );
       assert!(!app.data.sonarr_data.prompt_confirm);
       assert_eq!(app.tick_count, 0);
     }
 
+    #[tokio::test]
+    async fn test_dispatch_by_add_indexer_select_implementation_block() {
+      let (mut app, mut sync_network_rx) = construct_app_unit();
+
+      app
+        .dispatch_by_sonarr_block(&ActiveSonarrBlock::AddIndexerSelectImplementation)
+        .await;
+
+      assert!(app.is_loading);
+      assert_eq!(
+        sync_network_rx.recv().await.unwrap().network_event,
+        SonarrEvent::GetIndexerSchema.into()
+      );
+      assert_eq!(app.tick_count, 0);
+    }
+
     #[tokio::test]
     async fn test_dispatch_by_all_indexer_settings_block() {
       let (mut app, mut sync_network_rx) = construct_app_unit();
@@ -449,7 +636,7 @@ mod tests {
 
       assert!(app.is_loading);
       assert_eq!(
-        sync_network_rx.recv().await.unwrap(),
+        sync_network_rx.recv().await.unwrap().network_event,
         SonarrEvent::GetAllIndexerSettings.into()
       );
       assert!(!app.data.sonarr_data.prompt_confirm);
@@ -470,7 +657,7 @@ mod tests {
 
       assert!(app.is_loading);
       assert_eq!(
-        sync_network_rx.recv().await.unwrap(),
+        sync_network_rx.recv().await.unwrap().network_event,
         SonarrEvent::TestIndexer(1).into()
       );
       assert_eq!(app.tick_count, 0);
@@ -486,12 +673,123 @@ mod tests {
 
       assert!(app.is_loading);
       assert_eq!(
-        sync_network_rx.recv().await.unwrap(),
+        sync_network_rx.recv().await.unwrap().network_event,
         SonarrEvent::TestAllIndexers.into()
       );
       assert_eq!(app.tick_count, 0);
     }
 
+    #[tokio::test]
+    async fn test_dispatch_by_quality_profiles_block() {
+      let (mut app, mut sync_network_rx) = construct_app_unit();
+
+      app
+        .dispatch_by_sonarr_block(&ActiveSonarrBlock::QualityProfiles)
+        .await;
+
+      assert!(app.is_loading);
+      assert_eq!(
+        sync_network_rx.recv().await.unwrap().network_event,
+        SonarrEvent::GetQualityProfiles.into()
+      );
+      assert!(!app.data.sonarr_data.prompt_confirm);
+      assert_eq!(app.tick_count, 0);
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_by_custom_formats_block() {
+      let (mut app, mut sync_network_rx) = construct_app_unit();
+
+      app
+        .dispatch_by_sonarr_block(&ActiveSonarrBlock::CustomFormats)
+        .await;
+
+      assert!(app.is_loading);
+      assert_eq!(
+        sync_network_rx.recv().await.unwrap().network_event,
+        SonarrEvent::GetCustomFormats.into()
+      );
+      assert!(!app.data.sonarr_data.prompt_confirm);
+      assert_eq!(app.tick_count, 0);
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_by_download_clients_block() {
+      let (mut app, mut sync_network_rx) = construct_app_unit();
+
+      app
+        .dispatch_by_sonarr_block(&ActiveSonarrBlock::DownloadClients)
+        .await;
+
+      assert!(app.is_loading);
+      assert_eq!(
+        sync_network_rx.recv().await.unwrap().network_event,
+        SonarrEvent::GetTags.into()
+      );
+      assert_eq!(
+        sync_network_rx.recv().await.unwrap().network_event,
+        SonarrEvent::GetDownloadClients.into()
+      );
+      assert!(!app.data.sonarr_data.prompt_confirm);
+      assert_eq!(app.tick_count, 0);
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_by_add_download_client_select_implementation_block() {
+      let (mut app, mut sync_network_rx) = construct_app_unit();
+
+      app
+        .dispatch_by_sonarr_block(&ActiveSonarrBlock::AddDownloadClientSelectImplementation)
+        .await;
+
+      assert!(app.is_loading);
+      assert_eq!(
+        sync_network_rx.recv().await.unwrap().network_event,
+        SonarrEvent::GetDownloadClientSchema.into()
+      );
+      assert_eq!(app.tick_count, 0);
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_by_test_download_client_block() {
+      let (mut app, mut sync_network_rx) = construct_app_unit();
+      app
+        .data
+        .sonarr_data
+        .download_clients
+        .set_items(vec![DownloadClient {
+          id: 1,
+          ..DownloadClient::default()
+        }]);
+
+      app
+        .dispatch_by_sonarr_block(&ActiveSonarrBlock::TestDownloadClient)
+        .await;
+
+      assert!(app.is_loading);
+      assert_eq!(
+        sync_network_rx.recv().await.unwrap().network_event,
+        SonarrEvent::TestDownloadClient(1).into()
+      );
+      assert_eq!(app.tick_count, 0);
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_by_test_all_download_clients_block() {
+      let (mut app, mut sync_network_rx) = construct_app_unit();
+
+      app
+        .dispatch_by_sonarr_block(&ActiveSonarrBlock::TestAllDownloadClients)
+        .await;
+
+      assert!(app.is_loading);
+      assert_eq!(
+        sync_network_rx.recv().await.unwrap().network_event,
+        SonarrEvent::TestAllDownloadClients.into()
+      );
+      assert_eq!(app.tick_count, 0);
+    }
+
     #[tokio::test]
     async fn test_dispatch_by_system_block() {
       let (mut app, mut sync_network_rx) = construct_app_unit();
@@ -502,21 +800,38 @@ mod tests {
 
       assert!(app.is_loading);
       assert_eq!(
-        sync_network_rx.recv().await.unwrap(),
+        sync_network_rx.recv().await.unwrap().network_event,
         SonarrEvent::GetTasks.into()
       );
       assert_eq!(
-        sync_network_rx.recv().await.unwrap(),
+        sync_network_rx.recv().await.unwrap().network_event,
         SonarrEvent::GetQueuedEvents.into()
       );
       assert_eq!(
-        sync_network_rx.recv().await.unwrap(),
+        sync_network_rx.recv().await.unwrap().network_event,
         SonarrEvent::GetLogs(500).into()
       );
       assert!(!app.data.sonarr_data.prompt_confirm);
       assert_eq!(app.tick_count, 0);
     }
 
+    #[tokio::test]
+    async fn test_dispatch_by_system_backups_block() {
+      let (mut app, mut sync_network_rx) = construct_app_unit();
+
+      app
+        .dispatch_by_sonarr_block(&ActiveSonarrBlock::SystemBackups)
+        .await;
+
+      assert!(app.is_loading);
+      assert_eq!(
+        sync_network_rx.recv().await.unwrap().network_event,
+        SonarrEvent::GetBackups.into()
+      );
+      assert!(!app.data.sonarr_data.prompt_confirm);
+      assert_eq!(app.tick_count, 0);
+    }
+
     #[tokio::test]
     async fn test_dispatch_by_system_updates_block() {
       let (mut app, mut sync_network_rx) = construct_app_unit();
@@ -527,7 +842,7 @@ mod tests {
 
       assert!(app.is_loading);
       assert_eq!(
-        sync_network_rx.recv().await.unwrap(),
+        sync_network_rx.recv().await.unwrap().network_event,
         SonarrEvent::GetUpdates.into()
       );
       assert!(!app.data.sonarr_data.prompt_confirm);
@@ -545,7 +860,7 @@ mod tests {
 
       assert!(app.is_loading);
       assert_eq!(
-        sync_network_rx.recv().await.unwrap(),
+        sync_network_rx.recv().await.unwrap().network_event,
         SonarrEvent::SearchNewSeries("test search".into()).into()
       );
       assert!(!app.data.sonarr_data.prompt_confirm);
@@ -572,7 +887,7 @@ mod tests {
 
       assert!(!app.data.sonarr_data.prompt_confirm);
       assert_eq!(
-        sync_network_rx.recv().await.unwrap(),
+        sync_network_rx.recv().await.unwrap().network_event,
         SonarrEvent::GetStatus.into()
       );
       assert!(app.should_refresh);
@@ -587,31 +902,31 @@ mod tests {
       app.refresh_sonarr_metadata().await;
 
       assert_eq!(
-        sync_network_rx.recv().await.unwrap(),
+        sync_network_rx.recv().await.unwrap().network_event,
         SonarrEvent::GetQualityProfiles.into()
       );
       assert_eq!(
-        sync_network_rx.recv().await.unwrap(),
+        sync_network_rx.recv().await.unwrap().network_event,
         SonarrEvent::GetLanguageProfiles.into()
       );
       assert_eq!(
-        sync_network_rx.recv().await.unwrap(),
+        sync_network_rx.recv().await.unwrap().network_event,
         SonarrEvent::GetTags.into()
       );
       assert_eq!(
-        sync_network_rx.recv().await.unwrap(),
+        sync_network_rx.recv().await.unwrap().network_event,
         SonarrEvent::GetRootFolders.into()
       );
       assert_eq!(
-        sync_network_rx.recv().await.unwrap(),
+        sync_network_rx.recv().await.unwrap().network_event,
         SonarrEvent::GetDownloads(500).into()
       );
       assert_eq!(
-        sync_network_rx.recv().await.unwrap(),
+        sync_network_rx.recv().await.unwrap().network_event,
         SonarrEvent::GetDiskSpace.into()
       );
       assert_eq!(
-        sync_network_rx.recv().await.unwrap(),
+        sync_network_rx.recv().await.unwrap().network_event,
         SonarrEvent::GetStatus.into()
       );
       assert!(app.is_loading);
@@ -625,31 +940,31 @@ mod tests {
       app.sonarr_on_tick(ActiveSonarrBlock::Downloads).await;
 
       assert_eq!(
-        sync_network_rx.recv().await.unwrap(),
+        sync_network_rx.recv().await.unwrap().network_event,
         SonarrEvent::GetQualityProfiles.into()
       );
       assert_eq!(
-        sync_network_rx.recv().await.unwrap(),
+        sync_network_rx.recv().await.unwrap().network_event,
         SonarrEvent::GetLanguageProfiles.into()
       );
       assert_eq!(
-        sync_network_rx.recv().await.unwrap(),
+        sync_network_rx.recv().await.unwrap().network_event,
         SonarrEvent::GetTags.into()
       );
       assert_eq!(
-        sync_network_rx.recv().await.unwrap(),
+        sync_network_rx.recv().await.unwrap().network_event,
         SonarrEvent::GetRootFolders.into()
       );
       assert_eq!(
-        sync_network_rx.recv().await.unwrap(),
+        sync_network_rx.recv().await.unwrap().network_event,
         SonarrEvent::GetDownloads(500).into()
       );
       assert_eq!(
-        sync_network_rx.recv().await.unwrap(),
+        sync_network_rx.recv().await.unwrap().network_event,
         SonarrEvent::GetDiskSpace.into()
       );
       assert_eq!(
-        sync_network_rx.recv().await.unwrap(),
+        sync_network_rx.recv().await.unwrap().network_event,
         SonarrEvent::GetStatus.into()
       );
       assert!(app.is_loading);
@@ -666,7 +981,7 @@ mod tests {
       app.sonarr_on_tick(ActiveSonarrBlock::Downloads).await;
 
       assert_eq!(
-        sync_network_rx.recv().await.unwrap(),
+        sync_network_rx.recv().await.unwrap().network_event,
         SonarrEvent::GetDownloads(500).into()
       );
       assert!(!app.data.sonarr_data.prompt_confirm);
@@ -691,7 +1006,7 @@ mod tests {
       app.sonarr_on_tick(ActiveSonarrBlock::Downloads).await;
 
       assert_eq!(
-        sync_network_rx.recv().await.unwrap(),
+        sync_network_rx.recv().await.unwrap().network_event,
         SonarrEvent::GetDownloads(500).into()
       );
       assert!(app.should_refresh);
@@ -708,7 +1023,7 @@ mod tests {
       app.sonarr_on_tick(ActiveSonarrBlock::Downloads).await;
 
       assert_eq!(
-        sync_network_rx.recv().await.unwrap(),
+        sync_network_rx.recv().await.unwrap().network_event,
         SonarrEvent::GetDownloads(500).into()
       );
       assert!(app.is_loading);
@@ -726,23 +1041,23 @@ mod tests {
       app.sonarr_on_tick(ActiveSonarrBlock::Downloads).await;
 
       assert_eq!(
-        sync_network_rx.recv().await.unwrap(),
+        sync_network_rx.recv().await.unwrap().network_event,
         SonarrEvent::GetQualityProfiles.into()
       );
       assert_eq!(
-        sync_network_rx.recv().await.unwrap(),
+        sync_network_rx.recv().await.unwrap().network_event,
         SonarrEvent::GetLanguageProfiles.into()
       );
       assert_eq!(
-        sync_network_rx.recv().await.unwrap(),
+        sync_network_rx.recv().await.unwrap().network_event,
         SonarrEvent::GetTags.into()
       );
       assert_eq!(
-        sync_network_rx.recv().await.unwrap(),
+        sync_network_rx.recv().await.unwrap().network_event,
         SonarrEvent::GetRootFolders.into()
       );
       assert_eq!(
-        sync_network_rx.recv().await.unwrap(),
+        sync_network_rx.recv().await.unwrap().network_event,
         SonarrEvent::GetDownloads(500).into()
       );
       assert!(app.is_loading);
@@ -865,8 +1180,23 @@ mod tests {
       assert_eq!(app.extract_sonarr_indexer_id().await, 1);
     }
 
-    fn construct_app_unit<'a>() -> (App<'a>, mpsc::Receiver<NetworkEvent>) {
-      let (sync_network_tx, sync_network_rx) = mpsc::channel::<NetworkEvent>(500);
+    #[tokio::test]
+    async fn test_extract_sonarr_download_client_id() {
+      let mut app = App::test_default();
+      app
+        .data
+        .sonarr_data
+        .download_clients
+        .set_items(vec![DownloadClient {
+          id: 1,
+          ..DownloadClient::default()
+        }]);
+
+      assert_eq!(app.extract_sonarr_download_client_id().await, 1);
+    }
+
+    fn construct_app_unit<'a>() -> (App<'a>, mpsc::Receiver<QueuedNetworkEvent>) {
+      let (sync_network_tx, sync_network_rx) = mpsc::channel::<QueuedNetworkEvent>(500);
       let mut app = App {
         network_tx: Some(sync_network_tx),
         tick_count: 1,
@@ -2,10 +2,11 @@
 mod tests {
   use std::sync::Arc;
 
-  use clap::{CommandFactory, error::ErrorKind};
+  use bimap::BiMap;
+  use clap::{CommandFactory, Parser, error::ErrorKind};
   use mockall::predicate::eq;
   use rstest::rstest;
-  use serde_json::json;
+  use serde_json::{Number, json};
   use tokio::sync::Mutex;
 
   use crate::cli::lidarr::LidarrCommand;
@@ -13,7 +14,12 @@ mod tests {
   use crate::{
     Cli,
     app::App,
-    cli::{handle_command, mutex_flags_or_option, radarr::RadarrCommand, sonarr::SonarrCommand},
+    cli::{
+      Command, ConfigCommand, handle_command, mutex_flags_or_option,
+      output::{OutputFormat, OutputOptions},
+      radarr::{RadarrCommand, list_command_handler::RadarrListCommand},
+      sonarr::SonarrCommand,
+    },
     models::{
       Serdeable,
       lidarr_models::{
@@ -21,7 +27,7 @@ mod tests {
         LidarrSerdeable,
       },
       radarr_models::{
-        BlocklistItem as RadarrBlocklistItem, BlocklistResponse as RadarrBlocklistResponse,
+        BlocklistItem as RadarrBlocklistItem, BlocklistResponse as RadarrBlocklistResponse, Movie,
         RadarrSerdeable,
       },
       sonarr_models::{
@@ -35,6 +41,14 @@ mod tests {
   };
   use pretty_assertions::assert_eq;
 
+  #[rstest]
+  fn test_demo_conflicts_with_recordings(#[values("--record", "--replay")] flag: &str) {
+    let result = Cli::command().try_get_matches_from(["managarr", "--demo", flag, "recordings"]);
+
+    assert_err!(&result);
+    assert_eq!(result.unwrap_err().kind(), ErrorKind::ArgumentConflict);
+  }
+
   #[rstest]
   fn test_servarr_subcommand_requires_subcommand(#[values("radarr", "sonarr")] subcommand: &str) {
     let result = Cli::command().try_get_matches_from(["managarr", subcommand]);
@@ -68,6 +82,20 @@ mod tests {
     assert_ok!(&result);
   }
 
+  #[test]
+  fn test_prowlarr_subcommand_delegates_to_prowlarr() {
+    let result = Cli::command().try_get_matches_from(["managarr", "prowlarr", "list", "indexers"]);
+
+    assert_ok!(&result);
+  }
+
+  #[test]
+  fn test_readarr_subcommand_delegates_to_readarr() {
+    let result = Cli::command().try_get_matches_from(["managarr", "readarr", "list", "authors"]);
+
+    assert_ok!(&result);
+  }
+
   #[test]
   fn test_completions_requires_argument() {
     let result = Cli::command().try_get_matches_from(["managarr", "completions"]);
@@ -94,6 +122,33 @@ mod tests {
     assert_ok!(&result);
   }
 
+  #[test]
+  fn test_config_requires_subcommand() {
+    let result = Cli::command().try_get_matches_from(["managarr", "config"]);
+
+    assert_err!(&result);
+    assert_eq!(
+      result.unwrap_err().kind(),
+      ErrorKind::DisplayHelpOnMissingArgumentOrSubcommand
+    );
+  }
+
+  #[rstest]
+  fn test_config_validate(#[values(false, true)] json: bool) {
+    let mut args = vec!["managarr", "config", "validate"];
+    if json {
+      args.push("--json");
+    }
+
+    let result = Cli::try_parse_from(args);
+
+    assert_ok!(&result);
+    assert_eq!(
+      result.unwrap().command,
+      Some(Command::Config(ConfigCommand::Validate { json }))
+    );
+  }
+
   #[rstest]
   #[case(false, false, None)]
   #[case(false, true, Some(false))]
@@ -126,6 +181,59 @@ mod tests {
     assert_eq!(result, expected_output);
   }
 
+  #[rstest]
+  #[case("baseUrl=https://test.com", "baseUrl", "https://test.com")]
+  #[case(" apiKey =test=1234", "apiKey", "test=1234")]
+  #[case("seedCriteria.seedRatio=", "seedCriteria.seedRatio", "")]
+  fn test_parse_key_value(
+    #[case] arg: &str,
+    #[case] expected_key: &str,
+    #[case] expected_value: &str,
+  ) {
+    use crate::cli::parse_key_value;
+
+    let result = parse_key_value(arg);
+
+    assert_eq!(
+      result,
+      Ok((expected_key.to_owned(), expected_value.to_owned()))
+    );
+  }
+
+  #[rstest]
+  fn test_parse_key_value_invalid(#[values("baseUrl", "=test", "")] arg: &str) {
+    use crate::cli::parse_key_value;
+
+    let result = parse_key_value(arg);
+
+    assert_eq!(
+      result,
+      Err(format!("expected 'key=value', but got '{arg}'"))
+    );
+  }
+
+  #[rstest]
+  #[case(None, None, (2023, 5, 20), (2023, 5, 27))]
+  #[case(Some((2023, 5, 1)), None, (2023, 5, 1), (2023, 5, 8))]
+  #[case(None, Some((2023, 6, 1)), (2023, 5, 20), (2023, 6, 1))]
+  #[case(Some((2023, 5, 1)), Some((2023, 6, 1)), (2023, 5, 1), (2023, 6, 1))]
+  fn test_calendar_window_or_default(
+    #[case] start: Option<(i32, u32, u32)>,
+    #[case] end: Option<(i32, u32, u32)>,
+    #[case] expected_start: (i32, u32, u32),
+    #[case] expected_end: (i32, u32, u32),
+  ) {
+    use crate::cli::calendar_window_or_default;
+    use chrono::NaiveDate;
+
+    let date =
+      |(year, month, day): (i32, u32, u32)| NaiveDate::from_ymd_opt(year, month, day).unwrap();
+
+    let result = calendar_window_or_default(start.map(date), end.map(date), date((2023, 5, 20)));
+
+    assert_eq!(result, (date(expected_start), date(expected_end)));
+  }
+
   #[tokio::test]
   async fn test_cli_handler_delegates_radarr_commands_to_the_radarr_cli_handler() {
     let mut mock_network = MockNetworkTrait::new();
@@ -152,7 +260,13 @@ mod tests {
     let app_arc = Arc::new(Mutex::new(App::test_default()));
     let clear_blocklist_command = RadarrCommand::ClearBlocklist.into();
 
-    let result = handle_command(&app_arc, clear_blocklist_command, &mut mock_network).await;
+    let result = handle_command(
+      &app_arc,
+      clear_blocklist_command,
+      &mut mock_network,
+      &OutputOptions::default(),
+    )
+    .await;
 
     assert_ok!(&result);
   }
@@ -183,7 +297,13 @@ mod tests {
     let app_arc = Arc::new(Mutex::new(App::test_default()));
     let clear_blocklist_command = SonarrCommand::ClearBlocklist.into();
 
-    let result = handle_command(&app_arc, clear_blocklist_command, &mut mock_network).await;
+    let result = handle_command(
+      &app_arc,
+      clear_blocklist_command,
+      &mut mock_network,
+      &OutputOptions::default(),
+    )
+    .await;
 
     assert_ok!(&result);
   }
@@ -214,8 +334,60 @@ mod tests {
     let app_arc = Arc::new(Mutex::new(App::test_default()));
     let clear_blocklist_command = LidarrCommand::ClearBlocklist.into();
 
-    let result = handle_command(&app_arc, clear_blocklist_command, &mut mock_network).await;
+    let result = handle_command(
+      &app_arc,
+      clear_blocklist_command,
+      &mut mock_network,
+      &OutputOptions::default(),
+    )
+    .await;
 
     assert_ok!(&result);
   }
+
+  #[tokio::test]
+  async fn test_handle_command_resolves_library_table_ids_to_names() {
+    let mut mock_network = MockNetworkTrait::new();
+    mock_network
+      .expect_handle_network_event()
+      .with(eq::<NetworkEvent>(RadarrEvent::GetMovies.into()))
+      .times(1)
+      .returning(|_| {
+        Ok(Serdeable::Radarr(RadarrSerdeable::Movies(vec![Movie {
+          title: "Test".into(),
+          quality_profile_id: 1,
+          monitored: true,
+          tags: vec![Number::from(1)],
+          ..Movie::default()
+        }])))
+      });
+    for lookup_event in [RadarrEvent::GetQualityProfiles, RadarrEvent::GetTags] {
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(lookup_event.into()))
+        .times(1)
+        .returning(|_| Ok(Serdeable::Radarr(RadarrSerdeable::Value(json!([])))));
+    }
+    let mut app = App::test_default();
+    app.data.radarr_data.quality_profile_map = BiMap::from_iter([(1, "HD - 1080p".to_owned())]);
+    app.data.radarr_data.tags_map = BiMap::from_iter([(1, "alex".to_owned())]);
+    let app_arc = Arc::new(Mutex::new(app));
+    let output_options = OutputOptions {
+      format: OutputFormat::Csv,
+      fields: Vec::new(),
+    };
+
+    let result = handle_command(
+      &app_arc,
+      RadarrCommand::List(RadarrListCommand::Movies).into(),
+      &mut mock_network,
+      &output_options,
+    )
+    .await;
+
+    assert_eq!(
+      result.unwrap().lines().nth(1).unwrap(),
+      "Test,0,,0h 0m,,,0.00 GB,HD - 1080p,🏷,alex"
+    );
+  }
 }
@@ -7,10 +7,11 @@ use tokio::sync::Mutex;
 use super::LidarrCommand;
 use crate::{
   app::App,
-  cli::{CliCommandHandler, Command},
+  cli::{CliCommandHandler, Command, parse_key_value},
   models::lidarr_models::{
     AddArtistBody, AddArtistOptions, AddLidarrRootFolderBody, MonitorType, NewItemMonitorType,
   },
+  models::servarr_models::{AddDownloadClientParams, AddIndexerParams},
   network::{NetworkTrait, lidarr_network::LidarrEvent},
 };
 
@@ -77,6 +78,99 @@ pub enum LidarrAddCommand {
     )]
     no_search_for_missing_albums: bool,
   },
+  #[command(
+    about = "Add a new indexer using one of the implementations in the Lidarr indexer schema"
+  )]
+  Indexer {
+    #[arg(
+      long,
+      help = "The implementation of the indexer to add (e.g. 'Newznab' or 'Torznab')",
+      required = true
+    )]
+    implementation: String,
+    #[arg(long, help = "The name of the indexer", required = true)]
+    name: String,
+    #[arg(
+      long,
+      help = "Disable using this indexer when Lidarr periodically looks for releases via RSS Sync"
+    )]
+    disable_rss: bool,
+    #[arg(
+      long,
+      help = "Disable using this indexer whenever automatic searches are performed via the UI or by Lidarr"
+    )]
+    disable_automatic_search: bool,
+    #[arg(
+      long,
+      help = "Disable using this indexer whenever an interactive search is performed"
+    )]
+    disable_interactive_search: bool,
+    #[arg(
+      long,
+      help = "Indexer Priority from 1 (Highest) to 50 (Lowest)",
+      default_value_t = 25
+    )]
+    priority: i64,
+    #[arg(
+      long,
+      help = "Only use this indexer for artists with at least one matching tag ID. Leave blank to use with all artists.",
+      value_parser,
+      action = ArgAction::Append
+    )]
+    tag: Vec<i64>,
+    #[arg(
+      long,
+      help = "A setting of the indexer implementation in the form 'name=value' (e.g. 'baseUrl=https://indexer.example'). Can be specified multiple times",
+      value_parser = parse_key_value,
+      action = ArgAction::Append
+    )]
+    field: Vec<(String, String)>,
+  },
+  #[command(
+    about = "Add a new download client using one of the implementations in the Lidarr download client schema"
+  )]
+  DownloadClient {
+    #[arg(
+      long,
+      help = "The implementation of the download client to add (e.g. 'QBittorrent' or 'Sabnzbd')",
+      required = true
+    )]
+    implementation: String,
+    #[arg(long, help = "The name of the download client", required = true)]
+    name: String,
+    #[arg(long, help = "Add the download client without enabling it")]
+    disable: bool,
+    #[arg(
+      long,
+      help = "Download Client Priority from 1 (Highest) to 50 (Lowest). Round-Robin is used for clients with the same priority",
+      default_value_t = 1
+    )]
+    priority: i64,
+    #[arg(
+      long,
+      help = "Don't remove imported downloads from the download client history"
+    )]
+    disable_remove_completed_downloads: bool,
+    #[arg(
+      long,
+      help = "Don't remove failed downloads from the download client history"
+    )]
+    disable_remove_failed_downloads: bool,
+    #[arg(
+      long,
+      help = "Only use this download client for artists with at least one matching tag ID. Leave blank to use with all artists.",
+      value_parser,
+      action = ArgAction::Append
+    )]
+    tag: Vec<i64>,
+    #[arg(
+      long,
+      help = "A setting of the download client implementation in the form 'name=value' (e.g. 'host=localhost'). Can be specified multiple times",
+      value_parser = parse_key_value,
+      action = ArgAction::Append
+    )]
+    field: Vec<(String, String)>,
+  },
   #[command(about = "Add a new root folder")]
   RootFolder {
     #[arg(long, help = "The name of the root folder", required = true)]
@@ -184,6 +278,60 @@ impl<'a, 'b> CliCommandHandler<'a, 'b, LidarrAddCommand> for LidarrAddCommandHan
           .await?;
         serde_json::to_string_pretty(&resp)?
       }
+      LidarrAddCommand::Indexer {
+        implementation,
+        name,
+        disable_rss,
+        disable_automatic_search,
+        disable_interactive_search,
+        priority,
+        tag: tags,
+        field: fields,
+      } => {
+        let add_indexer_params = AddIndexerParams {
+          implementation,
+          name,
+          enable_rss: !disable_rss,
+          enable_automatic_search: !disable_automatic_search,
+          enable_interactive_search: !disable_interactive_search,
+          priority,
+          tags,
+          fields,
+          ..AddIndexerParams::default()
+        };
+        let resp = self
+          .network
+          .handle_network_event(LidarrEvent::AddIndexer(add_indexer_params).into())
+          .await?;
+        serde_json::to_string_pretty(&resp)?
+      }
+      LidarrAddCommand::DownloadClient {
+        implementation,
+        name,
+        disable,
+        priority,
+        disable_remove_completed_downloads,
+        disable_remove_failed_downloads,
+        tag: tags,
+        field: fields,
+      } => {
+        let add_download_client_params = AddDownloadClientParams {
+          implementation,
+          name,
+          enable: !disable,
+          priority,
+          remove_completed_downloads: !disable_remove_completed_downloads,
+          remove_failed_downloads: !disable_remove_failed_downloads,
+          tags,
+          tag_input_string: None,
+          fields,
+        };
+        let resp = self
+          .network
+          .handle_network_event(LidarrEvent::AddDownloadClient(add_download_client_params).into())
+          .await?;
+        serde_json::to_string_pretty(&resp)?
+      }
       LidarrAddCommand::RootFolder {
         name,
         root_folder_path,
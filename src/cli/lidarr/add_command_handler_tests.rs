@@ -27,6 +27,259 @@ mod tests {
     use super::*;
     use pretty_assertions::assert_eq;
 
+    #[test]
+    fn test_add_download_client_requires_arguments() {
+      let result =
+        Cli::command().try_get_matches_from(["managarr", "lidarr", "add", "download-client"]);
+
+      assert_err!(&result);
+      assert_eq!(
+        result.unwrap_err().kind(),
+        ErrorKind::MissingRequiredArgument
+      );
+    }
+
+    #[test]
+    fn test_add_download_client_requires_name() {
+      let result = Cli::command().try_get_matches_from([
+        "managarr",
+        "lidarr",
+        "add",
+        "download-client",
+        "--implementation",
+        "QBittorrent",
+      ]);
+
+      assert_err!(&result);
+      assert_eq!(
+        result.unwrap_err().kind(),
+        ErrorKind::MissingRequiredArgument
+      );
+    }
+
+    #[test]
+    fn test_add_download_client_field_requires_key_value_format() {
+      let result = Cli::command().try_get_matches_from([
+        "managarr",
+        "lidarr",
+        "add",
+        "download-client",
+        "--implementation",
+        "QBittorrent",
+        "--name",
+        "Test",
+        "--field",
+        "host",
+      ]);
+
+      assert_err!(&result);
+      assert_eq!(result.unwrap_err().kind(), ErrorKind::ValueValidation);
+    }
+
+    #[test]
+    fn test_add_download_client_defaults() {
+      let expected_args = LidarrAddCommand::DownloadClient {
+        implementation: "QBittorrent".to_owned(),
+        name: "Test".to_owned(),
+        disable: false,
+        priority: 1,
+        disable_remove_completed_downloads: false,
+        disable_remove_failed_downloads: false,
+        tag: vec![],
+        field: vec![],
+      };
+
+      let result = Cli::try_parse_from([
+        "managarr",
+        "lidarr",
+        "add",
+        "download-client",
+        "--implementation",
+        "QBittorrent",
+        "--name",
+        "Test",
+      ]);
+
+      assert_ok!(&result);
+      let Some(Command::Lidarr(LidarrCommand::Add(add_command))) = result.unwrap().command else {
+        panic!("Unexpected command type")
+      };
+      assert_eq!(add_command, expected_args);
+    }
+
+    #[test]
+    fn test_add_download_client_all_args() {
+      let expected_args = LidarrAddCommand::DownloadClient {
+        implementation: "QBittorrent".to_owned(),
+        name: "Test".to_owned(),
+        disable: true,
+        priority: 2,
+        disable_remove_completed_downloads: true,
+        disable_remove_failed_downloads: true,
+        tag: vec![1, 2],
+        field: vec![
+          ("host".to_owned(), "localhost".to_owned()),
+          ("port".to_owned(), "8080".to_owned()),
+        ],
+      };
+
+      let result = Cli::try_parse_from([
+        "managarr",
+        "lidarr",
+        "add",
+        "download-client",
+        "--implementation",
+        "QBittorrent",
+        "--name",
+        "Test",
+        "--disable",
+        "--priority",
+        "2",
+        "--disable-remove-completed-downloads",
+        "--disable-remove-failed-downloads",
+        "--tag",
+        "1",
+        "--tag",
+        "2",
+        "--field",
+        "host=localhost",
+        "--field",
+        "port=8080",
+      ]);
+
+      assert_ok!(&result);
+      let Some(Command::Lidarr(LidarrCommand::Add(add_command))) = result.unwrap().command else {
+        panic!("Unexpected command type")
+      };
+      assert_eq!(add_command, expected_args);
+    }
+
+    #[test]
+    fn test_add_indexer_requires_arguments() {
+      let result = Cli::command().try_get_matches_from(["managarr", "lidarr", "add", "indexer"]);
+
+      assert_err!(&result);
+      assert_eq!(
+        result.unwrap_err().kind(),
+        ErrorKind::MissingRequiredArgument
+      );
+    }
+
+    #[test]
+    fn test_add_indexer_requires_name() {
+      let result = Cli::command().try_get_matches_from([
+        "managarr",
+        "lidarr",
+        "add",
+        "indexer",
+        "--implementation",
+        "Torznab",
+      ]);
+
+      assert_err!(&result);
+      assert_eq!(
+        result.unwrap_err().kind(),
+        ErrorKind::MissingRequiredArgument
+      );
+    }
+
+    #[test]
+    fn test_add_indexer_field_requires_key_value_format() {
+      let result = Cli::command().try_get_matches_from([
+        "managarr",
+        "lidarr",
+        "add",
+        "indexer",
+        "--implementation",
+        "Torznab",
+        "--name",
+        "Test",
+        "--field",
+        "baseUrl",
+      ]);
+
+      assert_err!(&result);
+      assert_eq!(result.unwrap_err().kind(), ErrorKind::ValueValidation);
+    }
+
+    #[test]
+    fn test_add_indexer_defaults() {
+      let expected_args = LidarrAddCommand::Indexer {
+        implementation: "Torznab".to_owned(),
+        name: "Test".to_owned(),
+        disable_rss: false,
+        disable_automatic_search: false,
+        disable_interactive_search: false,
+        priority: 25,
+        tag: vec![],
+        field: vec![],
+      };
+
+      let result = Cli::try_parse_from([
+        "managarr",
+        "lidarr",
+        "add",
+        "indexer",
+        "--implementation",
+        "Torznab",
+        "--name",
+        "Test",
+      ]);
+
+      assert_ok!(&result);
+      let Some(Command::Lidarr(LidarrCommand::Add(add_command))) = result.unwrap().command else {
+        panic!("Unexpected command type")
+      };
+      assert_eq!(add_command, expected_args);
+    }
+
+    #[test]
+    fn test_add_indexer_all_args() {
+      let expected_args = LidarrAddCommand::Indexer {
+        implementation: "Torznab".to_owned(),
+        name: "Test".to_owned(),
+        disable_rss: true,
+        disable_automatic_search: true,
+        disable_interactive_search: true,
+        priority: 1,
+        tag: vec![1, 2],
+        field: vec![
+          ("baseUrl".to_owned(), "https://test.com".to_owned()),
+          ("apiKey".to_owned(), "test1234".to_owned()),
+        ],
+      };
+
+      let result = Cli::try_parse_from([
+        "managarr",
+        "lidarr",
+        "add",
+        "indexer",
+        "--implementation",
+        "Torznab",
+        "--name",
+        "Test",
+        "--disable-rss",
+        "--disable-automatic-search",
+        "--disable-interactive-search",
+        "--priority",
+        "1",
+        "--tag",
+        "1",
+        "--tag",
+        "2",
+        "--field",
+        "baseUrl=https://test.com",
+        "--field",
+        "apiKey=test1234",
+      ]);
+
+      assert_ok!(&result);
+      let Some(Command::Lidarr(LidarrCommand::Add(add_command))) = result.unwrap().command else {
+        panic!("Unexpected command type")
+      };
+      assert_eq!(add_command, expected_args);
+    }
+
     #[test]
     fn test_add_root_folder_requires_arguments() {
       let result =
@@ -420,6 +673,7 @@ mod tests {
   mod handler {
     use std::sync::Arc;
 
+    use crate::models::servarr_models::{AddDownloadClientParams, AddIndexerParams};
     use mockall::predicate::eq;
     use serde_json::json;
     use tokio::sync::Mutex;
@@ -437,6 +691,95 @@ mod tests {
       network::{MockNetworkTrait, NetworkEvent},
     };
 
+    #[tokio::test]
+    async fn test_handle_add_download_client_command() {
+      let expected_add_download_client_params = AddDownloadClientParams {
+        implementation: "QBittorrent".to_owned(),
+        name: "Test".to_owned(),
+        enable: false,
+        priority: 2,
+        remove_completed_downloads: true,
+        remove_failed_downloads: false,
+        tags: vec![1, 2],
+        tag_input_string: None,
+        fields: vec![("host".to_owned(), "localhost".to_owned())],
+      };
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(
+          LidarrEvent::AddDownloadClient(expected_add_download_client_params).into(),
+        ))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Lidarr(LidarrSerdeable::Value(
+            json!({"testResponse": "response"}),
+          )))
+        });
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+      let add_download_client_command = LidarrAddCommand::DownloadClient {
+        implementation: "QBittorrent".to_owned(),
+        name: "Test".to_owned(),
+        disable: true,
+        priority: 2,
+        disable_remove_completed_downloads: false,
+        disable_remove_failed_downloads: true,
+        tag: vec![1, 2],
+        field: vec![("host".to_owned(), "localhost".to_owned())],
+      };
+
+      let result =
+        LidarrAddCommandHandler::with(&app_arc, add_download_client_command, &mut mock_network)
+          .handle()
+          .await;
+
+      assert_ok!(&result);
+    }
+
+    #[tokio::test]
+    async fn test_handle_add_indexer_command() {
+      let expected_add_indexer_params = AddIndexerParams {
+        implementation: "Torznab".to_owned(),
+        name: "Test".to_owned(),
+        enable_rss: false,
+        enable_automatic_search: true,
+        enable_interactive_search: false,
+        priority: 1,
+        tags: vec![1, 2],
+        fields: vec![("baseUrl".to_owned(), "https://test.com".to_owned())],
+        ..AddIndexerParams::default()
+      };
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(
+          LidarrEvent::AddIndexer(expected_add_indexer_params).into(),
+        ))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Lidarr(LidarrSerdeable::Value(
+            json!({"testResponse": "response"}),
+          )))
+        });
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+      let add_indexer_command = LidarrAddCommand::Indexer {
+        implementation: "Torznab".to_owned(),
+        name: "Test".to_owned(),
+        disable_rss: true,
+        disable_automatic_search: false,
+        disable_interactive_search: true,
+        priority: 1,
+        tag: vec![1, 2],
+        field: vec![("baseUrl".to_owned(), "https://test.com".to_owned())],
+      };
+
+      let result = LidarrAddCommandHandler::with(&app_arc, add_indexer_command, &mut mock_network)
+        .handle()
+        .await;
+
+      assert_ok!(&result);
+    }
+
     #[tokio::test]
     async fn test_handle_add_root_folder_command() {
       let expected_root_folder_path = "/nfs/test".to_owned();
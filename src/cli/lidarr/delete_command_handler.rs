@@ -1,13 +1,16 @@
 use std::sync::Arc;
 
 use anyhow::Result;
-use clap::Subcommand;
+use clap::{ArgAction, Subcommand};
 use tokio::sync::Mutex;
 
 use crate::{
   app::App,
   cli::{CliCommandHandler, Command},
-  models::lidarr_models::DeleteParams,
+  models::{
+    lidarr_models::DeleteParams,
+    servarr_models::{BulkDeleteDownloadsParams, DeleteDownloadParams, QueueRemovalOptions},
+  },
   network::{NetworkTrait, lidarr_network::LidarrEvent},
 };
 
@@ -28,6 +31,11 @@ pub enum LidarrDeleteCommand {
     #[arg(long, help = "Add a list exclusion for this album")]
     add_list_exclusion: bool,
   },
+  #[command(about = "Delete the specified backup from your Lidarr instance")]
+  Backup {
+    #[arg(long, help = "The ID of the backup to delete", required = true)]
+    backup_id: i64,
+  },
   #[command(about = "Delete the specified item from the Lidarr blocklist")]
   BlocklistItem {
     #[arg(
@@ -51,10 +59,43 @@ pub enum LidarrDeleteCommand {
     #[arg(long, help = "Add a list exclusion for this artist")]
     add_list_exclusion: bool,
   },
-  #[command(about = "Delete the specified download")]
+  #[command(about = "Delete the specified download(s) from the Lidarr queue")]
   Download {
-    #[arg(long, help = "The ID of the download to delete", required = true)]
-    download_id: i64,
+    #[arg(
+      long,
+      help = "The ID of the download to delete. Repeat to delete multiple downloads at once",
+      required = true,
+      action = ArgAction::Append
+    )]
+    download_id: Vec<i64>,
+    #[arg(
+      long,
+      help = "Remove the download from the download client",
+      default_value_t = true,
+      action = ArgAction::Set
+    )]
+    remove_from_client: bool,
+    #[arg(long, help = "Add the release to the blocklist")]
+    blocklist: bool,
+    #[arg(
+      long,
+      help = "Don't search for a replacement release after blocklisting this one"
+    )]
+    skip_redownload: bool,
+    #[arg(
+      long,
+      help = "Change the download's category to the post-import category instead of removing it from the client"
+    )]
+    change_category: bool,
+  },
+  #[command(about = "Delete the download client with the given ID")]
+  DownloadClient {
+    #[arg(
+      long,
+      help = "The ID of the download client to delete",
+      required = true
+    )]
+    download_client_id: i64,
   },
   #[command(about = "Delete the indexer with the given ID")]
   Indexer {
@@ -116,6 +157,13 @@ impl<'a, 'b> CliCommandHandler<'a, 'b, LidarrDeleteCommand> for LidarrDeleteComm
           .await?;
         serde_json::to_string_pretty(&resp)?
       }
+      LidarrDeleteCommand::Backup { backup_id } => {
+        let resp = self
+          .network
+          .handle_network_event(LidarrEvent::DeleteBackup(backup_id).into())
+          .await?;
+        serde_json::to_string_pretty(&resp)?
+      }
       LidarrDeleteCommand::BlocklistItem { blocklist_item_id } => {
         let resp = self
           .network
@@ -146,10 +194,37 @@ impl<'a, 'b> CliCommandHandler<'a, 'b, LidarrDeleteCommand> for LidarrDeleteComm
           .await?;
         serde_json::to_string_pretty(&resp)?
       }
-      LidarrDeleteCommand::Download { download_id } => {
+      LidarrDeleteCommand::Download {
+        download_id,
+        remove_from_client,
+        blocklist,
+        skip_redownload,
+        change_category,
+      } => {
+        let removal_options = QueueRemovalOptions {
+          remove_from_client,
+          blocklist,
+          skip_redownload,
+          change_category,
+        };
+        let event = if let [id] = download_id[..] {
+          LidarrEvent::DeleteDownload(DeleteDownloadParams {
+            id,
+            removal_options,
+          })
+        } else {
+          LidarrEvent::BulkDeleteDownloads(BulkDeleteDownloadsParams {
+            ids: download_id,
+            removal_options,
+          })
+        };
+        let resp = self.network.handle_network_event(event.into()).await?;
+        serde_json::to_string_pretty(&resp)?
+      }
+      LidarrDeleteCommand::DownloadClient { download_client_id } => {
         let resp = self
           .network
-          .handle_network_event(LidarrEvent::DeleteDownload(download_id).into())
+          .handle_network_event(LidarrEvent::DeleteDownloadClient(download_client_id).into())
           .await?;
         serde_json::to_string_pretty(&resp)?
       }
@@ -6,11 +6,16 @@ use tokio::sync::Mutex;
 
 use super::LidarrCommand;
 use crate::models::Serdeable;
-use crate::models::lidarr_models::LidarrSerdeable;
-use crate::models::servarr_models::{EditIndexerParams, IndexerSettings};
+use crate::models::lidarr_models::{LidarrSerdeable, NamingConfig};
+use crate::models::servarr_models::{
+  EditDownloadClientParams, EditIndexerParams, EditQualityProfileParams, IndexerSettings,
+  MediaManagementConfig, MediaManagementSettings,
+};
 use crate::{
   app::App,
-  cli::{CliCommandHandler, Command, mutex_flags_or_option},
+  cli::{
+    CliCommandHandler, Command, mutex_flags_or_default, mutex_flags_or_option, parse_key_value,
+  },
   models::lidarr_models::{EditArtistParams, NewItemMonitorType},
   network::{NetworkTrait, lidarr_network::LidarrEvent},
 };
@@ -116,6 +121,89 @@ pub enum LidarrEditCommand {
     #[arg(long, help = "Clear all tags on this artist", conflicts_with = "tag")]
     clear_tags: bool,
   },
+  #[command(
+    about = "Edit preferences for the specified download client",
+    group(
+      ArgGroup::new("edit_download_client")
+      .args([
+        "name",
+        "enable",
+        "disable",
+        "priority",
+        "enable_remove_completed_downloads",
+        "disable_remove_completed_downloads",
+        "enable_remove_failed_downloads",
+        "disable_remove_failed_downloads",
+        "tag",
+        "clear_tags",
+        "field"
+      ]).required(true)
+      .multiple(true))
+  )]
+  DownloadClient {
+    #[arg(
+      long,
+      help = "The ID of the download client whose settings you wish to edit",
+      required = true
+    )]
+    download_client_id: i64,
+    #[arg(long, help = "The name of the download client")]
+    name: Option<String>,
+    #[arg(long, help = "Enable the download client", conflicts_with = "disable")]
+    enable: bool,
+    #[arg(long, help = "Disable the download client", conflicts_with = "enable")]
+    disable: bool,
+    #[arg(
+      long,
+      help = "Download Client Priority from 1 (Highest) to 50 (Lowest). Round-Robin is used for clients with the same priority"
+    )]
+    priority: Option<i64>,
+    #[arg(
+      long,
+      help = "Remove imported downloads from the download client history",
+      conflicts_with = "disable_remove_completed_downloads"
+    )]
+    enable_remove_completed_downloads: bool,
+    #[arg(
+      long,
+      help = "Don't remove imported downloads from the download client history",
+      conflicts_with = "enable_remove_completed_downloads"
+    )]
+    disable_remove_completed_downloads: bool,
+    #[arg(
+      long,
+      help = "Remove failed downloads from the download client history",
+      conflicts_with = "disable_remove_failed_downloads"
+    )]
+    enable_remove_failed_downloads: bool,
+    #[arg(
+      long,
+      help = "Don't remove failed downloads from the download client history",
+      conflicts_with = "enable_remove_failed_downloads"
+    )]
+    disable_remove_failed_downloads: bool,
+    #[arg(
+      long,
+      help = "Only use this download client for artists with at least one matching tag ID. Leave blank to use with all artists.",
+      value_parser,
+      action = ArgAction::Append,
+      conflicts_with = "clear_tags"
+    )]
+    tag: Option<Vec<i64>>,
+    #[arg(
+      long,
+      help = "Clear all tags on this download client",
+      conflicts_with = "tag"
+    )]
+    clear_tags: bool,
+    #[arg(
+      long,
+      help = "A setting of the download client implementation in the form 'name=value' (e.g. 'host=localhost'). Can be specified multiple times",
+      value_parser = parse_key_value,
+      action = ArgAction::Append
+    )]
+    field: Vec<(String, String)>,
+  },
   #[command(
     about = "Edit preferences for the specified indexer",
     group(
@@ -207,6 +295,184 @@ pub enum LidarrEditCommand {
     #[arg(long, help = "Clear all tags on this indexer", conflicts_with = "tag")]
     clear_tags: bool,
   },
+  #[command(
+    about = "Edit the file naming and media management settings",
+    group(
+      ArgGroup::new("edit_media_management_settings")
+      .args([
+        "rename_tracks",
+        "disable_rename_tracks",
+        "replace_illegal_characters",
+        "disable_replace_illegal_characters",
+        "standard_track_format",
+        "multi_disc_track_format",
+        "artist_folder_format",
+        "recycle_bin",
+        "recycle_bin_cleanup_days",
+        "set_permissions",
+        "disable_set_permissions",
+        "chmod_folder",
+        "chown_group",
+        "import_extra_files",
+        "disable_import_extra_files",
+        "extra_file_extensions"
+      ]).required(true)
+      .multiple(true))
+  )]
+  MediaManagementSettings {
+    #[arg(
+      long,
+      help = "Rename track files using the configured track formats",
+      conflicts_with = "disable_rename_tracks"
+    )]
+    rename_tracks: bool,
+    #[arg(
+      long,
+      help = "Keep the existing file names of tracks when importing them",
+      conflicts_with = "rename_tracks"
+    )]
+    disable_rename_tracks: bool,
+    #[arg(
+      long,
+      help = "Replace illegal characters in file names instead of removing them",
+      conflicts_with = "disable_replace_illegal_characters"
+    )]
+    replace_illegal_characters: bool,
+    #[arg(
+      long,
+      help = "Remove illegal characters from file names instead of replacing them",
+      conflicts_with = "replace_illegal_characters"
+    )]
+    disable_replace_illegal_characters: bool,
+    #[arg(
+      long,
+      help = "The naming format for single disc track files (e.g. '{Album Title} ({Release Year})/{Artist Name} - {Album Title} - {track:00} - {Track Title}')"
+    )]
+    standard_track_format: Option<String>,
+    #[arg(
+      long,
+      help = "The naming format for multi disc track files (e.g. '{Album Title} ({Release Year})/{Medium Format} {medium:00}/{Artist Name} - {Album Title} - {track:00} - {Track Title}')"
+    )]
+    multi_disc_track_format: Option<String>,
+    #[arg(
+      long,
+      help = "The naming format for artist folders (e.g. '{Artist Name}')"
+    )]
+    artist_folder_format: Option<String>,
+    #[arg(
+      long,
+      help = "The folder deleted files are moved to instead of being permanently deleted"
+    )]
+    recycle_bin: Option<String>,
+    #[arg(
+      long,
+      help = "The number of days after which files in the recycle bin are deleted. Set to zero to disable automatic cleanup"
+    )]
+    recycle_bin_cleanup_days: Option<i64>,
+    #[arg(
+      long,
+      help = "Set the permissions of imported files and folders",
+      conflicts_with = "disable_set_permissions"
+    )]
+    set_permissions: bool,
+    #[arg(
+      long,
+      help = "Leave the permissions of imported files and folders unchanged",
+      conflicts_with = "set_permissions"
+    )]
+    disable_set_permissions: bool,
+    #[arg(
+      long,
+      help = "The octal permissions set on media folders and files when importing (e.g. '755')"
+    )]
+    chmod_folder: Option<String>,
+    #[arg(long, help = "The group name or GID to set on imported files")]
+    chown_group: Option<String>,
+    #[arg(
+      long,
+      help = "Import matching extra files (subtitles, nfo, etc.) alongside track files",
+      conflicts_with = "disable_import_extra_files"
+    )]
+    import_extra_files: bool,
+    #[arg(
+      long,
+      help = "Disable importing extra files alongside track files",
+      conflicts_with = "import_extra_files"
+    )]
+    disable_import_extra_files: bool,
+    #[arg(
+      long,
+      help = "A comma separated list of the extra file extensions to import (e.g. 'srt,nfo')"
+    )]
+    extra_file_extensions: Option<String>,
+  },
+  #[command(
+    about = "Edit the specified quality profile",
+    group(
+      ArgGroup::new("edit_quality_profile")
+      .args([
+        "name",
+        "enable_upgrades",
+        "disable_upgrades",
+        "cutoff",
+        "min_format_score",
+        "cutoff_format_score",
+        "allow_quality",
+        "disallow_quality"
+      ]).required(true)
+      .multiple(true))
+  )]
+  QualityProfile {
+    #[arg(
+      long,
+      help = "The ID of the quality profile you wish to edit",
+      required = true
+    )]
+    quality_profile_id: i64,
+    #[arg(long, help = "The name of the quality profile")]
+    name: Option<String>,
+    #[arg(
+      long,
+      help = "Allow Lidarr to upgrade releases until the cutoff is reached",
+      conflicts_with = "disable_upgrades"
+    )]
+    enable_upgrades: bool,
+    #[arg(
+      long,
+      help = "Don't upgrade releases once one of the allowed qualities has been downloaded",
+      conflicts_with = "enable_upgrades"
+    )]
+    disable_upgrades: bool,
+    #[arg(
+      long,
+      help = "The name of the allowed quality or quality group to upgrade until (e.g. 'Bluray-1080p')"
+    )]
+    cutoff: Option<String>,
+    #[arg(
+      long,
+      help = "The minimum custom format score a release needs to be downloaded",
+      allow_negative_numbers = true
+    )]
+    min_format_score: Option<i64>,
+    #[arg(
+      long,
+      help = "Stop upgrading releases once this custom format score is reached",
+      allow_negative_numbers = true
+    )]
+    cutoff_format_score: Option<i64>,
+    #[arg(
+      long,
+      help = "The name of a quality or quality group to allow in this profile. Can be specified multiple times",
+      action = ArgAction::Append
+    )]
+    allow_quality: Vec<String>,
+    #[arg(
+      long,
+      help = "The name of a quality or quality group to no longer allow in this profile. Can be specified multiple times",
+      action = ArgAction::Append
+    )]
+    disallow_quality: Vec<String>,
+  },
 }
 
 impl From<LidarrEditCommand> for Command {
@@ -294,6 +560,45 @@ impl<'a, 'b> CliCommandHandler<'a, 'b, LidarrEditCommand> for LidarrEditCommandH
           .await?;
         "Artist Updated".to_owned()
       }
+      LidarrEditCommand::DownloadClient {
+        download_client_id,
+        name,
+        enable,
+        disable,
+        priority,
+        enable_remove_completed_downloads,
+        disable_remove_completed_downloads,
+        enable_remove_failed_downloads,
+        disable_remove_failed_downloads,
+        tag,
+        clear_tags,
+        field: fields,
+      } => {
+        let edit_download_client_params = EditDownloadClientParams {
+          download_client_id,
+          name,
+          enable: mutex_flags_or_option(enable, disable),
+          priority,
+          remove_completed_downloads: mutex_flags_or_option(
+            enable_remove_completed_downloads,
+            disable_remove_completed_downloads,
+          ),
+          remove_failed_downloads: mutex_flags_or_option(
+            enable_remove_failed_downloads,
+            disable_remove_failed_downloads,
+          ),
+          tags: tag,
+          tag_input_string: None,
+          clear_tags,
+          fields,
+        };
+
+        self
+          .network
+          .handle_network_event(LidarrEvent::EditDownloadClient(edit_download_client_params).into())
+          .await?;
+        "Download client updated".to_owned()
+      }
       LidarrEditCommand::Indexer {
         indexer_id,
         name,
@@ -336,6 +641,109 @@ impl<'a, 'b> CliCommandHandler<'a, 'b, LidarrEditCommand> for LidarrEditCommandH
           .await?;
         "Indexer updated".to_owned()
       }
+      LidarrEditCommand::MediaManagementSettings {
+        rename_tracks,
+        disable_rename_tracks,
+        replace_illegal_characters,
+        disable_replace_illegal_characters,
+        standard_track_format,
+        multi_disc_track_format,
+        artist_folder_format,
+        recycle_bin,
+        recycle_bin_cleanup_days,
+        set_permissions,
+        disable_set_permissions,
+        chmod_folder,
+        chown_group,
+        import_extra_files,
+        disable_import_extra_files,
+        extra_file_extensions,
+      } => {
+        if let Serdeable::Lidarr(LidarrSerdeable::MediaManagementSettings(previous_settings)) = self
+          .network
+          .handle_network_event(LidarrEvent::GetMediaManagementSettings.into())
+          .await?
+        {
+          let MediaManagementSettings {
+            naming,
+            media_management,
+          } = previous_settings;
+          let params = MediaManagementSettings {
+            naming: NamingConfig {
+              rename_tracks: mutex_flags_or_default(
+                rename_tracks,
+                disable_rename_tracks,
+                naming.rename_tracks,
+              ),
+              replace_illegal_characters: mutex_flags_or_default(
+                replace_illegal_characters,
+                disable_replace_illegal_characters,
+                naming.replace_illegal_characters,
+              ),
+              standard_track_format: standard_track_format.or(naming.standard_track_format),
+              multi_disc_track_format: multi_disc_track_format.or(naming.multi_disc_track_format),
+              artist_folder_format: artist_folder_format.or(naming.artist_folder_format),
+              ..naming
+            },
+            media_management: MediaManagementConfig {
+              recycle_bin: recycle_bin.or(media_management.recycle_bin),
+              recycle_bin_cleanup_days: recycle_bin_cleanup_days
+                .unwrap_or(media_management.recycle_bin_cleanup_days),
+              set_permissions_linux: mutex_flags_or_default(
+                set_permissions,
+                disable_set_permissions,
+                media_management.set_permissions_linux,
+              ),
+              chmod_folder: chmod_folder.or(media_management.chmod_folder),
+              chown_group: chown_group.or(media_management.chown_group),
+              import_extra_files: mutex_flags_or_default(
+                import_extra_files,
+                disable_import_extra_files,
+                media_management.import_extra_files,
+              ),
+              extra_file_extensions: extra_file_extensions
+                .or(media_management.extra_file_extensions),
+              ..media_management
+            },
+          };
+          self
+            .network
+            .handle_network_event(LidarrEvent::EditMediaManagementSettings(params).into())
+            .await?;
+          "Media management settings updated".to_owned()
+        } else {
+          String::new()
+        }
+      }
+      LidarrEditCommand::QualityProfile {
+        quality_profile_id,
+        name,
+        enable_upgrades,
+        disable_upgrades,
+        cutoff,
+        min_format_score,
+        cutoff_format_score,
+        allow_quality,
+        disallow_quality,
+      } => {
+        let edit_quality_profile_params = EditQualityProfileParams {
+          quality_profile_id,
+          name,
+          upgrade_allowed: mutex_flags_or_option(enable_upgrades, disable_upgrades),
+          cutoff,
+          min_format_score,
+          cutoff_format_score,
+          items: None,
+          allowed_qualities: allow_quality,
+          disallowed_qualities: disallow_quality,
+        };
+
+        self
+          .network
+          .handle_network_event(LidarrEvent::EditQualityProfile(edit_quality_profile_params).into())
+          .await?;
+        "Quality profile updated".to_owned()
+      }
     };
 
     Ok(result)
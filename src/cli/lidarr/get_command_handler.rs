@@ -40,6 +40,19 @@ pub enum LidarrGetCommand {
   },
   #[command(about = "Fetch the host config for your Lidarr instance")]
   HostConfig,
+  #[command(about = "Get the file naming and media management settings")]
+  MediaManagementSettings,
+  #[command(
+    about = "Get the qualities, cutoff and format scores of the quality profile with the given ID"
+  )]
+  QualityProfileDetails {
+    #[arg(
+      long,
+      help = "The Lidarr ID of the quality profile whose details you wish to fetch",
+      required = true
+    )]
+    quality_profile_id: i64,
+  },
   #[command(about = "Fetch the security config for your Lidarr instance")]
   SecurityConfig,
   #[command(about = "Get the system status")]
@@ -110,6 +123,20 @@ impl<'a, 'b> CliCommandHandler<'a, 'b, LidarrGetCommand> for LidarrGetCommandHan
           .await?;
         serde_json::to_string_pretty(&resp)?
       }
+      LidarrGetCommand::MediaManagementSettings => {
+        let resp = self
+          .network
+          .handle_network_event(LidarrEvent::GetMediaManagementSettings.into())
+          .await?;
+        serde_json::to_string_pretty(&resp)?
+      }
+      LidarrGetCommand::QualityProfileDetails { quality_profile_id } => {
+        let resp = self
+          .network
+          .handle_network_event(LidarrEvent::GetQualityProfileDetails(quality_profile_id).into())
+          .await?;
+        serde_json::to_string_pretty(&resp)?
+      }
       LidarrGetCommand::SecurityConfig => {
         let resp = self
           .network
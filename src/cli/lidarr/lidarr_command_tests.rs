@@ -159,6 +159,101 @@ mod tests {
       assert_ok!(&result);
     }
 
+    #[test]
+    fn test_manual_import_requires_download_id() {
+      let result = Cli::command().try_get_matches_from(["managarr", "lidarr", "manual-import"]);
+
+      assert_err!(&result);
+      assert_eq!(
+        result.unwrap_err().kind(),
+        ErrorKind::MissingRequiredArgument
+      );
+    }
+
+    #[rstest]
+    fn test_manual_import_requirements_satisfied(
+      #[values(
+        vec!["--download-id", "abc123"],
+        vec!["--download-id", "abc123", "--artist-id", "1"]
+      )]
+      args: Vec<&str>,
+    ) {
+      let mut command = vec!["managarr", "lidarr", "manual-import"];
+      command.extend(args);
+
+      let result = Cli::command().try_get_matches_from(command);
+
+      assert_ok!(&result);
+    }
+
+    #[test]
+    fn test_download_backup_requires_id() {
+      let result = Cli::command().try_get_matches_from([
+        "managarr",
+        "lidarr",
+        "download-backup",
+        "--dest",
+        "/tmp",
+      ]);
+
+      assert_err!(&result);
+      assert_eq!(
+        result.unwrap_err().kind(),
+        ErrorKind::MissingRequiredArgument
+      );
+    }
+
+    #[test]
+    fn test_download_backup_requires_dest() {
+      let result =
+        Cli::command().try_get_matches_from(["managarr", "lidarr", "download-backup", "--id", "1"]);
+
+      assert_err!(&result);
+      assert_eq!(
+        result.unwrap_err().kind(),
+        ErrorKind::MissingRequiredArgument
+      );
+    }
+
+    #[test]
+    fn test_download_backup_requirements_satisfied() {
+      let result = Cli::command().try_get_matches_from([
+        "managarr",
+        "lidarr",
+        "download-backup",
+        "--id",
+        "1",
+        "--dest",
+        "/tmp",
+      ]);
+
+      assert_ok!(&result);
+    }
+
+    #[test]
+    fn test_restore_backup_requires_file() {
+      let result = Cli::command().try_get_matches_from(["managarr", "lidarr", "restore-backup"]);
+
+      assert_err!(&result);
+      assert_eq!(
+        result.unwrap_err().kind(),
+        ErrorKind::MissingRequiredArgument
+      );
+    }
+
+    #[test]
+    fn test_restore_backup_requirements_satisfied() {
+      let result = Cli::command().try_get_matches_from([
+        "managarr",
+        "lidarr",
+        "restore-backup",
+        "--file",
+        "/tmp/backup.zip",
+      ]);
+
+      assert_ok!(&result);
+    }
+
     #[test]
     fn test_search_new_artist_requires_query() {
       let result = Cli::command().try_get_matches_from(["managarr", "lidarr", "search-new-artist"]);
@@ -246,6 +341,55 @@ mod tests {
       assert_ok!(&result);
     }
 
+    #[test]
+    fn test_grab_queue_item_requires_queue_item_id() {
+      let result = Cli::command().try_get_matches_from(["managarr", "lidarr", "grab-queue-item"]);
+
+      assert_err!(&result);
+      assert_eq!(
+        result.unwrap_err().kind(),
+        ErrorKind::MissingRequiredArgument
+      );
+    }
+
+    #[test]
+    fn test_grab_queue_item_requirements_satisfied() {
+      let result = Cli::command().try_get_matches_from([
+        "managarr",
+        "lidarr",
+        "grab-queue-item",
+        "--queue-item-id",
+        "1",
+      ]);
+
+      assert_ok!(&result);
+    }
+
+    #[test]
+    fn test_test_download_client_requires_download_client_id() {
+      let result =
+        Cli::command().try_get_matches_from(["managarr", "lidarr", "test-download-client"]);
+
+      assert_err!(&result);
+      assert_eq!(
+        result.unwrap_err().kind(),
+        ErrorKind::MissingRequiredArgument
+      );
+    }
+
+    #[test]
+    fn test_test_download_client_requirements_satisfied() {
+      let result = Cli::command().try_get_matches_from([
+        "managarr",
+        "lidarr",
+        "test-download-client",
+        "--download-client-id",
+        "1",
+      ]);
+
+      assert_ok!(&result);
+    }
+
     #[test]
     fn test_test_indexer_requires_indexer_id() {
       let result = Cli::command().try_get_matches_from(["managarr", "lidarr", "test-indexer"]);
@@ -272,8 +416,12 @@ mod tests {
   }
 
   mod handler {
+    use std::path::PathBuf;
     use std::sync::Arc;
 
+    use crate::models::servarr_models::DownloadBackupParams;
+    use crate::network::servarr_test_utils::backup;
+
     use mockall::predicate::eq;
     use serde_json::json;
     use tokio::sync::Mutex;
@@ -285,9 +433,13 @@ mod tests {
     use crate::cli::lidarr::refresh_command_handler::LidarrRefreshCommand;
     use crate::cli::lidarr::trigger_automatic_search_command_handler::LidarrTriggerAutomaticSearchCommand;
     use crate::models::lidarr_models::{
-      BlocklistItem, BlocklistResponse, LidarrReleaseDownloadBody, LidarrTaskName,
+      BlocklistItem, BlocklistResponse, LidarrManualImportItem, LidarrReleaseDownloadBody,
+      LidarrTaskName,
     };
     use crate::models::servarr_models::IndexerSettings;
+    use crate::network::lidarr_network::lidarr_network_test_utils::test_utils::{
+      manual_import_file, manual_import_item,
+    };
     use crate::{
       app::App,
       cli::{
@@ -640,6 +792,186 @@ mod tests {
       assert_ok!(&result);
     }
 
+    #[tokio::test]
+    async fn test_manual_import_command() {
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(
+          LidarrEvent::GetManualImport("abc123".to_owned(), Some(1)).into(),
+        ))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Lidarr(LidarrSerdeable::ManualImportItems(vec![
+            manual_import_item(),
+          ])))
+        });
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(
+          LidarrEvent::ManualImport(vec![manual_import_file()]).into(),
+        ))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Lidarr(LidarrSerdeable::Value(
+            json!({"testResponse": "response"}),
+          )))
+        });
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+      let manual_import_command = LidarrCommand::ManualImport {
+        download_id: "abc123".to_owned(),
+        artist_id: Some(1),
+      };
+
+      let result = LidarrCliHandler::with(&app_arc, manual_import_command, &mut mock_network)
+        .handle()
+        .await;
+
+      assert_ok!(&result);
+    }
+
+    #[tokio::test]
+    async fn test_manual_import_command_no_op_when_nothing_is_importable() {
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(
+          LidarrEvent::GetManualImport("abc123".to_owned(), None).into(),
+        ))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Lidarr(LidarrSerdeable::ManualImportItems(vec![
+            LidarrManualImportItem::default(),
+          ])))
+        });
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+      let manual_import_command = LidarrCommand::ManualImport {
+        download_id: "abc123".to_owned(),
+        artist_id: None,
+      };
+
+      let result = LidarrCliHandler::with(&app_arc, manual_import_command, &mut mock_network)
+        .handle()
+        .await;
+
+      assert_ok!(&result);
+      assert!(
+        result
+          .unwrap()
+          .contains("No files without rejections were found to import")
+      );
+    }
+
+    #[tokio::test]
+    async fn test_download_backup_command() {
+      let dest = std::env::temp_dir();
+      let expected_params = DownloadBackupParams {
+        backup_path: backup().path,
+        destination: dest.join(backup().name),
+      };
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(LidarrEvent::GetBackups.into()))
+        .times(1)
+        .returning(|_| Ok(Serdeable::Lidarr(LidarrSerdeable::Backups(vec![backup()]))));
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(
+          LidarrEvent::DownloadBackup(expected_params).into(),
+        ))
+        .times(1)
+        .returning(|_| Ok(Serdeable::Lidarr(LidarrSerdeable::Value(json!({})))));
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+      let download_backup_command = LidarrCommand::DownloadBackup { id: 1, dest };
+
+      let result = LidarrCliHandler::with(&app_arc, download_backup_command, &mut mock_network)
+        .handle()
+        .await;
+
+      assert_ok!(&result);
+      assert!(result.unwrap().contains("Backup downloaded to"));
+    }
+
+    #[tokio::test]
+    async fn test_download_backup_command_to_file() {
+      let dest = std::env::temp_dir().join("lidarr_backup.zip");
+      let expected_params = DownloadBackupParams {
+        backup_path: backup().path,
+        destination: dest.clone(),
+      };
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(LidarrEvent::GetBackups.into()))
+        .times(1)
+        .returning(|_| Ok(Serdeable::Lidarr(LidarrSerdeable::Backups(vec![backup()]))));
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(
+          LidarrEvent::DownloadBackup(expected_params).into(),
+        ))
+        .times(1)
+        .returning(|_| Ok(Serdeable::Lidarr(LidarrSerdeable::Value(json!({})))));
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+      let download_backup_command = LidarrCommand::DownloadBackup { id: 1, dest };
+
+      let result = LidarrCliHandler::with(&app_arc, download_backup_command, &mut mock_network)
+        .handle()
+        .await;
+
+      assert_ok!(&result);
+    }
+
+    #[tokio::test]
+    async fn test_download_backup_command_unknown_backup_id() {
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(LidarrEvent::GetBackups.into()))
+        .times(1)
+        .returning(|_| Ok(Serdeable::Lidarr(LidarrSerdeable::Backups(vec![backup()]))));
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+      let download_backup_command = LidarrCommand::DownloadBackup {
+        id: 2,
+        dest: std::env::temp_dir(),
+      };
+
+      let result = LidarrCliHandler::with(&app_arc, download_backup_command, &mut mock_network)
+        .handle()
+        .await;
+
+      assert_err!(&result);
+      assert_eq!(result.unwrap_err().to_string(), "No backup found with ID 2");
+    }
+
+    #[tokio::test]
+    async fn test_restore_backup_command() {
+      let expected_file = PathBuf::from("/tmp/backup.zip");
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(
+          LidarrEvent::RestoreBackup(expected_file).into(),
+        ))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Lidarr(LidarrSerdeable::Value(
+            json!({"testResponse": "response"}),
+          )))
+        });
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+      let restore_backup_command = LidarrCommand::RestoreBackup {
+        file: PathBuf::from("/tmp/backup.zip"),
+      };
+
+      let result = LidarrCliHandler::with(&app_arc, restore_backup_command, &mut mock_network)
+        .handle()
+        .await;
+
+      assert_ok!(&result);
+    }
+
     #[tokio::test]
     async fn test_search_new_artist_command() {
       let expected_query = "test artist".to_owned();
@@ -694,6 +1026,62 @@ mod tests {
       assert_ok!(&result);
     }
 
+    #[tokio::test]
+    async fn test_test_download_client_command() {
+      let expected_download_client_id = 1;
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(
+          LidarrEvent::TestDownloadClient(expected_download_client_id).into(),
+        ))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Lidarr(LidarrSerdeable::Value(
+            json!({"testResponse": "response"}),
+          )))
+        });
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+      let test_download_client_command = LidarrCommand::TestDownloadClient {
+        download_client_id: 1,
+      };
+
+      let result =
+        LidarrCliHandler::with(&app_arc, test_download_client_command, &mut mock_network)
+          .handle()
+          .await;
+
+      assert_ok!(&result);
+    }
+
+    #[tokio::test]
+    async fn test_test_all_download_clients_command() {
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(
+          LidarrEvent::TestAllDownloadClients.into(),
+        ))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Lidarr(LidarrSerdeable::Value(
+            json!({"testResponse": "response"}),
+          )))
+        });
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+      let test_all_download_clients_command = LidarrCommand::TestAllDownloadClients;
+
+      let result = LidarrCliHandler::with(
+        &app_arc,
+        test_all_download_clients_command,
+        &mut mock_network,
+      )
+      .handle()
+      .await;
+
+      assert_ok!(&result);
+    }
+
     #[tokio::test]
     async fn test_test_indexer_command() {
       let expected_indexer_id = 1;
@@ -771,5 +1159,27 @@ mod tests {
 
       assert_ok!(&result);
     }
+
+    #[tokio::test]
+    async fn test_grab_queue_item_command() {
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(LidarrEvent::GrabQueueItem(1).into()))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Lidarr(LidarrSerdeable::Value(
+            json!({"testResponse": "response"}),
+          )))
+        });
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+      let grab_queue_item_command = LidarrCommand::GrabQueueItem { queue_item_id: 1 };
+
+      let result = LidarrCliHandler::with(&app_arc, grab_queue_item_command, &mut mock_network)
+        .handle()
+        .await;
+
+      assert_ok!(&result);
+    }
   }
 }
@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use anyhow::Result;
+use chrono::{Local, NaiveDate};
 use clap::Subcommand;
 use serde_json::json;
 use tokio::sync::Mutex;
@@ -10,7 +11,7 @@ use crate::models::Serdeable;
 use crate::models::lidarr_models::{LidarrHistoryItem, LidarrSerdeable};
 use crate::{
   app::App,
-  cli::{CliCommandHandler, Command},
+  cli::{CliCommandHandler, Command, calendar_window_or_default},
   network::{NetworkTrait, lidarr_network::LidarrEvent},
 };
 
@@ -57,8 +58,28 @@ pub enum LidarrListCommand {
   },
   #[command(about = "List all artists in your Lidarr library")]
   Artists,
+  #[command(about = "List all backups for your Lidarr instance")]
+  Backups,
   #[command(about = "List all items in the Lidarr blocklist")]
   Blocklist,
+  #[command(about = "List the albums releasing in the given date range on the Lidarr calendar")]
+  Calendar {
+    #[arg(
+      long,
+      help = "The first date (YYYY-MM-DD) to include in the calendar. Defaults to today"
+    )]
+    start: Option<NaiveDate>,
+    #[arg(
+      long,
+      help = "The date (YYYY-MM-DD) to end the calendar on. Defaults to 7 days after the start date"
+    )]
+    end: Option<NaiveDate>,
+  },
+  #[command(about = "List the monitored albums that have not met their quality cutoff")]
+  CutoffUnmet {
+    #[arg(long, help = "How many albums to fetch", default_value_t = 500)]
+    count: u64,
+  },
   #[command(about = "List disk space details for all provisioned root folders in Lidarr")]
   DiskSpace,
   #[command(about = "List all active downloads in Lidarr")]
@@ -71,6 +92,8 @@ pub enum LidarrListCommand {
     #[arg(long, help = "How many history events to fetch", default_value_t = 500)]
     events: u64,
   },
+  #[command(about = "List all Lidarr download clients")]
+  DownloadClients,
   #[command(about = "List all Lidarr indexers")]
   Indexers,
   #[command(about = "Fetch Lidarr logs")]
@@ -144,6 +167,11 @@ pub enum LidarrListCommand {
   },
   #[command(about = "List all Lidarr updates")]
   Updates,
+  #[command(about = "List the monitored albums that are missing from your library")]
+  WantedMissing {
+    #[arg(long, help = "How many albums to fetch", default_value_t = 500)]
+    count: u64,
+  },
 }
 
 impl From<LidarrListCommand> for Command {
@@ -204,6 +232,13 @@ impl<'a, 'b> CliCommandHandler<'a, 'b, LidarrListCommand> for LidarrListCommandH
           .await?;
         serde_json::to_string_pretty(&resp)?
       }
+      LidarrListCommand::Backups => {
+        let resp = self
+          .network
+          .handle_network_event(LidarrEvent::GetBackups.into())
+          .await?;
+        serde_json::to_string_pretty(&resp)?
+      }
       LidarrListCommand::Blocklist => {
         let resp = self
           .network
@@ -211,6 +246,21 @@ impl<'a, 'b> CliCommandHandler<'a, 'b, LidarrListCommand> for LidarrListCommandH
           .await?;
         serde_json::to_string_pretty(&resp)?
       }
+      LidarrListCommand::Calendar { start, end } => {
+        let (start, end) = calendar_window_or_default(start, end, Local::now().date_naive());
+        let resp = self
+          .network
+          .handle_network_event(LidarrEvent::GetCalendar(start, end).into())
+          .await?;
+        serde_json::to_string_pretty(&resp)?
+      }
+      LidarrListCommand::CutoffUnmet { count } => {
+        let resp = self
+          .network
+          .handle_network_event(LidarrEvent::GetCutoffUnmet(count).into())
+          .await?;
+        serde_json::to_string_pretty(&resp)?
+      }
       LidarrListCommand::DiskSpace => {
         let resp = self
           .network
@@ -232,6 +282,13 @@ impl<'a, 'b> CliCommandHandler<'a, 'b, LidarrListCommand> for LidarrListCommandH
           .await?;
         serde_json::to_string_pretty(&resp)?
       }
+      LidarrListCommand::DownloadClients => {
+        let resp = self
+          .network
+          .handle_network_event(LidarrEvent::GetDownloadClients.into())
+          .await?;
+        serde_json::to_string_pretty(&resp)?
+      }
       LidarrListCommand::Indexers => {
         let resp = self
           .network
@@ -343,6 +400,13 @@ impl<'a, 'b> CliCommandHandler<'a, 'b, LidarrListCommand> for LidarrListCommandH
           .await?;
         serde_json::to_string_pretty(&resp)?
       }
+      LidarrListCommand::WantedMissing { count } => {
+        let resp = self
+          .network
+          .handle_network_event(LidarrEvent::GetWantedMissing(count).into())
+          .await?;
+        serde_json::to_string_pretty(&resp)?
+      }
     };
 
     Ok(result)
@@ -19,6 +19,7 @@ mod tests {
 
   mod cli {
     use super::*;
+    use chrono::NaiveDate;
     use clap::{Parser, error::ErrorKind};
     use pretty_assertions::assert_eq;
     use rstest::rstest;
@@ -27,8 +28,11 @@ mod tests {
     fn test_list_commands_have_no_arg_requirements(
       #[values(
         "artists",
+        "backups",
         "blocklist",
+        "calendar",
         "disk-space",
+        "download-clients",
         "indexers",
         "metadata-profiles",
         "quality-profiles",
@@ -156,6 +160,85 @@ mod tests {
       assert_eq!(artist_command, expected_args);
     }
 
+    #[rstest]
+    fn test_list_calendar_date_flags_require_arguments(#[values("--start", "--end")] flag: &str) {
+      let result =
+        Cli::command().try_get_matches_from(["managarr", "lidarr", "list", "calendar", flag]);
+
+      assert_err!(&result);
+      assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidValue);
+    }
+
+    #[test]
+    fn test_list_calendar_invalid_date() {
+      let result = Cli::command().try_get_matches_from([
+        "managarr",
+        "lidarr",
+        "list",
+        "calendar",
+        "--start",
+        "05/20/2023",
+      ]);
+
+      assert_err!(&result);
+      assert_eq!(result.unwrap_err().kind(), ErrorKind::ValueValidation);
+    }
+
+    #[test]
+    fn test_list_calendar_success() {
+      let expected_args = LidarrListCommand::Calendar {
+        start: NaiveDate::from_ymd_opt(2023, 5, 20),
+        end: NaiveDate::from_ymd_opt(2023, 6, 1),
+      };
+      let result = Cli::try_parse_from([
+        "managarr",
+        "lidarr",
+        "list",
+        "calendar",
+        "--start",
+        "2023-05-20",
+        "--end",
+        "2023-06-01",
+      ]);
+
+      assert_ok!(&result);
+
+      let Some(Command::Lidarr(LidarrCommand::List(calendar_command))) = result.unwrap().command
+      else {
+        panic!("Unexpected command type");
+      };
+      assert_eq!(calendar_command, expected_args);
+    }
+
+    #[rstest]
+    fn test_list_wanted_count_flag_requires_arguments(
+      #[values("wanted-missing", "cutoff-unmet")] subcommand: &str,
+    ) {
+      let result =
+        Cli::command().try_get_matches_from(["managarr", "lidarr", "list", subcommand, "--count"]);
+
+      assert_err!(&result);
+      assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidValue);
+    }
+
+    #[rstest]
+    #[case("wanted-missing", LidarrListCommand::WantedMissing { count: 500 })]
+    #[case("cutoff-unmet", LidarrListCommand::CutoffUnmet { count: 500 })]
+    fn test_list_wanted_default_values(
+      #[case] subcommand: &str,
+      #[case] expected_args: LidarrListCommand,
+    ) {
+      let result = Cli::try_parse_from(["managarr", "lidarr", "list", subcommand]);
+
+      assert_ok!(&result);
+
+      let Some(Command::Lidarr(LidarrCommand::List(wanted_command))) = result.unwrap().command
+      else {
+        panic!("Unexpected command type");
+      };
+      assert_eq!(wanted_command, expected_args);
+    }
+
     #[test]
     fn test_list_downloads_count_flag_requires_arguments() {
       let result =
@@ -416,6 +499,7 @@ mod tests {
   mod handler {
     use std::sync::Arc;
 
+    use chrono::NaiveDate;
     use mockall::predicate::eq;
     use pretty_assertions::assert_str_eq;
     use rstest::rstest;
@@ -435,8 +519,10 @@ mod tests {
 
     #[rstest]
     #[case(LidarrListCommand::Artists, LidarrEvent::ListArtists)]
+    #[case(LidarrListCommand::Backups, LidarrEvent::GetBackups)]
     #[case(LidarrListCommand::Blocklist, LidarrEvent::GetBlocklist)]
     #[case(LidarrListCommand::DiskSpace, LidarrEvent::GetDiskSpace)]
+    #[case(LidarrListCommand::DownloadClients, LidarrEvent::GetDownloadClients)]
     #[case(LidarrListCommand::Indexers, LidarrEvent::GetIndexers)]
     #[case(LidarrListCommand::MetadataProfiles, LidarrEvent::GetMetadataProfiles)]
     #[case(LidarrListCommand::QualityProfiles, LidarrEvent::GetQualityProfiles)]
@@ -547,6 +633,63 @@ mod tests {
       assert_ok!(&result);
     }
 
+    #[tokio::test]
+    async fn test_handle_list_calendar_command() {
+      let expected_start = NaiveDate::from_ymd_opt(2023, 5, 20).unwrap();
+      let expected_end = NaiveDate::from_ymd_opt(2023, 6, 1).unwrap();
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(
+          LidarrEvent::GetCalendar(expected_start, expected_end).into(),
+        ))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Lidarr(LidarrSerdeable::Value(
+            json!({"testResponse": "response"}),
+          )))
+        });
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+      let list_calendar_command = LidarrListCommand::Calendar {
+        start: Some(expected_start),
+        end: Some(expected_end),
+      };
+
+      let result =
+        LidarrListCommandHandler::with(&app_arc, list_calendar_command, &mut mock_network)
+          .handle()
+          .await;
+
+      assert_ok!(&result);
+    }
+
+    #[rstest]
+    #[case(LidarrListCommand::WantedMissing { count: 1000 }, LidarrEvent::GetWantedMissing(1000))]
+    #[case(LidarrListCommand::CutoffUnmet { count: 1000 }, LidarrEvent::GetCutoffUnmet(1000))]
+    #[tokio::test]
+    async fn test_handle_list_wanted_commands(
+      #[case] list_command: LidarrListCommand,
+      #[case] expected_lidarr_event: LidarrEvent,
+    ) {
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(expected_lidarr_event.into()))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Lidarr(LidarrSerdeable::Value(
+            json!({"testResponse": "response"}),
+          )))
+        });
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+
+      let result = LidarrListCommandHandler::with(&app_arc, list_command, &mut mock_network)
+        .handle()
+        .await;
+
+      assert_ok!(&result);
+    }
+
     #[tokio::test]
     async fn test_handle_list_downloads_command() {
       let expected_count = 1000;
@@ -1,7 +1,8 @@
+use std::path::PathBuf;
 use std::sync::Arc;
 
 use add_command_handler::{LidarrAddCommand, LidarrAddCommandHandler};
-use anyhow::Result;
+use anyhow::{Result, anyhow};
 use clap::Subcommand;
 use delete_command_handler::{LidarrDeleteCommand, LidarrDeleteCommandHandler};
 use edit_command_handler::{LidarrEditCommand, LidarrEditCommandHandler};
@@ -18,7 +19,9 @@ use super::{CliCommandHandler, Command};
 use crate::cli::lidarr::manual_search_command_handler::{
   LidarrManualSearchCommand, LidarrManualSearchCommandHandler,
 };
-use crate::models::lidarr_models::{LidarrReleaseDownloadBody, LidarrTaskName};
+use crate::models::Serdeable;
+use crate::models::lidarr_models::{LidarrReleaseDownloadBody, LidarrSerdeable, LidarrTaskName};
+use crate::models::servarr_models::DownloadBackupParams;
 use crate::network::lidarr_network::LidarrEvent;
 use crate::{app::App, network::NetworkTrait};
 
@@ -26,7 +29,7 @@ mod add_command_handler;
 mod delete_command_handler;
 mod edit_command_handler;
 mod get_command_handler;
-mod list_command_handler;
+pub(super) mod list_command_handler;
 mod manual_search_command_handler;
 mod refresh_command_handler;
 mod trigger_automatic_search_command_handler;
@@ -96,6 +99,49 @@ pub enum LidarrCommand {
     )]
     history_item_id: i64,
   },
+  #[command(
+    about = "Grab the pending release in the queue with the given ID now, skipping any delay"
+  )]
+  GrabQueueItem {
+    #[arg(
+      long,
+      help = "The Lidarr ID of the pending queue item you wish to grab",
+      required = true
+    )]
+    queue_item_id: i64,
+  },
+  #[command(
+    about = "Import all files with no rejections from the download with the given ID (i.e. the download client's ID for the download)"
+  )]
+  ManualImport {
+    #[arg(
+      long,
+      help = "The download client's ID of the download you wish to import",
+      required = true
+    )]
+    download_id: String,
+    #[arg(
+      long,
+      help = "The Lidarr ID of the artist to map the files to, if Lidarr couldn't identify it automatically"
+    )]
+    artist_id: Option<i64>,
+  },
+  #[command(about = "Download the backup with the given ID from your Lidarr instance")]
+  DownloadBackup {
+    #[arg(long, help = "The ID of the backup to download", required = true)]
+    id: i64,
+    #[arg(
+      long,
+      help = "The file or directory to download the backup to",
+      required = true
+    )]
+    dest: PathBuf,
+  },
+  #[command(about = "Upload the given backup file and restore your Lidarr instance from it")]
+  RestoreBackup {
+    #[arg(long, help = "The backup file to restore from", required = true)]
+    file: PathBuf,
+  },
   #[command(about = "Search for a new artist to add to Lidarr")]
   SearchNewArtist {
     #[arg(
@@ -124,6 +170,15 @@ pub enum LidarrCommand {
   },
   #[command(about = "Test all Lidarr indexers")]
   TestAllIndexers,
+  #[command(
+    about = "Test the download client with the given ID. Note that a successful test returns an empty JSON body; i.e. '{}'"
+  )]
+  TestDownloadClient {
+    #[arg(long, help = "The ID of the download client to test", required = true)]
+    download_client_id: i64,
+  },
+  #[command(about = "Test all Lidarr download clients")]
+  TestAllDownloadClients,
   #[command(
     about = "Toggle monitoring for the specified album corresponding to the given album ID"
   )]
@@ -245,6 +300,76 @@ impl<'a, 'b> CliCommandHandler<'a, 'b, LidarrCommand> for LidarrCliHandler<'a, '
           .await?;
         serde_json::to_string_pretty(&json!({"message": "Lidarr history item marked as 'failed'"}))?
       }
+      LidarrCommand::GrabQueueItem { queue_item_id } => {
+        let _ = self
+          .network
+          .handle_network_event(LidarrEvent::GrabQueueItem(queue_item_id).into())
+          .await?;
+        serde_json::to_string_pretty(&json!({"message": "Lidarr queue item grabbed"}))?
+      }
+      LidarrCommand::ManualImport {
+        download_id,
+        artist_id,
+      } => {
+        if let Serdeable::Lidarr(LidarrSerdeable::ManualImportItems(items)) = self
+          .network
+          .handle_network_event(LidarrEvent::GetManualImport(download_id, artist_id).into())
+          .await?
+        {
+          let files: Vec<_> = items.iter().filter_map(|item| item.import_file()).collect();
+
+          if files.is_empty() {
+            serde_json::to_string_pretty(
+              &json!({"message": "No files without rejections were found to import"}),
+            )?
+          } else {
+            let resp = self
+              .network
+              .handle_network_event(LidarrEvent::ManualImport(files).into())
+              .await?;
+            serde_json::to_string_pretty(&resp)?
+          }
+        } else {
+          String::new()
+        }
+      }
+      LidarrCommand::DownloadBackup { id, dest } => {
+        if let Serdeable::Lidarr(LidarrSerdeable::Backups(backups)) = self
+          .network
+          .handle_network_event(LidarrEvent::GetBackups.into())
+          .await?
+        {
+          let backup = backups
+            .into_iter()
+            .find(|backup| backup.id == id)
+            .ok_or_else(|| anyhow!("No backup found with ID {id}"))?;
+          let destination = if dest.is_dir() {
+            dest.join(&backup.name)
+          } else {
+            dest
+          };
+          let params = DownloadBackupParams {
+            backup_path: backup.path,
+            destination: destination.clone(),
+          };
+          self
+            .network
+            .handle_network_event(LidarrEvent::DownloadBackup(params).into())
+            .await?;
+          serde_json::to_string_pretty(
+            &json!({"message": format!("Backup downloaded to '{}'", destination.display())}),
+          )?
+        } else {
+          String::new()
+        }
+      }
+      LidarrCommand::RestoreBackup { file } => {
+        let resp = self
+          .network
+          .handle_network_event(LidarrEvent::RestoreBackup(file).into())
+          .await?;
+        serde_json::to_string_pretty(&resp)?
+      }
       LidarrCommand::SearchNewArtist { query } => {
         let resp = self
           .network
@@ -274,6 +399,21 @@ impl<'a, 'b> CliCommandHandler<'a, 'b, LidarrCommand> for LidarrCliHandler<'a, '
           .await?;
         serde_json::to_string_pretty(&resp)?
       }
+      LidarrCommand::TestDownloadClient { download_client_id } => {
+        let resp = self
+          .network
+          .handle_network_event(LidarrEvent::TestDownloadClient(download_client_id).into())
+          .await?;
+        serde_json::to_string_pretty(&resp)?
+      }
+      LidarrCommand::TestAllDownloadClients => {
+        println!("Testing all Lidarr download clients. This may take a minute...");
+        let resp = self
+          .network
+          .handle_network_event(LidarrEvent::TestAllDownloadClients.into())
+          .await?;
+        serde_json::to_string_pretty(&resp)?
+      }
       LidarrCommand::ToggleAlbumMonitoring { album_id } => {
         let resp = self
           .network
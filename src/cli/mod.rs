@@ -1,18 +1,25 @@
 use std::sync::Arc;
 
 use anyhow::Result;
+use chrono::{Days, NaiveDate};
 use clap::Subcommand;
 use clap_complete::Shell;
 use indoc::indoc;
 use lidarr::{LidarrCliHandler, LidarrCommand};
+use output::{OutputOptions, format_output, table_lookup_events};
+use prowlarr::{ProwlarrCliHandler, ProwlarrCommand};
 use radarr::{RadarrCliHandler, RadarrCommand};
+use readarr::{ReadarrCliHandler, ReadarrCommand};
 use sonarr::{SonarrCliHandler, SonarrCommand};
 use tokio::sync::Mutex;
 
 use crate::{app::App, network::NetworkTrait};
 
 pub mod lidarr;
+pub mod output;
+pub mod prowlarr;
 pub mod radarr;
+pub mod readarr;
 pub mod sonarr;
 
 #[cfg(test)]
@@ -30,6 +37,12 @@ pub enum Command {
   #[command(subcommand, about = "Commands for manging your Lidarr instance")]
   Lidarr(LidarrCommand),
 
+  #[command(subcommand, about = "Commands for manging your Prowlarr instance")]
+  Prowlarr(ProwlarrCommand),
+
+  #[command(subcommand, about = "Commands for manging your Readarr instance")]
+  Readarr(ReadarrCommand),
+
   #[command(
     arg_required_else_help = true,
     about = "Generate shell completions for the Managarr CLI"
@@ -50,6 +63,24 @@ pub enum Command {
       This file can be changed to another location using the '--config-file' flag
     "})]
   ConfigPath,
+
+  #[command(
+    subcommand,
+    about = "Commands for working with the Managarr configuration file"
+  )]
+  Config(ConfigCommand),
+}
+
+#[derive(Debug, Clone, Subcommand, PartialEq, Eq)]
+pub enum ConfigCommand {
+  #[command(about = indoc!{"
+      Validate the configuration file without connecting to any Servarrs.
+      Exits with a non-zero status code if any problems are found
+    "})]
+  Validate {
+    #[arg(long, help = "Print the validation results as JSON")]
+    json: bool,
+  },
 }
 
 pub trait CliCommandHandler<'a, 'b, T: Into<Command>> {
@@ -61,8 +92,9 @@ pub(crate) async fn handle_command(
   app: &Arc<Mutex<App<'_>>>,
   command: Command,
   network: &mut dyn NetworkTrait,
+  output_options: &OutputOptions,
 ) -> Result<String> {
-  let result = match command {
+  let result = match command.clone() {
     Command::Radarr(radarr_command) => {
       RadarrCliHandler::with(app, radarr_command, network)
         .handle()
@@ -78,10 +110,23 @@ pub(crate) async fn handle_command(
         .handle()
         .await?
     }
+    Command::Prowlarr(prowlarr_command) => {
+      ProwlarrCliHandler::with(app, prowlarr_command, network)
+        .handle()
+        .await?
+    }
+    Command::Readarr(readarr_command) => {
+      ReadarrCliHandler::with(app, readarr_command, network)
+        .handle()
+        .await?
+    }
     _ => String::new(),
   };
+  for lookup_event in table_lookup_events(&command, output_options) {
+    network.handle_network_event(lookup_event).await?;
+  }
 
-  Ok(result)
+  format_output(result, &command, output_options, &*app.lock().await)
 }
 
 #[inline]
@@ -105,3 +150,22 @@ pub fn mutex_flags_or_default(positive: bool, negative: bool, default_value: boo
     default_value
   }
 }
+
+/// Parses a `key=value` argument, like the `--field` values used when adding an indexer
+pub fn parse_key_value(arg: &str) -> Result<(String, String), String> {
+  match arg.split_once('=') {
+    Some((key, value)) if !key.trim().is_empty() => Ok((key.trim().to_owned(), value.to_owned())),
+    _ => Err(format!("expected 'key=value', but got '{arg}'")),
+  }
+}
+
+#[inline]
+pub fn calendar_window_or_default(
+  start: Option<NaiveDate>,
+  end: Option<NaiveDate>,
+  today: NaiveDate,
+) -> (NaiveDate, NaiveDate) {
+  let start = start.unwrap_or(today);
+
+  (start, end.unwrap_or(start + Days::new(7)))
+}
@@ -0,0 +1,340 @@
+use anyhow::Result;
+use clap::ValueEnum;
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+
+use crate::app::App;
+use crate::cli::Command;
+use crate::cli::lidarr::LidarrCommand;
+use crate::cli::lidarr::list_command_handler::LidarrListCommand;
+use crate::cli::radarr::RadarrCommand;
+use crate::cli::radarr::list_command_handler::RadarrListCommand;
+use crate::cli::sonarr::SonarrCommand;
+use crate::cli::sonarr::list_command_handler::SonarrListCommand;
+use crate::models::lidarr_models::Artist;
+use crate::models::radarr_models::Movie;
+use crate::models::sonarr_models::Series;
+use crate::models::table_columns::{
+  ARTIST_TABLE_HEADERS, DOWNLOADS_TABLE_COLUMNS, HISTORY_TABLE_COLUMNS,
+  LIDARR_HISTORY_TABLE_COLUMNS, MOVIE_TABLE_HEADERS, SERIES_TABLE_HEADERS, TableColumn, artist_row,
+  movie_row, series_row, text,
+};
+use crate::network::NetworkEvent;
+use crate::network::lidarr_network::LidarrEvent;
+use crate::network::radarr_network::RadarrEvent;
+use crate::network::sonarr_network::SonarrEvent;
+
+#[cfg(test)]
+#[path = "output_tests.rs"]
+mod output_tests;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+  #[default]
+  Json,
+  Yaml,
+  Table,
+  Csv,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct OutputOptions {
+  pub format: OutputFormat,
+  pub fields: Vec<String>,
+}
+
+/// The TUI table that renders the records returned by a CLI command
+enum TuiTable {
+  Columns(&'static [TableColumn]),
+  Movies,
+  Series,
+  Artists,
+}
+
+/// Renders the JSON output of a CLI command in the requested format. Paginated responses are
+/// unwrapped to their `records` when selecting fields or rendering a table, and output that isn't
+/// JSON is returned untouched. Library tables resolve profile and tag IDs to names using the
+/// lookups stored in the app, just like the TUI.
+pub fn format_output(
+  output: String,
+  command: &Command,
+  options: &OutputOptions,
+  app: &App<'_>,
+) -> Result<String> {
+  if options.format == OutputFormat::Json && options.fields.is_empty() {
+    return Ok(output);
+  }
+
+  let Ok(mut value) = serde_json::from_str::<Value>(&output) else {
+    return Ok(output);
+  };
+  if !options.fields.is_empty() || matches!(options.format, OutputFormat::Table | OutputFormat::Csv)
+  {
+    value = unwrap_records(value);
+  }
+
+  if matches!(options.format, OutputFormat::Json | OutputFormat::Yaml) {
+    if !options.fields.is_empty() {
+      value = select_fields(&value, &options.fields);
+    }
+
+    return if options.format == OutputFormat::Json {
+      Ok(serde_json::to_string_pretty(&value)?)
+    } else {
+      Ok(serde_yaml::to_string(&value)?.trim_end().to_owned())
+    };
+  }
+
+  let records = match value {
+    Value::Array(records) => records,
+    Value::Object(_) => vec![value],
+    _ => return Ok(text(&value)),
+  };
+  let (headers, rows) = if !options.fields.is_empty() {
+    field_rows(&records, &options.fields)
+  } else if let Some(tui_table) = tui_table(command) {
+    match tui_table {
+      TuiTable::Columns(columns) => column_rows(&records, columns),
+      TuiTable::Movies => {
+        let radarr_data = &app.data.radarr_data;
+        library_rows(records, MOVIE_TABLE_HEADERS, |movie: &Movie| {
+          movie_row(
+            movie,
+            &radarr_data.quality_profile_map,
+            &radarr_data.tags_map,
+          )
+        })?
+      }
+      TuiTable::Series => {
+        let sonarr_data = &app.data.sonarr_data;
+        library_rows(records, SERIES_TABLE_HEADERS, |series: &Series| {
+          series_row(
+            series,
+            &sonarr_data.quality_profile_map,
+            &sonarr_data.language_profiles_map,
+            &sonarr_data.tags_map,
+          )
+        })?
+      }
+      TuiTable::Artists => {
+        let lidarr_data = &app.data.lidarr_data;
+        library_rows(records, ARTIST_TABLE_HEADERS, |artist: &Artist| {
+          artist_row(
+            artist,
+            &lidarr_data.quality_profile_map,
+            &lidarr_data.metadata_profile_map,
+            &lidarr_data.tags_map,
+          )
+        })?
+      }
+    }
+  } else {
+    let keys = records
+      .first()
+      .and_then(Value::as_object)
+      .map(|record| record.keys().cloned().collect::<Vec<_>>())
+      .unwrap_or_default();
+    field_rows(&records, &keys)
+  };
+
+  if options.format == OutputFormat::Csv {
+    Ok(render_csv(&headers, &rows))
+  } else {
+    Ok(render_table(&headers, &rows))
+  }
+}
+
+/// The lookups that have to be fetched before the output of the given command can be rendered
+/// as a table, so IDs can be resolved to names
+pub(super) fn table_lookup_events(command: &Command, options: &OutputOptions) -> Vec<NetworkEvent> {
+  if !options.fields.is_empty()
+    || !matches!(options.format, OutputFormat::Table | OutputFormat::Csv)
+  {
+    return Vec::new();
+  }
+
+  match tui_table(command) {
+    Some(TuiTable::Movies) => vec![
+      RadarrEvent::GetQualityProfiles.into(),
+      RadarrEvent::GetTags.into(),
+    ],
+    Some(TuiTable::Series) => vec![
+      SonarrEvent::GetQualityProfiles.into(),
+      SonarrEvent::GetLanguageProfiles.into(),
+      SonarrEvent::GetTags.into(),
+    ],
+    Some(TuiTable::Artists) => vec![
+      LidarrEvent::GetQualityProfiles.into(),
+      LidarrEvent::GetMetadataProfiles.into(),
+      LidarrEvent::GetTags.into(),
+    ],
+    _ => Vec::new(),
+  }
+}
+
+/// The TUI table for the records returned by the given command, if it has one
+fn tui_table(command: &Command) -> Option<TuiTable> {
+  match command {
+    Command::Radarr(RadarrCommand::List(RadarrListCommand::Movies)) => Some(TuiTable::Movies),
+    Command::Sonarr(SonarrCommand::List(SonarrListCommand::Series)) => Some(TuiTable::Series),
+    Command::Lidarr(LidarrCommand::List(LidarrListCommand::Artists)) => Some(TuiTable::Artists),
+    Command::Radarr(RadarrCommand::List(RadarrListCommand::Downloads { .. }))
+    | Command::Sonarr(SonarrCommand::List(SonarrListCommand::Downloads { .. }))
+    | Command::Lidarr(LidarrCommand::List(LidarrListCommand::Downloads { .. })) => {
+      Some(TuiTable::Columns(&DOWNLOADS_TABLE_COLUMNS))
+    }
+    Command::Radarr(RadarrCommand::List(RadarrListCommand::History { .. }))
+    | Command::Sonarr(SonarrCommand::List(
+      SonarrListCommand::History { .. }
+      | SonarrListCommand::SeriesHistory { .. }
+      | SonarrListCommand::SeasonHistory { .. }
+      | SonarrListCommand::EpisodeHistory { .. },
+    )) => Some(TuiTable::Columns(&HISTORY_TABLE_COLUMNS)),
+    Command::Lidarr(LidarrCommand::List(
+      LidarrListCommand::History { .. }
+      | LidarrListCommand::ArtistHistory { .. }
+      | LidarrListCommand::AlbumHistory { .. }
+      | LidarrListCommand::TrackHistory { .. },
+    )) => Some(TuiTable::Columns(&LIDARR_HISTORY_TABLE_COLUMNS)),
+    _ => None,
+  }
+}
+
+fn unwrap_records(value: Value) -> Value {
+  match value {
+    Value::Object(mut object) if object.get("records").is_some_and(Value::is_array) => {
+      object.remove("records").unwrap_or_default()
+    }
+    _ => value,
+  }
+}
+
+/// Looks up a dot-separated field path (e.g. `quality.quality.name` or `languages.0.name`)
+fn field<'a>(value: &'a Value, path: &str) -> &'a Value {
+  path.split('.').fold(value, |current, segment| {
+    match (current, segment.parse::<usize>()) {
+      (Value::Array(items), Ok(index)) => items.get(index).unwrap_or(&Value::Null),
+      _ => &current[segment],
+    }
+  })
+}
+
+fn select_fields(value: &Value, fields: &[String]) -> Value {
+  match value {
+    Value::Array(items) => Value::Array(
+      items
+        .iter()
+        .map(|item| select_fields(item, fields))
+        .collect(),
+    ),
+    Value::Object(_) => {
+      let mut selected = Value::Object(Map::new());
+      for path in fields {
+        let target = path.split('.').fold(&mut selected, |current, segment| {
+          if !current.is_object() {
+            *current = Value::Object(Map::new());
+          }
+
+          &mut current[segment]
+        });
+        *target = field(value, path).clone();
+      }
+
+      selected
+    }
+    _ => value.clone(),
+  }
+}
+
+fn field_rows(records: &[Value], fields: &[String]) -> (Vec<String>, Vec<Vec<String>>) {
+  let rows = records
+    .iter()
+    .map(|record| {
+      fields
+        .iter()
+        .map(|path| text(field(record, path)))
+        .collect()
+    })
+    .collect();
+
+  (fields.to_vec(), rows)
+}
+
+fn column_rows(records: &[Value], columns: &[TableColumn]) -> (Vec<String>, Vec<Vec<String>>) {
+  let headers = columns
+    .iter()
+    .map(|column| column.header.to_owned())
+    .collect();
+  let rows = records
+    .iter()
+    .map(|record| {
+      columns
+        .iter()
+        .map(|column| (column.value)(record))
+        .collect()
+    })
+    .collect();
+
+  (headers, rows)
+}
+
+fn library_rows<T: DeserializeOwned, const N: usize>(
+  records: Vec<Value>,
+  headers: [&str; N],
+  row: impl Fn(&T) -> [String; N],
+) -> Result<(Vec<String>, Vec<Vec<String>>)> {
+  let headers = headers.map(str::to_owned).to_vec();
+  let rows = records
+    .into_iter()
+    .map(|record| Ok(row(&serde_json::from_value(record)?).to_vec()))
+    .collect::<Result<_>>()?;
+
+  Ok((headers, rows))
+}
+
+fn render_table(headers: &[String], rows: &[Vec<String>]) -> String {
+  let mut widths: Vec<usize> = headers
+    .iter()
+    .map(|header| header.chars().count())
+    .collect();
+  for row in rows {
+    for (width, cell) in widths.iter_mut().zip(row) {
+      *width = (*width).max(cell.chars().count());
+    }
+  }
+
+  std::iter::once(headers)
+    .chain(rows.iter().map(Vec::as_slice))
+    .map(|row| {
+      row
+        .iter()
+        .zip(&widths)
+        .map(|(cell, width)| format!("{cell:<width$}"))
+        .collect::<Vec<_>>()
+        .join("  ")
+        .trim_end()
+        .to_owned()
+    })
+    .collect::<Vec<_>>()
+    .join("\n")
+}
+
+fn render_csv(headers: &[String], rows: &[Vec<String>]) -> String {
+  std::iter::once(headers)
+    .chain(rows.iter().map(Vec::as_slice))
+    .map(|row| {
+      row
+        .iter()
+        .map(|cell| {
+          if cell.contains([',', '"', '\n', '\r']) {
+            format!("\"{}\"", cell.replace('"', "\"\""))
+          } else {
+            cell.to_owned()
+          }
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+    })
+    .collect::<Vec<_>>()
+    .join("\n")
+}
@@ -0,0 +1,315 @@
+#[cfg(test)]
+mod tests {
+  use bimap::BiMap;
+  use indoc::indoc;
+  use pretty_assertions::assert_str_eq;
+  use rstest::rstest;
+  use serde_json::{Number, json};
+
+  use crate::app::App;
+  use crate::cli::Command;
+  use crate::cli::lidarr::LidarrCommand;
+  use crate::cli::lidarr::list_command_handler::LidarrListCommand;
+  use crate::cli::output::{OutputFormat, OutputOptions, format_output, table_lookup_events};
+  use crate::cli::radarr::RadarrCommand;
+  use crate::cli::radarr::list_command_handler::RadarrListCommand;
+  use crate::cli::sonarr::SonarrCommand;
+  use crate::cli::sonarr::list_command_handler::SonarrListCommand;
+  use crate::models::radarr_models::Movie;
+  use crate::models::servarr_models::Language;
+  use crate::models::table_columns::movie_row;
+  use crate::network::NetworkEvent;
+  use crate::network::lidarr_network::LidarrEvent;
+  use crate::network::radarr_network::RadarrEvent;
+  use crate::network::sonarr_network::SonarrEvent;
+
+  fn options(format: OutputFormat, fields: &[&str]) -> OutputOptions {
+    OutputOptions {
+      format,
+      fields: fields.iter().map(|field| field.to_string()).collect(),
+    }
+  }
+
+  fn movies_command() -> Command {
+    RadarrCommand::List(RadarrListCommand::Movies).into()
+  }
+
+  fn movie() -> Movie {
+    Movie {
+      title: "Test".into(),
+      year: 2023,
+      studio: Some("21st Century Alex".to_owned()),
+      runtime: 120,
+      certification: Some("R".to_owned()),
+      original_language: Language {
+        id: 1,
+        name: "English".to_owned(),
+      },
+      size_on_disk: 3543348019,
+      quality_profile_id: 2222,
+      monitored: true,
+      tags: vec![Number::from(1), Number::from(2)],
+      ..Movie::default()
+    }
+  }
+
+  fn movies_output() -> String {
+    serde_json::to_string_pretty(&[movie()]).unwrap()
+  }
+
+  fn app() -> App<'static> {
+    let mut app = App::test_default();
+    app.data.radarr_data.quality_profile_map = BiMap::from_iter([(2222, "HD - 1080p".to_owned())]);
+    app.data.radarr_data.tags_map =
+      BiMap::from_iter([(1, "alex".to_owned()), (2, "testing".to_owned())]);
+
+    app
+  }
+
+  #[test]
+  fn test_format_output_json_without_fields_is_unchanged() {
+    let output = "{\n  \"records\": []\n}".to_owned();
+
+    let result = format_output(
+      output.clone(),
+      &movies_command(),
+      &OutputOptions::default(),
+      &app(),
+    );
+
+    assert_str_eq!(result.unwrap(), output);
+  }
+
+  #[rstest]
+  fn test_format_output_non_json_output_is_unchanged(
+    #[values(
+      OutputFormat::Json,
+      OutputFormat::Yaml,
+      OutputFormat::Table,
+      OutputFormat::Csv
+    )]
+    format: OutputFormat,
+  ) {
+    let result = format_output(
+      "Movie added".to_owned(),
+      &movies_command(),
+      &options(format, &["title"]),
+      &app(),
+    );
+
+    assert_str_eq!(result.unwrap(), "Movie added");
+  }
+
+  #[test]
+  fn test_format_output_json_with_fields() {
+    let output = json!({
+      "page": 1,
+      "records": [{
+        "sourceTitle": "Test",
+        "quality": { "quality": { "name": "HD - 1080p" }, "revision": 1 }
+      }]
+    })
+    .to_string();
+
+    let result = format_output(
+      output,
+      &movies_command(),
+      &options(OutputFormat::Json, &["sourceTitle", "quality.quality.name"]),
+      &app(),
+    );
+
+    assert_eq!(
+      serde_json::from_str::<serde_json::Value>(&result.unwrap()).unwrap(),
+      json!([{
+        "sourceTitle": "Test",
+        "quality": { "quality": { "name": "HD - 1080p" } }
+      }])
+    );
+  }
+
+  #[test]
+  fn test_format_output_yaml() {
+    let result = format_output(
+      movies_output(),
+      &movies_command(),
+      &options(OutputFormat::Yaml, &["title", "originalLanguage.name"]),
+      &app(),
+    );
+
+    assert_str_eq!(
+      result.unwrap(),
+      indoc! {"
+        - originalLanguage:
+            name: English
+          title: Test"}
+    );
+  }
+
+  #[test]
+  fn test_format_output_table_uses_tui_columns() {
+    let result = format_output(
+      movies_output(),
+      &movies_command(),
+      &options(OutputFormat::Table, &[]),
+      &app(),
+    );
+
+    assert_str_eq!(
+      result.unwrap(),
+      [
+        "Title  Year  Studio             Runtime  Rating  Language  Size     Quality Profile  Monitored  Tags",
+        "Test   2023  21st Century Alex  2h 0m    R       English   3.30 GB  HD - 1080p       🏷          alex, testing",
+      ]
+      .join("\n")
+    );
+  }
+
+  #[test]
+  fn test_format_output_table_with_fields() {
+    let result = format_output(
+      movies_output(),
+      &movies_command(),
+      &options(OutputFormat::Table, &["title", "originalLanguage.name"]),
+      &app(),
+    );
+
+    assert_str_eq!(
+      result.unwrap(),
+      indoc! {"
+        title  originalLanguage.name
+        Test   English"}
+    );
+  }
+
+  #[test]
+  fn test_format_output_table_without_tui_columns_uses_record_keys() {
+    let output = json!([{ "id": 1, "label": "alex" }, { "id": 2, "label": "bob" }]).to_string();
+
+    let result = format_output(
+      output,
+      &RadarrCommand::List(RadarrListCommand::Tags).into(),
+      &options(OutputFormat::Table, &[]),
+      &app(),
+    );
+
+    assert_str_eq!(
+      result.unwrap(),
+      indoc! {"
+        id  label
+        1   alex
+        2   bob"}
+    );
+  }
+
+  #[test]
+  fn test_format_output_csv() {
+    let output = json!({
+      "records": [
+        { "title": "Test, \"The\" Movie", "year": 2023 },
+        { "title": "Other", "year": null }
+      ]
+    })
+    .to_string();
+
+    let result = format_output(
+      output,
+      &movies_command(),
+      &options(OutputFormat::Csv, &["title", "year"]),
+      &app(),
+    );
+
+    assert_str_eq!(
+      result.unwrap(),
+      indoc! {r#"
+        title,year
+        "Test, ""The"" Movie",2023
+        Other,"#}
+    );
+  }
+
+  #[test]
+  fn test_format_output_table_scalar_value() {
+    let result = format_output(
+      "true".to_owned(),
+      &movies_command(),
+      &options(OutputFormat::Table, &[]),
+      &app(),
+    );
+
+    assert_str_eq!(result.unwrap(), "true");
+  }
+
+  #[test]
+  fn test_format_output_csv_library_row_matches_the_tui_row() {
+    let app = app();
+
+    let result = format_output(
+      movies_output(),
+      &movies_command(),
+      &options(OutputFormat::Csv, &[]),
+      &app,
+    );
+
+    let tui_row = movie_row(
+      &movie(),
+      &app.data.radarr_data.quality_profile_map,
+      &app.data.radarr_data.tags_map,
+    );
+    assert_str_eq!(
+      result.unwrap().lines().nth(1).unwrap(),
+      format!("{},\"{}\"", tui_row[..9].join(","), tui_row[9])
+    );
+  }
+
+  #[test]
+  fn test_table_lookup_events() {
+    assert_eq!(
+      table_lookup_events(&movies_command(), &options(OutputFormat::Table, &[])),
+      vec![
+        NetworkEvent::from(RadarrEvent::GetQualityProfiles),
+        RadarrEvent::GetTags.into()
+      ]
+    );
+    assert_eq!(
+      table_lookup_events(
+        &SonarrCommand::List(SonarrListCommand::Series).into(),
+        &options(OutputFormat::Csv, &[])
+      ),
+      vec![
+        NetworkEvent::from(SonarrEvent::GetQualityProfiles),
+        SonarrEvent::GetLanguageProfiles.into(),
+        SonarrEvent::GetTags.into()
+      ]
+    );
+    assert_eq!(
+      table_lookup_events(
+        &LidarrCommand::List(LidarrListCommand::Artists).into(),
+        &options(OutputFormat::Table, &[])
+      ),
+      vec![
+        NetworkEvent::from(LidarrEvent::GetQualityProfiles),
+        LidarrEvent::GetMetadataProfiles.into(),
+        LidarrEvent::GetTags.into()
+      ]
+    );
+  }
+
+  #[rstest]
+  fn test_table_lookup_events_empty_without_a_library_table(
+    #[values(
+      options(OutputFormat::Json, &[]),
+      options(OutputFormat::Yaml, &[]),
+      options(OutputFormat::Table, &["title"])
+    )]
+    output_options: OutputOptions,
+  ) {
+    assert!(table_lookup_events(&movies_command(), &output_options).is_empty());
+    assert!(
+      table_lookup_events(
+        &RadarrCommand::List(RadarrListCommand::Tags).into(),
+        &options(OutputFormat::Table, &[])
+      )
+      .is_empty()
+    );
+  }
+}
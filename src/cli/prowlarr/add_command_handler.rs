@@ -0,0 +1,120 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use clap::{ArgAction, Subcommand};
+use tokio::sync::Mutex;
+
+use super::ProwlarrCommand;
+use crate::models::servarr_models::AddIndexerParams;
+use crate::{
+  app::App,
+  cli::{CliCommandHandler, Command, parse_key_value},
+  network::{NetworkTrait, prowlarr_network::ProwlarrEvent},
+};
+
+#[cfg(test)]
+#[path = "add_command_handler_tests.rs"]
+mod add_command_handler_tests;
+
+#[derive(Debug, Clone, PartialEq, Eq, Subcommand)]
+pub enum ProwlarrAddCommand {
+  #[command(
+    about = "Add a new indexer using one of the definitions or implementations in the Prowlarr indexer schema"
+  )]
+  Indexer {
+    #[arg(
+      long,
+      help = "The indexer definition or implementation of the indexer to add (e.g. '1337x' or 'Torznab')",
+      required = true
+    )]
+    implementation: String,
+    #[arg(long, help = "The name of the indexer", required = true)]
+    name: String,
+    #[arg(long, help = "Add the indexer without enabling it")]
+    disable: bool,
+    #[arg(
+      long,
+      help = "The ID of the sync profile to use for this indexer",
+      default_value_t = 1
+    )]
+    app_profile_id: i64,
+    #[arg(
+      long,
+      help = "Indexer Priority from 1 (Highest) to 50 (Lowest)",
+      default_value_t = 25
+    )]
+    priority: i64,
+    #[arg(
+      long,
+      help = "Tag IDs to tag the indexer with",
+      value_parser,
+      action = ArgAction::Append
+    )]
+    tag: Vec<i64>,
+    #[arg(
+      long,
+      help = "A setting of the indexer in the form 'name=value' (e.g. 'baseUrl=https://indexer.example'). Can be specified multiple times",
+      value_parser = parse_key_value,
+      action = ArgAction::Append
+    )]
+    field: Vec<(String, String)>,
+  },
+}
+
+impl From<ProwlarrAddCommand> for Command {
+  fn from(value: ProwlarrAddCommand) -> Self {
+    Command::Prowlarr(ProwlarrCommand::Add(value))
+  }
+}
+
+pub(super) struct ProwlarrAddCommandHandler<'a, 'b> {
+  _app: &'a Arc<Mutex<App<'b>>>,
+  command: ProwlarrAddCommand,
+  network: &'a mut dyn NetworkTrait,
+}
+
+impl<'a, 'b> CliCommandHandler<'a, 'b, ProwlarrAddCommand> for ProwlarrAddCommandHandler<'a, 'b> {
+  fn with(
+    _app: &'a Arc<Mutex<App<'b>>>,
+    command: ProwlarrAddCommand,
+    network: &'a mut dyn NetworkTrait,
+  ) -> Self {
+    ProwlarrAddCommandHandler {
+      _app,
+      command,
+      network,
+    }
+  }
+
+  async fn handle(self) -> Result<String> {
+    let result = match self.command {
+      ProwlarrAddCommand::Indexer {
+        implementation,
+        name,
+        disable,
+        app_profile_id,
+        priority,
+        tag: tags,
+        field: fields,
+      } => {
+        let add_indexer_params = AddIndexerParams {
+          implementation,
+          name,
+          enable: !disable,
+          priority,
+          app_profile_id: Some(app_profile_id),
+          tags,
+          fields,
+          ..AddIndexerParams::default()
+        };
+        let resp = self
+          .network
+          .handle_network_event(ProwlarrEvent::AddIndexer(add_indexer_params).into())
+          .await?;
+        serde_json::to_string_pretty(&resp)?
+      }
+    };
+
+    Ok(result)
+  }
+}
@@ -0,0 +1,222 @@
+#[cfg(test)]
+mod tests {
+  use clap::{CommandFactory, Parser, error::ErrorKind};
+
+  use crate::{
+    Cli,
+    cli::{
+      Command,
+      prowlarr::{ProwlarrCommand, add_command_handler::ProwlarrAddCommand},
+    },
+  };
+  use pretty_assertions::assert_eq;
+
+  #[test]
+  fn test_prowlarr_add_command_from() {
+    let command = ProwlarrAddCommand::Indexer {
+      implementation: String::new(),
+      name: String::new(),
+      disable: false,
+      app_profile_id: 1,
+      priority: 25,
+      tag: vec![],
+      field: vec![],
+    };
+
+    let result = Command::from(command.clone());
+
+    assert_eq!(result, Command::Prowlarr(ProwlarrCommand::Add(command)));
+  }
+
+  mod cli {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_add_indexer_requires_arguments() {
+      let result = Cli::command().try_get_matches_from(["managarr", "prowlarr", "add", "indexer"]);
+
+      assert_err!(&result);
+      assert_eq!(
+        result.unwrap_err().kind(),
+        ErrorKind::MissingRequiredArgument
+      );
+    }
+
+    #[test]
+    fn test_add_indexer_requires_name() {
+      let result = Cli::command().try_get_matches_from([
+        "managarr",
+        "prowlarr",
+        "add",
+        "indexer",
+        "--implementation",
+        "1337x",
+      ]);
+
+      assert_err!(&result);
+      assert_eq!(
+        result.unwrap_err().kind(),
+        ErrorKind::MissingRequiredArgument
+      );
+    }
+
+    #[test]
+    fn test_add_indexer_field_requires_key_value_format() {
+      let result = Cli::command().try_get_matches_from([
+        "managarr",
+        "prowlarr",
+        "add",
+        "indexer",
+        "--implementation",
+        "1337x",
+        "--name",
+        "Test",
+        "--field",
+        "baseUrl",
+      ]);
+
+      assert_err!(&result);
+      assert_eq!(result.unwrap_err().kind(), ErrorKind::ValueValidation);
+    }
+
+    #[test]
+    fn test_add_indexer_defaults() {
+      let expected_args = ProwlarrAddCommand::Indexer {
+        implementation: "1337x".to_owned(),
+        name: "Test".to_owned(),
+        disable: false,
+        app_profile_id: 1,
+        priority: 25,
+        tag: vec![],
+        field: vec![],
+      };
+
+      let result = Cli::try_parse_from([
+        "managarr",
+        "prowlarr",
+        "add",
+        "indexer",
+        "--implementation",
+        "1337x",
+        "--name",
+        "Test",
+      ]);
+
+      assert_ok!(&result);
+      let Some(Command::Prowlarr(ProwlarrCommand::Add(add_command))) = result.unwrap().command
+      else {
+        panic!("Unexpected command type")
+      };
+      assert_eq!(add_command, expected_args);
+    }
+
+    #[test]
+    fn test_add_indexer_all_args() {
+      let expected_args = ProwlarrAddCommand::Indexer {
+        implementation: "Torznab".to_owned(),
+        name: "Test".to_owned(),
+        disable: true,
+        app_profile_id: 2,
+        priority: 1,
+        tag: vec![1, 2],
+        field: vec![
+          ("baseUrl".to_owned(), "https://test.com".to_owned()),
+          ("apiKey".to_owned(), "test1234".to_owned()),
+        ],
+      };
+
+      let result = Cli::try_parse_from([
+        "managarr",
+        "prowlarr",
+        "add",
+        "indexer",
+        "--implementation",
+        "Torznab",
+        "--name",
+        "Test",
+        "--disable",
+        "--app-profile-id",
+        "2",
+        "--priority",
+        "1",
+        "--tag",
+        "1",
+        "--tag",
+        "2",
+        "--field",
+        "baseUrl=https://test.com",
+        "--field",
+        "apiKey=test1234",
+      ]);
+
+      assert_ok!(&result);
+      let Some(Command::Prowlarr(ProwlarrCommand::Add(add_command))) = result.unwrap().command
+      else {
+        panic!("Unexpected command type")
+      };
+      assert_eq!(add_command, expected_args);
+    }
+  }
+
+  mod handler {
+    use std::sync::Arc;
+
+    use crate::{
+      app::App,
+      cli::{CliCommandHandler, prowlarr::add_command_handler::ProwlarrAddCommandHandler},
+      models::{Serdeable, prowlarr_models::ProwlarrSerdeable},
+      network::{MockNetworkTrait, NetworkEvent, prowlarr_network::ProwlarrEvent},
+    };
+
+    use super::*;
+    use mockall::predicate::eq;
+
+    use crate::models::servarr_models::AddIndexerParams;
+    use serde_json::json;
+    use tokio::sync::Mutex;
+
+    #[tokio::test]
+    async fn test_handle_add_indexer_command() {
+      let expected_add_indexer_params = AddIndexerParams {
+        implementation: "1337x".to_owned(),
+        name: "Test".to_owned(),
+        enable: false,
+        priority: 1,
+        app_profile_id: Some(2),
+        tags: vec![1, 2],
+        fields: vec![("baseUrl".to_owned(), "https://1337x.to/".to_owned())],
+        ..AddIndexerParams::default()
+      };
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(
+          ProwlarrEvent::AddIndexer(expected_add_indexer_params).into(),
+        ))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Prowlarr(ProwlarrSerdeable::Value(
+            json!({"testResponse": "response"}),
+          )))
+        });
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+      let add_indexer_command = ProwlarrAddCommand::Indexer {
+        implementation: "1337x".to_owned(),
+        name: "Test".to_owned(),
+        disable: true,
+        app_profile_id: 2,
+        priority: 1,
+        tag: vec![1, 2],
+        field: vec![("baseUrl".to_owned(), "https://1337x.to/".to_owned())],
+      };
+
+      let result =
+        ProwlarrAddCommandHandler::with(&app_arc, add_indexer_command, &mut mock_network)
+          .handle()
+          .await;
+
+      assert_ok!(&result);
+    }
+  }
+}
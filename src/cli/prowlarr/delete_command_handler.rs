@@ -0,0 +1,104 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use clap::Subcommand;
+use tokio::sync::Mutex;
+
+use crate::{
+  app::App,
+  cli::{CliCommandHandler, Command},
+  network::{NetworkTrait, prowlarr_network::ProwlarrEvent},
+};
+
+use super::ProwlarrCommand;
+
+#[cfg(test)]
+#[path = "delete_command_handler_tests.rs"]
+mod delete_command_handler_tests;
+
+#[derive(Debug, Clone, PartialEq, Eq, Subcommand)]
+pub enum ProwlarrDeleteCommand {
+  #[command(about = "Delete the application with the given ID")]
+  Application {
+    #[arg(long, help = "The ID of the application to delete", required = true)]
+    application_id: i64,
+  },
+  #[command(about = "Delete the indexer with the given ID")]
+  Indexer {
+    #[arg(long, help = "The ID of the indexer to delete", required = true)]
+    indexer_id: i64,
+  },
+  #[command(about = "Delete the indexer proxy with the given ID")]
+  IndexerProxy {
+    #[arg(long, help = "The ID of the indexer proxy to delete", required = true)]
+    indexer_proxy_id: i64,
+  },
+  #[command(about = "Delete the tag with the specified ID")]
+  Tag {
+    #[arg(long, help = "The ID of the tag to delete", required = true)]
+    tag_id: i64,
+  },
+}
+
+impl From<ProwlarrDeleteCommand> for Command {
+  fn from(value: ProwlarrDeleteCommand) -> Self {
+    Command::Prowlarr(ProwlarrCommand::Delete(value))
+  }
+}
+
+pub(super) struct ProwlarrDeleteCommandHandler<'a, 'b> {
+  _app: &'a Arc<Mutex<App<'b>>>,
+  command: ProwlarrDeleteCommand,
+  network: &'a mut dyn NetworkTrait,
+}
+
+impl<'a, 'b> CliCommandHandler<'a, 'b, ProwlarrDeleteCommand>
+  for ProwlarrDeleteCommandHandler<'a, 'b>
+{
+  fn with(
+    _app: &'a Arc<Mutex<App<'b>>>,
+    command: ProwlarrDeleteCommand,
+    network: &'a mut dyn NetworkTrait,
+  ) -> Self {
+    ProwlarrDeleteCommandHandler {
+      _app,
+      command,
+      network,
+    }
+  }
+
+  async fn handle(self) -> Result<String> {
+    let result = match self.command {
+      ProwlarrDeleteCommand::Application { application_id } => {
+        let resp = self
+          .network
+          .handle_network_event(ProwlarrEvent::DeleteApplication(application_id).into())
+          .await?;
+        serde_json::to_string_pretty(&resp)?
+      }
+      ProwlarrDeleteCommand::Indexer { indexer_id } => {
+        let resp = self
+          .network
+          .handle_network_event(ProwlarrEvent::DeleteIndexer(indexer_id).into())
+          .await?;
+        serde_json::to_string_pretty(&resp)?
+      }
+      ProwlarrDeleteCommand::IndexerProxy { indexer_proxy_id } => {
+        let resp = self
+          .network
+          .handle_network_event(ProwlarrEvent::DeleteIndexerProxy(indexer_proxy_id).into())
+          .await?;
+        serde_json::to_string_pretty(&resp)?
+      }
+      ProwlarrDeleteCommand::Tag { tag_id } => {
+        let resp = self
+          .network
+          .handle_network_event(ProwlarrEvent::DeleteTag(tag_id).into())
+          .await?;
+        serde_json::to_string_pretty(&resp)?
+      }
+    };
+
+    Ok(result)
+  }
+}
@@ -0,0 +1,181 @@
+#[cfg(test)]
+mod tests {
+  use crate::Cli;
+  use crate::cli::{
+    Command,
+    prowlarr::{ProwlarrCommand, delete_command_handler::ProwlarrDeleteCommand},
+  };
+  use clap::CommandFactory;
+  use pretty_assertions::assert_eq;
+
+  #[test]
+  fn test_prowlarr_delete_command_from() {
+    let command = ProwlarrDeleteCommand::Tag { tag_id: 1 };
+
+    let result = Command::from(command.clone());
+
+    assert_eq!(result, Command::Prowlarr(ProwlarrCommand::Delete(command)));
+  }
+
+  mod cli {
+    use super::*;
+    use clap::{Parser, error::ErrorKind};
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    #[rstest]
+    fn test_delete_commands_require_an_id(
+      #[values("application", "indexer", "indexer-proxy", "tag")] subcommand: &str,
+    ) {
+      let result =
+        Cli::command().try_get_matches_from(["managarr", "prowlarr", "delete", subcommand]);
+
+      assert_err!(&result);
+      assert_eq!(
+        result.unwrap_err().kind(),
+        ErrorKind::MissingRequiredArgument
+      );
+    }
+
+    #[test]
+    fn test_delete_application_success() {
+      let expected_args = ProwlarrDeleteCommand::Application { application_id: 1 };
+
+      let result = Cli::try_parse_from([
+        "managarr",
+        "prowlarr",
+        "delete",
+        "application",
+        "--application-id",
+        "1",
+      ]);
+
+      assert_ok!(&result);
+
+      let Some(Command::Prowlarr(ProwlarrCommand::Delete(delete_command))) =
+        result.unwrap().command
+      else {
+        panic!("Unexpected command type");
+      };
+      assert_eq!(delete_command, expected_args);
+    }
+
+    #[test]
+    fn test_delete_indexer_success() {
+      let expected_args = ProwlarrDeleteCommand::Indexer { indexer_id: 1 };
+
+      let result = Cli::try_parse_from([
+        "managarr",
+        "prowlarr",
+        "delete",
+        "indexer",
+        "--indexer-id",
+        "1",
+      ]);
+
+      assert_ok!(&result);
+
+      let Some(Command::Prowlarr(ProwlarrCommand::Delete(delete_command))) =
+        result.unwrap().command
+      else {
+        panic!("Unexpected command type");
+      };
+      assert_eq!(delete_command, expected_args);
+    }
+
+    #[test]
+    fn test_delete_indexer_proxy_success() {
+      let expected_args = ProwlarrDeleteCommand::IndexerProxy {
+        indexer_proxy_id: 1,
+      };
+
+      let result = Cli::try_parse_from([
+        "managarr",
+        "prowlarr",
+        "delete",
+        "indexer-proxy",
+        "--indexer-proxy-id",
+        "1",
+      ]);
+
+      assert_ok!(&result);
+
+      let Some(Command::Prowlarr(ProwlarrCommand::Delete(delete_command))) =
+        result.unwrap().command
+      else {
+        panic!("Unexpected command type");
+      };
+      assert_eq!(delete_command, expected_args);
+    }
+
+    #[test]
+    fn test_delete_tag_success() {
+      let expected_args = ProwlarrDeleteCommand::Tag { tag_id: 1 };
+
+      let result = Cli::try_parse_from(["managarr", "prowlarr", "delete", "tag", "--tag-id", "1"]);
+
+      assert_ok!(&result);
+
+      let Some(Command::Prowlarr(ProwlarrCommand::Delete(delete_command))) =
+        result.unwrap().command
+      else {
+        panic!("Unexpected command type");
+      };
+      assert_eq!(delete_command, expected_args);
+    }
+  }
+
+  mod handler {
+    use std::sync::Arc;
+
+    use mockall::predicate::eq;
+    use rstest::rstest;
+    use serde_json::json;
+    use tokio::sync::Mutex;
+
+    use crate::{
+      app::App,
+      cli::{
+        CliCommandHandler,
+        prowlarr::delete_command_handler::{ProwlarrDeleteCommand, ProwlarrDeleteCommandHandler},
+      },
+      models::{Serdeable, prowlarr_models::ProwlarrSerdeable},
+      network::{MockNetworkTrait, NetworkEvent, prowlarr_network::ProwlarrEvent},
+    };
+
+    #[rstest]
+    #[case(
+      ProwlarrDeleteCommand::Application { application_id: 1 },
+      ProwlarrEvent::DeleteApplication(1)
+    )]
+    #[case(ProwlarrDeleteCommand::Indexer { indexer_id: 1 }, ProwlarrEvent::DeleteIndexer(1))]
+    #[case(
+      ProwlarrDeleteCommand::IndexerProxy { indexer_proxy_id: 1 },
+      ProwlarrEvent::DeleteIndexerProxy(1)
+    )]
+    #[case(ProwlarrDeleteCommand::Tag { tag_id: 1 }, ProwlarrEvent::DeleteTag(1))]
+    #[tokio::test]
+    async fn test_handle_delete_command(
+      #[case] delete_command: ProwlarrDeleteCommand,
+      #[case] expected_prowlarr_event: ProwlarrEvent,
+    ) {
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(expected_prowlarr_event.into()))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Prowlarr(ProwlarrSerdeable::Value(
+            json!({"testResponse": "response"}),
+          )))
+        });
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+
+      let result = ProwlarrDeleteCommandHandler::with(&app_arc, delete_command, &mut mock_network)
+        .handle()
+        .await;
+
+      assert_ok!(&result);
+    }
+  }
+}
@@ -0,0 +1,81 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use clap::Subcommand;
+use tokio::sync::Mutex;
+
+use crate::{
+  app::App,
+  cli::{CliCommandHandler, Command},
+  network::{NetworkTrait, prowlarr_network::ProwlarrEvent},
+};
+
+use super::ProwlarrCommand;
+
+#[cfg(test)]
+#[path = "get_command_handler_tests.rs"]
+mod get_command_handler_tests;
+
+#[derive(Debug, Clone, PartialEq, Eq, Subcommand)]
+pub enum ProwlarrGetCommand {
+  #[command(about = "Fetch the host config for your Prowlarr instance")]
+  HostConfig,
+  #[command(about = "Fetch the security config for your Prowlarr instance")]
+  SecurityConfig,
+  #[command(about = "Get the system status")]
+  SystemStatus,
+}
+
+impl From<ProwlarrGetCommand> for Command {
+  fn from(value: ProwlarrGetCommand) -> Self {
+    Command::Prowlarr(ProwlarrCommand::Get(value))
+  }
+}
+
+pub(super) struct ProwlarrGetCommandHandler<'a, 'b> {
+  _app: &'a Arc<Mutex<App<'b>>>,
+  command: ProwlarrGetCommand,
+  network: &'a mut dyn NetworkTrait,
+}
+
+impl<'a, 'b> CliCommandHandler<'a, 'b, ProwlarrGetCommand> for ProwlarrGetCommandHandler<'a, 'b> {
+  fn with(
+    _app: &'a Arc<Mutex<App<'b>>>,
+    command: ProwlarrGetCommand,
+    network: &'a mut dyn NetworkTrait,
+  ) -> Self {
+    ProwlarrGetCommandHandler {
+      _app,
+      command,
+      network,
+    }
+  }
+
+  async fn handle(self) -> Result<String> {
+    let result = match self.command {
+      ProwlarrGetCommand::HostConfig => {
+        let resp = self
+          .network
+          .handle_network_event(ProwlarrEvent::GetHostConfig.into())
+          .await?;
+        serde_json::to_string_pretty(&resp)?
+      }
+      ProwlarrGetCommand::SecurityConfig => {
+        let resp = self
+          .network
+          .handle_network_event(ProwlarrEvent::GetSecurityConfig.into())
+          .await?;
+        serde_json::to_string_pretty(&resp)?
+      }
+      ProwlarrGetCommand::SystemStatus => {
+        let resp = self
+          .network
+          .handle_network_event(ProwlarrEvent::GetStatus.into())
+          .await?;
+        serde_json::to_string_pretty(&resp)?
+      }
+    };
+
+    Ok(result)
+  }
+}
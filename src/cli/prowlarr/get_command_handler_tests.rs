@@ -0,0 +1,80 @@
+#[cfg(test)]
+mod tests {
+  use crate::Cli;
+  use crate::cli::{
+    Command,
+    prowlarr::{ProwlarrCommand, get_command_handler::ProwlarrGetCommand},
+  };
+  use clap::CommandFactory;
+  use pretty_assertions::assert_eq;
+
+  #[test]
+  fn test_prowlarr_get_command_from() {
+    let command = ProwlarrGetCommand::SystemStatus;
+
+    let result = Command::from(command.clone());
+
+    assert_eq!(result, Command::Prowlarr(ProwlarrCommand::Get(command)));
+  }
+
+  mod cli {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    fn test_get_commands_have_no_arg_requirements(
+      #[values("host-config", "security-config", "system-status")] subcommand: &str,
+    ) {
+      let result = Cli::command().try_get_matches_from(["managarr", "prowlarr", "get", subcommand]);
+
+      assert_ok!(&result);
+    }
+  }
+
+  mod handler {
+    use std::sync::Arc;
+
+    use mockall::predicate::eq;
+    use rstest::rstest;
+    use serde_json::json;
+    use tokio::sync::Mutex;
+
+    use crate::{
+      app::App,
+      cli::{
+        CliCommandHandler,
+        prowlarr::get_command_handler::{ProwlarrGetCommand, ProwlarrGetCommandHandler},
+      },
+      models::{Serdeable, prowlarr_models::ProwlarrSerdeable},
+      network::{MockNetworkTrait, NetworkEvent, prowlarr_network::ProwlarrEvent},
+    };
+
+    #[rstest]
+    #[case(ProwlarrGetCommand::HostConfig, ProwlarrEvent::GetHostConfig)]
+    #[case(ProwlarrGetCommand::SecurityConfig, ProwlarrEvent::GetSecurityConfig)]
+    #[case(ProwlarrGetCommand::SystemStatus, ProwlarrEvent::GetStatus)]
+    #[tokio::test]
+    async fn test_handle_get_command(
+      #[case] get_command: ProwlarrGetCommand,
+      #[case] expected_prowlarr_event: ProwlarrEvent,
+    ) {
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(expected_prowlarr_event.into()))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Prowlarr(ProwlarrSerdeable::Value(
+            json!({"testResponse": "response"}),
+          )))
+        });
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+
+      let result = ProwlarrGetCommandHandler::with(&app_arc, get_command, &mut mock_network)
+        .handle()
+        .await;
+
+      assert_ok!(&result);
+    }
+  }
+}
@@ -0,0 +1,155 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use clap::Subcommand;
+use tokio::sync::Mutex;
+
+use super::ProwlarrCommand;
+use crate::{
+  app::App,
+  cli::{CliCommandHandler, Command},
+  network::{NetworkTrait, prowlarr_network::ProwlarrEvent},
+};
+
+#[cfg(test)]
+#[path = "list_command_handler_tests.rs"]
+mod list_command_handler_tests;
+
+#[derive(Debug, Clone, PartialEq, Eq, Subcommand)]
+pub enum ProwlarrListCommand {
+  #[command(about = "List all applications synced with Prowlarr")]
+  Applications,
+  #[command(about = "Fetch all Prowlarr history events")]
+  History {
+    #[arg(long, help = "How many history events to fetch", default_value_t = 500)]
+    events: u64,
+  },
+  #[command(about = "List all Prowlarr indexer proxies")]
+  IndexerProxies,
+  #[command(about = "List all Prowlarr indexers")]
+  Indexers,
+  #[command(about = "Fetch Prowlarr logs")]
+  Logs {
+    #[arg(long, help = "How many log events to fetch", default_value_t = 500)]
+    events: u64,
+    #[arg(
+      long,
+      help = "Output the logs in the same format as they appear in the log files"
+    )]
+    output_in_log_format: bool,
+  },
+  #[command(about = "List all queued events")]
+  QueuedEvents,
+  #[command(about = "List all Prowlarr tags")]
+  Tags,
+  #[command(about = "List all Prowlarr tasks")]
+  Tasks,
+  #[command(about = "List all Prowlarr updates")]
+  Updates,
+}
+
+impl From<ProwlarrListCommand> for Command {
+  fn from(value: ProwlarrListCommand) -> Self {
+    Command::Prowlarr(ProwlarrCommand::List(value))
+  }
+}
+
+pub(super) struct ProwlarrListCommandHandler<'a, 'b> {
+  app: &'a Arc<Mutex<App<'b>>>,
+  command: ProwlarrListCommand,
+  network: &'a mut dyn NetworkTrait,
+}
+
+impl<'a, 'b> CliCommandHandler<'a, 'b, ProwlarrListCommand> for ProwlarrListCommandHandler<'a, 'b> {
+  fn with(
+    app: &'a Arc<Mutex<App<'b>>>,
+    command: ProwlarrListCommand,
+    network: &'a mut dyn NetworkTrait,
+  ) -> Self {
+    ProwlarrListCommandHandler {
+      app,
+      command,
+      network,
+    }
+  }
+
+  async fn handle(self) -> Result<String> {
+    let result = match self.command {
+      ProwlarrListCommand::Applications => {
+        let resp = self
+          .network
+          .handle_network_event(ProwlarrEvent::GetApplications.into())
+          .await?;
+        serde_json::to_string_pretty(&resp)?
+      }
+      ProwlarrListCommand::History { events } => {
+        let resp = self
+          .network
+          .handle_network_event(ProwlarrEvent::GetHistory(events).into())
+          .await?;
+        serde_json::to_string_pretty(&resp)?
+      }
+      ProwlarrListCommand::IndexerProxies => {
+        let resp = self
+          .network
+          .handle_network_event(ProwlarrEvent::GetIndexerProxies.into())
+          .await?;
+        serde_json::to_string_pretty(&resp)?
+      }
+      ProwlarrListCommand::Indexers => {
+        let resp = self
+          .network
+          .handle_network_event(ProwlarrEvent::GetIndexers.into())
+          .await?;
+        serde_json::to_string_pretty(&resp)?
+      }
+      ProwlarrListCommand::Logs {
+        events,
+        output_in_log_format,
+      } => {
+        let logs = self
+          .network
+          .handle_network_event(ProwlarrEvent::GetLogs(events).into())
+          .await?;
+
+        if output_in_log_format {
+          let log_lines = &self.app.lock().await.data.prowlarr_data.logs.items;
+
+          serde_json::to_string_pretty(log_lines)?
+        } else {
+          serde_json::to_string_pretty(&logs)?
+        }
+      }
+      ProwlarrListCommand::QueuedEvents => {
+        let resp = self
+          .network
+          .handle_network_event(ProwlarrEvent::GetQueuedEvents.into())
+          .await?;
+        serde_json::to_string_pretty(&resp)?
+      }
+      ProwlarrListCommand::Tags => {
+        let resp = self
+          .network
+          .handle_network_event(ProwlarrEvent::GetTags.into())
+          .await?;
+        serde_json::to_string_pretty(&resp)?
+      }
+      ProwlarrListCommand::Tasks => {
+        let resp = self
+          .network
+          .handle_network_event(ProwlarrEvent::GetTasks.into())
+          .await?;
+        serde_json::to_string_pretty(&resp)?
+      }
+      ProwlarrListCommand::Updates => {
+        let resp = self
+          .network
+          .handle_network_event(ProwlarrEvent::GetUpdates.into())
+          .await?;
+        serde_json::to_string_pretty(&resp)?
+      }
+    };
+
+    Ok(result)
+  }
+}
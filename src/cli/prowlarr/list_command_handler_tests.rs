@@ -0,0 +1,234 @@
+#[cfg(test)]
+mod tests {
+  use crate::Cli;
+  use crate::cli::{
+    Command,
+    prowlarr::{ProwlarrCommand, list_command_handler::ProwlarrListCommand},
+  };
+  use clap::CommandFactory;
+  use pretty_assertions::assert_eq;
+
+  #[test]
+  fn test_prowlarr_list_command_from() {
+    let command = ProwlarrListCommand::Indexers;
+
+    let result = Command::from(command.clone());
+
+    assert_eq!(result, Command::Prowlarr(ProwlarrCommand::List(command)));
+  }
+
+  mod cli {
+    use super::*;
+    use clap::{Parser, error::ErrorKind};
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    #[rstest]
+    fn test_list_commands_have_no_arg_requirements(
+      #[values(
+        "applications",
+        "indexer-proxies",
+        "indexers",
+        "queued-events",
+        "tags",
+        "tasks",
+        "updates"
+      )]
+      subcommand: &str,
+    ) {
+      let result =
+        Cli::command().try_get_matches_from(["managarr", "prowlarr", "list", subcommand]);
+
+      assert_ok!(&result);
+    }
+
+    #[test]
+    fn test_list_history_events_flag_requires_arguments() {
+      let result = Cli::command()
+        .try_get_matches_from(["managarr", "prowlarr", "list", "history", "--events"]);
+
+      assert_err!(&result);
+      assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidValue);
+    }
+
+    #[test]
+    fn test_list_history_default_values() {
+      let expected_args = ProwlarrListCommand::History { events: 500 };
+      let result = Cli::try_parse_from(["managarr", "prowlarr", "list", "history"]);
+
+      assert_ok!(&result);
+
+      let Some(Command::Prowlarr(ProwlarrCommand::List(history_command))) = result.unwrap().command
+      else {
+        panic!("Unexpected command type");
+      };
+      assert_eq!(history_command, expected_args);
+    }
+
+    #[test]
+    fn test_list_logs_events_flag_requires_arguments() {
+      let result =
+        Cli::command().try_get_matches_from(["managarr", "prowlarr", "list", "logs", "--events"]);
+
+      assert_err!(&result);
+      assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidValue);
+    }
+
+    #[test]
+    fn test_list_logs_default_values() {
+      let expected_args = ProwlarrListCommand::Logs {
+        events: 500,
+        output_in_log_format: false,
+      };
+      let result = Cli::try_parse_from(["managarr", "prowlarr", "list", "logs"]);
+
+      assert_ok!(&result);
+
+      let Some(Command::Prowlarr(ProwlarrCommand::List(logs_command))) = result.unwrap().command
+      else {
+        panic!("Unexpected command type");
+      };
+      assert_eq!(logs_command, expected_args);
+    }
+  }
+
+  mod handler {
+    use std::sync::Arc;
+
+    use mockall::predicate::eq;
+    use pretty_assertions::assert_str_eq;
+    use rstest::rstest;
+    use serde_json::json;
+    use tokio::sync::Mutex;
+
+    use crate::cli::CliCommandHandler;
+    use crate::cli::prowlarr::list_command_handler::{
+      ProwlarrListCommand, ProwlarrListCommandHandler,
+    };
+    use crate::models::Serdeable;
+    use crate::models::prowlarr_models::ProwlarrSerdeable;
+    use crate::network::prowlarr_network::ProwlarrEvent;
+    use crate::{
+      app::App,
+      network::{MockNetworkTrait, NetworkEvent},
+    };
+
+    #[rstest]
+    #[case(ProwlarrListCommand::Applications, ProwlarrEvent::GetApplications)]
+    #[case(ProwlarrListCommand::IndexerProxies, ProwlarrEvent::GetIndexerProxies)]
+    #[case(ProwlarrListCommand::Indexers, ProwlarrEvent::GetIndexers)]
+    #[case(ProwlarrListCommand::QueuedEvents, ProwlarrEvent::GetQueuedEvents)]
+    #[case(ProwlarrListCommand::Tags, ProwlarrEvent::GetTags)]
+    #[case(ProwlarrListCommand::Tasks, ProwlarrEvent::GetTasks)]
+    #[case(ProwlarrListCommand::Updates, ProwlarrEvent::GetUpdates)]
+    #[tokio::test]
+    async fn test_handle_list_command(
+      #[case] list_command: ProwlarrListCommand,
+      #[case] expected_prowlarr_event: ProwlarrEvent,
+    ) {
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(expected_prowlarr_event.into()))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Prowlarr(ProwlarrSerdeable::Value(
+            json!({"testResponse": "response"}),
+          )))
+        });
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+
+      let result = ProwlarrListCommandHandler::with(&app_arc, list_command, &mut mock_network)
+        .handle()
+        .await;
+
+      assert_ok!(&result);
+    }
+
+    #[tokio::test]
+    async fn test_handle_list_history_command() {
+      let expected_events = 1000;
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(
+          ProwlarrEvent::GetHistory(expected_events).into(),
+        ))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Prowlarr(ProwlarrSerdeable::Value(
+            json!({"testResponse": "response"}),
+          )))
+        });
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+      let list_history_command = ProwlarrListCommand::History { events: 1000 };
+
+      let result =
+        ProwlarrListCommandHandler::with(&app_arc, list_history_command, &mut mock_network)
+          .handle()
+          .await;
+
+      assert_ok!(&result);
+    }
+
+    #[tokio::test]
+    async fn test_handle_list_logs_command() {
+      let expected_events = 1000;
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(
+          ProwlarrEvent::GetLogs(expected_events).into(),
+        ))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Prowlarr(ProwlarrSerdeable::Value(
+            json!({"testResponse": "response"}),
+          )))
+        });
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+      let list_logs_command = ProwlarrListCommand::Logs {
+        events: 1000,
+        output_in_log_format: false,
+      };
+
+      let result = ProwlarrListCommandHandler::with(&app_arc, list_logs_command, &mut mock_network)
+        .handle()
+        .await;
+
+      assert_ok!(&result);
+    }
+
+    #[tokio::test]
+    async fn test_handle_list_logs_command_output_in_log_format_uses_prowlarr_logs() {
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(ProwlarrEvent::GetLogs(500).into()))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Prowlarr(ProwlarrSerdeable::Value(
+            json!({"testResponse": "response"}),
+          )))
+        });
+      let mut app = App::test_default();
+      app
+        .data
+        .prowlarr_data
+        .logs
+        .set_items(vec!["Prowlarr log line".into()]);
+      let app_arc = Arc::new(Mutex::new(app));
+      let list_logs_command = ProwlarrListCommand::Logs {
+        events: 500,
+        output_in_log_format: true,
+      };
+
+      let result = ProwlarrListCommandHandler::with(&app_arc, list_logs_command, &mut mock_network)
+        .handle()
+        .await;
+
+      assert_ok!(&result);
+      assert_str_eq!(result.unwrap(), "[\n  \"Prowlarr log line\"\n]");
+    }
+  }
+}
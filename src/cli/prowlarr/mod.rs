@@ -0,0 +1,199 @@
+use std::sync::Arc;
+
+use add_command_handler::{ProwlarrAddCommand, ProwlarrAddCommandHandler};
+use anyhow::Result;
+use clap::Subcommand;
+use delete_command_handler::{ProwlarrDeleteCommand, ProwlarrDeleteCommandHandler};
+use get_command_handler::{ProwlarrGetCommand, ProwlarrGetCommandHandler};
+use list_command_handler::{ProwlarrListCommand, ProwlarrListCommandHandler};
+use tokio::sync::Mutex;
+
+use super::{CliCommandHandler, Command};
+use crate::models::prowlarr_models::{
+  ProwlarrReleaseDownloadBody, ProwlarrSearchParams, ProwlarrTaskName,
+};
+use crate::network::prowlarr_network::ProwlarrEvent;
+use crate::{app::App, network::NetworkTrait};
+
+mod add_command_handler;
+mod delete_command_handler;
+mod get_command_handler;
+mod list_command_handler;
+
+#[cfg(test)]
+#[path = "prowlarr_command_tests.rs"]
+mod prowlarr_command_tests;
+
+#[derive(Debug, Clone, PartialEq, Eq, Subcommand)]
+pub enum ProwlarrCommand {
+  #[command(
+    subcommand,
+    about = "Commands to add or create new resources within your Prowlarr instance"
+  )]
+  Add(ProwlarrAddCommand),
+  #[command(
+    subcommand,
+    about = "Commands to delete resources from your Prowlarr instance"
+  )]
+  Delete(ProwlarrDeleteCommand),
+  #[command(
+    subcommand,
+    about = "Commands to fetch details of the resources in your Prowlarr instance"
+  )]
+  Get(ProwlarrGetCommand),
+  #[command(
+    subcommand,
+    about = "Commands to list attributes from your Prowlarr instance"
+  )]
+  List(ProwlarrListCommand),
+  #[command(about = "Grab the given release and send it to your download client")]
+  GrabRelease {
+    #[arg(long, help = "The GUID of the release to grab", required = true)]
+    guid: String,
+    #[arg(
+      long,
+      help = "The indexer ID to grab the release from",
+      required = true
+    )]
+    indexer_id: i64,
+  },
+  #[command(about = "Search your Prowlarr indexers for releases matching the given query")]
+  SearchReleases {
+    #[arg(long, help = "The search query", required = true)]
+    query: String,
+    #[arg(
+      long,
+      help = "The IDs of the indexers to search. Searches all indexers if not specified",
+      value_delimiter = ','
+    )]
+    indexer_ids: Vec<i64>,
+    #[arg(
+      long,
+      help = "The Newznab category IDs to restrict the search to",
+      value_delimiter = ','
+    )]
+    categories: Vec<i64>,
+    #[arg(long, help = "The maximum number of results to return")]
+    limit: Option<u64>,
+  },
+  #[command(about = "Start the specified Prowlarr task")]
+  StartTask {
+    #[arg(
+      long,
+      help = "The name of the task to trigger",
+      value_enum,
+      required = true
+    )]
+    task_name: ProwlarrTaskName,
+  },
+  #[command(
+    about = "Test the indexer with the given ID. Note that a successful test returns an empty JSON body; i.e. '{}'"
+  )]
+  TestIndexer {
+    #[arg(long, help = "The ID of the indexer to test", required = true)]
+    indexer_id: i64,
+  },
+  #[command(about = "Test all Prowlarr indexers")]
+  TestAllIndexers,
+}
+
+impl From<ProwlarrCommand> for Command {
+  fn from(prowlarr_command: ProwlarrCommand) -> Command {
+    Command::Prowlarr(prowlarr_command)
+  }
+}
+
+pub(super) struct ProwlarrCliHandler<'a, 'b> {
+  app: &'a Arc<Mutex<App<'b>>>,
+  command: ProwlarrCommand,
+  network: &'a mut dyn NetworkTrait,
+}
+
+impl<'a, 'b> CliCommandHandler<'a, 'b, ProwlarrCommand> for ProwlarrCliHandler<'a, 'b> {
+  fn with(
+    app: &'a Arc<Mutex<App<'b>>>,
+    command: ProwlarrCommand,
+    network: &'a mut dyn NetworkTrait,
+  ) -> Self {
+    ProwlarrCliHandler {
+      app,
+      command,
+      network,
+    }
+  }
+
+  async fn handle(self) -> Result<String> {
+    let result = match self.command {
+      ProwlarrCommand::Add(add_command) => {
+        ProwlarrAddCommandHandler::with(self.app, add_command, self.network)
+          .handle()
+          .await?
+      }
+      ProwlarrCommand::Delete(delete_command) => {
+        ProwlarrDeleteCommandHandler::with(self.app, delete_command, self.network)
+          .handle()
+          .await?
+      }
+      ProwlarrCommand::Get(get_command) => {
+        ProwlarrGetCommandHandler::with(self.app, get_command, self.network)
+          .handle()
+          .await?
+      }
+      ProwlarrCommand::List(list_command) => {
+        ProwlarrListCommandHandler::with(self.app, list_command, self.network)
+          .handle()
+          .await?
+      }
+      ProwlarrCommand::GrabRelease { guid, indexer_id } => {
+        let params = ProwlarrReleaseDownloadBody { guid, indexer_id };
+        let resp = self
+          .network
+          .handle_network_event(ProwlarrEvent::GrabRelease(params).into())
+          .await?;
+        serde_json::to_string_pretty(&resp)?
+      }
+      ProwlarrCommand::SearchReleases {
+        query,
+        indexer_ids,
+        categories,
+        limit,
+      } => {
+        let params = ProwlarrSearchParams {
+          query,
+          indexer_ids,
+          categories,
+          limit,
+        };
+        let resp = self
+          .network
+          .handle_network_event(ProwlarrEvent::SearchReleases(params).into())
+          .await?;
+        serde_json::to_string_pretty(&resp)?
+      }
+      ProwlarrCommand::StartTask { task_name } => {
+        let resp = self
+          .network
+          .handle_network_event(ProwlarrEvent::StartTask(task_name).into())
+          .await?;
+        serde_json::to_string_pretty(&resp)?
+      }
+      ProwlarrCommand::TestIndexer { indexer_id } => {
+        let resp = self
+          .network
+          .handle_network_event(ProwlarrEvent::TestIndexer(indexer_id).into())
+          .await?;
+        serde_json::to_string_pretty(&resp)?
+      }
+      ProwlarrCommand::TestAllIndexers => {
+        println!("Testing all Prowlarr indexers. This may take a minute...");
+        let resp = self
+          .network
+          .handle_network_event(ProwlarrEvent::TestAllIndexers.into())
+          .await?;
+        serde_json::to_string_pretty(&resp)?
+      }
+    };
+
+    Ok(result)
+  }
+}
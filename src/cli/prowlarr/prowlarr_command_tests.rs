@@ -0,0 +1,474 @@
+#[cfg(test)]
+mod tests {
+  use crate::Cli;
+  use crate::cli::{
+    Command,
+    prowlarr::{ProwlarrCommand, list_command_handler::ProwlarrListCommand},
+  };
+  use clap::CommandFactory;
+  use pretty_assertions::assert_eq;
+
+  #[test]
+  fn test_prowlarr_command_from() {
+    let command = ProwlarrCommand::List(ProwlarrListCommand::Indexers);
+
+    let result = Command::from(command.clone());
+
+    assert_eq!(result, Command::Prowlarr(command));
+  }
+
+  mod cli {
+    use super::*;
+    use clap::{Parser, error::ErrorKind};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_test_all_indexers_has_no_arg_requirements() {
+      let result =
+        Cli::command().try_get_matches_from(["managarr", "prowlarr", "test-all-indexers"]);
+
+      assert_ok!(&result);
+    }
+
+    #[test]
+    fn test_grab_release_requires_guid() {
+      let result = Cli::command().try_get_matches_from([
+        "managarr",
+        "prowlarr",
+        "grab-release",
+        "--indexer-id",
+        "1",
+      ]);
+
+      assert_err!(&result);
+      assert_eq!(
+        result.unwrap_err().kind(),
+        ErrorKind::MissingRequiredArgument
+      );
+    }
+
+    #[test]
+    fn test_grab_release_requires_indexer_id() {
+      let result = Cli::command().try_get_matches_from([
+        "managarr",
+        "prowlarr",
+        "grab-release",
+        "--guid",
+        "1",
+      ]);
+
+      assert_err!(&result);
+      assert_eq!(
+        result.unwrap_err().kind(),
+        ErrorKind::MissingRequiredArgument
+      );
+    }
+
+    #[test]
+    fn test_grab_release_requirements_satisfied() {
+      let result = Cli::command().try_get_matches_from([
+        "managarr",
+        "prowlarr",
+        "grab-release",
+        "--guid",
+        "1",
+        "--indexer-id",
+        "1",
+      ]);
+
+      assert_ok!(&result);
+    }
+
+    #[test]
+    fn test_search_releases_requires_query() {
+      let result = Cli::command().try_get_matches_from(["managarr", "prowlarr", "search-releases"]);
+
+      assert_err!(&result);
+      assert_eq!(
+        result.unwrap_err().kind(),
+        ErrorKind::MissingRequiredArgument
+      );
+    }
+
+    #[test]
+    fn test_search_releases_default_values() {
+      let expected_args = ProwlarrCommand::SearchReleases {
+        query: "test".to_owned(),
+        indexer_ids: Vec::new(),
+        categories: Vec::new(),
+        limit: None,
+      };
+
+      let result =
+        Cli::try_parse_from(["managarr", "prowlarr", "search-releases", "--query", "test"]);
+
+      assert_ok!(&result);
+
+      let Some(Command::Prowlarr(search_command)) = result.unwrap().command else {
+        panic!("Unexpected command type");
+      };
+      assert_eq!(search_command, expected_args);
+    }
+
+    #[test]
+    fn test_search_releases_all_args_defined() {
+      let expected_args = ProwlarrCommand::SearchReleases {
+        query: "test".to_owned(),
+        indexer_ids: vec![1, 2],
+        categories: vec![2000, 5000],
+        limit: Some(50),
+      };
+
+      let result = Cli::try_parse_from([
+        "managarr",
+        "prowlarr",
+        "search-releases",
+        "--query",
+        "test",
+        "--indexer-ids",
+        "1,2",
+        "--categories",
+        "2000,5000",
+        "--limit",
+        "50",
+      ]);
+
+      assert_ok!(&result);
+
+      let Some(Command::Prowlarr(search_command)) = result.unwrap().command else {
+        panic!("Unexpected command type");
+      };
+      assert_eq!(search_command, expected_args);
+    }
+
+    #[test]
+    fn test_start_task_requires_task_name() {
+      let result = Cli::command().try_get_matches_from(["managarr", "prowlarr", "start-task"]);
+
+      assert_err!(&result);
+      assert_eq!(
+        result.unwrap_err().kind(),
+        ErrorKind::MissingRequiredArgument
+      );
+    }
+
+    #[test]
+    fn test_start_task_task_name_validation() {
+      let result = Cli::command().try_get_matches_from([
+        "managarr",
+        "prowlarr",
+        "start-task",
+        "--task-name",
+        "test",
+      ]);
+
+      assert_err!(&result);
+      assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidValue);
+    }
+
+    #[test]
+    fn test_start_task_requirements_satisfied() {
+      let result = Cli::command().try_get_matches_from([
+        "managarr",
+        "prowlarr",
+        "start-task",
+        "--task-name",
+        "application-indexer-sync",
+      ]);
+
+      assert_ok!(&result);
+    }
+
+    #[test]
+    fn test_test_indexer_requires_indexer_id() {
+      let result = Cli::command().try_get_matches_from(["managarr", "prowlarr", "test-indexer"]);
+
+      assert_err!(&result);
+      assert_eq!(
+        result.unwrap_err().kind(),
+        ErrorKind::MissingRequiredArgument
+      );
+    }
+
+    #[test]
+    fn test_test_indexer_requirements_satisfied() {
+      let result = Cli::command().try_get_matches_from([
+        "managarr",
+        "prowlarr",
+        "test-indexer",
+        "--indexer-id",
+        "1",
+      ]);
+
+      assert_ok!(&result);
+    }
+  }
+
+  mod handler {
+    use std::sync::Arc;
+
+    use mockall::predicate::eq;
+    use serde_json::json;
+    use tokio::sync::Mutex;
+
+    use crate::cli::prowlarr::add_command_handler::ProwlarrAddCommand;
+    use crate::cli::prowlarr::delete_command_handler::ProwlarrDeleteCommand;
+    use crate::cli::prowlarr::get_command_handler::ProwlarrGetCommand;
+    use crate::models::prowlarr_models::{
+      ProwlarrReleaseDownloadBody, ProwlarrSearchParams, ProwlarrTaskName,
+    };
+    use crate::models::servarr_models::AddIndexerParams;
+    use crate::{
+      app::App,
+      cli::{
+        CliCommandHandler,
+        prowlarr::{
+          ProwlarrCliHandler, ProwlarrCommand, list_command_handler::ProwlarrListCommand,
+        },
+      },
+      models::{Serdeable, prowlarr_models::ProwlarrSerdeable},
+      network::{MockNetworkTrait, NetworkEvent, prowlarr_network::ProwlarrEvent},
+    };
+
+    #[tokio::test]
+    async fn test_prowlarr_cli_handler_delegates_add_commands_to_the_add_command_handler() {
+      let expected_add_indexer_params = AddIndexerParams {
+        implementation: "1337x".to_owned(),
+        name: "Test".to_owned(),
+        app_profile_id: Some(1),
+        ..AddIndexerParams::default()
+      };
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(
+          ProwlarrEvent::AddIndexer(expected_add_indexer_params).into(),
+        ))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Prowlarr(ProwlarrSerdeable::Value(
+            json!({"testResponse": "response"}),
+          )))
+        });
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+      let add_indexer_command = ProwlarrCommand::Add(ProwlarrAddCommand::Indexer {
+        implementation: "1337x".to_owned(),
+        name: "Test".to_owned(),
+        disable: false,
+        app_profile_id: 1,
+        priority: 25,
+        tag: vec![],
+        field: vec![],
+      });
+
+      let result = ProwlarrCliHandler::with(&app_arc, add_indexer_command, &mut mock_network)
+        .handle()
+        .await;
+
+      assert_ok!(&result);
+    }
+
+    #[tokio::test]
+    async fn test_prowlarr_cli_handler_delegates_delete_commands_to_the_delete_command_handler() {
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(ProwlarrEvent::DeleteIndexer(1).into()))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Prowlarr(ProwlarrSerdeable::Value(
+            json!({"testResponse": "response"}),
+          )))
+        });
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+      let delete_indexer_command =
+        ProwlarrCommand::Delete(ProwlarrDeleteCommand::Indexer { indexer_id: 1 });
+
+      let result = ProwlarrCliHandler::with(&app_arc, delete_indexer_command, &mut mock_network)
+        .handle()
+        .await;
+
+      assert_ok!(&result);
+    }
+
+    #[tokio::test]
+    async fn test_prowlarr_cli_handler_delegates_get_commands_to_the_get_command_handler() {
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(ProwlarrEvent::GetStatus.into()))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Prowlarr(ProwlarrSerdeable::Value(
+            json!({"testResponse": "response"}),
+          )))
+        });
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+      let get_system_status_command = ProwlarrCommand::Get(ProwlarrGetCommand::SystemStatus);
+
+      let result = ProwlarrCliHandler::with(&app_arc, get_system_status_command, &mut mock_network)
+        .handle()
+        .await;
+
+      assert_ok!(&result);
+    }
+
+    #[tokio::test]
+    async fn test_prowlarr_cli_handler_delegates_list_commands_to_the_list_command_handler() {
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(ProwlarrEvent::GetIndexers.into()))
+        .times(1)
+        .returning(|_| Ok(Serdeable::Prowlarr(ProwlarrSerdeable::Indexers(vec![]))));
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+      let list_indexers_command = ProwlarrCommand::List(ProwlarrListCommand::Indexers);
+
+      let result = ProwlarrCliHandler::with(&app_arc, list_indexers_command, &mut mock_network)
+        .handle()
+        .await;
+
+      assert_ok!(&result);
+    }
+
+    #[tokio::test]
+    async fn test_grab_release_command() {
+      let expected_release_download_body = ProwlarrReleaseDownloadBody {
+        guid: "guid".to_owned(),
+        indexer_id: 1,
+      };
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(
+          ProwlarrEvent::GrabRelease(expected_release_download_body).into(),
+        ))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Prowlarr(ProwlarrSerdeable::Value(
+            json!({"testResponse": "response"}),
+          )))
+        });
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+      let grab_release_command = ProwlarrCommand::GrabRelease {
+        guid: "guid".to_owned(),
+        indexer_id: 1,
+      };
+
+      let result = ProwlarrCliHandler::with(&app_arc, grab_release_command, &mut mock_network)
+        .handle()
+        .await;
+
+      assert_ok!(&result);
+    }
+
+    #[tokio::test]
+    async fn test_search_releases_command() {
+      let expected_search_params = ProwlarrSearchParams {
+        query: "test".to_owned(),
+        indexer_ids: vec![1],
+        categories: vec![2000],
+        limit: Some(10),
+      };
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(
+          ProwlarrEvent::SearchReleases(expected_search_params).into(),
+        ))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Prowlarr(ProwlarrSerdeable::Value(
+            json!({"testResponse": "response"}),
+          )))
+        });
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+      let search_releases_command = ProwlarrCommand::SearchReleases {
+        query: "test".to_owned(),
+        indexer_ids: vec![1],
+        categories: vec![2000],
+        limit: Some(10),
+      };
+
+      let result = ProwlarrCliHandler::with(&app_arc, search_releases_command, &mut mock_network)
+        .handle()
+        .await;
+
+      assert_ok!(&result);
+    }
+
+    #[tokio::test]
+    async fn test_start_task_command() {
+      let expected_task_name = ProwlarrTaskName::ApplicationIndexerSync;
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(
+          ProwlarrEvent::StartTask(expected_task_name).into(),
+        ))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Prowlarr(ProwlarrSerdeable::Value(
+            json!({"testResponse": "response"}),
+          )))
+        });
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+      let start_task_command = ProwlarrCommand::StartTask {
+        task_name: ProwlarrTaskName::ApplicationIndexerSync,
+      };
+
+      let result = ProwlarrCliHandler::with(&app_arc, start_task_command, &mut mock_network)
+        .handle()
+        .await;
+
+      assert_ok!(&result);
+    }
+
+    #[tokio::test]
+    async fn test_test_indexer_command() {
+      let expected_indexer_id = 1;
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(
+          ProwlarrEvent::TestIndexer(expected_indexer_id).into(),
+        ))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Prowlarr(ProwlarrSerdeable::Value(
+            json!({"testResponse": "response"}),
+          )))
+        });
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+      let test_indexer_command = ProwlarrCommand::TestIndexer { indexer_id: 1 };
+
+      let result = ProwlarrCliHandler::with(&app_arc, test_indexer_command, &mut mock_network)
+        .handle()
+        .await;
+
+      assert_ok!(&result);
+    }
+
+    #[tokio::test]
+    async fn test_test_all_indexers_command() {
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(ProwlarrEvent::TestAllIndexers.into()))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Prowlarr(ProwlarrSerdeable::Value(
+            json!({"testResponse": "response"}),
+          )))
+        });
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+      let test_all_indexers_command = ProwlarrCommand::TestAllIndexers;
+
+      let result = ProwlarrCliHandler::with(&app_arc, test_all_indexers_command, &mut mock_network)
+        .handle()
+        .await;
+
+      assert_ok!(&result);
+    }
+  }
+}
@@ -1,14 +1,16 @@
+use std::fs;
+use std::path::PathBuf;
 use std::sync::Arc;
 
-use anyhow::Result;
-use clap::{ArgAction, Subcommand};
+use anyhow::{Context, Result};
+use clap::{ArgAction, ArgGroup, Subcommand};
 use tokio::sync::Mutex;
 
 use super::RadarrCommand;
-use crate::models::servarr_models::AddRootFolderBody;
+use crate::models::servarr_models::{AddDownloadClientParams, AddIndexerParams, AddRootFolderBody};
 use crate::{
   app::App,
-  cli::{CliCommandHandler, Command},
+  cli::{CliCommandHandler, Command, parse_key_value},
   models::radarr_models::{AddMovieBody, AddMovieOptions, MinimumAvailability, MovieMonitor},
   network::{NetworkTrait, radarr_network::RadarrEvent},
 };
@@ -68,6 +70,109 @@ pub enum RadarrAddCommand {
     )]
     no_search_for_movie: bool,
   },
+  #[command(
+    about = "Add a new indexer using one of the implementations in the Radarr indexer schema"
+  )]
+  Indexer {
+    #[arg(
+      long,
+      help = "The implementation of the indexer to add (e.g. 'Newznab' or 'Torznab')",
+      required = true
+    )]
+    implementation: String,
+    #[arg(long, help = "The name of the indexer", required = true)]
+    name: String,
+    #[arg(
+      long,
+      help = "Disable using this indexer when Radarr periodically looks for releases via RSS Sync"
+    )]
+    disable_rss: bool,
+    #[arg(
+      long,
+      help = "Disable using this indexer whenever automatic searches are performed via the UI or by Radarr"
+    )]
+    disable_automatic_search: bool,
+    #[arg(
+      long,
+      help = "Disable using this indexer whenever an interactive search is performed"
+    )]
+    disable_interactive_search: bool,
+    #[arg(
+      long,
+      help = "Indexer Priority from 1 (Highest) to 50 (Lowest)",
+      default_value_t = 25
+    )]
+    priority: i64,
+    #[arg(
+      long,
+      help = "Only use this indexer for movies with at least one matching tag ID. Leave blank to use with all movies.",
+      value_parser,
+      action = ArgAction::Append
+    )]
+    tag: Vec<i64>,
+    #[arg(
+      long,
+      help = "A setting of the indexer implementation in the form 'name=value' (e.g. 'baseUrl=https://indexer.example'). Can be specified multiple times",
+      value_parser = parse_key_value,
+      action = ArgAction::Append
+    )]
+    field: Vec<(String, String)>,
+  },
+  #[command(
+    about = "Add a new download client using one of the implementations in the Radarr download client schema"
+  )]
+  DownloadClient {
+    #[arg(
+      long,
+      help = "The implementation of the download client to add (e.g. 'QBittorrent' or 'Sabnzbd')",
+      required = true
+    )]
+    implementation: String,
+    #[arg(long, help = "The name of the download client", required = true)]
+    name: String,
+    #[arg(long, help = "Add the download client without enabling it")]
+    disable: bool,
+    #[arg(
+      long,
+      help = "Download Client Priority from 1 (Highest) to 50 (Lowest). Round-Robin is used for clients with the same priority",
+      default_value_t = 1
+    )]
+    priority: i64,
+    #[arg(
+      long,
+      help = "Don't remove imported downloads from the download client history"
+    )]
+    disable_remove_completed_downloads: bool,
+    #[arg(
+      long,
+      help = "Don't remove failed downloads from the download client history"
+    )]
+    disable_remove_failed_downloads: bool,
+    #[arg(
+      long,
+      help = "Only use this download client for movies with at least one matching tag ID. Leave blank to use with all movies.",
+      value_parser,
+      action = ArgAction::Append
+    )]
+    tag: Vec<i64>,
+    #[arg(
+      long,
+      help = "A setting of the download client implementation in the form 'name=value' (e.g. 'host=localhost'). Can be specified multiple times",
+      value_parser = parse_key_value,
+      action = ArgAction::Append
+    )]
+    field: Vec<(String, String)>,
+  },
+  #[command(
+    about = "Import a custom format from JSON, such as the custom formats published by TRaSH Guides",
+    group(ArgGroup::new("custom_format_source").args(["file", "json"]).required(true))
+  )]
+  CustomFormat {
+    #[arg(long, help = "The path to a JSON file containing the custom format")]
+    file: Option<PathBuf>,
+    #[arg(long, help = "The custom format as a JSON string")]
+    json: Option<String>,
+  },
   #[command(about = "Add a new root folder")]
   RootFolder {
     #[arg(long, help = "The path of the new root folder", required = true)]
@@ -137,6 +242,72 @@ impl<'a, 'b> CliCommandHandler<'a, 'b, RadarrAddCommand> for RadarrAddCommandHan
           .await?;
         serde_json::to_string_pretty(&resp)?
       }
+      RadarrAddCommand::Indexer {
+        implementation,
+        name,
+        disable_rss,
+        disable_automatic_search,
+        disable_interactive_search,
+        priority,
+        tag: tags,
+        field: fields,
+      } => {
+        let add_indexer_params = AddIndexerParams {
+          implementation,
+          name,
+          enable_rss: !disable_rss,
+          enable_automatic_search: !disable_automatic_search,
+          enable_interactive_search: !disable_interactive_search,
+          priority,
+          tags,
+          fields,
+          ..AddIndexerParams::default()
+        };
+        let resp = self
+          .network
+          .handle_network_event(RadarrEvent::AddIndexer(add_indexer_params).into())
+          .await?;
+        serde_json::to_string_pretty(&resp)?
+      }
+      RadarrAddCommand::DownloadClient {
+        implementation,
+        name,
+        disable,
+        priority,
+        disable_remove_completed_downloads,
+        disable_remove_failed_downloads,
+        tag: tags,
+        field: fields,
+      } => {
+        let add_download_client_params = AddDownloadClientParams {
+          implementation,
+          name,
+          enable: !disable,
+          priority,
+          remove_completed_downloads: !disable_remove_completed_downloads,
+          remove_failed_downloads: !disable_remove_failed_downloads,
+          tags,
+          tag_input_string: None,
+          fields,
+        };
+        let resp = self
+          .network
+          .handle_network_event(RadarrEvent::AddDownloadClient(add_download_client_params).into())
+          .await?;
+        serde_json::to_string_pretty(&resp)?
+      }
+      RadarrAddCommand::CustomFormat { file, json } => {
+        let custom_format_json = match file {
+          Some(path) => fs::read_to_string(&path)
+            .with_context(|| format!("Unable to read custom format file '{}'", path.display()))?,
+          None => json.unwrap_or_default(),
+        };
+        let resp = self
+          .network
+          .handle_network_event(RadarrEvent::ImportCustomFormat(custom_format_json).into())
+          .await?;
+        serde_json::to_string_pretty(&resp)?
+      }
       RadarrAddCommand::RootFolder { root_folder_path } => {
         let add_root_folder_body = AddRootFolderBody {
           path: root_folder_path,
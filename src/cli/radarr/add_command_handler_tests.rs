@@ -294,6 +294,315 @@ mod tests {
       assert_eq!(add_command, expected_args);
     }
 
+    #[test]
+    fn test_add_custom_format_requires_arguments() {
+      let result =
+        Cli::command().try_get_matches_from(["managarr", "radarr", "add", "custom-format"]);
+
+      assert_err!(&result);
+      assert_eq!(
+        result.unwrap_err().kind(),
+        ErrorKind::MissingRequiredArgument
+      );
+    }
+
+    #[test]
+    fn test_add_custom_format_file_and_json_conflict() {
+      let result = Cli::command().try_get_matches_from([
+        "managarr",
+        "radarr",
+        "add",
+        "custom-format",
+        "--file",
+        "x265.json",
+        "--json",
+        "{}",
+      ]);
+
+      assert_err!(&result);
+      assert_eq!(result.unwrap_err().kind(), ErrorKind::ArgumentConflict);
+    }
+
+    #[rstest]
+    fn test_add_custom_format_source_args(
+      #[values(
+        ("--file", RadarrAddCommand::CustomFormat { file: Some("x265.json".into()), json: None }),
+        ("--json", RadarrAddCommand::CustomFormat { file: None, json: Some("x265.json".to_owned()) })
+      )]
+      flag_and_expected_args: (&str, RadarrAddCommand),
+    ) {
+      let (flag, expected_args) = flag_and_expected_args;
+
+      let result = Cli::try_parse_from([
+        "managarr",
+        "radarr",
+        "add",
+        "custom-format",
+        flag,
+        "x265.json",
+      ]);
+
+      assert_ok!(&result);
+
+      let Some(Command::Radarr(RadarrCommand::Add(add_command))) = result.unwrap().command else {
+        panic!("Unexpected command type");
+      };
+      assert_eq!(add_command, expected_args);
+    }
+
+    #[test]
+    fn test_add_download_client_requires_arguments() {
+      let result =
+        Cli::command().try_get_matches_from(["managarr", "radarr", "add", "download-client"]);
+
+      assert_err!(&result);
+      assert_eq!(
+        result.unwrap_err().kind(),
+        ErrorKind::MissingRequiredArgument
+      );
+    }
+
+    #[test]
+    fn test_add_download_client_requires_name() {
+      let result = Cli::command().try_get_matches_from([
+        "managarr",
+        "radarr",
+        "add",
+        "download-client",
+        "--implementation",
+        "QBittorrent",
+      ]);
+
+      assert_err!(&result);
+      assert_eq!(
+        result.unwrap_err().kind(),
+        ErrorKind::MissingRequiredArgument
+      );
+    }
+
+    #[test]
+    fn test_add_download_client_field_requires_key_value_format() {
+      let result = Cli::command().try_get_matches_from([
+        "managarr",
+        "radarr",
+        "add",
+        "download-client",
+        "--implementation",
+        "QBittorrent",
+        "--name",
+        "Test",
+        "--field",
+        "host",
+      ]);
+
+      assert_err!(&result);
+      assert_eq!(result.unwrap_err().kind(), ErrorKind::ValueValidation);
+    }
+
+    #[test]
+    fn test_add_download_client_defaults() {
+      let expected_args = RadarrAddCommand::DownloadClient {
+        implementation: "QBittorrent".to_owned(),
+        name: "Test".to_owned(),
+        disable: false,
+        priority: 1,
+        disable_remove_completed_downloads: false,
+        disable_remove_failed_downloads: false,
+        tag: vec![],
+        field: vec![],
+      };
+
+      let result = Cli::try_parse_from([
+        "managarr",
+        "radarr",
+        "add",
+        "download-client",
+        "--implementation",
+        "QBittorrent",
+        "--name",
+        "Test",
+      ]);
+
+      assert_ok!(&result);
+      let Some(Command::Radarr(RadarrCommand::Add(add_command))) = result.unwrap().command else {
+        panic!("Unexpected command type")
+      };
+      assert_eq!(add_command, expected_args);
+    }
+
+    #[test]
+    fn test_add_download_client_all_args() {
+      let expected_args = RadarrAddCommand::DownloadClient {
+        implementation: "QBittorrent".to_owned(),
+        name: "Test".to_owned(),
+        disable: true,
+        priority: 2,
+        disable_remove_completed_downloads: true,
+        disable_remove_failed_downloads: true,
+        tag: vec![1, 2],
+        field: vec![
+          ("host".to_owned(), "localhost".to_owned()),
+          ("port".to_owned(), "8080".to_owned()),
+        ],
+      };
+
+      let result = Cli::try_parse_from([
+        "managarr",
+        "radarr",
+        "add",
+        "download-client",
+        "--implementation",
+        "QBittorrent",
+        "--name",
+        "Test",
+        "--disable",
+        "--priority",
+        "2",
+        "--disable-remove-completed-downloads",
+        "--disable-remove-failed-downloads",
+        "--tag",
+        "1",
+        "--tag",
+        "2",
+        "--field",
+        "host=localhost",
+        "--field",
+        "port=8080",
+      ]);
+
+      assert_ok!(&result);
+      let Some(Command::Radarr(RadarrCommand::Add(add_command))) = result.unwrap().command else {
+        panic!("Unexpected command type")
+      };
+      assert_eq!(add_command, expected_args);
+    }
+
+    #[test]
+    fn test_add_indexer_requires_arguments() {
+      let result = Cli::command().try_get_matches_from(["managarr", "radarr", "add", "indexer"]);
+
+      assert_err!(&result);
+      assert_eq!(
+        result.unwrap_err().kind(),
+        ErrorKind::MissingRequiredArgument
+      );
+    }
+
+    #[test]
+    fn test_add_indexer_requires_name() {
+      let result = Cli::command().try_get_matches_from([
+        "managarr",
+        "radarr",
+        "add",
+        "indexer",
+        "--implementation",
+        "Torznab",
+      ]);
+
+      assert_err!(&result);
+      assert_eq!(
+        result.unwrap_err().kind(),
+        ErrorKind::MissingRequiredArgument
+      );
+    }
+
+    #[test]
+    fn test_add_indexer_field_requires_key_value_format() {
+      let result = Cli::command().try_get_matches_from([
+        "managarr",
+        "radarr",
+        "add",
+        "indexer",
+        "--implementation",
+        "Torznab",
+        "--name",
+        "Test",
+        "--field",
+        "baseUrl",
+      ]);
+
+      assert_err!(&result);
+      assert_eq!(result.unwrap_err().kind(), ErrorKind::ValueValidation);
+    }
+
+    #[test]
+    fn test_add_indexer_defaults() {
+      let expected_args = RadarrAddCommand::Indexer {
+        implementation: "Torznab".to_owned(),
+        name: "Test".to_owned(),
+        disable_rss: false,
+        disable_automatic_search: false,
+        disable_interactive_search: false,
+        priority: 25,
+        tag: vec![],
+        field: vec![],
+      };
+
+      let result = Cli::try_parse_from([
+        "managarr",
+        "radarr",
+        "add",
+        "indexer",
+        "--implementation",
+        "Torznab",
+        "--name",
+        "Test",
+      ]);
+
+      assert_ok!(&result);
+      let Some(Command::Radarr(RadarrCommand::Add(add_command))) = result.unwrap().command else {
+        panic!("Unexpected command type")
+      };
+      assert_eq!(add_command, expected_args);
+    }
+
+    #[test]
+    fn test_add_indexer_all_args() {
+      let expected_args = RadarrAddCommand::Indexer {
+        implementation: "Torznab".to_owned(),
+        name: "Test".to_owned(),
+        disable_rss: true,
+        disable_automatic_search: true,
+        disable_interactive_search: true,
+        priority: 1,
+        tag: vec![1, 2],
+        field: vec![
+          ("baseUrl".to_owned(), "https://test.com".to_owned()),
+          ("apiKey".to_owned(), "test1234".to_owned()),
+        ],
+      };
+
+      let result = Cli::try_parse_from([
+        "managarr",
+        "radarr",
+        "add",
+        "indexer",
+        "--implementation",
+        "Torznab",
+        "--name",
+        "Test",
+        "--disable-rss",
+        "--disable-automatic-search",
+        "--disable-interactive-search",
+        "--priority",
+        "1",
+        "--tag",
+        "1",
+        "--tag",
+        "2",
+        "--field",
+        "baseUrl=https://test.com",
+        "--field",
+        "apiKey=test1234",
+      ]);
+
+      assert_ok!(&result);
+      let Some(Command::Radarr(RadarrCommand::Add(add_command))) = result.unwrap().command else {
+        panic!("Unexpected command type")
+      };
+      assert_eq!(add_command, expected_args);
+    }
+
     #[test]
     fn test_add_root_folder_requires_arguments() {
       let result =
@@ -372,8 +681,11 @@ mod tests {
 
     use super::*;
     use mockall::predicate::eq;
+    use pretty_assertions::assert_eq;
 
-    use crate::models::servarr_models::AddRootFolderBody;
+    use crate::models::servarr_models::{
+      AddDownloadClientParams, AddIndexerParams, AddRootFolderBody,
+    };
     use serde_json::json;
     use tokio::sync::Mutex;
 
@@ -424,6 +736,179 @@ mod tests {
       assert_ok!(&result);
     }
 
+    #[tokio::test]
+    async fn test_handle_add_custom_format_command_from_json() {
+      let custom_format_json = json!({"name": "x265"}).to_string();
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(
+          RadarrEvent::ImportCustomFormat(custom_format_json.clone()).into(),
+        ))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Radarr(RadarrSerdeable::Value(
+            json!({"testResponse": "response"}),
+          )))
+        });
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+      let add_custom_format_command = RadarrAddCommand::CustomFormat {
+        file: None,
+        json: Some(custom_format_json),
+      };
+
+      let result =
+        RadarrAddCommandHandler::with(&app_arc, add_custom_format_command, &mut mock_network)
+          .handle()
+          .await;
+
+      assert_ok!(&result);
+    }
+
+    #[tokio::test]
+    async fn test_handle_add_custom_format_command_from_file() {
+      let custom_format_json = json!({"name": "x265"}).to_string();
+      let file = std::env::temp_dir().join("managarr_test_radarr_cli_custom_format.json");
+      std::fs::write(&file, &custom_format_json).unwrap();
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(
+          RadarrEvent::ImportCustomFormat(custom_format_json).into(),
+        ))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Radarr(RadarrSerdeable::Value(
+            json!({"testResponse": "response"}),
+          )))
+        });
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+      let add_custom_format_command = RadarrAddCommand::CustomFormat {
+        file: Some(file.clone()),
+        json: None,
+      };
+
+      let result =
+        RadarrAddCommandHandler::with(&app_arc, add_custom_format_command, &mut mock_network)
+          .handle()
+          .await;
+      std::fs::remove_file(&file).unwrap();
+
+      assert_ok!(&result);
+    }
+
+    #[tokio::test]
+    async fn test_handle_add_custom_format_command_missing_file() {
+      let file = std::env::temp_dir().join("managarr_test_radarr_cli_missing_custom_format.json");
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network.expect_handle_network_event().times(0);
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+      let add_custom_format_command = RadarrAddCommand::CustomFormat {
+        file: Some(file.clone()),
+        json: None,
+      };
+
+      let result =
+        RadarrAddCommandHandler::with(&app_arc, add_custom_format_command, &mut mock_network)
+          .handle()
+          .await;
+
+      assert_err!(&result);
+      assert_eq!(
+        result.unwrap_err().to_string(),
+        format!("Unable to read custom format file '{}'", file.display())
+      );
+    }
+
+    #[tokio::test]
+    async fn test_handle_add_download_client_command() {
+      let expected_add_download_client_params = AddDownloadClientParams {
+        implementation: "QBittorrent".to_owned(),
+        name: "Test".to_owned(),
+        enable: false,
+        priority: 2,
+        remove_completed_downloads: true,
+        remove_failed_downloads: false,
+        tags: vec![1, 2],
+        tag_input_string: None,
+        fields: vec![("host".to_owned(), "localhost".to_owned())],
+      };
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(
+          RadarrEvent::AddDownloadClient(expected_add_download_client_params).into(),
+        ))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Radarr(RadarrSerdeable::Value(
+            json!({"testResponse": "response"}),
+          )))
+        });
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+      let add_download_client_command = RadarrAddCommand::DownloadClient {
+        implementation: "QBittorrent".to_owned(),
+        name: "Test".to_owned(),
+        disable: true,
+        priority: 2,
+        disable_remove_completed_downloads: false,
+        disable_remove_failed_downloads: true,
+        tag: vec![1, 2],
+        field: vec![("host".to_owned(), "localhost".to_owned())],
+      };
+
+      let result =
+        RadarrAddCommandHandler::with(&app_arc, add_download_client_command, &mut mock_network)
+          .handle()
+          .await;
+
+      assert_ok!(&result);
+    }
+
+    #[tokio::test]
+    async fn test_handle_add_indexer_command() {
+      let expected_add_indexer_params = AddIndexerParams {
+        implementation: "Torznab".to_owned(),
+        name: "Test".to_owned(),
+        enable_rss: false,
+        enable_automatic_search: true,
+        enable_interactive_search: false,
+        priority: 1,
+        tags: vec![1, 2],
+        fields: vec![("baseUrl".to_owned(), "https://test.com".to_owned())],
+        ..AddIndexerParams::default()
+      };
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(
+          RadarrEvent::AddIndexer(expected_add_indexer_params).into(),
+        ))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Radarr(RadarrSerdeable::Value(
+            json!({"testResponse": "response"}),
+          )))
+        });
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+      let add_indexer_command = RadarrAddCommand::Indexer {
+        implementation: "Torznab".to_owned(),
+        name: "Test".to_owned(),
+        disable_rss: true,
+        disable_automatic_search: false,
+        disable_interactive_search: true,
+        priority: 1,
+        tag: vec![1, 2],
+        field: vec![("baseUrl".to_owned(), "https://test.com".to_owned())],
+      };
+
+      let result = RadarrAddCommandHandler::with(&app_arc, add_indexer_command, &mut mock_network)
+        .handle()
+        .await;
+
+      assert_ok!(&result);
+    }
+
     #[tokio::test]
     async fn test_handle_add_root_folder_command() {
       let expected_root_folder_path = "/nfs/test".to_owned();
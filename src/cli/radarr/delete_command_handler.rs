@@ -1,13 +1,16 @@
 use std::sync::Arc;
 
 use anyhow::Result;
-use clap::Subcommand;
+use clap::{ArgAction, Subcommand};
 use tokio::sync::Mutex;
 
 use crate::{
   app::App,
   cli::{CliCommandHandler, Command},
-  models::radarr_models::DeleteMovieParams,
+  models::{
+    radarr_models::DeleteMovieParams,
+    servarr_models::{BulkDeleteDownloadsParams, DeleteDownloadParams, QueueRemovalOptions},
+  },
   network::{NetworkTrait, radarr_network::RadarrEvent},
 };
 
@@ -19,6 +22,11 @@ mod delete_command_handler_tests;
 
 #[derive(Debug, Clone, PartialEq, Eq, Subcommand)]
 pub enum RadarrDeleteCommand {
+  #[command(about = "Delete the specified backup from your Radarr instance")]
+  Backup {
+    #[arg(long, help = "The ID of the backup to delete", required = true)]
+    backup_id: i64,
+  },
   #[command(about = "Delete the specified item from the Radarr blocklist")]
   BlocklistItem {
     #[arg(
@@ -28,10 +36,48 @@ pub enum RadarrDeleteCommand {
     )]
     blocklist_item_id: i64,
   },
-  #[command(about = "Delete the specified download")]
+  #[command(about = "Delete the specified custom format")]
+  CustomFormat {
+    #[arg(long, help = "The ID of the custom format to delete", required = true)]
+    custom_format_id: i64,
+  },
+  #[command(about = "Delete the specified download(s) from the Radarr queue")]
   Download {
-    #[arg(long, help = "The ID of the download to delete", required = true)]
-    download_id: i64,
+    #[arg(
+      long,
+      help = "The ID of the download to delete. Repeat to delete multiple downloads at once",
+      required = true,
+      action = ArgAction::Append
+    )]
+    download_id: Vec<i64>,
+    #[arg(
+      long,
+      help = "Remove the download from the download client",
+      default_value_t = true,
+      action = ArgAction::Set
+    )]
+    remove_from_client: bool,
+    #[arg(long, help = "Add the release to the blocklist")]
+    blocklist: bool,
+    #[arg(
+      long,
+      help = "Don't search for a replacement release after blocklisting this one"
+    )]
+    skip_redownload: bool,
+    #[arg(
+      long,
+      help = "Change the download's category to the post-import category instead of removing it from the client"
+    )]
+    change_category: bool,
+  },
+  #[command(about = "Delete the download client with the given ID")]
+  DownloadClient {
+    #[arg(
+      long,
+      help = "The ID of the download client to delete",
+      required = true
+    )]
+    download_client_id: i64,
   },
   #[command(about = "Delete the indexer with the given ID")]
   Indexer {
@@ -86,6 +132,13 @@ impl<'a, 'b> CliCommandHandler<'a, 'b, RadarrDeleteCommand> for RadarrDeleteComm
 
   async fn handle(self) -> Result<String> {
     let result = match self.command {
+      RadarrDeleteCommand::Backup { backup_id } => {
+        let resp = self
+          .network
+          .handle_network_event(RadarrEvent::DeleteBackup(backup_id).into())
+          .await?;
+        serde_json::to_string_pretty(&resp)?
+      }
       RadarrDeleteCommand::BlocklistItem { blocklist_item_id } => {
         let resp = self
           .network
@@ -93,10 +146,44 @@ impl<'a, 'b> CliCommandHandler<'a, 'b, RadarrDeleteCommand> for RadarrDeleteComm
           .await?;
         serde_json::to_string_pretty(&resp)?
       }
-      RadarrDeleteCommand::Download { download_id } => {
+      RadarrDeleteCommand::CustomFormat { custom_format_id } => {
+        let resp = self
+          .network
+          .handle_network_event(RadarrEvent::DeleteCustomFormat(custom_format_id).into())
+          .await?;
+        serde_json::to_string_pretty(&resp)?
+      }
+      RadarrDeleteCommand::Download {
+        download_id,
+        remove_from_client,
+        blocklist,
+        skip_redownload,
+        change_category,
+      } => {
+        let removal_options = QueueRemovalOptions {
+          remove_from_client,
+          blocklist,
+          skip_redownload,
+          change_category,
+        };
+        let event = if let [id] = download_id[..] {
+          RadarrEvent::DeleteDownload(DeleteDownloadParams {
+            id,
+            removal_options,
+          })
+        } else {
+          RadarrEvent::BulkDeleteDownloads(BulkDeleteDownloadsParams {
+            ids: download_id,
+            removal_options,
+          })
+        };
+        let resp = self.network.handle_network_event(event.into()).await?;
+        serde_json::to_string_pretty(&resp)?
+      }
+      RadarrDeleteCommand::DownloadClient { download_client_id } => {
         let resp = self
           .network
-          .handle_network_event(RadarrEvent::DeleteDownload(download_id).into())
+          .handle_network_event(RadarrEvent::DeleteDownloadClient(download_client_id).into())
           .await?;
         serde_json::to_string_pretty(&resp)?
       }
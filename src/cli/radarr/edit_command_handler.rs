@@ -6,13 +6,19 @@ use tokio::sync::Mutex;
 
 use crate::{
   app::App,
-  cli::{CliCommandHandler, Command, mutex_flags_or_default, mutex_flags_or_option},
+  cli::{
+    CliCommandHandler, Command, mutex_flags_or_default, mutex_flags_or_option, parse_key_value,
+  },
   models::{
     Serdeable,
     radarr_models::{
-      EditCollectionParams, EditMovieParams, IndexerSettings, MinimumAvailability, RadarrSerdeable,
+      EditCollectionParams, EditMovieParams, IndexerSettings, MinimumAvailability, NamingConfig,
+      RadarrSerdeable,
+    },
+    servarr_models::{
+      EditCustomFormatParams, EditDownloadClientParams, EditIndexerParams,
+      EditQualityProfileParams, MediaManagementConfig, MediaManagementSettings,
     },
-    servarr_models::EditIndexerParams,
   },
   network::{NetworkTrait, radarr_network::RadarrEvent},
 };
@@ -162,6 +168,130 @@ pub enum RadarrEditCommand {
     )]
     disable_search_on_add: bool,
   },
+  #[command(
+    about = "Edit the name, renaming behaviour and specifications of the specified custom format",
+    group(
+      ArgGroup::new("edit_custom_format")
+      .args([
+        "name",
+        "include_when_renaming",
+        "exclude_when_renaming",
+        "field"
+      ]).required(true)
+      .multiple(true))
+  )]
+  CustomFormat {
+    #[arg(
+      long,
+      help = "The ID of the custom format whose settings you wish to edit",
+      required = true
+    )]
+    custom_format_id: i64,
+    #[arg(long, help = "The name of the custom format")]
+    name: Option<String>,
+    #[arg(
+      long,
+      help = "Include this custom format in the {Custom Formats} renaming token",
+      conflicts_with = "exclude_when_renaming"
+    )]
+    include_when_renaming: bool,
+    #[arg(
+      long,
+      help = "Exclude this custom format from the {Custom Formats} renaming token",
+      conflicts_with = "include_when_renaming"
+    )]
+    exclude_when_renaming: bool,
+    #[arg(
+      long,
+      help = "A setting of one of the custom format's specifications in the form '<specification>.<field>=value' (e.g. 'x265.value=\\bx265\\b' or 'x265.negate=true'). Can be specified multiple times",
+      value_parser = parse_key_value,
+      action = ArgAction::Append
+    )]
+    field: Vec<(String, String)>,
+  },
+  #[command(
+    about = "Edit preferences for the specified download client",
+    group(
+      ArgGroup::new("edit_download_client")
+      .args([
+        "name",
+        "enable",
+        "disable",
+        "priority",
+        "enable_remove_completed_downloads",
+        "disable_remove_completed_downloads",
+        "enable_remove_failed_downloads",
+        "disable_remove_failed_downloads",
+        "tag",
+        "clear_tags",
+        "field"
+      ]).required(true)
+      .multiple(true))
+  )]
+  DownloadClient {
+    #[arg(
+      long,
+      help = "The ID of the download client whose settings you wish to edit",
+      required = true
+    )]
+    download_client_id: i64,
+    #[arg(long, help = "The name of the download client")]
+    name: Option<String>,
+    #[arg(long, help = "Enable the download client", conflicts_with = "disable")]
+    enable: bool,
+    #[arg(long, help = "Disable the download client", conflicts_with = "enable")]
+    disable: bool,
+    #[arg(
+      long,
+      help = "Download Client Priority from 1 (Highest) to 50 (Lowest). Round-Robin is used for clients with the same priority"
+    )]
+    priority: Option<i64>,
+    #[arg(
+      long,
+      help = "Remove imported downloads from the download client history",
+      conflicts_with = "disable_remove_completed_downloads"
+    )]
+    enable_remove_completed_downloads: bool,
+    #[arg(
+      long,
+      help = "Don't remove imported downloads from the download client history",
+      conflicts_with = "enable_remove_completed_downloads"
+    )]
+    disable_remove_completed_downloads: bool,
+    #[arg(
+      long,
+      help = "Remove failed downloads from the download client history",
+      conflicts_with = "disable_remove_failed_downloads"
+    )]
+    enable_remove_failed_downloads: bool,
+    #[arg(
+      long,
+      help = "Don't remove failed downloads from the download client history",
+      conflicts_with = "enable_remove_failed_downloads"
+    )]
+    disable_remove_failed_downloads: bool,
+    #[arg(
+      long,
+      help = "Only use this download client for movies with at least one matching tag ID. Leave blank to use with all movies.",
+      value_parser,
+      action = ArgAction::Append,
+      conflicts_with = "clear_tags"
+    )]
+    tag: Option<Vec<i64>>,
+    #[arg(
+      long,
+      help = "Clear all tags on this download client",
+      conflicts_with = "tag"
+    )]
+    clear_tags: bool,
+    #[arg(
+      long,
+      help = "A setting of the download client implementation in the form 'name=value' (e.g. 'host=localhost'). Can be specified multiple times",
+      value_parser = parse_key_value,
+      action = ArgAction::Append
+    )]
+    field: Vec<(String, String)>,
+  },
   #[command(
     about = "Edit preferences for the specified indexer",
     group(
@@ -253,6 +383,111 @@ pub enum RadarrEditCommand {
     #[arg(long, help = "Clear all tags on this indexer", conflicts_with = "tag")]
     clear_tags: bool,
   },
+  #[command(
+    about = "Edit the file naming and media management settings",
+    group(
+      ArgGroup::new("edit_media_management_settings")
+      .args([
+        "rename_movies",
+        "disable_rename_movies",
+        "replace_illegal_characters",
+        "disable_replace_illegal_characters",
+        "standard_movie_format",
+        "movie_folder_format",
+        "recycle_bin",
+        "recycle_bin_cleanup_days",
+        "set_permissions",
+        "disable_set_permissions",
+        "chmod_folder",
+        "chown_group",
+        "import_extra_files",
+        "disable_import_extra_files",
+        "extra_file_extensions"
+      ]).required(true)
+      .multiple(true))
+  )]
+  MediaManagementSettings {
+    #[arg(
+      long,
+      help = "Rename movie files using the standard movie format",
+      conflicts_with = "disable_rename_movies"
+    )]
+    rename_movies: bool,
+    #[arg(
+      long,
+      help = "Keep the existing file names of movies when importing them",
+      conflicts_with = "rename_movies"
+    )]
+    disable_rename_movies: bool,
+    #[arg(
+      long,
+      help = "Replace illegal characters in file names instead of removing them",
+      conflicts_with = "disable_replace_illegal_characters"
+    )]
+    replace_illegal_characters: bool,
+    #[arg(
+      long,
+      help = "Remove illegal characters from file names instead of replacing them",
+      conflicts_with = "replace_illegal_characters"
+    )]
+    disable_replace_illegal_characters: bool,
+    #[arg(
+      long,
+      help = "The naming format for movie files (e.g. '{Movie Title} ({Release Year}) {Quality Full}')"
+    )]
+    standard_movie_format: Option<String>,
+    #[arg(
+      long,
+      help = "The naming format for movie folders (e.g. '{Movie Title} ({Release Year})')"
+    )]
+    movie_folder_format: Option<String>,
+    #[arg(
+      long,
+      help = "The folder deleted files are moved to instead of being permanently deleted"
+    )]
+    recycle_bin: Option<String>,
+    #[arg(
+      long,
+      help = "The number of days after which files in the recycle bin are deleted. Set to zero to disable automatic cleanup"
+    )]
+    recycle_bin_cleanup_days: Option<i64>,
+    #[arg(
+      long,
+      help = "Set the permissions of imported files and folders",
+      conflicts_with = "disable_set_permissions"
+    )]
+    set_permissions: bool,
+    #[arg(
+      long,
+      help = "Leave the permissions of imported files and folders unchanged",
+      conflicts_with = "set_permissions"
+    )]
+    disable_set_permissions: bool,
+    #[arg(
+      long,
+      help = "The octal permissions set on media folders and files when importing (e.g. '755')"
+    )]
+    chmod_folder: Option<String>,
+    #[arg(long, help = "The group name or GID to set on imported files")]
+    chown_group: Option<String>,
+    #[arg(
+      long,
+      help = "Import matching extra files (subtitles, nfo, etc.) alongside movie files",
+      conflicts_with = "disable_import_extra_files"
+    )]
+    import_extra_files: bool,
+    #[arg(
+      long,
+      help = "Disable importing extra files alongside movie files",
+      conflicts_with = "import_extra_files"
+    )]
+    disable_import_extra_files: bool,
+    #[arg(
+      long,
+      help = "A comma separated list of the extra file extensions to import (e.g. 'srt,nfo')"
+    )]
+    extra_file_extensions: Option<String>,
+  },
   #[command(
     about = "Edit preferences for the specified movie",
     group(
@@ -311,6 +546,73 @@ pub enum RadarrEditCommand {
     #[arg(long, help = "Clear all tags on this movie", conflicts_with = "tag")]
     clear_tags: bool,
   },
+  #[command(
+    about = "Edit the specified quality profile",
+    group(
+      ArgGroup::new("edit_quality_profile")
+      .args([
+        "name",
+        "enable_upgrades",
+        "disable_upgrades",
+        "cutoff",
+        "min_format_score",
+        "cutoff_format_score",
+        "allow_quality",
+        "disallow_quality"
+      ]).required(true)
+      .multiple(true))
+  )]
+  QualityProfile {
+    #[arg(
+      long,
+      help = "The ID of the quality profile you wish to edit",
+      required = true
+    )]
+    quality_profile_id: i64,
+    #[arg(long, help = "The name of the quality profile")]
+    name: Option<String>,
+    #[arg(
+      long,
+      help = "Allow Radarr to upgrade releases until the cutoff is reached",
+      conflicts_with = "disable_upgrades"
+    )]
+    enable_upgrades: bool,
+    #[arg(
+      long,
+      help = "Don't upgrade releases once one of the allowed qualities has been downloaded",
+      conflicts_with = "enable_upgrades"
+    )]
+    disable_upgrades: bool,
+    #[arg(
+      long,
+      help = "The name of the allowed quality or quality group to upgrade until (e.g. 'Bluray-1080p')"
+    )]
+    cutoff: Option<String>,
+    #[arg(
+      long,
+      help = "The minimum custom format score a release needs to be downloaded",
+      allow_negative_numbers = true
+    )]
+    min_format_score: Option<i64>,
+    #[arg(
+      long,
+      help = "Stop upgrading releases once this custom format score is reached",
+      allow_negative_numbers = true
+    )]
+    cutoff_format_score: Option<i64>,
+    #[arg(
+      long,
+      help = "The name of a quality or quality group to allow in this profile. Can be specified multiple times",
+      action = ArgAction::Append
+    )]
+    allow_quality: Vec<String>,
+    #[arg(
+      long,
+      help = "The name of a quality or quality group to no longer allow in this profile. Can be specified multiple times",
+      action = ArgAction::Append
+    )]
+    disallow_quality: Vec<String>,
+  },
 }
 
 impl From<RadarrEditCommand> for Command {
@@ -418,6 +720,68 @@ impl<'a, 'b> CliCommandHandler<'a, 'b, RadarrEditCommand> for RadarrEditCommandH
           .await?;
         "Collection updated".to_owned()
       }
+      RadarrEditCommand::CustomFormat {
+        custom_format_id,
+        name,
+        include_when_renaming,
+        exclude_when_renaming,
+        field: fields,
+      } => {
+        let edit_custom_format_params = EditCustomFormatParams {
+          custom_format_id,
+          name,
+          include_custom_format_when_renaming: mutex_flags_or_option(
+            include_when_renaming,
+            exclude_when_renaming,
+          ),
+          fields,
+        };
+
+        self
+          .network
+          .handle_network_event(RadarrEvent::EditCustomFormat(edit_custom_format_params).into())
+          .await?;
+        "Custom format updated".to_owned()
+      }
+      RadarrEditCommand::DownloadClient {
+        download_client_id,
+        name,
+        enable,
+        disable,
+        priority,
+        enable_remove_completed_downloads,
+        disable_remove_completed_downloads,
+        enable_remove_failed_downloads,
+        disable_remove_failed_downloads,
+        tag,
+        clear_tags,
+        field: fields,
+      } => {
+        let edit_download_client_params = EditDownloadClientParams {
+          download_client_id,
+          name,
+          enable: mutex_flags_or_option(enable, disable),
+          priority,
+          remove_completed_downloads: mutex_flags_or_option(
+            enable_remove_completed_downloads,
+            disable_remove_completed_downloads,
+          ),
+          remove_failed_downloads: mutex_flags_or_option(
+            enable_remove_failed_downloads,
+            disable_remove_failed_downloads,
+          ),
+          tags: tag,
+          tag_input_string: None,
+          clear_tags,
+          fields,
+        };
+
+        self
+          .network
+          .handle_network_event(RadarrEvent::EditDownloadClient(edit_download_client_params).into())
+          .await?;
+        "Download client updated".to_owned()
+      }
       RadarrEditCommand::Indexer {
         indexer_id,
         name,
@@ -460,6 +824,78 @@ impl<'a, 'b> CliCommandHandler<'a, 'b, RadarrEditCommand> for RadarrEditCommandH
           .await?;
         "Indexer updated".to_owned()
       }
+      RadarrEditCommand::MediaManagementSettings {
+        rename_movies,
+        disable_rename_movies,
+        replace_illegal_characters,
+        disable_replace_illegal_characters,
+        standard_movie_format,
+        movie_folder_format,
+        recycle_bin,
+        recycle_bin_cleanup_days,
+        set_permissions,
+        disable_set_permissions,
+        chmod_folder,
+        chown_group,
+        import_extra_files,
+        disable_import_extra_files,
+        extra_file_extensions,
+      } => {
+        if let Serdeable::Radarr(RadarrSerdeable::MediaManagementSettings(previous_settings)) = self
+          .network
+          .handle_network_event(RadarrEvent::GetMediaManagementSettings.into())
+          .await?
+        {
+          let MediaManagementSettings {
+            naming,
+            media_management,
+          } = previous_settings;
+          let params = MediaManagementSettings {
+            naming: NamingConfig {
+              rename_movies: mutex_flags_or_default(
+                rename_movies,
+                disable_rename_movies,
+                naming.rename_movies,
+              ),
+              replace_illegal_characters: mutex_flags_or_default(
+                replace_illegal_characters,
+                disable_replace_illegal_characters,
+                naming.replace_illegal_characters,
+              ),
+              standard_movie_format: standard_movie_format.or(naming.standard_movie_format),
+              movie_folder_format: movie_folder_format.or(naming.movie_folder_format),
+              ..naming
+            },
+            media_management: MediaManagementConfig {
+              recycle_bin: recycle_bin.or(media_management.recycle_bin),
+              recycle_bin_cleanup_days: recycle_bin_cleanup_days
+                .unwrap_or(media_management.recycle_bin_cleanup_days),
+              set_permissions_linux: mutex_flags_or_default(
+                set_permissions,
+                disable_set_permissions,
+                media_management.set_permissions_linux,
+              ),
+              chmod_folder: chmod_folder.or(media_management.chmod_folder),
+              chown_group: chown_group.or(media_management.chown_group),
+              import_extra_files: mutex_flags_or_default(
+                import_extra_files,
+                disable_import_extra_files,
+                media_management.import_extra_files,
+              ),
+              extra_file_extensions: extra_file_extensions
+                .or(media_management.extra_file_extensions),
+              ..media_management
+            },
+          };
+          self
+            .network
+            .handle_network_event(RadarrEvent::EditMediaManagementSettings(params).into())
+            .await?;
+          "Media management settings updated".to_owned()
+        } else {
+          String::new()
+        }
+      }
       RadarrEditCommand::Movie {
         movie_id,
         enable_monitoring,
@@ -488,6 +924,35 @@ impl<'a, 'b> CliCommandHandler<'a, 'b, RadarrEditCommand> for RadarrEditCommandH
           .await?;
         "Movie Updated".to_owned()
       }
+      RadarrEditCommand::QualityProfile {
+        quality_profile_id,
+        name,
+        enable_upgrades,
+        disable_upgrades,
+        cutoff,
+        min_format_score,
+        cutoff_format_score,
+        allow_quality,
+        disallow_quality,
+      } => {
+        let edit_quality_profile_params = EditQualityProfileParams {
+          quality_profile_id,
+          name,
+          upgrade_allowed: mutex_flags_or_option(enable_upgrades, disable_upgrades),
+          cutoff,
+          min_format_score,
+          cutoff_format_score,
+          items: None,
+          allowed_qualities: allow_quality,
+          disallowed_qualities: disallow_quality,
+        };
+
+        self
+          .network
+          .handle_network_event(RadarrEvent::EditQualityProfile(edit_quality_profile_params).into())
+          .await?;
+        "Quality profile updated".to_owned()
+      }
     };
 
     Ok(result)
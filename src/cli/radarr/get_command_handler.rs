@@ -20,8 +20,21 @@ mod get_command_handler_tests;
 pub enum RadarrGetCommand {
   #[command(about = "Get the shared settings for all indexers")]
   AllIndexerSettings,
+  #[command(
+    about = "Export the custom format with the given ID as JSON in the format used by TRaSH Guides"
+  )]
+  CustomFormatExport {
+    #[arg(
+      long,
+      help = "The Radarr ID of the custom format you wish to export",
+      required = true
+    )]
+    custom_format_id: i64,
+  },
   #[command(about = "Fetch the host config for your Radarr instance")]
   HostConfig,
+  #[command(about = "Get the file naming and media management settings")]
+  MediaManagementSettings,
   #[command(about = "Get detailed information for the movie with the given ID")]
   MovieDetails {
     #[arg(
@@ -40,6 +53,17 @@ pub enum RadarrGetCommand {
     )]
     movie_id: i64,
   },
+  #[command(
+    about = "Get the qualities, cutoff and format scores of the quality profile with the given ID"
+  )]
+  QualityProfileDetails {
+    #[arg(
+      long,
+      help = "The Radarr ID of the quality profile whose details you wish to fetch",
+      required = true
+    )]
+    quality_profile_id: i64,
+  },
   #[command(about = "Fetch the security config for your Radarr instance")]
   SecurityConfig,
   #[command(about = "Get the system status")]
@@ -80,6 +104,13 @@ impl<'a, 'b> CliCommandHandler<'a, 'b, RadarrGetCommand> for RadarrGetCommandHan
           .await?;
         serde_json::to_string_pretty(&resp)?
       }
+      RadarrGetCommand::CustomFormatExport { custom_format_id } => {
+        let resp = self
+          .network
+          .handle_network_event(RadarrEvent::ExportCustomFormat(custom_format_id).into())
+          .await?;
+        serde_json::to_string_pretty(&resp)?
+      }
       RadarrGetCommand::HostConfig => {
         let resp = self
           .network
@@ -87,6 +118,13 @@ impl<'a, 'b> CliCommandHandler<'a, 'b, RadarrGetCommand> for RadarrGetCommandHan
           .await?;
         serde_json::to_string_pretty(&resp)?
       }
+      RadarrGetCommand::MediaManagementSettings => {
+        let resp = self
+          .network
+          .handle_network_event(RadarrEvent::GetMediaManagementSettings.into())
+          .await?;
+        serde_json::to_string_pretty(&resp)?
+      }
       RadarrGetCommand::MovieDetails { movie_id } => {
         let resp = self
           .network
@@ -101,6 +139,13 @@ impl<'a, 'b> CliCommandHandler<'a, 'b, RadarrGetCommand> for RadarrGetCommandHan
           .await?;
         serde_json::to_string_pretty(&resp)?
       }
+      RadarrGetCommand::QualityProfileDetails { quality_profile_id } => {
+        let resp = self
+          .network
+          .handle_network_event(RadarrEvent::GetQualityProfileDetails(quality_profile_id).into())
+          .await?;
+        serde_json::to_string_pretty(&resp)?
+      }
       RadarrGetCommand::SecurityConfig => {
         let resp = self
           .network
@@ -1,12 +1,13 @@
 use std::sync::Arc;
 
 use anyhow::Result;
+use chrono::{Local, NaiveDate};
 use clap::Subcommand;
 use tokio::sync::Mutex;
 
 use crate::{
   app::App,
-  cli::{CliCommandHandler, Command},
+  cli::{CliCommandHandler, Command, calendar_window_or_default},
   network::{NetworkTrait, radarr_network::RadarrEvent},
 };
 
@@ -18,10 +19,32 @@ mod list_command_handler_tests;
 
 #[derive(Debug, Clone, PartialEq, Eq, Subcommand)]
 pub enum RadarrListCommand {
+  #[command(about = "List all backups for your Radarr instance")]
+  Backups,
   #[command(about = "List all items in the Radarr blocklist")]
   Blocklist,
+  #[command(about = "List the movies releasing in the given date range on the Radarr calendar")]
+  Calendar {
+    #[arg(
+      long,
+      help = "The first date (YYYY-MM-DD) to include in the calendar. Defaults to today"
+    )]
+    start: Option<NaiveDate>,
+    #[arg(
+      long,
+      help = "The date (YYYY-MM-DD) to end the calendar on. Defaults to 7 days after the start date"
+    )]
+    end: Option<NaiveDate>,
+  },
   #[command(about = "List all Radarr collections")]
   Collections,
+  #[command(about = "List all Radarr custom formats")]
+  CustomFormats,
+  #[command(about = "List the monitored movies that have not met their quality cutoff")]
+  CutoffUnmet {
+    #[arg(long, help = "How many movies to fetch", default_value_t = 500)]
+    count: u64,
+  },
   #[command(about = "List all active downloads in Radarr")]
   Downloads {
     #[arg(long, help = "How many downloads to fetch", default_value_t = 500)]
@@ -34,6 +57,8 @@ pub enum RadarrListCommand {
     #[arg(long, help = "How many history events to fetch", default_value_t = 500)]
     events: u64,
   },
+  #[command(about = "List all Radarr download clients")]
+  DownloadClients,
   #[command(about = "List all Radarr indexers")]
   Indexers,
   #[command(about = "Fetch Radarr logs")]
@@ -69,6 +94,11 @@ pub enum RadarrListCommand {
   Tasks,
   #[command(about = "List all Radarr updates")]
   Updates,
+  #[command(about = "List the monitored movies that are missing from your library")]
+  WantedMissing {
+    #[arg(long, help = "How many movies to fetch", default_value_t = 500)]
+    count: u64,
+  },
 }
 
 impl From<RadarrListCommand> for Command {
@@ -98,6 +128,13 @@ impl<'a, 'b> CliCommandHandler<'a, 'b, RadarrListCommand> for RadarrListCommandH
 
   async fn handle(self) -> Result<String> {
     let result = match self.command {
+      RadarrListCommand::Backups => {
+        let resp = self
+          .network
+          .handle_network_event(RadarrEvent::GetBackups.into())
+          .await?;
+        serde_json::to_string_pretty(&resp)?
+      }
       RadarrListCommand::Blocklist => {
         let resp = self
           .network
@@ -105,6 +142,14 @@ impl<'a, 'b> CliCommandHandler<'a, 'b, RadarrListCommand> for RadarrListCommandH
           .await?;
         serde_json::to_string_pretty(&resp)?
       }
+      RadarrListCommand::Calendar { start, end } => {
+        let (start, end) = calendar_window_or_default(start, end, Local::now().date_naive());
+        let resp = self
+          .network
+          .handle_network_event(RadarrEvent::GetCalendar(start, end).into())
+          .await?;
+        serde_json::to_string_pretty(&resp)?
+      }
       RadarrListCommand::Collections => {
         let resp = self
           .network
@@ -112,6 +157,20 @@ impl<'a, 'b> CliCommandHandler<'a, 'b, RadarrListCommand> for RadarrListCommandH
           .await?;
         serde_json::to_string_pretty(&resp)?
       }
+      RadarrListCommand::CustomFormats => {
+        let resp = self
+          .network
+          .handle_network_event(RadarrEvent::GetCustomFormats.into())
+          .await?;
+        serde_json::to_string_pretty(&resp)?
+      }
+      RadarrListCommand::CutoffUnmet { count } => {
+        let resp = self
+          .network
+          .handle_network_event(RadarrEvent::GetCutoffUnmet(count).into())
+          .await?;
+        serde_json::to_string_pretty(&resp)?
+      }
       RadarrListCommand::Downloads { count } => {
         let resp = self
           .network
@@ -133,6 +192,13 @@ impl<'a, 'b> CliCommandHandler<'a, 'b, RadarrListCommand> for RadarrListCommandH
           .await?;
         serde_json::to_string_pretty(&resp)?
       }
+      RadarrListCommand::DownloadClients => {
+        let resp = self
+          .network
+          .handle_network_event(RadarrEvent::GetDownloadClients.into())
+          .await?;
+        serde_json::to_string_pretty(&resp)?
+      }
       RadarrListCommand::Indexers => {
         let resp = self
           .network
@@ -213,6 +279,13 @@ impl<'a, 'b> CliCommandHandler<'a, 'b, RadarrListCommand> for RadarrListCommandH
           .await?;
         serde_json::to_string_pretty(&resp)?
       }
+      RadarrListCommand::WantedMissing { count } => {
+        let resp = self
+          .network
+          .handle_network_event(RadarrEvent::GetWantedMissing(count).into())
+          .await?;
+        serde_json::to_string_pretty(&resp)?
+      }
     };
 
     Ok(result)
@@ -20,6 +20,7 @@ mod tests {
 
   mod cli {
     use super::*;
+    use chrono::NaiveDate;
     use clap::Parser;
     use pretty_assertions::assert_eq;
     use rstest::rstest;
@@ -27,9 +28,13 @@ mod tests {
     #[rstest]
     fn test_list_commands_have_no_arg_requirements(
       #[values(
+        "backups",
         "blocklist",
+        "calendar",
+        "custom-formats",
         "collections",
         "disk-space",
+        "download-clients",
         "indexers",
         "movies",
         "quality-profiles",
@@ -58,6 +63,85 @@ mod tests {
       );
     }
 
+    #[rstest]
+    fn test_list_calendar_date_flags_require_arguments(#[values("--start", "--end")] flag: &str) {
+      let result =
+        Cli::command().try_get_matches_from(["managarr", "radarr", "list", "calendar", flag]);
+
+      assert_err!(&result);
+      assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidValue);
+    }
+
+    #[test]
+    fn test_list_calendar_invalid_date() {
+      let result = Cli::command().try_get_matches_from([
+        "managarr",
+        "radarr",
+        "list",
+        "calendar",
+        "--start",
+        "05/20/2023",
+      ]);
+
+      assert_err!(&result);
+      assert_eq!(result.unwrap_err().kind(), ErrorKind::ValueValidation);
+    }
+
+    #[test]
+    fn test_list_calendar_success() {
+      let expected_args = RadarrListCommand::Calendar {
+        start: NaiveDate::from_ymd_opt(2023, 5, 20),
+        end: NaiveDate::from_ymd_opt(2023, 6, 1),
+      };
+      let result = Cli::try_parse_from([
+        "managarr",
+        "radarr",
+        "list",
+        "calendar",
+        "--start",
+        "2023-05-20",
+        "--end",
+        "2023-06-01",
+      ]);
+
+      assert_ok!(&result);
+
+      let Some(Command::Radarr(RadarrCommand::List(calendar_command))) = result.unwrap().command
+      else {
+        panic!("Unexpected command type");
+      };
+      assert_eq!(calendar_command, expected_args);
+    }
+
+    #[rstest]
+    fn test_list_wanted_count_flag_requires_arguments(
+      #[values("wanted-missing", "cutoff-unmet")] subcommand: &str,
+    ) {
+      let result =
+        Cli::command().try_get_matches_from(["managarr", "radarr", "list", subcommand, "--count"]);
+
+      assert_err!(&result);
+      assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidValue);
+    }
+
+    #[rstest]
+    #[case("wanted-missing", RadarrListCommand::WantedMissing { count: 500 })]
+    #[case("cutoff-unmet", RadarrListCommand::CutoffUnmet { count: 500 })]
+    fn test_list_wanted_default_values(
+      #[case] subcommand: &str,
+      #[case] expected_args: RadarrListCommand,
+    ) {
+      let result = Cli::try_parse_from(["managarr", "radarr", "list", subcommand]);
+
+      assert_ok!(&result);
+
+      let Some(Command::Radarr(RadarrCommand::List(wanted_command))) = result.unwrap().command
+      else {
+        panic!("Unexpected command type");
+      };
+      assert_eq!(wanted_command, expected_args);
+    }
+
     #[test]
     fn test_list_downloads_count_flag_requires_arguments() {
       let result =
@@ -155,6 +239,7 @@ mod tests {
   mod handler {
     use std::sync::Arc;
 
+    use chrono::NaiveDate;
     use mockall::predicate::eq;
     use rstest::rstest;
     use serde_json::json;
@@ -169,9 +254,12 @@ mod tests {
     };
 
     #[rstest]
+    #[case(RadarrListCommand::Backups, RadarrEvent::GetBackups)]
     #[case(RadarrListCommand::Blocklist, RadarrEvent::GetBlocklist)]
     #[case(RadarrListCommand::Collections, RadarrEvent::GetCollections)]
     #[case(RadarrListCommand::DiskSpace, RadarrEvent::GetDiskSpace)]
+    #[case(RadarrListCommand::CustomFormats, RadarrEvent::GetCustomFormats)]
+    #[case(RadarrListCommand::DownloadClients, RadarrEvent::GetDownloadClients)]
     #[case(RadarrListCommand::Indexers, RadarrEvent::GetIndexers)]
     #[case(RadarrListCommand::Movies, RadarrEvent::GetMovies)]
     #[case(RadarrListCommand::QualityProfiles, RadarrEvent::GetQualityProfiles)]
@@ -230,6 +318,63 @@ mod tests {
       assert_ok!(&result);
     }
 
+    #[tokio::test]
+    async fn test_handle_list_calendar_command() {
+      let expected_start = NaiveDate::from_ymd_opt(2023, 5, 20).unwrap();
+      let expected_end = NaiveDate::from_ymd_opt(2023, 6, 1).unwrap();
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(
+          RadarrEvent::GetCalendar(expected_start, expected_end).into(),
+        ))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Radarr(RadarrSerdeable::Value(
+            json!({"testResponse": "response"}),
+          )))
+        });
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+      let list_calendar_command = RadarrListCommand::Calendar {
+        start: Some(expected_start),
+        end: Some(expected_end),
+      };
+
+      let result =
+        RadarrListCommandHandler::with(&app_arc, list_calendar_command, &mut mock_network)
+          .handle()
+          .await;
+
+      assert_ok!(&result);
+    }
+
+    #[rstest]
+    #[case(RadarrListCommand::WantedMissing { count: 1000 }, RadarrEvent::GetWantedMissing(1000))]
+    #[case(RadarrListCommand::CutoffUnmet { count: 1000 }, RadarrEvent::GetCutoffUnmet(1000))]
+    #[tokio::test]
+    async fn test_handle_list_wanted_commands(
+      #[case] list_command: RadarrListCommand,
+      #[case] expected_radarr_event: RadarrEvent,
+    ) {
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(expected_radarr_event.into()))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Radarr(RadarrSerdeable::Value(
+            json!({"testResponse": "response"}),
+          )))
+        });
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+
+      let result = RadarrListCommandHandler::with(&app_arc, list_command, &mut mock_network)
+        .handle()
+        .await;
+
+      assert_ok!(&result);
+    }
+
     #[tokio::test]
     async fn test_handle_list_downloads_command() {
       let expected_count = 1000;
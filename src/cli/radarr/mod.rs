@@ -1,3 +1,4 @@
+use std::path::PathBuf;
 use std::sync::Arc;
 
 use add_command_handler::{RadarrAddCommand, RadarrAddCommandHandler};
@@ -12,10 +13,12 @@ use tokio::sync::Mutex;
 use crate::app::App;
 
 use crate::cli::CliCommandHandler;
-use crate::models::radarr_models::{RadarrReleaseDownloadBody, RadarrTaskName};
+use crate::models::Serdeable;
+use crate::models::radarr_models::{RadarrReleaseDownloadBody, RadarrSerdeable, RadarrTaskName};
+use crate::models::servarr_models::DownloadBackupParams;
 use crate::network::NetworkTrait;
 use crate::network::radarr_network::RadarrEvent;
-use anyhow::Result;
+use anyhow::{Result, anyhow};
 
 use super::Command;
 
@@ -23,7 +26,7 @@ mod add_command_handler;
 mod delete_command_handler;
 mod edit_command_handler;
 mod get_command_handler;
-mod list_command_handler;
+pub(super) mod list_command_handler;
 mod refresh_command_handler;
 
 #[cfg(test)]
@@ -73,6 +76,17 @@ pub enum RadarrCommand {
     )]
     history_item_id: i64,
   },
+  #[command(
+    about = "Grab the pending release in the queue with the given ID now, skipping any delay"
+  )]
+  GrabQueueItem {
+    #[arg(
+      long,
+      help = "The Radarr ID of the pending queue item you wish to grab",
+      required = true
+    )]
+    queue_item_id: i64,
+  },
   #[command(about = "Manually download the given release for the specified movie ID")]
   DownloadRelease {
     #[arg(long, help = "The GUID of the release to download", required = true)]
@@ -90,6 +104,22 @@ pub enum RadarrCommand {
     )]
     movie_id: i64,
   },
+  #[command(
+    about = "Import all files with no rejections from the download with the given ID (i.e. the download client's ID for the download)"
+  )]
+  ManualImport {
+    #[arg(
+      long,
+      help = "The download client's ID of the download you wish to import",
+      required = true
+    )]
+    download_id: String,
+    #[arg(
+      long,
+      help = "The Radarr ID of the movie to map the files to, if Radarr couldn't identify it automatically"
+    )]
+    movie_id: Option<i64>,
+  },
   #[command(about = "Trigger a manual search of releases for the movie with the given ID")]
   ManualSearch {
     #[arg(
@@ -99,6 +129,22 @@ pub enum RadarrCommand {
     )]
     movie_id: i64,
   },
+  #[command(about = "Download the backup with the given ID from your Radarr instance")]
+  DownloadBackup {
+    #[arg(long, help = "The ID of the backup to download", required = true)]
+    id: i64,
+    #[arg(
+      long,
+      help = "The file or directory to download the backup to",
+      required = true
+    )]
+    dest: PathBuf,
+  },
+  #[command(about = "Upload the given backup file and restore your Radarr instance from it")]
+  RestoreBackup {
+    #[arg(long, help = "The backup file to restore from", required = true)]
+    file: PathBuf,
+  },
   #[command(about = "Search for a new film to add to Radarr")]
   SearchNewMovie {
     #[arg(
@@ -127,6 +173,15 @@ pub enum RadarrCommand {
   },
   #[command(about = "Test all Radarr indexers")]
   TestAllIndexers,
+  #[command(
+    about = "Test the download client with the given ID. Note that a successful test returns an empty JSON body; i.e. '{}'"
+  )]
+  TestDownloadClient {
+    #[arg(long, help = "The ID of the download client to test", required = true)]
+    download_client_id: i64,
+  },
+  #[command(about = "Test all Radarr download clients")]
+  TestAllDownloadClients,
   #[command(
     about = "Toggle monitoring for the specified movie corresponding to the given movie ID"
   )]
@@ -226,6 +281,13 @@ impl<'a, 'b> CliCommandHandler<'a, 'b, RadarrCommand> for RadarrCliHandler<'a, '
           &serde_json::json!({"message": "Radarr history item marked as 'failed'"}),
         )?
       }
+      RadarrCommand::GrabQueueItem { queue_item_id } => {
+        let _ = self
+          .network
+          .handle_network_event(RadarrEvent::GrabQueueItem(queue_item_id).into())
+          .await?;
+        serde_json::to_string_pretty(&serde_json::json!({"message": "Radarr queue item grabbed"}))?
+      }
       RadarrCommand::DownloadRelease {
         guid,
         indexer_id,
@@ -242,6 +304,32 @@ impl<'a, 'b> CliCommandHandler<'a, 'b, RadarrCommand> for RadarrCliHandler<'a, '
           .await?;
         serde_json::to_string_pretty(&resp)?
       }
+      RadarrCommand::ManualImport {
+        download_id,
+        movie_id,
+      } => {
+        if let Serdeable::Radarr(RadarrSerdeable::ManualImportItems(items)) = self
+          .network
+          .handle_network_event(RadarrEvent::GetManualImport(download_id, movie_id).into())
+          .await?
+        {
+          let files: Vec<_> = items.iter().filter_map(|item| item.import_file()).collect();
+
+          if files.is_empty() {
+            serde_json::to_string_pretty(
+              &serde_json::json!({"message": "No files without rejections were found to import"}),
+            )?
+          } else {
+            let resp = self
+              .network
+              .handle_network_event(RadarrEvent::ManualImport(files).into())
+              .await?;
+            serde_json::to_string_pretty(&resp)?
+          }
+        } else {
+          String::new()
+        }
+      }
       RadarrCommand::ManualSearch { movie_id } => {
         println!("Searching for releases. This may take a minute...");
         let resp = self
@@ -250,6 +338,43 @@ impl<'a, 'b> CliCommandHandler<'a, 'b, RadarrCommand> for RadarrCliHandler<'a, '
           .await?;
         serde_json::to_string_pretty(&resp)?
       }
+      RadarrCommand::DownloadBackup { id, dest } => {
+        if let Serdeable::Radarr(RadarrSerdeable::Backups(backups)) = self
+          .network
+          .handle_network_event(RadarrEvent::GetBackups.into())
+          .await?
+        {
+          let backup = backups
+            .into_iter()
+            .find(|backup| backup.id == id)
+            .ok_or_else(|| anyhow!("No backup found with ID {id}"))?;
+          let destination = if dest.is_dir() {
+            dest.join(&backup.name)
+          } else {
+            dest
+          };
+          let params = DownloadBackupParams {
+            backup_path: backup.path,
+            destination: destination.clone(),
+          };
+          self
+            .network
+            .handle_network_event(RadarrEvent::DownloadBackup(params).into())
+            .await?;
+          serde_json::to_string_pretty(
+            &serde_json::json!({"message": format!("Backup downloaded to '{}'", destination.display())}),
+          )?
+        } else {
+          String::new()
+        }
+      }
+      RadarrCommand::RestoreBackup { file } => {
+        let resp = self
+          .network
+          .handle_network_event(RadarrEvent::RestoreBackup(file).into())
+          .await?;
+        serde_json::to_string_pretty(&resp)?
+      }
       RadarrCommand::SearchNewMovie { query } => {
         let resp = self
           .network
@@ -279,6 +404,21 @@ impl<'a, 'b> CliCommandHandler<'a, 'b, RadarrCommand> for RadarrCliHandler<'a, '
           .await?;
         serde_json::to_string_pretty(&resp)?
       }
+      RadarrCommand::TestDownloadClient { download_client_id } => {
+        let resp = self
+          .network
+          .handle_network_event(RadarrEvent::TestDownloadClient(download_client_id).into())
+          .await?;
+        serde_json::to_string_pretty(&resp)?
+      }
+      RadarrCommand::TestAllDownloadClients => {
+        println!("Testing all Radarr download clients. This may take a minute...");
+        let resp = self
+          .network
+          .handle_network_event(RadarrEvent::TestAllDownloadClients.into())
+          .await?;
+        serde_json::to_string_pretty(&resp)?
+      }
       RadarrCommand::ToggleMovieMonitoring { movie_id } => {
         let resp = self
           .network
@@ -56,6 +56,30 @@ mod tests {
       assert_ok!(&result);
     }
 
+    #[test]
+    fn test_grab_queue_item_requires_queue_item_id() {
+      let result = Cli::command().try_get_matches_from(["managarr", "radarr", "grab-queue-item"]);
+
+      assert_err!(&result);
+      assert_eq!(
+        result.unwrap_err().kind(),
+        ErrorKind::MissingRequiredArgument
+      );
+    }
+
+    #[test]
+    fn test_grab_queue_item_requirements_satisfied() {
+      let result = Cli::command().try_get_matches_from([
+        "managarr",
+        "radarr",
+        "grab-queue-item",
+        "--queue-item-id",
+        "1",
+      ]);
+
+      assert_ok!(&result);
+    }
+
     #[test]
     fn test_download_release_requires_movie_id() {
       let result = Cli::command().try_get_matches_from([
@@ -130,6 +154,33 @@ mod tests {
       assert_ok!(&result);
     }
 
+    #[test]
+    fn test_manual_import_requires_download_id() {
+      let result = Cli::command().try_get_matches_from(["managarr", "radarr", "manual-import"]);
+
+      assert_err!(&result);
+      assert_eq!(
+        result.unwrap_err().kind(),
+        ErrorKind::MissingRequiredArgument
+      );
+    }
+
+    #[rstest]
+    fn test_manual_import_requirements_satisfied(
+      #[values(
+        vec!["--download-id", "abc123"],
+        vec!["--download-id", "abc123", "--movie-id", "1"]
+      )]
+      args: Vec<&str>,
+    ) {
+      let mut command = vec!["managarr", "radarr", "manual-import"];
+      command.extend(args);
+
+      let result = Cli::command().try_get_matches_from(command);
+
+      assert_ok!(&result);
+    }
+
     #[test]
     fn test_manual_search_requires_movie_id() {
       let result = Cli::command().try_get_matches_from(["managarr", "radarr", "manual-search"]);
@@ -154,6 +205,74 @@ mod tests {
       assert_ok!(&result);
     }
 
+    #[test]
+    fn test_download_backup_requires_id() {
+      let result = Cli::command().try_get_matches_from([
+        "managarr",
+        "radarr",
+        "download-backup",
+        "--dest",
+        "/tmp",
+      ]);
+
+      assert_err!(&result);
+      assert_eq!(
+        result.unwrap_err().kind(),
+        ErrorKind::MissingRequiredArgument
+      );
+    }
+
+    #[test]
+    fn test_download_backup_requires_dest() {
+      let result =
+        Cli::command().try_get_matches_from(["managarr", "radarr", "download-backup", "--id", "1"]);
+
+      assert_err!(&result);
+      assert_eq!(
+        result.unwrap_err().kind(),
+        ErrorKind::MissingRequiredArgument
+      );
+    }
+
+    #[test]
+    fn test_download_backup_requirements_satisfied() {
+      let result = Cli::command().try_get_matches_from([
+        "managarr",
+        "radarr",
+        "download-backup",
+        "--id",
+        "1",
+        "--dest",
+        "/tmp",
+      ]);
+
+      assert_ok!(&result);
+    }
+
+    #[test]
+    fn test_restore_backup_requires_file() {
+      let result = Cli::command().try_get_matches_from(["managarr", "radarr", "restore-backup"]);
+
+      assert_err!(&result);
+      assert_eq!(
+        result.unwrap_err().kind(),
+        ErrorKind::MissingRequiredArgument
+      );
+    }
+
+    #[test]
+    fn test_restore_backup_requirements_satisfied() {
+      let result = Cli::command().try_get_matches_from([
+        "managarr",
+        "radarr",
+        "restore-backup",
+        "--file",
+        "/tmp/backup.zip",
+      ]);
+
+      assert_ok!(&result);
+    }
+
     #[test]
     fn test_search_new_movie_requires_query() {
       let result = Cli::command().try_get_matches_from(["managarr", "radarr", "search-new-movie"]);
@@ -216,6 +335,31 @@ mod tests {
       assert_ok!(&result);
     }
 
+    #[test]
+    fn test_test_download_client_requires_download_client_id() {
+      let result =
+        Cli::command().try_get_matches_from(["managarr", "radarr", "test-download-client"]);
+
+      assert_err!(&result);
+      assert_eq!(
+        result.unwrap_err().kind(),
+        ErrorKind::MissingRequiredArgument
+      );
+    }
+
+    #[test]
+    fn test_test_download_client_requirements_satisfied() {
+      let result = Cli::command().try_get_matches_from([
+        "managarr",
+        "radarr",
+        "test-download-client",
+        "--download-client-id",
+        "1",
+      ]);
+
+      assert_ok!(&result);
+    }
+
     #[test]
     fn test_test_indexer_requires_indexer_id() {
       let result = Cli::command().try_get_matches_from(["managarr", "radarr", "test-indexer"]);
@@ -292,8 +436,12 @@ mod tests {
   }
 
   mod handler {
+    use std::path::PathBuf;
     use std::sync::Arc;
 
+    use crate::models::servarr_models::DownloadBackupParams;
+    use crate::network::servarr_test_utils::backup;
+
     use mockall::predicate::eq;
     use serde_json::json;
     use tokio::sync::Mutex;
@@ -312,11 +460,17 @@ mod tests {
       models::{
         Serdeable,
         radarr_models::{
-          BlocklistItem, BlocklistResponse, IndexerSettings, RadarrReleaseDownloadBody,
-          RadarrSerdeable, RadarrTaskName,
+          BlocklistItem, BlocklistResponse, IndexerSettings, RadarrManualImportItem,
+          RadarrReleaseDownloadBody, RadarrSerdeable, RadarrTaskName,
+        },
+      },
+      network::{
+        MockNetworkTrait, NetworkEvent,
+        radarr_network::{
+          RadarrEvent,
+          radarr_network_test_utils::test_utils::{manual_import_file, manual_import_item},
         },
       },
-      network::{MockNetworkTrait, NetworkEvent, radarr_network::RadarrEvent},
     };
 
     #[tokio::test]
@@ -382,6 +536,28 @@ mod tests {
       assert_ok!(&result);
     }
 
+    #[tokio::test]
+    async fn test_grab_queue_item_command() {
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(RadarrEvent::GrabQueueItem(1).into()))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Radarr(RadarrSerdeable::Value(
+            json!({"testResponse": "response"}),
+          )))
+        });
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+      let grab_queue_item_command = RadarrCommand::GrabQueueItem { queue_item_id: 1 };
+
+      let result = RadarrCliHandler::with(&app_arc, grab_queue_item_command, &mut mock_network)
+        .handle()
+        .await;
+
+      assert_ok!(&result);
+    }
+
     #[tokio::test]
     async fn test_download_release_command() {
       let expected_release_download_body = RadarrReleaseDownloadBody {
@@ -415,6 +591,76 @@ mod tests {
       assert_ok!(&result);
     }
 
+    #[tokio::test]
+    async fn test_manual_import_command() {
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(
+          RadarrEvent::GetManualImport("abc123".to_owned(), Some(1)).into(),
+        ))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Radarr(RadarrSerdeable::ManualImportItems(vec![
+            manual_import_item(),
+          ])))
+        });
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(
+          RadarrEvent::ManualImport(vec![manual_import_file()]).into(),
+        ))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Radarr(RadarrSerdeable::Value(
+            json!({"testResponse": "response"}),
+          )))
+        });
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+      let manual_import_command = RadarrCommand::ManualImport {
+        download_id: "abc123".to_owned(),
+        movie_id: Some(1),
+      };
+
+      let result = RadarrCliHandler::with(&app_arc, manual_import_command, &mut mock_network)
+        .handle()
+        .await;
+
+      assert_ok!(&result);
+    }
+
+    #[tokio::test]
+    async fn test_manual_import_command_no_op_when_nothing_is_importable() {
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(
+          RadarrEvent::GetManualImport("abc123".to_owned(), None).into(),
+        ))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Radarr(RadarrSerdeable::ManualImportItems(vec![
+            RadarrManualImportItem::default(),
+          ])))
+        });
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+      let manual_import_command = RadarrCommand::ManualImport {
+        download_id: "abc123".to_owned(),
+        movie_id: None,
+      };
+
+      let result = RadarrCliHandler::with(&app_arc, manual_import_command, &mut mock_network)
+        .handle()
+        .await;
+
+      assert_ok!(&result);
+      assert!(
+        result
+          .unwrap()
+          .contains("No files without rejections were found to import")
+      );
+    }
+
     #[tokio::test]
     async fn test_manual_search_command() {
       let expected_movie_id = 1;
@@ -440,6 +686,116 @@ mod tests {
       assert_ok!(&result);
     }
 
+    #[tokio::test]
+    async fn test_download_backup_command() {
+      let dest = std::env::temp_dir();
+      let expected_params = DownloadBackupParams {
+        backup_path: backup().path,
+        destination: dest.join(backup().name),
+      };
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(RadarrEvent::GetBackups.into()))
+        .times(1)
+        .returning(|_| Ok(Serdeable::Radarr(RadarrSerdeable::Backups(vec![backup()]))));
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(
+          RadarrEvent::DownloadBackup(expected_params).into(),
+        ))
+        .times(1)
+        .returning(|_| Ok(Serdeable::Radarr(RadarrSerdeable::Value(json!({})))));
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+      let download_backup_command = RadarrCommand::DownloadBackup { id: 1, dest };
+
+      let result = RadarrCliHandler::with(&app_arc, download_backup_command, &mut mock_network)
+        .handle()
+        .await;
+
+      assert_ok!(&result);
+      assert!(result.unwrap().contains("Backup downloaded to"));
+    }
+
+    #[tokio::test]
+    async fn test_download_backup_command_to_file() {
+      let dest = std::env::temp_dir().join("radarr_backup.zip");
+      let expected_params = DownloadBackupParams {
+        backup_path: backup().path,
+        destination: dest.clone(),
+      };
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(RadarrEvent::GetBackups.into()))
+        .times(1)
+        .returning(|_| Ok(Serdeable::Radarr(RadarrSerdeable::Backups(vec![backup()]))));
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(
+          RadarrEvent::DownloadBackup(expected_params).into(),
+        ))
+        .times(1)
+        .returning(|_| Ok(Serdeable::Radarr(RadarrSerdeable::Value(json!({})))));
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+      let download_backup_command = RadarrCommand::DownloadBackup { id: 1, dest };
+
+      let result = RadarrCliHandler::with(&app_arc, download_backup_command, &mut mock_network)
+        .handle()
+        .await;
+
+      assert_ok!(&result);
+    }
+
+    #[tokio::test]
+    async fn test_download_backup_command_unknown_backup_id() {
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(RadarrEvent::GetBackups.into()))
+        .times(1)
+        .returning(|_| Ok(Serdeable::Radarr(RadarrSerdeable::Backups(vec![backup()]))));
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+      let download_backup_command = RadarrCommand::DownloadBackup {
+        id: 2,
+        dest: std::env::temp_dir(),
+      };
+
+      let result = RadarrCliHandler::with(&app_arc, download_backup_command, &mut mock_network)
+        .handle()
+        .await;
+
+      assert_err!(&result);
+      assert_eq!(result.unwrap_err().to_string(), "No backup found with ID 2");
+    }
+
+    #[tokio::test]
+    async fn test_restore_backup_command() {
+      let expected_file = PathBuf::from("/tmp/backup.zip");
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(
+          RadarrEvent::RestoreBackup(expected_file).into(),
+        ))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Radarr(RadarrSerdeable::Value(
+            json!({"testResponse": "response"}),
+          )))
+        });
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+      let restore_backup_command = RadarrCommand::RestoreBackup {
+        file: PathBuf::from("/tmp/backup.zip"),
+      };
+
+      let result = RadarrCliHandler::with(&app_arc, restore_backup_command, &mut mock_network)
+        .handle()
+        .await;
+
+      assert_ok!(&result);
+    }
+
     #[tokio::test]
     async fn test_search_new_movie_command() {
       let expected_search_query = "halo".to_owned();
@@ -494,6 +850,62 @@ mod tests {
       assert_ok!(&result);
     }
 
+    #[tokio::test]
+    async fn test_test_download_client_command() {
+      let expected_download_client_id = 1;
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(
+          RadarrEvent::TestDownloadClient(expected_download_client_id).into(),
+        ))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Radarr(RadarrSerdeable::Value(
+            json!({"testResponse": "response"}),
+          )))
+        });
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+      let test_download_client_command = RadarrCommand::TestDownloadClient {
+        download_client_id: 1,
+      };
+
+      let result =
+        RadarrCliHandler::with(&app_arc, test_download_client_command, &mut mock_network)
+          .handle()
+          .await;
+
+      assert_ok!(&result);
+    }
+
+    #[tokio::test]
+    async fn test_test_all_download_clients_command() {
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(
+          RadarrEvent::TestAllDownloadClients.into(),
+        ))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Radarr(RadarrSerdeable::Value(
+            json!({"testResponse": "response"}),
+          )))
+        });
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+      let test_all_download_clients_command = RadarrCommand::TestAllDownloadClients;
+
+      let result = RadarrCliHandler::with(
+        &app_arc,
+        test_all_download_clients_command,
+        &mut mock_network,
+      )
+      .handle()
+      .await;
+
+      assert_ok!(&result);
+    }
+
     #[tokio::test]
     async fn test_test_indexer_command() {
       let expected_indexer_id = 1;
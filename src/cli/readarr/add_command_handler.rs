@@ -0,0 +1,232 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use clap::{ArgAction, Subcommand};
+use tokio::sync::Mutex;
+
+use super::ReadarrCommand;
+use crate::{
+  app::App,
+  cli::{CliCommandHandler, Command, parse_key_value},
+  models::readarr_models::{AddAuthorBody, AddAuthorOptions, MonitorType, NewItemMonitorType},
+  models::servarr_models::AddIndexerParams,
+  network::{NetworkTrait, readarr_network::ReadarrEvent},
+};
+
+#[cfg(test)]
+#[path = "add_command_handler_tests.rs"]
+mod add_command_handler_tests;
+
+#[derive(Debug, Clone, PartialEq, Eq, Subcommand)]
+pub enum ReadarrAddCommand {
+  #[command(about = "Add a new author to your Readarr library")]
+  Author {
+    #[arg(
+      long,
+      help = "The Goodreads foreign author ID of the author you wish to add to your library",
+      required = true
+    )]
+    foreign_author_id: String,
+    #[arg(long, help = "The name of the author", required = true)]
+    author_name: String,
+    #[arg(
+      long,
+      help = "The root folder path where all author data and metadata should live",
+      required = true
+    )]
+    root_folder_path: String,
+    #[arg(
+      long,
+      help = "The ID of the quality profile to use for this author",
+      required = true
+    )]
+    quality_profile_id: i64,
+    #[arg(
+      long,
+      help = "The ID of the metadata profile to use for this author",
+      required = true
+    )]
+    metadata_profile_id: i64,
+    #[arg(long, help = "Disable monitoring for this author")]
+    disable_monitoring: bool,
+    #[arg(
+      long,
+      help = "Tag IDs to tag the author with",
+      value_parser,
+      action = ArgAction::Append
+    )]
+    tag: Vec<i64>,
+    #[arg(
+      long,
+      help = "What Readarr should monitor for this author",
+      value_enum,
+      default_value_t = MonitorType::default()
+    )]
+    monitor: MonitorType,
+    #[arg(
+      long,
+      help = "How Readarr should monitor new items for this author",
+      value_enum,
+      default_value_t = NewItemMonitorType::default()
+    )]
+    monitor_new_items: NewItemMonitorType,
+    #[arg(
+      long,
+      help = "Tell Readarr to not start a search for missing books once the author is added to your library"
+    )]
+    no_search_for_missing_books: bool,
+  },
+  #[command(
+    about = "Add a new indexer using one of the implementations in the Readarr indexer schema"
+  )]
+  Indexer {
+    #[arg(
+      long,
+      help = "The implementation of the indexer to add (e.g. 'Newznab' or 'Torznab')",
+      required = true
+    )]
+    implementation: String,
+    #[arg(long, help = "The name of the indexer", required = true)]
+    name: String,
+    #[arg(
+      long,
+      help = "Disable using this indexer when Readarr periodically looks for releases via RSS Sync"
+    )]
+    disable_rss: bool,
+    #[arg(
+      long,
+      help = "Disable using this indexer whenever automatic searches are performed via the UI or by Readarr"
+    )]
+    disable_automatic_search: bool,
+    #[arg(
+      long,
+      help = "Disable using this indexer whenever an interactive search is performed"
+    )]
+    disable_interactive_search: bool,
+    #[arg(
+      long,
+      help = "Indexer Priority from 1 (Highest) to 50 (Lowest)",
+      default_value_t = 25
+    )]
+    priority: i64,
+    #[arg(
+      long,
+      help = "Only use this indexer for authors with at least one matching tag ID. Leave blank to use with all authors.",
+      value_parser,
+      action = ArgAction::Append
+    )]
+    tag: Vec<i64>,
+    #[arg(
+      long,
+      help = "A setting of the indexer implementation in the form 'name=value' (e.g. 'baseUrl=https://indexer.example'). Can be specified multiple times",
+      value_parser = parse_key_value,
+      action = ArgAction::Append
+    )]
+    field: Vec<(String, String)>,
+  },
+  #[command(about = "Add new tag")]
+  Tag {
+    #[arg(long, help = "The name of the tag to be added", required = true)]
+    name: String,
+  },
+}
+
+impl From<ReadarrAddCommand> for Command {
+  fn from(value: ReadarrAddCommand) -> Self {
+    Command::Readarr(ReadarrCommand::Add(value))
+  }
+}
+
+pub(super) struct ReadarrAddCommandHandler<'a, 'b> {
+  _app: &'a Arc<Mutex<App<'b>>>,
+  command: ReadarrAddCommand,
+  network: &'a mut dyn NetworkTrait,
+}
+
+impl<'a, 'b> CliCommandHandler<'a, 'b, ReadarrAddCommand> for ReadarrAddCommandHandler<'a, 'b> {
+  fn with(
+    app: &'a Arc<Mutex<App<'b>>>,
+    command: ReadarrAddCommand,
+    network: &'a mut dyn NetworkTrait,
+  ) -> Self {
+    ReadarrAddCommandHandler {
+      _app: app,
+      command,
+      network,
+    }
+  }
+
+  async fn handle(self) -> Result<String> {
+    let result = match self.command {
+      ReadarrAddCommand::Author {
+        foreign_author_id,
+        author_name,
+        root_folder_path,
+        quality_profile_id,
+        metadata_profile_id,
+        disable_monitoring,
+        tag: tags,
+        monitor,
+        monitor_new_items,
+        no_search_for_missing_books,
+      } => {
+        let body = AddAuthorBody {
+          foreign_author_id,
+          author_name,
+          monitored: !disable_monitoring,
+          root_folder_path,
+          quality_profile_id,
+          metadata_profile_id,
+          tags,
+          tag_input_string: None,
+          add_options: AddAuthorOptions {
+            monitor,
+            monitor_new_items,
+            search_for_missing_books: !no_search_for_missing_books,
+          },
+        };
+        let resp = self
+          .network
+          .handle_network_event(ReadarrEvent::AddAuthor(body).into())
+          .await?;
+        serde_json::to_string_pretty(&resp)?
+      }
+      ReadarrAddCommand::Indexer {
+        implementation,
+        name,
+        disable_rss,
+        disable_automatic_search,
+        disable_interactive_search,
+        priority,
+        tag: tags,
+        field: fields,
+      } => {
+        let add_indexer_params = AddIndexerParams {
+          implementation,
+          name,
+          enable_rss: !disable_rss,
+          enable_automatic_search: !disable_automatic_search,
+          enable_interactive_search: !disable_interactive_search,
+          priority,
+          tags,
+          fields,
+          ..AddIndexerParams::default()
+        };
+        let resp = self
+          .network
+          .handle_network_event(ReadarrEvent::AddIndexer(add_indexer_params).into())
+          .await?;
+        serde_json::to_string_pretty(&resp)?
+      }
+      ReadarrAddCommand::Tag { name } => {
+        let resp = self
+          .network
+          .handle_network_event(ReadarrEvent::AddTag(name).into())
+          .await?;
+        serde_json::to_string_pretty(&resp)?
+      }
+    };
+
+    Ok(result)
+  }
+}
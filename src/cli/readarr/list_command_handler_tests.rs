@@ -0,0 +1,254 @@
+#[cfg(test)]
+mod tests {
+  use crate::Cli;
+  use crate::cli::{
+    Command,
+    readarr::{ReadarrCommand, list_command_handler::ReadarrListCommand},
+  };
+  use clap::CommandFactory;
+  use pretty_assertions::assert_eq;
+
+  #[test]
+  fn test_readarr_list_command_from() {
+    let command = ReadarrListCommand::Authors;
+
+    let result = Command::from(command.clone());
+
+    assert_eq!(result, Command::Readarr(ReadarrCommand::List(command)));
+  }
+
+  mod cli {
+    use super::*;
+    use clap::{Parser, error::ErrorKind};
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    #[rstest]
+    fn test_list_commands_have_no_arg_requirements(
+      #[values(
+        "authors",
+        "disk-space",
+        "metadata-profiles",
+        "quality-profiles",
+        "queued-events",
+        "tags",
+        "tasks",
+        "updates",
+        "root-folders"
+      )]
+      subcommand: &str,
+    ) {
+      let result = Cli::command().try_get_matches_from(["managarr", "readarr", "list", subcommand]);
+
+      assert_ok!(&result);
+    }
+
+    #[test]
+    fn test_list_books_requires_author_id() {
+      let result = Cli::command().try_get_matches_from(["managarr", "readarr", "list", "books"]);
+
+      assert_err!(&result);
+      assert_eq!(
+        result.unwrap_err().kind(),
+        ErrorKind::MissingRequiredArgument
+      );
+    }
+
+    #[test]
+    fn test_list_books_with_author_id() {
+      let expected_args = ReadarrListCommand::Books { author_id: 1 };
+      let result =
+        Cli::try_parse_from(["managarr", "readarr", "list", "books", "--author-id", "1"]);
+
+      assert_ok!(&result);
+
+      let Some(Command::Readarr(ReadarrCommand::List(book_command))) = result.unwrap().command
+      else {
+        panic!("Unexpected command type");
+      };
+      assert_eq!(book_command, expected_args);
+    }
+
+    #[test]
+    fn test_list_editions_requires_book_id() {
+      let result = Cli::command().try_get_matches_from(["managarr", "readarr", "list", "editions"]);
+
+      assert_err!(&result);
+      assert_eq!(
+        result.unwrap_err().kind(),
+        ErrorKind::MissingRequiredArgument
+      );
+    }
+
+    #[test]
+    fn test_list_editions_with_book_id() {
+      let expected_args = ReadarrListCommand::Editions { book_id: 1 };
+      let result =
+        Cli::try_parse_from(["managarr", "readarr", "list", "editions", "--book-id", "1"]);
+
+      assert_ok!(&result);
+
+      let Some(Command::Readarr(ReadarrCommand::List(editions_command))) = result.unwrap().command
+      else {
+        panic!("Unexpected command type");
+      };
+      assert_eq!(editions_command, expected_args);
+    }
+
+    #[test]
+    fn test_list_logs_events_flag_requires_arguments() {
+      let result =
+        Cli::command().try_get_matches_from(["managarr", "readarr", "list", "logs", "--events"]);
+
+      assert_err!(&result);
+      assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidValue);
+    }
+
+    #[test]
+    fn test_list_logs_default_values() {
+      let expected_args = ReadarrListCommand::Logs {
+        events: 500,
+        output_in_log_format: false,
+      };
+      let result = Cli::try_parse_from(["managarr", "readarr", "list", "logs"]);
+
+      assert_ok!(&result);
+
+      let Some(Command::Readarr(ReadarrCommand::List(logs_command))) = result.unwrap().command
+      else {
+        panic!("Unexpected command type");
+      };
+      assert_eq!(logs_command, expected_args);
+    }
+  }
+
+  mod handler {
+    use std::sync::Arc;
+
+    use mockall::predicate::eq;
+    use rstest::rstest;
+    use serde_json::json;
+    use tokio::sync::Mutex;
+
+    use crate::cli::CliCommandHandler;
+    use crate::cli::readarr::list_command_handler::{
+      ReadarrListCommand, ReadarrListCommandHandler,
+    };
+    use crate::models::Serdeable;
+    use crate::models::readarr_models::ReadarrSerdeable;
+    use crate::network::readarr_network::ReadarrEvent;
+    use crate::{
+      app::App,
+      network::{MockNetworkTrait, NetworkEvent},
+    };
+
+    #[rstest]
+    #[case(ReadarrListCommand::Authors, ReadarrEvent::ListAuthors)]
+    #[case(ReadarrListCommand::DiskSpace, ReadarrEvent::GetDiskSpace)]
+    #[case(
+      ReadarrListCommand::MetadataProfiles,
+      ReadarrEvent::GetMetadataProfiles
+    )]
+    #[case(ReadarrListCommand::QualityProfiles, ReadarrEvent::GetQualityProfiles)]
+    #[case(ReadarrListCommand::QueuedEvents, ReadarrEvent::GetQueuedEvents)]
+    #[case(ReadarrListCommand::RootFolders, ReadarrEvent::GetRootFolders)]
+    #[case(ReadarrListCommand::Tags, ReadarrEvent::GetTags)]
+    #[case(ReadarrListCommand::Tasks, ReadarrEvent::GetTasks)]
+    #[case(ReadarrListCommand::Updates, ReadarrEvent::GetUpdates)]
+    #[tokio::test]
+    async fn test_handle_list_command(
+      #[case] list_command: ReadarrListCommand,
+      #[case] expected_readarr_event: ReadarrEvent,
+    ) {
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(expected_readarr_event.into()))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Readarr(ReadarrSerdeable::Value(
+            json!({"testResponse": "response"}),
+          )))
+        });
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+
+      let result = ReadarrListCommandHandler::with(&app_arc, list_command, &mut mock_network)
+        .handle()
+        .await;
+
+      assert_ok!(&result);
+    }
+
+    #[tokio::test]
+    async fn test_handle_list_books_command() {
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(ReadarrEvent::GetBooks(1).into()))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Readarr(ReadarrSerdeable::Value(
+            json!({"testResponse": "response"}),
+          )))
+        });
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+      let list_command = ReadarrListCommand::Books { author_id: 1 };
+
+      let result = ReadarrListCommandHandler::with(&app_arc, list_command, &mut mock_network)
+        .handle()
+        .await;
+
+      assert_ok!(&result);
+    }
+
+    #[tokio::test]
+    async fn test_handle_list_editions_command() {
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(ReadarrEvent::GetEditions(1).into()))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Readarr(ReadarrSerdeable::Value(
+            json!({"testResponse": "response"}),
+          )))
+        });
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+      let list_command = ReadarrListCommand::Editions { book_id: 1 };
+
+      let result = ReadarrListCommandHandler::with(&app_arc, list_command, &mut mock_network)
+        .handle()
+        .await;
+
+      assert_ok!(&result);
+    }
+
+    #[tokio::test]
+    async fn test_handle_list_logs_command() {
+      let expected_events = 1000;
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(
+          ReadarrEvent::GetLogs(expected_events).into(),
+        ))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Readarr(ReadarrSerdeable::Value(
+            json!({"testResponse": "response"}),
+          )))
+        });
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+      let list_logs_command = ReadarrListCommand::Logs {
+        events: 1000,
+        output_in_log_format: false,
+      };
+
+      let result = ReadarrListCommandHandler::with(&app_arc, list_logs_command, &mut mock_network)
+        .handle()
+        .await;
+
+      assert_ok!(&result);
+    }
+  }
+}
@@ -1,13 +1,16 @@
 use std::sync::Arc;
 
 use anyhow::Result;
-use clap::Subcommand;
+use clap::{ArgAction, Subcommand};
 use tokio::sync::Mutex;
 
 use crate::{
   app::App,
   cli::{CliCommandHandler, Command},
-  models::sonarr_models::DeleteSeriesParams,
+  models::{
+    servarr_models::{BulkDeleteDownloadsParams, DeleteDownloadParams, QueueRemovalOptions},
+    sonarr_models::DeleteSeriesParams,
+  },
   network::{NetworkTrait, sonarr_network::SonarrEvent},
 };
 
@@ -19,6 +22,11 @@ mod delete_command_handler_tests;
 
 #[derive(Debug, Clone, PartialEq, Eq, Subcommand)]
 pub enum SonarrDeleteCommand {
+  #[command(about = "Delete the specified backup from your Sonarr instance")]
+  Backup {
+    #[arg(long, help = "The ID of the backup to delete", required = true)]
+    backup_id: i64,
+  },
   #[command(about = "Delete the specified item from the Sonarr blocklist")]
   BlocklistItem {
     #[arg(
@@ -28,16 +36,54 @@ pub enum SonarrDeleteCommand {
     )]
     blocklist_item_id: i64,
   },
-  #[command(about = "Delete the specified download")]
+  #[command(about = "Delete the specified custom format")]
+  CustomFormat {
+    #[arg(long, help = "The ID of the custom format to delete", required = true)]
+    custom_format_id: i64,
+  },
+  #[command(about = "Delete the specified download(s) from the Sonarr queue")]
   Download {
-    #[arg(long, help = "The ID of the download to delete", required = true)]
-    download_id: i64,
+    #[arg(
+      long,
+      help = "The ID of the download to delete. Repeat to delete multiple downloads at once",
+      required = true,
+      action = ArgAction::Append
+    )]
+    download_id: Vec<i64>,
+    #[arg(
+      long,
+      help = "Remove the download from the download client",
+      default_value_t = true,
+      action = ArgAction::Set
+    )]
+    remove_from_client: bool,
+    #[arg(long, help = "Add the release to the blocklist")]
+    blocklist: bool,
+    #[arg(
+      long,
+      help = "Don't search for a replacement release after blocklisting this one"
+    )]
+    skip_redownload: bool,
+    #[arg(
+      long,
+      help = "Change the download's category to the post-import category instead of removing it from the client"
+    )]
+    change_category: bool,
   },
   #[command(about = "Delete the specified episode file from disk")]
   EpisodeFile {
     #[arg(long, help = "The ID of the episode file to delete", required = true)]
     episode_file_id: i64,
   },
+  #[command(about = "Delete the download client with the given ID")]
+  DownloadClient {
+    #[arg(
+      long,
+      help = "The ID of the download client to delete",
+      required = true
+    )]
+    download_client_id: i64,
+  },
   #[command(about = "Delete the indexer with the given ID")]
   Indexer {
     #[arg(long, help = "The ID of the indexer to delete", required = true)]
@@ -91,6 +137,13 @@ impl<'a, 'b> CliCommandHandler<'a, 'b, SonarrDeleteCommand> for SonarrDeleteComm
 
   async fn handle(self) -> Result<String> {
     let resp = match self.command {
+      SonarrDeleteCommand::Backup { backup_id } => {
+        let resp = self
+          .network
+          .handle_network_event(SonarrEvent::DeleteBackup(backup_id).into())
+          .await?;
+        serde_json::to_string_pretty(&resp)?
+      }
       SonarrDeleteCommand::BlocklistItem { blocklist_item_id } => {
         let resp = self
           .network
@@ -98,13 +151,40 @@ impl<'a, 'b> CliCommandHandler<'a, 'b, SonarrDeleteCommand> for SonarrDeleteComm
           .await?;
         serde_json::to_string_pretty(&resp)?
       }
-      SonarrDeleteCommand::Download { download_id } => {
+      SonarrDeleteCommand::CustomFormat { custom_format_id } => {
         let resp = self
           .network
-          .handle_network_event(SonarrEvent::DeleteDownload(download_id).into())
+          .handle_network_event(SonarrEvent::DeleteCustomFormat(custom_format_id).into())
           .await?;
         serde_json::to_string_pretty(&resp)?
       }
+      SonarrDeleteCommand::Download {
+        download_id,
+        remove_from_client,
+        blocklist,
+        skip_redownload,
+        change_category,
+      } => {
+        let removal_options = QueueRemovalOptions {
+          remove_from_client,
+          blocklist,
+          skip_redownload,
+          change_category,
+        };
+        let event = if let [id] = download_id[..] {
+          SonarrEvent::DeleteDownload(DeleteDownloadParams {
+            id,
+            removal_options,
+          })
+        } else {
+          SonarrEvent::BulkDeleteDownloads(BulkDeleteDownloadsParams {
+            ids: download_id,
+            removal_options,
+          })
+        };
+        let resp = self.network.handle_network_event(event.into()).await?;
+        serde_json::to_string_pretty(&resp)?
+      }
       SonarrDeleteCommand::EpisodeFile { episode_file_id } => {
         let resp = self
           .network
@@ -112,6 +192,13 @@ impl<'a, 'b> CliCommandHandler<'a, 'b, SonarrDeleteCommand> for SonarrDeleteComm
           .await?;
         serde_json::to_string_pretty(&resp)?
       }
+      SonarrDeleteCommand::DownloadClient { download_client_id } => {
+        let resp = self
+          .network
+          .handle_network_event(SonarrEvent::DeleteDownloadClient(download_client_id).into())
+          .await?;
+        serde_json::to_string_pretty(&resp)?
+      }
       SonarrDeleteCommand::Indexer { indexer_id } => {
         let resp = self
           .network
@@ -25,6 +25,33 @@ mod tests {
     use super::*;
     use pretty_assertions::assert_eq;
 
+    #[test]
+    fn test_delete_backup_requires_arguments() {
+      let result = Cli::command().try_get_matches_from(["managarr", "sonarr", "delete", "backup"]);
+
+      assert_err!(&result);
+      assert_eq!(
+        result.unwrap_err().kind(),
+        ErrorKind::MissingRequiredArgument
+      );
+    }
+
+    #[test]
+    fn test_delete_backup_success() {
+      let expected_args = SonarrDeleteCommand::Backup { backup_id: 1 };
+
+      let result =
+        Cli::try_parse_from(["managarr", "sonarr", "delete", "backup", "--backup-id", "1"]);
+
+      assert_ok!(&result);
+
+      let Some(Command::Sonarr(SonarrCommand::Delete(delete_command))) = result.unwrap().command
+      else {
+        panic!("Unexpected command type");
+      };
+      assert_eq!(delete_command, expected_args);
+    }
+
     #[test]
     fn test_delete_blocklist_item_requires_arguments() {
       let result =
@@ -75,7 +102,41 @@ mod tests {
 
     #[test]
     fn test_delete_download_success() {
-      let expected_args = SonarrDeleteCommand::Download { download_id: 1 };
+      let expected_args = SonarrDeleteCommand::Download {
+        download_id: vec![1],
+        remove_from_client: true,
+        blocklist: false,
+        skip_redownload: false,
+        change_category: false,
+      };
+
+      let result = Cli::try_parse_from([
+        "managarr",
+        "sonarr",
+        "delete",
+        "download",
+        "--download-id",
+        "1",
+      ]);
+
+      assert_ok!(&result);
+
+      let Some(Command::Sonarr(SonarrCommand::Delete(delete_command))) = result.unwrap().command
+      else {
+        panic!("Unexpected command type");
+      };
+      assert_eq!(delete_command, expected_args);
+    }
+
+    #[test]
+    fn test_delete_download_all_arguments_success() {
+      let expected_args = SonarrDeleteCommand::Download {
+        download_id: vec![1, 2],
+        remove_from_client: false,
+        blocklist: true,
+        skip_redownload: true,
+        change_category: true,
+      };
 
       let result = Cli::try_parse_from([
         "managarr",
@@ -84,6 +145,13 @@ mod tests {
         "download",
         "--download-id",
         "1",
+        "--download-id",
+        "2",
+        "--remove-from-client",
+        "false",
+        "--blocklist",
+        "--skip-redownload",
+        "--change-category",
       ]);
 
       assert_ok!(&result);
@@ -129,6 +197,78 @@ mod tests {
       assert_eq!(delete_command, expected_args);
     }
 
+    #[test]
+    fn test_delete_custom_format_requires_arguments() {
+      let result =
+        Cli::command().try_get_matches_from(["managarr", "sonarr", "delete", "custom-format"]);
+
+      assert_err!(&result);
+      assert_eq!(
+        result.unwrap_err().kind(),
+        ErrorKind::MissingRequiredArgument
+      );
+    }
+
+    #[test]
+    fn test_delete_custom_format_success() {
+      let expected_args = SonarrDeleteCommand::CustomFormat {
+        custom_format_id: 1,
+      };
+
+      let result = Cli::try_parse_from([
+        "managarr",
+        "sonarr",
+        "delete",
+        "custom-format",
+        "--custom-format-id",
+        "1",
+      ]);
+
+      assert_ok!(&result);
+
+      let Some(Command::Sonarr(SonarrCommand::Delete(delete_command))) = result.unwrap().command
+      else {
+        panic!("Unexpected command type");
+      };
+      assert_eq!(delete_command, expected_args);
+    }
+
+    #[test]
+    fn test_delete_download_client_requires_arguments() {
+      let result =
+        Cli::command().try_get_matches_from(["managarr", "sonarr", "delete", "download-client"]);
+
+      assert_err!(&result);
+      assert_eq!(
+        result.unwrap_err().kind(),
+        ErrorKind::MissingRequiredArgument
+      );
+    }
+
+    #[test]
+    fn test_delete_download_client_success() {
+      let expected_args = SonarrDeleteCommand::DownloadClient {
+        download_client_id: 1,
+      };
+
+      let result = Cli::try_parse_from([
+        "managarr",
+        "sonarr",
+        "delete",
+        "download-client",
+        "--download-client-id",
+        "1",
+      ]);
+
+      assert_ok!(&result);
+
+      let Some(Command::Sonarr(SonarrCommand::Delete(delete_command))) = result.unwrap().command
+      else {
+        panic!("Unexpected command type");
+      };
+      assert_eq!(delete_command, expected_args);
+    }
+
     #[test]
     fn test_delete_indexer_requires_arguments() {
       let result = Cli::command().try_get_matches_from(["managarr", "sonarr", "delete", "indexer"]);
@@ -289,6 +429,10 @@ mod tests {
     use serde_json::json;
     use tokio::sync::Mutex;
 
+    use crate::models::servarr_models::{
+      BulkDeleteDownloadsParams, DeleteDownloadParams, QueueRemovalOptions,
+    };
+
     use crate::{
       app::App,
       cli::{
@@ -302,6 +446,32 @@ mod tests {
       network::{MockNetworkTrait, NetworkEvent, sonarr_network::SonarrEvent},
     };
 
+    #[tokio::test]
+    async fn test_handle_delete_backup_command() {
+      let expected_backup_id = 1;
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(
+          SonarrEvent::DeleteBackup(expected_backup_id).into(),
+        ))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Sonarr(SonarrSerdeable::Value(
+            json!({"testResponse": "response"}),
+          )))
+        });
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+      let delete_backup_command = SonarrDeleteCommand::Backup { backup_id: 1 };
+
+      let result =
+        SonarrDeleteCommandHandler::with(&app_arc, delete_backup_command, &mut mock_network)
+          .handle()
+          .await;
+
+      assert_ok!(&result);
+    }
+
     #[tokio::test]
     async fn test_handle_delete_blocklist_item_command() {
       let expected_blocklist_item_id = 1;
@@ -335,12 +505,55 @@ mod tests {
 
     #[tokio::test]
     async fn test_handle_delete_download_command() {
-      let expected_download_id = 1;
+      let expected_params = DeleteDownloadParams {
+        id: 1,
+        removal_options: QueueRemovalOptions::default(),
+      };
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(
+          SonarrEvent::DeleteDownload(expected_params).into(),
+        ))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Sonarr(SonarrSerdeable::Value(
+            json!({"testResponse": "response"}),
+          )))
+        });
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+      let delete_download_command = SonarrDeleteCommand::Download {
+        download_id: vec![1],
+        remove_from_client: true,
+        blocklist: false,
+        skip_redownload: false,
+        change_category: false,
+      };
+
+      let result =
+        SonarrDeleteCommandHandler::with(&app_arc, delete_download_command, &mut mock_network)
+          .handle()
+          .await;
+
+      assert_ok!(&result);
+    }
+
+    #[tokio::test]
+    async fn test_handle_bulk_delete_downloads_command() {
+      let expected_params = BulkDeleteDownloadsParams {
+        ids: vec![1, 2],
+        removal_options: QueueRemovalOptions {
+          remove_from_client: false,
+          blocklist: true,
+          skip_redownload: false,
+          change_category: false,
+        },
+      };
       let mut mock_network = MockNetworkTrait::new();
       mock_network
         .expect_handle_network_event()
         .with(eq::<NetworkEvent>(
-          SonarrEvent::DeleteDownload(expected_download_id).into(),
+          SonarrEvent::BulkDeleteDownloads(expected_params).into(),
         ))
         .times(1)
         .returning(|_| {
@@ -349,7 +562,13 @@ mod tests {
           )))
         });
       let app_arc = Arc::new(Mutex::new(App::test_default()));
-      let delete_download_command = SonarrDeleteCommand::Download { download_id: 1 };
+      let delete_download_command = SonarrDeleteCommand::Download {
+        download_id: vec![1, 2],
+        remove_from_client: false,
+        blocklist: true,
+        skip_redownload: false,
+        change_category: false,
+      };
 
       let result =
         SonarrDeleteCommandHandler::with(&app_arc, delete_download_command, &mut mock_network)
@@ -385,6 +604,65 @@ mod tests {
       assert_ok!(&result);
     }
 
+    #[tokio::test]
+    async fn test_handle_delete_custom_format_command() {
+      let expected_custom_format_id = 1;
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(
+          SonarrEvent::DeleteCustomFormat(expected_custom_format_id).into(),
+        ))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Sonarr(SonarrSerdeable::Value(
+            json!({"testResponse": "response"}),
+          )))
+        });
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+      let delete_custom_format_command = SonarrDeleteCommand::CustomFormat {
+        custom_format_id: 1,
+      };
+
+      let result =
+        SonarrDeleteCommandHandler::with(&app_arc, delete_custom_format_command, &mut mock_network)
+          .handle()
+          .await;
+
+      assert_ok!(&result);
+    }
+
+    #[tokio::test]
+    async fn test_handle_delete_download_client_command() {
+      let expected_download_client_id = 1;
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(
+          SonarrEvent::DeleteDownloadClient(expected_download_client_id).into(),
+        ))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Sonarr(SonarrSerdeable::Value(
+            json!({"testResponse": "response"}),
+          )))
+        });
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+      let delete_download_client_command = SonarrDeleteCommand::DownloadClient {
+        download_client_id: 1,
+      };
+
+      let result = SonarrDeleteCommandHandler::with(
+        &app_arc,
+        delete_download_client_command,
+        &mut mock_network,
+      )
+      .handle()
+      .await;
+
+      assert_ok!(&result);
+    }
+
     #[tokio::test]
     async fn test_handle_delete_indexer_command() {
       let expected_indexer_id = 1;
@@ -6,11 +6,16 @@ use tokio::sync::Mutex;
 
 use crate::{
   app::App,
-  cli::{CliCommandHandler, Command, mutex_flags_or_option},
+  cli::{
+    CliCommandHandler, Command, mutex_flags_or_default, mutex_flags_or_option, parse_key_value,
+  },
   models::{
     Serdeable,
-    servarr_models::{EditIndexerParams, IndexerSettings},
-    sonarr_models::{EditSeriesParams, SeriesType, SonarrSerdeable},
+    servarr_models::{
+      EditCustomFormatParams, EditDownloadClientParams, EditIndexerParams,
+      EditQualityProfileParams, IndexerSettings, MediaManagementConfig, MediaManagementSettings,
+    },
+    sonarr_models::{EditSeriesParams, NamingConfig, SeriesType, SonarrSerdeable},
   },
   network::{NetworkTrait, sonarr_network::SonarrEvent},
 };
@@ -57,6 +62,130 @@ pub enum SonarrEditCommand {
     )]
     rss_sync_interval: Option<i64>,
   },
+  #[command(
+    about = "Edit the name, renaming behaviour and specifications of the specified custom format",
+    group(
+      ArgGroup::new("edit_custom_format")
+      .args([
+        "name",
+        "include_when_renaming",
+        "exclude_when_renaming",
+        "field"
+      ]).required(true)
+      .multiple(true))
+  )]
+  CustomFormat {
+    #[arg(
+      long,
+      help = "The ID of the custom format whose settings you wish to edit",
+      required = true
+    )]
+    custom_format_id: i64,
+    #[arg(long, help = "The name of the custom format")]
+    name: Option<String>,
+    #[arg(
+      long,
+      help = "Include this custom format in the {Custom Formats} renaming token",
+      conflicts_with = "exclude_when_renaming"
+    )]
+    include_when_renaming: bool,
+    #[arg(
+      long,
+      help = "Exclude this custom format from the {Custom Formats} renaming token",
+      conflicts_with = "include_when_renaming"
+    )]
+    exclude_when_renaming: bool,
+    #[arg(
+      long,
+      help = "A setting of one of the custom format's specifications in the form '<specification>.<field>=value' (e.g. 'x265.value=\\bx265\\b' or 'x265.negate=true'). Can be specified multiple times",
+      value_parser = parse_key_value,
+      action = ArgAction::Append
+    )]
+    field: Vec<(String, String)>,
+  },
+  #[command(
+    about = "Edit preferences for the specified download client",
+    group(
+      ArgGroup::new("edit_download_client")
+      .args([
+        "name",
+        "enable",
+        "disable",
+        "priority",
+        "enable_remove_completed_downloads",
+        "disable_remove_completed_downloads",
+        "enable_remove_failed_downloads",
+        "disable_remove_failed_downloads",
+        "tag",
+        "clear_tags",
+        "field"
+      ]).required(true)
+      .multiple(true))
+  )]
+  DownloadClient {
+    #[arg(
+      long,
+      help = "The ID of the download client whose settings you wish to edit",
+      required = true
+    )]
+    download_client_id: i64,
+    #[arg(long, help = "The name of the download client")]
+    name: Option<String>,
+    #[arg(long, help = "Enable the download client", conflicts_with = "disable")]
+    enable: bool,
+    #[arg(long, help = "Disable the download client", conflicts_with = "enable")]
+    disable: bool,
+    #[arg(
+      long,
+      help = "Download Client Priority from 1 (Highest) to 50 (Lowest). Round-Robin is used for clients with the same priority"
+    )]
+    priority: Option<i64>,
+    #[arg(
+      long,
+      help = "Remove imported downloads from the download client history",
+      conflicts_with = "disable_remove_completed_downloads"
+    )]
+    enable_remove_completed_downloads: bool,
+    #[arg(
+      long,
+      help = "Don't remove imported downloads from the download client history",
+      conflicts_with = "enable_remove_completed_downloads"
+    )]
+    disable_remove_completed_downloads: bool,
+    #[arg(
+      long,
+      help = "Remove failed downloads from the download client history",
+      conflicts_with = "disable_remove_failed_downloads"
+    )]
+    enable_remove_failed_downloads: bool,
+    #[arg(
+      long,
+      help = "Don't remove failed downloads from the download client history",
+      conflicts_with = "enable_remove_failed_downloads"
+    )]
+    disable_remove_failed_downloads: bool,
+    #[arg(
+      long,
+      help = "Only use this download client for series with at least one matching tag ID. Leave blank to use with all series.",
+      value_parser,
+      action = ArgAction::Append,
+      conflicts_with = "clear_tags"
+    )]
+    tag: Option<Vec<i64>>,
+    #[arg(
+      long,
+      help = "Clear all tags on this download client",
+      conflicts_with = "tag"
+    )]
+    clear_tags: bool,
+    #[arg(
+      long,
+      help = "A setting of the download client implementation in the form 'name=value' (e.g. 'host=localhost'). Can be specified multiple times",
+      value_parser = parse_key_value,
+      action = ArgAction::Append
+    )]
+    field: Vec<(String, String)>,
+  },
   #[command(
     about = "Edit preferences for the specified indexer",
     group(
@@ -148,6 +277,202 @@ pub enum SonarrEditCommand {
     #[arg(long, help = "Clear all tags on this indexer", conflicts_with = "tag")]
     clear_tags: bool,
   },
+  #[command(
+    about = "Edit the file naming and media management settings",
+    group(
+      ArgGroup::new("edit_media_management_settings")
+      .args([
+        "rename_episodes",
+        "disable_rename_episodes",
+        "replace_illegal_characters",
+        "disable_replace_illegal_characters",
+        "standard_episode_format",
+        "daily_episode_format",
+        "anime_episode_format",
+        "series_folder_format",
+        "season_folder_format",
+        "specials_folder_format",
+        "recycle_bin",
+        "recycle_bin_cleanup_days",
+        "set_permissions",
+        "disable_set_permissions",
+        "chmod_folder",
+        "chown_group",
+        "import_extra_files",
+        "disable_import_extra_files",
+        "extra_file_extensions"
+      ]).required(true)
+      .multiple(true))
+  )]
+  MediaManagementSettings {
+    #[arg(
+      long,
+      help = "Rename episode files using the configured episode formats",
+      conflicts_with = "disable_rename_episodes"
+    )]
+    rename_episodes: bool,
+    #[arg(
+      long,
+      help = "Keep the existing file names of episodes when importing them",
+      conflicts_with = "rename_episodes"
+    )]
+    disable_rename_episodes: bool,
+    #[arg(
+      long,
+      help = "Replace illegal characters in file names instead of removing them",
+      conflicts_with = "disable_replace_illegal_characters"
+    )]
+    replace_illegal_characters: bool,
+    #[arg(
+      long,
+      help = "Remove illegal characters from file names instead of replacing them",
+      conflicts_with = "replace_illegal_characters"
+    )]
+    disable_replace_illegal_characters: bool,
+    #[arg(
+      long,
+      help = "The naming format for standard episode files (e.g. '{Series Title} - S{season:00}E{episode:00} - {Episode Title} {Quality Full}')"
+    )]
+    standard_episode_format: Option<String>,
+    #[arg(
+      long,
+      help = "The naming format for daily episode files (e.g. '{Series Title} - {Air-Date} - {Episode Title} {Quality Full}')"
+    )]
+    daily_episode_format: Option<String>,
+    #[arg(
+      long,
+      help = "The naming format for anime episode files (e.g. '{Series Title} - S{season:00}E{episode:00} - {Episode Title} {Quality Full}')"
+    )]
+    anime_episode_format: Option<String>,
+    #[arg(
+      long,
+      help = "The naming format for series folders (e.g. '{Series Title}')"
+    )]
+    series_folder_format: Option<String>,
+    #[arg(
+      long,
+      help = "The naming format for season folders (e.g. 'Season {season}')"
+    )]
+    season_folder_format: Option<String>,
+    #[arg(
+      long,
+      help = "The naming format for the specials folder (e.g. 'Specials')"
+    )]
+    specials_folder_format: Option<String>,
+    #[arg(
+      long,
+      help = "The folder deleted files are moved to instead of being permanently deleted"
+    )]
+    recycle_bin: Option<String>,
+    #[arg(
+      long,
+      help = "The number of days after which files in the recycle bin are deleted. Set to zero to disable automatic cleanup"
+    )]
+    recycle_bin_cleanup_days: Option<i64>,
+    #[arg(
+      long,
+      help = "Set the permissions of imported files and folders",
+      conflicts_with = "disable_set_permissions"
+    )]
+    set_permissions: bool,
+    #[arg(
+      long,
+      help = "Leave the permissions of imported files and folders unchanged",
+      conflicts_with = "set_permissions"
+    )]
+    disable_set_permissions: bool,
+    #[arg(
+      long,
+      help = "The octal permissions set on media folders and files when importing (e.g. '755')"
+    )]
+    chmod_folder: Option<String>,
+    #[arg(long, help = "The group name or GID to set on imported files")]
+    chown_group: Option<String>,
+    #[arg(
+      long,
+      help = "Import matching extra files (subtitles, nfo, etc.) alongside episode files",
+      conflicts_with = "disable_import_extra_files"
+    )]
+    import_extra_files: bool,
+    #[arg(
+      long,
+      help = "Disable importing extra files alongside episode files",
+      conflicts_with = "import_extra_files"
+    )]
+    disable_import_extra_files: bool,
+    #[arg(
+      long,
+      help = "A comma separated list of the extra file extensions to import (e.g. 'srt,nfo')"
+    )]
+    extra_file_extensions: Option<String>,
+  },
+  #[command(
+    about = "Edit the specified quality profile",
+    group(
+      ArgGroup::new("edit_quality_profile")
+      .args([
+        "name",
+        "enable_upgrades",
+        "disable_upgrades",
+        "cutoff",
+        "min_format_score",
+        "cutoff_format_score",
+        "allow_quality",
+        "disallow_quality"
+      ]).required(true)
+      .multiple(true))
+  )]
+  QualityProfile {
+    #[arg(
+      long,
+      help = "The ID of the quality profile you wish to edit",
+      required = true
+    )]
+    quality_profile_id: i64,
+    #[arg(long, help = "The name of the quality profile")]
+    name: Option<String>,
+    #[arg(
+      long,
+      help = "Allow Sonarr to upgrade releases until the cutoff is reached",
+      conflicts_with = "disable_upgrades"
+    )]
+    enable_upgrades: bool,
+    #[arg(
+      long,
+      help = "Don't upgrade releases once one of the allowed qualities has been downloaded",
+      conflicts_with = "enable_upgrades"
+    )]
+    disable_upgrades: bool,
+    #[arg(
+      long,
+      help = "The name of the allowed quality or quality group to upgrade until (e.g. 'Bluray-1080p')"
+    )]
+    cutoff: Option<String>,
+    #[arg(
+      long,
+      help = "The minimum custom format score a release needs to be downloaded",
+      allow_negative_numbers = true
+    )]
+    min_format_score: Option<i64>,
+    #[arg(
+      long,
+      help = "Stop upgrading releases once this custom format score is reached",
+      allow_negative_numbers = true
+    )]
+    cutoff_format_score: Option<i64>,
+    #[arg(
+      long,
+      help = "The name of a quality or quality group to allow in this profile. Can be specified multiple times",
+      action = ArgAction::Append
+    )]
+    allow_quality: Vec<String>,
+    #[arg(
+      long,
+      help = "The name of a quality or quality group to no longer allow in this profile. Can be specified multiple times",
+      action = ArgAction::Append
+    )]
+    disallow_quality: Vec<String>,
+  },
   #[command(
     about = "Edit preferences for the specified series",
     group(
@@ -281,6 +606,68 @@ impl<'a, 'b> CliCommandHandler<'a, 'b, SonarrEditCommand> for SonarrEditCommandH
           String::new()
         }
       }
+      SonarrEditCommand::CustomFormat {
+        custom_format_id,
+        name,
+        include_when_renaming,
+        exclude_when_renaming,
+        field: fields,
+      } => {
+        let edit_custom_format_params = EditCustomFormatParams {
+          custom_format_id,
+          name,
+          include_custom_format_when_renaming: mutex_flags_or_option(
+            include_when_renaming,
+            exclude_when_renaming,
+          ),
+          fields,
+        };
+
+        self
+          .network
+          .handle_network_event(SonarrEvent::EditCustomFormat(edit_custom_format_params).into())
+          .await?;
+        "Custom format updated".to_owned()
+      }
+      SonarrEditCommand::DownloadClient {
+        download_client_id,
+        name,
+        enable,
+        disable,
+        priority,
+        enable_remove_completed_downloads,
+        disable_remove_completed_downloads,
+        enable_remove_failed_downloads,
+        disable_remove_failed_downloads,
+        tag,
+        clear_tags,
+        field: fields,
+      } => {
+        let edit_download_client_params = EditDownloadClientParams {
+          download_client_id,
+          name,
+          enable: mutex_flags_or_option(enable, disable),
+          priority,
+          remove_completed_downloads: mutex_flags_or_option(
+            enable_remove_completed_downloads,
+            disable_remove_completed_downloads,
+          ),
+          remove_failed_downloads: mutex_flags_or_option(
+            enable_remove_failed_downloads,
+            disable_remove_failed_downloads,
+          ),
+          tags: tag,
+          tag_input_string: None,
+          clear_tags,
+          fields,
+        };
+
+        self
+          .network
+          .handle_network_event(SonarrEvent::EditDownloadClient(edit_download_client_params).into())
+          .await?;
+        "Download client updated".to_owned()
+      }
       SonarrEditCommand::Indexer {
         indexer_id,
         name,
@@ -323,6 +710,115 @@ impl<'a, 'b> CliCommandHandler<'a, 'b, SonarrEditCommand> for SonarrEditCommandH
           .await?;
         "Indexer updated".to_owned()
       }
+      SonarrEditCommand::MediaManagementSettings {
+        rename_episodes,
+        disable_rename_episodes,
+        replace_illegal_characters,
+        disable_replace_illegal_characters,
+        standard_episode_format,
+        daily_episode_format,
+        anime_episode_format,
+        series_folder_format,
+        season_folder_format,
+        specials_folder_format,
+        recycle_bin,
+        recycle_bin_cleanup_days,
+        set_permissions,
+        disable_set_permissions,
+        chmod_folder,
+        chown_group,
+        import_extra_files,
+        disable_import_extra_files,
+        extra_file_extensions,
+      } => {
+        if let Serdeable::Sonarr(SonarrSerdeable::MediaManagementSettings(previous_settings)) = self
+          .network
+          .handle_network_event(SonarrEvent::GetMediaManagementSettings.into())
+          .await?
+        {
+          let MediaManagementSettings {
+            naming,
+            media_management,
+          } = previous_settings;
+          let params = MediaManagementSettings {
+            naming: NamingConfig {
+              rename_episodes: mutex_flags_or_default(
+                rename_episodes,
+                disable_rename_episodes,
+                naming.rename_episodes,
+              ),
+              replace_illegal_characters: mutex_flags_or_default(
+                replace_illegal_characters,
+                disable_replace_illegal_characters,
+                naming.replace_illegal_characters,
+              ),
+              standard_episode_format: standard_episode_format.or(naming.standard_episode_format),
+              daily_episode_format: daily_episode_format.or(naming.daily_episode_format),
+              anime_episode_format: anime_episode_format.or(naming.anime_episode_format),
+              series_folder_format: series_folder_format.or(naming.series_folder_format),
+              season_folder_format: season_folder_format.or(naming.season_folder_format),
+              specials_folder_format: specials_folder_format.or(naming.specials_folder_format),
+              ..naming
+            },
+            media_management: MediaManagementConfig {
+              recycle_bin: recycle_bin.or(media_management.recycle_bin),
+              recycle_bin_cleanup_days: recycle_bin_cleanup_days
+                .unwrap_or(media_management.recycle_bin_cleanup_days),
+              set_permissions_linux: mutex_flags_or_default(
+                set_permissions,
+                disable_set_permissions,
+                media_management.set_permissions_linux,
+              ),
+              chmod_folder: chmod_folder.or(media_management.chmod_folder),
+              chown_group: chown_group.or(media_management.chown_group),
+              import_extra_files: mutex_flags_or_default(
+                import_extra_files,
+                disable_import_extra_files,
+                media_management.import_extra_files,
+              ),
+              extra_file_extensions: extra_file_extensions
+                .or(media_management.extra_file_extensions),
+              ..media_management
+            },
+          };
+          self
+            .network
+            .handle_network_event(SonarrEvent::EditMediaManagementSettings(params).into())
+            .await?;
+          "Media management settings updated".to_owned()
+        } else {
+          String::new()
+        }
+      }
+      SonarrEditCommand::QualityProfile {
+        quality_profile_id,
+        name,
+        enable_upgrades,
+        disable_upgrades,
+        cutoff,
+        min_format_score,
+        cutoff_format_score,
+        allow_quality,
+        disallow_quality,
+      } => {
+        let edit_quality_profile_params = EditQualityProfileParams {
+          quality_profile_id,
+          name,
+          upgrade_allowed: mutex_flags_or_option(enable_upgrades, disable_upgrades),
+          cutoff,
+          min_format_score,
+          cutoff_format_score,
+          items: None,
+          allowed_qualities: allow_quality,
+          disallowed_qualities: disallow_quality,
+        };
+
+        self
+          .network
+          .handle_network_event(SonarrEvent::EditQualityProfile(edit_quality_profile_params).into())
+          .await?;
+        "Quality profile updated".to_owned()
+      }
       SonarrEditCommand::Series {
         series_id,
         enable_monitoring,
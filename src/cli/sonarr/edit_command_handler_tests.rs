@@ -117,6 +117,312 @@ mod tests {
       assert_eq!(edit_command, expected_args);
     }
 
+    #[test]
+    fn test_edit_custom_format_requires_arguments() {
+      let result =
+        Cli::command().try_get_matches_from(["managarr", "sonarr", "edit", "custom-format"]);
+
+      assert_err!(&result);
+      assert_eq!(
+        result.unwrap_err().kind(),
+        ErrorKind::MissingRequiredArgument
+      );
+    }
+
+    #[test]
+    fn test_edit_custom_format_with_custom_format_id_still_requires_arguments() {
+      let result = Cli::command().try_get_matches_from([
+        "managarr",
+        "sonarr",
+        "edit",
+        "custom-format",
+        "--custom-format-id",
+        "1",
+      ]);
+
+      assert_err!(&result);
+      assert_eq!(
+        result.unwrap_err().kind(),
+        ErrorKind::MissingRequiredArgument
+      );
+    }
+
+    #[test]
+    fn test_edit_custom_format_renaming_flags_conflict() {
+      let result = Cli::command().try_get_matches_from([
+        "managarr",
+        "sonarr",
+        "edit",
+        "custom-format",
+        "--custom-format-id",
+        "1",
+        "--include-when-renaming",
+        "--exclude-when-renaming",
+      ]);
+
+      assert_err!(&result);
+      assert_eq!(result.unwrap_err().kind(), ErrorKind::ArgumentConflict);
+    }
+
+    #[test]
+    fn test_edit_custom_format_field_requires_key_value_format() {
+      let result = Cli::command().try_get_matches_from([
+        "managarr",
+        "sonarr",
+        "edit",
+        "custom-format",
+        "--custom-format-id",
+        "1",
+        "--field",
+        "x265.value",
+      ]);
+
+      assert_err!(&result);
+      assert_eq!(result.unwrap_err().kind(), ErrorKind::ValueValidation);
+    }
+
+    #[test]
+    fn test_edit_custom_format_all_arguments_defined() {
+      let expected_args = SonarrEditCommand::CustomFormat {
+        custom_format_id: 1,
+        name: Some("HEVC".to_owned()),
+        include_when_renaming: true,
+        exclude_when_renaming: false,
+        field: vec![
+          ("x265.value".to_owned(), "HEVC".to_owned()),
+          ("x265.negate".to_owned(), "true".to_owned()),
+        ],
+      };
+
+      let result = Cli::try_parse_from([
+        "managarr",
+        "sonarr",
+        "edit",
+        "custom-format",
+        "--custom-format-id",
+        "1",
+        "--name",
+        "HEVC",
+        "--include-when-renaming",
+        "--field",
+        "x265.value=HEVC",
+        "--field",
+        "x265.negate=true",
+      ]);
+
+      assert_ok!(&result);
+
+      let Some(Command::Sonarr(SonarrCommand::Edit(edit_command))) = result.unwrap().command else {
+        panic!("Unexpected command type");
+      };
+      assert_eq!(edit_command, expected_args);
+    }
+
+    #[test]
+    fn test_edit_download_client_requires_arguments() {
+      let result =
+        Cli::command().try_get_matches_from(["managarr", "sonarr", "edit", "download-client"]);
+
+      assert_err!(&result);
+      assert_eq!(
+        result.unwrap_err().kind(),
+        ErrorKind::MissingRequiredArgument
+      );
+    }
+
+    #[test]
+    fn test_edit_download_client_with_download_client_id_still_requires_arguments() {
+      let result = Cli::command().try_get_matches_from([
+        "managarr",
+        "sonarr",
+        "edit",
+        "download-client",
+        "--download-client-id",
+        "1",
+      ]);
+
+      assert_err!(&result);
+      assert_eq!(
+        result.unwrap_err().kind(),
+        ErrorKind::MissingRequiredArgument
+      );
+    }
+
+    #[test]
+    fn test_edit_download_client_enable_flags_conflict() {
+      let result = Cli::command().try_get_matches_from([
+        "managarr",
+        "sonarr",
+        "edit",
+        "download-client",
+        "--download-client-id",
+        "1",
+        "--enable",
+        "--disable",
+      ]);
+
+      assert_err!(&result);
+      assert_eq!(result.unwrap_err().kind(), ErrorKind::ArgumentConflict);
+    }
+
+    #[test]
+    fn test_edit_download_client_remove_completed_downloads_flags_conflict() {
+      let result = Cli::command().try_get_matches_from([
+        "managarr",
+        "sonarr",
+        "edit",
+        "download-client",
+        "--download-client-id",
+        "1",
+        "--enable-remove-completed-downloads",
+        "--disable-remove-completed-downloads",
+      ]);
+
+      assert_err!(&result);
+      assert_eq!(result.unwrap_err().kind(), ErrorKind::ArgumentConflict);
+    }
+
+    #[test]
+    fn test_edit_download_client_remove_failed_downloads_flags_conflict() {
+      let result = Cli::command().try_get_matches_from([
+        "managarr",
+        "sonarr",
+        "edit",
+        "download-client",
+        "--download-client-id",
+        "1",
+        "--enable-remove-failed-downloads",
+        "--disable-remove-failed-downloads",
+      ]);
+
+      assert_err!(&result);
+      assert_eq!(result.unwrap_err().kind(), ErrorKind::ArgumentConflict);
+    }
+
+    #[test]
+    fn test_edit_download_client_tag_flags_conflict() {
+      let result = Cli::command().try_get_matches_from([
+        "managarr",
+        "sonarr",
+        "edit",
+        "download-client",
+        "--download-client-id",
+        "1",
+        "--tag",
+        "1",
+        "--clear-tags",
+      ]);
+
+      assert_err!(&result);
+      assert_eq!(result.unwrap_err().kind(), ErrorKind::ArgumentConflict);
+    }
+
+    #[rstest]
+    fn test_edit_download_client_assert_argument_flags_require_args(
+      #[values("--name", "--priority", "--tag", "--field")] flag: &str,
+    ) {
+      let result = Cli::command().try_get_matches_from([
+        "managarr",
+        "sonarr",
+        "edit",
+        "download-client",
+        "--download-client-id",
+        "1",
+        flag,
+      ]);
+
+      assert_err!(&result);
+      assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidValue);
+    }
+
+    #[test]
+    fn test_edit_download_client_only_requires_at_least_one_argument_plus_download_client_id() {
+      let expected_args = SonarrEditCommand::DownloadClient {
+        download_client_id: 1,
+        name: None,
+        enable: false,
+        disable: false,
+        priority: None,
+        enable_remove_completed_downloads: false,
+        disable_remove_completed_downloads: false,
+        enable_remove_failed_downloads: false,
+        disable_remove_failed_downloads: false,
+        tag: None,
+        clear_tags: false,
+        field: vec![("category".to_owned(), "movies".to_owned())],
+      };
+
+      let result = Cli::try_parse_from([
+        "managarr",
+        "sonarr",
+        "edit",
+        "download-client",
+        "--download-client-id",
+        "1",
+        "--field",
+        "category=movies",
+      ]);
+
+      assert_ok!(&result);
+
+      let Some(Command::Sonarr(SonarrCommand::Edit(edit_command))) = result.unwrap().command else {
+        panic!("Unexpected command type");
+      };
+      assert_eq!(edit_command, expected_args);
+    }
+
+    #[test]
+    fn test_edit_download_client_all_arguments_defined() {
+      let expected_args = SonarrEditCommand::DownloadClient {
+        download_client_id: 1,
+        name: Some("Test".to_owned()),
+        enable: true,
+        disable: false,
+        priority: Some(2),
+        enable_remove_completed_downloads: true,
+        disable_remove_completed_downloads: false,
+        enable_remove_failed_downloads: false,
+        disable_remove_failed_downloads: true,
+        tag: Some(vec![1, 2]),
+        clear_tags: false,
+        field: vec![
+          ("host".to_owned(), "localhost".to_owned()),
+          ("port".to_owned(), "8080".to_owned()),
+        ],
+      };
+
+      let result = Cli::try_parse_from([
+        "managarr",
+        "sonarr",
+        "edit",
+        "download-client",
+        "--download-client-id",
+        "1",
+        "--name",
+        "Test",
+        "--enable",
+        "--priority",
+        "2",
+        "--enable-remove-completed-downloads",
+        "--disable-remove-failed-downloads",
+        "--tag",
+        "1",
+        "--tag",
+        "2",
+        "--field",
+        "host=localhost",
+        "--field",
+        "port=8080",
+      ]);
+
+      assert_ok!(&result);
+
+      let Some(Command::Sonarr(SonarrCommand::Edit(edit_command))) = result.unwrap().command else {
+        panic!("Unexpected command type");
+      };
+      assert_eq!(edit_command, expected_args);
+    }
+
     #[test]
     fn test_edit_indexer_requires_arguments() {
       let result = Cli::command().try_get_matches_from(["managarr", "sonarr", "edit", "indexer"]);
@@ -365,25 +671,12 @@ mod tests {
     }
 
     #[test]
-    fn test_edit_series_requires_arguments() {
-      let result = Cli::command().try_get_matches_from(["managarr", "sonarr", "edit", "series"]);
-
-      assert_err!(&result);
-      assert_eq!(
-        result.unwrap_err().kind(),
-        ErrorKind::MissingRequiredArgument
-      );
-    }
-
-    #[test]
-    fn test_edit_series_with_series_id_still_requires_arguments() {
+    fn test_edit_media_management_settings_requires_arguments() {
       let result = Cli::command().try_get_matches_from([
         "managarr",
         "sonarr",
         "edit",
-        "series",
-        "--series-id",
-        "1",
+        "media-management-settings",
       ]);
 
       assert_err!(&result);
@@ -393,17 +686,22 @@ mod tests {
       );
     }
 
-    #[test]
-    fn test_edit_series_monitoring_flags_conflict() {
+    #[rstest]
+    #[case("--rename-episodes", "--disable-rename-episodes")]
+    #[case("--replace-illegal-characters", "--disable-replace-illegal-characters")]
+    #[case("--set-permissions", "--disable-set-permissions")]
+    #[case("--import-extra-files", "--disable-import-extra-files")]
+    fn test_edit_media_management_settings_flags_conflict(
+      #[case] flag: &str,
+      #[case] disable_flag: &str,
+    ) {
       let result = Cli::command().try_get_matches_from([
         "managarr",
         "sonarr",
         "edit",
-        "series",
-        "--series-id",
-        "1",
-        "--enable-monitoring",
-        "--disable-monitoring",
+        "media-management-settings",
+        flag,
+        disable_flag,
       ]);
 
       assert_err!(&result);
@@ -411,30 +709,180 @@ mod tests {
     }
 
     #[test]
-    fn test_edit_series_season_folders_flags_conflict() {
-      let result = Cli::command().try_get_matches_from([
+    fn test_edit_media_management_settings_only_requires_at_least_one_argument() {
+      let expected_args = SonarrEditCommand::MediaManagementSettings {
+        rename_episodes: false,
+        disable_rename_episodes: false,
+        replace_illegal_characters: false,
+        disable_replace_illegal_characters: false,
+        standard_episode_format: None,
+        daily_episode_format: None,
+        anime_episode_format: None,
+        series_folder_format: None,
+        season_folder_format: None,
+        specials_folder_format: None,
+        recycle_bin: None,
+        recycle_bin_cleanup_days: Some(14),
+        set_permissions: false,
+        disable_set_permissions: false,
+        chmod_folder: None,
+        chown_group: None,
+        import_extra_files: false,
+        disable_import_extra_files: false,
+        extra_file_extensions: None,
+      };
+      let result = Cli::try_parse_from([
         "managarr",
         "sonarr",
         "edit",
-        "series",
-        "--series-id",
-        "1",
-        "--enable-season-folders",
-        "--disable-season-folders",
+        "media-management-settings",
+        "--recycle-bin-cleanup-days",
+        "14",
       ]);
 
-      assert_err!(&result);
-      assert_eq!(result.unwrap_err().kind(), ErrorKind::ArgumentConflict);
+      assert_ok!(&result);
+
+      let Some(Command::Sonarr(SonarrCommand::Edit(edit_command))) = result.unwrap().command else {
+        panic!("Unexpected command type");
+      };
+      assert_eq!(edit_command, expected_args);
     }
 
     #[test]
-    fn test_edit_series_tag_flags_conflict() {
-      let result = Cli::command().try_get_matches_from([
+    fn test_edit_media_management_settings_all_arguments_defined() {
+      let expected_args = SonarrEditCommand::MediaManagementSettings {
+        rename_episodes: true,
+        disable_rename_episodes: false,
+        replace_illegal_characters: true,
+        disable_replace_illegal_characters: false,
+        standard_episode_format: Some("{Series Title} - S{season:00}E{episode:00}".to_owned()),
+        daily_episode_format: Some("{Series Title} - {Air-Date}".to_owned()),
+        anime_episode_format: Some("{Series Title} - {absolute:000}".to_owned()),
+        series_folder_format: Some("{Series TitleYear}".to_owned()),
+        season_folder_format: Some("Season {season:00}".to_owned()),
+        specials_folder_format: Some("Extras".to_owned()),
+        recycle_bin: Some("/nfs/recycle".to_owned()),
+        recycle_bin_cleanup_days: Some(14),
+        set_permissions: true,
+        disable_set_permissions: false,
+        chmod_folder: Some("775".to_owned()),
+        chown_group: Some("media".to_owned()),
+        import_extra_files: true,
+        disable_import_extra_files: false,
+        extra_file_extensions: Some("srt".to_owned()),
+      };
+      let result = Cli::try_parse_from([
         "managarr",
         "sonarr",
         "edit",
-        "series",
-        "--series-id",
+        "media-management-settings",
+        "--rename-episodes",
+        "--replace-illegal-characters",
+        "--standard-episode-format",
+        "{Series Title} - S{season:00}E{episode:00}",
+        "--daily-episode-format",
+        "{Series Title} - {Air-Date}",
+        "--anime-episode-format",
+        "{Series Title} - {absolute:000}",
+        "--series-folder-format",
+        "{Series TitleYear}",
+        "--season-folder-format",
+        "Season {season:00}",
+        "--specials-folder-format",
+        "Extras",
+        "--recycle-bin",
+        "/nfs/recycle",
+        "--recycle-bin-cleanup-days",
+        "14",
+        "--set-permissions",
+        "--chmod-folder",
+        "775",
+        "--chown-group",
+        "media",
+        "--import-extra-files",
+        "--extra-file-extensions",
+        "srt",
+      ]);
+
+      assert_ok!(&result);
+
+      let Some(Command::Sonarr(SonarrCommand::Edit(edit_command))) = result.unwrap().command else {
+        panic!("Unexpected command type");
+      };
+      assert_eq!(edit_command, expected_args);
+    }
+
+    #[test]
+    fn test_edit_series_requires_arguments() {
+      let result = Cli::command().try_get_matches_from(["managarr", "sonarr", "edit", "series"]);
+
+      assert_err!(&result);
+      assert_eq!(
+        result.unwrap_err().kind(),
+        ErrorKind::MissingRequiredArgument
+      );
+    }
+
+    #[test]
+    fn test_edit_series_with_series_id_still_requires_arguments() {
+      let result = Cli::command().try_get_matches_from([
+        "managarr",
+        "sonarr",
+        "edit",
+        "series",
+        "--series-id",
+        "1",
+      ]);
+
+      assert_err!(&result);
+      assert_eq!(
+        result.unwrap_err().kind(),
+        ErrorKind::MissingRequiredArgument
+      );
+    }
+
+    #[test]
+    fn test_edit_series_monitoring_flags_conflict() {
+      let result = Cli::command().try_get_matches_from([
+        "managarr",
+        "sonarr",
+        "edit",
+        "series",
+        "--series-id",
+        "1",
+        "--enable-monitoring",
+        "--disable-monitoring",
+      ]);
+
+      assert_err!(&result);
+      assert_eq!(result.unwrap_err().kind(), ErrorKind::ArgumentConflict);
+    }
+
+    #[test]
+    fn test_edit_series_season_folders_flags_conflict() {
+      let result = Cli::command().try_get_matches_from([
+        "managarr",
+        "sonarr",
+        "edit",
+        "series",
+        "--series-id",
+        "1",
+        "--enable-season-folders",
+        "--disable-season-folders",
+      ]);
+
+      assert_err!(&result);
+      assert_eq!(result.unwrap_err().kind(), ErrorKind::ArgumentConflict);
+    }
+
+    #[test]
+    fn test_edit_series_tag_flags_conflict() {
+      let result = Cli::command().try_get_matches_from([
+        "managarr",
+        "sonarr",
+        "edit",
+        "series",
+        "--series-id",
         "1",
         "--tag",
         "1",
@@ -605,6 +1053,158 @@ mod tests {
       };
       assert_eq!(edit_command, expected_args);
     }
+
+    #[test]
+    fn test_edit_quality_profile_requires_arguments() {
+      let result =
+        Cli::command().try_get_matches_from(["managarr", "sonarr", "edit", "quality-profile"]);
+
+      assert_err!(&result);
+      assert_eq!(
+        result.unwrap_err().kind(),
+        ErrorKind::MissingRequiredArgument
+      );
+    }
+
+    #[test]
+    fn test_edit_quality_profile_with_quality_profile_id_still_requires_arguments() {
+      let result = Cli::command().try_get_matches_from([
+        "managarr",
+        "sonarr",
+        "edit",
+        "quality-profile",
+        "--quality-profile-id",
+        "1",
+      ]);
+
+      assert_err!(&result);
+      assert_eq!(
+        result.unwrap_err().kind(),
+        ErrorKind::MissingRequiredArgument
+      );
+    }
+
+    #[test]
+    fn test_edit_quality_profile_upgrades_flags_conflict() {
+      let result = Cli::command().try_get_matches_from([
+        "managarr",
+        "sonarr",
+        "edit",
+        "quality-profile",
+        "--quality-profile-id",
+        "1",
+        "--enable-upgrades",
+        "--disable-upgrades",
+      ]);
+
+      assert_err!(&result);
+      assert_eq!(result.unwrap_err().kind(), ErrorKind::ArgumentConflict);
+    }
+
+    #[rstest]
+    fn test_edit_quality_profile_assert_argument_flags_require_args(
+      #[values(
+        "--name",
+        "--cutoff",
+        "--min-format-score",
+        "--cutoff-format-score",
+        "--allow-quality",
+        "--disallow-quality"
+      )]
+      flag: &str,
+    ) {
+      let result = Cli::command().try_get_matches_from([
+        "managarr",
+        "sonarr",
+        "edit",
+        "quality-profile",
+        "--quality-profile-id",
+        "1",
+        flag,
+      ]);
+
+      assert_err!(&result);
+      assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidValue);
+    }
+
+    #[test]
+    fn test_edit_quality_profile_only_requires_at_least_one_argument_plus_quality_profile_id() {
+      let expected_args = SonarrEditCommand::QualityProfile {
+        quality_profile_id: 1,
+        name: None,
+        enable_upgrades: false,
+        disable_upgrades: false,
+        cutoff: Some("Bluray-1080p".to_owned()),
+        min_format_score: None,
+        cutoff_format_score: None,
+        allow_quality: Vec::new(),
+        disallow_quality: Vec::new(),
+      };
+
+      let result = Cli::try_parse_from([
+        "managarr",
+        "sonarr",
+        "edit",
+        "quality-profile",
+        "--quality-profile-id",
+        "1",
+        "--cutoff",
+        "Bluray-1080p",
+      ]);
+
+      assert_ok!(&result);
+
+      let Some(Command::Sonarr(SonarrCommand::Edit(edit_command))) = result.unwrap().command else {
+        panic!("Unexpected command type");
+      };
+      assert_eq!(edit_command, expected_args);
+    }
+
+    #[test]
+    fn test_edit_quality_profile_all_arguments_defined() {
+      let expected_args = SonarrEditCommand::QualityProfile {
+        quality_profile_id: 1,
+        name: Some("HD".to_owned()),
+        enable_upgrades: true,
+        disable_upgrades: false,
+        cutoff: Some("Bluray-1080p".to_owned()),
+        min_format_score: Some(-10),
+        cutoff_format_score: Some(100),
+        allow_quality: vec!["Bluray-1080p".to_owned(), "WEB 1080p".to_owned()],
+        disallow_quality: vec!["SDTV".to_owned()],
+      };
+
+      let result = Cli::try_parse_from([
+        "managarr",
+        "sonarr",
+        "edit",
+        "quality-profile",
+        "--quality-profile-id",
+        "1",
+        "--name",
+        "HD",
+        "--enable-upgrades",
+        "--cutoff",
+        "Bluray-1080p",
+        "--min-format-score",
+        "-10",
+        "--cutoff-format-score",
+        "100",
+        "--allow-quality",
+        "Bluray-1080p",
+        "--allow-quality",
+        "WEB 1080p",
+        "--disallow-quality",
+        "SDTV",
+      ]);
+
+      assert_ok!(&result);
+
+      let Some(Command::Sonarr(SonarrCommand::Edit(edit_command))) = result.unwrap().command else {
+        panic!("Unexpected command type");
+      };
+      assert_eq!(edit_command, expected_args);
+    }
   }
 
   mod handler {
@@ -622,10 +1222,21 @@ mod tests {
       },
       models::{
         Serdeable,
-        servarr_models::{EditIndexerParams, IndexerSettings},
-        sonarr_models::{EditSeriesParams, SeriesType, SonarrSerdeable},
+        servarr_models::{
+          EditCustomFormatParams, EditDownloadClientParams, EditIndexerParams,
+          EditQualityProfileParams, IndexerSettings, MediaManagementConfig,
+          MediaManagementSettings,
+        },
+        sonarr_models::{EditSeriesParams, NamingConfig, SeriesType, SonarrSerdeable},
+      },
+      network::{
+        MockNetworkTrait, NetworkEvent,
+        servarr_test_utils::media_management_config,
+        sonarr_network::{
+          SonarrEvent,
+          sonarr_network_test_utils::test_utils::{media_management_settings, naming_config},
+        },
       },
-      network::{MockNetworkTrait, NetworkEvent, sonarr_network::SonarrEvent},
     };
 
     #[tokio::test]
@@ -685,6 +1296,193 @@ mod tests {
       assert_ok!(&result);
     }
 
+    #[tokio::test]
+    async fn test_handle_edit_custom_format_command() {
+      let expected_edit_custom_format_params = EditCustomFormatParams {
+        custom_format_id: 1,
+        name: Some("HEVC".to_owned()),
+        include_custom_format_when_renaming: Some(false),
+        fields: vec![("x265.value".to_owned(), "HEVC".to_owned())],
+      };
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(
+          SonarrEvent::EditCustomFormat(expected_edit_custom_format_params).into(),
+        ))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Sonarr(SonarrSerdeable::Value(
+            json!({"testResponse": "response"}),
+          )))
+        });
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+      let edit_custom_format_command = SonarrEditCommand::CustomFormat {
+        custom_format_id: 1,
+        name: Some("HEVC".to_owned()),
+        include_when_renaming: false,
+        exclude_when_renaming: true,
+        field: vec![("x265.value".to_owned(), "HEVC".to_owned())],
+      };
+
+      let result =
+        SonarrEditCommandHandler::with(&app_arc, edit_custom_format_command, &mut mock_network)
+          .handle()
+          .await;
+
+      assert_ok!(&result);
+    }
+
+    #[tokio::test]
+    async fn test_handle_edit_download_client_command() {
+      let expected_edit_download_client_params = EditDownloadClientParams {
+        download_client_id: 1,
+        name: Some("Test".to_owned()),
+        enable: Some(true),
+        priority: Some(2),
+        remove_completed_downloads: Some(true),
+        remove_failed_downloads: Some(true),
+        tags: Some(vec![1, 2]),
+        tag_input_string: None,
+        clear_tags: false,
+        fields: vec![("host".to_owned(), "localhost".to_owned())],
+      };
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(
+          SonarrEvent::EditDownloadClient(expected_edit_download_client_params).into(),
+        ))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Sonarr(SonarrSerdeable::Value(
+            json!({"testResponse": "response"}),
+          )))
+        });
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+      let edit_download_client_command = SonarrEditCommand::DownloadClient {
+        download_client_id: 1,
+        name: Some("Test".to_owned()),
+        enable: true,
+        disable: false,
+        priority: Some(2),
+        enable_remove_completed_downloads: true,
+        disable_remove_completed_downloads: false,
+        enable_remove_failed_downloads: true,
+        disable_remove_failed_downloads: false,
+        tag: Some(vec![1, 2]),
+        clear_tags: false,
+        field: vec![("host".to_owned(), "localhost".to_owned())],
+      };
+
+      let result =
+        SonarrEditCommandHandler::with(&app_arc, edit_download_client_command, &mut mock_network)
+          .handle()
+          .await;
+
+      assert_ok!(&result);
+    }
+
+    #[tokio::test]
+    async fn test_handle_edit_download_client_command_handles_disable_flags_properly() {
+      let expected_edit_download_client_params = EditDownloadClientParams {
+        download_client_id: 1,
+        name: Some("Test".to_owned()),
+        enable: Some(false),
+        priority: Some(2),
+        remove_completed_downloads: Some(false),
+        remove_failed_downloads: Some(false),
+        tags: Some(vec![1, 2]),
+        tag_input_string: None,
+        clear_tags: false,
+        fields: vec![("host".to_owned(), "localhost".to_owned())],
+      };
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(
+          SonarrEvent::EditDownloadClient(expected_edit_download_client_params).into(),
+        ))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Sonarr(SonarrSerdeable::Value(
+            json!({"testResponse": "response"}),
+          )))
+        });
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+      let edit_download_client_command = SonarrEditCommand::DownloadClient {
+        download_client_id: 1,
+        name: Some("Test".to_owned()),
+        enable: false,
+        disable: true,
+        priority: Some(2),
+        enable_remove_completed_downloads: false,
+        disable_remove_completed_downloads: true,
+        enable_remove_failed_downloads: false,
+        disable_remove_failed_downloads: true,
+        tag: Some(vec![1, 2]),
+        clear_tags: false,
+        field: vec![("host".to_owned(), "localhost".to_owned())],
+      };
+
+      let result =
+        SonarrEditCommandHandler::with(&app_arc, edit_download_client_command, &mut mock_network)
+          .handle()
+          .await;
+
+      assert_ok!(&result);
+    }
+
+    #[tokio::test]
+    async fn test_handle_edit_download_client_command_no_boolean_flags_returns_none_value() {
+      let expected_edit_download_client_params = EditDownloadClientParams {
+        download_client_id: 1,
+        name: Some("Test".to_owned()),
+        enable: None,
+        priority: Some(2),
+        remove_completed_downloads: None,
+        remove_failed_downloads: None,
+        tags: Some(vec![1, 2]),
+        tag_input_string: None,
+        clear_tags: false,
+        fields: vec![("host".to_owned(), "localhost".to_owned())],
+      };
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(
+          SonarrEvent::EditDownloadClient(expected_edit_download_client_params).into(),
+        ))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Sonarr(SonarrSerdeable::Value(
+            json!({"testResponse": "response"}),
+          )))
+        });
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+      let edit_download_client_command = SonarrEditCommand::DownloadClient {
+        download_client_id: 1,
+        name: Some("Test".to_owned()),
+        enable: false,
+        disable: false,
+        priority: Some(2),
+        enable_remove_completed_downloads: false,
+        disable_remove_completed_downloads: false,
+        enable_remove_failed_downloads: false,
+        disable_remove_failed_downloads: false,
+        tag: Some(vec![1, 2]),
+        clear_tags: false,
+        field: vec![("host".to_owned(), "localhost".to_owned())],
+      };
+
+      let result =
+        SonarrEditCommandHandler::with(&app_arc, edit_download_client_command, &mut mock_network)
+          .handle()
+          .await;
+
+      assert_ok!(&result);
+    }
+
     #[tokio::test]
     async fn test_handle_edit_indexer_command() {
       let expected_edit_indexer_params = EditIndexerParams {
@@ -739,6 +1537,148 @@ mod tests {
       assert_ok!(&result);
     }
 
+    #[tokio::test]
+    async fn test_handle_edit_media_management_settings_command() {
+      let expected_settings = MediaManagementSettings {
+        naming: NamingConfig {
+          rename_episodes: false,
+          replace_illegal_characters: false,
+          standard_episode_format: Some("{Series Title} - S{season:00}E{episode:00}".to_owned()),
+          daily_episode_format: Some("{Series Title} - {Air-Date}".to_owned()),
+          anime_episode_format: Some("{Series Title} - {absolute:000}".to_owned()),
+          series_folder_format: Some("{Series TitleYear}".to_owned()),
+          season_folder_format: Some("Season {season:00}".to_owned()),
+          specials_folder_format: Some("Extras".to_owned()),
+          ..naming_config()
+        },
+        media_management: MediaManagementConfig {
+          recycle_bin: Some("/nfs/trash".to_owned()),
+          recycle_bin_cleanup_days: 14,
+          set_permissions_linux: true,
+          chmod_folder: Some("775".to_owned()),
+          chown_group: Some("media".to_owned()),
+          import_extra_files: false,
+          extra_file_extensions: Some("srt".to_owned()),
+          ..media_management_config()
+        },
+      };
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(
+          SonarrEvent::GetMediaManagementSettings.into(),
+        ))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Sonarr(SonarrSerdeable::MediaManagementSettings(
+            media_management_settings(),
+          )))
+        });
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(
+          SonarrEvent::EditMediaManagementSettings(expected_settings).into(),
+        ))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Sonarr(SonarrSerdeable::Value(
+            json!({"testResponse": "response"}),
+          )))
+        });
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+      let edit_media_management_settings_command = SonarrEditCommand::MediaManagementSettings {
+        rename_episodes: false,
+        disable_rename_episodes: true,
+        replace_illegal_characters: false,
+        disable_replace_illegal_characters: true,
+        standard_episode_format: Some("{Series Title} - S{season:00}E{episode:00}".to_owned()),
+        daily_episode_format: Some("{Series Title} - {Air-Date}".to_owned()),
+        anime_episode_format: Some("{Series Title} - {absolute:000}".to_owned()),
+        series_folder_format: Some("{Series TitleYear}".to_owned()),
+        season_folder_format: Some("Season {season:00}".to_owned()),
+        specials_folder_format: Some("Extras".to_owned()),
+        recycle_bin: Some("/nfs/trash".to_owned()),
+        recycle_bin_cleanup_days: Some(14),
+        set_permissions: true,
+        disable_set_permissions: false,
+        chmod_folder: Some("775".to_owned()),
+        chown_group: Some("media".to_owned()),
+        import_extra_files: false,
+        disable_import_extra_files: true,
+        extra_file_extensions: Some("srt".to_owned()),
+      };
+
+      let result = SonarrEditCommandHandler::with(
+        &app_arc,
+        edit_media_management_settings_command,
+        &mut mock_network,
+      )
+      .handle()
+      .await;
+
+      assert_ok!(&result);
+    }
+
+    #[tokio::test]
+    async fn test_handle_edit_media_management_settings_command_unprovided_values_default_to_previous_values()
+     {
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(
+          SonarrEvent::GetMediaManagementSettings.into(),
+        ))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Sonarr(SonarrSerdeable::MediaManagementSettings(
+            media_management_settings(),
+          )))
+        });
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(
+          SonarrEvent::EditMediaManagementSettings(media_management_settings()).into(),
+        ))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Sonarr(SonarrSerdeable::Value(
+            json!({"testResponse": "response"}),
+          )))
+        });
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+      let edit_media_management_settings_command = SonarrEditCommand::MediaManagementSettings {
+        rename_episodes: false,
+        disable_rename_episodes: false,
+        replace_illegal_characters: false,
+        disable_replace_illegal_characters: false,
+        standard_episode_format: None,
+        daily_episode_format: None,
+        anime_episode_format: None,
+        series_folder_format: None,
+        season_folder_format: None,
+        specials_folder_format: None,
+        recycle_bin: None,
+        recycle_bin_cleanup_days: None,
+        set_permissions: false,
+        disable_set_permissions: false,
+        chmod_folder: None,
+        chown_group: None,
+        import_extra_files: false,
+        disable_import_extra_files: false,
+        extra_file_extensions: None,
+      };
+
+      let result = SonarrEditCommandHandler::with(
+        &app_arc,
+        edit_media_management_settings_command,
+        &mut mock_network,
+      )
+      .handle()
+      .await;
+
+      assert_ok!(&result);
+    }
+
     #[tokio::test]
     async fn test_handle_edit_series_command() {
       let expected_edit_series_params = EditSeriesParams {
@@ -882,5 +1822,143 @@ mod tests {
 
       assert_ok!(&result);
     }
+
+    #[tokio::test]
+    async fn test_handle_edit_quality_profile_command() {
+      let expected_edit_quality_profile_params = EditQualityProfileParams {
+        quality_profile_id: 1,
+        name: Some("HD".to_owned()),
+        upgrade_allowed: Some(true),
+        cutoff: Some("Bluray-1080p".to_owned()),
+        min_format_score: Some(0),
+        cutoff_format_score: Some(100),
+        items: None,
+        allowed_qualities: vec!["Bluray-1080p".to_owned()],
+        disallowed_qualities: vec!["SDTV".to_owned()],
+      };
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(
+          SonarrEvent::EditQualityProfile(expected_edit_quality_profile_params).into(),
+        ))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Sonarr(SonarrSerdeable::Value(
+            json!({"testResponse": "response"}),
+          )))
+        });
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+      let edit_quality_profile_command = SonarrEditCommand::QualityProfile {
+        quality_profile_id: 1,
+        name: Some("HD".to_owned()),
+        enable_upgrades: true,
+        disable_upgrades: false,
+        cutoff: Some("Bluray-1080p".to_owned()),
+        min_format_score: Some(0),
+        cutoff_format_score: Some(100),
+        allow_quality: vec!["Bluray-1080p".to_owned()],
+        disallow_quality: vec!["SDTV".to_owned()],
+      };
+
+      let result =
+        SonarrEditCommandHandler::with(&app_arc, edit_quality_profile_command, &mut mock_network)
+          .handle()
+          .await;
+
+      assert_ok!(&result);
+    }
+
+    #[tokio::test]
+    async fn test_handle_edit_quality_profile_command_handles_disable_flags_properly() {
+      let expected_edit_quality_profile_params = EditQualityProfileParams {
+        quality_profile_id: 1,
+        name: Some("HD".to_owned()),
+        upgrade_allowed: Some(false),
+        cutoff: Some("Bluray-1080p".to_owned()),
+        min_format_score: Some(0),
+        cutoff_format_score: Some(100),
+        items: None,
+        allowed_qualities: vec!["Bluray-1080p".to_owned()],
+        disallowed_qualities: vec!["SDTV".to_owned()],
+      };
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(
+          SonarrEvent::EditQualityProfile(expected_edit_quality_profile_params).into(),
+        ))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Sonarr(SonarrSerdeable::Value(
+            json!({"testResponse": "response"}),
+          )))
+        });
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+      let edit_quality_profile_command = SonarrEditCommand::QualityProfile {
+        quality_profile_id: 1,
+        name: Some("HD".to_owned()),
+        enable_upgrades: false,
+        disable_upgrades: true,
+        cutoff: Some("Bluray-1080p".to_owned()),
+        min_format_score: Some(0),
+        cutoff_format_score: Some(100),
+        allow_quality: vec!["Bluray-1080p".to_owned()],
+        disallow_quality: vec!["SDTV".to_owned()],
+      };
+
+      let result =
+        SonarrEditCommandHandler::with(&app_arc, edit_quality_profile_command, &mut mock_network)
+          .handle()
+          .await;
+
+      assert_ok!(&result);
+    }
+
+    #[tokio::test]
+    async fn test_handle_edit_quality_profile_command_no_boolean_flags_returns_none_value() {
+      let expected_edit_quality_profile_params = EditQualityProfileParams {
+        quality_profile_id: 1,
+        name: Some("HD".to_owned()),
+        upgrade_allowed: None,
+        cutoff: Some("Bluray-1080p".to_owned()),
+        min_format_score: Some(0),
+        cutoff_format_score: Some(100),
+        items: None,
+        allowed_qualities: vec!["Bluray-1080p".to_owned()],
+        disallowed_qualities: vec!["SDTV".to_owned()],
+      };
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(
+          SonarrEvent::EditQualityProfile(expected_edit_quality_profile_params).into(),
+        ))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Sonarr(SonarrSerdeable::Value(
+            json!({"testResponse": "response"}),
+          )))
+        });
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+      let edit_quality_profile_command = SonarrEditCommand::QualityProfile {
+        quality_profile_id: 1,
+        name: Some("HD".to_owned()),
+        enable_upgrades: false,
+        disable_upgrades: false,
+        cutoff: Some("Bluray-1080p".to_owned()),
+        min_format_score: Some(0),
+        cutoff_format_score: Some(100),
+        allow_quality: vec!["Bluray-1080p".to_owned()],
+        disallow_quality: vec!["SDTV".to_owned()],
+      };
+
+      let result =
+        SonarrEditCommandHandler::with(&app_arc, edit_quality_profile_command, &mut mock_network)
+          .handle()
+          .await;
+
+      assert_ok!(&result);
+    }
   }
 }
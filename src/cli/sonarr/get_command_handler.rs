@@ -20,6 +20,17 @@ mod get_command_handler_tests;
 pub enum SonarrGetCommand {
   #[command(about = "Get the shared settings for all indexers")]
   AllIndexerSettings,
+  #[command(
+    about = "Export the custom format with the given ID as JSON in the format used by TRaSH Guides"
+  )]
+  CustomFormatExport {
+    #[arg(
+      long,
+      help = "The Sonarr ID of the custom format you wish to export",
+      required = true
+    )]
+    custom_format_id: i64,
+  },
   #[command(about = "Get detailed information for the episode with the given ID")]
   EpisodeDetails {
     #[arg(
@@ -31,6 +42,19 @@ pub enum SonarrGetCommand {
   },
   #[command(about = "Fetch the host config for your Sonarr instance")]
   HostConfig,
+  #[command(about = "Get the file naming and media management settings")]
+  MediaManagementSettings,
+  #[command(
+    about = "Get the qualities, cutoff and format scores of the quality profile with the given ID"
+  )]
+  QualityProfileDetails {
+    #[arg(
+      long,
+      help = "The Sonarr ID of the quality profile whose details you wish to fetch",
+      required = true
+    )]
+    quality_profile_id: i64,
+  },
   #[command(about = "Fetch the security config for your Sonarr instance")]
   SecurityConfig,
   #[command(about = "Get detailed information for the series with the given ID")]
@@ -80,6 +104,13 @@ impl<'a, 'b> CliCommandHandler<'a, 'b, SonarrGetCommand> for SonarrGetCommandHan
           .await?;
         serde_json::to_string_pretty(&resp)?
       }
+      SonarrGetCommand::CustomFormatExport { custom_format_id } => {
+        let resp = self
+          .network
+          .handle_network_event(SonarrEvent::ExportCustomFormat(custom_format_id).into())
+          .await?;
+        serde_json::to_string_pretty(&resp)?
+      }
       SonarrGetCommand::EpisodeDetails { episode_id } => {
         let resp = self
           .network
@@ -94,6 +125,20 @@ impl<'a, 'b> CliCommandHandler<'a, 'b, SonarrGetCommand> for SonarrGetCommandHan
           .await?;
         serde_json::to_string_pretty(&resp)?
       }
+      SonarrGetCommand::MediaManagementSettings => {
+        let resp = self
+          .network
+          .handle_network_event(SonarrEvent::GetMediaManagementSettings.into())
+          .await?;
+        serde_json::to_string_pretty(&resp)?
+      }
+      SonarrGetCommand::QualityProfileDetails { quality_profile_id } => {
+        let resp = self
+          .network
+          .handle_network_event(SonarrEvent::GetQualityProfileDetails(quality_profile_id).into())
+          .await?;
+        serde_json::to_string_pretty(&resp)?
+      }
       SonarrGetCommand::SecurityConfig => {
         let resp = self
           .network
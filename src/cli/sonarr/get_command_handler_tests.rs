@@ -73,6 +73,74 @@ mod tests {
       assert_ok!(&result);
     }
 
+    #[test]
+    fn test_get_media_management_settings_has_no_arg_requirements() {
+      let result = Cli::command().try_get_matches_from([
+        "managarr",
+        "sonarr",
+        "get",
+        "media-management-settings",
+      ]);
+
+      assert_ok!(&result);
+    }
+
+    #[test]
+    fn test_custom_format_export_requires_custom_format_id() {
+      let result =
+        Cli::command().try_get_matches_from(["managarr", "sonarr", "get", "custom-format-export"]);
+
+      assert_err!(&result);
+      assert_eq!(
+        result.unwrap_err().kind(),
+        ErrorKind::MissingRequiredArgument
+      );
+    }
+
+    #[test]
+    fn test_custom_format_export_requirements_satisfied() {
+      let result = Cli::command().try_get_matches_from([
+        "managarr",
+        "sonarr",
+        "get",
+        "custom-format-export",
+        "--custom-format-id",
+        "1",
+      ]);
+
+      assert_ok!(&result);
+    }
+
+    #[test]
+    fn test_quality_profile_details_requires_quality_profile_id() {
+      let result = Cli::command().try_get_matches_from([
+        "managarr",
+        "sonarr",
+        "get",
+        "quality-profile-details",
+      ]);
+
+      assert_err!(&result);
+      assert_eq!(
+        result.unwrap_err().kind(),
+        ErrorKind::MissingRequiredArgument
+      );
+    }
+
+    #[test]
+    fn test_quality_profile_details_requirements_satisfied() {
+      let result = Cli::command().try_get_matches_from([
+        "managarr",
+        "sonarr",
+        "get",
+        "quality-profile-details",
+        "--quality-profile-id",
+        "1",
+      ]);
+
+      assert_ok!(&result);
+    }
+
     #[test]
     fn test_get_security_config_has_no_arg_requirements() {
       let result =
@@ -202,6 +270,96 @@ mod tests {
       assert_ok!(&result);
     }
 
+    #[tokio::test]
+    async fn test_handle_get_media_management_settings_command() {
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(
+          SonarrEvent::GetMediaManagementSettings.into(),
+        ))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Sonarr(SonarrSerdeable::Value(
+            json!({"testResponse": "response"}),
+          )))
+        });
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+      let get_media_management_settings_command = SonarrGetCommand::MediaManagementSettings;
+
+      let result = SonarrGetCommandHandler::with(
+        &app_arc,
+        get_media_management_settings_command,
+        &mut mock_network,
+      )
+      .handle()
+      .await;
+
+      assert_ok!(&result);
+    }
+
+    #[tokio::test]
+    async fn test_handle_get_custom_format_export_command() {
+      let expected_custom_format_id = 1;
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(
+          SonarrEvent::ExportCustomFormat(expected_custom_format_id).into(),
+        ))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Sonarr(SonarrSerdeable::Value(
+            json!({"testResponse": "response"}),
+          )))
+        });
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+      let get_custom_format_export_command = SonarrGetCommand::CustomFormatExport {
+        custom_format_id: 1,
+      };
+
+      let result = SonarrGetCommandHandler::with(
+        &app_arc,
+        get_custom_format_export_command,
+        &mut mock_network,
+      )
+      .handle()
+      .await;
+
+      assert_ok!(&result);
+    }
+
+    #[tokio::test]
+    async fn test_handle_get_quality_profile_details_command() {
+      let expected_quality_profile_id = 1;
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(
+          SonarrEvent::GetQualityProfileDetails(expected_quality_profile_id).into(),
+        ))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Sonarr(SonarrSerdeable::Value(
+            json!({"testResponse": "response"}),
+          )))
+        });
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+      let get_quality_profile_details_command = SonarrGetCommand::QualityProfileDetails {
+        quality_profile_id: 1,
+      };
+
+      let result = SonarrGetCommandHandler::with(
+        &app_arc,
+        get_quality_profile_details_command,
+        &mut mock_network,
+      )
+      .handle()
+      .await;
+
+      assert_ok!(&result);
+    }
+
     #[tokio::test]
     async fn test_handle_get_security_config_command() {
       let mut mock_network = MockNetworkTrait::new();
@@ -1,12 +1,13 @@
 use std::sync::Arc;
 
 use anyhow::Result;
+use chrono::{Local, NaiveDate};
 use clap::Subcommand;
 use tokio::sync::Mutex;
 
 use crate::{
   app::App,
-  cli::{CliCommandHandler, Command},
+  cli::{CliCommandHandler, Command, calendar_window_or_default},
   network::{NetworkTrait, sonarr_network::SonarrEvent},
 };
 
@@ -18,8 +19,30 @@ mod list_command_handler_tests;
 
 #[derive(Debug, Clone, PartialEq, Eq, Subcommand)]
 pub enum SonarrListCommand {
+  #[command(about = "List all backups for your Sonarr instance")]
+  Backups,
   #[command(about = "List all items in the Sonarr blocklist")]
   Blocklist,
+  #[command(about = "List the episodes airing in the given date range on the Sonarr calendar")]
+  Calendar {
+    #[arg(
+      long,
+      help = "The first date (YYYY-MM-DD) to include in the calendar. Defaults to today"
+    )]
+    start: Option<NaiveDate>,
+    #[arg(
+      long,
+      help = "The date (YYYY-MM-DD) to end the calendar on. Defaults to 7 days after the start date"
+    )]
+    end: Option<NaiveDate>,
+  },
+  #[command(about = "List all Sonarr custom formats")]
+  CustomFormats,
+  #[command(about = "List the monitored episodes that have not met their quality cutoff")]
+  CutoffUnmet {
+    #[arg(long, help = "How many episodes to fetch", default_value_t = 500)]
+    count: u64,
+  },
   #[command(about = "List all active downloads in Sonarr")]
   Downloads {
     #[arg(long, help = "How many downloads to fetch", default_value_t = 500)]
@@ -59,6 +82,8 @@ pub enum SonarrListCommand {
     #[arg(long, help = "How many history events to fetch", default_value_t = 500)]
     events: u64,
   },
+  #[command(about = "List all Sonarr download clients")]
+  DownloadClients,
   #[command(about = "List all Sonarr indexers")]
   Indexers,
   #[command(about = "List all Sonarr language profiles")]
@@ -113,6 +138,11 @@ pub enum SonarrListCommand {
   Tasks,
   #[command(about = "List all Sonarr updates")]
   Updates,
+  #[command(about = "List the monitored episodes that are missing from your library")]
+  WantedMissing {
+    #[arg(long, help = "How many episodes to fetch", default_value_t = 500)]
+    count: u64,
+  },
 }
 
 impl From<SonarrListCommand> for Command {
@@ -142,6 +172,13 @@ impl<'a, 'b> CliCommandHandler<'a, 'b, SonarrListCommand> for SonarrListCommandH
 
   async fn handle(self) -> Result<String> {
     let result = match self.command {
+      SonarrListCommand::Backups => {
+        let resp = self
+          .network
+          .handle_network_event(SonarrEvent::GetBackups.into())
+          .await?;
+        serde_json::to_string_pretty(&resp)?
+      }
       SonarrListCommand::Blocklist => {
         let resp = self
           .network
@@ -149,6 +186,28 @@ impl<'a, 'b> CliCommandHandler<'a, 'b, SonarrListCommand> for SonarrListCommandH
           .await?;
         serde_json::to_string_pretty(&resp)?
       }
+      SonarrListCommand::Calendar { start, end } => {
+        let (start, end) = calendar_window_or_default(start, end, Local::now().date_naive());
+        let resp = self
+          .network
+          .handle_network_event(SonarrEvent::GetCalendar(start, end).into())
+          .await?;
+        serde_json::to_string_pretty(&resp)?
+      }
+      SonarrListCommand::CustomFormats => {
+        let resp = self
+          .network
+          .handle_network_event(SonarrEvent::GetCustomFormats.into())
+          .await?;
+        serde_json::to_string_pretty(&resp)?
+      }
+      SonarrListCommand::CutoffUnmet { count } => {
+        let resp = self
+          .network
+          .handle_network_event(SonarrEvent::GetCutoffUnmet(count).into())
+          .await?;
+        serde_json::to_string_pretty(&resp)?
+      }
       SonarrListCommand::Downloads { count } => {
         let resp = self
           .network
@@ -191,6 +250,13 @@ impl<'a, 'b> CliCommandHandler<'a, 'b, SonarrListCommand> for SonarrListCommandH
           .await?;
         serde_json::to_string_pretty(&resp)?
       }
+      SonarrListCommand::DownloadClients => {
+        let resp = self
+          .network
+          .handle_network_event(SonarrEvent::GetDownloadClients.into())
+          .await?;
+        serde_json::to_string_pretty(&resp)?
+      }
       SonarrListCommand::Indexers => {
         let resp = self
           .network
@@ -288,6 +354,13 @@ impl<'a, 'b> CliCommandHandler<'a, 'b, SonarrListCommand> for SonarrListCommandH
           .await?;
         serde_json::to_string_pretty(&resp)?
       }
+      SonarrListCommand::WantedMissing { count } => {
+        let resp = self
+          .network
+          .handle_network_event(SonarrEvent::GetWantedMissing(count).into())
+          .await?;
+        serde_json::to_string_pretty(&resp)?
+      }
     };
 
     Ok(result)
@@ -19,6 +19,7 @@ mod tests {
 
   mod cli {
     use super::*;
+    use chrono::NaiveDate;
     use clap::{Parser, error::ErrorKind};
     use pretty_assertions::assert_eq;
     use rstest::rstest;
@@ -26,10 +27,14 @@ mod tests {
     #[rstest]
     fn test_list_commands_have_no_arg_requirements(
       #[values(
+        "backups",
         "blocklist",
+        "calendar",
+        "custom-formats",
         "series",
         "disk-space",
         "quality-profiles",
+        "download-clients",
         "indexers",
         "queued-events",
         "root-folders",
@@ -102,6 +107,85 @@ mod tests {
       assert_eq!(episode_history_command, expected_args);
     }
 
+    #[rstest]
+    fn test_list_calendar_date_flags_require_arguments(#[values("--start", "--end")] flag: &str) {
+      let result =
+        Cli::command().try_get_matches_from(["managarr", "sonarr", "list", "calendar", flag]);
+
+      assert_err!(&result);
+      assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidValue);
+    }
+
+    #[test]
+    fn test_list_calendar_invalid_date() {
+      let result = Cli::command().try_get_matches_from([
+        "managarr",
+        "sonarr",
+        "list",
+        "calendar",
+        "--start",
+        "05/20/2023",
+      ]);
+
+      assert_err!(&result);
+      assert_eq!(result.unwrap_err().kind(), ErrorKind::ValueValidation);
+    }
+
+    #[test]
+    fn test_list_calendar_success() {
+      let expected_args = SonarrListCommand::Calendar {
+        start: NaiveDate::from_ymd_opt(2023, 5, 20),
+        end: NaiveDate::from_ymd_opt(2023, 6, 1),
+      };
+      let result = Cli::try_parse_from([
+        "managarr",
+        "sonarr",
+        "list",
+        "calendar",
+        "--start",
+        "2023-05-20",
+        "--end",
+        "2023-06-01",
+      ]);
+
+      assert_ok!(&result);
+
+      let Some(Command::Sonarr(SonarrCommand::List(calendar_command))) = result.unwrap().command
+      else {
+        panic!("Unexpected command type");
+      };
+      assert_eq!(calendar_command, expected_args);
+    }
+
+    #[rstest]
+    fn test_list_wanted_count_flag_requires_arguments(
+      #[values("wanted-missing", "cutoff-unmet")] subcommand: &str,
+    ) {
+      let result =
+        Cli::command().try_get_matches_from(["managarr", "sonarr", "list", subcommand, "--count"]);
+
+      assert_err!(&result);
+      assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidValue);
+    }
+
+    #[rstest]
+    #[case("wanted-missing", SonarrListCommand::WantedMissing { count: 500 })]
+    #[case("cutoff-unmet", SonarrListCommand::CutoffUnmet { count: 500 })]
+    fn test_list_wanted_default_values(
+      #[case] subcommand: &str,
+      #[case] expected_args: SonarrListCommand,
+    ) {
+      let result = Cli::try_parse_from(["managarr", "sonarr", "list", subcommand]);
+
+      assert_ok!(&result);
+
+      let Some(Command::Sonarr(SonarrCommand::List(wanted_command))) = result.unwrap().command
+      else {
+        panic!("Unexpected command type");
+      };
+      assert_eq!(wanted_command, expected_args);
+    }
+
     #[test]
     fn test_list_downloads_count_flag_requires_arguments() {
       let result =
@@ -300,6 +384,7 @@ mod tests {
 
     use std::sync::Arc;
 
+    use chrono::NaiveDate;
     use mockall::predicate::eq;
     use rstest::rstest;
     use serde_json::json;
@@ -316,8 +401,11 @@ mod tests {
     };
 
     #[rstest]
+    #[case(SonarrListCommand::Backups, SonarrEvent::GetBackups)]
     #[case(SonarrListCommand::Blocklist, SonarrEvent::GetBlocklist)]
     #[case(SonarrListCommand::DiskSpace, SonarrEvent::GetDiskSpace)]
+    #[case(SonarrListCommand::CustomFormats, SonarrEvent::GetCustomFormats)]
+    #[case(SonarrListCommand::DownloadClients, SonarrEvent::GetDownloadClients)]
     #[case(SonarrListCommand::Indexers, SonarrEvent::GetIndexers)]
     #[case(SonarrListCommand::QualityProfiles, SonarrEvent::GetQualityProfiles)]
     #[case(SonarrListCommand::QueuedEvents, SonarrEvent::GetQueuedEvents)]
@@ -403,6 +491,63 @@ mod tests {
       assert_ok!(&result);
     }
 
+    #[tokio::test]
+    async fn test_handle_list_calendar_command() {
+      let expected_start = NaiveDate::from_ymd_opt(2023, 5, 20).unwrap();
+      let expected_end = NaiveDate::from_ymd_opt(2023, 6, 1).unwrap();
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(
+          SonarrEvent::GetCalendar(expected_start, expected_end).into(),
+        ))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Sonarr(SonarrSerdeable::Value(
+            json!({"testResponse": "response"}),
+          )))
+        });
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+      let list_calendar_command = SonarrListCommand::Calendar {
+        start: Some(expected_start),
+        end: Some(expected_end),
+      };
+
+      let result =
+        SonarrListCommandHandler::with(&app_arc, list_calendar_command, &mut mock_network)
+          .handle()
+          .await;
+
+      assert_ok!(&result);
+    }
+
+    #[rstest]
+    #[case(SonarrListCommand::WantedMissing { count: 1000 }, SonarrEvent::GetWantedMissing(1000))]
+    #[case(SonarrListCommand::CutoffUnmet { count: 1000 }, SonarrEvent::GetCutoffUnmet(1000))]
+    #[tokio::test]
+    async fn test_handle_list_wanted_commands(
+      #[case] list_command: SonarrListCommand,
+      #[case] expected_sonarr_event: SonarrEvent,
+    ) {
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(expected_sonarr_event.into()))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Sonarr(SonarrSerdeable::Value(
+            json!({"testResponse": "response"}),
+          )))
+        });
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+
+      let result = SonarrListCommandHandler::with(&app_arc, list_command, &mut mock_network)
+        .handle()
+        .await;
+
+      assert_ok!(&result);
+    }
+
     #[tokio::test]
     async fn test_handle_list_downloads_command() {
       let expected_count = 1000;
@@ -1,7 +1,8 @@
+use std::path::PathBuf;
 use std::sync::Arc;
 
 use add_command_handler::{SonarrAddCommand, SonarrAddCommandHandler};
-use anyhow::Result;
+use anyhow::{Result, anyhow};
 use clap::Subcommand;
 use delete_command_handler::{SonarrDeleteCommand, SonarrDeleteCommandHandler};
 use download_command_handler::{SonarrDownloadCommand, SonarrDownloadCommandHandler};
@@ -18,7 +19,11 @@ use trigger_automatic_search_command_handler::{
 
 use crate::{
   app::App,
-  models::sonarr_models::SonarrTaskName,
+  models::{
+    Serdeable,
+    servarr_models::DownloadBackupParams,
+    sonarr_models::{SonarrSerdeable, SonarrTaskName},
+  },
   network::{NetworkTrait, sonarr_network::SonarrEvent},
 };
 
@@ -29,7 +34,7 @@ mod delete_command_handler;
 mod download_command_handler;
 mod edit_command_handler;
 mod get_command_handler;
-mod list_command_handler;
+pub(super) mod list_command_handler;
 mod manual_search_command_handler;
 mod refresh_command_handler;
 mod trigger_automatic_search_command_handler;
@@ -93,6 +98,49 @@ pub enum SonarrCommand {
     )]
     history_item_id: i64,
   },
+  #[command(
+    about = "Grab the pending release in the queue with the given ID now, skipping any delay"
+  )]
+  GrabQueueItem {
+    #[arg(
+      long,
+      help = "The Sonarr ID of the pending queue item you wish to grab",
+      required = true
+    )]
+    queue_item_id: i64,
+  },
+  #[command(
+    about = "Import all files with no rejections from the download with the given ID (i.e. the download client's ID for the download)"
+  )]
+  ManualImport {
+    #[arg(
+      long,
+      help = "The download client's ID of the download you wish to import",
+      required = true
+    )]
+    download_id: String,
+    #[arg(
+      long,
+      help = "The Sonarr ID of the series to map the files to, if Sonarr couldn't identify it automatically"
+    )]
+    series_id: Option<i64>,
+  },
+  #[command(about = "Download the backup with the given ID from your Sonarr instance")]
+  DownloadBackup {
+    #[arg(long, help = "The ID of the backup to download", required = true)]
+    id: i64,
+    #[arg(
+      long,
+      help = "The file or directory to download the backup to",
+      required = true
+    )]
+    dest: PathBuf,
+  },
+  #[command(about = "Upload the given backup file and restore your Sonarr instance from it")]
+  RestoreBackup {
+    #[arg(long, help = "The backup file to restore from", required = true)]
+    file: PathBuf,
+  },
   #[command(about = "Search for a new series to add to Sonarr")]
   SearchNewSeries {
     #[arg(
@@ -121,6 +169,15 @@ pub enum SonarrCommand {
   },
   #[command(about = "Test all Sonarr indexers")]
   TestAllIndexers,
+  #[command(
+    about = "Test the download client with the given ID. Note that a successful test returns an empty JSON body; i.e. '{}'"
+  )]
+  TestDownloadClient {
+    #[arg(long, help = "The ID of the download client to test", required = true)]
+    download_client_id: i64,
+  },
+  #[command(about = "Test all Sonarr download clients")]
+  TestAllDownloadClients,
   #[command(about = "Toggle monitoring for the specified episode")]
   ToggleEpisodeMonitoring {
     #[arg(
@@ -254,6 +311,76 @@ impl<'a, 'b> CliCommandHandler<'a, 'b, SonarrCommand> for SonarrCliHandler<'a, '
           .await?;
         serde_json::to_string_pretty(&json!({"message": "Sonarr history item marked as 'failed'"}))?
       }
+      SonarrCommand::GrabQueueItem { queue_item_id } => {
+        let _ = self
+          .network
+          .handle_network_event(SonarrEvent::GrabQueueItem(queue_item_id).into())
+          .await?;
+        serde_json::to_string_pretty(&json!({"message": "Sonarr queue item grabbed"}))?
+      }
+      SonarrCommand::ManualImport {
+        download_id,
+        series_id,
+      } => {
+        if let Serdeable::Sonarr(SonarrSerdeable::ManualImportItems(items)) = self
+          .network
+          .handle_network_event(SonarrEvent::GetManualImport(download_id, series_id).into())
+          .await?
+        {
+          let files: Vec<_> = items.iter().filter_map(|item| item.import_file()).collect();
+
+          if files.is_empty() {
+            serde_json::to_string_pretty(
+              &json!({"message": "No files without rejections were found to import"}),
+            )?
+          } else {
+            let resp = self
+              .network
+              .handle_network_event(SonarrEvent::ManualImport(files).into())
+              .await?;
+            serde_json::to_string_pretty(&resp)?
+          }
+        } else {
+          String::new()
+        }
+      }
+      SonarrCommand::DownloadBackup { id, dest } => {
+        if let Serdeable::Sonarr(SonarrSerdeable::Backups(backups)) = self
+          .network
+          .handle_network_event(SonarrEvent::GetBackups.into())
+          .await?
+        {
+          let backup = backups
+            .into_iter()
+            .find(|backup| backup.id == id)
+            .ok_or_else(|| anyhow!("No backup found with ID {id}"))?;
+          let destination = if dest.is_dir() {
+            dest.join(&backup.name)
+          } else {
+            dest
+          };
+          let params = DownloadBackupParams {
+            backup_path: backup.path,
+            destination: destination.clone(),
+          };
+          self
+            .network
+            .handle_network_event(SonarrEvent::DownloadBackup(params).into())
+            .await?;
+          serde_json::to_string_pretty(
+            &json!({"message": format!("Backup downloaded to '{}'", destination.display())}),
+          )?
+        } else {
+          String::new()
+        }
+      }
+      SonarrCommand::RestoreBackup { file } => {
+        let resp = self
+          .network
+          .handle_network_event(SonarrEvent::RestoreBackup(file).into())
+          .await?;
+        serde_json::to_string_pretty(&resp)?
+      }
       SonarrCommand::SearchNewSeries { query } => {
         let resp = self
           .network
@@ -283,6 +410,21 @@ impl<'a, 'b> CliCommandHandler<'a, 'b, SonarrCommand> for SonarrCliHandler<'a, '
           .await?;
         serde_json::to_string_pretty(&resp)?
       }
+      SonarrCommand::TestDownloadClient { download_client_id } => {
+        let resp = self
+          .network
+          .handle_network_event(SonarrEvent::TestDownloadClient(download_client_id).into())
+          .await?;
+        serde_json::to_string_pretty(&resp)?
+      }
+      SonarrCommand::TestAllDownloadClients => {
+        println!("Testing all Sonarr download clients. This may take a minute...");
+        let resp = self
+          .network
+          .handle_network_event(SonarrEvent::TestAllDownloadClients.into())
+          .await?;
+        serde_json::to_string_pretty(&resp)?
+      }
       SonarrCommand::ToggleEpisodeMonitoring { episode_id } => {
         let resp = self
           .network
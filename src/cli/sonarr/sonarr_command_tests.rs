@@ -57,6 +57,125 @@ mod tests {
       assert_ok!(&result);
     }
 
+    #[test]
+    fn test_grab_queue_item_requires_queue_item_id() {
+      let result = Cli::command().try_get_matches_from(["managarr", "sonarr", "grab-queue-item"]);
+
+      assert_err!(&result);
+      assert_eq!(
+        result.unwrap_err().kind(),
+        ErrorKind::MissingRequiredArgument
+      );
+    }
+
+    #[test]
+    fn test_grab_queue_item_requirements_satisfied() {
+      let result = Cli::command().try_get_matches_from([
+        "managarr",
+        "sonarr",
+        "grab-queue-item",
+        "--queue-item-id",
+        "1",
+      ]);
+
+      assert_ok!(&result);
+    }
+
+    #[test]
+    fn test_manual_import_requires_download_id() {
+      let result = Cli::command().try_get_matches_from(["managarr", "sonarr", "manual-import"]);
+
+      assert_err!(&result);
+      assert_eq!(
+        result.unwrap_err().kind(),
+        ErrorKind::MissingRequiredArgument
+      );
+    }
+
+    #[rstest]
+    fn test_manual_import_requirements_satisfied(
+      #[values(
+        vec!["--download-id", "abc123"],
+        vec!["--download-id", "abc123", "--series-id", "1"]
+      )]
+      args: Vec<&str>,
+    ) {
+      let mut command = vec!["managarr", "sonarr", "manual-import"];
+      command.extend(args);
+
+      let result = Cli::command().try_get_matches_from(command);
+
+      assert_ok!(&result);
+    }
+
+    #[test]
+    fn test_download_backup_requires_id() {
+      let result = Cli::command().try_get_matches_from([
+        "managarr",
+        "sonarr",
+        "download-backup",
+        "--dest",
+        "/tmp",
+      ]);
+
+      assert_err!(&result);
+      assert_eq!(
+        result.unwrap_err().kind(),
+        ErrorKind::MissingRequiredArgument
+      );
+    }
+
+    #[test]
+    fn test_download_backup_requires_dest() {
+      let result =
+        Cli::command().try_get_matches_from(["managarr", "sonarr", "download-backup", "--id", "1"]);
+
+      assert_err!(&result);
+      assert_eq!(
+        result.unwrap_err().kind(),
+        ErrorKind::MissingRequiredArgument
+      );
+    }
+
+    #[test]
+    fn test_download_backup_requirements_satisfied() {
+      let result = Cli::command().try_get_matches_from([
+        "managarr",
+        "sonarr",
+        "download-backup",
+        "--id",
+        "1",
+        "--dest",
+        "/tmp",
+      ]);
+
+      assert_ok!(&result);
+    }
+
+    #[test]
+    fn test_restore_backup_requires_file() {
+      let result = Cli::command().try_get_matches_from(["managarr", "sonarr", "restore-backup"]);
+
+      assert_err!(&result);
+      assert_eq!(
+        result.unwrap_err().kind(),
+        ErrorKind::MissingRequiredArgument
+      );
+    }
+
+    #[test]
+    fn test_restore_backup_requirements_satisfied() {
+      let result = Cli::command().try_get_matches_from([
+        "managarr",
+        "sonarr",
+        "restore-backup",
+        "--file",
+        "/tmp/backup.zip",
+      ]);
+
+      assert_ok!(&result);
+    }
+
     #[test]
     fn test_search_new_series_requires_query() {
       let result = Cli::command().try_get_matches_from(["managarr", "sonarr", "search-new-series"]);
@@ -119,6 +238,31 @@ mod tests {
       assert_ok!(&result);
     }
 
+    #[test]
+    fn test_test_download_client_requires_download_client_id() {
+      let result =
+        Cli::command().try_get_matches_from(["managarr", "sonarr", "test-download-client"]);
+
+      assert_err!(&result);
+      assert_eq!(
+        result.unwrap_err().kind(),
+        ErrorKind::MissingRequiredArgument
+      );
+    }
+
+    #[test]
+    fn test_test_download_client_requirements_satisfied() {
+      let result = Cli::command().try_get_matches_from([
+        "managarr",
+        "sonarr",
+        "test-download-client",
+        "--download-client-id",
+        "1",
+      ]);
+
+      assert_ok!(&result);
+    }
+
     #[test]
     fn test_test_indexer_requires_indexer_id() {
       let result = Cli::command().try_get_matches_from(["managarr", "sonarr", "test-indexer"]);
@@ -244,8 +388,12 @@ mod tests {
   }
 
   mod handler {
+    use std::path::PathBuf;
     use std::sync::Arc;
 
+    use crate::models::servarr_models::DownloadBackupParams;
+    use crate::network::servarr_test_utils::backup;
+
     use mockall::predicate::eq;
     use serde_json::json;
     use tokio::sync::Mutex;
@@ -268,11 +416,17 @@ mod tests {
         Serdeable,
         servarr_models::IndexerSettings,
         sonarr_models::{
-          BlocklistItem, BlocklistResponse, Series, SonarrReleaseDownloadBody, SonarrSerdeable,
-          SonarrTaskName,
+          BlocklistItem, BlocklistResponse, Series, SonarrManualImportItem,
+          SonarrReleaseDownloadBody, SonarrSerdeable, SonarrTaskName,
+        },
+      },
+      network::{
+        MockNetworkTrait, NetworkEvent,
+        sonarr_network::{
+          SonarrEvent,
+          sonarr_network_test_utils::test_utils::{manual_import_file, manual_import_item},
         },
       },
-      network::{MockNetworkTrait, NetworkEvent, sonarr_network::SonarrEvent},
     };
 
     #[tokio::test]
@@ -338,6 +492,28 @@ mod tests {
       assert_ok!(&result);
     }
 
+    #[tokio::test]
+    async fn test_grab_queue_item_command() {
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(SonarrEvent::GrabQueueItem(1).into()))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Sonarr(SonarrSerdeable::Value(
+            json!({"testResponse": "response"}),
+          )))
+        });
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+      let grab_queue_item_command = SonarrCommand::GrabQueueItem { queue_item_id: 1 };
+
+      let result = SonarrCliHandler::with(&app_arc, grab_queue_item_command, &mut mock_network)
+        .handle()
+        .await;
+
+      assert_ok!(&result);
+    }
+
     #[tokio::test]
     async fn test_sonarr_cli_handler_delegates_add_commands_to_the_add_command_handler() {
       let expected_tag_name = "test".to_owned();
@@ -616,6 +792,186 @@ mod tests {
       assert_ok!(&result);
     }
 
+    #[tokio::test]
+    async fn test_manual_import_command() {
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(
+          SonarrEvent::GetManualImport("abc123".to_owned(), Some(1)).into(),
+        ))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Sonarr(SonarrSerdeable::ManualImportItems(vec![
+            manual_import_item(),
+          ])))
+        });
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(
+          SonarrEvent::ManualImport(vec![manual_import_file()]).into(),
+        ))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Sonarr(SonarrSerdeable::Value(
+            json!({"testResponse": "response"}),
+          )))
+        });
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+      let manual_import_command = SonarrCommand::ManualImport {
+        download_id: "abc123".to_owned(),
+        series_id: Some(1),
+      };
+
+      let result = SonarrCliHandler::with(&app_arc, manual_import_command, &mut mock_network)
+        .handle()
+        .await;
+
+      assert_ok!(&result);
+    }
+
+    #[tokio::test]
+    async fn test_manual_import_command_no_op_when_nothing_is_importable() {
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(
+          SonarrEvent::GetManualImport("abc123".to_owned(), None).into(),
+        ))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Sonarr(SonarrSerdeable::ManualImportItems(vec![
+            SonarrManualImportItem::default(),
+          ])))
+        });
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+      let manual_import_command = SonarrCommand::ManualImport {
+        download_id: "abc123".to_owned(),
+        series_id: None,
+      };
+
+      let result = SonarrCliHandler::with(&app_arc, manual_import_command, &mut mock_network)
+        .handle()
+        .await;
+
+      assert_ok!(&result);
+      assert!(
+        result
+          .unwrap()
+          .contains("No files without rejections were found to import")
+      );
+    }
+
+    #[tokio::test]
+    async fn test_download_backup_command() {
+      let dest = std::env::temp_dir();
+      let expected_params = DownloadBackupParams {
+        backup_path: backup().path,
+        destination: dest.join(backup().name),
+      };
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(SonarrEvent::GetBackups.into()))
+        .times(1)
+        .returning(|_| Ok(Serdeable::Sonarr(SonarrSerdeable::Backups(vec![backup()]))));
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(
+          SonarrEvent::DownloadBackup(expected_params).into(),
+        ))
+        .times(1)
+        .returning(|_| Ok(Serdeable::Sonarr(SonarrSerdeable::Value(json!({})))));
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+      let download_backup_command = SonarrCommand::DownloadBackup { id: 1, dest };
+
+      let result = SonarrCliHandler::with(&app_arc, download_backup_command, &mut mock_network)
+        .handle()
+        .await;
+
+      assert_ok!(&result);
+      assert!(result.unwrap().contains("Backup downloaded to"));
+    }
+
+    #[tokio::test]
+    async fn test_download_backup_command_to_file() {
+      let dest = std::env::temp_dir().join("sonarr_backup.zip");
+      let expected_params = DownloadBackupParams {
+        backup_path: backup().path,
+        destination: dest.clone(),
+      };
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(SonarrEvent::GetBackups.into()))
+        .times(1)
+        .returning(|_| Ok(Serdeable::Sonarr(SonarrSerdeable::Backups(vec![backup()]))));
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(
+          SonarrEvent::DownloadBackup(expected_params).into(),
+        ))
+        .times(1)
+        .returning(|_| Ok(Serdeable::Sonarr(SonarrSerdeable::Value(json!({})))));
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+      let download_backup_command = SonarrCommand::DownloadBackup { id: 1, dest };
+
+      let result = SonarrCliHandler::with(&app_arc, download_backup_command, &mut mock_network)
+        .handle()
+        .await;
+
+      assert_ok!(&result);
+    }
+
+    #[tokio::test]
+    async fn test_download_backup_command_unknown_backup_id() {
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(SonarrEvent::GetBackups.into()))
+        .times(1)
+        .returning(|_| Ok(Serdeable::Sonarr(SonarrSerdeable::Backups(vec![backup()]))));
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+      let download_backup_command = SonarrCommand::DownloadBackup {
+        id: 2,
+        dest: std::env::temp_dir(),
+      };
+
+      let result = SonarrCliHandler::with(&app_arc, download_backup_command, &mut mock_network)
+        .handle()
+        .await;
+
+      assert_err!(&result);
+      assert_eq!(result.unwrap_err().to_string(), "No backup found with ID 2");
+    }
+
+    #[tokio::test]
+    async fn test_restore_backup_command() {
+      let expected_file = PathBuf::from("/tmp/backup.zip");
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(
+          SonarrEvent::RestoreBackup(expected_file).into(),
+        ))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Sonarr(SonarrSerdeable::Value(
+            json!({"testResponse": "response"}),
+          )))
+        });
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+      let restore_backup_command = SonarrCommand::RestoreBackup {
+        file: PathBuf::from("/tmp/backup.zip"),
+      };
+
+      let result = SonarrCliHandler::with(&app_arc, restore_backup_command, &mut mock_network)
+        .handle()
+        .await;
+
+      assert_ok!(&result);
+    }
+
     #[tokio::test]
     async fn test_search_new_series_command() {
       let expected_search_query = "halo".to_owned();
@@ -670,6 +1026,62 @@ mod tests {
       assert_ok!(&result);
     }
 
+    #[tokio::test]
+    async fn test_test_download_client_command() {
+      let expected_download_client_id = 1;
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(
+          SonarrEvent::TestDownloadClient(expected_download_client_id).into(),
+        ))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Sonarr(SonarrSerdeable::Value(
+            json!({"testResponse": "response"}),
+          )))
+        });
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+      let test_download_client_command = SonarrCommand::TestDownloadClient {
+        download_client_id: 1,
+      };
+
+      let result =
+        SonarrCliHandler::with(&app_arc, test_download_client_command, &mut mock_network)
+          .handle()
+          .await;
+
+      assert_ok!(&result);
+    }
+
+    #[tokio::test]
+    async fn test_test_all_download_clients_command() {
+      let mut mock_network = MockNetworkTrait::new();
+      mock_network
+        .expect_handle_network_event()
+        .with(eq::<NetworkEvent>(
+          SonarrEvent::TestAllDownloadClients.into(),
+        ))
+        .times(1)
+        .returning(|_| {
+          Ok(Serdeable::Sonarr(SonarrSerdeable::Value(
+            json!({"testResponse": "response"}),
+          )))
+        });
+      let app_arc = Arc::new(Mutex::new(App::test_default()));
+      let test_all_download_clients_command = SonarrCommand::TestAllDownloadClients;
+
+      let result = SonarrCliHandler::with(
+        &app_arc,
+        test_all_download_clients_command,
+        &mut mock_network,
+      )
+      .handle()
+      .await;
+
+      assert_ok!(&result);
+    }
+
     #[tokio::test]
     async fn test_test_indexer_command() {
       let expected_indexer_id = 1;
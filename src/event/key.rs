@@ -2,12 +2,14 @@ use std::fmt;
 use std::fmt::{Display, Formatter};
 
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use serde::{Deserialize, Serialize};
 
 #[cfg(test)]
 #[path = "key_tests.rs"]
 mod key_tests;
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
 pub enum Key {
   Up,
   Down,
@@ -31,6 +33,7 @@ pub enum Key {
 impl Display for Key {
   fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
     match *self {
+      Key::Char(' ') => write!(f, "space"),
       Key::Char(c) => write!(f, "{c}"),
       Key::Ctrl(c) => write!(f, "ctrl-{c}"),
       Key::Up => write!(f, "↑"),
@@ -121,3 +124,58 @@ impl From<KeyEvent> for Key {
     }
   }
 }
+
+impl TryFrom<String> for Key {
+  type Error = String;
+
+  fn try_from(value: String) -> Result<Self, Self::Error> {
+    let key = match value.to_lowercase().as_str() {
+      "space" => Key::Char(' '),
+      "up" | "↑" => Key::Up,
+      "down" | "↓" => Key::Down,
+      "left" | "←" => Key::Left,
+      "right" | "→" => Key::Right,
+      "pgdown" => Key::PgDown,
+      "pgup" => Key::PgUp,
+      "enter" => Key::Enter,
+      "esc" => Key::Esc,
+      "backspace" => Key::Backspace,
+      "home" => Key::Home,
+      "end" => Key::End,
+      "tab" => Key::Tab,
+      "shift-tab" | "backtab" => Key::BackTab,
+      "del" | "delete" => Key::Delete,
+      lowercase_value => {
+        let single_char = |s: &str| {
+          let mut chars = s.chars();
+          match (chars.next(), chars.next()) {
+            (Some(c), None) => Some(c),
+            _ => None,
+          }
+        };
+
+        if let Some(c) = single_char(&value) {
+          Key::Char(c)
+        } else if let Some(c) = lowercase_value.strip_prefix("ctrl-").and_then(single_char) {
+          Key::Ctrl(c)
+        } else {
+          return Err(format!("Invalid key: '{value}'"));
+        }
+      }
+    };
+
+    Ok(key)
+  }
+}
+
+impl From<Key> for String {
+  fn from(key: Key) -> Self {
+    match key {
+      Key::Up => "up".to_owned(),
+      Key::Down => "down".to_owned(),
+      Key::Left => "left".to_owned(),
+      Key::Right => "right".to_owned(),
+      _ => key.to_string(),
+    }
+  }
+}
@@ -22,6 +22,7 @@ mod tests {
   #[case(Key::BackTab, "shift-tab")]
   #[case(Key::Delete, "del")]
   #[case(Key::Char('q'), "q")]
+  #[case(Key::Char(' '), "space")]
   #[case(Key::Ctrl('q'), "ctrl-q")]
   fn test_key_formatter(#[case] key: Key, #[case] expected_str: &str) {
     assert_str_eq!(format!("{key}"), format!("{expected_str}"));
@@ -133,4 +134,62 @@ mod tests {
   fn test_key_from_unknown() {
     assert_eq!(Key::from(KeyEvent::from(KeyCode::Pause)), Key::Unknown);
   }
+
+  #[rstest]
+  #[case("a", Key::Char('a'))]
+  #[case("S", Key::Char('S'))]
+  #[case("?", Key::Char('?'))]
+  #[case("space", Key::Char(' '))]
+  #[case("ctrl-r", Key::Ctrl('r'))]
+  #[case("Ctrl-R", Key::Ctrl('r'))]
+  #[case("up", Key::Up)]
+  #[case("↑", Key::Up)]
+  #[case("down", Key::Down)]
+  #[case("left", Key::Left)]
+  #[case("right", Key::Right)]
+  #[case("pgDown", Key::PgDown)]
+  #[case("pgup", Key::PgUp)]
+  #[case("enter", Key::Enter)]
+  #[case("esc", Key::Esc)]
+  #[case("backspace", Key::Backspace)]
+  #[case("home", Key::Home)]
+  #[case("end", Key::End)]
+  #[case("tab", Key::Tab)]
+  #[case("shift-tab", Key::BackTab)]
+  #[case("backtab", Key::BackTab)]
+  #[case("del", Key::Delete)]
+  #[case("delete", Key::Delete)]
+  fn test_key_try_from_string(#[case] key_str: &str, #[case] expected_key: Key) {
+    assert_eq!(Key::try_from(key_str.to_owned()), Ok(expected_key));
+  }
+
+  #[rstest]
+  fn test_key_try_from_string_invalid(#[values("", "ctrl-", "ctrl-ab", "f13")] key_str: &str) {
+    assert_eq!(
+      Key::try_from(key_str.to_owned()),
+      Err(format!("Invalid key: '{key_str}'"))
+    );
+  }
+
+  #[rstest]
+  #[case(Key::Up, "up")]
+  #[case(Key::Down, "down")]
+  #[case(Key::Left, "left")]
+  #[case(Key::Right, "right")]
+  #[case(Key::Char(' '), "space")]
+  #[case(Key::Ctrl('a'), "ctrl-a")]
+  #[case(Key::BackTab, "shift-tab")]
+  fn test_string_from_key(#[case] key: Key, #[case] expected_str: &str) {
+    assert_str_eq!(String::from(key), expected_str);
+  }
+
+  #[test]
+  fn test_key_serde_round_trip() {
+    let keys = vec![Key::Char('x'), Key::Ctrl('e'), Key::Up, Key::Delete];
+
+    let yaml = serde_yaml::to_string(&keys).unwrap();
+    let deserialized_keys: Vec<Key> = serde_yaml::from_str(&yaml).unwrap();
+
+    assert_eq!(deserialized_keys, keys);
+  }
 }
@@ -7,11 +7,14 @@ mod tests {
   use crate::{assert_modal_present, assert_navigation_popped};
   use pretty_assertions::assert_eq;
   use rstest::rstest;
+  use serial_test::serial;
   use tokio_util::sync::CancellationToken;
 
   use crate::app::App;
   use crate::app::context_clues::SERVARR_CONTEXT_CLUES;
-  use crate::app::key_binding::{DEFAULT_KEYBINDINGS, KeyBinding};
+  use crate::app::key_binding::{
+    DEFAULT_KEYBINDINGS, KeyBinding, KeyBindingOverride, KeyBindingOverrides, set_keybindings,
+  };
   use crate::app::radarr::radarr_context_clues::{
     LIBRARY_CONTEXT_CLUES, MOVIE_DETAILS_CONTEXT_CLUES,
   };
@@ -255,6 +258,42 @@ mod tests {
     );
   }
 
+  #[test]
+  #[serial]
+  fn test_populate_keymapping_table_uses_overridden_keybindings() {
+    let overrides = KeyBindingOverrides {
+      test: Some(KeyBindingOverride {
+        key: None,
+        alt: Some(Key::Ctrl('x')),
+      }),
+      ..KeyBindingOverrides::default()
+    };
+    set_keybindings(DEFAULT_KEYBINDINGS.with_overrides(&overrides));
+    let mut app = App::test_default();
+    app
+      .data
+      .radarr_data
+      .main_tabs
+      .select_tab_by_title("Indexers");
+    app.push_navigation_stack(ActiveRadarrBlock::Indexers.into());
+
+    populate_keymapping_table(&mut app);
+    set_keybindings(DEFAULT_KEYBINDINGS);
+
+    assert_modal_present!(app.keymapping_table);
+    assert!(
+      app
+        .keymapping_table
+        .unwrap()
+        .items
+        .contains(&KeybindingItem {
+          key: "t".to_owned(),
+          alt_key: "ctrl-x".to_owned(),
+          desc: "test indexer".to_owned(),
+        })
+    );
+  }
+
   #[test]
   fn test_populate_keymapping_table_populates_servarr_specific_tab_info_before_global_options() {
     let mut expected_keybinding_items = LIBRARY_CONTEXT_CLUES
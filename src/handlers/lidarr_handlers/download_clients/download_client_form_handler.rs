@@ -0,0 +1,242 @@
+use crate::app::App;
+use crate::event::Key;
+use crate::handlers::KeyEventHandler;
+use crate::models::servarr_data::lidarr::lidarr_data::{
+  ActiveLidarrBlock, DOWNLOAD_CLIENT_FORM_BLOCKS,
+};
+use crate::models::servarr_data::modals::{DownloadClientModal, SchemaFormFieldKind};
+use crate::models::servarr_models::{AddDownloadClientParams, EditDownloadClientParams};
+use crate::models::{Route, Scrollable};
+use crate::network::lidarr_network::LidarrEvent;
+use crate::{handle_text_box_keys, handle_text_box_left_right_keys, matches_key};
+
+#[cfg(test)]
+#[path = "download_client_form_handler_tests.rs"]
+mod download_client_form_handler_tests;
+
+pub(super) struct DownloadClientFormHandler<'a, 'b> {
+  key: Key,
+  app: &'a mut App<'b>,
+  active_lidarr_block: ActiveLidarrBlock,
+  _context: Option<ActiveLidarrBlock>,
+}
+
+impl DownloadClientFormHandler<'_, '_> {
+  fn download_client_modal(&mut self) -> &mut DownloadClientModal {
+    self
+      .app
+      .data
+      .lidarr_data
+      .download_client_modal
+      .as_mut()
+      .expect("Download Client Modal is None")
+  }
+
+  fn build_download_client_event(&mut self) -> LidarrEvent {
+    let download_client_modal = self
+      .app
+      .data
+      .lidarr_data
+      .download_client_modal
+      .take()
+      .expect("Download Client Modal is None");
+
+    if download_client_modal.id.is_some() {
+      LidarrEvent::EditDownloadClient(EditDownloadClientParams::from(&download_client_modal))
+    } else {
+      LidarrEvent::AddDownloadClient(AddDownloadClientParams::from(&download_client_modal))
+    }
+  }
+}
+
+impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveLidarrBlock> for DownloadClientFormHandler<'a, 'b> {
+  fn accepts(active_block: ActiveLidarrBlock) -> bool {
+    DOWNLOAD_CLIENT_FORM_BLOCKS.contains(&active_block)
+  }
+
+  fn ignore_special_keys(&self) -> bool {
+    self.app.ignore_special_keys_for_textbox_input
+  }
+
+  fn new(
+    key: Key,
+    app: &'a mut App<'b>,
+    active_block: ActiveLidarrBlock,
+    _context: Option<ActiveLidarrBlock>,
+  ) -> DownloadClientFormHandler<'a, 'b> {
+    DownloadClientFormHandler {
+      key,
+      app,
+      active_lidarr_block: active_block,
+      _context,
+    }
+  }
+
+  fn get_key(&self) -> Key {
+    self.key
+  }
+
+  fn is_ready(&self) -> bool {
+    if self.active_lidarr_block == ActiveLidarrBlock::AddDownloadClientSelectImplementation {
+      !self.app.is_loading && !self.app.data.lidarr_data.download_client_schema.is_empty()
+    } else {
+      !self.app.is_loading && self.app.data.lidarr_data.download_client_modal.is_some()
+    }
+  }
+
+  fn handle_scroll_up(&mut self) {
+    match self.active_lidarr_block {
+      ActiveLidarrBlock::AddDownloadClientSelectImplementation => {
+        self.app.data.lidarr_data.download_client_schema.scroll_up()
+      }
+      ActiveLidarrBlock::DownloadClientPrompt => self.download_client_modal().fields.scroll_up(),
+      _ => (),
+    }
+  }
+
+  fn handle_scroll_down(&mut self) {
+    match self.active_lidarr_block {
+      ActiveLidarrBlock::AddDownloadClientSelectImplementation => self
+        .app
+        .data
+        .lidarr_data
+        .download_client_schema
+        .scroll_down(),
+      ActiveLidarrBlock::DownloadClientPrompt => self.download_client_modal().fields.scroll_down(),
+      _ => (),
+    }
+  }
+
+  fn handle_home(&mut self) {
+    match self.active_lidarr_block {
+      ActiveLidarrBlock::AddDownloadClientSelectImplementation => self
+        .app
+        .data
+        .lidarr_data
+        .download_client_schema
+        .scroll_to_top(),
+      ActiveLidarrBlock::DownloadClientPrompt => {
+        self.download_client_modal().fields.scroll_to_top()
+      }
+      ActiveLidarrBlock::DownloadClientFieldInput => self
+        .download_client_modal()
+        .current_field_mut()
+        .value
+        .scroll_home(),
+      _ => (),
+    }
+  }
+
+  fn handle_end(&mut self) {
+    match self.active_lidarr_block {
+      ActiveLidarrBlock::AddDownloadClientSelectImplementation => self
+        .app
+        .data
+        .lidarr_data
+        .download_client_schema
+        .scroll_to_bottom(),
+      ActiveLidarrBlock::DownloadClientPrompt => {
+        self.download_client_modal().fields.scroll_to_bottom()
+      }
+      ActiveLidarrBlock::DownloadClientFieldInput => self
+        .download_client_modal()
+        .current_field_mut()
+        .value
+        .reset_offset(),
+      _ => (),
+    }
+  }
+
+  fn handle_delete(&mut self) {}
+
+  fn handle_left_right_action(&mut self) {
+    if self.active_lidarr_block == ActiveLidarrBlock::DownloadClientFieldInput {
+      handle_text_box_left_right_keys!(
+        self,
+        self.key,
+        self.download_client_modal().current_field_mut().value
+      );
+    }
+  }
+
+  fn handle_submit(&mut self) {
+    match self.active_lidarr_block {
+      ActiveLidarrBlock::AddDownloadClientSelectImplementation => {
+        let download_client_modal = DownloadClientModal::from(
+          self
+            .app
+            .data
+            .lidarr_data
+            .download_client_schema
+            .current_selection(),
+        );
+        self.app.data.lidarr_data.download_client_modal = Some(download_client_modal);
+        self
+          .app
+          .pop_and_push_navigation_stack(ActiveLidarrBlock::DownloadClientPrompt.into());
+      }
+      ActiveLidarrBlock::DownloadClientPrompt => {
+        let download_client_modal = self.download_client_modal();
+        match download_client_modal.current_field_mut().kind {
+          SchemaFormFieldKind::Checkbox | SchemaFormFieldKind::Select(_) => {
+            download_client_modal.toggle_current_field()
+          }
+          _ => {
+            self
+              .app
+              .push_navigation_stack(ActiveLidarrBlock::DownloadClientFieldInput.into());
+            self.app.ignore_special_keys_for_textbox_input = true;
+          }
+        }
+      }
+      ActiveLidarrBlock::DownloadClientFieldInput => {
+        self.app.pop_navigation_stack();
+        self.app.ignore_special_keys_for_textbox_input = false;
+      }
+      _ => (),
+    }
+  }
+
+  fn handle_esc(&mut self) {
+    match self.active_lidarr_block {
+      ActiveLidarrBlock::DownloadClientPrompt => {
+        self.app.pop_navigation_stack();
+        self.app.data.lidarr_data.prompt_confirm = false;
+        self.app.data.lidarr_data.download_client_modal = None;
+      }
+      ActiveLidarrBlock::DownloadClientFieldInput => {
+        self.app.pop_navigation_stack();
+        self.app.ignore_special_keys_for_textbox_input = false;
+      }
+      _ => self.app.pop_navigation_stack(),
+    }
+  }
+
+  fn handle_char_key_event(&mut self) {
+    match self.active_lidarr_block {
+      ActiveLidarrBlock::DownloadClientFieldInput => {
+        handle_text_box_keys!(
+          self,
+          self.key,
+          self.download_client_modal().current_field_mut().value
+        );
+      }
+      ActiveLidarrBlock::DownloadClientPrompt if matches_key!(confirm, self.key) => {
+        self.app.data.lidarr_data.prompt_confirm = true;
+        self.app.data.lidarr_data.prompt_confirm_action = Some(self.build_download_client_event());
+        self.app.should_refresh = true;
+
+        self.app.pop_navigation_stack();
+      }
+      _ => (),
+    }
+  }
+
+  fn app_mut(&mut self) -> &mut App<'b> {
+    self.app
+  }
+
+  fn current_route(&self) -> Route {
+    self.app.get_current_route()
+  }
+}
@@ -0,0 +1,207 @@
+use crate::app::App;
+use crate::event::Key;
+use crate::handlers::lidarr_handlers::download_clients::download_client_form_handler::DownloadClientFormHandler;
+use crate::handlers::lidarr_handlers::download_clients::test_all_download_clients_handler::TestAllDownloadClientsHandler;
+use crate::handlers::lidarr_handlers::handle_change_tab_left_right_keys;
+use crate::handlers::table_handler::{TableHandlingConfig, handle_table};
+use crate::handlers::{KeyEventHandler, handle_clear_errors, handle_prompt_toggle};
+use crate::matches_key;
+use crate::models::Route;
+use crate::models::servarr_data::lidarr::lidarr_data::{
+  ActiveLidarrBlock, DOWNLOAD_CLIENTS_BLOCKS,
+};
+use crate::network::lidarr_network::LidarrEvent;
+
+mod download_client_form_handler;
+mod test_all_download_clients_handler;
+
+#[cfg(test)]
+#[path = "download_clients_handler_tests.rs"]
+mod download_clients_handler_tests;
+
+pub(super) struct DownloadClientsHandler<'a, 'b> {
+  key: Key,
+  app: &'a mut App<'b>,
+  active_lidarr_block: ActiveLidarrBlock,
+  context: Option<ActiveLidarrBlock>,
+}
+
+impl DownloadClientsHandler<'_, '_> {
+  fn extract_download_client_id(&self) -> i64 {
+    self
+      .app
+      .data
+      .lidarr_data
+      .download_clients
+      .current_selection()
+      .id
+  }
+}
+
+impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveLidarrBlock> for DownloadClientsHandler<'a, 'b> {
+  fn handle(&mut self) {
+    let download_clients_table_handling_config =
+      TableHandlingConfig::new(ActiveLidarrBlock::DownloadClients.into());
+
+    if !handle_table(
+      self,
+      |app| &mut app.data.lidarr_data.download_clients,
+      download_clients_table_handling_config,
+    ) {
+      match self.active_lidarr_block {
+        _ if DownloadClientFormHandler::accepts(self.active_lidarr_block) => {
+          DownloadClientFormHandler::new(self.key, self.app, self.active_lidarr_block, self.context)
+            .handle()
+        }
+        _ if TestAllDownloadClientsHandler::accepts(self.active_lidarr_block) => {
+          TestAllDownloadClientsHandler::new(
+            self.key,
+            self.app,
+            self.active_lidarr_block,
+            self.context,
+          )
+          .handle()
+        }
+        _ => self.handle_key_event(),
+      }
+    }
+  }
+
+  fn accepts(active_block: ActiveLidarrBlock) -> bool {
+    DownloadClientFormHandler::accepts(active_block)
+      || TestAllDownloadClientsHandler::accepts(active_block)
+      || DOWNLOAD_CLIENTS_BLOCKS.contains(&active_block)
+  }
+
+  fn ignore_special_keys(&self) -> bool {
+    self.app.ignore_special_keys_for_textbox_input
+  }
+
+  fn new(
+    key: Key,
+    app: &'a mut App<'b>,
+    active_block: ActiveLidarrBlock,
+    context: Option<ActiveLidarrBlock>,
+  ) -> DownloadClientsHandler<'a, 'b> {
+    DownloadClientsHandler {
+      key,
+      app,
+      active_lidarr_block: active_block,
+      context,
+    }
+  }
+
+  fn get_key(&self) -> Key {
+    self.key
+  }
+
+  fn is_ready(&self) -> bool {
+    !self.app.is_loading && !self.app.data.lidarr_data.download_clients.is_empty()
+  }
+
+  fn handle_scroll_up(&mut self) {}
+
+  fn handle_scroll_down(&mut self) {}
+
+  fn handle_home(&mut self) {}
+
+  fn handle_end(&mut self) {}
+
+  fn handle_delete(&mut self) {
+    if self.active_lidarr_block == ActiveLidarrBlock::DownloadClients {
+      self
+        .app
+        .push_navigation_stack(ActiveLidarrBlock::DeleteDownloadClientPrompt.into());
+    }
+  }
+
+  fn handle_left_right_action(&mut self) {
+    match self.active_lidarr_block {
+      ActiveLidarrBlock::DownloadClients => handle_change_tab_left_right_keys(self.app, self.key),
+      ActiveLidarrBlock::DeleteDownloadClientPrompt => handle_prompt_toggle(self.app, self.key),
+      _ => (),
+    }
+  }
+
+  fn handle_submit(&mut self) {
+    match self.active_lidarr_block {
+      ActiveLidarrBlock::DeleteDownloadClientPrompt => {
+        let download_client_id = self.extract_download_client_id();
+        let lidarr_data = &mut self.app.data.lidarr_data;
+        if lidarr_data.prompt_confirm {
+          lidarr_data.prompt_confirm_action =
+            Some(LidarrEvent::DeleteDownloadClient(download_client_id));
+        }
+
+        self.app.pop_navigation_stack();
+      }
+      ActiveLidarrBlock::DownloadClients => {
+        self.app.data.lidarr_data.download_client_modal = Some((&self.app.data.lidarr_data).into());
+        self
+          .app
+          .push_navigation_stack(ActiveLidarrBlock::DownloadClientPrompt.into());
+      }
+      _ => (),
+    }
+  }
+
+  fn handle_esc(&mut self) {
+    match self.active_lidarr_block {
+      ActiveLidarrBlock::DeleteDownloadClientPrompt => {
+        self.app.pop_navigation_stack();
+        self.app.data.lidarr_data.prompt_confirm = false;
+      }
+      ActiveLidarrBlock::TestDownloadClient => {
+        self.app.pop_navigation_stack();
+        self.app.data.lidarr_data.download_client_test_errors = None;
+      }
+      _ => handle_clear_errors(self.app),
+    }
+  }
+
+  fn handle_char_key_event(&mut self) {
+    let key = self.key;
+    match self.active_lidarr_block {
+      ActiveLidarrBlock::DownloadClients => match self.key {
+        _ if matches_key!(refresh, key) => {
+          self.app.should_refresh = true;
+        }
+        _ if matches_key!(add, key) => {
+          self
+            .app
+            .push_navigation_stack(ActiveLidarrBlock::AddDownloadClientSelectImplementation.into());
+        }
+        _ if matches_key!(test, key) => {
+          self
+            .app
+            .push_navigation_stack(ActiveLidarrBlock::TestDownloadClient.into());
+        }
+        _ if matches_key!(test_all, key) => {
+          self
+            .app
+            .push_navigation_stack(ActiveLidarrBlock::TestAllDownloadClients.into());
+        }
+        _ => (),
+      },
+      ActiveLidarrBlock::DeleteDownloadClientPrompt => {
+        if matches_key!(confirm, key) {
+          self.app.data.lidarr_data.prompt_confirm = true;
+          self.app.data.lidarr_data.prompt_confirm_action = Some(
+            LidarrEvent::DeleteDownloadClient(self.extract_download_client_id()),
+          );
+
+          self.app.pop_navigation_stack();
+        }
+      }
+      _ => (),
+    }
+  }
+
+  fn app_mut(&mut self) -> &mut App<'b> {
+    self.app
+  }
+
+  fn current_route(&self) -> Route {
+    self.app.get_current_route()
+  }
+}
@@ -0,0 +1,138 @@
+#[cfg(test)]
+mod tests {
+  use crate::app::App;
+  use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+  use crate::assert_navigation_popped;
+  use crate::handlers::KeyEventHandler;
+  use crate::handlers::lidarr_handlers::download_clients::test_all_download_clients_handler::TestAllDownloadClientsHandler;
+  use crate::models::servarr_data::lidarr::lidarr_data::ActiveLidarrBlock;
+  use crate::models::servarr_data::modals::IndexerTestResultModalItem;
+  use crate::models::stateful_table::StatefulTable;
+  use pretty_assertions::assert_eq;
+  use rstest::rstest;
+  use strum::IntoEnumIterator;
+
+  mod test_handle_esc {
+    use super::*;
+
+    const ESC_KEY: crate::event::Key = DEFAULT_KEYBINDINGS.esc.key;
+
+    #[rstest]
+    fn test_test_all_download_clients_prompt_esc(#[values(true, false)] is_ready: bool) {
+      let mut app = App::test_default();
+      app.is_loading = is_ready;
+      app.push_navigation_stack(ActiveLidarrBlock::DownloadClients.into());
+      app.push_navigation_stack(ActiveLidarrBlock::TestAllDownloadClients.into());
+      app.data.lidarr_data.download_client_test_all_results = Some(StatefulTable::default());
+
+      TestAllDownloadClientsHandler::new(
+        ESC_KEY,
+        &mut app,
+        ActiveLidarrBlock::TestAllDownloadClients,
+        None,
+      )
+      .handle();
+
+      assert_navigation_popped!(app, ActiveLidarrBlock::DownloadClients.into());
+      assert_none!(app.data.lidarr_data.download_client_test_all_results);
+    }
+  }
+
+  #[test]
+  fn test_test_all_download_clients_handler_accepts() {
+    ActiveLidarrBlock::iter().for_each(|active_lidarr_block| {
+      if active_lidarr_block == ActiveLidarrBlock::TestAllDownloadClients {
+        assert!(TestAllDownloadClientsHandler::accepts(active_lidarr_block));
+      } else {
+        assert!(!TestAllDownloadClientsHandler::accepts(active_lidarr_block));
+      }
+    })
+  }
+
+  #[rstest]
+  fn test_test_all_download_clients_handler_ignore_special_keys(
+    #[values(true, false)] ignore_special_keys_for_textbox_input: bool,
+  ) {
+    let mut app = App::test_default();
+    app.ignore_special_keys_for_textbox_input = ignore_special_keys_for_textbox_input;
+    let handler = TestAllDownloadClientsHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveLidarrBlock::default(),
+      None,
+    );
+
+    assert_eq!(
+      handler.ignore_special_keys(),
+      ignore_special_keys_for_textbox_input
+    );
+  }
+
+  #[test]
+  fn test_test_all_download_clients_handler_not_ready_when_loading() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveLidarrBlock::TestAllDownloadClients.into());
+    app.is_loading = true;
+
+    let handler = TestAllDownloadClientsHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveLidarrBlock::TestAllDownloadClients,
+      None,
+    );
+
+    assert!(!handler.is_ready());
+  }
+
+  #[test]
+  fn test_test_all_download_clients_handler_not_ready_when_results_is_none() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveLidarrBlock::TestAllDownloadClients.into());
+    app.is_loading = false;
+
+    let handler = TestAllDownloadClientsHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveLidarrBlock::TestAllDownloadClients,
+      None,
+    );
+
+    assert!(!handler.is_ready());
+  }
+
+  #[test]
+  fn test_test_all_download_clients_handler_not_ready_when_results_is_empty() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveLidarrBlock::TestAllDownloadClients.into());
+    app.is_loading = false;
+    app.data.lidarr_data.download_client_test_all_results = Some(StatefulTable::default());
+
+    let handler = TestAllDownloadClientsHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveLidarrBlock::TestAllDownloadClients,
+      None,
+    );
+
+    assert!(!handler.is_ready());
+  }
+
+  #[test]
+  fn test_test_all_download_clients_handler_ready_when_not_loading_and_results_is_not_empty() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveLidarrBlock::TestAllDownloadClients.into());
+    app.is_loading = false;
+    let mut results = StatefulTable::default();
+    results.set_items(vec![IndexerTestResultModalItem::default()]);
+    app.data.lidarr_data.download_client_test_all_results = Some(results);
+
+    let handler = TestAllDownloadClientsHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveLidarrBlock::TestAllDownloadClients,
+      None,
+    );
+
+    assert!(handler.is_ready());
+  }
+}
@@ -10,10 +10,96 @@ mod tests {
   use crate::event::Key;
   use crate::handlers::KeyEventHandler;
   use crate::handlers::lidarr_handlers::downloads::DownloadsHandler;
-  use crate::models::lidarr_models::DownloadRecord;
-  use crate::models::servarr_data::lidarr::lidarr_data::{ActiveLidarrBlock, DOWNLOADS_BLOCKS};
+  use crate::models::BlockSelectionState;
+  use crate::models::lidarr_models::{DownloadRecord, DownloadStatus};
+  use crate::models::servarr_data::lidarr::lidarr_data::{
+    ActiveLidarrBlock, DELETE_DOWNLOAD_SELECTION_BLOCKS, DOWNLOADS_BLOCKS,
+  };
+  use crate::models::servarr_data::lidarr::modals::ManualImportModal;
+  use crate::models::servarr_models::{
+    BulkDeleteDownloadsParams, DeleteDownloadParams, QueueRemovalOptions,
+  };
+  use crate::network::lidarr_network::LidarrEvent;
   use crate::network::lidarr_network::lidarr_network_test_utils::test_utils::download_record;
 
+  mod test_handle_scroll_up_and_down {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use crate::models::lidarr_models::Artist;
+
+    use super::*;
+
+    #[rstest]
+    fn test_delete_download_prompt_scroll(
+      #[values(DEFAULT_KEYBINDINGS.up.key, DEFAULT_KEYBINDINGS.down.key)] key: Key,
+    ) {
+      let mut app = App::test_default();
+      app
+        .data
+        .lidarr_data
+        .downloads
+        .set_items(vec![download_record()]);
+      app.data.lidarr_data.selected_block =
+        BlockSelectionState::new(DELETE_DOWNLOAD_SELECTION_BLOCKS);
+      app.data.lidarr_data.selected_block.down();
+
+      DownloadsHandler::new(key, &mut app, ActiveLidarrBlock::DeleteDownloadPrompt, None).handle();
+
+      if key == DEFAULT_KEYBINDINGS.up.key {
+        assert_eq!(
+          app.data.lidarr_data.selected_block.get_active_block(),
+          ActiveLidarrBlock::DeleteDownloadToggleRemoveFromClient
+        );
+      } else {
+        assert_eq!(
+          app.data.lidarr_data.selected_block.get_active_block(),
+          ActiveLidarrBlock::DeleteDownloadToggleSkipRedownload
+        );
+      }
+    }
+
+    #[rstest]
+    fn test_manual_import_select_artist_scroll(
+      #[values(DEFAULT_KEYBINDINGS.up.key, DEFAULT_KEYBINDINGS.down.key)] key: Key,
+    ) {
+      let mut app = App::test_default();
+      let mut manual_import_modal = ManualImportModal::default();
+      manual_import_modal.artist_list.set_items(vec![
+        Artist {
+          id: 1,
+          ..Artist::default()
+        },
+        Artist {
+          id: 2,
+          ..Artist::default()
+        },
+      ]);
+      app.data.lidarr_data.manual_import_modal = Some(manual_import_modal);
+
+      DownloadsHandler::new(
+        key,
+        &mut app,
+        ActiveLidarrBlock::ManualImportSelectArtist,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app
+          .data
+          .lidarr_data
+          .manual_import_modal
+          .as_ref()
+          .unwrap()
+          .artist_list
+          .current_selection()
+          .id,
+        2
+      );
+    }
+  }
+
   mod test_handle_delete {
     use pretty_assertions::assert_eq;
 
@@ -34,6 +120,10 @@ mod tests {
       DownloadsHandler::new(DELETE_KEY, &mut app, ActiveLidarrBlock::Downloads, None).handle();
 
       assert_navigation_pushed!(app, ActiveLidarrBlock::DeleteDownloadPrompt.into());
+      assert_eq!(
+        app.data.lidarr_data.selected_block.get_active_block(),
+        ActiveLidarrBlock::DeleteDownloadToggleRemoveFromClient
+      );
     }
 
     #[test]
@@ -108,7 +198,9 @@ mod tests {
     fn test_downloads_left_right_prompt_toggle(
       #[values(
         ActiveLidarrBlock::DeleteDownloadPrompt,
-        ActiveLidarrBlock::UpdateDownloadsPrompt
+        ActiveLidarrBlock::GrabDownloadPrompt,
+        ActiveLidarrBlock::UpdateDownloadsPrompt,
+        ActiveLidarrBlock::ManualImportConfirmPrompt
       )]
       active_lidarr_block: ActiveLidarrBlock,
       #[values(DEFAULT_KEYBINDINGS.left.key, DEFAULT_KEYBINDINGS.right.key)] key: Key,
@@ -127,9 +219,11 @@ mod tests {
   }
 
   mod test_handle_submit {
+    use pretty_assertions::assert_eq;
     use rstest::rstest;
 
     use crate::network::lidarr_network::LidarrEvent;
+    use crate::network::lidarr_network::lidarr_network_test_utils::test_utils::manual_import_file;
 
     use super::*;
     use crate::assert_navigation_popped;
@@ -137,11 +231,157 @@ mod tests {
 
     const SUBMIT_KEY: Key = DEFAULT_KEYBINDINGS.submit.key;
 
+    #[test]
+    fn test_delete_download_prompt_confirm_submit() {
+      let mut app = App::test_default();
+      app
+        .data
+        .lidarr_data
+        .downloads
+        .set_items(vec![download_record()]);
+      app.data.lidarr_data.prompt_confirm = true;
+      app.data.lidarr_data.queue_removal_options = QueueRemovalOptions {
+        remove_from_client: false,
+        blocklist: true,
+        skip_redownload: true,
+        change_category: true,
+      };
+      app.push_navigation_stack(ActiveLidarrBlock::Downloads.into());
+      app.push_navigation_stack(ActiveLidarrBlock::DeleteDownloadPrompt.into());
+      app.data.lidarr_data.selected_block =
+        BlockSelectionState::new(DELETE_DOWNLOAD_SELECTION_BLOCKS);
+      app
+        .data
+        .lidarr_data
+        .selected_block
+        .set_index(0, DELETE_DOWNLOAD_SELECTION_BLOCKS.len() - 1);
+
+      DownloadsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveLidarrBlock::DeleteDownloadPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(app.data.lidarr_data.prompt_confirm);
+      assert_eq!(
+        app.data.lidarr_data.prompt_confirm_action,
+        Some(LidarrEvent::DeleteDownload(DeleteDownloadParams {
+          id: 1,
+          removal_options: QueueRemovalOptions {
+            remove_from_client: false,
+            blocklist: true,
+            skip_redownload: true,
+            change_category: true,
+          },
+        }))
+      );
+      assert_eq!(
+        app.data.lidarr_data.queue_removal_options,
+        QueueRemovalOptions::default()
+      );
+      assert_navigation_popped!(app, ActiveLidarrBlock::Downloads.into());
+    }
+
+    #[test]
+    fn test_delete_download_prompt_decline_submit() {
+      let mut app = App::test_default();
+      app
+        .data
+        .lidarr_data
+        .downloads
+        .set_items(vec![download_record()]);
+      app.data.lidarr_data.queue_removal_options = QueueRemovalOptions {
+        remove_from_client: false,
+        blocklist: true,
+        skip_redownload: true,
+        change_category: true,
+      };
+      app.push_navigation_stack(ActiveLidarrBlock::Downloads.into());
+      app.push_navigation_stack(ActiveLidarrBlock::DeleteDownloadPrompt.into());
+      app.data.lidarr_data.selected_block =
+        BlockSelectionState::new(DELETE_DOWNLOAD_SELECTION_BLOCKS);
+      app
+        .data
+        .lidarr_data
+        .selected_block
+        .set_index(0, DELETE_DOWNLOAD_SELECTION_BLOCKS.len() - 1);
+
+      DownloadsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveLidarrBlock::DeleteDownloadPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(!app.data.lidarr_data.prompt_confirm);
+      assert_none!(app.data.lidarr_data.prompt_confirm_action);
+      assert_eq!(
+        app.data.lidarr_data.queue_removal_options,
+        QueueRemovalOptions::default()
+      );
+      assert_navigation_popped!(app, ActiveLidarrBlock::Downloads.into());
+    }
+
+    #[rstest]
+    #[case(0, QueueRemovalOptions { remove_from_client: false, ..QueueRemovalOptions::default() })]
+    #[case(1, QueueRemovalOptions { blocklist: true, ..QueueRemovalOptions::default() })]
+    #[case(2, QueueRemovalOptions { skip_redownload: true, ..QueueRemovalOptions::default() })]
+    #[case(3, QueueRemovalOptions { change_category: true, ..QueueRemovalOptions::default() })]
+    fn test_delete_download_prompt_toggle_submit(
+      #[case] index: usize,
+      #[case] expected_removal_options: QueueRemovalOptions,
+    ) {
+      let mut app = App::test_default();
+      app
+        .data
+        .lidarr_data
+        .downloads
+        .set_items(vec![download_record()]);
+      app.push_navigation_stack(ActiveLidarrBlock::Downloads.into());
+      app.push_navigation_stack(ActiveLidarrBlock::DeleteDownloadPrompt.into());
+      app.data.lidarr_data.selected_block =
+        BlockSelectionState::new(DELETE_DOWNLOAD_SELECTION_BLOCKS);
+      app.data.lidarr_data.selected_block.set_index(0, index);
+
+      DownloadsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveLidarrBlock::DeleteDownloadPrompt,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveLidarrBlock::DeleteDownloadPrompt.into()
+      );
+      assert_eq!(
+        app.data.lidarr_data.queue_removal_options,
+        expected_removal_options
+      );
+
+      DownloadsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveLidarrBlock::DeleteDownloadPrompt,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.data.lidarr_data.queue_removal_options,
+        QueueRemovalOptions::default()
+      );
+    }
+
     #[rstest]
     #[case(
       ActiveLidarrBlock::Downloads,
-      ActiveLidarrBlock::DeleteDownloadPrompt,
-      LidarrEvent::DeleteDownload(1)
+      ActiveLidarrBlock::GrabDownloadPrompt,
+      LidarrEvent::GrabQueueItem(1)
     )]
     #[case(
       ActiveLidarrBlock::Downloads,
@@ -174,7 +414,7 @@ mod tests {
     }
 
     #[rstest]
-    #[case(ActiveLidarrBlock::Downloads, ActiveLidarrBlock::DeleteDownloadPrompt)]
+    #[case(ActiveLidarrBlock::Downloads, ActiveLidarrBlock::GrabDownloadPrompt)]
     #[case(ActiveLidarrBlock::Downloads, ActiveLidarrBlock::UpdateDownloadsPrompt)]
     fn test_downloads_prompt_decline_submit(
       #[case] base_route: ActiveLidarrBlock,
@@ -195,9 +435,119 @@ mod tests {
       assert_none!(app.data.lidarr_data.prompt_confirm_action);
       assert_navigation_popped!(app, base_route.into());
     }
+
+    #[test]
+    fn test_manual_import_submit_opens_select_artist() {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(ActiveLidarrBlock::Downloads.into());
+      app.push_navigation_stack(ActiveLidarrBlock::ManualImport.into());
+      let expected_artists = app.data.lidarr_data.artists.items.clone();
+
+      DownloadsHandler::new(SUBMIT_KEY, &mut app, ActiveLidarrBlock::ManualImport, None).handle();
+
+      assert_navigation_pushed!(app, ActiveLidarrBlock::ManualImportSelectArtist.into());
+      assert_eq!(
+        app
+          .data
+          .lidarr_data
+          .manual_import_modal
+          .as_ref()
+          .unwrap()
+          .artist_list
+          .items,
+        expected_artists
+      );
+    }
+
+    #[test]
+    fn test_manual_import_submit_no_op_when_no_items() {
+      let mut app = App::test_default();
+      app.data.lidarr_data.manual_import_modal = Some(ManualImportModal::default());
+      app.push_navigation_stack(ActiveLidarrBlock::ManualImport.into());
+
+      DownloadsHandler::new(SUBMIT_KEY, &mut app, ActiveLidarrBlock::ManualImport, None).handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveLidarrBlock::ManualImport.into()
+      );
+    }
+
+    #[test]
+    fn test_manual_import_select_artist_submit() {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(ActiveLidarrBlock::ManualImport.into());
+      app.push_navigation_stack(ActiveLidarrBlock::ManualImportSelectArtist.into());
+
+      DownloadsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveLidarrBlock::ManualImportSelectArtist,
+        None,
+      )
+      .handle();
+
+      assert_navigation_popped!(app, ActiveLidarrBlock::ManualImport.into());
+      assert_eq!(
+        app
+          .data
+          .lidarr_data
+          .manual_import_modal
+          .as_ref()
+          .unwrap()
+          .artist_id,
+        Some(1)
+      );
+    }
+
+    #[test]
+    fn test_manual_import_confirm_prompt_confirm_submit() {
+      let mut app = App::test_default_fully_populated();
+      app.data.lidarr_data.prompt_confirm = true;
+      app.push_navigation_stack(ActiveLidarrBlock::Downloads.into());
+      app.push_navigation_stack(ActiveLidarrBlock::ManualImport.into());
+      app.push_navigation_stack(ActiveLidarrBlock::ManualImportConfirmPrompt.into());
+
+      DownloadsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveLidarrBlock::ManualImportConfirmPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(app.data.lidarr_data.prompt_confirm);
+      assert_eq!(
+        app.data.lidarr_data.prompt_confirm_action,
+        Some(LidarrEvent::ManualImport(vec![manual_import_file()]))
+      );
+      assert_none!(app.data.lidarr_data.manual_import_modal);
+      assert_navigation_popped!(app, ActiveLidarrBlock::Downloads.into());
+    }
+
+    #[test]
+    fn test_manual_import_confirm_prompt_decline_submit() {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(ActiveLidarrBlock::ManualImport.into());
+      app.push_navigation_stack(ActiveLidarrBlock::ManualImportConfirmPrompt.into());
+
+      DownloadsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveLidarrBlock::ManualImportConfirmPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(!app.data.lidarr_data.prompt_confirm);
+      assert_none!(app.data.lidarr_data.prompt_confirm_action);
+      assert_some!(&app.data.lidarr_data.manual_import_modal);
+      assert_navigation_popped!(app, ActiveLidarrBlock::ManualImport.into());
+    }
   }
 
   mod test_handle_esc {
+    use pretty_assertions::assert_eq;
     use rstest::rstest;
 
     use super::*;
@@ -205,9 +555,41 @@ mod tests {
 
     const ESC_KEY: Key = DEFAULT_KEYBINDINGS.esc.key;
 
+    #[test]
+    fn test_delete_download_prompt_esc_resets_removal_options() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveLidarrBlock::Downloads.into());
+      app.push_navigation_stack(ActiveLidarrBlock::DeleteDownloadPrompt.into());
+      app.data.lidarr_data.queue_removal_options = QueueRemovalOptions {
+        remove_from_client: false,
+        blocklist: true,
+        skip_redownload: true,
+        change_category: true,
+      };
+
+      DownloadsHandler::new(
+        ESC_KEY,
+        &mut app,
+        ActiveLidarrBlock::DeleteDownloadPrompt,
+        None,
+      )
+      .handle();
+
+      assert_navigation_popped!(app, ActiveLidarrBlock::Downloads.into());
+      assert_eq!(
+        app.data.lidarr_data.queue_removal_options,
+        QueueRemovalOptions::default()
+      );
+    }
+
     #[rstest]
     #[case(ActiveLidarrBlock::Downloads, ActiveLidarrBlock::DeleteDownloadPrompt)]
+    #[case(ActiveLidarrBlock::Downloads, ActiveLidarrBlock::GrabDownloadPrompt)]
     #[case(ActiveLidarrBlock::Downloads, ActiveLidarrBlock::UpdateDownloadsPrompt)]
+    #[case(
+      ActiveLidarrBlock::ManualImport,
+      ActiveLidarrBlock::ManualImportConfirmPrompt
+    )]
     fn test_downloads_prompt_blocks_esc(
       #[case] base_block: ActiveLidarrBlock,
       #[case] prompt_block: ActiveLidarrBlock,
@@ -223,6 +605,44 @@ mod tests {
       assert!(!app.data.lidarr_data.prompt_confirm);
     }
 
+    #[test]
+    fn test_manual_import_esc() {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(ActiveLidarrBlock::Downloads.into());
+      app.push_navigation_stack(ActiveLidarrBlock::ManualImport.into());
+
+      DownloadsHandler::new(ESC_KEY, &mut app, ActiveLidarrBlock::ManualImport, None).handle();
+
+      assert_navigation_popped!(app, ActiveLidarrBlock::Downloads.into());
+      assert_none!(app.data.lidarr_data.manual_import_modal);
+    }
+
+    #[test]
+    fn test_manual_import_select_artist_esc() {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(ActiveLidarrBlock::ManualImport.into());
+      app.push_navigation_stack(ActiveLidarrBlock::ManualImportSelectArtist.into());
+
+      DownloadsHandler::new(
+        ESC_KEY,
+        &mut app,
+        ActiveLidarrBlock::ManualImportSelectArtist,
+        None,
+      )
+      .handle();
+
+      assert_navigation_popped!(app, ActiveLidarrBlock::ManualImport.into());
+      assert_none!(
+        app
+          .data
+          .lidarr_data
+          .manual_import_modal
+          .as_ref()
+          .unwrap()
+          .artist_id
+      );
+    }
+
     #[rstest]
     fn test_default_esc(#[values(true, false)] is_ready: bool) {
       let mut app = App::test_default();
@@ -241,9 +661,12 @@ mod tests {
   mod test_handle_key_char {
     use super::*;
     use crate::assert_navigation_popped;
+    use crate::models::lidarr_models::LidarrManualImportItem;
     use crate::network::lidarr_network::LidarrEvent;
-    use crate::network::lidarr_network::lidarr_network_test_utils::test_utils::download_record;
-    use pretty_assertions::assert_eq;
+    use crate::network::lidarr_network::lidarr_network_test_utils::test_utils::{
+      download_record, manual_import_file,
+    };
+    use pretty_assertions::{assert_eq, assert_str_eq};
     use rstest::rstest;
 
     #[test]
@@ -289,6 +712,51 @@ mod tests {
       assert_eq!(app.get_current_route(), ActiveLidarrBlock::Downloads.into());
     }
 
+    #[test]
+    fn test_grab_key() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveLidarrBlock::Downloads.into());
+      app
+        .data
+        .lidarr_data
+        .downloads
+        .set_items(vec![DownloadRecord {
+          status: DownloadStatus::Delay,
+          ..download_record()
+        }]);
+
+      DownloadsHandler::new(
+        DEFAULT_KEYBINDINGS.grab.key,
+        &mut app,
+        ActiveLidarrBlock::Downloads,
+        None,
+      )
+      .handle();
+
+      assert_navigation_pushed!(app, ActiveLidarrBlock::GrabDownloadPrompt.into());
+    }
+
+    #[test]
+    fn test_grab_key_no_op_when_download_is_not_pending() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveLidarrBlock::Downloads.into());
+      app
+        .data
+        .lidarr_data
+        .downloads
+        .set_items(vec![download_record()]);
+
+      DownloadsHandler::new(
+        DEFAULT_KEYBINDINGS.grab.key,
+        &mut app,
+        ActiveLidarrBlock::Downloads,
+        None,
+      )
+      .handle();
+
+      assert_eq!(app.get_current_route(), ActiveLidarrBlock::Downloads.into());
+    }
+
     #[test]
     fn test_refresh_downloads_key() {
       let mut app = App::test_default();
@@ -334,11 +802,205 @@ mod tests {
       assert!(!app.should_refresh);
     }
 
+    #[test]
+    fn test_manual_import_key() {
+      let mut app = App::test_default();
+      app
+        .data
+        .lidarr_data
+        .downloads
+        .set_items(vec![download_record()]);
+      app.push_navigation_stack(ActiveLidarrBlock::Downloads.into());
+
+      DownloadsHandler::new(
+        DEFAULT_KEYBINDINGS.import.key,
+        &mut app,
+        ActiveLidarrBlock::Downloads,
+        None,
+      )
+      .handle();
+
+      assert_navigation_pushed!(app, ActiveLidarrBlock::ManualImport.into());
+      let manual_import_modal = app.data.lidarr_data.manual_import_modal.as_ref().unwrap();
+      assert_str_eq!(manual_import_modal.download_id, "abc123");
+      assert_none!(manual_import_modal.artist_id);
+    }
+
+    #[test]
+    fn test_manual_import_key_no_op_when_download_id_is_missing() {
+      let mut app = App::test_default();
+      app
+        .data
+        .lidarr_data
+        .downloads
+        .set_items(vec![DownloadRecord::default()]);
+      app.push_navigation_stack(ActiveLidarrBlock::Downloads.into());
+
+      DownloadsHandler::new(
+        DEFAULT_KEYBINDINGS.import.key,
+        &mut app,
+        ActiveLidarrBlock::Downloads,
+        None,
+      )
+      .handle();
+
+      assert_eq!(app.get_current_route(), ActiveLidarrBlock::Downloads.into());
+      assert_none!(app.data.lidarr_data.manual_import_modal);
+    }
+
+    #[test]
+    fn test_manual_import_import_key() {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(ActiveLidarrBlock::ManualImport.into());
+
+      DownloadsHandler::new(
+        DEFAULT_KEYBINDINGS.import.key,
+        &mut app,
+        ActiveLidarrBlock::ManualImport,
+        None,
+      )
+      .handle();
+
+      assert_navigation_pushed!(app, ActiveLidarrBlock::ManualImportConfirmPrompt.into());
+    }
+
+    #[test]
+    fn test_manual_import_import_key_no_op_when_nothing_is_importable() {
+      let mut app = App::test_default_fully_populated();
+      app
+        .data
+        .lidarr_data
+        .manual_import_modal
+        .as_mut()
+        .unwrap()
+        .items
+        .set_items(vec![LidarrManualImportItem::default()]);
+      app.push_navigation_stack(ActiveLidarrBlock::ManualImport.into());
+
+      DownloadsHandler::new(
+        DEFAULT_KEYBINDINGS.import.key,
+        &mut app,
+        ActiveLidarrBlock::ManualImport,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveLidarrBlock::ManualImport.into()
+      );
+    }
+
+    #[test]
+    fn test_manual_import_refresh_key() {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(ActiveLidarrBlock::ManualImport.into());
+
+      DownloadsHandler::new(
+        DEFAULT_KEYBINDINGS.refresh.key,
+        &mut app,
+        ActiveLidarrBlock::ManualImport,
+        None,
+      )
+      .handle();
+
+      assert!(app.should_refresh);
+    }
+
+    #[test]
+    fn test_manual_import_confirm_prompt_confirm_key() {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(ActiveLidarrBlock::Downloads.into());
+      app.push_navigation_stack(ActiveLidarrBlock::ManualImport.into());
+      app.push_navigation_stack(ActiveLidarrBlock::ManualImportConfirmPrompt.into());
+
+      DownloadsHandler::new(
+        DEFAULT_KEYBINDINGS.confirm.key,
+        &mut app,
+        ActiveLidarrBlock::ManualImportConfirmPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(app.data.lidarr_data.prompt_confirm);
+      assert_eq!(
+        app.data.lidarr_data.prompt_confirm_action,
+        Some(LidarrEvent::ManualImport(vec![manual_import_file()]))
+      );
+      assert_navigation_popped!(app, ActiveLidarrBlock::Downloads.into());
+    }
+
+    #[test]
+    fn test_delete_download_prompt_confirm_key() {
+      let mut app = App::test_default();
+      app
+        .data
+        .lidarr_data
+        .downloads
+        .set_items(vec![download_record()]);
+      app.push_navigation_stack(ActiveLidarrBlock::Downloads.into());
+      app.push_navigation_stack(ActiveLidarrBlock::DeleteDownloadPrompt.into());
+      app.data.lidarr_data.selected_block =
+        BlockSelectionState::new(DELETE_DOWNLOAD_SELECTION_BLOCKS);
+      app
+        .data
+        .lidarr_data
+        .selected_block
+        .set_index(0, DELETE_DOWNLOAD_SELECTION_BLOCKS.len() - 1);
+
+      DownloadsHandler::new(
+        DEFAULT_KEYBINDINGS.confirm.key,
+        &mut app,
+        ActiveLidarrBlock::DeleteDownloadPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(app.data.lidarr_data.prompt_confirm);
+      assert_eq!(
+        app.data.lidarr_data.prompt_confirm_action,
+        Some(LidarrEvent::DeleteDownload(DeleteDownloadParams {
+          id: 1,
+          removal_options: QueueRemovalOptions::default(),
+        }))
+      );
+      assert_navigation_popped!(app, ActiveLidarrBlock::Downloads.into());
+    }
+
+    #[test]
+    fn test_delete_download_prompt_confirm_key_no_op_when_not_on_confirm_prompt() {
+      let mut app = App::test_default();
+      app
+        .data
+        .lidarr_data
+        .downloads
+        .set_items(vec![download_record()]);
+      app.push_navigation_stack(ActiveLidarrBlock::Downloads.into());
+      app.push_navigation_stack(ActiveLidarrBlock::DeleteDownloadPrompt.into());
+      app.data.lidarr_data.selected_block =
+        BlockSelectionState::new(DELETE_DOWNLOAD_SELECTION_BLOCKS);
+
+      DownloadsHandler::new(
+        DEFAULT_KEYBINDINGS.confirm.key,
+        &mut app,
+        ActiveLidarrBlock::DeleteDownloadPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(!app.data.lidarr_data.prompt_confirm);
+      assert_none!(app.data.lidarr_data.prompt_confirm_action);
+      assert_eq!(
+        app.get_current_route(),
+        ActiveLidarrBlock::DeleteDownloadPrompt.into()
+      );
+    }
+
     #[rstest]
     #[case(
       ActiveLidarrBlock::Downloads,
-      ActiveLidarrBlock::DeleteDownloadPrompt,
-      LidarrEvent::DeleteDownload(1)
+      ActiveLidarrBlock::GrabDownloadPrompt,
+      LidarrEvent::GrabQueueItem(1)
     )]
     #[case(
       ActiveLidarrBlock::Downloads,
@@ -407,23 +1069,78 @@ mod tests {
   }
 
   #[test]
-  fn test_extract_download_id() {
+  fn test_build_delete_download_event() {
     let mut app = App::test_default();
     app
       .data
       .lidarr_data
       .downloads
       .set_items(vec![download_record()]);
+    app.data.lidarr_data.queue_removal_options = QueueRemovalOptions {
+      remove_from_client: false,
+      blocklist: true,
+      skip_redownload: true,
+      change_category: true,
+    };
+
+    let delete_download_event = DownloadsHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveLidarrBlock::DeleteDownloadPrompt,
+      None,
+    )
+    .build_delete_download_event();
+
+    assert_eq!(
+      delete_download_event,
+      LidarrEvent::DeleteDownload(DeleteDownloadParams {
+        id: 1,
+        removal_options: QueueRemovalOptions {
+          remove_from_client: false,
+          blocklist: true,
+          skip_redownload: true,
+          change_category: true,
+        },
+      })
+    );
+    assert_eq!(
+      app.data.lidarr_data.queue_removal_options,
+      QueueRemovalOptions::default()
+    );
+  }
 
-    let download_id = DownloadsHandler::new(
+  #[test]
+  fn test_build_delete_download_event_with_multi_selection() {
+    let mut app = App::test_default();
+    app.data.lidarr_data.downloads.set_items(vec![
+      download_record(),
+      DownloadRecord {
+        id: 2,
+        ..download_record()
+      },
+    ]);
+    app
+      .data
+      .lidarr_data
+      .downloads
+      .toggle_select_all(|download| download.id);
+
+    let delete_download_event = DownloadsHandler::new(
       DEFAULT_KEYBINDINGS.esc.key,
       &mut app,
-      ActiveLidarrBlock::Downloads,
+      ActiveLidarrBlock::DeleteDownloadPrompt,
       None,
     )
-    .extract_download_id();
+    .build_delete_download_event();
 
-    assert_eq!(download_id, 1);
+    assert_eq!(
+      delete_download_event,
+      LidarrEvent::BulkDeleteDownloads(BulkDeleteDownloadsParams {
+        ids: vec![1, 2],
+        removal_options: QueueRemovalOptions::default(),
+      })
+    );
+    assert!(!app.data.lidarr_data.downloads.has_multi_selection());
   }
 
   #[test]
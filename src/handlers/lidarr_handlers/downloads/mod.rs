@@ -4,8 +4,15 @@ use crate::handlers::lidarr_handlers::handle_change_tab_left_right_keys;
 use crate::handlers::table_handler::{TableHandlingConfig, handle_table};
 use crate::handlers::{KeyEventHandler, handle_clear_errors, handle_prompt_toggle};
 use crate::matches_key;
-use crate::models::Route;
-use crate::models::servarr_data::lidarr::lidarr_data::{ActiveLidarrBlock, DOWNLOADS_BLOCKS};
+use crate::models::lidarr_models::DownloadRecord;
+use crate::models::servarr_data::lidarr::lidarr_data::{
+  ActiveLidarrBlock, DELETE_DOWNLOAD_SELECTION_BLOCKS, DOWNLOADS_BLOCKS,
+};
+use crate::models::servarr_data::lidarr::modals::ManualImportModal;
+use crate::models::servarr_models::{
+  BulkDeleteDownloadsParams, DeleteDownloadParams, QueueRemovalOptions,
+};
+use crate::models::{BlockSelectionState, Route, Scrollable};
 use crate::network::lidarr_network::LidarrEvent;
 
 #[cfg(test)]
@@ -20,20 +27,98 @@ pub(super) struct DownloadsHandler<'a, 'b> {
 }
 
 impl DownloadsHandler<'_, '_> {
-  fn extract_download_id(&self) -> i64 {
-    self.app.data.lidarr_data.downloads.current_selection().id
+  fn build_delete_download_event(&mut self) -> LidarrEvent {
+    let removal_options = self.app.data.lidarr_data.queue_removal_options;
+    self.app.data.lidarr_data.queue_removal_options = QueueRemovalOptions::default();
+
+    if self.app.data.lidarr_data.downloads.has_multi_selection() {
+      let ids = self
+        .app
+        .data
+        .lidarr_data
+        .downloads
+        .multi_selection()
+        .iter()
+        .map(|download: &DownloadRecord| download.id)
+        .collect();
+      self.app.data.lidarr_data.downloads.clear_multi_selection();
+
+      LidarrEvent::BulkDeleteDownloads(BulkDeleteDownloadsParams {
+        ids,
+        removal_options,
+      })
+    } else {
+      LidarrEvent::DeleteDownload(DeleteDownloadParams {
+        id: self.app.data.lidarr_data.downloads.current_selection().id,
+        removal_options,
+      })
+    }
+  }
+
+  fn manual_import_modal(&mut self) -> &mut ManualImportModal {
+    self
+      .app
+      .data
+      .lidarr_data
+      .manual_import_modal
+      .as_mut()
+      .expect("manual_import_modal must exist in this context")
+  }
+
+  fn build_manual_import_event(&self) -> Option<LidarrEvent> {
+    let files: Vec<_> = self
+      .app
+      .data
+      .lidarr_data
+      .manual_import_modal
+      .as_ref()
+      .expect("manual_import_modal must exist in this context")
+      .items
+      .items
+      .iter()
+      .filter_map(|item| item.import_file())
+      .collect();
+
+    if files.is_empty() {
+      None
+    } else {
+      Some(LidarrEvent::ManualImport(files))
+    }
+  }
+
+  fn confirm_manual_import(&mut self) {
+    self.app.data.lidarr_data.prompt_confirm_action = self.build_manual_import_event();
+    self.app.data.lidarr_data.manual_import_modal = None;
+    self.app.pop_navigation_stack();
+    self.app.pop_navigation_stack();
   }
 }
 
 impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveLidarrBlock> for DownloadsHandler<'a, 'b> {
   fn handle(&mut self) {
-    let download_table_handling_config =
-      TableHandlingConfig::new(ActiveLidarrBlock::Downloads.into());
+    let downloads_table_handling_config =
+      TableHandlingConfig::new(ActiveLidarrBlock::Downloads.into())
+        .multi_select_id_fn(|download: &DownloadRecord| download.id);
+
+    let manual_import_table_handling_config =
+      TableHandlingConfig::new(ActiveLidarrBlock::ManualImport.into());
 
     if !handle_table(
       self,
       |app| &mut app.data.lidarr_data.downloads,
-      download_table_handling_config,
+      downloads_table_handling_config,
+    ) && !handle_table(
+      self,
+      |app| {
+        &mut app
+          .data
+          .lidarr_data
+          .manual_import_modal
+          .as_mut()
+          .unwrap()
+          .items
+      },
+      manual_import_table_handling_config,
     ) {
       self.handle_key_event();
     }
@@ -66,31 +151,69 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveLidarrBlock> for DownloadsHandler<'a,
   }
 
   fn is_ready(&self) -> bool {
-    !self.app.is_loading && !self.app.data.lidarr_data.downloads.is_empty()
+    if self.app.is_loading {
+      return false;
+    }
+
+    match self.active_lidarr_block {
+      ActiveLidarrBlock::ManualImport
+      | ActiveLidarrBlock::ManualImportConfirmPrompt
+      | ActiveLidarrBlock::ManualImportSelectArtist => {
+        self.app.data.lidarr_data.manual_import_modal.is_some()
+      }
+      _ => !self.app.data.lidarr_data.downloads.is_empty(),
+    }
   }
 
-  fn handle_scroll_up(&mut self) {}
+  fn handle_scroll_up(&mut self) {
+    match self.active_lidarr_block {
+      ActiveLidarrBlock::DeleteDownloadPrompt => self.app.data.lidarr_data.selected_block.up(),
+      ActiveLidarrBlock::ManualImportSelectArtist => {
+        self.manual_import_modal().artist_list.scroll_up()
+      }
+      _ => (),
+    }
+  }
 
-  fn handle_scroll_down(&mut self) {}
+  fn handle_scroll_down(&mut self) {
+    match self.active_lidarr_block {
+      ActiveLidarrBlock::DeleteDownloadPrompt => self.app.data.lidarr_data.selected_block.down(),
+      ActiveLidarrBlock::ManualImportSelectArtist => {
+        self.manual_import_modal().artist_list.scroll_down()
+      }
+      _ => (),
+    }
+  }
 
-  fn handle_home(&mut self) {}
+  fn handle_home(&mut self) {
+    if self.active_lidarr_block == ActiveLidarrBlock::ManualImportSelectArtist {
+      self.manual_import_modal().artist_list.scroll_to_top();
+    }
+  }
 
-  fn handle_end(&mut self) {}
+  fn handle_end(&mut self) {
+    if self.active_lidarr_block == ActiveLidarrBlock::ManualImportSelectArtist {
+      self.manual_import_modal().artist_list.scroll_to_bottom();
+    }
+  }
 
   fn handle_delete(&mut self) {
     if self.active_lidarr_block == ActiveLidarrBlock::Downloads {
       self
         .app
-        .push_navigation_stack(ActiveLidarrBlock::DeleteDownloadPrompt.into())
+        .push_navigation_stack(ActiveLidarrBlock::DeleteDownloadPrompt.into());
+      self.app.data.lidarr_data.selected_block =
+        BlockSelectionState::new(DELETE_DOWNLOAD_SELECTION_BLOCKS);
     }
   }
 
   fn handle_left_right_action(&mut self) {
     match self.active_lidarr_block {
       ActiveLidarrBlock::Downloads => handle_change_tab_left_right_keys(self.app, self.key),
-      ActiveLidarrBlock::DeleteDownloadPrompt | ActiveLidarrBlock::UpdateDownloadsPrompt => {
-        handle_prompt_toggle(self.app, self.key)
-      }
+      ActiveLidarrBlock::DeleteDownloadPrompt
+      | ActiveLidarrBlock::GrabDownloadPrompt
+      | ActiveLidarrBlock::UpdateDownloadsPrompt
+      | ActiveLidarrBlock::ManualImportConfirmPrompt => handle_prompt_toggle(self.app, self.key),
       _ => (),
     }
   }
@@ -98,9 +221,38 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveLidarrBlock> for DownloadsHandler<'a,
   fn handle_submit(&mut self) {
     match self.active_lidarr_block {
       ActiveLidarrBlock::DeleteDownloadPrompt => {
+        let removal_options = &mut self.app.data.lidarr_data.queue_removal_options;
+        match self.app.data.lidarr_data.selected_block.get_active_block() {
+          ActiveLidarrBlock::DeleteDownloadConfirmPrompt => {
+            if self.app.data.lidarr_data.prompt_confirm {
+              self.app.data.lidarr_data.prompt_confirm_action =
+                Some(self.build_delete_download_event());
+            } else {
+              self.app.data.lidarr_data.queue_removal_options = QueueRemovalOptions::default();
+            }
+
+            self.app.pop_navigation_stack();
+          }
+          ActiveLidarrBlock::DeleteDownloadToggleRemoveFromClient => {
+            removal_options.remove_from_client = !removal_options.remove_from_client;
+          }
+          ActiveLidarrBlock::DeleteDownloadToggleBlocklist => {
+            removal_options.blocklist = !removal_options.blocklist;
+          }
+          ActiveLidarrBlock::DeleteDownloadToggleSkipRedownload => {
+            removal_options.skip_redownload = !removal_options.skip_redownload;
+          }
+          ActiveLidarrBlock::DeleteDownloadToggleChangeCategory => {
+            removal_options.change_category = !removal_options.change_category;
+          }
+          _ => (),
+        }
+      }
+      ActiveLidarrBlock::GrabDownloadPrompt => {
         if self.app.data.lidarr_data.prompt_confirm {
-          self.app.data.lidarr_data.prompt_confirm_action =
-            Some(LidarrEvent::DeleteDownload(self.extract_download_id()));
+          self.app.data.lidarr_data.prompt_confirm_action = Some(LidarrEvent::GrabQueueItem(
+            self.app.data.lidarr_data.downloads.current_selection().id,
+          ));
         }
 
         self.app.pop_navigation_stack();
@@ -112,16 +264,59 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveLidarrBlock> for DownloadsHandler<'a,
 
         self.app.pop_navigation_stack();
       }
+      ActiveLidarrBlock::ManualImport
+        if self
+          .app
+          .data
+          .lidarr_data
+          .manual_import_modal
+          .as_ref()
+          .is_some_and(|modal| !modal.items.is_empty()) =>
+      {
+        let artists = self.app.data.lidarr_data.artists.items.clone();
+        self.manual_import_modal().artist_list.set_items(artists);
+        self
+          .app
+          .push_navigation_stack(ActiveLidarrBlock::ManualImportSelectArtist.into());
+      }
+      ActiveLidarrBlock::ManualImportSelectArtist => {
+        let manual_import_modal = self.manual_import_modal();
+        if !manual_import_modal.artist_list.items.is_empty() {
+          manual_import_modal.artist_id =
+            Some(manual_import_modal.artist_list.current_selection().id);
+        }
+
+        self.app.pop_navigation_stack();
+      }
+      ActiveLidarrBlock::ManualImportConfirmPrompt => {
+        if self.app.data.lidarr_data.prompt_confirm {
+          self.confirm_manual_import();
+        } else {
+          self.app.pop_navigation_stack();
+        }
+      }
       _ => (),
     }
   }
 
   fn handle_esc(&mut self) {
     match self.active_lidarr_block {
-      ActiveLidarrBlock::DeleteDownloadPrompt | ActiveLidarrBlock::UpdateDownloadsPrompt => {
+      ActiveLidarrBlock::DeleteDownloadPrompt => {
         self.app.pop_navigation_stack();
+        self.app.data.lidarr_data.queue_removal_options = QueueRemovalOptions::default();
         self.app.data.lidarr_data.prompt_confirm = false;
       }
+      ActiveLidarrBlock::GrabDownloadPrompt
+      | ActiveLidarrBlock::UpdateDownloadsPrompt
+      | ActiveLidarrBlock::ManualImportConfirmPrompt => {
+        self.app.pop_navigation_stack();
+        self.app.data.lidarr_data.prompt_confirm = false;
+      }
+      ActiveLidarrBlock::ManualImport => {
+        self.app.pop_navigation_stack();
+        self.app.data.lidarr_data.manual_import_modal = None;
+      }
+      ActiveLidarrBlock::ManualImportSelectArtist => self.app.pop_navigation_stack(),
       _ => handle_clear_errors(self.app),
     }
   }
@@ -135,16 +330,71 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveLidarrBlock> for DownloadsHandler<'a,
             .app
             .push_navigation_stack(ActiveLidarrBlock::UpdateDownloadsPrompt.into());
         }
+        _ if matches_key!(grab, key)
+          && self
+            .app
+            .data
+            .lidarr_data
+            .downloads
+            .current_selection()
+            .is_pending() =>
+        {
+          self
+            .app
+            .push_navigation_stack(ActiveLidarrBlock::GrabDownloadPrompt.into());
+        }
         _ if matches_key!(refresh, key) => {
           self.app.should_refresh = true;
         }
+        _ if matches_key!(import, key) => {
+          let download_record = self.app.data.lidarr_data.downloads.current_selection();
+          if let Some(download_id) = download_record.download_id.clone() {
+            self.app.data.lidarr_data.manual_import_modal = Some(ManualImportModal {
+              download_id,
+              ..ManualImportModal::default()
+            });
+            self
+              .app
+              .push_navigation_stack(ActiveLidarrBlock::ManualImport.into());
+          }
+        }
         _ => (),
       },
-      ActiveLidarrBlock::DeleteDownloadPrompt => {
+      ActiveLidarrBlock::ManualImport => match self.key {
+        _ if matches_key!(import, key) && self.build_manual_import_event().is_some() => {
+          self
+            .app
+            .push_navigation_stack(ActiveLidarrBlock::ManualImportConfirmPrompt.into());
+        }
+        _ if matches_key!(refresh, key) => {
+          self.app.should_refresh = true;
+        }
+        _ => (),
+      },
+      ActiveLidarrBlock::ManualImportConfirmPrompt => {
+        if matches_key!(confirm, key) {
+          self.app.data.lidarr_data.prompt_confirm = true;
+          self.confirm_manual_import();
+        }
+      }
+      ActiveLidarrBlock::DeleteDownloadPrompt
+        if self.app.data.lidarr_data.selected_block.get_active_block()
+          == ActiveLidarrBlock::DeleteDownloadConfirmPrompt =>
+      {
         if matches_key!(confirm, key) {
           self.app.data.lidarr_data.prompt_confirm = true;
           self.app.data.lidarr_data.prompt_confirm_action =
-            Some(LidarrEvent::DeleteDownload(self.extract_download_id()));
+            Some(self.build_delete_download_event());
+
+          self.app.pop_navigation_stack();
+        }
+      }
+      ActiveLidarrBlock::GrabDownloadPrompt => {
+        if matches_key!(confirm, key) {
+          self.app.data.lidarr_data.prompt_confirm = true;
+          self.app.data.lidarr_data.prompt_confirm_action = Some(LidarrEvent::GrabQueueItem(
+            self.app.data.lidarr_data.downloads.current_selection().id,
+          ));
 
           self.app.pop_navigation_stack();
         }
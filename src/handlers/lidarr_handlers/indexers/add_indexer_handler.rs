@@ -0,0 +1,218 @@
+use crate::app::App;
+use crate::event::Key;
+use crate::handlers::KeyEventHandler;
+use crate::models::servarr_data::lidarr::lidarr_data::{ADD_INDEXER_BLOCKS, ActiveLidarrBlock};
+use crate::models::servarr_data::modals::{AddIndexerModal, SchemaFormFieldKind};
+use crate::models::servarr_models::AddIndexerParams;
+use crate::models::{Route, Scrollable};
+use crate::network::lidarr_network::LidarrEvent;
+use crate::{handle_text_box_keys, handle_text_box_left_right_keys, matches_key};
+
+#[cfg(test)]
+#[path = "add_indexer_handler_tests.rs"]
+mod add_indexer_handler_tests;
+
+pub(super) struct AddIndexerHandler<'a, 'b> {
+  key: Key,
+  app: &'a mut App<'b>,
+  active_lidarr_block: ActiveLidarrBlock,
+  _context: Option<ActiveLidarrBlock>,
+}
+
+impl AddIndexerHandler<'_, '_> {
+  fn add_indexer_modal(&mut self) -> &mut AddIndexerModal {
+    self
+      .app
+      .data
+      .lidarr_data
+      .add_indexer_modal
+      .as_mut()
+      .expect("Add Indexer Modal is None")
+  }
+
+  fn build_add_indexer_params(&mut self) -> AddIndexerParams {
+    let add_indexer_modal = self
+      .app
+      .data
+      .lidarr_data
+      .add_indexer_modal
+      .take()
+      .expect("Add Indexer Modal is None");
+
+    AddIndexerParams::from(&add_indexer_modal)
+  }
+}
+
+impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveLidarrBlock> for AddIndexerHandler<'a, 'b> {
+  fn accepts(active_block: ActiveLidarrBlock) -> bool {
+    ADD_INDEXER_BLOCKS.contains(&active_block)
+  }
+
+  fn ignore_special_keys(&self) -> bool {
+    self.app.ignore_special_keys_for_textbox_input
+  }
+
+  fn new(
+    key: Key,
+    app: &'a mut App<'b>,
+    active_block: ActiveLidarrBlock,
+    _context: Option<ActiveLidarrBlock>,
+  ) -> AddIndexerHandler<'a, 'b> {
+    AddIndexerHandler {
+      key,
+      app,
+      active_lidarr_block: active_block,
+      _context,
+    }
+  }
+
+  fn get_key(&self) -> Key {
+    self.key
+  }
+
+  fn is_ready(&self) -> bool {
+    if self.active_lidarr_block == ActiveLidarrBlock::AddIndexerSelectImplementation {
+      !self.app.is_loading && !self.app.data.lidarr_data.indexer_schema.is_empty()
+    } else {
+      !self.app.is_loading && self.app.data.lidarr_data.add_indexer_modal.is_some()
+    }
+  }
+
+  fn handle_scroll_up(&mut self) {
+    match self.active_lidarr_block {
+      ActiveLidarrBlock::AddIndexerSelectImplementation => {
+        self.app.data.lidarr_data.indexer_schema.scroll_up()
+      }
+      ActiveLidarrBlock::AddIndexerPrompt => self.add_indexer_modal().fields.scroll_up(),
+      _ => (),
+    }
+  }
+
+  fn handle_scroll_down(&mut self) {
+    match self.active_lidarr_block {
+      ActiveLidarrBlock::AddIndexerSelectImplementation => {
+        self.app.data.lidarr_data.indexer_schema.scroll_down()
+      }
+      ActiveLidarrBlock::AddIndexerPrompt => self.add_indexer_modal().fields.scroll_down(),
+      _ => (),
+    }
+  }
+
+  fn handle_home(&mut self) {
+    match self.active_lidarr_block {
+      ActiveLidarrBlock::AddIndexerSelectImplementation => {
+        self.app.data.lidarr_data.indexer_schema.scroll_to_top()
+      }
+      ActiveLidarrBlock::AddIndexerPrompt => self.add_indexer_modal().fields.scroll_to_top(),
+      ActiveLidarrBlock::AddIndexerFieldInput => self
+        .add_indexer_modal()
+        .current_field_mut()
+        .value
+        .scroll_home(),
+      _ => (),
+    }
+  }
+
+  fn handle_end(&mut self) {
+    match self.active_lidarr_block {
+      ActiveLidarrBlock::AddIndexerSelectImplementation => {
+        self.app.data.lidarr_data.indexer_schema.scroll_to_bottom()
+      }
+      ActiveLidarrBlock::AddIndexerPrompt => self.add_indexer_modal().fields.scroll_to_bottom(),
+      ActiveLidarrBlock::AddIndexerFieldInput => self
+        .add_indexer_modal()
+        .current_field_mut()
+        .value
+        .reset_offset(),
+      _ => (),
+    }
+  }
+
+  fn handle_delete(&mut self) {}
+
+  fn handle_left_right_action(&mut self) {
+    if self.active_lidarr_block == ActiveLidarrBlock::AddIndexerFieldInput {
+      handle_text_box_left_right_keys!(
+        self,
+        self.key,
+        self.add_indexer_modal().current_field_mut().value
+      );
+    }
+  }
+
+  fn handle_submit(&mut self) {
+    match self.active_lidarr_block {
+      ActiveLidarrBlock::AddIndexerSelectImplementation => {
+        let add_indexer_modal =
+          AddIndexerModal::from(self.app.data.lidarr_data.indexer_schema.current_selection());
+        self.app.data.lidarr_data.add_indexer_modal = Some(add_indexer_modal);
+        self
+          .app
+          .pop_and_push_navigation_stack(ActiveLidarrBlock::AddIndexerPrompt.into());
+      }
+      ActiveLidarrBlock::AddIndexerPrompt => {
+        let add_indexer_modal = self.add_indexer_modal();
+        match add_indexer_modal.current_field_mut().kind {
+          SchemaFormFieldKind::Checkbox | SchemaFormFieldKind::Select(_) => {
+            add_indexer_modal.toggle_current_field()
+          }
+          _ => {
+            self
+              .app
+              .push_navigation_stack(ActiveLidarrBlock::AddIndexerFieldInput.into());
+            self.app.ignore_special_keys_for_textbox_input = true;
+          }
+        }
+      }
+      ActiveLidarrBlock::AddIndexerFieldInput => {
+        self.app.pop_navigation_stack();
+        self.app.ignore_special_keys_for_textbox_input = false;
+      }
+      _ => (),
+    }
+  }
+
+  fn handle_esc(&mut self) {
+    match self.active_lidarr_block {
+      ActiveLidarrBlock::AddIndexerPrompt => {
+        self.app.pop_navigation_stack();
+        self.app.data.lidarr_data.prompt_confirm = false;
+        self.app.data.lidarr_data.add_indexer_modal = None;
+      }
+      ActiveLidarrBlock::AddIndexerFieldInput => {
+        self.app.pop_navigation_stack();
+        self.app.ignore_special_keys_for_textbox_input = false;
+      }
+      _ => self.app.pop_navigation_stack(),
+    }
+  }
+
+  fn handle_char_key_event(&mut self) {
+    match self.active_lidarr_block {
+      ActiveLidarrBlock::AddIndexerFieldInput => {
+        handle_text_box_keys!(
+          self,
+          self.key,
+          self.add_indexer_modal().current_field_mut().value
+        );
+      }
+      ActiveLidarrBlock::AddIndexerPrompt if matches_key!(confirm, self.key) => {
+        self.app.data.lidarr_data.prompt_confirm = true;
+        self.app.data.lidarr_data.prompt_confirm_action =
+          Some(LidarrEvent::AddIndexer(self.build_add_indexer_params()));
+        self.app.should_refresh = true;
+
+        self.app.pop_navigation_stack();
+      }
+      _ => (),
+    }
+  }
+
+  fn app_mut(&mut self) -> &mut App<'b> {
+    self.app
+  }
+
+  fn current_route(&self) -> Route {
+    self.app.get_current_route()
+  }
+}
@@ -0,0 +1,318 @@
+use crate::app::App;
+use crate::event::Key;
+use crate::handlers::{KeyEventHandler, handle_prompt_toggle};
+use crate::models::lidarr_models::BulkEditArtistsParams;
+use crate::models::servarr_data::lidarr::lidarr_data::{
+  ActiveLidarrBlock, BULK_EDIT_ARTISTS_BLOCKS,
+};
+use crate::models::{Route, Scrollable};
+use crate::network::lidarr_network::LidarrEvent;
+use crate::{handle_text_box_keys, handle_text_box_left_right_keys, matches_key};
+
+#[cfg(test)]
+#[path = "bulk_edit_artists_handler_tests.rs"]
+mod bulk_edit_artists_handler_tests;
+
+pub(super) struct BulkEditArtistsHandler<'a, 'b> {
+  key: Key,
+  app: &'a mut App<'b>,
+  active_lidarr_block: ActiveLidarrBlock,
+  _context: Option<ActiveLidarrBlock>,
+}
+
+impl BulkEditArtistsHandler<'_, '_> {
+  fn build_bulk_edit_artists_params(&mut self) -> BulkEditArtistsParams {
+    let artist_ids = self
+      .app
+      .data
+      .lidarr_data
+      .artists
+      .multi_selection()
+      .iter()
+      .map(|artist| artist.id)
+      .collect();
+    let bulk_edit_modal = self
+      .app
+      .data
+      .lidarr_data
+      .bulk_edit_modal
+      .take()
+      .expect("Bulk edit modal is None");
+    let quality_profile_id =
+      bulk_edit_modal
+        .selected_quality_profile()
+        .and_then(|quality_profile| {
+          self
+            .app
+            .data
+            .lidarr_data
+            .quality_profile_map
+            .get_by_right(quality_profile)
+            .copied()
+        });
+    self.app.data.lidarr_data.artists.clear_multi_selection();
+
+    BulkEditArtistsParams {
+      artist_ids,
+      monitored: bulk_edit_modal
+        .monitored_list
+        .current_selection()
+        .monitored(),
+      quality_profile_id,
+      root_folder_path: bulk_edit_modal.selected_root_folder_path(),
+      tags: None,
+      tag_input_string: bulk_edit_modal.tag_input_string(),
+      apply_tags: *bulk_edit_modal.apply_tags_list.current_selection(),
+      move_files: bulk_edit_modal.move_files.unwrap_or_default(),
+    }
+  }
+}
+
+impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveLidarrBlock> for BulkEditArtistsHandler<'a, 'b> {
+  fn accepts(active_block: ActiveLidarrBlock) -> bool {
+    BULK_EDIT_ARTISTS_BLOCKS.contains(&active_block)
+  }
+
+  fn ignore_special_keys(&self) -> bool {
+    self.app.ignore_special_keys_for_textbox_input
+  }
+
+  fn new(
+    key: Key,
+    app: &'a mut App<'b>,
+    active_block: ActiveLidarrBlock,
+    _context: Option<ActiveLidarrBlock>,
+  ) -> BulkEditArtistsHandler<'a, 'b> {
+    BulkEditArtistsHandler {
+      key,
+      app,
+      active_lidarr_block: active_block,
+      _context,
+    }
+  }
+
+  fn get_key(&self) -> Key {
+    self.key
+  }
+
+  fn is_ready(&self) -> bool {
+    !self.app.is_loading && self.app.data.lidarr_data.bulk_edit_modal.is_some()
+  }
+
+  fn handle_scroll_up(&mut self) {
+    let bulk_edit_modal = self.app.data.lidarr_data.bulk_edit_modal.as_mut().unwrap();
+    match self.active_lidarr_block {
+      ActiveLidarrBlock::BulkEditArtistsSelectMonitored => {
+        bulk_edit_modal.monitored_list.scroll_up()
+      }
+      ActiveLidarrBlock::BulkEditArtistsSelectQualityProfile => {
+        bulk_edit_modal.quality_profile_list.scroll_up()
+      }
+      ActiveLidarrBlock::BulkEditArtistsSelectRootFolder => {
+        bulk_edit_modal.root_folder_list.scroll_up()
+      }
+      ActiveLidarrBlock::BulkEditArtistsSelectApplyTags => {
+        bulk_edit_modal.apply_tags_list.scroll_up()
+      }
+      ActiveLidarrBlock::BulkEditArtistsPrompt => self.app.data.lidarr_data.selected_block.up(),
+      _ => (),
+    }
+  }
+
+  fn handle_scroll_down(&mut self) {
+    let bulk_edit_modal = self.app.data.lidarr_data.bulk_edit_modal.as_mut().unwrap();
+    match self.active_lidarr_block {
+      ActiveLidarrBlock::BulkEditArtistsSelectMonitored => {
+        bulk_edit_modal.monitored_list.scroll_down()
+      }
+      ActiveLidarrBlock::BulkEditArtistsSelectQualityProfile => {
+        bulk_edit_modal.quality_profile_list.scroll_down()
+      }
+      ActiveLidarrBlock::BulkEditArtistsSelectRootFolder => {
+        bulk_edit_modal.root_folder_list.scroll_down()
+      }
+      ActiveLidarrBlock::BulkEditArtistsSelectApplyTags => {
+        bulk_edit_modal.apply_tags_list.scroll_down()
+      }
+      ActiveLidarrBlock::BulkEditArtistsPrompt => self.app.data.lidarr_data.selected_block.down(),
+      _ => (),
+    }
+  }
+
+  fn handle_home(&mut self) {
+    let bulk_edit_modal = self.app.data.lidarr_data.bulk_edit_modal.as_mut().unwrap();
+    match self.active_lidarr_block {
+      ActiveLidarrBlock::BulkEditArtistsSelectMonitored => {
+        bulk_edit_modal.monitored_list.scroll_to_top()
+      }
+      ActiveLidarrBlock::BulkEditArtistsSelectQualityProfile => {
+        bulk_edit_modal.quality_profile_list.scroll_to_top()
+      }
+      ActiveLidarrBlock::BulkEditArtistsSelectRootFolder => {
+        bulk_edit_modal.root_folder_list.scroll_to_top()
+      }
+      ActiveLidarrBlock::BulkEditArtistsSelectApplyTags => {
+        bulk_edit_modal.apply_tags_list.scroll_to_top()
+      }
+      ActiveLidarrBlock::BulkEditArtistsTagsInput => bulk_edit_modal.tags.scroll_home(),
+      _ => (),
+    }
+  }
+
+  fn handle_end(&mut self) {
+    let bulk_edit_modal = self.app.data.lidarr_data.bulk_edit_modal.as_mut().unwrap();
+    match self.active_lidarr_block {
+      ActiveLidarrBlock::BulkEditArtistsSelectMonitored => {
+        bulk_edit_modal.monitored_list.scroll_to_bottom()
+      }
+      ActiveLidarrBlock::BulkEditArtistsSelectQualityProfile => {
+        bulk_edit_modal.quality_profile_list.scroll_to_bottom()
+      }
+      ActiveLidarrBlock::BulkEditArtistsSelectRootFolder => {
+        bulk_edit_modal.root_folder_list.scroll_to_bottom()
+      }
+      ActiveLidarrBlock::BulkEditArtistsSelectApplyTags => {
+        bulk_edit_modal.apply_tags_list.scroll_to_bottom()
+      }
+      ActiveLidarrBlock::BulkEditArtistsTagsInput => bulk_edit_modal.tags.reset_offset(),
+      _ => (),
+    }
+  }
+
+  fn handle_delete(&mut self) {}
+
+  fn handle_left_right_action(&mut self) {
+    match self.active_lidarr_block {
+      ActiveLidarrBlock::BulkEditArtistsPrompt => handle_prompt_toggle(self.app, self.key),
+      ActiveLidarrBlock::BulkEditArtistsTagsInput => {
+        handle_text_box_left_right_keys!(
+          self,
+          self.key,
+          self
+            .app
+            .data
+            .lidarr_data
+            .bulk_edit_modal
+            .as_mut()
+            .unwrap()
+            .tags
+        )
+      }
+      _ => (),
+    }
+  }
+
+  fn handle_submit(&mut self) {
+    match self.active_lidarr_block {
+      ActiveLidarrBlock::BulkEditArtistsPrompt => {
+        match self.app.data.lidarr_data.selected_block.get_active_block() {
+          ActiveLidarrBlock::BulkEditArtistsConfirmPrompt => {
+            if self.app.data.lidarr_data.prompt_confirm {
+              self.app.data.lidarr_data.prompt_confirm_action = Some(LidarrEvent::BulkEditArtists(
+                self.build_bulk_edit_artists_params(),
+              ));
+              self.app.should_refresh = true;
+            } else {
+              self.app.data.lidarr_data.bulk_edit_modal = None;
+            }
+
+            self.app.pop_navigation_stack();
+          }
+          ActiveLidarrBlock::BulkEditArtistsSelectMonitored
+          | ActiveLidarrBlock::BulkEditArtistsSelectQualityProfile
+          | ActiveLidarrBlock::BulkEditArtistsSelectRootFolder
+          | ActiveLidarrBlock::BulkEditArtistsSelectApplyTags => self.app.push_navigation_stack(
+            self
+              .app
+              .data
+              .lidarr_data
+              .selected_block
+              .get_active_block()
+              .into(),
+          ),
+          ActiveLidarrBlock::BulkEditArtistsTagsInput => {
+            self
+              .app
+              .push_navigation_stack(ActiveLidarrBlock::BulkEditArtistsTagsInput.into());
+            self.app.ignore_special_keys_for_textbox_input = true;
+          }
+          ActiveLidarrBlock::BulkEditArtistsToggleMoveFiles => {
+            let bulk_edit_modal = self.app.data.lidarr_data.bulk_edit_modal.as_mut().unwrap();
+            bulk_edit_modal.move_files = Some(!bulk_edit_modal.move_files.unwrap_or_default());
+          }
+          _ => (),
+        }
+      }
+      ActiveLidarrBlock::BulkEditArtistsSelectMonitored
+      | ActiveLidarrBlock::BulkEditArtistsSelectQualityProfile
+      | ActiveLidarrBlock::BulkEditArtistsSelectRootFolder
+      | ActiveLidarrBlock::BulkEditArtistsSelectApplyTags => self.app.pop_navigation_stack(),
+      ActiveLidarrBlock::BulkEditArtistsTagsInput => {
+        self.app.pop_navigation_stack();
+        self.app.ignore_special_keys_for_textbox_input = false;
+      }
+      _ => (),
+    }
+  }
+
+  fn handle_esc(&mut self) {
+    match self.active_lidarr_block {
+      ActiveLidarrBlock::BulkEditArtistsTagsInput => {
+        self.app.pop_navigation_stack();
+        self.app.ignore_special_keys_for_textbox_input = false;
+      }
+      ActiveLidarrBlock::BulkEditArtistsPrompt => {
+        self.app.pop_navigation_stack();
+        self.app.data.lidarr_data.bulk_edit_modal = None;
+        self.app.data.lidarr_data.prompt_confirm = false;
+      }
+      ActiveLidarrBlock::BulkEditArtistsSelectMonitored
+      | ActiveLidarrBlock::BulkEditArtistsSelectQualityProfile
+      | ActiveLidarrBlock::BulkEditArtistsSelectRootFolder
+      | ActiveLidarrBlock::BulkEditArtistsSelectApplyTags => self.app.pop_navigation_stack(),
+      _ => (),
+    }
+  }
+
+  fn handle_char_key_event(&mut self) {
+    let key = self.key;
+    match self.active_lidarr_block {
+      ActiveLidarrBlock::BulkEditArtistsTagsInput => {
+        handle_text_box_keys!(
+          self,
+          key,
+          self
+            .app
+            .data
+            .lidarr_data
+            .bulk_edit_modal
+            .as_mut()
+            .unwrap()
+            .tags
+        )
+      }
+      ActiveLidarrBlock::BulkEditArtistsPrompt
+        if self.app.data.lidarr_data.selected_block.get_active_block()
+          == ActiveLidarrBlock::BulkEditArtistsConfirmPrompt
+          && matches_key!(confirm, key) =>
+      {
+        self.app.data.lidarr_data.prompt_confirm = true;
+        self.app.data.lidarr_data.prompt_confirm_action = Some(LidarrEvent::BulkEditArtists(
+          self.build_bulk_edit_artists_params(),
+        ));
+        self.app.should_refresh = true;
+
+        self.app.pop_navigation_stack();
+      }
+      _ => (),
+    }
+  }
+
+  fn app_mut(&mut self) -> &mut App<'b> {
+    self.app
+  }
+
+  fn current_route(&self) -> Route {
+    self.app.get_current_route()
+  }
+}
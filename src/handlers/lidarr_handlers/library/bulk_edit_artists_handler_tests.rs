@@ -0,0 +1,846 @@
+#[cfg(test)]
+mod tests {
+  use std::sync::atomic::Ordering;
+
+  use bimap::BiMap;
+  use pretty_assertions::assert_eq;
+  use rstest::rstest;
+  use strum::IntoEnumIterator;
+
+  use crate::app::App;
+  use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+  use crate::assert_modal_absent;
+  use crate::assert_navigation_popped;
+  use crate::assert_navigation_pushed;
+  use crate::event::Key;
+  use crate::handlers::KeyEventHandler;
+  use crate::handlers::lidarr_handlers::library::bulk_edit_artists_handler::BulkEditArtistsHandler;
+  use crate::models::lidarr_models::{Artist, BulkEditArtistsParams};
+  use crate::models::servarr_data::lidarr::lidarr_data::{
+    ActiveLidarrBlock, BULK_EDIT_ARTISTS_BLOCKS, BULK_EDIT_ARTISTS_SELECTION_BLOCKS,
+  };
+  use crate::models::servarr_data::modals::BulkEditModal;
+  use crate::models::servarr_models::{ApplyTags, BulkEditMonitoring};
+  use crate::models::{BlockSelectionState, Scrollable};
+  use crate::network::lidarr_network::LidarrEvent;
+  use crate::network::lidarr_network::lidarr_network_test_utils::test_utils::artist;
+
+  fn app_with_selected_artists() -> App<'static> {
+    let mut app = App::test_default();
+    let artists = vec![
+      artist(),
+      Artist { id: 2, ..artist() },
+      Artist { id: 3, ..artist() },
+    ];
+    app.data.lidarr_data.artists.set_items(artists.clone());
+    app.data.lidarr_data.artists.selected_ids = [artists[2].id, artists[0].id].into();
+    app.data.lidarr_data.artists.selection_id_fn = Some(|artist| artist.id);
+    app.data.lidarr_data.quality_profile_map =
+      BiMap::from_iter([(1111, "Any".to_owned()), (2222, "HD - 1080p".to_owned())]);
+    let mut bulk_edit_modal = BulkEditModal::new(
+      vec!["Any".to_owned(), "HD - 1080p".to_owned()],
+      vec!["/nfs/music".to_owned()],
+    );
+    bulk_edit_modal.monitored_list.scroll_down();
+    bulk_edit_modal.quality_profile_list.scroll_to_bottom();
+    bulk_edit_modal.root_folder_list.scroll_to_bottom();
+    bulk_edit_modal.apply_tags_list.scroll_to_bottom();
+    bulk_edit_modal.tags = "usenet, testing".into();
+    bulk_edit_modal.move_files = Some(true);
+    app.data.lidarr_data.bulk_edit_modal = Some(bulk_edit_modal);
+
+    app
+  }
+
+  fn expected_bulk_edit_artists_params() -> BulkEditArtistsParams {
+    BulkEditArtistsParams {
+      artist_ids: vec![1, 3],
+      monitored: Some(true),
+      quality_profile_id: Some(2222),
+      root_folder_path: Some("/nfs/music".to_owned()),
+      tags: None,
+      tag_input_string: Some("usenet, testing".to_owned()),
+      apply_tags: ApplyTags::Replace,
+      move_files: true,
+    }
+  }
+
+  mod test_handle_scroll_up_and_down {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[rstest]
+    fn test_bulk_edit_artists_select_monitored_scroll(
+      #[values(DEFAULT_KEYBINDINGS.up.key, DEFAULT_KEYBINDINGS.down.key)] key: Key,
+    ) {
+      let monitoring_vec = Vec::from_iter(BulkEditMonitoring::iter());
+      let mut app = App::test_default();
+      app.data.lidarr_data.bulk_edit_modal = Some(BulkEditModal::new(Vec::new(), Vec::new()));
+
+      if key == Key::Up {
+        for i in (0..monitoring_vec.len()).rev() {
+          BulkEditArtistsHandler::new(
+            key,
+            &mut app,
+            ActiveLidarrBlock::BulkEditArtistsSelectMonitored,
+            None,
+          )
+          .handle();
+
+          assert_eq!(
+            app
+              .data
+              .lidarr_data
+              .bulk_edit_modal
+              .as_ref()
+              .unwrap()
+              .monitored_list
+              .current_selection(),
+            &monitoring_vec[i]
+          );
+        }
+      } else {
+        for i in 0..monitoring_vec.len() {
+          BulkEditArtistsHandler::new(
+            key,
+            &mut app,
+            ActiveLidarrBlock::BulkEditArtistsSelectMonitored,
+            None,
+          )
+          .handle();
+
+          assert_eq!(
+            app
+              .data
+              .lidarr_data
+              .bulk_edit_modal
+              .as_ref()
+              .unwrap()
+              .monitored_list
+              .current_selection(),
+            &monitoring_vec[(i + 1) % monitoring_vec.len()]
+          );
+        }
+      }
+    }
+
+    #[rstest]
+    fn test_bulk_edit_artists_select_quality_profile_scroll(
+      #[values(DEFAULT_KEYBINDINGS.up.key, DEFAULT_KEYBINDINGS.down.key)] key: Key,
+    ) {
+      let mut app = App::test_default();
+      app.data.lidarr_data.bulk_edit_modal = Some(BulkEditModal::new(
+        vec!["HD - 1080p".to_owned()],
+        Vec::new(),
+      ));
+
+      BulkEditArtistsHandler::new(
+        key,
+        &mut app,
+        ActiveLidarrBlock::BulkEditArtistsSelectQualityProfile,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app
+          .data
+          .lidarr_data
+          .bulk_edit_modal
+          .as_ref()
+          .unwrap()
+          .quality_profile_list
+          .current_selection(),
+        "HD - 1080p"
+      );
+    }
+
+    #[rstest]
+    fn test_bulk_edit_artists_select_root_folder_scroll(
+      #[values(DEFAULT_KEYBINDINGS.up.key, DEFAULT_KEYBINDINGS.down.key)] key: Key,
+    ) {
+      let mut app = App::test_default();
+      app.data.lidarr_data.bulk_edit_modal = Some(BulkEditModal::new(
+        Vec::new(),
+        vec!["/nfs/music".to_owned()],
+      ));
+
+      BulkEditArtistsHandler::new(
+        key,
+        &mut app,
+        ActiveLidarrBlock::BulkEditArtistsSelectRootFolder,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app
+          .data
+          .lidarr_data
+          .bulk_edit_modal
+          .as_ref()
+          .unwrap()
+          .root_folder_list
+          .current_selection(),
+        "/nfs/music"
+      );
+    }
+
+    #[rstest]
+    fn test_bulk_edit_artists_select_apply_tags_scroll(
+      #[values(DEFAULT_KEYBINDINGS.up.key, DEFAULT_KEYBINDINGS.down.key)] key: Key,
+    ) {
+      let mut app = App::test_default();
+      app.data.lidarr_data.bulk_edit_modal = Some(BulkEditModal::new(Vec::new(), Vec::new()));
+
+      BulkEditArtistsHandler::new(
+        key,
+        &mut app,
+        ActiveLidarrBlock::BulkEditArtistsSelectApplyTags,
+        None,
+      )
+      .handle();
+
+      let expected_apply_tags = if key == Key::Up {
+        ApplyTags::Replace
+      } else {
+        ApplyTags::Remove
+      };
+      assert_eq!(
+        app
+          .data
+          .lidarr_data
+          .bulk_edit_modal
+          .as_ref()
+          .unwrap()
+          .apply_tags_list
+          .current_selection(),
+        &expected_apply_tags
+      );
+    }
+
+    #[rstest]
+    fn test_bulk_edit_artists_prompt_scroll(#[values(Key::Up, Key::Down)] key: Key) {
+      let mut app = App::test_default();
+      app.data.lidarr_data.bulk_edit_modal = Some(BulkEditModal::new(Vec::new(), Vec::new()));
+      app.data.lidarr_data.selected_block =
+        BlockSelectionState::new(BULK_EDIT_ARTISTS_SELECTION_BLOCKS);
+      app.data.lidarr_data.selected_block.down();
+
+      BulkEditArtistsHandler::new(
+        key,
+        &mut app,
+        ActiveLidarrBlock::BulkEditArtistsPrompt,
+        None,
+      )
+      .handle();
+
+      if key == Key::Up {
+        assert_eq!(
+          app.data.lidarr_data.selected_block.get_active_block(),
+          ActiveLidarrBlock::BulkEditArtistsSelectMonitored
+        );
+      } else {
+        assert_eq!(
+          app.data.lidarr_data.selected_block.get_active_block(),
+          ActiveLidarrBlock::BulkEditArtistsSelectRootFolder
+        );
+      }
+    }
+  }
+
+  mod test_handle_home_end {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_bulk_edit_artists_select_monitored_home_end() {
+      let mut app = App::test_default();
+      app.data.lidarr_data.bulk_edit_modal = Some(BulkEditModal::new(Vec::new(), Vec::new()));
+
+      BulkEditArtistsHandler::new(
+        DEFAULT_KEYBINDINGS.end.key,
+        &mut app,
+        ActiveLidarrBlock::BulkEditArtistsSelectMonitored,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app
+          .data
+          .lidarr_data
+          .bulk_edit_modal
+          .as_ref()
+          .unwrap()
+          .monitored_list
+          .current_selection(),
+        &BulkEditMonitoring::Unmonitored
+      );
+
+      BulkEditArtistsHandler::new(
+        DEFAULT_KEYBINDINGS.home.key,
+        &mut app,
+        ActiveLidarrBlock::BulkEditArtistsSelectMonitored,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app
+          .data
+          .lidarr_data
+          .bulk_edit_modal
+          .as_ref()
+          .unwrap()
+          .monitored_list
+          .current_selection(),
+        &BulkEditMonitoring::NoChange
+      );
+    }
+
+    #[test]
+    fn test_bulk_edit_artists_tags_input_home_end_keys() {
+      let mut app = App::test_default();
+      app.data.lidarr_data.bulk_edit_modal = Some(BulkEditModal {
+        tags: "Test".into(),
+        ..BulkEditModal::default()
+      });
+
+      BulkEditArtistsHandler::new(
+        DEFAULT_KEYBINDINGS.home.key,
+        &mut app,
+        ActiveLidarrBlock::BulkEditArtistsTagsInput,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app
+          .data
+          .lidarr_data
+          .bulk_edit_modal
+          .as_ref()
+          .unwrap()
+          .tags
+          .offset
+          .load(Ordering::SeqCst),
+        4
+      );
+
+      BulkEditArtistsHandler::new(
+        DEFAULT_KEYBINDINGS.end.key,
+        &mut app,
+        ActiveLidarrBlock::BulkEditArtistsTagsInput,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app
+          .data
+          .lidarr_data
+          .bulk_edit_modal
+          .as_ref()
+          .unwrap()
+          .tags
+          .offset
+          .load(Ordering::SeqCst),
+        0
+      );
+    }
+  }
+
+  mod test_handle_left_right_action {
+    use super::*;
+
+    #[rstest]
+    fn test_left_right_prompt_toggle(#[values(Key::Left, Key::Right)] key: Key) {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveLidarrBlock::Artists.into());
+      app.data.lidarr_data.bulk_edit_modal = Some(BulkEditModal::new(Vec::new(), Vec::new()));
+
+      BulkEditArtistsHandler::new(
+        key,
+        &mut app,
+        ActiveLidarrBlock::BulkEditArtistsPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(app.data.lidarr_data.prompt_confirm);
+
+      BulkEditArtistsHandler::new(
+        key,
+        &mut app,
+        ActiveLidarrBlock::BulkEditArtistsPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(!app.data.lidarr_data.prompt_confirm);
+    }
+  }
+
+  mod test_handle_submit {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    const SUBMIT_KEY: Key = DEFAULT_KEYBINDINGS.submit.key;
+
+    #[test]
+    fn test_bulk_edit_artists_prompt_decline_submit() {
+      let mut app = app_with_selected_artists();
+      app.push_navigation_stack(ActiveLidarrBlock::Artists.into());
+      app.push_navigation_stack(ActiveLidarrBlock::BulkEditArtistsPrompt.into());
+      app.data.lidarr_data.selected_block =
+        BlockSelectionState::new(BULK_EDIT_ARTISTS_SELECTION_BLOCKS);
+      app
+        .data
+        .lidarr_data
+        .selected_block
+        .set_index(0, BULK_EDIT_ARTISTS_SELECTION_BLOCKS.len() - 1);
+
+      BulkEditArtistsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveLidarrBlock::BulkEditArtistsPrompt,
+        None,
+      )
+      .handle();
+
+      assert_navigation_popped!(app, ActiveLidarrBlock::Artists.into());
+      assert_none!(app.data.lidarr_data.prompt_confirm_action);
+      assert_modal_absent!(app.data.lidarr_data.bulk_edit_modal);
+      assert!(app.data.lidarr_data.artists.has_multi_selection());
+    }
+
+    #[test]
+    fn test_bulk_edit_artists_confirm_prompt_confirmation_submit() {
+      let mut app = app_with_selected_artists();
+      app.push_navigation_stack(ActiveLidarrBlock::Artists.into());
+      app.push_navigation_stack(ActiveLidarrBlock::BulkEditArtistsPrompt.into());
+      app.data.lidarr_data.prompt_confirm = true;
+      app.data.lidarr_data.selected_block =
+        BlockSelectionState::new(BULK_EDIT_ARTISTS_SELECTION_BLOCKS);
+      app
+        .data
+        .lidarr_data
+        .selected_block
+        .set_index(0, BULK_EDIT_ARTISTS_SELECTION_BLOCKS.len() - 1);
+
+      BulkEditArtistsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveLidarrBlock::BulkEditArtistsPrompt,
+        None,
+      )
+      .handle();
+
+      assert_navigation_popped!(app, ActiveLidarrBlock::Artists.into());
+      assert_eq!(
+        app.data.lidarr_data.prompt_confirm_action,
+        Some(LidarrEvent::BulkEditArtists(
+          expected_bulk_edit_artists_params()
+        ))
+      );
+      assert_modal_absent!(app.data.lidarr_data.bulk_edit_modal);
+      assert!(!app.data.lidarr_data.artists.has_multi_selection());
+      assert!(app.should_refresh);
+    }
+
+    #[test]
+    fn test_bulk_edit_artists_confirm_prompt_confirmation_submit_no_op_when_not_ready() {
+      let mut app = app_with_selected_artists();
+      app.is_loading = true;
+      app.push_navigation_stack(ActiveLidarrBlock::Artists.into());
+      app.push_navigation_stack(ActiveLidarrBlock::BulkEditArtistsPrompt.into());
+      app.data.lidarr_data.prompt_confirm = true;
+      app.data.lidarr_data.selected_block =
+        BlockSelectionState::new(BULK_EDIT_ARTISTS_SELECTION_BLOCKS);
+      app
+        .data
+        .lidarr_data
+        .selected_block
+        .set_index(0, BULK_EDIT_ARTISTS_SELECTION_BLOCKS.len() - 1);
+
+      BulkEditArtistsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveLidarrBlock::BulkEditArtistsPrompt,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveLidarrBlock::BulkEditArtistsPrompt.into()
+      );
+      assert_none!(app.data.lidarr_data.prompt_confirm_action);
+      assert!(!app.should_refresh);
+    }
+
+    #[test]
+    fn test_bulk_edit_artists_toggle_move_files_submit() {
+      let mut app = App::test_default();
+      app.data.lidarr_data.bulk_edit_modal = Some(BulkEditModal::new(Vec::new(), Vec::new()));
+      app.push_navigation_stack(ActiveLidarrBlock::BulkEditArtistsPrompt.into());
+      app.data.lidarr_data.selected_block =
+        BlockSelectionState::new(BULK_EDIT_ARTISTS_SELECTION_BLOCKS);
+      app.data.lidarr_data.selected_block.set_index(0, 3);
+
+      BulkEditArtistsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveLidarrBlock::BulkEditArtistsPrompt,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveLidarrBlock::BulkEditArtistsPrompt.into()
+      );
+      assert_eq!(
+        app
+          .data
+          .lidarr_data
+          .bulk_edit_modal
+          .as_ref()
+          .unwrap()
+          .move_files,
+        Some(true)
+      );
+
+      BulkEditArtistsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveLidarrBlock::BulkEditArtistsPrompt,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app
+          .data
+          .lidarr_data
+          .bulk_edit_modal
+          .as_ref()
+          .unwrap()
+          .move_files,
+        Some(false)
+      );
+    }
+
+    #[rstest]
+    #[case(ActiveLidarrBlock::BulkEditArtistsSelectMonitored, 0)]
+    #[case(ActiveLidarrBlock::BulkEditArtistsSelectQualityProfile, 1)]
+    #[case(ActiveLidarrBlock::BulkEditArtistsSelectRootFolder, 2)]
+    #[case(ActiveLidarrBlock::BulkEditArtistsTagsInput, 4)]
+    #[case(ActiveLidarrBlock::BulkEditArtistsSelectApplyTags, 5)]
+    fn test_bulk_edit_artists_prompt_selected_block_submit(
+      #[case] selected_block: ActiveLidarrBlock,
+      #[case] y_index: usize,
+    ) {
+      let mut app = App::test_default();
+      app.data.lidarr_data.bulk_edit_modal = Some(BulkEditModal::new(Vec::new(), Vec::new()));
+      app.push_navigation_stack(ActiveLidarrBlock::BulkEditArtistsPrompt.into());
+      app.data.lidarr_data.selected_block =
+        BlockSelectionState::new(BULK_EDIT_ARTISTS_SELECTION_BLOCKS);
+      app.data.lidarr_data.selected_block.set_index(0, y_index);
+
+      BulkEditArtistsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveLidarrBlock::BulkEditArtistsPrompt,
+        None,
+      )
+      .handle();
+
+      assert_navigation_pushed!(app, selected_block.into());
+      assert_none!(app.data.lidarr_data.prompt_confirm_action);
+      assert_eq!(
+        app.ignore_special_keys_for_textbox_input,
+        selected_block == ActiveLidarrBlock::BulkEditArtistsTagsInput
+      );
+    }
+
+    #[rstest]
+    fn test_bulk_edit_artists_selecting_blocks_submit(
+      #[values(
+        ActiveLidarrBlock::BulkEditArtistsSelectMonitored,
+        ActiveLidarrBlock::BulkEditArtistsSelectQualityProfile,
+        ActiveLidarrBlock::BulkEditArtistsSelectRootFolder,
+        ActiveLidarrBlock::BulkEditArtistsSelectApplyTags,
+        ActiveLidarrBlock::BulkEditArtistsTagsInput
+      )]
+      active_lidarr_block: ActiveLidarrBlock,
+    ) {
+      let mut app = App::test_default();
+      app.ignore_special_keys_for_textbox_input =
+        active_lidarr_block == ActiveLidarrBlock::BulkEditArtistsTagsInput;
+      app.data.lidarr_data.bulk_edit_modal = Some(BulkEditModal::new(Vec::new(), Vec::new()));
+      app.push_navigation_stack(ActiveLidarrBlock::BulkEditArtistsPrompt.into());
+      app.push_navigation_stack(active_lidarr_block.into());
+
+      BulkEditArtistsHandler::new(SUBMIT_KEY, &mut app, active_lidarr_block, None).handle();
+
+      assert_navigation_popped!(app, ActiveLidarrBlock::BulkEditArtistsPrompt.into());
+      assert!(!app.ignore_special_keys_for_textbox_input);
+    }
+  }
+
+  mod test_handle_esc {
+    use super::*;
+
+    const ESC_KEY: Key = DEFAULT_KEYBINDINGS.esc.key;
+
+    #[test]
+    fn test_bulk_edit_artists_tags_input_esc() {
+      let mut app = App::test_default();
+      app.ignore_special_keys_for_textbox_input = true;
+      app.data.lidarr_data.bulk_edit_modal = Some(BulkEditModal::new(Vec::new(), Vec::new()));
+      app.push_navigation_stack(ActiveLidarrBlock::BulkEditArtistsPrompt.into());
+      app.push_navigation_stack(ActiveLidarrBlock::BulkEditArtistsTagsInput.into());
+
+      BulkEditArtistsHandler::new(
+        ESC_KEY,
+        &mut app,
+        ActiveLidarrBlock::BulkEditArtistsTagsInput,
+        None,
+      )
+      .handle();
+
+      assert!(!app.ignore_special_keys_for_textbox_input);
+      assert_navigation_popped!(app, ActiveLidarrBlock::BulkEditArtistsPrompt.into());
+    }
+
+    #[test]
+    fn test_bulk_edit_artists_prompt_esc() {
+      let mut app = app_with_selected_artists();
+      app.data.lidarr_data.prompt_confirm = true;
+      app.push_navigation_stack(ActiveLidarrBlock::Artists.into());
+      app.push_navigation_stack(ActiveLidarrBlock::BulkEditArtistsPrompt.into());
+
+      BulkEditArtistsHandler::new(
+        ESC_KEY,
+        &mut app,
+        ActiveLidarrBlock::BulkEditArtistsPrompt,
+        None,
+      )
+      .handle();
+
+      assert_navigation_popped!(app, ActiveLidarrBlock::Artists.into());
+      assert_modal_absent!(app.data.lidarr_data.bulk_edit_modal);
+      assert!(!app.data.lidarr_data.prompt_confirm);
+      assert!(app.data.lidarr_data.artists.has_multi_selection());
+    }
+
+    #[rstest]
+    fn test_bulk_edit_artists_selecting_blocks_esc(
+      #[values(
+        ActiveLidarrBlock::BulkEditArtistsSelectMonitored,
+        ActiveLidarrBlock::BulkEditArtistsSelectQualityProfile,
+        ActiveLidarrBlock::BulkEditArtistsSelectRootFolder,
+        ActiveLidarrBlock::BulkEditArtistsSelectApplyTags
+      )]
+      active_lidarr_block: ActiveLidarrBlock,
+    ) {
+      let mut app = App::test_default();
+      app.data.lidarr_data.bulk_edit_modal = Some(BulkEditModal::new(Vec::new(), Vec::new()));
+      app.push_navigation_stack(ActiveLidarrBlock::BulkEditArtistsPrompt.into());
+      app.push_navigation_stack(active_lidarr_block.into());
+
+      BulkEditArtistsHandler::new(ESC_KEY, &mut app, active_lidarr_block, None).handle();
+
+      assert_navigation_popped!(app, ActiveLidarrBlock::BulkEditArtistsPrompt.into());
+    }
+  }
+
+  mod test_handle_key_char {
+    use pretty_assertions::{assert_eq, assert_str_eq};
+
+    use super::*;
+
+    #[test]
+    fn test_bulk_edit_artists_tags_input_char_key() {
+      let mut app = App::test_default();
+      app.data.lidarr_data.bulk_edit_modal = Some(BulkEditModal::new(Vec::new(), Vec::new()));
+
+      BulkEditArtistsHandler::new(
+        Key::Char('a'),
+        &mut app,
+        ActiveLidarrBlock::BulkEditArtistsTagsInput,
+        None,
+      )
+      .handle();
+
+      assert_str_eq!(
+        app
+          .data
+          .lidarr_data
+          .bulk_edit_modal
+          .as_ref()
+          .unwrap()
+          .tags
+          .text,
+        "a"
+      );
+    }
+
+    #[test]
+    fn test_bulk_edit_artists_confirm_prompt_confirm() {
+      let mut app = app_with_selected_artists();
+      app.push_navigation_stack(ActiveLidarrBlock::Artists.into());
+      app.push_navigation_stack(ActiveLidarrBlock::BulkEditArtistsPrompt.into());
+      app.data.lidarr_data.selected_block =
+        BlockSelectionState::new(BULK_EDIT_ARTISTS_SELECTION_BLOCKS);
+      app
+        .data
+        .lidarr_data
+        .selected_block
+        .set_index(0, BULK_EDIT_ARTISTS_SELECTION_BLOCKS.len() - 1);
+
+      BulkEditArtistsHandler::new(
+        DEFAULT_KEYBINDINGS.confirm.key,
+        &mut app,
+        ActiveLidarrBlock::BulkEditArtistsPrompt,
+        None,
+      )
+      .handle();
+
+      assert_navigation_popped!(app, ActiveLidarrBlock::Artists.into());
+      assert_eq!(
+        app.data.lidarr_data.prompt_confirm_action,
+        Some(LidarrEvent::BulkEditArtists(
+          expected_bulk_edit_artists_params()
+        ))
+      );
+      assert_modal_absent!(app.data.lidarr_data.bulk_edit_modal);
+      assert!(app.should_refresh);
+    }
+  }
+
+  #[test]
+  fn test_bulk_edit_artists_handler_accepts() {
+    ActiveLidarrBlock::iter().for_each(|active_lidarr_block| {
+      if BULK_EDIT_ARTISTS_BLOCKS.contains(&active_lidarr_block) {
+        assert!(BulkEditArtistsHandler::accepts(active_lidarr_block));
+      } else {
+        assert!(!BulkEditArtistsHandler::accepts(active_lidarr_block));
+      }
+    });
+  }
+
+  #[rstest]
+  fn test_bulk_edit_artists_handler_ignore_special_keys(
+    #[values(true, false)] ignore_special_keys_for_textbox_input: bool,
+  ) {
+    let mut app = App::test_default();
+    app.ignore_special_keys_for_textbox_input = ignore_special_keys_for_textbox_input;
+    let handler = BulkEditArtistsHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveLidarrBlock::default(),
+      None,
+    );
+
+    assert_eq!(
+      handler.ignore_special_keys(),
+      ignore_special_keys_for_textbox_input
+    );
+  }
+
+  #[test]
+  fn test_build_bulk_edit_artists_params() {
+    let mut app = app_with_selected_artists();
+
+    let bulk_edit_artists_params = BulkEditArtistsHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveLidarrBlock::BulkEditArtistsPrompt,
+      None,
+    )
+    .build_bulk_edit_artists_params();
+
+    assert_eq!(
+      bulk_edit_artists_params,
+      expected_bulk_edit_artists_params()
+    );
+    assert_modal_absent!(app.data.lidarr_data.bulk_edit_modal);
+    assert!(!app.data.lidarr_data.artists.has_multi_selection());
+  }
+
+  #[test]
+  fn test_build_bulk_edit_artists_params_defaults_to_no_change() {
+    let mut app = app_with_selected_artists();
+    app.data.lidarr_data.bulk_edit_modal = Some(BulkEditModal::new(
+      vec!["Any".to_owned()],
+      vec!["/nfs/music".to_owned()],
+    ));
+
+    let bulk_edit_artists_params = BulkEditArtistsHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveLidarrBlock::BulkEditArtistsPrompt,
+      None,
+    )
+    .build_bulk_edit_artists_params();
+
+    assert_eq!(
+      bulk_edit_artists_params,
+      BulkEditArtistsParams {
+        artist_ids: vec![1, 3],
+        ..BulkEditArtistsParams::default()
+      }
+    );
+  }
+
+  #[test]
+  fn test_bulk_edit_artists_handler_is_not_ready_when_loading() {
+    let mut app = App::test_default();
+    app.is_loading = true;
+    app.data.lidarr_data.bulk_edit_modal = Some(BulkEditModal::new(Vec::new(), Vec::new()));
+
+    let handler = BulkEditArtistsHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveLidarrBlock::BulkEditArtistsPrompt,
+      None,
+    );
+
+    assert!(!handler.is_ready());
+  }
+
+  #[test]
+  fn test_bulk_edit_artists_handler_is_not_ready_when_bulk_edit_modal_is_none() {
+    let mut app = App::test_default();
+
+    let handler = BulkEditArtistsHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveLidarrBlock::BulkEditArtistsPrompt,
+      None,
+    );
+
+    assert!(!handler.is_ready());
+  }
+
+  #[test]
+  fn test_bulk_edit_artists_handler_is_ready_when_bulk_edit_modal_is_some() {
+    let mut app = App::test_default();
+    app.data.lidarr_data.bulk_edit_modal = Some(BulkEditModal::new(Vec::new(), Vec::new()));
+
+    let handler = BulkEditArtistsHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveLidarrBlock::BulkEditArtistsPrompt,
+      None,
+    );
+
+    assert!(handler.is_ready());
+  }
+}
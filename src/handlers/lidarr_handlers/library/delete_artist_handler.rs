@@ -1,5 +1,5 @@
 use crate::models::Route;
-use crate::models::lidarr_models::DeleteParams;
+use crate::models::lidarr_models::{BulkDeleteArtistsParams, DeleteParams};
 use crate::network::lidarr_network::LidarrEvent;
 use crate::{
   app::App,
@@ -21,16 +21,34 @@ pub(in crate::handlers::lidarr_handlers) struct DeleteArtistHandler<'a, 'b> {
 }
 
 impl DeleteArtistHandler<'_, '_> {
-  fn build_delete_artist_params(&mut self) -> DeleteParams {
-    let id = self.app.data.lidarr_data.artists.current_selection().id;
+  fn build_delete_artist_event(&mut self) -> LidarrEvent {
     let delete_files = self.app.data.lidarr_data.delete_files;
     let add_import_list_exclusion = self.app.data.lidarr_data.add_import_list_exclusion;
     self.app.data.lidarr_data.reset_delete_preferences();
 
-    DeleteParams {
-      id,
-      delete_files,
-      add_import_list_exclusion,
+    if self.app.data.lidarr_data.artists.has_multi_selection() {
+      let artist_ids = self
+        .app
+        .data
+        .lidarr_data
+        .artists
+        .multi_selection()
+        .iter()
+        .map(|artist| artist.id)
+        .collect();
+      self.app.data.lidarr_data.artists.clear_multi_selection();
+
+      LidarrEvent::BulkDeleteArtists(BulkDeleteArtistsParams {
+        artist_ids,
+        delete_files,
+        add_import_list_exclusion,
+      })
+    } else {
+      LidarrEvent::DeleteArtist(DeleteParams {
+        id: self.app.data.lidarr_data.artists.current_selection().id,
+        delete_files,
+        add_import_list_exclusion,
+      })
     }
   }
 }
@@ -96,7 +114,7 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveLidarrBlock> for DeleteArtistHandler<
         ActiveLidarrBlock::DeleteArtistConfirmPrompt => {
           if self.app.data.lidarr_data.prompt_confirm {
             self.app.data.lidarr_data.prompt_confirm_action =
-              Some(LidarrEvent::DeleteArtist(self.build_delete_artist_params()));
+              Some(self.build_delete_artist_event());
             self.app.should_refresh = true;
           } else {
             self.app.data.lidarr_data.reset_delete_preferences();
@@ -131,8 +149,7 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveLidarrBlock> for DeleteArtistHandler<
       && matches_key!(confirm, self.key)
     {
       self.app.data.lidarr_data.prompt_confirm = true;
-      self.app.data.lidarr_data.prompt_confirm_action =
-        Some(LidarrEvent::DeleteArtist(self.build_delete_artist_params()));
+      self.app.data.lidarr_data.prompt_confirm_action = Some(self.build_delete_artist_event());
       self.app.should_refresh = true;
 
       self.app.pop_navigation_stack();
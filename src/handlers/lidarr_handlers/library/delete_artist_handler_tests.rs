@@ -9,8 +9,9 @@ mod tests {
   use crate::event::Key;
   use crate::handlers::KeyEventHandler;
   use crate::handlers::lidarr_handlers::library::delete_artist_handler::DeleteArtistHandler;
-  use crate::models::lidarr_models::{Artist, DeleteParams};
+  use crate::models::lidarr_models::{Artist, BulkDeleteArtistsParams, DeleteParams};
   use crate::models::servarr_data::lidarr::lidarr_data::{ActiveLidarrBlock, DELETE_ARTIST_BLOCKS};
+  use crate::network::lidarr_network::LidarrEvent;
 
   mod test_handle_scroll_up_and_down {
     use pretty_assertions::assert_eq;
@@ -350,7 +351,7 @@ mod tests {
   }
 
   #[test]
-  fn test_build_delete_artist_params() {
+  fn test_build_delete_artist_event() {
     let mut app = App::test_default();
     app
       .data
@@ -365,15 +366,61 @@ mod tests {
       add_import_list_exclusion: true,
     };
 
-    let delete_artist_params = DeleteArtistHandler::new(
+    let delete_artist_event = DeleteArtistHandler::new(
       DEFAULT_KEYBINDINGS.esc.key,
       &mut app,
       ActiveLidarrBlock::DeleteArtistPrompt,
       None,
     )
-    .build_delete_artist_params();
+    .build_delete_artist_event();
 
-    assert_eq!(delete_artist_params, expected_delete_artist_params);
+    assert_eq!(
+      delete_artist_event,
+      LidarrEvent::DeleteArtist(expected_delete_artist_params)
+    );
+    assert!(!app.data.lidarr_data.delete_files);
+    assert!(!app.data.lidarr_data.add_import_list_exclusion);
+  }
+
+  #[test]
+  fn test_build_delete_artist_event_with_multi_selection() {
+    let mut app = App::test_default();
+    app.data.lidarr_data.artists.set_items(vec![
+      Artist {
+        id: 1,
+        ..Artist::default()
+      },
+      Artist {
+        id: 2,
+        ..Artist::default()
+      },
+    ]);
+    app
+      .data
+      .lidarr_data
+      .artists
+      .toggle_select_all(|artist| artist.id);
+    app.data.lidarr_data.delete_files = true;
+    app.data.lidarr_data.add_import_list_exclusion = true;
+    let expected_bulk_delete_artists_params = BulkDeleteArtistsParams {
+      artist_ids: vec![1, 2],
+      delete_files: true,
+      add_import_list_exclusion: true,
+    };
+
+    let delete_artist_event = DeleteArtistHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveLidarrBlock::DeleteArtistPrompt,
+      None,
+    )
+    .build_delete_artist_event();
+
+    assert_eq!(
+      delete_artist_event,
+      LidarrEvent::BulkDeleteArtists(expected_bulk_delete_artists_params)
+    );
+    assert!(!app.data.lidarr_data.artists.has_multi_selection());
     assert!(!app.data.lidarr_data.delete_files);
     assert!(!app.data.lidarr_data.add_import_list_exclusion);
   }
@@ -14,8 +14,9 @@ mod tests {
   use crate::models::lidarr_models::{Album, Artist, ArtistStatistics, ArtistStatus};
   use crate::models::servarr_data::lidarr::lidarr_data::{
     ADD_ARTIST_BLOCKS, ALBUM_DETAILS_BLOCKS, ARTIST_DETAILS_BLOCKS, ActiveLidarrBlock,
-    DELETE_ALBUM_BLOCKS, DELETE_ARTIST_BLOCKS, EDIT_ARTIST_BLOCKS, EDIT_ARTIST_SELECTION_BLOCKS,
-    LIBRARY_BLOCKS, TRACK_DETAILS_BLOCKS,
+    BULK_EDIT_ARTISTS_BLOCKS, BULK_EDIT_ARTISTS_SELECTION_BLOCKS, DELETE_ALBUM_BLOCKS,
+    DELETE_ARTIST_BLOCKS, EDIT_ARTIST_BLOCKS, EDIT_ARTIST_SELECTION_BLOCKS, LIBRARY_BLOCKS,
+    TRACK_DETAILS_BLOCKS,
   };
   use crate::models::servarr_data::lidarr::modals::EditArtistModal;
   use crate::network::lidarr_network::LidarrEvent;
@@ -29,6 +30,7 @@ mod tests {
     let mut library_handler_blocks = Vec::new();
     library_handler_blocks.extend(LIBRARY_BLOCKS);
     library_handler_blocks.extend(ARTIST_DETAILS_BLOCKS);
+    library_handler_blocks.extend(BULK_EDIT_ARTISTS_BLOCKS);
     library_handler_blocks.extend(DELETE_ARTIST_BLOCKS);
     library_handler_blocks.extend(DELETE_ALBUM_BLOCKS);
     library_handler_blocks.extend(EDIT_ARTIST_BLOCKS);
@@ -588,6 +590,25 @@ mod tests {
     assert_eq!(app.get_current_route(), ActiveLidarrBlock::Artists.into());
   }
 
+  #[rstest]
+  fn test_delegates_bulk_edit_artists_blocks_to_bulk_edit_artists_handler(
+    #[values(
+      ActiveLidarrBlock::BulkEditArtistsPrompt,
+      ActiveLidarrBlock::BulkEditArtistsSelectApplyTags,
+      ActiveLidarrBlock::BulkEditArtistsSelectMonitored,
+      ActiveLidarrBlock::BulkEditArtistsSelectQualityProfile,
+      ActiveLidarrBlock::BulkEditArtistsSelectRootFolder,
+      ActiveLidarrBlock::BulkEditArtistsTagsInput
+    )]
+    active_lidarr_block: ActiveLidarrBlock,
+  ) {
+    test_handler_delegation!(
+      LibraryHandler,
+      ActiveLidarrBlock::Artists,
+      active_lidarr_block
+    );
+  }
+
   #[rstest]
   fn test_delegates_edit_artist_blocks_to_edit_artist_handler(
     #[values(
@@ -725,6 +746,43 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_edit_key_with_multi_selection_opens_bulk_edit_prompt() {
+    let mut app = App::test_default();
+    app.data.lidarr_data.artists.set_items(vec![
+      Artist {
+        id: 1,
+        ..Artist::default()
+      },
+      Artist {
+        id: 2,
+        ..Artist::default()
+      },
+    ]);
+    app
+      .data
+      .lidarr_data
+      .artists
+      .toggle_select_all(|artist| artist.id);
+    app.push_navigation_stack(ActiveLidarrBlock::Artists.into());
+
+    LibraryHandler::new(
+      DEFAULT_KEYBINDINGS.edit.key,
+      &mut app,
+      ActiveLidarrBlock::Artists,
+      None,
+    )
+    .handle();
+
+    assert_navigation_pushed!(app, ActiveLidarrBlock::BulkEditArtistsPrompt.into());
+    assert_modal_present!(app.data.lidarr_data.bulk_edit_modal);
+    assert_modal_absent!(app.data.lidarr_data.edit_artist_modal);
+    assert_eq!(
+      app.data.lidarr_data.selected_block.blocks,
+      BULK_EDIT_ARTISTS_SELECTION_BLOCKS
+    );
+  }
+
   #[test]
   fn test_edit_key_no_op_when_not_ready() {
     let mut app = App::test_default();
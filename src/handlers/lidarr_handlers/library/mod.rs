@@ -7,9 +7,10 @@ use crate::{
     BlockSelectionState, HorizontallyScrollableText,
     lidarr_models::Artist,
     servarr_data::lidarr::lidarr_data::{
-      ActiveLidarrBlock, DELETE_ARTIST_SELECTION_BLOCKS, EDIT_ARTIST_SELECTION_BLOCKS,
-      LIBRARY_BLOCKS,
+      ActiveLidarrBlock, BULK_EDIT_ARTISTS_SELECTION_BLOCKS, DELETE_ARTIST_SELECTION_BLOCKS,
+      EDIT_ARTIST_SELECTION_BLOCKS, LIBRARY_BLOCKS,
     },
+    servarr_data::modals::BulkEditModal,
     stateful_table::SortOption,
   },
   network::lidarr_network::LidarrEvent,
@@ -21,12 +22,14 @@ use crate::handlers::table_handler::{TableHandlingConfig, handle_table};
 mod add_artist_handler;
 mod album_details_handler;
 mod artist_details_handler;
+mod bulk_edit_artists_handler;
 mod delete_album_handler;
 mod delete_artist_handler;
 mod edit_artist_handler;
 mod track_details_handler;
 
 use crate::handlers::lidarr_handlers::library::album_details_handler::AlbumDetailsHandler;
+use crate::handlers::lidarr_handlers::library::bulk_edit_artists_handler::BulkEditArtistsHandler;
 use crate::handlers::lidarr_handlers::library::delete_album_handler::DeleteAlbumHandler;
 use crate::handlers::lidarr_handlers::library::track_details_handler::TrackDetailsHandler;
 use crate::models::Route;
@@ -62,7 +65,8 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveLidarrBlock> for LibraryHandler<'a, '
       .search_field_fn(|artist| &artist.artist_name.text)
       .filtering_block(ActiveLidarrBlock::FilterArtists.into())
       .filter_error_block(ActiveLidarrBlock::FilterArtistsError.into())
-      .filter_field_fn(|artist| &artist.artist_name.text);
+      .filter_field_fn(|artist| &artist.artist_name.text)
+      .multi_select_id_fn(|artist| artist.id);
 
     if !handle_table(
       self,
@@ -74,6 +78,10 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveLidarrBlock> for LibraryHandler<'a, '
           AddArtistHandler::new(self.key, self.app, self.active_lidarr_block, self.context)
             .handle();
         }
+        _ if BulkEditArtistsHandler::accepts(self.active_lidarr_block) => {
+          BulkEditArtistsHandler::new(self.key, self.app, self.active_lidarr_block, self.context)
+            .handle();
+        }
         _ if DeleteArtistHandler::accepts(self.active_lidarr_block) => {
           DeleteArtistHandler::new(self.key, self.app, self.active_lidarr_block, self.context)
             .handle();
@@ -105,6 +113,7 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveLidarrBlock> for LibraryHandler<'a, '
 
   fn accepts(active_block: ActiveLidarrBlock) -> bool {
     AddArtistHandler::accepts(active_block)
+      || BulkEditArtistsHandler::accepts(active_block)
       || DeleteArtistHandler::accepts(active_block)
       || DeleteAlbumHandler::accepts(active_block)
       || EditArtistHandler::accepts(active_block)
@@ -217,6 +226,15 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveLidarrBlock> for LibraryHandler<'a, '
             .app
             .pop_and_push_navigation_stack(self.active_lidarr_block.into());
         }
+        _ if matches_key!(edit, key) && self.app.data.lidarr_data.artists.has_multi_selection() => {
+          self
+            .app
+            .push_navigation_stack(ActiveLidarrBlock::BulkEditArtistsPrompt.into());
+          self.app.data.lidarr_data.bulk_edit_modal =
+            Some(BulkEditModal::from(&self.app.data.lidarr_data));
+          self.app.data.lidarr_data.selected_block =
+            BlockSelectionState::new(BULK_EDIT_ARTISTS_SELECTION_BLOCKS);
+        }
         _ if matches_key!(edit, key) => {
           self.app.data.lidarr_data.edit_artist_modal = Some((&self.app.data.lidarr_data).into());
           self
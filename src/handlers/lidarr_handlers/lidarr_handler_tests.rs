@@ -52,13 +52,17 @@ mod tests {
   }
 
   #[rstest]
-  #[case(0, ActiveLidarrBlock::System, ActiveLidarrBlock::Downloads)]
+  #[case(0, ActiveLidarrBlock::WantedMissing, ActiveLidarrBlock::Downloads)]
   #[case(1, ActiveLidarrBlock::Artists, ActiveLidarrBlock::Blocklist)]
   #[case(2, ActiveLidarrBlock::Downloads, ActiveLidarrBlock::History)]
   #[case(3, ActiveLidarrBlock::Blocklist, ActiveLidarrBlock::RootFolders)]
   #[case(4, ActiveLidarrBlock::History, ActiveLidarrBlock::Indexers)]
-  #[case(5, ActiveLidarrBlock::RootFolders, ActiveLidarrBlock::System)]
-  #[case(6, ActiveLidarrBlock::Indexers, ActiveLidarrBlock::Artists)]
+  #[case(5, ActiveLidarrBlock::RootFolders, ActiveLidarrBlock::DownloadClients)]
+  #[case(6, ActiveLidarrBlock::Indexers, ActiveLidarrBlock::QualityProfiles)]
+  #[case(7, ActiveLidarrBlock::DownloadClients, ActiveLidarrBlock::System)]
+  #[case(8, ActiveLidarrBlock::QualityProfiles, ActiveLidarrBlock::Calendar)]
+  #[case(9, ActiveLidarrBlock::System, ActiveLidarrBlock::WantedMissing)]
+  #[case(10, ActiveLidarrBlock::Calendar, ActiveLidarrBlock::Artists)]
   fn test_lidarr_handler_change_tab_left_right_keys(
     #[case] index: usize,
     #[case] left_block: ActiveLidarrBlock,
@@ -87,13 +91,17 @@ mod tests {
   }
 
   #[rstest]
-  #[case(0, ActiveLidarrBlock::System, ActiveLidarrBlock::Downloads)]
+  #[case(0, ActiveLidarrBlock::WantedMissing, ActiveLidarrBlock::Downloads)]
   #[case(1, ActiveLidarrBlock::Artists, ActiveLidarrBlock::Blocklist)]
   #[case(2, ActiveLidarrBlock::Downloads, ActiveLidarrBlock::History)]
   #[case(3, ActiveLidarrBlock::Blocklist, ActiveLidarrBlock::RootFolders)]
   #[case(4, ActiveLidarrBlock::History, ActiveLidarrBlock::Indexers)]
-  #[case(5, ActiveLidarrBlock::RootFolders, ActiveLidarrBlock::System)]
-  #[case(6, ActiveLidarrBlock::Indexers, ActiveLidarrBlock::Artists)]
+  #[case(5, ActiveLidarrBlock::RootFolders, ActiveLidarrBlock::DownloadClients)]
+  #[case(6, ActiveLidarrBlock::Indexers, ActiveLidarrBlock::QualityProfiles)]
+  #[case(7, ActiveLidarrBlock::DownloadClients, ActiveLidarrBlock::System)]
+  #[case(8, ActiveLidarrBlock::QualityProfiles, ActiveLidarrBlock::Calendar)]
+  #[case(9, ActiveLidarrBlock::System, ActiveLidarrBlock::WantedMissing)]
+  #[case(10, ActiveLidarrBlock::Calendar, ActiveLidarrBlock::Artists)]
   fn test_lidarr_handler_change_tab_left_right_keys_alt_navigation(
     #[case] index: usize,
     #[case] left_block: ActiveLidarrBlock,
@@ -128,7 +136,11 @@ mod tests {
   #[case(3, ActiveLidarrBlock::History)]
   #[case(4, ActiveLidarrBlock::RootFolders)]
   #[case(5, ActiveLidarrBlock::Indexers)]
-  #[case(6, ActiveLidarrBlock::System)]
+  #[case(6, ActiveLidarrBlock::DownloadClients)]
+  #[case(7, ActiveLidarrBlock::QualityProfiles)]
+  #[case(8, ActiveLidarrBlock::System)]
+  #[case(9, ActiveLidarrBlock::Calendar)]
+  #[case(10, ActiveLidarrBlock::WantedMissing)]
   fn test_lidarr_handler_change_tab_left_right_keys_alt_navigation_no_op_when_ignoring_quit_key(
     #[case] index: usize,
     #[case] block: ActiveLidarrBlock,
@@ -243,7 +255,9 @@ mod tests {
     #[values(
       ActiveLidarrBlock::RootFolders,
       ActiveLidarrBlock::AddRootFolderPrompt,
-      ActiveLidarrBlock::DeleteRootFolderPrompt
+      ActiveLidarrBlock::DeleteRootFolderPrompt,
+      ActiveLidarrBlock::MediaManagementSettingsPrompt,
+      ActiveLidarrBlock::MediaManagementFieldInput
     )]
     active_lidarr_block: ActiveLidarrBlock,
   ) {
@@ -275,6 +289,41 @@ mod tests {
     );
   }
 
+  #[rstest]
+  fn test_delegates_download_clients_blocks_to_download_clients_handler(
+    #[values(
+      ActiveLidarrBlock::DownloadClients,
+      ActiveLidarrBlock::DeleteDownloadClientPrompt,
+      ActiveLidarrBlock::TestDownloadClient,
+      ActiveLidarrBlock::TestAllDownloadClients,
+      ActiveLidarrBlock::AddDownloadClientSelectImplementation,
+      ActiveLidarrBlock::DownloadClientPrompt,
+      ActiveLidarrBlock::DownloadClientFieldInput
+    )]
+    active_lidarr_block: ActiveLidarrBlock,
+  ) {
+    test_handler_delegation!(
+      LidarrHandler,
+      ActiveLidarrBlock::DownloadClients,
+      active_lidarr_block
+    );
+  }
+
+  #[rstest]
+  fn test_delegates_quality_profiles_blocks_to_quality_profiles_handler(
+    #[values(
+      ActiveLidarrBlock::QualityProfiles,
+      ActiveLidarrBlock::EditQualityProfile
+    )]
+    active_lidarr_block: ActiveLidarrBlock,
+  ) {
+    test_handler_delegation!(
+      LidarrHandler,
+      ActiveLidarrBlock::QualityProfiles,
+      active_lidarr_block
+    );
+  }
+
   #[rstest]
   fn test_delegates_system_blocks_to_system_handler(
     #[values(
@@ -293,4 +342,35 @@ mod tests {
       active_sonarr_block
     );
   }
+
+  #[rstest]
+  fn test_delegates_calendar_blocks_to_calendar_handler(
+    #[values(ActiveLidarrBlock::Calendar, ActiveLidarrBlock::CalendarAgenda)]
+    active_lidarr_block: ActiveLidarrBlock,
+  ) {
+    test_handler_delegation!(LidarrHandler, active_lidarr_block, active_lidarr_block);
+  }
+
+  #[rstest]
+  fn test_delegates_wanted_table_blocks_to_wanted_handler(
+    #[values(ActiveLidarrBlock::WantedMissing, ActiveLidarrBlock::WantedCutoffUnmet)]
+    active_lidarr_block: ActiveLidarrBlock,
+  ) {
+    test_handler_delegation!(LidarrHandler, active_lidarr_block, active_lidarr_block);
+  }
+
+  #[rstest]
+  fn test_delegates_wanted_prompt_blocks_to_wanted_handler(
+    #[values(
+      ActiveLidarrBlock::WantedSearchAllPrompt,
+      ActiveLidarrBlock::WantedSearchSelectedPrompt
+    )]
+    active_lidarr_block: ActiveLidarrBlock,
+  ) {
+    test_handler_delegation!(
+      LidarrHandler,
+      ActiveLidarrBlock::WantedMissing,
+      active_lidarr_block
+    );
+  }
 }
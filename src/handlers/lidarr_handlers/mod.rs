@@ -4,21 +4,29 @@ use library::LibraryHandler;
 
 use super::KeyEventHandler;
 use crate::handlers::lidarr_handlers::blocklist::BlocklistHandler;
+use crate::handlers::lidarr_handlers::calendar::CalendarHandler;
+use crate::handlers::lidarr_handlers::download_clients::DownloadClientsHandler;
 use crate::handlers::lidarr_handlers::downloads::DownloadsHandler;
+use crate::handlers::lidarr_handlers::quality_profiles::QualityProfilesHandler;
 use crate::handlers::lidarr_handlers::root_folders::RootFoldersHandler;
 use crate::handlers::lidarr_handlers::system::SystemHandler;
+use crate::handlers::lidarr_handlers::wanted::WantedHandler;
 use crate::models::Route;
 use crate::{
   app::App, event::Key, matches_key, models::servarr_data::lidarr::lidarr_data::ActiveLidarrBlock,
 };
 
 mod blocklist;
+mod calendar;
+mod download_clients;
 mod downloads;
 mod history;
 mod indexers;
 mod library;
+mod quality_profiles;
 mod root_folders;
 mod system;
+mod wanted;
 
 #[cfg(test)]
 #[path = "lidarr_handler_tests.rs"]
@@ -53,9 +61,23 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveLidarrBlock> for LidarrHandler<'a, 'b
       _ if IndexersHandler::accepts(self.active_lidarr_block) => {
         IndexersHandler::new(self.key, self.app, self.active_lidarr_block, self.context).handle();
       }
+      _ if DownloadClientsHandler::accepts(self.active_lidarr_block) => {
+        DownloadClientsHandler::new(self.key, self.app, self.active_lidarr_block, self.context)
+          .handle();
+      }
+      _ if QualityProfilesHandler::accepts(self.active_lidarr_block) => {
+        QualityProfilesHandler::new(self.key, self.app, self.active_lidarr_block, self.context)
+          .handle();
+      }
       _ if SystemHandler::accepts(self.active_lidarr_block) => {
         SystemHandler::new(self.key, self.app, self.active_lidarr_block, self.context).handle();
       }
+      _ if CalendarHandler::accepts(self.active_lidarr_block) => {
+        CalendarHandler::new(self.key, self.app, self.active_lidarr_block, self.context).handle();
+      }
+      _ if WantedHandler::accepts(self.active_lidarr_block) => {
+        WantedHandler::new(self.key, self.app, self.active_lidarr_block, self.context).handle();
+      }
       _ => self.handle_key_event(),
     }
   }
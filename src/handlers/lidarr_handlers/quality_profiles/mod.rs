@@ -0,0 +1,176 @@
+use crate::app::App;
+use crate::event::Key;
+use crate::handlers::lidarr_handlers::handle_change_tab_left_right_keys;
+use crate::handlers::table_handler::{TableHandlingConfig, handle_table};
+use crate::handlers::{KeyEventHandler, handle_clear_errors};
+use crate::matches_key;
+use crate::models::servarr_data::lidarr::lidarr_data::{
+  ActiveLidarrBlock, QUALITY_PROFILES_BLOCKS,
+};
+use crate::models::servarr_data::modals::QualityProfileModal;
+use crate::models::servarr_models::EditQualityProfileParams;
+use crate::models::{Route, Scrollable};
+use crate::network::lidarr_network::LidarrEvent;
+
+#[cfg(test)]
+#[path = "quality_profiles_handler_tests.rs"]
+mod quality_profiles_handler_tests;
+
+pub(super) struct QualityProfilesHandler<'a, 'b> {
+  key: Key,
+  app: &'a mut App<'b>,
+  active_lidarr_block: ActiveLidarrBlock,
+  _context: Option<ActiveLidarrBlock>,
+}
+
+impl QualityProfilesHandler<'_, '_> {
+  fn quality_profile_modal(&mut self) -> &mut QualityProfileModal {
+    self
+      .app
+      .data
+      .lidarr_data
+      .quality_profile_modal
+      .as_mut()
+      .expect("Quality profile modal is undefined")
+  }
+}
+
+impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveLidarrBlock> for QualityProfilesHandler<'a, 'b> {
+  fn handle(&mut self) {
+    let quality_profiles_table_handling_config =
+      TableHandlingConfig::new(ActiveLidarrBlock::QualityProfiles.into());
+
+    if !handle_table(
+      self,
+      |app| &mut app.data.lidarr_data.quality_profiles,
+      quality_profiles_table_handling_config,
+    ) {
+      self.handle_key_event();
+    }
+  }
+
+  fn accepts(active_block: ActiveLidarrBlock) -> bool {
+    QUALITY_PROFILES_BLOCKS.contains(&active_block)
+  }
+
+  fn ignore_special_keys(&self) -> bool {
+    self.app.ignore_special_keys_for_textbox_input
+  }
+
+  fn new(
+    key: Key,
+    app: &'a mut App<'b>,
+    active_block: ActiveLidarrBlock,
+    context: Option<ActiveLidarrBlock>,
+  ) -> QualityProfilesHandler<'a, 'b> {
+    QualityProfilesHandler {
+      key,
+      app,
+      active_lidarr_block: active_block,
+      _context: context,
+    }
+  }
+
+  fn get_key(&self) -> Key {
+    self.key
+  }
+
+  fn is_ready(&self) -> bool {
+    !self.app.is_loading && !self.app.data.lidarr_data.quality_profiles.is_empty()
+  }
+
+  fn handle_scroll_up(&mut self) {
+    if self.active_lidarr_block == ActiveLidarrBlock::EditQualityProfile {
+      self.quality_profile_modal().items.scroll_up();
+    }
+  }
+
+  fn handle_scroll_down(&mut self) {
+    if self.active_lidarr_block == ActiveLidarrBlock::EditQualityProfile {
+      self.quality_profile_modal().items.scroll_down();
+    }
+  }
+
+  fn handle_home(&mut self) {
+    if self.active_lidarr_block == ActiveLidarrBlock::EditQualityProfile {
+      self.quality_profile_modal().items.scroll_to_top();
+    }
+  }
+
+  fn handle_end(&mut self) {
+    if self.active_lidarr_block == ActiveLidarrBlock::EditQualityProfile {
+      self.quality_profile_modal().items.scroll_to_bottom();
+    }
+  }
+
+  fn handle_delete(&mut self) {}
+
+  fn handle_left_right_action(&mut self) {
+    if self.active_lidarr_block == ActiveLidarrBlock::QualityProfiles {
+      handle_change_tab_left_right_keys(self.app, self.key);
+    }
+  }
+
+  fn handle_submit(&mut self) {
+    if self.active_lidarr_block == ActiveLidarrBlock::QualityProfiles {
+      self.app.data.lidarr_data.quality_profile_modal = Some(QualityProfileModal::from(
+        self
+          .app
+          .data
+          .lidarr_data
+          .quality_profiles
+          .current_selection(),
+      ));
+      self
+        .app
+        .push_navigation_stack(ActiveLidarrBlock::EditQualityProfile.into());
+    }
+  }
+
+  fn handle_esc(&mut self) {
+    match self.active_lidarr_block {
+      ActiveLidarrBlock::EditQualityProfile => {
+        self.app.pop_navigation_stack();
+        self.app.data.lidarr_data.prompt_confirm = false;
+        self.app.data.lidarr_data.quality_profile_modal = None;
+      }
+      _ => handle_clear_errors(self.app),
+    }
+  }
+
+  fn handle_char_key_event(&mut self) {
+    let key = self.key;
+    match self.active_lidarr_block {
+      ActiveLidarrBlock::QualityProfiles if matches_key!(refresh, key) => {
+        self.app.should_refresh = true;
+      }
+      ActiveLidarrBlock::EditQualityProfile => match key {
+        _ if matches_key!(select, key) => self.quality_profile_modal().toggle_selected_allowed(),
+        _ if matches_key!(move_up, key) => self.quality_profile_modal().move_selected_up(),
+        _ if matches_key!(move_down, key) => self.quality_profile_modal().move_selected_down(),
+        _ if matches_key!(group, key) => self.quality_profile_modal().toggle_selected_group(),
+        _ if matches_key!(cutoff, key) => self.quality_profile_modal().set_cutoff_to_selected(),
+        _ if matches_key!(confirm, key) => {
+          let edit_quality_profile_params =
+            EditQualityProfileParams::from(&*self.quality_profile_modal());
+          self.app.data.lidarr_data.prompt_confirm = true;
+          self.app.data.lidarr_data.prompt_confirm_action =
+            Some(LidarrEvent::EditQualityProfile(edit_quality_profile_params));
+          self.app.should_refresh = true;
+
+          self.app.pop_navigation_stack();
+        }
+        _ => (),
+      },
+      _ => (),
+    }
+  }
+
+  fn app_mut(&mut self) -> &mut App<'b> {
+    self.app
+  }
+
+  fn current_route(&self) -> Route {
+    self.app.get_current_route()
+  }
+}
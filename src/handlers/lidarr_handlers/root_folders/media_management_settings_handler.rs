@@ -0,0 +1,193 @@
+use crate::app::App;
+use crate::event::Key;
+use crate::handlers::KeyEventHandler;
+use crate::models::servarr_data::lidarr::lidarr_data::{
+  ActiveLidarrBlock, MEDIA_MANAGEMENT_SETTINGS_BLOCKS,
+};
+use crate::models::servarr_data::modals::{MediaManagementModal, SchemaFormFieldKind};
+use crate::models::{Route, Scrollable};
+use crate::network::lidarr_network::LidarrEvent;
+use crate::{handle_text_box_keys, handle_text_box_left_right_keys, matches_key};
+
+#[cfg(test)]
+#[path = "media_management_settings_handler_tests.rs"]
+mod media_management_settings_handler_tests;
+
+pub(super) struct MediaManagementSettingsHandler<'a, 'b> {
+  key: Key,
+  app: &'a mut App<'b>,
+  active_lidarr_block: ActiveLidarrBlock,
+  _context: Option<ActiveLidarrBlock>,
+}
+
+impl MediaManagementSettingsHandler<'_, '_> {
+  fn media_management_modal(&mut self) -> &mut MediaManagementModal {
+    self
+      .app
+      .data
+      .lidarr_data
+      .media_management_modal
+      .as_mut()
+      .expect("Media Management Modal is None")
+  }
+}
+
+impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveLidarrBlock> for MediaManagementSettingsHandler<'a, 'b> {
+  fn accepts(active_block: ActiveLidarrBlock) -> bool {
+    MEDIA_MANAGEMENT_SETTINGS_BLOCKS.contains(&active_block)
+  }
+
+  fn ignore_special_keys(&self) -> bool {
+    self.app.ignore_special_keys_for_textbox_input
+  }
+
+  fn new(
+    key: Key,
+    app: &'a mut App<'b>,
+    active_block: ActiveLidarrBlock,
+    _context: Option<ActiveLidarrBlock>,
+  ) -> MediaManagementSettingsHandler<'a, 'b> {
+    MediaManagementSettingsHandler {
+      key,
+      app,
+      active_lidarr_block: active_block,
+      _context,
+    }
+  }
+
+  fn get_key(&self) -> Key {
+    self.key
+  }
+
+  fn is_ready(&self) -> bool {
+    !self.app.is_loading && self.app.data.lidarr_data.media_management_modal.is_some()
+  }
+
+  fn handle_scroll_up(&mut self) {
+    if self.active_lidarr_block == ActiveLidarrBlock::MediaManagementSettingsPrompt {
+      self.media_management_modal().fields.scroll_up();
+    }
+  }
+
+  fn handle_scroll_down(&mut self) {
+    if self.active_lidarr_block == ActiveLidarrBlock::MediaManagementSettingsPrompt {
+      self.media_management_modal().fields.scroll_down();
+    }
+  }
+
+  fn handle_home(&mut self) {
+    match self.active_lidarr_block {
+      ActiveLidarrBlock::MediaManagementSettingsPrompt => {
+        self.media_management_modal().fields.scroll_to_top()
+      }
+      ActiveLidarrBlock::MediaManagementFieldInput => self
+        .media_management_modal()
+        .current_field_mut()
+        .value
+        .scroll_home(),
+      _ => (),
+    }
+  }
+
+  fn handle_end(&mut self) {
+    match self.active_lidarr_block {
+      ActiveLidarrBlock::MediaManagementSettingsPrompt => {
+        self.media_management_modal().fields.scroll_to_bottom()
+      }
+      ActiveLidarrBlock::MediaManagementFieldInput => self
+        .media_management_modal()
+        .current_field_mut()
+        .value
+        .reset_offset(),
+      _ => (),
+    }
+  }
+
+  fn handle_delete(&mut self) {}
+
+  fn handle_left_right_action(&mut self) {
+    if self.active_lidarr_block == ActiveLidarrBlock::MediaManagementFieldInput {
+      handle_text_box_left_right_keys!(
+        self,
+        self.key,
+        self.media_management_modal().current_field_mut().value
+      );
+    }
+  }
+
+  fn handle_submit(&mut self) {
+    match self.active_lidarr_block {
+      ActiveLidarrBlock::MediaManagementSettingsPrompt => {
+        let media_management_modal = self.media_management_modal();
+        if media_management_modal.current_field_mut().kind == SchemaFormFieldKind::Checkbox {
+          media_management_modal.toggle_current_field();
+          self.app.should_refresh = true;
+        } else {
+          self
+            .app
+            .push_navigation_stack(ActiveLidarrBlock::MediaManagementFieldInput.into());
+          self.app.ignore_special_keys_for_textbox_input = true;
+        }
+      }
+      ActiveLidarrBlock::MediaManagementFieldInput => {
+        self.app.pop_navigation_stack();
+        self.app.ignore_special_keys_for_textbox_input = false;
+      }
+      _ => (),
+    }
+  }
+
+  fn handle_esc(&mut self) {
+    match self.active_lidarr_block {
+      ActiveLidarrBlock::MediaManagementSettingsPrompt => {
+        self.app.pop_navigation_stack();
+        self.app.data.lidarr_data.prompt_confirm = false;
+        self.app.data.lidarr_data.media_management_modal = None;
+        self.app.data.lidarr_data.naming_examples = None;
+      }
+      ActiveLidarrBlock::MediaManagementFieldInput => {
+        self.app.pop_navigation_stack();
+        self.app.ignore_special_keys_for_textbox_input = false;
+      }
+      _ => self.app.pop_navigation_stack(),
+    }
+  }
+
+  fn handle_char_key_event(&mut self) {
+    match self.active_lidarr_block {
+      ActiveLidarrBlock::MediaManagementFieldInput => {
+        handle_text_box_keys!(
+          self,
+          self.key,
+          self.media_management_modal().current_field_mut().value
+        );
+      }
+      ActiveLidarrBlock::MediaManagementSettingsPrompt if matches_key!(confirm, self.key) => {
+        let media_management_modal = self
+          .app
+          .data
+          .lidarr_data
+          .media_management_modal
+          .take()
+          .expect("Media Management Modal is None");
+        self.app.data.lidarr_data.naming_examples = None;
+        self.app.data.lidarr_data.prompt_confirm = true;
+        self.app.data.lidarr_data.prompt_confirm_action = Some(
+          LidarrEvent::EditMediaManagementSettings(media_management_modal.settings()),
+        );
+        self.app.should_refresh = true;
+
+        self.app.pop_navigation_stack();
+      }
+      _ => (),
+    }
+  }
+
+  fn app_mut(&mut self) -> &mut App<'b> {
+    self.app
+  }
+
+  fn current_route(&self) -> Route {
+    self.app.get_current_route()
+  }
+}
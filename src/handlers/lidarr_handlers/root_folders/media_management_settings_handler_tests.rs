@@ -0,0 +1,514 @@
+#[cfg(test)]
+mod tests {
+  use crate::app::App;
+  use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+  use crate::assert_modal_absent;
+  use crate::assert_modal_present;
+  use crate::assert_navigation_popped;
+  use crate::assert_navigation_pushed;
+  use crate::event::Key;
+  use crate::handlers::KeyEventHandler;
+  use crate::handlers::lidarr_handlers::root_folders::media_management_settings_handler::MediaManagementSettingsHandler;
+  use crate::models::servarr_data::lidarr::lidarr_data::{
+    ActiveLidarrBlock, MEDIA_MANAGEMENT_NAMING_FIELDS, MEDIA_MANAGEMENT_SETTINGS_BLOCKS,
+  };
+  use crate::models::servarr_data::modals::{MediaManagementModal, SchemaFormField};
+  use crate::network::lidarr_network::LidarrEvent;
+  use crate::network::lidarr_network::lidarr_network_test_utils::test_utils::{
+    media_management_settings, naming_examples,
+  };
+  use pretty_assertions::assert_eq;
+  use rstest::rstest;
+  use strum::IntoEnumIterator;
+
+  fn media_management_modal() -> MediaManagementModal {
+    MediaManagementModal::new(
+      &media_management_settings(),
+      &MEDIA_MANAGEMENT_NAMING_FIELDS,
+    )
+  }
+
+  fn select_field(app: &mut App<'_>, name: &str) {
+    let fields = &mut app
+      .data
+      .lidarr_data
+      .media_management_modal
+      .as_mut()
+      .unwrap()
+      .fields;
+    let index = fields
+      .items
+      .iter()
+      .position(|field| field.name == name)
+      .unwrap();
+    fields.state.select(Some(index));
+  }
+
+  fn current_field(app: &App<'_>) -> SchemaFormField {
+    app
+      .data
+      .lidarr_data
+      .media_management_modal
+      .as_ref()
+      .unwrap()
+      .fields
+      .current_selection()
+      .clone()
+  }
+
+  mod test_handle_scroll_up_and_down {
+    use pretty_assertions::assert_str_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_media_management_settings_prompt_scroll(#[values(Key::Up, Key::Down)] key: Key) {
+      let mut app = App::test_default();
+      app.data.lidarr_data.media_management_modal = Some(media_management_modal());
+
+      MediaManagementSettingsHandler::new(
+        key,
+        &mut app,
+        ActiveLidarrBlock::MediaManagementSettingsPrompt,
+        None,
+      )
+      .handle();
+
+      if key == Key::Up {
+        assert_str_eq!(
+          current_field(&app).name,
+          "mediaManagement.extraFileExtensions"
+        );
+      } else {
+        assert_str_eq!(current_field(&app).name, "naming.replaceIllegalCharacters");
+      }
+    }
+
+    #[rstest]
+    fn test_media_management_settings_prompt_scroll_no_op_when_not_ready(
+      #[values(Key::Up, Key::Down)] key: Key,
+    ) {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.data.lidarr_data.media_management_modal = Some(media_management_modal());
+
+      MediaManagementSettingsHandler::new(
+        key,
+        &mut app,
+        ActiveLidarrBlock::MediaManagementSettingsPrompt,
+        None,
+      )
+      .handle();
+
+      assert_str_eq!(current_field(&app).name, "naming.renameTracks");
+    }
+  }
+
+  mod test_handle_home_end {
+    use std::sync::atomic::Ordering;
+
+    use pretty_assertions::{assert_eq, assert_str_eq};
+
+    use super::*;
+
+    #[test]
+    fn test_media_management_settings_prompt_home_end() {
+      let mut app = App::test_default();
+      app.data.lidarr_data.media_management_modal = Some(media_management_modal());
+
+      MediaManagementSettingsHandler::new(
+        DEFAULT_KEYBINDINGS.end.key,
+        &mut app,
+        ActiveLidarrBlock::MediaManagementSettingsPrompt,
+        None,
+      )
+      .handle();
+
+      assert_str_eq!(
+        current_field(&app).name,
+        "mediaManagement.extraFileExtensions"
+      );
+
+      MediaManagementSettingsHandler::new(
+        DEFAULT_KEYBINDINGS.home.key,
+        &mut app,
+        ActiveLidarrBlock::MediaManagementSettingsPrompt,
+        None,
+      )
+      .handle();
+
+      assert_str_eq!(current_field(&app).name, "naming.renameTracks");
+    }
+
+    #[test]
+    fn test_media_management_field_input_home_end() {
+      let mut app = App::test_default();
+      app.data.lidarr_data.media_management_modal = Some(media_management_modal());
+      select_field(&mut app, "mediaManagement.chmodFolder");
+
+      MediaManagementSettingsHandler::new(
+        DEFAULT_KEYBINDINGS.home.key,
+        &mut app,
+        ActiveLidarrBlock::MediaManagementFieldInput,
+        None,
+      )
+      .handle();
+
+      assert_eq!(current_field(&app).value.offset.load(Ordering::SeqCst), 3);
+
+      MediaManagementSettingsHandler::new(
+        DEFAULT_KEYBINDINGS.end.key,
+        &mut app,
+        ActiveLidarrBlock::MediaManagementFieldInput,
+        None,
+      )
+      .handle();
+
+      assert_eq!(current_field(&app).value.offset.load(Ordering::SeqCst), 0);
+    }
+  }
+
+  mod test_handle_left_right_action {
+    use std::sync::atomic::Ordering;
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_media_management_field_input_left_right_keys() {
+      let mut app = App::test_default();
+      app.data.lidarr_data.media_management_modal = Some(media_management_modal());
+      select_field(&mut app, "mediaManagement.chmodFolder");
+
+      MediaManagementSettingsHandler::new(
+        DEFAULT_KEYBINDINGS.left.key,
+        &mut app,
+        ActiveLidarrBlock::MediaManagementFieldInput,
+        None,
+      )
+      .handle();
+
+      assert_eq!(current_field(&app).value.offset.load(Ordering::SeqCst), 1);
+
+      MediaManagementSettingsHandler::new(
+        DEFAULT_KEYBINDINGS.right.key,
+        &mut app,
+        ActiveLidarrBlock::MediaManagementFieldInput,
+        None,
+      )
+      .handle();
+
+      assert_eq!(current_field(&app).value.offset.load(Ordering::SeqCst), 0);
+    }
+  }
+
+  mod test_handle_submit {
+    use pretty_assertions::{assert_eq, assert_str_eq};
+
+    use super::*;
+
+    const SUBMIT_KEY: Key = DEFAULT_KEYBINDINGS.submit.key;
+
+    #[test]
+    fn test_media_management_settings_prompt_submit_text_field() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveLidarrBlock::MediaManagementSettingsPrompt.into());
+      app.data.lidarr_data.media_management_modal = Some(media_management_modal());
+      select_field(&mut app, "naming.standardTrackFormat");
+
+      MediaManagementSettingsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveLidarrBlock::MediaManagementSettingsPrompt,
+        None,
+      )
+      .handle();
+
+      assert_navigation_pushed!(app, ActiveLidarrBlock::MediaManagementFieldInput.into());
+      assert!(app.ignore_special_keys_for_textbox_input);
+    }
+
+    #[test]
+    fn test_media_management_settings_prompt_submit_toggles_checkbox() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveLidarrBlock::MediaManagementSettingsPrompt.into());
+      app.data.lidarr_data.media_management_modal = Some(media_management_modal());
+
+      MediaManagementSettingsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveLidarrBlock::MediaManagementSettingsPrompt,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveLidarrBlock::MediaManagementSettingsPrompt.into()
+      );
+      assert_str_eq!(current_field(&app).value.text, "false");
+      assert!(app.should_refresh);
+
+      MediaManagementSettingsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveLidarrBlock::MediaManagementSettingsPrompt,
+        None,
+      )
+      .handle();
+
+      assert_str_eq!(current_field(&app).value.text, "true");
+    }
+
+    #[test]
+    fn test_media_management_field_input_submit() {
+      let mut app = App::test_default();
+      app.ignore_special_keys_for_textbox_input = true;
+      app.push_navigation_stack(ActiveLidarrBlock::MediaManagementSettingsPrompt.into());
+      app.push_navigation_stack(ActiveLidarrBlock::MediaManagementFieldInput.into());
+      app.data.lidarr_data.media_management_modal = Some(media_management_modal());
+
+      MediaManagementSettingsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveLidarrBlock::MediaManagementFieldInput,
+        None,
+      )
+      .handle();
+
+      assert_navigation_popped!(app, ActiveLidarrBlock::MediaManagementSettingsPrompt.into());
+      assert!(!app.ignore_special_keys_for_textbox_input);
+    }
+  }
+
+  mod test_handle_esc {
+    use super::*;
+
+    const ESC_KEY: Key = DEFAULT_KEYBINDINGS.esc.key;
+
+    #[test]
+    fn test_media_management_settings_prompt_esc() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveLidarrBlock::RootFolders.into());
+      app.push_navigation_stack(ActiveLidarrBlock::MediaManagementSettingsPrompt.into());
+      app.data.lidarr_data.media_management_modal = Some(media_management_modal());
+      app.data.lidarr_data.naming_examples = Some(naming_examples());
+      app.data.lidarr_data.prompt_confirm = true;
+
+      MediaManagementSettingsHandler::new(
+        ESC_KEY,
+        &mut app,
+        ActiveLidarrBlock::MediaManagementSettingsPrompt,
+        None,
+      )
+      .handle();
+
+      assert_navigation_popped!(app, ActiveLidarrBlock::RootFolders.into());
+      assert!(!app.data.lidarr_data.prompt_confirm);
+      assert_modal_absent!(app.data.lidarr_data.media_management_modal);
+      assert_none!(&app.data.lidarr_data.naming_examples);
+    }
+
+    #[test]
+    fn test_media_management_field_input_esc() {
+      let mut app = App::test_default();
+      app.ignore_special_keys_for_textbox_input = true;
+      app.push_navigation_stack(ActiveLidarrBlock::MediaManagementSettingsPrompt.into());
+      app.push_navigation_stack(ActiveLidarrBlock::MediaManagementFieldInput.into());
+      app.data.lidarr_data.media_management_modal = Some(media_management_modal());
+
+      MediaManagementSettingsHandler::new(
+        ESC_KEY,
+        &mut app,
+        ActiveLidarrBlock::MediaManagementFieldInput,
+        None,
+      )
+      .handle();
+
+      assert_navigation_popped!(app, ActiveLidarrBlock::MediaManagementSettingsPrompt.into());
+      assert!(!app.ignore_special_keys_for_textbox_input);
+      assert_modal_present!(app.data.lidarr_data.media_management_modal);
+    }
+  }
+
+  mod test_handle_key_char {
+    use pretty_assertions::{assert_eq, assert_str_eq};
+
+    use super::*;
+
+    #[test]
+    fn test_media_management_field_input_backspace() {
+      let mut app = App::test_default();
+      app.data.lidarr_data.media_management_modal = Some(media_management_modal());
+      select_field(&mut app, "mediaManagement.chmodFolder");
+
+      MediaManagementSettingsHandler::new(
+        DEFAULT_KEYBINDINGS.backspace.key,
+        &mut app,
+        ActiveLidarrBlock::MediaManagementFieldInput,
+        None,
+      )
+      .handle();
+
+      assert_str_eq!(current_field(&app).value.text, "75");
+    }
+
+    #[test]
+    fn test_media_management_field_input_char_key() {
+      let mut app = App::test_default();
+      app.data.lidarr_data.media_management_modal = Some(media_management_modal());
+      select_field(&mut app, "mediaManagement.chmodFolder");
+
+      MediaManagementSettingsHandler::new(
+        Key::Char('7'),
+        &mut app,
+        ActiveLidarrBlock::MediaManagementFieldInput,
+        None,
+      )
+      .handle();
+
+      assert_str_eq!(current_field(&app).value.text, "7557");
+    }
+
+    #[test]
+    fn test_media_management_settings_prompt_confirm() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveLidarrBlock::RootFolders.into());
+      app.push_navigation_stack(ActiveLidarrBlock::MediaManagementSettingsPrompt.into());
+      app.data.lidarr_data.media_management_modal = Some(media_management_modal());
+      app.data.lidarr_data.naming_examples = Some(naming_examples());
+      select_field(&mut app, "mediaManagement.chmodFolder");
+      app
+        .data
+        .lidarr_data
+        .media_management_modal
+        .as_mut()
+        .unwrap()
+        .current_field_mut()
+        .value = "775".into();
+      let mut expected_settings = media_management_settings();
+      expected_settings.media_management.chmod_folder = Some("775".to_owned());
+
+      MediaManagementSettingsHandler::new(
+        DEFAULT_KEYBINDINGS.confirm.key,
+        &mut app,
+        ActiveLidarrBlock::MediaManagementSettingsPrompt,
+        None,
+      )
+      .handle();
+
+      assert_navigation_popped!(app, ActiveLidarrBlock::RootFolders.into());
+      assert!(app.data.lidarr_data.prompt_confirm);
+      assert!(app.should_refresh);
+      assert_modal_absent!(app.data.lidarr_data.media_management_modal);
+      assert_none!(&app.data.lidarr_data.naming_examples);
+      assert_eq!(
+        app.data.lidarr_data.prompt_confirm_action,
+        Some(LidarrEvent::EditMediaManagementSettings(expected_settings))
+      );
+    }
+
+    #[test]
+    fn test_media_management_settings_prompt_confirm_no_op_when_not_ready() {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.push_navigation_stack(ActiveLidarrBlock::RootFolders.into());
+      app.push_navigation_stack(ActiveLidarrBlock::MediaManagementSettingsPrompt.into());
+      app.data.lidarr_data.media_management_modal = Some(media_management_modal());
+
+      MediaManagementSettingsHandler::new(
+        DEFAULT_KEYBINDINGS.confirm.key,
+        &mut app,
+        ActiveLidarrBlock::MediaManagementSettingsPrompt,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveLidarrBlock::MediaManagementSettingsPrompt.into()
+      );
+      assert!(app.data.lidarr_data.prompt_confirm_action.is_none());
+      assert_modal_present!(app.data.lidarr_data.media_management_modal);
+    }
+  }
+
+  #[test]
+  fn test_media_management_settings_handler_accepts() {
+    ActiveLidarrBlock::iter().for_each(|active_lidarr_block| {
+      if MEDIA_MANAGEMENT_SETTINGS_BLOCKS.contains(&active_lidarr_block) {
+        assert!(MediaManagementSettingsHandler::accepts(active_lidarr_block));
+      } else {
+        assert!(!MediaManagementSettingsHandler::accepts(
+          active_lidarr_block
+        ));
+      }
+    })
+  }
+
+  #[rstest]
+  fn test_media_management_settings_handler_ignore_special_keys(
+    #[values(true, false)] ignore_special_keys_for_textbox_input: bool,
+  ) {
+    let mut app = App::test_default();
+    app.ignore_special_keys_for_textbox_input = ignore_special_keys_for_textbox_input;
+    let handler = MediaManagementSettingsHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveLidarrBlock::default(),
+      None,
+    );
+
+    assert_eq!(
+      handler.ignore_special_keys(),
+      ignore_special_keys_for_textbox_input
+    );
+  }
+
+  #[test]
+  fn test_media_management_settings_handler_is_not_ready_when_loading() {
+    let mut app = App::test_default();
+    app.is_loading = true;
+    app.data.lidarr_data.media_management_modal = Some(media_management_modal());
+
+    let handler = MediaManagementSettingsHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveLidarrBlock::MediaManagementSettingsPrompt,
+      None,
+    );
+
+    assert!(!handler.is_ready());
+  }
+
+  #[test]
+  fn test_media_management_settings_handler_is_not_ready_when_media_management_modal_is_none() {
+    let mut app = App::test_default();
+
+    let handler = MediaManagementSettingsHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveLidarrBlock::MediaManagementSettingsPrompt,
+      None,
+    );
+
+    assert!(!handler.is_ready());
+  }
+
+  #[test]
+  fn test_media_management_settings_handler_is_ready_when_media_management_modal_is_some() {
+    let mut app = App::test_default();
+    app.data.lidarr_data.media_management_modal = Some(media_management_modal());
+
+    let handler = MediaManagementSettingsHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveLidarrBlock::MediaManagementSettingsPrompt,
+      None,
+    );
+
+    assert!(handler.is_ready());
+  }
+}
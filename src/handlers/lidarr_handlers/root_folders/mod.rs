@@ -1,4 +1,5 @@
 use add_root_folder_handler::AddRootFolderHandler;
+use media_management_settings_handler::MediaManagementSettingsHandler;
 
 use crate::app::App;
 use crate::event::Key;
@@ -14,6 +15,7 @@ use crate::models::{BlockSelectionState, Route};
 use crate::network::lidarr_network::LidarrEvent;
 
 mod add_root_folder_handler;
+mod media_management_settings_handler;
 
 #[cfg(test)]
 #[path = "root_folders_handler_tests.rs"]
@@ -48,6 +50,16 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveLidarrBlock> for RootFoldersHandler<'
         .handle();
     }
 
+    if MediaManagementSettingsHandler::accepts(self.active_lidarr_block) {
+      return MediaManagementSettingsHandler::new(
+        self.key,
+        self.app,
+        self.active_lidarr_block,
+        self.context,
+      )
+      .handle();
+    }
+
     if !handle_table(
       self,
       |app| &mut app.data.lidarr_data.root_folders,
@@ -58,7 +70,9 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveLidarrBlock> for RootFoldersHandler<'
   }
 
   fn accepts(active_block: ActiveLidarrBlock) -> bool {
-    ROOT_FOLDERS_BLOCKS.contains(&active_block) || ADD_ROOT_FOLDER_BLOCKS.contains(&active_block)
+    ROOT_FOLDERS_BLOCKS.contains(&active_block)
+      || ADD_ROOT_FOLDER_BLOCKS.contains(&active_block)
+      || MediaManagementSettingsHandler::accepts(active_block)
   }
 
   fn new(
@@ -148,6 +162,11 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveLidarrBlock> for RootFoldersHandler<'
             .app
             .push_navigation_stack(ActiveLidarrBlock::AddRootFolderPrompt.into());
         }
+        _ if matches_key!(settings, key) => {
+          self
+            .app
+            .push_navigation_stack(ActiveLidarrBlock::MediaManagementSettingsPrompt.into());
+        }
         _ => (),
       },
       ActiveLidarrBlock::DeleteRootFolderPrompt => {
@@ -12,10 +12,12 @@ mod tests {
   use crate::handlers::KeyEventHandler;
   use crate::handlers::lidarr_handlers::root_folders::RootFoldersHandler;
   use crate::models::servarr_data::lidarr::lidarr_data::{
-    ADD_ROOT_FOLDER_BLOCKS, ActiveLidarrBlock, ROOT_FOLDERS_BLOCKS,
+    ADD_ROOT_FOLDER_BLOCKS, ActiveLidarrBlock, MEDIA_MANAGEMENT_SETTINGS_BLOCKS,
+    ROOT_FOLDERS_BLOCKS,
   };
   use crate::models::servarr_models::RootFolder;
   use crate::network::lidarr_network::lidarr_network_test_utils::test_utils::root_folder;
+  use crate::test_handler_delegation;
 
   mod test_handle_delete {
     use pretty_assertions::assert_eq;
@@ -345,6 +347,51 @@ mod tests {
       assert!(!app.should_refresh);
     }
 
+    #[test]
+    fn test_media_management_settings_key() {
+      let mut app = App::test_default();
+      app
+        .data
+        .lidarr_data
+        .root_folders
+        .set_items(vec![RootFolder::default()]);
+
+      RootFoldersHandler::new(
+        DEFAULT_KEYBINDINGS.settings.key,
+        &mut app,
+        ActiveLidarrBlock::RootFolders,
+        None,
+      )
+      .handle();
+
+      assert_navigation_pushed!(app, ActiveLidarrBlock::MediaManagementSettingsPrompt.into());
+    }
+
+    #[test]
+    fn test_media_management_settings_key_no_op_when_not_ready() {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.push_navigation_stack(ActiveLidarrBlock::RootFolders.into());
+      app
+        .data
+        .lidarr_data
+        .root_folders
+        .set_items(vec![RootFolder::default()]);
+
+      RootFoldersHandler::new(
+        DEFAULT_KEYBINDINGS.settings.key,
+        &mut app,
+        ActiveLidarrBlock::RootFolders,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveLidarrBlock::RootFolders.into()
+      );
+    }
+
     #[test]
     fn test_delete_root_folder_prompt_confirm() {
       let mut app = App::test_default();
@@ -373,11 +420,27 @@ mod tests {
     }
   }
 
+  #[rstest]
+  fn test_delegates_media_management_settings_blocks_to_media_management_settings_handler(
+    #[values(
+      ActiveLidarrBlock::MediaManagementSettingsPrompt,
+      ActiveLidarrBlock::MediaManagementFieldInput
+    )]
+    active_lidarr_block: ActiveLidarrBlock,
+  ) {
+    test_handler_delegation!(
+      RootFoldersHandler,
+      ActiveLidarrBlock::RootFolders,
+      active_lidarr_block
+    );
+  }
+
   #[test]
   fn test_root_folders_handler_accepts() {
     ActiveLidarrBlock::iter().for_each(|active_lidarr_block| {
       if ROOT_FOLDERS_BLOCKS.contains(&active_lidarr_block)
         || ADD_ROOT_FOLDER_BLOCKS.contains(&active_lidarr_block)
+        || MEDIA_MANAGEMENT_SETTINGS_BLOCKS.contains(&active_lidarr_block)
       {
         assert!(RootFoldersHandler::accepts(active_lidarr_block));
       } else {
@@ -20,6 +20,10 @@ pub(super) struct SystemDetailsHandler<'a, 'b> {
 }
 
 impl SystemDetailsHandler<'_, '_> {
+  fn extract_backup_id(&self) -> i64 {
+    self.app.data.lidarr_data.backups.current_selection().id
+  }
+
   fn extract_task_name(&self) -> LidarrTaskName {
     self
       .app
@@ -62,6 +66,7 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveLidarrBlock> for SystemDetailsHandler
     !self.app.is_loading
       && (!self.app.data.lidarr_data.log_details.is_empty()
         || !self.app.data.lidarr_data.tasks.is_empty()
+        || !self.app.data.lidarr_data.backups.is_empty()
         || !self.app.data.lidarr_data.updates.is_empty())
   }
 
@@ -69,6 +74,7 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveLidarrBlock> for SystemDetailsHandler
     match self.active_lidarr_block {
       ActiveLidarrBlock::SystemLogs => self.app.data.lidarr_data.log_details.scroll_up(),
       ActiveLidarrBlock::SystemTasks => self.app.data.lidarr_data.tasks.scroll_up(),
+      ActiveLidarrBlock::SystemBackups => self.app.data.lidarr_data.backups.scroll_up(),
       ActiveLidarrBlock::SystemUpdates => self.app.data.lidarr_data.updates.scroll_up(),
       ActiveLidarrBlock::SystemQueuedEvents => self.app.data.lidarr_data.queued_events.scroll_up(),
       _ => (),
@@ -79,6 +85,7 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveLidarrBlock> for SystemDetailsHandler
     match self.active_lidarr_block {
       ActiveLidarrBlock::SystemLogs => self.app.data.lidarr_data.log_details.scroll_down(),
       ActiveLidarrBlock::SystemTasks => self.app.data.lidarr_data.tasks.scroll_down(),
+      ActiveLidarrBlock::SystemBackups => self.app.data.lidarr_data.backups.scroll_down(),
       ActiveLidarrBlock::SystemUpdates => self.app.data.lidarr_data.updates.scroll_down(),
       ActiveLidarrBlock::SystemQueuedEvents => {
         self.app.data.lidarr_data.queued_events.scroll_down()
@@ -91,6 +98,7 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveLidarrBlock> for SystemDetailsHandler
     match self.active_lidarr_block {
       ActiveLidarrBlock::SystemLogs => self.app.data.lidarr_data.log_details.scroll_to_top(),
       ActiveLidarrBlock::SystemTasks => self.app.data.lidarr_data.tasks.scroll_to_top(),
+      ActiveLidarrBlock::SystemBackups => self.app.data.lidarr_data.backups.scroll_to_top(),
       ActiveLidarrBlock::SystemUpdates => self.app.data.lidarr_data.updates.scroll_to_top(),
       ActiveLidarrBlock::SystemQueuedEvents => {
         self.app.data.lidarr_data.queued_events.scroll_to_top()
@@ -103,6 +111,7 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveLidarrBlock> for SystemDetailsHandler
     match self.active_lidarr_block {
       ActiveLidarrBlock::SystemLogs => self.app.data.lidarr_data.log_details.scroll_to_bottom(),
       ActiveLidarrBlock::SystemTasks => self.app.data.lidarr_data.tasks.scroll_to_bottom(),
+      ActiveLidarrBlock::SystemBackups => self.app.data.lidarr_data.backups.scroll_to_bottom(),
       ActiveLidarrBlock::SystemUpdates => self.app.data.lidarr_data.updates.scroll_to_bottom(),
       ActiveLidarrBlock::SystemQueuedEvents => {
         self.app.data.lidarr_data.queued_events.scroll_to_bottom()
@@ -111,7 +120,15 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveLidarrBlock> for SystemDetailsHandler
     }
   }
 
-  fn handle_delete(&mut self) {}
+  fn handle_delete(&mut self) {
+    if self.active_lidarr_block == ActiveLidarrBlock::SystemBackups
+      && !self.app.data.lidarr_data.backups.is_empty()
+    {
+      self
+        .app
+        .push_navigation_stack(ActiveLidarrBlock::DeleteBackupPrompt.into());
+    }
+  }
 
   fn handle_left_right_action(&mut self) {
     let key = self.key;
@@ -140,13 +157,23 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveLidarrBlock> for SystemDetailsHandler
         }
         _ => (),
       },
-      ActiveLidarrBlock::SystemTaskStartConfirmPrompt => handle_prompt_toggle(self.app, self.key),
+      ActiveLidarrBlock::SystemTaskStartConfirmPrompt | ActiveLidarrBlock::DeleteBackupPrompt => {
+        handle_prompt_toggle(self.app, self.key)
+      }
       _ => (),
     }
   }
 
   fn handle_submit(&mut self) {
     match self.active_lidarr_block {
+      ActiveLidarrBlock::DeleteBackupPrompt => {
+        if self.app.data.lidarr_data.prompt_confirm {
+          self.app.data.lidarr_data.prompt_confirm_action =
+            Some(LidarrEvent::DeleteBackup(self.extract_backup_id()));
+        }
+
+        self.app.pop_navigation_stack();
+      }
       ActiveLidarrBlock::SystemTasks => {
         self
           .app
@@ -170,10 +197,11 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveLidarrBlock> for SystemDetailsHandler
         self.app.data.lidarr_data.log_details = StatefulList::default();
         self.app.pop_navigation_stack()
       }
-      ActiveLidarrBlock::SystemQueuedEvents
+      ActiveLidarrBlock::SystemBackups
+      | ActiveLidarrBlock::SystemQueuedEvents
       | ActiveLidarrBlock::SystemTasks
       | ActiveLidarrBlock::SystemUpdates => self.app.pop_navigation_stack(),
-      ActiveLidarrBlock::SystemTaskStartConfirmPrompt => {
+      ActiveLidarrBlock::SystemTaskStartConfirmPrompt | ActiveLidarrBlock::DeleteBackupPrompt => {
         self.app.pop_navigation_stack();
         self.app.data.lidarr_data.prompt_confirm = false;
       }
@@ -187,6 +215,15 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveLidarrBlock> for SystemDetailsHandler
       self.app.should_refresh = true;
     }
 
+    if self.active_lidarr_block == ActiveLidarrBlock::DeleteBackupPrompt
+      && matches_key!(confirm, self.key)
+    {
+      self.app.data.lidarr_data.prompt_confirm = true;
+      self.app.data.lidarr_data.prompt_confirm_action =
+        Some(LidarrEvent::DeleteBackup(self.extract_backup_id()));
+      self.app.pop_navigation_stack();
+    }
+
     if self.active_lidarr_block == ActiveLidarrBlock::SystemTaskStartConfirmPrompt
       && matches_key!(confirm, self.key)
     {
@@ -14,7 +14,7 @@ mod tests {
   use crate::models::servarr_data::lidarr::lidarr_data::{
     ActiveLidarrBlock, SYSTEM_DETAILS_BLOCKS,
   };
-  use crate::models::servarr_models::QueueEvent;
+  use crate::models::servarr_models::{Backup, QueueEvent};
   use crate::models::{HorizontallyScrollableText, ScrollableText};
 
   mod test_handle_scroll_up_and_down {
@@ -187,6 +187,32 @@ mod tests {
       );
     }
 
+    #[rstest]
+    fn test_backups_scroll(
+      #[values(DEFAULT_KEYBINDINGS.up.key, DEFAULT_KEYBINDINGS.down.key)] key: Key,
+    ) {
+      let mut app = App::test_default();
+      app
+        .data
+        .lidarr_data
+        .backups
+        .set_items(simple_stateful_iterable_vec!(Backup, String, name));
+
+      SystemDetailsHandler::new(key, &mut app, ActiveLidarrBlock::SystemBackups, None).handle();
+
+      assert_str_eq!(
+        app.data.lidarr_data.backups.current_selection().name,
+        "Test 2"
+      );
+
+      SystemDetailsHandler::new(key, &mut app, ActiveLidarrBlock::SystemBackups, None).handle();
+
+      assert_str_eq!(
+        app.data.lidarr_data.backups.current_selection().name,
+        "Test 1"
+      );
+    }
+
     #[test]
     fn test_system_updates_scroll() {
       let mut app = App::test_default();
@@ -461,6 +487,42 @@ mod tests {
       );
     }
 
+    #[test]
+    fn test_backups_home_end() {
+      let mut app = App::test_default();
+      app
+        .data
+        .lidarr_data
+        .backups
+        .set_items(extended_stateful_iterable_vec!(Backup, String, name));
+
+      SystemDetailsHandler::new(
+        DEFAULT_KEYBINDINGS.end.key,
+        &mut app,
+        ActiveLidarrBlock::SystemBackups,
+        None,
+      )
+      .handle();
+
+      assert_str_eq!(
+        app.data.lidarr_data.backups.current_selection().name,
+        "Test 3"
+      );
+
+      SystemDetailsHandler::new(
+        DEFAULT_KEYBINDINGS.home.key,
+        &mut app,
+        ActiveLidarrBlock::SystemBackups,
+        None,
+      )
+      .handle();
+
+      assert_str_eq!(
+        app.data.lidarr_data.backups.current_selection().name,
+        "Test 1"
+      );
+    }
+
     #[test]
     fn test_system_updates_home_end() {
       let mut app = App::test_default();
@@ -517,6 +579,44 @@ mod tests {
     }
   }
 
+  mod test_handle_delete {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    const DELETE_KEY: Key = DEFAULT_KEYBINDINGS.delete.key;
+
+    #[test]
+    fn test_backups_delete() {
+      let mut app = App::test_default();
+      app
+        .data
+        .lidarr_data
+        .backups
+        .set_items(vec![Backup::default()]);
+
+      SystemDetailsHandler::new(DELETE_KEY, &mut app, ActiveLidarrBlock::SystemBackups, None)
+        .handle();
+
+      assert_navigation_pushed!(app, ActiveLidarrBlock::DeleteBackupPrompt.into());
+    }
+
+    #[test]
+    fn test_backups_delete_no_op_when_backups_are_empty() {
+      let mut app = App::test_default();
+      app.data.lidarr_data.updates = ScrollableText::with_string("Test".to_owned());
+      app.push_navigation_stack(ActiveLidarrBlock::SystemBackups.into());
+
+      SystemDetailsHandler::new(DELETE_KEY, &mut app, ActiveLidarrBlock::SystemBackups, None)
+        .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveLidarrBlock::SystemBackups.into()
+      );
+    }
+  }
+
   mod test_handle_left_right_action {
     use pretty_assertions::assert_eq;
     use rstest::rstest;
@@ -626,27 +726,20 @@ mod tests {
     #[rstest]
     fn test_left_right_prompt_toggle(
       #[values(DEFAULT_KEYBINDINGS.left.key, DEFAULT_KEYBINDINGS.right.key)] key: Key,
+      #[values(
+        ActiveLidarrBlock::SystemTaskStartConfirmPrompt,
+        ActiveLidarrBlock::DeleteBackupPrompt
+      )]
+      active_lidarr_block: ActiveLidarrBlock,
     ) {
       let mut app = App::test_default();
       app.push_navigation_stack(ActiveLidarrBlock::System.into());
 
-      SystemDetailsHandler::new(
-        key,
-        &mut app,
-        ActiveLidarrBlock::SystemTaskStartConfirmPrompt,
-        None,
-      )
-      .handle();
+      SystemDetailsHandler::new(key, &mut app, active_lidarr_block, None).handle();
 
       assert!(app.data.lidarr_data.prompt_confirm);
 
-      SystemDetailsHandler::new(
-        key,
-        &mut app,
-        ActiveLidarrBlock::SystemTaskStartConfirmPrompt,
-        None,
-      )
-      .handle();
+      SystemDetailsHandler::new(key, &mut app, active_lidarr_block, None).handle();
 
       assert!(!app.data.lidarr_data.prompt_confirm);
     }
@@ -737,6 +830,57 @@ mod tests {
       assert_none!(app.data.lidarr_data.prompt_confirm_action);
       assert_navigation_popped!(app, ActiveLidarrBlock::SystemTasks.into());
     }
+
+    #[test]
+    fn test_delete_backup_prompt_confirm_submit() {
+      let mut app = App::test_default();
+      app.data.lidarr_data.prompt_confirm = true;
+      app.data.lidarr_data.backups.set_items(vec![Backup {
+        id: 1,
+        ..Backup::default()
+      }]);
+      app.push_navigation_stack(ActiveLidarrBlock::SystemBackups.into());
+      app.push_navigation_stack(ActiveLidarrBlock::DeleteBackupPrompt.into());
+
+      SystemDetailsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveLidarrBlock::DeleteBackupPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(app.data.lidarr_data.prompt_confirm);
+      assert_some_eq_x!(
+        &app.data.lidarr_data.prompt_confirm_action,
+        &LidarrEvent::DeleteBackup(1)
+      );
+      assert_navigation_popped!(app, ActiveLidarrBlock::SystemBackups.into());
+    }
+
+    #[test]
+    fn test_delete_backup_prompt_decline_submit() {
+      let mut app = App::test_default();
+      app
+        .data
+        .lidarr_data
+        .backups
+        .set_items(vec![Backup::default()]);
+      app.push_navigation_stack(ActiveLidarrBlock::SystemBackups.into());
+      app.push_navigation_stack(ActiveLidarrBlock::DeleteBackupPrompt.into());
+
+      SystemDetailsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveLidarrBlock::DeleteBackupPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(!app.data.lidarr_data.prompt_confirm);
+      assert_none!(app.data.lidarr_data.prompt_confirm_action);
+      assert_navigation_popped!(app, ActiveLidarrBlock::SystemBackups.into());
+    }
   }
 
   mod test_handle_esc {
@@ -823,6 +967,42 @@ mod tests {
       assert_navigation_popped!(app, ActiveLidarrBlock::System.into());
     }
 
+    #[rstest]
+    fn test_esc_system_backups(#[values(true, false)] is_ready: bool) {
+      let mut app = App::test_default();
+      app.is_loading = is_ready;
+      app.push_navigation_stack(ActiveLidarrBlock::System.into());
+      app.push_navigation_stack(ActiveLidarrBlock::SystemBackups.into());
+      app
+        .data
+        .lidarr_data
+        .backups
+        .set_items(vec![Backup::default()]);
+
+      SystemDetailsHandler::new(ESC_KEY, &mut app, ActiveLidarrBlock::SystemBackups, None).handle();
+
+      assert_navigation_popped!(app, ActiveLidarrBlock::System.into());
+    }
+
+    #[test]
+    fn test_delete_backup_prompt_esc() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveLidarrBlock::SystemBackups.into());
+      app.push_navigation_stack(ActiveLidarrBlock::DeleteBackupPrompt.into());
+      app.data.lidarr_data.prompt_confirm = true;
+
+      SystemDetailsHandler::new(
+        ESC_KEY,
+        &mut app,
+        ActiveLidarrBlock::DeleteBackupPrompt,
+        None,
+      )
+      .handle();
+
+      assert_navigation_popped!(app, ActiveLidarrBlock::SystemBackups.into());
+      assert!(!app.data.lidarr_data.prompt_confirm);
+    }
+
     #[test]
     fn test_system_tasks_start_task_prompt_esc() {
       let mut app = App::test_default();
@@ -855,6 +1035,7 @@ mod tests {
     #[rstest]
     fn test_refresh_key(
       #[values(
+        ActiveLidarrBlock::SystemBackups,
         ActiveLidarrBlock::SystemLogs,
         ActiveLidarrBlock::SystemTasks,
         ActiveLidarrBlock::SystemQueuedEvents,
@@ -882,6 +1063,7 @@ mod tests {
     #[rstest]
     fn test_refresh_key_no_op_when_not_ready(
       #[values(
+        ActiveLidarrBlock::SystemBackups,
         ActiveLidarrBlock::SystemLogs,
         ActiveLidarrBlock::SystemTasks,
         ActiveLidarrBlock::SystemQueuedEvents,
@@ -935,6 +1117,32 @@ mod tests {
       );
       assert_navigation_popped!(app, ActiveLidarrBlock::SystemTasks.into());
     }
+
+    #[test]
+    fn test_delete_backup_prompt_confirm() {
+      let mut app = App::test_default();
+      app.data.lidarr_data.backups.set_items(vec![Backup {
+        id: 1,
+        ..Backup::default()
+      }]);
+      app.push_navigation_stack(ActiveLidarrBlock::SystemBackups.into());
+      app.push_navigation_stack(ActiveLidarrBlock::DeleteBackupPrompt.into());
+
+      SystemDetailsHandler::new(
+        DEFAULT_KEYBINDINGS.confirm.key,
+        &mut app,
+        ActiveLidarrBlock::DeleteBackupPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(app.data.lidarr_data.prompt_confirm);
+      assert_some_eq_x!(
+        &app.data.lidarr_data.prompt_confirm_action,
+        &LidarrEvent::DeleteBackup(1)
+      );
+      assert_navigation_popped!(app, ActiveLidarrBlock::SystemBackups.into());
+    }
   }
 
   #[test]
@@ -967,6 +1175,25 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_extract_backup_id() {
+    let mut app = App::test_default();
+    app.data.lidarr_data.backups.set_items(vec![Backup {
+      id: 1,
+      ..Backup::default()
+    }]);
+
+    let backup_id = SystemDetailsHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveLidarrBlock::SystemBackups,
+      None,
+    )
+    .extract_backup_id();
+
+    assert_eq!(backup_id, 1);
+  }
+
   #[test]
   fn test_extract_task_name() {
     let mut app = App::test_default();
@@ -1061,6 +1288,26 @@ mod tests {
     assert!(handler.is_ready());
   }
 
+  #[test]
+  fn test_system_details_handler_ready_when_not_loading_and_backups_is_not_empty() {
+    let mut app = App::test_default();
+    app.is_loading = false;
+    app
+      .data
+      .lidarr_data
+      .backups
+      .set_items(vec![Backup::default()]);
+
+    let handler = SystemDetailsHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveLidarrBlock::SystemBackups,
+      None,
+    );
+
+    assert!(handler.is_ready());
+  }
+
   #[test]
   fn test_system_details_handler_ready_when_not_loading_and_updates_is_not_empty() {
     let mut app = App::test_default();
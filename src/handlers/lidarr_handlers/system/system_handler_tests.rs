@@ -27,7 +27,7 @@ mod tests {
       let mut app = App::test_default();
       app.push_navigation_stack(ActiveLidarrBlock::System.into());
       app.is_loading = is_ready;
-      app.data.lidarr_data.main_tabs.set_index(6);
+      app.data.lidarr_data.main_tabs.set_index(8);
 
       SystemHandler::new(
         DEFAULT_KEYBINDINGS.left.key,
@@ -39,9 +39,9 @@ mod tests {
 
       assert_eq!(
         app.data.lidarr_data.main_tabs.get_active_route(),
-        ActiveLidarrBlock::Indexers.into()
+        ActiveLidarrBlock::QualityProfiles.into()
       );
-      assert_navigation_pushed!(app, ActiveLidarrBlock::Indexers.into());
+      assert_navigation_pushed!(app, ActiveLidarrBlock::QualityProfiles.into());
     }
 
     #[rstest]
@@ -49,7 +49,7 @@ mod tests {
       let mut app = App::test_default();
       app.push_navigation_stack(ActiveLidarrBlock::System.into());
       app.is_loading = is_ready;
-      app.data.lidarr_data.main_tabs.set_index(6);
+      app.data.lidarr_data.main_tabs.set_index(8);
 
       SystemHandler::new(
         DEFAULT_KEYBINDINGS.right.key,
@@ -61,9 +61,9 @@ mod tests {
 
       assert_eq!(
         app.data.lidarr_data.main_tabs.get_active_route(),
-        ActiveLidarrBlock::Artists.into()
+        ActiveLidarrBlock::Calendar.into()
       );
-      assert_navigation_pushed!(app, ActiveLidarrBlock::Artists.into());
+      assert_navigation_pushed!(app, ActiveLidarrBlock::Calendar.into());
     }
   }
 
@@ -352,6 +352,67 @@ mod tests {
       assert_is_empty!(app.data.lidarr_data.log_details);
     }
 
+    #[test]
+    fn test_backups_key() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveLidarrBlock::System.into());
+      app.data.lidarr_data.logs.set_items(vec![
+        HorizontallyScrollableText::from("test 1"),
+        HorizontallyScrollableText::from("test 2"),
+      ]);
+      app
+        .data
+        .lidarr_data
+        .queued_events
+        .set_items(vec![QueueEvent::default()]);
+      app
+        .data
+        .lidarr_data
+        .tasks
+        .set_items(vec![LidarrTask::default()]);
+
+      SystemHandler::new(
+        DEFAULT_KEYBINDINGS.backups.key,
+        &mut app,
+        ActiveLidarrBlock::System,
+        None,
+      )
+      .handle();
+
+      assert_navigation_pushed!(app, ActiveLidarrBlock::SystemBackups.into());
+    }
+
+    #[test]
+    fn test_backups_key_no_op_when_not_ready() {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.push_navigation_stack(ActiveLidarrBlock::System.into());
+      app.data.lidarr_data.logs.set_items(vec![
+        HorizontallyScrollableText::from("test 1"),
+        HorizontallyScrollableText::from("test 2"),
+      ]);
+      app
+        .data
+        .lidarr_data
+        .queued_events
+        .set_items(vec![QueueEvent::default()]);
+      app
+        .data
+        .lidarr_data
+        .tasks
+        .set_items(vec![LidarrTask::default()]);
+
+      SystemHandler::new(
+        DEFAULT_KEYBINDINGS.backups.key,
+        &mut app,
+        ActiveLidarrBlock::System,
+        None,
+      )
+      .handle();
+
+      assert_eq!(app.get_current_route(), ActiveLidarrBlock::System.into());
+    }
+
     #[test]
     fn test_tasks_key() {
       let mut app = App::test_default();
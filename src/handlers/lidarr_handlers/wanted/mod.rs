@@ -0,0 +1,206 @@
+use crate::app::App;
+use crate::event::Key;
+use crate::handlers::lidarr_handlers::handle_change_tab_left_right_keys;
+use crate::handlers::table_handler::{TableHandlingConfig, handle_table};
+use crate::handlers::{KeyEventHandler, handle_clear_errors, handle_prompt_toggle};
+use crate::matches_key;
+use crate::models::Route;
+use crate::models::lidarr_models::Album;
+use crate::models::servarr_data::lidarr::lidarr_data::{ActiveLidarrBlock, WANTED_BLOCKS};
+use crate::models::stateful_table::StatefulTable;
+use crate::network::lidarr_network::LidarrEvent;
+
+#[cfg(test)]
+#[path = "wanted_handler_tests.rs"]
+mod wanted_handler_tests;
+
+pub(super) struct WantedHandler<'a, 'b> {
+  key: Key,
+  app: &'a mut App<'b>,
+  active_lidarr_block: ActiveLidarrBlock,
+  context: Option<ActiveLidarrBlock>,
+}
+
+impl WantedHandler<'_, '_> {
+  fn wanted_table_block(&self) -> ActiveLidarrBlock {
+    match self.active_lidarr_block {
+      ActiveLidarrBlock::WantedSearchAllPrompt | ActiveLidarrBlock::WantedSearchSelectedPrompt => {
+        self.context.unwrap_or(ActiveLidarrBlock::WantedMissing)
+      }
+      _ => self.active_lidarr_block,
+    }
+  }
+
+  fn wanted_table(&self) -> &StatefulTable<Album> {
+    if self.wanted_table_block() == ActiveLidarrBlock::WantedCutoffUnmet {
+      &self.app.data.lidarr_data.cutoff_unmet
+    } else {
+      &self.app.data.lidarr_data.wanted_missing
+    }
+  }
+
+  fn build_search_action(&self) -> LidarrEvent {
+    match self.active_lidarr_block {
+      ActiveLidarrBlock::WantedSearchSelectedPrompt => {
+        LidarrEvent::TriggerAutomaticAlbumSearch(self.wanted_table().current_selection().id)
+      }
+      _ if self.wanted_table_block() == ActiveLidarrBlock::WantedCutoffUnmet => {
+        LidarrEvent::TriggerCutoffUnmetAlbumSearch
+      }
+      _ => LidarrEvent::TriggerMissingAlbumSearch,
+    }
+  }
+}
+
+impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveLidarrBlock> for WantedHandler<'a, 'b> {
+  fn handle(&mut self) {
+    let missing_table_handling_config =
+      TableHandlingConfig::new(ActiveLidarrBlock::WantedMissing.into());
+    let cutoff_unmet_table_handling_config =
+      TableHandlingConfig::new(ActiveLidarrBlock::WantedCutoffUnmet.into());
+
+    if !handle_table(
+      self,
+      |app| &mut app.data.lidarr_data.wanted_missing,
+      missing_table_handling_config,
+    ) && !handle_table(
+      self,
+      |app| &mut app.data.lidarr_data.cutoff_unmet,
+      cutoff_unmet_table_handling_config,
+    ) {
+      self.handle_key_event();
+    }
+  }
+
+  fn accepts(active_block: ActiveLidarrBlock) -> bool {
+    WANTED_BLOCKS.contains(&active_block)
+  }
+
+  fn ignore_special_keys(&self) -> bool {
+    self.app.ignore_special_keys_for_textbox_input
+  }
+
+  fn new(
+    key: Key,
+    app: &'a mut App<'b>,
+    active_block: ActiveLidarrBlock,
+    context: Option<ActiveLidarrBlock>,
+  ) -> WantedHandler<'a, 'b> {
+    WantedHandler {
+      key,
+      app,
+      active_lidarr_block: active_block,
+      context,
+    }
+  }
+
+  fn get_key(&self) -> Key {
+    self.key
+  }
+
+  fn is_ready(&self) -> bool {
+    !self.app.is_loading
+  }
+
+  fn handle_scroll_up(&mut self) {}
+
+  fn handle_scroll_down(&mut self) {}
+
+  fn handle_home(&mut self) {}
+
+  fn handle_end(&mut self) {}
+
+  fn handle_delete(&mut self) {}
+
+  fn handle_left_right_action(&mut self) {
+    match self.active_lidarr_block {
+      ActiveLidarrBlock::WantedMissing | ActiveLidarrBlock::WantedCutoffUnmet => {
+        handle_change_tab_left_right_keys(self.app, self.key)
+      }
+      ActiveLidarrBlock::WantedSearchAllPrompt | ActiveLidarrBlock::WantedSearchSelectedPrompt => {
+        handle_prompt_toggle(self.app, self.key)
+      }
+      _ => (),
+    }
+  }
+
+  fn handle_submit(&mut self) {
+    match self.active_lidarr_block {
+      ActiveLidarrBlock::WantedMissing | ActiveLidarrBlock::WantedCutoffUnmet
+        if !self.wanted_table().is_empty() =>
+      {
+        self.app.push_navigation_stack(
+          (
+            ActiveLidarrBlock::WantedSearchSelectedPrompt,
+            Some(self.active_lidarr_block),
+          )
+            .into(),
+        );
+      }
+      ActiveLidarrBlock::WantedSearchAllPrompt | ActiveLidarrBlock::WantedSearchSelectedPrompt => {
+        if self.app.data.lidarr_data.prompt_confirm {
+          self.app.data.lidarr_data.prompt_confirm_action = Some(self.build_search_action());
+        }
+
+        self.app.pop_navigation_stack();
+      }
+      _ => (),
+    }
+  }
+
+  fn handle_esc(&mut self) {
+    match self.active_lidarr_block {
+      ActiveLidarrBlock::WantedSearchAllPrompt | ActiveLidarrBlock::WantedSearchSelectedPrompt => {
+        self.app.pop_navigation_stack();
+        self.app.data.lidarr_data.prompt_confirm = false;
+      }
+      _ => handle_clear_errors(self.app),
+    }
+  }
+
+  fn handle_char_key_event(&mut self) {
+    let key = self.key;
+    match self.active_lidarr_block {
+      ActiveLidarrBlock::WantedMissing | ActiveLidarrBlock::WantedCutoffUnmet => match self.key {
+        _ if matches_key!(toggle_view, key) => {
+          let toggled_block = if self.active_lidarr_block == ActiveLidarrBlock::WantedMissing {
+            ActiveLidarrBlock::WantedCutoffUnmet
+          } else {
+            ActiveLidarrBlock::WantedMissing
+          };
+          self.app.pop_and_push_navigation_stack(toggled_block.into());
+        }
+        _ if matches_key!(auto_search, key) => {
+          self.app.push_navigation_stack(
+            (
+              ActiveLidarrBlock::WantedSearchAllPrompt,
+              Some(self.active_lidarr_block),
+            )
+              .into(),
+          );
+        }
+        _ if matches_key!(refresh, key) => {
+          self.app.should_refresh = true;
+        }
+        _ => (),
+      },
+      ActiveLidarrBlock::WantedSearchAllPrompt | ActiveLidarrBlock::WantedSearchSelectedPrompt => {
+        if matches_key!(confirm, key) {
+          self.app.data.lidarr_data.prompt_confirm = true;
+          self.app.data.lidarr_data.prompt_confirm_action = Some(self.build_search_action());
+
+          self.app.pop_navigation_stack();
+        }
+      }
+      _ => (),
+    }
+  }
+
+  fn app_mut(&mut self) -> &mut App<'b> {
+    self.app
+  }
+
+  fn current_route(&self) -> Route {
+    self.app.get_current_route()
+  }
+}
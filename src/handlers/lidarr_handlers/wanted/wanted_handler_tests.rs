@@ -0,0 +1,477 @@
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+  use rstest::rstest;
+  use strum::IntoEnumIterator;
+
+  use crate::app::App;
+  use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+  use crate::assert_navigation_pushed;
+  use crate::event::Key;
+  use crate::handlers::KeyEventHandler;
+  use crate::handlers::lidarr_handlers::wanted::WantedHandler;
+  use crate::models::lidarr_models::Album;
+  use crate::models::servarr_data::lidarr::lidarr_data::{ActiveLidarrBlock, WANTED_BLOCKS};
+
+  mod test_handle_left_right_action {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::assert_navigation_pushed;
+
+    #[rstest]
+    fn test_wanted_tab_left(
+      #[values(ActiveLidarrBlock::WantedMissing, ActiveLidarrBlock::WantedCutoffUnmet)]
+      active_lidarr_block: ActiveLidarrBlock,
+      #[values(true, false)] is_ready: bool,
+    ) {
+      let mut app = App::test_default();
+      app.push_navigation_stack(active_lidarr_block.into());
+      app.is_loading = is_ready;
+      app.data.lidarr_data.main_tabs.set_index(10);
+
+      WantedHandler::new(
+        DEFAULT_KEYBINDINGS.left.key,
+        &mut app,
+        active_lidarr_block,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.data.lidarr_data.main_tabs.get_active_route(),
+        ActiveLidarrBlock::Calendar.into()
+      );
+      assert_navigation_pushed!(app, ActiveLidarrBlock::Calendar.into());
+    }
+
+    #[rstest]
+    fn test_wanted_tab_right(
+      #[values(ActiveLidarrBlock::WantedMissing, ActiveLidarrBlock::WantedCutoffUnmet)]
+      active_lidarr_block: ActiveLidarrBlock,
+      #[values(true, false)] is_ready: bool,
+    ) {
+      let mut app = App::test_default();
+      app.push_navigation_stack(active_lidarr_block.into());
+      app.is_loading = is_ready;
+      app.data.lidarr_data.main_tabs.set_index(10);
+
+      WantedHandler::new(
+        DEFAULT_KEYBINDINGS.right.key,
+        &mut app,
+        active_lidarr_block,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.data.lidarr_data.main_tabs.get_active_route(),
+        ActiveLidarrBlock::Artists.into()
+      );
+      assert_navigation_pushed!(app, ActiveLidarrBlock::Artists.into());
+    }
+
+    #[rstest]
+    fn test_wanted_left_right_prompt_toggle(
+      #[values(
+        ActiveLidarrBlock::WantedSearchAllPrompt,
+        ActiveLidarrBlock::WantedSearchSelectedPrompt
+      )]
+      active_lidarr_block: ActiveLidarrBlock,
+      #[values(DEFAULT_KEYBINDINGS.left.key, DEFAULT_KEYBINDINGS.right.key)] key: Key,
+    ) {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveLidarrBlock::WantedMissing.into());
+
+      WantedHandler::new(key, &mut app, active_lidarr_block, None).handle();
+
+      assert!(app.data.lidarr_data.prompt_confirm);
+
+      WantedHandler::new(key, &mut app, active_lidarr_block, None).handle();
+
+      assert!(!app.data.lidarr_data.prompt_confirm);
+    }
+  }
+
+  mod test_handle_submit {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::assert_navigation_popped;
+    use crate::models::Scrollable;
+    use crate::network::lidarr_network::LidarrEvent;
+
+    const SUBMIT_KEY: Key = DEFAULT_KEYBINDINGS.submit.key;
+
+    #[rstest]
+    fn test_wanted_submit(
+      #[values(ActiveLidarrBlock::WantedMissing, ActiveLidarrBlock::WantedCutoffUnmet)]
+      active_lidarr_block: ActiveLidarrBlock,
+    ) {
+      let mut app = App::test_default();
+      app.data.lidarr_data.wanted_missing.set_items(albums());
+      app.data.lidarr_data.cutoff_unmet.set_items(albums());
+      app.push_navigation_stack(active_lidarr_block.into());
+
+      WantedHandler::new(SUBMIT_KEY, &mut app, active_lidarr_block, None).handle();
+
+      assert_navigation_pushed!(
+        app,
+        (
+          ActiveLidarrBlock::WantedSearchSelectedPrompt,
+          Some(active_lidarr_block)
+        )
+          .into()
+      );
+    }
+
+    #[rstest]
+    fn test_wanted_submit_no_op_when_table_is_empty(
+      #[values(ActiveLidarrBlock::WantedMissing, ActiveLidarrBlock::WantedCutoffUnmet)]
+      active_lidarr_block: ActiveLidarrBlock,
+    ) {
+      let mut app = App::test_default();
+      app.push_navigation_stack(active_lidarr_block.into());
+
+      WantedHandler::new(SUBMIT_KEY, &mut app, active_lidarr_block, None).handle();
+
+      assert_eq!(app.get_current_route(), active_lidarr_block.into());
+    }
+
+    #[rstest]
+    #[case(
+      ActiveLidarrBlock::WantedMissing,
+      ActiveLidarrBlock::WantedSearchSelectedPrompt,
+      LidarrEvent::TriggerAutomaticAlbumSearch(2)
+    )]
+    #[case(
+      ActiveLidarrBlock::WantedCutoffUnmet,
+      ActiveLidarrBlock::WantedSearchSelectedPrompt,
+      LidarrEvent::TriggerAutomaticAlbumSearch(4)
+    )]
+    #[case(
+      ActiveLidarrBlock::WantedMissing,
+      ActiveLidarrBlock::WantedSearchAllPrompt,
+      LidarrEvent::TriggerMissingAlbumSearch
+    )]
+    #[case(
+      ActiveLidarrBlock::WantedCutoffUnmet,
+      ActiveLidarrBlock::WantedSearchAllPrompt,
+      LidarrEvent::TriggerCutoffUnmetAlbumSearch
+    )]
+    fn test_wanted_prompt_confirm_submit(
+      #[case] base_route: ActiveLidarrBlock,
+      #[case] prompt_block: ActiveLidarrBlock,
+      #[case] expected_action: LidarrEvent,
+    ) {
+      let mut app = App::test_default();
+      app.data.lidarr_data.wanted_missing.set_items(albums());
+      app.data.lidarr_data.wanted_missing.scroll_down();
+      app.data.lidarr_data.cutoff_unmet.set_items(vec![Album {
+        id: 4,
+        ..Album::default()
+      }]);
+      app.data.lidarr_data.prompt_confirm = true;
+      app.push_navigation_stack(base_route.into());
+      app.push_navigation_stack((prompt_block, Some(base_route)).into());
+
+      WantedHandler::new(SUBMIT_KEY, &mut app, prompt_block, Some(base_route)).handle();
+
+      assert!(app.data.lidarr_data.prompt_confirm);
+      assert_some_eq_x!(
+        &app.data.lidarr_data.prompt_confirm_action,
+        &expected_action
+      );
+      assert_navigation_popped!(app, base_route.into());
+    }
+
+    #[rstest]
+    fn test_wanted_prompt_decline_submit(
+      #[values(
+        ActiveLidarrBlock::WantedSearchAllPrompt,
+        ActiveLidarrBlock::WantedSearchSelectedPrompt
+      )]
+      prompt_block: ActiveLidarrBlock,
+    ) {
+      let mut app = App::test_default();
+      app.data.lidarr_data.wanted_missing.set_items(albums());
+      app.push_navigation_stack(ActiveLidarrBlock::WantedMissing.into());
+      app.push_navigation_stack((prompt_block, Some(ActiveLidarrBlock::WantedMissing)).into());
+
+      WantedHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        prompt_block,
+        Some(ActiveLidarrBlock::WantedMissing),
+      )
+      .handle();
+
+      assert!(!app.data.lidarr_data.prompt_confirm);
+      assert_none!(app.data.lidarr_data.prompt_confirm_action);
+      assert_navigation_popped!(app, ActiveLidarrBlock::WantedMissing.into());
+    }
+  }
+
+  mod test_handle_esc {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::assert_navigation_popped;
+
+    const ESC_KEY: Key = DEFAULT_KEYBINDINGS.esc.key;
+
+    #[rstest]
+    fn test_wanted_prompt_esc(
+      #[values(
+        ActiveLidarrBlock::WantedSearchAllPrompt,
+        ActiveLidarrBlock::WantedSearchSelectedPrompt
+      )]
+      prompt_block: ActiveLidarrBlock,
+    ) {
+      let mut app = App::test_default();
+      app.data.lidarr_data.prompt_confirm = true;
+      app.push_navigation_stack(ActiveLidarrBlock::WantedMissing.into());
+      app.push_navigation_stack((prompt_block, Some(ActiveLidarrBlock::WantedMissing)).into());
+
+      WantedHandler::new(
+        ESC_KEY,
+        &mut app,
+        prompt_block,
+        Some(ActiveLidarrBlock::WantedMissing),
+      )
+      .handle();
+
+      assert_navigation_popped!(app, ActiveLidarrBlock::WantedMissing.into());
+      assert!(!app.data.lidarr_data.prompt_confirm);
+    }
+
+    #[rstest]
+    fn test_wanted_esc_clears_errors(
+      #[values(ActiveLidarrBlock::WantedMissing, ActiveLidarrBlock::WantedCutoffUnmet)]
+      active_lidarr_block: ActiveLidarrBlock,
+    ) {
+      let mut app = App::test_default();
+      app.push_navigation_stack(active_lidarr_block.into());
+      app.error = "test error".to_owned().into();
+
+      WantedHandler::new(ESC_KEY, &mut app, active_lidarr_block, None).handle();
+
+      assert_eq!(app.get_current_route(), active_lidarr_block.into());
+      assert_is_empty!(app.error.text);
+    }
+  }
+
+  mod test_handle_key_char {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::assert_navigation_popped;
+    use crate::network::lidarr_network::LidarrEvent;
+
+    #[rstest]
+    #[case(ActiveLidarrBlock::WantedMissing, ActiveLidarrBlock::WantedCutoffUnmet)]
+    #[case(ActiveLidarrBlock::WantedCutoffUnmet, ActiveLidarrBlock::WantedMissing)]
+    fn test_toggle_view_key(
+      #[case] active_lidarr_block: ActiveLidarrBlock,
+      #[case] expected_lidarr_block: ActiveLidarrBlock,
+    ) {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveLidarrBlock::Artists.into());
+      app.push_navigation_stack(active_lidarr_block.into());
+
+      WantedHandler::new(
+        DEFAULT_KEYBINDINGS.toggle_view.key,
+        &mut app,
+        active_lidarr_block,
+        None,
+      )
+      .handle();
+
+      assert_navigation_pushed!(app, expected_lidarr_block.into());
+      app.pop_navigation_stack();
+      assert_eq!(app.get_current_route(), ActiveLidarrBlock::Artists.into());
+    }
+
+    #[rstest]
+    fn test_search_all_key(
+      #[values(ActiveLidarrBlock::WantedMissing, ActiveLidarrBlock::WantedCutoffUnmet)]
+      active_lidarr_block: ActiveLidarrBlock,
+    ) {
+      let mut app = App::test_default();
+      app.push_navigation_stack(active_lidarr_block.into());
+
+      WantedHandler::new(
+        DEFAULT_KEYBINDINGS.auto_search.key,
+        &mut app,
+        active_lidarr_block,
+        None,
+      )
+      .handle();
+
+      assert_navigation_pushed!(
+        app,
+        (
+          ActiveLidarrBlock::WantedSearchAllPrompt,
+          Some(active_lidarr_block)
+        )
+          .into()
+      );
+    }
+
+    #[test]
+    fn test_search_all_key_no_op_when_not_ready() {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.push_navigation_stack(ActiveLidarrBlock::WantedMissing.into());
+
+      WantedHandler::new(
+        DEFAULT_KEYBINDINGS.auto_search.key,
+        &mut app,
+        ActiveLidarrBlock::WantedMissing,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveLidarrBlock::WantedMissing.into()
+      );
+    }
+
+    #[rstest]
+    fn test_refresh_wanted_key(
+      #[values(ActiveLidarrBlock::WantedMissing, ActiveLidarrBlock::WantedCutoffUnmet)]
+      active_lidarr_block: ActiveLidarrBlock,
+    ) {
+      let mut app = App::test_default();
+      app.push_navigation_stack(active_lidarr_block.into());
+
+      WantedHandler::new(
+        DEFAULT_KEYBINDINGS.refresh.key,
+        &mut app,
+        active_lidarr_block,
+        None,
+      )
+      .handle();
+
+      assert_eq!(app.get_current_route(), active_lidarr_block.into());
+      assert!(app.should_refresh);
+    }
+
+    #[rstest]
+    #[case(
+      ActiveLidarrBlock::WantedMissing,
+      ActiveLidarrBlock::WantedSearchSelectedPrompt,
+      LidarrEvent::TriggerAutomaticAlbumSearch(1)
+    )]
+    #[case(
+      ActiveLidarrBlock::WantedCutoffUnmet,
+      ActiveLidarrBlock::WantedSearchAllPrompt,
+      LidarrEvent::TriggerCutoffUnmetAlbumSearch
+    )]
+    fn test_wanted_prompt_confirm(
+      #[case] base_route: ActiveLidarrBlock,
+      #[case] prompt_block: ActiveLidarrBlock,
+      #[case] expected_action: LidarrEvent,
+    ) {
+      let mut app = App::test_default();
+      app.data.lidarr_data.wanted_missing.set_items(albums());
+      app.push_navigation_stack(base_route.into());
+      app.push_navigation_stack((prompt_block, Some(base_route)).into());
+
+      WantedHandler::new(
+        DEFAULT_KEYBINDINGS.confirm.key,
+        &mut app,
+        prompt_block,
+        Some(base_route),
+      )
+      .handle();
+
+      assert!(app.data.lidarr_data.prompt_confirm);
+      assert_some_eq_x!(
+        &app.data.lidarr_data.prompt_confirm_action,
+        &expected_action
+      );
+      assert_navigation_popped!(app, base_route.into());
+    }
+  }
+
+  #[test]
+  fn test_wanted_handler_accepts() {
+    ActiveLidarrBlock::iter().for_each(|active_lidarr_block| {
+      if WANTED_BLOCKS.contains(&active_lidarr_block) {
+        assert!(WantedHandler::accepts(active_lidarr_block));
+      } else {
+        assert!(!WantedHandler::accepts(active_lidarr_block));
+      }
+    })
+  }
+
+  #[rstest]
+  fn test_wanted_handler_ignore_special_keys(
+    #[values(true, false)] ignore_special_keys_for_textbox_input: bool,
+  ) {
+    let mut app = App::test_default();
+    app.ignore_special_keys_for_textbox_input = ignore_special_keys_for_textbox_input;
+    let handler = WantedHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveLidarrBlock::default(),
+      None,
+    );
+
+    assert_eq!(
+      handler.ignore_special_keys(),
+      ignore_special_keys_for_textbox_input
+    );
+  }
+
+  #[test]
+  fn test_wanted_handler_not_ready_when_loading() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveLidarrBlock::WantedMissing.into());
+    app.is_loading = true;
+
+    let handler = WantedHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveLidarrBlock::WantedMissing,
+      None,
+    );
+
+    assert!(!handler.is_ready());
+  }
+
+  #[test]
+  fn test_wanted_handler_ready_when_not_loading() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveLidarrBlock::WantedMissing.into());
+    app.is_loading = false;
+
+    let handler = WantedHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveLidarrBlock::WantedMissing,
+      None,
+    );
+
+    assert!(handler.is_ready());
+  }
+
+  fn albums() -> Vec<Album> {
+    vec![
+      Album {
+        id: 1,
+        ..Album::default()
+      },
+      Album {
+        id: 2,
+        ..Album::default()
+      },
+    ]
+  }
+}
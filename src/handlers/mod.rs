@@ -1,12 +1,14 @@
 use lidarr_handlers::LidarrHandler;
+use prowlarr_handlers::ProwlarrHandler;
 use radarr_handlers::RadarrHandler;
+use readarr_handlers::ReadarrHandler;
 use sonarr_handlers::SonarrHandler;
 
 use crate::app::App;
 use crate::app::context_clues::{
   ContextClueProvider, SERVARR_CONTEXT_CLUES, ServarrContextClueProvider,
 };
-use crate::app::key_binding::KeyBinding;
+use crate::app::key_binding::{DEFAULT_KEYBINDINGS, KeyBinding, keybindings};
 use crate::event::Key;
 use crate::handlers::keybinding_handler::KeybindingHandler;
 use crate::matches_key;
@@ -17,7 +19,9 @@ use crate::models::{HorizontallyScrollableText, Route};
 
 mod keybinding_handler;
 mod lidarr_handlers;
+mod prowlarr_handlers;
 mod radarr_handlers;
+mod readarr_handlers;
 mod sonarr_handlers;
 
 #[cfg(test)]
@@ -29,6 +33,33 @@ mod handlers_tests;
 pub mod handler_test_utils;
 mod table_handler;
 
+/// The bindings that are matched in every route besides those listed in its context clues: the
+/// global bindings, those every [KeyEventHandler] dispatches on, and those of the table handler
+pub static HANDLER_KEYBINDINGS: [KeyBinding; 22] = [
+  DEFAULT_KEYBINDINGS.next_servarr,
+  DEFAULT_KEYBINDINGS.previous_servarr,
+  DEFAULT_KEYBINDINGS.help,
+  DEFAULT_KEYBINDINGS.quit,
+  DEFAULT_KEYBINDINGS.up,
+  DEFAULT_KEYBINDINGS.down,
+  DEFAULT_KEYBINDINGS.home,
+  DEFAULT_KEYBINDINGS.end,
+  DEFAULT_KEYBINDINGS.delete,
+  DEFAULT_KEYBINDINGS.left,
+  DEFAULT_KEYBINDINGS.right,
+  DEFAULT_KEYBINDINGS.submit,
+  DEFAULT_KEYBINDINGS.esc,
+  DEFAULT_KEYBINDINGS.pg_up,
+  DEFAULT_KEYBINDINGS.pg_down,
+  DEFAULT_KEYBINDINGS.filter,
+  DEFAULT_KEYBINDINGS.search,
+  DEFAULT_KEYBINDINGS.sort,
+  DEFAULT_KEYBINDINGS.select,
+  DEFAULT_KEYBINDINGS.select_all,
+  DEFAULT_KEYBINDINGS.visual_select,
+  DEFAULT_KEYBINDINGS.backspace,
+];
+
 pub trait KeyEventHandler<'a, 'b, T: Into<Route> + Copy> {
   fn handle_key_event(&mut self) {
     let key = self.get_key();
@@ -132,13 +163,21 @@ pub fn handle_events(key: Key, app: &mut App<'_>) {
       Route::Lidarr(active_lidarr_block, context) => {
         LidarrHandler::new(key, app, active_lidarr_block, context).handle()
       }
+      Route::Prowlarr(active_prowlarr_block, context) => {
+        ProwlarrHandler::new(key, app, active_prowlarr_block, context).handle()
+      }
+      Route::Readarr(active_readarr_block, context) => {
+        ReadarrHandler::new(key, app, active_readarr_block, context).handle()
+      }
       _ => (),
     }
   }
 }
 
 pub fn populate_keymapping_table(app: &mut App<'_>) {
+  let active_keybindings = keybindings();
   let context_clue_to_keybinding_item = |key: &KeyBinding, desc: &&str| {
+    let key = active_keybindings.resolve(key);
     let (key, alt_key) = if let Some(key1) = key.alt {
       (key.key.to_string(), key1.to_string())
     } else {
@@ -197,6 +236,12 @@ fn handle_prompt_toggle(app: &mut App<'_>, key: Key) {
       Route::Lidarr(_, _) => {
         app.data.lidarr_data.prompt_confirm = !app.data.lidarr_data.prompt_confirm
       }
+      Route::Prowlarr(_, _) => {
+        app.data.prowlarr_data.prompt_confirm = !app.data.prowlarr_data.prompt_confirm
+      }
+      Route::Readarr(_, _) => {
+        app.data.readarr_data.prompt_confirm = !app.data.readarr_data.prompt_confirm
+      }
       _ => (),
     },
     _ => (),
@@ -207,10 +252,10 @@ fn handle_prompt_toggle(app: &mut App<'_>, key: Key) {
 macro_rules! handle_text_box_left_right_keys {
   ($self:expr, $key:expr, $input:expr) => {
     match $self.key {
-      _ if $key == $crate::app::key_binding::DEFAULT_KEYBINDINGS.left.key => {
+      _ if $key == $crate::app::key_binding::keybindings().left.key => {
         $input.scroll_left();
       }
-      _ if $key == $crate::app::key_binding::DEFAULT_KEYBINDINGS.right.key => {
+      _ if $key == $crate::app::key_binding::keybindings().right.key => {
         $input.scroll_right();
       }
       _ => (),
@@ -0,0 +1,429 @@
+#[cfg(test)]
+mod tests {
+  use rstest::rstest;
+  use strum::IntoEnumIterator;
+
+  use crate::app::App;
+  use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+  use crate::event::Key;
+  use crate::handlers::KeyEventHandler;
+  use crate::handlers::prowlarr_handlers::applications::ApplicationsHandler;
+  use crate::models::prowlarr_models::{Application, ProwlarrTaskName};
+  use crate::models::servarr_data::prowlarr::prowlarr_data::{
+    APPLICATIONS_BLOCKS, ActiveProwlarrBlock,
+  };
+  use crate::network::prowlarr_network::ProwlarrEvent;
+  use crate::network::prowlarr_network::prowlarr_network_test_utils::test_utils::application;
+  use crate::{assert_navigation_popped, assert_navigation_pushed};
+
+  mod test_handle_delete {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    const DELETE_KEY: Key = DEFAULT_KEYBINDINGS.delete.key;
+
+    #[test]
+    fn test_delete_application_prompt() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveProwlarrBlock::Applications.into());
+      app
+        .data
+        .prowlarr_data
+        .applications
+        .set_items(vec![Application::default()]);
+
+      ApplicationsHandler::new(
+        DELETE_KEY,
+        &mut app,
+        ActiveProwlarrBlock::Applications,
+        None,
+      )
+      .handle();
+
+      assert_navigation_pushed!(app, ActiveProwlarrBlock::DeleteApplicationPrompt.into());
+    }
+
+    #[test]
+    fn test_delete_application_prompt_no_op_when_not_ready() {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.push_navigation_stack(ActiveProwlarrBlock::Applications.into());
+      app
+        .data
+        .prowlarr_data
+        .applications
+        .set_items(vec![Application::default()]);
+
+      ApplicationsHandler::new(
+        DELETE_KEY,
+        &mut app,
+        ActiveProwlarrBlock::Applications,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveProwlarrBlock::Applications.into()
+      );
+    }
+  }
+
+  mod test_handle_left_right_action {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[rstest]
+    fn test_applications_tab_left(#[values(true, false)] is_ready: bool) {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveProwlarrBlock::Applications.into());
+      app.is_loading = is_ready;
+      app.data.prowlarr_data.main_tabs.set_index(2);
+
+      ApplicationsHandler::new(
+        DEFAULT_KEYBINDINGS.left.key,
+        &mut app,
+        ActiveProwlarrBlock::Applications,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.data.prowlarr_data.main_tabs.get_active_route(),
+        ActiveProwlarrBlock::SearchReleases.into()
+      );
+      assert_navigation_pushed!(app, ActiveProwlarrBlock::SearchReleases.into());
+    }
+
+    #[rstest]
+    fn test_applications_tab_right(#[values(true, false)] is_ready: bool) {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveProwlarrBlock::Applications.into());
+      app.is_loading = is_ready;
+      app.data.prowlarr_data.main_tabs.set_index(2);
+
+      ApplicationsHandler::new(
+        DEFAULT_KEYBINDINGS.right.key,
+        &mut app,
+        ActiveProwlarrBlock::Applications,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.data.prowlarr_data.main_tabs.get_active_route(),
+        ActiveProwlarrBlock::IndexerProxies.into()
+      );
+      assert_navigation_pushed!(app, ActiveProwlarrBlock::IndexerProxies.into());
+    }
+
+    #[rstest]
+    fn test_left_right_prompt_toggle(
+      #[values(
+        ActiveProwlarrBlock::DeleteApplicationPrompt,
+        ActiveProwlarrBlock::SyncAppIndexersPrompt
+      )]
+      active_prowlarr_block: ActiveProwlarrBlock,
+      #[values(DEFAULT_KEYBINDINGS.left.key, DEFAULT_KEYBINDINGS.right.key)] key: Key,
+    ) {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveProwlarrBlock::Applications.into());
+      app.push_navigation_stack(active_prowlarr_block.into());
+
+      ApplicationsHandler::new(key, &mut app, active_prowlarr_block, None).handle();
+
+      assert!(app.data.prowlarr_data.prompt_confirm);
+
+      ApplicationsHandler::new(key, &mut app, active_prowlarr_block, None).handle();
+
+      assert!(!app.data.prowlarr_data.prompt_confirm);
+    }
+  }
+
+  mod test_handle_submit {
+    use super::*;
+
+    const SUBMIT_KEY: Key = DEFAULT_KEYBINDINGS.submit.key;
+
+    #[rstest]
+    #[case(
+      ActiveProwlarrBlock::DeleteApplicationPrompt,
+      ProwlarrEvent::DeleteApplication(1)
+    )]
+    #[case(
+      ActiveProwlarrBlock::SyncAppIndexersPrompt,
+      ProwlarrEvent::StartTask(ProwlarrTaskName::ApplicationIndexerSync)
+    )]
+    fn test_applications_prompt_confirm_submit(
+      #[case] prompt_block: ActiveProwlarrBlock,
+      #[case] expected_action: ProwlarrEvent,
+    ) {
+      let mut app = App::test_default();
+      app
+        .data
+        .prowlarr_data
+        .applications
+        .set_items(vec![application()]);
+      app.data.prowlarr_data.prompt_confirm = true;
+      app.push_navigation_stack(ActiveProwlarrBlock::Applications.into());
+      app.push_navigation_stack(prompt_block.into());
+
+      ApplicationsHandler::new(SUBMIT_KEY, &mut app, prompt_block, None).handle();
+
+      assert!(app.data.prowlarr_data.prompt_confirm);
+      assert_some_eq_x!(
+        &app.data.prowlarr_data.prompt_confirm_action,
+        &expected_action
+      );
+      assert_navigation_popped!(app, ActiveProwlarrBlock::Applications.into());
+    }
+
+    #[rstest]
+    fn test_applications_prompt_decline_submit(
+      #[values(
+        ActiveProwlarrBlock::DeleteApplicationPrompt,
+        ActiveProwlarrBlock::SyncAppIndexersPrompt
+      )]
+      prompt_block: ActiveProwlarrBlock,
+    ) {
+      let mut app = App::test_default();
+      app
+        .data
+        .prowlarr_data
+        .applications
+        .set_items(vec![application()]);
+      app.push_navigation_stack(ActiveProwlarrBlock::Applications.into());
+      app.push_navigation_stack(prompt_block.into());
+
+      ApplicationsHandler::new(SUBMIT_KEY, &mut app, prompt_block, None).handle();
+
+      assert!(!app.data.prowlarr_data.prompt_confirm);
+      assert_none!(app.data.prowlarr_data.prompt_confirm_action);
+      assert_navigation_popped!(app, ActiveProwlarrBlock::Applications.into());
+    }
+  }
+
+  mod test_handle_esc {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    const ESC_KEY: Key = DEFAULT_KEYBINDINGS.esc.key;
+
+    #[rstest]
+    fn test_applications_prompt_esc(
+      #[values(
+        ActiveProwlarrBlock::DeleteApplicationPrompt,
+        ActiveProwlarrBlock::SyncAppIndexersPrompt
+      )]
+      prompt_block: ActiveProwlarrBlock,
+      #[values(true, false)] is_ready: bool,
+    ) {
+      let mut app = App::test_default();
+      app.is_loading = is_ready;
+      app.push_navigation_stack(ActiveProwlarrBlock::Applications.into());
+      app.push_navigation_stack(prompt_block.into());
+      app.data.prowlarr_data.prompt_confirm = true;
+
+      ApplicationsHandler::new(ESC_KEY, &mut app, prompt_block, None).handle();
+
+      assert_navigation_popped!(app, ActiveProwlarrBlock::Applications.into());
+      assert!(!app.data.prowlarr_data.prompt_confirm);
+    }
+
+    #[rstest]
+    fn test_default_esc(#[values(true, false)] is_ready: bool) {
+      let mut app = App::test_default();
+      app.is_loading = is_ready;
+      app.error = "test error".to_owned().into();
+      app.push_navigation_stack(ActiveProwlarrBlock::Applications.into());
+      app.push_navigation_stack(ActiveProwlarrBlock::Applications.into());
+
+      ApplicationsHandler::new(ESC_KEY, &mut app, ActiveProwlarrBlock::Applications, None).handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveProwlarrBlock::Applications.into()
+      );
+      assert_is_empty!(app.error.text);
+    }
+  }
+
+  mod test_handle_key_char {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_refresh_applications_key() {
+      let mut app = App::test_default();
+      app
+        .data
+        .prowlarr_data
+        .applications
+        .set_items(vec![application()]);
+      app.push_navigation_stack(ActiveProwlarrBlock::Applications.into());
+
+      ApplicationsHandler::new(
+        DEFAULT_KEYBINDINGS.refresh.key,
+        &mut app,
+        ActiveProwlarrBlock::Applications,
+        None,
+      )
+      .handle();
+
+      assert_navigation_pushed!(app, ActiveProwlarrBlock::Applications.into());
+      assert!(app.should_refresh);
+    }
+
+    #[test]
+    fn test_sync_app_indexers_key() {
+      let mut app = App::test_default();
+      app
+        .data
+        .prowlarr_data
+        .applications
+        .set_items(vec![application()]);
+      app.push_navigation_stack(ActiveProwlarrBlock::Applications.into());
+
+      ApplicationsHandler::new(
+        DEFAULT_KEYBINDINGS.update.key,
+        &mut app,
+        ActiveProwlarrBlock::Applications,
+        None,
+      )
+      .handle();
+
+      assert_navigation_pushed!(app, ActiveProwlarrBlock::SyncAppIndexersPrompt.into());
+    }
+
+    #[test]
+    fn test_sync_app_indexers_key_no_op_when_not_ready() {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app
+        .data
+        .prowlarr_data
+        .applications
+        .set_items(vec![application()]);
+      app.push_navigation_stack(ActiveProwlarrBlock::Applications.into());
+
+      ApplicationsHandler::new(
+        DEFAULT_KEYBINDINGS.update.key,
+        &mut app,
+        ActiveProwlarrBlock::Applications,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveProwlarrBlock::Applications.into()
+      );
+    }
+
+    #[rstest]
+    #[case(
+      ActiveProwlarrBlock::DeleteApplicationPrompt,
+      ProwlarrEvent::DeleteApplication(1)
+    )]
+    #[case(
+      ActiveProwlarrBlock::SyncAppIndexersPrompt,
+      ProwlarrEvent::StartTask(ProwlarrTaskName::ApplicationIndexerSync)
+    )]
+    fn test_applications_prompt_confirm(
+      #[case] prompt_block: ActiveProwlarrBlock,
+      #[case] expected_action: ProwlarrEvent,
+    ) {
+      let mut app = App::test_default();
+      app
+        .data
+        .prowlarr_data
+        .applications
+        .set_items(vec![application()]);
+      app.push_navigation_stack(ActiveProwlarrBlock::Applications.into());
+      app.push_navigation_stack(prompt_block.into());
+
+      ApplicationsHandler::new(
+        DEFAULT_KEYBINDINGS.confirm.key,
+        &mut app,
+        prompt_block,
+        None,
+      )
+      .handle();
+
+      assert!(app.data.prowlarr_data.prompt_confirm);
+      assert_some_eq_x!(
+        &app.data.prowlarr_data.prompt_confirm_action,
+        &expected_action
+      );
+      assert_navigation_popped!(app, ActiveProwlarrBlock::Applications.into());
+    }
+  }
+
+  #[test]
+  fn test_applications_handler_accepts() {
+    ActiveProwlarrBlock::iter().for_each(|active_prowlarr_block| {
+      if APPLICATIONS_BLOCKS.contains(&active_prowlarr_block) {
+        assert!(ApplicationsHandler::accepts(active_prowlarr_block));
+      } else {
+        assert!(!ApplicationsHandler::accepts(active_prowlarr_block));
+      }
+    })
+  }
+
+  #[test]
+  fn test_applications_handler_not_ready_when_loading() {
+    let mut app = App::test_default();
+    app.is_loading = true;
+    app
+      .data
+      .prowlarr_data
+      .applications
+      .set_items(vec![application()]);
+
+    let handler = ApplicationsHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveProwlarrBlock::Applications,
+      None,
+    );
+
+    assert!(!handler.is_ready());
+  }
+
+  #[test]
+  fn test_applications_handler_not_ready_when_applications_is_empty() {
+    let mut app = App::test_default();
+
+    let handler = ApplicationsHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveProwlarrBlock::Applications,
+      None,
+    );
+
+    assert!(!handler.is_ready());
+  }
+
+  #[test]
+  fn test_applications_handler_ready_when_not_loading_and_applications_is_not_empty() {
+    let mut app = App::test_default();
+    app
+      .data
+      .prowlarr_data
+      .applications
+      .set_items(vec![application()]);
+
+    let handler = ApplicationsHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveProwlarrBlock::Applications,
+      None,
+    );
+
+    assert!(handler.is_ready());
+  }
+}
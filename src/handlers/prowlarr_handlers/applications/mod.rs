@@ -0,0 +1,175 @@
+use crate::app::App;
+use crate::event::Key;
+use crate::handlers::prowlarr_handlers::handle_change_tab_left_right_keys;
+use crate::handlers::table_handler::{TableHandlingConfig, handle_table};
+use crate::handlers::{KeyEventHandler, handle_clear_errors, handle_prompt_toggle};
+use crate::matches_key;
+use crate::models::Route;
+use crate::models::prowlarr_models::ProwlarrTaskName;
+use crate::models::servarr_data::prowlarr::prowlarr_data::{
+  APPLICATIONS_BLOCKS, ActiveProwlarrBlock,
+};
+use crate::network::prowlarr_network::ProwlarrEvent;
+
+#[cfg(test)]
+#[path = "applications_handler_tests.rs"]
+mod applications_handler_tests;
+
+pub(super) struct ApplicationsHandler<'a, 'b> {
+  key: Key,
+  app: &'a mut App<'b>,
+  active_prowlarr_block: ActiveProwlarrBlock,
+  _context: Option<ActiveProwlarrBlock>,
+}
+
+impl ApplicationsHandler<'_, '_> {
+  fn extract_application_id(&self) -> i64 {
+    self
+      .app
+      .data
+      .prowlarr_data
+      .applications
+      .current_selection()
+      .id
+  }
+
+  fn build_prompt_confirm_action(&self) -> Option<ProwlarrEvent> {
+    match self.active_prowlarr_block {
+      ActiveProwlarrBlock::DeleteApplicationPrompt => Some(ProwlarrEvent::DeleteApplication(
+        self.extract_application_id(),
+      )),
+      ActiveProwlarrBlock::SyncAppIndexersPrompt => Some(ProwlarrEvent::StartTask(
+        ProwlarrTaskName::ApplicationIndexerSync,
+      )),
+      _ => None,
+    }
+  }
+}
+
+impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveProwlarrBlock> for ApplicationsHandler<'a, 'b> {
+  fn handle(&mut self) {
+    let applications_table_handling_config =
+      TableHandlingConfig::new(ActiveProwlarrBlock::Applications.into());
+
+    if !handle_table(
+      self,
+      |app| &mut app.data.prowlarr_data.applications,
+      applications_table_handling_config,
+    ) {
+      self.handle_key_event();
+    }
+  }
+
+  fn accepts(active_block: ActiveProwlarrBlock) -> bool {
+    APPLICATIONS_BLOCKS.contains(&active_block)
+  }
+
+  fn ignore_special_keys(&self) -> bool {
+    self.app.ignore_special_keys_for_textbox_input
+  }
+
+  fn new(
+    key: Key,
+    app: &'a mut App<'b>,
+    active_block: ActiveProwlarrBlock,
+    context: Option<ActiveProwlarrBlock>,
+  ) -> ApplicationsHandler<'a, 'b> {
+    ApplicationsHandler {
+      key,
+      app,
+      active_prowlarr_block: active_block,
+      _context: context,
+    }
+  }
+
+  fn get_key(&self) -> Key {
+    self.key
+  }
+
+  fn is_ready(&self) -> bool {
+    !self.app.is_loading && !self.app.data.prowlarr_data.applications.is_empty()
+  }
+
+  fn handle_scroll_up(&mut self) {}
+
+  fn handle_scroll_down(&mut self) {}
+
+  fn handle_home(&mut self) {}
+
+  fn handle_end(&mut self) {}
+
+  fn handle_delete(&mut self) {
+    if self.active_prowlarr_block == ActiveProwlarrBlock::Applications {
+      self
+        .app
+        .push_navigation_stack(ActiveProwlarrBlock::DeleteApplicationPrompt.into());
+    }
+  }
+
+  fn handle_left_right_action(&mut self) {
+    match self.active_prowlarr_block {
+      ActiveProwlarrBlock::Applications => handle_change_tab_left_right_keys(self.app, self.key),
+      ActiveProwlarrBlock::DeleteApplicationPrompt | ActiveProwlarrBlock::SyncAppIndexersPrompt => {
+        handle_prompt_toggle(self.app, self.key)
+      }
+      _ => (),
+    }
+  }
+
+  fn handle_submit(&mut self) {
+    match self.active_prowlarr_block {
+      ActiveProwlarrBlock::DeleteApplicationPrompt | ActiveProwlarrBlock::SyncAppIndexersPrompt => {
+        if self.app.data.prowlarr_data.prompt_confirm {
+          self.app.data.prowlarr_data.prompt_confirm_action = self.build_prompt_confirm_action();
+        }
+
+        self.app.pop_navigation_stack();
+      }
+      _ => (),
+    }
+  }
+
+  fn handle_esc(&mut self) {
+    match self.active_prowlarr_block {
+      ActiveProwlarrBlock::DeleteApplicationPrompt | ActiveProwlarrBlock::SyncAppIndexersPrompt => {
+        self.app.pop_navigation_stack();
+        self.app.data.prowlarr_data.prompt_confirm = false;
+      }
+      _ => handle_clear_errors(self.app),
+    }
+  }
+
+  fn handle_char_key_event(&mut self) {
+    let key = self.key;
+    match self.active_prowlarr_block {
+      ActiveProwlarrBlock::Applications => match self.key {
+        _ if matches_key!(refresh, key) => {
+          self.app.should_refresh = true;
+        }
+        _ if matches_key!(update, key) => {
+          self
+            .app
+            .push_navigation_stack(ActiveProwlarrBlock::SyncAppIndexersPrompt.into());
+        }
+        _ => (),
+      },
+      ActiveProwlarrBlock::DeleteApplicationPrompt | ActiveProwlarrBlock::SyncAppIndexersPrompt => {
+        if matches_key!(confirm, key) {
+          self.app.data.prowlarr_data.prompt_confirm = true;
+          self.app.data.prowlarr_data.prompt_confirm_action = self.build_prompt_confirm_action();
+
+          self.app.pop_navigation_stack();
+        }
+      }
+      _ => (),
+    }
+  }
+
+  fn app_mut(&mut self) -> &mut App<'b> {
+    self.app
+  }
+
+  fn current_route(&self) -> Route {
+    self.app.get_current_route()
+  }
+}
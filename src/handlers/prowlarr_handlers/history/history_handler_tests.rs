@@ -0,0 +1,385 @@
+#[cfg(test)]
+mod tests {
+  use std::cmp::Ordering;
+
+  use chrono::DateTime;
+  use pretty_assertions::{assert_eq, assert_str_eq};
+  use rstest::rstest;
+  use strum::IntoEnumIterator;
+
+  use crate::app::App;
+  use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+  use crate::assert_navigation_pushed;
+  use crate::event::Key;
+  use crate::handlers::KeyEventHandler;
+  use crate::handlers::prowlarr_handlers::history::{HistoryHandler, history_sorting_options};
+  use crate::models::prowlarr_models::{
+    ProwlarrHistoryData, ProwlarrHistoryEventType, ProwlarrHistoryItem,
+  };
+  use crate::models::servarr_data::prowlarr::prowlarr_data::{ActiveProwlarrBlock, HISTORY_BLOCKS};
+
+  mod test_handle_left_right_action {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::assert_navigation_pushed;
+
+    #[rstest]
+    fn test_history_tab_left(#[values(true, false)] is_ready: bool) {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveProwlarrBlock::History.into());
+      app.is_loading = is_ready;
+      app.data.prowlarr_data.main_tabs.set_index(4);
+
+      HistoryHandler::new(
+        DEFAULT_KEYBINDINGS.left.key,
+        &mut app,
+        ActiveProwlarrBlock::History,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.data.prowlarr_data.main_tabs.get_active_route(),
+        ActiveProwlarrBlock::IndexerProxies.into()
+      );
+      assert_navigation_pushed!(app, ActiveProwlarrBlock::IndexerProxies.into());
+    }
+
+    #[rstest]
+    fn test_history_tab_right(#[values(true, false)] is_ready: bool) {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveProwlarrBlock::History.into());
+      app.is_loading = is_ready;
+      app.data.prowlarr_data.main_tabs.set_index(4);
+
+      HistoryHandler::new(
+        DEFAULT_KEYBINDINGS.right.key,
+        &mut app,
+        ActiveProwlarrBlock::History,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.data.prowlarr_data.main_tabs.get_active_route(),
+        ActiveProwlarrBlock::System.into()
+      );
+      assert_eq!(app.get_current_route(), ActiveProwlarrBlock::System.into());
+    }
+  }
+
+  mod test_handle_submit {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    const SUBMIT_KEY: Key = DEFAULT_KEYBINDINGS.submit.key;
+
+    #[test]
+    fn test_history_submit() {
+      let mut app = App::test_default();
+      app.data.prowlarr_data.history.set_items(history_vec());
+      app.push_navigation_stack(ActiveProwlarrBlock::History.into());
+
+      HistoryHandler::new(SUBMIT_KEY, &mut app, ActiveProwlarrBlock::History, None).handle();
+
+      assert_navigation_pushed!(app, ActiveProwlarrBlock::HistoryItemDetails.into());
+    }
+
+    #[test]
+    fn test_history_submit_no_op_when_not_ready() {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.data.prowlarr_data.history.set_items(history_vec());
+      app.push_navigation_stack(ActiveProwlarrBlock::History.into());
+
+      HistoryHandler::new(SUBMIT_KEY, &mut app, ActiveProwlarrBlock::History, None).handle();
+
+      assert_eq!(app.get_current_route(), ActiveProwlarrBlock::History.into());
+    }
+  }
+
+  mod test_handle_esc {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::assert_navigation_popped;
+
+    const ESC_KEY: Key = DEFAULT_KEYBINDINGS.esc.key;
+
+    #[test]
+    fn test_esc_history_item_details() {
+      let mut app = App::test_default();
+      app
+        .data
+        .prowlarr_data
+        .history
+        .set_items(vec![ProwlarrHistoryItem::default()]);
+      app.push_navigation_stack(ActiveProwlarrBlock::History.into());
+      app.push_navigation_stack(ActiveProwlarrBlock::HistoryItemDetails.into());
+
+      HistoryHandler::new(
+        ESC_KEY,
+        &mut app,
+        ActiveProwlarrBlock::HistoryItemDetails,
+        None,
+      )
+      .handle();
+
+      assert_navigation_popped!(app, ActiveProwlarrBlock::History.into());
+    }
+
+    #[rstest]
+    fn test_default_esc(#[values(true, false)] is_ready: bool) {
+      let mut app = App::test_default();
+      app.is_loading = is_ready;
+      app.error = "test error".to_owned().into();
+      app.push_navigation_stack(ActiveProwlarrBlock::History.into());
+      app.push_navigation_stack(ActiveProwlarrBlock::History.into());
+      app
+        .data
+        .prowlarr_data
+        .history
+        .set_items(vec![ProwlarrHistoryItem::default()]);
+
+      HistoryHandler::new(ESC_KEY, &mut app, ActiveProwlarrBlock::History, None).handle();
+
+      assert_eq!(app.get_current_route(), ActiveProwlarrBlock::History.into());
+      assert_is_empty!(app.error.text);
+    }
+  }
+
+  mod test_handle_key_char {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::assert_navigation_pushed;
+
+    #[test]
+    fn test_refresh_history_key() {
+      let mut app = App::test_default();
+      app.data.prowlarr_data.history.set_items(history_vec());
+      app.push_navigation_stack(ActiveProwlarrBlock::History.into());
+
+      HistoryHandler::new(
+        DEFAULT_KEYBINDINGS.refresh.key,
+        &mut app,
+        ActiveProwlarrBlock::History,
+        None,
+      )
+      .handle();
+
+      assert_navigation_pushed!(app, ActiveProwlarrBlock::History.into());
+      assert!(app.should_refresh);
+    }
+
+    #[test]
+    fn test_refresh_history_key_no_op_when_not_ready() {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.data.prowlarr_data.history.set_items(history_vec());
+      app.push_navigation_stack(ActiveProwlarrBlock::History.into());
+
+      HistoryHandler::new(
+        DEFAULT_KEYBINDINGS.refresh.key,
+        &mut app,
+        ActiveProwlarrBlock::History,
+        None,
+      )
+      .handle();
+
+      assert_eq!(app.get_current_route(), ActiveProwlarrBlock::History.into());
+      assert!(!app.should_refresh);
+    }
+  }
+
+  #[test]
+  fn test_history_sorting_options_event_type() {
+    let expected_cmp_fn: fn(&ProwlarrHistoryItem, &ProwlarrHistoryItem) -> Ordering = |a, b| {
+      a.event_type
+        .to_string()
+        .to_lowercase()
+        .cmp(&b.event_type.to_string().to_lowercase())
+    };
+    let mut expected_history_vec = history_vec();
+    expected_history_vec.sort_by(expected_cmp_fn);
+
+    let sort_option = history_sorting_options()[0].clone();
+    let mut sorted_history_vec = history_vec();
+    sorted_history_vec.sort_by(sort_option.cmp_fn.unwrap());
+
+    assert_eq!(sorted_history_vec, expected_history_vec);
+    assert_str_eq!(sort_option.name, "Event Type");
+  }
+
+  #[test]
+  fn test_history_sorting_options_query() {
+    let expected_cmp_fn: fn(&ProwlarrHistoryItem, &ProwlarrHistoryItem) -> Ordering = |a, b| {
+      a.data
+        .query
+        .as_deref()
+        .unwrap_or_default()
+        .to_lowercase()
+        .cmp(&b.data.query.as_deref().unwrap_or_default().to_lowercase())
+    };
+    let mut expected_history_vec = history_vec();
+    expected_history_vec.sort_by(expected_cmp_fn);
+
+    let sort_option = history_sorting_options()[1].clone();
+    let mut sorted_history_vec = history_vec();
+    sorted_history_vec.sort_by(sort_option.cmp_fn.unwrap());
+
+    assert_eq!(sorted_history_vec, expected_history_vec);
+    assert_str_eq!(sort_option.name, "Query");
+  }
+
+  #[test]
+  fn test_history_sorting_options_successful() {
+    let expected_cmp_fn: fn(&ProwlarrHistoryItem, &ProwlarrHistoryItem) -> Ordering =
+      |a, b| a.successful.cmp(&b.successful);
+    let mut expected_history_vec = history_vec();
+    expected_history_vec.sort_by(expected_cmp_fn);
+
+    let sort_option = history_sorting_options()[2].clone();
+    let mut sorted_history_vec = history_vec();
+    sorted_history_vec.sort_by(sort_option.cmp_fn.unwrap());
+
+    assert_eq!(sorted_history_vec, expected_history_vec);
+    assert_str_eq!(sort_option.name, "Successful");
+  }
+
+  #[test]
+  fn test_history_sorting_options_date() {
+    let expected_cmp_fn: fn(&ProwlarrHistoryItem, &ProwlarrHistoryItem) -> Ordering =
+      |a, b| a.date.cmp(&b.date);
+    let mut expected_history_vec = history_vec();
+    expected_history_vec.sort_by(expected_cmp_fn);
+
+    let sort_option = history_sorting_options()[3].clone();
+    let mut sorted_history_vec = history_vec();
+    sorted_history_vec.sort_by(sort_option.cmp_fn.unwrap());
+
+    assert_eq!(sorted_history_vec, expected_history_vec);
+    assert_str_eq!(sort_option.name, "Date");
+  }
+
+  #[test]
+  fn test_history_handler_accepts() {
+    ActiveProwlarrBlock::iter().for_each(|active_prowlarr_block| {
+      if HISTORY_BLOCKS.contains(&active_prowlarr_block) {
+        assert!(HistoryHandler::accepts(active_prowlarr_block));
+      } else {
+        assert!(!HistoryHandler::accepts(active_prowlarr_block));
+      }
+    })
+  }
+
+  #[rstest]
+  fn test_history_handler_ignore_special_keys(
+    #[values(true, false)] ignore_special_keys_for_textbox_input: bool,
+  ) {
+    let mut app = App::test_default();
+    app.ignore_special_keys_for_textbox_input = ignore_special_keys_for_textbox_input;
+    let handler = HistoryHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveProwlarrBlock::default(),
+      None,
+    );
+
+    assert_eq!(
+      handler.ignore_special_keys(),
+      ignore_special_keys_for_textbox_input
+    );
+  }
+
+  #[test]
+  fn test_history_handler_not_ready_when_loading() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveProwlarrBlock::History.into());
+    app.is_loading = true;
+
+    let handler = HistoryHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveProwlarrBlock::History,
+      None,
+    );
+
+    assert!(!handler.is_ready());
+  }
+
+  #[test]
+  fn test_history_handler_not_ready_when_history_is_empty() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveProwlarrBlock::History.into());
+    app.is_loading = false;
+
+    let handler = HistoryHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveProwlarrBlock::History,
+      None,
+    );
+
+    assert!(!handler.is_ready());
+  }
+
+  #[test]
+  fn test_history_handler_ready_when_not_loading_and_history_is_not_empty() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveProwlarrBlock::History.into());
+    app.is_loading = false;
+    app
+      .data
+      .prowlarr_data
+      .history
+      .set_items(vec![ProwlarrHistoryItem::default()]);
+
+    let handler = HistoryHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveProwlarrBlock::History,
+      None,
+    );
+
+    assert!(handler.is_ready());
+  }
+
+  fn history_vec() -> Vec<ProwlarrHistoryItem> {
+    vec![
+      ProwlarrHistoryItem {
+        id: 3,
+        event_type: ProwlarrHistoryEventType::ReleaseGrabbed,
+        successful: true,
+        data: ProwlarrHistoryData {
+          query: Some("test 1".to_owned()),
+          ..ProwlarrHistoryData::default()
+        },
+        date: DateTime::from(DateTime::parse_from_rfc3339("2024-01-10T07:28:45Z").unwrap()),
+        ..ProwlarrHistoryItem::default()
+      },
+      ProwlarrHistoryItem {
+        id: 2,
+        event_type: ProwlarrHistoryEventType::IndexerQuery,
+        successful: false,
+        data: ProwlarrHistoryData {
+          query: Some("test 2".to_owned()),
+          ..ProwlarrHistoryData::default()
+        },
+        date: DateTime::from(DateTime::parse_from_rfc3339("2024-02-10T07:28:45Z").unwrap()),
+        ..ProwlarrHistoryItem::default()
+      },
+      ProwlarrHistoryItem {
+        id: 1,
+        event_type: ProwlarrHistoryEventType::IndexerRss,
+        successful: true,
+        date: DateTime::from(DateTime::parse_from_rfc3339("2024-03-10T07:28:45Z").unwrap()),
+        ..ProwlarrHistoryItem::default()
+      },
+    ]
+  }
+}
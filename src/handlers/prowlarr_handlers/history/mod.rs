@@ -0,0 +1,162 @@
+use crate::app::App;
+use crate::event::Key;
+use crate::handlers::prowlarr_handlers::handle_change_tab_left_right_keys;
+use crate::handlers::table_handler::{TableHandlingConfig, handle_table};
+use crate::handlers::{KeyEventHandler, handle_clear_errors};
+use crate::matches_key;
+use crate::models::Route;
+use crate::models::prowlarr_models::ProwlarrHistoryItem;
+use crate::models::servarr_data::prowlarr::prowlarr_data::{ActiveProwlarrBlock, HISTORY_BLOCKS};
+use crate::models::stateful_table::SortOption;
+
+#[cfg(test)]
+#[path = "history_handler_tests.rs"]
+mod history_handler_tests;
+
+pub(super) struct HistoryHandler<'a, 'b> {
+  key: Key,
+  app: &'a mut App<'b>,
+  active_prowlarr_block: ActiveProwlarrBlock,
+  _context: Option<ActiveProwlarrBlock>,
+}
+
+impl HistoryHandler<'_, '_> {}
+
+impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveProwlarrBlock> for HistoryHandler<'a, 'b> {
+  fn handle(&mut self) {
+    let history_table_handling_config =
+      TableHandlingConfig::new(ActiveProwlarrBlock::History.into())
+        .sorting_block(ActiveProwlarrBlock::HistorySortPrompt.into())
+        .sort_options(history_sorting_options())
+        .searching_block(ActiveProwlarrBlock::SearchHistory.into())
+        .search_error_block(ActiveProwlarrBlock::SearchHistoryError.into())
+        .search_field_fn(|history| history.data.query.as_deref().unwrap_or_default())
+        .filtering_block(ActiveProwlarrBlock::FilterHistory.into())
+        .filter_error_block(ActiveProwlarrBlock::FilterHistoryError.into())
+        .filter_field_fn(|history| history.data.query.as_deref().unwrap_or_default());
+
+    if !handle_table(
+      self,
+      |app| &mut app.data.prowlarr_data.history,
+      history_table_handling_config,
+    ) {
+      self.handle_key_event();
+    }
+  }
+
+  fn accepts(active_block: ActiveProwlarrBlock) -> bool {
+    HISTORY_BLOCKS.contains(&active_block)
+  }
+
+  fn new(
+    key: Key,
+    app: &'a mut App<'b>,
+    active_block: ActiveProwlarrBlock,
+    context: Option<ActiveProwlarrBlock>,
+  ) -> Self {
+    HistoryHandler {
+      key,
+      app,
+      active_prowlarr_block: active_block,
+      _context: context,
+    }
+  }
+
+  fn get_key(&self) -> Key {
+    self.key
+  }
+
+  fn ignore_special_keys(&self) -> bool {
+    self.app.ignore_special_keys_for_textbox_input
+  }
+
+  fn is_ready(&self) -> bool {
+    !self.app.is_loading && !self.app.data.prowlarr_data.history.is_empty()
+  }
+
+  fn handle_scroll_up(&mut self) {}
+
+  fn handle_scroll_down(&mut self) {}
+
+  fn handle_home(&mut self) {}
+
+  fn handle_end(&mut self) {}
+
+  fn handle_delete(&mut self) {}
+
+  fn handle_left_right_action(&mut self) {
+    if self.active_prowlarr_block == ActiveProwlarrBlock::History {
+      handle_change_tab_left_right_keys(self.app, self.key)
+    }
+  }
+
+  fn handle_submit(&mut self) {
+    if self.active_prowlarr_block == ActiveProwlarrBlock::History {
+      self
+        .app
+        .push_navigation_stack(ActiveProwlarrBlock::HistoryItemDetails.into());
+    }
+  }
+
+  fn handle_esc(&mut self) {
+    if self.active_prowlarr_block == ActiveProwlarrBlock::HistoryItemDetails {
+      self.app.pop_navigation_stack();
+    } else {
+      handle_clear_errors(self.app);
+    }
+  }
+
+  fn handle_char_key_event(&mut self) {
+    let key = self.key;
+    if self.active_prowlarr_block == ActiveProwlarrBlock::History {
+      match self.key {
+        _ if matches_key!(refresh, key) => {
+          self.app.should_refresh = true;
+        }
+        _ => (),
+      }
+    }
+  }
+
+  fn app_mut(&mut self) -> &mut App<'b> {
+    self.app
+  }
+
+  fn current_route(&self) -> Route {
+    self.app.get_current_route()
+  }
+}
+
+pub(in crate::handlers::prowlarr_handlers) fn history_sorting_options()
+-> Vec<SortOption<ProwlarrHistoryItem>> {
+  vec![
+    SortOption {
+      name: "Event Type",
+      cmp_fn: Some(|a, b| {
+        a.event_type
+          .to_string()
+          .to_lowercase()
+          .cmp(&b.event_type.to_string().to_lowercase())
+      }),
+    },
+    SortOption {
+      name: "Query",
+      cmp_fn: Some(|a, b| {
+        a.data
+          .query
+          .as_deref()
+          .unwrap_or_default()
+          .to_lowercase()
+          .cmp(&b.data.query.as_deref().unwrap_or_default().to_lowercase())
+      }),
+    },
+    SortOption {
+      name: "Successful",
+      cmp_fn: Some(|a, b| a.successful.cmp(&b.successful)),
+    },
+    SortOption {
+      name: "Date",
+      cmp_fn: Some(|a, b| a.date.cmp(&b.date)),
+    },
+  ]
+}
@@ -0,0 +1,357 @@
+#[cfg(test)]
+mod tests {
+  use rstest::rstest;
+  use strum::IntoEnumIterator;
+
+  use crate::app::App;
+  use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+  use crate::event::Key;
+  use crate::handlers::KeyEventHandler;
+  use crate::handlers::prowlarr_handlers::indexer_proxies::IndexerProxiesHandler;
+  use crate::models::servarr_data::prowlarr::prowlarr_data::{
+    ActiveProwlarrBlock, INDEXER_PROXIES_BLOCKS,
+  };
+  use crate::network::prowlarr_network::ProwlarrEvent;
+  use crate::network::prowlarr_network::prowlarr_network_test_utils::test_utils::indexer_proxy;
+  use crate::{assert_navigation_popped, assert_navigation_pushed};
+
+  mod test_handle_delete {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    const DELETE_KEY: Key = DEFAULT_KEYBINDINGS.delete.key;
+
+    #[test]
+    fn test_delete_indexer_proxy_prompt() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveProwlarrBlock::IndexerProxies.into());
+      app
+        .data
+        .prowlarr_data
+        .indexer_proxies
+        .set_items(vec![indexer_proxy()]);
+
+      IndexerProxiesHandler::new(
+        DELETE_KEY,
+        &mut app,
+        ActiveProwlarrBlock::IndexerProxies,
+        None,
+      )
+      .handle();
+
+      assert_navigation_pushed!(app, ActiveProwlarrBlock::DeleteIndexerProxyPrompt.into());
+    }
+
+    #[test]
+    fn test_delete_indexer_proxy_prompt_no_op_when_not_ready() {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.push_navigation_stack(ActiveProwlarrBlock::IndexerProxies.into());
+      app
+        .data
+        .prowlarr_data
+        .indexer_proxies
+        .set_items(vec![indexer_proxy()]);
+
+      IndexerProxiesHandler::new(
+        DELETE_KEY,
+        &mut app,
+        ActiveProwlarrBlock::IndexerProxies,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveProwlarrBlock::IndexerProxies.into()
+      );
+    }
+  }
+
+  mod test_handle_left_right_action {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[rstest]
+    fn test_indexer_proxies_tab_left(#[values(true, false)] is_ready: bool) {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveProwlarrBlock::IndexerProxies.into());
+      app.is_loading = is_ready;
+      app.data.prowlarr_data.main_tabs.set_index(3);
+
+      IndexerProxiesHandler::new(
+        DEFAULT_KEYBINDINGS.left.key,
+        &mut app,
+        ActiveProwlarrBlock::IndexerProxies,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.data.prowlarr_data.main_tabs.get_active_route(),
+        ActiveProwlarrBlock::Applications.into()
+      );
+      assert_navigation_pushed!(app, ActiveProwlarrBlock::Applications.into());
+    }
+
+    #[rstest]
+    fn test_indexer_proxies_tab_right(#[values(true, false)] is_ready: bool) {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveProwlarrBlock::IndexerProxies.into());
+      app.is_loading = is_ready;
+      app.data.prowlarr_data.main_tabs.set_index(3);
+
+      IndexerProxiesHandler::new(
+        DEFAULT_KEYBINDINGS.right.key,
+        &mut app,
+        ActiveProwlarrBlock::IndexerProxies,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.data.prowlarr_data.main_tabs.get_active_route(),
+        ActiveProwlarrBlock::History.into()
+      );
+      assert_navigation_pushed!(app, ActiveProwlarrBlock::History.into());
+    }
+
+    #[rstest]
+    fn test_left_right_delete_indexer_proxy_prompt_toggle(
+      #[values(DEFAULT_KEYBINDINGS.left.key, DEFAULT_KEYBINDINGS.right.key)] key: Key,
+    ) {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveProwlarrBlock::IndexerProxies.into());
+      app.push_navigation_stack(ActiveProwlarrBlock::DeleteIndexerProxyPrompt.into());
+
+      IndexerProxiesHandler::new(
+        key,
+        &mut app,
+        ActiveProwlarrBlock::DeleteIndexerProxyPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(app.data.prowlarr_data.prompt_confirm);
+
+      IndexerProxiesHandler::new(
+        key,
+        &mut app,
+        ActiveProwlarrBlock::DeleteIndexerProxyPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(!app.data.prowlarr_data.prompt_confirm);
+    }
+  }
+
+  mod test_handle_submit {
+    use super::*;
+
+    const SUBMIT_KEY: Key = DEFAULT_KEYBINDINGS.submit.key;
+
+    #[test]
+    fn test_delete_indexer_proxy_prompt_confirm_submit() {
+      let mut app = App::test_default();
+      app
+        .data
+        .prowlarr_data
+        .indexer_proxies
+        .set_items(vec![indexer_proxy()]);
+      app.data.prowlarr_data.prompt_confirm = true;
+      app.push_navigation_stack(ActiveProwlarrBlock::IndexerProxies.into());
+      app.push_navigation_stack(ActiveProwlarrBlock::DeleteIndexerProxyPrompt.into());
+
+      IndexerProxiesHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveProwlarrBlock::DeleteIndexerProxyPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(app.data.prowlarr_data.prompt_confirm);
+      assert_some_eq_x!(
+        &app.data.prowlarr_data.prompt_confirm_action,
+        &ProwlarrEvent::DeleteIndexerProxy(1)
+      );
+      assert_navigation_popped!(app, ActiveProwlarrBlock::IndexerProxies.into());
+    }
+
+    #[test]
+    fn test_delete_indexer_proxy_prompt_decline_submit() {
+      let mut app = App::test_default();
+      app
+        .data
+        .prowlarr_data
+        .indexer_proxies
+        .set_items(vec![indexer_proxy()]);
+      app.push_navigation_stack(ActiveProwlarrBlock::IndexerProxies.into());
+      app.push_navigation_stack(ActiveProwlarrBlock::DeleteIndexerProxyPrompt.into());
+
+      IndexerProxiesHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveProwlarrBlock::DeleteIndexerProxyPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(!app.data.prowlarr_data.prompt_confirm);
+      assert_none!(app.data.prowlarr_data.prompt_confirm_action);
+      assert_navigation_popped!(app, ActiveProwlarrBlock::IndexerProxies.into());
+    }
+  }
+
+  mod test_handle_esc {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    const ESC_KEY: Key = DEFAULT_KEYBINDINGS.esc.key;
+
+    #[rstest]
+    fn test_delete_indexer_proxy_prompt_esc(#[values(true, false)] is_ready: bool) {
+      let mut app = App::test_default();
+      app.is_loading = is_ready;
+      app.push_navigation_stack(ActiveProwlarrBlock::IndexerProxies.into());
+      app.push_navigation_stack(ActiveProwlarrBlock::DeleteIndexerProxyPrompt.into());
+      app.data.prowlarr_data.prompt_confirm = true;
+
+      IndexerProxiesHandler::new(
+        ESC_KEY,
+        &mut app,
+        ActiveProwlarrBlock::DeleteIndexerProxyPrompt,
+        None,
+      )
+      .handle();
+
+      assert_navigation_popped!(app, ActiveProwlarrBlock::IndexerProxies.into());
+      assert!(!app.data.prowlarr_data.prompt_confirm);
+    }
+
+    #[rstest]
+    fn test_default_esc(#[values(true, false)] is_ready: bool) {
+      let mut app = App::test_default();
+      app.is_loading = is_ready;
+      app.error = "test error".to_owned().into();
+      app.push_navigation_stack(ActiveProwlarrBlock::IndexerProxies.into());
+      app.push_navigation_stack(ActiveProwlarrBlock::IndexerProxies.into());
+
+      IndexerProxiesHandler::new(ESC_KEY, &mut app, ActiveProwlarrBlock::IndexerProxies, None)
+        .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveProwlarrBlock::IndexerProxies.into()
+      );
+      assert_is_empty!(app.error.text);
+    }
+  }
+
+  mod test_handle_key_char {
+    use super::*;
+
+    #[test]
+    fn test_refresh_indexer_proxies_key() {
+      let mut app = App::test_default();
+      app
+        .data
+        .prowlarr_data
+        .indexer_proxies
+        .set_items(vec![indexer_proxy()]);
+      app.push_navigation_stack(ActiveProwlarrBlock::IndexerProxies.into());
+
+      IndexerProxiesHandler::new(
+        DEFAULT_KEYBINDINGS.refresh.key,
+        &mut app,
+        ActiveProwlarrBlock::IndexerProxies,
+        None,
+      )
+      .handle();
+
+      assert_navigation_pushed!(app, ActiveProwlarrBlock::IndexerProxies.into());
+      assert!(app.should_refresh);
+    }
+
+    #[test]
+    fn test_delete_indexer_proxy_prompt_confirm() {
+      let mut app = App::test_default();
+      app
+        .data
+        .prowlarr_data
+        .indexer_proxies
+        .set_items(vec![indexer_proxy()]);
+      app.push_navigation_stack(ActiveProwlarrBlock::IndexerProxies.into());
+      app.push_navigation_stack(ActiveProwlarrBlock::DeleteIndexerProxyPrompt.into());
+
+      IndexerProxiesHandler::new(
+        DEFAULT_KEYBINDINGS.confirm.key,
+        &mut app,
+        ActiveProwlarrBlock::DeleteIndexerProxyPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(app.data.prowlarr_data.prompt_confirm);
+      assert_some_eq_x!(
+        &app.data.prowlarr_data.prompt_confirm_action,
+        &ProwlarrEvent::DeleteIndexerProxy(1)
+      );
+      assert_navigation_popped!(app, ActiveProwlarrBlock::IndexerProxies.into());
+    }
+  }
+
+  #[test]
+  fn test_indexer_proxies_handler_accepts() {
+    ActiveProwlarrBlock::iter().for_each(|active_prowlarr_block| {
+      if INDEXER_PROXIES_BLOCKS.contains(&active_prowlarr_block) {
+        assert!(IndexerProxiesHandler::accepts(active_prowlarr_block));
+      } else {
+        assert!(!IndexerProxiesHandler::accepts(active_prowlarr_block));
+      }
+    })
+  }
+
+  #[test]
+  fn test_indexer_proxies_handler_not_ready_when_loading() {
+    let mut app = App::test_default();
+    app.is_loading = true;
+    app
+      .data
+      .prowlarr_data
+      .indexer_proxies
+      .set_items(vec![indexer_proxy()]);
+
+    let handler = IndexerProxiesHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveProwlarrBlock::IndexerProxies,
+      None,
+    );
+
+    assert!(!handler.is_ready());
+  }
+
+  #[test]
+  fn test_indexer_proxies_handler_ready_when_not_loading_and_proxies_is_not_empty() {
+    let mut app = App::test_default();
+    app
+      .data
+      .prowlarr_data
+      .indexer_proxies
+      .set_items(vec![indexer_proxy()]);
+
+    let handler = IndexerProxiesHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveProwlarrBlock::IndexerProxies,
+      None,
+    );
+
+    assert!(handler.is_ready());
+  }
+}
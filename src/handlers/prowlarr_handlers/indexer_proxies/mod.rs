@@ -0,0 +1,154 @@
+use crate::app::App;
+use crate::event::Key;
+use crate::handlers::prowlarr_handlers::handle_change_tab_left_right_keys;
+use crate::handlers::table_handler::{TableHandlingConfig, handle_table};
+use crate::handlers::{KeyEventHandler, handle_clear_errors, handle_prompt_toggle};
+use crate::matches_key;
+use crate::models::Route;
+use crate::models::servarr_data::prowlarr::prowlarr_data::{
+  ActiveProwlarrBlock, INDEXER_PROXIES_BLOCKS,
+};
+use crate::network::prowlarr_network::ProwlarrEvent;
+
+#[cfg(test)]
+#[path = "indexer_proxies_handler_tests.rs"]
+mod indexer_proxies_handler_tests;
+
+pub(super) struct IndexerProxiesHandler<'a, 'b> {
+  key: Key,
+  app: &'a mut App<'b>,
+  active_prowlarr_block: ActiveProwlarrBlock,
+  _context: Option<ActiveProwlarrBlock>,
+}
+
+impl IndexerProxiesHandler<'_, '_> {
+  fn extract_indexer_proxy_id(&self) -> i64 {
+    self
+      .app
+      .data
+      .prowlarr_data
+      .indexer_proxies
+      .current_selection()
+      .id
+  }
+}
+
+impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveProwlarrBlock> for IndexerProxiesHandler<'a, 'b> {
+  fn handle(&mut self) {
+    let indexer_proxies_table_handling_config =
+      TableHandlingConfig::new(ActiveProwlarrBlock::IndexerProxies.into());
+
+    if !handle_table(
+      self,
+      |app| &mut app.data.prowlarr_data.indexer_proxies,
+      indexer_proxies_table_handling_config,
+    ) {
+      self.handle_key_event();
+    }
+  }
+
+  fn accepts(active_block: ActiveProwlarrBlock) -> bool {
+    INDEXER_PROXIES_BLOCKS.contains(&active_block)
+  }
+
+  fn ignore_special_keys(&self) -> bool {
+    self.app.ignore_special_keys_for_textbox_input
+  }
+
+  fn new(
+    key: Key,
+    app: &'a mut App<'b>,
+    active_block: ActiveProwlarrBlock,
+    context: Option<ActiveProwlarrBlock>,
+  ) -> IndexerProxiesHandler<'a, 'b> {
+    IndexerProxiesHandler {
+      key,
+      app,
+      active_prowlarr_block: active_block,
+      _context: context,
+    }
+  }
+
+  fn get_key(&self) -> Key {
+    self.key
+  }
+
+  fn is_ready(&self) -> bool {
+    !self.app.is_loading && !self.app.data.prowlarr_data.indexer_proxies.is_empty()
+  }
+
+  fn handle_scroll_up(&mut self) {}
+
+  fn handle_scroll_down(&mut self) {}
+
+  fn handle_home(&mut self) {}
+
+  fn handle_end(&mut self) {}
+
+  fn handle_delete(&mut self) {
+    if self.active_prowlarr_block == ActiveProwlarrBlock::IndexerProxies {
+      self
+        .app
+        .push_navigation_stack(ActiveProwlarrBlock::DeleteIndexerProxyPrompt.into());
+    }
+  }
+
+  fn handle_left_right_action(&mut self) {
+    match self.active_prowlarr_block {
+      ActiveProwlarrBlock::IndexerProxies => handle_change_tab_left_right_keys(self.app, self.key),
+      ActiveProwlarrBlock::DeleteIndexerProxyPrompt => handle_prompt_toggle(self.app, self.key),
+      _ => (),
+    }
+  }
+
+  fn handle_submit(&mut self) {
+    if self.active_prowlarr_block == ActiveProwlarrBlock::DeleteIndexerProxyPrompt {
+      if self.app.data.prowlarr_data.prompt_confirm {
+        self.app.data.prowlarr_data.prompt_confirm_action = Some(
+          ProwlarrEvent::DeleteIndexerProxy(self.extract_indexer_proxy_id()),
+        );
+      }
+
+      self.app.pop_navigation_stack();
+    }
+  }
+
+  fn handle_esc(&mut self) {
+    if self.active_prowlarr_block == ActiveProwlarrBlock::DeleteIndexerProxyPrompt {
+      self.app.pop_navigation_stack();
+      self.app.data.prowlarr_data.prompt_confirm = false;
+    } else {
+      handle_clear_errors(self.app);
+    }
+  }
+
+  fn handle_char_key_event(&mut self) {
+    let key = self.key;
+    match self.active_prowlarr_block {
+      ActiveProwlarrBlock::IndexerProxies => {
+        if matches_key!(refresh, key) {
+          self.app.should_refresh = true;
+        }
+      }
+      ActiveProwlarrBlock::DeleteIndexerProxyPrompt => {
+        if matches_key!(confirm, key) {
+          self.app.data.prowlarr_data.prompt_confirm = true;
+          self.app.data.prowlarr_data.prompt_confirm_action = Some(
+            ProwlarrEvent::DeleteIndexerProxy(self.extract_indexer_proxy_id()),
+          );
+
+          self.app.pop_navigation_stack();
+        }
+      }
+      _ => (),
+    }
+  }
+
+  fn app_mut(&mut self) -> &mut App<'b> {
+    self.app
+  }
+
+  fn current_route(&self) -> Route {
+    self.app.get_current_route()
+  }
+}
@@ -0,0 +1,680 @@
+#[cfg(test)]
+mod tests {
+  use crate::app::App;
+  use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+  use crate::assert_modal_absent;
+  use crate::assert_modal_present;
+  use crate::assert_navigation_popped;
+  use crate::assert_navigation_pushed;
+  use crate::event::Key;
+  use crate::handlers::KeyEventHandler;
+  use crate::handlers::prowlarr_handlers::indexers::add_indexer_handler::AddIndexerHandler;
+  use crate::models::prowlarr_models::ProwlarrIndexer;
+  use crate::models::servarr_data::modals::{
+    AddIndexerModal, SchemaFormField, SchemaFormFieldKind,
+  };
+  use crate::models::servarr_data::prowlarr::prowlarr_data::{
+    ADD_INDEXER_BLOCKS, ActiveProwlarrBlock,
+  };
+  use crate::models::servarr_models::{AddIndexerParams, IndexerFieldSelectOption};
+  use crate::network::prowlarr_network::ProwlarrEvent;
+  use crate::network::prowlarr_network::prowlarr_network_test_utils::test_utils::indexer;
+  use pretty_assertions::assert_eq;
+  use rstest::rstest;
+  use strum::IntoEnumIterator;
+
+  fn add_indexer_modal() -> AddIndexerModal {
+    AddIndexerModal::from(&indexer())
+  }
+
+  fn select_field(app: &mut App<'_>, name: &str) {
+    let fields = &mut app
+      .data
+      .prowlarr_data
+      .add_indexer_modal
+      .as_mut()
+      .unwrap()
+      .fields;
+    let index = fields
+      .items
+      .iter()
+      .position(|field| field.name == name)
+      .unwrap();
+    fields.state.select(Some(index));
+  }
+
+  fn current_field(app: &App<'_>) -> SchemaFormField {
+    app
+      .data
+      .prowlarr_data
+      .add_indexer_modal
+      .as_ref()
+      .unwrap()
+      .fields
+      .current_selection()
+      .clone()
+  }
+
+  mod test_handle_scroll_up_and_down {
+    use pretty_assertions::assert_str_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_add_indexer_select_implementation_scroll(#[values(Key::Up, Key::Down)] key: Key) {
+      let mut app = App::test_default();
+      app.data.prowlarr_data.indexer_schema.set_items(vec![
+        ProwlarrIndexer {
+          implementation_name: Some("Newznab".to_owned()),
+          ..ProwlarrIndexer::default()
+        },
+        ProwlarrIndexer {
+          implementation_name: Some("Torznab".to_owned()),
+          ..ProwlarrIndexer::default()
+        },
+      ]);
+
+      AddIndexerHandler::new(
+        key,
+        &mut app,
+        ActiveProwlarrBlock::AddIndexerSelectImplementation,
+        None,
+      )
+      .handle();
+
+      assert_str_eq!(
+        app
+          .data
+          .prowlarr_data
+          .indexer_schema
+          .current_selection()
+          .implementation_name
+          .as_ref()
+          .unwrap(),
+        "Torznab"
+      );
+    }
+
+    #[rstest]
+    fn test_add_indexer_prompt_scroll(#[values(Key::Up, Key::Down)] key: Key) {
+      let mut app = App::test_default();
+      app.data.prowlarr_data.add_indexer_modal = Some(add_indexer_modal());
+
+      AddIndexerHandler::new(key, &mut app, ActiveProwlarrBlock::AddIndexerPrompt, None).handle();
+
+      if key == Key::Up {
+        assert_str_eq!(current_field(&app).name, "baseUrl");
+      } else {
+        assert_str_eq!(current_field(&app).name, "enable");
+      }
+    }
+
+    #[rstest]
+    fn test_add_indexer_prompt_scroll_no_op_when_not_ready(#[values(Key::Up, Key::Down)] key: Key) {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.data.prowlarr_data.add_indexer_modal = Some(add_indexer_modal());
+
+      AddIndexerHandler::new(key, &mut app, ActiveProwlarrBlock::AddIndexerPrompt, None).handle();
+
+      assert_str_eq!(current_field(&app).name, "name");
+    }
+  }
+
+  mod test_handle_home_end {
+    use std::sync::atomic::Ordering;
+
+    use pretty_assertions::{assert_eq, assert_str_eq};
+
+    use super::*;
+
+    #[test]
+    fn test_add_indexer_prompt_home_end() {
+      let mut app = App::test_default();
+      app.data.prowlarr_data.add_indexer_modal = Some(add_indexer_modal());
+
+      AddIndexerHandler::new(
+        DEFAULT_KEYBINDINGS.end.key,
+        &mut app,
+        ActiveProwlarrBlock::AddIndexerPrompt,
+        None,
+      )
+      .handle();
+
+      assert_str_eq!(current_field(&app).name, "baseUrl");
+
+      AddIndexerHandler::new(
+        DEFAULT_KEYBINDINGS.home.key,
+        &mut app,
+        ActiveProwlarrBlock::AddIndexerPrompt,
+        None,
+      )
+      .handle();
+
+      assert_str_eq!(current_field(&app).name, "name");
+    }
+
+    #[test]
+    fn test_add_indexer_field_input_home_end() {
+      let mut app = App::test_default();
+      app.data.prowlarr_data.add_indexer_modal = Some(add_indexer_modal());
+
+      AddIndexerHandler::new(
+        DEFAULT_KEYBINDINGS.home.key,
+        &mut app,
+        ActiveProwlarrBlock::AddIndexerFieldInput,
+        None,
+      )
+      .handle();
+
+      assert_eq!(current_field(&app).value.offset.load(Ordering::SeqCst), 12);
+
+      AddIndexerHandler::new(
+        DEFAULT_KEYBINDINGS.end.key,
+        &mut app,
+        ActiveProwlarrBlock::AddIndexerFieldInput,
+        None,
+      )
+      .handle();
+
+      assert_eq!(current_field(&app).value.offset.load(Ordering::SeqCst), 0);
+    }
+  }
+
+  mod test_handle_left_right_action {
+    use std::sync::atomic::Ordering;
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_add_indexer_field_input_left_right_keys() {
+      let mut app = App::test_default();
+      app.data.prowlarr_data.add_indexer_modal = Some(add_indexer_modal());
+
+      AddIndexerHandler::new(
+        DEFAULT_KEYBINDINGS.left.key,
+        &mut app,
+        ActiveProwlarrBlock::AddIndexerFieldInput,
+        None,
+      )
+      .handle();
+
+      assert_eq!(current_field(&app).value.offset.load(Ordering::SeqCst), 1);
+
+      AddIndexerHandler::new(
+        DEFAULT_KEYBINDINGS.right.key,
+        &mut app,
+        ActiveProwlarrBlock::AddIndexerFieldInput,
+        None,
+      )
+      .handle();
+
+      assert_eq!(current_field(&app).value.offset.load(Ordering::SeqCst), 0);
+    }
+  }
+
+  mod test_handle_submit {
+    use pretty_assertions::{assert_eq, assert_str_eq};
+
+    use super::*;
+
+    const SUBMIT_KEY: Key = DEFAULT_KEYBINDINGS.submit.key;
+
+    #[test]
+    fn test_add_indexer_select_implementation_submit() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveProwlarrBlock::Indexers.into());
+      app.push_navigation_stack(ActiveProwlarrBlock::AddIndexerSelectImplementation.into());
+      app
+        .data
+        .prowlarr_data
+        .indexer_schema
+        .set_items(vec![indexer()]);
+
+      AddIndexerHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveProwlarrBlock::AddIndexerSelectImplementation,
+        None,
+      )
+      .handle();
+
+      assert_navigation_popped!(app, ActiveProwlarrBlock::AddIndexerPrompt.into());
+      assert_modal_present!(app.data.prowlarr_data.add_indexer_modal);
+      assert_str_eq!(
+        app
+          .data
+          .prowlarr_data
+          .add_indexer_modal
+          .as_ref()
+          .unwrap()
+          .implementation,
+        "testindexer"
+      );
+      app.pop_navigation_stack();
+      assert_eq!(
+        app.get_current_route(),
+        ActiveProwlarrBlock::Indexers.into()
+      );
+    }
+
+    #[test]
+    fn test_add_indexer_select_implementation_submit_no_op_when_schema_is_empty() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveProwlarrBlock::Indexers.into());
+      app.push_navigation_stack(ActiveProwlarrBlock::AddIndexerSelectImplementation.into());
+
+      AddIndexerHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveProwlarrBlock::AddIndexerSelectImplementation,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveProwlarrBlock::AddIndexerSelectImplementation.into()
+      );
+      assert_modal_absent!(app.data.prowlarr_data.add_indexer_modal);
+    }
+
+    #[test]
+    fn test_add_indexer_prompt_submit_text_field() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveProwlarrBlock::AddIndexerPrompt.into());
+      app.data.prowlarr_data.add_indexer_modal = Some(add_indexer_modal());
+
+      AddIndexerHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveProwlarrBlock::AddIndexerPrompt,
+        None,
+      )
+      .handle();
+
+      assert_navigation_pushed!(app, ActiveProwlarrBlock::AddIndexerFieldInput.into());
+      assert!(app.ignore_special_keys_for_textbox_input);
+    }
+
+    #[test]
+    fn test_add_indexer_prompt_submit_toggles_checkbox() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveProwlarrBlock::AddIndexerPrompt.into());
+      app.data.prowlarr_data.add_indexer_modal = Some(add_indexer_modal());
+      select_field(&mut app, "enable");
+
+      AddIndexerHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveProwlarrBlock::AddIndexerPrompt,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveProwlarrBlock::AddIndexerPrompt.into()
+      );
+      assert_str_eq!(current_field(&app).value.text, "false");
+
+      AddIndexerHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveProwlarrBlock::AddIndexerPrompt,
+        None,
+      )
+      .handle();
+
+      assert_str_eq!(current_field(&app).value.text, "true");
+    }
+
+    #[test]
+    fn test_add_indexer_prompt_submit_cycles_select_options() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveProwlarrBlock::AddIndexerPrompt.into());
+      let mut add_indexer_modal = AddIndexerModal::default();
+      add_indexer_modal.fields.set_items(vec![SchemaFormField {
+        name: "animeStandardFormatSearch".to_owned(),
+        label: "Anime Standard Format Search".to_owned(),
+        kind: SchemaFormFieldKind::Select(vec![
+          IndexerFieldSelectOption {
+            value: 0,
+            name: "Disabled".to_owned(),
+          },
+          IndexerFieldSelectOption {
+            value: 1,
+            name: "Enabled".to_owned(),
+          },
+        ]),
+        value: "0".into(),
+        ..SchemaFormField::default()
+      }]);
+      app.data.prowlarr_data.add_indexer_modal = Some(add_indexer_modal);
+
+      AddIndexerHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveProwlarrBlock::AddIndexerPrompt,
+        None,
+      )
+      .handle();
+
+      assert_str_eq!(current_field(&app).value.text, "1");
+
+      AddIndexerHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveProwlarrBlock::AddIndexerPrompt,
+        None,
+      )
+      .handle();
+
+      assert_str_eq!(current_field(&app).value.text, "0");
+    }
+
+    #[test]
+    fn test_add_indexer_field_input_submit() {
+      let mut app = App::test_default();
+      app.ignore_special_keys_for_textbox_input = true;
+      app.push_navigation_stack(ActiveProwlarrBlock::AddIndexerPrompt.into());
+      app.push_navigation_stack(ActiveProwlarrBlock::AddIndexerFieldInput.into());
+      app.data.prowlarr_data.add_indexer_modal = Some(add_indexer_modal());
+
+      AddIndexerHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveProwlarrBlock::AddIndexerFieldInput,
+        None,
+      )
+      .handle();
+
+      assert_navigation_popped!(app, ActiveProwlarrBlock::AddIndexerPrompt.into());
+      assert!(!app.ignore_special_keys_for_textbox_input);
+    }
+  }
+
+  mod test_handle_esc {
+    use super::*;
+
+    const ESC_KEY: Key = DEFAULT_KEYBINDINGS.esc.key;
+
+    #[test]
+    fn test_add_indexer_select_implementation_esc() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveProwlarrBlock::Indexers.into());
+      app.push_navigation_stack(ActiveProwlarrBlock::AddIndexerSelectImplementation.into());
+
+      AddIndexerHandler::new(
+        ESC_KEY,
+        &mut app,
+        ActiveProwlarrBlock::AddIndexerSelectImplementation,
+        None,
+      )
+      .handle();
+
+      assert_navigation_popped!(app, ActiveProwlarrBlock::Indexers.into());
+    }
+
+    #[test]
+    fn test_add_indexer_prompt_esc() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveProwlarrBlock::Indexers.into());
+      app.push_navigation_stack(ActiveProwlarrBlock::AddIndexerPrompt.into());
+      app.data.prowlarr_data.add_indexer_modal = Some(add_indexer_modal());
+      app.data.prowlarr_data.prompt_confirm = true;
+
+      AddIndexerHandler::new(
+        ESC_KEY,
+        &mut app,
+        ActiveProwlarrBlock::AddIndexerPrompt,
+        None,
+      )
+      .handle();
+
+      assert_navigation_popped!(app, ActiveProwlarrBlock::Indexers.into());
+      assert!(!app.data.prowlarr_data.prompt_confirm);
+      assert_modal_absent!(app.data.prowlarr_data.add_indexer_modal);
+    }
+
+    #[test]
+    fn test_add_indexer_field_input_esc() {
+      let mut app = App::test_default();
+      app.ignore_special_keys_for_textbox_input = true;
+      app.push_navigation_stack(ActiveProwlarrBlock::AddIndexerPrompt.into());
+      app.push_navigation_stack(ActiveProwlarrBlock::AddIndexerFieldInput.into());
+      app.data.prowlarr_data.add_indexer_modal = Some(add_indexer_modal());
+
+      AddIndexerHandler::new(
+        ESC_KEY,
+        &mut app,
+        ActiveProwlarrBlock::AddIndexerFieldInput,
+        None,
+      )
+      .handle();
+
+      assert_navigation_popped!(app, ActiveProwlarrBlock::AddIndexerPrompt.into());
+      assert!(!app.ignore_special_keys_for_textbox_input);
+      assert_modal_present!(app.data.prowlarr_data.add_indexer_modal);
+    }
+  }
+
+  mod test_handle_key_char {
+    use pretty_assertions::{assert_eq, assert_str_eq};
+
+    use super::*;
+
+    #[test]
+    fn test_add_indexer_field_input_backspace() {
+      let mut app = App::test_default();
+      app.data.prowlarr_data.add_indexer_modal = Some(add_indexer_modal());
+
+      AddIndexerHandler::new(
+        DEFAULT_KEYBINDINGS.backspace.key,
+        &mut app,
+        ActiveProwlarrBlock::AddIndexerFieldInput,
+        None,
+      )
+      .handle();
+
+      assert_str_eq!(current_field(&app).value.text, "Test Indexe");
+    }
+
+    #[test]
+    fn test_add_indexer_field_input_char_key() {
+      let mut app = App::test_default();
+      app.data.prowlarr_data.add_indexer_modal = Some(add_indexer_modal());
+
+      AddIndexerHandler::new(
+        Key::Char('s'),
+        &mut app,
+        ActiveProwlarrBlock::AddIndexerFieldInput,
+        None,
+      )
+      .handle();
+
+      assert_str_eq!(current_field(&app).value.text, "Test Indexers");
+    }
+
+    #[test]
+    fn test_add_indexer_prompt_confirm() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveProwlarrBlock::Indexers.into());
+      app.push_navigation_stack(ActiveProwlarrBlock::AddIndexerPrompt.into());
+      app.data.prowlarr_data.add_indexer_modal = Some(add_indexer_modal());
+      let expected_add_indexer_params = AddIndexerParams {
+        implementation: "testindexer".to_owned(),
+        name: "Test Indexer".to_owned(),
+        app_profile_id: Some(1),
+        fields: vec![
+          ("definitionFile".to_owned(), "testindexer".to_owned()),
+          ("baseUrl".to_owned(), "https://test.com".to_owned()),
+        ],
+        ..AddIndexerParams::default()
+      };
+
+      AddIndexerHandler::new(
+        DEFAULT_KEYBINDINGS.confirm.key,
+        &mut app,
+        ActiveProwlarrBlock::AddIndexerPrompt,
+        None,
+      )
+      .handle();
+
+      assert_navigation_popped!(app, ActiveProwlarrBlock::Indexers.into());
+      assert!(app.data.prowlarr_data.prompt_confirm);
+      assert!(app.should_refresh);
+      assert_modal_absent!(app.data.prowlarr_data.add_indexer_modal);
+      assert_eq!(
+        app.data.prowlarr_data.prompt_confirm_action,
+        Some(ProwlarrEvent::AddIndexer(expected_add_indexer_params))
+      );
+    }
+
+    #[test]
+    fn test_add_indexer_prompt_confirm_no_op_when_not_ready() {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.push_navigation_stack(ActiveProwlarrBlock::Indexers.into());
+      app.push_navigation_stack(ActiveProwlarrBlock::AddIndexerPrompt.into());
+      app.data.prowlarr_data.add_indexer_modal = Some(add_indexer_modal());
+
+      AddIndexerHandler::new(
+        DEFAULT_KEYBINDINGS.confirm.key,
+        &mut app,
+        ActiveProwlarrBlock::AddIndexerPrompt,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveProwlarrBlock::AddIndexerPrompt.into()
+      );
+      assert!(app.data.prowlarr_data.prompt_confirm_action.is_none());
+      assert_modal_present!(app.data.prowlarr_data.add_indexer_modal);
+    }
+  }
+
+  #[test]
+  fn test_add_indexer_handler_accepts() {
+    ActiveProwlarrBlock::iter().for_each(|active_prowlarr_block| {
+      if ADD_INDEXER_BLOCKS.contains(&active_prowlarr_block) {
+        assert!(AddIndexerHandler::accepts(active_prowlarr_block));
+      } else {
+        assert!(!AddIndexerHandler::accepts(active_prowlarr_block));
+      }
+    })
+  }
+
+  #[rstest]
+  fn test_add_indexer_handler_ignore_special_keys(
+    #[values(true, false)] ignore_special_keys_for_textbox_input: bool,
+  ) {
+    let mut app = App::test_default();
+    app.ignore_special_keys_for_textbox_input = ignore_special_keys_for_textbox_input;
+    let handler = AddIndexerHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveProwlarrBlock::default(),
+      None,
+    );
+
+    assert_eq!(
+      handler.ignore_special_keys(),
+      ignore_special_keys_for_textbox_input
+    );
+  }
+
+  #[rstest]
+  fn test_add_indexer_handler_is_not_ready_when_loading(
+    #[values(
+      ActiveProwlarrBlock::AddIndexerSelectImplementation,
+      ActiveProwlarrBlock::AddIndexerPrompt
+    )]
+    active_prowlarr_block: ActiveProwlarrBlock,
+  ) {
+    let mut app = App::test_default();
+    app.is_loading = true;
+    app
+      .data
+      .prowlarr_data
+      .indexer_schema
+      .set_items(vec![indexer()]);
+    app.data.prowlarr_data.add_indexer_modal = Some(add_indexer_modal());
+
+    let handler = AddIndexerHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      active_prowlarr_block,
+      None,
+    );
+
+    assert!(!handler.is_ready());
+  }
+
+  #[test]
+  fn test_add_indexer_handler_is_not_ready_when_indexer_schema_is_empty() {
+    let mut app = App::test_default();
+
+    let handler = AddIndexerHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveProwlarrBlock::AddIndexerSelectImplementation,
+      None,
+    );
+
+    assert!(!handler.is_ready());
+  }
+
+  #[test]
+  fn test_add_indexer_handler_is_ready_when_indexer_schema_is_not_empty() {
+    let mut app = App::test_default();
+    app
+      .data
+      .prowlarr_data
+      .indexer_schema
+      .set_items(vec![indexer()]);
+
+    let handler = AddIndexerHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveProwlarrBlock::AddIndexerSelectImplementation,
+      None,
+    );
+
+    assert!(handler.is_ready());
+  }
+
+  #[test]
+  fn test_add_indexer_handler_is_not_ready_when_add_indexer_modal_is_none() {
+    let mut app = App::test_default();
+
+    let handler = AddIndexerHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveProwlarrBlock::AddIndexerPrompt,
+      None,
+    );
+
+    assert!(!handler.is_ready());
+  }
+
+  #[test]
+  fn test_add_indexer_handler_is_ready_when_add_indexer_modal_is_some() {
+    let mut app = App::test_default();
+    app.data.prowlarr_data.add_indexer_modal = Some(add_indexer_modal());
+
+    let handler = AddIndexerHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveProwlarrBlock::AddIndexerPrompt,
+      None,
+    );
+
+    assert!(handler.is_ready());
+  }
+}
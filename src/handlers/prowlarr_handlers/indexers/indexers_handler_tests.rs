@@ -0,0 +1,536 @@
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+  use rstest::rstest;
+  use strum::IntoEnumIterator;
+
+  use crate::app::App;
+  use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+  use crate::assert_navigation_pushed;
+  use crate::event::Key;
+  use crate::handlers::KeyEventHandler;
+  use crate::handlers::prowlarr_handlers::indexers::IndexersHandler;
+  use crate::models::prowlarr_models::ProwlarrIndexer;
+  use crate::models::servarr_data::prowlarr::prowlarr_data::{
+    ADD_INDEXER_BLOCKS, ActiveProwlarrBlock, INDEXERS_BLOCKS,
+  };
+  use crate::network::prowlarr_network::prowlarr_network_test_utils::test_utils::indexer;
+  use crate::test_handler_delegation;
+
+  mod test_handle_delete {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    const DELETE_KEY: Key = DEFAULT_KEYBINDINGS.delete.key;
+
+    #[test]
+    fn test_delete_indexer_prompt() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveProwlarrBlock::Indexers.into());
+      app
+        .data
+        .prowlarr_data
+        .indexers
+        .set_items(vec![ProwlarrIndexer::default()]);
+
+      IndexersHandler::new(DELETE_KEY, &mut app, ActiveProwlarrBlock::Indexers, None).handle();
+
+      assert_navigation_pushed!(app, ActiveProwlarrBlock::DeleteIndexerPrompt.into());
+    }
+
+    #[test]
+    fn test_delete_indexer_prompt_no_op_when_not_ready() {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.push_navigation_stack(ActiveProwlarrBlock::Indexers.into());
+      app
+        .data
+        .prowlarr_data
+        .indexers
+        .set_items(vec![ProwlarrIndexer::default()]);
+
+      IndexersHandler::new(DELETE_KEY, &mut app, ActiveProwlarrBlock::Indexers, None).handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveProwlarrBlock::Indexers.into()
+      );
+    }
+  }
+
+  mod test_handle_left_right_action {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_indexers_tab_left(#[values(true, false)] is_ready: bool) {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveProwlarrBlock::Indexers.into());
+      app.is_loading = is_ready;
+      app.data.prowlarr_data.main_tabs.set_index(0);
+
+      IndexersHandler::new(
+        DEFAULT_KEYBINDINGS.left.key,
+        &mut app,
+        ActiveProwlarrBlock::Indexers,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.data.prowlarr_data.main_tabs.get_active_route(),
+        ActiveProwlarrBlock::System.into()
+      );
+      assert_navigation_pushed!(app, ActiveProwlarrBlock::System.into());
+    }
+
+    #[rstest]
+    fn test_indexers_tab_right(#[values(true, false)] is_ready: bool) {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveProwlarrBlock::Indexers.into());
+      app.is_loading = is_ready;
+      app.data.prowlarr_data.main_tabs.set_index(0);
+
+      IndexersHandler::new(
+        DEFAULT_KEYBINDINGS.right.key,
+        &mut app,
+        ActiveProwlarrBlock::Indexers,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.data.prowlarr_data.main_tabs.get_active_route(),
+        ActiveProwlarrBlock::SearchReleases.into()
+      );
+      assert_navigation_pushed!(app, ActiveProwlarrBlock::SearchReleases.into());
+    }
+
+    #[rstest]
+    fn test_left_right_delete_indexer_prompt_toggle(
+      #[values(DEFAULT_KEYBINDINGS.left.key, DEFAULT_KEYBINDINGS.right.key)] key: Key,
+    ) {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveProwlarrBlock::Indexers.into());
+      app.push_navigation_stack(ActiveProwlarrBlock::DeleteIndexerPrompt.into());
+
+      IndexersHandler::new(
+        key,
+        &mut app,
+        ActiveProwlarrBlock::DeleteIndexerPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(app.data.prowlarr_data.prompt_confirm);
+
+      IndexersHandler::new(
+        key,
+        &mut app,
+        ActiveProwlarrBlock::DeleteIndexerPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(!app.data.prowlarr_data.prompt_confirm);
+    }
+  }
+
+  mod test_handle_submit {
+    use super::*;
+    use crate::assert_navigation_popped;
+    use crate::network::prowlarr_network::ProwlarrEvent;
+
+    const SUBMIT_KEY: Key = DEFAULT_KEYBINDINGS.submit.key;
+
+    #[test]
+    fn test_delete_indexer_prompt_confirm_submit() {
+      let mut app = App::test_default();
+      app.data.prowlarr_data.indexers.set_items(vec![indexer()]);
+      app.data.prowlarr_data.prompt_confirm = true;
+      app.push_navigation_stack(ActiveProwlarrBlock::Indexers.into());
+      app.push_navigation_stack(ActiveProwlarrBlock::DeleteIndexerPrompt.into());
+
+      IndexersHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveProwlarrBlock::DeleteIndexerPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(app.data.prowlarr_data.prompt_confirm);
+      assert_some_eq_x!(
+        &app.data.prowlarr_data.prompt_confirm_action,
+        &ProwlarrEvent::DeleteIndexer(1)
+      );
+      assert_navigation_popped!(app, ActiveProwlarrBlock::Indexers.into());
+    }
+
+    #[test]
+    fn test_prompt_decline_submit() {
+      let mut app = App::test_default();
+      app
+        .data
+        .prowlarr_data
+        .indexers
+        .set_items(vec![ProwlarrIndexer::default()]);
+      app.push_navigation_stack(ActiveProwlarrBlock::Indexers.into());
+      app.push_navigation_stack(ActiveProwlarrBlock::DeleteIndexerPrompt.into());
+
+      IndexersHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveProwlarrBlock::DeleteIndexerPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(!app.data.prowlarr_data.prompt_confirm);
+      assert_none!(app.data.prowlarr_data.prompt_confirm_action);
+      assert_navigation_popped!(app, ActiveProwlarrBlock::Indexers.into());
+    }
+  }
+
+  mod test_handle_esc {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::assert_navigation_popped;
+
+    const ESC_KEY: Key = DEFAULT_KEYBINDINGS.esc.key;
+
+    #[rstest]
+    fn test_delete_indexer_prompt_block_esc(#[values(true, false)] is_ready: bool) {
+      let mut app = App::test_default();
+      app.is_loading = is_ready;
+      app.push_navigation_stack(ActiveProwlarrBlock::Indexers.into());
+      app.push_navigation_stack(ActiveProwlarrBlock::DeleteIndexerPrompt.into());
+      app.data.prowlarr_data.prompt_confirm = true;
+
+      IndexersHandler::new(
+        ESC_KEY,
+        &mut app,
+        ActiveProwlarrBlock::DeleteIndexerPrompt,
+        None,
+      )
+      .handle();
+
+      assert_navigation_popped!(app, ActiveProwlarrBlock::Indexers.into());
+      assert!(!app.data.prowlarr_data.prompt_confirm);
+    }
+
+    #[rstest]
+    fn test_test_indexer_esc(#[values(true, false)] is_ready: bool) {
+      let mut app = App::test_default();
+      app.is_loading = is_ready;
+      app.data.prowlarr_data.indexer_test_errors = Some("test result".to_owned());
+      app.push_navigation_stack(ActiveProwlarrBlock::Indexers.into());
+      app.push_navigation_stack(ActiveProwlarrBlock::TestIndexer.into());
+
+      IndexersHandler::new(ESC_KEY, &mut app, ActiveProwlarrBlock::TestIndexer, None).handle();
+
+      assert_navigation_popped!(app, ActiveProwlarrBlock::Indexers.into());
+      assert_none!(app.data.prowlarr_data.indexer_test_errors);
+    }
+
+    #[rstest]
+    fn test_default_esc(#[values(true, false)] is_ready: bool) {
+      let mut app = App::test_default();
+      app.is_loading = is_ready;
+      app.error = "test error".to_owned().into();
+      app.push_navigation_stack(ActiveProwlarrBlock::Indexers.into());
+      app.push_navigation_stack(ActiveProwlarrBlock::Indexers.into());
+
+      IndexersHandler::new(ESC_KEY, &mut app, ActiveProwlarrBlock::Indexers, None).handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveProwlarrBlock::Indexers.into()
+      );
+      assert_is_empty!(app.error.text);
+    }
+  }
+
+  mod test_handle_key_char {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::assert_navigation_popped;
+    use crate::network::prowlarr_network::ProwlarrEvent;
+
+    #[test]
+    fn test_refresh_indexers_key() {
+      let mut app = App::test_default();
+      app.data.prowlarr_data.indexers.set_items(vec![indexer()]);
+      app.push_navigation_stack(ActiveProwlarrBlock::Indexers.into());
+
+      IndexersHandler::new(
+        DEFAULT_KEYBINDINGS.refresh.key,
+        &mut app,
+        ActiveProwlarrBlock::Indexers,
+        None,
+      )
+      .handle();
+
+      assert_navigation_pushed!(app, ActiveProwlarrBlock::Indexers.into());
+      assert!(app.should_refresh);
+    }
+
+    #[test]
+    fn test_refresh_indexers_key_no_op_when_not_ready() {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.data.prowlarr_data.indexers.set_items(vec![indexer()]);
+      app.push_navigation_stack(ActiveProwlarrBlock::Indexers.into());
+
+      IndexersHandler::new(
+        DEFAULT_KEYBINDINGS.refresh.key,
+        &mut app,
+        ActiveProwlarrBlock::Indexers,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveProwlarrBlock::Indexers.into()
+      );
+      assert!(!app.should_refresh);
+    }
+
+    #[test]
+    fn test_indexer_add_key() {
+      let mut app = App::test_default();
+      app.data.prowlarr_data.indexers.set_items(vec![indexer()]);
+      app.push_navigation_stack(ActiveProwlarrBlock::Indexers.into());
+
+      IndexersHandler::new(
+        DEFAULT_KEYBINDINGS.add.key,
+        &mut app,
+        ActiveProwlarrBlock::Indexers,
+        None,
+      )
+      .handle();
+
+      assert_navigation_pushed!(
+        app,
+        ActiveProwlarrBlock::AddIndexerSelectImplementation.into()
+      );
+    }
+
+    #[test]
+    fn test_indexer_add_key_no_op_when_not_ready() {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.data.prowlarr_data.indexers.set_items(vec![indexer()]);
+      app.push_navigation_stack(ActiveProwlarrBlock::Indexers.into());
+
+      IndexersHandler::new(
+        DEFAULT_KEYBINDINGS.add.key,
+        &mut app,
+        ActiveProwlarrBlock::Indexers,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveProwlarrBlock::Indexers.into()
+      );
+    }
+
+    #[test]
+    fn test_indexer_test_key() {
+      let mut app = App::test_default();
+      app.data.prowlarr_data.indexers.set_items(vec![indexer()]);
+      app.push_navigation_stack(ActiveProwlarrBlock::Indexers.into());
+
+      IndexersHandler::new(
+        DEFAULT_KEYBINDINGS.test.key,
+        &mut app,
+        ActiveProwlarrBlock::Indexers,
+        None,
+      )
+      .handle();
+
+      assert_navigation_pushed!(app, ActiveProwlarrBlock::TestIndexer.into());
+    }
+
+    #[test]
+    fn test_indexer_test_all_key() {
+      let mut app = App::test_default();
+      app.data.prowlarr_data.indexers.set_items(vec![indexer()]);
+      app.push_navigation_stack(ActiveProwlarrBlock::Indexers.into());
+
+      IndexersHandler::new(
+        DEFAULT_KEYBINDINGS.test_all.key,
+        &mut app,
+        ActiveProwlarrBlock::Indexers,
+        None,
+      )
+      .handle();
+
+      assert_navigation_pushed!(app, ActiveProwlarrBlock::TestAllIndexers.into());
+    }
+
+    #[test]
+    fn test_indexer_test_all_key_no_op_when_not_ready() {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.data.prowlarr_data.indexers.set_items(vec![indexer()]);
+      app.push_navigation_stack(ActiveProwlarrBlock::Indexers.into());
+
+      IndexersHandler::new(
+        DEFAULT_KEYBINDINGS.test_all.key,
+        &mut app,
+        ActiveProwlarrBlock::Indexers,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveProwlarrBlock::Indexers.into()
+      );
+    }
+
+    #[test]
+    fn test_delete_indexer_prompt_confirm() {
+      let mut app = App::test_default();
+      app.data.prowlarr_data.indexers.set_items(vec![indexer()]);
+      app.push_navigation_stack(ActiveProwlarrBlock::Indexers.into());
+      app.push_navigation_stack(ActiveProwlarrBlock::DeleteIndexerPrompt.into());
+
+      IndexersHandler::new(
+        DEFAULT_KEYBINDINGS.confirm.key,
+        &mut app,
+        ActiveProwlarrBlock::DeleteIndexerPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(app.data.prowlarr_data.prompt_confirm);
+      assert_some_eq_x!(
+        &app.data.prowlarr_data.prompt_confirm_action,
+        &ProwlarrEvent::DeleteIndexer(1)
+      );
+      assert_navigation_popped!(app, ActiveProwlarrBlock::Indexers.into());
+    }
+  }
+
+  #[rstest]
+  fn test_delegates_test_all_indexers_block_to_test_all_indexers_handler() {
+    test_handler_delegation!(
+      IndexersHandler,
+      ActiveProwlarrBlock::Indexers,
+      ActiveProwlarrBlock::TestAllIndexers
+    );
+  }
+
+  #[rstest]
+  fn test_delegates_add_indexer_blocks_to_add_indexer_handler(
+    #[values(
+      ActiveProwlarrBlock::AddIndexerFieldInput,
+      ActiveProwlarrBlock::AddIndexerPrompt,
+      ActiveProwlarrBlock::AddIndexerSelectImplementation
+    )]
+    active_prowlarr_block: ActiveProwlarrBlock,
+  ) {
+    test_handler_delegation!(
+      IndexersHandler,
+      ActiveProwlarrBlock::Indexers,
+      active_prowlarr_block
+    );
+  }
+
+  #[test]
+  fn test_indexers_handler_accepts() {
+    let mut indexers_blocks = INDEXERS_BLOCKS.to_vec();
+    indexers_blocks.extend(ADD_INDEXER_BLOCKS);
+    indexers_blocks.push(ActiveProwlarrBlock::TestAllIndexers);
+
+    ActiveProwlarrBlock::iter().for_each(|active_prowlarr_block| {
+      if indexers_blocks.contains(&active_prowlarr_block) {
+        assert!(IndexersHandler::accepts(active_prowlarr_block));
+      } else {
+        assert!(!IndexersHandler::accepts(active_prowlarr_block));
+      }
+    })
+  }
+
+  #[rstest]
+  fn test_indexers_handler_ignore_special_keys(
+    #[values(true, false)] ignore_special_keys_for_textbox_input: bool,
+  ) {
+    let mut app = App::test_default();
+    app.ignore_special_keys_for_textbox_input = ignore_special_keys_for_textbox_input;
+    let handler = IndexersHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveProwlarrBlock::default(),
+      None,
+    );
+
+    assert_eq!(
+      handler.ignore_special_keys(),
+      ignore_special_keys_for_textbox_input
+    );
+  }
+
+  #[test]
+  fn test_indexers_handler_not_ready_when_loading() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveProwlarrBlock::Indexers.into());
+    app.is_loading = true;
+
+    let handler = IndexersHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveProwlarrBlock::Indexers,
+      None,
+    );
+
+    assert!(!handler.is_ready());
+  }
+
+  #[test]
+  fn test_indexers_handler_not_ready_when_indexers_is_empty() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveProwlarrBlock::Indexers.into());
+    app.is_loading = false;
+
+    let handler = IndexersHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveProwlarrBlock::Indexers,
+      None,
+    );
+
+    assert!(!handler.is_ready());
+  }
+
+  #[test]
+  fn test_indexers_handler_ready_when_not_loading_and_indexers_is_not_empty() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveProwlarrBlock::Indexers.into());
+    app.is_loading = false;
+    app
+      .data
+      .prowlarr_data
+      .indexers
+      .set_items(vec![ProwlarrIndexer::default()]);
+
+    let handler = IndexersHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveProwlarrBlock::Indexers,
+      None,
+    );
+
+    assert!(handler.is_ready());
+  }
+}
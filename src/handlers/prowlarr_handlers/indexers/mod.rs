@@ -0,0 +1,182 @@
+use crate::app::App;
+use crate::event::Key;
+use crate::handlers::prowlarr_handlers::handle_change_tab_left_right_keys;
+use crate::handlers::prowlarr_handlers::indexers::add_indexer_handler::AddIndexerHandler;
+use crate::handlers::prowlarr_handlers::indexers::test_all_indexers_handler::TestAllIndexersHandler;
+use crate::handlers::table_handler::{TableHandlingConfig, handle_table};
+use crate::handlers::{KeyEventHandler, handle_clear_errors, handle_prompt_toggle};
+use crate::matches_key;
+use crate::models::Route;
+use crate::models::servarr_data::prowlarr::prowlarr_data::{ActiveProwlarrBlock, INDEXERS_BLOCKS};
+use crate::network::prowlarr_network::ProwlarrEvent;
+
+mod add_indexer_handler;
+mod test_all_indexers_handler;
+
+#[cfg(test)]
+#[path = "indexers_handler_tests.rs"]
+mod indexers_handler_tests;
+
+pub(super) struct IndexersHandler<'a, 'b> {
+  key: Key,
+  app: &'a mut App<'b>,
+  active_prowlarr_block: ActiveProwlarrBlock,
+  context: Option<ActiveProwlarrBlock>,
+}
+
+impl IndexersHandler<'_, '_> {
+  fn extract_indexer_id(&self) -> i64 {
+    self.app.data.prowlarr_data.indexers.current_selection().id
+  }
+}
+
+impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveProwlarrBlock> for IndexersHandler<'a, 'b> {
+  fn handle(&mut self) {
+    let indexers_table_handling_config =
+      TableHandlingConfig::new(ActiveProwlarrBlock::Indexers.into());
+
+    if !handle_table(
+      self,
+      |app| &mut app.data.prowlarr_data.indexers,
+      indexers_table_handling_config,
+    ) {
+      match self.active_prowlarr_block {
+        _ if AddIndexerHandler::accepts(self.active_prowlarr_block) => {
+          AddIndexerHandler::new(self.key, self.app, self.active_prowlarr_block, self.context)
+            .handle()
+        }
+        _ if TestAllIndexersHandler::accepts(self.active_prowlarr_block) => {
+          TestAllIndexersHandler::new(self.key, self.app, self.active_prowlarr_block, self.context)
+            .handle()
+        }
+        _ => self.handle_key_event(),
+      }
+    }
+  }
+
+  fn accepts(active_block: ActiveProwlarrBlock) -> bool {
+    AddIndexerHandler::accepts(active_block)
+      || TestAllIndexersHandler::accepts(active_block)
+      || INDEXERS_BLOCKS.contains(&active_block)
+  }
+
+  fn ignore_special_keys(&self) -> bool {
+    self.app.ignore_special_keys_for_textbox_input
+  }
+
+  fn new(
+    key: Key,
+    app: &'a mut App<'b>,
+    active_block: ActiveProwlarrBlock,
+    context: Option<ActiveProwlarrBlock>,
+  ) -> IndexersHandler<'a, 'b> {
+    IndexersHandler {
+      key,
+      app,
+      active_prowlarr_block: active_block,
+      context,
+    }
+  }
+
+  fn get_key(&self) -> Key {
+    self.key
+  }
+
+  fn is_ready(&self) -> bool {
+    !self.app.is_loading && !self.app.data.prowlarr_data.indexers.is_empty()
+  }
+
+  fn handle_scroll_up(&mut self) {}
+
+  fn handle_scroll_down(&mut self) {}
+
+  fn handle_home(&mut self) {}
+
+  fn handle_end(&mut self) {}
+
+  fn handle_delete(&mut self) {
+    if self.active_prowlarr_block == ActiveProwlarrBlock::Indexers {
+      self
+        .app
+        .push_navigation_stack(ActiveProwlarrBlock::DeleteIndexerPrompt.into());
+    }
+  }
+
+  fn handle_left_right_action(&mut self) {
+    match self.active_prowlarr_block {
+      ActiveProwlarrBlock::Indexers => handle_change_tab_left_right_keys(self.app, self.key),
+      ActiveProwlarrBlock::DeleteIndexerPrompt => handle_prompt_toggle(self.app, self.key),
+      _ => (),
+    }
+  }
+
+  fn handle_submit(&mut self) {
+    if self.active_prowlarr_block == ActiveProwlarrBlock::DeleteIndexerPrompt {
+      if self.app.data.prowlarr_data.prompt_confirm {
+        self.app.data.prowlarr_data.prompt_confirm_action =
+          Some(ProwlarrEvent::DeleteIndexer(self.extract_indexer_id()));
+      }
+
+      self.app.pop_navigation_stack();
+    }
+  }
+
+  fn handle_esc(&mut self) {
+    match self.active_prowlarr_block {
+      ActiveProwlarrBlock::DeleteIndexerPrompt => {
+        self.app.pop_navigation_stack();
+        self.app.data.prowlarr_data.prompt_confirm = false;
+      }
+      ActiveProwlarrBlock::TestIndexer => {
+        self.app.pop_navigation_stack();
+        self.app.data.prowlarr_data.indexer_test_errors = None;
+      }
+      _ => handle_clear_errors(self.app),
+    }
+  }
+
+  fn handle_char_key_event(&mut self) {
+    let key = self.key;
+    match self.active_prowlarr_block {
+      ActiveProwlarrBlock::Indexers => match self.key {
+        _ if matches_key!(refresh, key) => {
+          self.app.should_refresh = true;
+        }
+        _ if matches_key!(add, key) => {
+          self
+            .app
+            .push_navigation_stack(ActiveProwlarrBlock::AddIndexerSelectImplementation.into());
+        }
+        _ if matches_key!(test, key) => {
+          self
+            .app
+            .push_navigation_stack(ActiveProwlarrBlock::TestIndexer.into());
+        }
+        _ if matches_key!(test_all, key) => {
+          self
+            .app
+            .push_navigation_stack(ActiveProwlarrBlock::TestAllIndexers.into());
+        }
+        _ => (),
+      },
+      ActiveProwlarrBlock::DeleteIndexerPrompt => {
+        if matches_key!(confirm, key) {
+          self.app.data.prowlarr_data.prompt_confirm = true;
+          self.app.data.prowlarr_data.prompt_confirm_action =
+            Some(ProwlarrEvent::DeleteIndexer(self.extract_indexer_id()));
+
+          self.app.pop_navigation_stack();
+        }
+      }
+      _ => (),
+    }
+  }
+
+  fn app_mut(&mut self) -> &mut App<'b> {
+    self.app
+  }
+
+  fn current_route(&self) -> Route {
+    self.app.get_current_route()
+  }
+}
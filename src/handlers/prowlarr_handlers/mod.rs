@@ -0,0 +1,131 @@
+use applications::ApplicationsHandler;
+use history::HistoryHandler;
+use indexer_proxies::IndexerProxiesHandler;
+use indexers::IndexersHandler;
+use search::SearchReleasesHandler;
+
+use super::KeyEventHandler;
+use crate::handlers::prowlarr_handlers::system::SystemHandler;
+use crate::models::Route;
+use crate::{
+  app::App, event::Key, matches_key,
+  models::servarr_data::prowlarr::prowlarr_data::ActiveProwlarrBlock,
+};
+
+mod applications;
+mod history;
+mod indexer_proxies;
+mod indexers;
+mod search;
+mod system;
+
+#[cfg(test)]
+#[path = "prowlarr_handler_tests.rs"]
+mod prowlarr_handler_tests;
+
+pub(super) struct ProwlarrHandler<'a, 'b> {
+  key: Key,
+  app: &'a mut App<'b>,
+  active_prowlarr_block: ActiveProwlarrBlock,
+  context: Option<ActiveProwlarrBlock>,
+}
+
+impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveProwlarrBlock> for ProwlarrHandler<'a, 'b> {
+  fn handle(&mut self) {
+    match self.active_prowlarr_block {
+      _ if IndexersHandler::accepts(self.active_prowlarr_block) => {
+        IndexersHandler::new(self.key, self.app, self.active_prowlarr_block, self.context).handle();
+      }
+      _ if SearchReleasesHandler::accepts(self.active_prowlarr_block) => {
+        SearchReleasesHandler::new(self.key, self.app, self.active_prowlarr_block, self.context)
+          .handle();
+      }
+      _ if ApplicationsHandler::accepts(self.active_prowlarr_block) => {
+        ApplicationsHandler::new(self.key, self.app, self.active_prowlarr_block, self.context)
+          .handle();
+      }
+      _ if IndexerProxiesHandler::accepts(self.active_prowlarr_block) => {
+        IndexerProxiesHandler::new(self.key, self.app, self.active_prowlarr_block, self.context)
+          .handle();
+      }
+      _ if HistoryHandler::accepts(self.active_prowlarr_block) => {
+        HistoryHandler::new(self.key, self.app, self.active_prowlarr_block, self.context).handle();
+      }
+      _ if SystemHandler::accepts(self.active_prowlarr_block) => {
+        SystemHandler::new(self.key, self.app, self.active_prowlarr_block, self.context).handle();
+      }
+      _ => self.handle_key_event(),
+    }
+  }
+
+  fn accepts(_active_block: ActiveProwlarrBlock) -> bool {
+    true
+  }
+
+  fn new(
+    key: Key,
+    app: &'a mut App<'b>,
+    active_block: ActiveProwlarrBlock,
+    context: Option<ActiveProwlarrBlock>,
+  ) -> ProwlarrHandler<'a, 'b> {
+    ProwlarrHandler {
+      key,
+      app,
+      active_prowlarr_block: active_block,
+      context,
+    }
+  }
+
+  fn get_key(&self) -> Key {
+    self.key
+  }
+
+  fn ignore_special_keys(&self) -> bool {
+    self.app.ignore_special_keys_for_textbox_input
+  }
+
+  fn is_ready(&self) -> bool {
+    true
+  }
+
+  fn handle_scroll_up(&mut self) {}
+
+  fn handle_scroll_down(&mut self) {}
+
+  fn handle_home(&mut self) {}
+
+  fn handle_end(&mut self) {}
+
+  fn handle_delete(&mut self) {}
+
+  fn handle_left_right_action(&mut self) {}
+
+  fn handle_submit(&mut self) {}
+
+  fn handle_esc(&mut self) {}
+
+  fn handle_char_key_event(&mut self) {}
+
+  fn app_mut(&mut self) -> &mut App<'b> {
+    self.app
+  }
+
+  fn current_route(&self) -> Route {
+    self.app.get_current_route()
+  }
+}
+
+pub fn handle_change_tab_left_right_keys(app: &mut App<'_>, key: Key) {
+  let key_ref = key;
+  match key_ref {
+    _ if matches_key!(left, key, app.ignore_special_keys_for_textbox_input) => {
+      app.data.prowlarr_data.main_tabs.previous();
+      app.pop_and_push_navigation_stack(app.data.prowlarr_data.main_tabs.get_active_route());
+    }
+    _ if matches_key!(right, key, app.ignore_special_keys_for_textbox_input) => {
+      app.data.prowlarr_data.main_tabs.next();
+      app.pop_and_push_navigation_stack(app.data.prowlarr_data.main_tabs.get_active_route());
+    }
+    _ => (),
+  }
+}
@@ -0,0 +1,268 @@
+#[cfg(test)]
+mod tests {
+  use crate::app::App;
+  use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+  use crate::handlers::KeyEventHandler;
+  use crate::handlers::prowlarr_handlers::{ProwlarrHandler, handle_change_tab_left_right_keys};
+  use crate::models::servarr_data::prowlarr::prowlarr_data::ActiveProwlarrBlock;
+  use crate::{assert_navigation_pushed, test_handler_delegation};
+  use pretty_assertions::assert_eq;
+  use rstest::rstest;
+  use strum::IntoEnumIterator;
+
+  #[rstest]
+  fn test_prowlarr_handler_ignore_special_keys(
+    #[values(true, false)] ignore_special_keys_for_textbox_input: bool,
+  ) {
+    let mut app = App::test_default();
+    app.ignore_special_keys_for_textbox_input = ignore_special_keys_for_textbox_input;
+    let handler = ProwlarrHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveProwlarrBlock::default(),
+      None,
+    );
+
+    assert_eq!(
+      handler.ignore_special_keys(),
+      ignore_special_keys_for_textbox_input
+    );
+  }
+
+  #[test]
+  fn test_prowlarr_handler_is_ready() {
+    let mut app = App::test_default();
+    app.is_loading = true;
+
+    let handler = ProwlarrHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveProwlarrBlock::default(),
+      None,
+    );
+
+    assert!(handler.is_ready());
+  }
+
+  #[test]
+  fn test_prowlarr_handler_accepts() {
+    for prowlarr_block in ActiveProwlarrBlock::iter() {
+      assert!(ProwlarrHandler::accepts(prowlarr_block));
+    }
+  }
+
+  #[rstest]
+  #[case(0, ActiveProwlarrBlock::System, ActiveProwlarrBlock::SearchReleases)]
+  #[case(1, ActiveProwlarrBlock::Indexers, ActiveProwlarrBlock::Applications)]
+  #[case(
+    2,
+    ActiveProwlarrBlock::SearchReleases,
+    ActiveProwlarrBlock::IndexerProxies
+  )]
+  #[case(3, ActiveProwlarrBlock::Applications, ActiveProwlarrBlock::History)]
+  #[case(4, ActiveProwlarrBlock::IndexerProxies, ActiveProwlarrBlock::System)]
+  #[case(5, ActiveProwlarrBlock::History, ActiveProwlarrBlock::Indexers)]
+  fn test_prowlarr_handler_change_tab_left_right_keys(
+    #[case] index: usize,
+    #[case] left_block: ActiveProwlarrBlock,
+    #[case] right_block: ActiveProwlarrBlock,
+  ) {
+    let mut app = App::test_default();
+    app.data.prowlarr_data.main_tabs.set_index(index);
+
+    handle_change_tab_left_right_keys(&mut app, DEFAULT_KEYBINDINGS.left.key);
+
+    assert_eq!(
+      app.data.prowlarr_data.main_tabs.get_active_route(),
+      left_block.into()
+    );
+    assert_navigation_pushed!(app, left_block.into());
+
+    app.data.prowlarr_data.main_tabs.set_index(index);
+
+    handle_change_tab_left_right_keys(&mut app, DEFAULT_KEYBINDINGS.right.key);
+
+    assert_eq!(
+      app.data.prowlarr_data.main_tabs.get_active_route(),
+      right_block.into()
+    );
+    assert_navigation_pushed!(app, right_block.into());
+  }
+
+  #[rstest]
+  #[case(0, ActiveProwlarrBlock::System, ActiveProwlarrBlock::SearchReleases)]
+  #[case(1, ActiveProwlarrBlock::Indexers, ActiveProwlarrBlock::Applications)]
+  #[case(
+    2,
+    ActiveProwlarrBlock::SearchReleases,
+    ActiveProwlarrBlock::IndexerProxies
+  )]
+  #[case(3, ActiveProwlarrBlock::Applications, ActiveProwlarrBlock::History)]
+  #[case(4, ActiveProwlarrBlock::IndexerProxies, ActiveProwlarrBlock::System)]
+  #[case(5, ActiveProwlarrBlock::History, ActiveProwlarrBlock::Indexers)]
+  fn test_prowlarr_handler_change_tab_left_right_keys_alt_navigation(
+    #[case] index: usize,
+    #[case] left_block: ActiveProwlarrBlock,
+    #[case] right_block: ActiveProwlarrBlock,
+  ) {
+    let mut app = App::test_default();
+    app.data.prowlarr_data.main_tabs.set_index(index);
+
+    handle_change_tab_left_right_keys(&mut app, DEFAULT_KEYBINDINGS.left.alt.unwrap());
+
+    assert_eq!(
+      app.data.prowlarr_data.main_tabs.get_active_route(),
+      left_block.into()
+    );
+    assert_navigation_pushed!(app, left_block.into());
+
+    app.data.prowlarr_data.main_tabs.set_index(index);
+
+    handle_change_tab_left_right_keys(&mut app, DEFAULT_KEYBINDINGS.right.alt.unwrap());
+
+    assert_eq!(
+      app.data.prowlarr_data.main_tabs.get_active_route(),
+      right_block.into()
+    );
+    assert_navigation_pushed!(app, right_block.into());
+  }
+
+  #[rstest]
+  #[case(0, ActiveProwlarrBlock::Indexers)]
+  #[case(1, ActiveProwlarrBlock::SearchReleases)]
+  #[case(2, ActiveProwlarrBlock::Applications)]
+  #[case(3, ActiveProwlarrBlock::IndexerProxies)]
+  #[case(4, ActiveProwlarrBlock::History)]
+  #[case(5, ActiveProwlarrBlock::System)]
+  fn test_prowlarr_handler_change_tab_left_right_keys_alt_navigation_no_op_when_ignoring_quit_key(
+    #[case] index: usize,
+    #[case] block: ActiveProwlarrBlock,
+  ) {
+    let mut app = App::test_default();
+    app.push_navigation_stack(block.into());
+    app.ignore_special_keys_for_textbox_input = true;
+    app.data.prowlarr_data.main_tabs.set_index(index);
+
+    handle_change_tab_left_right_keys(&mut app, DEFAULT_KEYBINDINGS.left.alt.unwrap());
+
+    assert_eq!(
+      app.data.prowlarr_data.main_tabs.get_active_route(),
+      block.into()
+    );
+    assert_eq!(app.get_current_route(), block.into());
+
+    app.data.prowlarr_data.main_tabs.set_index(index);
+
+    handle_change_tab_left_right_keys(&mut app, DEFAULT_KEYBINDINGS.right.alt.unwrap());
+
+    assert_eq!(
+      app.data.prowlarr_data.main_tabs.get_active_route(),
+      block.into()
+    );
+    assert_eq!(app.get_current_route(), block.into());
+  }
+
+  #[rstest]
+  fn test_delegates_indexers_blocks_to_indexers_handler(
+    #[values(
+      ActiveProwlarrBlock::Indexers,
+      ActiveProwlarrBlock::DeleteIndexerPrompt,
+      ActiveProwlarrBlock::TestIndexer,
+      ActiveProwlarrBlock::TestAllIndexers
+    )]
+    active_prowlarr_block: ActiveProwlarrBlock,
+  ) {
+    test_handler_delegation!(
+      ProwlarrHandler,
+      ActiveProwlarrBlock::Indexers,
+      active_prowlarr_block
+    );
+  }
+
+  #[rstest]
+  fn test_delegates_search_releases_blocks_to_search_releases_handler(
+    #[values(
+      ActiveProwlarrBlock::SearchReleases,
+      ActiveProwlarrBlock::SearchReleasesInput,
+      ActiveProwlarrBlock::SearchReleasesSortPrompt,
+      ActiveProwlarrBlock::GrabReleaseConfirmPrompt
+    )]
+    active_prowlarr_block: ActiveProwlarrBlock,
+  ) {
+    test_handler_delegation!(
+      ProwlarrHandler,
+      ActiveProwlarrBlock::SearchReleases,
+      active_prowlarr_block
+    );
+  }
+
+  #[rstest]
+  fn test_delegates_applications_blocks_to_applications_handler(
+    #[values(
+      ActiveProwlarrBlock::Applications,
+      ActiveProwlarrBlock::DeleteApplicationPrompt,
+      ActiveProwlarrBlock::SyncAppIndexersPrompt
+    )]
+    active_prowlarr_block: ActiveProwlarrBlock,
+  ) {
+    test_handler_delegation!(
+      ProwlarrHandler,
+      ActiveProwlarrBlock::Applications,
+      active_prowlarr_block
+    );
+  }
+
+  #[rstest]
+  fn test_delegates_indexer_proxies_blocks_to_indexer_proxies_handler(
+    #[values(
+      ActiveProwlarrBlock::IndexerProxies,
+      ActiveProwlarrBlock::DeleteIndexerProxyPrompt
+    )]
+    active_prowlarr_block: ActiveProwlarrBlock,
+  ) {
+    test_handler_delegation!(
+      ProwlarrHandler,
+      ActiveProwlarrBlock::IndexerProxies,
+      active_prowlarr_block
+    );
+  }
+
+  #[rstest]
+  fn test_delegates_history_blocks_to_history_handler(
+    #[values(
+      ActiveProwlarrBlock::History,
+      ActiveProwlarrBlock::HistoryItemDetails,
+      ActiveProwlarrBlock::HistorySortPrompt,
+      ActiveProwlarrBlock::FilterHistory,
+      ActiveProwlarrBlock::FilterHistoryError,
+      ActiveProwlarrBlock::SearchHistory,
+      ActiveProwlarrBlock::SearchHistoryError
+    )]
+    active_prowlarr_block: ActiveProwlarrBlock,
+  ) {
+    test_handler_delegation!(
+      ProwlarrHandler,
+      ActiveProwlarrBlock::History,
+      active_prowlarr_block
+    );
+  }
+
+  #[rstest]
+  fn test_delegates_system_blocks_to_system_handler(
+    #[values(
+      ActiveProwlarrBlock::System,
+      ActiveProwlarrBlock::SystemLogs,
+      ActiveProwlarrBlock::SystemQueuedEvents,
+      ActiveProwlarrBlock::SystemTasks,
+      ActiveProwlarrBlock::SystemTaskStartConfirmPrompt,
+      ActiveProwlarrBlock::SystemUpdates
+    )]
+    active_prowlarr_block: ActiveProwlarrBlock,
+  ) {
+    test_handler_delegation!(
+      ProwlarrHandler,
+      ActiveProwlarrBlock::System,
+      active_prowlarr_block
+    );
+  }
+}
@@ -0,0 +1,288 @@
+use serde_json::Number;
+
+use crate::app::App;
+use crate::event::Key;
+use crate::handlers::prowlarr_handlers::handle_change_tab_left_right_keys;
+use crate::handlers::table_handler::{TableHandlingConfig, handle_table};
+use crate::handlers::{KeyEventHandler, handle_clear_errors, handle_prompt_toggle};
+use crate::models::Route;
+use crate::models::prowlarr_models::{ProwlarrRelease, ProwlarrReleaseDownloadBody};
+use crate::models::servarr_data::prowlarr::prowlarr_data::{
+  ActiveProwlarrBlock, SEARCH_RELEASES_BLOCKS,
+};
+use crate::models::stateful_table::SortOption;
+use crate::network::prowlarr_network::ProwlarrEvent;
+use crate::{handle_text_box_keys, handle_text_box_left_right_keys, matches_key};
+
+#[cfg(test)]
+#[path = "search_releases_handler_tests.rs"]
+mod search_releases_handler_tests;
+
+pub(super) struct SearchReleasesHandler<'a, 'b> {
+  key: Key,
+  app: &'a mut App<'b>,
+  active_prowlarr_block: ActiveProwlarrBlock,
+  _context: Option<ActiveProwlarrBlock>,
+}
+
+impl SearchReleasesHandler<'_, '_> {
+  fn build_grab_release_body(&self) -> ProwlarrReleaseDownloadBody {
+    let ProwlarrRelease {
+      guid, indexer_id, ..
+    } = self
+      .app
+      .data
+      .prowlarr_data
+      .search_results
+      .current_selection();
+
+    ProwlarrReleaseDownloadBody {
+      guid: guid.clone(),
+      indexer_id: *indexer_id,
+    }
+  }
+}
+
+impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveProwlarrBlock> for SearchReleasesHandler<'a, 'b> {
+  fn handle(&mut self) {
+    let search_releases_table_handling_config =
+      TableHandlingConfig::new(ActiveProwlarrBlock::SearchReleases.into())
+        .sorting_block(ActiveProwlarrBlock::SearchReleasesSortPrompt.into())
+        .sort_options(releases_sorting_options());
+
+    if self.active_prowlarr_block == ActiveProwlarrBlock::SearchReleasesInput
+      || self.app.data.prowlarr_data.search_results.is_empty()
+      || !handle_table(
+        self,
+        |app| &mut app.data.prowlarr_data.search_results,
+        search_releases_table_handling_config,
+      )
+    {
+      self.handle_key_event();
+    }
+  }
+
+  fn accepts(active_block: ActiveProwlarrBlock) -> bool {
+    SEARCH_RELEASES_BLOCKS.contains(&active_block)
+  }
+
+  fn ignore_special_keys(&self) -> bool {
+    self.app.ignore_special_keys_for_textbox_input
+  }
+
+  fn new(
+    key: Key,
+    app: &'a mut App<'b>,
+    active_block: ActiveProwlarrBlock,
+    context: Option<ActiveProwlarrBlock>,
+  ) -> SearchReleasesHandler<'a, 'b> {
+    SearchReleasesHandler {
+      key,
+      app,
+      active_prowlarr_block: active_block,
+      _context: context,
+    }
+  }
+
+  fn get_key(&self) -> Key {
+    self.key
+  }
+
+  fn is_ready(&self) -> bool {
+    !self.app.is_loading
+  }
+
+  fn handle_scroll_up(&mut self) {}
+
+  fn handle_scroll_down(&mut self) {}
+
+  fn handle_home(&mut self) {
+    if self.active_prowlarr_block == ActiveProwlarrBlock::SearchReleasesInput
+      && let Some(search_query) = self.app.data.prowlarr_data.search_query.as_mut()
+    {
+      search_query.scroll_home();
+    }
+  }
+
+  fn handle_end(&mut self) {
+    if self.active_prowlarr_block == ActiveProwlarrBlock::SearchReleasesInput
+      && let Some(search_query) = self.app.data.prowlarr_data.search_query.as_mut()
+    {
+      search_query.reset_offset();
+    }
+  }
+
+  fn handle_delete(&mut self) {}
+
+  fn handle_left_right_action(&mut self) {
+    match self.active_prowlarr_block {
+      ActiveProwlarrBlock::SearchReleases => handle_change_tab_left_right_keys(self.app, self.key),
+      ActiveProwlarrBlock::SearchReleasesInput => {
+        handle_text_box_left_right_keys!(
+          self,
+          self.key,
+          self.app.data.prowlarr_data.search_query.as_mut().unwrap()
+        )
+      }
+      ActiveProwlarrBlock::GrabReleaseConfirmPrompt => handle_prompt_toggle(self.app, self.key),
+      _ => (),
+    }
+  }
+
+  fn handle_submit(&mut self) {
+    match self.active_prowlarr_block {
+      ActiveProwlarrBlock::SearchReleases
+        if !self.app.data.prowlarr_data.search_results.is_empty() =>
+      {
+        self
+          .app
+          .push_navigation_stack(ActiveProwlarrBlock::GrabReleaseConfirmPrompt.into());
+      }
+      ActiveProwlarrBlock::SearchReleasesInput
+        if !self
+          .app
+          .data
+          .prowlarr_data
+          .search_query
+          .as_ref()
+          .unwrap()
+          .text
+          .trim()
+          .is_empty() =>
+      {
+        self.app.pop_navigation_stack();
+        self
+          .app
+          .data
+          .prowlarr_data
+          .search_results
+          .set_items(Vec::new());
+        self.app.ignore_special_keys_for_textbox_input = false;
+        self.app.should_refresh = true;
+      }
+      ActiveProwlarrBlock::GrabReleaseConfirmPrompt => {
+        if self.app.data.prowlarr_data.prompt_confirm {
+          self.app.data.prowlarr_data.prompt_confirm_action =
+            Some(ProwlarrEvent::GrabRelease(self.build_grab_release_body()));
+        }
+
+        self.app.pop_navigation_stack();
+      }
+      _ => (),
+    }
+  }
+
+  fn handle_esc(&mut self) {
+    match self.active_prowlarr_block {
+      ActiveProwlarrBlock::SearchReleasesInput => {
+        self.app.pop_navigation_stack();
+        self.app.ignore_special_keys_for_textbox_input = false;
+      }
+      ActiveProwlarrBlock::GrabReleaseConfirmPrompt => {
+        self.app.pop_navigation_stack();
+        self.app.data.prowlarr_data.prompt_confirm = false;
+      }
+      _ => handle_clear_errors(self.app),
+    }
+  }
+
+  fn handle_char_key_event(&mut self) {
+    let key = self.key;
+    match self.active_prowlarr_block {
+      ActiveProwlarrBlock::SearchReleases => match self.key {
+        _ if matches_key!(search, key) => {
+          if self.app.data.prowlarr_data.search_query.is_none() {
+            self.app.data.prowlarr_data.search_query = Some(Default::default());
+          }
+
+          self
+            .app
+            .push_navigation_stack(ActiveProwlarrBlock::SearchReleasesInput.into());
+          self.app.ignore_special_keys_for_textbox_input = true;
+        }
+        _ if matches_key!(refresh, key) => {
+          self
+            .app
+            .data
+            .prowlarr_data
+            .search_results
+            .set_items(Vec::new());
+          self.app.should_refresh = true;
+        }
+        _ => (),
+      },
+      ActiveProwlarrBlock::SearchReleasesInput => {
+        handle_text_box_keys!(
+          self,
+          key,
+          self.app.data.prowlarr_data.search_query.as_mut().unwrap()
+        )
+      }
+      ActiveProwlarrBlock::GrabReleaseConfirmPrompt => {
+        if matches_key!(confirm, key) {
+          self.app.data.prowlarr_data.prompt_confirm = true;
+          self.app.data.prowlarr_data.prompt_confirm_action =
+            Some(ProwlarrEvent::GrabRelease(self.build_grab_release_body()));
+
+          self.app.pop_navigation_stack();
+        }
+      }
+      _ => (),
+    }
+  }
+
+  fn app_mut(&mut self) -> &mut App<'b> {
+    self.app
+  }
+
+  fn current_route(&self) -> Route {
+    self.app.get_current_route()
+  }
+}
+
+pub(in crate::handlers::prowlarr_handlers) fn releases_sorting_options()
+-> Vec<SortOption<ProwlarrRelease>> {
+  vec![
+    SortOption {
+      name: "Age",
+      cmp_fn: Some(|a, b| a.age.cmp(&b.age)),
+    },
+    SortOption {
+      name: "Title",
+      cmp_fn: Some(|a, b| {
+        a.title
+          .text
+          .to_lowercase()
+          .cmp(&b.title.text.to_lowercase())
+      }),
+    },
+    SortOption {
+      name: "Indexer",
+      cmp_fn: Some(|a, b| a.indexer.to_lowercase().cmp(&b.indexer.to_lowercase())),
+    },
+    SortOption {
+      name: "Size",
+      cmp_fn: Some(|a, b| a.size.cmp(&b.size)),
+    },
+    SortOption {
+      name: "Peers",
+      cmp_fn: Some(|a, b| {
+        let default_number = Number::from(i64::MAX);
+        let seeder_a = a
+          .seeders
+          .as_ref()
+          .unwrap_or(&default_number)
+          .as_u64()
+          .unwrap();
+        let seeder_b = b
+          .seeders
+          .as_ref()
+          .unwrap_or(&default_number)
+          .as_u64()
+          .unwrap();
+
+        seeder_a.cmp(&seeder_b)
+      }),
+    },
+  ]
+}
@@ -0,0 +1,742 @@
+#[cfg(test)]
+mod tests {
+  use std::cmp::Ordering;
+  use std::sync::atomic;
+
+  use pretty_assertions::{assert_eq, assert_str_eq};
+  use rstest::rstest;
+  use serde_json::Number;
+  use strum::IntoEnumIterator;
+
+  use crate::app::App;
+  use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+  use crate::event::Key;
+  use crate::handlers::KeyEventHandler;
+  use crate::handlers::prowlarr_handlers::search::{
+    SearchReleasesHandler, releases_sorting_options,
+  };
+  use crate::models::HorizontallyScrollableText;
+  use crate::models::prowlarr_models::{ProwlarrRelease, ProwlarrReleaseDownloadBody};
+  use crate::models::servarr_data::prowlarr::prowlarr_data::{
+    ActiveProwlarrBlock, SEARCH_RELEASES_BLOCKS,
+  };
+  use crate::network::prowlarr_network::ProwlarrEvent;
+  use crate::network::prowlarr_network::prowlarr_network_test_utils::test_utils::release;
+  use crate::{assert_navigation_popped, assert_navigation_pushed};
+
+  mod test_handle_home_end {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_search_releases_input_home_end_keys() {
+      let mut app = App::test_default();
+      app.data.prowlarr_data.search_query = Some("Test".into());
+
+      SearchReleasesHandler::new(
+        DEFAULT_KEYBINDINGS.home.key,
+        &mut app,
+        ActiveProwlarrBlock::SearchReleasesInput,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app
+          .data
+          .prowlarr_data
+          .search_query
+          .as_ref()
+          .unwrap()
+          .offset
+          .load(atomic::Ordering::SeqCst),
+        4
+      );
+
+      SearchReleasesHandler::new(
+        DEFAULT_KEYBINDINGS.end.key,
+        &mut app,
+        ActiveProwlarrBlock::SearchReleasesInput,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app
+          .data
+          .prowlarr_data
+          .search_query
+          .as_ref()
+          .unwrap()
+          .offset
+          .load(atomic::Ordering::SeqCst),
+        0
+      );
+    }
+  }
+
+  mod test_handle_left_right_action {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[rstest]
+    fn test_search_releases_tab_left(#[values(true, false)] is_ready: bool) {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveProwlarrBlock::SearchReleases.into());
+      app.is_loading = is_ready;
+      app.data.prowlarr_data.main_tabs.set_index(1);
+
+      SearchReleasesHandler::new(
+        DEFAULT_KEYBINDINGS.left.key,
+        &mut app,
+        ActiveProwlarrBlock::SearchReleases,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.data.prowlarr_data.main_tabs.get_active_route(),
+        ActiveProwlarrBlock::Indexers.into()
+      );
+      assert_navigation_pushed!(app, ActiveProwlarrBlock::Indexers.into());
+    }
+
+    #[rstest]
+    fn test_search_releases_tab_right(#[values(true, false)] is_ready: bool) {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveProwlarrBlock::SearchReleases.into());
+      app.is_loading = is_ready;
+      app.data.prowlarr_data.main_tabs.set_index(1);
+
+      SearchReleasesHandler::new(
+        DEFAULT_KEYBINDINGS.right.key,
+        &mut app,
+        ActiveProwlarrBlock::SearchReleases,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.data.prowlarr_data.main_tabs.get_active_route(),
+        ActiveProwlarrBlock::Applications.into()
+      );
+      assert_navigation_pushed!(app, ActiveProwlarrBlock::Applications.into());
+    }
+
+    #[test]
+    fn test_search_releases_input_left_right_keys() {
+      let mut app = App::test_default();
+      app.data.prowlarr_data.search_query = Some("Test".into());
+
+      SearchReleasesHandler::new(
+        DEFAULT_KEYBINDINGS.left.key,
+        &mut app,
+        ActiveProwlarrBlock::SearchReleasesInput,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app
+          .data
+          .prowlarr_data
+          .search_query
+          .as_ref()
+          .unwrap()
+          .offset
+          .load(atomic::Ordering::SeqCst),
+        1
+      );
+
+      SearchReleasesHandler::new(
+        DEFAULT_KEYBINDINGS.right.key,
+        &mut app,
+        ActiveProwlarrBlock::SearchReleasesInput,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app
+          .data
+          .prowlarr_data
+          .search_query
+          .as_ref()
+          .unwrap()
+          .offset
+          .load(atomic::Ordering::SeqCst),
+        0
+      );
+    }
+
+    #[rstest]
+    fn test_left_right_grab_release_prompt_toggle(
+      #[values(DEFAULT_KEYBINDINGS.left.key, DEFAULT_KEYBINDINGS.right.key)] key: Key,
+    ) {
+      let mut app = App::test_default();
+      app
+        .data
+        .prowlarr_data
+        .search_results
+        .set_items(vec![release()]);
+      app.push_navigation_stack(ActiveProwlarrBlock::SearchReleases.into());
+      app.push_navigation_stack(ActiveProwlarrBlock::GrabReleaseConfirmPrompt.into());
+
+      SearchReleasesHandler::new(
+        key,
+        &mut app,
+        ActiveProwlarrBlock::GrabReleaseConfirmPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(app.data.prowlarr_data.prompt_confirm);
+
+      SearchReleasesHandler::new(
+        key,
+        &mut app,
+        ActiveProwlarrBlock::GrabReleaseConfirmPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(!app.data.prowlarr_data.prompt_confirm);
+    }
+  }
+
+  mod test_handle_submit {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    const SUBMIT_KEY: Key = DEFAULT_KEYBINDINGS.submit.key;
+
+    #[test]
+    fn test_search_releases_submit() {
+      let mut app = App::test_default();
+      app
+        .data
+        .prowlarr_data
+        .search_results
+        .set_items(vec![release()]);
+      app.push_navigation_stack(ActiveProwlarrBlock::SearchReleases.into());
+
+      SearchReleasesHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveProwlarrBlock::SearchReleases,
+        None,
+      )
+      .handle();
+
+      assert_navigation_pushed!(app, ActiveProwlarrBlock::GrabReleaseConfirmPrompt.into());
+    }
+
+    #[test]
+    fn test_search_releases_submit_no_op_when_no_results() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveProwlarrBlock::SearchReleases.into());
+
+      SearchReleasesHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveProwlarrBlock::SearchReleases,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveProwlarrBlock::SearchReleases.into()
+      );
+    }
+
+    #[test]
+    fn test_search_releases_input_submit() {
+      let mut app = App::test_default();
+      app.ignore_special_keys_for_textbox_input = true;
+      app
+        .data
+        .prowlarr_data
+        .search_results
+        .set_items(vec![release()]);
+      app.data.prowlarr_data.search_query = Some("Test".into());
+      app.push_navigation_stack(ActiveProwlarrBlock::SearchReleases.into());
+      app.push_navigation_stack(ActiveProwlarrBlock::SearchReleasesInput.into());
+
+      SearchReleasesHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveProwlarrBlock::SearchReleasesInput,
+        None,
+      )
+      .handle();
+
+      assert_navigation_popped!(app, ActiveProwlarrBlock::SearchReleases.into());
+      assert!(!app.ignore_special_keys_for_textbox_input);
+      assert!(app.should_refresh);
+      assert_is_empty!(app.data.prowlarr_data.search_results.items);
+    }
+
+    #[test]
+    fn test_search_releases_input_submit_noop_on_empty_query() {
+      let mut app = App::test_default();
+      app.ignore_special_keys_for_textbox_input = true;
+      app.data.prowlarr_data.search_query = Some(HorizontallyScrollableText::default());
+      app.push_navigation_stack(ActiveProwlarrBlock::SearchReleases.into());
+      app.push_navigation_stack(ActiveProwlarrBlock::SearchReleasesInput.into());
+
+      SearchReleasesHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveProwlarrBlock::SearchReleasesInput,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveProwlarrBlock::SearchReleasesInput.into()
+      );
+      assert!(app.ignore_special_keys_for_textbox_input);
+      assert!(!app.should_refresh);
+    }
+
+    #[test]
+    fn test_grab_release_prompt_confirm_submit() {
+      let mut app = App::test_default();
+      app
+        .data
+        .prowlarr_data
+        .search_results
+        .set_items(vec![release()]);
+      app.data.prowlarr_data.prompt_confirm = true;
+      app.push_navigation_stack(ActiveProwlarrBlock::SearchReleases.into());
+      app.push_navigation_stack(ActiveProwlarrBlock::GrabReleaseConfirmPrompt.into());
+
+      SearchReleasesHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveProwlarrBlock::GrabReleaseConfirmPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(app.data.prowlarr_data.prompt_confirm);
+      assert_some_eq_x!(
+        &app.data.prowlarr_data.prompt_confirm_action,
+        &ProwlarrEvent::GrabRelease(ProwlarrReleaseDownloadBody {
+          guid: "1234".to_owned(),
+          indexer_id: 1,
+        })
+      );
+      assert_navigation_popped!(app, ActiveProwlarrBlock::SearchReleases.into());
+    }
+
+    #[test]
+    fn test_grab_release_prompt_decline_submit() {
+      let mut app = App::test_default();
+      app
+        .data
+        .prowlarr_data
+        .search_results
+        .set_items(vec![release()]);
+      app.push_navigation_stack(ActiveProwlarrBlock::SearchReleases.into());
+      app.push_navigation_stack(ActiveProwlarrBlock::GrabReleaseConfirmPrompt.into());
+
+      SearchReleasesHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveProwlarrBlock::GrabReleaseConfirmPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(!app.data.prowlarr_data.prompt_confirm);
+      assert_none!(app.data.prowlarr_data.prompt_confirm_action);
+      assert_navigation_popped!(app, ActiveProwlarrBlock::SearchReleases.into());
+    }
+  }
+
+  mod test_handle_esc {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    const ESC_KEY: Key = DEFAULT_KEYBINDINGS.esc.key;
+
+    #[test]
+    fn test_search_releases_input_esc() {
+      let mut app = App::test_default();
+      app.ignore_special_keys_for_textbox_input = true;
+      app.data.prowlarr_data.search_query = Some("Test".into());
+      app.push_navigation_stack(ActiveProwlarrBlock::SearchReleases.into());
+      app.push_navigation_stack(ActiveProwlarrBlock::SearchReleasesInput.into());
+
+      SearchReleasesHandler::new(
+        ESC_KEY,
+        &mut app,
+        ActiveProwlarrBlock::SearchReleasesInput,
+        None,
+      )
+      .handle();
+
+      assert_navigation_popped!(app, ActiveProwlarrBlock::SearchReleases.into());
+      assert!(!app.ignore_special_keys_for_textbox_input);
+    }
+
+    #[test]
+    fn test_grab_release_prompt_esc() {
+      let mut app = App::test_default();
+      app
+        .data
+        .prowlarr_data
+        .search_results
+        .set_items(vec![release()]);
+      app.data.prowlarr_data.prompt_confirm = true;
+      app.push_navigation_stack(ActiveProwlarrBlock::SearchReleases.into());
+      app.push_navigation_stack(ActiveProwlarrBlock::GrabReleaseConfirmPrompt.into());
+
+      SearchReleasesHandler::new(
+        ESC_KEY,
+        &mut app,
+        ActiveProwlarrBlock::GrabReleaseConfirmPrompt,
+        None,
+      )
+      .handle();
+
+      assert_navigation_popped!(app, ActiveProwlarrBlock::SearchReleases.into());
+      assert!(!app.data.prowlarr_data.prompt_confirm);
+    }
+
+    #[rstest]
+    fn test_default_esc(#[values(true, false)] is_ready: bool) {
+      let mut app = App::test_default();
+      app.is_loading = is_ready;
+      app.error = "test error".to_owned().into();
+      app.push_navigation_stack(ActiveProwlarrBlock::SearchReleases.into());
+      app.push_navigation_stack(ActiveProwlarrBlock::SearchReleases.into());
+
+      SearchReleasesHandler::new(ESC_KEY, &mut app, ActiveProwlarrBlock::SearchReleases, None)
+        .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveProwlarrBlock::SearchReleases.into()
+      );
+      assert_is_empty!(app.error.text);
+    }
+  }
+
+  mod test_handle_key_char {
+    use pretty_assertions::assert_str_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_search_releases_search_key() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveProwlarrBlock::SearchReleases.into());
+
+      SearchReleasesHandler::new(
+        DEFAULT_KEYBINDINGS.search.key,
+        &mut app,
+        ActiveProwlarrBlock::SearchReleases,
+        None,
+      )
+      .handle();
+
+      assert_navigation_pushed!(app, ActiveProwlarrBlock::SearchReleasesInput.into());
+      assert!(app.ignore_special_keys_for_textbox_input);
+      assert_some_eq_x!(
+        &app.data.prowlarr_data.search_query,
+        &HorizontallyScrollableText::default()
+      );
+    }
+
+    #[test]
+    fn test_search_releases_search_key_preserves_previous_query() {
+      let mut app = App::test_default();
+      app
+        .data
+        .prowlarr_data
+        .search_results
+        .set_items(vec![release()]);
+      app.data.prowlarr_data.search_query = Some("Test".into());
+      app.push_navigation_stack(ActiveProwlarrBlock::SearchReleases.into());
+
+      SearchReleasesHandler::new(
+        DEFAULT_KEYBINDINGS.search.key,
+        &mut app,
+        ActiveProwlarrBlock::SearchReleases,
+        None,
+      )
+      .handle();
+
+      assert_navigation_pushed!(app, ActiveProwlarrBlock::SearchReleasesInput.into());
+      assert_str_eq!(
+        app.data.prowlarr_data.search_query.as_ref().unwrap().text,
+        "Test"
+      );
+    }
+
+    #[test]
+    fn test_refresh_search_releases_key() {
+      let mut app = App::test_default();
+      app
+        .data
+        .prowlarr_data
+        .search_results
+        .set_items(vec![release()]);
+      app.push_navigation_stack(ActiveProwlarrBlock::SearchReleases.into());
+
+      SearchReleasesHandler::new(
+        DEFAULT_KEYBINDINGS.refresh.key,
+        &mut app,
+        ActiveProwlarrBlock::SearchReleases,
+        None,
+      )
+      .handle();
+
+      assert_navigation_pushed!(app, ActiveProwlarrBlock::SearchReleases.into());
+      assert!(app.should_refresh);
+      assert_is_empty!(app.data.prowlarr_data.search_results.items);
+    }
+
+    #[test]
+    fn test_search_releases_input_backspace() {
+      let mut app = App::test_default();
+      app.data.prowlarr_data.search_query = Some("Test".into());
+
+      SearchReleasesHandler::new(
+        DEFAULT_KEYBINDINGS.backspace.key,
+        &mut app,
+        ActiveProwlarrBlock::SearchReleasesInput,
+        None,
+      )
+      .handle();
+
+      assert_str_eq!(
+        app.data.prowlarr_data.search_query.as_ref().unwrap().text,
+        "Tes"
+      );
+    }
+
+    #[test]
+    fn test_search_releases_input_char_key() {
+      let mut app = App::test_default();
+      app.data.prowlarr_data.search_query = Some(HorizontallyScrollableText::default());
+
+      SearchReleasesHandler::new(
+        Key::Char('a'),
+        &mut app,
+        ActiveProwlarrBlock::SearchReleasesInput,
+        None,
+      )
+      .handle();
+
+      assert_str_eq!(
+        app.data.prowlarr_data.search_query.as_ref().unwrap().text,
+        "a"
+      );
+    }
+
+    #[test]
+    fn test_grab_release_prompt_confirm() {
+      let mut app = App::test_default();
+      app
+        .data
+        .prowlarr_data
+        .search_results
+        .set_items(vec![release()]);
+      app.push_navigation_stack(ActiveProwlarrBlock::SearchReleases.into());
+      app.push_navigation_stack(ActiveProwlarrBlock::GrabReleaseConfirmPrompt.into());
+
+      SearchReleasesHandler::new(
+        DEFAULT_KEYBINDINGS.confirm.key,
+        &mut app,
+        ActiveProwlarrBlock::GrabReleaseConfirmPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(app.data.prowlarr_data.prompt_confirm);
+      assert_some_eq_x!(
+        &app.data.prowlarr_data.prompt_confirm_action,
+        &ProwlarrEvent::GrabRelease(ProwlarrReleaseDownloadBody {
+          guid: "1234".to_owned(),
+          indexer_id: 1,
+        })
+      );
+      assert_navigation_popped!(app, ActiveProwlarrBlock::SearchReleases.into());
+    }
+  }
+
+  #[test]
+  fn test_releases_sorting_options_age() {
+    let expected_cmp_fn: fn(&ProwlarrRelease, &ProwlarrRelease) -> Ordering =
+      |a, b| a.age.cmp(&b.age);
+    let mut expected_releases_vec = releases_vec();
+    expected_releases_vec.sort_by(expected_cmp_fn);
+
+    let sort_option = releases_sorting_options()[0].clone();
+    let mut sorted_releases_vec = releases_vec();
+    sorted_releases_vec.sort_by(sort_option.cmp_fn.unwrap());
+
+    assert_eq!(sorted_releases_vec, expected_releases_vec);
+    assert_str_eq!(sort_option.name, "Age");
+  }
+
+  #[test]
+  fn test_releases_sorting_options_title() {
+    let expected_cmp_fn: fn(&ProwlarrRelease, &ProwlarrRelease) -> Ordering = |a, b| {
+      a.title
+        .text
+        .to_lowercase()
+        .cmp(&b.title.text.to_lowercase())
+    };
+    let mut expected_releases_vec = releases_vec();
+    expected_releases_vec.sort_by(expected_cmp_fn);
+
+    let sort_option = releases_sorting_options()[1].clone();
+    let mut sorted_releases_vec = releases_vec();
+    sorted_releases_vec.sort_by(sort_option.cmp_fn.unwrap());
+
+    assert_eq!(sorted_releases_vec, expected_releases_vec);
+    assert_str_eq!(sort_option.name, "Title");
+  }
+
+  #[test]
+  fn test_releases_sorting_options_indexer() {
+    let expected_cmp_fn: fn(&ProwlarrRelease, &ProwlarrRelease) -> Ordering =
+      |a, b| a.indexer.to_lowercase().cmp(&b.indexer.to_lowercase());
+    let mut expected_releases_vec = releases_vec();
+    expected_releases_vec.sort_by(expected_cmp_fn);
+
+    let sort_option = releases_sorting_options()[2].clone();
+    let mut sorted_releases_vec = releases_vec();
+    sorted_releases_vec.sort_by(sort_option.cmp_fn.unwrap());
+
+    assert_eq!(sorted_releases_vec, expected_releases_vec);
+    assert_str_eq!(sort_option.name, "Indexer");
+  }
+
+  #[test]
+  fn test_releases_sorting_options_size() {
+    let expected_cmp_fn: fn(&ProwlarrRelease, &ProwlarrRelease) -> Ordering =
+      |a, b| a.size.cmp(&b.size);
+    let mut expected_releases_vec = releases_vec();
+    expected_releases_vec.sort_by(expected_cmp_fn);
+
+    let sort_option = releases_sorting_options()[3].clone();
+    let mut sorted_releases_vec = releases_vec();
+    sorted_releases_vec.sort_by(sort_option.cmp_fn.unwrap());
+
+    assert_eq!(sorted_releases_vec, expected_releases_vec);
+    assert_str_eq!(sort_option.name, "Size");
+  }
+
+  #[test]
+  fn test_releases_sorting_options_peers() {
+    let expected_cmp_fn: fn(&ProwlarrRelease, &ProwlarrRelease) -> Ordering = |a, b| {
+      let default_number = Number::from(i64::MAX);
+      let seeder_a = a
+        .seeders
+        .as_ref()
+        .unwrap_or(&default_number)
+        .as_u64()
+        .unwrap();
+      let seeder_b = b
+        .seeders
+        .as_ref()
+        .unwrap_or(&default_number)
+        .as_u64()
+        .unwrap();
+
+      seeder_a.cmp(&seeder_b)
+    };
+    let mut expected_releases_vec = releases_vec();
+    expected_releases_vec.sort_by(expected_cmp_fn);
+
+    let sort_option = releases_sorting_options()[4].clone();
+    let mut sorted_releases_vec = releases_vec();
+    sorted_releases_vec.sort_by(sort_option.cmp_fn.unwrap());
+
+    assert_eq!(sorted_releases_vec, expected_releases_vec);
+    assert_str_eq!(sort_option.name, "Peers");
+  }
+
+  #[test]
+  fn test_search_releases_handler_accepts() {
+    ActiveProwlarrBlock::iter().for_each(|active_prowlarr_block| {
+      if SEARCH_RELEASES_BLOCKS.contains(&active_prowlarr_block) {
+        assert!(SearchReleasesHandler::accepts(active_prowlarr_block));
+      } else {
+        assert!(!SearchReleasesHandler::accepts(active_prowlarr_block));
+      }
+    })
+  }
+
+  #[test]
+  fn test_search_releases_handler_not_ready_when_loading() {
+    let mut app = App::test_default();
+    app.is_loading = true;
+
+    let handler = SearchReleasesHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveProwlarrBlock::SearchReleases,
+      None,
+    );
+
+    assert!(!handler.is_ready());
+  }
+
+  #[test]
+  fn test_search_releases_handler_ready_when_not_loading() {
+    let mut app = App::test_default();
+
+    let handler = SearchReleasesHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveProwlarrBlock::SearchReleases,
+      None,
+    );
+
+    assert!(handler.is_ready());
+  }
+
+  fn releases_vec() -> Vec<ProwlarrRelease> {
+    vec![
+      ProwlarrRelease {
+        guid: "1".to_owned(),
+        age: 3,
+        title: "Test 1".into(),
+        indexer: "Indexer B".to_owned(),
+        size: 2,
+        seeders: Some(Number::from(3)),
+        ..ProwlarrRelease::default()
+      },
+      ProwlarrRelease {
+        guid: "2".to_owned(),
+        age: 1,
+        title: "test 3".into(),
+        indexer: "indexer a".to_owned(),
+        size: 3,
+        seeders: None,
+        ..ProwlarrRelease::default()
+      },
+      ProwlarrRelease {
+        guid: "3".to_owned(),
+        age: 2,
+        title: "Test 2".into(),
+        indexer: "Indexer C".to_owned(),
+        size: 1,
+        seeders: Some(Number::from(1)),
+        ..ProwlarrRelease::default()
+      },
+    ]
+  }
+}
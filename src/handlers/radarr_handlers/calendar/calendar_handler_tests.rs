@@ -0,0 +1,247 @@
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+  use rstest::rstest;
+  use strum::IntoEnumIterator;
+
+  use crate::app::App;
+  use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+  use crate::assert_navigation_pushed;
+  use crate::handlers::KeyEventHandler;
+  use crate::handlers::radarr_handlers::calendar::CalendarHandler;
+  use crate::models::servarr_data::radarr::radarr_data::{ActiveRadarrBlock, CALENDAR_BLOCKS};
+
+  mod test_handle_left_right_action {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::assert_navigation_pushed;
+
+    #[rstest]
+    fn test_calendar_tab_left(
+      #[values(ActiveRadarrBlock::Calendar, ActiveRadarrBlock::CalendarAgenda)]
+      active_radarr_block: ActiveRadarrBlock,
+      #[values(true, false)] is_ready: bool,
+    ) {
+      let mut app = App::test_default();
+      app.push_navigation_stack(active_radarr_block.into());
+      app.is_loading = is_ready;
+      app.data.radarr_data.main_tabs.set_index(11);
+
+      CalendarHandler::new(
+        DEFAULT_KEYBINDINGS.left.key,
+        &mut app,
+        active_radarr_block,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.data.radarr_data.main_tabs.get_active_route(),
+        ActiveRadarrBlock::System.into()
+      );
+      assert_navigation_pushed!(app, ActiveRadarrBlock::System.into());
+    }
+
+    #[rstest]
+    fn test_calendar_tab_right(
+      #[values(ActiveRadarrBlock::Calendar, ActiveRadarrBlock::CalendarAgenda)]
+      active_radarr_block: ActiveRadarrBlock,
+      #[values(true, false)] is_ready: bool,
+    ) {
+      let mut app = App::test_default();
+      app.push_navigation_stack(active_radarr_block.into());
+      app.is_loading = is_ready;
+      app.data.radarr_data.main_tabs.set_index(11);
+
+      CalendarHandler::new(
+        DEFAULT_KEYBINDINGS.right.key,
+        &mut app,
+        active_radarr_block,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.data.radarr_data.main_tabs.get_active_route(),
+        ActiveRadarrBlock::WantedMissing.into()
+      );
+      assert_navigation_pushed!(app, ActiveRadarrBlock::WantedMissing.into());
+    }
+  }
+
+  mod test_handle_esc {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[rstest]
+    fn test_calendar_esc_clears_errors(
+      #[values(ActiveRadarrBlock::Calendar, ActiveRadarrBlock::CalendarAgenda)]
+      active_radarr_block: ActiveRadarrBlock,
+    ) {
+      let mut app = App::test_default();
+      app.push_navigation_stack(active_radarr_block.into());
+      app.error = "test error".to_owned().into();
+
+      CalendarHandler::new(
+        DEFAULT_KEYBINDINGS.esc.key,
+        &mut app,
+        active_radarr_block,
+        None,
+      )
+      .handle();
+
+      assert_eq!(app.get_current_route(), active_radarr_block.into());
+      assert_is_empty!(app.error.text);
+    }
+  }
+
+  mod test_handle_key_char {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[rstest]
+    #[case(ActiveRadarrBlock::Calendar, ActiveRadarrBlock::CalendarAgenda)]
+    #[case(ActiveRadarrBlock::CalendarAgenda, ActiveRadarrBlock::Calendar)]
+    fn test_toggle_view_key(
+      #[case] active_radarr_block: ActiveRadarrBlock,
+      #[case] expected_radarr_block: ActiveRadarrBlock,
+    ) {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
+      app.push_navigation_stack(active_radarr_block.into());
+
+      CalendarHandler::new(
+        DEFAULT_KEYBINDINGS.toggle_view.key,
+        &mut app,
+        active_radarr_block,
+        None,
+      )
+      .handle();
+
+      assert_navigation_pushed!(app, expected_radarr_block.into());
+      app.pop_navigation_stack();
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::Movies.into());
+    }
+
+    #[test]
+    fn test_toggle_view_key_no_op_when_not_ready() {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.push_navigation_stack(ActiveRadarrBlock::Calendar.into());
+
+      CalendarHandler::new(
+        DEFAULT_KEYBINDINGS.toggle_view.key,
+        &mut app,
+        ActiveRadarrBlock::Calendar,
+        None,
+      )
+      .handle();
+
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::Calendar.into());
+    }
+
+    #[rstest]
+    fn test_refresh_calendar_key(
+      #[values(ActiveRadarrBlock::Calendar, ActiveRadarrBlock::CalendarAgenda)]
+      active_radarr_block: ActiveRadarrBlock,
+    ) {
+      let mut app = App::test_default();
+      app.push_navigation_stack(active_radarr_block.into());
+
+      CalendarHandler::new(
+        DEFAULT_KEYBINDINGS.refresh.key,
+        &mut app,
+        active_radarr_block,
+        None,
+      )
+      .handle();
+
+      assert_eq!(app.get_current_route(), active_radarr_block.into());
+      assert!(app.should_refresh);
+    }
+
+    #[test]
+    fn test_refresh_calendar_key_no_op_when_not_ready() {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.push_navigation_stack(ActiveRadarrBlock::Calendar.into());
+
+      CalendarHandler::new(
+        DEFAULT_KEYBINDINGS.refresh.key,
+        &mut app,
+        ActiveRadarrBlock::Calendar,
+        None,
+      )
+      .handle();
+
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::Calendar.into());
+      assert!(!app.should_refresh);
+    }
+  }
+
+  #[test]
+  fn test_calendar_handler_accepts() {
+    ActiveRadarrBlock::iter().for_each(|active_radarr_block| {
+      if CALENDAR_BLOCKS.contains(&active_radarr_block) {
+        assert!(CalendarHandler::accepts(active_radarr_block));
+      } else {
+        assert!(!CalendarHandler::accepts(active_radarr_block));
+      }
+    })
+  }
+
+  #[rstest]
+  fn test_calendar_handler_ignore_special_keys(
+    #[values(true, false)] ignore_special_keys_for_textbox_input: bool,
+  ) {
+    let mut app = App::test_default();
+    app.ignore_special_keys_for_textbox_input = ignore_special_keys_for_textbox_input;
+    let handler = CalendarHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveRadarrBlock::default(),
+      None,
+    );
+
+    assert_eq!(
+      handler.ignore_special_keys(),
+      ignore_special_keys_for_textbox_input
+    );
+  }
+
+  #[test]
+  fn test_calendar_handler_not_ready_when_loading() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveRadarrBlock::Calendar.into());
+    app.is_loading = true;
+
+    let handler = CalendarHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveRadarrBlock::Calendar,
+      None,
+    );
+
+    assert!(!handler.is_ready());
+  }
+
+  #[test]
+  fn test_calendar_handler_ready_when_not_loading() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveRadarrBlock::Calendar.into());
+    app.is_loading = false;
+
+    let handler = CalendarHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveRadarrBlock::Calendar,
+      None,
+    );
+
+    assert!(handler.is_ready());
+  }
+}
@@ -0,0 +1,194 @@
+use crate::app::App;
+use crate::event::Key;
+use crate::handlers::KeyEventHandler;
+use crate::models::servarr_data::modals::{CustomFormatModal, SchemaFormFieldKind};
+use crate::models::servarr_data::radarr::radarr_data::{
+  ActiveRadarrBlock, CUSTOM_FORMAT_FORM_BLOCKS,
+};
+use crate::models::servarr_models::EditCustomFormatParams;
+use crate::models::{Route, Scrollable};
+use crate::network::radarr_network::RadarrEvent;
+use crate::{handle_text_box_keys, handle_text_box_left_right_keys, matches_key};
+
+#[cfg(test)]
+#[path = "custom_format_form_handler_tests.rs"]
+mod custom_format_form_handler_tests;
+
+pub(super) struct CustomFormatFormHandler<'a, 'b> {
+  key: Key,
+  app: &'a mut App<'b>,
+  active_radarr_block: ActiveRadarrBlock,
+  _context: Option<ActiveRadarrBlock>,
+}
+
+impl CustomFormatFormHandler<'_, '_> {
+  fn custom_format_modal(&mut self) -> &mut CustomFormatModal {
+    self
+      .app
+      .data
+      .radarr_data
+      .custom_format_modal
+      .as_mut()
+      .expect("Custom Format Modal is None")
+  }
+}
+
+impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for CustomFormatFormHandler<'a, 'b> {
+  fn accepts(active_block: ActiveRadarrBlock) -> bool {
+    CUSTOM_FORMAT_FORM_BLOCKS.contains(&active_block)
+  }
+
+  fn ignore_special_keys(&self) -> bool {
+    self.app.ignore_special_keys_for_textbox_input
+  }
+
+  fn new(
+    key: Key,
+    app: &'a mut App<'b>,
+    active_block: ActiveRadarrBlock,
+    _context: Option<ActiveRadarrBlock>,
+  ) -> CustomFormatFormHandler<'a, 'b> {
+    CustomFormatFormHandler {
+      key,
+      app,
+      active_radarr_block: active_block,
+      _context,
+    }
+  }
+
+  fn get_key(&self) -> Key {
+    self.key
+  }
+
+  fn is_ready(&self) -> bool {
+    !self.app.is_loading && self.app.data.radarr_data.custom_format_modal.is_some()
+  }
+
+  fn handle_scroll_up(&mut self) {
+    if self.active_radarr_block == ActiveRadarrBlock::EditCustomFormatPrompt {
+      self.custom_format_modal().fields.scroll_up();
+    }
+  }
+
+  fn handle_scroll_down(&mut self) {
+    if self.active_radarr_block == ActiveRadarrBlock::EditCustomFormatPrompt {
+      self.custom_format_modal().fields.scroll_down();
+    }
+  }
+
+  fn handle_home(&mut self) {
+    match self.active_radarr_block {
+      ActiveRadarrBlock::EditCustomFormatPrompt => {
+        self.custom_format_modal().fields.scroll_to_top()
+      }
+      ActiveRadarrBlock::CustomFormatFieldInput => self
+        .custom_format_modal()
+        .current_field_mut()
+        .value
+        .scroll_home(),
+      _ => (),
+    }
+  }
+
+  fn handle_end(&mut self) {
+    match self.active_radarr_block {
+      ActiveRadarrBlock::EditCustomFormatPrompt => {
+        self.custom_format_modal().fields.scroll_to_bottom()
+      }
+      ActiveRadarrBlock::CustomFormatFieldInput => self
+        .custom_format_modal()
+        .current_field_mut()
+        .value
+        .reset_offset(),
+      _ => (),
+    }
+  }
+
+  fn handle_delete(&mut self) {}
+
+  fn handle_left_right_action(&mut self) {
+    if self.active_radarr_block == ActiveRadarrBlock::CustomFormatFieldInput {
+      handle_text_box_left_right_keys!(
+        self,
+        self.key,
+        self.custom_format_modal().current_field_mut().value
+      );
+    }
+  }
+
+  fn handle_submit(&mut self) {
+    match self.active_radarr_block {
+      ActiveRadarrBlock::EditCustomFormatPrompt => {
+        let custom_format_modal = self.custom_format_modal();
+        match custom_format_modal.current_field_mut().kind {
+          SchemaFormFieldKind::Checkbox | SchemaFormFieldKind::Select(_) => {
+            custom_format_modal.toggle_current_field()
+          }
+          _ => {
+            self
+              .app
+              .push_navigation_stack(ActiveRadarrBlock::CustomFormatFieldInput.into());
+            self.app.ignore_special_keys_for_textbox_input = true;
+          }
+        }
+      }
+      ActiveRadarrBlock::CustomFormatFieldInput => {
+        self.app.pop_navigation_stack();
+        self.app.ignore_special_keys_for_textbox_input = false;
+      }
+      _ => (),
+    }
+  }
+
+  fn handle_esc(&mut self) {
+    match self.active_radarr_block {
+      ActiveRadarrBlock::EditCustomFormatPrompt => {
+        self.app.pop_navigation_stack();
+        self.app.data.radarr_data.prompt_confirm = false;
+        self.app.data.radarr_data.custom_format_modal = None;
+      }
+      ActiveRadarrBlock::CustomFormatFieldInput => {
+        self.app.pop_navigation_stack();
+        self.app.ignore_special_keys_for_textbox_input = false;
+      }
+      _ => self.app.pop_navigation_stack(),
+    }
+  }
+
+  fn handle_char_key_event(&mut self) {
+    match self.active_radarr_block {
+      ActiveRadarrBlock::CustomFormatFieldInput => {
+        handle_text_box_keys!(
+          self,
+          self.key,
+          self.custom_format_modal().current_field_mut().value
+        );
+      }
+      ActiveRadarrBlock::EditCustomFormatPrompt if matches_key!(confirm, self.key) => {
+        let custom_format_modal = self
+          .app
+          .data
+          .radarr_data
+          .custom_format_modal
+          .take()
+          .expect("Custom Format Modal is None");
+        self.app.data.radarr_data.prompt_confirm = true;
+        self.app.data.radarr_data.prompt_confirm_action = Some(RadarrEvent::EditCustomFormat(
+          EditCustomFormatParams::from(&custom_format_modal),
+        ));
+        self.app.should_refresh = true;
+
+        self.app.pop_navigation_stack();
+      }
+      _ => (),
+    }
+  }
+
+  fn app_mut(&mut self) -> &mut App<'b> {
+    self.app
+  }
+
+  fn current_route(&self) -> Route {
+    self.app.get_current_route()
+  }
+}
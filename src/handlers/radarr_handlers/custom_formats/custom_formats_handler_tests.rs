@@ -0,0 +1,953 @@
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+  use rstest::rstest;
+  use strum::IntoEnumIterator;
+
+  use crate::app::App;
+  use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+  use crate::assert_navigation_pushed;
+  use crate::event::Key;
+  use crate::handlers::KeyEventHandler;
+  use crate::handlers::radarr_handlers::custom_formats::CustomFormatsHandler;
+  use crate::models::servarr_data::radarr::radarr_data::{
+    ActiveRadarrBlock, CUSTOM_FORMAT_FORM_BLOCKS, CUSTOM_FORMATS_BLOCKS,
+  };
+  use crate::models::servarr_models::CustomFormat;
+  use crate::network::servarr_test_utils::custom_format;
+  use crate::test_handler_delegation;
+
+  mod test_handle_delete {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    const DELETE_KEY: Key = DEFAULT_KEYBINDINGS.delete.key;
+
+    #[test]
+    fn test_delete_custom_format_prompt() {
+      let mut app = App::test_default();
+      app
+        .data
+        .radarr_data
+        .custom_formats
+        .set_items(vec![CustomFormat::default()]);
+
+      CustomFormatsHandler::new(DELETE_KEY, &mut app, ActiveRadarrBlock::CustomFormats, None)
+        .handle();
+
+      assert_navigation_pushed!(app, ActiveRadarrBlock::DeleteCustomFormatPrompt.into());
+    }
+
+    #[test]
+    fn test_delete_custom_format_prompt_no_op_when_not_ready() {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.push_navigation_stack(ActiveRadarrBlock::CustomFormats.into());
+      app
+        .data
+        .radarr_data
+        .custom_formats
+        .set_items(vec![CustomFormat::default()]);
+
+      CustomFormatsHandler::new(DELETE_KEY, &mut app, ActiveRadarrBlock::CustomFormats, None)
+        .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveRadarrBlock::CustomFormats.into()
+      );
+    }
+  }
+
+  mod test_handle_left_right_action {
+    use std::sync::atomic::Ordering;
+
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_custom_formats_tab_left(#[values(true, false)] is_ready: bool) {
+      let mut app = App::test_default();
+      app.is_loading = is_ready;
+      app.data.radarr_data.main_tabs.set_index(9);
+
+      CustomFormatsHandler::new(
+        DEFAULT_KEYBINDINGS.left.key,
+        &mut app,
+        ActiveRadarrBlock::CustomFormats,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.data.radarr_data.main_tabs.get_active_route(),
+        ActiveRadarrBlock::QualityProfiles.into()
+      );
+      assert_navigation_pushed!(app, ActiveRadarrBlock::QualityProfiles.into());
+    }
+
+    #[rstest]
+    fn test_custom_formats_tab_right(#[values(true, false)] is_ready: bool) {
+      let mut app = App::test_default();
+      app.is_loading = is_ready;
+      app.data.radarr_data.main_tabs.set_index(9);
+
+      CustomFormatsHandler::new(
+        DEFAULT_KEYBINDINGS.right.key,
+        &mut app,
+        ActiveRadarrBlock::CustomFormats,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.data.radarr_data.main_tabs.get_active_route(),
+        ActiveRadarrBlock::System.into()
+      );
+      assert_navigation_pushed!(app, ActiveRadarrBlock::System.into());
+    }
+
+    #[rstest]
+    fn test_left_right_delete_custom_format_prompt_toggle(
+      #[values(DEFAULT_KEYBINDINGS.left.key, DEFAULT_KEYBINDINGS.right.key)] key: Key,
+    ) {
+      let mut app = App::test_default();
+
+      CustomFormatsHandler::new(
+        key,
+        &mut app,
+        ActiveRadarrBlock::DeleteCustomFormatPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(app.data.radarr_data.prompt_confirm);
+
+      CustomFormatsHandler::new(
+        key,
+        &mut app,
+        ActiveRadarrBlock::DeleteCustomFormatPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(!app.data.radarr_data.prompt_confirm);
+    }
+
+    #[rstest]
+    fn test_custom_format_path_prompt_left_right_keys(
+      #[values(
+        ActiveRadarrBlock::ImportCustomFormatPrompt,
+        ActiveRadarrBlock::ExportCustomFormatPrompt
+      )]
+      active_radarr_block: ActiveRadarrBlock,
+    ) {
+      let mut app = App::test_default();
+      app
+        .data
+        .radarr_data
+        .custom_formats
+        .set_items(vec![CustomFormat::default()]);
+      app.data.radarr_data.custom_format_path = Some("test".into());
+
+      CustomFormatsHandler::new(
+        DEFAULT_KEYBINDINGS.left.key,
+        &mut app,
+        active_radarr_block,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app
+          .data
+          .radarr_data
+          .custom_format_path
+          .as_ref()
+          .unwrap()
+          .offset
+          .load(Ordering::SeqCst),
+        1
+      );
+
+      CustomFormatsHandler::new(
+        DEFAULT_KEYBINDINGS.right.key,
+        &mut app,
+        active_radarr_block,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app
+          .data
+          .radarr_data
+          .custom_format_path
+          .as_ref()
+          .unwrap()
+          .offset
+          .load(Ordering::SeqCst),
+        0
+      );
+    }
+  }
+
+  mod test_handle_submit {
+    use super::*;
+    use crate::assert_navigation_popped;
+    use crate::network::radarr_network::RadarrEvent;
+    use pretty_assertions::assert_eq;
+
+    const SUBMIT_KEY: Key = DEFAULT_KEYBINDINGS.submit.key;
+
+    #[test]
+    fn test_custom_format_details_submit() {
+      let mut app = App::test_default();
+      app
+        .data
+        .radarr_data
+        .custom_formats
+        .set_items(vec![custom_format()]);
+
+      CustomFormatsHandler::new(SUBMIT_KEY, &mut app, ActiveRadarrBlock::CustomFormats, None)
+        .handle();
+
+      assert_navigation_pushed!(app, ActiveRadarrBlock::CustomFormatDetails.into());
+    }
+
+    #[test]
+    fn test_custom_format_details_submit_no_op_when_not_ready() {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.push_navigation_stack(ActiveRadarrBlock::CustomFormats.into());
+      app
+        .data
+        .radarr_data
+        .custom_formats
+        .set_items(vec![custom_format()]);
+
+      CustomFormatsHandler::new(SUBMIT_KEY, &mut app, ActiveRadarrBlock::CustomFormats, None)
+        .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveRadarrBlock::CustomFormats.into()
+      );
+    }
+
+    #[test]
+    fn test_delete_custom_format_prompt_confirm_submit() {
+      let mut app = App::test_default();
+      app
+        .data
+        .radarr_data
+        .custom_formats
+        .set_items(vec![custom_format()]);
+      app.data.radarr_data.prompt_confirm = true;
+      app.push_navigation_stack(ActiveRadarrBlock::CustomFormats.into());
+      app.push_navigation_stack(ActiveRadarrBlock::DeleteCustomFormatPrompt.into());
+
+      CustomFormatsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveRadarrBlock::DeleteCustomFormatPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(app.data.radarr_data.prompt_confirm);
+      assert_some_eq_x!(
+        &app.data.radarr_data.prompt_confirm_action,
+        &RadarrEvent::DeleteCustomFormat(1)
+      );
+      assert_navigation_popped!(app, ActiveRadarrBlock::CustomFormats.into());
+    }
+
+    #[test]
+    fn test_prompt_decline_submit() {
+      let mut app = App::test_default();
+      app
+        .data
+        .radarr_data
+        .custom_formats
+        .set_items(vec![CustomFormat::default()]);
+      app.push_navigation_stack(ActiveRadarrBlock::CustomFormats.into());
+      app.push_navigation_stack(ActiveRadarrBlock::DeleteCustomFormatPrompt.into());
+
+      CustomFormatsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveRadarrBlock::DeleteCustomFormatPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(!app.data.radarr_data.prompt_confirm);
+      assert_none!(app.data.radarr_data.prompt_confirm_action);
+      assert_navigation_popped!(app, ActiveRadarrBlock::CustomFormats.into());
+    }
+
+    #[test]
+    fn test_import_custom_format_prompt_submit() {
+      let file = std::env::temp_dir().join("managarr_test_radarr_import_custom_format.json");
+      std::fs::write(&file, "{\"name\": \"x265\"}").unwrap();
+      let mut app = App::test_default();
+      app.ignore_special_keys_for_textbox_input = true;
+      app.data.radarr_data.custom_format_path = Some(file.to_string_lossy().to_string().into());
+      app.push_navigation_stack(ActiveRadarrBlock::CustomFormats.into());
+      app.push_navigation_stack(ActiveRadarrBlock::ImportCustomFormatPrompt.into());
+
+      CustomFormatsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveRadarrBlock::ImportCustomFormatPrompt,
+        None,
+      )
+      .handle();
+      std::fs::remove_file(&file).unwrap();
+
+      assert!(app.data.radarr_data.prompt_confirm);
+      assert_some_eq_x!(
+        &app.data.radarr_data.prompt_confirm_action,
+        &RadarrEvent::ImportCustomFormat("{\"name\": \"x265\"}".to_owned())
+      );
+      assert!(!app.ignore_special_keys_for_textbox_input);
+      assert_none!(app.data.radarr_data.custom_format_path);
+      assert_none!(app.notification);
+      assert_navigation_popped!(app, ActiveRadarrBlock::CustomFormats.into());
+    }
+
+    #[test]
+    fn test_import_custom_format_prompt_submit_missing_file() {
+      let file = std::env::temp_dir().join("managarr_test_radarr_missing_custom_format.json");
+      let mut app = App::test_default();
+      app.ignore_special_keys_for_textbox_input = true;
+      app.data.radarr_data.custom_format_path = Some(file.to_string_lossy().to_string().into());
+      app.push_navigation_stack(ActiveRadarrBlock::CustomFormats.into());
+      app.push_navigation_stack(ActiveRadarrBlock::ImportCustomFormatPrompt.into());
+
+      CustomFormatsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveRadarrBlock::ImportCustomFormatPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(!app.data.radarr_data.prompt_confirm);
+      assert_none!(app.data.radarr_data.prompt_confirm_action);
+      let notification = app.notification.as_ref().unwrap();
+      assert_eq!(notification.title, "Import Failed");
+      assert!(!notification.success);
+      assert_navigation_popped!(app, ActiveRadarrBlock::CustomFormats.into());
+    }
+
+    #[rstest]
+    fn test_custom_format_path_prompt_submit_no_op_when_path_is_empty(
+      #[values(
+        ActiveRadarrBlock::ImportCustomFormatPrompt,
+        ActiveRadarrBlock::ExportCustomFormatPrompt
+      )]
+      active_radarr_block: ActiveRadarrBlock,
+    ) {
+      let mut app = App::test_default();
+      app
+        .data
+        .radarr_data
+        .custom_formats
+        .set_items(vec![custom_format()]);
+      app.ignore_special_keys_for_textbox_input = true;
+      app.data.radarr_data.custom_format_path = Some("  ".into());
+      app.push_navigation_stack(ActiveRadarrBlock::CustomFormats.into());
+      app.push_navigation_stack(active_radarr_block.into());
+
+      CustomFormatsHandler::new(SUBMIT_KEY, &mut app, active_radarr_block, None).handle();
+
+      assert!(app.ignore_special_keys_for_textbox_input);
+      assert_some!(&app.data.radarr_data.custom_format_path);
+      assert_eq!(app.get_current_route(), active_radarr_block.into());
+    }
+
+    #[test]
+    fn test_export_custom_format_prompt_submit() {
+      let file = std::env::temp_dir().join("managarr_test_radarr_export_custom_format.json");
+      let mut app = App::test_default();
+      app
+        .data
+        .radarr_data
+        .custom_formats
+        .set_items(vec![custom_format()]);
+      app.ignore_special_keys_for_textbox_input = true;
+      app.data.radarr_data.custom_format_path = Some(file.to_string_lossy().to_string().into());
+      app.push_navigation_stack(ActiveRadarrBlock::CustomFormats.into());
+      app.push_navigation_stack(ActiveRadarrBlock::ExportCustomFormatPrompt.into());
+
+      CustomFormatsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveRadarrBlock::ExportCustomFormatPrompt,
+        None,
+      )
+      .handle();
+      let exported_json: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&file).unwrap()).unwrap();
+      std::fs::remove_file(&file).unwrap();
+
+      assert_eq!(exported_json, custom_format().to_trash_json());
+      let notification = app.notification.as_ref().unwrap();
+      assert_eq!(notification.title, "Export Result");
+      assert!(notification.success);
+      assert!(!app.ignore_special_keys_for_textbox_input);
+      assert_none!(app.data.radarr_data.custom_format_path);
+      assert_none!(app.data.radarr_data.prompt_confirm_action);
+      assert_navigation_popped!(app, ActiveRadarrBlock::CustomFormats.into());
+    }
+
+    #[test]
+    fn test_export_custom_format_prompt_submit_write_failure() {
+      let mut app = App::test_default();
+      app
+        .data
+        .radarr_data
+        .custom_formats
+        .set_items(vec![custom_format()]);
+      app.data.radarr_data.custom_format_path =
+        Some(std::env::temp_dir().to_string_lossy().to_string().into());
+      app.push_navigation_stack(ActiveRadarrBlock::CustomFormats.into());
+      app.push_navigation_stack(ActiveRadarrBlock::ExportCustomFormatPrompt.into());
+
+      CustomFormatsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveRadarrBlock::ExportCustomFormatPrompt,
+        None,
+      )
+      .handle();
+
+      let notification = app.notification.as_ref().unwrap();
+      assert_eq!(notification.title, "Export Failed");
+      assert!(!notification.success);
+      assert_navigation_popped!(app, ActiveRadarrBlock::CustomFormats.into());
+    }
+  }
+
+  mod test_handle_esc {
+    use super::*;
+    use crate::assert_navigation_popped;
+
+    const ESC_KEY: Key = DEFAULT_KEYBINDINGS.esc.key;
+
+    #[rstest]
+    fn test_delete_custom_format_prompt_block_esc(#[values(true, false)] is_ready: bool) {
+      let mut app = App::test_default();
+      app.is_loading = is_ready;
+      app.push_navigation_stack(ActiveRadarrBlock::CustomFormats.into());
+      app.push_navigation_stack(ActiveRadarrBlock::DeleteCustomFormatPrompt.into());
+      app.data.radarr_data.prompt_confirm = true;
+
+      CustomFormatsHandler::new(
+        ESC_KEY,
+        &mut app,
+        ActiveRadarrBlock::DeleteCustomFormatPrompt,
+        None,
+      )
+      .handle();
+
+      assert_navigation_popped!(app, ActiveRadarrBlock::CustomFormats.into());
+      assert!(!app.data.radarr_data.prompt_confirm);
+    }
+
+    #[rstest]
+    fn test_custom_format_details_esc(#[values(true, false)] is_ready: bool) {
+      let mut app = App::test_default();
+      app.is_loading = is_ready;
+      app.push_navigation_stack(ActiveRadarrBlock::CustomFormats.into());
+      app.push_navigation_stack(ActiveRadarrBlock::CustomFormatDetails.into());
+
+      CustomFormatsHandler::new(
+        ESC_KEY,
+        &mut app,
+        ActiveRadarrBlock::CustomFormatDetails,
+        None,
+      )
+      .handle();
+
+      assert_navigation_popped!(app, ActiveRadarrBlock::CustomFormats.into());
+    }
+
+    #[rstest]
+    fn test_custom_format_path_prompt_esc(
+      #[values(
+        ActiveRadarrBlock::ImportCustomFormatPrompt,
+        ActiveRadarrBlock::ExportCustomFormatPrompt
+      )]
+      active_radarr_block: ActiveRadarrBlock,
+    ) {
+      let mut app = App::test_default();
+      app.ignore_special_keys_for_textbox_input = true;
+      app.data.radarr_data.custom_format_path = Some("test".into());
+      app.push_navigation_stack(ActiveRadarrBlock::CustomFormats.into());
+      app.push_navigation_stack(active_radarr_block.into());
+
+      CustomFormatsHandler::new(ESC_KEY, &mut app, active_radarr_block, None).handle();
+
+      assert_navigation_popped!(app, ActiveRadarrBlock::CustomFormats.into());
+      assert!(!app.ignore_special_keys_for_textbox_input);
+      assert_none!(app.data.radarr_data.custom_format_path);
+    }
+
+    #[rstest]
+    fn test_default_esc(#[values(true, false)] is_ready: bool) {
+      let mut app = App::test_default();
+      app.is_loading = is_ready;
+      app.error = "test error".to_owned().into();
+      app.push_navigation_stack(ActiveRadarrBlock::CustomFormats.into());
+      app.push_navigation_stack(ActiveRadarrBlock::CustomFormats.into());
+
+      CustomFormatsHandler::new(ESC_KEY, &mut app, ActiveRadarrBlock::CustomFormats, None).handle();
+
+      assert_navigation_popped!(app, ActiveRadarrBlock::CustomFormats.into());
+      assert_is_empty!(app.error.text);
+    }
+  }
+
+  mod test_handle_key_char {
+    use pretty_assertions::{assert_eq, assert_str_eq};
+
+    use super::*;
+    use crate::models::HorizontallyScrollableText;
+    use crate::models::servarr_data::modals::CustomFormatModal;
+    use crate::{assert_navigation_popped, network::radarr_network::RadarrEvent};
+
+    #[test]
+    fn test_refresh_custom_formats_key() {
+      let mut app = App::test_default();
+      app
+        .data
+        .radarr_data
+        .custom_formats
+        .set_items(vec![CustomFormat::default()]);
+      app.push_navigation_stack(ActiveRadarrBlock::CustomFormats.into());
+
+      CustomFormatsHandler::new(
+        DEFAULT_KEYBINDINGS.refresh.key,
+        &mut app,
+        ActiveRadarrBlock::CustomFormats,
+        None,
+      )
+      .handle();
+
+      assert_navigation_pushed!(app, ActiveRadarrBlock::CustomFormats.into());
+      assert!(app.should_refresh);
+    }
+
+    #[test]
+    fn test_refresh_custom_formats_key_no_op_when_not_ready() {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app
+        .data
+        .radarr_data
+        .custom_formats
+        .set_items(vec![CustomFormat::default()]);
+      app.push_navigation_stack(ActiveRadarrBlock::CustomFormats.into());
+
+      CustomFormatsHandler::new(
+        DEFAULT_KEYBINDINGS.refresh.key,
+        &mut app,
+        ActiveRadarrBlock::CustomFormats,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveRadarrBlock::CustomFormats.into()
+      );
+      assert!(!app.should_refresh);
+    }
+
+    #[test]
+    fn test_edit_custom_format_key() {
+      let mut app = App::test_default();
+      app
+        .data
+        .radarr_data
+        .custom_formats
+        .set_items(vec![custom_format()]);
+      app.push_navigation_stack(ActiveRadarrBlock::CustomFormats.into());
+
+      CustomFormatsHandler::new(
+        DEFAULT_KEYBINDINGS.edit.key,
+        &mut app,
+        ActiveRadarrBlock::CustomFormats,
+        None,
+      )
+      .handle();
+
+      assert_navigation_pushed!(app, ActiveRadarrBlock::EditCustomFormatPrompt.into());
+      let custom_format_modal = app.data.radarr_data.custom_format_modal.as_ref().unwrap();
+      let expected_custom_format_modal = CustomFormatModal::from(&custom_format());
+      assert_eq!(custom_format_modal.id, 1);
+      assert_eq!(
+        custom_format_modal.fields.items,
+        expected_custom_format_modal.fields.items
+      );
+    }
+
+    #[test]
+    fn test_edit_custom_format_key_no_op_when_not_ready() {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app
+        .data
+        .radarr_data
+        .custom_formats
+        .set_items(vec![custom_format()]);
+      app.push_navigation_stack(ActiveRadarrBlock::CustomFormats.into());
+
+      CustomFormatsHandler::new(
+        DEFAULT_KEYBINDINGS.edit.key,
+        &mut app,
+        ActiveRadarrBlock::CustomFormats,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveRadarrBlock::CustomFormats.into()
+      );
+      assert_none!(app.data.radarr_data.custom_format_modal);
+    }
+
+    #[test]
+    fn test_import_custom_format_key() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveRadarrBlock::CustomFormats.into());
+
+      CustomFormatsHandler::new(
+        DEFAULT_KEYBINDINGS.import.key,
+        &mut app,
+        ActiveRadarrBlock::CustomFormats,
+        None,
+      )
+      .handle();
+
+      assert_navigation_pushed!(app, ActiveRadarrBlock::ImportCustomFormatPrompt.into());
+      assert!(app.ignore_special_keys_for_textbox_input);
+      assert_some_eq_x!(
+        &app.data.radarr_data.custom_format_path,
+        &HorizontallyScrollableText::default()
+      );
+    }
+
+    #[test]
+    fn test_import_custom_format_key_no_op_when_loading() {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.push_navigation_stack(ActiveRadarrBlock::CustomFormats.into());
+
+      CustomFormatsHandler::new(
+        DEFAULT_KEYBINDINGS.import.key,
+        &mut app,
+        ActiveRadarrBlock::CustomFormats,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveRadarrBlock::CustomFormats.into()
+      );
+      assert_none!(app.data.radarr_data.custom_format_path);
+    }
+
+    #[test]
+    fn test_export_custom_format_key() {
+      let mut app = App::test_default();
+      app
+        .data
+        .radarr_data
+        .custom_formats
+        .set_items(vec![CustomFormat {
+          name: "HDR10+/DV".to_owned(),
+          ..custom_format()
+        }]);
+      app.push_navigation_stack(ActiveRadarrBlock::CustomFormats.into());
+
+      CustomFormatsHandler::new(
+        DEFAULT_KEYBINDINGS.export.key,
+        &mut app,
+        ActiveRadarrBlock::CustomFormats,
+        None,
+      )
+      .handle();
+
+      assert_navigation_pushed!(app, ActiveRadarrBlock::ExportCustomFormatPrompt.into());
+      assert!(app.ignore_special_keys_for_textbox_input);
+      assert_str_eq!(
+        app
+          .data
+          .radarr_data
+          .custom_format_path
+          .as_ref()
+          .unwrap()
+          .text,
+        "HDR10+_DV.json"
+      );
+    }
+
+    #[test]
+    fn test_export_custom_format_key_no_op_when_custom_formats_is_empty() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveRadarrBlock::CustomFormats.into());
+
+      CustomFormatsHandler::new(
+        DEFAULT_KEYBINDINGS.export.key,
+        &mut app,
+        ActiveRadarrBlock::CustomFormats,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveRadarrBlock::CustomFormats.into()
+      );
+      assert_none!(app.data.radarr_data.custom_format_path);
+    }
+
+    #[rstest]
+    fn test_custom_format_path_prompt_backspace_key(
+      #[values(
+        ActiveRadarrBlock::ImportCustomFormatPrompt,
+        ActiveRadarrBlock::ExportCustomFormatPrompt
+      )]
+      active_radarr_block: ActiveRadarrBlock,
+    ) {
+      let mut app = App::test_default();
+      app
+        .data
+        .radarr_data
+        .custom_formats
+        .set_items(vec![CustomFormat::default()]);
+      app.data.radarr_data.custom_format_path = Some("test".into());
+
+      CustomFormatsHandler::new(
+        DEFAULT_KEYBINDINGS.backspace.key,
+        &mut app,
+        active_radarr_block,
+        None,
+      )
+      .handle();
+
+      assert_str_eq!(
+        app
+          .data
+          .radarr_data
+          .custom_format_path
+          .as_ref()
+          .unwrap()
+          .text,
+        "tes"
+      );
+    }
+
+    #[rstest]
+    fn test_custom_format_path_prompt_char_key(
+      #[values(
+        ActiveRadarrBlock::ImportCustomFormatPrompt,
+        ActiveRadarrBlock::ExportCustomFormatPrompt
+      )]
+      active_radarr_block: ActiveRadarrBlock,
+    ) {
+      let mut app = App::test_default();
+      app
+        .data
+        .radarr_data
+        .custom_formats
+        .set_items(vec![CustomFormat::default()]);
+      app.data.radarr_data.custom_format_path = Some(HorizontallyScrollableText::default());
+
+      CustomFormatsHandler::new(Key::Char('a'), &mut app, active_radarr_block, None).handle();
+
+      assert_str_eq!(
+        app
+          .data
+          .radarr_data
+          .custom_format_path
+          .as_ref()
+          .unwrap()
+          .text,
+        "a"
+      );
+    }
+
+    #[test]
+    fn test_delete_custom_format_prompt_confirm() {
+      let mut app = App::test_default();
+      app
+        .data
+        .radarr_data
+        .custom_formats
+        .set_items(vec![custom_format()]);
+      app.push_navigation_stack(ActiveRadarrBlock::CustomFormats.into());
+      app.push_navigation_stack(ActiveRadarrBlock::DeleteCustomFormatPrompt.into());
+
+      CustomFormatsHandler::new(
+        DEFAULT_KEYBINDINGS.confirm.key,
+        &mut app,
+        ActiveRadarrBlock::DeleteCustomFormatPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(app.data.radarr_data.prompt_confirm);
+      assert_some_eq_x!(
+        &app.data.radarr_data.prompt_confirm_action,
+        &RadarrEvent::DeleteCustomFormat(1)
+      );
+      assert_navigation_popped!(app, ActiveRadarrBlock::CustomFormats.into());
+    }
+  }
+
+  #[rstest]
+  fn test_delegates_custom_format_form_blocks_to_custom_format_form_handler(
+    #[values(
+      ActiveRadarrBlock::EditCustomFormatPrompt,
+      ActiveRadarrBlock::CustomFormatFieldInput
+    )]
+    active_radarr_block: ActiveRadarrBlock,
+  ) {
+    test_handler_delegation!(
+      CustomFormatsHandler,
+      ActiveRadarrBlock::CustomFormats,
+      active_radarr_block
+    );
+  }
+
+  #[test]
+  fn test_custom_formats_handler_accepts() {
+    let mut custom_formats_blocks = Vec::new();
+    custom_formats_blocks.extend(CUSTOM_FORMATS_BLOCKS);
+    custom_formats_blocks.extend(CUSTOM_FORMAT_FORM_BLOCKS);
+
+    ActiveRadarrBlock::iter().for_each(|active_radarr_block| {
+      if custom_formats_blocks.contains(&active_radarr_block) {
+        assert!(CustomFormatsHandler::accepts(active_radarr_block));
+      } else {
+        assert!(!CustomFormatsHandler::accepts(active_radarr_block));
+      }
+    })
+  }
+
+  #[rstest]
+  fn test_custom_formats_handler_ignore_special_keys(
+    #[values(true, false)] ignore_special_keys_for_textbox_input: bool,
+  ) {
+    let mut app = App::test_default();
+    app.ignore_special_keys_for_textbox_input = ignore_special_keys_for_textbox_input;
+    let handler = CustomFormatsHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveRadarrBlock::default(),
+      None,
+    );
+
+    assert_eq!(
+      handler.ignore_special_keys(),
+      ignore_special_keys_for_textbox_input
+    );
+  }
+
+  #[test]
+  fn test_extract_custom_format_id() {
+    let mut app = App::test_default();
+    app
+      .data
+      .radarr_data
+      .custom_formats
+      .set_items(vec![custom_format()]);
+
+    let custom_format_id = CustomFormatsHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveRadarrBlock::CustomFormats,
+      None,
+    )
+    .extract_custom_format_id();
+
+    assert_eq!(custom_format_id, 1);
+  }
+
+  #[test]
+  fn test_custom_formats_handler_not_ready_when_loading() {
+    let mut app = App::test_default();
+    app.is_loading = true;
+
+    let handler = CustomFormatsHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveRadarrBlock::CustomFormats,
+      None,
+    );
+
+    assert!(!handler.is_ready());
+  }
+
+  #[test]
+  fn test_custom_formats_handler_not_ready_when_custom_formats_is_empty() {
+    let mut app = App::test_default();
+    app.is_loading = false;
+
+    let handler = CustomFormatsHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveRadarrBlock::CustomFormats,
+      None,
+    );
+
+    assert!(!handler.is_ready());
+  }
+
+  #[rstest]
+  fn test_custom_formats_handler_ready_for_import_when_custom_formats_is_empty(
+    #[values(
+      (ActiveRadarrBlock::CustomFormats, DEFAULT_KEYBINDINGS.import.key),
+      (ActiveRadarrBlock::ImportCustomFormatPrompt, DEFAULT_KEYBINDINGS.submit.key)
+    )]
+    block_and_key: (ActiveRadarrBlock, Key),
+  ) {
+    let (active_radarr_block, key) = block_and_key;
+    let mut app = App::test_default();
+    app.is_loading = false;
+
+    let handler = CustomFormatsHandler::new(key, &mut app, active_radarr_block, None);
+
+    assert!(handler.is_ready());
+  }
+
+  #[test]
+  fn test_custom_formats_handler_ready_when_not_loading_and_custom_formats_is_not_empty() {
+    let mut app = App::test_default();
+    app.is_loading = false;
+    app
+      .data
+      .radarr_data
+      .custom_formats
+      .set_items(vec![CustomFormat::default()]);
+
+    let handler = CustomFormatsHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveRadarrBlock::CustomFormats,
+      None,
+    );
+
+    assert!(handler.is_ready());
+  }
+}
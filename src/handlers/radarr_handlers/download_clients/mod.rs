@@ -0,0 +1,207 @@
+use crate::app::App;
+use crate::event::Key;
+use crate::handlers::radarr_handlers::download_clients::download_client_form_handler::DownloadClientFormHandler;
+use crate::handlers::radarr_handlers::download_clients::test_all_download_clients_handler::TestAllDownloadClientsHandler;
+use crate::handlers::radarr_handlers::handle_change_tab_left_right_keys;
+use crate::handlers::table_handler::{TableHandlingConfig, handle_table};
+use crate::handlers::{KeyEventHandler, handle_clear_errors, handle_prompt_toggle};
+use crate::matches_key;
+use crate::models::Route;
+use crate::models::servarr_data::radarr::radarr_data::{
+  ActiveRadarrBlock, DOWNLOAD_CLIENTS_BLOCKS,
+};
+use crate::network::radarr_network::RadarrEvent;
+
+mod download_client_form_handler;
+mod test_all_download_clients_handler;
+
+#[cfg(test)]
+#[path = "download_clients_handler_tests.rs"]
+mod download_clients_handler_tests;
+
+pub(super) struct DownloadClientsHandler<'a, 'b> {
+  key: Key,
+  app: &'a mut App<'b>,
+  active_radarr_block: ActiveRadarrBlock,
+  context: Option<ActiveRadarrBlock>,
+}
+
+impl DownloadClientsHandler<'_, '_> {
+  fn extract_download_client_id(&self) -> i64 {
+    self
+      .app
+      .data
+      .radarr_data
+      .download_clients
+      .current_selection()
+      .id
+  }
+}
+
+impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for DownloadClientsHandler<'a, 'b> {
+  fn handle(&mut self) {
+    let download_clients_table_handling_config =
+      TableHandlingConfig::new(ActiveRadarrBlock::DownloadClients.into());
+
+    if !handle_table(
+      self,
+      |app| &mut app.data.radarr_data.download_clients,
+      download_clients_table_handling_config,
+    ) {
+      match self.active_radarr_block {
+        _ if DownloadClientFormHandler::accepts(self.active_radarr_block) => {
+          DownloadClientFormHandler::new(self.key, self.app, self.active_radarr_block, self.context)
+            .handle()
+        }
+        _ if TestAllDownloadClientsHandler::accepts(self.active_radarr_block) => {
+          TestAllDownloadClientsHandler::new(
+            self.key,
+            self.app,
+            self.active_radarr_block,
+            self.context,
+          )
+          .handle()
+        }
+        _ => self.handle_key_event(),
+      }
+    }
+  }
+
+  fn accepts(active_block: ActiveRadarrBlock) -> bool {
+    DownloadClientFormHandler::accepts(active_block)
+      || TestAllDownloadClientsHandler::accepts(active_block)
+      || DOWNLOAD_CLIENTS_BLOCKS.contains(&active_block)
+  }
+
+  fn ignore_special_keys(&self) -> bool {
+    self.app.ignore_special_keys_for_textbox_input
+  }
+
+  fn new(
+    key: Key,
+    app: &'a mut App<'b>,
+    active_block: ActiveRadarrBlock,
+    context: Option<ActiveRadarrBlock>,
+  ) -> DownloadClientsHandler<'a, 'b> {
+    DownloadClientsHandler {
+      key,
+      app,
+      active_radarr_block: active_block,
+      context,
+    }
+  }
+
+  fn get_key(&self) -> Key {
+    self.key
+  }
+
+  fn is_ready(&self) -> bool {
+    !self.app.is_loading && !self.app.data.radarr_data.download_clients.is_empty()
+  }
+
+  fn handle_scroll_up(&mut self) {}
+
+  fn handle_scroll_down(&mut self) {}
+
+  fn handle_home(&mut self) {}
+
+  fn handle_end(&mut self) {}
+
+  fn handle_delete(&mut self) {
+    if self.active_radarr_block == ActiveRadarrBlock::DownloadClients {
+      self
+        .app
+        .push_navigation_stack(ActiveRadarrBlock::DeleteDownloadClientPrompt.into());
+    }
+  }
+
+  fn handle_left_right_action(&mut self) {
+    match self.active_radarr_block {
+      ActiveRadarrBlock::DownloadClients => handle_change_tab_left_right_keys(self.app, self.key),
+      ActiveRadarrBlock::DeleteDownloadClientPrompt => handle_prompt_toggle(self.app, self.key),
+      _ => (),
+    }
+  }
+
+  fn handle_submit(&mut self) {
+    match self.active_radarr_block {
+      ActiveRadarrBlock::DeleteDownloadClientPrompt => {
+        let download_client_id = self.extract_download_client_id();
+        let radarr_data = &mut self.app.data.radarr_data;
+        if radarr_data.prompt_confirm {
+          radarr_data.prompt_confirm_action =
+            Some(RadarrEvent::DeleteDownloadClient(download_client_id));
+        }
+
+        self.app.pop_navigation_stack();
+      }
+      ActiveRadarrBlock::DownloadClients => {
+        self.app.data.radarr_data.download_client_modal = Some((&self.app.data.radarr_data).into());
+        self
+          .app
+          .push_navigation_stack(ActiveRadarrBlock::DownloadClientPrompt.into());
+      }
+      _ => (),
+    }
+  }
+
+  fn handle_esc(&mut self) {
+    match self.active_radarr_block {
+      ActiveRadarrBlock::DeleteDownloadClientPrompt => {
+        self.app.pop_navigation_stack();
+        self.app.data.radarr_data.prompt_confirm = false;
+      }
+      ActiveRadarrBlock::TestDownloadClient => {
+        self.app.pop_navigation_stack();
+        self.app.data.radarr_data.download_client_test_errors = None;
+      }
+      _ => handle_clear_errors(self.app),
+    }
+  }
+
+  fn handle_char_key_event(&mut self) {
+    let key = self.key;
+    match self.active_radarr_block {
+      ActiveRadarrBlock::DownloadClients => match self.key {
+        _ if matches_key!(refresh, key) => {
+          self.app.should_refresh = true;
+        }
+        _ if matches_key!(add, key) => {
+          self
+            .app
+            .push_navigation_stack(ActiveRadarrBlock::AddDownloadClientSelectImplementation.into());
+        }
+        _ if matches_key!(test, key) => {
+          self
+            .app
+            .push_navigation_stack(ActiveRadarrBlock::TestDownloadClient.into());
+        }
+        _ if matches_key!(test_all, key) => {
+          self
+            .app
+            .push_navigation_stack(ActiveRadarrBlock::TestAllDownloadClients.into());
+        }
+        _ => (),
+      },
+      ActiveRadarrBlock::DeleteDownloadClientPrompt => {
+        if matches_key!(confirm, key) {
+          self.app.data.radarr_data.prompt_confirm = true;
+          self.app.data.radarr_data.prompt_confirm_action = Some(
+            RadarrEvent::DeleteDownloadClient(self.extract_download_client_id()),
+          );
+
+          self.app.pop_navigation_stack();
+        }
+      }
+      _ => (),
+    }
+  }
+
+  fn app_mut(&mut self) -> &mut App<'b> {
+    self.app
+  }
+
+  fn current_route(&self) -> Route {
+    self.app.get_current_route()
+  }
+}
@@ -0,0 +1,134 @@
+#[cfg(test)]
+mod tests {
+  use crate::app::App;
+  use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+  use crate::handlers::KeyEventHandler;
+  use crate::handlers::radarr_handlers::download_clients::test_all_download_clients_handler::TestAllDownloadClientsHandler;
+  use crate::models::servarr_data::modals::IndexerTestResultModalItem;
+  use crate::models::servarr_data::radarr::radarr_data::ActiveRadarrBlock;
+  use crate::models::stateful_table::StatefulTable;
+  use rstest::rstest;
+  use strum::IntoEnumIterator;
+
+  mod test_handle_esc {
+    use super::*;
+    use crate::models::stateful_table::StatefulTable;
+    use crate::{assert_modal_absent, assert_navigation_popped};
+    use rstest::rstest;
+
+    #[rstest]
+    fn test_test_all_download_clients_esc(#[values(true, false)] is_ready: bool) {
+      let mut app = App::test_default();
+      app.is_loading = is_ready;
+      app.push_navigation_stack(ActiveRadarrBlock::DownloadClients.into());
+      app.push_navigation_stack(ActiveRadarrBlock::TestAllDownloadClients.into());
+      app.data.radarr_data.download_client_test_all_results = Some(StatefulTable::default());
+
+      TestAllDownloadClientsHandler::new(
+        DEFAULT_KEYBINDINGS.esc.key,
+        &mut app,
+        ActiveRadarrBlock::TestAllDownloadClients,
+        None,
+      )
+      .handle();
+
+      assert_navigation_popped!(app, ActiveRadarrBlock::DownloadClients.into());
+      assert!(!app.data.radarr_data.prompt_confirm);
+      assert_modal_absent!(app.data.radarr_data.download_client_test_all_results);
+    }
+  }
+
+  #[test]
+  fn test_test_all_download_clients_handler_accepts() {
+    ActiveRadarrBlock::iter().for_each(|active_radarr_block| {
+      if active_radarr_block == ActiveRadarrBlock::TestAllDownloadClients {
+        assert!(TestAllDownloadClientsHandler::accepts(active_radarr_block));
+      } else {
+        assert!(!TestAllDownloadClientsHandler::accepts(active_radarr_block));
+      }
+    });
+  }
+
+  #[rstest]
+  fn test_test_all_download_clients_handler_ignore_special_keys(
+    #[values(true, false)] ignore_special_keys_for_textbox_input: bool,
+  ) {
+    let mut app = App::test_default();
+    app.ignore_special_keys_for_textbox_input = ignore_special_keys_for_textbox_input;
+    let handler = TestAllDownloadClientsHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveRadarrBlock::default(),
+      None,
+    );
+
+    assert_eq!(
+      handler.ignore_special_keys(),
+      ignore_special_keys_for_textbox_input
+    );
+  }
+
+  #[test]
+  fn test_test_all_download_clients_handler_is_not_ready_when_loading() {
+    let mut app = App::test_default();
+    app.is_loading = true;
+
+    let handler = TestAllDownloadClientsHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveRadarrBlock::TestAllDownloadClients,
+      None,
+    );
+
+    assert!(!handler.is_ready());
+  }
+
+  #[test]
+  fn test_test_all_download_clients_handler_is_not_ready_when_results_is_none() {
+    let mut app = App::test_default();
+    app.is_loading = false;
+
+    let handler = TestAllDownloadClientsHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveRadarrBlock::TestAllDownloadClients,
+      None,
+    );
+
+    assert!(!handler.is_ready());
+  }
+
+  #[test]
+  fn test_test_all_download_clients_handler_is_not_ready_when_results_is_empty() {
+    let mut app = App::test_default();
+    app.is_loading = false;
+    app.data.radarr_data.download_client_test_all_results = Some(StatefulTable::default());
+
+    let handler = TestAllDownloadClientsHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveRadarrBlock::TestAllDownloadClients,
+      None,
+    );
+
+    assert!(!handler.is_ready());
+  }
+
+  #[test]
+  fn test_test_all_download_clients_handler_is_ready_when_results_is_not_empty_and_is_loaded() {
+    let mut app = App::test_default();
+    app.is_loading = false;
+    let mut download_client_test_results = StatefulTable::default();
+    download_client_test_results.set_items(vec![IndexerTestResultModalItem::default()]);
+    app.data.radarr_data.download_client_test_all_results = Some(download_client_test_results);
+
+    let handler = TestAllDownloadClientsHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveRadarrBlock::TestAllDownloadClients,
+      None,
+    );
+
+    assert!(handler.is_ready());
+  }
+}
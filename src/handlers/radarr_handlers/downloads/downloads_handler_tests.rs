@@ -11,8 +11,94 @@ mod tests {
   use crate::handlers::KeyEventHandler;
   use crate::handlers::radarr_handlers::downloads::DownloadsHandler;
   use crate::handlers::radarr_handlers::radarr_handler_test_utils::utils::download_record;
+  use crate::models::BlockSelectionState;
   use crate::models::radarr_models::DownloadRecord;
-  use crate::models::servarr_data::radarr::radarr_data::{ActiveRadarrBlock, DOWNLOADS_BLOCKS};
+  use crate::models::servarr_data::radarr::modals::ManualImportModal;
+  use crate::models::servarr_data::radarr::radarr_data::{
+    ActiveRadarrBlock, DELETE_DOWNLOAD_SELECTION_BLOCKS, DOWNLOADS_BLOCKS,
+  };
+  use crate::models::servarr_models::{
+    BulkDeleteDownloadsParams, DeleteDownloadParams, QueueRemovalOptions,
+  };
+  use crate::network::radarr_network::RadarrEvent;
+
+  mod test_handle_scroll_up_and_down {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use crate::models::radarr_models::Movie;
+
+    use super::*;
+
+    #[rstest]
+    fn test_delete_download_prompt_scroll(
+      #[values(DEFAULT_KEYBINDINGS.up.key, DEFAULT_KEYBINDINGS.down.key)] key: Key,
+    ) {
+      let mut app = App::test_default();
+      app
+        .data
+        .radarr_data
+        .downloads
+        .set_items(vec![download_record()]);
+      app.data.radarr_data.selected_block =
+        BlockSelectionState::new(DELETE_DOWNLOAD_SELECTION_BLOCKS);
+      app.data.radarr_data.selected_block.down();
+
+      DownloadsHandler::new(key, &mut app, ActiveRadarrBlock::DeleteDownloadPrompt, None).handle();
+
+      if key == DEFAULT_KEYBINDINGS.up.key {
+        assert_eq!(
+          app.data.radarr_data.selected_block.get_active_block(),
+          ActiveRadarrBlock::DeleteDownloadToggleRemoveFromClient
+        );
+      } else {
+        assert_eq!(
+          app.data.radarr_data.selected_block.get_active_block(),
+          ActiveRadarrBlock::DeleteDownloadToggleSkipRedownload
+        );
+      }
+    }
+
+    #[rstest]
+    fn test_manual_import_select_movie_scroll(
+      #[values(DEFAULT_KEYBINDINGS.up.key, DEFAULT_KEYBINDINGS.down.key)] key: Key,
+    ) {
+      let mut app = App::test_default();
+      let mut manual_import_modal = ManualImportModal::default();
+      manual_import_modal.movie_list.set_items(vec![
+        Movie {
+          id: 1,
+          ..Movie::default()
+        },
+        Movie {
+          id: 2,
+          ..Movie::default()
+        },
+      ]);
+      app.data.radarr_data.manual_import_modal = Some(manual_import_modal);
+
+      DownloadsHandler::new(
+        key,
+        &mut app,
+        ActiveRadarrBlock::ManualImportSelectMovie,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app
+          .data
+          .radarr_data
+          .manual_import_modal
+          .as_ref()
+          .unwrap()
+          .movie_list
+          .current_selection()
+          .id,
+        2
+      );
+    }
+  }
 
   mod test_handle_delete {
     use pretty_assertions::assert_eq;
@@ -33,6 +119,10 @@ mod tests {
       DownloadsHandler::new(DELETE_KEY, &mut app, ActiveRadarrBlock::Downloads, None).handle();
 
       assert_navigation_pushed!(app, ActiveRadarrBlock::DeleteDownloadPrompt.into());
+      assert_eq!(
+        app.data.radarr_data.selected_block.get_active_block(),
+        ActiveRadarrBlock::DeleteDownloadToggleRemoveFromClient
+      );
     }
 
     #[test]
@@ -104,7 +194,9 @@ mod tests {
     fn test_downloads_left_right_prompt_toggle(
       #[values(
         ActiveRadarrBlock::DeleteDownloadPrompt,
-        ActiveRadarrBlock::UpdateDownloadsPrompt
+        ActiveRadarrBlock::GrabDownloadPrompt,
+        ActiveRadarrBlock::UpdateDownloadsPrompt,
+        ActiveRadarrBlock::ManualImportConfirmPrompt
       )]
       active_radarr_block: ActiveRadarrBlock,
       #[values(DEFAULT_KEYBINDINGS.left.key, DEFAULT_KEYBINDINGS.right.key)] key: Key,
@@ -126,17 +218,164 @@ mod tests {
     use rstest::rstest;
 
     use crate::network::radarr_network::RadarrEvent;
+    use crate::network::radarr_network::radarr_network_test_utils::test_utils::manual_import_file;
 
     use super::*;
     use crate::assert_navigation_popped;
 
     const SUBMIT_KEY: Key = DEFAULT_KEYBINDINGS.submit.key;
 
+    #[test]
+    fn test_delete_download_prompt_confirm_submit() {
+      let mut app = App::test_default();
+      app
+        .data
+        .radarr_data
+        .downloads
+        .set_items(vec![download_record()]);
+      app.data.radarr_data.prompt_confirm = true;
+      app.data.radarr_data.queue_removal_options = QueueRemovalOptions {
+        remove_from_client: false,
+        blocklist: true,
+        skip_redownload: true,
+        change_category: true,
+      };
+      app.push_navigation_stack(ActiveRadarrBlock::Downloads.into());
+      app.push_navigation_stack(ActiveRadarrBlock::DeleteDownloadPrompt.into());
+      app.data.radarr_data.selected_block =
+        BlockSelectionState::new(DELETE_DOWNLOAD_SELECTION_BLOCKS);
+      app
+        .data
+        .radarr_data
+        .selected_block
+        .set_index(0, DELETE_DOWNLOAD_SELECTION_BLOCKS.len() - 1);
+
+      DownloadsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveRadarrBlock::DeleteDownloadPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(app.data.radarr_data.prompt_confirm);
+      assert_eq!(
+        app.data.radarr_data.prompt_confirm_action,
+        Some(RadarrEvent::DeleteDownload(DeleteDownloadParams {
+          id: 1,
+          removal_options: QueueRemovalOptions {
+            remove_from_client: false,
+            blocklist: true,
+            skip_redownload: true,
+            change_category: true,
+          },
+        }))
+      );
+      assert_eq!(
+        app.data.radarr_data.queue_removal_options,
+        QueueRemovalOptions::default()
+      );
+      assert_navigation_popped!(app, ActiveRadarrBlock::Downloads.into());
+    }
+
+    #[test]
+    fn test_delete_download_prompt_decline_submit() {
+      let mut app = App::test_default();
+      app
+        .data
+        .radarr_data
+        .downloads
+        .set_items(vec![download_record()]);
+      app.data.radarr_data.queue_removal_options = QueueRemovalOptions {
+        remove_from_client: false,
+        blocklist: true,
+        skip_redownload: true,
+        change_category: true,
+      };
+      app.push_navigation_stack(ActiveRadarrBlock::Downloads.into());
+      app.push_navigation_stack(ActiveRadarrBlock::DeleteDownloadPrompt.into());
+      app.data.radarr_data.selected_block =
+        BlockSelectionState::new(DELETE_DOWNLOAD_SELECTION_BLOCKS);
+      app
+        .data
+        .radarr_data
+        .selected_block
+        .set_index(0, DELETE_DOWNLOAD_SELECTION_BLOCKS.len() - 1);
+
+      DownloadsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveRadarrBlock::DeleteDownloadPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(!app.data.radarr_data.prompt_confirm);
+      assert_none!(app.data.radarr_data.prompt_confirm_action);
+      assert_eq!(
+        app.data.radarr_data.queue_removal_options,
+        QueueRemovalOptions::default()
+      );
+      assert_navigation_popped!(app, ActiveRadarrBlock::Downloads.into());
+    }
+
+    #[rstest]
+    #[case(0, QueueRemovalOptions { remove_from_client: false, ..QueueRemovalOptions::default() })]
+    #[case(1, QueueRemovalOptions { blocklist: true, ..QueueRemovalOptions::default() })]
+    #[case(2, QueueRemovalOptions { skip_redownload: true, ..QueueRemovalOptions::default() })]
+    #[case(3, QueueRemovalOptions { change_category: true, ..QueueRemovalOptions::default() })]
+    fn test_delete_download_prompt_toggle_submit(
+      #[case] index: usize,
+      #[case] expected_removal_options: QueueRemovalOptions,
+    ) {
+      let mut app = App::test_default();
+      app
+        .data
+        .radarr_data
+        .downloads
+        .set_items(vec![download_record()]);
+      app.push_navigation_stack(ActiveRadarrBlock::Downloads.into());
+      app.push_navigation_stack(ActiveRadarrBlock::DeleteDownloadPrompt.into());
+      app.data.radarr_data.selected_block =
+        BlockSelectionState::new(DELETE_DOWNLOAD_SELECTION_BLOCKS);
+      app.data.radarr_data.selected_block.set_index(0, index);
+
+      DownloadsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveRadarrBlock::DeleteDownloadPrompt,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveRadarrBlock::DeleteDownloadPrompt.into()
+      );
+      assert_eq!(
+        app.data.radarr_data.queue_removal_options,
+        expected_removal_options
+      );
+
+      DownloadsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveRadarrBlock::DeleteDownloadPrompt,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.data.radarr_data.queue_removal_options,
+        QueueRemovalOptions::default()
+      );
+    }
+
     #[rstest]
     #[case(
       ActiveRadarrBlock::Downloads,
-      ActiveRadarrBlock::DeleteDownloadPrompt,
-      RadarrEvent::DeleteDownload(1)
+      ActiveRadarrBlock::GrabDownloadPrompt,
+      RadarrEvent::GrabQueueItem(1)
     )]
     #[case(
       ActiveRadarrBlock::Downloads,
@@ -169,7 +408,7 @@ mod tests {
     }
 
     #[rstest]
-    #[case(ActiveRadarrBlock::Downloads, ActiveRadarrBlock::DeleteDownloadPrompt)]
+    #[case(ActiveRadarrBlock::Downloads, ActiveRadarrBlock::GrabDownloadPrompt)]
     #[case(ActiveRadarrBlock::Downloads, ActiveRadarrBlock::UpdateDownloadsPrompt)]
     fn test_downloads_prompt_decline_submit(
       #[case] base_route: ActiveRadarrBlock,
@@ -190,9 +429,119 @@ mod tests {
       assert_none!(app.data.radarr_data.prompt_confirm_action);
       assert_navigation_popped!(app, base_route.into());
     }
+
+    #[test]
+    fn test_manual_import_submit_opens_select_movie() {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(ActiveRadarrBlock::Downloads.into());
+      app.push_navigation_stack(ActiveRadarrBlock::ManualImport.into());
+      let expected_movies = app.data.radarr_data.movies.items.clone();
+
+      DownloadsHandler::new(SUBMIT_KEY, &mut app, ActiveRadarrBlock::ManualImport, None).handle();
+
+      assert_navigation_pushed!(app, ActiveRadarrBlock::ManualImportSelectMovie.into());
+      assert_eq!(
+        app
+          .data
+          .radarr_data
+          .manual_import_modal
+          .as_ref()
+          .unwrap()
+          .movie_list
+          .items,
+        expected_movies
+      );
+    }
+
+    #[test]
+    fn test_manual_import_submit_no_op_when_no_items() {
+      let mut app = App::test_default();
+      app.data.radarr_data.manual_import_modal = Some(ManualImportModal::default());
+      app.push_navigation_stack(ActiveRadarrBlock::ManualImport.into());
+
+      DownloadsHandler::new(SUBMIT_KEY, &mut app, ActiveRadarrBlock::ManualImport, None).handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveRadarrBlock::ManualImport.into()
+      );
+    }
+
+    #[test]
+    fn test_manual_import_select_movie_submit() {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(ActiveRadarrBlock::ManualImport.into());
+      app.push_navigation_stack(ActiveRadarrBlock::ManualImportSelectMovie.into());
+
+      DownloadsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveRadarrBlock::ManualImportSelectMovie,
+        None,
+      )
+      .handle();
+
+      assert_navigation_popped!(app, ActiveRadarrBlock::ManualImport.into());
+      assert_eq!(
+        app
+          .data
+          .radarr_data
+          .manual_import_modal
+          .as_ref()
+          .unwrap()
+          .movie_id,
+        Some(1)
+      );
+    }
+
+    #[test]
+    fn test_manual_import_confirm_prompt_confirm_submit() {
+      let mut app = App::test_default_fully_populated();
+      app.data.radarr_data.prompt_confirm = true;
+      app.push_navigation_stack(ActiveRadarrBlock::Downloads.into());
+      app.push_navigation_stack(ActiveRadarrBlock::ManualImport.into());
+      app.push_navigation_stack(ActiveRadarrBlock::ManualImportConfirmPrompt.into());
+
+      DownloadsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveRadarrBlock::ManualImportConfirmPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(app.data.radarr_data.prompt_confirm);
+      assert_eq!(
+        app.data.radarr_data.prompt_confirm_action,
+        Some(RadarrEvent::ManualImport(vec![manual_import_file()]))
+      );
+      assert_none!(app.data.radarr_data.manual_import_modal);
+      assert_navigation_popped!(app, ActiveRadarrBlock::Downloads.into());
+    }
+
+    #[test]
+    fn test_manual_import_confirm_prompt_decline_submit() {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(ActiveRadarrBlock::ManualImport.into());
+      app.push_navigation_stack(ActiveRadarrBlock::ManualImportConfirmPrompt.into());
+
+      DownloadsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveRadarrBlock::ManualImportConfirmPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(!app.data.radarr_data.prompt_confirm);
+      assert_none!(app.data.radarr_data.prompt_confirm_action);
+      assert_some!(&app.data.radarr_data.manual_import_modal);
+      assert_navigation_popped!(app, ActiveRadarrBlock::ManualImport.into());
+    }
   }
 
   mod test_handle_esc {
+    use pretty_assertions::assert_eq;
     use rstest::rstest;
 
     use super::*;
@@ -200,9 +549,41 @@ mod tests {
 
     const ESC_KEY: Key = DEFAULT_KEYBINDINGS.esc.key;
 
+    #[test]
+    fn test_delete_download_prompt_esc_resets_removal_options() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveRadarrBlock::Downloads.into());
+      app.push_navigation_stack(ActiveRadarrBlock::DeleteDownloadPrompt.into());
+      app.data.radarr_data.queue_removal_options = QueueRemovalOptions {
+        remove_from_client: false,
+        blocklist: true,
+        skip_redownload: true,
+        change_category: true,
+      };
+
+      DownloadsHandler::new(
+        ESC_KEY,
+        &mut app,
+        ActiveRadarrBlock::DeleteDownloadPrompt,
+        None,
+      )
+      .handle();
+
+      assert_navigation_popped!(app, ActiveRadarrBlock::Downloads.into());
+      assert_eq!(
+        app.data.radarr_data.queue_removal_options,
+        QueueRemovalOptions::default()
+      );
+    }
+
     #[rstest]
     #[case(ActiveRadarrBlock::Downloads, ActiveRadarrBlock::DeleteDownloadPrompt)]
+    #[case(ActiveRadarrBlock::Downloads, ActiveRadarrBlock::GrabDownloadPrompt)]
     #[case(ActiveRadarrBlock::Downloads, ActiveRadarrBlock::UpdateDownloadsPrompt)]
+    #[case(
+      ActiveRadarrBlock::ManualImport,
+      ActiveRadarrBlock::ManualImportConfirmPrompt
+    )]
     fn test_downloads_prompt_blocks_esc(
       #[case] base_block: ActiveRadarrBlock,
       #[case] prompt_block: ActiveRadarrBlock,
@@ -218,6 +599,44 @@ mod tests {
       assert!(!app.data.radarr_data.prompt_confirm);
     }
 
+    #[test]
+    fn test_manual_import_esc() {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(ActiveRadarrBlock::Downloads.into());
+      app.push_navigation_stack(ActiveRadarrBlock::ManualImport.into());
+
+      DownloadsHandler::new(ESC_KEY, &mut app, ActiveRadarrBlock::ManualImport, None).handle();
+
+      assert_navigation_popped!(app, ActiveRadarrBlock::Downloads.into());
+      assert_none!(app.data.radarr_data.manual_import_modal);
+    }
+
+    #[test]
+    fn test_manual_import_select_movie_esc() {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(ActiveRadarrBlock::ManualImport.into());
+      app.push_navigation_stack(ActiveRadarrBlock::ManualImportSelectMovie.into());
+
+      DownloadsHandler::new(
+        ESC_KEY,
+        &mut app,
+        ActiveRadarrBlock::ManualImportSelectMovie,
+        None,
+      )
+      .handle();
+
+      assert_navigation_popped!(app, ActiveRadarrBlock::ManualImport.into());
+      assert_none!(
+        app
+          .data
+          .radarr_data
+          .manual_import_modal
+          .as_ref()
+          .unwrap()
+          .movie_id
+      );
+    }
+
     #[rstest]
     fn test_default_esc(#[values(true, false)] is_ready: bool) {
       let mut app = App::test_default();
@@ -235,8 +654,10 @@ mod tests {
 
   mod test_handle_key_char {
     use crate::assert_navigation_popped;
+    use crate::models::radarr_models::RadarrManualImportItem;
     use crate::network::radarr_network::RadarrEvent;
-    use pretty_assertions::assert_eq;
+    use crate::network::radarr_network::radarr_network_test_utils::test_utils::manual_import_file;
+    use pretty_assertions::{assert_eq, assert_str_eq};
     use rstest::rstest;
 
     use super::*;
@@ -283,6 +704,51 @@ mod tests {
       assert_eq!(app.get_current_route(), ActiveRadarrBlock::Downloads.into());
     }
 
+    #[test]
+    fn test_grab_key() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveRadarrBlock::Downloads.into());
+      app
+        .data
+        .radarr_data
+        .downloads
+        .set_items(vec![DownloadRecord {
+          status: "delay".to_owned(),
+          ..download_record()
+        }]);
+
+      DownloadsHandler::new(
+        DEFAULT_KEYBINDINGS.grab.key,
+        &mut app,
+        ActiveRadarrBlock::Downloads,
+        None,
+      )
+      .handle();
+
+      assert_navigation_pushed!(app, ActiveRadarrBlock::GrabDownloadPrompt.into());
+    }
+
+    #[test]
+    fn test_grab_key_no_op_when_download_is_not_pending() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveRadarrBlock::Downloads.into());
+      app
+        .data
+        .radarr_data
+        .downloads
+        .set_items(vec![download_record()]);
+
+      DownloadsHandler::new(
+        DEFAULT_KEYBINDINGS.grab.key,
+        &mut app,
+        ActiveRadarrBlock::Downloads,
+        None,
+      )
+      .handle();
+
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::Downloads.into());
+    }
+
     #[test]
     fn test_refresh_downloads_key() {
       let mut app = App::test_default();
@@ -328,11 +794,205 @@ mod tests {
       assert!(!app.should_refresh);
     }
 
+    #[test]
+    fn test_manual_import_key() {
+      let mut app = App::test_default();
+      app
+        .data
+        .radarr_data
+        .downloads
+        .set_items(vec![download_record()]);
+      app.push_navigation_stack(ActiveRadarrBlock::Downloads.into());
+
+      DownloadsHandler::new(
+        DEFAULT_KEYBINDINGS.import.key,
+        &mut app,
+        ActiveRadarrBlock::Downloads,
+        None,
+      )
+      .handle();
+
+      assert_navigation_pushed!(app, ActiveRadarrBlock::ManualImport.into());
+      let manual_import_modal = app.data.radarr_data.manual_import_modal.as_ref().unwrap();
+      assert_str_eq!(manual_import_modal.download_id, "abc123");
+      assert_none!(manual_import_modal.movie_id);
+    }
+
+    #[test]
+    fn test_manual_import_key_no_op_when_download_id_is_missing() {
+      let mut app = App::test_default();
+      app
+        .data
+        .radarr_data
+        .downloads
+        .set_items(vec![DownloadRecord::default()]);
+      app.push_navigation_stack(ActiveRadarrBlock::Downloads.into());
+
+      DownloadsHandler::new(
+        DEFAULT_KEYBINDINGS.import.key,
+        &mut app,
+        ActiveRadarrBlock::Downloads,
+        None,
+      )
+      .handle();
+
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::Downloads.into());
+      assert_none!(app.data.radarr_data.manual_import_modal);
+    }
+
+    #[test]
+    fn test_manual_import_import_key() {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(ActiveRadarrBlock::ManualImport.into());
+
+      DownloadsHandler::new(
+        DEFAULT_KEYBINDINGS.import.key,
+        &mut app,
+        ActiveRadarrBlock::ManualImport,
+        None,
+      )
+      .handle();
+
+      assert_navigation_pushed!(app, ActiveRadarrBlock::ManualImportConfirmPrompt.into());
+    }
+
+    #[test]
+    fn test_manual_import_import_key_no_op_when_nothing_is_importable() {
+      let mut app = App::test_default_fully_populated();
+      app
+        .data
+        .radarr_data
+        .manual_import_modal
+        .as_mut()
+        .unwrap()
+        .items
+        .set_items(vec![RadarrManualImportItem::default()]);
+      app.push_navigation_stack(ActiveRadarrBlock::ManualImport.into());
+
+      DownloadsHandler::new(
+        DEFAULT_KEYBINDINGS.import.key,
+        &mut app,
+        ActiveRadarrBlock::ManualImport,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveRadarrBlock::ManualImport.into()
+      );
+    }
+
+    #[test]
+    fn test_manual_import_refresh_key() {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(ActiveRadarrBlock::ManualImport.into());
+
+      DownloadsHandler::new(
+        DEFAULT_KEYBINDINGS.refresh.key,
+        &mut app,
+        ActiveRadarrBlock::ManualImport,
+        None,
+      )
+      .handle();
+
+      assert!(app.should_refresh);
+    }
+
+    #[test]
+    fn test_manual_import_confirm_prompt_confirm_key() {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(ActiveRadarrBlock::Downloads.into());
+      app.push_navigation_stack(ActiveRadarrBlock::ManualImport.into());
+      app.push_navigation_stack(ActiveRadarrBlock::ManualImportConfirmPrompt.into());
+
+      DownloadsHandler::new(
+        DEFAULT_KEYBINDINGS.confirm.key,
+        &mut app,
+        ActiveRadarrBlock::ManualImportConfirmPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(app.data.radarr_data.prompt_confirm);
+      assert_eq!(
+        app.data.radarr_data.prompt_confirm_action,
+        Some(RadarrEvent::ManualImport(vec![manual_import_file()]))
+      );
+      assert_navigation_popped!(app, ActiveRadarrBlock::Downloads.into());
+    }
+
+    #[test]
+    fn test_delete_download_prompt_confirm_key() {
+      let mut app = App::test_default();
+      app
+        .data
+        .radarr_data
+        .downloads
+        .set_items(vec![download_record()]);
+      app.push_navigation_stack(ActiveRadarrBlock::Downloads.into());
+      app.push_navigation_stack(ActiveRadarrBlock::DeleteDownloadPrompt.into());
+      app.data.radarr_data.selected_block =
+        BlockSelectionState::new(DELETE_DOWNLOAD_SELECTION_BLOCKS);
+      app
+        .data
+        .radarr_data
+        .selected_block
+        .set_index(0, DELETE_DOWNLOAD_SELECTION_BLOCKS.len() - 1);
+
+      DownloadsHandler::new(
+        DEFAULT_KEYBINDINGS.confirm.key,
+        &mut app,
+        ActiveRadarrBlock::DeleteDownloadPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(app.data.radarr_data.prompt_confirm);
+      assert_eq!(
+        app.data.radarr_data.prompt_confirm_action,
+        Some(RadarrEvent::DeleteDownload(DeleteDownloadParams {
+          id: 1,
+          removal_options: QueueRemovalOptions::default(),
+        }))
+      );
+      assert_navigation_popped!(app, ActiveRadarrBlock::Downloads.into());
+    }
+
+    #[test]
+    fn test_delete_download_prompt_confirm_key_no_op_when_not_on_confirm_prompt() {
+      let mut app = App::test_default();
+      app
+        .data
+        .radarr_data
+        .downloads
+        .set_items(vec![download_record()]);
+      app.push_navigation_stack(ActiveRadarrBlock::Downloads.into());
+      app.push_navigation_stack(ActiveRadarrBlock::DeleteDownloadPrompt.into());
+      app.data.radarr_data.selected_block =
+        BlockSelectionState::new(DELETE_DOWNLOAD_SELECTION_BLOCKS);
+
+      DownloadsHandler::new(
+        DEFAULT_KEYBINDINGS.confirm.key,
+        &mut app,
+        ActiveRadarrBlock::DeleteDownloadPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(!app.data.radarr_data.prompt_confirm);
+      assert_none!(app.data.radarr_data.prompt_confirm_action);
+      assert_eq!(
+        app.get_current_route(),
+        ActiveRadarrBlock::DeleteDownloadPrompt.into()
+      );
+    }
+
     #[rstest]
     #[case(
       ActiveRadarrBlock::Downloads,
-      ActiveRadarrBlock::DeleteDownloadPrompt,
-      RadarrEvent::DeleteDownload(1)
+      ActiveRadarrBlock::GrabDownloadPrompt,
+      RadarrEvent::GrabQueueItem(1)
     )]
     #[case(
       ActiveRadarrBlock::Downloads,
@@ -401,23 +1061,78 @@ mod tests {
   }
 
   #[test]
-  fn test_extract_download_id() {
+  fn test_build_delete_download_event() {
     let mut app = App::test_default();
     app
       .data
       .radarr_data
       .downloads
       .set_items(vec![download_record()]);
+    app.data.radarr_data.queue_removal_options = QueueRemovalOptions {
+      remove_from_client: false,
+      blocklist: true,
+      skip_redownload: true,
+      change_category: true,
+    };
+
+    let delete_download_event = DownloadsHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveRadarrBlock::DeleteDownloadPrompt,
+      None,
+    )
+    .build_delete_download_event();
+
+    assert_eq!(
+      delete_download_event,
+      RadarrEvent::DeleteDownload(DeleteDownloadParams {
+        id: 1,
+        removal_options: QueueRemovalOptions {
+          remove_from_client: false,
+          blocklist: true,
+          skip_redownload: true,
+          change_category: true,
+        },
+      })
+    );
+    assert_eq!(
+      app.data.radarr_data.queue_removal_options,
+      QueueRemovalOptions::default()
+    );
+  }
 
-    let download_id = DownloadsHandler::new(
+  #[test]
+  fn test_build_delete_download_event_with_multi_selection() {
+    let mut app = App::test_default();
+    app.data.radarr_data.downloads.set_items(vec![
+      download_record(),
+      DownloadRecord {
+        id: 2,
+        ..download_record()
+      },
+    ]);
+    app
+      .data
+      .radarr_data
+      .downloads
+      .toggle_select_all(|download| download.id);
+
+    let delete_download_event = DownloadsHandler::new(
       DEFAULT_KEYBINDINGS.esc.key,
       &mut app,
-      ActiveRadarrBlock::Downloads,
+      ActiveRadarrBlock::DeleteDownloadPrompt,
       None,
     )
-    .extract_download_id();
+    .build_delete_download_event();
 
-    assert_eq!(download_id, 1);
+    assert_eq!(
+      delete_download_event,
+      RadarrEvent::BulkDeleteDownloads(BulkDeleteDownloadsParams {
+        ids: vec![1, 2],
+        removal_options: QueueRemovalOptions::default(),
+      })
+    );
+    assert!(!app.data.radarr_data.downloads.has_multi_selection());
   }
 
   #[test]
@@ -4,8 +4,15 @@ use crate::handlers::radarr_handlers::handle_change_tab_left_right_keys;
 use crate::handlers::table_handler::{TableHandlingConfig, handle_table};
 use crate::handlers::{KeyEventHandler, handle_clear_errors, handle_prompt_toggle};
 use crate::matches_key;
-use crate::models::Route;
-use crate::models::servarr_data::radarr::radarr_data::{ActiveRadarrBlock, DOWNLOADS_BLOCKS};
+use crate::models::radarr_models::DownloadRecord;
+use crate::models::servarr_data::radarr::modals::ManualImportModal;
+use crate::models::servarr_data::radarr::radarr_data::{
+  ActiveRadarrBlock, DELETE_DOWNLOAD_SELECTION_BLOCKS, DOWNLOADS_BLOCKS,
+};
+use crate::models::servarr_models::{
+  BulkDeleteDownloadsParams, DeleteDownloadParams, QueueRemovalOptions,
+};
+use crate::models::{BlockSelectionState, Route, Scrollable};
 use crate::network::radarr_network::RadarrEvent;
 
 #[cfg(test)]
@@ -20,20 +27,98 @@ pub(super) struct DownloadsHandler<'a, 'b> {
 }
 
 impl DownloadsHandler<'_, '_> {
-  fn extract_download_id(&self) -> i64 {
-    self.app.data.radarr_data.downloads.current_selection().id
+  fn build_delete_download_event(&mut self) -> RadarrEvent {
+    let removal_options = self.app.data.radarr_data.queue_removal_options;
+    self.app.data.radarr_data.queue_removal_options = QueueRemovalOptions::default();
+
+    if self.app.data.radarr_data.downloads.has_multi_selection() {
+      let ids = self
+        .app
+        .data
+        .radarr_data
+        .downloads
+        .multi_selection()
+        .iter()
+        .map(|download: &DownloadRecord| download.id)
+        .collect();
+      self.app.data.radarr_data.downloads.clear_multi_selection();
+
+      RadarrEvent::BulkDeleteDownloads(BulkDeleteDownloadsParams {
+        ids,
+        removal_options,
+      })
+    } else {
+      RadarrEvent::DeleteDownload(DeleteDownloadParams {
+        id: self.app.data.radarr_data.downloads.current_selection().id,
+        removal_options,
+      })
+    }
+  }
+
+  fn manual_import_modal(&mut self) -> &mut ManualImportModal {
+    self
+      .app
+      .data
+      .radarr_data
+      .manual_import_modal
+      .as_mut()
+      .expect("manual_import_modal must exist in this context")
+  }
+
+  fn build_manual_import_event(&self) -> Option<RadarrEvent> {
+    let files: Vec<_> = self
+      .app
+      .data
+      .radarr_data
+      .manual_import_modal
+      .as_ref()
+      .expect("manual_import_modal must exist in this context")
+      .items
+      .items
+      .iter()
+      .filter_map(|item| item.import_file())
+      .collect();
+
+    if files.is_empty() {
+      None
+    } else {
+      Some(RadarrEvent::ManualImport(files))
+    }
+  }
+
+  fn confirm_manual_import(&mut self) {
+    self.app.data.radarr_data.prompt_confirm_action = self.build_manual_import_event();
+    self.app.data.radarr_data.manual_import_modal = None;
+    self.app.pop_navigation_stack();
+    self.app.pop_navigation_stack();
   }
 }
 
 impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for DownloadsHandler<'a, 'b> {
   fn handle(&mut self) {
     let downloads_table_handling_config =
-      TableHandlingConfig::new(ActiveRadarrBlock::Downloads.into());
+      TableHandlingConfig::new(ActiveRadarrBlock::Downloads.into())
+        .multi_select_id_fn(|download: &DownloadRecord| download.id);
+
+    let manual_import_table_handling_config =
+      TableHandlingConfig::new(ActiveRadarrBlock::ManualImport.into());
 
     if !handle_table(
       self,
       |app| &mut app.data.radarr_data.downloads,
       downloads_table_handling_config,
+    ) && !handle_table(
+      self,
+      |app| {
+        &mut app
+          .data
+          .radarr_data
+          .manual_import_modal
+          .as_mut()
+          .unwrap()
+          .items
+      },
+      manual_import_table_handling_config,
     ) {
       self.handle_key_event();
     }
@@ -66,31 +151,69 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for DownloadsHandler<'a,
   }
 
   fn is_ready(&self) -> bool {
-    !self.app.is_loading && !self.app.data.radarr_data.downloads.is_empty()
+    if self.app.is_loading {
+      return false;
+    }
+
+    match self.active_radarr_block {
+      ActiveRadarrBlock::ManualImport
+      | ActiveRadarrBlock::ManualImportConfirmPrompt
+      | ActiveRadarrBlock::ManualImportSelectMovie => {
+        self.app.data.radarr_data.manual_import_modal.is_some()
+      }
+      _ => !self.app.data.radarr_data.downloads.is_empty(),
+    }
   }
 
-  fn handle_scroll_up(&mut self) {}
+  fn handle_scroll_up(&mut self) {
+    match self.active_radarr_block {
+      ActiveRadarrBlock::DeleteDownloadPrompt => self.app.data.radarr_data.selected_block.up(),
+      ActiveRadarrBlock::ManualImportSelectMovie => {
+        self.manual_import_modal().movie_list.scroll_up()
+      }
+      _ => (),
+    }
+  }
 
-  fn handle_scroll_down(&mut self) {}
+  fn handle_scroll_down(&mut self) {
+    match self.active_radarr_block {
+      ActiveRadarrBlock::DeleteDownloadPrompt => self.app.data.radarr_data.selected_block.down(),
+      ActiveRadarrBlock::ManualImportSelectMovie => {
+        self.manual_import_modal().movie_list.scroll_down()
+      }
+      _ => (),
+    }
+  }
 
-  fn handle_home(&mut self) {}
+  fn handle_home(&mut self) {
+    if self.active_radarr_block == ActiveRadarrBlock::ManualImportSelectMovie {
+      self.manual_import_modal().movie_list.scroll_to_top();
+    }
+  }
 
-  fn handle_end(&mut self) {}
+  fn handle_end(&mut self) {
+    if self.active_radarr_block == ActiveRadarrBlock::ManualImportSelectMovie {
+      self.manual_import_modal().movie_list.scroll_to_bottom();
+    }
+  }
 
   fn handle_delete(&mut self) {
     if self.active_radarr_block == ActiveRadarrBlock::Downloads {
       self
         .app
-        .push_navigation_stack(ActiveRadarrBlock::DeleteDownloadPrompt.into())
+        .push_navigation_stack(ActiveRadarrBlock::DeleteDownloadPrompt.into());
+      self.app.data.radarr_data.selected_block =
+        BlockSelectionState::new(DELETE_DOWNLOAD_SELECTION_BLOCKS);
     }
   }
 
   fn handle_left_right_action(&mut self) {
     match self.active_radarr_block {
       ActiveRadarrBlock::Downloads => handle_change_tab_left_right_keys(self.app, self.key),
-      ActiveRadarrBlock::DeleteDownloadPrompt | ActiveRadarrBlock::UpdateDownloadsPrompt => {
-        handle_prompt_toggle(self.app, self.key)
-      }
+      ActiveRadarrBlock::DeleteDownloadPrompt
+      | ActiveRadarrBlock::GrabDownloadPrompt
+      | ActiveRadarrBlock::UpdateDownloadsPrompt
+      | ActiveRadarrBlock::ManualImportConfirmPrompt => handle_prompt_toggle(self.app, self.key),
       _ => (),
     }
   }
@@ -98,9 +221,38 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for DownloadsHandler<'a,
   fn handle_submit(&mut self) {
     match self.active_radarr_block {
       ActiveRadarrBlock::DeleteDownloadPrompt => {
+        let removal_options = &mut self.app.data.radarr_data.queue_removal_options;
+        match self.app.data.radarr_data.selected_block.get_active_block() {
+          ActiveRadarrBlock::DeleteDownloadConfirmPrompt => {
+            if self.app.data.radarr_data.prompt_confirm {
+              self.app.data.radarr_data.prompt_confirm_action =
+                Some(self.build_delete_download_event());
+            } else {
+              self.app.data.radarr_data.queue_removal_options = QueueRemovalOptions::default();
+            }
+
+            self.app.pop_navigation_stack();
+          }
+          ActiveRadarrBlock::DeleteDownloadToggleRemoveFromClient => {
+            removal_options.remove_from_client = !removal_options.remove_from_client;
+          }
+          ActiveRadarrBlock::DeleteDownloadToggleBlocklist => {
+            removal_options.blocklist = !removal_options.blocklist;
+          }
+          ActiveRadarrBlock::DeleteDownloadToggleSkipRedownload => {
+            removal_options.skip_redownload = !removal_options.skip_redownload;
+          }
+          ActiveRadarrBlock::DeleteDownloadToggleChangeCategory => {
+            removal_options.change_category = !removal_options.change_category;
+          }
+          _ => (),
+        }
+      }
+      ActiveRadarrBlock::GrabDownloadPrompt => {
         if self.app.data.radarr_data.prompt_confirm {
-          self.app.data.radarr_data.prompt_confirm_action =
-            Some(RadarrEvent::DeleteDownload(self.extract_download_id()));
+          self.app.data.radarr_data.prompt_confirm_action = Some(RadarrEvent::GrabQueueItem(
+            self.app.data.radarr_data.downloads.current_selection().id,
+          ));
         }
 
         self.app.pop_navigation_stack();
@@ -112,16 +264,59 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for DownloadsHandler<'a,
 
         self.app.pop_navigation_stack();
       }
+      ActiveRadarrBlock::ManualImport
+        if self
+          .app
+          .data
+          .radarr_data
+          .manual_import_modal
+          .as_ref()
+          .is_some_and(|modal| !modal.items.is_empty()) =>
+      {
+        let movies = self.app.data.radarr_data.movies.items.clone();
+        self.manual_import_modal().movie_list.set_items(movies);
+        self
+          .app
+          .push_navigation_stack(ActiveRadarrBlock::ManualImportSelectMovie.into());
+      }
+      ActiveRadarrBlock::ManualImportSelectMovie => {
+        let manual_import_modal = self.manual_import_modal();
+        if !manual_import_modal.movie_list.items.is_empty() {
+          manual_import_modal.movie_id =
+            Some(manual_import_modal.movie_list.current_selection().id);
+        }
+
+        self.app.pop_navigation_stack();
+      }
+      ActiveRadarrBlock::ManualImportConfirmPrompt => {
+        if self.app.data.radarr_data.prompt_confirm {
+          self.confirm_manual_import();
+        } else {
+          self.app.pop_navigation_stack();
+        }
+      }
       _ => (),
     }
   }
 
   fn handle_esc(&mut self) {
     match self.active_radarr_block {
-      ActiveRadarrBlock::DeleteDownloadPrompt | ActiveRadarrBlock::UpdateDownloadsPrompt => {
+      ActiveRadarrBlock::DeleteDownloadPrompt => {
         self.app.pop_navigation_stack();
+        self.app.data.radarr_data.queue_removal_options = QueueRemovalOptions::default();
         self.app.data.radarr_data.prompt_confirm = false;
       }
+      ActiveRadarrBlock::GrabDownloadPrompt
+      | ActiveRadarrBlock::UpdateDownloadsPrompt
+      | ActiveRadarrBlock::ManualImportConfirmPrompt => {
+        self.app.pop_navigation_stack();
+        self.app.data.radarr_data.prompt_confirm = false;
+      }
+      ActiveRadarrBlock::ManualImport => {
+        self.app.pop_navigation_stack();
+        self.app.data.radarr_data.manual_import_modal = None;
+      }
+      ActiveRadarrBlock::ManualImportSelectMovie => self.app.pop_navigation_stack(),
       _ => handle_clear_errors(self.app),
     }
   }
@@ -135,16 +330,71 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for DownloadsHandler<'a,
             .app
             .push_navigation_stack(ActiveRadarrBlock::UpdateDownloadsPrompt.into());
         }
+        _ if matches_key!(grab, key)
+          && self
+            .app
+            .data
+            .radarr_data
+            .downloads
+            .current_selection()
+            .is_pending() =>
+        {
+          self
+            .app
+            .push_navigation_stack(ActiveRadarrBlock::GrabDownloadPrompt.into());
+        }
         _ if matches_key!(refresh, key) => {
           self.app.should_refresh = true;
         }
+        _ if matches_key!(import, key) => {
+          let download_record = self.app.data.radarr_data.downloads.current_selection();
+          if let Some(download_id) = download_record.download_id.clone() {
+            self.app.data.radarr_data.manual_import_modal = Some(ManualImportModal {
+              download_id,
+              ..ManualImportModal::default()
+            });
+            self
+              .app
+              .push_navigation_stack(ActiveRadarrBlock::ManualImport.into());
+          }
+        }
         _ => (),
       },
-      ActiveRadarrBlock::DeleteDownloadPrompt => {
+      ActiveRadarrBlock::ManualImport => match self.key {
+        _ if matches_key!(import, key) && self.build_manual_import_event().is_some() => {
+          self
+            .app
+            .push_navigation_stack(ActiveRadarrBlock::ManualImportConfirmPrompt.into());
+        }
+        _ if matches_key!(refresh, key) => {
+          self.app.should_refresh = true;
+        }
+        _ => (),
+      },
+      ActiveRadarrBlock::ManualImportConfirmPrompt => {
+        if matches_key!(confirm, key) {
+          self.app.data.radarr_data.prompt_confirm = true;
+          self.confirm_manual_import();
+        }
+      }
+      ActiveRadarrBlock::DeleteDownloadPrompt
+        if self.app.data.radarr_data.selected_block.get_active_block()
+          == ActiveRadarrBlock::DeleteDownloadConfirmPrompt =>
+      {
         if matches_key!(confirm, key) {
           self.app.data.radarr_data.prompt_confirm = true;
           self.app.data.radarr_data.prompt_confirm_action =
-            Some(RadarrEvent::DeleteDownload(self.extract_download_id()));
+            Some(self.build_delete_download_event());
+
+          self.app.pop_navigation_stack();
+        }
+      }
+      ActiveRadarrBlock::GrabDownloadPrompt => {
+        if matches_key!(confirm, key) {
+          self.app.data.radarr_data.prompt_confirm = true;
+          self.app.data.radarr_data.prompt_confirm_action = Some(RadarrEvent::GrabQueueItem(
+            self.app.data.radarr_data.downloads.current_selection().id,
+          ));
 
           self.app.pop_navigation_stack();
         }
@@ -12,7 +12,8 @@ mod tests {
   use crate::handlers::radarr_handlers::indexers::IndexersHandler;
   use crate::handlers::radarr_handlers::radarr_handler_test_utils::utils::indexer;
   use crate::models::servarr_data::radarr::radarr_data::{
-    ActiveRadarrBlock, EDIT_INDEXER_BLOCKS, INDEXER_SETTINGS_BLOCKS, INDEXERS_BLOCKS,
+    ADD_INDEXER_BLOCKS, ActiveRadarrBlock, EDIT_INDEXER_BLOCKS, INDEXER_SETTINGS_BLOCKS,
+    INDEXERS_BLOCKS,
   };
   use crate::models::servarr_models::Indexer;
   use crate::test_handler_delegation;
@@ -98,9 +99,9 @@ mod tests {
 
       assert_eq!(
         app.data.radarr_data.main_tabs.get_active_route(),
-        ActiveRadarrBlock::System.into()
+        ActiveRadarrBlock::DownloadClients.into()
       );
-      assert_navigation_pushed!(app, ActiveRadarrBlock::System.into());
+      assert_navigation_pushed!(app, ActiveRadarrBlock::DownloadClients.into());
     }
 
     #[rstest]
@@ -161,10 +162,12 @@ mod tests {
         IndexerField {
           name: Some("baseUrl".to_owned()),
           value: Some(Value::String("https://test.com".to_owned())),
+          ..IndexerField::default()
         },
         IndexerField {
           name: Some("apiKey".to_owned()),
           value: Some(Value::String("1234".to_owned())),
+          ..IndexerField::default()
         },
       ];
 
@@ -172,6 +175,7 @@ mod tests {
         fields.push(IndexerField {
           name: Some("seedCriteria.seedRatio".to_owned()),
           value: Some(Value::from(1.2f64)),
+          ..IndexerField::default()
         });
         expected_edit_indexer_modal.seed_ratio = "1.2".into();
       }
@@ -390,6 +394,52 @@ mod tests {
       assert!(!app.should_refresh);
     }
 
+    #[test]
+    fn test_indexer_add_key() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveRadarrBlock::Indexers.into());
+      app
+        .data
+        .radarr_data
+        .indexers
+        .set_items(vec![Indexer::default()]);
+
+      IndexersHandler::new(
+        DEFAULT_KEYBINDINGS.add.key,
+        &mut app,
+        ActiveRadarrBlock::Indexers,
+        None,
+      )
+      .handle();
+
+      assert_navigation_pushed!(
+        app,
+        ActiveRadarrBlock::AddIndexerSelectImplementation.into()
+      );
+    }
+
+    #[test]
+    fn test_indexer_add_key_no_op_when_not_ready() {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.push_navigation_stack(ActiveRadarrBlock::Indexers.into());
+      app
+        .data
+        .radarr_data
+        .indexers
+        .set_items(vec![Indexer::default()]);
+
+      IndexersHandler::new(
+        DEFAULT_KEYBINDINGS.add.key,
+        &mut app,
+        ActiveRadarrBlock::Indexers,
+        None,
+      )
+      .handle();
+
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::Indexers.into());
+    }
+
     #[test]
     fn test_indexer_settings_key() {
       let mut app = App::test_default();
@@ -604,6 +654,7 @@ mod tests {
     let mut indexers_blocks = Vec::new();
     indexers_blocks.extend(INDEXERS_BLOCKS);
     indexers_blocks.extend(INDEXER_SETTINGS_BLOCKS);
+    indexers_blocks.extend(ADD_INDEXER_BLOCKS);
     indexers_blocks.extend(EDIT_INDEXER_BLOCKS);
     indexers_blocks.push(ActiveRadarrBlock::TestAllIndexers);
 
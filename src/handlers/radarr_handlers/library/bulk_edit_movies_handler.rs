@@ -0,0 +1,318 @@
+use crate::app::App;
+use crate::event::Key;
+use crate::handlers::{KeyEventHandler, handle_prompt_toggle};
+use crate::models::radarr_models::BulkEditMoviesParams;
+use crate::models::servarr_data::radarr::radarr_data::{
+  ActiveRadarrBlock, BULK_EDIT_MOVIES_BLOCKS,
+};
+use crate::models::{Route, Scrollable};
+use crate::network::radarr_network::RadarrEvent;
+use crate::{handle_text_box_keys, handle_text_box_left_right_keys, matches_key};
+
+#[cfg(test)]
+#[path = "bulk_edit_movies_handler_tests.rs"]
+mod bulk_edit_movies_handler_tests;
+
+pub(super) struct BulkEditMoviesHandler<'a, 'b> {
+  key: Key,
+  app: &'a mut App<'b>,
+  active_radarr_block: ActiveRadarrBlock,
+  _context: Option<ActiveRadarrBlock>,
+}
+
+impl BulkEditMoviesHandler<'_, '_> {
+  fn build_bulk_edit_movies_params(&mut self) -> BulkEditMoviesParams {
+    let movie_ids = self
+      .app
+      .data
+      .radarr_data
+      .movies
+      .multi_selection()
+      .iter()
+      .map(|movie| movie.id)
+      .collect();
+    let bulk_edit_modal = self
+      .app
+      .data
+      .radarr_data
+      .bulk_edit_modal
+      .take()
+      .expect("Bulk edit modal is None");
+    let quality_profile_id =
+      bulk_edit_modal
+        .selected_quality_profile()
+        .and_then(|quality_profile| {
+          self
+            .app
+            .data
+            .radarr_data
+            .quality_profile_map
+            .get_by_right(quality_profile)
+            .copied()
+        });
+    self.app.data.radarr_data.movies.clear_multi_selection();
+
+    BulkEditMoviesParams {
+      movie_ids,
+      monitored: bulk_edit_modal
+        .monitored_list
+        .current_selection()
+        .monitored(),
+      quality_profile_id,
+      root_folder_path: bulk_edit_modal.selected_root_folder_path(),
+      tags: None,
+      tag_input_string: bulk_edit_modal.tag_input_string(),
+      apply_tags: *bulk_edit_modal.apply_tags_list.current_selection(),
+      move_files: bulk_edit_modal.move_files.unwrap_or_default(),
+    }
+  }
+}
+
+impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for BulkEditMoviesHandler<'a, 'b> {
+  fn accepts(active_block: ActiveRadarrBlock) -> bool {
+    BULK_EDIT_MOVIES_BLOCKS.contains(&active_block)
+  }
+
+  fn ignore_special_keys(&self) -> bool {
+    self.app.ignore_special_keys_for_textbox_input
+  }
+
+  fn new(
+    key: Key,
+    app: &'a mut App<'b>,
+    active_block: ActiveRadarrBlock,
+    _context: Option<ActiveRadarrBlock>,
+  ) -> BulkEditMoviesHandler<'a, 'b> {
+    BulkEditMoviesHandler {
+      key,
+      app,
+      active_radarr_block: active_block,
+      _context,
+    }
+  }
+
+  fn get_key(&self) -> Key {
+    self.key
+  }
+
+  fn is_ready(&self) -> bool {
+    !self.app.is_loading && self.app.data.radarr_data.bulk_edit_modal.is_some()
+  }
+
+  fn handle_scroll_up(&mut self) {
+    let bulk_edit_modal = self.app.data.radarr_data.bulk_edit_modal.as_mut().unwrap();
+    match self.active_radarr_block {
+      ActiveRadarrBlock::BulkEditMoviesSelectMonitored => {
+        bulk_edit_modal.monitored_list.scroll_up()
+      }
+      ActiveRadarrBlock::BulkEditMoviesSelectQualityProfile => {
+        bulk_edit_modal.quality_profile_list.scroll_up()
+      }
+      ActiveRadarrBlock::BulkEditMoviesSelectRootFolder => {
+        bulk_edit_modal.root_folder_list.scroll_up()
+      }
+      ActiveRadarrBlock::BulkEditMoviesSelectApplyTags => {
+        bulk_edit_modal.apply_tags_list.scroll_up()
+      }
+      ActiveRadarrBlock::BulkEditMoviesPrompt => self.app.data.radarr_data.selected_block.up(),
+      _ => (),
+    }
+  }
+
+  fn handle_scroll_down(&mut self) {
+    let bulk_edit_modal = self.app.data.radarr_data.bulk_edit_modal.as_mut().unwrap();
+    match self.active_radarr_block {
+      ActiveRadarrBlock::BulkEditMoviesSelectMonitored => {
+        bulk_edit_modal.monitored_list.scroll_down()
+      }
+      ActiveRadarrBlock::BulkEditMoviesSelectQualityProfile => {
+        bulk_edit_modal.quality_profile_list.scroll_down()
+      }
+      ActiveRadarrBlock::BulkEditMoviesSelectRootFolder => {
+        bulk_edit_modal.root_folder_list.scroll_down()
+      }
+      ActiveRadarrBlock::BulkEditMoviesSelectApplyTags => {
+        bulk_edit_modal.apply_tags_list.scroll_down()
+      }
+      ActiveRadarrBlock::BulkEditMoviesPrompt => self.app.data.radarr_data.selected_block.down(),
+      _ => (),
+    }
+  }
+
+  fn handle_home(&mut self) {
+    let bulk_edit_modal = self.app.data.radarr_data.bulk_edit_modal.as_mut().unwrap();
+    match self.active_radarr_block {
+      ActiveRadarrBlock::BulkEditMoviesSelectMonitored => {
+        bulk_edit_modal.monitored_list.scroll_to_top()
+      }
+      ActiveRadarrBlock::BulkEditMoviesSelectQualityProfile => {
+        bulk_edit_modal.quality_profile_list.scroll_to_top()
+      }
+      ActiveRadarrBlock::BulkEditMoviesSelectRootFolder => {
+        bulk_edit_modal.root_folder_list.scroll_to_top()
+      }
+      ActiveRadarrBlock::BulkEditMoviesSelectApplyTags => {
+        bulk_edit_modal.apply_tags_list.scroll_to_top()
+      }
+      ActiveRadarrBlock::BulkEditMoviesTagsInput => bulk_edit_modal.tags.scroll_home(),
+      _ => (),
+    }
+  }
+
+  fn handle_end(&mut self) {
+    let bulk_edit_modal = self.app.data.radarr_data.bulk_edit_modal.as_mut().unwrap();
+    match self.active_radarr_block {
+      ActiveRadarrBlock::BulkEditMoviesSelectMonitored => {
+        bulk_edit_modal.monitored_list.scroll_to_bottom()
+      }
+      ActiveRadarrBlock::BulkEditMoviesSelectQualityProfile => {
+        bulk_edit_modal.quality_profile_list.scroll_to_bottom()
+      }
+      ActiveRadarrBlock::BulkEditMoviesSelectRootFolder => {
+        bulk_edit_modal.root_folder_list.scroll_to_bottom()
+      }
+      ActiveRadarrBlock::BulkEditMoviesSelectApplyTags => {
+        bulk_edit_modal.apply_tags_list.scroll_to_bottom()
+      }
+      ActiveRadarrBlock::BulkEditMoviesTagsInput => bulk_edit_modal.tags.reset_offset(),
+      _ => (),
+    }
+  }
+
+  fn handle_delete(&mut self) {}
+
+  fn handle_left_right_action(&mut self) {
+    match self.active_radarr_block {
+      ActiveRadarrBlock::BulkEditMoviesPrompt => handle_prompt_toggle(self.app, self.key),
+      ActiveRadarrBlock::BulkEditMoviesTagsInput => {
+        handle_text_box_left_right_keys!(
+          self,
+          self.key,
+          self
+            .app
+            .data
+            .radarr_data
+            .bulk_edit_modal
+            .as_mut()
+            .unwrap()
+            .tags
+        )
+      }
+      _ => (),
+    }
+  }
+
+  fn handle_submit(&mut self) {
+    match self.active_radarr_block {
+      ActiveRadarrBlock::BulkEditMoviesPrompt => {
+        match self.app.data.radarr_data.selected_block.get_active_block() {
+          ActiveRadarrBlock::BulkEditMoviesConfirmPrompt => {
+            if self.app.data.radarr_data.prompt_confirm {
+              self.app.data.radarr_data.prompt_confirm_action = Some(RadarrEvent::BulkEditMovies(
+                self.build_bulk_edit_movies_params(),
+              ));
+              self.app.should_refresh = true;
+            } else {
+              self.app.data.radarr_data.bulk_edit_modal = None;
+            }
+
+            self.app.pop_navigation_stack();
+          }
+          ActiveRadarrBlock::BulkEditMoviesSelectMonitored
+          | ActiveRadarrBlock::BulkEditMoviesSelectQualityProfile
+          | ActiveRadarrBlock::BulkEditMoviesSelectRootFolder
+          | ActiveRadarrBlock::BulkEditMoviesSelectApplyTags => self.app.push_navigation_stack(
+            self
+              .app
+              .data
+              .radarr_data
+              .selected_block
+              .get_active_block()
+              .into(),
+          ),
+          ActiveRadarrBlock::BulkEditMoviesTagsInput => {
+            self
+              .app
+              .push_navigation_stack(ActiveRadarrBlock::BulkEditMoviesTagsInput.into());
+            self.app.ignore_special_keys_for_textbox_input = true;
+          }
+          ActiveRadarrBlock::BulkEditMoviesToggleMoveFiles => {
+            let bulk_edit_modal = self.app.data.radarr_data.bulk_edit_modal.as_mut().unwrap();
+            bulk_edit_modal.move_files = Some(!bulk_edit_modal.move_files.unwrap_or_default());
+          }
+          _ => (),
+        }
+      }
+      ActiveRadarrBlock::BulkEditMoviesSelectMonitored
+      | ActiveRadarrBlock::BulkEditMoviesSelectQualityProfile
+      | ActiveRadarrBlock::BulkEditMoviesSelectRootFolder
+      | ActiveRadarrBlock::BulkEditMoviesSelectApplyTags => self.app.pop_navigation_stack(),
+      ActiveRadarrBlock::BulkEditMoviesTagsInput => {
+        self.app.pop_navigation_stack();
+        self.app.ignore_special_keys_for_textbox_input = false;
+      }
+      _ => (),
+    }
+  }
+
+  fn handle_esc(&mut self) {
+    match self.active_radarr_block {
+      ActiveRadarrBlock::BulkEditMoviesTagsInput => {
+        self.app.pop_navigation_stack();
+        self.app.ignore_special_keys_for_textbox_input = false;
+      }
+      ActiveRadarrBlock::BulkEditMoviesPrompt => {
+        self.app.pop_navigation_stack();
+        self.app.data.radarr_data.bulk_edit_modal = None;
+        self.app.data.radarr_data.prompt_confirm = false;
+      }
+      ActiveRadarrBlock::BulkEditMoviesSelectMonitored
+      | ActiveRadarrBlock::BulkEditMoviesSelectQualityProfile
+      | ActiveRadarrBlock::BulkEditMoviesSelectRootFolder
+      | ActiveRadarrBlock::BulkEditMoviesSelectApplyTags => self.app.pop_navigation_stack(),
+      _ => (),
+    }
+  }
+
+  fn handle_char_key_event(&mut self) {
+    let key = self.key;
+    match self.active_radarr_block {
+      ActiveRadarrBlock::BulkEditMoviesTagsInput => {
+        handle_text_box_keys!(
+          self,
+          key,
+          self
+            .app
+            .data
+            .radarr_data
+            .bulk_edit_modal
+            .as_mut()
+            .unwrap()
+            .tags
+        )
+      }
+      ActiveRadarrBlock::BulkEditMoviesPrompt
+        if self.app.data.radarr_data.selected_block.get_active_block()
+          == ActiveRadarrBlock::BulkEditMoviesConfirmPrompt
+          && matches_key!(confirm, key) =>
+      {
+        self.app.data.radarr_data.prompt_confirm = true;
+        self.app.data.radarr_data.prompt_confirm_action = Some(RadarrEvent::BulkEditMovies(
+          self.build_bulk_edit_movies_params(),
+        ));
+        self.app.should_refresh = true;
+
+        self.app.pop_navigation_stack();
+      }
+      _ => (),
+    }
+  }
+
+  fn app_mut(&mut self) -> &mut App<'b> {
+    self.app
+  }
+
+  fn current_route(&self) -> Route {
+    self.app.get_current_route()
+  }
+}
@@ -0,0 +1,827 @@
+#[cfg(test)]
+mod tests {
+  use std::sync::atomic::Ordering;
+
+  use bimap::BiMap;
+  use pretty_assertions::assert_eq;
+  use rstest::rstest;
+  use strum::IntoEnumIterator;
+
+  use crate::app::App;
+  use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+  use crate::assert_modal_absent;
+  use crate::assert_navigation_popped;
+  use crate::assert_navigation_pushed;
+  use crate::event::Key;
+  use crate::handlers::KeyEventHandler;
+  use crate::handlers::radarr_handlers::library::bulk_edit_movies_handler::BulkEditMoviesHandler;
+  use crate::handlers::radarr_handlers::radarr_handler_test_utils::utils::movie;
+  use crate::models::radarr_models::{BulkEditMoviesParams, Movie};
+  use crate::models::servarr_data::modals::BulkEditModal;
+  use crate::models::servarr_data::radarr::radarr_data::{
+    ActiveRadarrBlock, BULK_EDIT_MOVIES_BLOCKS, BULK_EDIT_MOVIES_SELECTION_BLOCKS,
+  };
+  use crate::models::servarr_models::{ApplyTags, BulkEditMonitoring};
+  use crate::models::{BlockSelectionState, Scrollable};
+  use crate::network::radarr_network::RadarrEvent;
+
+  fn app_with_selected_movies() -> App<'static> {
+    let mut app = App::test_default();
+    let movies = vec![
+      movie(),
+      Movie { id: 2, ..movie() },
+      Movie { id: 3, ..movie() },
+    ];
+    app.data.radarr_data.movies.set_items(movies.clone());
+    app.data.radarr_data.movies.selected_ids = [movies[2].id, movies[0].id].into();
+    app.data.radarr_data.movies.selection_id_fn = Some(|movie| movie.id);
+    app.data.radarr_data.quality_profile_map =
+      BiMap::from_iter([(1111, "Any".to_owned()), (2222, "HD - 1080p".to_owned())]);
+    let mut bulk_edit_modal = BulkEditModal::new(
+      vec!["Any".to_owned(), "HD - 1080p".to_owned()],
+      vec!["/nfs/movies".to_owned()],
+    );
+    bulk_edit_modal.monitored_list.scroll_down();
+    bulk_edit_modal.quality_profile_list.scroll_to_bottom();
+    bulk_edit_modal.root_folder_list.scroll_to_bottom();
+    bulk_edit_modal.apply_tags_list.scroll_to_bottom();
+    bulk_edit_modal.tags = "usenet, testing".into();
+    bulk_edit_modal.move_files = Some(true);
+    app.data.radarr_data.bulk_edit_modal = Some(bulk_edit_modal);
+
+    app
+  }
+
+  fn expected_bulk_edit_movies_params() -> BulkEditMoviesParams {
+    BulkEditMoviesParams {
+      movie_ids: vec![1, 3],
+      monitored: Some(true),
+      quality_profile_id: Some(2222),
+      root_folder_path: Some("/nfs/movies".to_owned()),
+      tags: None,
+      tag_input_string: Some("usenet, testing".to_owned()),
+      apply_tags: ApplyTags::Replace,
+      move_files: true,
+    }
+  }
+
+  mod test_handle_scroll_up_and_down {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[rstest]
+    fn test_bulk_edit_movies_select_monitored_scroll(
+      #[values(DEFAULT_KEYBINDINGS.up.key, DEFAULT_KEYBINDINGS.down.key)] key: Key,
+    ) {
+      let monitoring_vec = Vec::from_iter(BulkEditMonitoring::iter());
+      let mut app = App::test_default();
+      app.data.radarr_data.bulk_edit_modal = Some(BulkEditModal::new(Vec::new(), Vec::new()));
+
+      if key == Key::Up {
+        for i in (0..monitoring_vec.len()).rev() {
+          BulkEditMoviesHandler::new(
+            key,
+            &mut app,
+            ActiveRadarrBlock::BulkEditMoviesSelectMonitored,
+            None,
+          )
+          .handle();
+
+          assert_eq!(
+            app
+              .data
+              .radarr_data
+              .bulk_edit_modal
+              .as_ref()
+              .unwrap()
+              .monitored_list
+              .current_selection(),
+            &monitoring_vec[i]
+          );
+        }
+      } else {
+        for i in 0..monitoring_vec.len() {
+          BulkEditMoviesHandler::new(
+            key,
+            &mut app,
+            ActiveRadarrBlock::BulkEditMoviesSelectMonitored,
+            None,
+          )
+          .handle();
+
+          assert_eq!(
+            app
+              .data
+              .radarr_data
+              .bulk_edit_modal
+              .as_ref()
+              .unwrap()
+              .monitored_list
+              .current_selection(),
+            &monitoring_vec[(i + 1) % monitoring_vec.len()]
+          );
+        }
+      }
+    }
+
+    #[rstest]
+    fn test_bulk_edit_movies_select_quality_profile_scroll(
+      #[values(DEFAULT_KEYBINDINGS.up.key, DEFAULT_KEYBINDINGS.down.key)] key: Key,
+    ) {
+      let mut app = App::test_default();
+      app.data.radarr_data.bulk_edit_modal = Some(BulkEditModal::new(
+        vec!["HD - 1080p".to_owned()],
+        Vec::new(),
+      ));
+
+      BulkEditMoviesHandler::new(
+        key,
+        &mut app,
+        ActiveRadarrBlock::BulkEditMoviesSelectQualityProfile,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app
+          .data
+          .radarr_data
+          .bulk_edit_modal
+          .as_ref()
+          .unwrap()
+          .quality_profile_list
+          .current_selection(),
+        "HD - 1080p"
+      );
+    }
+
+    #[rstest]
+    fn test_bulk_edit_movies_select_root_folder_scroll(
+      #[values(DEFAULT_KEYBINDINGS.up.key, DEFAULT_KEYBINDINGS.down.key)] key: Key,
+    ) {
+      let mut app = App::test_default();
+      app.data.radarr_data.bulk_edit_modal = Some(BulkEditModal::new(
+        Vec::new(),
+        vec!["/nfs/movies".to_owned()],
+      ));
+
+      BulkEditMoviesHandler::new(
+        key,
+        &mut app,
+        ActiveRadarrBlock::BulkEditMoviesSelectRootFolder,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app
+          .data
+          .radarr_data
+          .bulk_edit_modal
+          .as_ref()
+          .unwrap()
+          .root_folder_list
+          .current_selection(),
+        "/nfs/movies"
+      );
+    }
+
+    #[rstest]
+    fn test_bulk_edit_movies_select_apply_tags_scroll(
+      #[values(DEFAULT_KEYBINDINGS.up.key, DEFAULT_KEYBINDINGS.down.key)] key: Key,
+    ) {
+      let mut app = App::test_default();
+      app.data.radarr_data.bulk_edit_modal = Some(BulkEditModal::new(Vec::new(), Vec::new()));
+
+      BulkEditMoviesHandler::new(
+        key,
+        &mut app,
+        ActiveRadarrBlock::BulkEditMoviesSelectApplyTags,
+        None,
+      )
+      .handle();
+
+      let expected_apply_tags = if key == Key::Up {
+        ApplyTags::Replace
+      } else {
+        ApplyTags::Remove
+      };
+      assert_eq!(
+        app
+          .data
+          .radarr_data
+          .bulk_edit_modal
+          .as_ref()
+          .unwrap()
+          .apply_tags_list
+          .current_selection(),
+        &expected_apply_tags
+      );
+    }
+
+    #[rstest]
+    fn test_bulk_edit_movies_prompt_scroll(#[values(Key::Up, Key::Down)] key: Key) {
+      let mut app = App::test_default();
+      app.data.radarr_data.bulk_edit_modal = Some(BulkEditModal::new(Vec::new(), Vec::new()));
+      app.data.radarr_data.selected_block =
+        BlockSelectionState::new(BULK_EDIT_MOVIES_SELECTION_BLOCKS);
+      app.data.radarr_data.selected_block.down();
+
+      BulkEditMoviesHandler::new(key, &mut app, ActiveRadarrBlock::BulkEditMoviesPrompt, None)
+        .handle();
+
+      if key == Key::Up {
+        assert_eq!(
+          app.data.radarr_data.selected_block.get_active_block(),
+          ActiveRadarrBlock::BulkEditMoviesSelectMonitored
+        );
+      } else {
+        assert_eq!(
+          app.data.radarr_data.selected_block.get_active_block(),
+          ActiveRadarrBlock::BulkEditMoviesSelectRootFolder
+        );
+      }
+    }
+  }
+
+  mod test_handle_home_end {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_bulk_edit_movies_select_monitored_home_end() {
+      let mut app = App::test_default();
+      app.data.radarr_data.bulk_edit_modal = Some(BulkEditModal::new(Vec::new(), Vec::new()));
+
+      BulkEditMoviesHandler::new(
+        DEFAULT_KEYBINDINGS.end.key,
+        &mut app,
+        ActiveRadarrBlock::BulkEditMoviesSelectMonitored,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app
+          .data
+          .radarr_data
+          .bulk_edit_modal
+          .as_ref()
+          .unwrap()
+          .monitored_list
+          .current_selection(),
+        &BulkEditMonitoring::Unmonitored
+      );
+
+      BulkEditMoviesHandler::new(
+        DEFAULT_KEYBINDINGS.home.key,
+        &mut app,
+        ActiveRadarrBlock::BulkEditMoviesSelectMonitored,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app
+          .data
+          .radarr_data
+          .bulk_edit_modal
+          .as_ref()
+          .unwrap()
+          .monitored_list
+          .current_selection(),
+        &BulkEditMonitoring::NoChange
+      );
+    }
+
+    #[test]
+    fn test_bulk_edit_movies_tags_input_home_end_keys() {
+      let mut app = App::test_default();
+      app.data.radarr_data.bulk_edit_modal = Some(BulkEditModal {
+        tags: "Test".into(),
+        ..BulkEditModal::default()
+      });
+
+      BulkEditMoviesHandler::new(
+        DEFAULT_KEYBINDINGS.home.key,
+        &mut app,
+        ActiveRadarrBlock::BulkEditMoviesTagsInput,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app
+          .data
+          .radarr_data
+          .bulk_edit_modal
+          .as_ref()
+          .unwrap()
+          .tags
+          .offset
+          .load(Ordering::SeqCst),
+        4
+      );
+
+      BulkEditMoviesHandler::new(
+        DEFAULT_KEYBINDINGS.end.key,
+        &mut app,
+        ActiveRadarrBlock::BulkEditMoviesTagsInput,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app
+          .data
+          .radarr_data
+          .bulk_edit_modal
+          .as_ref()
+          .unwrap()
+          .tags
+          .offset
+          .load(Ordering::SeqCst),
+        0
+      );
+    }
+  }
+
+  mod test_handle_left_right_action {
+    use super::*;
+
+    #[rstest]
+    fn test_left_right_prompt_toggle(#[values(Key::Left, Key::Right)] key: Key) {
+      let mut app = App::test_default();
+      app.data.radarr_data.bulk_edit_modal = Some(BulkEditModal::new(Vec::new(), Vec::new()));
+
+      BulkEditMoviesHandler::new(key, &mut app, ActiveRadarrBlock::BulkEditMoviesPrompt, None)
+        .handle();
+
+      assert!(app.data.radarr_data.prompt_confirm);
+
+      BulkEditMoviesHandler::new(key, &mut app, ActiveRadarrBlock::BulkEditMoviesPrompt, None)
+        .handle();
+
+      assert!(!app.data.radarr_data.prompt_confirm);
+    }
+  }
+
+  mod test_handle_submit {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    const SUBMIT_KEY: Key = DEFAULT_KEYBINDINGS.submit.key;
+
+    #[test]
+    fn test_bulk_edit_movies_prompt_decline_submit() {
+      let mut app = app_with_selected_movies();
+      app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
+      app.push_navigation_stack(ActiveRadarrBlock::BulkEditMoviesPrompt.into());
+      app.data.radarr_data.selected_block =
+        BlockSelectionState::new(BULK_EDIT_MOVIES_SELECTION_BLOCKS);
+      app
+        .data
+        .radarr_data
+        .selected_block
+        .set_index(0, BULK_EDIT_MOVIES_SELECTION_BLOCKS.len() - 1);
+
+      BulkEditMoviesHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveRadarrBlock::BulkEditMoviesPrompt,
+        None,
+      )
+      .handle();
+
+      assert_navigation_popped!(app, ActiveRadarrBlock::Movies.into());
+      assert_none!(app.data.radarr_data.prompt_confirm_action);
+      assert_modal_absent!(app.data.radarr_data.bulk_edit_modal);
+      assert!(app.data.radarr_data.movies.has_multi_selection());
+    }
+
+    #[test]
+    fn test_bulk_edit_movies_confirm_prompt_confirmation_submit() {
+      let mut app = app_with_selected_movies();
+      app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
+      app.push_navigation_stack(ActiveRadarrBlock::BulkEditMoviesPrompt.into());
+      app.data.radarr_data.prompt_confirm = true;
+      app.data.radarr_data.selected_block =
+        BlockSelectionState::new(BULK_EDIT_MOVIES_SELECTION_BLOCKS);
+      app
+        .data
+        .radarr_data
+        .selected_block
+        .set_index(0, BULK_EDIT_MOVIES_SELECTION_BLOCKS.len() - 1);
+
+      BulkEditMoviesHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveRadarrBlock::BulkEditMoviesPrompt,
+        None,
+      )
+      .handle();
+
+      assert_navigation_popped!(app, ActiveRadarrBlock::Movies.into());
+      assert_eq!(
+        app.data.radarr_data.prompt_confirm_action,
+        Some(RadarrEvent::BulkEditMovies(
+          expected_bulk_edit_movies_params()
+        ))
+      );
+      assert_modal_absent!(app.data.radarr_data.bulk_edit_modal);
+      assert!(!app.data.radarr_data.movies.has_multi_selection());
+      assert!(app.should_refresh);
+    }
+
+    #[test]
+    fn test_bulk_edit_movies_confirm_prompt_confirmation_submit_no_op_when_not_ready() {
+      let mut app = app_with_selected_movies();
+      app.is_loading = true;
+      app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
+      app.push_navigation_stack(ActiveRadarrBlock::BulkEditMoviesPrompt.into());
+      app.data.radarr_data.prompt_confirm = true;
+      app.data.radarr_data.selected_block =
+        BlockSelectionState::new(BULK_EDIT_MOVIES_SELECTION_BLOCKS);
+      app
+        .data
+        .radarr_data
+        .selected_block
+        .set_index(0, BULK_EDIT_MOVIES_SELECTION_BLOCKS.len() - 1);
+
+      BulkEditMoviesHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveRadarrBlock::BulkEditMoviesPrompt,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveRadarrBlock::BulkEditMoviesPrompt.into()
+      );
+      assert_none!(app.data.radarr_data.prompt_confirm_action);
+      assert!(!app.should_refresh);
+    }
+
+    #[test]
+    fn test_bulk_edit_movies_toggle_move_files_submit() {
+      let mut app = App::test_default();
+      app.data.radarr_data.bulk_edit_modal = Some(BulkEditModal::new(Vec::new(), Vec::new()));
+      app.push_navigation_stack(ActiveRadarrBlock::BulkEditMoviesPrompt.into());
+      app.data.radarr_data.selected_block =
+        BlockSelectionState::new(BULK_EDIT_MOVIES_SELECTION_BLOCKS);
+      app.data.radarr_data.selected_block.set_index(0, 3);
+
+      BulkEditMoviesHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveRadarrBlock::BulkEditMoviesPrompt,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveRadarrBlock::BulkEditMoviesPrompt.into()
+      );
+      assert_eq!(
+        app
+          .data
+          .radarr_data
+          .bulk_edit_modal
+          .as_ref()
+          .unwrap()
+          .move_files,
+        Some(true)
+      );
+
+      BulkEditMoviesHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveRadarrBlock::BulkEditMoviesPrompt,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app
+          .data
+          .radarr_data
+          .bulk_edit_modal
+          .as_ref()
+          .unwrap()
+          .move_files,
+        Some(false)
+      );
+    }
+
+    #[rstest]
+    #[case(ActiveRadarrBlock::BulkEditMoviesSelectMonitored, 0)]
+    #[case(ActiveRadarrBlock::BulkEditMoviesSelectQualityProfile, 1)]
+    #[case(ActiveRadarrBlock::BulkEditMoviesSelectRootFolder, 2)]
+    #[case(ActiveRadarrBlock::BulkEditMoviesTagsInput, 4)]
+    #[case(ActiveRadarrBlock::BulkEditMoviesSelectApplyTags, 5)]
+    fn test_bulk_edit_movies_prompt_selected_block_submit(
+      #[case] selected_block: ActiveRadarrBlock,
+      #[case] y_index: usize,
+    ) {
+      let mut app = App::test_default();
+      app.data.radarr_data.bulk_edit_modal = Some(BulkEditModal::new(Vec::new(), Vec::new()));
+      app.push_navigation_stack(ActiveRadarrBlock::BulkEditMoviesPrompt.into());
+      app.data.radarr_data.selected_block =
+        BlockSelectionState::new(BULK_EDIT_MOVIES_SELECTION_BLOCKS);
+      app.data.radarr_data.selected_block.set_index(0, y_index);
+
+      BulkEditMoviesHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveRadarrBlock::BulkEditMoviesPrompt,
+        None,
+      )
+      .handle();
+
+      assert_navigation_pushed!(app, selected_block.into());
+      assert_none!(app.data.radarr_data.prompt_confirm_action);
+      assert_eq!(
+        app.ignore_special_keys_for_textbox_input,
+        selected_block == ActiveRadarrBlock::BulkEditMoviesTagsInput
+      );
+    }
+
+    #[rstest]
+    fn test_bulk_edit_movies_selecting_blocks_submit(
+      #[values(
+        ActiveRadarrBlock::BulkEditMoviesSelectMonitored,
+        ActiveRadarrBlock::BulkEditMoviesSelectQualityProfile,
+        ActiveRadarrBlock::BulkEditMoviesSelectRootFolder,
+        ActiveRadarrBlock::BulkEditMoviesSelectApplyTags,
+        ActiveRadarrBlock::BulkEditMoviesTagsInput
+      )]
+      active_radarr_block: ActiveRadarrBlock,
+    ) {
+      let mut app = App::test_default();
+      app.ignore_special_keys_for_textbox_input =
+        active_radarr_block == ActiveRadarrBlock::BulkEditMoviesTagsInput;
+      app.data.radarr_data.bulk_edit_modal = Some(BulkEditModal::new(Vec::new(), Vec::new()));
+      app.push_navigation_stack(ActiveRadarrBlock::BulkEditMoviesPrompt.into());
+      app.push_navigation_stack(active_radarr_block.into());
+
+      BulkEditMoviesHandler::new(SUBMIT_KEY, &mut app, active_radarr_block, None).handle();
+
+      assert_navigation_popped!(app, ActiveRadarrBlock::BulkEditMoviesPrompt.into());
+      assert!(!app.ignore_special_keys_for_textbox_input);
+    }
+  }
+
+  mod test_handle_esc {
+    use super::*;
+
+    const ESC_KEY: Key = DEFAULT_KEYBINDINGS.esc.key;
+
+    #[test]
+    fn test_bulk_edit_movies_tags_input_esc() {
+      let mut app = App::test_default();
+      app.ignore_special_keys_for_textbox_input = true;
+      app.data.radarr_data.bulk_edit_modal = Some(BulkEditModal::new(Vec::new(), Vec::new()));
+      app.push_navigation_stack(ActiveRadarrBlock::BulkEditMoviesPrompt.into());
+      app.push_navigation_stack(ActiveRadarrBlock::BulkEditMoviesTagsInput.into());
+
+      BulkEditMoviesHandler::new(
+        ESC_KEY,
+        &mut app,
+        ActiveRadarrBlock::BulkEditMoviesTagsInput,
+        None,
+      )
+      .handle();
+
+      assert!(!app.ignore_special_keys_for_textbox_input);
+      assert_navigation_popped!(app, ActiveRadarrBlock::BulkEditMoviesPrompt.into());
+    }
+
+    #[test]
+    fn test_bulk_edit_movies_prompt_esc() {
+      let mut app = app_with_selected_movies();
+      app.data.radarr_data.prompt_confirm = true;
+      app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
+      app.push_navigation_stack(ActiveRadarrBlock::BulkEditMoviesPrompt.into());
+
+      BulkEditMoviesHandler::new(
+        ESC_KEY,
+        &mut app,
+        ActiveRadarrBlock::BulkEditMoviesPrompt,
+        None,
+      )
+      .handle();
+
+      assert_navigation_popped!(app, ActiveRadarrBlock::Movies.into());
+      assert_modal_absent!(app.data.radarr_data.bulk_edit_modal);
+      assert!(!app.data.radarr_data.prompt_confirm);
+      assert!(app.data.radarr_data.movies.has_multi_selection());
+    }
+
+    #[rstest]
+    fn test_bulk_edit_movies_selecting_blocks_esc(
+      #[values(
+        ActiveRadarrBlock::BulkEditMoviesSelectMonitored,
+        ActiveRadarrBlock::BulkEditMoviesSelectQualityProfile,
+        ActiveRadarrBlock::BulkEditMoviesSelectRootFolder,
+        ActiveRadarrBlock::BulkEditMoviesSelectApplyTags
+      )]
+      active_radarr_block: ActiveRadarrBlock,
+    ) {
+      let mut app = App::test_default();
+      app.data.radarr_data.bulk_edit_modal = Some(BulkEditModal::new(Vec::new(), Vec::new()));
+      app.push_navigation_stack(ActiveRadarrBlock::BulkEditMoviesPrompt.into());
+      app.push_navigation_stack(active_radarr_block.into());
+
+      BulkEditMoviesHandler::new(ESC_KEY, &mut app, active_radarr_block, None).handle();
+
+      assert_navigation_popped!(app, ActiveRadarrBlock::BulkEditMoviesPrompt.into());
+    }
+  }
+
+  mod test_handle_key_char {
+    use pretty_assertions::{assert_eq, assert_str_eq};
+
+    use super::*;
+
+    #[test]
+    fn test_bulk_edit_movies_tags_input_char_key() {
+      let mut app = App::test_default();
+      app.data.radarr_data.bulk_edit_modal = Some(BulkEditModal::new(Vec::new(), Vec::new()));
+
+      BulkEditMoviesHandler::new(
+        Key::Char('a'),
+        &mut app,
+        ActiveRadarrBlock::BulkEditMoviesTagsInput,
+        None,
+      )
+      .handle();
+
+      assert_str_eq!(
+        app
+          .data
+          .radarr_data
+          .bulk_edit_modal
+          .as_ref()
+          .unwrap()
+          .tags
+          .text,
+        "a"
+      );
+    }
+
+    #[test]
+    fn test_bulk_edit_movies_confirm_prompt_confirm() {
+      let mut app = app_with_selected_movies();
+      app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
+      app.push_navigation_stack(ActiveRadarrBlock::BulkEditMoviesPrompt.into());
+      app.data.radarr_data.selected_block =
+        BlockSelectionState::new(BULK_EDIT_MOVIES_SELECTION_BLOCKS);
+      app
+        .data
+        .radarr_data
+        .selected_block
+        .set_index(0, BULK_EDIT_MOVIES_SELECTION_BLOCKS.len() - 1);
+
+      BulkEditMoviesHandler::new(
+        DEFAULT_KEYBINDINGS.confirm.key,
+        &mut app,
+        ActiveRadarrBlock::BulkEditMoviesPrompt,
+        None,
+      )
+      .handle();
+
+      assert_navigation_popped!(app, ActiveRadarrBlock::Movies.into());
+      assert_eq!(
+        app.data.radarr_data.prompt_confirm_action,
+        Some(RadarrEvent::BulkEditMovies(
+          expected_bulk_edit_movies_params()
+        ))
+      );
+      assert_modal_absent!(app.data.radarr_data.bulk_edit_modal);
+      assert!(app.should_refresh);
+    }
+  }
+
+  #[test]
+  fn test_bulk_edit_movies_handler_accepts() {
+    ActiveRadarrBlock::iter().for_each(|active_radarr_block| {
+      if BULK_EDIT_MOVIES_BLOCKS.contains(&active_radarr_block) {
+        assert!(BulkEditMoviesHandler::accepts(active_radarr_block));
+      } else {
+        assert!(!BulkEditMoviesHandler::accepts(active_radarr_block));
+      }
+    });
+  }
+
+  #[rstest]
+  fn test_bulk_edit_movies_handler_ignore_special_keys(
+    #[values(true, false)] ignore_special_keys_for_textbox_input: bool,
+  ) {
+    let mut app = App::test_default();
+    app.ignore_special_keys_for_textbox_input = ignore_special_keys_for_textbox_input;
+    let handler = BulkEditMoviesHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveRadarrBlock::default(),
+      None,
+    );
+
+    assert_eq!(
+      handler.ignore_special_keys(),
+      ignore_special_keys_for_textbox_input
+    );
+  }
+
+  #[test]
+  fn test_build_bulk_edit_movies_params() {
+    let mut app = app_with_selected_movies();
+
+    let bulk_edit_movies_params = BulkEditMoviesHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveRadarrBlock::BulkEditMoviesPrompt,
+      None,
+    )
+    .build_bulk_edit_movies_params();
+
+    assert_eq!(bulk_edit_movies_params, expected_bulk_edit_movies_params());
+    assert_modal_absent!(app.data.radarr_data.bulk_edit_modal);
+    assert!(!app.data.radarr_data.movies.has_multi_selection());
+  }
+
+  #[test]
+  fn test_build_bulk_edit_movies_params_defaults_to_no_change() {
+    let mut app = app_with_selected_movies();
+    app.data.radarr_data.bulk_edit_modal = Some(BulkEditModal::new(
+      vec!["Any".to_owned()],
+      vec!["/nfs/movies".to_owned()],
+    ));
+
+    let bulk_edit_movies_params = BulkEditMoviesHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveRadarrBlock::BulkEditMoviesPrompt,
+      None,
+    )
+    .build_bulk_edit_movies_params();
+
+    assert_eq!(
+      bulk_edit_movies_params,
+      BulkEditMoviesParams {
+        movie_ids: vec![1, 3],
+        ..BulkEditMoviesParams::default()
+      }
+    );
+  }
+
+  #[test]
+  fn test_bulk_edit_movies_handler_is_not_ready_when_loading() {
+    let mut app = App::test_default();
+    app.is_loading = true;
+    app.data.radarr_data.bulk_edit_modal = Some(BulkEditModal::new(Vec::new(), Vec::new()));
+
+    let handler = BulkEditMoviesHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveRadarrBlock::BulkEditMoviesPrompt,
+      None,
+    );
+
+    assert!(!handler.is_ready());
+  }
+
+  #[test]
+  fn test_bulk_edit_movies_handler_is_not_ready_when_bulk_edit_modal_is_none() {
+    let mut app = App::test_default();
+
+    let handler = BulkEditMoviesHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveRadarrBlock::BulkEditMoviesPrompt,
+      None,
+    );
+
+    assert!(!handler.is_ready());
+  }
+
+  #[test]
+  fn test_bulk_edit_movies_handler_is_ready_when_bulk_edit_modal_is_some() {
+    let mut app = App::test_default();
+    app.data.radarr_data.bulk_edit_modal = Some(BulkEditModal::new(Vec::new(), Vec::new()));
+
+    let handler = BulkEditMoviesHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveRadarrBlock::BulkEditMoviesPrompt,
+      None,
+    );
+
+    assert!(handler.is_ready());
+  }
+}
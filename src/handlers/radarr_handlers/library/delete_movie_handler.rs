@@ -3,7 +3,7 @@ use crate::event::Key;
 use crate::handlers::{KeyEventHandler, handle_prompt_toggle};
 use crate::matches_key;
 use crate::models::Route;
-use crate::models::radarr_models::DeleteMovieParams;
+use crate::models::radarr_models::{BulkDeleteMoviesParams, DeleteMovieParams};
 use crate::models::servarr_data::radarr::radarr_data::{ActiveRadarrBlock, DELETE_MOVIE_BLOCKS};
 use crate::network::radarr_network::RadarrEvent;
 
@@ -19,16 +19,34 @@ pub(super) struct DeleteMovieHandler<'a, 'b> {
 }
 
 impl DeleteMovieHandler<'_, '_> {
-  fn build_delete_movie_params(&mut self) -> DeleteMovieParams {
-    let id = self.app.data.radarr_data.movies.current_selection().id;
+  fn build_delete_movie_event(&mut self) -> RadarrEvent {
     let delete_movie_files = self.app.data.radarr_data.delete_movie_files;
     let add_list_exclusion = self.app.data.radarr_data.add_list_exclusion;
     self.app.data.radarr_data.reset_delete_movie_preferences();
 
-    DeleteMovieParams {
-      id,
-      delete_movie_files,
-      add_list_exclusion,
+    if self.app.data.radarr_data.movies.has_multi_selection() {
+      let movie_ids = self
+        .app
+        .data
+        .radarr_data
+        .movies
+        .multi_selection()
+        .iter()
+        .map(|movie| movie.id)
+        .collect();
+      self.app.data.radarr_data.movies.clear_multi_selection();
+
+      RadarrEvent::BulkDeleteMovies(BulkDeleteMoviesParams {
+        movie_ids,
+        delete_files: delete_movie_files,
+        add_import_exclusion: add_list_exclusion,
+      })
+    } else {
+      RadarrEvent::DeleteMovie(DeleteMovieParams {
+        id: self.app.data.radarr_data.movies.current_selection().id,
+        delete_movie_files,
+        add_list_exclusion,
+      })
     }
   }
 }
@@ -93,8 +111,7 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for DeleteMovieHandler<'
       match self.app.data.radarr_data.selected_block.get_active_block() {
         ActiveRadarrBlock::DeleteMovieConfirmPrompt => {
           if self.app.data.radarr_data.prompt_confirm {
-            self.app.data.radarr_data.prompt_confirm_action =
-              Some(RadarrEvent::DeleteMovie(self.build_delete_movie_params()));
+            self.app.data.radarr_data.prompt_confirm_action = Some(self.build_delete_movie_event());
             self.app.should_refresh = true;
           } else {
             self.app.data.radarr_data.reset_delete_movie_preferences();
@@ -130,8 +147,7 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for DeleteMovieHandler<'
       && matches_key!(confirm, self.key)
     {
       self.app.data.radarr_data.prompt_confirm = true;
-      self.app.data.radarr_data.prompt_confirm_action =
-        Some(RadarrEvent::DeleteMovie(self.build_delete_movie_params()));
+      self.app.data.radarr_data.prompt_confirm_action = Some(self.build_delete_movie_event());
       self.app.should_refresh = true;
 
       self.app.pop_navigation_stack();
@@ -10,8 +10,9 @@ mod tests {
   use crate::handlers::KeyEventHandler;
   use crate::handlers::radarr_handlers::library::delete_movie_handler::DeleteMovieHandler;
   use crate::handlers::radarr_handlers::radarr_handler_test_utils::utils::movie;
-  use crate::models::radarr_models::DeleteMovieParams;
+  use crate::models::radarr_models::{BulkDeleteMoviesParams, DeleteMovieParams, Movie};
   use crate::models::servarr_data::radarr::radarr_data::{ActiveRadarrBlock, DELETE_MOVIE_BLOCKS};
+  use crate::network::radarr_network::RadarrEvent;
 
   mod test_handle_scroll_up_and_down {
     use pretty_assertions::assert_eq;
@@ -336,7 +337,7 @@ mod tests {
   }
 
   #[test]
-  fn test_build_delete_movie_params() {
+  fn test_build_delete_movie_event() {
     let mut app = App::test_default();
     app.data.radarr_data.movies.set_items(vec![movie()]);
     app.data.radarr_data.delete_movie_files = true;
@@ -347,15 +348,56 @@ mod tests {
       add_list_exclusion: true,
     };
 
-    let delete_movie_params = DeleteMovieHandler::new(
+    let delete_movie_event = DeleteMovieHandler::new(
       DEFAULT_KEYBINDINGS.esc.key,
       &mut app,
       ActiveRadarrBlock::DeleteMoviePrompt,
       None,
     )
-    .build_delete_movie_params();
+    .build_delete_movie_event();
 
-    assert_eq!(delete_movie_params, expected_delete_movie_params);
+    assert_eq!(
+      delete_movie_event,
+      RadarrEvent::DeleteMovie(expected_delete_movie_params)
+    );
+    assert!(!app.data.radarr_data.delete_movie_files);
+    assert!(!app.data.radarr_data.add_list_exclusion);
+  }
+
+  #[test]
+  fn test_build_delete_movie_event_with_multi_selection() {
+    let mut app = App::test_default();
+    app
+      .data
+      .radarr_data
+      .movies
+      .set_items(vec![movie(), Movie { id: 2, ..movie() }]);
+    app
+      .data
+      .radarr_data
+      .movies
+      .toggle_select_all(|movie| movie.id);
+    app.data.radarr_data.delete_movie_files = true;
+    app.data.radarr_data.add_list_exclusion = true;
+    let expected_bulk_delete_movies_params = BulkDeleteMoviesParams {
+      movie_ids: vec![1, 2],
+      delete_files: true,
+      add_import_exclusion: true,
+    };
+
+    let delete_movie_event = DeleteMovieHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveRadarrBlock::DeleteMoviePrompt,
+      None,
+    )
+    .build_delete_movie_event();
+
+    assert_eq!(
+      delete_movie_event,
+      RadarrEvent::BulkDeleteMovies(expected_bulk_delete_movies_params)
+    );
+    assert!(!app.data.radarr_data.movies.has_multi_selection());
     assert!(!app.data.radarr_data.delete_movie_files);
     assert!(!app.data.radarr_data.add_list_exclusion);
   }
@@ -16,8 +16,8 @@ mod tests {
   use crate::handlers::radarr_handlers::radarr_handler_test_utils::utils::movie;
   use crate::models::radarr_models::Movie;
   use crate::models::servarr_data::radarr::radarr_data::{
-    ADD_MOVIE_BLOCKS, ActiveRadarrBlock, DELETE_MOVIE_BLOCKS, EDIT_MOVIE_BLOCKS, LIBRARY_BLOCKS,
-    MOVIE_DETAILS_BLOCKS,
+    ADD_MOVIE_BLOCKS, ActiveRadarrBlock, BULK_EDIT_MOVIES_BLOCKS, DELETE_MOVIE_BLOCKS,
+    EDIT_MOVIE_BLOCKS, LIBRARY_BLOCKS, MOVIE_DETAILS_BLOCKS,
   };
   use crate::models::servarr_models::Language;
   use crate::test_handler_delegation;
@@ -93,9 +93,9 @@ mod tests {
 
       assert_eq!(
         app.data.radarr_data.main_tabs.get_active_route(),
-        ActiveRadarrBlock::System.into()
+        ActiveRadarrBlock::WantedMissing.into()
       );
-      assert_navigation_pushed!(app, ActiveRadarrBlock::System.into());
+      assert_navigation_pushed!(app, ActiveRadarrBlock::WantedMissing.into());
     }
 
     #[rstest]
@@ -364,6 +364,39 @@ mod tests {
       );
     }
 
+    #[test]
+    fn test_movie_edit_key_with_multi_selection_opens_bulk_edit_prompt() {
+      let mut app = App::test_default();
+      app.data.radarr_data = create_test_radarr_data();
+      app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
+      app
+        .data
+        .radarr_data
+        .movies
+        .set_items(vec![movie(), Movie { id: 2, ..movie() }]);
+      app
+        .data
+        .radarr_data
+        .movies
+        .toggle_select_all(|movie| movie.id);
+
+      LibraryHandler::new(
+        DEFAULT_KEYBINDINGS.edit.key,
+        &mut app,
+        ActiveRadarrBlock::Movies,
+        None,
+      )
+      .handle();
+
+      assert_navigation_pushed!(app, ActiveRadarrBlock::BulkEditMoviesPrompt.into());
+      assert_modal_present!(app.data.radarr_data.bulk_edit_modal);
+      assert_modal_absent!(app.data.radarr_data.edit_movie_modal);
+      assert_eq!(
+        app.data.radarr_data.selected_block.get_active_block(),
+        ActiveRadarrBlock::BulkEditMoviesSelectMonitored
+      );
+    }
+
     #[test]
     fn test_movie_edit_key_no_op_when_not_ready() {
       let mut app = App::test_default();
@@ -609,6 +642,25 @@ mod tests {
     );
   }
 
+  #[rstest]
+  fn test_delegates_bulk_edit_movies_blocks_to_bulk_edit_movies_handler(
+    #[values(
+      ActiveRadarrBlock::BulkEditMoviesPrompt,
+      ActiveRadarrBlock::BulkEditMoviesSelectApplyTags,
+      ActiveRadarrBlock::BulkEditMoviesSelectMonitored,
+      ActiveRadarrBlock::BulkEditMoviesSelectQualityProfile,
+      ActiveRadarrBlock::BulkEditMoviesSelectRootFolder,
+      ActiveRadarrBlock::BulkEditMoviesTagsInput
+    )]
+    active_radarr_block: ActiveRadarrBlock,
+  ) {
+    test_handler_delegation!(
+      LibraryHandler,
+      ActiveRadarrBlock::Movies,
+      active_radarr_block
+    );
+  }
+
   #[test]
   fn test_delegates_delete_movie_blocks_to_delete_movie_handler() {
     test_handler_delegation!(
@@ -823,6 +875,7 @@ mod tests {
     let mut library_handler_blocks = Vec::new();
     library_handler_blocks.extend(LIBRARY_BLOCKS);
     library_handler_blocks.extend(ADD_MOVIE_BLOCKS);
+    library_handler_blocks.extend(BULK_EDIT_MOVIES_BLOCKS);
     library_handler_blocks.extend(DELETE_MOVIE_BLOCKS);
     library_handler_blocks.extend(EDIT_MOVIE_BLOCKS);
     library_handler_blocks.extend(MOVIE_DETAILS_BLOCKS);
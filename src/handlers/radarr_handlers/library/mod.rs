@@ -2,6 +2,7 @@ use crate::app::App;
 use crate::event::Key;
 use crate::handlers::radarr_handlers::handle_change_tab_left_right_keys;
 use crate::handlers::radarr_handlers::library::add_movie_handler::AddMovieHandler;
+use crate::handlers::radarr_handlers::library::bulk_edit_movies_handler::BulkEditMoviesHandler;
 use crate::handlers::radarr_handlers::library::delete_movie_handler::DeleteMovieHandler;
 use crate::handlers::radarr_handlers::library::edit_movie_handler::EditMovieHandler;
 use crate::handlers::radarr_handlers::library::movie_details_handler::MovieDetailsHandler;
@@ -10,14 +11,17 @@ use crate::handlers::{KeyEventHandler, handle_clear_errors, handle_prompt_toggle
 use crate::handlers::table_handler::{TableHandlingConfig, handle_table};
 use crate::matches_key;
 use crate::models::radarr_models::Movie;
+use crate::models::servarr_data::modals::BulkEditModal;
 use crate::models::servarr_data::radarr::radarr_data::{
-  ActiveRadarrBlock, DELETE_MOVIE_SELECTION_BLOCKS, EDIT_MOVIE_SELECTION_BLOCKS, LIBRARY_BLOCKS,
+  ActiveRadarrBlock, BULK_EDIT_MOVIES_SELECTION_BLOCKS, DELETE_MOVIE_SELECTION_BLOCKS,
+  EDIT_MOVIE_SELECTION_BLOCKS, LIBRARY_BLOCKS,
 };
 use crate::models::stateful_table::SortOption;
 use crate::models::{BlockSelectionState, HorizontallyScrollableText, Route};
 use crate::network::radarr_network::RadarrEvent;
 
 mod add_movie_handler;
+mod bulk_edit_movies_handler;
 mod delete_movie_handler;
 mod edit_movie_handler;
 mod movie_details_handler;
@@ -49,7 +53,8 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for LibraryHandler<'a, '
       .search_field_fn(|movie| &movie.title.text)
       .filtering_block(ActiveRadarrBlock::FilterMovies.into())
       .filter_error_block(ActiveRadarrBlock::FilterMoviesError.into())
-      .filter_field_fn(|movie| &movie.title.text);
+      .filter_field_fn(|movie| &movie.title.text)
+      .multi_select_id_fn(|movie| movie.id);
 
     if !handle_table(
       self,
@@ -60,6 +65,10 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for LibraryHandler<'a, '
         _ if AddMovieHandler::accepts(self.active_radarr_block) => {
           AddMovieHandler::new(self.key, self.app, self.active_radarr_block, self.context).handle();
         }
+        _ if BulkEditMoviesHandler::accepts(self.active_radarr_block) => {
+          BulkEditMoviesHandler::new(self.key, self.app, self.active_radarr_block, self.context)
+            .handle();
+        }
         _ if DeleteMovieHandler::accepts(self.active_radarr_block) => {
           DeleteMovieHandler::new(self.key, self.app, self.active_radarr_block, self.context)
             .handle();
@@ -79,6 +88,7 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for LibraryHandler<'a, '
 
   fn accepts(active_block: ActiveRadarrBlock) -> bool {
     AddMovieHandler::accepts(active_block)
+      || BulkEditMoviesHandler::accepts(active_block)
       || DeleteMovieHandler::accepts(active_block)
       || EditMovieHandler::accepts(active_block)
       || MovieDetailsHandler::accepts(active_block)
@@ -169,6 +179,15 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for LibraryHandler<'a, '
     let key = self.key;
     match self.active_radarr_block {
       ActiveRadarrBlock::Movies => match self.key {
+        _ if matches_key!(edit, key) && self.app.data.radarr_data.movies.has_multi_selection() => {
+          self
+            .app
+            .push_navigation_stack(ActiveRadarrBlock::BulkEditMoviesPrompt.into());
+          self.app.data.radarr_data.bulk_edit_modal =
+            Some(BulkEditModal::from(&self.app.data.radarr_data));
+          self.app.data.radarr_data.selected_block =
+            BlockSelectionState::new(BULK_EDIT_MOVIES_SELECTION_BLOCKS);
+        }
         _ if matches_key!(edit, key) => {
           self.app.push_navigation_stack(
             (
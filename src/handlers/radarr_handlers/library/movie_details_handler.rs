@@ -452,5 +452,9 @@ fn releases_sorting_options() -> Vec<SortOption<RadarrRelease>> {
       name: "Quality",
       cmp_fn: Some(|a, b| a.quality.cmp(&b.quality)),
     },
+    SortOption {
+      name: "CF Score",
+      cmp_fn: Some(|a, b| a.custom_format_score.cmp(&b.custom_format_score)),
+    },
   ]
 }
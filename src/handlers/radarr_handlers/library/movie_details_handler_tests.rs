@@ -1018,6 +1018,21 @@ mod tests {
     assert_str_eq!(sort_option.name, "Quality");
   }
 
+  #[test]
+  fn test_releases_sorting_options_custom_format_score() {
+    let expected_cmp_fn: fn(&RadarrRelease, &RadarrRelease) -> Ordering =
+      |a, b| a.custom_format_score.cmp(&b.custom_format_score);
+    let mut expected_releases_vec = release_vec();
+    expected_releases_vec.sort_by(expected_cmp_fn);
+
+    let sort_option = releases_sorting_options()[9].clone();
+    let mut sorted_releases_vec = release_vec();
+    sorted_releases_vec.sort_by(sort_option.cmp_fn.unwrap());
+
+    assert_eq!(sorted_releases_vec, expected_releases_vec);
+    assert_str_eq!(sort_option.name, "CF Score");
+  }
+
   #[test]
   fn test_movie_details_handler_accepts() {
     ActiveRadarrBlock::iter().for_each(|active_radarr_block| {
@@ -1216,6 +1231,7 @@ mod tests {
           name: "Quality A".to_owned(),
         },
       },
+      custom_format_score: 10,
       ..RadarrRelease::default()
     };
     let release_b = RadarrRelease {
@@ -1235,6 +1251,7 @@ mod tests {
           name: "Quality B".to_owned(),
         },
       },
+      custom_format_score: -5,
       ..RadarrRelease::default()
     };
     let release_c = RadarrRelease {
@@ -1251,6 +1268,7 @@ mod tests {
           name: "Quality C".to_owned(),
         },
       },
+      custom_format_score: 0,
       ..RadarrRelease::default()
     };
 
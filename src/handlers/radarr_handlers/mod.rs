@@ -1,24 +1,34 @@
 use crate::handlers::KeyEventHandler;
 use crate::handlers::radarr_handlers::blocklist::BlocklistHandler;
+use crate::handlers::radarr_handlers::calendar::CalendarHandler;
 use crate::handlers::radarr_handlers::collections::CollectionsHandler;
+use crate::handlers::radarr_handlers::custom_formats::CustomFormatsHandler;
+use crate::handlers::radarr_handlers::download_clients::DownloadClientsHandler;
 use crate::handlers::radarr_handlers::downloads::DownloadsHandler;
 use crate::handlers::radarr_handlers::history::HistoryHandler;
 use crate::handlers::radarr_handlers::indexers::IndexersHandler;
 use crate::handlers::radarr_handlers::library::LibraryHandler;
+use crate::handlers::radarr_handlers::quality_profiles::QualityProfilesHandler;
 use crate::handlers::radarr_handlers::root_folders::RootFoldersHandler;
 use crate::handlers::radarr_handlers::system::SystemHandler;
+use crate::handlers::radarr_handlers::wanted::WantedHandler;
 use crate::models::Route;
 use crate::models::servarr_data::radarr::radarr_data::ActiveRadarrBlock;
 use crate::{App, Key, matches_key};
 
 mod blocklist;
+mod calendar;
 mod collections;
+mod custom_formats;
+mod download_clients;
 mod downloads;
 mod history;
 mod indexers;
 mod library;
+mod quality_profiles;
 mod root_folders;
 mod system;
+mod wanted;
 
 #[cfg(test)]
 #[path = "radarr_handler_tests.rs"]
@@ -47,6 +57,18 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for RadarrHandler<'a, 'b
       _ if IndexersHandler::accepts(self.active_radarr_block) => {
         IndexersHandler::new(self.key, self.app, self.active_radarr_block, self.context).handle()
       }
+      _ if DownloadClientsHandler::accepts(self.active_radarr_block) => {
+        DownloadClientsHandler::new(self.key, self.app, self.active_radarr_block, self.context)
+          .handle()
+      }
+      _ if QualityProfilesHandler::accepts(self.active_radarr_block) => {
+        QualityProfilesHandler::new(self.key, self.app, self.active_radarr_block, self.context)
+          .handle()
+      }
+      _ if CustomFormatsHandler::accepts(self.active_radarr_block) => {
+        CustomFormatsHandler::new(self.key, self.app, self.active_radarr_block, self.context)
+          .handle()
+      }
       _ if SystemHandler::accepts(self.active_radarr_block) => {
         SystemHandler::new(self.key, self.app, self.active_radarr_block, self.context).handle()
       }
@@ -62,6 +84,12 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for RadarrHandler<'a, 'b
       _ if BlocklistHandler::accepts(self.active_radarr_block) => {
         BlocklistHandler::new(self.key, self.app, self.active_radarr_block, self.context).handle()
       }
+      _ if CalendarHandler::accepts(self.active_radarr_block) => {
+        CalendarHandler::new(self.key, self.app, self.active_radarr_block, self.context).handle()
+      }
+      _ if WantedHandler::accepts(self.active_radarr_block) => {
+        WantedHandler::new(self.key, self.app, self.active_radarr_block, self.context).handle()
+      }
       _ => self.handle_key_event(),
     }
   }
@@ -336,6 +336,9 @@ pub(in crate::handlers::radarr_handlers) mod utils {
       tmdb_id: 1234,
       quality_profile_id: 2222,
       minimum_availability: MinimumAvailability::Announced,
+      in_cinemas: None,
+      digital_release: None,
+      physical_release: None,
       certification: Some("R".to_owned()),
       tags: vec![Number::from(1)],
       ratings: ratings_list(),
@@ -382,6 +385,8 @@ pub(in crate::handlers::radarr_handlers) mod utils {
       leechers: Some(Number::from(1)),
       languages: Some(vec![language()]),
       quality: quality_wrapper(),
+      custom_formats: Vec::new(),
+      custom_format_score: 0,
     }
   }
 
@@ -410,6 +415,8 @@ pub(in crate::handlers::radarr_handlers) mod utils {
       output_path: Some(HorizontallyScrollableText::from("/nfs/movies/Test")),
       indexer: "kickass torrents".to_owned(),
       download_client: "transmission".to_owned(),
+      download_id: Some("abc123".to_owned()),
+      estimated_completion_time: None,
     }
   }
 
@@ -443,14 +450,17 @@ pub(in crate::handlers::radarr_handlers) mod utils {
         IndexerField {
           name: Some("baseUrl".to_owned()),
           value: Some(json!("https://test.com")),
+          ..IndexerField::default()
         },
         IndexerField {
           name: Some("apiKey".to_owned()),
           value: Some(json!("")),
+          ..IndexerField::default()
         },
         IndexerField {
           name: Some("seedCriteria.seedRatio".to_owned()),
           value: Some(json!("1.2")),
+          ..IndexerField::default()
         },
       ]),
     }
@@ -13,14 +13,23 @@ mod tests {
   use crate::test_handler_delegation;
 
   #[rstest]
-  #[case(0, ActiveRadarrBlock::System, ActiveRadarrBlock::Collections)]
+  #[case(0, ActiveRadarrBlock::WantedMissing, ActiveRadarrBlock::Collections)]
   #[case(1, ActiveRadarrBlock::Movies, ActiveRadarrBlock::Downloads)]
   #[case(2, ActiveRadarrBlock::Collections, ActiveRadarrBlock::Blocklist)]
   #[case(3, ActiveRadarrBlock::Downloads, ActiveRadarrBlock::History)]
   #[case(4, ActiveRadarrBlock::Blocklist, ActiveRadarrBlock::RootFolders)]
   #[case(5, ActiveRadarrBlock::History, ActiveRadarrBlock::Indexers)]
-  #[case(6, ActiveRadarrBlock::RootFolders, ActiveRadarrBlock::System)]
-  #[case(7, ActiveRadarrBlock::Indexers, ActiveRadarrBlock::Movies)]
+  #[case(6, ActiveRadarrBlock::RootFolders, ActiveRadarrBlock::DownloadClients)]
+  #[case(7, ActiveRadarrBlock::Indexers, ActiveRadarrBlock::QualityProfiles)]
+  #[case(
+    8,
+    ActiveRadarrBlock::DownloadClients,
+    ActiveRadarrBlock::CustomFormats
+  )]
+  #[case(9, ActiveRadarrBlock::QualityProfiles, ActiveRadarrBlock::System)]
+  #[case(10, ActiveRadarrBlock::CustomFormats, ActiveRadarrBlock::Calendar)]
+  #[case(11, ActiveRadarrBlock::System, ActiveRadarrBlock::WantedMissing)]
+  #[case(12, ActiveRadarrBlock::Calendar, ActiveRadarrBlock::Movies)]
   fn test_radarr_handler_change_tab_left_right_keys(
     #[case] index: usize,
     #[case] left_block: ActiveRadarrBlock,
@@ -49,14 +58,23 @@ mod tests {
   }
 
   #[rstest]
-  #[case(0, ActiveRadarrBlock::System, ActiveRadarrBlock::Collections)]
+  #[case(0, ActiveRadarrBlock::WantedMissing, ActiveRadarrBlock::Collections)]
   #[case(1, ActiveRadarrBlock::Movies, ActiveRadarrBlock::Downloads)]
   #[case(2, ActiveRadarrBlock::Collections, ActiveRadarrBlock::Blocklist)]
   #[case(3, ActiveRadarrBlock::Downloads, ActiveRadarrBlock::History)]
   #[case(4, ActiveRadarrBlock::Blocklist, ActiveRadarrBlock::RootFolders)]
   #[case(5, ActiveRadarrBlock::History, ActiveRadarrBlock::Indexers)]
-  #[case(6, ActiveRadarrBlock::RootFolders, ActiveRadarrBlock::System)]
-  #[case(7, ActiveRadarrBlock::Indexers, ActiveRadarrBlock::Movies)]
+  #[case(6, ActiveRadarrBlock::RootFolders, ActiveRadarrBlock::DownloadClients)]
+  #[case(7, ActiveRadarrBlock::Indexers, ActiveRadarrBlock::QualityProfiles)]
+  #[case(
+    8,
+    ActiveRadarrBlock::DownloadClients,
+    ActiveRadarrBlock::CustomFormats
+  )]
+  #[case(9, ActiveRadarrBlock::QualityProfiles, ActiveRadarrBlock::System)]
+  #[case(10, ActiveRadarrBlock::CustomFormats, ActiveRadarrBlock::Calendar)]
+  #[case(11, ActiveRadarrBlock::System, ActiveRadarrBlock::WantedMissing)]
+  #[case(12, ActiveRadarrBlock::Calendar, ActiveRadarrBlock::Movies)]
   fn test_radarr_handler_change_tab_left_right_keys_alt_navigation(
     #[case] index: usize,
     #[case] left_block: ActiveRadarrBlock,
@@ -93,7 +111,12 @@ mod tests {
   #[case(4, ActiveRadarrBlock::History)]
   #[case(5, ActiveRadarrBlock::RootFolders)]
   #[case(6, ActiveRadarrBlock::Indexers)]
-  #[case(7, ActiveRadarrBlock::System)]
+  #[case(7, ActiveRadarrBlock::DownloadClients)]
+  #[case(8, ActiveRadarrBlock::QualityProfiles)]
+  #[case(9, ActiveRadarrBlock::CustomFormats)]
+  #[case(10, ActiveRadarrBlock::System)]
+  #[case(11, ActiveRadarrBlock::Calendar)]
+  #[case(12, ActiveRadarrBlock::WantedMissing)]
   fn test_radarr_handler_change_tab_left_right_keys_alt_navigation_no_op_when_ignoring_quit_key(
     #[case] index: usize,
     #[case] block: ActiveRadarrBlock,
@@ -234,6 +257,60 @@ mod tests {
     );
   }
 
+  #[rstest]
+  fn test_delegates_download_clients_blocks_to_download_clients_handler(
+    #[values(
+      ActiveRadarrBlock::DownloadClients,
+      ActiveRadarrBlock::DeleteDownloadClientPrompt,
+      ActiveRadarrBlock::TestDownloadClient,
+      ActiveRadarrBlock::TestAllDownloadClients,
+      ActiveRadarrBlock::AddDownloadClientSelectImplementation,
+      ActiveRadarrBlock::DownloadClientPrompt,
+      ActiveRadarrBlock::DownloadClientFieldInput
+    )]
+    active_radarr_block: ActiveRadarrBlock,
+  ) {
+    test_handler_delegation!(
+      RadarrHandler,
+      ActiveRadarrBlock::DownloadClients,
+      active_radarr_block
+    );
+  }
+
+  #[rstest]
+  fn test_delegates_quality_profiles_blocks_to_quality_profiles_handler(
+    #[values(
+      ActiveRadarrBlock::QualityProfiles,
+      ActiveRadarrBlock::EditQualityProfile
+    )]
+    active_radarr_block: ActiveRadarrBlock,
+  ) {
+    test_handler_delegation!(
+      RadarrHandler,
+      ActiveRadarrBlock::QualityProfiles,
+      active_radarr_block
+    );
+  }
+
+  #[rstest]
+  fn test_delegates_custom_formats_blocks_to_custom_formats_handler(
+    #[values(
+      ActiveRadarrBlock::CustomFormats,
+      ActiveRadarrBlock::CustomFormatDetails,
+      ActiveRadarrBlock::DeleteCustomFormatPrompt,
+      ActiveRadarrBlock::ExportCustomFormatPrompt,
+      ActiveRadarrBlock::ImportCustomFormatPrompt,
+      ActiveRadarrBlock::EditCustomFormatPrompt
+    )]
+    active_radarr_block: ActiveRadarrBlock,
+  ) {
+    test_handler_delegation!(
+      RadarrHandler,
+      ActiveRadarrBlock::CustomFormats,
+      active_radarr_block
+    );
+  }
+
   #[rstest]
   fn test_delegates_downloads_blocks_to_downloads_handler(
     #[values(
@@ -255,7 +332,9 @@ mod tests {
     #[values(
       ActiveRadarrBlock::RootFolders,
       ActiveRadarrBlock::AddRootFolderPrompt,
-      ActiveRadarrBlock::DeleteRootFolderPrompt
+      ActiveRadarrBlock::DeleteRootFolderPrompt,
+      ActiveRadarrBlock::MediaManagementSettingsPrompt,
+      ActiveRadarrBlock::MediaManagementFieldInput
     )]
     active_radarr_block: ActiveRadarrBlock,
   ) {
@@ -304,6 +383,37 @@ mod tests {
     );
   }
 
+  #[rstest]
+  fn test_delegates_calendar_blocks_to_calendar_handler(
+    #[values(ActiveRadarrBlock::Calendar, ActiveRadarrBlock::CalendarAgenda)]
+    active_radarr_block: ActiveRadarrBlock,
+  ) {
+    test_handler_delegation!(RadarrHandler, active_radarr_block, active_radarr_block);
+  }
+
+  #[rstest]
+  fn test_delegates_wanted_table_blocks_to_wanted_handler(
+    #[values(ActiveRadarrBlock::WantedMissing, ActiveRadarrBlock::WantedCutoffUnmet)]
+    active_radarr_block: ActiveRadarrBlock,
+  ) {
+    test_handler_delegation!(RadarrHandler, active_radarr_block, active_radarr_block);
+  }
+
+  #[rstest]
+  fn test_delegates_wanted_prompt_blocks_to_wanted_handler(
+    #[values(
+      ActiveRadarrBlock::WantedSearchAllPrompt,
+      ActiveRadarrBlock::WantedSearchSelectedPrompt
+    )]
+    active_radarr_block: ActiveRadarrBlock,
+  ) {
+    test_handler_delegation!(
+      RadarrHandler,
+      ActiveRadarrBlock::WantedMissing,
+      active_radarr_block
+    );
+  }
+
   #[test]
   fn test_radarr_handler_accepts() {
     ActiveRadarrBlock::iter().for_each(|active_radarr_block| {
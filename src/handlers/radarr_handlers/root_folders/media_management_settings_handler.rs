@@ -0,0 +1,193 @@
+use crate::app::App;
+use crate::event::Key;
+use crate::handlers::KeyEventHandler;
+use crate::models::servarr_data::modals::{MediaManagementModal, SchemaFormFieldKind};
+use crate::models::servarr_data::radarr::radarr_data::{
+  ActiveRadarrBlock, MEDIA_MANAGEMENT_SETTINGS_BLOCKS,
+};
+use crate::models::{Route, Scrollable};
+use crate::network::radarr_network::RadarrEvent;
+use crate::{handle_text_box_keys, handle_text_box_left_right_keys, matches_key};
+
+#[cfg(test)]
+#[path = "media_management_settings_handler_tests.rs"]
+mod media_management_settings_handler_tests;
+
+pub(super) struct MediaManagementSettingsHandler<'a, 'b> {
+  key: Key,
+  app: &'a mut App<'b>,
+  active_radarr_block: ActiveRadarrBlock,
+  _context: Option<ActiveRadarrBlock>,
+}
+
+impl MediaManagementSettingsHandler<'_, '_> {
+  fn media_management_modal(&mut self) -> &mut MediaManagementModal {
+    self
+      .app
+      .data
+      .radarr_data
+      .media_management_modal
+      .as_mut()
+      .expect("Media Management Modal is None")
+  }
+}
+
+impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for MediaManagementSettingsHandler<'a, 'b> {
+  fn accepts(active_block: ActiveRadarrBlock) -> bool {
+    MEDIA_MANAGEMENT_SETTINGS_BLOCKS.contains(&active_block)
+  }
+
+  fn ignore_special_keys(&self) -> bool {
+    self.app.ignore_special_keys_for_textbox_input
+  }
+
+  fn new(
+    key: Key,
+    app: &'a mut App<'b>,
+    active_block: ActiveRadarrBlock,
+    _context: Option<ActiveRadarrBlock>,
+  ) -> MediaManagementSettingsHandler<'a, 'b> {
+    MediaManagementSettingsHandler {
+      key,
+      app,
+      active_radarr_block: active_block,
+      _context,
+    }
+  }
+
+  fn get_key(&self) -> Key {
+    self.key
+  }
+
+  fn is_ready(&self) -> bool {
+    !self.app.is_loading && self.app.data.radarr_data.media_management_modal.is_some()
+  }
+
+  fn handle_scroll_up(&mut self) {
+    if self.active_radarr_block == ActiveRadarrBlock::MediaManagementSettingsPrompt {
+      self.media_management_modal().fields.scroll_up();
+    }
+  }
+
+  fn handle_scroll_down(&mut self) {
+    if self.active_radarr_block == ActiveRadarrBlock::MediaManagementSettingsPrompt {
+      self.media_management_modal().fields.scroll_down();
+    }
+  }
+
+  fn handle_home(&mut self) {
+    match self.active_radarr_block {
+      ActiveRadarrBlock::MediaManagementSettingsPrompt => {
+        self.media_management_modal().fields.scroll_to_top()
+      }
+      ActiveRadarrBlock::MediaManagementFieldInput => self
+        .media_management_modal()
+        .current_field_mut()
+        .value
+        .scroll_home(),
+      _ => (),
+    }
+  }
+
+  fn handle_end(&mut self) {
+    match self.active_radarr_block {
+      ActiveRadarrBlock::MediaManagementSettingsPrompt => {
+        self.media_management_modal().fields.scroll_to_bottom()
+      }
+      ActiveRadarrBlock::MediaManagementFieldInput => self
+        .media_management_modal()
+        .current_field_mut()
+        .value
+        .reset_offset(),
+      _ => (),
+    }
+  }
+
+  fn handle_delete(&mut self) {}
+
+  fn handle_left_right_action(&mut self) {
+    if self.active_radarr_block == ActiveRadarrBlock::MediaManagementFieldInput {
+      handle_text_box_left_right_keys!(
+        self,
+        self.key,
+        self.media_management_modal().current_field_mut().value
+      );
+    }
+  }
+
+  fn handle_submit(&mut self) {
+    match self.active_radarr_block {
+      ActiveRadarrBlock::MediaManagementSettingsPrompt => {
+        let media_management_modal = self.media_management_modal();
+        if media_management_modal.current_field_mut().kind == SchemaFormFieldKind::Checkbox {
+          media_management_modal.toggle_current_field();
+          self.app.should_refresh = true;
+        } else {
+          self
+            .app
+            .push_navigation_stack(ActiveRadarrBlock::MediaManagementFieldInput.into());
+          self.app.ignore_special_keys_for_textbox_input = true;
+        }
+      }
+      ActiveRadarrBlock::MediaManagementFieldInput => {
+        self.app.pop_navigation_stack();
+        self.app.ignore_special_keys_for_textbox_input = false;
+      }
+      _ => (),
+    }
+  }
+
+  fn handle_esc(&mut self) {
+    match self.active_radarr_block {
+      ActiveRadarrBlock::MediaManagementSettingsPrompt => {
+        self.app.pop_navigation_stack();
+        self.app.data.radarr_data.prompt_confirm = false;
+        self.app.data.radarr_data.media_management_modal = None;
+        self.app.data.radarr_data.naming_examples = None;
+      }
+      ActiveRadarrBlock::MediaManagementFieldInput => {
+        self.app.pop_navigation_stack();
+        self.app.ignore_special_keys_for_textbox_input = false;
+      }
+      _ => self.app.pop_navigation_stack(),
+    }
+  }
+
+  fn handle_char_key_event(&mut self) {
+    match self.active_radarr_block {
+      ActiveRadarrBlock::MediaManagementFieldInput => {
+        handle_text_box_keys!(
+          self,
+          self.key,
+          self.media_management_modal().current_field_mut().value
+        );
+      }
+      ActiveRadarrBlock::MediaManagementSettingsPrompt if matches_key!(confirm, self.key) => {
+        let media_management_modal = self
+          .app
+          .data
+          .radarr_data
+          .media_management_modal
+          .take()
+          .expect("Media Management Modal is None");
+        self.app.data.radarr_data.naming_examples = None;
+        self.app.data.radarr_data.prompt_confirm = true;
+        self.app.data.radarr_data.prompt_confirm_action = Some(
+          RadarrEvent::EditMediaManagementSettings(media_management_modal.settings()),
+        );
+        self.app.should_refresh = true;
+
+        self.app.pop_navigation_stack();
+      }
+      _ => (),
+    }
+  }
+
+  fn app_mut(&mut self) -> &mut App<'b> {
+    self.app
+  }
+
+  fn current_route(&self) -> Route {
+    self.app.get_current_route()
+  }
+}
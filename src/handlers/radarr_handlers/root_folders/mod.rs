@@ -1,6 +1,7 @@
 use crate::app::App;
 use crate::event::Key;
 use crate::handlers::radarr_handlers::handle_change_tab_left_right_keys;
+use crate::handlers::radarr_handlers::root_folders::media_management_settings_handler::MediaManagementSettingsHandler;
 use crate::handlers::table_handler::{TableHandlingConfig, handle_table};
 use crate::handlers::{KeyEventHandler, handle_clear_errors, handle_prompt_toggle};
 use crate::models::servarr_data::radarr::radarr_data::{ActiveRadarrBlock, ROOT_FOLDERS_BLOCKS};
@@ -9,6 +10,8 @@ use crate::models::{HorizontallyScrollableText, Route};
 use crate::network::radarr_network::RadarrEvent;
 use crate::{handle_text_box_keys, handle_text_box_left_right_keys, matches_key};
 
+mod media_management_settings_handler;
+
 #[cfg(test)]
 #[path = "root_folders_handler_tests.rs"]
 mod root_folders_handler_tests;
@@ -17,7 +20,7 @@ pub(super) struct RootFoldersHandler<'a, 'b> {
   key: Key,
   app: &'a mut App<'b>,
   active_radarr_block: ActiveRadarrBlock,
-  _context: Option<ActiveRadarrBlock>,
+  context: Option<ActiveRadarrBlock>,
 }
 
 impl RootFoldersHandler<'_, '_> {
@@ -56,12 +59,24 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for RootFoldersHandler<'
       |app| &mut app.data.radarr_data.root_folders,
       root_folder_table_handling_config,
     ) {
-      self.handle_key_event();
+      match self.active_radarr_block {
+        _ if MediaManagementSettingsHandler::accepts(self.active_radarr_block) => {
+          MediaManagementSettingsHandler::new(
+            self.key,
+            self.app,
+            self.active_radarr_block,
+            self.context,
+          )
+          .handle()
+        }
+        _ => self.handle_key_event(),
+      }
     }
   }
 
   fn accepts(active_block: ActiveRadarrBlock) -> bool {
-    ROOT_FOLDERS_BLOCKS.contains(&active_block)
+    MediaManagementSettingsHandler::accepts(active_block)
+      || ROOT_FOLDERS_BLOCKS.contains(&active_block)
   }
 
   fn ignore_special_keys(&self) -> bool {
@@ -72,13 +87,13 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for RootFoldersHandler<'
     key: Key,
     app: &'a mut App<'b>,
     active_block: ActiveRadarrBlock,
-    _context: Option<ActiveRadarrBlock>,
+    context: Option<ActiveRadarrBlock>,
   ) -> RootFoldersHandler<'a, 'b> {
     RootFoldersHandler {
       key,
       app,
       active_radarr_block: active_block,
-      _context,
+      context,
     }
   }
 
@@ -205,6 +220,11 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for RootFoldersHandler<'
           self.app.data.radarr_data.edit_root_folder = Some(HorizontallyScrollableText::default());
           self.app.ignore_special_keys_for_textbox_input = true;
         }
+        _ if matches_key!(settings, key) => {
+          self
+            .app
+            .push_navigation_stack(ActiveRadarrBlock::MediaManagementSettingsPrompt.into());
+        }
         _ => (),
       },
       ActiveRadarrBlock::AddRootFolderPrompt => {
@@ -14,8 +14,11 @@ mod tests {
   use crate::handlers::radarr_handlers::radarr_handler_test_utils::utils::root_folder;
   use crate::handlers::radarr_handlers::root_folders::RootFoldersHandler;
   use crate::models::HorizontallyScrollableText;
-  use crate::models::servarr_data::radarr::radarr_data::{ActiveRadarrBlock, ROOT_FOLDERS_BLOCKS};
+  use crate::models::servarr_data::radarr::radarr_data::{
+    ActiveRadarrBlock, MEDIA_MANAGEMENT_SETTINGS_BLOCKS, ROOT_FOLDERS_BLOCKS,
+  };
   use crate::models::servarr_models::{AddRootFolderBody, RootFolder};
+  use crate::test_handler_delegation;
 
   mod test_handle_home_end {
     use pretty_assertions::assert_eq;
@@ -532,6 +535,51 @@ mod tests {
       assert!(!app.should_refresh);
     }
 
+    #[test]
+    fn test_media_management_settings_key() {
+      let mut app = App::test_default();
+      app
+        .data
+        .radarr_data
+        .root_folders
+        .set_items(vec![RootFolder::default()]);
+
+      RootFoldersHandler::new(
+        DEFAULT_KEYBINDINGS.settings.key,
+        &mut app,
+        ActiveRadarrBlock::RootFolders,
+        None,
+      )
+      .handle();
+
+      assert_navigation_pushed!(app, ActiveRadarrBlock::MediaManagementSettingsPrompt.into());
+    }
+
+    #[test]
+    fn test_media_management_settings_key_no_op_when_not_ready() {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.push_navigation_stack(ActiveRadarrBlock::RootFolders.into());
+      app
+        .data
+        .radarr_data
+        .root_folders
+        .set_items(vec![RootFolder::default()]);
+
+      RootFoldersHandler::new(
+        DEFAULT_KEYBINDINGS.settings.key,
+        &mut app,
+        ActiveRadarrBlock::RootFolders,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveRadarrBlock::RootFolders.into()
+      );
+    }
+
     #[test]
     fn test_add_root_folder_prompt_backspace_key() {
       let mut app = App::test_default();
@@ -608,10 +656,29 @@ mod tests {
     }
   }
 
+  #[rstest]
+  fn test_delegates_media_management_settings_blocks_to_media_management_settings_handler(
+    #[values(
+      ActiveRadarrBlock::MediaManagementSettingsPrompt,
+      ActiveRadarrBlock::MediaManagementFieldInput
+    )]
+    active_radarr_block: ActiveRadarrBlock,
+  ) {
+    test_handler_delegation!(
+      RootFoldersHandler,
+      ActiveRadarrBlock::RootFolders,
+      active_radarr_block
+    );
+  }
+
   #[test]
   fn test_root_folders_handler_accepts() {
+    let mut root_folders_blocks = Vec::new();
+    root_folders_blocks.extend(ROOT_FOLDERS_BLOCKS);
+    root_folders_blocks.extend(MEDIA_MANAGEMENT_SETTINGS_BLOCKS);
+
     ActiveRadarrBlock::iter().for_each(|active_radarr_block| {
-      if ROOT_FOLDERS_BLOCKS.contains(&active_radarr_block) {
+      if root_folders_blocks.contains(&active_radarr_block) {
         assert!(RootFoldersHandler::accepts(active_radarr_block));
       } else {
         assert!(!RootFoldersHandler::accepts(active_radarr_block));
@@ -93,6 +93,11 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for SystemHandler<'a, 'b
         _ if matches_key!(refresh, key) => {
           self.app.should_refresh = true;
         }
+        _ if matches_key!(backups, key) => {
+          self
+            .app
+            .push_navigation_stack(ActiveRadarrBlock::SystemBackups.into());
+        }
         _ if matches_key!(events, key) => {
           self
             .app
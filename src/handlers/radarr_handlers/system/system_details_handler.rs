@@ -20,6 +20,10 @@ pub(super) struct SystemDetailsHandler<'a, 'b> {
 }
 
 impl SystemDetailsHandler<'_, '_> {
+  fn extract_backup_id(&self) -> i64 {
+    self.app.data.radarr_data.backups.current_selection().id
+  }
+
   fn extract_task_name(&self) -> RadarrTaskName {
     self
       .app
@@ -62,6 +66,7 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for SystemDetailsHandler
     !self.app.is_loading
       && (!self.app.data.radarr_data.log_details.is_empty()
         || !self.app.data.radarr_data.tasks.is_empty()
+        || !self.app.data.radarr_data.backups.is_empty()
         || !self.app.data.radarr_data.updates.is_empty())
   }
 
@@ -69,6 +74,7 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for SystemDetailsHandler
     match self.active_radarr_block {
       ActiveRadarrBlock::SystemLogs => self.app.data.radarr_data.log_details.scroll_up(),
       ActiveRadarrBlock::SystemTasks => self.app.data.radarr_data.tasks.scroll_up(),
+      ActiveRadarrBlock::SystemBackups => self.app.data.radarr_data.backups.scroll_up(),
       ActiveRadarrBlock::SystemUpdates => self.app.data.radarr_data.updates.scroll_up(),
       ActiveRadarrBlock::SystemQueuedEvents => self.app.data.radarr_data.queued_events.scroll_up(),
       _ => (),
@@ -79,6 +85,7 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for SystemDetailsHandler
     match self.active_radarr_block {
       ActiveRadarrBlock::SystemLogs => self.app.data.radarr_data.log_details.scroll_down(),
       ActiveRadarrBlock::SystemTasks => self.app.data.radarr_data.tasks.scroll_down(),
+      ActiveRadarrBlock::SystemBackups => self.app.data.radarr_data.backups.scroll_down(),
       ActiveRadarrBlock::SystemUpdates => self.app.data.radarr_data.updates.scroll_down(),
       ActiveRadarrBlock::SystemQueuedEvents => {
         self.app.data.radarr_data.queued_events.scroll_down()
@@ -91,6 +98,7 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for SystemDetailsHandler
     match self.active_radarr_block {
       ActiveRadarrBlock::SystemLogs => self.app.data.radarr_data.log_details.scroll_to_top(),
       ActiveRadarrBlock::SystemTasks => self.app.data.radarr_data.tasks.scroll_to_top(),
+      ActiveRadarrBlock::SystemBackups => self.app.data.radarr_data.backups.scroll_to_top(),
       ActiveRadarrBlock::SystemUpdates => self.app.data.radarr_data.updates.scroll_to_top(),
       ActiveRadarrBlock::SystemQueuedEvents => {
         self.app.data.radarr_data.queued_events.scroll_to_top()
@@ -103,6 +111,7 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for SystemDetailsHandler
     match self.active_radarr_block {
       ActiveRadarrBlock::SystemLogs => self.app.data.radarr_data.log_details.scroll_to_bottom(),
       ActiveRadarrBlock::SystemTasks => self.app.data.radarr_data.tasks.scroll_to_bottom(),
+      ActiveRadarrBlock::SystemBackups => self.app.data.radarr_data.backups.scroll_to_bottom(),
       ActiveRadarrBlock::SystemUpdates => self.app.data.radarr_data.updates.scroll_to_bottom(),
       ActiveRadarrBlock::SystemQueuedEvents => {
         self.app.data.radarr_data.queued_events.scroll_to_bottom()
@@ -111,7 +120,15 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for SystemDetailsHandler
     }
   }
 
-  fn handle_delete(&mut self) {}
+  fn handle_delete(&mut self) {
+    if self.active_radarr_block == ActiveRadarrBlock::SystemBackups
+      && !self.app.data.radarr_data.backups.is_empty()
+    {
+      self
+        .app
+        .push_navigation_stack(ActiveRadarrBlock::DeleteBackupPrompt.into());
+    }
+  }
 
   fn handle_left_right_action(&mut self) {
     let key = self.key;
@@ -140,13 +157,23 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for SystemDetailsHandler
         }
         _ => (),
       },
-      ActiveRadarrBlock::SystemTaskStartConfirmPrompt => handle_prompt_toggle(self.app, self.key),
+      ActiveRadarrBlock::SystemTaskStartConfirmPrompt | ActiveRadarrBlock::DeleteBackupPrompt => {
+        handle_prompt_toggle(self.app, self.key)
+      }
       _ => (),
     }
   }
 
   fn handle_submit(&mut self) {
     match self.active_radarr_block {
+      ActiveRadarrBlock::DeleteBackupPrompt => {
+        if self.app.data.radarr_data.prompt_confirm {
+          self.app.data.radarr_data.prompt_confirm_action =
+            Some(RadarrEvent::DeleteBackup(self.extract_backup_id()));
+        }
+
+        self.app.pop_navigation_stack();
+      }
       ActiveRadarrBlock::SystemTasks => {
         self
           .app
@@ -170,10 +197,11 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for SystemDetailsHandler
         self.app.data.radarr_data.log_details = StatefulList::default();
         self.app.pop_navigation_stack()
       }
-      ActiveRadarrBlock::SystemQueuedEvents
+      ActiveRadarrBlock::SystemBackups
+      | ActiveRadarrBlock::SystemQueuedEvents
       | ActiveRadarrBlock::SystemTasks
       | ActiveRadarrBlock::SystemUpdates => self.app.pop_navigation_stack(),
-      ActiveRadarrBlock::SystemTaskStartConfirmPrompt => {
+      ActiveRadarrBlock::SystemTaskStartConfirmPrompt | ActiveRadarrBlock::DeleteBackupPrompt => {
         self.app.pop_navigation_stack();
         self.app.data.radarr_data.prompt_confirm = false;
       }
@@ -187,6 +215,15 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for SystemDetailsHandler
       self.app.should_refresh = true;
     }
 
+    if self.active_radarr_block == ActiveRadarrBlock::DeleteBackupPrompt
+      && matches_key!(confirm, self.key)
+    {
+      self.app.data.radarr_data.prompt_confirm = true;
+      self.app.data.radarr_data.prompt_confirm_action =
+        Some(RadarrEvent::DeleteBackup(self.extract_backup_id()));
+      self.app.pop_navigation_stack();
+    }
+
     if self.active_radarr_block == ActiveRadarrBlock::SystemTaskStartConfirmPrompt
       && matches_key!(confirm, self.key)
     {
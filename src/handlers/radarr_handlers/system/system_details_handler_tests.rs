@@ -14,7 +14,7 @@ mod tests {
   use crate::models::servarr_data::radarr::radarr_data::{
     ActiveRadarrBlock, SYSTEM_DETAILS_BLOCKS,
   };
-  use crate::models::servarr_models::QueueEvent;
+  use crate::models::servarr_models::{Backup, QueueEvent};
   use crate::models::{HorizontallyScrollableText, ScrollableText};
 
   mod test_handle_scroll_up_and_down {
@@ -182,6 +182,32 @@ mod tests {
       );
     }
 
+    #[rstest]
+    fn test_backups_scroll(
+      #[values(DEFAULT_KEYBINDINGS.up.key, DEFAULT_KEYBINDINGS.down.key)] key: Key,
+    ) {
+      let mut app = App::test_default();
+      app
+        .data
+        .radarr_data
+        .backups
+        .set_items(simple_stateful_iterable_vec!(Backup, String, name));
+
+      SystemDetailsHandler::new(key, &mut app, ActiveRadarrBlock::SystemBackups, None).handle();
+
+      assert_str_eq!(
+        app.data.radarr_data.backups.current_selection().name,
+        "Test 2"
+      );
+
+      SystemDetailsHandler::new(key, &mut app, ActiveRadarrBlock::SystemBackups, None).handle();
+
+      assert_str_eq!(
+        app.data.radarr_data.backups.current_selection().name,
+        "Test 1"
+      );
+    }
+
     #[test]
     fn test_system_updates_scroll() {
       let mut app = App::test_default();
@@ -449,6 +475,42 @@ mod tests {
       );
     }
 
+    #[test]
+    fn test_backups_home_end() {
+      let mut app = App::test_default();
+      app
+        .data
+        .radarr_data
+        .backups
+        .set_items(extended_stateful_iterable_vec!(Backup, String, name));
+
+      SystemDetailsHandler::new(
+        DEFAULT_KEYBINDINGS.end.key,
+        &mut app,
+        ActiveRadarrBlock::SystemBackups,
+        None,
+      )
+      .handle();
+
+      assert_str_eq!(
+        app.data.radarr_data.backups.current_selection().name,
+        "Test 3"
+      );
+
+      SystemDetailsHandler::new(
+        DEFAULT_KEYBINDINGS.home.key,
+        &mut app,
+        ActiveRadarrBlock::SystemBackups,
+        None,
+      )
+      .handle();
+
+      assert_str_eq!(
+        app.data.radarr_data.backups.current_selection().name,
+        "Test 1"
+      );
+    }
+
     #[test]
     fn test_system_updates_home_end() {
       let mut app = App::test_default();
@@ -503,6 +565,44 @@ mod tests {
     }
   }
 
+  mod test_handle_delete {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    const DELETE_KEY: Key = DEFAULT_KEYBINDINGS.delete.key;
+
+    #[test]
+    fn test_backups_delete() {
+      let mut app = App::test_default();
+      app
+        .data
+        .radarr_data
+        .backups
+        .set_items(vec![Backup::default()]);
+
+      SystemDetailsHandler::new(DELETE_KEY, &mut app, ActiveRadarrBlock::SystemBackups, None)
+        .handle();
+
+      assert_navigation_pushed!(app, ActiveRadarrBlock::DeleteBackupPrompt.into());
+    }
+
+    #[test]
+    fn test_backups_delete_no_op_when_backups_are_empty() {
+      let mut app = App::test_default();
+      app.data.radarr_data.updates = ScrollableText::with_string("Test".to_owned());
+      app.push_navigation_stack(ActiveRadarrBlock::SystemBackups.into());
+
+      SystemDetailsHandler::new(DELETE_KEY, &mut app, ActiveRadarrBlock::SystemBackups, None)
+        .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveRadarrBlock::SystemBackups.into()
+      );
+    }
+  }
+
   mod test_handle_left_right_action {
     use pretty_assertions::assert_eq;
     use rstest::rstest;
@@ -611,26 +711,19 @@ mod tests {
     #[rstest]
     fn test_left_right_prompt_toggle(
       #[values(DEFAULT_KEYBINDINGS.left.key, DEFAULT_KEYBINDINGS.right.key)] key: Key,
+      #[values(
+        ActiveRadarrBlock::SystemTaskStartConfirmPrompt,
+        ActiveRadarrBlock::DeleteBackupPrompt
+      )]
+      active_radarr_block: ActiveRadarrBlock,
     ) {
       let mut app = App::test_default();
 
-      SystemDetailsHandler::new(
-        key,
-        &mut app,
-        ActiveRadarrBlock::SystemTaskStartConfirmPrompt,
-        None,
-      )
-      .handle();
+      SystemDetailsHandler::new(key, &mut app, active_radarr_block, None).handle();
 
       assert!(app.data.radarr_data.prompt_confirm);
 
-      SystemDetailsHandler::new(
-        key,
-        &mut app,
-        ActiveRadarrBlock::SystemTaskStartConfirmPrompt,
-        None,
-      )
-      .handle();
+      SystemDetailsHandler::new(key, &mut app, active_radarr_block, None).handle();
 
       assert!(!app.data.radarr_data.prompt_confirm);
     }
@@ -719,6 +812,57 @@ mod tests {
       assert_none!(app.data.radarr_data.prompt_confirm_action);
       assert_navigation_popped!(app, ActiveRadarrBlock::SystemTasks.into());
     }
+
+    #[test]
+    fn test_delete_backup_prompt_confirm_submit() {
+      let mut app = App::test_default();
+      app.data.radarr_data.prompt_confirm = true;
+      app.data.radarr_data.backups.set_items(vec![Backup {
+        id: 1,
+        ..Backup::default()
+      }]);
+      app.push_navigation_stack(ActiveRadarrBlock::SystemBackups.into());
+      app.push_navigation_stack(ActiveRadarrBlock::DeleteBackupPrompt.into());
+
+      SystemDetailsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveRadarrBlock::DeleteBackupPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(app.data.radarr_data.prompt_confirm);
+      assert_some_eq_x!(
+        &app.data.radarr_data.prompt_confirm_action,
+        &RadarrEvent::DeleteBackup(1)
+      );
+      assert_navigation_popped!(app, ActiveRadarrBlock::SystemBackups.into());
+    }
+
+    #[test]
+    fn test_delete_backup_prompt_decline_submit() {
+      let mut app = App::test_default();
+      app
+        .data
+        .radarr_data
+        .backups
+        .set_items(vec![Backup::default()]);
+      app.push_navigation_stack(ActiveRadarrBlock::SystemBackups.into());
+      app.push_navigation_stack(ActiveRadarrBlock::DeleteBackupPrompt.into());
+
+      SystemDetailsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveRadarrBlock::DeleteBackupPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(!app.data.radarr_data.prompt_confirm);
+      assert_none!(app.data.radarr_data.prompt_confirm_action);
+      assert_navigation_popped!(app, ActiveRadarrBlock::SystemBackups.into());
+    }
   }
 
   mod test_handle_esc {
@@ -805,6 +949,42 @@ mod tests {
       assert_navigation_popped!(app, ActiveRadarrBlock::System.into());
     }
 
+    #[rstest]
+    fn test_esc_system_backups(#[values(true, false)] is_ready: bool) {
+      let mut app = App::test_default();
+      app.is_loading = is_ready;
+      app.push_navigation_stack(ActiveRadarrBlock::System.into());
+      app.push_navigation_stack(ActiveRadarrBlock::SystemBackups.into());
+      app
+        .data
+        .radarr_data
+        .backups
+        .set_items(vec![Backup::default()]);
+
+      SystemDetailsHandler::new(ESC_KEY, &mut app, ActiveRadarrBlock::SystemBackups, None).handle();
+
+      assert_navigation_popped!(app, ActiveRadarrBlock::System.into());
+    }
+
+    #[test]
+    fn test_delete_backup_prompt_esc() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveRadarrBlock::SystemBackups.into());
+      app.push_navigation_stack(ActiveRadarrBlock::DeleteBackupPrompt.into());
+      app.data.radarr_data.prompt_confirm = true;
+
+      SystemDetailsHandler::new(
+        ESC_KEY,
+        &mut app,
+        ActiveRadarrBlock::DeleteBackupPrompt,
+        None,
+      )
+      .handle();
+
+      assert_navigation_popped!(app, ActiveRadarrBlock::SystemBackups.into());
+      assert!(!app.data.radarr_data.prompt_confirm);
+    }
+
     #[test]
     fn test_system_tasks_start_task_prompt_esc() {
       let mut app = App::test_default();
@@ -837,6 +1017,7 @@ mod tests {
     #[rstest]
     fn test_refresh_key(
       #[values(
+        ActiveRadarrBlock::SystemBackups,
         ActiveRadarrBlock::SystemLogs,
         ActiveRadarrBlock::SystemTasks,
         ActiveRadarrBlock::SystemQueuedEvents,
@@ -863,6 +1044,7 @@ mod tests {
     #[rstest]
     fn test_refresh_key_no_op_when_not_ready(
       #[values(
+        ActiveRadarrBlock::SystemBackups,
         ActiveRadarrBlock::SystemLogs,
         ActiveRadarrBlock::SystemTasks,
         ActiveRadarrBlock::SystemQueuedEvents,
@@ -913,6 +1095,32 @@ mod tests {
       );
       assert_navigation_popped!(app, ActiveRadarrBlock::SystemTasks.into());
     }
+
+    #[test]
+    fn test_delete_backup_prompt_confirm() {
+      let mut app = App::test_default();
+      app.data.radarr_data.backups.set_items(vec![Backup {
+        id: 1,
+        ..Backup::default()
+      }]);
+      app.push_navigation_stack(ActiveRadarrBlock::SystemBackups.into());
+      app.push_navigation_stack(ActiveRadarrBlock::DeleteBackupPrompt.into());
+
+      SystemDetailsHandler::new(
+        DEFAULT_KEYBINDINGS.confirm.key,
+        &mut app,
+        ActiveRadarrBlock::DeleteBackupPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(app.data.radarr_data.prompt_confirm);
+      assert_some_eq_x!(
+        &app.data.radarr_data.prompt_confirm_action,
+        &RadarrEvent::DeleteBackup(1)
+      );
+      assert_navigation_popped!(app, ActiveRadarrBlock::SystemBackups.into());
+    }
   }
 
   #[test]
@@ -945,6 +1153,25 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_extract_backup_id() {
+    let mut app = App::test_default();
+    app.data.radarr_data.backups.set_items(vec![Backup {
+      id: 1,
+      ..Backup::default()
+    }]);
+
+    let backup_id = SystemDetailsHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveRadarrBlock::SystemBackups,
+      None,
+    )
+    .extract_backup_id();
+
+    assert_eq!(backup_id, 1);
+  }
+
   #[test]
   fn test_extract_task_name() {
     let mut app = App::test_default();
@@ -1034,6 +1261,26 @@ mod tests {
     assert!(handler.is_ready());
   }
 
+  #[test]
+  fn test_system_details_handler_ready_when_not_loading_and_backups_is_not_empty() {
+    let mut app = App::test_default();
+    app.is_loading = false;
+    app
+      .data
+      .radarr_data
+      .backups
+      .set_items(vec![Backup::default()]);
+
+    let handler = SystemDetailsHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveRadarrBlock::SystemBackups,
+      None,
+    );
+
+    assert!(handler.is_ready());
+  }
+
   #[test]
   fn test_system_details_handler_ready_when_not_loading_and_updates_is_not_empty() {
     let mut app = App::test_default();
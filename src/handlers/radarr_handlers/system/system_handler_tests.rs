@@ -26,7 +26,7 @@ mod tests {
     fn test_system_tab_left(#[values(true, false)] is_ready: bool) {
       let mut app = App::test_default();
       app.is_loading = is_ready;
-      app.data.radarr_data.main_tabs.set_index(7);
+      app.data.radarr_data.main_tabs.set_index(10);
 
       SystemHandler::new(
         DEFAULT_KEYBINDINGS.left.key,
@@ -38,16 +38,16 @@ mod tests {
 
       assert_eq!(
         app.data.radarr_data.main_tabs.get_active_route(),
-        ActiveRadarrBlock::Indexers.into()
+        ActiveRadarrBlock::CustomFormats.into()
       );
-      assert_navigation_pushed!(app, ActiveRadarrBlock::Indexers.into());
+      assert_navigation_pushed!(app, ActiveRadarrBlock::CustomFormats.into());
     }
 
     #[rstest]
     fn test_system_tab_right(#[values(true, false)] is_ready: bool) {
       let mut app = App::test_default();
       app.is_loading = is_ready;
-      app.data.radarr_data.main_tabs.set_index(7);
+      app.data.radarr_data.main_tabs.set_index(10);
 
       SystemHandler::new(
         DEFAULT_KEYBINDINGS.right.key,
@@ -59,9 +59,9 @@ mod tests {
 
       assert_eq!(
         app.data.radarr_data.main_tabs.get_active_route(),
-        ActiveRadarrBlock::Movies.into()
+        ActiveRadarrBlock::Calendar.into()
       );
-      assert_navigation_pushed!(app, ActiveRadarrBlock::Movies.into());
+      assert_navigation_pushed!(app, ActiveRadarrBlock::Calendar.into());
     }
   }
 
@@ -347,6 +347,66 @@ mod tests {
       assert_is_empty!(app.data.radarr_data.log_details);
     }
 
+    #[test]
+    fn test_backups_key() {
+      let mut app = App::test_default();
+      app.data.radarr_data.logs.set_items(vec![
+        HorizontallyScrollableText::from("test 1"),
+        HorizontallyScrollableText::from("test 2"),
+      ]);
+      app
+        .data
+        .radarr_data
+        .queued_events
+        .set_items(vec![QueueEvent::default()]);
+      app
+        .data
+        .radarr_data
+        .tasks
+        .set_items(vec![RadarrTask::default()]);
+
+      SystemHandler::new(
+        DEFAULT_KEYBINDINGS.backups.key,
+        &mut app,
+        ActiveRadarrBlock::System,
+        None,
+      )
+      .handle();
+
+      assert_navigation_pushed!(app, ActiveRadarrBlock::SystemBackups.into());
+    }
+
+    #[test]
+    fn test_backups_key_no_op_when_not_ready() {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.push_navigation_stack(ActiveRadarrBlock::System.into());
+      app.data.radarr_data.logs.set_items(vec![
+        HorizontallyScrollableText::from("test 1"),
+        HorizontallyScrollableText::from("test 2"),
+      ]);
+      app
+        .data
+        .radarr_data
+        .queued_events
+        .set_items(vec![QueueEvent::default()]);
+      app
+        .data
+        .radarr_data
+        .tasks
+        .set_items(vec![RadarrTask::default()]);
+
+      SystemHandler::new(
+        DEFAULT_KEYBINDINGS.backups.key,
+        &mut app,
+        ActiveRadarrBlock::System,
+        None,
+      )
+      .handle();
+
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::System.into());
+    }
+
     #[test]
     fn test_tasks_key() {
       let mut app = App::test_default();
@@ -0,0 +1,206 @@
+use crate::app::App;
+use crate::event::Key;
+use crate::handlers::radarr_handlers::handle_change_tab_left_right_keys;
+use crate::handlers::table_handler::{TableHandlingConfig, handle_table};
+use crate::handlers::{KeyEventHandler, handle_clear_errors, handle_prompt_toggle};
+use crate::matches_key;
+use crate::models::Route;
+use crate::models::radarr_models::Movie;
+use crate::models::servarr_data::radarr::radarr_data::{ActiveRadarrBlock, WANTED_BLOCKS};
+use crate::models::stateful_table::StatefulTable;
+use crate::network::radarr_network::RadarrEvent;
+
+#[cfg(test)]
+#[path = "wanted_handler_tests.rs"]
+mod wanted_handler_tests;
+
+pub(super) struct WantedHandler<'a, 'b> {
+  key: Key,
+  app: &'a mut App<'b>,
+  active_radarr_block: ActiveRadarrBlock,
+  context: Option<ActiveRadarrBlock>,
+}
+
+impl WantedHandler<'_, '_> {
+  fn wanted_table_block(&self) -> ActiveRadarrBlock {
+    match self.active_radarr_block {
+      ActiveRadarrBlock::WantedSearchAllPrompt | ActiveRadarrBlock::WantedSearchSelectedPrompt => {
+        self.context.unwrap_or(ActiveRadarrBlock::WantedMissing)
+      }
+      _ => self.active_radarr_block,
+    }
+  }
+
+  fn wanted_table(&self) -> &StatefulTable<Movie> {
+    if self.wanted_table_block() == ActiveRadarrBlock::WantedCutoffUnmet {
+      &self.app.data.radarr_data.cutoff_unmet
+    } else {
+      &self.app.data.radarr_data.wanted_missing
+    }
+  }
+
+  fn build_search_action(&self) -> RadarrEvent {
+    match self.active_radarr_block {
+      ActiveRadarrBlock::WantedSearchSelectedPrompt => {
+        RadarrEvent::TriggerAutomaticSearch(self.wanted_table().current_selection().id)
+      }
+      _ if self.wanted_table_block() == ActiveRadarrBlock::WantedCutoffUnmet => {
+        RadarrEvent::TriggerCutoffUnmetMoviesSearch
+      }
+      _ => RadarrEvent::TriggerMissingMoviesSearch,
+    }
+  }
+}
+
+impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveRadarrBlock> for WantedHandler<'a, 'b> {
+  fn handle(&mut self) {
+    let missing_table_handling_config =
+      TableHandlingConfig::new(ActiveRadarrBlock::WantedMissing.into());
+    let cutoff_unmet_table_handling_config =
+      TableHandlingConfig::new(ActiveRadarrBlock::WantedCutoffUnmet.into());
+
+    if !handle_table(
+      self,
+      |app| &mut app.data.radarr_data.wanted_missing,
+      missing_table_handling_config,
+    ) && !handle_table(
+      self,
+      |app| &mut app.data.radarr_data.cutoff_unmet,
+      cutoff_unmet_table_handling_config,
+    ) {
+      self.handle_key_event();
+    }
+  }
+
+  fn accepts(active_block: ActiveRadarrBlock) -> bool {
+    WANTED_BLOCKS.contains(&active_block)
+  }
+
+  fn ignore_special_keys(&self) -> bool {
+    self.app.ignore_special_keys_for_textbox_input
+  }
+
+  fn new(
+    key: Key,
+    app: &'a mut App<'b>,
+    active_block: ActiveRadarrBlock,
+    context: Option<ActiveRadarrBlock>,
+  ) -> WantedHandler<'a, 'b> {
+    WantedHandler {
+      key,
+      app,
+      active_radarr_block: active_block,
+      context,
+    }
+  }
+
+  fn get_key(&self) -> Key {
+    self.key
+  }
+
+  fn is_ready(&self) -> bool {
+    !self.app.is_loading
+  }
+
+  fn handle_scroll_up(&mut self) {}
+
+  fn handle_scroll_down(&mut self) {}
+
+  fn handle_home(&mut self) {}
+
+  fn handle_end(&mut self) {}
+
+  fn handle_delete(&mut self) {}
+
+  fn handle_left_right_action(&mut self) {
+    match self.active_radarr_block {
+      ActiveRadarrBlock::WantedMissing | ActiveRadarrBlock::WantedCutoffUnmet => {
+        handle_change_tab_left_right_keys(self.app, self.key)
+      }
+      ActiveRadarrBlock::WantedSearchAllPrompt | ActiveRadarrBlock::WantedSearchSelectedPrompt => {
+        handle_prompt_toggle(self.app, self.key)
+      }
+      _ => (),
+    }
+  }
+
+  fn handle_submit(&mut self) {
+    match self.active_radarr_block {
+      ActiveRadarrBlock::WantedMissing | ActiveRadarrBlock::WantedCutoffUnmet
+        if !self.wanted_table().is_empty() =>
+      {
+        self.app.push_navigation_stack(
+          (
+            ActiveRadarrBlock::WantedSearchSelectedPrompt,
+            Some(self.active_radarr_block),
+          )
+            .into(),
+        );
+      }
+      ActiveRadarrBlock::WantedSearchAllPrompt | ActiveRadarrBlock::WantedSearchSelectedPrompt => {
+        if self.app.data.radarr_data.prompt_confirm {
+          self.app.data.radarr_data.prompt_confirm_action = Some(self.build_search_action());
+        }
+
+        self.app.pop_navigation_stack();
+      }
+      _ => (),
+    }
+  }
+
+  fn handle_esc(&mut self) {
+    match self.active_radarr_block {
+      ActiveRadarrBlock::WantedSearchAllPrompt | ActiveRadarrBlock::WantedSearchSelectedPrompt => {
+        self.app.pop_navigation_stack();
+        self.app.data.radarr_data.prompt_confirm = false;
+      }
+      _ => handle_clear_errors(self.app),
+    }
+  }
+
+  fn handle_char_key_event(&mut self) {
+    let key = self.key;
+    match self.active_radarr_block {
+      ActiveRadarrBlock::WantedMissing | ActiveRadarrBlock::WantedCutoffUnmet => match self.key {
+        _ if matches_key!(toggle_view, key) => {
+          let toggled_block = if self.active_radarr_block == ActiveRadarrBlock::WantedMissing {
+            ActiveRadarrBlock::WantedCutoffUnmet
+          } else {
+            ActiveRadarrBlock::WantedMissing
+          };
+          self.app.pop_and_push_navigation_stack(toggled_block.into());
+        }
+        _ if matches_key!(auto_search, key) => {
+          self.app.push_navigation_stack(
+            (
+              ActiveRadarrBlock::WantedSearchAllPrompt,
+              Some(self.active_radarr_block),
+            )
+              .into(),
+          );
+        }
+        _ if matches_key!(refresh, key) => {
+          self.app.should_refresh = true;
+        }
+        _ => (),
+      },
+      ActiveRadarrBlock::WantedSearchAllPrompt | ActiveRadarrBlock::WantedSearchSelectedPrompt => {
+        if matches_key!(confirm, key) {
+          self.app.data.radarr_data.prompt_confirm = true;
+          self.app.data.radarr_data.prompt_confirm_action = Some(self.build_search_action());
+
+          self.app.pop_navigation_stack();
+        }
+      }
+      _ => (),
+    }
+  }
+
+  fn app_mut(&mut self) -> &mut App<'b> {
+    self.app
+  }
+
+  fn current_route(&self) -> Route {
+    self.app.get_current_route()
+  }
+}
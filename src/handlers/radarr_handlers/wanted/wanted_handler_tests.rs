@@ -0,0 +1,477 @@
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+  use rstest::rstest;
+  use strum::IntoEnumIterator;
+
+  use crate::app::App;
+  use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+  use crate::assert_navigation_pushed;
+  use crate::event::Key;
+  use crate::handlers::KeyEventHandler;
+  use crate::handlers::radarr_handlers::wanted::WantedHandler;
+  use crate::models::radarr_models::Movie;
+  use crate::models::servarr_data::radarr::radarr_data::{ActiveRadarrBlock, WANTED_BLOCKS};
+
+  mod test_handle_left_right_action {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::assert_navigation_pushed;
+
+    #[rstest]
+    fn test_wanted_tab_left(
+      #[values(ActiveRadarrBlock::WantedMissing, ActiveRadarrBlock::WantedCutoffUnmet)]
+      active_radarr_block: ActiveRadarrBlock,
+      #[values(true, false)] is_ready: bool,
+    ) {
+      let mut app = App::test_default();
+      app.push_navigation_stack(active_radarr_block.into());
+      app.is_loading = is_ready;
+      app.data.radarr_data.main_tabs.set_index(12);
+
+      WantedHandler::new(
+        DEFAULT_KEYBINDINGS.left.key,
+        &mut app,
+        active_radarr_block,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.data.radarr_data.main_tabs.get_active_route(),
+        ActiveRadarrBlock::Calendar.into()
+      );
+      assert_navigation_pushed!(app, ActiveRadarrBlock::Calendar.into());
+    }
+
+    #[rstest]
+    fn test_wanted_tab_right(
+      #[values(ActiveRadarrBlock::WantedMissing, ActiveRadarrBlock::WantedCutoffUnmet)]
+      active_radarr_block: ActiveRadarrBlock,
+      #[values(true, false)] is_ready: bool,
+    ) {
+      let mut app = App::test_default();
+      app.push_navigation_stack(active_radarr_block.into());
+      app.is_loading = is_ready;
+      app.data.radarr_data.main_tabs.set_index(12);
+
+      WantedHandler::new(
+        DEFAULT_KEYBINDINGS.right.key,
+        &mut app,
+        active_radarr_block,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.data.radarr_data.main_tabs.get_active_route(),
+        ActiveRadarrBlock::Movies.into()
+      );
+      assert_navigation_pushed!(app, ActiveRadarrBlock::Movies.into());
+    }
+
+    #[rstest]
+    fn test_wanted_left_right_prompt_toggle(
+      #[values(
+        ActiveRadarrBlock::WantedSearchAllPrompt,
+        ActiveRadarrBlock::WantedSearchSelectedPrompt
+      )]
+      active_radarr_block: ActiveRadarrBlock,
+      #[values(DEFAULT_KEYBINDINGS.left.key, DEFAULT_KEYBINDINGS.right.key)] key: Key,
+    ) {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveRadarrBlock::WantedMissing.into());
+
+      WantedHandler::new(key, &mut app, active_radarr_block, None).handle();
+
+      assert!(app.data.radarr_data.prompt_confirm);
+
+      WantedHandler::new(key, &mut app, active_radarr_block, None).handle();
+
+      assert!(!app.data.radarr_data.prompt_confirm);
+    }
+  }
+
+  mod test_handle_submit {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::assert_navigation_popped;
+    use crate::models::Scrollable;
+    use crate::network::radarr_network::RadarrEvent;
+
+    const SUBMIT_KEY: Key = DEFAULT_KEYBINDINGS.submit.key;
+
+    #[rstest]
+    fn test_wanted_submit(
+      #[values(ActiveRadarrBlock::WantedMissing, ActiveRadarrBlock::WantedCutoffUnmet)]
+      active_radarr_block: ActiveRadarrBlock,
+    ) {
+      let mut app = App::test_default();
+      app.data.radarr_data.wanted_missing.set_items(movies());
+      app.data.radarr_data.cutoff_unmet.set_items(movies());
+      app.push_navigation_stack(active_radarr_block.into());
+
+      WantedHandler::new(SUBMIT_KEY, &mut app, active_radarr_block, None).handle();
+
+      assert_navigation_pushed!(
+        app,
+        (
+          ActiveRadarrBlock::WantedSearchSelectedPrompt,
+          Some(active_radarr_block)
+        )
+          .into()
+      );
+    }
+
+    #[rstest]
+    fn test_wanted_submit_no_op_when_table_is_empty(
+      #[values(ActiveRadarrBlock::WantedMissing, ActiveRadarrBlock::WantedCutoffUnmet)]
+      active_radarr_block: ActiveRadarrBlock,
+    ) {
+      let mut app = App::test_default();
+      app.push_navigation_stack(active_radarr_block.into());
+
+      WantedHandler::new(SUBMIT_KEY, &mut app, active_radarr_block, None).handle();
+
+      assert_eq!(app.get_current_route(), active_radarr_block.into());
+    }
+
+    #[rstest]
+    #[case(
+      ActiveRadarrBlock::WantedMissing,
+      ActiveRadarrBlock::WantedSearchSelectedPrompt,
+      RadarrEvent::TriggerAutomaticSearch(2)
+    )]
+    #[case(
+      ActiveRadarrBlock::WantedCutoffUnmet,
+      ActiveRadarrBlock::WantedSearchSelectedPrompt,
+      RadarrEvent::TriggerAutomaticSearch(4)
+    )]
+    #[case(
+      ActiveRadarrBlock::WantedMissing,
+      ActiveRadarrBlock::WantedSearchAllPrompt,
+      RadarrEvent::TriggerMissingMoviesSearch
+    )]
+    #[case(
+      ActiveRadarrBlock::WantedCutoffUnmet,
+      ActiveRadarrBlock::WantedSearchAllPrompt,
+      RadarrEvent::TriggerCutoffUnmetMoviesSearch
+    )]
+    fn test_wanted_prompt_confirm_submit(
+      #[case] base_route: ActiveRadarrBlock,
+      #[case] prompt_block: ActiveRadarrBlock,
+      #[case] expected_action: RadarrEvent,
+    ) {
+      let mut app = App::test_default();
+      app.data.radarr_data.wanted_missing.set_items(movies());
+      app.data.radarr_data.wanted_missing.scroll_down();
+      app.data.radarr_data.cutoff_unmet.set_items(vec![Movie {
+        id: 4,
+        ..Movie::default()
+      }]);
+      app.data.radarr_data.prompt_confirm = true;
+      app.push_navigation_stack(base_route.into());
+      app.push_navigation_stack((prompt_block, Some(base_route)).into());
+
+      WantedHandler::new(SUBMIT_KEY, &mut app, prompt_block, Some(base_route)).handle();
+
+      assert!(app.data.radarr_data.prompt_confirm);
+      assert_some_eq_x!(
+        &app.data.radarr_data.prompt_confirm_action,
+        &expected_action
+      );
+      assert_navigation_popped!(app, base_route.into());
+    }
+
+    #[rstest]
+    fn test_wanted_prompt_decline_submit(
+      #[values(
+        ActiveRadarrBlock::WantedSearchAllPrompt,
+        ActiveRadarrBlock::WantedSearchSelectedPrompt
+      )]
+      prompt_block: ActiveRadarrBlock,
+    ) {
+      let mut app = App::test_default();
+      app.data.radarr_data.wanted_missing.set_items(movies());
+      app.push_navigation_stack(ActiveRadarrBlock::WantedMissing.into());
+      app.push_navigation_stack((prompt_block, Some(ActiveRadarrBlock::WantedMissing)).into());
+
+      WantedHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        prompt_block,
+        Some(ActiveRadarrBlock::WantedMissing),
+      )
+      .handle();
+
+      assert!(!app.data.radarr_data.prompt_confirm);
+      assert_none!(app.data.radarr_data.prompt_confirm_action);
+      assert_navigation_popped!(app, ActiveRadarrBlock::WantedMissing.into());
+    }
+  }
+
+  mod test_handle_esc {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::assert_navigation_popped;
+
+    const ESC_KEY: Key = DEFAULT_KEYBINDINGS.esc.key;
+
+    #[rstest]
+    fn test_wanted_prompt_esc(
+      #[values(
+        ActiveRadarrBlock::WantedSearchAllPrompt,
+        ActiveRadarrBlock::WantedSearchSelectedPrompt
+      )]
+      prompt_block: ActiveRadarrBlock,
+    ) {
+      let mut app = App::test_default();
+      app.data.radarr_data.prompt_confirm = true;
+      app.push_navigation_stack(ActiveRadarrBlock::WantedMissing.into());
+      app.push_navigation_stack((prompt_block, Some(ActiveRadarrBlock::WantedMissing)).into());
+
+      WantedHandler::new(
+        ESC_KEY,
+        &mut app,
+        prompt_block,
+        Some(ActiveRadarrBlock::WantedMissing),
+      )
+      .handle();
+
+      assert_navigation_popped!(app, ActiveRadarrBlock::WantedMissing.into());
+      assert!(!app.data.radarr_data.prompt_confirm);
+    }
+
+    #[rstest]
+    fn test_wanted_esc_clears_errors(
+      #[values(ActiveRadarrBlock::WantedMissing, ActiveRadarrBlock::WantedCutoffUnmet)]
+      active_radarr_block: ActiveRadarrBlock,
+    ) {
+      let mut app = App::test_default();
+      app.push_navigation_stack(active_radarr_block.into());
+      app.error = "test error".to_owned().into();
+
+      WantedHandler::new(ESC_KEY, &mut app, active_radarr_block, None).handle();
+
+      assert_eq!(app.get_current_route(), active_radarr_block.into());
+      assert_is_empty!(app.error.text);
+    }
+  }
+
+  mod test_handle_key_char {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::assert_navigation_popped;
+    use crate::network::radarr_network::RadarrEvent;
+
+    #[rstest]
+    #[case(ActiveRadarrBlock::WantedMissing, ActiveRadarrBlock::WantedCutoffUnmet)]
+    #[case(ActiveRadarrBlock::WantedCutoffUnmet, ActiveRadarrBlock::WantedMissing)]
+    fn test_toggle_view_key(
+      #[case] active_radarr_block: ActiveRadarrBlock,
+      #[case] expected_radarr_block: ActiveRadarrBlock,
+    ) {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
+      app.push_navigation_stack(active_radarr_block.into());
+
+      WantedHandler::new(
+        DEFAULT_KEYBINDINGS.toggle_view.key,
+        &mut app,
+        active_radarr_block,
+        None,
+      )
+      .handle();
+
+      assert_navigation_pushed!(app, expected_radarr_block.into());
+      app.pop_navigation_stack();
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::Movies.into());
+    }
+
+    #[rstest]
+    fn test_search_all_key(
+      #[values(ActiveRadarrBlock::WantedMissing, ActiveRadarrBlock::WantedCutoffUnmet)]
+      active_radarr_block: ActiveRadarrBlock,
+    ) {
+      let mut app = App::test_default();
+      app.push_navigation_stack(active_radarr_block.into());
+
+      WantedHandler::new(
+        DEFAULT_KEYBINDINGS.auto_search.key,
+        &mut app,
+        active_radarr_block,
+        None,
+      )
+      .handle();
+
+      assert_navigation_pushed!(
+        app,
+        (
+          ActiveRadarrBlock::WantedSearchAllPrompt,
+          Some(active_radarr_block)
+        )
+          .into()
+      );
+    }
+
+    #[test]
+    fn test_search_all_key_no_op_when_not_ready() {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.push_navigation_stack(ActiveRadarrBlock::WantedMissing.into());
+
+      WantedHandler::new(
+        DEFAULT_KEYBINDINGS.auto_search.key,
+        &mut app,
+        ActiveRadarrBlock::WantedMissing,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveRadarrBlock::WantedMissing.into()
+      );
+    }
+
+    #[rstest]
+    fn test_refresh_wanted_key(
+      #[values(ActiveRadarrBlock::WantedMissing, ActiveRadarrBlock::WantedCutoffUnmet)]
+      active_radarr_block: ActiveRadarrBlock,
+    ) {
+      let mut app = App::test_default();
+      app.push_navigation_stack(active_radarr_block.into());
+
+      WantedHandler::new(
+        DEFAULT_KEYBINDINGS.refresh.key,
+        &mut app,
+        active_radarr_block,
+        None,
+      )
+      .handle();
+
+      assert_eq!(app.get_current_route(), active_radarr_block.into());
+      assert!(app.should_refresh);
+    }
+
+    #[rstest]
+    #[case(
+      ActiveRadarrBlock::WantedMissing,
+      ActiveRadarrBlock::WantedSearchSelectedPrompt,
+      RadarrEvent::TriggerAutomaticSearch(1)
+    )]
+    #[case(
+      ActiveRadarrBlock::WantedCutoffUnmet,
+      ActiveRadarrBlock::WantedSearchAllPrompt,
+      RadarrEvent::TriggerCutoffUnmetMoviesSearch
+    )]
+    fn test_wanted_prompt_confirm(
+      #[case] base_route: ActiveRadarrBlock,
+      #[case] prompt_block: ActiveRadarrBlock,
+      #[case] expected_action: RadarrEvent,
+    ) {
+      let mut app = App::test_default();
+      app.data.radarr_data.wanted_missing.set_items(movies());
+      app.push_navigation_stack(base_route.into());
+      app.push_navigation_stack((prompt_block, Some(base_route)).into());
+
+      WantedHandler::new(
+        DEFAULT_KEYBINDINGS.confirm.key,
+        &mut app,
+        prompt_block,
+        Some(base_route),
+      )
+      .handle();
+
+      assert!(app.data.radarr_data.prompt_confirm);
+      assert_some_eq_x!(
+        &app.data.radarr_data.prompt_confirm_action,
+        &expected_action
+      );
+      assert_navigation_popped!(app, base_route.into());
+    }
+  }
+
+  #[test]
+  fn test_wanted_handler_accepts() {
+    ActiveRadarrBlock::iter().for_each(|active_radarr_block| {
+      if WANTED_BLOCKS.contains(&active_radarr_block) {
+        assert!(WantedHandler::accepts(active_radarr_block));
+      } else {
+        assert!(!WantedHandler::accepts(active_radarr_block));
+      }
+    })
+  }
+
+  #[rstest]
+  fn test_wanted_handler_ignore_special_keys(
+    #[values(true, false)] ignore_special_keys_for_textbox_input: bool,
+  ) {
+    let mut app = App::test_default();
+    app.ignore_special_keys_for_textbox_input = ignore_special_keys_for_textbox_input;
+    let handler = WantedHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveRadarrBlock::default(),
+      None,
+    );
+
+    assert_eq!(
+      handler.ignore_special_keys(),
+      ignore_special_keys_for_textbox_input
+    );
+  }
+
+  #[test]
+  fn test_wanted_handler_not_ready_when_loading() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveRadarrBlock::WantedMissing.into());
+    app.is_loading = true;
+
+    let handler = WantedHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveRadarrBlock::WantedMissing,
+      None,
+    );
+
+    assert!(!handler.is_ready());
+  }
+
+  #[test]
+  fn test_wanted_handler_ready_when_not_loading() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveRadarrBlock::WantedMissing.into());
+    app.is_loading = false;
+
+    let handler = WantedHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveRadarrBlock::WantedMissing,
+      None,
+    );
+
+    assert!(handler.is_ready());
+  }
+
+  fn movies() -> Vec<Movie> {
+    vec![
+      Movie {
+        id: 1,
+        ..Movie::default()
+      },
+      Movie {
+        id: 2,
+        ..Movie::default()
+      },
+    ]
+  }
+}
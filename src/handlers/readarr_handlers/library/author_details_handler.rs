@@ -0,0 +1,217 @@
+use crate::app::App;
+use crate::event::Key;
+use crate::handlers::table_handler::{TableHandlingConfig, handle_table};
+use crate::handlers::{KeyEventHandler, handle_prompt_toggle};
+use crate::matches_key;
+use crate::models::readarr_models::Book;
+use crate::models::servarr_data::readarr::readarr_data::{
+  AUTHOR_DETAILS_BLOCKS, ActiveReadarrBlock, EDIT_AUTHOR_SELECTION_BLOCKS,
+};
+use crate::models::stateful_table::StatefulTable;
+use crate::models::{BlockSelectionState, Route};
+use crate::network::readarr_network::ReadarrEvent;
+
+#[cfg(test)]
+#[path = "author_details_handler_tests.rs"]
+mod author_details_handler_tests;
+
+pub struct AuthorDetailsHandler<'a, 'b> {
+  key: Key,
+  app: &'a mut App<'b>,
+  active_readarr_block: ActiveReadarrBlock,
+  _context: Option<ActiveReadarrBlock>,
+}
+
+impl AuthorDetailsHandler<'_, '_> {
+  fn extract_author_id(&self) -> i64 {
+    self.app.data.readarr_data.authors.current_selection().id
+  }
+
+  fn extract_book_id(&self) -> i64 {
+    self.app.data.readarr_data.books.current_selection().id
+  }
+}
+
+impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveReadarrBlock> for AuthorDetailsHandler<'a, 'b> {
+  fn handle(&mut self) {
+    let books_table_handling_config =
+      TableHandlingConfig::new(ActiveReadarrBlock::AuthorDetails.into())
+        .searching_block(ActiveReadarrBlock::SearchBooks.into())
+        .search_error_block(ActiveReadarrBlock::SearchBooksError.into())
+        .search_field_fn(|book: &Book| &book.title.text);
+
+    if !handle_table(
+      self,
+      |app| &mut app.data.readarr_data.books,
+      books_table_handling_config,
+    ) {
+      self.handle_key_event();
+    }
+  }
+
+  fn accepts(active_block: ActiveReadarrBlock) -> bool {
+    AUTHOR_DETAILS_BLOCKS.contains(&active_block)
+  }
+
+  fn ignore_special_keys(&self) -> bool {
+    self.app.ignore_special_keys_for_textbox_input
+  }
+
+  fn new(
+    key: Key,
+    app: &'a mut App<'b>,
+    active_block: ActiveReadarrBlock,
+    _context: Option<ActiveReadarrBlock>,
+  ) -> AuthorDetailsHandler<'a, 'b> {
+    AuthorDetailsHandler {
+      key,
+      app,
+      active_readarr_block: active_block,
+      _context,
+    }
+  }
+
+  fn get_key(&self) -> Key {
+    self.key
+  }
+
+  fn is_ready(&self) -> bool {
+    !self.app.is_loading
+  }
+
+  fn handle_scroll_up(&mut self) {}
+
+  fn handle_scroll_down(&mut self) {}
+
+  fn handle_home(&mut self) {}
+
+  fn handle_end(&mut self) {}
+
+  fn handle_delete(&mut self) {}
+
+  fn handle_left_right_action(&mut self) {
+    match self.active_readarr_block {
+      ActiveReadarrBlock::UpdateAndScanAuthorPrompt
+      | ActiveReadarrBlock::AutomaticallySearchAuthorPrompt => {
+        handle_prompt_toggle(self.app, self.key);
+      }
+      _ => (),
+    }
+  }
+
+  fn handle_submit(&mut self) {
+    match self.active_readarr_block {
+      ActiveReadarrBlock::AuthorDetails if !self.app.data.readarr_data.books.is_empty() => {
+        self
+          .app
+          .push_navigation_stack(ActiveReadarrBlock::BookDetails.into());
+      }
+      ActiveReadarrBlock::AutomaticallySearchAuthorPrompt => {
+        if self.app.data.readarr_data.prompt_confirm {
+          self.app.data.readarr_data.prompt_confirm_action = Some(
+            ReadarrEvent::TriggerAutomaticAuthorSearch(self.extract_author_id()),
+          );
+        }
+
+        self.app.pop_navigation_stack();
+      }
+      ActiveReadarrBlock::UpdateAndScanAuthorPrompt => {
+        if self.app.data.readarr_data.prompt_confirm {
+          self.app.data.readarr_data.prompt_confirm_action =
+            Some(ReadarrEvent::UpdateAndScanAuthor(self.extract_author_id()));
+        }
+
+        self.app.pop_navigation_stack();
+      }
+      _ => (),
+    }
+  }
+
+  fn handle_esc(&mut self) {
+    match self.active_readarr_block {
+      ActiveReadarrBlock::UpdateAndScanAuthorPrompt
+      | ActiveReadarrBlock::AutomaticallySearchAuthorPrompt => {
+        self.app.pop_navigation_stack();
+        self.app.data.readarr_data.prompt_confirm = false;
+      }
+      ActiveReadarrBlock::AuthorDetails => {
+        self.app.pop_navigation_stack();
+        self.app.data.readarr_data.books = StatefulTable::default();
+      }
+      _ => (),
+    }
+  }
+
+  fn handle_char_key_event(&mut self) {
+    let key = self.key;
+    match self.active_readarr_block {
+      ActiveReadarrBlock::AuthorDetails => match self.key {
+        _ if matches_key!(refresh, key) => self
+          .app
+          .pop_and_push_navigation_stack(self.active_readarr_block.into()),
+        _ if matches_key!(auto_search, key) => {
+          self
+            .app
+            .push_navigation_stack(ActiveReadarrBlock::AutomaticallySearchAuthorPrompt.into());
+        }
+        _ if matches_key!(update, key) => {
+          self
+            .app
+            .push_navigation_stack(ActiveReadarrBlock::UpdateAndScanAuthorPrompt.into());
+        }
+        _ if matches_key!(edit, key) => {
+          self.app.push_navigation_stack(
+            (
+              ActiveReadarrBlock::EditAuthorPrompt,
+              Some(self.active_readarr_block),
+            )
+              .into(),
+          );
+          self.app.data.readarr_data.edit_author_modal = Some((&self.app.data.readarr_data).into());
+          self.app.data.readarr_data.selected_block =
+            BlockSelectionState::new(EDIT_AUTHOR_SELECTION_BLOCKS);
+        }
+        _ if matches_key!(toggle_monitoring, key)
+          && !self.app.data.readarr_data.books.is_empty() =>
+        {
+          self.app.data.readarr_data.prompt_confirm = true;
+          self.app.data.readarr_data.prompt_confirm_action =
+            Some(ReadarrEvent::ToggleBookMonitoring(self.extract_book_id()));
+
+          self
+            .app
+            .pop_and_push_navigation_stack(self.active_readarr_block.into());
+        }
+        _ => (),
+      },
+      ActiveReadarrBlock::AutomaticallySearchAuthorPrompt => {
+        if matches_key!(confirm, key) {
+          self.app.data.readarr_data.prompt_confirm = true;
+          self.app.data.readarr_data.prompt_confirm_action = Some(
+            ReadarrEvent::TriggerAutomaticAuthorSearch(self.extract_author_id()),
+          );
+
+          self.app.pop_navigation_stack();
+        }
+      }
+      ActiveReadarrBlock::UpdateAndScanAuthorPrompt => {
+        if matches_key!(confirm, key) {
+          self.app.data.readarr_data.prompt_confirm = true;
+          self.app.data.readarr_data.prompt_confirm_action =
+            Some(ReadarrEvent::UpdateAndScanAuthor(self.extract_author_id()));
+
+          self.app.pop_navigation_stack();
+        }
+      }
+      _ => (),
+    }
+  }
+
+  fn app_mut(&mut self) -> &mut App<'b> {
+    self.app
+  }
+
+  fn current_route(&self) -> Route {
+    self.app.get_current_route()
+  }
+}
@@ -0,0 +1,576 @@
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+  use rstest::rstest;
+  use strum::IntoEnumIterator;
+
+  use crate::app::App;
+  use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+  use crate::handlers::KeyEventHandler;
+  use crate::handlers::readarr_handlers::library::author_details_handler::AuthorDetailsHandler;
+  use crate::models::servarr_data::readarr::readarr_data::{
+    AUTHOR_DETAILS_BLOCKS, ActiveReadarrBlock,
+  };
+
+  mod test_handle_left_right_action {
+    use rstest::rstest;
+
+    use crate::app::App;
+    use crate::event::Key;
+    use crate::handlers::KeyEventHandler;
+    use crate::handlers::readarr_handlers::library::author_details_handler::AuthorDetailsHandler;
+    use crate::models::servarr_data::readarr::readarr_data::ActiveReadarrBlock;
+
+    #[rstest]
+    fn test_left_right_prompt_toggle(
+      #[values(
+        ActiveReadarrBlock::UpdateAndScanAuthorPrompt,
+        ActiveReadarrBlock::AutomaticallySearchAuthorPrompt
+      )]
+      active_readarr_block: ActiveReadarrBlock,
+      #[values(Key::Left, Key::Right)] key: Key,
+    ) {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveReadarrBlock::AuthorDetails.into());
+      app.push_navigation_stack(active_readarr_block.into());
+
+      AuthorDetailsHandler::new(key, &mut app, active_readarr_block, None).handle();
+
+      assert!(app.data.readarr_data.prompt_confirm);
+
+      AuthorDetailsHandler::new(key, &mut app, active_readarr_block, None).handle();
+
+      assert!(!app.data.readarr_data.prompt_confirm);
+    }
+  }
+
+  mod test_handle_submit {
+    use crate::app::App;
+    use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+    use crate::event::Key;
+    use crate::handlers::KeyEventHandler;
+    use crate::handlers::readarr_handlers::library::author_details_handler::AuthorDetailsHandler;
+    use crate::models::servarr_data::readarr::readarr_data::ActiveReadarrBlock;
+    use crate::network::readarr_network::ReadarrEvent;
+    use crate::network::readarr_network::readarr_network_test_utils::test_utils::{author, book};
+    use crate::{assert_navigation_popped, assert_navigation_pushed};
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    const SUBMIT_KEY: Key = DEFAULT_KEYBINDINGS.submit.key;
+
+    #[test]
+    fn test_author_details_submit() {
+      let mut app = App::test_default();
+      app.data.readarr_data.books.set_items(vec![book()]);
+      app.push_navigation_stack(ActiveReadarrBlock::AuthorDetails.into());
+
+      AuthorDetailsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveReadarrBlock::AuthorDetails,
+        None,
+      )
+      .handle();
+
+      assert_navigation_pushed!(app, ActiveReadarrBlock::BookDetails.into());
+    }
+
+    #[test]
+    fn test_author_details_submit_no_op_when_books_are_empty() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveReadarrBlock::AuthorDetails.into());
+
+      AuthorDetailsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveReadarrBlock::AuthorDetails,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveReadarrBlock::AuthorDetails.into()
+      );
+    }
+
+    #[rstest]
+    #[case(
+      ActiveReadarrBlock::AutomaticallySearchAuthorPrompt,
+      ReadarrEvent::TriggerAutomaticAuthorSearch(1)
+    )]
+    #[case(
+      ActiveReadarrBlock::UpdateAndScanAuthorPrompt,
+      ReadarrEvent::UpdateAndScanAuthor(1)
+    )]
+    fn test_author_details_prompt_confirm_submit(
+      #[case] prompt_block: ActiveReadarrBlock,
+      #[case] expected_action: ReadarrEvent,
+    ) {
+      let mut app = App::test_default();
+      app.data.readarr_data.prompt_confirm = true;
+      app.data.readarr_data.authors.set_items(vec![author()]);
+      app.push_navigation_stack(ActiveReadarrBlock::AuthorDetails.into());
+      app.push_navigation_stack(prompt_block.into());
+
+      AuthorDetailsHandler::new(SUBMIT_KEY, &mut app, prompt_block, None).handle();
+
+      assert!(app.data.readarr_data.prompt_confirm);
+      assert_navigation_popped!(app, ActiveReadarrBlock::AuthorDetails.into());
+      assert_some_eq_x!(
+        &app.data.readarr_data.prompt_confirm_action,
+        &expected_action
+      );
+    }
+
+    #[rstest]
+    fn test_author_details_prompt_decline_submit(
+      #[values(
+        ActiveReadarrBlock::AutomaticallySearchAuthorPrompt,
+        ActiveReadarrBlock::UpdateAndScanAuthorPrompt
+      )]
+      prompt_block: ActiveReadarrBlock,
+    ) {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveReadarrBlock::AuthorDetails.into());
+      app.push_navigation_stack(prompt_block.into());
+
+      AuthorDetailsHandler::new(SUBMIT_KEY, &mut app, prompt_block, None).handle();
+
+      assert!(!app.data.readarr_data.prompt_confirm);
+      assert_navigation_popped!(app, ActiveReadarrBlock::AuthorDetails.into());
+      assert_none!(app.data.readarr_data.prompt_confirm_action);
+    }
+  }
+
+  mod test_handle_esc {
+    use crate::app::App;
+    use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+    use crate::assert_navigation_popped;
+    use crate::event::Key;
+    use crate::handlers::KeyEventHandler;
+    use crate::handlers::readarr_handlers::library::author_details_handler::AuthorDetailsHandler;
+    use crate::models::servarr_data::readarr::readarr_data::ActiveReadarrBlock;
+    use crate::network::readarr_network::readarr_network_test_utils::test_utils::book;
+    use rstest::rstest;
+
+    const ESC_KEY: Key = DEFAULT_KEYBINDINGS.esc.key;
+
+    #[rstest]
+    fn test_author_details_esc(
+      #[values(
+        ActiveReadarrBlock::AutomaticallySearchAuthorPrompt,
+        ActiveReadarrBlock::UpdateAndScanAuthorPrompt
+      )]
+      prompt_block: ActiveReadarrBlock,
+      #[values(true, false)] is_ready: bool,
+    ) {
+      let mut app = App::test_default();
+      app.is_loading = is_ready;
+      app.data.readarr_data.prompt_confirm = true;
+      app.push_navigation_stack(ActiveReadarrBlock::AuthorDetails.into());
+      app.push_navigation_stack(prompt_block.into());
+
+      AuthorDetailsHandler::new(ESC_KEY, &mut app, prompt_block, None).handle();
+
+      assert!(!app.data.readarr_data.prompt_confirm);
+      assert_navigation_popped!(app, ActiveReadarrBlock::AuthorDetails.into());
+    }
+
+    #[test]
+    fn test_author_details_esc_clears_books() {
+      let mut app = App::test_default();
+      app.data.readarr_data.books.set_items(vec![book()]);
+      app.push_navigation_stack(ActiveReadarrBlock::Authors.into());
+      app.push_navigation_stack(ActiveReadarrBlock::AuthorDetails.into());
+
+      AuthorDetailsHandler::new(ESC_KEY, &mut app, ActiveReadarrBlock::AuthorDetails, None)
+        .handle();
+
+      assert_navigation_popped!(app, ActiveReadarrBlock::Authors.into());
+      assert_is_empty!(app.data.readarr_data.books);
+    }
+  }
+
+  mod test_handle_char_key_event {
+    use crate::app::App;
+    use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+    use crate::assert_navigation_pushed;
+    use crate::handlers::KeyEventHandler;
+    use crate::handlers::readarr_handlers::library::author_details_handler::AuthorDetailsHandler;
+    use crate::models::readarr_models::Author;
+    use crate::models::servarr_data::readarr::readarr_data::{
+      ActiveReadarrBlock, EDIT_AUTHOR_SELECTION_BLOCKS,
+    };
+    use crate::network::readarr_network::ReadarrEvent;
+    use crate::{assert_modal_absent, assert_modal_present, assert_navigation_popped};
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    #[test]
+    fn test_author_details_edit_key() {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(ActiveReadarrBlock::AuthorDetails.into());
+      app.push_navigation_stack(ActiveReadarrBlock::AuthorDetails.into());
+
+      AuthorDetailsHandler::new(
+        DEFAULT_KEYBINDINGS.edit.key,
+        &mut app,
+        ActiveReadarrBlock::AuthorDetails,
+        None,
+      )
+      .handle();
+
+      assert_navigation_pushed!(
+        app,
+        (
+          ActiveReadarrBlock::EditAuthorPrompt,
+          Some(ActiveReadarrBlock::AuthorDetails)
+        )
+          .into()
+      );
+      assert_modal_present!(app.data.readarr_data.edit_author_modal);
+      assert_some!(app.data.readarr_data.edit_author_modal);
+      assert_eq!(
+        app.data.readarr_data.selected_block.blocks,
+        EDIT_AUTHOR_SELECTION_BLOCKS
+      );
+    }
+
+    #[test]
+    fn test_author_details_edit_key_no_op_when_not_ready() {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.push_navigation_stack(ActiveReadarrBlock::AuthorDetails.into());
+      app.push_navigation_stack(ActiveReadarrBlock::AuthorDetails.into());
+
+      AuthorDetailsHandler::new(
+        DEFAULT_KEYBINDINGS.edit.key,
+        &mut app,
+        ActiveReadarrBlock::AuthorDetails,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveReadarrBlock::AuthorDetails.into()
+      );
+      assert_modal_absent!(app.data.readarr_data.edit_author_modal);
+    }
+
+    #[test]
+    fn test_author_details_toggle_monitoring_key() {
+      let mut app = App::test_default_fully_populated();
+      app.is_routing = false;
+      app.push_navigation_stack(ActiveReadarrBlock::Authors.into());
+      app.push_navigation_stack(ActiveReadarrBlock::AuthorDetails.into());
+
+      AuthorDetailsHandler::new(
+        DEFAULT_KEYBINDINGS.toggle_monitoring.key,
+        &mut app,
+        ActiveReadarrBlock::AuthorDetails,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveReadarrBlock::AuthorDetails.into()
+      );
+      assert!(app.data.readarr_data.prompt_confirm);
+      assert!(app.is_routing);
+      assert_eq!(
+        app.data.readarr_data.prompt_confirm_action,
+        Some(ReadarrEvent::ToggleBookMonitoring(1))
+      );
+    }
+
+    #[test]
+    fn test_author_details_toggle_monitoring_key_no_op_when_not_ready() {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.data.readarr_data.prompt_confirm = false;
+      app.push_navigation_stack(ActiveReadarrBlock::AuthorDetails.into());
+
+      AuthorDetailsHandler::new(
+        DEFAULT_KEYBINDINGS.toggle_monitoring.key,
+        &mut app,
+        ActiveReadarrBlock::AuthorDetails,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveReadarrBlock::AuthorDetails.into()
+      );
+      assert!(!app.data.readarr_data.prompt_confirm);
+      assert_none!(app.data.readarr_data.prompt_confirm_action);
+    }
+
+    #[test]
+    fn test_author_details_toggle_monitoring_key_no_op_when_books_empty() {
+      let mut app = App::test_default();
+      app.data.readarr_data.authors.set_items(vec![Author {
+        id: 1,
+        ..Author::default()
+      }]);
+      app.push_navigation_stack(ActiveReadarrBlock::AuthorDetails.into());
+
+      AuthorDetailsHandler::new(
+        DEFAULT_KEYBINDINGS.toggle_monitoring.key,
+        &mut app,
+        ActiveReadarrBlock::AuthorDetails,
+        None,
+      )
+      .handle();
+
+      assert!(!app.data.readarr_data.prompt_confirm);
+      assert!(app.data.readarr_data.prompt_confirm_action.is_none());
+    }
+
+    #[test]
+    fn test_author_details_auto_search_key() {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(ActiveReadarrBlock::AuthorDetails.into());
+
+      AuthorDetailsHandler::new(
+        DEFAULT_KEYBINDINGS.auto_search.key,
+        &mut app,
+        ActiveReadarrBlock::AuthorDetails,
+        None,
+      )
+      .handle();
+
+      assert_navigation_pushed!(
+        app,
+        ActiveReadarrBlock::AutomaticallySearchAuthorPrompt.into()
+      );
+    }
+
+    #[test]
+    fn test_author_details_auto_search_key_no_op_when_not_ready() {
+      let mut app = App::test_default_fully_populated();
+      app.is_loading = true;
+      app.push_navigation_stack(ActiveReadarrBlock::AuthorDetails.into());
+
+      AuthorDetailsHandler::new(
+        DEFAULT_KEYBINDINGS.auto_search.key,
+        &mut app,
+        ActiveReadarrBlock::AuthorDetails,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveReadarrBlock::AuthorDetails.into()
+      );
+    }
+
+    #[test]
+    fn test_author_details_update_key() {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(ActiveReadarrBlock::AuthorDetails.into());
+
+      AuthorDetailsHandler::new(
+        DEFAULT_KEYBINDINGS.update.key,
+        &mut app,
+        ActiveReadarrBlock::AuthorDetails,
+        None,
+      )
+      .handle();
+
+      assert_navigation_pushed!(app, ActiveReadarrBlock::UpdateAndScanAuthorPrompt.into());
+    }
+
+    #[test]
+    fn test_author_details_update_key_no_op_when_not_ready() {
+      let mut app = App::test_default_fully_populated();
+      app.is_loading = true;
+      app.push_navigation_stack(ActiveReadarrBlock::AuthorDetails.into());
+
+      AuthorDetailsHandler::new(
+        DEFAULT_KEYBINDINGS.update.key,
+        &mut app,
+        ActiveReadarrBlock::AuthorDetails,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveReadarrBlock::AuthorDetails.into()
+      );
+    }
+
+    #[test]
+    fn test_author_details_refresh_key() {
+      let mut app = App::test_default_fully_populated();
+      app.is_routing = false;
+      app.push_navigation_stack(ActiveReadarrBlock::Authors.into());
+      app.push_navigation_stack(ActiveReadarrBlock::AuthorDetails.into());
+
+      AuthorDetailsHandler::new(
+        DEFAULT_KEYBINDINGS.refresh.key,
+        &mut app,
+        ActiveReadarrBlock::AuthorDetails,
+        None,
+      )
+      .handle();
+
+      assert_navigation_pushed!(app, ActiveReadarrBlock::AuthorDetails.into());
+      assert!(app.is_routing);
+    }
+
+    #[test]
+    fn test_author_details_refresh_key_no_op_when_not_ready() {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.push_navigation_stack(ActiveReadarrBlock::AuthorDetails.into());
+      app.is_routing = false;
+
+      AuthorDetailsHandler::new(
+        DEFAULT_KEYBINDINGS.refresh.key,
+        &mut app,
+        ActiveReadarrBlock::AuthorDetails,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveReadarrBlock::AuthorDetails.into()
+      );
+      assert!(!app.is_routing);
+    }
+
+    #[rstest]
+    #[case(
+      ActiveReadarrBlock::AutomaticallySearchAuthorPrompt,
+      ReadarrEvent::TriggerAutomaticAuthorSearch(1)
+    )]
+    #[case(
+      ActiveReadarrBlock::UpdateAndScanAuthorPrompt,
+      ReadarrEvent::UpdateAndScanAuthor(1)
+    )]
+    fn test_author_details_prompt_confirm_key(
+      #[case] prompt_block: ActiveReadarrBlock,
+      #[case] expected_action: ReadarrEvent,
+    ) {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(ActiveReadarrBlock::AuthorDetails.into());
+      app.push_navigation_stack(prompt_block.into());
+
+      AuthorDetailsHandler::new(
+        DEFAULT_KEYBINDINGS.confirm.key,
+        &mut app,
+        prompt_block,
+        None,
+      )
+      .handle();
+
+      assert!(app.data.readarr_data.prompt_confirm);
+      assert_navigation_popped!(app, ActiveReadarrBlock::AuthorDetails.into());
+      assert_some_eq_x!(
+        &app.data.readarr_data.prompt_confirm_action,
+        &expected_action
+      );
+    }
+  }
+
+  #[test]
+  fn test_author_details_handler_accepts() {
+    ActiveReadarrBlock::iter().for_each(|active_readarr_block| {
+      if AUTHOR_DETAILS_BLOCKS.contains(&active_readarr_block) {
+        assert!(AuthorDetailsHandler::accepts(active_readarr_block));
+      } else {
+        assert!(!AuthorDetailsHandler::accepts(active_readarr_block));
+      }
+    });
+  }
+
+  #[test]
+  fn test_extract_author_id() {
+    let mut app = App::test_default_fully_populated();
+
+    let author_id = AuthorDetailsHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveReadarrBlock::AuthorDetails,
+      None,
+    )
+    .extract_author_id();
+
+    assert_eq!(author_id, 1);
+  }
+
+  #[test]
+  fn test_extract_book_id() {
+    let mut app = App::test_default_fully_populated();
+
+    let book_id = AuthorDetailsHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveReadarrBlock::AuthorDetails,
+      None,
+    )
+    .extract_book_id();
+
+    assert_eq!(book_id, 1);
+  }
+
+  #[rstest]
+  fn test_author_details_handler_ignore_special_keys(
+    #[values(true, false)] ignore_special_keys_for_textbox_input: bool,
+  ) {
+    let mut app = App::test_default();
+    app.ignore_special_keys_for_textbox_input = ignore_special_keys_for_textbox_input;
+
+    let handler = AuthorDetailsHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveReadarrBlock::AuthorDetails,
+      None,
+    );
+
+    assert_eq!(
+      handler.ignore_special_keys(),
+      ignore_special_keys_for_textbox_input
+    );
+  }
+
+  #[test]
+  fn test_author_details_handler_is_not_ready_when_loading() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveReadarrBlock::AuthorDetails.into());
+    app.is_loading = true;
+
+    let handler = AuthorDetailsHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveReadarrBlock::AuthorDetails,
+      None,
+    );
+
+    assert!(!handler.is_ready());
+  }
+
+  #[test]
+  fn test_author_details_handler_is_ready_when_not_loading() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveReadarrBlock::AuthorDetails.into());
+    app.is_loading = false;
+
+    let handler = AuthorDetailsHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveReadarrBlock::AuthorDetails,
+      None,
+    );
+
+    assert!(handler.is_ready());
+  }
+}
@@ -0,0 +1,160 @@
+use crate::app::App;
+use crate::event::Key;
+use crate::handlers::table_handler::{TableHandlingConfig, handle_table};
+use crate::handlers::{KeyEventHandler, handle_prompt_toggle};
+use crate::matches_key;
+use crate::models::Route;
+use crate::models::readarr_models::Edition;
+use crate::models::servarr_data::readarr::readarr_data::{ActiveReadarrBlock, BOOK_DETAILS_BLOCKS};
+use crate::network::readarr_network::ReadarrEvent;
+
+#[cfg(test)]
+#[path = "book_details_handler_tests.rs"]
+mod book_details_handler_tests;
+
+pub(in crate::handlers::readarr_handlers) struct BookDetailsHandler<'a, 'b> {
+  key: Key,
+  app: &'a mut App<'b>,
+  active_readarr_block: ActiveReadarrBlock,
+  _context: Option<ActiveReadarrBlock>,
+}
+
+impl BookDetailsHandler<'_, '_> {
+  fn extract_book_id(&self) -> i64 {
+    self.app.data.readarr_data.books.current_selection().id
+  }
+}
+
+impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveReadarrBlock> for BookDetailsHandler<'a, 'b> {
+  fn handle(&mut self) {
+    let editions_table_handling_config =
+      TableHandlingConfig::new(ActiveReadarrBlock::BookDetails.into())
+        .searching_block(ActiveReadarrBlock::SearchEditions.into())
+        .search_error_block(ActiveReadarrBlock::SearchEditionsError.into())
+        .search_field_fn(|edition: &Edition| &edition.title);
+
+    if !handle_table(
+      self,
+      |app| {
+        &mut app
+          .data
+          .readarr_data
+          .book_details_modal
+          .as_mut()
+          .expect("Book details modal is undefined")
+          .editions
+      },
+      editions_table_handling_config,
+    ) {
+      self.handle_key_event();
+    }
+  }
+
+  fn accepts(active_block: ActiveReadarrBlock) -> bool {
+    BOOK_DETAILS_BLOCKS.contains(&active_block)
+  }
+
+  fn ignore_special_keys(&self) -> bool {
+    self.app.ignore_special_keys_for_textbox_input
+  }
+
+  fn new(
+    key: Key,
+    app: &'a mut App<'b>,
+    active_block: ActiveReadarrBlock,
+    context: Option<ActiveReadarrBlock>,
+  ) -> BookDetailsHandler<'a, 'b> {
+    BookDetailsHandler {
+      key,
+      app,
+      active_readarr_block: active_block,
+      _context: context,
+    }
+  }
+
+  fn get_key(&self) -> Key {
+    self.key
+  }
+
+  fn is_ready(&self) -> bool {
+    !self.app.is_loading && self.app.data.readarr_data.book_details_modal.is_some()
+  }
+
+  fn handle_scroll_up(&mut self) {}
+
+  fn handle_scroll_down(&mut self) {}
+
+  fn handle_home(&mut self) {}
+
+  fn handle_end(&mut self) {}
+
+  fn handle_delete(&mut self) {}
+
+  fn handle_left_right_action(&mut self) {
+    if self.active_readarr_block == ActiveReadarrBlock::AutomaticallySearchBookPrompt {
+      handle_prompt_toggle(self.app, self.key);
+    }
+  }
+
+  fn handle_submit(&mut self) {
+    if self.active_readarr_block == ActiveReadarrBlock::AutomaticallySearchBookPrompt {
+      if self.app.data.readarr_data.prompt_confirm {
+        self.app.data.readarr_data.prompt_confirm_action = Some(
+          ReadarrEvent::TriggerAutomaticBookSearch(self.extract_book_id()),
+        );
+      }
+
+      self.app.pop_navigation_stack();
+    }
+  }
+
+  fn handle_esc(&mut self) {
+    match self.active_readarr_block {
+      ActiveReadarrBlock::BookDetails => {
+        self.app.pop_navigation_stack();
+        self.app.data.readarr_data.book_details_modal = None;
+      }
+      ActiveReadarrBlock::AutomaticallySearchBookPrompt => {
+        self.app.pop_navigation_stack();
+        self.app.data.readarr_data.prompt_confirm = false;
+      }
+      _ => (),
+    }
+  }
+
+  fn handle_char_key_event(&mut self) {
+    let key = self.key;
+    match self.active_readarr_block {
+      ActiveReadarrBlock::BookDetails => match self.key {
+        _ if matches_key!(refresh, key) => {
+          self
+            .app
+            .pop_and_push_navigation_stack(self.active_readarr_block.into());
+        }
+        _ if matches_key!(auto_search, key) => {
+          self
+            .app
+            .push_navigation_stack(ActiveReadarrBlock::AutomaticallySearchBookPrompt.into());
+        }
+        _ => (),
+      },
+      ActiveReadarrBlock::AutomaticallySearchBookPrompt if matches_key!(confirm, key) => {
+        self.app.data.readarr_data.prompt_confirm = true;
+        self.app.data.readarr_data.prompt_confirm_action = Some(
+          ReadarrEvent::TriggerAutomaticBookSearch(self.extract_book_id()),
+        );
+
+        self.app.pop_navigation_stack();
+      }
+      _ => (),
+    }
+  }
+
+  fn app_mut(&mut self) -> &mut App<'b> {
+    self.app
+  }
+
+  fn current_route(&self) -> Route {
+    self.app.get_current_route()
+  }
+}
@@ -0,0 +1,337 @@
+#[cfg(test)]
+mod tests {
+  use crate::app::App;
+  use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+  use crate::assert_navigation_pushed;
+  use crate::handlers::KeyEventHandler;
+  use crate::handlers::readarr_handlers::library::book_details_handler::BookDetailsHandler;
+  use crate::models::servarr_data::readarr::readarr_data::{
+    ActiveReadarrBlock, BOOK_DETAILS_BLOCKS,
+  };
+  use pretty_assertions::assert_eq;
+  use rstest::rstest;
+  use strum::IntoEnumIterator;
+
+  mod test_handle_left_right_actions {
+    use super::*;
+    use crate::event::Key;
+
+    #[rstest]
+    fn test_left_right_prompt_toggle(#[values(Key::Left, Key::Right)] key: Key) {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(ActiveReadarrBlock::BookDetails.into());
+      app.push_navigation_stack(ActiveReadarrBlock::AutomaticallySearchBookPrompt.into());
+
+      BookDetailsHandler::new(
+        key,
+        &mut app,
+        ActiveReadarrBlock::AutomaticallySearchBookPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(app.data.readarr_data.prompt_confirm);
+
+      BookDetailsHandler::new(
+        key,
+        &mut app,
+        ActiveReadarrBlock::AutomaticallySearchBookPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(!app.data.readarr_data.prompt_confirm);
+    }
+  }
+
+  mod test_handle_submit {
+    use super::*;
+    use crate::assert_navigation_popped;
+    use crate::event::Key;
+    use crate::network::readarr_network::ReadarrEvent;
+
+    const SUBMIT_KEY: Key = DEFAULT_KEYBINDINGS.submit.key;
+
+    #[test]
+    fn test_automatically_search_book_prompt_confirm_submit() {
+      let mut app = App::test_default_fully_populated();
+      app.data.readarr_data.prompt_confirm = true;
+      app.push_navigation_stack(ActiveReadarrBlock::BookDetails.into());
+      app.push_navigation_stack(ActiveReadarrBlock::AutomaticallySearchBookPrompt.into());
+
+      BookDetailsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveReadarrBlock::AutomaticallySearchBookPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(app.data.readarr_data.prompt_confirm);
+      assert_navigation_popped!(app, ActiveReadarrBlock::BookDetails.into());
+      assert_some_eq_x!(
+        &app.data.readarr_data.prompt_confirm_action,
+        &ReadarrEvent::TriggerAutomaticBookSearch(1)
+      );
+    }
+
+    #[test]
+    fn test_automatically_search_book_prompt_decline_submit() {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(ActiveReadarrBlock::BookDetails.into());
+      app.push_navigation_stack(ActiveReadarrBlock::AutomaticallySearchBookPrompt.into());
+
+      BookDetailsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveReadarrBlock::AutomaticallySearchBookPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(!app.data.readarr_data.prompt_confirm);
+      assert_navigation_popped!(app, ActiveReadarrBlock::BookDetails.into());
+      assert_none!(app.data.readarr_data.prompt_confirm_action);
+    }
+  }
+
+  mod test_handle_esc {
+    use super::*;
+    use crate::assert_navigation_popped;
+    use crate::event::Key;
+
+    const ESC_KEY: Key = DEFAULT_KEYBINDINGS.esc.key;
+
+    #[rstest]
+    fn test_automatically_search_book_prompt_esc(#[values(true, false)] is_ready: bool) {
+      let mut app = App::test_default_fully_populated();
+      app.is_loading = is_ready;
+      app.data.readarr_data.prompt_confirm = true;
+      app.push_navigation_stack(ActiveReadarrBlock::BookDetails.into());
+      app.push_navigation_stack(ActiveReadarrBlock::AutomaticallySearchBookPrompt.into());
+
+      BookDetailsHandler::new(
+        ESC_KEY,
+        &mut app,
+        ActiveReadarrBlock::AutomaticallySearchBookPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(!app.data.readarr_data.prompt_confirm);
+      assert_navigation_popped!(app, ActiveReadarrBlock::BookDetails.into());
+    }
+
+    #[test]
+    fn test_book_details_esc() {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(ActiveReadarrBlock::AuthorDetails.into());
+      app.push_navigation_stack(ActiveReadarrBlock::BookDetails.into());
+
+      BookDetailsHandler::new(ESC_KEY, &mut app, ActiveReadarrBlock::BookDetails, None).handle();
+
+      assert_navigation_popped!(app, ActiveReadarrBlock::AuthorDetails.into());
+      assert_none!(app.data.readarr_data.book_details_modal);
+    }
+  }
+
+  mod test_handle_key_char {
+    use super::*;
+    use crate::assert_navigation_popped;
+    use crate::network::readarr_network::ReadarrEvent;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_auto_search_key() {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(ActiveReadarrBlock::BookDetails.into());
+
+      BookDetailsHandler::new(
+        DEFAULT_KEYBINDINGS.auto_search.key,
+        &mut app,
+        ActiveReadarrBlock::BookDetails,
+        None,
+      )
+      .handle();
+
+      assert_navigation_pushed!(
+        app,
+        ActiveReadarrBlock::AutomaticallySearchBookPrompt.into()
+      );
+    }
+
+    #[test]
+    fn test_auto_search_key_no_op_when_not_ready() {
+      let mut app = App::test_default_fully_populated();
+      app.is_loading = true;
+      app.push_navigation_stack(ActiveReadarrBlock::BookDetails.into());
+
+      BookDetailsHandler::new(
+        DEFAULT_KEYBINDINGS.auto_search.key,
+        &mut app,
+        ActiveReadarrBlock::BookDetails,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveReadarrBlock::BookDetails.into()
+      );
+    }
+
+    #[test]
+    fn test_refresh_key() {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(ActiveReadarrBlock::AuthorDetails.into());
+      app.push_navigation_stack(ActiveReadarrBlock::BookDetails.into());
+      app.is_routing = false;
+
+      BookDetailsHandler::new(
+        DEFAULT_KEYBINDINGS.refresh.key,
+        &mut app,
+        ActiveReadarrBlock::BookDetails,
+        None,
+      )
+      .handle();
+
+      assert_navigation_pushed!(app, ActiveReadarrBlock::BookDetails.into());
+      assert!(app.is_routing);
+    }
+
+    #[test]
+    fn test_refresh_key_no_op_when_not_ready() {
+      let mut app = App::test_default_fully_populated();
+      app.is_loading = true;
+      app.push_navigation_stack(ActiveReadarrBlock::BookDetails.into());
+      app.is_routing = false;
+
+      BookDetailsHandler::new(
+        DEFAULT_KEYBINDINGS.refresh.key,
+        &mut app,
+        ActiveReadarrBlock::BookDetails,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveReadarrBlock::BookDetails.into()
+      );
+      assert!(!app.is_routing);
+    }
+
+    #[test]
+    fn test_automatically_search_book_prompt_confirm_key() {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(ActiveReadarrBlock::BookDetails.into());
+      app.push_navigation_stack(ActiveReadarrBlock::AutomaticallySearchBookPrompt.into());
+
+      BookDetailsHandler::new(
+        DEFAULT_KEYBINDINGS.confirm.key,
+        &mut app,
+        ActiveReadarrBlock::AutomaticallySearchBookPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(app.data.readarr_data.prompt_confirm);
+      assert_navigation_popped!(app, ActiveReadarrBlock::BookDetails.into());
+      assert_some_eq_x!(
+        &app.data.readarr_data.prompt_confirm_action,
+        &ReadarrEvent::TriggerAutomaticBookSearch(1)
+      );
+    }
+  }
+
+  #[test]
+  fn test_book_details_handler_accepts() {
+    ActiveReadarrBlock::iter().for_each(|active_readarr_block| {
+      if BOOK_DETAILS_BLOCKS.contains(&active_readarr_block) {
+        assert!(BookDetailsHandler::accepts(active_readarr_block));
+      } else {
+        assert!(!BookDetailsHandler::accepts(active_readarr_block));
+      }
+    });
+  }
+
+  #[rstest]
+  fn test_book_details_handler_ignore_special_keys(
+    #[values(true, false)] ignore_special_keys_for_textbox_input: bool,
+  ) {
+    let mut app = App::test_default();
+    app.ignore_special_keys_for_textbox_input = ignore_special_keys_for_textbox_input;
+    let handler = BookDetailsHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveReadarrBlock::default(),
+      None,
+    );
+
+    assert_eq!(
+      handler.ignore_special_keys(),
+      ignore_special_keys_for_textbox_input
+    );
+  }
+
+  #[test]
+  fn test_extract_book_id() {
+    let mut app = App::test_default_fully_populated();
+
+    let book_id = BookDetailsHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveReadarrBlock::BookDetails,
+      None,
+    )
+    .extract_book_id();
+
+    assert_eq!(book_id, 1);
+  }
+
+  #[test]
+  fn test_book_details_handler_is_not_ready_when_loading() {
+    let mut app = App::test_default_fully_populated();
+    app.push_navigation_stack(ActiveReadarrBlock::BookDetails.into());
+    app.is_loading = true;
+
+    let handler = BookDetailsHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveReadarrBlock::BookDetails,
+      None,
+    );
+
+    assert!(!handler.is_ready());
+  }
+
+  #[test]
+  fn test_book_details_handler_is_not_ready_when_not_loading_and_book_details_is_none() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveReadarrBlock::BookDetails.into());
+
+    let handler = BookDetailsHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveReadarrBlock::BookDetails,
+      None,
+    );
+
+    assert!(!handler.is_ready());
+  }
+
+  #[test]
+  fn test_book_details_handler_is_ready_when_not_loading_and_book_details_modal_is_populated() {
+    let mut app = App::test_default_fully_populated();
+    app.push_navigation_stack(ActiveReadarrBlock::BookDetails.into());
+
+    let handler = BookDetailsHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveReadarrBlock::BookDetails,
+      None,
+    );
+
+    assert!(handler.is_ready());
+  }
+}
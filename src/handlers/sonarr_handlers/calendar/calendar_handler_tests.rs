@@ -0,0 +1,247 @@
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+  use rstest::rstest;
+  use strum::IntoEnumIterator;
+
+  use crate::app::App;
+  use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+  use crate::assert_navigation_pushed;
+  use crate::handlers::KeyEventHandler;
+  use crate::handlers::sonarr_handlers::calendar::CalendarHandler;
+  use crate::models::servarr_data::sonarr::sonarr_data::{ActiveSonarrBlock, CALENDAR_BLOCKS};
+
+  mod test_handle_left_right_action {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::assert_navigation_pushed;
+
+    #[rstest]
+    fn test_calendar_tab_left(
+      #[values(ActiveSonarrBlock::Calendar, ActiveSonarrBlock::CalendarAgenda)]
+      active_sonarr_block: ActiveSonarrBlock,
+      #[values(true, false)] is_ready: bool,
+    ) {
+      let mut app = App::test_default();
+      app.push_navigation_stack(active_sonarr_block.into());
+      app.is_loading = is_ready;
+      app.data.sonarr_data.main_tabs.set_index(10);
+
+      CalendarHandler::new(
+        DEFAULT_KEYBINDINGS.left.key,
+        &mut app,
+        active_sonarr_block,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.data.sonarr_data.main_tabs.get_active_route(),
+        ActiveSonarrBlock::System.into()
+      );
+      assert_navigation_pushed!(app, ActiveSonarrBlock::System.into());
+    }
+
+    #[rstest]
+    fn test_calendar_tab_right(
+      #[values(ActiveSonarrBlock::Calendar, ActiveSonarrBlock::CalendarAgenda)]
+      active_sonarr_block: ActiveSonarrBlock,
+      #[values(true, false)] is_ready: bool,
+    ) {
+      let mut app = App::test_default();
+      app.push_navigation_stack(active_sonarr_block.into());
+      app.is_loading = is_ready;
+      app.data.sonarr_data.main_tabs.set_index(10);
+
+      CalendarHandler::new(
+        DEFAULT_KEYBINDINGS.right.key,
+        &mut app,
+        active_sonarr_block,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.data.sonarr_data.main_tabs.get_active_route(),
+        ActiveSonarrBlock::WantedMissing.into()
+      );
+      assert_navigation_pushed!(app, ActiveSonarrBlock::WantedMissing.into());
+    }
+  }
+
+  mod test_handle_esc {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[rstest]
+    fn test_calendar_esc_clears_errors(
+      #[values(ActiveSonarrBlock::Calendar, ActiveSonarrBlock::CalendarAgenda)]
+      active_sonarr_block: ActiveSonarrBlock,
+    ) {
+      let mut app = App::test_default();
+      app.push_navigation_stack(active_sonarr_block.into());
+      app.error = "test error".to_owned().into();
+
+      CalendarHandler::new(
+        DEFAULT_KEYBINDINGS.esc.key,
+        &mut app,
+        active_sonarr_block,
+        None,
+      )
+      .handle();
+
+      assert_eq!(app.get_current_route(), active_sonarr_block.into());
+      assert_is_empty!(app.error.text);
+    }
+  }
+
+  mod test_handle_key_char {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[rstest]
+    #[case(ActiveSonarrBlock::Calendar, ActiveSonarrBlock::CalendarAgenda)]
+    #[case(ActiveSonarrBlock::CalendarAgenda, ActiveSonarrBlock::Calendar)]
+    fn test_toggle_view_key(
+      #[case] active_sonarr_block: ActiveSonarrBlock,
+      #[case] expected_sonarr_block: ActiveSonarrBlock,
+    ) {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveSonarrBlock::Series.into());
+      app.push_navigation_stack(active_sonarr_block.into());
+
+      CalendarHandler::new(
+        DEFAULT_KEYBINDINGS.toggle_view.key,
+        &mut app,
+        active_sonarr_block,
+        None,
+      )
+      .handle();
+
+      assert_navigation_pushed!(app, expected_sonarr_block.into());
+      app.pop_navigation_stack();
+      assert_eq!(app.get_current_route(), ActiveSonarrBlock::Series.into());
+    }
+
+    #[test]
+    fn test_toggle_view_key_no_op_when_not_ready() {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.push_navigation_stack(ActiveSonarrBlock::Calendar.into());
+
+      CalendarHandler::new(
+        DEFAULT_KEYBINDINGS.toggle_view.key,
+        &mut app,
+        ActiveSonarrBlock::Calendar,
+        None,
+      )
+      .handle();
+
+      assert_eq!(app.get_current_route(), ActiveSonarrBlock::Calendar.into());
+    }
+
+    #[rstest]
+    fn test_refresh_calendar_key(
+      #[values(ActiveSonarrBlock::Calendar, ActiveSonarrBlock::CalendarAgenda)]
+      active_sonarr_block: ActiveSonarrBlock,
+    ) {
+      let mut app = App::test_default();
+      app.push_navigation_stack(active_sonarr_block.into());
+
+      CalendarHandler::new(
+        DEFAULT_KEYBINDINGS.refresh.key,
+        &mut app,
+        active_sonarr_block,
+        None,
+      )
+      .handle();
+
+      assert_eq!(app.get_current_route(), active_sonarr_block.into());
+      assert!(app.should_refresh);
+    }
+
+    #[test]
+    fn test_refresh_calendar_key_no_op_when_not_ready() {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.push_navigation_stack(ActiveSonarrBlock::Calendar.into());
+
+      CalendarHandler::new(
+        DEFAULT_KEYBINDINGS.refresh.key,
+        &mut app,
+        ActiveSonarrBlock::Calendar,
+        None,
+      )
+      .handle();
+
+      assert_eq!(app.get_current_route(), ActiveSonarrBlock::Calendar.into());
+      assert!(!app.should_refresh);
+    }
+  }
+
+  #[test]
+  fn test_calendar_handler_accepts() {
+    ActiveSonarrBlock::iter().for_each(|active_sonarr_block| {
+      if CALENDAR_BLOCKS.contains(&active_sonarr_block) {
+        assert!(CalendarHandler::accepts(active_sonarr_block));
+      } else {
+        assert!(!CalendarHandler::accepts(active_sonarr_block));
+      }
+    })
+  }
+
+  #[rstest]
+  fn test_calendar_handler_ignore_special_keys(
+    #[values(true, false)] ignore_special_keys_for_textbox_input: bool,
+  ) {
+    let mut app = App::test_default();
+    app.ignore_special_keys_for_textbox_input = ignore_special_keys_for_textbox_input;
+    let handler = CalendarHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveSonarrBlock::default(),
+      None,
+    );
+
+    assert_eq!(
+      handler.ignore_special_keys(),
+      ignore_special_keys_for_textbox_input
+    );
+  }
+
+  #[test]
+  fn test_calendar_handler_not_ready_when_loading() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveSonarrBlock::Calendar.into());
+    app.is_loading = true;
+
+    let handler = CalendarHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveSonarrBlock::Calendar,
+      None,
+    );
+
+    assert!(!handler.is_ready());
+  }
+
+  #[test]
+  fn test_calendar_handler_ready_when_not_loading() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveSonarrBlock::Calendar.into());
+    app.is_loading = false;
+
+    let handler = CalendarHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveSonarrBlock::Calendar,
+      None,
+    );
+
+    assert!(handler.is_ready());
+  }
+}
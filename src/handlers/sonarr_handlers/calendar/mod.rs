@@ -0,0 +1,108 @@
+use crate::app::App;
+use crate::event::Key;
+use crate::handlers::sonarr_handlers::handle_change_tab_left_right_keys;
+use crate::handlers::table_handler::{TableHandlingConfig, handle_table};
+use crate::handlers::{KeyEventHandler, handle_clear_errors};
+use crate::matches_key;
+use crate::models::Route;
+use crate::models::servarr_data::sonarr::sonarr_data::{ActiveSonarrBlock, CALENDAR_BLOCKS};
+
+#[cfg(test)]
+#[path = "calendar_handler_tests.rs"]
+mod calendar_handler_tests;
+
+pub(super) struct CalendarHandler<'a, 'b> {
+  key: Key,
+  app: &'a mut App<'b>,
+  active_sonarr_block: ActiveSonarrBlock,
+  _context: Option<ActiveSonarrBlock>,
+}
+
+impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for CalendarHandler<'a, 'b> {
+  fn handle(&mut self) {
+    let calendar_table_handling_config =
+      TableHandlingConfig::new(ActiveSonarrBlock::CalendarAgenda.into());
+
+    if !handle_table(
+      self,
+      |app| &mut app.data.sonarr_data.calendar,
+      calendar_table_handling_config,
+    ) {
+      self.handle_key_event();
+    }
+  }
+
+  fn accepts(active_block: ActiveSonarrBlock) -> bool {
+    CALENDAR_BLOCKS.contains(&active_block)
+  }
+
+  fn ignore_special_keys(&self) -> bool {
+    self.app.ignore_special_keys_for_textbox_input
+  }
+
+  fn new(
+    key: Key,
+    app: &'a mut App<'b>,
+    active_block: ActiveSonarrBlock,
+    _context: Option<ActiveSonarrBlock>,
+  ) -> CalendarHandler<'a, 'b> {
+    CalendarHandler {
+      key,
+      app,
+      active_sonarr_block: active_block,
+      _context,
+    }
+  }
+
+  fn get_key(&self) -> Key {
+    self.key
+  }
+
+  fn is_ready(&self) -> bool {
+    !self.app.is_loading
+  }
+
+  fn handle_scroll_up(&mut self) {}
+
+  fn handle_scroll_down(&mut self) {}
+
+  fn handle_home(&mut self) {}
+
+  fn handle_end(&mut self) {}
+
+  fn handle_delete(&mut self) {}
+
+  fn handle_left_right_action(&mut self) {
+    handle_change_tab_left_right_keys(self.app, self.key);
+  }
+
+  fn handle_submit(&mut self) {}
+
+  fn handle_esc(&mut self) {
+    handle_clear_errors(self.app);
+  }
+
+  fn handle_char_key_event(&mut self) {
+    let key = self.key;
+    match self.active_sonarr_block {
+      ActiveSonarrBlock::Calendar if matches_key!(toggle_view, key) => self
+        .app
+        .pop_and_push_navigation_stack(ActiveSonarrBlock::CalendarAgenda.into()),
+      ActiveSonarrBlock::CalendarAgenda if matches_key!(toggle_view, key) => self
+        .app
+        .pop_and_push_navigation_stack(ActiveSonarrBlock::Calendar.into()),
+      _ if matches_key!(refresh, key) => {
+        self.app.should_refresh = true;
+      }
+      _ => (),
+    }
+  }
+
+  fn app_mut(&mut self) -> &mut App<'b> {
+    self.app
+  }
+
+  fn current_route(&self) -> Route {
+    self.app.get_current_route()
+  }
+}
@@ -0,0 +1,194 @@
+use crate::app::App;
+use crate::event::Key;
+use crate::handlers::KeyEventHandler;
+use crate::models::servarr_data::modals::{CustomFormatModal, SchemaFormFieldKind};
+use crate::models::servarr_data::sonarr::sonarr_data::{
+  ActiveSonarrBlock, CUSTOM_FORMAT_FORM_BLOCKS,
+};
+use crate::models::servarr_models::EditCustomFormatParams;
+use crate::models::{Route, Scrollable};
+use crate::network::sonarr_network::SonarrEvent;
+use crate::{handle_text_box_keys, handle_text_box_left_right_keys, matches_key};
+
+#[cfg(test)]
+#[path = "custom_format_form_handler_tests.rs"]
+mod custom_format_form_handler_tests;
+
+pub(super) struct CustomFormatFormHandler<'a, 'b> {
+  key: Key,
+  app: &'a mut App<'b>,
+  active_sonarr_block: ActiveSonarrBlock,
+  _context: Option<ActiveSonarrBlock>,
+}
+
+impl CustomFormatFormHandler<'_, '_> {
+  fn custom_format_modal(&mut self) -> &mut CustomFormatModal {
+    self
+      .app
+      .data
+      .sonarr_data
+      .custom_format_modal
+      .as_mut()
+      .expect("Custom Format Modal is None")
+  }
+}
+
+impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for CustomFormatFormHandler<'a, 'b> {
+  fn accepts(active_block: ActiveSonarrBlock) -> bool {
+    CUSTOM_FORMAT_FORM_BLOCKS.contains(&active_block)
+  }
+
+  fn ignore_special_keys(&self) -> bool {
+    self.app.ignore_special_keys_for_textbox_input
+  }
+
+  fn new(
+    key: Key,
+    app: &'a mut App<'b>,
+    active_block: ActiveSonarrBlock,
+    _context: Option<ActiveSonarrBlock>,
+  ) -> CustomFormatFormHandler<'a, 'b> {
+    CustomFormatFormHandler {
+      key,
+      app,
+      active_sonarr_block: active_block,
+      _context,
+    }
+  }
+
+  fn get_key(&self) -> Key {
+    self.key
+  }
+
+  fn is_ready(&self) -> bool {
+    !self.app.is_loading && self.app.data.sonarr_data.custom_format_modal.is_some()
+  }
+
+  fn handle_scroll_up(&mut self) {
+    if self.active_sonarr_block == ActiveSonarrBlock::EditCustomFormatPrompt {
+      self.custom_format_modal().fields.scroll_up();
+    }
+  }
+
+  fn handle_scroll_down(&mut self) {
+    if self.active_sonarr_block == ActiveSonarrBlock::EditCustomFormatPrompt {
+      self.custom_format_modal().fields.scroll_down();
+    }
+  }
+
+  fn handle_home(&mut self) {
+    match self.active_sonarr_block {
+      ActiveSonarrBlock::EditCustomFormatPrompt => {
+        self.custom_format_modal().fields.scroll_to_top()
+      }
+      ActiveSonarrBlock::CustomFormatFieldInput => self
+        .custom_format_modal()
+        .current_field_mut()
+        .value
+        .scroll_home(),
+      _ => (),
+    }
+  }
+
+  fn handle_end(&mut self) {
+    match self.active_sonarr_block {
+      ActiveSonarrBlock::EditCustomFormatPrompt => {
+        self.custom_format_modal().fields.scroll_to_bottom()
+      }
+      ActiveSonarrBlock::CustomFormatFieldInput => self
+        .custom_format_modal()
+        .current_field_mut()
+        .value
+        .reset_offset(),
+      _ => (),
+    }
+  }
+
+  fn handle_delete(&mut self) {}
+
+  fn handle_left_right_action(&mut self) {
+    if self.active_sonarr_block == ActiveSonarrBlock::CustomFormatFieldInput {
+      handle_text_box_left_right_keys!(
+        self,
+        self.key,
+        self.custom_format_modal().current_field_mut().value
+      );
+    }
+  }
+
+  fn handle_submit(&mut self) {
+    match self.active_sonarr_block {
+      ActiveSonarrBlock::EditCustomFormatPrompt => {
+        let custom_format_modal = self.custom_format_modal();
+        match custom_format_modal.current_field_mut().kind {
+          SchemaFormFieldKind::Checkbox | SchemaFormFieldKind::Select(_) => {
+            custom_format_modal.toggle_current_field()
+          }
+          _ => {
+            self
+              .app
+              .push_navigation_stack(ActiveSonarrBlock::CustomFormatFieldInput.into());
+            self.app.ignore_special_keys_for_textbox_input = true;
+          }
+        }
+      }
+      ActiveSonarrBlock::CustomFormatFieldInput => {
+        self.app.pop_navigation_stack();
+        self.app.ignore_special_keys_for_textbox_input = false;
+      }
+      _ => (),
+    }
+  }
+
+  fn handle_esc(&mut self) {
+    match self.active_sonarr_block {
+      ActiveSonarrBlock::EditCustomFormatPrompt => {
+        self.app.pop_navigation_stack();
+        self.app.data.sonarr_data.prompt_confirm = false;
+        self.app.data.sonarr_data.custom_format_modal = None;
+      }
+      ActiveSonarrBlock::CustomFormatFieldInput => {
+        self.app.pop_navigation_stack();
+        self.app.ignore_special_keys_for_textbox_input = false;
+      }
+      _ => self.app.pop_navigation_stack(),
+    }
+  }
+
+  fn handle_char_key_event(&mut self) {
+    match self.active_sonarr_block {
+      ActiveSonarrBlock::CustomFormatFieldInput => {
+        handle_text_box_keys!(
+          self,
+          self.key,
+          self.custom_format_modal().current_field_mut().value
+        );
+      }
+      ActiveSonarrBlock::EditCustomFormatPrompt if matches_key!(confirm, self.key) => {
+        let custom_format_modal = self
+          .app
+          .data
+          .sonarr_data
+          .custom_format_modal
+          .take()
+          .expect("Custom Format Modal is None");
+        self.app.data.sonarr_data.prompt_confirm = true;
+        self.app.data.sonarr_data.prompt_confirm_action = Some(SonarrEvent::EditCustomFormat(
+          EditCustomFormatParams::from(&custom_format_modal),
+        ));
+        self.app.should_refresh = true;
+
+        self.app.pop_navigation_stack();
+      }
+      _ => (),
+    }
+  }
+
+  fn app_mut(&mut self) -> &mut App<'b> {
+    self.app
+  }
+
+  fn current_route(&self) -> Route {
+    self.app.get_current_route()
+  }
+}
@@ -0,0 +1,494 @@
+#[cfg(test)]
+mod tests {
+  use crate::app::App;
+  use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+  use crate::assert_modal_absent;
+  use crate::assert_modal_present;
+  use crate::assert_navigation_popped;
+  use crate::assert_navigation_pushed;
+  use crate::event::Key;
+  use crate::handlers::KeyEventHandler;
+  use crate::handlers::sonarr_handlers::custom_formats::custom_format_form_handler::CustomFormatFormHandler;
+  use crate::models::servarr_data::modals::{CustomFormatModal, SchemaFormField};
+  use crate::models::servarr_data::sonarr::sonarr_data::{
+    ActiveSonarrBlock, CUSTOM_FORMAT_FORM_BLOCKS,
+  };
+  use crate::models::servarr_models::EditCustomFormatParams;
+  use crate::network::servarr_test_utils::custom_format;
+  use crate::network::sonarr_network::SonarrEvent;
+  use pretty_assertions::assert_eq;
+  use rstest::rstest;
+  use strum::IntoEnumIterator;
+
+  fn custom_format_modal() -> CustomFormatModal {
+    CustomFormatModal::from(&custom_format())
+  }
+
+  fn select_field(app: &mut App<'_>, name: &str) {
+    let fields = &mut app
+      .data
+      .sonarr_data
+      .custom_format_modal
+      .as_mut()
+      .unwrap()
+      .fields;
+    let index = fields
+      .items
+      .iter()
+      .position(|field| field.name == name)
+      .unwrap();
+    fields.state.select(Some(index));
+  }
+
+  fn current_field(app: &App<'_>) -> SchemaFormField {
+    app
+      .data
+      .sonarr_data
+      .custom_format_modal
+      .as_ref()
+      .unwrap()
+      .fields
+      .current_selection()
+      .clone()
+  }
+
+  mod test_handle_scroll_up_and_down {
+    use pretty_assertions::assert_str_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_edit_custom_format_prompt_scroll(#[values(Key::Up, Key::Down)] key: Key) {
+      let mut app = App::test_default();
+      app.data.sonarr_data.custom_format_modal = Some(custom_format_modal());
+
+      CustomFormatFormHandler::new(
+        key,
+        &mut app,
+        ActiveSonarrBlock::EditCustomFormatPrompt,
+        None,
+      )
+      .handle();
+
+      if key == Key::Up {
+        assert_str_eq!(current_field(&app).name, "x265.value");
+      } else {
+        assert_str_eq!(current_field(&app).name, "includeCustomFormatWhenRenaming");
+      }
+    }
+
+    #[rstest]
+    fn test_edit_custom_format_prompt_scroll_no_op_when_not_ready(
+      #[values(Key::Up, Key::Down)] key: Key,
+    ) {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.data.sonarr_data.custom_format_modal = Some(custom_format_modal());
+
+      CustomFormatFormHandler::new(
+        key,
+        &mut app,
+        ActiveSonarrBlock::EditCustomFormatPrompt,
+        None,
+      )
+      .handle();
+
+      assert_str_eq!(current_field(&app).name, "name");
+    }
+  }
+
+  mod test_handle_home_end {
+    use std::sync::atomic::Ordering;
+
+    use pretty_assertions::{assert_eq, assert_str_eq};
+
+    use super::*;
+
+    #[test]
+    fn test_edit_custom_format_prompt_home_end() {
+      let mut app = App::test_default();
+      app.data.sonarr_data.custom_format_modal = Some(custom_format_modal());
+
+      CustomFormatFormHandler::new(
+        DEFAULT_KEYBINDINGS.end.key,
+        &mut app,
+        ActiveSonarrBlock::EditCustomFormatPrompt,
+        None,
+      )
+      .handle();
+
+      assert_str_eq!(current_field(&app).name, "x265.value");
+
+      CustomFormatFormHandler::new(
+        DEFAULT_KEYBINDINGS.home.key,
+        &mut app,
+        ActiveSonarrBlock::EditCustomFormatPrompt,
+        None,
+      )
+      .handle();
+
+      assert_str_eq!(current_field(&app).name, "name");
+    }
+
+    #[test]
+    fn test_custom_format_field_input_home_end() {
+      let mut app = App::test_default();
+      app.data.sonarr_data.custom_format_modal = Some(custom_format_modal());
+
+      CustomFormatFormHandler::new(
+        DEFAULT_KEYBINDINGS.home.key,
+        &mut app,
+        ActiveSonarrBlock::CustomFormatFieldInput,
+        None,
+      )
+      .handle();
+
+      assert_eq!(current_field(&app).value.offset.load(Ordering::SeqCst), 4);
+
+      CustomFormatFormHandler::new(
+        DEFAULT_KEYBINDINGS.end.key,
+        &mut app,
+        ActiveSonarrBlock::CustomFormatFieldInput,
+        None,
+      )
+      .handle();
+
+      assert_eq!(current_field(&app).value.offset.load(Ordering::SeqCst), 0);
+    }
+  }
+
+  mod test_handle_left_right_action {
+    use std::sync::atomic::Ordering;
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_custom_format_field_input_left_right_keys() {
+      let mut app = App::test_default();
+      app.data.sonarr_data.custom_format_modal = Some(custom_format_modal());
+
+      CustomFormatFormHandler::new(
+        DEFAULT_KEYBINDINGS.left.key,
+        &mut app,
+        ActiveSonarrBlock::CustomFormatFieldInput,
+        None,
+      )
+      .handle();
+
+      assert_eq!(current_field(&app).value.offset.load(Ordering::SeqCst), 1);
+
+      CustomFormatFormHandler::new(
+        DEFAULT_KEYBINDINGS.right.key,
+        &mut app,
+        ActiveSonarrBlock::CustomFormatFieldInput,
+        None,
+      )
+      .handle();
+
+      assert_eq!(current_field(&app).value.offset.load(Ordering::SeqCst), 0);
+    }
+  }
+
+  mod test_handle_submit {
+    use pretty_assertions::{assert_eq, assert_str_eq};
+
+    use super::*;
+
+    const SUBMIT_KEY: Key = DEFAULT_KEYBINDINGS.submit.key;
+
+    #[test]
+    fn test_edit_custom_format_prompt_submit_text_field() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveSonarrBlock::EditCustomFormatPrompt.into());
+      app.data.sonarr_data.custom_format_modal = Some(custom_format_modal());
+
+      CustomFormatFormHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveSonarrBlock::EditCustomFormatPrompt,
+        None,
+      )
+      .handle();
+
+      assert_navigation_pushed!(app, ActiveSonarrBlock::CustomFormatFieldInput.into());
+      assert!(app.ignore_special_keys_for_textbox_input);
+    }
+
+    #[test]
+    fn test_edit_custom_format_prompt_submit_toggles_checkbox() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveSonarrBlock::EditCustomFormatPrompt.into());
+      app.data.sonarr_data.custom_format_modal = Some(custom_format_modal());
+      select_field(&mut app, "x265.required");
+
+      CustomFormatFormHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveSonarrBlock::EditCustomFormatPrompt,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveSonarrBlock::EditCustomFormatPrompt.into()
+      );
+      assert_str_eq!(current_field(&app).value.text, "false");
+
+      CustomFormatFormHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveSonarrBlock::EditCustomFormatPrompt,
+        None,
+      )
+      .handle();
+
+      assert_str_eq!(current_field(&app).value.text, "true");
+    }
+
+    #[test]
+    fn test_custom_format_field_input_submit() {
+      let mut app = App::test_default();
+      app.ignore_special_keys_for_textbox_input = true;
+      app.push_navigation_stack(ActiveSonarrBlock::EditCustomFormatPrompt.into());
+      app.push_navigation_stack(ActiveSonarrBlock::CustomFormatFieldInput.into());
+      app.data.sonarr_data.custom_format_modal = Some(custom_format_modal());
+
+      CustomFormatFormHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveSonarrBlock::CustomFormatFieldInput,
+        None,
+      )
+      .handle();
+
+      assert_navigation_popped!(app, ActiveSonarrBlock::EditCustomFormatPrompt.into());
+      assert!(!app.ignore_special_keys_for_textbox_input);
+    }
+  }
+
+  mod test_handle_esc {
+    use super::*;
+
+    const ESC_KEY: Key = DEFAULT_KEYBINDINGS.esc.key;
+
+    #[test]
+    fn test_edit_custom_format_prompt_esc() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveSonarrBlock::CustomFormats.into());
+      app.push_navigation_stack(ActiveSonarrBlock::EditCustomFormatPrompt.into());
+      app.data.sonarr_data.custom_format_modal = Some(custom_format_modal());
+      app.data.sonarr_data.prompt_confirm = true;
+
+      CustomFormatFormHandler::new(
+        ESC_KEY,
+        &mut app,
+        ActiveSonarrBlock::EditCustomFormatPrompt,
+        None,
+      )
+      .handle();
+
+      assert_navigation_popped!(app, ActiveSonarrBlock::CustomFormats.into());
+      assert!(!app.data.sonarr_data.prompt_confirm);
+      assert_modal_absent!(app.data.sonarr_data.custom_format_modal);
+    }
+
+    #[test]
+    fn test_custom_format_field_input_esc() {
+      let mut app = App::test_default();
+      app.ignore_special_keys_for_textbox_input = true;
+      app.push_navigation_stack(ActiveSonarrBlock::EditCustomFormatPrompt.into());
+      app.push_navigation_stack(ActiveSonarrBlock::CustomFormatFieldInput.into());
+      app.data.sonarr_data.custom_format_modal = Some(custom_format_modal());
+
+      CustomFormatFormHandler::new(
+        ESC_KEY,
+        &mut app,
+        ActiveSonarrBlock::CustomFormatFieldInput,
+        None,
+      )
+      .handle();
+
+      assert_navigation_popped!(app, ActiveSonarrBlock::EditCustomFormatPrompt.into());
+      assert!(!app.ignore_special_keys_for_textbox_input);
+      assert_modal_present!(app.data.sonarr_data.custom_format_modal);
+    }
+  }
+
+  mod test_handle_key_char {
+    use pretty_assertions::{assert_eq, assert_str_eq};
+
+    use super::*;
+
+    #[test]
+    fn test_custom_format_field_input_backspace() {
+      let mut app = App::test_default();
+      app.data.sonarr_data.custom_format_modal = Some(custom_format_modal());
+
+      CustomFormatFormHandler::new(
+        DEFAULT_KEYBINDINGS.backspace.key,
+        &mut app,
+        ActiveSonarrBlock::CustomFormatFieldInput,
+        None,
+      )
+      .handle();
+
+      assert_str_eq!(current_field(&app).value.text, "x26");
+    }
+
+    #[test]
+    fn test_custom_format_field_input_char_key() {
+      let mut app = App::test_default();
+      app.data.sonarr_data.custom_format_modal = Some(custom_format_modal());
+
+      CustomFormatFormHandler::new(
+        Key::Char('5'),
+        &mut app,
+        ActiveSonarrBlock::CustomFormatFieldInput,
+        None,
+      )
+      .handle();
+
+      assert_str_eq!(current_field(&app).value.text, "x2655");
+    }
+
+    #[test]
+    fn test_edit_custom_format_prompt_confirm() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveSonarrBlock::CustomFormats.into());
+      app.push_navigation_stack(ActiveSonarrBlock::EditCustomFormatPrompt.into());
+      app.data.sonarr_data.custom_format_modal = Some(custom_format_modal());
+      let expected_edit_custom_format_params = EditCustomFormatParams {
+        custom_format_id: 1,
+        name: Some("x265".to_owned()),
+        include_custom_format_when_renaming: Some(false),
+        fields: vec![
+          ("x265.negate".to_owned(), "false".to_owned()),
+          ("x265.required".to_owned(), "true".to_owned()),
+          ("x265.value".to_owned(), "\\bx265\\b".to_owned()),
+        ],
+      };
+
+      CustomFormatFormHandler::new(
+        DEFAULT_KEYBINDINGS.confirm.key,
+        &mut app,
+        ActiveSonarrBlock::EditCustomFormatPrompt,
+        None,
+      )
+      .handle();
+
+      assert_navigation_popped!(app, ActiveSonarrBlock::CustomFormats.into());
+      assert!(app.data.sonarr_data.prompt_confirm);
+      assert!(app.should_refresh);
+      assert_modal_absent!(app.data.sonarr_data.custom_format_modal);
+      assert_eq!(
+        app.data.sonarr_data.prompt_confirm_action,
+        Some(SonarrEvent::EditCustomFormat(
+          expected_edit_custom_format_params
+        ))
+      );
+    }
+
+    #[test]
+    fn test_edit_custom_format_prompt_confirm_no_op_when_not_ready() {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.push_navigation_stack(ActiveSonarrBlock::CustomFormats.into());
+      app.push_navigation_stack(ActiveSonarrBlock::EditCustomFormatPrompt.into());
+      app.data.sonarr_data.custom_format_modal = Some(custom_format_modal());
+
+      CustomFormatFormHandler::new(
+        DEFAULT_KEYBINDINGS.confirm.key,
+        &mut app,
+        ActiveSonarrBlock::EditCustomFormatPrompt,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveSonarrBlock::EditCustomFormatPrompt.into()
+      );
+      assert!(app.data.sonarr_data.prompt_confirm_action.is_none());
+      assert_modal_present!(app.data.sonarr_data.custom_format_modal);
+    }
+  }
+
+  #[test]
+  fn test_custom_format_form_handler_accepts() {
+    ActiveSonarrBlock::iter().for_each(|active_sonarr_block| {
+      if CUSTOM_FORMAT_FORM_BLOCKS.contains(&active_sonarr_block) {
+        assert!(CustomFormatFormHandler::accepts(active_sonarr_block));
+      } else {
+        assert!(!CustomFormatFormHandler::accepts(active_sonarr_block));
+      }
+    })
+  }
+
+  #[rstest]
+  fn test_custom_format_form_handler_ignore_special_keys(
+    #[values(true, false)] ignore_special_keys_for_textbox_input: bool,
+  ) {
+    let mut app = App::test_default();
+    app.ignore_special_keys_for_textbox_input = ignore_special_keys_for_textbox_input;
+    let handler = CustomFormatFormHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveSonarrBlock::default(),
+      None,
+    );
+
+    assert_eq!(
+      handler.ignore_special_keys(),
+      ignore_special_keys_for_textbox_input
+    );
+  }
+
+  #[test]
+  fn test_custom_format_form_handler_is_not_ready_when_loading() {
+    let mut app = App::test_default();
+    app.is_loading = true;
+    app.data.sonarr_data.custom_format_modal = Some(custom_format_modal());
+
+    let handler = CustomFormatFormHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveSonarrBlock::EditCustomFormatPrompt,
+      None,
+    );
+
+    assert!(!handler.is_ready());
+  }
+
+  #[test]
+  fn test_custom_format_form_handler_is_not_ready_when_custom_format_modal_is_none() {
+    let mut app = App::test_default();
+
+    let handler = CustomFormatFormHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveSonarrBlock::EditCustomFormatPrompt,
+      None,
+    );
+
+    assert!(!handler.is_ready());
+  }
+
+  #[test]
+  fn test_custom_format_form_handler_is_ready_when_custom_format_modal_is_some() {
+    let mut app = App::test_default();
+    app.data.sonarr_data.custom_format_modal = Some(custom_format_modal());
+
+    let handler = CustomFormatFormHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveSonarrBlock::EditCustomFormatPrompt,
+      None,
+    );
+
+    assert!(handler.is_ready());
+  }
+}
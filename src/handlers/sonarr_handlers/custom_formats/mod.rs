@@ -0,0 +1,323 @@
+use std::fs;
+
+use crate::app::App;
+use crate::event::Key;
+use crate::handlers::sonarr_handlers::custom_formats::custom_format_form_handler::CustomFormatFormHandler;
+use crate::handlers::sonarr_handlers::handle_change_tab_left_right_keys;
+use crate::handlers::table_handler::{TableHandlingConfig, handle_table};
+use crate::handlers::{KeyEventHandler, handle_clear_errors, handle_prompt_toggle};
+use crate::models::servarr_data::Notification;
+use crate::models::servarr_data::modals::CustomFormatModal;
+use crate::models::servarr_data::sonarr::sonarr_data::{ActiveSonarrBlock, CUSTOM_FORMATS_BLOCKS};
+use crate::models::{HorizontallyScrollableText, Route};
+use crate::network::sonarr_network::SonarrEvent;
+use crate::{handle_text_box_keys, handle_text_box_left_right_keys, matches_key};
+
+mod custom_format_form_handler;
+
+#[cfg(test)]
+#[path = "custom_formats_handler_tests.rs"]
+mod custom_formats_handler_tests;
+
+pub(super) struct CustomFormatsHandler<'a, 'b> {
+  key: Key,
+  app: &'a mut App<'b>,
+  active_sonarr_block: ActiveSonarrBlock,
+  context: Option<ActiveSonarrBlock>,
+}
+
+impl CustomFormatsHandler<'_, '_> {
+  fn extract_custom_format_id(&self) -> i64 {
+    self
+      .app
+      .data
+      .sonarr_data
+      .custom_formats
+      .current_selection()
+      .id
+  }
+
+  fn custom_format_path(&mut self) -> &mut HorizontallyScrollableText {
+    self
+      .app
+      .data
+      .sonarr_data
+      .custom_format_path
+      .as_mut()
+      .expect("Custom format path is undefined")
+  }
+
+  fn take_custom_format_path(&mut self) -> String {
+    self
+      .app
+      .data
+      .sonarr_data
+      .custom_format_path
+      .take()
+      .expect("Custom format path is undefined")
+      .text
+      .trim()
+      .to_owned()
+  }
+
+  fn import_custom_format(&mut self) {
+    let path = self.take_custom_format_path();
+
+    match fs::read_to_string(&path) {
+      Ok(custom_format_json) => {
+        self.app.data.sonarr_data.prompt_confirm = true;
+        self.app.data.sonarr_data.prompt_confirm_action =
+          Some(SonarrEvent::ImportCustomFormat(custom_format_json));
+      }
+      Err(e) => {
+        self.app.notification = Some(Notification::new(
+          "Import Failed".to_owned(),
+          format!("Unable to read '{path}': {e}"),
+          false,
+        ));
+      }
+    }
+  }
+
+  fn export_custom_format(&mut self) {
+    let path = self.take_custom_format_path();
+    let custom_format = self.app.data.sonarr_data.custom_formats.current_selection();
+    let custom_format_json = serde_json::to_string_pretty(&custom_format.to_trash_json())
+      .expect("Custom format JSON is always serializable");
+
+    self.app.notification = Some(match fs::write(&path, custom_format_json) {
+      Ok(()) => Notification::new(
+        "Export Result".to_owned(),
+        format!("Exported '{}' to '{path}'", custom_format.name),
+        true,
+      ),
+      Err(e) => Notification::new(
+        "Export Failed".to_owned(),
+        format!("Unable to write '{path}': {e}"),
+        false,
+      ),
+    });
+  }
+}
+
+impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for CustomFormatsHandler<'a, 'b> {
+  fn handle(&mut self) {
+    let custom_formats_table_handling_config =
+      TableHandlingConfig::new(ActiveSonarrBlock::CustomFormats.into());
+
+    if !handle_table(
+      self,
+      |app| &mut app.data.sonarr_data.custom_formats,
+      custom_formats_table_handling_config,
+    ) {
+      match self.active_sonarr_block {
+        _ if CustomFormatFormHandler::accepts(self.active_sonarr_block) => {
+          CustomFormatFormHandler::new(self.key, self.app, self.active_sonarr_block, self.context)
+            .handle()
+        }
+        _ => self.handle_key_event(),
+      }
+    }
+  }
+
+  fn accepts(active_block: ActiveSonarrBlock) -> bool {
+    CustomFormatFormHandler::accepts(active_block) || CUSTOM_FORMATS_BLOCKS.contains(&active_block)
+  }
+
+  fn ignore_special_keys(&self) -> bool {
+    self.app.ignore_special_keys_for_textbox_input
+  }
+
+  fn new(
+    key: Key,
+    app: &'a mut App<'b>,
+    active_block: ActiveSonarrBlock,
+    context: Option<ActiveSonarrBlock>,
+  ) -> CustomFormatsHandler<'a, 'b> {
+    CustomFormatsHandler {
+      key,
+      app,
+      active_sonarr_block: active_block,
+      context,
+    }
+  }
+
+  fn get_key(&self) -> Key {
+    self.key
+  }
+
+  /// Importing doesn't need a selected custom format, so it's allowed even when there are none yet
+  fn is_ready(&self) -> bool {
+    let is_importing = match self.active_sonarr_block {
+      ActiveSonarrBlock::CustomFormats => matches_key!(import, self.key),
+      ActiveSonarrBlock::ImportCustomFormatPrompt => true,
+      _ => false,
+    };
+
+    !self.app.is_loading && (is_importing || !self.app.data.sonarr_data.custom_formats.is_empty())
+  }
+
+  fn handle_scroll_up(&mut self) {}
+
+  fn handle_scroll_down(&mut self) {}
+
+  fn handle_home(&mut self) {
+    if matches!(
+      self.active_sonarr_block,
+      ActiveSonarrBlock::ImportCustomFormatPrompt | ActiveSonarrBlock::ExportCustomFormatPrompt
+    ) {
+      self.custom_format_path().scroll_home();
+    }
+  }
+
+  fn handle_end(&mut self) {
+    if matches!(
+      self.active_sonarr_block,
+      ActiveSonarrBlock::ImportCustomFormatPrompt | ActiveSonarrBlock::ExportCustomFormatPrompt
+    ) {
+      self.custom_format_path().reset_offset();
+    }
+  }
+
+  fn handle_delete(&mut self) {
+    if self.active_sonarr_block == ActiveSonarrBlock::CustomFormats {
+      self
+        .app
+        .push_navigation_stack(ActiveSonarrBlock::DeleteCustomFormatPrompt.into());
+    }
+  }
+
+  fn handle_left_right_action(&mut self) {
+    match self.active_sonarr_block {
+      ActiveSonarrBlock::CustomFormats => handle_change_tab_left_right_keys(self.app, self.key),
+      ActiveSonarrBlock::DeleteCustomFormatPrompt => handle_prompt_toggle(self.app, self.key),
+      ActiveSonarrBlock::ImportCustomFormatPrompt | ActiveSonarrBlock::ExportCustomFormatPrompt => {
+        handle_text_box_left_right_keys!(self, self.key, self.custom_format_path())
+      }
+      _ => (),
+    }
+  }
+
+  fn handle_submit(&mut self) {
+    match self.active_sonarr_block {
+      ActiveSonarrBlock::CustomFormats => self
+        .app
+        .push_navigation_stack(ActiveSonarrBlock::CustomFormatDetails.into()),
+      ActiveSonarrBlock::DeleteCustomFormatPrompt => {
+        let custom_format_id = self.extract_custom_format_id();
+        let sonarr_data = &mut self.app.data.sonarr_data;
+        if sonarr_data.prompt_confirm {
+          sonarr_data.prompt_confirm_action =
+            Some(SonarrEvent::DeleteCustomFormat(custom_format_id));
+        }
+
+        self.app.pop_navigation_stack();
+      }
+      _ if matches!(
+        self.active_sonarr_block,
+        ActiveSonarrBlock::ImportCustomFormatPrompt | ActiveSonarrBlock::ExportCustomFormatPrompt
+      ) && self
+        .app
+        .data
+        .sonarr_data
+        .custom_format_path
+        .as_ref()
+        .is_some_and(|path| !path.text.trim().is_empty()) =>
+      {
+        if self.active_sonarr_block == ActiveSonarrBlock::ImportCustomFormatPrompt {
+          self.import_custom_format();
+        } else {
+          self.export_custom_format();
+        }
+
+        self.app.ignore_special_keys_for_textbox_input = false;
+        self.app.pop_navigation_stack();
+      }
+      _ => (),
+    }
+  }
+
+  fn handle_esc(&mut self) {
+    match self.active_sonarr_block {
+      ActiveSonarrBlock::CustomFormatDetails => self.app.pop_navigation_stack(),
+      ActiveSonarrBlock::DeleteCustomFormatPrompt => {
+        self.app.pop_navigation_stack();
+        self.app.data.sonarr_data.prompt_confirm = false;
+      }
+      ActiveSonarrBlock::ImportCustomFormatPrompt | ActiveSonarrBlock::ExportCustomFormatPrompt => {
+        self.app.pop_navigation_stack();
+        self.app.data.sonarr_data.custom_format_path = None;
+        self.app.ignore_special_keys_for_textbox_input = false;
+      }
+      _ => handle_clear_errors(self.app),
+    }
+  }
+
+  fn handle_char_key_event(&mut self) {
+    let key = self.key;
+    match self.active_sonarr_block {
+      ActiveSonarrBlock::CustomFormats => match self.key {
+        _ if matches_key!(refresh, key) => {
+          self.app.should_refresh = true;
+        }
+        _ if matches_key!(edit, key) => {
+          self.app.data.sonarr_data.custom_format_modal = Some(CustomFormatModal::from(
+            self.app.data.sonarr_data.custom_formats.current_selection(),
+          ));
+          self
+            .app
+            .push_navigation_stack(ActiveSonarrBlock::EditCustomFormatPrompt.into());
+        }
+        _ if matches_key!(import, key) => {
+          self.app.data.sonarr_data.custom_format_path =
+            Some(HorizontallyScrollableText::default());
+          self
+            .app
+            .push_navigation_stack(ActiveSonarrBlock::ImportCustomFormatPrompt.into());
+          self.app.ignore_special_keys_for_textbox_input = true;
+        }
+        _ if matches_key!(export, key) => {
+          let file_name = format!(
+            "{}.json",
+            self
+              .app
+              .data
+              .sonarr_data
+              .custom_formats
+              .current_selection()
+              .name
+              .replace(['/', '\\'], "_")
+          );
+          self.app.data.sonarr_data.custom_format_path = Some(file_name.into());
+          self
+            .app
+            .push_navigation_stack(ActiveSonarrBlock::ExportCustomFormatPrompt.into());
+          self.app.ignore_special_keys_for_textbox_input = true;
+        }
+        _ => (),
+      },
+      ActiveSonarrBlock::DeleteCustomFormatPrompt => {
+        if matches_key!(confirm, key) {
+          self.app.data.sonarr_data.prompt_confirm = true;
+          self.app.data.sonarr_data.prompt_confirm_action = Some(SonarrEvent::DeleteCustomFormat(
+            self.extract_custom_format_id(),
+          ));
+
+          self.app.pop_navigation_stack();
+        }
+      }
+      ActiveSonarrBlock::ImportCustomFormatPrompt | ActiveSonarrBlock::ExportCustomFormatPrompt => {
+        handle_text_box_keys!(self, key, self.custom_format_path())
+      }
+      _ => (),
+    }
+  }
+
+  fn app_mut(&mut self) -> &mut App<'b> {
+    self.app
+  }
+
+  fn current_route(&self) -> Route {
+    self.app.get_current_route()
+  }
+}
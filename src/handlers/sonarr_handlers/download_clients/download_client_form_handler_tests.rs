@@ -0,0 +1,729 @@
+#[cfg(test)]
+mod tests {
+  use crate::app::App;
+  use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+  use crate::assert_modal_absent;
+  use crate::assert_modal_present;
+  use crate::assert_navigation_popped;
+  use crate::assert_navigation_pushed;
+  use crate::event::Key;
+  use crate::handlers::KeyEventHandler;
+  use crate::handlers::sonarr_handlers::download_clients::download_client_form_handler::DownloadClientFormHandler;
+  use crate::models::servarr_data::modals::{
+    DownloadClientModal, SchemaFormField, SchemaFormFieldKind,
+  };
+  use crate::models::servarr_data::sonarr::sonarr_data::{
+    ActiveSonarrBlock, DOWNLOAD_CLIENT_FORM_BLOCKS,
+  };
+  use crate::models::servarr_models::{
+    AddDownloadClientParams, DownloadClient, EditDownloadClientParams, IndexerFieldSelectOption,
+  };
+  use crate::network::servarr_test_utils::download_client;
+  use crate::network::sonarr_network::SonarrEvent;
+  use pretty_assertions::assert_eq;
+  use rstest::rstest;
+  use strum::IntoEnumIterator;
+
+  fn download_client_modal() -> DownloadClientModal {
+    DownloadClientModal::from(&download_client())
+  }
+
+  fn select_field(app: &mut App<'_>, name: &str) {
+    let fields = &mut app
+      .data
+      .sonarr_data
+      .download_client_modal
+      .as_mut()
+      .unwrap()
+      .fields;
+    let index = fields
+      .items
+      .iter()
+      .position(|field| field.name == name)
+      .unwrap();
+    fields.state.select(Some(index));
+  }
+
+  fn current_field(app: &App<'_>) -> SchemaFormField {
+    app
+      .data
+      .sonarr_data
+      .download_client_modal
+      .as_ref()
+      .unwrap()
+      .fields
+      .current_selection()
+      .clone()
+  }
+
+  mod test_handle_scroll_up_and_down {
+    use pretty_assertions::assert_str_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_add_download_client_select_implementation_scroll(
+      #[values(Key::Up, Key::Down)] key: Key,
+    ) {
+      let mut app = App::test_default();
+      app.data.sonarr_data.download_client_schema.set_items(vec![
+        DownloadClient {
+          implementation_name: Some("qBittorrent".to_owned()),
+          ..DownloadClient::default()
+        },
+        DownloadClient {
+          implementation_name: Some("SABnzbd".to_owned()),
+          ..DownloadClient::default()
+        },
+      ]);
+
+      DownloadClientFormHandler::new(
+        key,
+        &mut app,
+        ActiveSonarrBlock::AddDownloadClientSelectImplementation,
+        None,
+      )
+      .handle();
+
+      assert_str_eq!(
+        app
+          .data
+          .sonarr_data
+          .download_client_schema
+          .current_selection()
+          .implementation_name
+          .as_ref()
+          .unwrap(),
+        "SABnzbd"
+      );
+    }
+
+    #[rstest]
+    fn test_download_client_prompt_scroll(#[values(Key::Up, Key::Down)] key: Key) {
+      let mut app = App::test_default();
+      app.data.sonarr_data.download_client_modal = Some(download_client_modal());
+
+      DownloadClientFormHandler::new(key, &mut app, ActiveSonarrBlock::DownloadClientPrompt, None)
+        .handle();
+
+      if key == Key::Up {
+        assert_str_eq!(current_field(&app).name, "category");
+      } else {
+        assert_str_eq!(current_field(&app).name, "enable");
+      }
+    }
+
+    #[rstest]
+    fn test_download_client_prompt_scroll_no_op_when_not_ready(
+      #[values(Key::Up, Key::Down)] key: Key,
+    ) {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.data.sonarr_data.download_client_modal = Some(download_client_modal());
+
+      DownloadClientFormHandler::new(key, &mut app, ActiveSonarrBlock::DownloadClientPrompt, None)
+        .handle();
+
+      assert_str_eq!(current_field(&app).name, "name");
+    }
+  }
+
+  mod test_handle_home_end {
+    use std::sync::atomic::Ordering;
+
+    use pretty_assertions::{assert_eq, assert_str_eq};
+
+    use super::*;
+
+    #[test]
+    fn test_download_client_prompt_home_end() {
+      let mut app = App::test_default();
+      app.data.sonarr_data.download_client_modal = Some(download_client_modal());
+
+      DownloadClientFormHandler::new(
+        DEFAULT_KEYBINDINGS.end.key,
+        &mut app,
+        ActiveSonarrBlock::DownloadClientPrompt,
+        None,
+      )
+      .handle();
+
+      assert_str_eq!(current_field(&app).name, "category");
+
+      DownloadClientFormHandler::new(
+        DEFAULT_KEYBINDINGS.home.key,
+        &mut app,
+        ActiveSonarrBlock::DownloadClientPrompt,
+        None,
+      )
+      .handle();
+
+      assert_str_eq!(current_field(&app).name, "name");
+    }
+
+    #[test]
+    fn test_download_client_field_input_home_end() {
+      let mut app = App::test_default();
+      app.data.sonarr_data.download_client_modal = Some(download_client_modal());
+
+      DownloadClientFormHandler::new(
+        DEFAULT_KEYBINDINGS.home.key,
+        &mut app,
+        ActiveSonarrBlock::DownloadClientFieldInput,
+        None,
+      )
+      .handle();
+
+      assert_eq!(current_field(&app).value.offset.load(Ordering::SeqCst), 11);
+
+      DownloadClientFormHandler::new(
+        DEFAULT_KEYBINDINGS.end.key,
+        &mut app,
+        ActiveSonarrBlock::DownloadClientFieldInput,
+        None,
+      )
+      .handle();
+
+      assert_eq!(current_field(&app).value.offset.load(Ordering::SeqCst), 0);
+    }
+  }
+
+  mod test_handle_left_right_action {
+    use std::sync::atomic::Ordering;
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_download_client_field_input_left_right_keys() {
+      let mut app = App::test_default();
+      app.data.sonarr_data.download_client_modal = Some(download_client_modal());
+
+      DownloadClientFormHandler::new(
+        DEFAULT_KEYBINDINGS.left.key,
+        &mut app,
+        ActiveSonarrBlock::DownloadClientFieldInput,
+        None,
+      )
+      .handle();
+
+      assert_eq!(current_field(&app).value.offset.load(Ordering::SeqCst), 1);
+
+      DownloadClientFormHandler::new(
+        DEFAULT_KEYBINDINGS.right.key,
+        &mut app,
+        ActiveSonarrBlock::DownloadClientFieldInput,
+        None,
+      )
+      .handle();
+
+      assert_eq!(current_field(&app).value.offset.load(Ordering::SeqCst), 0);
+    }
+  }
+
+  mod test_handle_submit {
+    use pretty_assertions::{assert_eq, assert_str_eq};
+
+    use super::*;
+
+    const SUBMIT_KEY: Key = DEFAULT_KEYBINDINGS.submit.key;
+
+    #[test]
+    fn test_add_download_client_select_implementation_submit() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveSonarrBlock::DownloadClients.into());
+      app.push_navigation_stack(ActiveSonarrBlock::AddDownloadClientSelectImplementation.into());
+      app
+        .data
+        .sonarr_data
+        .download_client_schema
+        .set_items(vec![download_client()]);
+
+      DownloadClientFormHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveSonarrBlock::AddDownloadClientSelectImplementation,
+        None,
+      )
+      .handle();
+
+      assert_navigation_popped!(app, ActiveSonarrBlock::DownloadClientPrompt.into());
+      assert_modal_present!(app.data.sonarr_data.download_client_modal);
+      let download_client_modal = app.data.sonarr_data.download_client_modal.as_ref().unwrap();
+      assert_str_eq!(download_client_modal.implementation, "QBittorrent");
+      assert_none!(download_client_modal.id);
+      app.pop_navigation_stack();
+      assert_eq!(
+        app.get_current_route(),
+        ActiveSonarrBlock::DownloadClients.into()
+      );
+    }
+
+    #[test]
+    fn test_add_download_client_select_implementation_submit_no_op_when_schema_is_empty() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveSonarrBlock::DownloadClients.into());
+      app.push_navigation_stack(ActiveSonarrBlock::AddDownloadClientSelectImplementation.into());
+
+      DownloadClientFormHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveSonarrBlock::AddDownloadClientSelectImplementation,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveSonarrBlock::AddDownloadClientSelectImplementation.into()
+      );
+      assert_modal_absent!(app.data.sonarr_data.download_client_modal);
+    }
+
+    #[test]
+    fn test_download_client_prompt_submit_text_field() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveSonarrBlock::DownloadClientPrompt.into());
+      app.data.sonarr_data.download_client_modal = Some(download_client_modal());
+
+      DownloadClientFormHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveSonarrBlock::DownloadClientPrompt,
+        None,
+      )
+      .handle();
+
+      assert_navigation_pushed!(app, ActiveSonarrBlock::DownloadClientFieldInput.into());
+      assert!(app.ignore_special_keys_for_textbox_input);
+    }
+
+    #[test]
+    fn test_download_client_prompt_submit_toggles_checkbox() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveSonarrBlock::DownloadClientPrompt.into());
+      app.data.sonarr_data.download_client_modal = Some(download_client_modal());
+      select_field(&mut app, "enable");
+
+      DownloadClientFormHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveSonarrBlock::DownloadClientPrompt,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveSonarrBlock::DownloadClientPrompt.into()
+      );
+      assert_str_eq!(current_field(&app).value.text, "false");
+
+      DownloadClientFormHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveSonarrBlock::DownloadClientPrompt,
+        None,
+      )
+      .handle();
+
+      assert_str_eq!(current_field(&app).value.text, "true");
+    }
+
+    #[test]
+    fn test_download_client_prompt_submit_cycles_select_options() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveSonarrBlock::DownloadClientPrompt.into());
+      let mut download_client_modal = DownloadClientModal::default();
+      download_client_modal
+        .fields
+        .set_items(vec![SchemaFormField {
+          name: "initialState".to_owned(),
+          label: "Initial State".to_owned(),
+          kind: SchemaFormFieldKind::Select(vec![
+            IndexerFieldSelectOption {
+              value: 0,
+              name: "Start".to_owned(),
+            },
+            IndexerFieldSelectOption {
+              value: 1,
+              name: "Pause".to_owned(),
+            },
+          ]),
+          value: "0".into(),
+          ..SchemaFormField::default()
+        }]);
+      app.data.sonarr_data.download_client_modal = Some(download_client_modal);
+
+      DownloadClientFormHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveSonarrBlock::DownloadClientPrompt,
+        None,
+      )
+      .handle();
+
+      assert_str_eq!(current_field(&app).value.text, "1");
+
+      DownloadClientFormHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveSonarrBlock::DownloadClientPrompt,
+        None,
+      )
+      .handle();
+
+      assert_str_eq!(current_field(&app).value.text, "0");
+    }
+
+    #[test]
+    fn test_download_client_field_input_submit() {
+      let mut app = App::test_default();
+      app.ignore_special_keys_for_textbox_input = true;
+      app.push_navigation_stack(ActiveSonarrBlock::DownloadClientPrompt.into());
+      app.push_navigation_stack(ActiveSonarrBlock::DownloadClientFieldInput.into());
+      app.data.sonarr_data.download_client_modal = Some(download_client_modal());
+
+      DownloadClientFormHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveSonarrBlock::DownloadClientFieldInput,
+        None,
+      )
+      .handle();
+
+      assert_navigation_popped!(app, ActiveSonarrBlock::DownloadClientPrompt.into());
+      assert!(!app.ignore_special_keys_for_textbox_input);
+    }
+  }
+
+  mod test_handle_esc {
+    use super::*;
+
+    const ESC_KEY: Key = DEFAULT_KEYBINDINGS.esc.key;
+
+    #[test]
+    fn test_add_download_client_select_implementation_esc() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveSonarrBlock::DownloadClients.into());
+      app.push_navigation_stack(ActiveSonarrBlock::AddDownloadClientSelectImplementation.into());
+
+      DownloadClientFormHandler::new(
+        ESC_KEY,
+        &mut app,
+        ActiveSonarrBlock::AddDownloadClientSelectImplementation,
+        None,
+      )
+      .handle();
+
+      assert_navigation_popped!(app, ActiveSonarrBlock::DownloadClients.into());
+    }
+
+    #[test]
+    fn test_download_client_prompt_esc() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveSonarrBlock::DownloadClients.into());
+      app.push_navigation_stack(ActiveSonarrBlock::DownloadClientPrompt.into());
+      app.data.sonarr_data.download_client_modal = Some(download_client_modal());
+      app.data.sonarr_data.prompt_confirm = true;
+
+      DownloadClientFormHandler::new(
+        ESC_KEY,
+        &mut app,
+        ActiveSonarrBlock::DownloadClientPrompt,
+        None,
+      )
+      .handle();
+
+      assert_navigation_popped!(app, ActiveSonarrBlock::DownloadClients.into());
+      assert!(!app.data.sonarr_data.prompt_confirm);
+      assert_modal_absent!(app.data.sonarr_data.download_client_modal);
+    }
+
+    #[test]
+    fn test_download_client_field_input_esc() {
+      let mut app = App::test_default();
+      app.ignore_special_keys_for_textbox_input = true;
+      app.push_navigation_stack(ActiveSonarrBlock::DownloadClientPrompt.into());
+      app.push_navigation_stack(ActiveSonarrBlock::DownloadClientFieldInput.into());
+      app.data.sonarr_data.download_client_modal = Some(download_client_modal());
+
+      DownloadClientFormHandler::new(
+        ESC_KEY,
+        &mut app,
+        ActiveSonarrBlock::DownloadClientFieldInput,
+        None,
+      )
+      .handle();
+
+      assert_navigation_popped!(app, ActiveSonarrBlock::DownloadClientPrompt.into());
+      assert!(!app.ignore_special_keys_for_textbox_input);
+      assert_modal_present!(app.data.sonarr_data.download_client_modal);
+    }
+  }
+
+  mod test_handle_key_char {
+    use pretty_assertions::{assert_eq, assert_str_eq};
+
+    use super::*;
+
+    #[test]
+    fn test_download_client_field_input_backspace() {
+      let mut app = App::test_default();
+      app.data.sonarr_data.download_client_modal = Some(download_client_modal());
+
+      DownloadClientFormHandler::new(
+        DEFAULT_KEYBINDINGS.backspace.key,
+        &mut app,
+        ActiveSonarrBlock::DownloadClientFieldInput,
+        None,
+      )
+      .handle();
+
+      assert_str_eq!(current_field(&app).value.text, "qBittorren");
+    }
+
+    #[test]
+    fn test_download_client_field_input_char_key() {
+      let mut app = App::test_default();
+      app.data.sonarr_data.download_client_modal = Some(download_client_modal());
+
+      DownloadClientFormHandler::new(
+        Key::Char('s'),
+        &mut app,
+        ActiveSonarrBlock::DownloadClientFieldInput,
+        None,
+      )
+      .handle();
+
+      assert_str_eq!(current_field(&app).value.text, "qBittorrents");
+    }
+
+    #[test]
+    fn test_add_download_client_prompt_confirm() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveSonarrBlock::DownloadClients.into());
+      app.push_navigation_stack(ActiveSonarrBlock::DownloadClientPrompt.into());
+      app.data.sonarr_data.download_client_modal = Some(download_client_modal());
+      let expected_add_download_client_params = AddDownloadClientParams {
+        implementation: "QBittorrent".to_owned(),
+        name: "qBittorrent".to_owned(),
+        fields: vec![
+          ("host".to_owned(), "localhost".to_owned()),
+          ("port".to_owned(), "8080".to_owned()),
+          ("category".to_owned(), "managarr".to_owned()),
+        ],
+        ..AddDownloadClientParams::default()
+      };
+
+      DownloadClientFormHandler::new(
+        DEFAULT_KEYBINDINGS.confirm.key,
+        &mut app,
+        ActiveSonarrBlock::DownloadClientPrompt,
+        None,
+      )
+      .handle();
+
+      assert_navigation_popped!(app, ActiveSonarrBlock::DownloadClients.into());
+      assert!(app.data.sonarr_data.prompt_confirm);
+      assert!(app.should_refresh);
+      assert_modal_absent!(app.data.sonarr_data.download_client_modal);
+      assert_eq!(
+        app.data.sonarr_data.prompt_confirm_action,
+        Some(SonarrEvent::AddDownloadClient(
+          expected_add_download_client_params
+        ))
+      );
+    }
+
+    #[test]
+    fn test_edit_download_client_prompt_confirm() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveSonarrBlock::DownloadClients.into());
+      app.push_navigation_stack(ActiveSonarrBlock::DownloadClientPrompt.into());
+      let mut download_client_modal = download_client_modal();
+      download_client_modal.id = Some(1);
+      download_client_modal.set_field_value("tags", "usenet".to_owned());
+      app.data.sonarr_data.download_client_modal = Some(download_client_modal);
+      let expected_edit_download_client_params = EditDownloadClientParams {
+        download_client_id: 1,
+        name: Some("qBittorrent".to_owned()),
+        enable: Some(true),
+        priority: Some(1),
+        remove_completed_downloads: Some(true),
+        remove_failed_downloads: Some(true),
+        tag_input_string: Some("usenet".to_owned()),
+        fields: vec![
+          ("host".to_owned(), "localhost".to_owned()),
+          ("port".to_owned(), "8080".to_owned()),
+          ("category".to_owned(), "managarr".to_owned()),
+        ],
+        ..EditDownloadClientParams::default()
+      };
+
+      DownloadClientFormHandler::new(
+        DEFAULT_KEYBINDINGS.confirm.key,
+        &mut app,
+        ActiveSonarrBlock::DownloadClientPrompt,
+        None,
+      )
+      .handle();
+
+      assert_navigation_popped!(app, ActiveSonarrBlock::DownloadClients.into());
+      assert!(app.data.sonarr_data.prompt_confirm);
+      assert!(app.should_refresh);
+      assert_modal_absent!(app.data.sonarr_data.download_client_modal);
+      assert_eq!(
+        app.data.sonarr_data.prompt_confirm_action,
+        Some(SonarrEvent::EditDownloadClient(
+          expected_edit_download_client_params
+        ))
+      );
+    }
+
+    #[test]
+    fn test_download_client_prompt_confirm_no_op_when_not_ready() {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.push_navigation_stack(ActiveSonarrBlock::DownloadClients.into());
+      app.push_navigation_stack(ActiveSonarrBlock::DownloadClientPrompt.into());
+      app.data.sonarr_data.download_client_modal = Some(download_client_modal());
+
+      DownloadClientFormHandler::new(
+        DEFAULT_KEYBINDINGS.confirm.key,
+        &mut app,
+        ActiveSonarrBlock::DownloadClientPrompt,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveSonarrBlock::DownloadClientPrompt.into()
+      );
+      assert!(app.data.sonarr_data.prompt_confirm_action.is_none());
+      assert_modal_present!(app.data.sonarr_data.download_client_modal);
+    }
+  }
+
+  #[test]
+  fn test_download_client_form_handler_accepts() {
+    ActiveSonarrBlock::iter().for_each(|active_sonarr_block| {
+      if DOWNLOAD_CLIENT_FORM_BLOCKS.contains(&active_sonarr_block) {
+        assert!(DownloadClientFormHandler::accepts(active_sonarr_block));
+      } else {
+        assert!(!DownloadClientFormHandler::accepts(active_sonarr_block));
+      }
+    })
+  }
+
+  #[rstest]
+  fn test_download_client_form_handler_ignore_special_keys(
+    #[values(true, false)] ignore_special_keys_for_textbox_input: bool,
+  ) {
+    let mut app = App::test_default();
+    app.ignore_special_keys_for_textbox_input = ignore_special_keys_for_textbox_input;
+    let handler = DownloadClientFormHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveSonarrBlock::default(),
+      None,
+    );
+
+    assert_eq!(
+      handler.ignore_special_keys(),
+      ignore_special_keys_for_textbox_input
+    );
+  }
+
+  #[rstest]
+  fn test_download_client_form_handler_is_not_ready_when_loading(
+    #[values(
+      ActiveSonarrBlock::AddDownloadClientSelectImplementation,
+      ActiveSonarrBlock::DownloadClientPrompt
+    )]
+    active_sonarr_block: ActiveSonarrBlock,
+  ) {
+    let mut app = App::test_default();
+    app.is_loading = true;
+    app
+      .data
+      .sonarr_data
+      .download_client_schema
+      .set_items(vec![download_client()]);
+    app.data.sonarr_data.download_client_modal = Some(download_client_modal());
+
+    let handler = DownloadClientFormHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      active_sonarr_block,
+      None,
+    );
+
+    assert!(!handler.is_ready());
+  }
+
+  #[test]
+  fn test_download_client_form_handler_is_not_ready_when_download_client_schema_is_empty() {
+    let mut app = App::test_default();
+
+    let handler = DownloadClientFormHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveSonarrBlock::AddDownloadClientSelectImplementation,
+      None,
+    );
+
+    assert!(!handler.is_ready());
+  }
+
+  #[test]
+  fn test_download_client_form_handler_is_ready_when_download_client_schema_is_not_empty() {
+    let mut app = App::test_default();
+    app
+      .data
+      .sonarr_data
+      .download_client_schema
+      .set_items(vec![download_client()]);
+
+    let handler = DownloadClientFormHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveSonarrBlock::AddDownloadClientSelectImplementation,
+      None,
+    );
+
+    assert!(handler.is_ready());
+  }
+
+  #[test]
+  fn test_download_client_form_handler_is_not_ready_when_download_client_modal_is_none() {
+    let mut app = App::test_default();
+
+    let handler = DownloadClientFormHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveSonarrBlock::DownloadClientPrompt,
+      None,
+    );
+
+    assert!(!handler.is_ready());
+  }
+
+  #[test]
+  fn test_download_client_form_handler_is_ready_when_download_client_modal_is_some() {
+    let mut app = App::test_default();
+    app.data.sonarr_data.download_client_modal = Some(download_client_modal());
+
+    let handler = DownloadClientFormHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveSonarrBlock::DownloadClientPrompt,
+      None,
+    );
+
+    assert!(handler.is_ready());
+  }
+}
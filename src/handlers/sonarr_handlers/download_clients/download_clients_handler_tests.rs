@@ -0,0 +1,694 @@
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+  use rstest::rstest;
+  use strum::IntoEnumIterator;
+
+  use crate::app::App;
+  use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+  use crate::assert_navigation_pushed;
+  use crate::event::Key;
+  use crate::handlers::KeyEventHandler;
+  use crate::handlers::sonarr_handlers::download_clients::DownloadClientsHandler;
+  use crate::models::servarr_data::sonarr::sonarr_data::{
+    ActiveSonarrBlock, DOWNLOAD_CLIENT_FORM_BLOCKS, DOWNLOAD_CLIENTS_BLOCKS,
+  };
+  use crate::models::servarr_models::DownloadClient;
+  use crate::network::servarr_test_utils::download_client;
+  use crate::test_handler_delegation;
+
+  mod test_handle_delete {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    const DELETE_KEY: Key = DEFAULT_KEYBINDINGS.delete.key;
+
+    #[test]
+    fn test_delete_download_client_prompt() {
+      let mut app = App::test_default();
+      app
+        .data
+        .sonarr_data
+        .download_clients
+        .set_items(vec![DownloadClient::default()]);
+
+      DownloadClientsHandler::new(
+        DELETE_KEY,
+        &mut app,
+        ActiveSonarrBlock::DownloadClients,
+        None,
+      )
+      .handle();
+
+      assert_navigation_pushed!(app, ActiveSonarrBlock::DeleteDownloadClientPrompt.into());
+    }
+
+    #[test]
+    fn test_delete_download_client_prompt_no_op_when_not_ready() {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.push_navigation_stack(ActiveSonarrBlock::DownloadClients.into());
+      app
+        .data
+        .sonarr_data
+        .download_clients
+        .set_items(vec![DownloadClient::default()]);
+
+      DownloadClientsHandler::new(
+        DELETE_KEY,
+        &mut app,
+        ActiveSonarrBlock::DownloadClients,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveSonarrBlock::DownloadClients.into()
+      );
+    }
+  }
+
+  mod test_handle_left_right_action {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_download_clients_tab_left(#[values(true, false)] is_ready: bool) {
+      let mut app = App::test_default();
+      app.is_loading = is_ready;
+      app.data.sonarr_data.main_tabs.set_index(6);
+
+      DownloadClientsHandler::new(
+        DEFAULT_KEYBINDINGS.left.key,
+        &mut app,
+        ActiveSonarrBlock::DownloadClients,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.data.sonarr_data.main_tabs.get_active_route(),
+        ActiveSonarrBlock::Indexers.into()
+      );
+      assert_navigation_pushed!(app, ActiveSonarrBlock::Indexers.into());
+    }
+
+    #[rstest]
+    fn test_download_clients_tab_right(#[values(true, false)] is_ready: bool) {
+      let mut app = App::test_default();
+      app.is_loading = is_ready;
+      app.data.sonarr_data.main_tabs.set_index(6);
+
+      DownloadClientsHandler::new(
+        DEFAULT_KEYBINDINGS.right.key,
+        &mut app,
+        ActiveSonarrBlock::DownloadClients,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.data.sonarr_data.main_tabs.get_active_route(),
+        ActiveSonarrBlock::QualityProfiles.into()
+      );
+      assert_navigation_pushed!(app, ActiveSonarrBlock::QualityProfiles.into());
+    }
+
+    #[rstest]
+    fn test_left_right_delete_download_client_prompt_toggle(
+      #[values(DEFAULT_KEYBINDINGS.left.key, DEFAULT_KEYBINDINGS.right.key)] key: Key,
+    ) {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveSonarrBlock::DownloadClients.into());
+
+      DownloadClientsHandler::new(
+        key,
+        &mut app,
+        ActiveSonarrBlock::DeleteDownloadClientPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(app.data.sonarr_data.prompt_confirm);
+
+      DownloadClientsHandler::new(
+        key,
+        &mut app,
+        ActiveSonarrBlock::DeleteDownloadClientPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(!app.data.sonarr_data.prompt_confirm);
+    }
+  }
+
+  mod test_handle_submit {
+    use super::*;
+    use crate::assert_navigation_popped;
+    use crate::models::servarr_data::modals::DownloadClientModal;
+    use crate::network::sonarr_network::SonarrEvent;
+    use bimap::BiMap;
+    use pretty_assertions::assert_eq;
+
+    const SUBMIT_KEY: Key = DEFAULT_KEYBINDINGS.submit.key;
+
+    #[test]
+    fn test_edit_download_client_submit() {
+      let mut app = App::test_default();
+      app.data.sonarr_data.tags_map = BiMap::from_iter([(1, "usenet".to_owned())]);
+      app
+        .data
+        .sonarr_data
+        .download_clients
+        .set_items(vec![download_client()]);
+
+      DownloadClientsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveSonarrBlock::DownloadClients,
+        None,
+      )
+      .handle();
+
+      assert_navigation_pushed!(app, ActiveSonarrBlock::DownloadClientPrompt.into());
+      let expected_download_client_modal = DownloadClientModal::from(&app.data.sonarr_data);
+      let download_client_modal = app.data.sonarr_data.download_client_modal.as_ref().unwrap();
+      assert_some_eq_x!(download_client_modal.id, 1);
+      assert_eq!(
+        download_client_modal.implementation,
+        expected_download_client_modal.implementation
+      );
+      assert_eq!(
+        download_client_modal.fields.items,
+        expected_download_client_modal.fields.items
+      );
+      assert_eq!(
+        download_client_modal
+          .fields
+          .items
+          .iter()
+          .find(|field| field.name == "tags")
+          .unwrap()
+          .value
+          .text,
+        "usenet"
+      );
+    }
+
+    #[test]
+    fn test_edit_download_client_submit_no_op_when_not_ready() {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.push_navigation_stack(ActiveSonarrBlock::DownloadClients.into());
+      app
+        .data
+        .sonarr_data
+        .download_clients
+        .set_items(vec![DownloadClient::default()]);
+
+      DownloadClientsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveSonarrBlock::DownloadClients,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveSonarrBlock::DownloadClients.into()
+      );
+      assert_none!(app.data.sonarr_data.download_client_modal);
+    }
+
+    #[test]
+    fn test_delete_download_client_prompt_confirm_submit() {
+      let mut app = App::test_default();
+      app
+        .data
+        .sonarr_data
+        .download_clients
+        .set_items(vec![download_client()]);
+      app.data.sonarr_data.prompt_confirm = true;
+      app.push_navigation_stack(ActiveSonarrBlock::DownloadClients.into());
+      app.push_navigation_stack(ActiveSonarrBlock::DeleteDownloadClientPrompt.into());
+
+      DownloadClientsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveSonarrBlock::DeleteDownloadClientPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(app.data.sonarr_data.prompt_confirm);
+      assert_some_eq_x!(
+        &app.data.sonarr_data.prompt_confirm_action,
+        &SonarrEvent::DeleteDownloadClient(1)
+      );
+      assert_navigation_popped!(app, ActiveSonarrBlock::DownloadClients.into());
+    }
+
+    #[test]
+    fn test_prompt_decline_submit() {
+      let mut app = App::test_default();
+      app
+        .data
+        .sonarr_data
+        .download_clients
+        .set_items(vec![DownloadClient::default()]);
+      app.push_navigation_stack(ActiveSonarrBlock::DownloadClients.into());
+      app.push_navigation_stack(ActiveSonarrBlock::DeleteDownloadClientPrompt.into());
+
+      DownloadClientsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveSonarrBlock::DeleteDownloadClientPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(!app.data.sonarr_data.prompt_confirm);
+      assert_none!(app.data.sonarr_data.prompt_confirm_action);
+      assert_navigation_popped!(app, ActiveSonarrBlock::DownloadClients.into());
+    }
+  }
+
+  mod test_handle_esc {
+    use super::*;
+    use crate::assert_navigation_popped;
+
+    const ESC_KEY: Key = DEFAULT_KEYBINDINGS.esc.key;
+
+    #[rstest]
+    fn test_delete_download_client_prompt_block_esc(#[values(true, false)] is_ready: bool) {
+      let mut app = App::test_default();
+      app.is_loading = is_ready;
+      app.push_navigation_stack(ActiveSonarrBlock::DownloadClients.into());
+      app.push_navigation_stack(ActiveSonarrBlock::DeleteDownloadClientPrompt.into());
+      app.data.sonarr_data.prompt_confirm = true;
+
+      DownloadClientsHandler::new(
+        ESC_KEY,
+        &mut app,
+        ActiveSonarrBlock::DeleteDownloadClientPrompt,
+        None,
+      )
+      .handle();
+
+      assert_navigation_popped!(app, ActiveSonarrBlock::DownloadClients.into());
+      assert!(!app.data.sonarr_data.prompt_confirm);
+    }
+
+    #[rstest]
+    fn test_test_download_client_esc(#[values(true, false)] is_ready: bool) {
+      let mut app = App::test_default();
+      app.is_loading = is_ready;
+      app.data.sonarr_data.download_client_test_errors = Some("test result".to_owned());
+      app.push_navigation_stack(ActiveSonarrBlock::DownloadClients.into());
+      app.push_navigation_stack(ActiveSonarrBlock::TestDownloadClient.into());
+
+      DownloadClientsHandler::new(
+        ESC_KEY,
+        &mut app,
+        ActiveSonarrBlock::TestDownloadClient,
+        None,
+      )
+      .handle();
+
+      assert_navigation_popped!(app, ActiveSonarrBlock::DownloadClients.into());
+      assert_none!(app.data.sonarr_data.download_client_test_errors);
+    }
+
+    #[rstest]
+    fn test_default_esc(#[values(true, false)] is_ready: bool) {
+      let mut app = App::test_default();
+      app.is_loading = is_ready;
+      app.error = "test error".to_owned().into();
+      app.push_navigation_stack(ActiveSonarrBlock::DownloadClients.into());
+      app.push_navigation_stack(ActiveSonarrBlock::DownloadClients.into());
+
+      DownloadClientsHandler::new(ESC_KEY, &mut app, ActiveSonarrBlock::DownloadClients, None)
+        .handle();
+
+      assert_navigation_popped!(app, ActiveSonarrBlock::DownloadClients.into());
+      assert_is_empty!(app.error.text);
+    }
+  }
+
+  mod test_handle_key_char {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::{assert_navigation_popped, network::sonarr_network::SonarrEvent};
+
+    #[test]
+    fn test_refresh_download_clients_key() {
+      let mut app = App::test_default();
+      app
+        .data
+        .sonarr_data
+        .download_clients
+        .set_items(vec![DownloadClient::default()]);
+      app.push_navigation_stack(ActiveSonarrBlock::DownloadClients.into());
+
+      DownloadClientsHandler::new(
+        DEFAULT_KEYBINDINGS.refresh.key,
+        &mut app,
+        ActiveSonarrBlock::DownloadClients,
+        None,
+      )
+      .handle();
+
+      assert_navigation_pushed!(app, ActiveSonarrBlock::DownloadClients.into());
+      assert!(app.should_refresh);
+    }
+
+    #[test]
+    fn test_refresh_download_clients_key_no_op_when_not_ready() {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app
+        .data
+        .sonarr_data
+        .download_clients
+        .set_items(vec![DownloadClient::default()]);
+      app.push_navigation_stack(ActiveSonarrBlock::DownloadClients.into());
+
+      DownloadClientsHandler::new(
+        DEFAULT_KEYBINDINGS.refresh.key,
+        &mut app,
+        ActiveSonarrBlock::DownloadClients,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveSonarrBlock::DownloadClients.into()
+      );
+      assert!(!app.should_refresh);
+    }
+
+    #[test]
+    fn test_download_client_add_key() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveSonarrBlock::DownloadClients.into());
+      app
+        .data
+        .sonarr_data
+        .download_clients
+        .set_items(vec![DownloadClient::default()]);
+
+      DownloadClientsHandler::new(
+        DEFAULT_KEYBINDINGS.add.key,
+        &mut app,
+        ActiveSonarrBlock::DownloadClients,
+        None,
+      )
+      .handle();
+
+      assert_navigation_pushed!(
+        app,
+        ActiveSonarrBlock::AddDownloadClientSelectImplementation.into()
+      );
+    }
+
+    #[test]
+    fn test_download_client_add_key_no_op_when_not_ready() {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.push_navigation_stack(ActiveSonarrBlock::DownloadClients.into());
+      app
+        .data
+        .sonarr_data
+        .download_clients
+        .set_items(vec![DownloadClient::default()]);
+
+      DownloadClientsHandler::new(
+        DEFAULT_KEYBINDINGS.add.key,
+        &mut app,
+        ActiveSonarrBlock::DownloadClients,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveSonarrBlock::DownloadClients.into()
+      );
+    }
+
+    #[test]
+    fn test_test_key() {
+      let mut app = App::test_default();
+      app
+        .data
+        .sonarr_data
+        .download_clients
+        .set_items(vec![DownloadClient::default()]);
+
+      DownloadClientsHandler::new(
+        DEFAULT_KEYBINDINGS.test.key,
+        &mut app,
+        ActiveSonarrBlock::DownloadClients,
+        None,
+      )
+      .handle();
+
+      assert_navigation_pushed!(app, ActiveSonarrBlock::TestDownloadClient.into());
+    }
+
+    #[test]
+    fn test_test_key_no_op_when_not_ready() {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.push_navigation_stack(ActiveSonarrBlock::DownloadClients.into());
+      app
+        .data
+        .sonarr_data
+        .download_clients
+        .set_items(vec![DownloadClient::default()]);
+
+      DownloadClientsHandler::new(
+        DEFAULT_KEYBINDINGS.test.key,
+        &mut app,
+        ActiveSonarrBlock::DownloadClients,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveSonarrBlock::DownloadClients.into()
+      );
+    }
+
+    #[test]
+    fn test_test_all_key() {
+      let mut app = App::test_default();
+      app
+        .data
+        .sonarr_data
+        .download_clients
+        .set_items(vec![DownloadClient::default()]);
+
+      DownloadClientsHandler::new(
+        DEFAULT_KEYBINDINGS.test_all.key,
+        &mut app,
+        ActiveSonarrBlock::DownloadClients,
+        None,
+      )
+      .handle();
+
+      assert_navigation_pushed!(app, ActiveSonarrBlock::TestAllDownloadClients.into());
+    }
+
+    #[test]
+    fn test_test_all_key_no_op_when_not_ready() {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.push_navigation_stack(ActiveSonarrBlock::DownloadClients.into());
+      app
+        .data
+        .sonarr_data
+        .download_clients
+        .set_items(vec![DownloadClient::default()]);
+
+      DownloadClientsHandler::new(
+        DEFAULT_KEYBINDINGS.test_all.key,
+        &mut app,
+        ActiveSonarrBlock::DownloadClients,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveSonarrBlock::DownloadClients.into()
+      );
+    }
+
+    #[test]
+    fn test_delete_download_client_prompt_confirm() {
+      let mut app = App::test_default();
+      app
+        .data
+        .sonarr_data
+        .download_clients
+        .set_items(vec![download_client()]);
+      app.push_navigation_stack(ActiveSonarrBlock::DownloadClients.into());
+      app.push_navigation_stack(ActiveSonarrBlock::DeleteDownloadClientPrompt.into());
+
+      DownloadClientsHandler::new(
+        DEFAULT_KEYBINDINGS.confirm.key,
+        &mut app,
+        ActiveSonarrBlock::DeleteDownloadClientPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(app.data.sonarr_data.prompt_confirm);
+      assert_some_eq_x!(
+        &app.data.sonarr_data.prompt_confirm_action,
+        &SonarrEvent::DeleteDownloadClient(1)
+      );
+      assert_navigation_popped!(app, ActiveSonarrBlock::DownloadClients.into());
+    }
+  }
+
+  #[rstest]
+  fn test_delegates_download_client_form_blocks_to_download_client_form_handler(
+    #[values(
+      ActiveSonarrBlock::AddDownloadClientSelectImplementation,
+      ActiveSonarrBlock::DownloadClientFieldInput,
+      ActiveSonarrBlock::DownloadClientPrompt
+    )]
+    active_sonarr_block: ActiveSonarrBlock,
+  ) {
+    test_handler_delegation!(
+      DownloadClientsHandler,
+      ActiveSonarrBlock::DownloadClients,
+      active_sonarr_block
+    );
+  }
+
+  #[test]
+  fn test_delegates_test_all_download_clients_block_to_test_all_download_clients_handler() {
+    test_handler_delegation!(
+      DownloadClientsHandler,
+      ActiveSonarrBlock::DownloadClients,
+      ActiveSonarrBlock::TestAllDownloadClients
+    );
+  }
+
+  #[test]
+  fn test_download_clients_handler_accepts() {
+    let mut download_clients_blocks = Vec::new();
+    download_clients_blocks.extend(DOWNLOAD_CLIENTS_BLOCKS);
+    download_clients_blocks.extend(DOWNLOAD_CLIENT_FORM_BLOCKS);
+    download_clients_blocks.push(ActiveSonarrBlock::TestAllDownloadClients);
+
+    ActiveSonarrBlock::iter().for_each(|active_sonarr_block| {
+      if download_clients_blocks.contains(&active_sonarr_block) {
+        assert!(DownloadClientsHandler::accepts(active_sonarr_block));
+      } else {
+        assert!(!DownloadClientsHandler::accepts(active_sonarr_block));
+      }
+    })
+  }
+
+  #[rstest]
+  fn test_download_clients_handler_ignore_special_keys(
+    #[values(true, false)] ignore_special_keys_for_textbox_input: bool,
+  ) {
+    let mut app = App::test_default();
+    app.ignore_special_keys_for_textbox_input = ignore_special_keys_for_textbox_input;
+    let handler = DownloadClientsHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveSonarrBlock::default(),
+      None,
+    );
+
+    assert_eq!(
+      handler.ignore_special_keys(),
+      ignore_special_keys_for_textbox_input
+    );
+  }
+
+  #[test]
+  fn test_extract_download_client_id() {
+    let mut app = App::test_default();
+    app
+      .data
+      .sonarr_data
+      .download_clients
+      .set_items(vec![download_client()]);
+
+    let download_client_id = DownloadClientsHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveSonarrBlock::DownloadClients,
+      None,
+    )
+    .extract_download_client_id();
+
+    assert_eq!(download_client_id, 1);
+  }
+
+  #[test]
+  fn test_download_clients_handler_not_ready_when_loading() {
+    let mut app = App::test_default();
+    app.is_loading = true;
+
+    let handler = DownloadClientsHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveSonarrBlock::DownloadClients,
+      None,
+    );
+
+    assert!(!handler.is_ready());
+  }
+
+  #[test]
+  fn test_download_clients_handler_not_ready_when_download_clients_is_empty() {
+    let mut app = App::test_default();
+    app.is_loading = false;
+
+    let handler = DownloadClientsHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveSonarrBlock::DownloadClients,
+      None,
+    );
+
+    assert!(!handler.is_ready());
+  }
+
+  #[test]
+  fn test_download_clients_handler_ready_when_not_loading_and_download_clients_is_not_empty() {
+    let mut app = App::test_default();
+    app.is_loading = false;
+    app
+      .data
+      .sonarr_data
+      .download_clients
+      .set_items(vec![DownloadClient::default()]);
+
+    let handler = DownloadClientsHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveSonarrBlock::DownloadClients,
+      None,
+    );
+
+    assert!(handler.is_ready());
+  }
+}
@@ -11,8 +11,94 @@ mod tests {
   use crate::handlers::KeyEventHandler;
   use crate::handlers::sonarr_handlers::downloads::DownloadsHandler;
   use crate::handlers::sonarr_handlers::sonarr_handler_test_utils::utils::download_record;
-  use crate::models::servarr_data::sonarr::sonarr_data::{ActiveSonarrBlock, DOWNLOADS_BLOCKS};
-  use crate::models::sonarr_models::DownloadRecord;
+  use crate::models::BlockSelectionState;
+  use crate::models::servarr_data::sonarr::modals::ManualImportModal;
+  use crate::models::servarr_data::sonarr::sonarr_data::{
+    ActiveSonarrBlock, DELETE_DOWNLOAD_SELECTION_BLOCKS, DOWNLOADS_BLOCKS,
+  };
+  use crate::models::servarr_models::{
+    BulkDeleteDownloadsParams, DeleteDownloadParams, QueueRemovalOptions,
+  };
+  use crate::models::sonarr_models::{DownloadRecord, DownloadStatus};
+  use crate::network::sonarr_network::SonarrEvent;
+
+  mod test_handle_scroll_up_and_down {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use crate::models::sonarr_models::Series;
+
+    use super::*;
+
+    #[rstest]
+    fn test_delete_download_prompt_scroll(
+      #[values(DEFAULT_KEYBINDINGS.up.key, DEFAULT_KEYBINDINGS.down.key)] key: Key,
+    ) {
+      let mut app = App::test_default();
+      app
+        .data
+        .sonarr_data
+        .downloads
+        .set_items(vec![download_record()]);
+      app.data.sonarr_data.selected_block =
+        BlockSelectionState::new(DELETE_DOWNLOAD_SELECTION_BLOCKS);
+      app.data.sonarr_data.selected_block.down();
+
+      DownloadsHandler::new(key, &mut app, ActiveSonarrBlock::DeleteDownloadPrompt, None).handle();
+
+      if key == DEFAULT_KEYBINDINGS.up.key {
+        assert_eq!(
+          app.data.sonarr_data.selected_block.get_active_block(),
+          ActiveSonarrBlock::DeleteDownloadToggleRemoveFromClient
+        );
+      } else {
+        assert_eq!(
+          app.data.sonarr_data.selected_block.get_active_block(),
+          ActiveSonarrBlock::DeleteDownloadToggleSkipRedownload
+        );
+      }
+    }
+
+    #[rstest]
+    fn test_manual_import_select_series_scroll(
+      #[values(DEFAULT_KEYBINDINGS.up.key, DEFAULT_KEYBINDINGS.down.key)] key: Key,
+    ) {
+      let mut app = App::test_default();
+      let mut manual_import_modal = ManualImportModal::default();
+      manual_import_modal.series_list.set_items(vec![
+        Series {
+          id: 1,
+          ..Series::default()
+        },
+        Series {
+          id: 2,
+          ..Series::default()
+        },
+      ]);
+      app.data.sonarr_data.manual_import_modal = Some(manual_import_modal);
+
+      DownloadsHandler::new(
+        key,
+        &mut app,
+        ActiveSonarrBlock::ManualImportSelectSeries,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app
+          .data
+          .sonarr_data
+          .manual_import_modal
+          .as_ref()
+          .unwrap()
+          .series_list
+          .current_selection()
+          .id,
+        2
+      );
+    }
+  }
 
   mod test_handle_delete {
     use pretty_assertions::assert_eq;
@@ -34,6 +120,10 @@ mod tests {
       DownloadsHandler::new(DELETE_KEY, &mut app, ActiveSonarrBlock::Downloads, None).handle();
 
       assert_navigation_pushed!(app, ActiveSonarrBlock::DeleteDownloadPrompt.into());
+      assert_eq!(
+        app.data.sonarr_data.selected_block.get_active_block(),
+        ActiveSonarrBlock::DeleteDownloadToggleRemoveFromClient
+      );
     }
 
     #[test]
@@ -108,7 +198,9 @@ mod tests {
     fn test_downloads_left_right_prompt_toggle(
       #[values(
         ActiveSonarrBlock::DeleteDownloadPrompt,
-        ActiveSonarrBlock::UpdateDownloadsPrompt
+        ActiveSonarrBlock::GrabDownloadPrompt,
+        ActiveSonarrBlock::UpdateDownloadsPrompt,
+        ActiveSonarrBlock::ManualImportConfirmPrompt
       )]
       active_sonarr_block: ActiveSonarrBlock,
       #[values(DEFAULT_KEYBINDINGS.left.key, DEFAULT_KEYBINDINGS.right.key)] key: Key,
@@ -127,20 +219,168 @@ mod tests {
   }
 
   mod test_handle_submit {
+    use pretty_assertions::assert_eq;
     use rstest::rstest;
 
     use crate::network::sonarr_network::SonarrEvent;
+    use crate::network::sonarr_network::sonarr_network_test_utils::test_utils::manual_import_file;
 
     use super::*;
     use crate::assert_navigation_popped;
 
     const SUBMIT_KEY: Key = DEFAULT_KEYBINDINGS.submit.key;
 
+    #[test]
+    fn test_delete_download_prompt_confirm_submit() {
+      let mut app = App::test_default();
+      app
+        .data
+        .sonarr_data
+        .downloads
+        .set_items(vec![download_record()]);
+      app.data.sonarr_data.prompt_confirm = true;
+      app.data.sonarr_data.queue_removal_options = QueueRemovalOptions {
+        remove_from_client: false,
+        blocklist: true,
+        skip_redownload: true,
+        change_category: true,
+      };
+      app.push_navigation_stack(ActiveSonarrBlock::Downloads.into());
+      app.push_navigation_stack(ActiveSonarrBlock::DeleteDownloadPrompt.into());
+      app.data.sonarr_data.selected_block =
+        BlockSelectionState::new(DELETE_DOWNLOAD_SELECTION_BLOCKS);
+      app
+        .data
+        .sonarr_data
+        .selected_block
+        .set_index(0, DELETE_DOWNLOAD_SELECTION_BLOCKS.len() - 1);
+
+      DownloadsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveSonarrBlock::DeleteDownloadPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(app.data.sonarr_data.prompt_confirm);
+      assert_eq!(
+        app.data.sonarr_data.prompt_confirm_action,
+        Some(SonarrEvent::DeleteDownload(DeleteDownloadParams {
+          id: 1,
+          removal_options: QueueRemovalOptions {
+            remove_from_client: false,
+            blocklist: true,
+            skip_redownload: true,
+            change_category: true,
+          },
+        }))
+      );
+      assert_eq!(
+        app.data.sonarr_data.queue_removal_options,
+        QueueRemovalOptions::default()
+      );
+      assert_navigation_popped!(app, ActiveSonarrBlock::Downloads.into());
+    }
+
+    #[test]
+    fn test_delete_download_prompt_decline_submit() {
+      let mut app = App::test_default();
+      app
+        .data
+        .sonarr_data
+        .downloads
+        .set_items(vec![download_record()]);
+      app.data.sonarr_data.queue_removal_options = QueueRemovalOptions {
+        remove_from_client: false,
+        blocklist: true,
+        skip_redownload: true,
+        change_category: true,
+      };
+      app.push_navigation_stack(ActiveSonarrBlock::Downloads.into());
+      app.push_navigation_stack(ActiveSonarrBlock::DeleteDownloadPrompt.into());
+      app.data.sonarr_data.selected_block =
+        BlockSelectionState::new(DELETE_DOWNLOAD_SELECTION_BLOCKS);
+      app
+        .data
+        .sonarr_data
+        .selected_block
+        .set_index(0, DELETE_DOWNLOAD_SELECTION_BLOCKS.len() - 1);
+
+      DownloadsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveSonarrBlock::DeleteDownloadPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(!app.data.sonarr_data.prompt_confirm);
+      assert_none!(app.data.sonarr_data.prompt_confirm_action);
+      assert_eq!(
+        app.data.sonarr_data.queue_removal_options,
+        QueueRemovalOptions::default()
+      );
+      assert_navigation_popped!(app, ActiveSonarrBlock::Downloads.into());
+    }
+
+    #[rstest]
+    #[case(0, QueueRemovalOptions { remove_from_client: false, ..QueueRemovalOptions::default() })]
+    #[case(1, QueueRemovalOptions { blocklist: true, ..QueueRemovalOptions::default() })]
+    #[case(2, QueueRemovalOptions { skip_redownload: true, ..QueueRemovalOptions::default() })]
+    #[case(3, QueueRemovalOptions { change_category: true, ..QueueRemovalOptions::default() })]
+    fn test_delete_download_prompt_toggle_submit(
+      #[case] index: usize,
+      #[case] expected_removal_options: QueueRemovalOptions,
+    ) {
+      let mut app = App::test_default();
+      app
+        .data
+        .sonarr_data
+        .downloads
+        .set_items(vec![download_record()]);
+      app.push_navigation_stack(ActiveSonarrBlock::Downloads.into());
+      app.push_navigation_stack(ActiveSonarrBlock::DeleteDownloadPrompt.into());
+      app.data.sonarr_data.selected_block =
+        BlockSelectionState::new(DELETE_DOWNLOAD_SELECTION_BLOCKS);
+      app.data.sonarr_data.selected_block.set_index(0, index);
+
+      DownloadsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveSonarrBlock::DeleteDownloadPrompt,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveSonarrBlock::DeleteDownloadPrompt.into()
+      );
+      assert_eq!(
+        app.data.sonarr_data.queue_removal_options,
+        expected_removal_options
+      );
+
+      DownloadsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveSonarrBlock::DeleteDownloadPrompt,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.data.sonarr_data.queue_removal_options,
+        QueueRemovalOptions::default()
+      );
+    }
+
     #[rstest]
     #[case(
       ActiveSonarrBlock::Downloads,
-      ActiveSonarrBlock::DeleteDownloadPrompt,
-      SonarrEvent::DeleteDownload(1)
+      ActiveSonarrBlock::GrabDownloadPrompt,
+      SonarrEvent::GrabQueueItem(1)
     )]
     #[case(
       ActiveSonarrBlock::Downloads,
@@ -173,7 +413,7 @@ mod tests {
     }
 
     #[rstest]
-    #[case(ActiveSonarrBlock::Downloads, ActiveSonarrBlock::DeleteDownloadPrompt)]
+    #[case(ActiveSonarrBlock::Downloads, ActiveSonarrBlock::GrabDownloadPrompt)]
     #[case(ActiveSonarrBlock::Downloads, ActiveSonarrBlock::UpdateDownloadsPrompt)]
     fn test_downloads_prompt_decline_submit(
       #[case] base_route: ActiveSonarrBlock,
@@ -194,9 +434,119 @@ mod tests {
       assert_none!(app.data.sonarr_data.prompt_confirm_action);
       assert_navigation_popped!(app, base_route.into());
     }
+
+    #[test]
+    fn test_manual_import_submit_opens_select_series() {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(ActiveSonarrBlock::Downloads.into());
+      app.push_navigation_stack(ActiveSonarrBlock::ManualImport.into());
+      let expected_series = app.data.sonarr_data.series.items.clone();
+
+      DownloadsHandler::new(SUBMIT_KEY, &mut app, ActiveSonarrBlock::ManualImport, None).handle();
+
+      assert_navigation_pushed!(app, ActiveSonarrBlock::ManualImportSelectSeries.into());
+      assert_eq!(
+        app
+          .data
+          .sonarr_data
+          .manual_import_modal
+          .as_ref()
+          .unwrap()
+          .series_list
+          .items,
+        expected_series
+      );
+    }
+
+    #[test]
+    fn test_manual_import_submit_no_op_when_no_items() {
+      let mut app = App::test_default();
+      app.data.sonarr_data.manual_import_modal = Some(ManualImportModal::default());
+      app.push_navigation_stack(ActiveSonarrBlock::ManualImport.into());
+
+      DownloadsHandler::new(SUBMIT_KEY, &mut app, ActiveSonarrBlock::ManualImport, None).handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveSonarrBlock::ManualImport.into()
+      );
+    }
+
+    #[test]
+    fn test_manual_import_select_series_submit() {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(ActiveSonarrBlock::ManualImport.into());
+      app.push_navigation_stack(ActiveSonarrBlock::ManualImportSelectSeries.into());
+
+      DownloadsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveSonarrBlock::ManualImportSelectSeries,
+        None,
+      )
+      .handle();
+
+      assert_navigation_popped!(app, ActiveSonarrBlock::ManualImport.into());
+      assert_eq!(
+        app
+          .data
+          .sonarr_data
+          .manual_import_modal
+          .as_ref()
+          .unwrap()
+          .series_id,
+        Some(1)
+      );
+    }
+
+    #[test]
+    fn test_manual_import_confirm_prompt_confirm_submit() {
+      let mut app = App::test_default_fully_populated();
+      app.data.sonarr_data.prompt_confirm = true;
+      app.push_navigation_stack(ActiveSonarrBlock::Downloads.into());
+      app.push_navigation_stack(ActiveSonarrBlock::ManualImport.into());
+      app.push_navigation_stack(ActiveSonarrBlock::ManualImportConfirmPrompt.into());
+
+      DownloadsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveSonarrBlock::ManualImportConfirmPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(app.data.sonarr_data.prompt_confirm);
+      assert_eq!(
+        app.data.sonarr_data.prompt_confirm_action,
+        Some(SonarrEvent::ManualImport(vec![manual_import_file()]))
+      );
+      assert_none!(app.data.sonarr_data.manual_import_modal);
+      assert_navigation_popped!(app, ActiveSonarrBlock::Downloads.into());
+    }
+
+    #[test]
+    fn test_manual_import_confirm_prompt_decline_submit() {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(ActiveSonarrBlock::ManualImport.into());
+      app.push_navigation_stack(ActiveSonarrBlock::ManualImportConfirmPrompt.into());
+
+      DownloadsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveSonarrBlock::ManualImportConfirmPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(!app.data.sonarr_data.prompt_confirm);
+      assert_none!(app.data.sonarr_data.prompt_confirm_action);
+      assert_some!(&app.data.sonarr_data.manual_import_modal);
+      assert_navigation_popped!(app, ActiveSonarrBlock::ManualImport.into());
+    }
   }
 
   mod test_handle_esc {
+    use pretty_assertions::assert_eq;
     use rstest::rstest;
 
     use super::*;
@@ -204,9 +554,41 @@ mod tests {
 
     const ESC_KEY: Key = DEFAULT_KEYBINDINGS.esc.key;
 
+    #[test]
+    fn test_delete_download_prompt_esc_resets_removal_options() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveSonarrBlock::Downloads.into());
+      app.push_navigation_stack(ActiveSonarrBlock::DeleteDownloadPrompt.into());
+      app.data.sonarr_data.queue_removal_options = QueueRemovalOptions {
+        remove_from_client: false,
+        blocklist: true,
+        skip_redownload: true,
+        change_category: true,
+      };
+
+      DownloadsHandler::new(
+        ESC_KEY,
+        &mut app,
+        ActiveSonarrBlock::DeleteDownloadPrompt,
+        None,
+      )
+      .handle();
+
+      assert_navigation_popped!(app, ActiveSonarrBlock::Downloads.into());
+      assert_eq!(
+        app.data.sonarr_data.queue_removal_options,
+        QueueRemovalOptions::default()
+      );
+    }
+
     #[rstest]
     #[case(ActiveSonarrBlock::Downloads, ActiveSonarrBlock::DeleteDownloadPrompt)]
+    #[case(ActiveSonarrBlock::Downloads, ActiveSonarrBlock::GrabDownloadPrompt)]
     #[case(ActiveSonarrBlock::Downloads, ActiveSonarrBlock::UpdateDownloadsPrompt)]
+    #[case(
+      ActiveSonarrBlock::ManualImport,
+      ActiveSonarrBlock::ManualImportConfirmPrompt
+    )]
     fn test_downloads_prompt_blocks_esc(
       #[case] base_block: ActiveSonarrBlock,
       #[case] prompt_block: ActiveSonarrBlock,
@@ -222,6 +604,44 @@ mod tests {
       assert!(!app.data.sonarr_data.prompt_confirm);
     }
 
+    #[test]
+    fn test_manual_import_esc() {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(ActiveSonarrBlock::Downloads.into());
+      app.push_navigation_stack(ActiveSonarrBlock::ManualImport.into());
+
+      DownloadsHandler::new(ESC_KEY, &mut app, ActiveSonarrBlock::ManualImport, None).handle();
+
+      assert_navigation_popped!(app, ActiveSonarrBlock::Downloads.into());
+      assert_none!(app.data.sonarr_data.manual_import_modal);
+    }
+
+    #[test]
+    fn test_manual_import_select_series_esc() {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(ActiveSonarrBlock::ManualImport.into());
+      app.push_navigation_stack(ActiveSonarrBlock::ManualImportSelectSeries.into());
+
+      DownloadsHandler::new(
+        ESC_KEY,
+        &mut app,
+        ActiveSonarrBlock::ManualImportSelectSeries,
+        None,
+      )
+      .handle();
+
+      assert_navigation_popped!(app, ActiveSonarrBlock::ManualImport.into());
+      assert_none!(
+        app
+          .data
+          .sonarr_data
+          .manual_import_modal
+          .as_ref()
+          .unwrap()
+          .series_id
+      );
+    }
+
     #[rstest]
     fn test_default_esc(#[values(true, false)] is_ready: bool) {
       let mut app = App::test_default();
@@ -239,8 +659,10 @@ mod tests {
 
   mod test_handle_key_char {
     use crate::assert_navigation_popped;
+    use crate::models::sonarr_models::SonarrManualImportItem;
     use crate::network::sonarr_network::SonarrEvent;
-    use pretty_assertions::assert_eq;
+    use crate::network::sonarr_network::sonarr_network_test_utils::test_utils::manual_import_file;
+    use pretty_assertions::{assert_eq, assert_str_eq};
     use rstest::rstest;
 
     use super::*;
@@ -288,6 +710,51 @@ mod tests {
       assert_eq!(app.get_current_route(), ActiveSonarrBlock::Downloads.into());
     }
 
+    #[test]
+    fn test_grab_key() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveSonarrBlock::Downloads.into());
+      app
+        .data
+        .sonarr_data
+        .downloads
+        .set_items(vec![DownloadRecord {
+          status: DownloadStatus::Delay,
+          ..download_record()
+        }]);
+
+      DownloadsHandler::new(
+        DEFAULT_KEYBINDINGS.grab.key,
+        &mut app,
+        ActiveSonarrBlock::Downloads,
+        None,
+      )
+      .handle();
+
+      assert_navigation_pushed!(app, ActiveSonarrBlock::GrabDownloadPrompt.into());
+    }
+
+    #[test]
+    fn test_grab_key_no_op_when_download_is_not_pending() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveSonarrBlock::Downloads.into());
+      app
+        .data
+        .sonarr_data
+        .downloads
+        .set_items(vec![download_record()]);
+
+      DownloadsHandler::new(
+        DEFAULT_KEYBINDINGS.grab.key,
+        &mut app,
+        ActiveSonarrBlock::Downloads,
+        None,
+      )
+      .handle();
+
+      assert_eq!(app.get_current_route(), ActiveSonarrBlock::Downloads.into());
+    }
+
     #[test]
     fn test_refresh_downloads_key() {
       let mut app = App::test_default();
@@ -333,11 +800,205 @@ mod tests {
       assert!(!app.should_refresh);
     }
 
+    #[test]
+    fn test_manual_import_key() {
+      let mut app = App::test_default();
+      app
+        .data
+        .sonarr_data
+        .downloads
+        .set_items(vec![download_record()]);
+      app.push_navigation_stack(ActiveSonarrBlock::Downloads.into());
+
+      DownloadsHandler::new(
+        DEFAULT_KEYBINDINGS.import.key,
+        &mut app,
+        ActiveSonarrBlock::Downloads,
+        None,
+      )
+      .handle();
+
+      assert_navigation_pushed!(app, ActiveSonarrBlock::ManualImport.into());
+      let manual_import_modal = app.data.sonarr_data.manual_import_modal.as_ref().unwrap();
+      assert_str_eq!(manual_import_modal.download_id, "abc123");
+      assert_none!(manual_import_modal.series_id);
+    }
+
+    #[test]
+    fn test_manual_import_key_no_op_when_download_id_is_missing() {
+      let mut app = App::test_default();
+      app
+        .data
+        .sonarr_data
+        .downloads
+        .set_items(vec![DownloadRecord::default()]);
+      app.push_navigation_stack(ActiveSonarrBlock::Downloads.into());
+
+      DownloadsHandler::new(
+        DEFAULT_KEYBINDINGS.import.key,
+        &mut app,
+        ActiveSonarrBlock::Downloads,
+        None,
+      )
+      .handle();
+
+      assert_eq!(app.get_current_route(), ActiveSonarrBlock::Downloads.into());
+      assert_none!(app.data.sonarr_data.manual_import_modal);
+    }
+
+    #[test]
+    fn test_manual_import_import_key() {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(ActiveSonarrBlock::ManualImport.into());
+
+      DownloadsHandler::new(
+        DEFAULT_KEYBINDINGS.import.key,
+        &mut app,
+        ActiveSonarrBlock::ManualImport,
+        None,
+      )
+      .handle();
+
+      assert_navigation_pushed!(app, ActiveSonarrBlock::ManualImportConfirmPrompt.into());
+    }
+
+    #[test]
+    fn test_manual_import_import_key_no_op_when_nothing_is_importable() {
+      let mut app = App::test_default_fully_populated();
+      app
+        .data
+        .sonarr_data
+        .manual_import_modal
+        .as_mut()
+        .unwrap()
+        .items
+        .set_items(vec![SonarrManualImportItem::default()]);
+      app.push_navigation_stack(ActiveSonarrBlock::ManualImport.into());
+
+      DownloadsHandler::new(
+        DEFAULT_KEYBINDINGS.import.key,
+        &mut app,
+        ActiveSonarrBlock::ManualImport,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveSonarrBlock::ManualImport.into()
+      );
+    }
+
+    #[test]
+    fn test_manual_import_refresh_key() {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(ActiveSonarrBlock::ManualImport.into());
+
+      DownloadsHandler::new(
+        DEFAULT_KEYBINDINGS.refresh.key,
+        &mut app,
+        ActiveSonarrBlock::ManualImport,
+        None,
+      )
+      .handle();
+
+      assert!(app.should_refresh);
+    }
+
+    #[test]
+    fn test_manual_import_confirm_prompt_confirm_key() {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(ActiveSonarrBlock::Downloads.into());
+      app.push_navigation_stack(ActiveSonarrBlock::ManualImport.into());
+      app.push_navigation_stack(ActiveSonarrBlock::ManualImportConfirmPrompt.into());
+
+      DownloadsHandler::new(
+        DEFAULT_KEYBINDINGS.confirm.key,
+        &mut app,
+        ActiveSonarrBlock::ManualImportConfirmPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(app.data.sonarr_data.prompt_confirm);
+      assert_eq!(
+        app.data.sonarr_data.prompt_confirm_action,
+        Some(SonarrEvent::ManualImport(vec![manual_import_file()]))
+      );
+      assert_navigation_popped!(app, ActiveSonarrBlock::Downloads.into());
+    }
+
+    #[test]
+    fn test_delete_download_prompt_confirm_key() {
+      let mut app = App::test_default();
+      app
+        .data
+        .sonarr_data
+        .downloads
+        .set_items(vec![download_record()]);
+      app.push_navigation_stack(ActiveSonarrBlock::Downloads.into());
+      app.push_navigation_stack(ActiveSonarrBlock::DeleteDownloadPrompt.into());
+      app.data.sonarr_data.selected_block =
+        BlockSelectionState::new(DELETE_DOWNLOAD_SELECTION_BLOCKS);
+      app
+        .data
+        .sonarr_data
+        .selected_block
+        .set_index(0, DELETE_DOWNLOAD_SELECTION_BLOCKS.len() - 1);
+
+      DownloadsHandler::new(
+        DEFAULT_KEYBINDINGS.confirm.key,
+        &mut app,
+        ActiveSonarrBlock::DeleteDownloadPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(app.data.sonarr_data.prompt_confirm);
+      assert_eq!(
+        app.data.sonarr_data.prompt_confirm_action,
+        Some(SonarrEvent::DeleteDownload(DeleteDownloadParams {
+          id: 1,
+          removal_options: QueueRemovalOptions::default(),
+        }))
+      );
+      assert_navigation_popped!(app, ActiveSonarrBlock::Downloads.into());
+    }
+
+    #[test]
+    fn test_delete_download_prompt_confirm_key_no_op_when_not_on_confirm_prompt() {
+      let mut app = App::test_default();
+      app
+        .data
+        .sonarr_data
+        .downloads
+        .set_items(vec![download_record()]);
+      app.push_navigation_stack(ActiveSonarrBlock::Downloads.into());
+      app.push_navigation_stack(ActiveSonarrBlock::DeleteDownloadPrompt.into());
+      app.data.sonarr_data.selected_block =
+        BlockSelectionState::new(DELETE_DOWNLOAD_SELECTION_BLOCKS);
+
+      DownloadsHandler::new(
+        DEFAULT_KEYBINDINGS.confirm.key,
+        &mut app,
+        ActiveSonarrBlock::DeleteDownloadPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(!app.data.sonarr_data.prompt_confirm);
+      assert_none!(app.data.sonarr_data.prompt_confirm_action);
+      assert_eq!(
+        app.get_current_route(),
+        ActiveSonarrBlock::DeleteDownloadPrompt.into()
+      );
+    }
+
     #[rstest]
     #[case(
       ActiveSonarrBlock::Downloads,
-      ActiveSonarrBlock::DeleteDownloadPrompt,
-      SonarrEvent::DeleteDownload(1)
+      ActiveSonarrBlock::GrabDownloadPrompt,
+      SonarrEvent::GrabQueueItem(1)
     )]
     #[case(
       ActiveSonarrBlock::Downloads,
@@ -406,23 +1067,78 @@ mod tests {
   }
 
   #[test]
-  fn test_extract_download_id() {
+  fn test_build_delete_download_event() {
     let mut app = App::test_default();
     app
       .data
       .sonarr_data
       .downloads
       .set_items(vec![download_record()]);
+    app.data.sonarr_data.queue_removal_options = QueueRemovalOptions {
+      remove_from_client: false,
+      blocklist: true,
+      skip_redownload: true,
+      change_category: true,
+    };
+
+    let delete_download_event = DownloadsHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveSonarrBlock::DeleteDownloadPrompt,
+      None,
+    )
+    .build_delete_download_event();
+
+    assert_eq!(
+      delete_download_event,
+      SonarrEvent::DeleteDownload(DeleteDownloadParams {
+        id: 1,
+        removal_options: QueueRemovalOptions {
+          remove_from_client: false,
+          blocklist: true,
+          skip_redownload: true,
+          change_category: true,
+        },
+      })
+    );
+    assert_eq!(
+      app.data.sonarr_data.queue_removal_options,
+      QueueRemovalOptions::default()
+    );
+  }
 
-    let download_id = DownloadsHandler::new(
+  #[test]
+  fn test_build_delete_download_event_with_multi_selection() {
+    let mut app = App::test_default();
+    app.data.sonarr_data.downloads.set_items(vec![
+      download_record(),
+      DownloadRecord {
+        id: 2,
+        ..download_record()
+      },
+    ]);
+    app
+      .data
+      .sonarr_data
+      .downloads
+      .toggle_select_all(|download| download.id);
+
+    let delete_download_event = DownloadsHandler::new(
       DEFAULT_KEYBINDINGS.esc.key,
       &mut app,
-      ActiveSonarrBlock::Downloads,
+      ActiveSonarrBlock::DeleteDownloadPrompt,
       None,
     )
-    .extract_download_id();
+    .build_delete_download_event();
 
-    assert_eq!(download_id, 1);
+    assert_eq!(
+      delete_download_event,
+      SonarrEvent::BulkDeleteDownloads(BulkDeleteDownloadsParams {
+        ids: vec![1, 2],
+        removal_options: QueueRemovalOptions::default(),
+      })
+    );
+    assert!(!app.data.sonarr_data.downloads.has_multi_selection());
   }
 
   #[test]
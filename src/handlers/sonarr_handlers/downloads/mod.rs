@@ -4,8 +4,15 @@ use crate::handlers::sonarr_handlers::handle_change_tab_left_right_keys;
 use crate::handlers::table_handler::{TableHandlingConfig, handle_table};
 use crate::handlers::{KeyEventHandler, handle_clear_errors, handle_prompt_toggle};
 use crate::matches_key;
-use crate::models::Route;
-use crate::models::servarr_data::sonarr::sonarr_data::{ActiveSonarrBlock, DOWNLOADS_BLOCKS};
+use crate::models::servarr_data::sonarr::modals::ManualImportModal;
+use crate::models::servarr_data::sonarr::sonarr_data::{
+  ActiveSonarrBlock, DELETE_DOWNLOAD_SELECTION_BLOCKS, DOWNLOADS_BLOCKS,
+};
+use crate::models::servarr_models::{
+  BulkDeleteDownloadsParams, DeleteDownloadParams, QueueRemovalOptions,
+};
+use crate::models::sonarr_models::DownloadRecord;
+use crate::models::{BlockSelectionState, Route, Scrollable};
 use crate::network::sonarr_network::SonarrEvent;
 
 #[cfg(test)]
@@ -20,20 +27,98 @@ pub(super) struct DownloadsHandler<'a, 'b> {
 }
 
 impl DownloadsHandler<'_, '_> {
-  fn extract_download_id(&self) -> i64 {
-    self.app.data.sonarr_data.downloads.current_selection().id
+  fn build_delete_download_event(&mut self) -> SonarrEvent {
+    let removal_options = self.app.data.sonarr_data.queue_removal_options;
+    self.app.data.sonarr_data.queue_removal_options = QueueRemovalOptions::default();
+
+    if self.app.data.sonarr_data.downloads.has_multi_selection() {
+      let ids = self
+        .app
+        .data
+        .sonarr_data
+        .downloads
+        .multi_selection()
+        .iter()
+        .map(|download: &DownloadRecord| download.id)
+        .collect();
+      self.app.data.sonarr_data.downloads.clear_multi_selection();
+
+      SonarrEvent::BulkDeleteDownloads(BulkDeleteDownloadsParams {
+        ids,
+        removal_options,
+      })
+    } else {
+      SonarrEvent::DeleteDownload(DeleteDownloadParams {
+        id: self.app.data.sonarr_data.downloads.current_selection().id,
+        removal_options,
+      })
+    }
+  }
+
+  fn manual_import_modal(&mut self) -> &mut ManualImportModal {
+    self
+      .app
+      .data
+      .sonarr_data
+      .manual_import_modal
+      .as_mut()
+      .expect("manual_import_modal must exist in this context")
+  }
+
+  fn build_manual_import_event(&self) -> Option<SonarrEvent> {
+    let files: Vec<_> = self
+      .app
+      .data
+      .sonarr_data
+      .manual_import_modal
+      .as_ref()
+      .expect("manual_import_modal must exist in this context")
+      .items
+      .items
+      .iter()
+      .filter_map(|item| item.import_file())
+      .collect();
+
+    if files.is_empty() {
+      None
+    } else {
+      Some(SonarrEvent::ManualImport(files))
+    }
+  }
+
+  fn confirm_manual_import(&mut self) {
+    self.app.data.sonarr_data.prompt_confirm_action = self.build_manual_import_event();
+    self.app.data.sonarr_data.manual_import_modal = None;
+    self.app.pop_navigation_stack();
+    self.app.pop_navigation_stack();
   }
 }
 
 impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for DownloadsHandler<'a, 'b> {
   fn handle(&mut self) {
-    let download_table_handling_config =
-      TableHandlingConfig::new(ActiveSonarrBlock::Downloads.into());
+    let downloads_table_handling_config =
+      TableHandlingConfig::new(ActiveSonarrBlock::Downloads.into())
+        .multi_select_id_fn(|download: &DownloadRecord| download.id);
+
+    let manual_import_table_handling_config =
+      TableHandlingConfig::new(ActiveSonarrBlock::ManualImport.into());
 
     if !handle_table(
       self,
       |app| &mut app.data.sonarr_data.downloads,
-      download_table_handling_config,
+      downloads_table_handling_config,
+    ) && !handle_table(
+      self,
+      |app| {
+        &mut app
+          .data
+          .sonarr_data
+          .manual_import_modal
+          .as_mut()
+          .unwrap()
+          .items
+      },
+      manual_import_table_handling_config,
     ) {
       self.handle_key_event();
     }
@@ -66,31 +151,69 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for DownloadsHandler<'a,
   }
 
   fn is_ready(&self) -> bool {
-    !self.app.is_loading && !self.app.data.sonarr_data.downloads.is_empty()
+    if self.app.is_loading {
+      return false;
+    }
+
+    match self.active_sonarr_block {
+      ActiveSonarrBlock::ManualImport
+      | ActiveSonarrBlock::ManualImportConfirmPrompt
+      | ActiveSonarrBlock::ManualImportSelectSeries => {
+        self.app.data.sonarr_data.manual_import_modal.is_some()
+      }
+      _ => !self.app.data.sonarr_data.downloads.is_empty(),
+    }
   }
 
-  fn handle_scroll_up(&mut self) {}
+  fn handle_scroll_up(&mut self) {
+    match self.active_sonarr_block {
+      ActiveSonarrBlock::DeleteDownloadPrompt => self.app.data.sonarr_data.selected_block.up(),
+      ActiveSonarrBlock::ManualImportSelectSeries => {
+        self.manual_import_modal().series_list.scroll_up()
+      }
+      _ => (),
+    }
+  }
 
-  fn handle_scroll_down(&mut self) {}
+  fn handle_scroll_down(&mut self) {
+    match self.active_sonarr_block {
+      ActiveSonarrBlock::DeleteDownloadPrompt => self.app.data.sonarr_data.selected_block.down(),
+      ActiveSonarrBlock::ManualImportSelectSeries => {
+        self.manual_import_modal().series_list.scroll_down()
+      }
+      _ => (),
+    }
+  }
 
-  fn handle_home(&mut self) {}
+  fn handle_home(&mut self) {
+    if self.active_sonarr_block == ActiveSonarrBlock::ManualImportSelectSeries {
+      self.manual_import_modal().series_list.scroll_to_top();
+    }
+  }
 
-  fn handle_end(&mut self) {}
+  fn handle_end(&mut self) {
+    if self.active_sonarr_block == ActiveSonarrBlock::ManualImportSelectSeries {
+      self.manual_import_modal().series_list.scroll_to_bottom();
+    }
+  }
 
   fn handle_delete(&mut self) {
     if self.active_sonarr_block == ActiveSonarrBlock::Downloads {
       self
         .app
-        .push_navigation_stack(ActiveSonarrBlock::DeleteDownloadPrompt.into())
+        .push_navigation_stack(ActiveSonarrBlock::DeleteDownloadPrompt.into());
+      self.app.data.sonarr_data.selected_block =
+        BlockSelectionState::new(DELETE_DOWNLOAD_SELECTION_BLOCKS);
     }
   }
 
   fn handle_left_right_action(&mut self) {
     match self.active_sonarr_block {
       ActiveSonarrBlock::Downloads => handle_change_tab_left_right_keys(self.app, self.key),
-      ActiveSonarrBlock::DeleteDownloadPrompt | ActiveSonarrBlock::UpdateDownloadsPrompt => {
-        handle_prompt_toggle(self.app, self.key)
-      }
+      ActiveSonarrBlock::DeleteDownloadPrompt
+      | ActiveSonarrBlock::GrabDownloadPrompt
+      | ActiveSonarrBlock::UpdateDownloadsPrompt
+      | ActiveSonarrBlock::ManualImportConfirmPrompt => handle_prompt_toggle(self.app, self.key),
       _ => (),
     }
   }
@@ -98,9 +221,38 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for DownloadsHandler<'a,
   fn handle_submit(&mut self) {
     match self.active_sonarr_block {
       ActiveSonarrBlock::DeleteDownloadPrompt => {
+        let removal_options = &mut self.app.data.sonarr_data.queue_removal_options;
+        match self.app.data.sonarr_data.selected_block.get_active_block() {
+          ActiveSonarrBlock::DeleteDownloadConfirmPrompt => {
+            if self.app.data.sonarr_data.prompt_confirm {
+              self.app.data.sonarr_data.prompt_confirm_action =
+                Some(self.build_delete_download_event());
+            } else {
+              self.app.data.sonarr_data.queue_removal_options = QueueRemovalOptions::default();
+            }
+
+            self.app.pop_navigation_stack();
+          }
+          ActiveSonarrBlock::DeleteDownloadToggleRemoveFromClient => {
+            removal_options.remove_from_client = !removal_options.remove_from_client;
+          }
+          ActiveSonarrBlock::DeleteDownloadToggleBlocklist => {
+            removal_options.blocklist = !removal_options.blocklist;
+          }
+          ActiveSonarrBlock::DeleteDownloadToggleSkipRedownload => {
+            removal_options.skip_redownload = !removal_options.skip_redownload;
+          }
+          ActiveSonarrBlock::DeleteDownloadToggleChangeCategory => {
+            removal_options.change_category = !removal_options.change_category;
+          }
+          _ => (),
+        }
+      }
+      ActiveSonarrBlock::GrabDownloadPrompt => {
         if self.app.data.sonarr_data.prompt_confirm {
-          self.app.data.sonarr_data.prompt_confirm_action =
-            Some(SonarrEvent::DeleteDownload(self.extract_download_id()));
+          self.app.data.sonarr_data.prompt_confirm_action = Some(SonarrEvent::GrabQueueItem(
+            self.app.data.sonarr_data.downloads.current_selection().id,
+          ));
         }
 
         self.app.pop_navigation_stack();
@@ -112,16 +264,59 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for DownloadsHandler<'a,
 
         self.app.pop_navigation_stack();
       }
+      ActiveSonarrBlock::ManualImport
+        if self
+          .app
+          .data
+          .sonarr_data
+          .manual_import_modal
+          .as_ref()
+          .is_some_and(|modal| !modal.items.is_empty()) =>
+      {
+        let series = self.app.data.sonarr_data.series.items.clone();
+        self.manual_import_modal().series_list.set_items(series);
+        self
+          .app
+          .push_navigation_stack(ActiveSonarrBlock::ManualImportSelectSeries.into());
+      }
+      ActiveSonarrBlock::ManualImportSelectSeries => {
+        let manual_import_modal = self.manual_import_modal();
+        if !manual_import_modal.series_list.items.is_empty() {
+          manual_import_modal.series_id =
+            Some(manual_import_modal.series_list.current_selection().id);
+        }
+
+        self.app.pop_navigation_stack();
+      }
+      ActiveSonarrBlock::ManualImportConfirmPrompt => {
+        if self.app.data.sonarr_data.prompt_confirm {
+          self.confirm_manual_import();
+        } else {
+          self.app.pop_navigation_stack();
+        }
+      }
       _ => (),
     }
   }
 
   fn handle_esc(&mut self) {
     match self.active_sonarr_block {
-      ActiveSonarrBlock::DeleteDownloadPrompt | ActiveSonarrBlock::UpdateDownloadsPrompt => {
+      ActiveSonarrBlock::DeleteDownloadPrompt => {
         self.app.pop_navigation_stack();
+        self.app.data.sonarr_data.queue_removal_options = QueueRemovalOptions::default();
         self.app.data.sonarr_data.prompt_confirm = false;
       }
+      ActiveSonarrBlock::GrabDownloadPrompt
+      | ActiveSonarrBlock::UpdateDownloadsPrompt
+      | ActiveSonarrBlock::ManualImportConfirmPrompt => {
+        self.app.pop_navigation_stack();
+        self.app.data.sonarr_data.prompt_confirm = false;
+      }
+      ActiveSonarrBlock::ManualImport => {
+        self.app.pop_navigation_stack();
+        self.app.data.sonarr_data.manual_import_modal = None;
+      }
+      ActiveSonarrBlock::ManualImportSelectSeries => self.app.pop_navigation_stack(),
       _ => handle_clear_errors(self.app),
     }
   }
@@ -135,16 +330,71 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for DownloadsHandler<'a,
             .app
             .push_navigation_stack(ActiveSonarrBlock::UpdateDownloadsPrompt.into());
         }
+        _ if matches_key!(grab, key)
+          && self
+            .app
+            .data
+            .sonarr_data
+            .downloads
+            .current_selection()
+            .is_pending() =>
+        {
+          self
+            .app
+            .push_navigation_stack(ActiveSonarrBlock::GrabDownloadPrompt.into());
+        }
         _ if matches_key!(refresh, key) => {
           self.app.should_refresh = true;
         }
+        _ if matches_key!(import, key) => {
+          let download_record = self.app.data.sonarr_data.downloads.current_selection();
+          if let Some(download_id) = download_record.download_id.clone() {
+            self.app.data.sonarr_data.manual_import_modal = Some(ManualImportModal {
+              download_id,
+              ..ManualImportModal::default()
+            });
+            self
+              .app
+              .push_navigation_stack(ActiveSonarrBlock::ManualImport.into());
+          }
+        }
         _ => (),
       },
-      ActiveSonarrBlock::DeleteDownloadPrompt => {
+      ActiveSonarrBlock::ManualImport => match self.key {
+        _ if matches_key!(import, key) && self.build_manual_import_event().is_some() => {
+          self
+            .app
+            .push_navigation_stack(ActiveSonarrBlock::ManualImportConfirmPrompt.into());
+        }
+        _ if matches_key!(refresh, key) => {
+          self.app.should_refresh = true;
+        }
+        _ => (),
+      },
+      ActiveSonarrBlock::ManualImportConfirmPrompt => {
+        if matches_key!(confirm, key) {
+          self.app.data.sonarr_data.prompt_confirm = true;
+          self.confirm_manual_import();
+        }
+      }
+      ActiveSonarrBlock::DeleteDownloadPrompt
+        if self.app.data.sonarr_data.selected_block.get_active_block()
+          == ActiveSonarrBlock::DeleteDownloadConfirmPrompt =>
+      {
         if matches_key!(confirm, key) {
           self.app.data.sonarr_data.prompt_confirm = true;
           self.app.data.sonarr_data.prompt_confirm_action =
-            Some(SonarrEvent::DeleteDownload(self.extract_download_id()));
+            Some(self.build_delete_download_event());
+
+          self.app.pop_navigation_stack();
+        }
+      }
+      ActiveSonarrBlock::GrabDownloadPrompt => {
+        if matches_key!(confirm, key) {
+          self.app.data.sonarr_data.prompt_confirm = true;
+          self.app.data.sonarr_data.prompt_confirm_action = Some(SonarrEvent::GrabQueueItem(
+            self.app.data.sonarr_data.downloads.current_selection().id,
+          ));
 
           self.app.pop_navigation_stack();
         }
@@ -12,7 +12,8 @@ mod tests {
   use crate::handlers::sonarr_handlers::indexers::IndexersHandler;
   use crate::handlers::sonarr_handlers::sonarr_handler_test_utils::utils::indexer;
   use crate::models::servarr_data::sonarr::sonarr_data::{
-    ActiveSonarrBlock, EDIT_INDEXER_BLOCKS, INDEXER_SETTINGS_BLOCKS, INDEXERS_BLOCKS,
+    ADD_INDEXER_BLOCKS, ActiveSonarrBlock, EDIT_INDEXER_BLOCKS, INDEXER_SETTINGS_BLOCKS,
+    INDEXERS_BLOCKS,
   };
   use crate::models::servarr_models::Indexer;
   use crate::test_handler_delegation;
@@ -101,9 +102,9 @@ mod tests {
 
       assert_eq!(
         app.data.sonarr_data.main_tabs.get_active_route(),
-        ActiveSonarrBlock::System.into()
+        ActiveSonarrBlock::DownloadClients.into()
       );
-      assert_navigation_pushed!(app, ActiveSonarrBlock::System.into());
+      assert_navigation_pushed!(app, ActiveSonarrBlock::DownloadClients.into());
     }
 
     #[rstest]
@@ -166,10 +167,12 @@ mod tests {
         IndexerField {
           name: Some("baseUrl".to_owned()),
           value: Some(Value::String("https://test.com".to_owned())),
+          ..IndexerField::default()
         },
         IndexerField {
           name: Some("apiKey".to_owned()),
           value: Some(Value::String("1234".to_owned())),
+          ..IndexerField::default()
         },
       ];
 
@@ -177,6 +180,7 @@ mod tests {
         fields.push(IndexerField {
           name: Some("seedCriteria.seedRatio".to_owned()),
           value: Some(Value::from(1.2f64)),
+          ..IndexerField::default()
         });
         expected_edit_indexer_modal.seed_ratio = "1.2".into();
       }
@@ -397,6 +401,52 @@ mod tests {
       assert!(!app.should_refresh);
     }
 
+    #[test]
+    fn test_indexer_add_key() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveSonarrBlock::Indexers.into());
+      app
+        .data
+        .sonarr_data
+        .indexers
+        .set_items(vec![Indexer::default()]);
+
+      IndexersHandler::new(
+        DEFAULT_KEYBINDINGS.add.key,
+        &mut app,
+        ActiveSonarrBlock::Indexers,
+        None,
+      )
+      .handle();
+
+      assert_navigation_pushed!(
+        app,
+        ActiveSonarrBlock::AddIndexerSelectImplementation.into()
+      );
+    }
+
+    #[test]
+    fn test_indexer_add_key_no_op_when_not_ready() {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.push_navigation_stack(ActiveSonarrBlock::Indexers.into());
+      app
+        .data
+        .sonarr_data
+        .indexers
+        .set_items(vec![Indexer::default()]);
+
+      IndexersHandler::new(
+        DEFAULT_KEYBINDINGS.add.key,
+        &mut app,
+        ActiveSonarrBlock::Indexers,
+        None,
+      )
+      .handle();
+
+      assert_eq!(app.get_current_route(), ActiveSonarrBlock::Indexers.into());
+    }
+
     #[test]
     fn test_indexer_settings_key() {
       let mut app = App::test_default();
@@ -615,6 +665,7 @@ mod tests {
     let mut indexers_blocks = Vec::new();
     indexers_blocks.extend(INDEXERS_BLOCKS);
     indexers_blocks.extend(INDEXER_SETTINGS_BLOCKS);
+    indexers_blocks.extend(ADD_INDEXER_BLOCKS);
     indexers_blocks.extend(EDIT_INDEXER_BLOCKS);
     indexers_blocks.push(ActiveSonarrBlock::TestAllIndexers);
 
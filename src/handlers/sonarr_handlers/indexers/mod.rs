@@ -1,6 +1,7 @@
 use crate::app::App;
 use crate::event::Key;
 use crate::handlers::sonarr_handlers::handle_change_tab_left_right_keys;
+use crate::handlers::sonarr_handlers::indexers::add_indexer_handler::AddIndexerHandler;
 use crate::handlers::sonarr_handlers::indexers::edit_indexer_handler::EditIndexerHandler;
 use crate::handlers::sonarr_handlers::indexers::edit_indexer_settings_handler::IndexerSettingsHandler;
 use crate::handlers::sonarr_handlers::indexers::test_all_indexers_handler::TestAllIndexersHandler;
@@ -14,6 +15,7 @@ use crate::models::servarr_data::sonarr::sonarr_data::{
 use crate::models::{BlockSelectionState, Route};
 use crate::network::sonarr_network::SonarrEvent;
 
+mod add_indexer_handler;
 mod edit_indexer_handler;
 mod edit_indexer_settings_handler;
 mod test_all_indexers_handler;
@@ -46,6 +48,10 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for IndexersHandler<'a,
       indexers_table_handling_config,
     ) {
       match self.active_sonarr_block {
+        _ if AddIndexerHandler::accepts(self.active_sonarr_block) => {
+          AddIndexerHandler::new(self.key, self.app, self.active_sonarr_block, self.context)
+            .handle()
+        }
         _ if EditIndexerHandler::accepts(self.active_sonarr_block) => {
           EditIndexerHandler::new(self.key, self.app, self.active_sonarr_block, self.context)
             .handle()
@@ -64,7 +70,8 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for IndexersHandler<'a,
   }
 
   fn accepts(active_block: ActiveSonarrBlock) -> bool {
-    EditIndexerHandler::accepts(active_block)
+    AddIndexerHandler::accepts(active_block)
+      || EditIndexerHandler::accepts(active_block)
       || IndexerSettingsHandler::accepts(active_block)
       || TestAllIndexersHandler::accepts(active_block)
       || INDEXERS_BLOCKS.contains(&active_block)
@@ -175,6 +182,11 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for IndexersHandler<'a,
         _ if matches_key!(refresh, key) => {
           self.app.should_refresh = true;
         }
+        _ if matches_key!(add, key) => {
+          self
+            .app
+            .push_navigation_stack(ActiveSonarrBlock::AddIndexerSelectImplementation.into());
+        }
         _ if matches_key!(test, key) => {
           self
             .app
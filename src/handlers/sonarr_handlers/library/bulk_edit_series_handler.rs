@@ -0,0 +1,318 @@
+use crate::app::App;
+use crate::event::Key;
+use crate::handlers::{KeyEventHandler, handle_prompt_toggle};
+use crate::models::servarr_data::sonarr::sonarr_data::{
+  ActiveSonarrBlock, BULK_EDIT_SERIES_BLOCKS,
+};
+use crate::models::sonarr_models::BulkEditSeriesParams;
+use crate::models::{Route, Scrollable};
+use crate::network::sonarr_network::SonarrEvent;
+use crate::{handle_text_box_keys, handle_text_box_left_right_keys, matches_key};
+
+#[cfg(test)]
+#[path = "bulk_edit_series_handler_tests.rs"]
+mod bulk_edit_series_handler_tests;
+
+pub(super) struct BulkEditSeriesHandler<'a, 'b> {
+  key: Key,
+  app: &'a mut App<'b>,
+  active_sonarr_block: ActiveSonarrBlock,
+  _context: Option<ActiveSonarrBlock>,
+}
+
+impl BulkEditSeriesHandler<'_, '_> {
+  fn build_bulk_edit_series_params(&mut self) -> BulkEditSeriesParams {
+    let series_ids = self
+      .app
+      .data
+      .sonarr_data
+      .series
+      .multi_selection()
+      .iter()
+      .map(|series| series.id)
+      .collect();
+    let bulk_edit_modal = self
+      .app
+      .data
+      .sonarr_data
+      .bulk_edit_modal
+      .take()
+      .expect("Bulk edit modal is None");
+    let quality_profile_id =
+      bulk_edit_modal
+        .selected_quality_profile()
+        .and_then(|quality_profile| {
+          self
+            .app
+            .data
+            .sonarr_data
+            .quality_profile_map
+            .get_by_right(quality_profile)
+            .copied()
+        });
+    self.app.data.sonarr_data.series.clear_multi_selection();
+
+    BulkEditSeriesParams {
+      series_ids,
+      monitored: bulk_edit_modal
+        .monitored_list
+        .current_selection()
+        .monitored(),
+      quality_profile_id,
+      root_folder_path: bulk_edit_modal.selected_root_folder_path(),
+      tags: None,
+      tag_input_string: bulk_edit_modal.tag_input_string(),
+      apply_tags: *bulk_edit_modal.apply_tags_list.current_selection(),
+      move_files: bulk_edit_modal.move_files.unwrap_or_default(),
+    }
+  }
+}
+
+impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for BulkEditSeriesHandler<'a, 'b> {
+  fn accepts(active_block: ActiveSonarrBlock) -> bool {
+    BULK_EDIT_SERIES_BLOCKS.contains(&active_block)
+  }
+
+  fn ignore_special_keys(&self) -> bool {
+    self.app.ignore_special_keys_for_textbox_input
+  }
+
+  fn new(
+    key: Key,
+    app: &'a mut App<'b>,
+    active_block: ActiveSonarrBlock,
+    _context: Option<ActiveSonarrBlock>,
+  ) -> BulkEditSeriesHandler<'a, 'b> {
+    BulkEditSeriesHandler {
+      key,
+      app,
+      active_sonarr_block: active_block,
+      _context,
+    }
+  }
+
+  fn get_key(&self) -> Key {
+    self.key
+  }
+
+  fn is_ready(&self) -> bool {
+    !self.app.is_loading && self.app.data.sonarr_data.bulk_edit_modal.is_some()
+  }
+
+  fn handle_scroll_up(&mut self) {
+    let bulk_edit_modal = self.app.data.sonarr_data.bulk_edit_modal.as_mut().unwrap();
+    match self.active_sonarr_block {
+      ActiveSonarrBlock::BulkEditSeriesSelectMonitored => {
+        bulk_edit_modal.monitored_list.scroll_up()
+      }
+      ActiveSonarrBlock::BulkEditSeriesSelectQualityProfile => {
+        bulk_edit_modal.quality_profile_list.scroll_up()
+      }
+      ActiveSonarrBlock::BulkEditSeriesSelectRootFolder => {
+        bulk_edit_modal.root_folder_list.scroll_up()
+      }
+      ActiveSonarrBlock::BulkEditSeriesSelectApplyTags => {
+        bulk_edit_modal.apply_tags_list.scroll_up()
+      }
+      ActiveSonarrBlock::BulkEditSeriesPrompt => self.app.data.sonarr_data.selected_block.up(),
+      _ => (),
+    }
+  }
+
+  fn handle_scroll_down(&mut self) {
+    let bulk_edit_modal = self.app.data.sonarr_data.bulk_edit_modal.as_mut().unwrap();
+    match self.active_sonarr_block {
+      ActiveSonarrBlock::BulkEditSeriesSelectMonitored => {
+        bulk_edit_modal.monitored_list.scroll_down()
+      }
+      ActiveSonarrBlock::BulkEditSeriesSelectQualityProfile => {
+        bulk_edit_modal.quality_profile_list.scroll_down()
+      }
+      ActiveSonarrBlock::BulkEditSeriesSelectRootFolder => {
+        bulk_edit_modal.root_folder_list.scroll_down()
+      }
+      ActiveSonarrBlock::BulkEditSeriesSelectApplyTags => {
+        bulk_edit_modal.apply_tags_list.scroll_down()
+      }
+      ActiveSonarrBlock::BulkEditSeriesPrompt => self.app.data.sonarr_data.selected_block.down(),
+      _ => (),
+    }
+  }
+
+  fn handle_home(&mut self) {
+    let bulk_edit_modal = self.app.data.sonarr_data.bulk_edit_modal.as_mut().unwrap();
+    match self.active_sonarr_block {
+      ActiveSonarrBlock::BulkEditSeriesSelectMonitored => {
+        bulk_edit_modal.monitored_list.scroll_to_top()
+      }
+      ActiveSonarrBlock::BulkEditSeriesSelectQualityProfile => {
+        bulk_edit_modal.quality_profile_list.scroll_to_top()
+      }
+      ActiveSonarrBlock::BulkEditSeriesSelectRootFolder => {
+        bulk_edit_modal.root_folder_list.scroll_to_top()
+      }
+      ActiveSonarrBlock::BulkEditSeriesSelectApplyTags => {
+        bulk_edit_modal.apply_tags_list.scroll_to_top()
+      }
+      ActiveSonarrBlock::BulkEditSeriesTagsInput => bulk_edit_modal.tags.scroll_home(),
+      _ => (),
+    }
+  }
+
+  fn handle_end(&mut self) {
+    let bulk_edit_modal = self.app.data.sonarr_data.bulk_edit_modal.as_mut().unwrap();
+    match self.active_sonarr_block {
+      ActiveSonarrBlock::BulkEditSeriesSelectMonitored => {
+        bulk_edit_modal.monitored_list.scroll_to_bottom()
+      }
+      ActiveSonarrBlock::BulkEditSeriesSelectQualityProfile => {
+        bulk_edit_modal.quality_profile_list.scroll_to_bottom()
+      }
+      ActiveSonarrBlock::BulkEditSeriesSelectRootFolder => {
+        bulk_edit_modal.root_folder_list.scroll_to_bottom()
+      }
+      ActiveSonarrBlock::BulkEditSeriesSelectApplyTags => {
+        bulk_edit_modal.apply_tags_list.scroll_to_bottom()
+      }
+      ActiveSonarrBlock::BulkEditSeriesTagsInput => bulk_edit_modal.tags.reset_offset(),
+      _ => (),
+    }
+  }
+
+  fn handle_delete(&mut self) {}
+
+  fn handle_left_right_action(&mut self) {
+    match self.active_sonarr_block {
+      ActiveSonarrBlock::BulkEditSeriesPrompt => handle_prompt_toggle(self.app, self.key),
+      ActiveSonarrBlock::BulkEditSeriesTagsInput => {
+        handle_text_box_left_right_keys!(
+          self,
+          self.key,
+          self
+            .app
+            .data
+            .sonarr_data
+            .bulk_edit_modal
+            .as_mut()
+            .unwrap()
+            .tags
+        )
+      }
+      _ => (),
+    }
+  }
+
+  fn handle_submit(&mut self) {
+    match self.active_sonarr_block {
+      ActiveSonarrBlock::BulkEditSeriesPrompt => {
+        match self.app.data.sonarr_data.selected_block.get_active_block() {
+          ActiveSonarrBlock::BulkEditSeriesConfirmPrompt => {
+            if self.app.data.sonarr_data.prompt_confirm {
+              self.app.data.sonarr_data.prompt_confirm_action = Some(SonarrEvent::BulkEditSeries(
+                self.build_bulk_edit_series_params(),
+              ));
+              self.app.should_refresh = true;
+            } else {
+              self.app.data.sonarr_data.bulk_edit_modal = None;
+            }
+
+            self.app.pop_navigation_stack();
+          }
+          ActiveSonarrBlock::BulkEditSeriesSelectMonitored
+          | ActiveSonarrBlock::BulkEditSeriesSelectQualityProfile
+          | ActiveSonarrBlock::BulkEditSeriesSelectRootFolder
+          | ActiveSonarrBlock::BulkEditSeriesSelectApplyTags => self.app.push_navigation_stack(
+            self
+              .app
+              .data
+              .sonarr_data
+              .selected_block
+              .get_active_block()
+              .into(),
+          ),
+          ActiveSonarrBlock::BulkEditSeriesTagsInput => {
+            self
+              .app
+              .push_navigation_stack(ActiveSonarrBlock::BulkEditSeriesTagsInput.into());
+            self.app.ignore_special_keys_for_textbox_input = true;
+          }
+          ActiveSonarrBlock::BulkEditSeriesToggleMoveFiles => {
+            let bulk_edit_modal = self.app.data.sonarr_data.bulk_edit_modal.as_mut().unwrap();
+            bulk_edit_modal.move_files = Some(!bulk_edit_modal.move_files.unwrap_or_default());
+          }
+          _ => (),
+        }
+      }
+      ActiveSonarrBlock::BulkEditSeriesSelectMonitored
+      | ActiveSonarrBlock::BulkEditSeriesSelectQualityProfile
+      | ActiveSonarrBlock::BulkEditSeriesSelectRootFolder
+      | ActiveSonarrBlock::BulkEditSeriesSelectApplyTags => self.app.pop_navigation_stack(),
+      ActiveSonarrBlock::BulkEditSeriesTagsInput => {
+        self.app.pop_navigation_stack();
+        self.app.ignore_special_keys_for_textbox_input = false;
+      }
+      _ => (),
+    }
+  }
+
+  fn handle_esc(&mut self) {
+    match self.active_sonarr_block {
+      ActiveSonarrBlock::BulkEditSeriesTagsInput => {
+        self.app.pop_navigation_stack();
+        self.app.ignore_special_keys_for_textbox_input = false;
+      }
+      ActiveSonarrBlock::BulkEditSeriesPrompt => {
+        self.app.pop_navigation_stack();
+        self.app.data.sonarr_data.bulk_edit_modal = None;
+        self.app.data.sonarr_data.prompt_confirm = false;
+      }
+      ActiveSonarrBlock::BulkEditSeriesSelectMonitored
+      | ActiveSonarrBlock::BulkEditSeriesSelectQualityProfile
+      | ActiveSonarrBlock::BulkEditSeriesSelectRootFolder
+      | ActiveSonarrBlock::BulkEditSeriesSelectApplyTags => self.app.pop_navigation_stack(),
+      _ => (),
+    }
+  }
+
+  fn handle_char_key_event(&mut self) {
+    let key = self.key;
+    match self.active_sonarr_block {
+      ActiveSonarrBlock::BulkEditSeriesTagsInput => {
+        handle_text_box_keys!(
+          self,
+          key,
+          self
+            .app
+            .data
+            .sonarr_data
+            .bulk_edit_modal
+            .as_mut()
+            .unwrap()
+            .tags
+        )
+      }
+      ActiveSonarrBlock::BulkEditSeriesPrompt
+        if self.app.data.sonarr_data.selected_block.get_active_block()
+          == ActiveSonarrBlock::BulkEditSeriesConfirmPrompt
+          && matches_key!(confirm, key) =>
+      {
+        self.app.data.sonarr_data.prompt_confirm = true;
+        self.app.data.sonarr_data.prompt_confirm_action = Some(SonarrEvent::BulkEditSeries(
+          self.build_bulk_edit_series_params(),
+        ));
+        self.app.should_refresh = true;
+
+        self.app.pop_navigation_stack();
+      }
+      _ => (),
+    }
+  }
+
+  fn app_mut(&mut self) -> &mut App<'b> {
+    self.app
+  }
+
+  fn current_route(&self) -> Route {
+    self.app.get_current_route()
+  }
+}
@@ -0,0 +1,828 @@
+#[cfg(test)]
+mod tests {
+  use std::sync::atomic::Ordering;
+
+  use bimap::BiMap;
+  use pretty_assertions::assert_eq;
+  use rstest::rstest;
+  use strum::IntoEnumIterator;
+
+  use crate::app::App;
+  use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+  use crate::assert_modal_absent;
+  use crate::assert_navigation_popped;
+  use crate::assert_navigation_pushed;
+  use crate::event::Key;
+  use crate::handlers::KeyEventHandler;
+  use crate::handlers::sonarr_handlers::library::bulk_edit_series_handler::BulkEditSeriesHandler;
+  use crate::handlers::sonarr_handlers::sonarr_handler_test_utils::utils::series;
+  use crate::models::servarr_data::modals::BulkEditModal;
+  use crate::models::servarr_data::sonarr::sonarr_data::{
+    ActiveSonarrBlock, BULK_EDIT_SERIES_BLOCKS, BULK_EDIT_SERIES_SELECTION_BLOCKS,
+  };
+  use crate::models::servarr_models::{ApplyTags, BulkEditMonitoring};
+  use crate::models::sonarr_models::{BulkEditSeriesParams, Series};
+  use crate::models::{BlockSelectionState, Scrollable};
+  use crate::network::sonarr_network::SonarrEvent;
+
+  fn app_with_selected_series() -> App<'static> {
+    let mut app = App::test_default();
+    let series = vec![
+      series(),
+      Series { id: 2, ..series() },
+      Series { id: 3, ..series() },
+    ];
+    app.data.sonarr_data.series.set_items(series.clone());
+    app.data.sonarr_data.series.selected_ids = [series[2].id, series[0].id].into();
+    app.data.sonarr_data.series.selection_id_fn = Some(|series| series.id);
+    app.data.sonarr_data.quality_profile_map =
+      BiMap::from_iter([(1111, "Any".to_owned()), (2222, "HD - 1080p".to_owned())]);
+    let mut bulk_edit_modal = BulkEditModal::new(
+      vec!["Any".to_owned(), "HD - 1080p".to_owned()],
+      vec!["/nfs/series".to_owned()],
+    );
+    bulk_edit_modal.monitored_list.scroll_down();
+    bulk_edit_modal.quality_profile_list.scroll_to_bottom();
+    bulk_edit_modal.root_folder_list.scroll_to_bottom();
+    bulk_edit_modal.apply_tags_list.scroll_to_bottom();
+    bulk_edit_modal.tags = "usenet, testing".into();
+    bulk_edit_modal.move_files = Some(true);
+    app.data.sonarr_data.bulk_edit_modal = Some(bulk_edit_modal);
+
+    app
+  }
+
+  fn expected_bulk_edit_series_params() -> BulkEditSeriesParams {
+    BulkEditSeriesParams {
+      series_ids: vec![1, 3],
+      monitored: Some(true),
+      quality_profile_id: Some(2222),
+      root_folder_path: Some("/nfs/series".to_owned()),
+      tags: None,
+      tag_input_string: Some("usenet, testing".to_owned()),
+      apply_tags: ApplyTags::Replace,
+      move_files: true,
+    }
+  }
+
+  mod test_handle_scroll_up_and_down {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[rstest]
+    fn test_bulk_edit_series_select_monitored_scroll(
+      #[values(DEFAULT_KEYBINDINGS.up.key, DEFAULT_KEYBINDINGS.down.key)] key: Key,
+    ) {
+      let monitoring_vec = Vec::from_iter(BulkEditMonitoring::iter());
+      let mut app = App::test_default();
+      app.data.sonarr_data.bulk_edit_modal = Some(BulkEditModal::new(Vec::new(), Vec::new()));
+
+      if key == Key::Up {
+        for i in (0..monitoring_vec.len()).rev() {
+          BulkEditSeriesHandler::new(
+            key,
+            &mut app,
+            ActiveSonarrBlock::BulkEditSeriesSelectMonitored,
+            None,
+          )
+          .handle();
+
+          assert_eq!(
+            app
+              .data
+              .sonarr_data
+              .bulk_edit_modal
+              .as_ref()
+              .unwrap()
+              .monitored_list
+              .current_selection(),
+            &monitoring_vec[i]
+          );
+        }
+      } else {
+        for i in 0..monitoring_vec.len() {
+          BulkEditSeriesHandler::new(
+            key,
+            &mut app,
+            ActiveSonarrBlock::BulkEditSeriesSelectMonitored,
+            None,
+          )
+          .handle();
+
+          assert_eq!(
+            app
+              .data
+              .sonarr_data
+              .bulk_edit_modal
+              .as_ref()
+              .unwrap()
+              .monitored_list
+              .current_selection(),
+            &monitoring_vec[(i + 1) % monitoring_vec.len()]
+          );
+        }
+      }
+    }
+
+    #[rstest]
+    fn test_bulk_edit_series_select_quality_profile_scroll(
+      #[values(DEFAULT_KEYBINDINGS.up.key, DEFAULT_KEYBINDINGS.down.key)] key: Key,
+    ) {
+      let mut app = App::test_default();
+      app.data.sonarr_data.bulk_edit_modal = Some(BulkEditModal::new(
+        vec!["HD - 1080p".to_owned()],
+        Vec::new(),
+      ));
+
+      BulkEditSeriesHandler::new(
+        key,
+        &mut app,
+        ActiveSonarrBlock::BulkEditSeriesSelectQualityProfile,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app
+          .data
+          .sonarr_data
+          .bulk_edit_modal
+          .as_ref()
+          .unwrap()
+          .quality_profile_list
+          .current_selection(),
+        "HD - 1080p"
+      );
+    }
+
+    #[rstest]
+    fn test_bulk_edit_series_select_root_folder_scroll(
+      #[values(DEFAULT_KEYBINDINGS.up.key, DEFAULT_KEYBINDINGS.down.key)] key: Key,
+    ) {
+      let mut app = App::test_default();
+      app.data.sonarr_data.bulk_edit_modal = Some(BulkEditModal::new(
+        Vec::new(),
+        vec!["/nfs/series".to_owned()],
+      ));
+
+      BulkEditSeriesHandler::new(
+        key,
+        &mut app,
+        ActiveSonarrBlock::BulkEditSeriesSelectRootFolder,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app
+          .data
+          .sonarr_data
+          .bulk_edit_modal
+          .as_ref()
+          .unwrap()
+          .root_folder_list
+          .current_selection(),
+        "/nfs/series"
+      );
+    }
+
+    #[rstest]
+    fn test_bulk_edit_series_select_apply_tags_scroll(
+      #[values(DEFAULT_KEYBINDINGS.up.key, DEFAULT_KEYBINDINGS.down.key)] key: Key,
+    ) {
+      let mut app = App::test_default();
+      app.data.sonarr_data.bulk_edit_modal = Some(BulkEditModal::new(Vec::new(), Vec::new()));
+
+      BulkEditSeriesHandler::new(
+        key,
+        &mut app,
+        ActiveSonarrBlock::BulkEditSeriesSelectApplyTags,
+        None,
+      )
+      .handle();
+
+      let expected_apply_tags = if key == Key::Up {
+        ApplyTags::Replace
+      } else {
+        ApplyTags::Remove
+      };
+      assert_eq!(
+        app
+          .data
+          .sonarr_data
+          .bulk_edit_modal
+          .as_ref()
+          .unwrap()
+          .apply_tags_list
+          .current_selection(),
+        &expected_apply_tags
+      );
+    }
+
+    #[rstest]
+    fn test_bulk_edit_series_prompt_scroll(#[values(Key::Up, Key::Down)] key: Key) {
+      let mut app = App::test_default();
+      app.data.sonarr_data.bulk_edit_modal = Some(BulkEditModal::new(Vec::new(), Vec::new()));
+      app.data.sonarr_data.selected_block =
+        BlockSelectionState::new(BULK_EDIT_SERIES_SELECTION_BLOCKS);
+      app.data.sonarr_data.selected_block.down();
+
+      BulkEditSeriesHandler::new(key, &mut app, ActiveSonarrBlock::BulkEditSeriesPrompt, None)
+        .handle();
+
+      if key == Key::Up {
+        assert_eq!(
+          app.data.sonarr_data.selected_block.get_active_block(),
+          ActiveSonarrBlock::BulkEditSeriesSelectMonitored
+        );
+      } else {
+        assert_eq!(
+          app.data.sonarr_data.selected_block.get_active_block(),
+          ActiveSonarrBlock::BulkEditSeriesSelectRootFolder
+        );
+      }
+    }
+  }
+
+  mod test_handle_home_end {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_bulk_edit_series_select_monitored_home_end() {
+      let mut app = App::test_default();
+      app.data.sonarr_data.bulk_edit_modal = Some(BulkEditModal::new(Vec::new(), Vec::new()));
+
+      BulkEditSeriesHandler::new(
+        DEFAULT_KEYBINDINGS.end.key,
+        &mut app,
+        ActiveSonarrBlock::BulkEditSeriesSelectMonitored,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app
+          .data
+          .sonarr_data
+          .bulk_edit_modal
+          .as_ref()
+          .unwrap()
+          .monitored_list
+          .current_selection(),
+        &BulkEditMonitoring::Unmonitored
+      );
+
+      BulkEditSeriesHandler::new(
+        DEFAULT_KEYBINDINGS.home.key,
+        &mut app,
+        ActiveSonarrBlock::BulkEditSeriesSelectMonitored,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app
+          .data
+          .sonarr_data
+          .bulk_edit_modal
+          .as_ref()
+          .unwrap()
+          .monitored_list
+          .current_selection(),
+        &BulkEditMonitoring::NoChange
+      );
+    }
+
+    #[test]
+    fn test_bulk_edit_series_tags_input_home_end_keys() {
+      let mut app = App::test_default();
+      app.data.sonarr_data.bulk_edit_modal = Some(BulkEditModal {
+        tags: "Test".into(),
+        ..BulkEditModal::default()
+      });
+
+      BulkEditSeriesHandler::new(
+        DEFAULT_KEYBINDINGS.home.key,
+        &mut app,
+        ActiveSonarrBlock::BulkEditSeriesTagsInput,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app
+          .data
+          .sonarr_data
+          .bulk_edit_modal
+          .as_ref()
+          .unwrap()
+          .tags
+          .offset
+          .load(Ordering::SeqCst),
+        4
+      );
+
+      BulkEditSeriesHandler::new(
+        DEFAULT_KEYBINDINGS.end.key,
+        &mut app,
+        ActiveSonarrBlock::BulkEditSeriesTagsInput,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app
+          .data
+          .sonarr_data
+          .bulk_edit_modal
+          .as_ref()
+          .unwrap()
+          .tags
+          .offset
+          .load(Ordering::SeqCst),
+        0
+      );
+    }
+  }
+
+  mod test_handle_left_right_action {
+    use super::*;
+
+    #[rstest]
+    fn test_left_right_prompt_toggle(#[values(Key::Left, Key::Right)] key: Key) {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveSonarrBlock::Series.into());
+      app.data.sonarr_data.bulk_edit_modal = Some(BulkEditModal::new(Vec::new(), Vec::new()));
+
+      BulkEditSeriesHandler::new(key, &mut app, ActiveSonarrBlock::BulkEditSeriesPrompt, None)
+        .handle();
+
+      assert!(app.data.sonarr_data.prompt_confirm);
+
+      BulkEditSeriesHandler::new(key, &mut app, ActiveSonarrBlock::BulkEditSeriesPrompt, None)
+        .handle();
+
+      assert!(!app.data.sonarr_data.prompt_confirm);
+    }
+  }
+
+  mod test_handle_submit {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    const SUBMIT_KEY: Key = DEFAULT_KEYBINDINGS.submit.key;
+
+    #[test]
+    fn test_bulk_edit_series_prompt_decline_submit() {
+      let mut app = app_with_selected_series();
+      app.push_navigation_stack(ActiveSonarrBlock::Series.into());
+      app.push_navigation_stack(ActiveSonarrBlock::BulkEditSeriesPrompt.into());
+      app.data.sonarr_data.selected_block =
+        BlockSelectionState::new(BULK_EDIT_SERIES_SELECTION_BLOCKS);
+      app
+        .data
+        .sonarr_data
+        .selected_block
+        .set_index(0, BULK_EDIT_SERIES_SELECTION_BLOCKS.len() - 1);
+
+      BulkEditSeriesHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveSonarrBlock::BulkEditSeriesPrompt,
+        None,
+      )
+      .handle();
+
+      assert_navigation_popped!(app, ActiveSonarrBlock::Series.into());
+      assert_none!(app.data.sonarr_data.prompt_confirm_action);
+      assert_modal_absent!(app.data.sonarr_data.bulk_edit_modal);
+      assert!(app.data.sonarr_data.series.has_multi_selection());
+    }
+
+    #[test]
+    fn test_bulk_edit_series_confirm_prompt_confirmation_submit() {
+      let mut app = app_with_selected_series();
+      app.push_navigation_stack(ActiveSonarrBlock::Series.into());
+      app.push_navigation_stack(ActiveSonarrBlock::BulkEditSeriesPrompt.into());
+      app.data.sonarr_data.prompt_confirm = true;
+      app.data.sonarr_data.selected_block =
+        BlockSelectionState::new(BULK_EDIT_SERIES_SELECTION_BLOCKS);
+      app
+        .data
+        .sonarr_data
+        .selected_block
+        .set_index(0, BULK_EDIT_SERIES_SELECTION_BLOCKS.len() - 1);
+
+      BulkEditSeriesHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveSonarrBlock::BulkEditSeriesPrompt,
+        None,
+      )
+      .handle();
+
+      assert_navigation_popped!(app, ActiveSonarrBlock::Series.into());
+      assert_eq!(
+        app.data.sonarr_data.prompt_confirm_action,
+        Some(SonarrEvent::BulkEditSeries(
+          expected_bulk_edit_series_params()
+        ))
+      );
+      assert_modal_absent!(app.data.sonarr_data.bulk_edit_modal);
+      assert!(!app.data.sonarr_data.series.has_multi_selection());
+      assert!(app.should_refresh);
+    }
+
+    #[test]
+    fn test_bulk_edit_series_confirm_prompt_confirmation_submit_no_op_when_not_ready() {
+      let mut app = app_with_selected_series();
+      app.is_loading = true;
+      app.push_navigation_stack(ActiveSonarrBlock::Series.into());
+      app.push_navigation_stack(ActiveSonarrBlock::BulkEditSeriesPrompt.into());
+      app.data.sonarr_data.prompt_confirm = true;
+      app.data.sonarr_data.selected_block =
+        BlockSelectionState::new(BULK_EDIT_SERIES_SELECTION_BLOCKS);
+      app
+        .data
+        .sonarr_data
+        .selected_block
+        .set_index(0, BULK_EDIT_SERIES_SELECTION_BLOCKS.len() - 1);
+
+      BulkEditSeriesHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveSonarrBlock::BulkEditSeriesPrompt,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveSonarrBlock::BulkEditSeriesPrompt.into()
+      );
+      assert_none!(app.data.sonarr_data.prompt_confirm_action);
+      assert!(!app.should_refresh);
+    }
+
+    #[test]
+    fn test_bulk_edit_series_toggle_move_files_submit() {
+      let mut app = App::test_default();
+      app.data.sonarr_data.bulk_edit_modal = Some(BulkEditModal::new(Vec::new(), Vec::new()));
+      app.push_navigation_stack(ActiveSonarrBlock::BulkEditSeriesPrompt.into());
+      app.data.sonarr_data.selected_block =
+        BlockSelectionState::new(BULK_EDIT_SERIES_SELECTION_BLOCKS);
+      app.data.sonarr_data.selected_block.set_index(0, 3);
+
+      BulkEditSeriesHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveSonarrBlock::BulkEditSeriesPrompt,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveSonarrBlock::BulkEditSeriesPrompt.into()
+      );
+      assert_eq!(
+        app
+          .data
+          .sonarr_data
+          .bulk_edit_modal
+          .as_ref()
+          .unwrap()
+          .move_files,
+        Some(true)
+      );
+
+      BulkEditSeriesHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveSonarrBlock::BulkEditSeriesPrompt,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app
+          .data
+          .sonarr_data
+          .bulk_edit_modal
+          .as_ref()
+          .unwrap()
+          .move_files,
+        Some(false)
+      );
+    }
+
+    #[rstest]
+    #[case(ActiveSonarrBlock::BulkEditSeriesSelectMonitored, 0)]
+    #[case(ActiveSonarrBlock::BulkEditSeriesSelectQualityProfile, 1)]
+    #[case(ActiveSonarrBlock::BulkEditSeriesSelectRootFolder, 2)]
+    #[case(ActiveSonarrBlock::BulkEditSeriesTagsInput, 4)]
+    #[case(ActiveSonarrBlock::BulkEditSeriesSelectApplyTags, 5)]
+    fn test_bulk_edit_series_prompt_selected_block_submit(
+      #[case] selected_block: ActiveSonarrBlock,
+      #[case] y_index: usize,
+    ) {
+      let mut app = App::test_default();
+      app.data.sonarr_data.bulk_edit_modal = Some(BulkEditModal::new(Vec::new(), Vec::new()));
+      app.push_navigation_stack(ActiveSonarrBlock::BulkEditSeriesPrompt.into());
+      app.data.sonarr_data.selected_block =
+        BlockSelectionState::new(BULK_EDIT_SERIES_SELECTION_BLOCKS);
+      app.data.sonarr_data.selected_block.set_index(0, y_index);
+
+      BulkEditSeriesHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveSonarrBlock::BulkEditSeriesPrompt,
+        None,
+      )
+      .handle();
+
+      assert_navigation_pushed!(app, selected_block.into());
+      assert_none!(app.data.sonarr_data.prompt_confirm_action);
+      assert_eq!(
+        app.ignore_special_keys_for_textbox_input,
+        selected_block == ActiveSonarrBlock::BulkEditSeriesTagsInput
+      );
+    }
+
+    #[rstest]
+    fn test_bulk_edit_series_selecting_blocks_submit(
+      #[values(
+        ActiveSonarrBlock::BulkEditSeriesSelectMonitored,
+        ActiveSonarrBlock::BulkEditSeriesSelectQualityProfile,
+        ActiveSonarrBlock::BulkEditSeriesSelectRootFolder,
+        ActiveSonarrBlock::BulkEditSeriesSelectApplyTags,
+        ActiveSonarrBlock::BulkEditSeriesTagsInput
+      )]
+      active_sonarr_block: ActiveSonarrBlock,
+    ) {
+      let mut app = App::test_default();
+      app.ignore_special_keys_for_textbox_input =
+        active_sonarr_block == ActiveSonarrBlock::BulkEditSeriesTagsInput;
+      app.data.sonarr_data.bulk_edit_modal = Some(BulkEditModal::new(Vec::new(), Vec::new()));
+      app.push_navigation_stack(ActiveSonarrBlock::BulkEditSeriesPrompt.into());
+      app.push_navigation_stack(active_sonarr_block.into());
+
+      BulkEditSeriesHandler::new(SUBMIT_KEY, &mut app, active_sonarr_block, None).handle();
+
+      assert_navigation_popped!(app, ActiveSonarrBlock::BulkEditSeriesPrompt.into());
+      assert!(!app.ignore_special_keys_for_textbox_input);
+    }
+  }
+
+  mod test_handle_esc {
+    use super::*;
+
+    const ESC_KEY: Key = DEFAULT_KEYBINDINGS.esc.key;
+
+    #[test]
+    fn test_bulk_edit_series_tags_input_esc() {
+      let mut app = App::test_default();
+      app.ignore_special_keys_for_textbox_input = true;
+      app.data.sonarr_data.bulk_edit_modal = Some(BulkEditModal::new(Vec::new(), Vec::new()));
+      app.push_navigation_stack(ActiveSonarrBlock::BulkEditSeriesPrompt.into());
+      app.push_navigation_stack(ActiveSonarrBlock::BulkEditSeriesTagsInput.into());
+
+      BulkEditSeriesHandler::new(
+        ESC_KEY,
+        &mut app,
+        ActiveSonarrBlock::BulkEditSeriesTagsInput,
+        None,
+      )
+      .handle();
+
+      assert!(!app.ignore_special_keys_for_textbox_input);
+      assert_navigation_popped!(app, ActiveSonarrBlock::BulkEditSeriesPrompt.into());
+    }
+
+    #[test]
+    fn test_bulk_edit_series_prompt_esc() {
+      let mut app = app_with_selected_series();
+      app.data.sonarr_data.prompt_confirm = true;
+      app.push_navigation_stack(ActiveSonarrBlock::Series.into());
+      app.push_navigation_stack(ActiveSonarrBlock::BulkEditSeriesPrompt.into());
+
+      BulkEditSeriesHandler::new(
+        ESC_KEY,
+        &mut app,
+        ActiveSonarrBlock::BulkEditSeriesPrompt,
+        None,
+      )
+      .handle();
+
+      assert_navigation_popped!(app, ActiveSonarrBlock::Series.into());
+      assert_modal_absent!(app.data.sonarr_data.bulk_edit_modal);
+      assert!(!app.data.sonarr_data.prompt_confirm);
+      assert!(app.data.sonarr_data.series.has_multi_selection());
+    }
+
+    #[rstest]
+    fn test_bulk_edit_series_selecting_blocks_esc(
+      #[values(
+        ActiveSonarrBlock::BulkEditSeriesSelectMonitored,
+        ActiveSonarrBlock::BulkEditSeriesSelectQualityProfile,
+        ActiveSonarrBlock::BulkEditSeriesSelectRootFolder,
+        ActiveSonarrBlock::BulkEditSeriesSelectApplyTags
+      )]
+      active_sonarr_block: ActiveSonarrBlock,
+    ) {
+      let mut app = App::test_default();
+      app.data.sonarr_data.bulk_edit_modal = Some(BulkEditModal::new(Vec::new(), Vec::new()));
+      app.push_navigation_stack(ActiveSonarrBlock::BulkEditSeriesPrompt.into());
+      app.push_navigation_stack(active_sonarr_block.into());
+
+      BulkEditSeriesHandler::new(ESC_KEY, &mut app, active_sonarr_block, None).handle();
+
+      assert_navigation_popped!(app, ActiveSonarrBlock::BulkEditSeriesPrompt.into());
+    }
+  }
+
+  mod test_handle_key_char {
+    use pretty_assertions::{assert_eq, assert_str_eq};
+
+    use super::*;
+
+    #[test]
+    fn test_bulk_edit_series_tags_input_char_key() {
+      let mut app = App::test_default();
+      app.data.sonarr_data.bulk_edit_modal = Some(BulkEditModal::new(Vec::new(), Vec::new()));
+
+      BulkEditSeriesHandler::new(
+        Key::Char('a'),
+        &mut app,
+        ActiveSonarrBlock::BulkEditSeriesTagsInput,
+        None,
+      )
+      .handle();
+
+      assert_str_eq!(
+        app
+          .data
+          .sonarr_data
+          .bulk_edit_modal
+          .as_ref()
+          .unwrap()
+          .tags
+          .text,
+        "a"
+      );
+    }
+
+    #[test]
+    fn test_bulk_edit_series_confirm_prompt_confirm() {
+      let mut app = app_with_selected_series();
+      app.push_navigation_stack(ActiveSonarrBlock::Series.into());
+      app.push_navigation_stack(ActiveSonarrBlock::BulkEditSeriesPrompt.into());
+      app.data.sonarr_data.selected_block =
+        BlockSelectionState::new(BULK_EDIT_SERIES_SELECTION_BLOCKS);
+      app
+        .data
+        .sonarr_data
+        .selected_block
+        .set_index(0, BULK_EDIT_SERIES_SELECTION_BLOCKS.len() - 1);
+
+      BulkEditSeriesHandler::new(
+        DEFAULT_KEYBINDINGS.confirm.key,
+        &mut app,
+        ActiveSonarrBlock::BulkEditSeriesPrompt,
+        None,
+      )
+      .handle();
+
+      assert_navigation_popped!(app, ActiveSonarrBlock::Series.into());
+      assert_eq!(
+        app.data.sonarr_data.prompt_confirm_action,
+        Some(SonarrEvent::BulkEditSeries(
+          expected_bulk_edit_series_params()
+        ))
+      );
+      assert_modal_absent!(app.data.sonarr_data.bulk_edit_modal);
+      assert!(app.should_refresh);
+    }
+  }
+
+  #[test]
+  fn test_bulk_edit_series_handler_accepts() {
+    ActiveSonarrBlock::iter().for_each(|active_sonarr_block| {
+      if BULK_EDIT_SERIES_BLOCKS.contains(&active_sonarr_block) {
+        assert!(BulkEditSeriesHandler::accepts(active_sonarr_block));
+      } else {
+        assert!(!BulkEditSeriesHandler::accepts(active_sonarr_block));
+      }
+    });
+  }
+
+  #[rstest]
+  fn test_bulk_edit_series_handler_ignore_special_keys(
+    #[values(true, false)] ignore_special_keys_for_textbox_input: bool,
+  ) {
+    let mut app = App::test_default();
+    app.ignore_special_keys_for_textbox_input = ignore_special_keys_for_textbox_input;
+    let handler = BulkEditSeriesHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveSonarrBlock::default(),
+      None,
+    );
+
+    assert_eq!(
+      handler.ignore_special_keys(),
+      ignore_special_keys_for_textbox_input
+    );
+  }
+
+  #[test]
+  fn test_build_bulk_edit_series_params() {
+    let mut app = app_with_selected_series();
+
+    let bulk_edit_series_params = BulkEditSeriesHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveSonarrBlock::BulkEditSeriesPrompt,
+      None,
+    )
+    .build_bulk_edit_series_params();
+
+    assert_eq!(bulk_edit_series_params, expected_bulk_edit_series_params());
+    assert_modal_absent!(app.data.sonarr_data.bulk_edit_modal);
+    assert!(!app.data.sonarr_data.series.has_multi_selection());
+  }
+
+  #[test]
+  fn test_build_bulk_edit_series_params_defaults_to_no_change() {
+    let mut app = app_with_selected_series();
+    app.data.sonarr_data.bulk_edit_modal = Some(BulkEditModal::new(
+      vec!["Any".to_owned()],
+      vec!["/nfs/series".to_owned()],
+    ));
+
+    let bulk_edit_series_params = BulkEditSeriesHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveSonarrBlock::BulkEditSeriesPrompt,
+      None,
+    )
+    .build_bulk_edit_series_params();
+
+    assert_eq!(
+      bulk_edit_series_params,
+      BulkEditSeriesParams {
+        series_ids: vec![1, 3],
+        ..BulkEditSeriesParams::default()
+      }
+    );
+  }
+
+  #[test]
+  fn test_bulk_edit_series_handler_is_not_ready_when_loading() {
+    let mut app = App::test_default();
+    app.is_loading = true;
+    app.data.sonarr_data.bulk_edit_modal = Some(BulkEditModal::new(Vec::new(), Vec::new()));
+
+    let handler = BulkEditSeriesHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveSonarrBlock::BulkEditSeriesPrompt,
+      None,
+    );
+
+    assert!(!handler.is_ready());
+  }
+
+  #[test]
+  fn test_bulk_edit_series_handler_is_not_ready_when_bulk_edit_modal_is_none() {
+    let mut app = App::test_default();
+
+    let handler = BulkEditSeriesHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveSonarrBlock::BulkEditSeriesPrompt,
+      None,
+    );
+
+    assert!(!handler.is_ready());
+  }
+
+  #[test]
+  fn test_bulk_edit_series_handler_is_ready_when_bulk_edit_modal_is_some() {
+    let mut app = App::test_default();
+    app.data.sonarr_data.bulk_edit_modal = Some(BulkEditModal::new(Vec::new(), Vec::new()));
+
+    let handler = BulkEditSeriesHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveSonarrBlock::BulkEditSeriesPrompt,
+      None,
+    );
+
+    assert!(handler.is_ready());
+  }
+}
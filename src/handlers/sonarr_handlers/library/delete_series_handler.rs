@@ -1,5 +1,5 @@
 use crate::models::Route;
-use crate::models::sonarr_models::DeleteSeriesParams;
+use crate::models::sonarr_models::{BulkDeleteSeriesParams, DeleteSeriesParams};
 use crate::network::sonarr_network::SonarrEvent;
 use crate::{
   app::App,
@@ -21,16 +21,34 @@ pub(super) struct DeleteSeriesHandler<'a, 'b> {
 }
 
 impl DeleteSeriesHandler<'_, '_> {
-  fn build_delete_series_params(&mut self) -> DeleteSeriesParams {
-    let id = self.app.data.sonarr_data.series.current_selection().id;
+  fn build_delete_series_event(&mut self) -> SonarrEvent {
     let delete_series_files = self.app.data.sonarr_data.delete_series_files;
     let add_list_exclusion = self.app.data.sonarr_data.add_list_exclusion;
     self.app.data.sonarr_data.reset_delete_series_preferences();
 
-    DeleteSeriesParams {
-      id,
-      delete_series_files,
-      add_list_exclusion,
+    if self.app.data.sonarr_data.series.has_multi_selection() {
+      let series_ids = self
+        .app
+        .data
+        .sonarr_data
+        .series
+        .multi_selection()
+        .iter()
+        .map(|series| series.id)
+        .collect();
+      self.app.data.sonarr_data.series.clear_multi_selection();
+
+      SonarrEvent::BulkDeleteSeries(BulkDeleteSeriesParams {
+        series_ids,
+        delete_files: delete_series_files,
+        add_import_list_exclusion: add_list_exclusion,
+      })
+    } else {
+      SonarrEvent::DeleteSeries(DeleteSeriesParams {
+        id: self.app.data.sonarr_data.series.current_selection().id,
+        delete_series_files,
+        add_list_exclusion,
+      })
     }
   }
 }
@@ -96,7 +114,7 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for DeleteSeriesHandler<
         ActiveSonarrBlock::DeleteSeriesConfirmPrompt => {
           if self.app.data.sonarr_data.prompt_confirm {
             self.app.data.sonarr_data.prompt_confirm_action =
-              Some(SonarrEvent::DeleteSeries(self.build_delete_series_params()));
+              Some(self.build_delete_series_event());
             self.app.should_refresh = true;
           } else {
             self.app.data.sonarr_data.reset_delete_series_preferences();
@@ -132,8 +150,7 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for DeleteSeriesHandler<
       && matches_key!(confirm, self.key)
     {
       self.app.data.sonarr_data.prompt_confirm = true;
-      self.app.data.sonarr_data.prompt_confirm_action =
-        Some(SonarrEvent::DeleteSeries(self.build_delete_series_params()));
+      self.app.data.sonarr_data.prompt_confirm_action = Some(self.build_delete_series_event());
       self.app.should_refresh = true;
 
       self.app.pop_navigation_stack();
@@ -11,7 +11,8 @@ mod tests {
   use crate::handlers::sonarr_handlers::library::delete_series_handler::DeleteSeriesHandler;
   use crate::handlers::sonarr_handlers::sonarr_handler_test_utils::utils::series;
   use crate::models::servarr_data::sonarr::sonarr_data::{ActiveSonarrBlock, DELETE_SERIES_BLOCKS};
-  use crate::models::sonarr_models::DeleteSeriesParams;
+  use crate::models::sonarr_models::{BulkDeleteSeriesParams, DeleteSeriesParams, Series};
+  use crate::network::sonarr_network::SonarrEvent;
 
   mod test_handle_scroll_up_and_down {
     use pretty_assertions::assert_eq;
@@ -343,7 +344,7 @@ mod tests {
   }
 
   #[test]
-  fn test_build_delete_series_params() {
+  fn test_build_delete_series_event() {
     let mut app = App::test_default();
     app.data.sonarr_data.series.set_items(vec![series()]);
     app.data.sonarr_data.delete_series_files = true;
@@ -354,15 +355,56 @@ mod tests {
       add_list_exclusion: true,
     };
 
-    let delete_series_params = DeleteSeriesHandler::new(
+    let delete_series_event = DeleteSeriesHandler::new(
       DEFAULT_KEYBINDINGS.esc.key,
       &mut app,
       ActiveSonarrBlock::DeleteSeriesPrompt,
       None,
     )
-    .build_delete_series_params();
+    .build_delete_series_event();
 
-    assert_eq!(delete_series_params, expected_delete_series_params);
+    assert_eq!(
+      delete_series_event,
+      SonarrEvent::DeleteSeries(expected_delete_series_params)
+    );
+    assert!(!app.data.sonarr_data.delete_series_files);
+    assert!(!app.data.sonarr_data.add_list_exclusion);
+  }
+
+  #[test]
+  fn test_build_delete_series_event_with_multi_selection() {
+    let mut app = App::test_default();
+    app
+      .data
+      .sonarr_data
+      .series
+      .set_items(vec![series(), Series { id: 2, ..series() }]);
+    app
+      .data
+      .sonarr_data
+      .series
+      .toggle_select_all(|series| series.id);
+    app.data.sonarr_data.delete_series_files = true;
+    app.data.sonarr_data.add_list_exclusion = true;
+    let expected_bulk_delete_series_params = BulkDeleteSeriesParams {
+      series_ids: vec![1, 2],
+      delete_files: true,
+      add_import_list_exclusion: true,
+    };
+
+    let delete_series_event = DeleteSeriesHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveSonarrBlock::DeleteSeriesPrompt,
+      None,
+    )
+    .build_delete_series_event();
+
+    assert_eq!(
+      delete_series_event,
+      SonarrEvent::BulkDeleteSeries(expected_bulk_delete_series_params)
+    );
+    assert!(!app.data.sonarr_data.series.has_multi_selection());
     assert!(!app.data.sonarr_data.delete_series_files);
     assert!(!app.data.sonarr_data.add_list_exclusion);
   }
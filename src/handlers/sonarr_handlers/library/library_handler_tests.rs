@@ -15,8 +15,9 @@ mod tests {
   use crate::handlers::sonarr_handlers::library::{LibraryHandler, series_sorting_options};
   use crate::handlers::sonarr_handlers::sonarr_handler_test_utils::utils::series;
   use crate::models::servarr_data::sonarr::sonarr_data::{
-    ADD_SERIES_BLOCKS, ActiveSonarrBlock, DELETE_SERIES_BLOCKS, EDIT_SERIES_BLOCKS,
-    EPISODE_DETAILS_BLOCKS, LIBRARY_BLOCKS, SEASON_DETAILS_BLOCKS, SERIES_DETAILS_BLOCKS,
+    ADD_SERIES_BLOCKS, ActiveSonarrBlock, BULK_EDIT_SERIES_BLOCKS, DELETE_SERIES_BLOCKS,
+    EDIT_SERIES_BLOCKS, EPISODE_DETAILS_BLOCKS, LIBRARY_BLOCKS, SEASON_DETAILS_BLOCKS,
+    SERIES_DETAILS_BLOCKS,
   };
   use crate::models::sonarr_models::{Series, SeriesStatistics, SeriesStatus, SeriesType};
   use crate::test_handler_delegation;
@@ -92,9 +93,9 @@ mod tests {
 
       assert_eq!(
         app.data.sonarr_data.main_tabs.get_active_route(),
-        ActiveSonarrBlock::System.into()
+        ActiveSonarrBlock::WantedMissing.into()
       );
-      assert_navigation_pushed!(app, ActiveSonarrBlock::System.into());
+      assert_navigation_pushed!(app, ActiveSonarrBlock::WantedMissing.into());
     }
 
     #[rstest]
@@ -352,6 +353,39 @@ mod tests {
       );
     }
 
+    #[test]
+    fn test_series_edit_key_with_multi_selection_opens_bulk_edit_prompt() {
+      let mut app = App::test_default();
+      app.data.sonarr_data = create_test_sonarr_data();
+      app.push_navigation_stack(ActiveSonarrBlock::Series.into());
+      app
+        .data
+        .sonarr_data
+        .series
+        .set_items(vec![series(), Series { id: 2, ..series() }]);
+      app
+        .data
+        .sonarr_data
+        .series
+        .toggle_select_all(|series| series.id);
+
+      LibraryHandler::new(
+        DEFAULT_KEYBINDINGS.edit.key,
+        &mut app,
+        ActiveSonarrBlock::Series,
+        None,
+      )
+      .handle();
+
+      assert_navigation_pushed!(app, ActiveSonarrBlock::BulkEditSeriesPrompt.into());
+      assert_modal_present!(app.data.sonarr_data.bulk_edit_modal);
+      assert_modal_absent!(app.data.sonarr_data.edit_series_modal);
+      assert_eq!(
+        app.data.sonarr_data.selected_block.get_active_block(),
+        ActiveSonarrBlock::BulkEditSeriesSelectMonitored
+      );
+    }
+
     #[test]
     fn test_series_edit_key_no_op_when_not_ready() {
       let mut app = App::test_default();
@@ -650,6 +684,25 @@ mod tests {
     );
   }
 
+  #[rstest]
+  fn test_delegates_bulk_edit_series_blocks_to_bulk_edit_series_handler(
+    #[values(
+      ActiveSonarrBlock::BulkEditSeriesPrompt,
+      ActiveSonarrBlock::BulkEditSeriesSelectApplyTags,
+      ActiveSonarrBlock::BulkEditSeriesSelectMonitored,
+      ActiveSonarrBlock::BulkEditSeriesSelectQualityProfile,
+      ActiveSonarrBlock::BulkEditSeriesSelectRootFolder,
+      ActiveSonarrBlock::BulkEditSeriesTagsInput
+    )]
+    active_sonarr_block: ActiveSonarrBlock,
+  ) {
+    test_handler_delegation!(
+      LibraryHandler,
+      ActiveSonarrBlock::Series,
+      active_sonarr_block
+    );
+  }
+
   #[test]
   fn test_delegates_delete_series_blocks_to_delete_series_handler() {
     test_handler_delegation!(
@@ -888,6 +941,7 @@ mod tests {
     let mut library_handler_blocks = Vec::new();
     library_handler_blocks.extend(LIBRARY_BLOCKS);
     library_handler_blocks.extend(ADD_SERIES_BLOCKS);
+    library_handler_blocks.extend(BULK_EDIT_SERIES_BLOCKS);
     library_handler_blocks.extend(DELETE_SERIES_BLOCKS);
     library_handler_blocks.extend(EDIT_SERIES_BLOCKS);
     library_handler_blocks.extend(SERIES_DETAILS_BLOCKS);
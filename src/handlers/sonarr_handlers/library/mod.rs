@@ -1,4 +1,5 @@
 use add_series_handler::AddSeriesHandler;
+use bulk_edit_series_handler::BulkEditSeriesHandler;
 mod edit_series_handler;
 use delete_series_handler::DeleteSeriesHandler;
 use edit_series_handler::EditSeriesHandler;
@@ -10,9 +11,10 @@ use crate::{
   matches_key,
   models::{
     BlockSelectionState, HorizontallyScrollableText,
+    servarr_data::modals::BulkEditModal,
     servarr_data::sonarr::sonarr_data::{
-      ActiveSonarrBlock, DELETE_SERIES_SELECTION_BLOCKS, EDIT_SERIES_SELECTION_BLOCKS,
-      LIBRARY_BLOCKS,
+      ActiveSonarrBlock, BULK_EDIT_SERIES_SELECTION_BLOCKS, DELETE_SERIES_SELECTION_BLOCKS,
+      EDIT_SERIES_SELECTION_BLOCKS, LIBRARY_BLOCKS,
     },
     sonarr_models::Series,
     stateful_table::SortOption,
@@ -28,6 +30,7 @@ use crate::handlers::table_handler::{TableHandlingConfig, handle_table};
 use crate::models::Route;
 
 mod add_series_handler;
+mod bulk_edit_series_handler;
 mod delete_series_handler;
 
 mod episode_details_handler;
@@ -60,7 +63,8 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for LibraryHandler<'a, '
       .search_field_fn(|series| &series.title.text)
       .filtering_block(ActiveSonarrBlock::FilterSeries.into())
       .filter_error_block(ActiveSonarrBlock::FilterSeriesError.into())
-      .filter_field_fn(|series| &series.title.text);
+      .filter_field_fn(|series| &series.title.text)
+      .multi_select_id_fn(|series| series.id);
 
     if !handle_table(
       self,
@@ -72,6 +76,10 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for LibraryHandler<'a, '
           AddSeriesHandler::new(self.key, self.app, self.active_sonarr_block, self.context)
             .handle();
         }
+        _ if BulkEditSeriesHandler::accepts(self.active_sonarr_block) => {
+          BulkEditSeriesHandler::new(self.key, self.app, self.active_sonarr_block, self.context)
+            .handle();
+        }
         _ if DeleteSeriesHandler::accepts(self.active_sonarr_block) => {
           DeleteSeriesHandler::new(self.key, self.app, self.active_sonarr_block, self.context)
             .handle();
@@ -99,6 +107,7 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for LibraryHandler<'a, '
 
   fn accepts(active_block: ActiveSonarrBlock) -> bool {
     AddSeriesHandler::accepts(active_block)
+      || BulkEditSeriesHandler::accepts(active_block)
       || DeleteSeriesHandler::accepts(active_block)
       || EditSeriesHandler::accepts(active_block)
       || SeriesDetailsHandler::accepts(active_block)
@@ -191,6 +200,15 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for LibraryHandler<'a, '
     let key = self.key;
     match self.active_sonarr_block {
       ActiveSonarrBlock::Series => match self.key {
+        _ if matches_key!(edit, key) && self.app.data.sonarr_data.series.has_multi_selection() => {
+          self
+            .app
+            .push_navigation_stack(ActiveSonarrBlock::BulkEditSeriesPrompt.into());
+          self.app.data.sonarr_data.bulk_edit_modal =
+            Some(BulkEditModal::from(&self.app.data.sonarr_data));
+          self.app.data.sonarr_data.selected_block =
+            BlockSelectionState::new(BULK_EDIT_SERIES_SELECTION_BLOCKS);
+        }
         _ if matches_key!(edit, key) => {
           self.app.push_navigation_stack(
             (
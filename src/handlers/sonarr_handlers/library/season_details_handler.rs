@@ -540,5 +540,9 @@ pub(in crate::handlers::sonarr_handlers::library) fn releases_sorting_options()
       name: "Quality",
       cmp_fn: Some(|a, b| a.quality.cmp(&b.quality)),
     },
+    SortOption {
+      name: "CF Score",
+      cmp_fn: Some(|a, b| a.custom_format_score.cmp(&b.custom_format_score)),
+    },
   ]
 }
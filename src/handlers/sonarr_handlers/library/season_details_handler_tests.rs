@@ -1137,6 +1137,21 @@ mod tests {
     assert_str_eq!(sort_option.name, "Quality");
   }
 
+  #[test]
+  fn test_releases_sorting_options_custom_format_score() {
+    let expected_cmp_fn: fn(&SonarrRelease, &SonarrRelease) -> Ordering =
+      |a, b| a.custom_format_score.cmp(&b.custom_format_score);
+    let mut expected_releases_vec = release_vec();
+    expected_releases_vec.sort_by(expected_cmp_fn);
+
+    let sort_option = releases_sorting_options()[9].clone();
+    let mut sorted_releases_vec = release_vec();
+    sorted_releases_vec.sort_by(sort_option.cmp_fn.unwrap());
+
+    assert_eq!(sorted_releases_vec, expected_releases_vec);
+    assert_str_eq!(sort_option.name, "CF Score");
+  }
+
   fn release_vec() -> Vec<SonarrRelease> {
     let release_a = SonarrRelease {
       protocol: "Protocol A".to_owned(),
@@ -1155,6 +1170,7 @@ mod tests {
           name: "Quality A".to_owned(),
         },
       },
+      custom_format_score: 10,
       ..SonarrRelease::default()
     };
     let release_b = SonarrRelease {
@@ -1174,6 +1190,7 @@ mod tests {
           name: "Quality B".to_owned(),
         },
       },
+      custom_format_score: -5,
       ..SonarrRelease::default()
     };
     let release_c = SonarrRelease {
@@ -1190,6 +1207,7 @@ mod tests {
           name: "Quality C".to_owned(),
         },
       },
+      custom_format_score: 0,
       ..SonarrRelease::default()
     };
 
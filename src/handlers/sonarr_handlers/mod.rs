@@ -1,10 +1,15 @@
 use blocklist::BlocklistHandler;
+use calendar::CalendarHandler;
+use custom_formats::CustomFormatsHandler;
+use download_clients::DownloadClientsHandler;
 use downloads::DownloadsHandler;
 use history::HistoryHandler;
 use indexers::IndexersHandler;
 use library::LibraryHandler;
+use quality_profiles::QualityProfilesHandler;
 use root_folders::RootFoldersHandler;
 use system::SystemHandler;
+use wanted::WantedHandler;
 
 use super::KeyEventHandler;
 use crate::models::Route;
@@ -13,12 +18,17 @@ use crate::{
 };
 
 mod blocklist;
+mod calendar;
+mod custom_formats;
+mod download_clients;
 mod downloads;
 mod history;
 mod indexers;
 mod library;
+mod quality_profiles;
 mod root_folders;
 mod system;
+mod wanted;
 
 #[cfg(test)]
 #[path = "sonarr_handler_tests.rs"]
@@ -56,9 +66,27 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for SonarrHandler<'a, 'b
       _ if IndexersHandler::accepts(self.active_sonarr_block) => {
         IndexersHandler::new(self.key, self.app, self.active_sonarr_block, self.context).handle()
       }
+      _ if DownloadClientsHandler::accepts(self.active_sonarr_block) => {
+        DownloadClientsHandler::new(self.key, self.app, self.active_sonarr_block, self.context)
+          .handle()
+      }
+      _ if QualityProfilesHandler::accepts(self.active_sonarr_block) => {
+        QualityProfilesHandler::new(self.key, self.app, self.active_sonarr_block, self.context)
+          .handle()
+      }
+      _ if CustomFormatsHandler::accepts(self.active_sonarr_block) => {
+        CustomFormatsHandler::new(self.key, self.app, self.active_sonarr_block, self.context)
+          .handle()
+      }
       _ if SystemHandler::accepts(self.active_sonarr_block) => {
         SystemHandler::new(self.key, self.app, self.active_sonarr_block, self.context).handle()
       }
+      _ if CalendarHandler::accepts(self.active_sonarr_block) => {
+        CalendarHandler::new(self.key, self.app, self.active_sonarr_block, self.context).handle()
+      }
+      _ if WantedHandler::accepts(self.active_sonarr_block) => {
+        WantedHandler::new(self.key, self.app, self.active_sonarr_block, self.context).handle()
+      }
       _ => self.handle_key_event(),
     }
   }
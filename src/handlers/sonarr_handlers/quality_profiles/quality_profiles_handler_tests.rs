@@ -0,0 +1,531 @@
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+  use rstest::rstest;
+  use strum::IntoEnumIterator;
+
+  use crate::app::App;
+  use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+  use crate::assert_navigation_pushed;
+  use crate::event::Key;
+  use crate::handlers::KeyEventHandler;
+  use crate::handlers::sonarr_handlers::quality_profiles::QualityProfilesHandler;
+  use crate::models::servarr_data::modals::QualityProfileModal;
+  use crate::models::servarr_data::sonarr::sonarr_data::{
+    ActiveSonarrBlock, QUALITY_PROFILES_BLOCKS,
+  };
+  use crate::models::servarr_models::QualityProfile;
+  use crate::network::servarr_test_utils::quality_profile_details;
+
+  fn edit_quality_profile_app() -> App<'static> {
+    let mut app = App::test_default();
+    app
+      .data
+      .sonarr_data
+      .quality_profiles
+      .set_items(vec![quality_profile_details()]);
+    app.data.sonarr_data.quality_profile_modal =
+      Some(QualityProfileModal::from(&quality_profile_details()));
+    app.push_navigation_stack(ActiveSonarrBlock::QualityProfiles.into());
+    app.push_navigation_stack(ActiveSonarrBlock::EditQualityProfile.into());
+
+    app
+  }
+
+  fn selected_item_name(app: &App<'_>) -> String {
+    app
+      .data
+      .sonarr_data
+      .quality_profile_modal
+      .as_ref()
+      .unwrap()
+      .items
+      .current_selection()
+      .display_name()
+  }
+
+  mod test_handle_scroll_up_and_down {
+    use pretty_assertions::assert_str_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_edit_quality_profile_scroll(
+      #[values(DEFAULT_KEYBINDINGS.up.key, DEFAULT_KEYBINDINGS.down.key)] key: Key,
+    ) {
+      let mut app = edit_quality_profile_app();
+
+      QualityProfilesHandler::new(key, &mut app, ActiveSonarrBlock::EditQualityProfile, None)
+        .handle();
+
+      if key == DEFAULT_KEYBINDINGS.up.key {
+        assert_str_eq!(selected_item_name(&app), "SDTV");
+      } else {
+        assert_str_eq!(selected_item_name(&app), "WEB 720p");
+      }
+    }
+  }
+
+  mod test_handle_home_end {
+    use pretty_assertions::assert_str_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_edit_quality_profile_home_end() {
+      let mut app = edit_quality_profile_app();
+
+      QualityProfilesHandler::new(
+        DEFAULT_KEYBINDINGS.end.key,
+        &mut app,
+        ActiveSonarrBlock::EditQualityProfile,
+        None,
+      )
+      .handle();
+
+      assert_str_eq!(selected_item_name(&app), "SDTV");
+
+      QualityProfilesHandler::new(
+        DEFAULT_KEYBINDINGS.home.key,
+        &mut app,
+        ActiveSonarrBlock::EditQualityProfile,
+        None,
+      )
+      .handle();
+
+      assert_str_eq!(selected_item_name(&app), "Bluray-1080p");
+    }
+  }
+
+  mod test_handle_left_right_action {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_quality_profiles_tab_left(#[values(true, false)] is_ready: bool) {
+      let mut app = App::test_default();
+      app.is_loading = is_ready;
+      app.data.sonarr_data.main_tabs.set_index(7);
+
+      QualityProfilesHandler::new(
+        DEFAULT_KEYBINDINGS.left.key,
+        &mut app,
+        ActiveSonarrBlock::QualityProfiles,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.data.sonarr_data.main_tabs.get_active_route(),
+        ActiveSonarrBlock::DownloadClients.into()
+      );
+      assert_navigation_pushed!(app, ActiveSonarrBlock::DownloadClients.into());
+    }
+
+    #[rstest]
+    fn test_quality_profiles_tab_right(#[values(true, false)] is_ready: bool) {
+      let mut app = App::test_default();
+      app.is_loading = is_ready;
+      app.data.sonarr_data.main_tabs.set_index(7);
+
+      QualityProfilesHandler::new(
+        DEFAULT_KEYBINDINGS.right.key,
+        &mut app,
+        ActiveSonarrBlock::QualityProfiles,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.data.sonarr_data.main_tabs.get_active_route(),
+        ActiveSonarrBlock::CustomFormats.into()
+      );
+      assert_navigation_pushed!(app, ActiveSonarrBlock::CustomFormats.into());
+    }
+
+    #[rstest]
+    fn test_edit_quality_profile_left_right_no_op(
+      #[values(DEFAULT_KEYBINDINGS.left.key, DEFAULT_KEYBINDINGS.right.key)] key: Key,
+    ) {
+      let mut app = edit_quality_profile_app();
+      app.data.sonarr_data.main_tabs.set_index(7);
+
+      QualityProfilesHandler::new(key, &mut app, ActiveSonarrBlock::EditQualityProfile, None)
+        .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveSonarrBlock::EditQualityProfile.into()
+      );
+      assert_eq!(
+        app.data.sonarr_data.main_tabs.get_active_route(),
+        ActiveSonarrBlock::QualityProfiles.into()
+      );
+    }
+  }
+
+  mod test_handle_submit {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    const SUBMIT_KEY: Key = DEFAULT_KEYBINDINGS.submit.key;
+
+    #[test]
+    fn test_edit_quality_profile_submit() {
+      let mut app = App::test_default();
+      app
+        .data
+        .sonarr_data
+        .quality_profiles
+        .set_items(vec![quality_profile_details()]);
+      app.push_navigation_stack(ActiveSonarrBlock::QualityProfiles.into());
+
+      QualityProfilesHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveSonarrBlock::QualityProfiles,
+        None,
+      )
+      .handle();
+
+      assert_navigation_pushed!(app, ActiveSonarrBlock::EditQualityProfile.into());
+      let quality_profile_modal = app.data.sonarr_data.quality_profile_modal.as_ref().unwrap();
+      let expected_quality_profile_modal = QualityProfileModal::from(&quality_profile_details());
+      assert_eq!(quality_profile_modal.id, 1);
+      assert_eq!(quality_profile_modal.cutoff, 1000);
+      assert_eq!(
+        quality_profile_modal.items.items,
+        expected_quality_profile_modal.items.items
+      );
+    }
+
+    #[test]
+    fn test_edit_quality_profile_submit_no_op_when_not_ready() {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.push_navigation_stack(ActiveSonarrBlock::QualityProfiles.into());
+      app
+        .data
+        .sonarr_data
+        .quality_profiles
+        .set_items(vec![QualityProfile::default()]);
+
+      QualityProfilesHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveSonarrBlock::QualityProfiles,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveSonarrBlock::QualityProfiles.into()
+      );
+      assert_none!(app.data.sonarr_data.quality_profile_modal);
+    }
+  }
+
+  mod test_handle_esc {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::assert_navigation_popped;
+
+    const ESC_KEY: Key = DEFAULT_KEYBINDINGS.esc.key;
+
+    #[test]
+    fn test_edit_quality_profile_esc() {
+      let mut app = edit_quality_profile_app();
+      app.data.sonarr_data.prompt_confirm = true;
+
+      QualityProfilesHandler::new(
+        ESC_KEY,
+        &mut app,
+        ActiveSonarrBlock::EditQualityProfile,
+        None,
+      )
+      .handle();
+
+      assert_navigation_popped!(app, ActiveSonarrBlock::QualityProfiles.into());
+      assert!(!app.data.sonarr_data.prompt_confirm);
+      assert_none!(app.data.sonarr_data.quality_profile_modal);
+    }
+
+    #[rstest]
+    fn test_default_esc(#[values(true, false)] is_ready: bool) {
+      let mut app = App::test_default();
+      app.is_loading = is_ready;
+      app.error = "test error".to_owned().into();
+      app.push_navigation_stack(ActiveSonarrBlock::QualityProfiles.into());
+      app.push_navigation_stack(ActiveSonarrBlock::QualityProfiles.into());
+
+      QualityProfilesHandler::new(ESC_KEY, &mut app, ActiveSonarrBlock::QualityProfiles, None)
+        .handle();
+
+      assert_navigation_popped!(app, ActiveSonarrBlock::QualityProfiles.into());
+      assert_is_empty!(app.error.text);
+    }
+  }
+
+  mod test_handle_key_char {
+    use pretty_assertions::{assert_eq, assert_str_eq};
+
+    use super::*;
+    use crate::assert_navigation_popped;
+    use crate::models::servarr_models::EditQualityProfileParams;
+    use crate::network::sonarr_network::SonarrEvent;
+
+    #[test]
+    fn test_refresh_quality_profiles_key() {
+      let mut app = App::test_default();
+      app
+        .data
+        .sonarr_data
+        .quality_profiles
+        .set_items(vec![QualityProfile::default()]);
+      app.push_navigation_stack(ActiveSonarrBlock::QualityProfiles.into());
+
+      QualityProfilesHandler::new(
+        DEFAULT_KEYBINDINGS.refresh.key,
+        &mut app,
+        ActiveSonarrBlock::QualityProfiles,
+        None,
+      )
+      .handle();
+
+      assert_navigation_pushed!(app, ActiveSonarrBlock::QualityProfiles.into());
+      assert!(app.should_refresh);
+    }
+
+    #[test]
+    fn test_refresh_quality_profiles_key_no_op_when_not_ready() {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app
+        .data
+        .sonarr_data
+        .quality_profiles
+        .set_items(vec![QualityProfile::default()]);
+      app.push_navigation_stack(ActiveSonarrBlock::QualityProfiles.into());
+
+      QualityProfilesHandler::new(
+        DEFAULT_KEYBINDINGS.refresh.key,
+        &mut app,
+        ActiveSonarrBlock::QualityProfiles,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveSonarrBlock::QualityProfiles.into()
+      );
+      assert!(!app.should_refresh);
+    }
+
+    #[test]
+    fn test_edit_quality_profile_toggle_allowed_key() {
+      let mut app = edit_quality_profile_app();
+
+      QualityProfilesHandler::new(
+        DEFAULT_KEYBINDINGS.select.key,
+        &mut app,
+        ActiveSonarrBlock::EditQualityProfile,
+        None,
+      )
+      .handle();
+
+      let quality_profile_modal = app.data.sonarr_data.quality_profile_modal.as_ref().unwrap();
+      assert!(!quality_profile_modal.items.current_selection().allowed);
+    }
+
+    #[test]
+    fn test_edit_quality_profile_move_down_and_up_keys() {
+      let mut app = edit_quality_profile_app();
+
+      QualityProfilesHandler::new(
+        DEFAULT_KEYBINDINGS.move_down.key,
+        &mut app,
+        ActiveSonarrBlock::EditQualityProfile,
+        None,
+      )
+      .handle();
+
+      let quality_profile_modal = app.data.sonarr_data.quality_profile_modal.as_ref().unwrap();
+      assert_str_eq!(
+        quality_profile_modal.items.items[0].display_name(),
+        "WEB 720p"
+      );
+      assert_str_eq!(selected_item_name(&app), "Bluray-1080p");
+
+      QualityProfilesHandler::new(
+        DEFAULT_KEYBINDINGS.move_up.key,
+        &mut app,
+        ActiveSonarrBlock::EditQualityProfile,
+        None,
+      )
+      .handle();
+
+      let quality_profile_modal = app.data.sonarr_data.quality_profile_modal.as_ref().unwrap();
+      assert_eq!(
+        quality_profile_modal.items.items,
+        QualityProfileModal::from(&quality_profile_details())
+          .items
+          .items
+      );
+    }
+
+    #[test]
+    fn test_edit_quality_profile_group_key() {
+      let mut app = edit_quality_profile_app();
+
+      QualityProfilesHandler::new(
+        DEFAULT_KEYBINDINGS.group.key,
+        &mut app,
+        ActiveSonarrBlock::EditQualityProfile,
+        None,
+      )
+      .handle();
+
+      let quality_profile_modal = app.data.sonarr_data.quality_profile_modal.as_ref().unwrap();
+      assert_eq!(quality_profile_modal.items.items.len(), 2);
+      assert_eq!(
+        quality_profile_modal.items.current_selection().items.len(),
+        3
+      );
+    }
+
+    #[test]
+    fn test_edit_quality_profile_cutoff_key() {
+      let mut app = edit_quality_profile_app();
+
+      QualityProfilesHandler::new(
+        DEFAULT_KEYBINDINGS.cutoff.key,
+        &mut app,
+        ActiveSonarrBlock::EditQualityProfile,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app
+          .data
+          .sonarr_data
+          .quality_profile_modal
+          .as_ref()
+          .unwrap()
+          .cutoff,
+        7
+      );
+    }
+
+    #[test]
+    fn test_edit_quality_profile_confirm_key() {
+      let mut app = edit_quality_profile_app();
+      let expected_edit_quality_profile_params = EditQualityProfileParams::from(
+        app.data.sonarr_data.quality_profile_modal.as_ref().unwrap(),
+      );
+
+      QualityProfilesHandler::new(
+        DEFAULT_KEYBINDINGS.confirm.key,
+        &mut app,
+        ActiveSonarrBlock::EditQualityProfile,
+        None,
+      )
+      .handle();
+
+      assert!(app.data.sonarr_data.prompt_confirm);
+      assert_some_eq_x!(
+        &app.data.sonarr_data.prompt_confirm_action,
+        &SonarrEvent::EditQualityProfile(expected_edit_quality_profile_params.clone())
+      );
+      assert!(app.should_refresh);
+      assert_navigation_popped!(app, ActiveSonarrBlock::QualityProfiles.into());
+    }
+  }
+
+  #[test]
+  fn test_quality_profiles_handler_accepts() {
+    ActiveSonarrBlock::iter().for_each(|active_sonarr_block| {
+      if QUALITY_PROFILES_BLOCKS.contains(&active_sonarr_block) {
+        assert!(QualityProfilesHandler::accepts(active_sonarr_block));
+      } else {
+        assert!(!QualityProfilesHandler::accepts(active_sonarr_block));
+      }
+    })
+  }
+
+  #[rstest]
+  fn test_quality_profiles_handler_ignore_special_keys(
+    #[values(true, false)] ignore_special_keys_for_textbox_input: bool,
+  ) {
+    let mut app = App::test_default();
+    app.ignore_special_keys_for_textbox_input = ignore_special_keys_for_textbox_input;
+    let handler = QualityProfilesHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveSonarrBlock::default(),
+      None,
+    );
+
+    assert_eq!(
+      handler.ignore_special_keys(),
+      ignore_special_keys_for_textbox_input
+    );
+  }
+
+  #[test]
+  fn test_quality_profiles_handler_not_ready_when_loading() {
+    let mut app = App::test_default();
+    app.is_loading = true;
+
+    let handler = QualityProfilesHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveSonarrBlock::QualityProfiles,
+      None,
+    );
+
+    assert!(!handler.is_ready());
+  }
+
+  #[test]
+  fn test_quality_profiles_handler_not_ready_when_quality_profiles_is_empty() {
+    let mut app = App::test_default();
+    app.is_loading = false;
+
+    let handler = QualityProfilesHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveSonarrBlock::QualityProfiles,
+      None,
+    );
+
+    assert!(!handler.is_ready());
+  }
+
+  #[test]
+  fn test_quality_profiles_handler_ready_when_not_loading_and_quality_profiles_is_not_empty() {
+    let mut app = App::test_default();
+    app.is_loading = false;
+    app
+      .data
+      .sonarr_data
+      .quality_profiles
+      .set_items(vec![QualityProfile::default()]);
+
+    let handler = QualityProfilesHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveSonarrBlock::QualityProfiles,
+      None,
+    );
+
+    assert!(handler.is_ready());
+  }
+}
@@ -0,0 +1,193 @@
+use crate::app::App;
+use crate::event::Key;
+use crate::handlers::KeyEventHandler;
+use crate::models::servarr_data::modals::{MediaManagementModal, SchemaFormFieldKind};
+use crate::models::servarr_data::sonarr::sonarr_data::{
+  ActiveSonarrBlock, MEDIA_MANAGEMENT_SETTINGS_BLOCKS,
+};
+use crate::models::{Route, Scrollable};
+use crate::network::sonarr_network::SonarrEvent;
+use crate::{handle_text_box_keys, handle_text_box_left_right_keys, matches_key};
+
+#[cfg(test)]
+#[path = "media_management_settings_handler_tests.rs"]
+mod media_management_settings_handler_tests;
+
+pub(super) struct MediaManagementSettingsHandler<'a, 'b> {
+  key: Key,
+  app: &'a mut App<'b>,
+  active_sonarr_block: ActiveSonarrBlock,
+  _context: Option<ActiveSonarrBlock>,
+}
+
+impl MediaManagementSettingsHandler<'_, '_> {
+  fn media_management_modal(&mut self) -> &mut MediaManagementModal {
+    self
+      .app
+      .data
+      .sonarr_data
+      .media_management_modal
+      .as_mut()
+      .expect("Media Management Modal is None")
+  }
+}
+
+impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for MediaManagementSettingsHandler<'a, 'b> {
+  fn accepts(active_block: ActiveSonarrBlock) -> bool {
+    MEDIA_MANAGEMENT_SETTINGS_BLOCKS.contains(&active_block)
+  }
+
+  fn ignore_special_keys(&self) -> bool {
+    self.app.ignore_special_keys_for_textbox_input
+  }
+
+  fn new(
+    key: Key,
+    app: &'a mut App<'b>,
+    active_block: ActiveSonarrBlock,
+    _context: Option<ActiveSonarrBlock>,
+  ) -> MediaManagementSettingsHandler<'a, 'b> {
+    MediaManagementSettingsHandler {
+      key,
+      app,
+      active_sonarr_block: active_block,
+      _context,
+    }
+  }
+
+  fn get_key(&self) -> Key {
+    self.key
+  }
+
+  fn is_ready(&self) -> bool {
+    !self.app.is_loading && self.app.data.sonarr_data.media_management_modal.is_some()
+  }
+
+  fn handle_scroll_up(&mut self) {
+    if self.active_sonarr_block == ActiveSonarrBlock::MediaManagementSettingsPrompt {
+      self.media_management_modal().fields.scroll_up();
+    }
+  }
+
+  fn handle_scroll_down(&mut self) {
+    if self.active_sonarr_block == ActiveSonarrBlock::MediaManagementSettingsPrompt {
+      self.media_management_modal().fields.scroll_down();
+    }
+  }
+
+  fn handle_home(&mut self) {
+    match self.active_sonarr_block {
+      ActiveSonarrBlock::MediaManagementSettingsPrompt => {
+        self.media_management_modal().fields.scroll_to_top()
+      }
+      ActiveSonarrBlock::MediaManagementFieldInput => self
+        .media_management_modal()
+        .current_field_mut()
+        .value
+        .scroll_home(),
+      _ => (),
+    }
+  }
+
+  fn handle_end(&mut self) {
+    match self.active_sonarr_block {
+      ActiveSonarrBlock::MediaManagementSettingsPrompt => {
+        self.media_management_modal().fields.scroll_to_bottom()
+      }
+      ActiveSonarrBlock::MediaManagementFieldInput => self
+        .media_management_modal()
+        .current_field_mut()
+        .value
+        .reset_offset(),
+      _ => (),
+    }
+  }
+
+  fn handle_delete(&mut self) {}
+
+  fn handle_left_right_action(&mut self) {
+    if self.active_sonarr_block == ActiveSonarrBlock::MediaManagementFieldInput {
+      handle_text_box_left_right_keys!(
+        self,
+        self.key,
+        self.media_management_modal().current_field_mut().value
+      );
+    }
+  }
+
+  fn handle_submit(&mut self) {
+    match self.active_sonarr_block {
+      ActiveSonarrBlock::MediaManagementSettingsPrompt => {
+        let media_management_modal = self.media_management_modal();
+        if media_management_modal.current_field_mut().kind == SchemaFormFieldKind::Checkbox {
+          media_management_modal.toggle_current_field();
+          self.app.should_refresh = true;
+        } else {
+          self
+            .app
+            .push_navigation_stack(ActiveSonarrBlock::MediaManagementFieldInput.into());
+          self.app.ignore_special_keys_for_textbox_input = true;
+        }
+      }
+      ActiveSonarrBlock::MediaManagementFieldInput => {
+        self.app.pop_navigation_stack();
+        self.app.ignore_special_keys_for_textbox_input = false;
+      }
+      _ => (),
+    }
+  }
+
+  fn handle_esc(&mut self) {
+    match self.active_sonarr_block {
+      ActiveSonarrBlock::MediaManagementSettingsPrompt => {
+        self.app.pop_navigation_stack();
+        self.app.data.sonarr_data.prompt_confirm = false;
+        self.app.data.sonarr_data.media_management_modal = None;
+        self.app.data.sonarr_data.naming_examples = None;
+      }
+      ActiveSonarrBlock::MediaManagementFieldInput => {
+        self.app.pop_navigation_stack();
+        self.app.ignore_special_keys_for_textbox_input = false;
+      }
+      _ => self.app.pop_navigation_stack(),
+    }
+  }
+
+  fn handle_char_key_event(&mut self) {
+    match self.active_sonarr_block {
+      ActiveSonarrBlock::MediaManagementFieldInput => {
+        handle_text_box_keys!(
+          self,
+          self.key,
+          self.media_management_modal().current_field_mut().value
+        );
+      }
+      ActiveSonarrBlock::MediaManagementSettingsPrompt if matches_key!(confirm, self.key) => {
+        let media_management_modal = self
+          .app
+          .data
+          .sonarr_data
+          .media_management_modal
+          .take()
+          .expect("Media Management Modal is None");
+        self.app.data.sonarr_data.naming_examples = None;
+        self.app.data.sonarr_data.prompt_confirm = true;
+        self.app.data.sonarr_data.prompt_confirm_action = Some(
+          SonarrEvent::EditMediaManagementSettings(media_management_modal.settings()),
+        );
+        self.app.should_refresh = true;
+
+        self.app.pop_navigation_stack();
+      }
+      _ => (),
+    }
+  }
+
+  fn app_mut(&mut self) -> &mut App<'b> {
+    self.app
+  }
+
+  fn current_route(&self) -> Route {
+    self.app.get_current_route()
+  }
+}
@@ -1,6 +1,7 @@
 use crate::app::App;
 use crate::event::Key;
 use crate::handlers::sonarr_handlers::handle_change_tab_left_right_keys;
+use crate::handlers::sonarr_handlers::root_folders::media_management_settings_handler::MediaManagementSettingsHandler;
 use crate::handlers::table_handler::{TableHandlingConfig, handle_table};
 use crate::handlers::{KeyEventHandler, handle_clear_errors, handle_prompt_toggle};
 use crate::models::servarr_data::sonarr::sonarr_data::{ActiveSonarrBlock, ROOT_FOLDERS_BLOCKS};
@@ -9,6 +10,8 @@ use crate::models::{HorizontallyScrollableText, Route};
 use crate::network::sonarr_network::SonarrEvent;
 use crate::{handle_text_box_keys, handle_text_box_left_right_keys, matches_key};
 
+mod media_management_settings_handler;
+
 #[cfg(test)]
 #[path = "root_folders_handler_tests.rs"]
 mod root_folders_handler_tests;
@@ -17,7 +20,7 @@ pub(super) struct RootFoldersHandler<'a, 'b> {
   key: Key,
   app: &'a mut App<'b>,
   active_sonarr_block: ActiveSonarrBlock,
-  _context: Option<ActiveSonarrBlock>,
+  context: Option<ActiveSonarrBlock>,
 }
 
 impl RootFoldersHandler<'_, '_> {
@@ -54,12 +57,24 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for RootFoldersHandler<'
       |app| &mut app.data.sonarr_data.root_folders,
       root_folders_table_handling_config,
     ) {
-      self.handle_key_event();
+      match self.active_sonarr_block {
+        _ if MediaManagementSettingsHandler::accepts(self.active_sonarr_block) => {
+          MediaManagementSettingsHandler::new(
+            self.key,
+            self.app,
+            self.active_sonarr_block,
+            self.context,
+          )
+          .handle()
+        }
+        _ => self.handle_key_event(),
+      }
     }
   }
 
   fn accepts(active_block: ActiveSonarrBlock) -> bool {
-    ROOT_FOLDERS_BLOCKS.contains(&active_block)
+    MediaManagementSettingsHandler::accepts(active_block)
+      || ROOT_FOLDERS_BLOCKS.contains(&active_block)
   }
 
   fn ignore_special_keys(&self) -> bool {
@@ -70,13 +85,13 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for RootFoldersHandler<'
     key: Key,
     app: &'a mut App<'b>,
     active_block: ActiveSonarrBlock,
-    _context: Option<ActiveSonarrBlock>,
+    context: Option<ActiveSonarrBlock>,
   ) -> RootFoldersHandler<'a, 'b> {
     RootFoldersHandler {
       key,
       app,
       active_sonarr_block: active_block,
-      _context,
+      context,
     }
   }
 
@@ -203,6 +218,11 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for RootFoldersHandler<'
           self.app.data.sonarr_data.edit_root_folder = Some(HorizontallyScrollableText::default());
           self.app.ignore_special_keys_for_textbox_input = true;
         }
+        _ if matches_key!(settings, key) => {
+          self
+            .app
+            .push_navigation_stack(ActiveSonarrBlock::MediaManagementSettingsPrompt.into());
+        }
         _ => (),
       },
       ActiveSonarrBlock::AddRootFolderPrompt => {
@@ -14,8 +14,11 @@ mod tests {
   use crate::handlers::sonarr_handlers::root_folders::RootFoldersHandler;
   use crate::handlers::sonarr_handlers::sonarr_handler_test_utils::utils::root_folder;
   use crate::models::HorizontallyScrollableText;
-  use crate::models::servarr_data::sonarr::sonarr_data::{ActiveSonarrBlock, ROOT_FOLDERS_BLOCKS};
+  use crate::models::servarr_data::sonarr::sonarr_data::{
+    ActiveSonarrBlock, MEDIA_MANAGEMENT_SETTINGS_BLOCKS, ROOT_FOLDERS_BLOCKS,
+  };
   use crate::models::servarr_models::{AddRootFolderBody, RootFolder};
+  use crate::test_handler_delegation;
 
   mod test_handle_home_end {
     use crate::models::servarr_models::RootFolder;
@@ -542,6 +545,51 @@ mod tests {
       assert!(!app.should_refresh);
     }
 
+    #[test]
+    fn test_media_management_settings_key() {
+      let mut app = App::test_default();
+      app
+        .data
+        .sonarr_data
+        .root_folders
+        .set_items(vec![RootFolder::default()]);
+
+      RootFoldersHandler::new(
+        DEFAULT_KEYBINDINGS.settings.key,
+        &mut app,
+        ActiveSonarrBlock::RootFolders,
+        None,
+      )
+      .handle();
+
+      assert_navigation_pushed!(app, ActiveSonarrBlock::MediaManagementSettingsPrompt.into());
+    }
+
+    #[test]
+    fn test_media_management_settings_key_no_op_when_not_ready() {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.push_navigation_stack(ActiveSonarrBlock::RootFolders.into());
+      app
+        .data
+        .sonarr_data
+        .root_folders
+        .set_items(vec![RootFolder::default()]);
+
+      RootFoldersHandler::new(
+        DEFAULT_KEYBINDINGS.settings.key,
+        &mut app,
+        ActiveSonarrBlock::RootFolders,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveSonarrBlock::RootFolders.into()
+      );
+    }
+
     #[test]
     fn test_add_root_folder_prompt_backspace_key() {
       let mut app = App::test_default();
@@ -620,10 +668,29 @@ mod tests {
     }
   }
 
+  #[rstest]
+  fn test_delegates_media_management_settings_blocks_to_media_management_settings_handler(
+    #[values(
+      ActiveSonarrBlock::MediaManagementSettingsPrompt,
+      ActiveSonarrBlock::MediaManagementFieldInput
+    )]
+    active_sonarr_block: ActiveSonarrBlock,
+  ) {
+    test_handler_delegation!(
+      RootFoldersHandler,
+      ActiveSonarrBlock::RootFolders,
+      active_sonarr_block
+    );
+  }
+
   #[test]
   fn test_root_folders_handler_accepts() {
+    let mut root_folders_blocks = Vec::new();
+    root_folders_blocks.extend(ROOT_FOLDERS_BLOCKS);
+    root_folders_blocks.extend(MEDIA_MANAGEMENT_SETTINGS_BLOCKS);
+
     ActiveSonarrBlock::iter().for_each(|active_sonarr_block| {
-      if ROOT_FOLDERS_BLOCKS.contains(&active_sonarr_block) {
+      if root_folders_blocks.contains(&active_sonarr_block) {
         assert!(RootFoldersHandler::accepts(active_sonarr_block));
       } else {
         assert!(!RootFoldersHandler::accepts(active_sonarr_block));
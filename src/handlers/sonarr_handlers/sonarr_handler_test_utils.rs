@@ -200,6 +200,8 @@ pub(in crate::handlers::sonarr_handlers) mod utils {
       )),
       indexer: "kickass torrents".to_owned(),
       download_client: Some("transmission".to_owned()),
+      download_id: Some("abc123".to_owned()),
+      estimated_completion_time: None,
     }
   }
 
@@ -259,14 +261,17 @@ pub(in crate::handlers::sonarr_handlers) mod utils {
         IndexerField {
           name: Some("baseUrl".to_owned()),
           value: Some(json!("https://test.com")),
+          ..IndexerField::default()
         },
         IndexerField {
           name: Some("apiKey".to_owned()),
           value: Some(json!("")),
+          ..IndexerField::default()
         },
         IndexerField {
           name: Some("seedCriteria.seedRatio".to_owned()),
           value: Some(json!("1.2")),
+          ..IndexerField::default()
         },
       ]),
     }
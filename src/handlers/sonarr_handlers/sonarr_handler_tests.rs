@@ -13,13 +13,22 @@ mod tests {
   use strum::IntoEnumIterator;
 
   #[rstest]
-  #[case(0, ActiveSonarrBlock::System, ActiveSonarrBlock::Downloads)]
+  #[case(0, ActiveSonarrBlock::WantedMissing, ActiveSonarrBlock::Downloads)]
   #[case(1, ActiveSonarrBlock::Series, ActiveSonarrBlock::Blocklist)]
   #[case(2, ActiveSonarrBlock::Downloads, ActiveSonarrBlock::History)]
   #[case(3, ActiveSonarrBlock::Blocklist, ActiveSonarrBlock::RootFolders)]
   #[case(4, ActiveSonarrBlock::History, ActiveSonarrBlock::Indexers)]
-  #[case(5, ActiveSonarrBlock::RootFolders, ActiveSonarrBlock::System)]
-  #[case(6, ActiveSonarrBlock::Indexers, ActiveSonarrBlock::Series)]
+  #[case(5, ActiveSonarrBlock::RootFolders, ActiveSonarrBlock::DownloadClients)]
+  #[case(6, ActiveSonarrBlock::Indexers, ActiveSonarrBlock::QualityProfiles)]
+  #[case(
+    7,
+    ActiveSonarrBlock::DownloadClients,
+    ActiveSonarrBlock::CustomFormats
+  )]
+  #[case(8, ActiveSonarrBlock::QualityProfiles, ActiveSonarrBlock::System)]
+  #[case(9, ActiveSonarrBlock::CustomFormats, ActiveSonarrBlock::Calendar)]
+  #[case(10, ActiveSonarrBlock::System, ActiveSonarrBlock::WantedMissing)]
+  #[case(11, ActiveSonarrBlock::Calendar, ActiveSonarrBlock::Series)]
   fn test_sonarr_handler_change_tab_left_right_keys(
     #[case] index: usize,
     #[case] left_block: ActiveSonarrBlock,
@@ -48,13 +57,22 @@ mod tests {
   }
 
   #[rstest]
-  #[case(0, ActiveSonarrBlock::System, ActiveSonarrBlock::Downloads)]
+  #[case(0, ActiveSonarrBlock::WantedMissing, ActiveSonarrBlock::Downloads)]
   #[case(1, ActiveSonarrBlock::Series, ActiveSonarrBlock::Blocklist)]
   #[case(2, ActiveSonarrBlock::Downloads, ActiveSonarrBlock::History)]
   #[case(3, ActiveSonarrBlock::Blocklist, ActiveSonarrBlock::RootFolders)]
   #[case(4, ActiveSonarrBlock::History, ActiveSonarrBlock::Indexers)]
-  #[case(5, ActiveSonarrBlock::RootFolders, ActiveSonarrBlock::System)]
-  #[case(6, ActiveSonarrBlock::Indexers, ActiveSonarrBlock::Series)]
+  #[case(5, ActiveSonarrBlock::RootFolders, ActiveSonarrBlock::DownloadClients)]
+  #[case(6, ActiveSonarrBlock::Indexers, ActiveSonarrBlock::QualityProfiles)]
+  #[case(
+    7,
+    ActiveSonarrBlock::DownloadClients,
+    ActiveSonarrBlock::CustomFormats
+  )]
+  #[case(8, ActiveSonarrBlock::QualityProfiles, ActiveSonarrBlock::System)]
+  #[case(9, ActiveSonarrBlock::CustomFormats, ActiveSonarrBlock::Calendar)]
+  #[case(10, ActiveSonarrBlock::System, ActiveSonarrBlock::WantedMissing)]
+  #[case(11, ActiveSonarrBlock::Calendar, ActiveSonarrBlock::Series)]
   fn test_sonarr_handler_change_tab_left_right_keys_alt_navigation(
     #[case] index: usize,
     #[case] left_block: ActiveSonarrBlock,
@@ -89,7 +107,12 @@ mod tests {
   #[case(3, ActiveSonarrBlock::History)]
   #[case(4, ActiveSonarrBlock::RootFolders)]
   #[case(5, ActiveSonarrBlock::Indexers)]
-  #[case(6, ActiveSonarrBlock::System)]
+  #[case(6, ActiveSonarrBlock::DownloadClients)]
+  #[case(7, ActiveSonarrBlock::QualityProfiles)]
+  #[case(8, ActiveSonarrBlock::CustomFormats)]
+  #[case(9, ActiveSonarrBlock::System)]
+  #[case(10, ActiveSonarrBlock::Calendar)]
+  #[case(11, ActiveSonarrBlock::WantedMissing)]
   fn test_sonarr_handler_change_tab_left_right_keys_alt_navigation_no_op_when_ignoring_quit_key(
     #[case] index: usize,
     #[case] block: ActiveSonarrBlock,
@@ -242,7 +265,9 @@ mod tests {
     #[values(
       ActiveSonarrBlock::RootFolders,
       ActiveSonarrBlock::AddRootFolderPrompt,
-      ActiveSonarrBlock::DeleteRootFolderPrompt
+      ActiveSonarrBlock::DeleteRootFolderPrompt,
+      ActiveSonarrBlock::MediaManagementSettingsPrompt,
+      ActiveSonarrBlock::MediaManagementFieldInput
     )]
     active_sonarr_block: ActiveSonarrBlock,
   ) {
@@ -274,6 +299,60 @@ mod tests {
     );
   }
 
+  #[rstest]
+  fn test_delegates_download_clients_blocks_to_download_clients_handler(
+    #[values(
+      ActiveSonarrBlock::DownloadClients,
+      ActiveSonarrBlock::DeleteDownloadClientPrompt,
+      ActiveSonarrBlock::TestDownloadClient,
+      ActiveSonarrBlock::TestAllDownloadClients,
+      ActiveSonarrBlock::AddDownloadClientSelectImplementation,
+      ActiveSonarrBlock::DownloadClientPrompt,
+      ActiveSonarrBlock::DownloadClientFieldInput
+    )]
+    active_sonarr_block: ActiveSonarrBlock,
+  ) {
+    test_handler_delegation!(
+      SonarrHandler,
+      ActiveSonarrBlock::DownloadClients,
+      active_sonarr_block
+    );
+  }
+
+  #[rstest]
+  fn test_delegates_quality_profiles_blocks_to_quality_profiles_handler(
+    #[values(
+      ActiveSonarrBlock::QualityProfiles,
+      ActiveSonarrBlock::EditQualityProfile
+    )]
+    active_sonarr_block: ActiveSonarrBlock,
+  ) {
+    test_handler_delegation!(
+      SonarrHandler,
+      ActiveSonarrBlock::QualityProfiles,
+      active_sonarr_block
+    );
+  }
+
+  #[rstest]
+  fn test_delegates_custom_formats_blocks_to_custom_formats_handler(
+    #[values(
+      ActiveSonarrBlock::CustomFormats,
+      ActiveSonarrBlock::CustomFormatDetails,
+      ActiveSonarrBlock::DeleteCustomFormatPrompt,
+      ActiveSonarrBlock::ExportCustomFormatPrompt,
+      ActiveSonarrBlock::ImportCustomFormatPrompt,
+      ActiveSonarrBlock::EditCustomFormatPrompt
+    )]
+    active_sonarr_block: ActiveSonarrBlock,
+  ) {
+    test_handler_delegation!(
+      SonarrHandler,
+      ActiveSonarrBlock::CustomFormats,
+      active_sonarr_block
+    );
+  }
+
   #[rstest]
   fn test_delegates_system_blocks_to_system_handler(
     #[values(
@@ -293,6 +372,37 @@ mod tests {
     );
   }
 
+  #[rstest]
+  fn test_delegates_calendar_blocks_to_calendar_handler(
+    #[values(ActiveSonarrBlock::Calendar, ActiveSonarrBlock::CalendarAgenda)]
+    active_sonarr_block: ActiveSonarrBlock,
+  ) {
+    test_handler_delegation!(SonarrHandler, active_sonarr_block, active_sonarr_block);
+  }
+
+  #[rstest]
+  fn test_delegates_wanted_table_blocks_to_wanted_handler(
+    #[values(ActiveSonarrBlock::WantedMissing, ActiveSonarrBlock::WantedCutoffUnmet)]
+    active_sonarr_block: ActiveSonarrBlock,
+  ) {
+    test_handler_delegation!(SonarrHandler, active_sonarr_block, active_sonarr_block);
+  }
+
+  #[rstest]
+  fn test_delegates_wanted_prompt_blocks_to_wanted_handler(
+    #[values(
+      ActiveSonarrBlock::WantedSearchAllPrompt,
+      ActiveSonarrBlock::WantedSearchSelectedPrompt
+    )]
+    active_sonarr_block: ActiveSonarrBlock,
+  ) {
+    test_handler_delegation!(
+      SonarrHandler,
+      ActiveSonarrBlock::WantedMissing,
+      active_sonarr_block
+    );
+  }
+
   #[test]
   fn test_sonarr_handler_accepts() {
     ActiveSonarrBlock::iter().for_each(|active_sonarr_block| {
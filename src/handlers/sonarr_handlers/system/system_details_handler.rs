@@ -20,6 +20,10 @@ pub(super) struct SystemDetailsHandler<'a, 'b> {
 }
 
 impl SystemDetailsHandler<'_, '_> {
+  fn extract_backup_id(&self) -> i64 {
+    self.app.data.sonarr_data.backups.current_selection().id
+  }
+
   fn extract_task_name(&self) -> SonarrTaskName {
     self
       .app
@@ -62,6 +66,7 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for SystemDetailsHandler
     !self.app.is_loading
       && (!self.app.data.sonarr_data.log_details.is_empty()
         || !self.app.data.sonarr_data.tasks.is_empty()
+        || !self.app.data.sonarr_data.backups.is_empty()
         || !self.app.data.sonarr_data.updates.is_empty())
   }
 
@@ -69,6 +74,7 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for SystemDetailsHandler
     match self.active_sonarr_block {
       ActiveSonarrBlock::SystemLogs => self.app.data.sonarr_data.log_details.scroll_up(),
       ActiveSonarrBlock::SystemTasks => self.app.data.sonarr_data.tasks.scroll_up(),
+      ActiveSonarrBlock::SystemBackups => self.app.data.sonarr_data.backups.scroll_up(),
       ActiveSonarrBlock::SystemUpdates => self.app.data.sonarr_data.updates.scroll_up(),
       ActiveSonarrBlock::SystemQueuedEvents => self.app.data.sonarr_data.queued_events.scroll_up(),
       _ => (),
@@ -79,6 +85,7 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for SystemDetailsHandler
     match self.active_sonarr_block {
       ActiveSonarrBlock::SystemLogs => self.app.data.sonarr_data.log_details.scroll_down(),
       ActiveSonarrBlock::SystemTasks => self.app.data.sonarr_data.tasks.scroll_down(),
+      ActiveSonarrBlock::SystemBackups => self.app.data.sonarr_data.backups.scroll_down(),
       ActiveSonarrBlock::SystemUpdates => self.app.data.sonarr_data.updates.scroll_down(),
       ActiveSonarrBlock::SystemQueuedEvents => {
         self.app.data.sonarr_data.queued_events.scroll_down()
@@ -91,6 +98,7 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for SystemDetailsHandler
     match self.active_sonarr_block {
       ActiveSonarrBlock::SystemLogs => self.app.data.sonarr_data.log_details.scroll_to_top(),
       ActiveSonarrBlock::SystemTasks => self.app.data.sonarr_data.tasks.scroll_to_top(),
+      ActiveSonarrBlock::SystemBackups => self.app.data.sonarr_data.backups.scroll_to_top(),
       ActiveSonarrBlock::SystemUpdates => self.app.data.sonarr_data.updates.scroll_to_top(),
       ActiveSonarrBlock::SystemQueuedEvents => {
         self.app.data.sonarr_data.queued_events.scroll_to_top()
@@ -103,6 +111,7 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for SystemDetailsHandler
     match self.active_sonarr_block {
       ActiveSonarrBlock::SystemLogs => self.app.data.sonarr_data.log_details.scroll_to_bottom(),
       ActiveSonarrBlock::SystemTasks => self.app.data.sonarr_data.tasks.scroll_to_bottom(),
+      ActiveSonarrBlock::SystemBackups => self.app.data.sonarr_data.backups.scroll_to_bottom(),
       ActiveSonarrBlock::SystemUpdates => self.app.data.sonarr_data.updates.scroll_to_bottom(),
       ActiveSonarrBlock::SystemQueuedEvents => {
         self.app.data.sonarr_data.queued_events.scroll_to_bottom()
@@ -111,7 +120,15 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for SystemDetailsHandler
     }
   }
 
-  fn handle_delete(&mut self) {}
+  fn handle_delete(&mut self) {
+    if self.active_sonarr_block == ActiveSonarrBlock::SystemBackups
+      && !self.app.data.sonarr_data.backups.is_empty()
+    {
+      self
+        .app
+        .push_navigation_stack(ActiveSonarrBlock::DeleteBackupPrompt.into());
+    }
+  }
 
   fn handle_left_right_action(&mut self) {
     let key = self.key;
@@ -140,13 +157,23 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for SystemDetailsHandler
         }
         _ => (),
       },
-      ActiveSonarrBlock::SystemTaskStartConfirmPrompt => handle_prompt_toggle(self.app, self.key),
+      ActiveSonarrBlock::SystemTaskStartConfirmPrompt | ActiveSonarrBlock::DeleteBackupPrompt => {
+        handle_prompt_toggle(self.app, self.key)
+      }
       _ => (),
     }
   }
 
   fn handle_submit(&mut self) {
     match self.active_sonarr_block {
+      ActiveSonarrBlock::DeleteBackupPrompt => {
+        if self.app.data.sonarr_data.prompt_confirm {
+          self.app.data.sonarr_data.prompt_confirm_action =
+            Some(SonarrEvent::DeleteBackup(self.extract_backup_id()));
+        }
+
+        self.app.pop_navigation_stack();
+      }
       ActiveSonarrBlock::SystemTasks => {
         self
           .app
@@ -170,10 +197,11 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for SystemDetailsHandler
         self.app.data.sonarr_data.log_details = StatefulList::default();
         self.app.pop_navigation_stack()
       }
-      ActiveSonarrBlock::SystemQueuedEvents
+      ActiveSonarrBlock::SystemBackups
+      | ActiveSonarrBlock::SystemQueuedEvents
       | ActiveSonarrBlock::SystemTasks
       | ActiveSonarrBlock::SystemUpdates => self.app.pop_navigation_stack(),
-      ActiveSonarrBlock::SystemTaskStartConfirmPrompt => {
+      ActiveSonarrBlock::SystemTaskStartConfirmPrompt | ActiveSonarrBlock::DeleteBackupPrompt => {
         self.app.pop_navigation_stack();
         self.app.data.sonarr_data.prompt_confirm = false;
       }
@@ -187,6 +215,15 @@ impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for SystemDetailsHandler
       self.app.should_refresh = true;
     }
 
+    if self.active_sonarr_block == ActiveSonarrBlock::DeleteBackupPrompt
+      && matches_key!(confirm, self.key)
+    {
+      self.app.data.sonarr_data.prompt_confirm = true;
+      self.app.data.sonarr_data.prompt_confirm_action =
+        Some(SonarrEvent::DeleteBackup(self.extract_backup_id()));
+      self.app.pop_navigation_stack();
+    }
+
     if self.active_sonarr_block == ActiveSonarrBlock::SystemTaskStartConfirmPrompt
       && matches_key!(confirm, self.key)
     {
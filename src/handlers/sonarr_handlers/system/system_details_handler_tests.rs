@@ -13,7 +13,7 @@ mod tests {
   use crate::models::servarr_data::sonarr::sonarr_data::{
     ActiveSonarrBlock, SYSTEM_DETAILS_BLOCKS,
   };
-  use crate::models::servarr_models::QueueEvent;
+  use crate::models::servarr_models::{Backup, QueueEvent};
   use crate::models::sonarr_models::{SonarrTask, SonarrTaskName};
   use crate::models::{HorizontallyScrollableText, ScrollableText};
 
@@ -187,6 +187,32 @@ mod tests {
       );
     }
 
+    #[rstest]
+    fn test_backups_scroll(
+      #[values(DEFAULT_KEYBINDINGS.up.key, DEFAULT_KEYBINDINGS.down.key)] key: Key,
+    ) {
+      let mut app = App::test_default();
+      app
+        .data
+        .sonarr_data
+        .backups
+        .set_items(simple_stateful_iterable_vec!(Backup, String, name));
+
+      SystemDetailsHandler::new(key, &mut app, ActiveSonarrBlock::SystemBackups, None).handle();
+
+      assert_str_eq!(
+        app.data.sonarr_data.backups.current_selection().name,
+        "Test 2"
+      );
+
+      SystemDetailsHandler::new(key, &mut app, ActiveSonarrBlock::SystemBackups, None).handle();
+
+      assert_str_eq!(
+        app.data.sonarr_data.backups.current_selection().name,
+        "Test 1"
+      );
+    }
+
     #[test]
     fn test_system_updates_scroll() {
       let mut app = App::test_default();
@@ -461,6 +487,42 @@ mod tests {
       );
     }
 
+    #[test]
+    fn test_backups_home_end() {
+      let mut app = App::test_default();
+      app
+        .data
+        .sonarr_data
+        .backups
+        .set_items(extended_stateful_iterable_vec!(Backup, String, name));
+
+      SystemDetailsHandler::new(
+        DEFAULT_KEYBINDINGS.end.key,
+        &mut app,
+        ActiveSonarrBlock::SystemBackups,
+        None,
+      )
+      .handle();
+
+      assert_str_eq!(
+        app.data.sonarr_data.backups.current_selection().name,
+        "Test 3"
+      );
+
+      SystemDetailsHandler::new(
+        DEFAULT_KEYBINDINGS.home.key,
+        &mut app,
+        ActiveSonarrBlock::SystemBackups,
+        None,
+      )
+      .handle();
+
+      assert_str_eq!(
+        app.data.sonarr_data.backups.current_selection().name,
+        "Test 1"
+      );
+    }
+
     #[test]
     fn test_system_updates_home_end() {
       let mut app = App::test_default();
@@ -517,6 +579,44 @@ mod tests {
     }
   }
 
+  mod test_handle_delete {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    const DELETE_KEY: Key = DEFAULT_KEYBINDINGS.delete.key;
+
+    #[test]
+    fn test_backups_delete() {
+      let mut app = App::test_default();
+      app
+        .data
+        .sonarr_data
+        .backups
+        .set_items(vec![Backup::default()]);
+
+      SystemDetailsHandler::new(DELETE_KEY, &mut app, ActiveSonarrBlock::SystemBackups, None)
+        .handle();
+
+      assert_navigation_pushed!(app, ActiveSonarrBlock::DeleteBackupPrompt.into());
+    }
+
+    #[test]
+    fn test_backups_delete_no_op_when_backups_are_empty() {
+      let mut app = App::test_default();
+      app.data.sonarr_data.updates = ScrollableText::with_string("Test".to_owned());
+      app.push_navigation_stack(ActiveSonarrBlock::SystemBackups.into());
+
+      SystemDetailsHandler::new(DELETE_KEY, &mut app, ActiveSonarrBlock::SystemBackups, None)
+        .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveSonarrBlock::SystemBackups.into()
+      );
+    }
+  }
+
   mod test_handle_left_right_action {
     use pretty_assertions::assert_eq;
     use rstest::rstest;
@@ -626,27 +726,20 @@ mod tests {
     #[rstest]
     fn test_left_right_prompt_toggle(
       #[values(DEFAULT_KEYBINDINGS.left.key, DEFAULT_KEYBINDINGS.right.key)] key: Key,
+      #[values(
+        ActiveSonarrBlock::SystemTaskStartConfirmPrompt,
+        ActiveSonarrBlock::DeleteBackupPrompt
+      )]
+      active_sonarr_block: ActiveSonarrBlock,
     ) {
       let mut app = App::test_default();
       app.push_navigation_stack(ActiveSonarrBlock::System.into());
 
-      SystemDetailsHandler::new(
-        key,
-        &mut app,
-        ActiveSonarrBlock::SystemTaskStartConfirmPrompt,
-        None,
-      )
-      .handle();
+      SystemDetailsHandler::new(key, &mut app, active_sonarr_block, None).handle();
 
       assert!(app.data.sonarr_data.prompt_confirm);
 
-      SystemDetailsHandler::new(
-        key,
-        &mut app,
-        ActiveSonarrBlock::SystemTaskStartConfirmPrompt,
-        None,
-      )
-      .handle();
+      SystemDetailsHandler::new(key, &mut app, active_sonarr_block, None).handle();
 
       assert!(!app.data.sonarr_data.prompt_confirm);
     }
@@ -737,6 +830,57 @@ mod tests {
       assert_none!(app.data.sonarr_data.prompt_confirm_action);
       assert_navigation_popped!(app, ActiveSonarrBlock::SystemTasks.into());
     }
+
+    #[test]
+    fn test_delete_backup_prompt_confirm_submit() {
+      let mut app = App::test_default();
+      app.data.sonarr_data.prompt_confirm = true;
+      app.data.sonarr_data.backups.set_items(vec![Backup {
+        id: 1,
+        ..Backup::default()
+      }]);
+      app.push_navigation_stack(ActiveSonarrBlock::SystemBackups.into());
+      app.push_navigation_stack(ActiveSonarrBlock::DeleteBackupPrompt.into());
+
+      SystemDetailsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveSonarrBlock::DeleteBackupPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(app.data.sonarr_data.prompt_confirm);
+      assert_some_eq_x!(
+        &app.data.sonarr_data.prompt_confirm_action,
+        &SonarrEvent::DeleteBackup(1)
+      );
+      assert_navigation_popped!(app, ActiveSonarrBlock::SystemBackups.into());
+    }
+
+    #[test]
+    fn test_delete_backup_prompt_decline_submit() {
+      let mut app = App::test_default();
+      app
+        .data
+        .sonarr_data
+        .backups
+        .set_items(vec![Backup::default()]);
+      app.push_navigation_stack(ActiveSonarrBlock::SystemBackups.into());
+      app.push_navigation_stack(ActiveSonarrBlock::DeleteBackupPrompt.into());
+
+      SystemDetailsHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        ActiveSonarrBlock::DeleteBackupPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(!app.data.sonarr_data.prompt_confirm);
+      assert_none!(app.data.sonarr_data.prompt_confirm_action);
+      assert_navigation_popped!(app, ActiveSonarrBlock::SystemBackups.into());
+    }
   }
 
   mod test_handle_esc {
@@ -823,6 +967,42 @@ mod tests {
       assert_navigation_popped!(app, ActiveSonarrBlock::System.into());
     }
 
+    #[rstest]
+    fn test_esc_system_backups(#[values(true, false)] is_ready: bool) {
+      let mut app = App::test_default();
+      app.is_loading = is_ready;
+      app.push_navigation_stack(ActiveSonarrBlock::System.into());
+      app.push_navigation_stack(ActiveSonarrBlock::SystemBackups.into());
+      app
+        .data
+        .sonarr_data
+        .backups
+        .set_items(vec![Backup::default()]);
+
+      SystemDetailsHandler::new(ESC_KEY, &mut app, ActiveSonarrBlock::SystemBackups, None).handle();
+
+      assert_navigation_popped!(app, ActiveSonarrBlock::System.into());
+    }
+
+    #[test]
+    fn test_delete_backup_prompt_esc() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveSonarrBlock::SystemBackups.into());
+      app.push_navigation_stack(ActiveSonarrBlock::DeleteBackupPrompt.into());
+      app.data.sonarr_data.prompt_confirm = true;
+
+      SystemDetailsHandler::new(
+        ESC_KEY,
+        &mut app,
+        ActiveSonarrBlock::DeleteBackupPrompt,
+        None,
+      )
+      .handle();
+
+      assert_navigation_popped!(app, ActiveSonarrBlock::SystemBackups.into());
+      assert!(!app.data.sonarr_data.prompt_confirm);
+    }
+
     #[test]
     fn test_system_tasks_start_task_prompt_esc() {
       let mut app = App::test_default();
@@ -855,6 +1035,7 @@ mod tests {
     #[rstest]
     fn test_refresh_key(
       #[values(
+        ActiveSonarrBlock::SystemBackups,
         ActiveSonarrBlock::SystemLogs,
         ActiveSonarrBlock::SystemTasks,
         ActiveSonarrBlock::SystemQueuedEvents,
@@ -882,6 +1063,7 @@ mod tests {
     #[rstest]
     fn test_refresh_key_no_op_when_not_ready(
       #[values(
+        ActiveSonarrBlock::SystemBackups,
         ActiveSonarrBlock::SystemLogs,
         ActiveSonarrBlock::SystemTasks,
         ActiveSonarrBlock::SystemQueuedEvents,
@@ -935,6 +1117,32 @@ mod tests {
       );
       assert_navigation_popped!(app, ActiveSonarrBlock::SystemTasks.into());
     }
+
+    #[test]
+    fn test_delete_backup_prompt_confirm() {
+      let mut app = App::test_default();
+      app.data.sonarr_data.backups.set_items(vec![Backup {
+        id: 1,
+        ..Backup::default()
+      }]);
+      app.push_navigation_stack(ActiveSonarrBlock::SystemBackups.into());
+      app.push_navigation_stack(ActiveSonarrBlock::DeleteBackupPrompt.into());
+
+      SystemDetailsHandler::new(
+        DEFAULT_KEYBINDINGS.confirm.key,
+        &mut app,
+        ActiveSonarrBlock::DeleteBackupPrompt,
+        None,
+      )
+      .handle();
+
+      assert!(app.data.sonarr_data.prompt_confirm);
+      assert_some_eq_x!(
+        &app.data.sonarr_data.prompt_confirm_action,
+        &SonarrEvent::DeleteBackup(1)
+      );
+      assert_navigation_popped!(app, ActiveSonarrBlock::SystemBackups.into());
+    }
   }
 
   #[test]
@@ -967,6 +1175,25 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_extract_backup_id() {
+    let mut app = App::test_default();
+    app.data.sonarr_data.backups.set_items(vec![Backup {
+      id: 1,
+      ..Backup::default()
+    }]);
+
+    let backup_id = SystemDetailsHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveSonarrBlock::SystemBackups,
+      None,
+    )
+    .extract_backup_id();
+
+    assert_eq!(backup_id, 1);
+  }
+
   #[test]
   fn test_extract_task_name() {
     let mut app = App::test_default();
@@ -1061,6 +1288,26 @@ mod tests {
     assert!(handler.is_ready());
   }
 
+  #[test]
+  fn test_system_details_handler_ready_when_not_loading_and_backups_is_not_empty() {
+    let mut app = App::test_default();
+    app.is_loading = false;
+    app
+      .data
+      .sonarr_data
+      .backups
+      .set_items(vec![Backup::default()]);
+
+    let handler = SystemDetailsHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveSonarrBlock::SystemBackups,
+      None,
+    );
+
+    assert!(handler.is_ready());
+  }
+
   #[test]
   fn test_system_details_handler_ready_when_not_loading_and_updates_is_not_empty() {
     let mut app = App::test_default();
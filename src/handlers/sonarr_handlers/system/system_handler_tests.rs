@@ -27,7 +27,7 @@ mod tests {
       let mut app = App::test_default();
       app.push_navigation_stack(ActiveSonarrBlock::System.into());
       app.is_loading = is_ready;
-      app.data.sonarr_data.main_tabs.set_index(6);
+      app.data.sonarr_data.main_tabs.set_index(9);
 
       SystemHandler::new(
         DEFAULT_KEYBINDINGS.left.key,
@@ -39,9 +39,9 @@ mod tests {
 
       assert_eq!(
         app.data.sonarr_data.main_tabs.get_active_route(),
-        ActiveSonarrBlock::Indexers.into()
+        ActiveSonarrBlock::CustomFormats.into()
       );
-      assert_navigation_pushed!(app, ActiveSonarrBlock::Indexers.into());
+      assert_navigation_pushed!(app, ActiveSonarrBlock::CustomFormats.into());
     }
 
     #[rstest]
@@ -49,7 +49,7 @@ mod tests {
       let mut app = App::test_default();
       app.push_navigation_stack(ActiveSonarrBlock::System.into());
       app.is_loading = is_ready;
-      app.data.sonarr_data.main_tabs.set_index(6);
+      app.data.sonarr_data.main_tabs.set_index(9);
 
       SystemHandler::new(
         DEFAULT_KEYBINDINGS.right.key,
@@ -61,9 +61,9 @@ mod tests {
 
       assert_eq!(
         app.data.sonarr_data.main_tabs.get_active_route(),
-        ActiveSonarrBlock::Series.into()
+        ActiveSonarrBlock::Calendar.into()
       );
-      assert_navigation_pushed!(app, ActiveSonarrBlock::Series.into());
+      assert_navigation_pushed!(app, ActiveSonarrBlock::Calendar.into());
     }
   }
 
@@ -352,6 +352,67 @@ mod tests {
       assert_is_empty!(app.data.sonarr_data.log_details);
     }
 
+    #[test]
+    fn test_backups_key() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveSonarrBlock::System.into());
+      app.data.sonarr_data.logs.set_items(vec![
+        HorizontallyScrollableText::from("test 1"),
+        HorizontallyScrollableText::from("test 2"),
+      ]);
+      app
+        .data
+        .sonarr_data
+        .queued_events
+        .set_items(vec![QueueEvent::default()]);
+      app
+        .data
+        .sonarr_data
+        .tasks
+        .set_items(vec![SonarrTask::default()]);
+
+      SystemHandler::new(
+        DEFAULT_KEYBINDINGS.backups.key,
+        &mut app,
+        ActiveSonarrBlock::System,
+        None,
+      )
+      .handle();
+
+      assert_navigation_pushed!(app, ActiveSonarrBlock::SystemBackups.into());
+    }
+
+    #[test]
+    fn test_backups_key_no_op_when_not_ready() {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.push_navigation_stack(ActiveSonarrBlock::System.into());
+      app.data.sonarr_data.logs.set_items(vec![
+        HorizontallyScrollableText::from("test 1"),
+        HorizontallyScrollableText::from("test 2"),
+      ]);
+      app
+        .data
+        .sonarr_data
+        .queued_events
+        .set_items(vec![QueueEvent::default()]);
+      app
+        .data
+        .sonarr_data
+        .tasks
+        .set_items(vec![SonarrTask::default()]);
+
+      SystemHandler::new(
+        DEFAULT_KEYBINDINGS.backups.key,
+        &mut app,
+        ActiveSonarrBlock::System,
+        None,
+      )
+      .handle();
+
+      assert_eq!(app.get_current_route(), ActiveSonarrBlock::System.into());
+    }
+
     #[test]
     fn test_tasks_key() {
       let mut app = App::test_default();
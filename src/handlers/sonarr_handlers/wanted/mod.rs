@@ -0,0 +1,206 @@
+use crate::app::App;
+use crate::event::Key;
+use crate::handlers::sonarr_handlers::handle_change_tab_left_right_keys;
+use crate::handlers::table_handler::{TableHandlingConfig, handle_table};
+use crate::handlers::{KeyEventHandler, handle_clear_errors, handle_prompt_toggle};
+use crate::matches_key;
+use crate::models::Route;
+use crate::models::servarr_data::sonarr::sonarr_data::{ActiveSonarrBlock, WANTED_BLOCKS};
+use crate::models::sonarr_models::Episode;
+use crate::models::stateful_table::StatefulTable;
+use crate::network::sonarr_network::SonarrEvent;
+
+#[cfg(test)]
+#[path = "wanted_handler_tests.rs"]
+mod wanted_handler_tests;
+
+pub(super) struct WantedHandler<'a, 'b> {
+  key: Key,
+  app: &'a mut App<'b>,
+  active_sonarr_block: ActiveSonarrBlock,
+  context: Option<ActiveSonarrBlock>,
+}
+
+impl WantedHandler<'_, '_> {
+  fn wanted_table_block(&self) -> ActiveSonarrBlock {
+    match self.active_sonarr_block {
+      ActiveSonarrBlock::WantedSearchAllPrompt | ActiveSonarrBlock::WantedSearchSelectedPrompt => {
+        self.context.unwrap_or(ActiveSonarrBlock::WantedMissing)
+      }
+      _ => self.active_sonarr_block,
+    }
+  }
+
+  fn wanted_table(&self) -> &StatefulTable<Episode> {
+    if self.wanted_table_block() == ActiveSonarrBlock::WantedCutoffUnmet {
+      &self.app.data.sonarr_data.cutoff_unmet
+    } else {
+      &self.app.data.sonarr_data.wanted_missing
+    }
+  }
+
+  fn build_search_action(&self) -> SonarrEvent {
+    match self.active_sonarr_block {
+      ActiveSonarrBlock::WantedSearchSelectedPrompt => {
+        SonarrEvent::TriggerAutomaticEpisodeSearch(self.wanted_table().current_selection().id)
+      }
+      _ if self.wanted_table_block() == ActiveSonarrBlock::WantedCutoffUnmet => {
+        SonarrEvent::TriggerCutoffUnmetEpisodeSearch
+      }
+      _ => SonarrEvent::TriggerMissingEpisodeSearch,
+    }
+  }
+}
+
+impl<'a, 'b> KeyEventHandler<'a, 'b, ActiveSonarrBlock> for WantedHandler<'a, 'b> {
+  fn handle(&mut self) {
+    let missing_table_handling_config =
+      TableHandlingConfig::new(ActiveSonarrBlock::WantedMissing.into());
+    let cutoff_unmet_table_handling_config =
+      TableHandlingConfig::new(ActiveSonarrBlock::WantedCutoffUnmet.into());
+
+    if !handle_table(
+      self,
+      |app| &mut app.data.sonarr_data.wanted_missing,
+      missing_table_handling_config,
+    ) && !handle_table(
+      self,
+      |app| &mut app.data.sonarr_data.cutoff_unmet,
+      cutoff_unmet_table_handling_config,
+    ) {
+      self.handle_key_event();
+    }
+  }
+
+  fn accepts(active_block: ActiveSonarrBlock) -> bool {
+    WANTED_BLOCKS.contains(&active_block)
+  }
+
+  fn ignore_special_keys(&self) -> bool {
+    self.app.ignore_special_keys_for_textbox_input
+  }
+
+  fn new(
+    key: Key,
+    app: &'a mut App<'b>,
+    active_block: ActiveSonarrBlock,
+    context: Option<ActiveSonarrBlock>,
+  ) -> WantedHandler<'a, 'b> {
+    WantedHandler {
+      key,
+      app,
+      active_sonarr_block: active_block,
+      context,
+    }
+  }
+
+  fn get_key(&self) -> Key {
+    self.key
+  }
+
+  fn is_ready(&self) -> bool {
+    !self.app.is_loading
+  }
+
+  fn handle_scroll_up(&mut self) {}
+
+  fn handle_scroll_down(&mut self) {}
+
+  fn handle_home(&mut self) {}
+
+  fn handle_end(&mut self) {}
+
+  fn handle_delete(&mut self) {}
+
+  fn handle_left_right_action(&mut self) {
+    match self.active_sonarr_block {
+      ActiveSonarrBlock::WantedMissing | ActiveSonarrBlock::WantedCutoffUnmet => {
+        handle_change_tab_left_right_keys(self.app, self.key)
+      }
+      ActiveSonarrBlock::WantedSearchAllPrompt | ActiveSonarrBlock::WantedSearchSelectedPrompt => {
+        handle_prompt_toggle(self.app, self.key)
+      }
+      _ => (),
+    }
+  }
+
+  fn handle_submit(&mut self) {
+    match self.active_sonarr_block {
+      ActiveSonarrBlock::WantedMissing | ActiveSonarrBlock::WantedCutoffUnmet
+        if !self.wanted_table().is_empty() =>
+      {
+        self.app.push_navigation_stack(
+          (
+            ActiveSonarrBlock::WantedSearchSelectedPrompt,
+            Some(self.active_sonarr_block),
+          )
+            .into(),
+        );
+      }
+      ActiveSonarrBlock::WantedSearchAllPrompt | ActiveSonarrBlock::WantedSearchSelectedPrompt => {
+        if self.app.data.sonarr_data.prompt_confirm {
+          self.app.data.sonarr_data.prompt_confirm_action = Some(self.build_search_action());
+        }
+
+        self.app.pop_navigation_stack();
+      }
+      _ => (),
+    }
+  }
+
+  fn handle_esc(&mut self) {
+    match self.active_sonarr_block {
+      ActiveSonarrBlock::WantedSearchAllPrompt | ActiveSonarrBlock::WantedSearchSelectedPrompt => {
+        self.app.pop_navigation_stack();
+        self.app.data.sonarr_data.prompt_confirm = false;
+      }
+      _ => handle_clear_errors(self.app),
+    }
+  }
+
+  fn handle_char_key_event(&mut self) {
+    let key = self.key;
+    match self.active_sonarr_block {
+      ActiveSonarrBlock::WantedMissing | ActiveSonarrBlock::WantedCutoffUnmet => match self.key {
+        _ if matches_key!(toggle_view, key) => {
+          let toggled_block = if self.active_sonarr_block == ActiveSonarrBlock::WantedMissing {
+            ActiveSonarrBlock::WantedCutoffUnmet
+          } else {
+            ActiveSonarrBlock::WantedMissing
+          };
+          self.app.pop_and_push_navigation_stack(toggled_block.into());
+        }
+        _ if matches_key!(auto_search, key) => {
+          self.app.push_navigation_stack(
+            (
+              ActiveSonarrBlock::WantedSearchAllPrompt,
+              Some(self.active_sonarr_block),
+            )
+              .into(),
+          );
+        }
+        _ if matches_key!(refresh, key) => {
+          self.app.should_refresh = true;
+        }
+        _ => (),
+      },
+      ActiveSonarrBlock::WantedSearchAllPrompt | ActiveSonarrBlock::WantedSearchSelectedPrompt => {
+        if matches_key!(confirm, key) {
+          self.app.data.sonarr_data.prompt_confirm = true;
+          self.app.data.sonarr_data.prompt_confirm_action = Some(self.build_search_action());
+
+          self.app.pop_navigation_stack();
+        }
+      }
+      _ => (),
+    }
+  }
+
+  fn app_mut(&mut self) -> &mut App<'b> {
+    self.app
+  }
+
+  fn current_route(&self) -> Route {
+    self.app.get_current_route()
+  }
+}
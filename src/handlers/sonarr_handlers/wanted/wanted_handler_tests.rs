@@ -0,0 +1,477 @@
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+  use rstest::rstest;
+  use strum::IntoEnumIterator;
+
+  use crate::app::App;
+  use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+  use crate::assert_navigation_pushed;
+  use crate::event::Key;
+  use crate::handlers::KeyEventHandler;
+  use crate::handlers::sonarr_handlers::wanted::WantedHandler;
+  use crate::models::servarr_data::sonarr::sonarr_data::{ActiveSonarrBlock, WANTED_BLOCKS};
+  use crate::models::sonarr_models::Episode;
+
+  mod test_handle_left_right_action {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::assert_navigation_pushed;
+
+    #[rstest]
+    fn test_wanted_tab_left(
+      #[values(ActiveSonarrBlock::WantedMissing, ActiveSonarrBlock::WantedCutoffUnmet)]
+      active_sonarr_block: ActiveSonarrBlock,
+      #[values(true, false)] is_ready: bool,
+    ) {
+      let mut app = App::test_default();
+      app.push_navigation_stack(active_sonarr_block.into());
+      app.is_loading = is_ready;
+      app.data.sonarr_data.main_tabs.set_index(11);
+
+      WantedHandler::new(
+        DEFAULT_KEYBINDINGS.left.key,
+        &mut app,
+        active_sonarr_block,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.data.sonarr_data.main_tabs.get_active_route(),
+        ActiveSonarrBlock::Calendar.into()
+      );
+      assert_navigation_pushed!(app, ActiveSonarrBlock::Calendar.into());
+    }
+
+    #[rstest]
+    fn test_wanted_tab_right(
+      #[values(ActiveSonarrBlock::WantedMissing, ActiveSonarrBlock::WantedCutoffUnmet)]
+      active_sonarr_block: ActiveSonarrBlock,
+      #[values(true, false)] is_ready: bool,
+    ) {
+      let mut app = App::test_default();
+      app.push_navigation_stack(active_sonarr_block.into());
+      app.is_loading = is_ready;
+      app.data.sonarr_data.main_tabs.set_index(11);
+
+      WantedHandler::new(
+        DEFAULT_KEYBINDINGS.right.key,
+        &mut app,
+        active_sonarr_block,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.data.sonarr_data.main_tabs.get_active_route(),
+        ActiveSonarrBlock::Series.into()
+      );
+      assert_navigation_pushed!(app, ActiveSonarrBlock::Series.into());
+    }
+
+    #[rstest]
+    fn test_wanted_left_right_prompt_toggle(
+      #[values(
+        ActiveSonarrBlock::WantedSearchAllPrompt,
+        ActiveSonarrBlock::WantedSearchSelectedPrompt
+      )]
+      active_sonarr_block: ActiveSonarrBlock,
+      #[values(DEFAULT_KEYBINDINGS.left.key, DEFAULT_KEYBINDINGS.right.key)] key: Key,
+    ) {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveSonarrBlock::WantedMissing.into());
+
+      WantedHandler::new(key, &mut app, active_sonarr_block, None).handle();
+
+      assert!(app.data.sonarr_data.prompt_confirm);
+
+      WantedHandler::new(key, &mut app, active_sonarr_block, None).handle();
+
+      assert!(!app.data.sonarr_data.prompt_confirm);
+    }
+  }
+
+  mod test_handle_submit {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::assert_navigation_popped;
+    use crate::models::Scrollable;
+    use crate::network::sonarr_network::SonarrEvent;
+
+    const SUBMIT_KEY: Key = DEFAULT_KEYBINDINGS.submit.key;
+
+    #[rstest]
+    fn test_wanted_submit(
+      #[values(ActiveSonarrBlock::WantedMissing, ActiveSonarrBlock::WantedCutoffUnmet)]
+      active_sonarr_block: ActiveSonarrBlock,
+    ) {
+      let mut app = App::test_default();
+      app.data.sonarr_data.wanted_missing.set_items(episodes());
+      app.data.sonarr_data.cutoff_unmet.set_items(episodes());
+      app.push_navigation_stack(active_sonarr_block.into());
+
+      WantedHandler::new(SUBMIT_KEY, &mut app, active_sonarr_block, None).handle();
+
+      assert_navigation_pushed!(
+        app,
+        (
+          ActiveSonarrBlock::WantedSearchSelectedPrompt,
+          Some(active_sonarr_block)
+        )
+          .into()
+      );
+    }
+
+    #[rstest]
+    fn test_wanted_submit_no_op_when_table_is_empty(
+      #[values(ActiveSonarrBlock::WantedMissing, ActiveSonarrBlock::WantedCutoffUnmet)]
+      active_sonarr_block: ActiveSonarrBlock,
+    ) {
+      let mut app = App::test_default();
+      app.push_navigation_stack(active_sonarr_block.into());
+
+      WantedHandler::new(SUBMIT_KEY, &mut app, active_sonarr_block, None).handle();
+
+      assert_eq!(app.get_current_route(), active_sonarr_block.into());
+    }
+
+    #[rstest]
+    #[case(
+      ActiveSonarrBlock::WantedMissing,
+      ActiveSonarrBlock::WantedSearchSelectedPrompt,
+      SonarrEvent::TriggerAutomaticEpisodeSearch(2)
+    )]
+    #[case(
+      ActiveSonarrBlock::WantedCutoffUnmet,
+      ActiveSonarrBlock::WantedSearchSelectedPrompt,
+      SonarrEvent::TriggerAutomaticEpisodeSearch(4)
+    )]
+    #[case(
+      ActiveSonarrBlock::WantedMissing,
+      ActiveSonarrBlock::WantedSearchAllPrompt,
+      SonarrEvent::TriggerMissingEpisodeSearch
+    )]
+    #[case(
+      ActiveSonarrBlock::WantedCutoffUnmet,
+      ActiveSonarrBlock::WantedSearchAllPrompt,
+      SonarrEvent::TriggerCutoffUnmetEpisodeSearch
+    )]
+    fn test_wanted_prompt_confirm_submit(
+      #[case] base_route: ActiveSonarrBlock,
+      #[case] prompt_block: ActiveSonarrBlock,
+      #[case] expected_action: SonarrEvent,
+    ) {
+      let mut app = App::test_default();
+      app.data.sonarr_data.wanted_missing.set_items(episodes());
+      app.data.sonarr_data.wanted_missing.scroll_down();
+      app.data.sonarr_data.cutoff_unmet.set_items(vec![Episode {
+        id: 4,
+        ..Episode::default()
+      }]);
+      app.data.sonarr_data.prompt_confirm = true;
+      app.push_navigation_stack(base_route.into());
+      app.push_navigation_stack((prompt_block, Some(base_route)).into());
+
+      WantedHandler::new(SUBMIT_KEY, &mut app, prompt_block, Some(base_route)).handle();
+
+      assert!(app.data.sonarr_data.prompt_confirm);
+      assert_some_eq_x!(
+        &app.data.sonarr_data.prompt_confirm_action,
+        &expected_action
+      );
+      assert_navigation_popped!(app, base_route.into());
+    }
+
+    #[rstest]
+    fn test_wanted_prompt_decline_submit(
+      #[values(
+        ActiveSonarrBlock::WantedSearchAllPrompt,
+        ActiveSonarrBlock::WantedSearchSelectedPrompt
+      )]
+      prompt_block: ActiveSonarrBlock,
+    ) {
+      let mut app = App::test_default();
+      app.data.sonarr_data.wanted_missing.set_items(episodes());
+      app.push_navigation_stack(ActiveSonarrBlock::WantedMissing.into());
+      app.push_navigation_stack((prompt_block, Some(ActiveSonarrBlock::WantedMissing)).into());
+
+      WantedHandler::new(
+        SUBMIT_KEY,
+        &mut app,
+        prompt_block,
+        Some(ActiveSonarrBlock::WantedMissing),
+      )
+      .handle();
+
+      assert!(!app.data.sonarr_data.prompt_confirm);
+      assert_none!(app.data.sonarr_data.prompt_confirm_action);
+      assert_navigation_popped!(app, ActiveSonarrBlock::WantedMissing.into());
+    }
+  }
+
+  mod test_handle_esc {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::assert_navigation_popped;
+
+    const ESC_KEY: Key = DEFAULT_KEYBINDINGS.esc.key;
+
+    #[rstest]
+    fn test_wanted_prompt_esc(
+      #[values(
+        ActiveSonarrBlock::WantedSearchAllPrompt,
+        ActiveSonarrBlock::WantedSearchSelectedPrompt
+      )]
+      prompt_block: ActiveSonarrBlock,
+    ) {
+      let mut app = App::test_default();
+      app.data.sonarr_data.prompt_confirm = true;
+      app.push_navigation_stack(ActiveSonarrBlock::WantedMissing.into());
+      app.push_navigation_stack((prompt_block, Some(ActiveSonarrBlock::WantedMissing)).into());
+
+      WantedHandler::new(
+        ESC_KEY,
+        &mut app,
+        prompt_block,
+        Some(ActiveSonarrBlock::WantedMissing),
+      )
+      .handle();
+
+      assert_navigation_popped!(app, ActiveSonarrBlock::WantedMissing.into());
+      assert!(!app.data.sonarr_data.prompt_confirm);
+    }
+
+    #[rstest]
+    fn test_wanted_esc_clears_errors(
+      #[values(ActiveSonarrBlock::WantedMissing, ActiveSonarrBlock::WantedCutoffUnmet)]
+      active_sonarr_block: ActiveSonarrBlock,
+    ) {
+      let mut app = App::test_default();
+      app.push_navigation_stack(active_sonarr_block.into());
+      app.error = "test error".to_owned().into();
+
+      WantedHandler::new(ESC_KEY, &mut app, active_sonarr_block, None).handle();
+
+      assert_eq!(app.get_current_route(), active_sonarr_block.into());
+      assert_is_empty!(app.error.text);
+    }
+  }
+
+  mod test_handle_key_char {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::assert_navigation_popped;
+    use crate::network::sonarr_network::SonarrEvent;
+
+    #[rstest]
+    #[case(ActiveSonarrBlock::WantedMissing, ActiveSonarrBlock::WantedCutoffUnmet)]
+    #[case(ActiveSonarrBlock::WantedCutoffUnmet, ActiveSonarrBlock::WantedMissing)]
+    fn test_toggle_view_key(
+      #[case] active_sonarr_block: ActiveSonarrBlock,
+      #[case] expected_sonarr_block: ActiveSonarrBlock,
+    ) {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveSonarrBlock::Series.into());
+      app.push_navigation_stack(active_sonarr_block.into());
+
+      WantedHandler::new(
+        DEFAULT_KEYBINDINGS.toggle_view.key,
+        &mut app,
+        active_sonarr_block,
+        None,
+      )
+      .handle();
+
+      assert_navigation_pushed!(app, expected_sonarr_block.into());
+      app.pop_navigation_stack();
+      assert_eq!(app.get_current_route(), ActiveSonarrBlock::Series.into());
+    }
+
+    #[rstest]
+    fn test_search_all_key(
+      #[values(ActiveSonarrBlock::WantedMissing, ActiveSonarrBlock::WantedCutoffUnmet)]
+      active_sonarr_block: ActiveSonarrBlock,
+    ) {
+      let mut app = App::test_default();
+      app.push_navigation_stack(active_sonarr_block.into());
+
+      WantedHandler::new(
+        DEFAULT_KEYBINDINGS.auto_search.key,
+        &mut app,
+        active_sonarr_block,
+        None,
+      )
+      .handle();
+
+      assert_navigation_pushed!(
+        app,
+        (
+          ActiveSonarrBlock::WantedSearchAllPrompt,
+          Some(active_sonarr_block)
+        )
+          .into()
+      );
+    }
+
+    #[test]
+    fn test_search_all_key_no_op_when_not_ready() {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.push_navigation_stack(ActiveSonarrBlock::WantedMissing.into());
+
+      WantedHandler::new(
+        DEFAULT_KEYBINDINGS.auto_search.key,
+        &mut app,
+        ActiveSonarrBlock::WantedMissing,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.get_current_route(),
+        ActiveSonarrBlock::WantedMissing.into()
+      );
+    }
+
+    #[rstest]
+    fn test_refresh_wanted_key(
+      #[values(ActiveSonarrBlock::WantedMissing, ActiveSonarrBlock::WantedCutoffUnmet)]
+      active_sonarr_block: ActiveSonarrBlock,
+    ) {
+      let mut app = App::test_default();
+      app.push_navigation_stack(active_sonarr_block.into());
+
+      WantedHandler::new(
+        DEFAULT_KEYBINDINGS.refresh.key,
+        &mut app,
+        active_sonarr_block,
+        None,
+      )
+      .handle();
+
+      assert_eq!(app.get_current_route(), active_sonarr_block.into());
+      assert!(app.should_refresh);
+    }
+
+    #[rstest]
+    #[case(
+      ActiveSonarrBlock::WantedMissing,
+      ActiveSonarrBlock::WantedSearchSelectedPrompt,
+      SonarrEvent::TriggerAutomaticEpisodeSearch(1)
+    )]
+    #[case(
+      ActiveSonarrBlock::WantedCutoffUnmet,
+      ActiveSonarrBlock::WantedSearchAllPrompt,
+      SonarrEvent::TriggerCutoffUnmetEpisodeSearch
+    )]
+    fn test_wanted_prompt_confirm(
+      #[case] base_route: ActiveSonarrBlock,
+      #[case] prompt_block: ActiveSonarrBlock,
+      #[case] expected_action: SonarrEvent,
+    ) {
+      let mut app = App::test_default();
+      app.data.sonarr_data.wanted_missing.set_items(episodes());
+      app.push_navigation_stack(base_route.into());
+      app.push_navigation_stack((prompt_block, Some(base_route)).into());
+
+      WantedHandler::new(
+        DEFAULT_KEYBINDINGS.confirm.key,
+        &mut app,
+        prompt_block,
+        Some(base_route),
+      )
+      .handle();
+
+      assert!(app.data.sonarr_data.prompt_confirm);
+      assert_some_eq_x!(
+        &app.data.sonarr_data.prompt_confirm_action,
+        &expected_action
+      );
+      assert_navigation_popped!(app, base_route.into());
+    }
+  }
+
+  #[test]
+  fn test_wanted_handler_accepts() {
+    ActiveSonarrBlock::iter().for_each(|active_sonarr_block| {
+      if WANTED_BLOCKS.contains(&active_sonarr_block) {
+        assert!(WantedHandler::accepts(active_sonarr_block));
+      } else {
+        assert!(!WantedHandler::accepts(active_sonarr_block));
+      }
+    })
+  }
+
+  #[rstest]
+  fn test_wanted_handler_ignore_special_keys(
+    #[values(true, false)] ignore_special_keys_for_textbox_input: bool,
+  ) {
+    let mut app = App::test_default();
+    app.ignore_special_keys_for_textbox_input = ignore_special_keys_for_textbox_input;
+    let handler = WantedHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveSonarrBlock::default(),
+      None,
+    );
+
+    assert_eq!(
+      handler.ignore_special_keys(),
+      ignore_special_keys_for_textbox_input
+    );
+  }
+
+  #[test]
+  fn test_wanted_handler_not_ready_when_loading() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveSonarrBlock::WantedMissing.into());
+    app.is_loading = true;
+
+    let handler = WantedHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveSonarrBlock::WantedMissing,
+      None,
+    );
+
+    assert!(!handler.is_ready());
+  }
+
+  #[test]
+  fn test_wanted_handler_ready_when_not_loading() {
+    let mut app = App::test_default();
+    app.push_navigation_stack(ActiveSonarrBlock::WantedMissing.into());
+    app.is_loading = false;
+
+    let handler = WantedHandler::new(
+      DEFAULT_KEYBINDINGS.esc.key,
+      &mut app,
+      ActiveSonarrBlock::WantedMissing,
+      None,
+    );
+
+    assert!(handler.is_ready());
+  }
+
+  fn episodes() -> Vec<Episode> {
+    vec![
+      Episode {
+        id: 1,
+        ..Episode::default()
+      },
+      Episode {
+        id: 2,
+        ..Episode::default()
+      },
+    ]
+  }
+}
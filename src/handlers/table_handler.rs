@@ -1,5 +1,5 @@
 use crate::app::App;
-use crate::app::key_binding::DEFAULT_KEYBINDINGS;
+use crate::app::key_binding::keybindings;
 use crate::event::Key;
 use crate::matches_key;
 use crate::models::stateful_table::{SortOption, StatefulTable};
@@ -33,6 +33,8 @@ where
   pub filter_error_block: Option<Route>,
   #[setters(strip_option)]
   pub filter_field_fn: Option<fn(&T) -> &str>,
+  #[setters(strip_option)]
+  pub multi_select_id_fn: Option<fn(&T) -> i64>,
   #[setters(skip)]
   pub table_block: Route,
 }
@@ -52,6 +54,7 @@ where
       filtering_block: None,
       filter_error_block: None,
       filter_field_fn: None,
+      multi_select_id_fn: None,
     }
   }
 }
@@ -126,6 +129,13 @@ where
         self.handle_search_key()
       }
       _ if matches_key!(sort, key) && config.sorting_block.is_some() => self.handle_sort_key(),
+      _ if config.multi_select_id_fn.is_some()
+        && (matches_key!(select, key)
+          || matches_key!(select_all, key)
+          || matches_key!(visual_select, key)) =>
+      {
+        self.handle_multi_select_keys()
+      }
       _ => false,
     }
   }
@@ -280,9 +290,9 @@ where
         && current_route == *config.searching_block.as_ref().unwrap() =>
       {
         if let Some(ref mut search) = self.table_mut().search {
-          if key == DEFAULT_KEYBINDINGS.left.key {
+          if key == keybindings().left.key {
             search.scroll_left();
-          } else if key == DEFAULT_KEYBINDINGS.right.key {
+          } else if key == keybindings().right.key {
             search.scroll_right();
           }
         }
@@ -292,9 +302,9 @@ where
         && current_route == *config.filtering_block.as_ref().unwrap() =>
       {
         if let Some(ref mut filter) = self.table_mut().filter {
-          if key == DEFAULT_KEYBINDINGS.left.key {
+          if key == keybindings().left.key {
             filter.scroll_left();
-          } else if key == DEFAULT_KEYBINDINGS.right.key {
+          } else if key == keybindings().right.key {
             filter.scroll_right();
           }
         }
@@ -372,6 +382,7 @@ where
     let filtering_block = config.filtering_block;
     let filter_error_block = config.filter_error_block;
     let table_block = config.table_block;
+    let multi_select = config.multi_select_id_fn.is_some();
 
     match current_route {
       _ if sorting_block.is_some() && current_route == *sorting_block.as_ref().unwrap() => {
@@ -396,6 +407,13 @@ where
         self.app_mut().ignore_special_keys_for_textbox_input = false;
         true
       }
+      _ if table_block == current_route
+        && multi_select
+        && self.table_mut().has_multi_selection() =>
+      {
+        self.table_mut().clear_multi_selection();
+        true
+      }
       _ if table_block == current_route && self.table_mut().filtered_items.is_some() => {
         self.table_mut().reset_filter();
         true
@@ -499,6 +517,31 @@ where
     self.app_mut().push_navigation_stack(sorting_block);
     true
   }
+
+  fn handle_multi_select_keys(&mut self) -> bool {
+    let config = self.config();
+    let current_route = self.current_route();
+    let key = self.key();
+
+    if current_route != config.table_block {
+      return false;
+    }
+
+    let Some(id_fn) = config.multi_select_id_fn else {
+      return false;
+    };
+
+    let table = self.table_mut();
+    if matches_key!(select, key) {
+      table.toggle_current_selection(id_fn);
+    } else if matches_key!(select_all, key) {
+      table.toggle_select_all(id_fn);
+    } else {
+      table.toggle_visual_select(id_fn);
+    }
+
+    true
+  }
 }
 
 /// Adapter struct that implements `TableEventHandler` for any `KeyEventHandler`.
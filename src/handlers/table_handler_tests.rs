@@ -33,7 +33,8 @@ mod tests {
         .search_field_fn(|movie| &movie.title.text)
         .filtering_block(ActiveRadarrBlock::FilterMovies.into())
         .filter_error_block(ActiveRadarrBlock::FilterMoviesError.into())
-        .filter_field_fn(|movie| &movie.title.text);
+        .filter_field_fn(|movie| &movie.title.text)
+        .multi_select_id_fn(|movie| movie.id);
       let minimal_movie_table_handling_config =
         TableHandlingConfig::new(ActiveRadarrBlock::Movies.into());
 
@@ -1006,6 +1007,25 @@ mod tests {
       assert_none!(app.data.radarr_data.movies.filtered_items);
       assert_none!(app.data.radarr_data.movies.filtered_state);
     }
+
+    #[test]
+    fn test_table_block_esc_clears_multi_selection_before_filter() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
+      app.data.radarr_data.movies.set_items(movies_vec());
+      app.data.radarr_data.movies.set_filtered_items(movies_vec());
+      app
+        .data
+        .radarr_data
+        .movies
+        .toggle_current_selection(|movie| movie.id);
+
+      TableHandlerUnit::new(ESC_KEY, &mut app, ActiveRadarrBlock::Movies, None).handle();
+
+      assert_eq!(app.get_current_route(), ActiveRadarrBlock::Movies.into());
+      assert!(!app.data.radarr_data.movies.has_multi_selection());
+      assert_some!(app.data.radarr_data.movies.filtered_items);
+    }
   }
 
   mod test_handle_key_char {
@@ -1353,6 +1373,126 @@ mod tests {
       assert_eq!(app.get_current_route(), ActiveRadarrBlock::Movies.into());
       assert_modal_absent!(app.data.radarr_data.movies.sort);
     }
+
+    #[test]
+    fn test_select_key() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
+      app.data.radarr_data.movies.set_items(movies_vec());
+
+      TableHandlerUnit::new(
+        DEFAULT_KEYBINDINGS.select.key,
+        &mut app,
+        ActiveRadarrBlock::Movies,
+        None,
+      )
+      .handle();
+
+      assert_eq!(
+        app.data.radarr_data.movies.multi_selection(),
+        vec![movies_vec()[0].clone()]
+      );
+    }
+
+    #[test]
+    fn test_select_all_key() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
+      app.data.radarr_data.movies.set_items(movies_vec());
+
+      TableHandlerUnit::new(
+        DEFAULT_KEYBINDINGS.select_all.key,
+        &mut app,
+        ActiveRadarrBlock::Movies,
+        None,
+      )
+      .handle();
+
+      assert_eq!(app.data.radarr_data.movies.multi_selection(), movies_vec());
+    }
+
+    #[test]
+    fn test_visual_select_key() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
+      app.data.radarr_data.movies.set_items(movies_vec());
+
+      TableHandlerUnit::new(
+        DEFAULT_KEYBINDINGS.visual_select.key,
+        &mut app,
+        ActiveRadarrBlock::Movies,
+        None,
+      )
+      .handle();
+      TableHandlerUnit::new(
+        DEFAULT_KEYBINDINGS.down.key,
+        &mut app,
+        ActiveRadarrBlock::Movies,
+        None,
+      )
+      .handle();
+
+      assert_some_eq_x!(app.data.radarr_data.movies.visual_select_anchor, 0);
+      assert_eq!(
+        app.data.radarr_data.movies.multi_selection(),
+        movies_vec()[..2].to_vec()
+      );
+
+      TableHandlerUnit::new(
+        DEFAULT_KEYBINDINGS.visual_select.key,
+        &mut app,
+        ActiveRadarrBlock::Movies,
+        None,
+      )
+      .handle();
+
+      assert_none!(app.data.radarr_data.movies.visual_select_anchor);
+      assert_eq!(
+        app.data.radarr_data.movies.multi_selection(),
+        movies_vec()[..2].to_vec()
+      );
+    }
+
+    #[rstest]
+    fn test_multi_select_keys_no_op_when_multi_select_is_disabled(
+      #[values(
+        DEFAULT_KEYBINDINGS.select.key,
+        DEFAULT_KEYBINDINGS.select_all.key,
+        DEFAULT_KEYBINDINGS.visual_select.key
+      )]
+      key: Key,
+    ) {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
+      app.data.radarr_data.movies.set_items(movies_vec());
+
+      TableHandlerUnit::new(key, &mut app, ActiveRadarrBlock::MovieDetails, None).handle();
+
+      assert!(!app.data.radarr_data.movies.has_multi_selection());
+    }
+
+    #[test]
+    fn test_select_key_in_filter_box_is_treated_as_input() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveRadarrBlock::Movies.into());
+      app.push_navigation_stack(ActiveRadarrBlock::FilterMovies.into());
+      app.data.radarr_data.movies.set_items(movies_vec());
+      app.data.radarr_data.movies.filter = Some(HorizontallyScrollableText::default());
+
+      TableHandlerUnit::new(
+        DEFAULT_KEYBINDINGS.select.key,
+        &mut app,
+        ActiveRadarrBlock::FilterMovies,
+        None,
+      )
+      .handle();
+
+      assert_str_eq!(
+        app.data.radarr_data.movies.filter.as_ref().unwrap().text,
+        " "
+      );
+      assert!(!app.data.radarr_data.movies.has_multi_selection());
+    }
   }
 
   fn movies_vec() -> Vec<Movie> {
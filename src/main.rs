@@ -11,12 +11,13 @@ use crossterm::execute;
 use crossterm::terminal::{
   EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode,
 };
+use futures_util::StreamExt;
+use futures_util::stream::FuturesUnordered;
 use indoc::indoc;
-use log::{debug, error, warn};
+use log::{debug, error, info, warn};
 use network::NetworkTrait;
 use ratatui::Terminal;
 use ratatui::backend::CrosstermBackend;
-use reqwest::Client;
 use std::panic::PanicHookInfo;
 use std::path::PathBuf;
 use std::sync::Arc;
@@ -27,14 +28,23 @@ use tokio::sync::mpsc::Receiver;
 use tokio::sync::{Mutex, mpsc};
 use tokio_util::sync::CancellationToken;
 use utils::{
-  build_network_client, load_config, start_cli_no_spinner, start_cli_with_spinner, tail_logs,
+  config_validation_report, load_config, start_cli_no_spinner, start_cli_with_spinner, tail_logs,
+  validate_config_file,
 };
 
+use crate::app::config_watcher::ConfigWatcher;
+use crate::app::key_binding::{KeyBindings, set_keybindings};
 use crate::app::{App, log_and_print_error};
-use crate::cli::Command;
+use crate::cli::output::{OutputFormat, OutputOptions};
+use crate::cli::{Command, ConfigCommand};
 use crate::event::Key;
 use crate::event::input_event::{Events, InputEvent};
-use crate::network::{Network, NetworkEvent};
+use crate::network::demo_network::demo_config;
+use crate::network::event_queue::{MAX_CONCURRENT_NETWORK_EVENTS, NetworkEventQueue};
+use crate::network::recording::{Recorder, Recording, Replayer};
+use crate::network::servarr_error::ServarrError;
+use crate::network::signalr::SignalRSubscribers;
+use crate::network::{Network, QueuedNetworkEvent};
 use crate::ui::theme::{Theme, ThemeDefinitionsWrapper};
 use crate::ui::{THEME, ui};
 use crate::utils::load_theme_config;
@@ -67,6 +77,12 @@ mod utils;
 struct Cli {
   #[command(subcommand)]
   command: Option<Command>,
+  #[arg(
+    long,
+    conflicts_with_all = ["record", "replay"],
+    help = "Launch the TUI in an offline demo mode that uses built-in sample data instead of any configured Servarrs"
+  )]
+  demo: bool,
   #[command(flatten)]
   global: GlobalOpts,
 }
@@ -116,6 +132,45 @@ struct GlobalOpts {
     "}
   )]
   servarr_name: Option<String>,
+  #[arg(
+    long,
+    global = true,
+    value_enum,
+    default_value_t = OutputFormat::Json,
+    env = "MANAGARR_OUTPUT",
+    help = "The format to print CLI command output in"
+  )]
+  output: OutputFormat,
+  #[arg(
+    long,
+    global = true,
+    value_delimiter = ',',
+    help = indoc!{"
+      A comma-separated list of the fields to include in CLI command output (e.g. 'title,year,quality.quality.name').
+
+      Nested fields are selected using dot-separated paths.
+    "}
+  )]
+  fields: Vec<String>,
+  #[arg(
+    long,
+    global = true,
+    value_parser,
+    conflicts_with = "replay",
+    help = indoc!{"
+      Record every request made to the Servarrs, along with its response, to the given directory.
+
+      API keys and the values of any custom headers are redacted from the recordings.
+    "}
+  )]
+  record: Option<PathBuf>,
+  #[arg(
+    long,
+    global = true,
+    value_parser,
+    help = "Replay the responses recorded with '--record' from the given directory instead of contacting the Servarrs"
+  )]
+  replay: Option<PathBuf>,
 }
 
 #[tokio::main]
@@ -127,6 +182,7 @@ async fn main() -> Result<()> {
   let running = Arc::new(AtomicBool::new(true));
   let r = running.clone();
   let args = Cli::parse();
+  let demo = args.demo;
   let config_file_path = confy::get_configuration_file_path("managarr", "config")?;
   let default_config_path = config_file_path.display().to_string();
 
@@ -135,7 +191,38 @@ async fn main() -> Result<()> {
     return Ok(());
   }
 
-  let (mut config, config_path) = if let Some(ref config_file) = args.global.config_file {
+  if let Some(Command::Config(ConfigCommand::Validate { json })) = args.command {
+    let config_path = args
+      .global
+      .config_file
+      .as_ref()
+      .map_or(default_config_path, |config_file| {
+        config_file.display().to_string()
+      });
+    let result = validate_config_file(&config_path);
+    println!("{}", config_validation_report(&config_path, &result, json));
+
+    process::exit(if result.is_ok() { 0 } else { 1 });
+  }
+
+  if demo && args.command.is_some() {
+    log_and_print_error("The '--demo' flag can only be used with the TUI".to_owned());
+    process::exit(1);
+  }
+
+  let recording = if let Some(replay_dir) = &args.global.replay {
+    Some(Recording::Replay(Replayer::load(replay_dir)?))
+  } else if let Some(record_dir) = &args.global.record {
+    Some(Recording::Record(Recorder::new(record_dir)?))
+  } else {
+    None
+  };
+
+  let (mut config, config_path) = if demo {
+    (demo_config(), String::new())
+  } else if let Some(Recording::Replay(replayer)) = &recording {
+    (replayer.config(), String::new())
+  } else if let Some(ref config_file) = args.global.config_file {
     (
       load_config(config_file.to_str().expect("Invalid config file specified"))?,
       config_file.display().to_string(),
@@ -148,12 +235,16 @@ async fn main() -> Result<()> {
     )
   };
   let theme_name = config.theme.clone();
+  let keybindings = config.keybindings();
   let spinner_disabled = args.global.disable_spinner;
+  let output_options = OutputOptions {
+    format: args.global.output,
+    fields: args.global.fields,
+  };
   debug!("Managarr loaded using config: {config:?}");
   config.validate(&config_path);
   config.post_process_initialization();
 
-  let reqwest_client = build_network_client(&config);
   let (sync_network_tx, sync_network_rx) = mpsc::channel(500);
   let cancellation_token = CancellationToken::new();
   let ctrlc_cancellation_token = cancellation_token.clone();
@@ -165,19 +256,45 @@ async fn main() -> Result<()> {
   })
   .expect("Error setting Ctrl-C handler");
 
-  let app = Arc::new(Mutex::new(App::new(
-    sync_network_tx,
+  let app = match App::new(
+    sync_network_tx.clone(),
     config.clone(),
     cancellation_token.clone(),
-  )));
+  ) {
+    Ok(app) => Arc::new(Mutex::new(app)),
+    Err(e) => {
+      log_and_print_error(e.to_string());
+      process::exit(1);
+    }
+  };
 
   match args.command {
     Some(command) => match command {
-      Command::Radarr(_) | Command::Sonarr(_) | Command::Lidarr(_) => {
+      Command::Radarr(_)
+      | Command::Sonarr(_)
+      | Command::Lidarr(_)
+      | Command::Prowlarr(_)
+      | Command::Readarr(_) => {
         if spinner_disabled {
-          start_cli_no_spinner(config, reqwest_client, cancellation_token, app, command).await;
+          start_cli_no_spinner(
+            config,
+            cancellation_token,
+            app,
+            command,
+            output_options,
+            recording,
+          )
+          .await;
         } else {
-          start_cli_with_spinner(config, reqwest_client, cancellation_token, app, command).await;
+          start_cli_with_spinner(
+            config,
+            cancellation_token,
+            app,
+            command,
+            output_options,
+            recording,
+          )
+          .await;
         }
       }
       Command::Completions { shell } => {
@@ -190,16 +307,33 @@ async fn main() -> Result<()> {
           "ConfigPath command is handled before this match and should be unreachable here"
         );
       }
+      Command::Config(_) => {
+        unreachable!(
+          "Config commands are handled before this match and should be unreachable here"
+        );
+      }
     },
     None => {
       let app_nw = Arc::clone(&app);
+      let config_watcher = ConfigWatcher::new(&config_path, &config);
+      let mut signalr_subscribers = SignalRSubscribers::new(app.clone(), sync_network_tx.clone());
+      signalr_subscribers.reconcile(&mut *app.lock().await);
       std::thread::spawn(move || {
-        start_networking(sync_network_rx, &app_nw, cancellation_token, reqwest_client)
+        start_networking(
+          sync_network_rx,
+          &app_nw,
+          cancellation_token,
+          demo,
+          recording,
+        )
       });
       start_ui(
         &app,
         &args.global.themes_file,
         args.global.theme.unwrap_or(theme_name.unwrap_or_default()),
+        keybindings,
+        config_watcher,
+        signalr_subscribers,
       )
       .await?;
     }
@@ -210,18 +344,50 @@ async fn main() -> Result<()> {
 
 #[tokio::main]
 async fn start_networking(
-  mut network_rx: Receiver<NetworkEvent>,
+  mut network_rx: Receiver<QueuedNetworkEvent>,
   app: &Arc<Mutex<App<'_>>>,
   cancellation_token: CancellationToken,
-  client: Client,
+  demo: bool,
+  recording: Option<Recording>,
 ) {
-  let mut network = Network::new(app, cancellation_token, client);
+  let mut network = if demo {
+    Network::demo(app, cancellation_token)
+  } else {
+    Network::new(app, cancellation_token).with_recording(recording)
+  };
+
+  let mut network_event_queue = NetworkEventQueue::new(MAX_CONCURRENT_NETWORK_EVENTS);
+  let mut running_events = FuturesUnordered::new();
 
   loop {
+    while let Some(queued_event) = network_event_queue.next_ready() {
+      let mut worker = network.clone();
+      running_events.push(async move {
+        let result = async {
+          worker.target(&queued_event.instance).await?;
+          worker
+            .handle_network_event(queued_event.network_event.clone())
+            .await
+        }
+        .await;
+        (queued_event, result)
+      });
+    }
+
     select! {
-      Some(network_event) = network_rx.recv() => {
-        if let Err(e) = network.handle_network_event(network_event).await {
-          error!("Encountered an error handling network event: {e:?}");
+      Some(queued_event) = network_rx.recv() => {
+        if !network_event_queue.push(queued_event.clone()) {
+          debug!("Coalesced duplicate network event: {queued_event:?}");
+        }
+      }
+      Some((queued_event, result)) = running_events.next() => {
+        network_event_queue.finish(&queued_event);
+        match result {
+          Err(e) if matches!(e.downcast_ref(), Some(ServarrError::Cancelled)) => {
+            debug!("Cancelled network event: {queued_event:?}");
+          }
+          Err(e) => error!("Encountered an error handling network event: {e:?}"),
+          Ok(_) => (),
         }
       }
       _ = network.cancellation_token.cancelled() => {
@@ -229,6 +395,7 @@ async fn start_networking(
         while network_rx.try_recv().is_ok() {
           // Discard the message
         }
+        network_event_queue.clear();
         network.reset_cancellation_token().await;
       }
     }
@@ -239,6 +406,9 @@ async fn start_ui(
   app: &Arc<Mutex<App<'_>>>,
   themes_file_arg: &Option<PathBuf>,
   theme_name: String,
+  keybindings: KeyBindings,
+  mut config_watcher: ConfigWatcher,
+  mut signalr_subscribers: SignalRSubscribers,
 ) -> Result<()> {
   let theme_definitions_wrapper = if let Some(theme_file) = themes_file_arg {
     load_theme_config(theme_file.to_str().expect("Invalid theme file specified"))?
@@ -264,6 +434,7 @@ async fn start_ui(
   debug!("Managarr loaded using theme: {theme:?}");
   theme.validate();
   THEME.set(theme);
+  set_keybindings(keybindings);
 
   let mut stdout = io::stdout();
   enable_raw_mode()?;
@@ -283,14 +454,21 @@ async fn start_ui(
 
     match input_events.next()? {
       Some(InputEvent::KeyEvent(key)) => {
-        if key == Key::Char('q') && !app.ignore_special_keys_for_textbox_input {
+        if matches_key!(quit, key) && !app.ignore_special_keys_for_textbox_input {
           break;
         }
 
         handlers::handle_events(key, &mut app);
       }
 
-      Some(InputEvent::Tick) => app.on_tick().await,
+      Some(InputEvent::Tick) => {
+        if config_watcher.poll() {
+          reload_config(&mut app, &mut config_watcher);
+          signalr_subscribers.reconcile(&mut app);
+        }
+
+        app.on_tick().await
+      }
       _ => {}
     }
   }
@@ -303,6 +481,25 @@ async fn start_ui(
   Ok(())
 }
 
+fn reload_config(app: &mut App<'_>, config_watcher: &mut ConfigWatcher) {
+  let reloaded_config = config_watcher.reload().and_then(|config| {
+    let keybindings = config.keybindings();
+    app.reload_config(config)?;
+    Ok(keybindings)
+  });
+
+  match reloaded_config {
+    Ok(keybindings) => {
+      info!("Reloaded the Managarr configuration");
+      set_keybindings(keybindings);
+    }
+    Err(e) => {
+      error!("Unable to reload the Managarr configuration: {e:?}");
+      app.error = format!("Unable to reload configuration: {e:#}").into();
+    }
+  }
+}
+
 #[cfg(debug_assertions)]
 fn panic_hook(info: &PanicHookInfo<'_>) {
   use backtrace::Backtrace;
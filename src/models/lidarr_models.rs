@@ -1,11 +1,13 @@
 use super::{
   HorizontallyScrollableText, Serdeable,
   servarr_models::{
-    DiskSpace, HostConfig, Indexer, IndexerTestResult, QualityProfile, QualityWrapper, RootFolder,
-    SecurityConfig, Tag,
+    ApplyTags, Backup, DiskSpace, DownloadClient, HostConfig, Indexer, IndexerTestResult,
+    QualityProfile, QualityWrapper, RootFolder, SecurityConfig, Tag,
   },
 };
-use crate::models::servarr_models::{IndexerSettings, LogResponse, QueueEvent, Update};
+use crate::models::servarr_models::{
+  IndexerSettings, LogResponse, ManualImportRejection, MediaManagementSettings, QueueEvent, Update,
+};
 use crate::serde_enum_from;
 use chrono::{DateTime, Utc};
 use clap::ValueEnum;
@@ -190,10 +192,80 @@ pub struct DownloadRecord {
   #[serde(default)]
   pub indexer: String,
   pub download_client: Option<String>,
+  pub download_id: Option<String>,
+  pub estimated_completion_time: Option<DateTime<Utc>>,
+}
+
+impl DownloadRecord {
+  /// Whether this is a release held back by Lidarr (e.g. by a delay profile) that hasn't been
+  /// sent to a download client yet
+  pub fn is_pending(&self) -> bool {
+    matches!(
+      self.status,
+      DownloadStatus::Delay | DownloadStatus::DownloadClientUnavailable | DownloadStatus::Fallback
+    )
+  }
 }
 
 impl Eq for DownloadRecord {}
 
+#[derive(Default, Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct LidarrManualImportItem {
+  #[serde(deserialize_with = "super::from_i64")]
+  pub id: i64,
+  pub path: String,
+  #[serde(default)]
+  pub name: String,
+  #[serde(deserialize_with = "super::from_i64")]
+  pub size: i64,
+  pub artist: Option<Artist>,
+  pub album: Option<Album>,
+  pub album_release_id: Option<i64>,
+  #[serde(default)]
+  pub tracks: Vec<Track>,
+  pub quality: Value,
+  pub release_group: Option<String>,
+  pub download_id: Option<String>,
+  #[serde(default)]
+  pub rejections: Vec<ManualImportRejection>,
+}
+
+impl LidarrManualImportItem {
+  pub fn import_file(&self) -> Option<LidarrManualImportFile> {
+    if !self.rejections.is_empty() || self.tracks.is_empty() {
+      return None;
+    }
+
+    let artist = self.artist.as_ref()?;
+    let album = self.album.as_ref()?;
+
+    Some(LidarrManualImportFile {
+      path: self.path.clone(),
+      artist_id: artist.id,
+      album_id: album.id,
+      album_release_id: self.album_release_id,
+      track_ids: self.tracks.iter().map(|track| track.id).collect(),
+      quality: self.quality.clone(),
+      release_group: self.release_group.clone(),
+      download_id: self.download_id.clone(),
+    })
+  }
+}
+
+#[derive(Default, Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct LidarrManualImportFile {
+  pub path: String,
+  pub artist_id: i64,
+  pub album_id: i64,
+  pub album_release_id: Option<i64>,
+  pub track_ids: Vec<i64>,
+  pub quality: Value,
+  pub release_group: Option<String>,
+  pub download_id: Option<String>,
+}
+
 #[derive(
   Serialize,
   Deserialize,
@@ -268,6 +340,32 @@ pub struct DeleteParams {
   pub add_import_list_exclusion: bool,
 }
 
+#[derive(Default, Clone, Serialize, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct BulkDeleteArtistsParams {
+  pub artist_ids: Vec<i64>,
+  pub delete_files: bool,
+  pub add_import_list_exclusion: bool,
+}
+
+#[derive(Default, Clone, Serialize, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct BulkEditArtistsParams {
+  pub artist_ids: Vec<i64>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub monitored: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub quality_profile_id: Option<i64>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub root_folder_path: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub tags: Option<Vec<i64>>,
+  #[serde(skip_serializing, skip_deserializing)]
+  pub tag_input_string: Option<String>,
+  pub apply_tags: ApplyTags,
+  pub move_files: bool,
+}
+
 #[derive(Default, Clone, Serialize, Debug, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct AddArtistBody {
@@ -326,6 +424,8 @@ pub struct Album {
   #[serde(deserialize_with = "super::from_i64")]
   pub id: i64,
   pub title: HorizontallyScrollableText,
+  #[serde(default, deserialize_with = "super::from_i64")]
+  pub artist_id: i64,
   pub foreign_album_id: String,
   pub monitored: bool,
   #[serde(default)]
@@ -358,6 +458,11 @@ pub struct AlbumStatistics {
 
 impl Eq for AlbumStatistics {}
 
+#[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq, Eq)]
+pub struct WantedAlbumsResponse {
+  pub records: Vec<Album>,
+}
+
 #[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct LidarrHistoryWrapper {
@@ -535,6 +640,26 @@ pub struct TrackFile {
   pub audio_tags: Option<AudioTags>,
 }
 
+#[derive(Default, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct NamingConfig {
+  #[serde(deserialize_with = "super::from_i64")]
+  pub id: i64,
+  pub rename_tracks: bool,
+  pub replace_illegal_characters: bool,
+  pub standard_track_format: Option<String>,
+  pub multi_disc_track_format: Option<String>,
+  pub artist_folder_format: Option<String>,
+}
+
+#[derive(Default, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct NamingExamples {
+  pub single_track_example: Option<String>,
+  pub multi_disc_track_example: Option<String>,
+  pub artist_folder_example: Option<String>,
+}
+
 #[derive(Serialize, Deserialize, Derivative, Debug, Clone, PartialEq, Eq)]
 #[derivative(Default)]
 #[serde(rename_all = "camelCase")]
@@ -596,6 +721,7 @@ serde_enum_from!(
     Album(Album),
     Artist(Artist),
     Artists(Vec<Artist>),
+    Backups(Vec<Backup>),
     BlocklistResponse(BlocklistResponse),
     DiskSpaces(Vec<DiskSpace>),
     DownloadsResponse(DownloadsResponse),
@@ -603,10 +729,14 @@ serde_enum_from!(
     LidarrHistoryItems(Vec<LidarrHistoryItem>),
     HostConfig(HostConfig),
     IndexerSettings(IndexerSettings),
+    DownloadClients(Vec<DownloadClient>),
     Indexers(Vec<Indexer>),
     IndexerTestResults(Vec<IndexerTestResult>),
     LogResponse(LogResponse),
+    MediaManagementSettings(MediaManagementSettings<NamingConfig>),
+    NamingExamples(NamingExamples),
     MetadataProfiles(Vec<MetadataProfile>),
+    QualityProfile(QualityProfile),
     QualityProfiles(Vec<QualityProfile>),
     QueueEvents(Vec<QueueEvent>),
     Releases(Vec<LidarrRelease>),
@@ -621,5 +751,7 @@ serde_enum_from!(
     TrackFiles(Vec<TrackFile>),
     Updates(Vec<Update>),
     Value(Value),
+    WantedAlbumsResponse(WantedAlbumsResponse),
+    ManualImportItems(Vec<LidarrManualImportItem>),
   }
 );
@@ -8,11 +8,13 @@ mod tests {
     AddArtistSearchResult, Album, AudioTags, BlocklistItem, BlocklistResponse, DownloadRecord,
     DownloadStatus, DownloadsResponse, LidarrHistoryEventType, LidarrHistoryItem,
     LidarrHistoryWrapper, LidarrRelease, LidarrTask, MediaInfo, Member, MetadataProfile,
-    MonitorType, NewItemMonitorType, SystemStatus, Track, TrackFile,
+    MonitorType, NamingConfig, NamingExamples, NewItemMonitorType, SystemStatus, Track, TrackFile,
+    WantedAlbumsResponse,
   };
   use crate::models::servarr_models::{
-    DiskSpace, HostConfig, Indexer, IndexerSettings, IndexerTestResult, Log, LogResponse,
-    QualityProfile, QueueEvent, RootFolder, SecurityConfig, Tag, Update,
+    Backup, DiskSpace, HostConfig, Indexer, IndexerSettings, IndexerTestResult, Log, LogResponse,
+    MediaManagementConfig, MediaManagementSettings, QualityProfile, QueueEvent, RootFolder,
+    SecurityConfig, Tag, Update,
   };
   use crate::models::{
     Serdeable,
@@ -293,6 +295,18 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_lidarr_serdeable_from_backups() {
+    let backups = vec![Backup {
+      id: 1,
+      ..Backup::default()
+    }];
+
+    let lidarr_serdeable: LidarrSerdeable = backups.clone().into();
+
+    assert_eq!(lidarr_serdeable, LidarrSerdeable::Backups(backups));
+  }
+
   #[test]
   fn test_lidarr_serdeable_from_disk_spaces() {
     let disk_spaces = vec![DiskSpace {
@@ -410,6 +424,42 @@ mod tests {
     assert_eq!(lidarr_serdeable, LidarrSerdeable::LogResponse(log_response));
   }
 
+  #[test]
+  fn test_lidarr_serdeable_from_media_management_settings() {
+    let media_management_settings = MediaManagementSettings {
+      naming: NamingConfig {
+        id: 1,
+        ..NamingConfig::default()
+      },
+      media_management: MediaManagementConfig {
+        id: 1,
+        ..MediaManagementConfig::default()
+      },
+    };
+
+    let lidarr_serdeable: LidarrSerdeable = media_management_settings.clone().into();
+
+    assert_eq!(
+      lidarr_serdeable,
+      LidarrSerdeable::MediaManagementSettings(media_management_settings)
+    );
+  }
+
+  #[test]
+  fn test_lidarr_serdeable_from_naming_examples() {
+    let naming_examples = NamingExamples {
+      single_track_example: Some("Test".to_owned()),
+      ..NamingExamples::default()
+    };
+
+    let lidarr_serdeable: LidarrSerdeable = naming_examples.clone().into();
+
+    assert_eq!(
+      lidarr_serdeable,
+      LidarrSerdeable::NamingExamples(naming_examples)
+    );
+  }
+
   #[test]
   fn test_lidarr_serdeable_from_metadata_profiles() {
     let metadata_profiles = vec![MetadataProfile {
@@ -437,11 +487,27 @@ mod tests {
     assert_eq!(lidarr_serdeable, LidarrSerdeable::HostConfig(host_config));
   }
 
+  #[test]
+  fn test_lidarr_serdeable_from_quality_profile() {
+    let quality_profile = QualityProfile {
+      id: 1,
+      ..QualityProfile::default()
+    };
+
+    let lidarr_serdeable: LidarrSerdeable = quality_profile.clone().into();
+
+    assert_eq!(
+      lidarr_serdeable,
+      LidarrSerdeable::QualityProfile(quality_profile)
+    );
+  }
+
   #[test]
   fn test_lidarr_serdeable_from_quality_profiles() {
     let quality_profiles = vec![QualityProfile {
       id: 1,
       name: "Any".to_owned(),
+      ..QualityProfile::default()
     }];
 
     let lidarr_serdeable: LidarrSerdeable = quality_profiles.clone().into();
@@ -640,6 +706,23 @@ mod tests {
     assert_eq!(lidarr_serdeable, LidarrSerdeable::Tracks(tracks));
   }
 
+  #[test]
+  fn test_lidarr_serdeable_from_wanted_albums_response() {
+    let wanted_albums_response = WantedAlbumsResponse {
+      records: vec![Album {
+        id: 1,
+        ..Album::default()
+      }],
+    };
+
+    let lidarr_serdeable: LidarrSerdeable = wanted_albums_response.clone().into();
+
+    assert_eq!(
+      lidarr_serdeable,
+      LidarrSerdeable::WantedAlbumsResponse(wanted_albums_response)
+    );
+  }
+
   #[test]
   fn test_artist_status_display() {
     assert_str_eq!(ArtistStatus::Continuing.to_string(), "continuing");
@@ -654,6 +737,20 @@ mod tests {
     assert_str_eq!(ArtistStatus::Deleted.to_display_str(), "Deleted");
   }
 
+  #[test]
+  fn test_download_record_is_pending() {
+    let download_record = |status: DownloadStatus| DownloadRecord {
+      status,
+      ..DownloadRecord::default()
+    };
+
+    assert!(download_record(DownloadStatus::Delay).is_pending());
+    assert!(download_record(DownloadStatus::DownloadClientUnavailable).is_pending());
+    assert!(download_record(DownloadStatus::Fallback).is_pending());
+    assert!(!download_record(DownloadStatus::Downloading).is_pending());
+    assert!(!download_record(DownloadStatus::Completed).is_pending());
+  }
+
   #[test]
   fn test_download_status_display() {
     assert_str_eq!(DownloadStatus::Unknown.to_string(), "unknown");
@@ -1,12 +1,17 @@
 use std::fmt::{Debug, Display, Formatter};
+use std::mem::{self, Discriminant};
 use std::sync::atomic::{AtomicUsize, Ordering};
 
 use crate::app::ServarrConfig;
 use crate::app::context_clues::ContextClue;
 use crate::models::servarr_data::lidarr::lidarr_data::ActiveLidarrBlock;
+use crate::models::servarr_data::prowlarr::prowlarr_data::ActiveProwlarrBlock;
 use crate::models::servarr_data::radarr::radarr_data::ActiveRadarrBlock;
+use crate::models::servarr_data::readarr::readarr_data::ActiveReadarrBlock;
 use lidarr_models::LidarrSerdeable;
+use prowlarr_models::ProwlarrSerdeable;
 use radarr_models::RadarrSerdeable;
+use readarr_models::ReadarrSerdeable;
 use regex::Regex;
 use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
 use serde_json::Number;
@@ -14,13 +19,16 @@ use servarr_data::sonarr::sonarr_data::ActiveSonarrBlock;
 use sonarr_models::SonarrSerdeable;
 
 pub mod lidarr_models;
+pub mod prowlarr_models;
 pub mod radarr_models;
+pub mod readarr_models;
 pub mod servarr_data;
 pub mod servarr_models;
 pub mod sonarr_models;
 pub mod stateful_list;
 pub mod stateful_table;
 pub mod stateful_tree;
+pub mod table_columns;
 
 #[cfg(test)]
 #[path = "model_tests.rs"]
@@ -32,11 +40,11 @@ mod model_tests;
 pub enum Route {
   Radarr(ActiveRadarrBlock, Option<ActiveRadarrBlock>),
   Sonarr(ActiveSonarrBlock, Option<ActiveSonarrBlock>),
-  Readarr,
+  Readarr(ActiveReadarrBlock, Option<ActiveReadarrBlock>),
   Lidarr(ActiveLidarrBlock, Option<ActiveLidarrBlock>),
   Whisparr,
   Bazarr,
-  Prowlarr,
+  Prowlarr(ActiveProwlarrBlock, Option<ActiveProwlarrBlock>),
   Tautulli,
   Keybindings,
 }
@@ -47,6 +55,8 @@ pub enum Serdeable {
   Radarr(RadarrSerdeable),
   Sonarr(SonarrSerdeable),
   Lidarr(LidarrSerdeable),
+  Prowlarr(ProwlarrSerdeable),
+  Readarr(ReadarrSerdeable),
 }
 
 pub trait Scrollable {
@@ -282,6 +292,29 @@ pub struct TabRoute {
   pub config: Option<ServarrConfig>,
 }
 
+impl TabRoute {
+  pub fn instance(&self) -> ServarrInstance {
+    ServarrInstance {
+      servarr: mem::discriminant(&self.route),
+      name: self.title.clone(),
+    }
+  }
+}
+
+/// Identifies a configured Servarr instance by its type and tab title, both of which stay the same
+/// when the rest of its config is edited
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ServarrInstance {
+  servarr: Discriminant<Route>,
+  name: String,
+}
+
+impl Display for ServarrInstance {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.name)
+  }
+}
+
 #[cfg_attr(test, derive(Debug))]
 pub struct TabState {
   pub tabs: Vec<TabRoute>,
@@ -311,6 +344,10 @@ impl TabState {
     &self.tabs[self.index].config
   }
 
+  pub fn get_active_instance(&self) -> Option<ServarrInstance> {
+    self.tabs.get(self.index).map(TabRoute::instance)
+  }
+
   pub fn select_tab_by_title(&mut self, name: &str) -> bool {
     if !self.tabs.is_empty() {
       let mut found = false;
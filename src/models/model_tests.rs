@@ -8,6 +8,7 @@ mod tests {
   use crate::app::key_binding::DEFAULT_KEYBINDINGS;
   use crate::models::from_f64;
   use crate::models::servarr_data::radarr::radarr_data::ActiveRadarrBlock;
+  use crate::models::servarr_data::sonarr::sonarr_data::ActiveSonarrBlock;
   use crate::models::{
     BlockSelectionState, HorizontallyScrollableText, Scrollable, ScrollableText, TabRoute, TabState,
   };
@@ -544,6 +545,35 @@ mod tests {
     assert_none!(active_config);
   }
 
+  #[test]
+  fn test_tab_route_instance() {
+    let tabs = create_test_tab_routes();
+    let edited_tab = TabRoute {
+      route: ActiveRadarrBlock::Downloads.into(),
+      config: Some(ServarrConfig::default()),
+      ..tabs[0].clone()
+    };
+    let sonarr_tab = TabRoute {
+      route: ActiveSonarrBlock::Series.into(),
+      ..tabs[0].clone()
+    };
+
+    assert_eq!(tabs[0].instance(), edited_tab.instance());
+    assert_ne!(tabs[0].instance(), tabs[1].instance());
+    assert_ne!(tabs[0].instance(), sonarr_tab.instance());
+    assert_str_eq!(tabs[0].instance().to_string(), "Test 1");
+  }
+
+  #[test]
+  fn test_tab_state_get_active_instance() {
+    let tabs = create_test_tab_routes();
+    let second_instance = tabs[1].instance();
+    let tab_state = TabState { tabs, index: 1 };
+
+    assert_some_eq_x!(tab_state.get_active_instance(), second_instance);
+    assert_none!(TabState::new(Vec::new()).get_active_instance());
+  }
+
   #[test]
   fn test_select_tab_by_title() {
     let tabs = create_test_tab_routes();
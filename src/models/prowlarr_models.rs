@@ -0,0 +1,286 @@
+use super::{
+  HorizontallyScrollableText, Serdeable,
+  servarr_models::{HostConfig, IndexerField, IndexerTestResult, SecurityConfig, Tag},
+};
+use crate::models::servarr_models::{LogResponse, QueueEvent, Update};
+use crate::serde_enum_from;
+use chrono::{DateTime, Utc};
+use clap::ValueEnum;
+use enum_display_style_derive::EnumDisplayStyle;
+use serde::{Deserialize, Serialize};
+use serde_json::{Number, Value};
+use std::fmt::{Display, Formatter};
+use strum::{Display, EnumIter};
+
+#[cfg(test)]
+#[path = "prowlarr_models_tests.rs"]
+mod prowlarr_models_tests;
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ProwlarrIndexer {
+  #[serde(deserialize_with = "super::from_i64")]
+  pub id: i64,
+  pub name: Option<String>,
+  pub implementation: Option<String>,
+  pub implementation_name: Option<String>,
+  pub definition_name: Option<String>,
+  pub config_contract: Option<String>,
+  pub info_link: Option<String>,
+  pub protocol: String,
+  #[serde(default)]
+  pub privacy: IndexerPrivacy,
+  pub enable: bool,
+  #[serde(default)]
+  pub supports_rss: bool,
+  #[serde(default)]
+  pub supports_search: bool,
+  #[serde(deserialize_with = "super::from_i64")]
+  pub priority: i64,
+  #[serde(deserialize_with = "super::from_i64")]
+  pub app_profile_id: i64,
+  pub added: Option<DateTime<Utc>>,
+  pub fields: Option<Vec<IndexerField>>,
+  pub tags: Vec<Number>,
+}
+
+#[derive(
+  Serialize,
+  Deserialize,
+  Default,
+  PartialEq,
+  Eq,
+  Clone,
+  Copy,
+  Debug,
+  EnumIter,
+  Display,
+  EnumDisplayStyle,
+)]
+#[serde(rename_all = "camelCase")]
+#[strum(serialize_all = "camelCase")]
+pub enum IndexerPrivacy {
+  #[default]
+  Public,
+  #[display_style(name = "Semi-Private")]
+  SemiPrivate,
+  Private,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexerProxy {
+  #[serde(deserialize_with = "super::from_i64")]
+  pub id: i64,
+  pub name: Option<String>,
+  pub implementation: Option<String>,
+  pub implementation_name: Option<String>,
+  pub config_contract: Option<String>,
+  pub fields: Option<Vec<IndexerField>>,
+  pub tags: Vec<Number>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Application {
+  #[serde(deserialize_with = "super::from_i64")]
+  pub id: i64,
+  pub name: Option<String>,
+  pub implementation: Option<String>,
+  pub implementation_name: Option<String>,
+  pub config_contract: Option<String>,
+  #[serde(default)]
+  pub sync_level: ApplicationSyncLevel,
+  pub fields: Option<Vec<IndexerField>>,
+  pub tags: Vec<Number>,
+}
+
+#[derive(
+  Serialize,
+  Deserialize,
+  Default,
+  PartialEq,
+  Eq,
+  Clone,
+  Copy,
+  Debug,
+  EnumIter,
+  Display,
+  EnumDisplayStyle,
+)]
+#[serde(rename_all = "camelCase")]
+#[strum(serialize_all = "camelCase")]
+pub enum ApplicationSyncLevel {
+  #[default]
+  Disabled,
+  #[display_style(name = "Add and Remove Only")]
+  AddOnly,
+  #[display_style(name = "Full Sync")]
+  FullSync,
+}
+
+#[derive(Serialize, Deserialize, Default, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct ProwlarrRelease {
+  pub guid: String,
+  #[serde(deserialize_with = "super::from_i64")]
+  pub age: i64,
+  pub title: HorizontallyScrollableText,
+  pub indexer: String,
+  #[serde(deserialize_with = "super::from_i64")]
+  pub indexer_id: i64,
+  #[serde(deserialize_with = "super::from_i64")]
+  pub size: i64,
+  pub protocol: String,
+  pub seeders: Option<Number>,
+  pub leechers: Option<Number>,
+  pub grabs: Option<Number>,
+  pub categories: Vec<ReleaseCategory>,
+  pub publish_date: Option<DateTime<Utc>>,
+  pub info_url: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Default, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ReleaseCategory {
+  #[serde(deserialize_with = "super::from_i64")]
+  pub id: i64,
+  pub name: Option<String>,
+}
+
+#[derive(Default, Serialize, Debug, PartialEq, Eq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ProwlarrReleaseDownloadBody {
+  pub guid: String,
+  pub indexer_id: i64,
+}
+
+#[derive(Default, Debug, PartialEq, Eq, Clone)]
+pub struct ProwlarrSearchParams {
+  pub query: String,
+  pub indexer_ids: Vec<i64>,
+  pub categories: Vec<i64>,
+  pub limit: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ProwlarrHistoryWrapper {
+  pub records: Vec<ProwlarrHistoryItem>,
+}
+
+#[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ProwlarrHistoryData {
+  pub query: Option<String>,
+  pub query_type: Option<String>,
+  pub categories: Option<String>,
+  pub source: Option<String>,
+  pub host: Option<String>,
+  pub grab_title: Option<String>,
+  pub elapsed_time: Option<String>,
+  pub query_results: Option<String>,
+  pub url: Option<String>,
+}
+
+#[derive(
+  Serialize, Deserialize, Default, Debug, Clone, PartialEq, Eq, Display, EnumDisplayStyle,
+)]
+#[serde(rename_all = "camelCase")]
+#[strum(serialize_all = "camelCase")]
+pub enum ProwlarrHistoryEventType {
+  #[default]
+  Unknown,
+  #[display_style(name = "Query")]
+  IndexerQuery,
+  #[display_style(name = "Grabbed")]
+  ReleaseGrabbed,
+  #[display_style(name = "Auth")]
+  IndexerAuth,
+  #[display_style(name = "RSS")]
+  IndexerRss,
+  #[display_style(name = "Info")]
+  IndexerInfo,
+}
+
+#[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ProwlarrHistoryItem {
+  #[serde(deserialize_with = "super::from_i64")]
+  pub id: i64,
+  #[serde(deserialize_with = "super::from_i64")]
+  pub indexer_id: i64,
+  pub date: DateTime<Utc>,
+  pub successful: bool,
+  pub event_type: ProwlarrHistoryEventType,
+  #[serde(default)]
+  pub data: ProwlarrHistoryData,
+}
+
+#[derive(Default, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SystemStatus {
+  pub version: String,
+  pub start_time: DateTime<Utc>,
+}
+
+#[derive(Default, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ProwlarrTask {
+  pub name: String,
+  pub task_name: ProwlarrTaskName,
+  #[serde(deserialize_with = "super::from_i64")]
+  pub interval: i64,
+  pub last_execution: DateTime<Utc>,
+  pub next_execution: DateTime<Utc>,
+}
+
+#[derive(Default, Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Copy, ValueEnum)]
+#[serde(rename_all = "PascalCase")]
+pub enum ProwlarrTaskName {
+  #[default]
+  ApplicationIndexerSync,
+  ApplicationUpdateCheck,
+  Backup,
+  CheckHealth,
+  Housekeeping,
+  IndexerDefinitionUpdate,
+  MessagingCleanup,
+}
+
+impl Display for ProwlarrTaskName {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    let task_name = serde_json::to_string(&self)
+      .expect("Unable to serialize task name")
+      .replace('"', "");
+    write!(f, "{task_name}")
+  }
+}
+
+impl From<ProwlarrSerdeable> for Serdeable {
+  fn from(value: ProwlarrSerdeable) -> Serdeable {
+    Serdeable::Prowlarr(value)
+  }
+}
+
+serde_enum_from!(
+  ProwlarrSerdeable {
+    Applications(Vec<Application>),
+    HostConfig(HostConfig),
+    IndexerProxies(Vec<IndexerProxy>),
+    Indexers(Vec<ProwlarrIndexer>),
+    IndexerTestResults(Vec<IndexerTestResult>),
+    LogResponse(LogResponse),
+    ProwlarrHistoryWrapper(ProwlarrHistoryWrapper),
+    QueueEvents(Vec<QueueEvent>),
+    Releases(Vec<ProwlarrRelease>),
+    SecurityConfig(SecurityConfig),
+    SystemStatus(SystemStatus),
+    Tag(Tag),
+    Tags(Vec<Tag>),
+    Tasks(Vec<ProwlarrTask>),
+    Updates(Vec<Update>),
+    Value(Value),
+  }
+);
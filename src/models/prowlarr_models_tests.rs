@@ -0,0 +1,411 @@
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::{assert_eq, assert_str_eq};
+  use serde_json::json;
+
+  use crate::models::Serdeable;
+  use crate::models::prowlarr_models::{
+    Application, ApplicationSyncLevel, IndexerPrivacy, IndexerProxy, ProwlarrHistoryEventType,
+    ProwlarrHistoryItem, ProwlarrHistoryWrapper, ProwlarrIndexer, ProwlarrRelease,
+    ProwlarrSerdeable, ProwlarrTask, ProwlarrTaskName, SystemStatus,
+  };
+  use crate::models::servarr_models::{
+    HostConfig, IndexerTestResult, LogResponse, QueueEvent, SecurityConfig, Tag, Update,
+  };
+
+  #[test]
+  fn test_indexer_privacy_display() {
+    assert_str_eq!(IndexerPrivacy::Public.to_string(), "public");
+    assert_str_eq!(IndexerPrivacy::SemiPrivate.to_string(), "semiPrivate");
+    assert_str_eq!(IndexerPrivacy::Private.to_string(), "private");
+  }
+
+  #[test]
+  fn test_indexer_privacy_to_display_str() {
+    assert_str_eq!(IndexerPrivacy::Public.to_display_str(), "Public");
+    assert_str_eq!(IndexerPrivacy::SemiPrivate.to_display_str(), "Semi-Private");
+    assert_str_eq!(IndexerPrivacy::Private.to_display_str(), "Private");
+  }
+
+  #[test]
+  fn test_application_sync_level_display() {
+    assert_str_eq!(ApplicationSyncLevel::Disabled.to_string(), "disabled");
+    assert_str_eq!(ApplicationSyncLevel::AddOnly.to_string(), "addOnly");
+    assert_str_eq!(ApplicationSyncLevel::FullSync.to_string(), "fullSync");
+  }
+
+  #[test]
+  fn test_application_sync_level_to_display_str() {
+    assert_str_eq!(ApplicationSyncLevel::Disabled.to_display_str(), "Disabled");
+    assert_str_eq!(
+      ApplicationSyncLevel::AddOnly.to_display_str(),
+      "Add and Remove Only"
+    );
+    assert_str_eq!(ApplicationSyncLevel::FullSync.to_display_str(), "Full Sync");
+  }
+
+  #[test]
+  fn test_prowlarr_history_event_type_display() {
+    assert_str_eq!(ProwlarrHistoryEventType::Unknown.to_string(), "unknown");
+    assert_str_eq!(
+      ProwlarrHistoryEventType::IndexerQuery.to_string(),
+      "indexerQuery"
+    );
+    assert_str_eq!(
+      ProwlarrHistoryEventType::ReleaseGrabbed.to_string(),
+      "releaseGrabbed"
+    );
+    assert_str_eq!(
+      ProwlarrHistoryEventType::IndexerAuth.to_string(),
+      "indexerAuth"
+    );
+    assert_str_eq!(
+      ProwlarrHistoryEventType::IndexerRss.to_string(),
+      "indexerRss"
+    );
+    assert_str_eq!(
+      ProwlarrHistoryEventType::IndexerInfo.to_string(),
+      "indexerInfo"
+    );
+  }
+
+  #[test]
+  fn test_prowlarr_history_event_type_to_display_str() {
+    assert_str_eq!(
+      ProwlarrHistoryEventType::Unknown.to_display_str(),
+      "Unknown"
+    );
+    assert_str_eq!(
+      ProwlarrHistoryEventType::IndexerQuery.to_display_str(),
+      "Query"
+    );
+    assert_str_eq!(
+      ProwlarrHistoryEventType::ReleaseGrabbed.to_display_str(),
+      "Grabbed"
+    );
+    assert_str_eq!(
+      ProwlarrHistoryEventType::IndexerAuth.to_display_str(),
+      "Auth"
+    );
+    assert_str_eq!(ProwlarrHistoryEventType::IndexerRss.to_display_str(), "RSS");
+    assert_str_eq!(
+      ProwlarrHistoryEventType::IndexerInfo.to_display_str(),
+      "Info"
+    );
+  }
+
+  #[test]
+  fn test_prowlarr_task_name_display() {
+    assert_str_eq!(
+      ProwlarrTaskName::ApplicationIndexerSync.to_string(),
+      "ApplicationIndexerSync"
+    );
+    assert_str_eq!(ProwlarrTaskName::Backup.to_string(), "Backup");
+    assert_str_eq!(
+      ProwlarrTaskName::IndexerDefinitionUpdate.to_string(),
+      "IndexerDefinitionUpdate"
+    );
+  }
+
+  #[test]
+  fn test_prowlarr_indexer_deserialization() {
+    let indexer_json = json!({
+      "id": 1,
+      "name": "Test Indexer",
+      "implementation": "Cardigann",
+      "implementationName": "Cardigann",
+      "definitionName": "testindexer",
+      "configContract": "CardigannSettings",
+      "protocol": "torrent",
+      "privacy": "semiPrivate",
+      "enable": true,
+      "supportsRss": true,
+      "supportsSearch": true,
+      "priority": 25,
+      "appProfileId": 1,
+      "added": "2023-05-20T21:29:16Z",
+      "tags": [1]
+    });
+
+    let indexer: ProwlarrIndexer = serde_json::from_value(indexer_json).unwrap();
+
+    assert_eq!(indexer.id, 1);
+    assert_eq!(indexer.name, Some("Test Indexer".to_owned()));
+    assert_eq!(indexer.definition_name, Some("testindexer".to_owned()));
+    assert_eq!(indexer.privacy, IndexerPrivacy::SemiPrivate);
+    assert!(indexer.enable);
+    assert_eq!(indexer.priority, 25);
+    assert_eq!(indexer.app_profile_id, 1);
+    assert_eq!(indexer.tags, vec![1.into()]);
+  }
+
+  #[test]
+  fn test_application_deserialization_defaults_sync_level() {
+    let application_json = json!({
+      "id": 1,
+      "name": "Sonarr",
+      "tags": []
+    });
+
+    let application: Application = serde_json::from_value(application_json).unwrap();
+
+    assert_eq!(application.sync_level, ApplicationSyncLevel::Disabled);
+  }
+
+  #[test]
+  fn test_prowlarr_release_deserialization() {
+    let release_json = json!({
+      "guid": "1234",
+      "age": 1,
+      "title": "Test Release",
+      "indexer": "Test Indexer",
+      "indexerId": 2,
+      "size": 1234,
+      "protocol": "torrent",
+      "seeders": 10,
+      "leechers": 2,
+      "categories": [{ "id": 2000, "name": "Movies" }]
+    });
+
+    let release: ProwlarrRelease = serde_json::from_value(release_json).unwrap();
+
+    assert_str_eq!(release.guid, "1234");
+    assert_str_eq!(release.title.text, "Test Release");
+    assert_eq!(release.indexer_id, 2);
+    assert_eq!(release.categories.len(), 1);
+    assert_eq!(release.categories[0].id, 2000);
+  }
+
+  #[test]
+  fn test_prowlarr_serdeable_from() {
+    let prowlarr_serdeable = ProwlarrSerdeable::Value(json!({}));
+
+    let serdeable: Serdeable = Serdeable::from(prowlarr_serdeable.clone());
+
+    assert_eq!(serdeable, Serdeable::Prowlarr(prowlarr_serdeable));
+  }
+
+  #[test]
+  fn test_prowlarr_serdeable_from_unit() {
+    let prowlarr_serdeable = ProwlarrSerdeable::from(());
+
+    assert_eq!(prowlarr_serdeable, ProwlarrSerdeable::Value(json!({})));
+  }
+
+  #[test]
+  fn test_prowlarr_serdeable_from_value() {
+    let value = json!({"test": "test"});
+
+    let prowlarr_serdeable: ProwlarrSerdeable = value.clone().into();
+
+    assert_eq!(prowlarr_serdeable, ProwlarrSerdeable::Value(value));
+  }
+
+  #[test]
+  fn test_prowlarr_serdeable_from_applications() {
+    let applications = vec![Application {
+      id: 1,
+      ..Application::default()
+    }];
+
+    let prowlarr_serdeable: ProwlarrSerdeable = applications.clone().into();
+
+    assert_eq!(
+      prowlarr_serdeable,
+      ProwlarrSerdeable::Applications(applications)
+    );
+  }
+
+  #[test]
+  fn test_prowlarr_serdeable_from_host_config() {
+    let host_config = HostConfig {
+      port: 1234,
+      ..HostConfig::default()
+    };
+
+    let prowlarr_serdeable: ProwlarrSerdeable = host_config.clone().into();
+
+    assert_eq!(
+      prowlarr_serdeable,
+      ProwlarrSerdeable::HostConfig(host_config)
+    );
+  }
+
+  #[test]
+  fn test_prowlarr_serdeable_from_indexer_proxies() {
+    let indexer_proxies = vec![IndexerProxy {
+      id: 1,
+      ..IndexerProxy::default()
+    }];
+
+    let prowlarr_serdeable: ProwlarrSerdeable = indexer_proxies.clone().into();
+
+    assert_eq!(
+      prowlarr_serdeable,
+      ProwlarrSerdeable::IndexerProxies(indexer_proxies)
+    );
+  }
+
+  #[test]
+  fn test_prowlarr_serdeable_from_indexers() {
+    let indexers = vec![ProwlarrIndexer {
+      id: 1,
+      ..ProwlarrIndexer::default()
+    }];
+
+    let prowlarr_serdeable: ProwlarrSerdeable = indexers.clone().into();
+
+    assert_eq!(prowlarr_serdeable, ProwlarrSerdeable::Indexers(indexers));
+  }
+
+  #[test]
+  fn test_prowlarr_serdeable_from_indexer_test_results() {
+    let indexer_test_results = vec![IndexerTestResult {
+      id: 1,
+      ..IndexerTestResult::default()
+    }];
+
+    let prowlarr_serdeable: ProwlarrSerdeable = indexer_test_results.clone().into();
+
+    assert_eq!(
+      prowlarr_serdeable,
+      ProwlarrSerdeable::IndexerTestResults(indexer_test_results)
+    );
+  }
+
+  #[test]
+  fn test_prowlarr_serdeable_from_log_response() {
+    let log_response = LogResponse {
+      records: Vec::new(),
+    };
+
+    let prowlarr_serdeable: ProwlarrSerdeable = log_response.clone().into();
+
+    assert_eq!(
+      prowlarr_serdeable,
+      ProwlarrSerdeable::LogResponse(log_response)
+    );
+  }
+
+  #[test]
+  fn test_prowlarr_serdeable_from_history_wrapper() {
+    let history_wrapper = ProwlarrHistoryWrapper {
+      records: vec![ProwlarrHistoryItem {
+        id: 1,
+        ..ProwlarrHistoryItem::default()
+      }],
+    };
+
+    let prowlarr_serdeable: ProwlarrSerdeable = history_wrapper.clone().into();
+
+    assert_eq!(
+      prowlarr_serdeable,
+      ProwlarrSerdeable::ProwlarrHistoryWrapper(history_wrapper)
+    );
+  }
+
+  #[test]
+  fn test_prowlarr_serdeable_from_queue_events() {
+    let queue_events = vec![QueueEvent {
+      trigger: "test".to_owned(),
+      ..QueueEvent::default()
+    }];
+
+    let prowlarr_serdeable: ProwlarrSerdeable = queue_events.clone().into();
+
+    assert_eq!(
+      prowlarr_serdeable,
+      ProwlarrSerdeable::QueueEvents(queue_events)
+    );
+  }
+
+  #[test]
+  fn test_prowlarr_serdeable_from_releases() {
+    let releases = vec![ProwlarrRelease {
+      size: 1,
+      ..ProwlarrRelease::default()
+    }];
+
+    let prowlarr_serdeable: ProwlarrSerdeable = releases.clone().into();
+
+    assert_eq!(prowlarr_serdeable, ProwlarrSerdeable::Releases(releases));
+  }
+
+  #[test]
+  fn test_prowlarr_serdeable_from_security_config() {
+    let security_config = SecurityConfig {
+      username: Some("Test".to_owned()),
+      ..SecurityConfig::default()
+    };
+
+    let prowlarr_serdeable: ProwlarrSerdeable = security_config.clone().into();
+
+    assert_eq!(
+      prowlarr_serdeable,
+      ProwlarrSerdeable::SecurityConfig(security_config)
+    );
+  }
+
+  #[test]
+  fn test_prowlarr_serdeable_from_system_status() {
+    let system_status = SystemStatus {
+      version: "1".to_owned(),
+      ..SystemStatus::default()
+    };
+
+    let prowlarr_serdeable: ProwlarrSerdeable = system_status.clone().into();
+
+    assert_eq!(
+      prowlarr_serdeable,
+      ProwlarrSerdeable::SystemStatus(system_status)
+    );
+  }
+
+  #[test]
+  fn test_prowlarr_serdeable_from_tag() {
+    let tag = Tag {
+      id: 1,
+      ..Tag::default()
+    };
+
+    let prowlarr_serdeable: ProwlarrSerdeable = tag.clone().into();
+
+    assert_eq!(prowlarr_serdeable, ProwlarrSerdeable::Tag(tag));
+  }
+
+  #[test]
+  fn test_prowlarr_serdeable_from_tags() {
+    let tags = vec![Tag {
+      id: 1,
+      ..Tag::default()
+    }];
+
+    let prowlarr_serdeable: ProwlarrSerdeable = tags.clone().into();
+
+    assert_eq!(prowlarr_serdeable, ProwlarrSerdeable::Tags(tags));
+  }
+
+  #[test]
+  fn test_prowlarr_serdeable_from_tasks() {
+    let tasks = vec![ProwlarrTask {
+      name: "test".to_owned(),
+      ..ProwlarrTask::default()
+    }];
+
+    let prowlarr_serdeable: ProwlarrSerdeable = tasks.clone().into();
+
+    assert_eq!(prowlarr_serdeable, ProwlarrSerdeable::Tasks(tasks));
+  }
+
+  #[test]
+  fn test_prowlarr_serdeable_from_updates() {
+    let updates = vec![Update {
+      version: "test".to_owned(),
+      ..Update::default()
+    }];
+
+    let prowlarr_serdeable: ProwlarrSerdeable = updates.clone().into();
+
+    assert_eq!(prowlarr_serdeable, ProwlarrSerdeable::Updates(updates));
+  }
+}
@@ -12,7 +12,8 @@ use strum_macros::{Display, EnumIter};
 
 use super::Serdeable;
 use super::servarr_models::{
-  DiskSpace, HostConfig, Indexer, Language, LogResponse, QualityProfile, QualityWrapper,
+  ApplyTags, Backup, CustomFormat, DiskSpace, DownloadClient, HostConfig, Indexer, Language,
+  LogResponse, ManualImportRejection, MediaManagementSettings, QualityProfile, QualityWrapper,
   QueueEvent, RootFolder, SecurityConfig, Tag, Update,
 };
 
@@ -87,6 +88,32 @@ pub struct BlocklistItemMovie {
   pub title: HorizontallyScrollableText,
 }
 
+#[derive(Default, Clone, Serialize, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct BulkDeleteMoviesParams {
+  pub movie_ids: Vec<i64>,
+  pub delete_files: bool,
+  pub add_import_exclusion: bool,
+}
+
+#[derive(Default, Clone, Serialize, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct BulkEditMoviesParams {
+  pub movie_ids: Vec<i64>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub monitored: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub quality_profile_id: Option<i64>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub root_folder_path: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub tags: Option<Vec<i64>>,
+  #[serde(skip_serializing, skip_deserializing)]
+  pub tag_input_string: Option<String>,
+  pub apply_tags: ApplyTags,
+  pub move_files: bool,
+}
+
 #[derive(Serialize, Deserialize, Derivative, Default, Clone, Debug, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct Collection {
@@ -153,7 +180,7 @@ pub struct DownloadRecord {
   pub status: String,
   #[serde(deserialize_with = "super::from_i64")]
   pub id: i64,
-  #[serde(deserialize_with = "super::from_i64")]
+  #[serde(default, deserialize_with = "super::from_i64")]
   pub movie_id: i64,
   #[serde(deserialize_with = "super::from_i64")]
   pub size: i64,
@@ -162,7 +189,68 @@ pub struct DownloadRecord {
   pub output_path: Option<HorizontallyScrollableText>,
   #[serde(default)]
   pub indexer: String,
+  #[serde(default)]
   pub download_client: String,
+  pub download_id: Option<String>,
+  pub estimated_completion_time: Option<DateTime<Utc>>,
+}
+
+impl DownloadRecord {
+  /// Whether this is a release held back by Radarr (e.g. by a delay profile) that hasn't been
+  /// sent to a download client yet
+  pub fn is_pending(&self) -> bool {
+    matches!(
+      self.status.as_str(),
+      "delay" | "downloadClientUnavailable" | "fallback"
+    )
+  }
+}
+
+#[derive(Default, Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RadarrManualImportItem {
+  #[serde(deserialize_with = "super::from_i64")]
+  pub id: i64,
+  pub path: String,
+  pub relative_path: String,
+  #[serde(deserialize_with = "super::from_i64")]
+  pub size: i64,
+  pub movie: Option<Movie>,
+  pub quality: Value,
+  #[serde(default)]
+  pub languages: Vec<Language>,
+  pub release_group: Option<String>,
+  pub download_id: Option<String>,
+  #[serde(default)]
+  pub rejections: Vec<ManualImportRejection>,
+}
+
+impl RadarrManualImportItem {
+  pub fn import_file(&self) -> Option<RadarrManualImportFile> {
+    if !self.rejections.is_empty() {
+      return None;
+    }
+
+    self.movie.as_ref().map(|movie| RadarrManualImportFile {
+      path: self.path.clone(),
+      movie_id: movie.id,
+      quality: self.quality.clone(),
+      languages: self.languages.clone(),
+      release_group: self.release_group.clone(),
+      download_id: self.download_id.clone(),
+    })
+  }
+}
+
+#[derive(Default, Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RadarrManualImportFile {
+  pub path: String,
+  pub movie_id: i64,
+  pub quality: Value,
+  pub languages: Vec<Language>,
+  pub release_group: Option<String>,
+  pub download_id: Option<String>,
 }
 
 #[derive(Default, Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
@@ -317,6 +405,9 @@ pub struct Movie {
   #[serde(deserialize_with = "super::from_i64")]
   pub quality_profile_id: i64,
   pub minimum_availability: MinimumAvailability,
+  pub in_cinemas: Option<DateTime<Utc>>,
+  pub digital_release: Option<DateTime<Utc>>,
+  pub physical_release: Option<DateTime<Utc>>,
   pub certification: Option<String>,
   pub tags: Vec<Number>,
   pub ratings: RatingsList,
@@ -356,6 +447,24 @@ pub struct MovieHistoryItem {
   pub event_type: String,
 }
 
+#[derive(Default, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct NamingConfig {
+  #[serde(deserialize_with = "super::from_i64")]
+  pub id: i64,
+  pub rename_movies: bool,
+  pub replace_illegal_characters: bool,
+  pub standard_movie_format: Option<String>,
+  pub movie_folder_format: Option<String>,
+}
+
+#[derive(Default, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct NamingExamples {
+  pub movie_example: Option<String>,
+  pub movie_folder_example: Option<String>,
+}
+
 #[derive(Derivative, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 #[derivative(Default)]
 pub struct Rating {
@@ -391,6 +500,9 @@ pub struct RadarrRelease {
   pub leechers: Option<Number>,
   pub languages: Option<Vec<Language>>,
   pub quality: QualityWrapper,
+  pub custom_formats: Vec<CustomFormat>,
+  #[serde(deserialize_with = "super::from_i64")]
+  pub custom_format_score: i64,
 }
 
 #[derive(Default, Serialize, Debug, PartialEq, Eq, Clone)]
@@ -519,19 +631,25 @@ serde_enum_from!(
   RadarrSerdeable {
     Value(Value),
     Tag(Tag),
+    Backups(Vec<Backup>),
     BlocklistResponse(BlocklistResponse),
     Collections(Vec<Collection>),
     Credits(Vec<Credit>),
+    CustomFormats(Vec<CustomFormat>),
     DiskSpaces(Vec<DiskSpace>),
     DownloadsResponse(DownloadsResponse),
     HistoryWrapper(RadarrHistoryWrapper),
     HostConfig(HostConfig),
+    DownloadClients(Vec<DownloadClient>),
     Indexers(Vec<Indexer>),
     IndexerSettings(IndexerSettings),
     LogResponse(LogResponse),
+    MediaManagementSettings(MediaManagementSettings<NamingConfig>),
+    NamingExamples(NamingExamples),
     Movie(Movie),
     MovieHistoryItems(Vec<MovieHistoryItem>),
     Movies(Vec<Movie>),
+    QualityProfile(QualityProfile),
     QualityProfiles(Vec<QualityProfile>),
     QueueEvents(Vec<QueueEvent>),
     Releases(Vec<RadarrRelease>),
@@ -543,5 +661,12 @@ serde_enum_from!(
     Updates(Vec<Update>),
     AddMovieSearchResults(Vec<AddMovieSearchResult>),
     IndexerTestResults(Vec<IndexerTestResult>),
+    WantedMoviesResponse(WantedMoviesResponse),
+    ManualImportItems(Vec<RadarrManualImportItem>),
   }
 );
+
+#[derive(Default, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct WantedMoviesResponse {
+  pub records: Vec<Movie>,
+}
@@ -11,10 +11,14 @@ mod tests {
     radarr_models::{
       AddMovieSearchResult, BlocklistItem, BlocklistResponse, Collection, Credit, DiskSpace,
       DownloadRecord, DownloadsResponse, Indexer, IndexerSettings, IndexerTestResult,
-      MinimumAvailability, Movie, MovieHistoryItem, MovieMonitor, QualityProfile, RadarrRelease,
-      RadarrSerdeable, RadarrTask, RadarrTaskName, SystemStatus, Tag, Update,
+      MinimumAvailability, Movie, MovieHistoryItem, MovieMonitor, NamingConfig, NamingExamples,
+      QualityProfile, RadarrRelease, RadarrSerdeable, RadarrTask, RadarrTaskName, SystemStatus,
+      Tag, Update, WantedMoviesResponse,
+    },
+    servarr_models::{
+      Backup, CustomFormat, HostConfig, Log, LogResponse, MediaManagementConfig,
+      MediaManagementSettings, QueueEvent, RootFolder, SecurityConfig,
     },
-    servarr_models::{HostConfig, Log, LogResponse, QueueEvent, RootFolder, SecurityConfig},
   };
 
   #[test]
@@ -124,6 +128,20 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_download_record_is_pending() {
+    let download_record = |status: &str| DownloadRecord {
+      status: status.to_owned(),
+      ..DownloadRecord::default()
+    };
+
+    assert!(download_record("delay").is_pending());
+    assert!(download_record("downloadClientUnavailable").is_pending());
+    assert!(download_record("fallback").is_pending());
+    assert!(!download_record("downloading").is_pending());
+    assert!(!download_record("completed").is_pending());
+  }
+
   #[test]
   fn test_download_record_default_indexer_value() {
     let json = r#"{ 
@@ -145,6 +163,8 @@ mod tests {
       output_path: None,
       indexer: "".to_owned(),
       download_client: "test".to_owned(),
+      download_id: None,
+      estimated_completion_time: None,
     };
 
     let result: DownloadRecord = serde_json::from_str(json).unwrap();
@@ -230,6 +250,33 @@ mod tests {
     assert_eq!(radarr_serdeable, RadarrSerdeable::Credits(credits));
   }
 
+  #[test]
+  fn test_radarr_serdeable_from_backups() {
+    let backups = vec![Backup {
+      id: 1,
+      ..Backup::default()
+    }];
+
+    let radarr_serdeable: RadarrSerdeable = backups.clone().into();
+
+    assert_eq!(radarr_serdeable, RadarrSerdeable::Backups(backups));
+  }
+
+  #[test]
+  fn test_radarr_serdeable_from_custom_formats() {
+    let custom_formats = vec![CustomFormat {
+      id: 1,
+      ..CustomFormat::default()
+    }];
+
+    let radarr_serdeable: RadarrSerdeable = custom_formats.clone().into();
+
+    assert_eq!(
+      radarr_serdeable,
+      RadarrSerdeable::CustomFormats(custom_formats)
+    );
+  }
+
   #[test]
   fn test_radarr_serdeable_from_disk_spaces() {
     let disk_spaces = vec![DiskSpace {
@@ -330,6 +377,42 @@ mod tests {
     assert_eq!(radarr_serdeable, RadarrSerdeable::LogResponse(log_response));
   }
 
+  #[test]
+  fn test_radarr_serdeable_from_media_management_settings() {
+    let media_management_settings = MediaManagementSettings {
+      naming: NamingConfig {
+        id: 1,
+        ..NamingConfig::default()
+      },
+      media_management: MediaManagementConfig {
+        id: 1,
+        ..MediaManagementConfig::default()
+      },
+    };
+
+    let radarr_serdeable: RadarrSerdeable = media_management_settings.clone().into();
+
+    assert_eq!(
+      radarr_serdeable,
+      RadarrSerdeable::MediaManagementSettings(media_management_settings)
+    );
+  }
+
+  #[test]
+  fn test_radarr_serdeable_from_naming_examples() {
+    let naming_examples = NamingExamples {
+      movie_example: Some("Test".to_owned()),
+      ..NamingExamples::default()
+    };
+
+    let radarr_serdeable: RadarrSerdeable = naming_examples.clone().into();
+
+    assert_eq!(
+      radarr_serdeable,
+      RadarrSerdeable::NamingExamples(naming_examples)
+    );
+  }
+
   #[test]
   fn test_radarr_serdeable_from_movie() {
     let movie = Movie {
@@ -369,6 +452,21 @@ mod tests {
     assert_eq!(radarr_serdeable, RadarrSerdeable::Movies(movies));
   }
 
+  #[test]
+  fn test_radarr_serdeable_from_quality_profile() {
+    let quality_profile = QualityProfile {
+      id: 1,
+      ..QualityProfile::default()
+    };
+
+    let radarr_serdeable: RadarrSerdeable = quality_profile.clone().into();
+
+    assert_eq!(
+      radarr_serdeable,
+      RadarrSerdeable::QualityProfile(quality_profile)
+    );
+  }
+
   #[test]
   fn test_radarr_serdeable_from_quality_profiles() {
     let quality_profiles = vec![QualityProfile {
@@ -515,4 +613,20 @@ mod tests {
       RadarrSerdeable::IndexerTestResults(indexer_test_results)
     );
   }
+  #[test]
+  fn test_radarr_serdeable_from_wanted_movies_response() {
+    let wanted_movies_response = WantedMoviesResponse {
+      records: vec![Movie {
+        id: 1,
+        ..Movie::default()
+      }],
+    };
+
+    let radarr_serdeable: RadarrSerdeable = wanted_movies_response.clone().into();
+
+    assert_eq!(
+      radarr_serdeable,
+      RadarrSerdeable::WantedMoviesResponse(wanted_movies_response)
+    );
+  }
 }
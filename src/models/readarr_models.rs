@@ -0,0 +1,365 @@
+use super::{
+  HorizontallyScrollableText, Serdeable,
+  servarr_models::{
+    DiskSpace, HostConfig, Indexer, QualityProfile, RootFolder, SecurityConfig, Tag,
+  },
+};
+use crate::models::servarr_models::{LogResponse, QueueEvent, Update};
+use crate::serde_enum_from;
+use chrono::{DateTime, Utc};
+use clap::ValueEnum;
+use derivative::Derivative;
+use enum_display_style_derive::EnumDisplayStyle;
+use serde::{Deserialize, Serialize};
+use serde_json::{Number, Value};
+use std::fmt::{Display, Formatter};
+use strum::{Display, EnumIter};
+
+#[cfg(test)]
+#[path = "readarr_models_tests.rs"]
+mod readarr_models_tests;
+
+#[derive(Derivative, Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Author {
+  #[serde(deserialize_with = "super::from_i64")]
+  pub id: i64,
+  pub author_name: HorizontallyScrollableText,
+  pub foreign_author_id: String,
+  pub status: AuthorStatus,
+  pub overview: Option<String>,
+  pub disambiguation: Option<String>,
+  pub path: String,
+  #[serde(deserialize_with = "super::from_i64")]
+  pub quality_profile_id: i64,
+  #[serde(deserialize_with = "super::from_i64")]
+  pub metadata_profile_id: i64,
+  pub monitored: bool,
+  pub monitor_new_items: NewItemMonitorType,
+  pub genres: Vec<String>,
+  pub tags: Vec<Number>,
+  pub added: DateTime<Utc>,
+  pub ratings: Option<Ratings>,
+  pub statistics: Option<AuthorStatistics>,
+}
+
+#[derive(
+  Serialize, Deserialize, Default, PartialEq, Eq, Clone, Copy, Debug, Display, EnumDisplayStyle,
+)]
+#[serde(rename_all = "camelCase")]
+#[strum(serialize_all = "camelCase")]
+pub enum AuthorStatus {
+  #[default]
+  Continuing,
+  Ended,
+}
+
+#[derive(Derivative, Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Ratings {
+  #[serde(deserialize_with = "super::from_i64")]
+  pub votes: i64,
+  #[serde(deserialize_with = "super::from_f64")]
+  pub value: f64,
+}
+
+impl Eq for Ratings {}
+
+#[derive(Derivative, Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthorStatistics {
+  #[serde(deserialize_with = "super::from_i64")]
+  pub book_file_count: i64,
+  #[serde(deserialize_with = "super::from_i64")]
+  pub book_count: i64,
+  #[serde(deserialize_with = "super::from_i64")]
+  pub available_book_count: i64,
+  #[serde(deserialize_with = "super::from_i64")]
+  pub total_book_count: i64,
+  #[serde(deserialize_with = "super::from_i64")]
+  pub size_on_disk: i64,
+  #[serde(deserialize_with = "super::from_f64")]
+  pub percent_of_books: f64,
+}
+
+impl Eq for AuthorStatistics {}
+
+#[derive(Default, Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct MetadataProfile {
+  #[serde(deserialize_with = "super::from_i64")]
+  pub id: i64,
+  pub name: String,
+}
+
+impl From<(&i64, &String)> for MetadataProfile {
+  fn from(value: (&i64, &String)) -> Self {
+    MetadataProfile {
+      id: *value.0,
+      name: value.1.clone(),
+    }
+  }
+}
+
+#[derive(
+  Serialize,
+  Deserialize,
+  Default,
+  PartialEq,
+  Eq,
+  Clone,
+  Copy,
+  Debug,
+  EnumIter,
+  clap::ValueEnum,
+  Display,
+  EnumDisplayStyle,
+)]
+#[serde(rename_all = "camelCase")]
+#[strum(serialize_all = "camelCase")]
+pub enum NewItemMonitorType {
+  #[default]
+  #[display_style(name = "All Books")]
+  All,
+  #[display_style(name = "No New Books")]
+  None,
+  #[display_style(name = "New Books")]
+  New,
+}
+
+#[derive(
+  Serialize,
+  Deserialize,
+  Default,
+  PartialEq,
+  Eq,
+  Clone,
+  Copy,
+  Debug,
+  EnumIter,
+  clap::ValueEnum,
+  Display,
+  EnumDisplayStyle,
+)]
+#[serde(rename_all = "camelCase")]
+#[strum(serialize_all = "camelCase")]
+pub enum MonitorType {
+  #[default]
+  #[display_style(name = "All Books")]
+  All,
+  #[display_style(name = "Future Books")]
+  Future,
+  #[display_style(name = "Missing Books")]
+  Missing,
+  #[display_style(name = "Existing Books")]
+  Existing,
+  #[display_style(name = "First Book")]
+  First,
+  #[display_style(name = "Latest Book")]
+  Latest,
+  None,
+  Unknown,
+}
+
+#[derive(Default, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SystemStatus {
+  pub version: String,
+  pub start_time: DateTime<Utc>,
+}
+
+#[derive(Derivative, Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct AddAuthorSearchResult {
+  pub foreign_author_id: String,
+  pub author_name: HorizontallyScrollableText,
+  pub status: AuthorStatus,
+  pub overview: Option<String>,
+  pub disambiguation: Option<String>,
+  pub genres: Vec<String>,
+  pub ratings: Option<Ratings>,
+}
+
+#[derive(Default, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ReadarrCommandBody {
+  pub name: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub author_id: Option<i64>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub book_ids: Option<Vec<i64>>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Eq, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub struct DeleteParams {
+  pub id: i64,
+  pub delete_files: bool,
+  pub add_import_list_exclusion: bool,
+}
+
+#[derive(Default, Clone, Serialize, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct AddAuthorBody {
+  pub foreign_author_id: String,
+  pub author_name: String,
+  pub monitored: bool,
+  pub root_folder_path: String,
+  pub quality_profile_id: i64,
+  pub metadata_profile_id: i64,
+  pub tags: Vec<i64>,
+  #[serde(skip_serializing, skip_deserializing)]
+  pub tag_input_string: Option<String>,
+  pub add_options: AddAuthorOptions,
+}
+
+#[derive(Default, Clone, Serialize, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct AddAuthorOptions {
+  pub monitor: MonitorType,
+  pub monitor_new_items: NewItemMonitorType,
+  pub search_for_missing_books: bool,
+}
+
+#[derive(Default, Clone, Serialize, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct EditAuthorParams {
+  pub author_id: i64,
+  pub monitored: Option<bool>,
+  pub monitor_new_items: Option<NewItemMonitorType>,
+  pub quality_profile_id: Option<i64>,
+  pub metadata_profile_id: Option<i64>,
+  pub root_folder_path: Option<String>,
+  pub tags: Option<Vec<i64>>,
+  #[serde(skip_serializing, skip_deserializing)]
+  pub tag_input_string: Option<String>,
+  pub clear_tags: bool,
+}
+
+#[derive(Derivative, Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Book {
+  #[serde(deserialize_with = "super::from_i64")]
+  pub id: i64,
+  pub title: HorizontallyScrollableText,
+  #[serde(deserialize_with = "super::from_i64")]
+  pub author_id: i64,
+  pub foreign_book_id: String,
+  pub monitored: bool,
+  #[serde(default)]
+  pub any_edition_ok: bool,
+  #[serde(default, deserialize_with = "super::from_i64")]
+  pub page_count: i64,
+  pub series_title: Option<String>,
+  pub overview: Option<String>,
+  pub genres: Vec<String>,
+  pub ratings: Option<Ratings>,
+  pub release_date: Option<DateTime<Utc>>,
+  pub statistics: Option<BookStatistics>,
+}
+
+#[derive(Derivative, Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct BookStatistics {
+  #[serde(deserialize_with = "super::from_i64")]
+  pub book_file_count: i64,
+  #[serde(deserialize_with = "super::from_i64")]
+  pub book_count: i64,
+  #[serde(deserialize_with = "super::from_i64")]
+  pub total_book_count: i64,
+  #[serde(deserialize_with = "super::from_i64")]
+  pub size_on_disk: i64,
+  #[serde(deserialize_with = "super::from_f64")]
+  pub percent_of_books: f64,
+}
+
+impl Eq for BookStatistics {}
+
+#[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Edition {
+  #[serde(deserialize_with = "super::from_i64")]
+  pub id: i64,
+  #[serde(deserialize_with = "super::from_i64")]
+  pub book_id: i64,
+  pub foreign_edition_id: String,
+  pub title: String,
+  pub isbn13: Option<String>,
+  pub asin: Option<String>,
+  pub publisher: Option<String>,
+  pub language: Option<String>,
+  #[serde(default, deserialize_with = "super::from_i64")]
+  pub page_count: i64,
+  pub format: Option<String>,
+  #[serde(default)]
+  pub is_ebook: bool,
+  pub monitored: bool,
+  pub release_date: Option<DateTime<Utc>>,
+}
+
+#[derive(Default, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ReadarrTask {
+  pub name: String,
+  pub task_name: ReadarrTaskName,
+  #[serde(deserialize_with = "super::from_i64")]
+  pub interval: i64,
+  pub last_execution: DateTime<Utc>,
+  pub next_execution: DateTime<Utc>,
+}
+
+#[derive(Default, Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Copy, ValueEnum)]
+#[serde(rename_all = "PascalCase")]
+pub enum ReadarrTaskName {
+  #[default]
+  ApplicationUpdateCheck,
+  Backup,
+  CheckHealth,
+  Housekeeping,
+  ImportListSync,
+  MessagingCleanup,
+  RefreshAuthor,
+  RefreshMonitoredDownloads,
+  RescanFolders,
+  RssSync,
+}
+
+impl Display for ReadarrTaskName {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    let task_name = serde_json::to_string(&self)
+      .expect("Unable to serialize task name")
+      .replace('"', "");
+    write!(f, "{task_name}")
+  }
+}
+
+impl From<ReadarrSerdeable> for Serdeable {
+  fn from(value: ReadarrSerdeable) -> Serdeable {
+    Serdeable::Readarr(value)
+  }
+}
+
+serde_enum_from!(
+  ReadarrSerdeable {
+    AddAuthorSearchResults(Vec<AddAuthorSearchResult>),
+    Author(Author),
+    Authors(Vec<Author>),
+    Book(Book),
+    Books(Vec<Book>),
+    DiskSpaces(Vec<DiskSpace>),
+    Editions(Vec<Edition>),
+    HostConfig(HostConfig),
+    Indexers(Vec<Indexer>),
+    LogResponse(LogResponse),
+    MetadataProfiles(Vec<MetadataProfile>),
+    QualityProfiles(Vec<QualityProfile>),
+    QueueEvents(Vec<QueueEvent>),
+    RootFolders(Vec<RootFolder>),
+    SecurityConfig(SecurityConfig),
+    SystemStatus(SystemStatus),
+    Tag(Tag),
+    Tags(Vec<Tag>),
+    Tasks(Vec<ReadarrTask>),
+    Updates(Vec<Update>),
+    Value(Value),
+  }
+);
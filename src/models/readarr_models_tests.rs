@@ -0,0 +1,613 @@
+#[cfg(test)]
+mod tests {
+  use chrono::Utc;
+  use pretty_assertions::{assert_eq, assert_str_eq};
+  use serde_json::json;
+
+  use crate::models::readarr_models::{
+    AddAuthorSearchResult, Book, BookStatistics, Edition, MetadataProfile, MonitorType,
+    NewItemMonitorType, ReadarrTask, ReadarrTaskName, SystemStatus,
+  };
+  use crate::models::servarr_models::{
+    DiskSpace, HostConfig, Indexer, Log, LogResponse, QualityProfile, QueueEvent, RootFolder,
+    SecurityConfig, Tag, Update,
+  };
+  use crate::models::{
+    Serdeable,
+    readarr_models::{Author, AuthorStatistics, AuthorStatus, Ratings, ReadarrSerdeable},
+  };
+
+  #[test]
+  fn test_author_status_default() {
+    assert_eq!(AuthorStatus::default(), AuthorStatus::Continuing);
+  }
+
+  #[test]
+  fn test_author_status_display() {
+    assert_str_eq!(AuthorStatus::Continuing.to_string(), "continuing");
+    assert_str_eq!(AuthorStatus::Ended.to_string(), "ended");
+  }
+
+  #[test]
+  fn test_author_status_to_display_str() {
+    assert_str_eq!(AuthorStatus::Continuing.to_display_str(), "Continuing");
+    assert_str_eq!(AuthorStatus::Ended.to_display_str(), "Ended");
+  }
+
+  #[test]
+  fn test_new_item_monitor_type_display() {
+    assert_str_eq!(NewItemMonitorType::All.to_string(), "all");
+    assert_str_eq!(NewItemMonitorType::None.to_string(), "none");
+    assert_str_eq!(NewItemMonitorType::New.to_string(), "new");
+  }
+
+  #[test]
+  fn test_new_item_monitor_type_to_display_str() {
+    assert_str_eq!(NewItemMonitorType::All.to_display_str(), "All Books");
+    assert_str_eq!(NewItemMonitorType::None.to_display_str(), "No New Books");
+    assert_str_eq!(NewItemMonitorType::New.to_display_str(), "New Books");
+  }
+
+  #[test]
+  fn test_monitor_type_display() {
+    assert_str_eq!(MonitorType::All.to_string(), "all");
+    assert_str_eq!(MonitorType::Future.to_string(), "future");
+    assert_str_eq!(MonitorType::Missing.to_string(), "missing");
+    assert_str_eq!(MonitorType::Existing.to_string(), "existing");
+    assert_str_eq!(MonitorType::First.to_string(), "first");
+    assert_str_eq!(MonitorType::Latest.to_string(), "latest");
+    assert_str_eq!(MonitorType::None.to_string(), "none");
+    assert_str_eq!(MonitorType::Unknown.to_string(), "unknown");
+  }
+
+  #[test]
+  fn test_monitor_type_to_display_str() {
+    assert_str_eq!(MonitorType::All.to_display_str(), "All Books");
+    assert_str_eq!(MonitorType::Future.to_display_str(), "Future Books");
+    assert_str_eq!(MonitorType::Missing.to_display_str(), "Missing Books");
+    assert_str_eq!(MonitorType::Existing.to_display_str(), "Existing Books");
+    assert_str_eq!(MonitorType::First.to_display_str(), "First Book");
+    assert_str_eq!(MonitorType::Latest.to_display_str(), "Latest Book");
+    assert_str_eq!(MonitorType::None.to_display_str(), "None");
+    assert_str_eq!(MonitorType::Unknown.to_display_str(), "Unknown");
+  }
+
+  #[test]
+  fn test_readarr_task_name_display() {
+    assert_str_eq!(ReadarrTaskName::RefreshAuthor.to_string(), "RefreshAuthor");
+    assert_str_eq!(ReadarrTaskName::RssSync.to_string(), "RssSync");
+  }
+
+  #[test]
+  fn test_author_deserialization() {
+    let author_json = json!({
+      "id": 1,
+      "authorName": "Test Author",
+      "foreignAuthorId": "test-foreign-id",
+      "status": "continuing",
+      "overview": "Test overview",
+      "disambiguation": "Novelist",
+      "path": "/books/test-author",
+      "qualityProfileId": 1,
+      "metadataProfileId": 1,
+      "monitored": true,
+      "monitorNewItems": "all",
+      "genres": ["Fantasy", "Science Fiction"],
+      "tags": [1, 2],
+      "added": "2023-01-01T00:00:00Z",
+      "ratings": {
+        "votes": 100,
+        "value": 4.5
+      },
+      "statistics": {
+        "bookFileCount": 5,
+        "bookCount": 6,
+        "availableBookCount": 5,
+        "totalBookCount": 10,
+        "sizeOnDisk": 1000000,
+        "percentOfBooks": 83.33
+      }
+    });
+
+    let author: Author = serde_json::from_value(author_json).unwrap();
+
+    assert_eq!(author.id, 1);
+    assert_str_eq!(author.author_name.text, "Test Author");
+    assert_str_eq!(author.foreign_author_id, "test-foreign-id");
+    assert_eq!(author.status, AuthorStatus::Continuing);
+    assert_some_eq_x!(&author.overview, "Test overview");
+    assert_some_eq_x!(&author.disambiguation, "Novelist");
+    assert_str_eq!(author.path, "/books/test-author");
+    assert_eq!(author.quality_profile_id, 1);
+    assert_eq!(author.metadata_profile_id, 1);
+    assert!(author.monitored);
+    assert_eq!(author.monitor_new_items, NewItemMonitorType::All);
+    assert_eq!(author.genres, vec!["Fantasy", "Science Fiction"]);
+    assert_eq!(author.tags.len(), 2);
+    assert_some!(&author.ratings);
+    assert_some!(&author.statistics);
+
+    let ratings = author.ratings.unwrap();
+    assert_eq!(ratings.votes, 100);
+    assert_eq!(ratings.value, 4.5);
+
+    let stats = author.statistics.unwrap();
+    assert_eq!(stats.book_file_count, 5);
+    assert_eq!(stats.book_count, 6);
+    assert_eq!(stats.available_book_count, 5);
+    assert_eq!(stats.total_book_count, 10);
+    assert_eq!(stats.size_on_disk, 1000000);
+    assert_eq!(stats.percent_of_books, 83.33);
+  }
+
+  #[test]
+  fn test_author_with_optional_fields_none() {
+    let author_json = json!({
+      "id": 1,
+      "authorName": "Test Author",
+      "foreignAuthorId": "",
+      "status": "ended",
+      "path": "",
+      "qualityProfileId": 1,
+      "metadataProfileId": 1,
+      "monitored": false,
+      "monitorNewItems": "none",
+      "genres": [],
+      "tags": [],
+      "added": "2023-01-01T00:00:00Z"
+    });
+
+    let author: Author = serde_json::from_value(author_json).unwrap();
+
+    assert_eq!(author.status, AuthorStatus::Ended);
+    assert_none!(&author.overview);
+    assert_none!(&author.disambiguation);
+    assert_eq!(author.monitor_new_items, NewItemMonitorType::None);
+    assert_none!(&author.ratings);
+    assert_none!(&author.statistics);
+  }
+
+  #[test]
+  fn test_ratings_equality() {
+    let ratings1 = Ratings {
+      votes: 100,
+      value: 4.5,
+    };
+    let ratings2 = Ratings {
+      votes: 100,
+      value: 4.5,
+    };
+    let ratings3 = Ratings {
+      votes: 50,
+      value: 3.0,
+    };
+
+    assert_eq!(ratings1, ratings2);
+    assert_ne!(ratings1, ratings3);
+  }
+
+  #[test]
+  fn test_author_statistics_equality() {
+    let stats1 = AuthorStatistics {
+      book_file_count: 5,
+      book_count: 6,
+      available_book_count: 5,
+      total_book_count: 10,
+      size_on_disk: 1000000,
+      percent_of_books: 83.33,
+    };
+    let stats2 = stats1.clone();
+    let stats3 = AuthorStatistics::default();
+
+    assert_eq!(stats1, stats2);
+    assert_ne!(stats1, stats3);
+  }
+
+  #[test]
+  fn test_book_statistics_equality() {
+    let stats1 = BookStatistics {
+      book_file_count: 1,
+      book_count: 1,
+      total_book_count: 2,
+      size_on_disk: 1000,
+      percent_of_books: 50.0,
+    };
+    let stats2 = stats1.clone();
+    let stats3 = BookStatistics::default();
+
+    assert_eq!(stats1, stats2);
+    assert_ne!(stats1, stats3);
+  }
+
+  #[test]
+  fn test_book_deserialization() {
+    let book_json = json!({
+      "id": 1,
+      "title": "Test Book",
+      "authorId": 1,
+      "foreignBookId": "test-foreign-book-id",
+      "monitored": true,
+      "anyEditionOk": true,
+      "pageCount": 320,
+      "seriesTitle": "Test Series",
+      "overview": "Test overview",
+      "genres": ["Fantasy"],
+      "releaseDate": "2023-01-01T00:00:00Z",
+      "statistics": {
+        "bookFileCount": 1,
+        "bookCount": 1,
+        "totalBookCount": 2,
+        "sizeOnDisk": 1000,
+        "percentOfBooks": 50.0
+      }
+    });
+
+    let book: Book = serde_json::from_value(book_json).unwrap();
+
+    assert_eq!(book.id, 1);
+    assert_str_eq!(book.title.text, "Test Book");
+    assert_eq!(book.author_id, 1);
+    assert_str_eq!(book.foreign_book_id, "test-foreign-book-id");
+    assert!(book.monitored);
+    assert!(book.any_edition_ok);
+    assert_eq!(book.page_count, 320);
+    assert_some_eq_x!(&book.series_title, "Test Series");
+    assert_some_eq_x!(&book.overview, "Test overview");
+    assert_eq!(book.genres, vec!["Fantasy"]);
+    assert_none!(&book.ratings);
+    assert_some!(&book.release_date);
+    assert_eq!(book.statistics.unwrap().total_book_count, 2);
+  }
+
+  #[test]
+  fn test_edition_deserialization() {
+    let edition_json = json!({
+      "id": 1,
+      "bookId": 1,
+      "foreignEditionId": "test-foreign-edition-id",
+      "title": "Test Edition",
+      "isbn13": "9780000000000",
+      "publisher": "Test Publisher",
+      "language": "eng",
+      "pageCount": 320,
+      "format": "Paperback",
+      "isEbook": false,
+      "monitored": true
+    });
+
+    let edition: Edition = serde_json::from_value(edition_json).unwrap();
+
+    assert_eq!(edition.id, 1);
+    assert_eq!(edition.book_id, 1);
+    assert_str_eq!(edition.foreign_edition_id, "test-foreign-edition-id");
+    assert_str_eq!(edition.title, "Test Edition");
+    assert_some_eq_x!(&edition.isbn13, "9780000000000");
+    assert_none!(&edition.asin);
+    assert_some_eq_x!(&edition.publisher, "Test Publisher");
+    assert_some_eq_x!(&edition.language, "eng");
+    assert_eq!(edition.page_count, 320);
+    assert_some_eq_x!(&edition.format, "Paperback");
+    assert!(!edition.is_ebook);
+    assert!(edition.monitored);
+    assert_none!(&edition.release_date);
+  }
+
+  #[test]
+  fn test_add_author_search_result_deserialization() {
+    let search_result_json = json!({
+      "foreignAuthorId": "test-foreign-id",
+      "authorName": "Test Author",
+      "status": "continuing",
+      "overview": "Test overview",
+      "disambiguation": "Novelist",
+      "genres": ["Fantasy"],
+      "ratings": {
+        "votes": 100,
+        "value": 4.5
+      }
+    });
+
+    let search_result: AddAuthorSearchResult = serde_json::from_value(search_result_json).unwrap();
+
+    assert_str_eq!(search_result.foreign_author_id, "test-foreign-id");
+    assert_str_eq!(search_result.author_name.text, "Test Author");
+    assert_eq!(search_result.status, AuthorStatus::Continuing);
+    assert_some_eq_x!(&search_result.overview, "Test overview");
+    assert_some_eq_x!(&search_result.disambiguation, "Novelist");
+    assert_eq!(search_result.genres, vec!["Fantasy"]);
+    assert_some!(&search_result.ratings);
+  }
+
+  #[test]
+  fn test_readarr_serdeable_from() {
+    let readarr_serdeable = ReadarrSerdeable::Value(json!({}));
+
+    let serdeable: Serdeable = Serdeable::from(readarr_serdeable.clone());
+
+    assert_eq!(serdeable, Serdeable::Readarr(readarr_serdeable));
+  }
+
+  #[test]
+  fn test_readarr_serdeable_from_unit() {
+    let readarr_serdeable = ReadarrSerdeable::from(());
+
+    assert_eq!(readarr_serdeable, ReadarrSerdeable::Value(json!({})));
+  }
+
+  #[test]
+  fn test_readarr_serdeable_from_value() {
+    let value = json!({"test": "test"});
+
+    let readarr_serdeable: ReadarrSerdeable = value.clone().into();
+
+    assert_eq!(readarr_serdeable, ReadarrSerdeable::Value(value));
+  }
+
+  #[test]
+  fn test_readarr_serdeable_from_add_author_search_results() {
+    let search_results = vec![AddAuthorSearchResult {
+      foreign_author_id: "test-id".to_owned(),
+      ..AddAuthorSearchResult::default()
+    }];
+
+    let readarr_serdeable: ReadarrSerdeable = search_results.clone().into();
+
+    assert_eq!(
+      readarr_serdeable,
+      ReadarrSerdeable::AddAuthorSearchResults(search_results)
+    );
+  }
+
+  #[test]
+  fn test_readarr_serdeable_from_author() {
+    let author = Author {
+      id: 1,
+      ..Author::default()
+    };
+
+    let readarr_serdeable: ReadarrSerdeable = author.clone().into();
+
+    assert_eq!(readarr_serdeable, ReadarrSerdeable::Author(author));
+  }
+
+  #[test]
+  fn test_readarr_serdeable_from_authors() {
+    let authors = vec![Author {
+      id: 1,
+      ..Author::default()
+    }];
+
+    let readarr_serdeable: ReadarrSerdeable = authors.clone().into();
+
+    assert_eq!(readarr_serdeable, ReadarrSerdeable::Authors(authors));
+  }
+
+  #[test]
+  fn test_readarr_serdeable_from_book() {
+    let book = Book {
+      id: 1,
+      ..Book::default()
+    };
+
+    let readarr_serdeable: ReadarrSerdeable = book.clone().into();
+
+    assert_eq!(readarr_serdeable, ReadarrSerdeable::Book(book));
+  }
+
+  #[test]
+  fn test_readarr_serdeable_from_books() {
+    let books = vec![Book {
+      id: 1,
+      ..Book::default()
+    }];
+
+    let readarr_serdeable: ReadarrSerdeable = books.clone().into();
+
+    assert_eq!(readarr_serdeable, ReadarrSerdeable::Books(books));
+  }
+
+  #[test]
+  fn test_readarr_serdeable_from_disk_spaces() {
+    let disk_spaces = vec![DiskSpace {
+      path: Some("/path".to_owned()),
+      free_space: 1,
+      total_space: 1,
+    }];
+
+    let readarr_serdeable: ReadarrSerdeable = disk_spaces.clone().into();
+
+    assert_eq!(readarr_serdeable, ReadarrSerdeable::DiskSpaces(disk_spaces));
+  }
+
+  #[test]
+  fn test_readarr_serdeable_from_editions() {
+    let editions = vec![Edition {
+      id: 1,
+      ..Edition::default()
+    }];
+
+    let readarr_serdeable: ReadarrSerdeable = editions.clone().into();
+
+    assert_eq!(readarr_serdeable, ReadarrSerdeable::Editions(editions));
+  }
+
+  #[test]
+  fn test_readarr_serdeable_from_host_config() {
+    let host_config = HostConfig {
+      port: 1234,
+      ..HostConfig::default()
+    };
+
+    let readarr_serdeable: ReadarrSerdeable = host_config.clone().into();
+
+    assert_eq!(readarr_serdeable, ReadarrSerdeable::HostConfig(host_config));
+  }
+
+  #[test]
+  fn test_readarr_serdeable_from_indexers() {
+    let indexers = vec![Indexer {
+      id: 1,
+      ..Indexer::default()
+    }];
+
+    let readarr_serdeable: ReadarrSerdeable = indexers.clone().into();
+
+    assert_eq!(readarr_serdeable, ReadarrSerdeable::Indexers(indexers));
+  }
+
+  #[test]
+  fn test_readarr_serdeable_from_log_response() {
+    let log_response = LogResponse {
+      records: vec![Log {
+        level: "info".to_owned(),
+        ..Log::default()
+      }],
+    };
+
+    let readarr_serdeable: ReadarrSerdeable = log_response.clone().into();
+
+    assert_eq!(
+      readarr_serdeable,
+      ReadarrSerdeable::LogResponse(log_response)
+    );
+  }
+
+  #[test]
+  fn test_readarr_serdeable_from_metadata_profiles() {
+    let metadata_profiles = vec![MetadataProfile {
+      id: 1,
+      name: "Standard".to_owned(),
+    }];
+
+    let readarr_serdeable: ReadarrSerdeable = metadata_profiles.clone().into();
+
+    assert_eq!(
+      readarr_serdeable,
+      ReadarrSerdeable::MetadataProfiles(metadata_profiles)
+    );
+  }
+
+  #[test]
+  fn test_readarr_serdeable_from_quality_profiles() {
+    let quality_profiles = vec![QualityProfile {
+      id: 1,
+      name: "eBook".to_owned(),
+      ..QualityProfile::default()
+    }];
+
+    let readarr_serdeable: ReadarrSerdeable = quality_profiles.clone().into();
+
+    assert_eq!(
+      readarr_serdeable,
+      ReadarrSerdeable::QualityProfiles(quality_profiles)
+    );
+  }
+
+  #[test]
+  fn test_readarr_serdeable_from_queue_events() {
+    let queue_events = vec![QueueEvent {
+      name: "test".to_owned(),
+      ..QueueEvent::default()
+    }];
+
+    let readarr_serdeable: ReadarrSerdeable = queue_events.clone().into();
+
+    assert_eq!(
+      readarr_serdeable,
+      ReadarrSerdeable::QueueEvents(queue_events)
+    );
+  }
+
+  #[test]
+  fn test_readarr_serdeable_from_root_folders() {
+    let root_folders = vec![RootFolder {
+      id: 1,
+      ..RootFolder::default()
+    }];
+
+    let readarr_serdeable: ReadarrSerdeable = root_folders.clone().into();
+
+    assert_eq!(
+      readarr_serdeable,
+      ReadarrSerdeable::RootFolders(root_folders)
+    );
+  }
+
+  #[test]
+  fn test_readarr_serdeable_from_security_config() {
+    let security_config = SecurityConfig {
+      username: Some("test".to_owned()),
+      ..SecurityConfig::default()
+    };
+
+    let readarr_serdeable: ReadarrSerdeable = security_config.clone().into();
+
+    assert_eq!(
+      readarr_serdeable,
+      ReadarrSerdeable::SecurityConfig(security_config)
+    );
+  }
+
+  #[test]
+  fn test_readarr_serdeable_from_system_status() {
+    let system_status = SystemStatus {
+      version: "1.0.0".to_owned(),
+      start_time: Utc::now(),
+    };
+
+    let readarr_serdeable: ReadarrSerdeable = system_status.clone().into();
+
+    assert_eq!(
+      readarr_serdeable,
+      ReadarrSerdeable::SystemStatus(system_status)
+    );
+  }
+
+  #[test]
+  fn test_readarr_serdeable_from_tag() {
+    let tag = Tag {
+      id: 1,
+      label: "fantasy".to_owned(),
+    };
+
+    let readarr_serdeable: ReadarrSerdeable = tag.clone().into();
+
+    assert_eq!(readarr_serdeable, ReadarrSerdeable::Tag(tag));
+  }
+
+  #[test]
+  fn test_readarr_serdeable_from_tags() {
+    let tags = vec![Tag {
+      id: 1,
+      label: "fantasy".to_owned(),
+    }];
+
+    let readarr_serdeable: ReadarrSerdeable = tags.clone().into();
+
+    assert_eq!(readarr_serdeable, ReadarrSerdeable::Tags(tags));
+  }
+
+  #[test]
+  fn test_readarr_serdeable_from_tasks() {
+    let tasks = vec![ReadarrTask {
+      name: "test".to_owned(),
+      ..ReadarrTask::default()
+    }];
+
+    let readarr_serdeable: ReadarrSerdeable = tasks.clone().into();
+
+    assert_eq!(readarr_serdeable, ReadarrSerdeable::Tasks(tasks));
+  }
+
+  #[test]
+  fn test_readarr_serdeable_from_updates() {
+    let updates = vec![Update {
+      version: "test".to_owned(),
+      ..Update::default()
+    }];
+
+    let readarr_serdeable: ReadarrSerdeable = updates.clone().into();
+
+    assert_eq!(readarr_serdeable, ReadarrSerdeable::Updates(updates));
+  }
+}
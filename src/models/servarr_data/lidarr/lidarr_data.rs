@@ -1,49 +1,61 @@
 use serde_json::Number;
 
-use super::modals::{AddArtistModal, AddRootFolderModal, AlbumDetailsModal, EditArtistModal};
+use super::modals::{
+  AddArtistModal, AddRootFolderModal, AlbumDetailsModal, EditArtistModal, ManualImportModal,
+};
 use crate::app::context_clues::{
-  BLOCKLIST_CONTEXT_CLUES, DOWNLOADS_CONTEXT_CLUES, HISTORY_CONTEXT_CLUES, INDEXERS_CONTEXT_CLUES,
-  ROOT_FOLDERS_CONTEXT_CLUES, SYSTEM_CONTEXT_CLUES,
+  BLOCKLIST_CONTEXT_CLUES, CALENDAR_CONTEXT_CLUES, DOWNLOAD_CLIENTS_CONTEXT_CLUES,
+  DOWNLOADS_CONTEXT_CLUES, HISTORY_CONTEXT_CLUES, INDEXERS_CONTEXT_CLUES,
+  QUALITY_PROFILES_CONTEXT_CLUES, ROOT_FOLDERS_CONTEXT_CLUES, SYSTEM_WITH_BACKUPS_CONTEXT_CLUES,
+  WANTED_CONTEXT_CLUES,
 };
 use crate::app::lidarr::lidarr_context_clues::{
   ARTIST_DETAILS_CONTEXT_CLUES, ARTIST_HISTORY_CONTEXT_CLUES, ARTISTS_CONTEXT_CLUES,
   MANUAL_ARTIST_SEARCH_CONTEXT_CLUES,
 };
-use crate::models::lidarr_models::{BlocklistItem, LidarrRelease, LidarrTask};
-use crate::models::servarr_data::modals::EditIndexerModal;
-use crate::models::servarr_models::{IndexerSettings, QueueEvent};
+use crate::models::lidarr_models::{BlocklistItem, LidarrRelease, LidarrTask, NamingExamples};
+use crate::models::servarr_data::modals::{
+  AddIndexerModal, BulkEditModal, DownloadClientModal, EditIndexerModal, MediaManagementFormField,
+  MediaManagementModal, QualityProfileModal,
+};
+use crate::models::servarr_models::{Backup, IndexerSettings, QueueEvent, QueueRemovalOptions};
 use crate::models::stateful_list::StatefulList;
 use crate::models::{
   BlockSelectionState, HorizontallyScrollableText, Route, ScrollableText, TabRoute, TabState,
   lidarr_models::{AddArtistSearchResult, Album, Artist, DownloadRecord, LidarrHistoryItem},
   servarr_data::modals::IndexerTestResultModalItem,
-  servarr_models::{DiskSpace, Indexer, RootFolder},
+  servarr_models::{DiskSpace, DownloadClient, Indexer, QualityProfile, RootFolder},
   stateful_table::StatefulTable,
 };
 use crate::network::lidarr_network::LidarrEvent;
 use bimap::BiMap;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use itertools::Itertools;
 use strum::EnumIter;
 #[cfg(test)]
 use {
   super::modals::TrackDetailsModal,
-  crate::models::lidarr_models::{MonitorType, NewItemMonitorType},
+  crate::models::lidarr_models::{LidarrManualImportItem, MonitorType, NewItemMonitorType},
+  crate::models::servarr_models::ManualImportRejection,
   crate::models::stateful_table::SortOption,
   crate::network::lidarr_network::lidarr_network_test_utils::test_utils::blocklist_item,
   crate::network::lidarr_network::lidarr_network_test_utils::test_utils::indexer_settings,
   crate::network::lidarr_network::lidarr_network_test_utils::test_utils::quality_profile_map,
   crate::network::lidarr_network::lidarr_network_test_utils::test_utils::{
     add_artist_search_result, album, artist, download_record, indexer, lidarr_history_item,
-    metadata_profile, metadata_profile_map, quality_profile, root_folder, tags_map,
+    manual_import_item, media_management_settings, metadata_profile, metadata_profile_map,
+    naming_examples, quality_profile, root_folder, tags_map,
   },
   crate::network::lidarr_network::lidarr_network_test_utils::test_utils::{log_line, task},
   crate::network::lidarr_network::lidarr_network_test_utils::test_utils::{
     torrent_release, usenet_release,
   },
   crate::network::lidarr_network::lidarr_network_test_utils::test_utils::{track, track_file},
+  crate::network::servarr_test_utils::backup,
   crate::network::servarr_test_utils::diskspace,
+  crate::network::servarr_test_utils::download_client,
   crate::network::servarr_test_utils::indexer_test_result,
+  crate::network::servarr_test_utils::quality_profile_details,
   crate::network::servarr_test_utils::queued_event,
   crate::network::sonarr_network::sonarr_network_test_utils::test_utils::updates,
   crate::sort_option,
@@ -58,6 +70,7 @@ pub struct LidarrData<'a> {
   pub add_artist_modal: Option<AddArtistModal>,
   pub add_artist_search: Option<HorizontallyScrollableText>,
   pub add_import_list_exclusion: bool,
+  pub add_indexer_modal: Option<AddIndexerModal>,
   pub add_root_folder_modal: Option<AddRootFolderModal>,
   pub add_searched_artists: Option<StatefulTable<AddArtistSearchResult>>,
   pub albums: StatefulTable<Album>,
@@ -66,6 +79,10 @@ pub struct LidarrData<'a> {
   pub artist_info_tabs: TabState,
   pub artists: StatefulTable<Artist>,
   pub blocklist: StatefulTable<BlocklistItem>,
+  pub bulk_edit_modal: Option<BulkEditModal>,
+  pub calendar: StatefulTable<Album>,
+  pub calendar_start: Option<NaiveDate>,
+  pub cutoff_unmet: StatefulTable<Album>,
   pub delete_files: bool,
   pub discography_releases: StatefulTable<LidarrRelease>,
   pub disk_space_vec: Vec<DiskSpace>,
@@ -74,15 +91,27 @@ pub struct LidarrData<'a> {
   pub edit_indexer_modal: Option<EditIndexerModal>,
   pub history: StatefulTable<LidarrHistoryItem>,
   pub indexers: StatefulTable<Indexer>,
+  pub indexer_schema: StatefulList<Indexer>,
+  pub download_clients: StatefulTable<DownloadClient>,
+  pub download_client_schema: StatefulList<DownloadClient>,
+  pub download_client_modal: Option<DownloadClientModal>,
+  pub download_client_test_errors: Option<String>,
+  pub download_client_test_all_results: Option<StatefulTable<IndexerTestResultModalItem>>,
+  pub quality_profiles: StatefulTable<QualityProfile>,
+  pub quality_profile_modal: Option<QualityProfileModal>,
   pub indexer_settings: Option<IndexerSettings>,
   pub indexer_test_all_results: Option<StatefulTable<IndexerTestResultModalItem>>,
   pub indexer_test_errors: Option<String>,
   pub logs: StatefulList<HorizontallyScrollableText>,
   pub log_details: StatefulList<HorizontallyScrollableText>,
   pub main_tabs: TabState,
+  pub manual_import_modal: Option<ManualImportModal>,
+  pub media_management_modal: Option<MediaManagementModal>,
   pub metadata_profile_map: BiMap<i64, String>,
+  pub naming_examples: Option<NamingExamples>,
   pub prompt_confirm: bool,
   pub prompt_confirm_action: Option<LidarrEvent>,
+  pub queue_removal_options: QueueRemovalOptions,
   pub quality_profile_map: BiMap<i64, String>,
   pub queued_events: StatefulTable<QueueEvent>,
   pub root_folders: StatefulTable<RootFolder>,
@@ -90,8 +119,10 @@ pub struct LidarrData<'a> {
   pub start_time: DateTime<Utc>,
   pub tags_map: BiMap<i64, String>,
   pub tasks: StatefulTable<LidarrTask>,
+  pub backups: StatefulTable<Backup>,
   pub updates: ScrollableText,
   pub version: String,
+  pub wanted_missing: StatefulTable<Album>,
 }
 
 impl LidarrData<'_> {
@@ -145,6 +176,7 @@ impl<'a> Default for LidarrData<'a> {
       add_artist_modal: None,
       add_artist_search: None,
       add_import_list_exclusion: false,
+      add_indexer_modal: None,
       add_root_folder_modal: None,
       add_searched_artists: None,
       albums: StatefulTable::default(),
@@ -152,6 +184,10 @@ impl<'a> Default for LidarrData<'a> {
       artist_history: StatefulTable::default(),
       artists: StatefulTable::default(),
       blocklist: StatefulTable::default(),
+      bulk_edit_modal: None,
+      calendar: StatefulTable::default(),
+      calendar_start: None,
+      cutoff_unmet: StatefulTable::default(),
       delete_files: false,
       discography_releases: StatefulTable::default(),
       disk_space_vec: Vec::new(),
@@ -160,14 +196,26 @@ impl<'a> Default for LidarrData<'a> {
       edit_indexer_modal: None,
       history: StatefulTable::default(),
       indexers: StatefulTable::default(),
+      indexer_schema: StatefulList::default(),
+      download_clients: StatefulTable::default(),
+      download_client_schema: StatefulList::default(),
+      download_client_modal: None,
+      download_client_test_errors: None,
+      download_client_test_all_results: None,
+      quality_profiles: StatefulTable::default(),
+      quality_profile_modal: None,
       indexer_settings: None,
       indexer_test_all_results: None,
       indexer_test_errors: None,
       logs: StatefulList::default(),
       log_details: StatefulList::default(),
+      manual_import_modal: None,
+      media_management_modal: None,
       metadata_profile_map: BiMap::new(),
+      naming_examples: None,
       prompt_confirm: false,
       prompt_confirm_action: None,
+      queue_removal_options: QueueRemovalOptions::default(),
       quality_profile_map: BiMap::new(),
       queued_events: StatefulTable::default(),
       root_folders: StatefulTable::default(),
@@ -175,8 +223,10 @@ impl<'a> Default for LidarrData<'a> {
       start_time: DateTime::default(),
       tags_map: BiMap::new(),
       tasks: StatefulTable::default(),
+      backups: StatefulTable::default(),
       updates: ScrollableText::default(),
       version: String::new(),
+      wanted_missing: StatefulTable::default(),
       main_tabs: TabState::new(vec![
         TabRoute {
           title: "Library".to_string(),
@@ -214,10 +264,34 @@ impl<'a> Default for LidarrData<'a> {
           contextual_help: Some(&INDEXERS_CONTEXT_CLUES),
           config: None,
         },
+        TabRoute {
+          title: "Download Clients".to_string(),
+          route: ActiveLidarrBlock::DownloadClients.into(),
+          contextual_help: Some(&DOWNLOAD_CLIENTS_CONTEXT_CLUES),
+          config: None,
+        },
+        TabRoute {
+          title: "Quality Profiles".to_string(),
+          route: ActiveLidarrBlock::QualityProfiles.into(),
+          contextual_help: Some(&QUALITY_PROFILES_CONTEXT_CLUES),
+          config: None,
+        },
         TabRoute {
           title: "System".to_string(),
           route: ActiveLidarrBlock::System.into(),
-          contextual_help: Some(&SYSTEM_CONTEXT_CLUES),
+          contextual_help: Some(&SYSTEM_WITH_BACKUPS_CONTEXT_CLUES),
+          config: None,
+        },
+        TabRoute {
+          title: "Calendar".to_string(),
+          route: ActiveLidarrBlock::Calendar.into(),
+          contextual_help: Some(&CALENDAR_CONTEXT_CLUES),
+          config: None,
+        },
+        TabRoute {
+          title: "Wanted".to_string(),
+          route: ActiveLidarrBlock::WantedMissing.into(),
+          contextual_help: Some(&WANTED_CONTEXT_CLUES),
           config: None,
         },
       ]),
@@ -283,6 +357,11 @@ impl LidarrData<'_> {
       .metadata_profile_list
       .set_items(vec![metadata_profile().name]);
 
+    let bulk_edit_modal = BulkEditModal {
+      tags: "alex".into(),
+      ..BulkEditModal::new(vec![quality_profile().name], vec![root_folder().path])
+    };
+
     let mut add_root_folder_modal = AddRootFolderModal {
       name: "Test Root Folder".into(),
       path: "/nfs/music".into(),
@@ -354,8 +433,28 @@ impl LidarrData<'_> {
     let mut indexer_test_all_results = StatefulTable::default();
     indexer_test_all_results.set_items(vec![indexer_test_result()]);
 
+    let mut manual_import_modal = ManualImportModal {
+      download_id: "abc123".to_owned(),
+      ..ManualImportModal::default()
+    };
+    manual_import_modal.items.set_items(vec![
+      manual_import_item(),
+      LidarrManualImportItem {
+        id: 2,
+        path: "/nfs/downloads/Test/cover.flac".to_owned(),
+        name: "cover".to_owned(),
+        tracks: Vec::new(),
+        rejections: vec![ManualImportRejection {
+          reason: "Not an audio file".to_owned(),
+        }],
+        ..manual_import_item()
+      },
+    ]);
+    manual_import_modal.artist_list.set_items(vec![artist()]);
+
     let mut lidarr_data = LidarrData {
       album_details_modal: Some(album_details_modal),
+      bulk_edit_modal: Some(bulk_edit_modal),
       delete_files: true,
       disk_space_vec: vec![diskspace()],
       quality_profile_map: quality_profile_map(),
@@ -367,6 +466,7 @@ impl LidarrData<'_> {
       indexer_settings: Some(indexer_settings()),
       indexer_test_all_results: Some(indexer_test_all_results),
       indexer_test_errors: Some("error".to_string()),
+      manual_import_modal: Some(manual_import_modal),
       start_time: DateTime::from(DateTime::parse_from_rfc3339("2023-05-20T21:29:16Z").unwrap()),
       tags_map: tags_map(),
       updates: updates(),
@@ -390,6 +490,9 @@ impl LidarrData<'_> {
     lidarr_data.artists.filter = Some("artist filter".into());
     lidarr_data.blocklist.set_items(vec![blocklist_item()]);
     lidarr_data.blocklist.sorting(vec![sort_option!(id)]);
+    lidarr_data.calendar.set_items(vec![album()]);
+    lidarr_data.calendar_start = NaiveDate::from_ymd_opt(2022, 12, 26);
+    lidarr_data.cutoff_unmet.set_items(vec![album()]);
     lidarr_data.downloads.set_items(vec![download_record()]);
     lidarr_data.history.set_items(vec![lidarr_history_item()]);
     lidarr_data.history.sorting(vec![SortOption {
@@ -406,6 +509,24 @@ impl LidarrData<'_> {
       .sorting(vec![sort_option!(indexer_id)]);
     lidarr_data.root_folders.set_items(vec![root_folder()]);
     lidarr_data.indexers.set_items(vec![indexer()]);
+    lidarr_data.indexer_schema.set_items(vec![indexer()]);
+    lidarr_data
+      .download_clients
+      .set_items(vec![download_client()]);
+    lidarr_data
+      .download_client_schema
+      .set_items(vec![download_client()]);
+    lidarr_data.download_client_modal = Some(DownloadClientModal::from(&download_client()));
+    lidarr_data
+      .quality_profiles
+      .set_items(vec![quality_profile_details()]);
+    lidarr_data.quality_profile_modal = Some(QualityProfileModal::from(&quality_profile_details()));
+    lidarr_data.add_indexer_modal = Some(AddIndexerModal::from(&indexer()));
+    lidarr_data.media_management_modal = Some(MediaManagementModal::new(
+      &media_management_settings(),
+      &MEDIA_MANAGEMENT_NAMING_FIELDS,
+    ));
+    lidarr_data.naming_examples = Some(naming_examples());
     lidarr_data.queued_events.set_items(vec![queued_event()]);
     lidarr_data.add_artist_search = Some("Test Artist".into());
     let mut add_searched_artists = StatefulTable::default();
@@ -414,6 +535,8 @@ impl LidarrData<'_> {
     lidarr_data.logs.set_items(vec![log_line().into()]);
     lidarr_data.log_details.set_items(vec![log_line().into()]);
     lidarr_data.tasks.set_items(vec![task()]);
+    lidarr_data.backups.set_items(vec![backup()]);
+    lidarr_data.wanted_missing.set_items(vec![album()]);
 
     lidarr_data
   }
@@ -459,9 +582,20 @@ pub enum ActiveLidarrBlock {
   AutomaticallySearchArtistPrompt,
   Blocklist,
   BlocklistItemDetails,
+  DeleteBackupPrompt,
   DeleteBlocklistItemPrompt,
   BlocklistClearAllItemsPrompt,
   BlocklistSortPrompt,
+  BulkEditArtistsPrompt,
+  BulkEditArtistsConfirmPrompt,
+  BulkEditArtistsSelectApplyTags,
+  BulkEditArtistsSelectMonitored,
+  BulkEditArtistsSelectQualityProfile,
+  BulkEditArtistsSelectRootFolder,
+  BulkEditArtistsTagsInput,
+  BulkEditArtistsToggleMoveFiles,
+  Calendar,
+  CalendarAgenda,
   DeleteAlbumPrompt,
   DeleteAlbumConfirmPrompt,
   DeleteAlbumToggleDeleteFile,
@@ -472,6 +606,12 @@ pub enum ActiveLidarrBlock {
   DeleteArtistToggleAddListExclusion,
   DeleteTrackFilePrompt,
   DeleteDownloadPrompt,
+  DeleteDownloadConfirmPrompt,
+  DeleteDownloadToggleRemoveFromClient,
+  DeleteDownloadToggleBlocklist,
+  DeleteDownloadToggleSkipRedownload,
+  DeleteDownloadToggleChangeCategory,
+  GrabDownloadPrompt,
   DeleteRootFolderPrompt,
   Downloads,
   EditArtistPrompt,
@@ -493,6 +633,16 @@ pub enum ActiveLidarrBlock {
   EditIndexerUrlInput,
   EditIndexerPriorityInput,
   EditIndexerTagsInput,
+  AddIndexerFieldInput,
+  AddIndexerPrompt,
+  AddIndexerSelectImplementation,
+  AddDownloadClientSelectImplementation,
+  DeleteDownloadClientPrompt,
+  DownloadClientFieldInput,
+  DownloadClientPrompt,
+  DownloadClients,
+  EditQualityProfile,
+  QualityProfiles,
   DeleteIndexerPrompt,
   FilterAlbumHistory,
   FilterAlbumHistoryError,
@@ -519,7 +669,14 @@ pub enum ActiveLidarrBlock {
   ManualArtistSearch,
   ManualArtistSearchConfirmPrompt,
   ManualArtistSearchSortPrompt,
+  ManualImport,
+  ManualImportConfirmPrompt,
+  ManualImportSelectArtist,
+  MediaManagementFieldInput,
+  MediaManagementSettingsPrompt,
   TestAllIndexers,
+  TestDownloadClient,
+  TestAllDownloadClients,
   TestIndexer,
   RootFolders,
   SearchAlbumHistory,
@@ -537,6 +694,7 @@ pub enum ActiveLidarrBlock {
   SearchTrackHistory,
   SearchTrackHistoryError,
   System,
+  SystemBackups,
   SystemLogs,
   SystemQueuedEvents,
   SystemTasks,
@@ -549,6 +707,10 @@ pub enum ActiveLidarrBlock {
   UpdateAllArtistsPrompt,
   UpdateAndScanArtistPrompt,
   UpdateDownloadsPrompt,
+  WantedCutoffUnmet,
+  WantedMissing,
+  WantedSearchAllPrompt,
+  WantedSearchSelectedPrompt,
 }
 
 pub static LIBRARY_BLOCKS: [ActiveLidarrBlock; 7] = [
@@ -605,10 +767,38 @@ pub static BLOCKLIST_BLOCKS: [ActiveLidarrBlock; 5] = [
   ActiveLidarrBlock::BlocklistSortPrompt,
 ];
 
-pub static DOWNLOADS_BLOCKS: [ActiveLidarrBlock; 3] = [
+pub static DOWNLOADS_BLOCKS: [ActiveLidarrBlock; 12] = [
   ActiveLidarrBlock::Downloads,
   ActiveLidarrBlock::DeleteDownloadPrompt,
+  ActiveLidarrBlock::DeleteDownloadConfirmPrompt,
+  ActiveLidarrBlock::DeleteDownloadToggleRemoveFromClient,
+  ActiveLidarrBlock::DeleteDownloadToggleBlocklist,
+  ActiveLidarrBlock::DeleteDownloadToggleSkipRedownload,
+  ActiveLidarrBlock::DeleteDownloadToggleChangeCategory,
+  ActiveLidarrBlock::GrabDownloadPrompt,
   ActiveLidarrBlock::UpdateDownloadsPrompt,
+  ActiveLidarrBlock::ManualImport,
+  ActiveLidarrBlock::ManualImportConfirmPrompt,
+  ActiveLidarrBlock::ManualImportSelectArtist,
+];
+pub const DELETE_DOWNLOAD_SELECTION_BLOCKS: &[&[ActiveLidarrBlock]] = &[
+  &[ActiveLidarrBlock::DeleteDownloadToggleRemoveFromClient],
+  &[ActiveLidarrBlock::DeleteDownloadToggleBlocklist],
+  &[ActiveLidarrBlock::DeleteDownloadToggleSkipRedownload],
+  &[ActiveLidarrBlock::DeleteDownloadToggleChangeCategory],
+  &[ActiveLidarrBlock::DeleteDownloadConfirmPrompt],
+];
+
+pub static CALENDAR_BLOCKS: [ActiveLidarrBlock; 2] = [
+  ActiveLidarrBlock::Calendar,
+  ActiveLidarrBlock::CalendarAgenda,
+];
+
+pub static WANTED_BLOCKS: [ActiveLidarrBlock; 4] = [
+  ActiveLidarrBlock::WantedMissing,
+  ActiveLidarrBlock::WantedCutoffUnmet,
+  ActiveLidarrBlock::WantedSearchAllPrompt,
+  ActiveLidarrBlock::WantedSearchSelectedPrompt,
 ];
 
 pub static HISTORY_BLOCKS: [ActiveLidarrBlock; 7] = [
@@ -693,6 +883,27 @@ pub const EDIT_ARTIST_SELECTION_BLOCKS: &[&[ActiveLidarrBlock]] = &[
   &[ActiveLidarrBlock::EditArtistConfirmPrompt],
 ];
 
+pub const BULK_EDIT_ARTISTS_BLOCKS: [ActiveLidarrBlock; 8] = [
+  ActiveLidarrBlock::BulkEditArtistsPrompt,
+  ActiveLidarrBlock::BulkEditArtistsConfirmPrompt,
+  ActiveLidarrBlock::BulkEditArtistsSelectApplyTags,
+  ActiveLidarrBlock::BulkEditArtistsSelectMonitored,
+  ActiveLidarrBlock::BulkEditArtistsSelectQualityProfile,
+  ActiveLidarrBlock::BulkEditArtistsSelectRootFolder,
+  ActiveLidarrBlock::BulkEditArtistsTagsInput,
+  ActiveLidarrBlock::BulkEditArtistsToggleMoveFiles,
+];
+
+pub const BULK_EDIT_ARTISTS_SELECTION_BLOCKS: &[&[ActiveLidarrBlock]] = &[
+  &[ActiveLidarrBlock::BulkEditArtistsSelectMonitored],
+  &[ActiveLidarrBlock::BulkEditArtistsSelectQualityProfile],
+  &[ActiveLidarrBlock::BulkEditArtistsSelectRootFolder],
+  &[ActiveLidarrBlock::BulkEditArtistsToggleMoveFiles],
+  &[ActiveLidarrBlock::BulkEditArtistsTagsInput],
+  &[ActiveLidarrBlock::BulkEditArtistsSelectApplyTags],
+  &[ActiveLidarrBlock::BulkEditArtistsConfirmPrompt],
+];
+
 pub const ROOT_FOLDERS_BLOCKS: [ActiveLidarrBlock; 2] = [
   ActiveLidarrBlock::RootFolders,
   ActiveLidarrBlock::DeleteRootFolderPrompt,
@@ -710,6 +921,43 @@ pub static ADD_ROOT_FOLDER_BLOCKS: [ActiveLidarrBlock; 9] = [
   ActiveLidarrBlock::AddRootFolderTagsInput,
 ];
 
+pub static MEDIA_MANAGEMENT_SETTINGS_BLOCKS: [ActiveLidarrBlock; 2] = [
+  ActiveLidarrBlock::MediaManagementSettingsPrompt,
+  ActiveLidarrBlock::MediaManagementFieldInput,
+];
+
+pub const MEDIA_MANAGEMENT_NAMING_FIELDS: [MediaManagementFormField; 5] = [
+  (
+    "naming.renameTracks",
+    "Rename Tracks",
+    Some("The existing file name is used when renaming is disabled"),
+  ),
+  (
+    "naming.replaceIllegalCharacters",
+    "Replace Illegal Characters",
+    Some("Replace illegal characters instead of removing them"),
+  ),
+  (
+    "naming.standardTrackFormat",
+    "Standard Track Format",
+    Some(
+      "e.g. {Album Title} ({Release Year})/{Artist Name} - {Album Title} - {track:00} - {Track Title}",
+    ),
+  ),
+  (
+    "naming.multiDiscTrackFormat",
+    "Multi Disc Track Format",
+    Some(
+      "e.g. {Album Title} ({Release Year})/{Medium Format} {medium:00}/{Artist Name} - {Album Title} - {track:00} - {Track Title}",
+    ),
+  ),
+  (
+    "naming.artistFolderFormat",
+    "Artist Folder Format",
+    Some("e.g. {Artist Name}"),
+  ),
+];
+
 pub const ADD_ROOT_FOLDER_SELECTION_BLOCKS: &[&[ActiveLidarrBlock]] = &[
   &[ActiveLidarrBlock::AddRootFolderNameInput],
   &[ActiveLidarrBlock::AddRootFolderPathInput],
@@ -802,13 +1050,35 @@ pub const INDEXER_SETTINGS_SELECTION_BLOCKS: &[&[ActiveLidarrBlock]] = &[
   &[ActiveLidarrBlock::IndexerSettingsConfirmPrompt],
 ];
 
+pub static ADD_INDEXER_BLOCKS: [ActiveLidarrBlock; 3] = [
+  ActiveLidarrBlock::AddIndexerFieldInput,
+  ActiveLidarrBlock::AddIndexerPrompt,
+  ActiveLidarrBlock::AddIndexerSelectImplementation,
+];
+
 pub static INDEXERS_BLOCKS: [ActiveLidarrBlock; 3] = [
   ActiveLidarrBlock::Indexers,
   ActiveLidarrBlock::DeleteIndexerPrompt,
   ActiveLidarrBlock::TestIndexer,
 ];
+pub static DOWNLOAD_CLIENTS_BLOCKS: [ActiveLidarrBlock; 3] = [
+  ActiveLidarrBlock::DeleteDownloadClientPrompt,
+  ActiveLidarrBlock::DownloadClients,
+  ActiveLidarrBlock::TestDownloadClient,
+];
+pub static DOWNLOAD_CLIENT_FORM_BLOCKS: [ActiveLidarrBlock; 3] = [
+  ActiveLidarrBlock::AddDownloadClientSelectImplementation,
+  ActiveLidarrBlock::DownloadClientFieldInput,
+  ActiveLidarrBlock::DownloadClientPrompt,
+];
+pub static QUALITY_PROFILES_BLOCKS: [ActiveLidarrBlock; 2] = [
+  ActiveLidarrBlock::QualityProfiles,
+  ActiveLidarrBlock::EditQualityProfile,
+];
 
-pub static SYSTEM_DETAILS_BLOCKS: [ActiveLidarrBlock; 5] = [
+pub static SYSTEM_DETAILS_BLOCKS: [ActiveLidarrBlock; 7] = [
+  ActiveLidarrBlock::SystemBackups,
+  ActiveLidarrBlock::DeleteBackupPrompt,
   ActiveLidarrBlock::SystemLogs,
   ActiveLidarrBlock::SystemQueuedEvents,
   ActiveLidarrBlock::SystemTasks,
@@ -1,8 +1,10 @@
 #[cfg(test)]
 mod tests {
   use crate::app::context_clues::{
-    BLOCKLIST_CONTEXT_CLUES, DOWNLOADS_CONTEXT_CLUES, HISTORY_CONTEXT_CLUES,
-    INDEXERS_CONTEXT_CLUES, ROOT_FOLDERS_CONTEXT_CLUES, SYSTEM_CONTEXT_CLUES,
+    BLOCKLIST_CONTEXT_CLUES, CALENDAR_CONTEXT_CLUES, DOWNLOAD_CLIENTS_CONTEXT_CLUES,
+    DOWNLOADS_CONTEXT_CLUES, HISTORY_CONTEXT_CLUES, INDEXERS_CONTEXT_CLUES,
+    QUALITY_PROFILES_CONTEXT_CLUES, ROOT_FOLDERS_CONTEXT_CLUES, SYSTEM_WITH_BACKUPS_CONTEXT_CLUES,
+    WANTED_CONTEXT_CLUES,
   };
   use crate::app::lidarr::lidarr_context_clues::{
     ARTIST_DETAILS_CONTEXT_CLUES, ARTIST_HISTORY_CONTEXT_CLUES, ARTISTS_CONTEXT_CLUES,
@@ -10,14 +12,18 @@ mod tests {
   };
   use crate::models::lidarr_models::{Album, LidarrHistoryItem, LidarrRelease};
   use crate::models::servarr_data::lidarr::lidarr_data::{
-    ADD_ARTIST_BLOCKS, ADD_ARTIST_SELECTION_BLOCKS, ADD_ROOT_FOLDER_BLOCKS, ALBUM_DETAILS_BLOCKS,
-    ARTIST_DETAILS_BLOCKS, BLOCKLIST_BLOCKS, DELETE_ALBUM_BLOCKS, DELETE_ALBUM_SELECTION_BLOCKS,
-    DELETE_ARTIST_BLOCKS, DELETE_ARTIST_SELECTION_BLOCKS, DOWNLOADS_BLOCKS, EDIT_ARTIST_BLOCKS,
-    EDIT_ARTIST_SELECTION_BLOCKS, EDIT_INDEXER_BLOCKS, EDIT_INDEXER_NZB_SELECTION_BLOCKS,
-    EDIT_INDEXER_TORRENT_SELECTION_BLOCKS, HISTORY_BLOCKS, INDEXER_SETTINGS_BLOCKS,
-    INDEXER_SETTINGS_SELECTION_BLOCKS, INDEXERS_BLOCKS, ROOT_FOLDERS_BLOCKS, SYSTEM_DETAILS_BLOCKS,
-    TRACK_DETAILS_BLOCKS,
+    ADD_ARTIST_BLOCKS, ADD_ARTIST_SELECTION_BLOCKS, ADD_INDEXER_BLOCKS, ADD_ROOT_FOLDER_BLOCKS,
+    ALBUM_DETAILS_BLOCKS, ARTIST_DETAILS_BLOCKS, BLOCKLIST_BLOCKS, BULK_EDIT_ARTISTS_BLOCKS,
+    BULK_EDIT_ARTISTS_SELECTION_BLOCKS, CALENDAR_BLOCKS, DELETE_ALBUM_BLOCKS,
+    DELETE_ALBUM_SELECTION_BLOCKS, DELETE_ARTIST_BLOCKS, DELETE_ARTIST_SELECTION_BLOCKS,
+    DELETE_DOWNLOAD_SELECTION_BLOCKS, DOWNLOAD_CLIENT_FORM_BLOCKS, DOWNLOAD_CLIENTS_BLOCKS,
+    DOWNLOADS_BLOCKS, EDIT_ARTIST_BLOCKS, EDIT_ARTIST_SELECTION_BLOCKS, EDIT_INDEXER_BLOCKS,
+    EDIT_INDEXER_NZB_SELECTION_BLOCKS, EDIT_INDEXER_TORRENT_SELECTION_BLOCKS, HISTORY_BLOCKS,
+    INDEXER_SETTINGS_BLOCKS, INDEXER_SETTINGS_SELECTION_BLOCKS, INDEXERS_BLOCKS,
+    MEDIA_MANAGEMENT_SETTINGS_BLOCKS, QUALITY_PROFILES_BLOCKS, ROOT_FOLDERS_BLOCKS,
+    SYSTEM_DETAILS_BLOCKS, TRACK_DETAILS_BLOCKS, WANTED_BLOCKS,
   };
+  use crate::models::servarr_models::QueueRemovalOptions;
   use crate::models::{
     BlockSelectionState, Route,
     servarr_data::lidarr::lidarr_data::{ActiveLidarrBlock, LIBRARY_BLOCKS, LidarrData},
@@ -150,6 +156,10 @@ mod tests {
     assert_is_empty!(lidarr_data.artists);
     assert_is_empty!(lidarr_data.artist_history);
     assert_is_empty!(lidarr_data.blocklist);
+    assert_none!(lidarr_data.bulk_edit_modal);
+    assert_is_empty!(lidarr_data.calendar);
+    assert_none!(lidarr_data.calendar_start);
+    assert_is_empty!(lidarr_data.cutoff_unmet);
     assert!(!lidarr_data.delete_files);
     assert_is_empty!(lidarr_data.disk_space_vec);
     assert_is_empty!(lidarr_data.downloads);
@@ -159,20 +169,31 @@ mod tests {
     assert_is_empty!(lidarr_data.history);
     assert_is_empty!(lidarr_data.logs);
     assert_is_empty!(lidarr_data.log_details);
+    assert_none!(lidarr_data.manual_import_modal);
+    assert_none!(lidarr_data.media_management_modal);
     assert_is_empty!(lidarr_data.metadata_profile_map);
+    assert_none!(lidarr_data.naming_examples);
     assert!(!lidarr_data.prompt_confirm);
+    assert_eq!(
+      lidarr_data.queue_removal_options,
+      QueueRemovalOptions::default()
+    );
     assert_none!(lidarr_data.prompt_confirm_action);
     assert_is_empty!(lidarr_data.quality_profile_map);
+    assert_is_empty!(lidarr_data.quality_profiles);
+    assert_none!(lidarr_data.quality_profile_modal);
     assert_is_empty!(lidarr_data.queued_events);
     assert_is_empty!(lidarr_data.root_folders);
     assert_eq!(lidarr_data.selected_block, BlockSelectionState::default());
     assert_eq!(lidarr_data.start_time, <DateTime<Utc>>::default());
     assert_is_empty!(lidarr_data.tags_map);
     assert_is_empty!(lidarr_data.tasks);
+    assert_is_empty!(lidarr_data.backups);
     assert_is_empty!(lidarr_data.updates);
     assert_is_empty!(lidarr_data.version);
+    assert_is_empty!(lidarr_data.wanted_missing);
 
-    assert_eq!(lidarr_data.main_tabs.tabs.len(), 7);
+    assert_eq!(lidarr_data.main_tabs.tabs.len(), 11);
 
     assert_str_eq!(lidarr_data.main_tabs.tabs[0].title, "Library");
     assert_eq!(
@@ -240,17 +261,61 @@ mod tests {
     );
     assert_none!(lidarr_data.main_tabs.tabs[5].config);
 
-    assert_str_eq!(lidarr_data.main_tabs.tabs[6].title, "System");
+    assert_str_eq!(lidarr_data.main_tabs.tabs[6].title, "Download Clients");
     assert_eq!(
       lidarr_data.main_tabs.tabs[6].route,
-      ActiveLidarrBlock::System.into()
+      ActiveLidarrBlock::DownloadClients.into()
     );
     assert_some_eq_x!(
       &lidarr_data.main_tabs.tabs[6].contextual_help,
-      &SYSTEM_CONTEXT_CLUES
+      &DOWNLOAD_CLIENTS_CONTEXT_CLUES
     );
     assert_none!(lidarr_data.main_tabs.tabs[6].config);
 
+    assert_str_eq!(lidarr_data.main_tabs.tabs[7].title, "Quality Profiles");
+    assert_eq!(
+      lidarr_data.main_tabs.tabs[7].route,
+      ActiveLidarrBlock::QualityProfiles.into()
+    );
+    assert_some_eq_x!(
+      &lidarr_data.main_tabs.tabs[7].contextual_help,
+      &QUALITY_PROFILES_CONTEXT_CLUES
+    );
+    assert_none!(lidarr_data.main_tabs.tabs[7].config);
+
+    assert_str_eq!(lidarr_data.main_tabs.tabs[8].title, "System");
+    assert_eq!(
+      lidarr_data.main_tabs.tabs[8].route,
+      ActiveLidarrBlock::System.into()
+    );
+    assert_some_eq_x!(
+      &lidarr_data.main_tabs.tabs[8].contextual_help,
+      &SYSTEM_WITH_BACKUPS_CONTEXT_CLUES
+    );
+    assert_none!(lidarr_data.main_tabs.tabs[8].config);
+
+    assert_str_eq!(lidarr_data.main_tabs.tabs[9].title, "Calendar");
+    assert_eq!(
+      lidarr_data.main_tabs.tabs[9].route,
+      ActiveLidarrBlock::Calendar.into()
+    );
+    assert_some_eq_x!(
+      &lidarr_data.main_tabs.tabs[9].contextual_help,
+      &CALENDAR_CONTEXT_CLUES
+    );
+    assert_none!(lidarr_data.main_tabs.tabs[9].config);
+
+    assert_str_eq!(lidarr_data.main_tabs.tabs[10].title, "Wanted");
+    assert_eq!(
+      lidarr_data.main_tabs.tabs[10].route,
+      ActiveLidarrBlock::WantedMissing.into()
+    );
+    assert_some_eq_x!(
+      &lidarr_data.main_tabs.tabs[10].contextual_help,
+      &WANTED_CONTEXT_CLUES
+    );
+    assert_none!(lidarr_data.main_tabs.tabs[10].config);
+
     assert_eq!(lidarr_data.artist_info_tabs.tabs.len(), 3);
     assert_str_eq!(lidarr_data.artist_info_tabs.tabs[0].title, "Albums");
     assert_eq!(
@@ -348,12 +413,64 @@ mod tests {
     assert!(BLOCKLIST_BLOCKS.contains(&ActiveLidarrBlock::BlocklistSortPrompt));
   }
 
+  #[test]
+  fn test_calendar_blocks_contents() {
+    assert_eq!(CALENDAR_BLOCKS.len(), 2);
+    assert!(CALENDAR_BLOCKS.contains(&ActiveLidarrBlock::Calendar));
+    assert!(CALENDAR_BLOCKS.contains(&ActiveLidarrBlock::CalendarAgenda));
+  }
+
+  #[test]
+  fn test_wanted_blocks_contents() {
+    assert_eq!(WANTED_BLOCKS.len(), 4);
+    assert!(WANTED_BLOCKS.contains(&ActiveLidarrBlock::WantedMissing));
+    assert!(WANTED_BLOCKS.contains(&ActiveLidarrBlock::WantedCutoffUnmet));
+    assert!(WANTED_BLOCKS.contains(&ActiveLidarrBlock::WantedSearchAllPrompt));
+    assert!(WANTED_BLOCKS.contains(&ActiveLidarrBlock::WantedSearchSelectedPrompt));
+  }
+
   #[test]
   fn test_downloads_blocks_contains_expected_blocks() {
-    assert_eq!(DOWNLOADS_BLOCKS.len(), 3);
+    assert_eq!(DOWNLOADS_BLOCKS.len(), 12);
     assert!(DOWNLOADS_BLOCKS.contains(&ActiveLidarrBlock::Downloads));
     assert!(DOWNLOADS_BLOCKS.contains(&ActiveLidarrBlock::DeleteDownloadPrompt));
+    assert!(DOWNLOADS_BLOCKS.contains(&ActiveLidarrBlock::DeleteDownloadConfirmPrompt));
+    assert!(DOWNLOADS_BLOCKS.contains(&ActiveLidarrBlock::DeleteDownloadToggleRemoveFromClient));
+    assert!(DOWNLOADS_BLOCKS.contains(&ActiveLidarrBlock::DeleteDownloadToggleBlocklist));
+    assert!(DOWNLOADS_BLOCKS.contains(&ActiveLidarrBlock::DeleteDownloadToggleSkipRedownload));
+    assert!(DOWNLOADS_BLOCKS.contains(&ActiveLidarrBlock::DeleteDownloadToggleChangeCategory));
+    assert!(DOWNLOADS_BLOCKS.contains(&ActiveLidarrBlock::GrabDownloadPrompt));
     assert!(DOWNLOADS_BLOCKS.contains(&ActiveLidarrBlock::UpdateDownloadsPrompt));
+    assert!(DOWNLOADS_BLOCKS.contains(&ActiveLidarrBlock::ManualImport));
+    assert!(DOWNLOADS_BLOCKS.contains(&ActiveLidarrBlock::ManualImportConfirmPrompt));
+    assert!(DOWNLOADS_BLOCKS.contains(&ActiveLidarrBlock::ManualImportSelectArtist));
+  }
+
+  #[test]
+  fn test_delete_download_selection_blocks_ordering() {
+    let mut delete_download_block_iter = DELETE_DOWNLOAD_SELECTION_BLOCKS.iter();
+
+    assert_eq!(
+      delete_download_block_iter.next().unwrap(),
+      &[ActiveLidarrBlock::DeleteDownloadToggleRemoveFromClient]
+    );
+    assert_eq!(
+      delete_download_block_iter.next().unwrap(),
+      &[ActiveLidarrBlock::DeleteDownloadToggleBlocklist]
+    );
+    assert_eq!(
+      delete_download_block_iter.next().unwrap(),
+      &[ActiveLidarrBlock::DeleteDownloadToggleSkipRedownload]
+    );
+    assert_eq!(
+      delete_download_block_iter.next().unwrap(),
+      &[ActiveLidarrBlock::DeleteDownloadToggleChangeCategory]
+    );
+    assert_eq!(
+      delete_download_block_iter.next().unwrap(),
+      &[ActiveLidarrBlock::DeleteDownloadConfirmPrompt]
+    );
+    assert_eq!(delete_download_block_iter.next(), None);
   }
 
   #[test]
@@ -524,6 +641,56 @@ mod tests {
     assert_none!(edit_artist_block_iter.next());
   }
 
+  #[test]
+  fn test_bulk_edit_artists_blocks_contents() {
+    assert_eq!(BULK_EDIT_ARTISTS_BLOCKS.len(), 8);
+    assert!(BULK_EDIT_ARTISTS_BLOCKS.contains(&ActiveLidarrBlock::BulkEditArtistsPrompt));
+    assert!(BULK_EDIT_ARTISTS_BLOCKS.contains(&ActiveLidarrBlock::BulkEditArtistsConfirmPrompt));
+    assert!(BULK_EDIT_ARTISTS_BLOCKS.contains(&ActiveLidarrBlock::BulkEditArtistsSelectApplyTags));
+    assert!(BULK_EDIT_ARTISTS_BLOCKS.contains(&ActiveLidarrBlock::BulkEditArtistsSelectMonitored));
+    assert!(
+      BULK_EDIT_ARTISTS_BLOCKS.contains(&ActiveLidarrBlock::BulkEditArtistsSelectQualityProfile)
+    );
+    assert!(BULK_EDIT_ARTISTS_BLOCKS.contains(&ActiveLidarrBlock::BulkEditArtistsSelectRootFolder));
+    assert!(BULK_EDIT_ARTISTS_BLOCKS.contains(&ActiveLidarrBlock::BulkEditArtistsTagsInput));
+    assert!(BULK_EDIT_ARTISTS_BLOCKS.contains(&ActiveLidarrBlock::BulkEditArtistsToggleMoveFiles));
+  }
+
+  #[test]
+  fn test_bulk_edit_artists_selection_blocks_ordering() {
+    let mut bulk_edit_artists_block_iter = BULK_EDIT_ARTISTS_SELECTION_BLOCKS.iter();
+
+    assert_eq!(
+      bulk_edit_artists_block_iter.next().unwrap(),
+      &[ActiveLidarrBlock::BulkEditArtistsSelectMonitored]
+    );
+    assert_eq!(
+      bulk_edit_artists_block_iter.next().unwrap(),
+      &[ActiveLidarrBlock::BulkEditArtistsSelectQualityProfile]
+    );
+    assert_eq!(
+      bulk_edit_artists_block_iter.next().unwrap(),
+      &[ActiveLidarrBlock::BulkEditArtistsSelectRootFolder]
+    );
+    assert_eq!(
+      bulk_edit_artists_block_iter.next().unwrap(),
+      &[ActiveLidarrBlock::BulkEditArtistsToggleMoveFiles]
+    );
+    assert_eq!(
+      bulk_edit_artists_block_iter.next().unwrap(),
+      &[ActiveLidarrBlock::BulkEditArtistsTagsInput]
+    );
+    assert_eq!(
+      bulk_edit_artists_block_iter.next().unwrap(),
+      &[ActiveLidarrBlock::BulkEditArtistsSelectApplyTags]
+    );
+    assert_eq!(
+      bulk_edit_artists_block_iter.next().unwrap(),
+      &[ActiveLidarrBlock::BulkEditArtistsConfirmPrompt]
+    );
+    assert_none!(bulk_edit_artists_block_iter.next());
+  }
+
   #[test]
   fn test_root_folders_blocks_contents() {
     assert_eq!(ROOT_FOLDERS_BLOCKS.len(), 2);
@@ -531,6 +698,40 @@ mod tests {
     assert!(ROOT_FOLDERS_BLOCKS.contains(&ActiveLidarrBlock::DeleteRootFolderPrompt));
   }
 
+  #[test]
+  fn test_add_indexer_blocks_contents() {
+    assert_eq!(ADD_INDEXER_BLOCKS.len(), 3);
+    assert!(ADD_INDEXER_BLOCKS.contains(&ActiveLidarrBlock::AddIndexerFieldInput));
+    assert!(ADD_INDEXER_BLOCKS.contains(&ActiveLidarrBlock::AddIndexerPrompt));
+    assert!(ADD_INDEXER_BLOCKS.contains(&ActiveLidarrBlock::AddIndexerSelectImplementation));
+  }
+
+  #[test]
+  fn test_download_clients_blocks_contents() {
+    assert_eq!(DOWNLOAD_CLIENTS_BLOCKS.len(), 3);
+    assert!(DOWNLOAD_CLIENTS_BLOCKS.contains(&ActiveLidarrBlock::DeleteDownloadClientPrompt));
+    assert!(DOWNLOAD_CLIENTS_BLOCKS.contains(&ActiveLidarrBlock::DownloadClients));
+    assert!(DOWNLOAD_CLIENTS_BLOCKS.contains(&ActiveLidarrBlock::TestDownloadClient));
+  }
+
+  #[test]
+  fn test_quality_profiles_blocks_contents() {
+    assert_eq!(QUALITY_PROFILES_BLOCKS.len(), 2);
+    assert!(QUALITY_PROFILES_BLOCKS.contains(&ActiveLidarrBlock::EditQualityProfile));
+    assert!(QUALITY_PROFILES_BLOCKS.contains(&ActiveLidarrBlock::QualityProfiles));
+  }
+
+  #[test]
+  fn test_download_client_form_blocks_contents() {
+    assert_eq!(DOWNLOAD_CLIENT_FORM_BLOCKS.len(), 3);
+    assert!(
+      DOWNLOAD_CLIENT_FORM_BLOCKS
+        .contains(&ActiveLidarrBlock::AddDownloadClientSelectImplementation)
+    );
+    assert!(DOWNLOAD_CLIENT_FORM_BLOCKS.contains(&ActiveLidarrBlock::DownloadClientFieldInput));
+    assert!(DOWNLOAD_CLIENT_FORM_BLOCKS.contains(&ActiveLidarrBlock::DownloadClientPrompt));
+  }
+
   #[test]
   fn test_edit_indexer_blocks_contents() {
     assert_eq!(EDIT_INDEXER_BLOCKS.len(), 11);
@@ -709,9 +910,22 @@ mod tests {
     assert!(ADD_ROOT_FOLDER_BLOCKS.contains(&ActiveLidarrBlock::AddRootFolderTagsInput));
   }
 
+  #[test]
+  fn test_media_management_settings_blocks_contents() {
+    assert_eq!(MEDIA_MANAGEMENT_SETTINGS_BLOCKS.len(), 2);
+    assert!(
+      MEDIA_MANAGEMENT_SETTINGS_BLOCKS.contains(&ActiveLidarrBlock::MediaManagementSettingsPrompt)
+    );
+    assert!(
+      MEDIA_MANAGEMENT_SETTINGS_BLOCKS.contains(&ActiveLidarrBlock::MediaManagementFieldInput)
+    );
+  }
+
   #[test]
   fn test_system_details_blocks_contents() {
-    assert_eq!(SYSTEM_DETAILS_BLOCKS.len(), 5);
+    assert_eq!(SYSTEM_DETAILS_BLOCKS.len(), 7);
+    assert!(SYSTEM_DETAILS_BLOCKS.contains(&ActiveLidarrBlock::SystemBackups));
+    assert!(SYSTEM_DETAILS_BLOCKS.contains(&ActiveLidarrBlock::DeleteBackupPrompt));
     assert!(SYSTEM_DETAILS_BLOCKS.contains(&ActiveLidarrBlock::SystemLogs));
     assert!(SYSTEM_DETAILS_BLOCKS.contains(&ActiveLidarrBlock::SystemQueuedEvents));
     assert!(SYSTEM_DETAILS_BLOCKS.contains(&ActiveLidarrBlock::SystemTasks));
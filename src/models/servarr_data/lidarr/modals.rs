@@ -3,8 +3,10 @@ use crate::app::lidarr::lidarr_context_clues::{
   ALBUM_DETAILS_CONTEXT_CLUES, ALBUM_HISTORY_CONTEXT_CLUES, MANUAL_ALBUM_SEARCH_CONTEXT_CLUES,
   TRACK_DETAILS_CONTEXT_CLUES, TRACK_HISTORY_CONTEXT_CLUES,
 };
-use crate::models::lidarr_models::{LidarrHistoryItem, LidarrRelease, Track, TrackFile};
-use crate::models::servarr_data::modals::EditIndexerModal;
+use crate::models::lidarr_models::{
+  Artist, LidarrHistoryItem, LidarrManualImportItem, LidarrRelease, Track, TrackFile,
+};
+use crate::models::servarr_data::modals::{BulkEditModal, DownloadClientModal, EditIndexerModal};
 use crate::models::servarr_models::Indexer;
 use crate::models::stateful_table::StatefulTable;
 use crate::models::{
@@ -64,6 +66,20 @@ pub struct EditArtistModal {
   pub tags: HorizontallyScrollableText,
 }
 
+impl From<&LidarrData<'_>> for BulkEditModal {
+  fn from(lidarr_data: &LidarrData<'_>) -> BulkEditModal {
+    BulkEditModal::new(
+      lidarr_data.sorted_quality_profile_names(),
+      lidarr_data
+        .root_folders
+        .items
+        .iter()
+        .map(|root_folder| root_folder.path.clone())
+        .collect(),
+    )
+  }
+}
+
 impl From<&LidarrData<'_>> for EditArtistModal {
   fn from(lidarr_data: &LidarrData<'_>) -> EditArtistModal {
     let mut edit_artist_modal = EditArtistModal::default();
@@ -191,6 +207,20 @@ impl From<&LidarrData<'_>> for EditIndexerModal {
   }
 }
 
+impl From<&LidarrData<'_>> for DownloadClientModal {
+  fn from(lidarr_data: &LidarrData<'_>) -> DownloadClientModal {
+    let download_client = lidarr_data.download_clients.current_selection();
+    let mut download_client_modal = DownloadClientModal::from(download_client);
+    download_client_modal.id = Some(download_client.id);
+    download_client_modal.set_field_value(
+      "tags",
+      lidarr_data.tag_ids_to_display(&download_client.tags),
+    );
+
+    download_client_modal
+  }
+}
+
 #[derive(Default)]
 #[cfg_attr(test, derive(Debug))]
 pub struct AddRootFolderModal {
@@ -265,6 +295,15 @@ impl Default for AlbumDetailsModal {
   }
 }
 
+#[derive(Default)]
+#[cfg_attr(test, derive(Debug))]
+pub struct ManualImportModal {
+  pub download_id: String,
+  pub artist_id: Option<i64>,
+  pub items: StatefulTable<LidarrManualImportItem>,
+  pub artist_list: StatefulList<Artist>,
+}
+
 #[cfg_attr(test, derive(Debug))]
 pub struct TrackDetailsModal {
   pub track_details: ScrollableText,
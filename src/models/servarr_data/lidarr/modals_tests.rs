@@ -9,7 +9,7 @@ mod tests {
   use crate::models::servarr_data::lidarr::modals::{
     AddArtistModal, AlbumDetailsModal, EditArtistModal, TrackDetailsModal,
   };
-  use crate::models::servarr_data::modals::EditIndexerModal;
+  use crate::models::servarr_data::modals::{BULK_EDIT_NO_CHANGE, BulkEditModal, EditIndexerModal};
   use crate::models::servarr_models::{Indexer, IndexerField, RootFolder};
   use bimap::BiMap;
   use pretty_assertions::{assert_eq, assert_str_eq};
@@ -72,6 +72,39 @@ mod tests {
     assert_is_empty!(add_artist_modal.tags.text);
   }
 
+  #[test]
+  fn test_bulk_edit_modal_from_lidarr_data() {
+    let mut lidarr_data = LidarrData {
+      quality_profile_map: BiMap::from_iter([
+        (2i64, "Lossless".to_owned()),
+        (1i64, "Standard".to_owned()),
+      ]),
+      ..LidarrData::default()
+    };
+    lidarr_data.root_folders.set_items(vec![RootFolder {
+      id: 1,
+      path: "/nfs".to_owned(),
+      accessible: true,
+      free_space: 219902325555200,
+      unmapped_folders: None,
+    }]);
+
+    let bulk_edit_modal = BulkEditModal::from(&lidarr_data);
+
+    assert_eq!(
+      bulk_edit_modal.quality_profile_list.items,
+      vec![
+        BULK_EDIT_NO_CHANGE.to_owned(),
+        "Standard".to_owned(),
+        "Lossless".to_owned()
+      ]
+    );
+    assert_eq!(
+      bulk_edit_modal.root_folder_list.items,
+      vec![BULK_EDIT_NO_CHANGE.to_owned(), "/nfs".to_owned()]
+    );
+  }
+
   #[test]
   fn test_edit_artist_modal_from_lidarr_data() {
     let mut lidarr_data = LidarrData {
@@ -127,10 +160,12 @@ mod tests {
       IndexerField {
         name: Some("baseUrl".to_owned()),
         value: Some(Value::String("https://test.com".to_owned())),
+        ..IndexerField::default()
       },
       IndexerField {
         name: Some("apiKey".to_owned()),
         value: Some(Value::String("1234".to_owned())),
+        ..IndexerField::default()
       },
     ];
 
@@ -138,6 +173,7 @@ mod tests {
       fields.push(IndexerField {
         name: Some("seedCriteria.seedRatio".to_owned()),
         value: Some(Value::from(1.2f64)),
+        ..IndexerField::default()
       });
     }
 
@@ -180,14 +216,17 @@ mod tests {
       IndexerField {
         name: Some("baseUrl".to_owned()),
         value: Some(Value::String("https://test.com".to_owned())),
+        ..IndexerField::default()
       },
       IndexerField {
         name: Some("apiKey".to_owned()),
         value: Some(Value::String("1234".to_owned())),
+        ..IndexerField::default()
       },
       IndexerField {
         name: Some("seedCriteria.seedRatio".to_owned()),
         value: None,
+        ..IndexerField::default()
       },
     ];
 
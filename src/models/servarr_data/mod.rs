@@ -2,7 +2,9 @@ use crate::models::Route;
 
 pub mod lidarr;
 pub mod modals;
+pub mod prowlarr;
 pub mod radarr;
+pub mod readarr;
 pub mod sonarr;
 
 #[cfg(test)]
@@ -1,4 +1,17 @@
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::{Value, json};
+use strum::IntoEnumIterator;
+
 use crate::models::HorizontallyScrollableText;
+use crate::models::prowlarr_models::ProwlarrIndexer;
+use crate::models::servarr_models::{
+  AddDownloadClientParams, AddIndexerParams, ApplyTags, BulkEditMonitoring, CustomFormat,
+  DownloadClient, EditCustomFormatParams, EditDownloadClientParams, EditQualityProfileParams,
+  Indexer, IndexerField, IndexerFieldSelectOption, MediaManagementSettings, QualityProfile,
+  QualityProfileItem,
+};
+use crate::models::stateful_list::StatefulList;
 
 #[cfg(test)]
 #[path = "modals_tests.rs"]
@@ -33,9 +46,884 @@ impl Default for EditIndexerModal {
   }
 }
 
+/// The form used to add a new indexer, generated from the schema of the selected implementation.
+///
+/// The general indexer settings come first and use the indexer's top level keys as their names
+/// (e.g. `enableRss`), followed by the implementation specific fields from the schema.
+#[derive(Default)]
+#[cfg_attr(test, derive(Debug))]
+pub struct AddIndexerModal {
+  pub implementation: String,
+  pub implementation_name: String,
+  pub fields: StatefulList<SchemaFormField>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct SchemaFormField {
+  pub name: String,
+  pub label: String,
+  pub help_text: Option<String>,
+  pub kind: SchemaFormFieldKind,
+  pub value: HorizontallyScrollableText,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub enum SchemaFormFieldKind {
+  #[default]
+  Text,
+  Password,
+  Number,
+  Checkbox,
+  Select(Vec<IndexerFieldSelectOption>),
+}
+
+impl AddIndexerModal {
+  pub fn current_field_mut(&mut self) -> &mut SchemaFormField {
+    let index = self.fields.state.selected().unwrap_or(0);
+    &mut self.fields.items[index]
+  }
+
+  pub fn toggle_current_field(&mut self) {
+    self.current_field_mut().toggle();
+  }
+}
+
+impl From<&Indexer> for AddIndexerModal {
+  fn from(schema: &Indexer) -> AddIndexerModal {
+    let checkbox = |name: &str, label: &str, checked: bool| SchemaFormField {
+      name: name.to_owned(),
+      label: label.to_owned(),
+      help_text: None,
+      kind: SchemaFormFieldKind::Checkbox,
+      value: checked.to_string().into(),
+    };
+    let settings = vec![
+      SchemaFormField {
+        name: "name".to_owned(),
+        label: "Name".to_owned(),
+        help_text: None,
+        kind: SchemaFormFieldKind::Text,
+        value: schema.name.clone().unwrap_or_default().into(),
+      },
+      checkbox("enableRss", "Enable RSS", schema.supports_rss),
+      checkbox(
+        "enableAutomaticSearch",
+        "Enable Automatic Search",
+        schema.supports_search,
+      ),
+      checkbox(
+        "enableInteractiveSearch",
+        "Enable Interactive Search",
+        schema.supports_search,
+      ),
+      SchemaFormField {
+        name: "priority".to_owned(),
+        label: "Indexer Priority".to_owned(),
+        help_text: None,
+        kind: SchemaFormFieldKind::Number,
+        value: AddIndexerParams::default().priority.to_string().into(),
+      },
+      SchemaFormField {
+        name: "tags".to_owned(),
+        label: "Tags".to_owned(),
+        help_text: None,
+        kind: SchemaFormFieldKind::Text,
+        value: HorizontallyScrollableText::default(),
+      },
+    ];
+    let schema_fields = schema
+      .fields
+      .iter()
+      .flatten()
+      .filter_map(SchemaFormField::from_schema_field);
+
+    let mut fields = StatefulList::default();
+    fields.set_items(settings.into_iter().chain(schema_fields).collect());
+
+    AddIndexerModal {
+      implementation: schema.implementation.clone().unwrap_or_default(),
+      implementation_name: schema
+        .implementation_name
+        .clone()
+        .or_else(|| schema.implementation.clone())
+        .unwrap_or_default(),
+      fields,
+    }
+  }
+}
+
+/// Prowlarr indexers are enabled as a whole and synced to applications using an app (sync)
+/// profile, and every indexer definition has its own schema. So the definition is used as the
+/// implementation, falling back to the implementation itself for generic indexers
+impl From<&ProwlarrIndexer> for AddIndexerModal {
+  fn from(schema: &ProwlarrIndexer) -> AddIndexerModal {
+    let settings = vec![
+      SchemaFormField {
+        name: "name".to_owned(),
+        label: "Name".to_owned(),
+        help_text: None,
+        kind: SchemaFormFieldKind::Text,
+        value: schema.name.clone().unwrap_or_default().into(),
+      },
+      SchemaFormField {
+        name: "enable".to_owned(),
+        label: "Enable".to_owned(),
+        help_text: None,
+        kind: SchemaFormFieldKind::Checkbox,
+        value: AddIndexerParams::default().enable.to_string().into(),
+      },
+      SchemaFormField {
+        name: "appProfileId".to_owned(),
+        label: "Sync Profile ID".to_owned(),
+        help_text: None,
+        kind: SchemaFormFieldKind::Number,
+        value: schema.app_profile_id.max(1).to_string().into(),
+      },
+      SchemaFormField {
+        name: "priority".to_owned(),
+        label: "Indexer Priority".to_owned(),
+        help_text: None,
+        kind: SchemaFormFieldKind::Number,
+        value: AddIndexerParams::default().priority.to_string().into(),
+      },
+      SchemaFormField {
+        name: "tags".to_owned(),
+        label: "Tags".to_owned(),
+        help_text: None,
+        kind: SchemaFormFieldKind::Text,
+        value: HorizontallyScrollableText::default(),
+      },
+    ];
+    let schema_fields = schema
+      .fields
+      .iter()
+      .flatten()
+      .filter_map(SchemaFormField::from_schema_field);
+
+    let mut fields = StatefulList::default();
+    fields.set_items(settings.into_iter().chain(schema_fields).collect());
+
+    AddIndexerModal {
+      implementation: schema
+        .definition_name
+        .clone()
+        .or_else(|| schema.implementation.clone())
+        .unwrap_or_default(),
+      implementation_name: schema
+        .name
+        .clone()
+        .or_else(|| schema.implementation_name.clone())
+        .unwrap_or_default(),
+      fields,
+    }
+  }
+}
+
+impl From<&AddIndexerModal> for AddIndexerParams {
+  fn from(modal: &AddIndexerModal) -> AddIndexerParams {
+    let mut params = AddIndexerParams {
+      implementation: modal.implementation.clone(),
+      ..AddIndexerParams::default()
+    };
+
+    for field in &modal.fields.items {
+      let value = field.value.text.trim();
+      match field.name.as_str() {
+        "name" => params.name = value.to_owned(),
+        "enable" => params.enable = value == "true",
+        "appProfileId" => params.app_profile_id = value.parse().ok(),
+        "enableRss" => params.enable_rss = value == "true",
+        "enableAutomaticSearch" => params.enable_automatic_search = value == "true",
+        "enableInteractiveSearch" => params.enable_interactive_search = value == "true",
+        "priority" => params.priority = value.parse().unwrap_or(params.priority),
+        "tags" => params.tag_input_string = Some(value.to_owned()).filter(|tags| !tags.is_empty()),
+        name => params.fields.push((name.to_owned(), value.to_owned())),
+      }
+    }
+
+    params
+  }
+}
+
+/// The form used to add a new download client or edit an existing one, generated from the schema
+/// of the selected implementation. The `id` is only set when editing an existing download client.
+///
+/// Like the [`AddIndexerModal`], the general download client settings come first, followed by the
+/// implementation specific fields.
+#[derive(Default)]
+#[cfg_attr(test, derive(Debug))]
+pub struct DownloadClientModal {
+  pub id: Option<i64>,
+  pub implementation: String,
+  pub implementation_name: String,
+  pub fields: StatefulList<SchemaFormField>,
+}
+
+impl DownloadClientModal {
+  pub fn current_field_mut(&mut self) -> &mut SchemaFormField {
+    let index = self.fields.state.selected().unwrap_or(0);
+    &mut self.fields.items[index]
+  }
+
+  pub fn toggle_current_field(&mut self) {
+    self.current_field_mut().toggle();
+  }
+
+  /// Sets the value of the field with the given name
+  pub fn set_field_value(&mut self, name: &str, value: String) {
+    if let Some(field) = self
+      .fields
+      .items
+      .iter_mut()
+      .find(|field| field.name == name)
+    {
+      field.value = value.into();
+    }
+  }
+}
+
+impl From<&DownloadClient> for DownloadClientModal {
+  fn from(download_client: &DownloadClient) -> DownloadClientModal {
+    let checkbox = |name: &str, label: &str, checked: bool| SchemaFormField {
+      name: name.to_owned(),
+      label: label.to_owned(),
+      help_text: None,
+      kind: SchemaFormFieldKind::Checkbox,
+      value: checked.to_string().into(),
+    };
+    let settings = vec![
+      SchemaFormField {
+        name: "name".to_owned(),
+        label: "Name".to_owned(),
+        help_text: None,
+        kind: SchemaFormFieldKind::Text,
+        value: download_client.name.clone().unwrap_or_default().into(),
+      },
+      checkbox("enable", "Enable", download_client.enable),
+      SchemaFormField {
+        name: "priority".to_owned(),
+        label: "Client Priority".to_owned(),
+        help_text: None,
+        kind: SchemaFormFieldKind::Number,
+        value: download_client.priority.to_string().into(),
+      },
+      checkbox(
+        "removeCompletedDownloads",
+        "Remove Completed",
+        download_client.remove_completed_downloads,
+      ),
+      checkbox(
+        "removeFailedDownloads",
+        "Remove Failed",
+        download_client.remove_failed_downloads,
+      ),
+      SchemaFormField {
+        name: "tags".to_owned(),
+        label: "Tags".to_owned(),
+        help_text: None,
+        kind: SchemaFormFieldKind::Text,
+        value: HorizontallyScrollableText::default(),
+      },
+    ];
+    let schema_fields = download_client
+      .fields
+      .iter()
+      .flatten()
+      .filter_map(SchemaFormField::from_schema_field);
+
+    let mut fields = StatefulList::default();
+    fields.set_items(settings.into_iter().chain(schema_fields).collect());
+
+    DownloadClientModal {
+      id: None,
+      implementation: download_client.implementation.clone().unwrap_or_default(),
+      implementation_name: download_client
+        .implementation_name
+        .clone()
+        .or_else(|| download_client.implementation.clone())
+        .unwrap_or_default(),
+      fields,
+    }
+  }
+}
+
+impl From<&DownloadClientModal> for AddDownloadClientParams {
+  fn from(modal: &DownloadClientModal) -> AddDownloadClientParams {
+    let mut params = AddDownloadClientParams {
+      implementation: modal.implementation.clone(),
+      ..AddDownloadClientParams::default()
+    };
+
+    for field in &modal.fields.items {
+      let value = field.value.text.trim();
+      match field.name.as_str() {
+        "name" => params.name = value.to_owned(),
+        "enable" => params.enable = value == "true",
+        "priority" => params.priority = value.parse().unwrap_or(params.priority),
+        "removeCompletedDownloads" => params.remove_completed_downloads = value == "true",
+        "removeFailedDownloads" => params.remove_failed_downloads = value == "true",
+        "tags" => params.tag_input_string = Some(value.to_owned()).filter(|tags| !tags.is_empty()),
+        name => params.fields.push((name.to_owned(), value.to_owned())),
+      }
+    }
+
+    params
+  }
+}
+
+impl From<&DownloadClientModal> for EditDownloadClientParams {
+  fn from(modal: &DownloadClientModal) -> EditDownloadClientParams {
+    let mut params = EditDownloadClientParams {
+      download_client_id: modal.id.unwrap_or_default(),
+      ..EditDownloadClientParams::default()
+    };
+
+    for field in &modal.fields.items {
+      let value = field.value.text.trim();
+      match field.name.as_str() {
+        "name" => params.name = Some(value.to_owned()),
+        "enable" => params.enable = Some(value == "true"),
+        "priority" => params.priority = value.parse().ok(),
+        "removeCompletedDownloads" => params.remove_completed_downloads = Some(value == "true"),
+        "removeFailedDownloads" => params.remove_failed_downloads = Some(value == "true"),
+        "tags" if value.is_empty() => params.clear_tags = true,
+        "tags" => params.tag_input_string = Some(value.to_owned()),
+        name => params.fields.push((name.to_owned(), value.to_owned())),
+      }
+    }
+
+    params
+  }
+}
+
+/// The quality profile editor. The quality items are listed from the highest quality to the
+/// lowest like in the Servarr web UI, which is the reverse of the order the API uses.
+#[derive(Default)]
+#[cfg_attr(test, derive(Debug))]
+pub struct QualityProfileModal {
+  pub id: i64,
+  pub name: String,
+  pub cutoff: i64,
+  pub items: StatefulList<QualityProfileItem>,
+}
+
+impl QualityProfileModal {
+  fn selected_index(&self) -> Option<usize> {
+    self
+      .items
+      .state
+      .selected()
+      .filter(|&index| index < self.items.items.len())
+  }
+
+  pub fn move_selected_up(&mut self) {
+    if let Some(index) = self.selected_index().filter(|&index| index > 0) {
+      self.items.items.swap(index, index - 1);
+      self.items.state.select(Some(index - 1));
+    }
+  }
+
+  pub fn move_selected_down(&mut self) {
+    if let Some(index) = self
+      .selected_index()
+      .filter(|&index| index + 1 < self.items.items.len())
+    {
+      self.items.items.swap(index, index + 1);
+      self.items.state.select(Some(index + 1));
+    }
+  }
+
+  pub fn toggle_selected_allowed(&mut self) {
+    if let Some(index) = self.selected_index() {
+      let item = &mut self.items.items[index];
+      item.allowed = !item.allowed;
+      for group_item in &mut item.items {
+        group_item.allowed = item.allowed;
+      }
+    }
+  }
+
+  /// Splits the selected group back into its qualities, or groups the selected quality with the
+  /// item below it. When the item below is already a group, the quality is added to it.
+  pub fn toggle_selected_group(&mut self) {
+    let Some(index) = self.selected_index() else {
+      return;
+    };
+
+    if self.items.items[index].is_group() {
+      let group = self.items.items.remove(index);
+      let qualities = group.items.into_iter().rev().map(|mut quality| {
+        quality.allowed = group.allowed;
+        quality
+      });
+      let quality_count = qualities.len();
+      self.items.items.splice(index..index, qualities);
+      if quality_count > 0 && group.id == Some(self.cutoff) {
+        self.cutoff = self.items.items[index].item_id();
+      }
+      return;
+    }
+
+    if index + 1 >= self.items.items.len() {
+      return;
+    }
+
+    let quality = self.items.items.remove(index);
+    let next_group_id = self
+      .items
+      .items
+      .iter()
+      .filter_map(|item| item.id)
+      .max()
+      .unwrap_or(999)
+      + 1;
+    let below = &mut self.items.items[index];
+    if !below.is_group() {
+      let below_quality = std::mem::take(below);
+      *below = QualityProfileItem {
+        id: Some(next_group_id),
+        name: Some(format!(
+          "{} | {}",
+          quality.display_name(),
+          below_quality.display_name()
+        )),
+        quality: None,
+        allowed: below_quality.allowed,
+        items: vec![below_quality],
+      };
+    }
+
+    below.allowed |= quality.allowed;
+    below.items.push(quality);
+    for group_item in &mut below.items {
+      group_item.allowed = below.allowed;
+    }
+    if below
+      .items
+      .iter()
+      .any(|group_item| group_item.item_id() == self.cutoff)
+    {
+      self.cutoff = below.item_id();
+    }
+  }
+
+  /// Makes the selected quality or group the cutoff, as long as it's allowed
+  pub fn set_cutoff_to_selected(&mut self) {
+    if let Some(index) = self.selected_index() {
+      let item = &self.items.items[index];
+      if item.allowed {
+        self.cutoff = item.item_id();
+      }
+    }
+  }
+
+  pub fn cutoff_name(&self) -> Option<String> {
+    self
+      .items
+      .items
+      .iter()
+      .find(|item| item.item_id() == self.cutoff)
+      .map(QualityProfileItem::display_name)
+  }
+}
+
+impl From<&QualityProfile> for QualityProfileModal {
+  fn from(quality_profile: &QualityProfile) -> QualityProfileModal {
+    let mut items = StatefulList::default();
+    items.set_items(quality_profile.items.iter().rev().cloned().collect());
+
+    QualityProfileModal {
+      id: quality_profile.id,
+      name: quality_profile.name.clone(),
+      cutoff: quality_profile.cutoff,
+      items,
+    }
+  }
+}
+
+impl From<&QualityProfileModal> for EditQualityProfileParams {
+  fn from(modal: &QualityProfileModal) -> EditQualityProfileParams {
+    EditQualityProfileParams {
+      quality_profile_id: modal.id,
+      cutoff: modal.cutoff_name(),
+      items: Some(modal.items.items.iter().rev().cloned().collect()),
+      ..EditQualityProfileParams::default()
+    }
+  }
+}
+
+/// The form used to edit a custom format. The general custom format settings come first, followed
+/// by the settings of each specification, which are named `<specification>.<field>`
+#[derive(Default)]
+#[cfg_attr(test, derive(Debug))]
+pub struct CustomFormatModal {
+  pub id: i64,
+  pub fields: StatefulList<SchemaFormField>,
+}
+
+impl CustomFormatModal {
+  pub fn current_field_mut(&mut self) -> &mut SchemaFormField {
+    let index = self.fields.state.selected().unwrap_or(0);
+    &mut self.fields.items[index]
+  }
+
+  pub fn toggle_current_field(&mut self) {
+    self.current_field_mut().toggle();
+  }
+}
+
+impl From<&CustomFormat> for CustomFormatModal {
+  fn from(custom_format: &CustomFormat) -> CustomFormatModal {
+    let checkbox = |name: String, label: String, checked: bool| SchemaFormField {
+      name,
+      label,
+      help_text: None,
+      kind: SchemaFormFieldKind::Checkbox,
+      value: checked.to_string().into(),
+    };
+    let settings = vec![
+      SchemaFormField {
+        name: "name".to_owned(),
+        label: "Name".to_owned(),
+        help_text: None,
+        kind: SchemaFormFieldKind::Text,
+        value: custom_format.name.clone().into(),
+      },
+      checkbox(
+        "includeCustomFormatWhenRenaming".to_owned(),
+        "Include In {Custom Formats} Renaming Format".to_owned(),
+        custom_format.include_custom_format_when_renaming,
+      ),
+    ];
+    let specification_fields = custom_format
+      .specifications
+      .iter()
+      .flat_map(|specification| {
+        let spec_name = &specification.name;
+        let schema_fields = specification
+          .fields
+          .iter()
+          .filter_map(SchemaFormField::from_schema_field)
+          .map(|field| SchemaFormField {
+            name: format!("{spec_name}.{}", field.name),
+            label: format!("{spec_name}: {}", field.label),
+            ..field
+          });
+
+        [
+          checkbox(
+            format!("{spec_name}.negate"),
+            format!("{spec_name}: Negate"),
+            specification.negate,
+          ),
+          checkbox(
+            format!("{spec_name}.required"),
+            format!("{spec_name}: Required"),
+            specification.required,
+          ),
+        ]
+        .into_iter()
+        .chain(schema_fields)
+        .collect::<Vec<_>>()
+      });
+
+    let mut fields = StatefulList::default();
+    fields.set_items(settings.into_iter().chain(specification_fields).collect());
+
+    CustomFormatModal {
+      id: custom_format.id,
+      fields,
+    }
+  }
+}
+
+impl From<&CustomFormatModal> for EditCustomFormatParams {
+  fn from(modal: &CustomFormatModal) -> EditCustomFormatParams {
+    let mut params = EditCustomFormatParams {
+      custom_format_id: modal.id,
+      ..EditCustomFormatParams::default()
+    };
+
+    for field in &modal.fields.items {
+      let value = field.value.text.trim();
+      match field.name.as_str() {
+        "name" => params.name = Some(value.to_owned()),
+        "includeCustomFormatWhenRenaming" => {
+          params.include_custom_format_when_renaming = Some(value == "true")
+        }
+        name => params.fields.push((name.to_owned(), value.to_owned())),
+      }
+    }
+
+    params
+  }
+}
+
+/// A setting shown in the media management form as `(name, label, help text)`. The naming
+/// settings are named `naming.<setting>` and the others `mediaManagement.<setting>`
+pub type MediaManagementFormField = (&'static str, &'static str, Option<&'static str>);
+
+pub const MEDIA_MANAGEMENT_FORM_FIELDS: [MediaManagementFormField; 7] = [
+  (
+    "mediaManagement.recycleBin",
+    "Recycling Bin",
+    Some("Files will go here when deleted instead of being permanently deleted"),
+  ),
+  (
+    "mediaManagement.recycleBinCleanupDays",
+    "Recycling Bin Cleanup",
+    Some("Days before files in the recycling bin are deleted. Set to 0 to disable"),
+  ),
+  (
+    "mediaManagement.setPermissionsLinux",
+    "Set Permissions",
+    Some("Whether chmod should be run when files are imported or renamed"),
+  ),
+  (
+    "mediaManagement.chmodFolder",
+    "chmod Folder",
+    Some("Octal permissions applied to media folders and files during import and rename"),
+  ),
+  (
+    "mediaManagement.chownGroup",
+    "chown Group",
+    Some("Group name or gid"),
+  ),
+  (
+    "mediaManagement.importExtraFiles",
+    "Import Extra Files",
+    Some("Import matching extra files (subtitles, nfo, etc.) after importing a file"),
+  ),
+  (
+    "mediaManagement.extraFileExtensions",
+    "Import Extra Files Extensions",
+    Some("Comma separated list of extra file extensions to import"),
+  ),
+];
+
+/// The form used to edit the file naming and media management settings. The settings that aren't
+/// shown in the form are kept as they are
+#[derive(Default)]
+#[cfg_attr(test, derive(Debug))]
+pub struct MediaManagementModal {
+  pub fields: StatefulList<SchemaFormField>,
+  settings: Value,
+}
+
+impl MediaManagementModal {
+  pub fn new<N: Serialize>(
+    settings: &MediaManagementSettings<N>,
+    naming_fields: &[MediaManagementFormField],
+  ) -> MediaManagementModal {
+    let settings = json!(settings);
+    let form_fields = naming_fields
+      .iter()
+      .chain(MEDIA_MANAGEMENT_FORM_FIELDS.iter())
+      .filter_map(|&(name, label, help_text)| {
+        let (kind, value) = match settings.pointer(&setting_pointer(name))? {
+          Value::Bool(checked) => (SchemaFormFieldKind::Checkbox, checked.to_string()),
+          Value::Number(number) => (SchemaFormFieldKind::Number, number.to_string()),
+          Value::String(text) => (SchemaFormFieldKind::Text, text.clone()),
+          _ => (SchemaFormFieldKind::Text, String::new()),
+        };
+
+        Some(SchemaFormField {
+          name: name.to_owned(),
+          label: label.to_owned(),
+          help_text: help_text.map(str::to_owned),
+          kind,
+          value: value.into(),
+        })
+      })
+      .collect();
+
+    let mut fields = StatefulList::default();
+    fields.set_items(form_fields);
+
+    MediaManagementModal { fields, settings }
+  }
+
+  pub fn current_field_mut(&mut self) -> &mut SchemaFormField {
+    let index = self.fields.state.selected().unwrap_or(0);
+    &mut self.fields.items[index]
+  }
+
+  pub fn toggle_current_field(&mut self) {
+    self.current_field_mut().toggle();
+  }
+
+  /// The settings with the values entered in the form. Numbers that can't be parsed keep their
+  /// previous value
+  pub fn settings<N: DeserializeOwned>(&self) -> MediaManagementSettings<N> {
+    let mut settings = self.settings.clone();
+
+    for field in &self.fields.items {
+      let Some(setting) = settings.pointer_mut(&setting_pointer(&field.name)) else {
+        continue;
+      };
+      let value = field.value.text.trim();
+      let new_value = match field.kind {
+        SchemaFormFieldKind::Checkbox => Value::Bool(value == "true"),
+        SchemaFormFieldKind::Number => value
+          .parse::<i64>()
+          .map_or_else(|_| setting.clone(), Value::from),
+        _ => Value::String(value.to_owned()),
+      };
+      *setting = new_value;
+    }
+
+    serde_json::from_value(settings).expect("Media management form produced invalid settings")
+  }
+}
+
+fn setting_pointer(name: &str) -> String {
+  format!("/{}", name.replace('.', "/"))
+}
+
+impl SchemaFormField {
+  /// Creates the form field for a schema field, skipping hidden fields and the ones that can't be
+  /// edited as text (e.g. captchas and OAuth)
+  fn from_schema_field(field: &IndexerField) -> Option<SchemaFormField> {
+    let field_type = field.field_type.as_deref().unwrap_or("textbox");
+    if field.hidden.as_deref() == Some("hidden")
+      || ![
+        "textbox", "url", "path", "password", "number", "checkbox", "select", "tag",
+      ]
+      .contains(&field_type)
+    {
+      return None;
+    }
+
+    let name = field.name.clone()?;
+    let kind = match (field_type, &field.select_options) {
+      ("password", _) => SchemaFormFieldKind::Password,
+      ("number", _) => SchemaFormFieldKind::Number,
+      ("checkbox", _) => SchemaFormFieldKind::Checkbox,
+      ("select", Some(options)) if !options.is_empty() => {
+        SchemaFormFieldKind::Select(options.clone())
+      }
+      _ => SchemaFormFieldKind::Text,
+    };
+    let value = match field.value.as_ref() {
+      Some(Value::String(value)) => value.clone(),
+      Some(Value::Array(values)) => values
+        .iter()
+        .map(|value| {
+          value
+            .as_str()
+            .map_or_else(|| value.to_string(), str::to_owned)
+        })
+        .collect::<Vec<_>>()
+        .join(","),
+      Some(Value::Null) | None => match &kind {
+        SchemaFormFieldKind::Checkbox => false.to_string(),
+        SchemaFormFieldKind::Select(options) => options[0].value.to_string(),
+        _ => String::new(),
+      },
+      Some(value) => value.to_string(),
+    };
+
+    Some(SchemaFormField {
+      label: field.label.clone().unwrap_or_else(|| name.clone()),
+      name,
+      help_text: field.help_text.clone(),
+      kind,
+      value: value.into(),
+    })
+  }
+
+  /// Toggles a checkbox, or moves a dropdown on to its next option
+  pub fn toggle(&mut self) {
+    let value = match &self.kind {
+      SchemaFormFieldKind::Checkbox => (self.value.text != "true").to_string(),
+      SchemaFormFieldKind::Select(options) if !options.is_empty() => {
+        let next_index = options
+          .iter()
+          .position(|option| option.value.to_string() == self.value.text)
+          .map_or(0, |index| (index + 1) % options.len());
+        options[next_index].value.to_string()
+      }
+      _ => return,
+    };
+
+    self.value = value.into();
+  }
+
+  /// The value as it's shown in the form
+  pub fn display_value(&self) -> String {
+    match &self.kind {
+      SchemaFormFieldKind::Password => "*".repeat(self.value.text.chars().count()),
+      SchemaFormFieldKind::Checkbox if self.value.text == "true" => "✔".to_owned(),
+      SchemaFormFieldKind::Checkbox => String::new(),
+      SchemaFormFieldKind::Select(options) => options
+        .iter()
+        .find(|option| option.value.to_string() == self.value.text)
+        .map_or_else(|| self.value.text.clone(), |option| option.name.clone()),
+      _ => self.value.text.clone(),
+    }
+  }
+}
+
 #[derive(Default, Clone, Eq, PartialEq, Debug)]
 pub struct IndexerTestResultModalItem {
   pub name: String,
   pub is_valid: bool,
   pub validation_failures: HorizontallyScrollableText,
 }
+
+pub const BULK_EDIT_NO_CHANGE: &str = "No Change";
+
+#[derive(Default)]
+#[cfg_attr(test, derive(Debug))]
+pub struct BulkEditModal {
+  pub monitored_list: StatefulList<BulkEditMonitoring>,
+  pub quality_profile_list: StatefulList<String>,
+  pub root_folder_list: StatefulList<String>,
+  pub tags: HorizontallyScrollableText,
+  pub apply_tags_list: StatefulList<ApplyTags>,
+  pub move_files: Option<bool>,
+}
+
+impl BulkEditModal {
+  pub fn new(quality_profile_names: Vec<String>, root_folder_paths: Vec<String>) -> Self {
+    let mut bulk_edit_modal = BulkEditModal {
+      move_files: Some(false),
+      ..BulkEditModal::default()
+    };
+    bulk_edit_modal
+      .monitored_list
+      .set_items(Vec::from_iter(BulkEditMonitoring::iter()));
+    bulk_edit_modal.quality_profile_list.set_items(
+      [BULK_EDIT_NO_CHANGE.to_owned()]
+        .into_iter()
+        .chain(quality_profile_names)
+        .collect(),
+    );
+    bulk_edit_modal.root_folder_list.set_items(
+      [BULK_EDIT_NO_CHANGE.to_owned()]
+        .into_iter()
+        .chain(root_folder_paths)
+        .collect(),
+    );
+    bulk_edit_modal
+      .apply_tags_list
+      .set_items(Vec::from_iter(ApplyTags::iter()));
+
+    bulk_edit_modal
+  }
+
+  pub fn selected_quality_profile(&self) -> Option<&String> {
+    Some(self.quality_profile_list.current_selection())
+      .filter(|quality_profile| *quality_profile != BULK_EDIT_NO_CHANGE)
+  }
+
+  pub fn selected_root_folder_path(&self) -> Option<String> {
+    Some(self.root_folder_list.current_selection())
+      .filter(|root_folder| *root_folder != BULK_EDIT_NO_CHANGE)
+      .cloned()
+  }
+
+  pub fn tag_input_string(&self) -> Option<String> {
+    Some(self.tags.text.trim().to_owned()).filter(|tags| !tags.is_empty())
+  }
+}
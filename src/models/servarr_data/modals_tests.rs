@@ -1,7 +1,22 @@
 #[cfg(test)]
 mod tests {
-  use crate::models::servarr_data::modals::EditIndexerModal;
-  use pretty_assertions::assert_eq;
+  use crate::models::Scrollable;
+  use crate::models::prowlarr_models::ProwlarrIndexer;
+  use crate::models::servarr_data::modals::{
+    AddIndexerModal, BULK_EDIT_NO_CHANGE, BulkEditModal, CustomFormatModal, DownloadClientModal,
+    EditIndexerModal, QualityProfileModal, SchemaFormField, SchemaFormFieldKind,
+  };
+  use crate::models::servarr_models::{
+    AddDownloadClientParams, AddIndexerParams, ApplyTags, BulkEditMonitoring,
+    EditCustomFormatParams, EditDownloadClientParams, EditQualityProfileParams, Indexer,
+    IndexerField, IndexerFieldSelectOption, QualityProfile, QualityProfileItem,
+  };
+  use crate::network::servarr_test_utils::{
+    custom_format, download_client, quality_profile_details, quality_profile_item,
+  };
+  use pretty_assertions::{assert_eq, assert_str_eq};
+  use serde_json::json;
+  use strum::IntoEnumIterator;
 
   #[test]
   fn test_edit_indexer_modal_default() {
@@ -17,4 +32,734 @@ mod tests {
     assert_is_empty!(edit_indexer_modal.tags.text);
     assert_eq!(edit_indexer_modal.priority, 1);
   }
+
+  fn select_options() -> Vec<IndexerFieldSelectOption> {
+    vec![
+      IndexerFieldSelectOption {
+        value: 0,
+        name: "Disabled".to_owned(),
+      },
+      IndexerFieldSelectOption {
+        value: 1,
+        name: "Enabled".to_owned(),
+      },
+    ]
+  }
+
+  fn indexer_schema() -> Indexer {
+    Indexer {
+      supports_rss: true,
+      supports_search: false,
+      implementation_name: Some("Torznab".to_owned()),
+      implementation: Some("Torznab".to_owned()),
+      fields: Some(vec![
+        IndexerField {
+          name: Some("baseUrl".to_owned()),
+          label: Some("URL".to_owned()),
+          help_text: Some("The URL of the indexer".to_owned()),
+          field_type: Some("url".to_owned()),
+          value: Some(json!("")),
+          ..IndexerField::default()
+        },
+        IndexerField {
+          name: Some("apiKey".to_owned()),
+          label: Some("API Key".to_owned()),
+          field_type: Some("password".to_owned()),
+          ..IndexerField::default()
+        },
+        IndexerField {
+          name: Some("minimumSeeders".to_owned()),
+          field_type: Some("number".to_owned()),
+          value: Some(json!(1)),
+          ..IndexerField::default()
+        },
+        IndexerField {
+          name: Some("categories".to_owned()),
+          field_type: Some("select".to_owned()),
+          value: Some(json!([2000, 5000])),
+          ..IndexerField::default()
+        },
+        IndexerField {
+          name: Some("animeStandardFormatSearch".to_owned()),
+          field_type: Some("select".to_owned()),
+          select_options: Some(select_options()),
+          ..IndexerField::default()
+        },
+        IndexerField {
+          name: Some("rejectBlocklistedTorrentHashesWhileGrabbing".to_owned()),
+          field_type: Some("checkbox".to_owned()),
+          ..IndexerField::default()
+        },
+        IndexerField {
+          name: Some("baseSettings.limitsUnit".to_owned()),
+          field_type: Some("textbox".to_owned()),
+          hidden: Some("hidden".to_owned()),
+          ..IndexerField::default()
+        },
+        IndexerField {
+          name: Some("cookie".to_owned()),
+          field_type: Some("captcha".to_owned()),
+          ..IndexerField::default()
+        },
+      ]),
+      ..Indexer::default()
+    }
+  }
+
+  #[test]
+  fn test_add_indexer_modal_from_indexer_schema() {
+    let add_indexer_modal = AddIndexerModal::from(&indexer_schema());
+    let field_values: Vec<(&str, &str)> = add_indexer_modal
+      .fields
+      .items
+      .iter()
+      .map(|field| (field.name.as_str(), field.value.text.as_str()))
+      .collect();
+
+    assert_str_eq!(add_indexer_modal.implementation, "Torznab");
+    assert_str_eq!(add_indexer_modal.implementation_name, "Torznab");
+    assert_eq!(
+      field_values,
+      vec![
+        ("name", ""),
+        ("enableRss", "true"),
+        ("enableAutomaticSearch", "false"),
+        ("enableInteractiveSearch", "false"),
+        ("priority", "25"),
+        ("tags", ""),
+        ("baseUrl", ""),
+        ("apiKey", ""),
+        ("minimumSeeders", "1"),
+        ("categories", "2000,5000"),
+        ("animeStandardFormatSearch", "0"),
+        ("rejectBlocklistedTorrentHashesWhileGrabbing", "false"),
+      ]
+    );
+    assert_eq!(
+      add_indexer_modal.fields.items[6],
+      SchemaFormField {
+        name: "baseUrl".to_owned(),
+        label: "URL".to_owned(),
+        help_text: Some("The URL of the indexer".to_owned()),
+        kind: SchemaFormFieldKind::Text,
+        value: "".into(),
+      }
+    );
+    assert_eq!(
+      add_indexer_modal.fields.items[7].kind,
+      SchemaFormFieldKind::Password
+    );
+    assert_str_eq!(add_indexer_modal.fields.items[8].label, "minimumSeeders");
+    assert_eq!(
+      add_indexer_modal.fields.items[8].kind,
+      SchemaFormFieldKind::Number
+    );
+    assert_eq!(
+      add_indexer_modal.fields.items[9].kind,
+      SchemaFormFieldKind::Text
+    );
+    assert_eq!(
+      add_indexer_modal.fields.items[10].kind,
+      SchemaFormFieldKind::Select(select_options())
+    );
+    assert_eq!(
+      add_indexer_modal.fields.items[11].kind,
+      SchemaFormFieldKind::Checkbox
+    );
+  }
+
+  #[test]
+  fn test_add_indexer_modal_from_prowlarr_indexer_schema() {
+    let schema = ProwlarrIndexer {
+      name: Some("1337x".to_owned()),
+      implementation: Some("Cardigann".to_owned()),
+      implementation_name: Some("Cardigann".to_owned()),
+      definition_name: Some("1337x".to_owned()),
+      fields: Some(vec![
+        IndexerField {
+          name: Some("definitionFile".to_owned()),
+          value: Some(json!("1337x")),
+          hidden: Some("hidden".to_owned()),
+          ..IndexerField::default()
+        },
+        IndexerField {
+          name: Some("baseUrl".to_owned()),
+          label: Some("Base Url".to_owned()),
+          field_type: Some("select".to_owned()),
+          ..IndexerField::default()
+        },
+      ]),
+      ..ProwlarrIndexer::default()
+    };
+
+    let add_indexer_modal = AddIndexerModal::from(&schema);
+    let field_values: Vec<(&str, &str)> = add_indexer_modal
+      .fields
+      .items
+      .iter()
+      .map(|field| (field.name.as_str(), field.value.text.as_str()))
+      .collect();
+
+    assert_str_eq!(add_indexer_modal.implementation, "1337x");
+    assert_str_eq!(add_indexer_modal.implementation_name, "1337x");
+    assert_eq!(
+      field_values,
+      vec![
+        ("name", "1337x"),
+        ("enable", "true"),
+        ("appProfileId", "1"),
+        ("priority", "25"),
+        ("tags", ""),
+        ("baseUrl", ""),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_add_indexer_params_from_prowlarr_add_indexer_modal() {
+    let mut add_indexer_modal = AddIndexerModal::from(&ProwlarrIndexer {
+      implementation: Some("Newznab".to_owned()),
+      ..ProwlarrIndexer::default()
+    });
+    add_indexer_modal.fields.items[0].value = "Test".into();
+    add_indexer_modal.fields.items[1].value = "false".into();
+    add_indexer_modal.fields.items[2].value = "2".into();
+    let expected_add_indexer_params = AddIndexerParams {
+      implementation: "Newznab".to_owned(),
+      name: "Test".to_owned(),
+      enable: false,
+      app_profile_id: Some(2),
+      ..AddIndexerParams::default()
+    };
+
+    assert_eq!(
+      AddIndexerParams::from(&add_indexer_modal),
+      expected_add_indexer_params
+    );
+  }
+
+  #[test]
+  fn test_add_indexer_modal_toggle_current_field() {
+    let mut add_indexer_modal = AddIndexerModal::from(&indexer_schema());
+    add_indexer_modal.fields.state.select(Some(1));
+
+    add_indexer_modal.toggle_current_field();
+
+    assert_str_eq!(add_indexer_modal.current_field_mut().value.text, "false");
+
+    add_indexer_modal.toggle_current_field();
+
+    assert_str_eq!(add_indexer_modal.current_field_mut().value.text, "true");
+
+    add_indexer_modal.fields.state.select(Some(10));
+    add_indexer_modal.toggle_current_field();
+
+    assert_str_eq!(add_indexer_modal.current_field_mut().value.text, "1");
+
+    add_indexer_modal.toggle_current_field();
+
+    assert_str_eq!(add_indexer_modal.current_field_mut().value.text, "0");
+
+    add_indexer_modal.fields.state.select(Some(0));
+    add_indexer_modal.toggle_current_field();
+
+    assert_is_empty!(add_indexer_modal.current_field_mut().value.text);
+  }
+
+  #[test]
+  fn test_add_indexer_params_from_add_indexer_modal() {
+    let mut add_indexer_modal = AddIndexerModal::from(&indexer_schema());
+    add_indexer_modal.fields.items[0].value = "Test".into();
+    add_indexer_modal.fields.items[4].value = "10".into();
+    add_indexer_modal.fields.items[5].value = " usenet, testing ".into();
+    add_indexer_modal.fields.items[6].value = "https://test.com".into();
+    let expected_add_indexer_params = AddIndexerParams {
+      implementation: "Torznab".to_owned(),
+      name: "Test".to_owned(),
+      enable_rss: true,
+      enable_automatic_search: false,
+      enable_interactive_search: false,
+      priority: 10,
+      tags: Vec::new(),
+      tag_input_string: Some("usenet, testing".to_owned()),
+      fields: vec![
+        ("baseUrl".to_owned(), "https://test.com".to_owned()),
+        ("apiKey".to_owned(), String::new()),
+        ("minimumSeeders".to_owned(), "1".to_owned()),
+        ("categories".to_owned(), "2000,5000".to_owned()),
+        ("animeStandardFormatSearch".to_owned(), "0".to_owned()),
+        (
+          "rejectBlocklistedTorrentHashesWhileGrabbing".to_owned(),
+          "false".to_owned(),
+        ),
+      ],
+      ..AddIndexerParams::default()
+    };
+
+    assert_eq!(
+      AddIndexerParams::from(&add_indexer_modal),
+      expected_add_indexer_params
+    );
+  }
+
+  #[test]
+  fn test_download_client_modal_from_download_client() {
+    let download_client_modal = DownloadClientModal::from(&download_client());
+    let field_values: Vec<(&str, &str)> = download_client_modal
+      .fields
+      .items
+      .iter()
+      .map(|field| (field.name.as_str(), field.value.text.as_str()))
+      .collect();
+
+    assert_none!(download_client_modal.id);
+    assert_str_eq!(download_client_modal.implementation, "QBittorrent");
+    assert_str_eq!(download_client_modal.implementation_name, "qBittorrent");
+    assert_eq!(
+      field_values,
+      vec![
+        ("name", "qBittorrent"),
+        ("enable", "true"),
+        ("priority", "1"),
+        ("removeCompletedDownloads", "true"),
+        ("removeFailedDownloads", "true"),
+        ("tags", ""),
+        ("host", "localhost"),
+        ("port", "8080"),
+        ("category", "managarr"),
+      ]
+    );
+    assert_eq!(
+      download_client_modal.fields.items[1].kind,
+      SchemaFormFieldKind::Checkbox
+    );
+    assert_eq!(
+      download_client_modal.fields.items[2].kind,
+      SchemaFormFieldKind::Number
+    );
+    assert_eq!(
+      download_client_modal.fields.items[7].kind,
+      SchemaFormFieldKind::Number
+    );
+  }
+
+  #[test]
+  fn test_download_client_modal_set_field_value() {
+    let mut download_client_modal = DownloadClientModal::from(&download_client());
+
+    download_client_modal.set_field_value("tags", "usenet".to_owned());
+    download_client_modal.set_field_value("unknown", "test".to_owned());
+
+    assert_str_eq!(download_client_modal.fields.items[5].value.text, "usenet");
+    assert_eq!(download_client_modal.fields.items.len(), 9);
+  }
+
+  #[test]
+  fn test_add_download_client_params_from_download_client_modal() {
+    let mut download_client_modal = DownloadClientModal::from(&download_client());
+    download_client_modal.fields.items[0].value = "Test".into();
+    download_client_modal.fields.items[2].value = "10".into();
+    download_client_modal.fields.items[4].value = "false".into();
+    download_client_modal.fields.items[5].value = " usenet, testing ".into();
+    let expected_add_download_client_params = AddDownloadClientParams {
+      implementation: "QBittorrent".to_owned(),
+      name: "Test".to_owned(),
+      enable: true,
+      priority: 10,
+      remove_completed_downloads: true,
+      remove_failed_downloads: false,
+      tags: Vec::new(),
+      tag_input_string: Some("usenet, testing".to_owned()),
+      fields: vec![
+        ("host".to_owned(), "localhost".to_owned()),
+        ("port".to_owned(), "8080".to_owned()),
+        ("category".to_owned(), "managarr".to_owned()),
+      ],
+    };
+
+    assert_eq!(
+      AddDownloadClientParams::from(&download_client_modal),
+      expected_add_download_client_params
+    );
+  }
+
+  #[test]
+  fn test_edit_download_client_params_from_download_client_modal() {
+    let mut download_client_modal = DownloadClientModal::from(&download_client());
+    download_client_modal.id = Some(1);
+    download_client_modal.fields.items[1].value = "false".into();
+    download_client_modal.fields.items[5].value = "usenet".into();
+    let expected_edit_download_client_params = EditDownloadClientParams {
+      download_client_id: 1,
+      name: Some("qBittorrent".to_owned()),
+      enable: Some(false),
+      priority: Some(1),
+      remove_completed_downloads: Some(true),
+      remove_failed_downloads: Some(true),
+      tags: None,
+      tag_input_string: Some("usenet".to_owned()),
+      clear_tags: false,
+      fields: vec![
+        ("host".to_owned(), "localhost".to_owned()),
+        ("port".to_owned(), "8080".to_owned()),
+        ("category".to_owned(), "managarr".to_owned()),
+      ],
+    };
+
+    assert_eq!(
+      EditDownloadClientParams::from(&download_client_modal),
+      expected_edit_download_client_params
+    );
+  }
+
+  #[test]
+  fn test_edit_download_client_params_from_download_client_modal_clears_empty_tags() {
+    let mut download_client_modal = DownloadClientModal::from(&download_client());
+    download_client_modal.id = Some(1);
+
+    let edit_download_client_params = EditDownloadClientParams::from(&download_client_modal);
+
+    assert!(edit_download_client_params.clear_tags);
+    assert_none!(edit_download_client_params.tag_input_string);
+  }
+
+  fn quality_profile_modal_item_names(quality_profile_modal: &QualityProfileModal) -> Vec<String> {
+    quality_profile_modal
+      .items
+      .items
+      .iter()
+      .map(QualityProfileItem::display_name)
+      .collect()
+  }
+
+  #[test]
+  fn test_quality_profile_modal_from_quality_profile() {
+    let quality_profile_modal = QualityProfileModal::from(&quality_profile_details());
+
+    assert_eq!(quality_profile_modal.id, 1);
+    assert_str_eq!(quality_profile_modal.name, "HD");
+    assert_eq!(quality_profile_modal.cutoff, 1000);
+    assert_eq!(
+      quality_profile_modal_item_names(&quality_profile_modal),
+      vec!["Bluray-1080p", "WEB 720p", "SDTV"]
+    );
+    assert_some_eq_x!(quality_profile_modal.items.state.selected(), 0);
+  }
+
+  #[test]
+  fn test_quality_profile_modal_move_selected_up_and_down() {
+    let mut quality_profile_modal = QualityProfileModal::from(&quality_profile_details());
+
+    quality_profile_modal.move_selected_up();
+
+    assert_eq!(
+      quality_profile_modal_item_names(&quality_profile_modal),
+      vec!["Bluray-1080p", "WEB 720p", "SDTV"]
+    );
+
+    quality_profile_modal.move_selected_down();
+
+    assert_eq!(
+      quality_profile_modal_item_names(&quality_profile_modal),
+      vec!["WEB 720p", "Bluray-1080p", "SDTV"]
+    );
+    assert_some_eq_x!(quality_profile_modal.items.state.selected(), 1);
+
+    quality_profile_modal.move_selected_down();
+    quality_profile_modal.move_selected_down();
+
+    assert_eq!(
+      quality_profile_modal_item_names(&quality_profile_modal),
+      vec!["WEB 720p", "SDTV", "Bluray-1080p"]
+    );
+    assert_some_eq_x!(quality_profile_modal.items.state.selected(), 2);
+
+    quality_profile_modal.move_selected_up();
+
+    assert_eq!(
+      quality_profile_modal_item_names(&quality_profile_modal),
+      vec!["WEB 720p", "Bluray-1080p", "SDTV"]
+    );
+    assert_some_eq_x!(quality_profile_modal.items.state.selected(), 1);
+  }
+
+  #[test]
+  fn test_quality_profile_modal_toggle_selected_allowed() {
+    let mut quality_profile_modal = QualityProfileModal::from(&quality_profile_details());
+    quality_profile_modal.items.state.select(Some(1));
+
+    quality_profile_modal.toggle_selected_allowed();
+
+    let group = &quality_profile_modal.items.items[1];
+    assert!(!group.allowed);
+    assert!(group.items.iter().all(|quality| !quality.allowed));
+
+    quality_profile_modal.toggle_selected_allowed();
+
+    let group = &quality_profile_modal.items.items[1];
+    assert!(group.allowed);
+    assert!(group.items.iter().all(|quality| quality.allowed));
+  }
+
+  #[test]
+  fn test_quality_profile_modal_toggle_selected_group_ungroups_a_group() {
+    let mut quality_profile_modal = QualityProfileModal::from(&quality_profile_details());
+    quality_profile_modal.items.state.select(Some(1));
+
+    quality_profile_modal.toggle_selected_group();
+
+    assert_eq!(
+      quality_profile_modal_item_names(&quality_profile_modal),
+      vec!["Bluray-1080p", "WEBRip-720p", "WEBDL-720p", "SDTV"]
+    );
+    assert!(quality_profile_modal.items.items[1].allowed);
+    assert!(quality_profile_modal.items.items[2].allowed);
+    assert_eq!(quality_profile_modal.cutoff, 14);
+  }
+
+  #[test]
+  fn test_quality_profile_modal_toggle_selected_group_adds_a_quality_to_the_group_below() {
+    let mut quality_profile_modal = QualityProfileModal::from(&quality_profile_details());
+    quality_profile_modal.cutoff = 7;
+
+    quality_profile_modal.toggle_selected_group();
+
+    assert_eq!(
+      quality_profile_modal_item_names(&quality_profile_modal),
+      vec!["WEB 720p", "SDTV"]
+    );
+    let group_quality_ids: Vec<i64> = quality_profile_modal.items.items[0]
+      .items
+      .iter()
+      .map(QualityProfileItem::item_id)
+      .collect();
+    assert_eq!(group_quality_ids, vec![5, 14, 7]);
+    assert_eq!(quality_profile_modal.cutoff, 1000);
+  }
+
+  #[test]
+  fn test_quality_profile_modal_toggle_selected_group_groups_two_qualities() {
+    let quality_profile = QualityProfile {
+      cutoff: 7,
+      items: vec![
+        quality_profile_item(1, "SDTV", false),
+        quality_profile_item(7, "Bluray-1080p", true),
+      ],
+      ..quality_profile_details()
+    };
+    let mut quality_profile_modal = QualityProfileModal::from(&quality_profile);
+
+    quality_profile_modal.toggle_selected_group();
+
+    assert_eq!(quality_profile_modal.items.items.len(), 1);
+    let group = &quality_profile_modal.items.items[0];
+    assert!(group.is_group());
+    assert_some_eq_x!(group.id, 1000);
+    assert_str_eq!(group.display_name(), "Bluray-1080p | SDTV");
+    assert!(group.allowed);
+    assert!(group.items.iter().all(|quality| quality.allowed));
+    assert_eq!(quality_profile_modal.cutoff, 1000);
+  }
+
+  #[test]
+  fn test_quality_profile_modal_toggle_selected_group_does_nothing_for_the_last_quality() {
+    let mut quality_profile_modal = QualityProfileModal::from(&quality_profile_details());
+    quality_profile_modal.items.state.select(Some(2));
+
+    quality_profile_modal.toggle_selected_group();
+
+    assert_eq!(
+      quality_profile_modal_item_names(&quality_profile_modal),
+      vec!["Bluray-1080p", "WEB 720p", "SDTV"]
+    );
+  }
+
+  #[test]
+  fn test_quality_profile_modal_set_cutoff_to_selected() {
+    let mut quality_profile_modal = QualityProfileModal::from(&quality_profile_details());
+
+    quality_profile_modal.set_cutoff_to_selected();
+
+    assert_eq!(quality_profile_modal.cutoff, 7);
+    assert_some_eq_x!(quality_profile_modal.cutoff_name(), "Bluray-1080p");
+  }
+
+  #[test]
+  fn test_quality_profile_modal_set_cutoff_to_selected_ignores_disallowed_qualities() {
+    let mut quality_profile_modal = QualityProfileModal::from(&quality_profile_details());
+    quality_profile_modal.items.state.select(Some(2));
+
+    quality_profile_modal.set_cutoff_to_selected();
+
+    assert_eq!(quality_profile_modal.cutoff, 1000);
+    assert_some_eq_x!(quality_profile_modal.cutoff_name(), "WEB 720p");
+  }
+
+  #[test]
+  fn test_edit_quality_profile_params_from_quality_profile_modal() {
+    let quality_profile = quality_profile_details();
+    let mut quality_profile_modal = QualityProfileModal::from(&quality_profile);
+    quality_profile_modal.set_cutoff_to_selected();
+    let expected_edit_quality_profile_params = EditQualityProfileParams {
+      quality_profile_id: 1,
+      cutoff: Some("Bluray-1080p".to_owned()),
+      items: Some(quality_profile.items),
+      ..EditQualityProfileParams::default()
+    };
+
+    assert_eq!(
+      EditQualityProfileParams::from(&quality_profile_modal),
+      expected_edit_quality_profile_params
+    );
+  }
+
+  #[test]
+  fn test_custom_format_modal_from_custom_format() {
+    let custom_format_modal = CustomFormatModal::from(&custom_format());
+    let fields: Vec<(&str, &str, &str)> = custom_format_modal
+      .fields
+      .items
+      .iter()
+      .map(|field| {
+        (
+          field.name.as_str(),
+          field.label.as_str(),
+          field.value.text.as_str(),
+        )
+      })
+      .collect();
+
+    assert_eq!(custom_format_modal.id, 1);
+    assert_eq!(
+      fields,
+      vec![
+        ("name", "Name", "x265"),
+        (
+          "includeCustomFormatWhenRenaming",
+          "Include In {Custom Formats} Renaming Format",
+          "false"
+        ),
+        ("x265.negate", "x265: Negate", "false"),
+        ("x265.required", "x265: Required", "true"),
+        ("x265.value", "x265: Regular Expression", "\\bx265\\b"),
+      ]
+    );
+    assert_eq!(
+      custom_format_modal.fields.items[1].kind,
+      SchemaFormFieldKind::Checkbox
+    );
+    assert_eq!(
+      custom_format_modal.fields.items[4].kind,
+      SchemaFormFieldKind::Text
+    );
+  }
+
+  #[test]
+  fn test_custom_format_modal_toggle_current_field() {
+    let mut custom_format_modal = CustomFormatModal::from(&custom_format());
+    custom_format_modal.fields.state.select(Some(2));
+
+    custom_format_modal.toggle_current_field();
+
+    assert_str_eq!(custom_format_modal.current_field_mut().value.text, "true");
+  }
+
+  #[test]
+  fn test_edit_custom_format_params_from_custom_format_modal() {
+    let mut custom_format_modal = CustomFormatModal::from(&custom_format());
+    custom_format_modal.fields.items[0].value = " HEVC ".into();
+    custom_format_modal.fields.items[1].value = "true".into();
+    custom_format_modal.fields.items[4].value = "HEVC".into();
+    let expected_edit_custom_format_params = EditCustomFormatParams {
+      custom_format_id: 1,
+      name: Some("HEVC".to_owned()),
+      include_custom_format_when_renaming: Some(true),
+      fields: vec![
+        ("x265.negate".to_owned(), "false".to_owned()),
+        ("x265.required".to_owned(), "true".to_owned()),
+        ("x265.value".to_owned(), "HEVC".to_owned()),
+      ],
+    };
+
+    assert_eq!(
+      EditCustomFormatParams::from(&custom_format_modal),
+      expected_edit_custom_format_params
+    );
+  }
+
+  #[test]
+  fn test_add_indexer_field_display_value() {
+    let field = |kind: SchemaFormFieldKind, value: &str| SchemaFormField {
+      kind,
+      value: value.into(),
+      ..SchemaFormField::default()
+    };
+
+    assert_str_eq!(
+      field(SchemaFormFieldKind::Text, "test").display_value(),
+      "test"
+    );
+    assert_str_eq!(
+      field(SchemaFormFieldKind::Password, "test").display_value(),
+      "****"
+    );
+    assert_str_eq!(
+      field(SchemaFormFieldKind::Checkbox, "true").display_value(),
+      "✔"
+    );
+    assert_is_empty!(field(SchemaFormFieldKind::Checkbox, "false").display_value());
+    assert_str_eq!(
+      field(SchemaFormFieldKind::Select(select_options()), "1").display_value(),
+      "Enabled"
+    );
+  }
+
+  #[test]
+  fn test_bulk_edit_modal_new() {
+    let bulk_edit_modal = BulkEditModal::new(
+      vec!["Any".to_owned(), "HD - 1080p".to_owned()],
+      vec!["/nfs".to_owned()],
+    );
+
+    assert_eq!(
+      bulk_edit_modal.monitored_list.items,
+      Vec::from_iter(BulkEditMonitoring::iter())
+    );
+    assert_eq!(
+      bulk_edit_modal.quality_profile_list.items,
+      vec![
+        BULK_EDIT_NO_CHANGE.to_owned(),
+        "Any".to_owned(),
+        "HD - 1080p".to_owned()
+      ]
+    );
+    assert_eq!(
+      bulk_edit_modal.root_folder_list.items,
+      vec![BULK_EDIT_NO_CHANGE.to_owned(), "/nfs".to_owned()]
+    );
+    assert_eq!(
+      bulk_edit_modal.apply_tags_list.items,
+      Vec::from_iter(ApplyTags::iter())
+    );
+    assert_is_empty!(bulk_edit_modal.tags.text);
+    assert_some_eq_x!(bulk_edit_modal.move_files, false);
+    assert_none!(bulk_edit_modal.selected_quality_profile());
+    assert_none!(bulk_edit_modal.selected_root_folder_path());
+    assert_none!(bulk_edit_modal.tag_input_string());
+  }
+
+  #[test]
+  fn test_bulk_edit_modal_selections() {
+    let mut bulk_edit_modal = BulkEditModal::new(
+      vec!["Any".to_owned(), "HD - 1080p".to_owned()],
+      vec!["/nfs".to_owned()],
+    );
+    bulk_edit_modal.quality_profile_list.scroll_down();
+    bulk_edit_modal.root_folder_list.scroll_down();
+    bulk_edit_modal.tags = " test, usenet ".into();
+
+    assert_some_eq_x!(bulk_edit_modal.selected_quality_profile(), "Any");
+    assert_some_eq_x!(bulk_edit_modal.selected_root_folder_path(), "/nfs");
+    assert_str_eq!(bulk_edit_modal.tag_input_string().unwrap(), "test, usenet");
+  }
 }
@@ -0,0 +1,276 @@
+use crate::app::context_clues::{HISTORY_CONTEXT_CLUES, SYSTEM_CONTEXT_CLUES};
+use crate::app::prowlarr::prowlarr_context_clues::{
+  APPLICATIONS_CONTEXT_CLUES, INDEXER_PROXIES_CONTEXT_CLUES, PROWLARR_INDEXERS_CONTEXT_CLUES,
+  SEARCH_RELEASES_CONTEXT_CLUES,
+};
+use crate::models::prowlarr_models::{
+  Application, IndexerProxy, ProwlarrHistoryItem, ProwlarrIndexer, ProwlarrRelease, ProwlarrTask,
+};
+use crate::models::servarr_models::QueueEvent;
+use crate::models::stateful_list::StatefulList;
+use crate::models::{
+  HorizontallyScrollableText, Route, ScrollableText, TabRoute, TabState,
+  servarr_data::modals::{AddIndexerModal, IndexerTestResultModalItem},
+  stateful_table::StatefulTable,
+};
+use crate::network::prowlarr_network::ProwlarrEvent;
+use bimap::BiMap;
+use chrono::{DateTime, Utc};
+use strum::EnumIter;
+#[cfg(test)]
+use {
+  crate::models::stateful_table::SortOption,
+  crate::network::prowlarr_network::prowlarr_network_test_utils::test_utils::{
+    application, indexer, indexer_proxy, log_line, prowlarr_history_item, release, tags_map, task,
+    updates,
+  },
+  crate::network::servarr_test_utils::indexer_test_result,
+  crate::network::servarr_test_utils::queued_event,
+  crate::sort_option,
+  strum::{Display, EnumString},
+};
+
+#[cfg(test)]
+#[path = "prowlarr_data_tests.rs"]
+mod prowlarr_data_tests;
+
+pub struct ProwlarrData {
+  pub add_indexer_modal: Option<AddIndexerModal>,
+  pub applications: StatefulTable<Application>,
+  pub history: StatefulTable<ProwlarrHistoryItem>,
+  pub indexer_proxies: StatefulTable<IndexerProxy>,
+  pub indexers: StatefulTable<ProwlarrIndexer>,
+  pub indexer_schema: StatefulList<ProwlarrIndexer>,
+  pub indexer_test_all_results: Option<StatefulTable<IndexerTestResultModalItem>>,
+  pub indexer_test_errors: Option<String>,
+  pub logs: StatefulList<HorizontallyScrollableText>,
+  pub log_details: StatefulList<HorizontallyScrollableText>,
+  pub main_tabs: TabState,
+  pub prompt_confirm: bool,
+  pub prompt_confirm_action: Option<ProwlarrEvent>,
+  pub queued_events: StatefulTable<QueueEvent>,
+  pub search_query: Option<HorizontallyScrollableText>,
+  pub search_results: StatefulTable<ProwlarrRelease>,
+  pub start_time: DateTime<Utc>,
+  pub tags_map: BiMap<i64, String>,
+  pub tasks: StatefulTable<ProwlarrTask>,
+  pub updates: ScrollableText,
+  pub version: String,
+}
+
+impl ProwlarrData {
+  pub fn indexer_name(&self, indexer_id: i64) -> String {
+    self
+      .indexers
+      .items
+      .iter()
+      .find(|indexer| indexer.id == indexer_id)
+      .and_then(|indexer| indexer.name.clone())
+      .unwrap_or_default()
+  }
+}
+
+impl Default for ProwlarrData {
+  fn default() -> ProwlarrData {
+    ProwlarrData {
+      add_indexer_modal: None,
+      applications: StatefulTable::default(),
+      history: StatefulTable::default(),
+      indexer_proxies: StatefulTable::default(),
+      indexers: StatefulTable::default(),
+      indexer_schema: StatefulList::default(),
+      indexer_test_all_results: None,
+      indexer_test_errors: None,
+      logs: StatefulList::default(),
+      log_details: StatefulList::default(),
+      prompt_confirm: false,
+      prompt_confirm_action: None,
+      queued_events: StatefulTable::default(),
+      search_query: None,
+      search_results: StatefulTable::default(),
+      start_time: DateTime::default(),
+      tags_map: BiMap::new(),
+      tasks: StatefulTable::default(),
+      updates: ScrollableText::default(),
+      version: String::new(),
+      main_tabs: TabState::new(vec![
+        TabRoute {
+          title: "Indexers".to_string(),
+          route: ActiveProwlarrBlock::Indexers.into(),
+          contextual_help: Some(&PROWLARR_INDEXERS_CONTEXT_CLUES),
+          config: None,
+        },
+        TabRoute {
+          title: "Search".to_string(),
+          route: ActiveProwlarrBlock::SearchReleases.into(),
+          contextual_help: Some(&SEARCH_RELEASES_CONTEXT_CLUES),
+          config: None,
+        },
+        TabRoute {
+          title: "Applications".to_string(),
+          route: ActiveProwlarrBlock::Applications.into(),
+          contextual_help: Some(&APPLICATIONS_CONTEXT_CLUES),
+          config: None,
+        },
+        TabRoute {
+          title: "Indexer Proxies".to_string(),
+          route: ActiveProwlarrBlock::IndexerProxies.into(),
+          contextual_help: Some(&INDEXER_PROXIES_CONTEXT_CLUES),
+          config: None,
+        },
+        TabRoute {
+          title: "History".to_string(),
+          route: ActiveProwlarrBlock::History.into(),
+          contextual_help: Some(&HISTORY_CONTEXT_CLUES),
+          config: None,
+        },
+        TabRoute {
+          title: "System".to_string(),
+          route: ActiveProwlarrBlock::System.into(),
+          contextual_help: Some(&SYSTEM_CONTEXT_CLUES),
+          config: None,
+        },
+      ]),
+    }
+  }
+}
+
+#[cfg(test)]
+impl ProwlarrData {
+  pub fn test_default_fully_populated() -> Self {
+    let mut indexer_test_all_results = StatefulTable::default();
+    indexer_test_all_results.set_items(vec![indexer_test_result()]);
+
+    let mut prowlarr_data = ProwlarrData {
+      indexer_test_all_results: Some(indexer_test_all_results),
+      indexer_test_errors: Some("error".to_string()),
+      search_query: Some("Test Release".into()),
+      start_time: DateTime::from(DateTime::parse_from_rfc3339("2023-05-20T21:29:16Z").unwrap()),
+      tags_map: tags_map(),
+      updates: updates(),
+      version: "1.2.3.4".to_owned(),
+      ..ProwlarrData::default()
+    };
+    prowlarr_data.applications.set_items(vec![application()]);
+    prowlarr_data
+      .history
+      .set_items(vec![prowlarr_history_item()]);
+    prowlarr_data.history.sorting(vec![SortOption {
+      name: "Date",
+      cmp_fn: Some(|a: &ProwlarrHistoryItem, b: &ProwlarrHistoryItem| a.date.cmp(&b.date)),
+    }]);
+    prowlarr_data.history.search = Some("test search".into());
+    prowlarr_data.history.filter = Some("test filter".into());
+    prowlarr_data
+      .indexer_proxies
+      .set_items(vec![indexer_proxy()]);
+    prowlarr_data.indexers.set_items(vec![indexer()]);
+    prowlarr_data.indexer_schema.set_items(vec![indexer()]);
+    prowlarr_data.add_indexer_modal = Some(AddIndexerModal::from(&indexer()));
+    prowlarr_data.search_results.set_items(vec![release()]);
+    prowlarr_data
+      .search_results
+      .sorting(vec![sort_option!(indexer_id)]);
+    prowlarr_data.queued_events.set_items(vec![queued_event()]);
+    prowlarr_data.logs.set_items(vec![log_line().into()]);
+    prowlarr_data.log_details.set_items(vec![log_line().into()]);
+    prowlarr_data.tasks.set_items(vec![task()]);
+
+    prowlarr_data
+  }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, EnumIter)]
+#[cfg_attr(test, derive(Display, EnumString))]
+pub enum ActiveProwlarrBlock {
+  #[default]
+  Indexers,
+  AddIndexerFieldInput,
+  AddIndexerPrompt,
+  AddIndexerSelectImplementation,
+  Applications,
+  DeleteApplicationPrompt,
+  DeleteIndexerPrompt,
+  DeleteIndexerProxyPrompt,
+  FilterHistory,
+  FilterHistoryError,
+  GrabReleaseConfirmPrompt,
+  History,
+  HistoryItemDetails,
+  HistorySortPrompt,
+  IndexerProxies,
+  SearchHistory,
+  SearchHistoryError,
+  SearchReleases,
+  SearchReleasesInput,
+  SearchReleasesSortPrompt,
+  SyncAppIndexersPrompt,
+  System,
+  SystemLogs,
+  SystemQueuedEvents,
+  SystemTasks,
+  SystemTaskStartConfirmPrompt,
+  SystemUpdates,
+  TestAllIndexers,
+  TestIndexer,
+}
+
+pub static ADD_INDEXER_BLOCKS: [ActiveProwlarrBlock; 3] = [
+  ActiveProwlarrBlock::AddIndexerFieldInput,
+  ActiveProwlarrBlock::AddIndexerPrompt,
+  ActiveProwlarrBlock::AddIndexerSelectImplementation,
+];
+
+pub static INDEXERS_BLOCKS: [ActiveProwlarrBlock; 3] = [
+  ActiveProwlarrBlock::Indexers,
+  ActiveProwlarrBlock::DeleteIndexerPrompt,
+  ActiveProwlarrBlock::TestIndexer,
+];
+
+pub static SEARCH_RELEASES_BLOCKS: [ActiveProwlarrBlock; 4] = [
+  ActiveProwlarrBlock::SearchReleases,
+  ActiveProwlarrBlock::SearchReleasesInput,
+  ActiveProwlarrBlock::SearchReleasesSortPrompt,
+  ActiveProwlarrBlock::GrabReleaseConfirmPrompt,
+];
+
+pub static APPLICATIONS_BLOCKS: [ActiveProwlarrBlock; 3] = [
+  ActiveProwlarrBlock::Applications,
+  ActiveProwlarrBlock::DeleteApplicationPrompt,
+  ActiveProwlarrBlock::SyncAppIndexersPrompt,
+];
+
+pub static INDEXER_PROXIES_BLOCKS: [ActiveProwlarrBlock; 2] = [
+  ActiveProwlarrBlock::IndexerProxies,
+  ActiveProwlarrBlock::DeleteIndexerProxyPrompt,
+];
+
+pub static HISTORY_BLOCKS: [ActiveProwlarrBlock; 7] = [
+  ActiveProwlarrBlock::History,
+  ActiveProwlarrBlock::HistoryItemDetails,
+  ActiveProwlarrBlock::HistorySortPrompt,
+  ActiveProwlarrBlock::SearchHistory,
+  ActiveProwlarrBlock::SearchHistoryError,
+  ActiveProwlarrBlock::FilterHistory,
+  ActiveProwlarrBlock::FilterHistoryError,
+];
+
+pub static SYSTEM_DETAILS_BLOCKS: [ActiveProwlarrBlock; 5] = [
+  ActiveProwlarrBlock::SystemLogs,
+  ActiveProwlarrBlock::SystemQueuedEvents,
+  ActiveProwlarrBlock::SystemTasks,
+  ActiveProwlarrBlock::SystemTaskStartConfirmPrompt,
+  ActiveProwlarrBlock::SystemUpdates,
+];
+
+impl From<ActiveProwlarrBlock> for Route {
+  fn from(active_prowlarr_block: ActiveProwlarrBlock) -> Route {
+    Route::Prowlarr(active_prowlarr_block, None)
+  }
+}
+
+impl From<(ActiveProwlarrBlock, Option<ActiveProwlarrBlock>)> for Route {
+  fn from(value: (ActiveProwlarrBlock, Option<ActiveProwlarrBlock>)) -> Route {
+    Route::Prowlarr(value.0, value.1)
+  }
+}
@@ -0,0 +1,189 @@
+#[cfg(test)]
+mod tests {
+  use crate::app::context_clues::{HISTORY_CONTEXT_CLUES, SYSTEM_CONTEXT_CLUES};
+  use crate::app::prowlarr::prowlarr_context_clues::{
+    APPLICATIONS_CONTEXT_CLUES, INDEXER_PROXIES_CONTEXT_CLUES, PROWLARR_INDEXERS_CONTEXT_CLUES,
+    SEARCH_RELEASES_CONTEXT_CLUES,
+  };
+  use crate::models::prowlarr_models::ProwlarrIndexer;
+  use crate::models::servarr_data::prowlarr::prowlarr_data::{
+    ADD_INDEXER_BLOCKS, APPLICATIONS_BLOCKS, ActiveProwlarrBlock, HISTORY_BLOCKS,
+    INDEXER_PROXIES_BLOCKS, INDEXERS_BLOCKS, ProwlarrData, SEARCH_RELEASES_BLOCKS,
+    SYSTEM_DETAILS_BLOCKS,
+  };
+  use crate::models::{Route, TabRoute};
+  use pretty_assertions::{assert_eq, assert_str_eq};
+
+  #[test]
+  fn test_from_active_prowlarr_block_to_route() {
+    assert_eq!(
+      Route::from(ActiveProwlarrBlock::Indexers),
+      Route::Prowlarr(ActiveProwlarrBlock::Indexers, None)
+    );
+  }
+
+  #[test]
+  fn test_from_tuple_to_route_with_context() {
+    assert_eq!(
+      Route::from((
+        ActiveProwlarrBlock::Indexers,
+        Some(ActiveProwlarrBlock::Indexers)
+      )),
+      Route::Prowlarr(
+        ActiveProwlarrBlock::Indexers,
+        Some(ActiveProwlarrBlock::Indexers),
+      )
+    );
+  }
+
+  #[test]
+  fn test_indexer_name() {
+    let mut prowlarr_data = ProwlarrData::default();
+    prowlarr_data.indexers.set_items(vec![ProwlarrIndexer {
+      id: 2,
+      name: Some("Test Indexer".to_owned()),
+      ..ProwlarrIndexer::default()
+    }]);
+
+    assert_str_eq!(prowlarr_data.indexer_name(2), "Test Indexer");
+    assert!(prowlarr_data.indexer_name(3).is_empty());
+  }
+
+  #[test]
+  fn test_prowlarr_data_default() {
+    let prowlarr_data = ProwlarrData::default();
+
+    assert!(prowlarr_data.add_indexer_modal.is_none());
+    assert!(prowlarr_data.applications.is_empty());
+    assert!(prowlarr_data.history.is_empty());
+    assert!(prowlarr_data.indexer_proxies.is_empty());
+    assert!(prowlarr_data.indexers.is_empty());
+    assert!(prowlarr_data.indexer_schema.items.is_empty());
+    assert!(prowlarr_data.indexer_test_all_results.is_none());
+    assert!(prowlarr_data.indexer_test_errors.is_none());
+    assert!(prowlarr_data.logs.items.is_empty());
+    assert!(prowlarr_data.log_details.items.is_empty());
+    assert!(!prowlarr_data.prompt_confirm);
+    assert!(prowlarr_data.prompt_confirm_action.is_none());
+    assert!(prowlarr_data.queued_events.is_empty());
+    assert!(prowlarr_data.search_query.is_none());
+    assert!(prowlarr_data.search_results.is_empty());
+    assert!(prowlarr_data.tags_map.is_empty());
+    assert!(prowlarr_data.tasks.is_empty());
+    assert!(prowlarr_data.updates.is_empty());
+    assert!(prowlarr_data.version.is_empty());
+
+    assert_eq!(prowlarr_data.main_tabs.tabs.len(), 6);
+    assert_tab(
+      &prowlarr_data.main_tabs.tabs[0],
+      "Indexers",
+      ActiveProwlarrBlock::Indexers,
+      &PROWLARR_INDEXERS_CONTEXT_CLUES,
+    );
+    assert_tab(
+      &prowlarr_data.main_tabs.tabs[1],
+      "Search",
+      ActiveProwlarrBlock::SearchReleases,
+      &SEARCH_RELEASES_CONTEXT_CLUES,
+    );
+    assert_tab(
+      &prowlarr_data.main_tabs.tabs[2],
+      "Applications",
+      ActiveProwlarrBlock::Applications,
+      &APPLICATIONS_CONTEXT_CLUES,
+    );
+    assert_tab(
+      &prowlarr_data.main_tabs.tabs[3],
+      "Indexer Proxies",
+      ActiveProwlarrBlock::IndexerProxies,
+      &INDEXER_PROXIES_CONTEXT_CLUES,
+    );
+    assert_tab(
+      &prowlarr_data.main_tabs.tabs[4],
+      "History",
+      ActiveProwlarrBlock::History,
+      &HISTORY_CONTEXT_CLUES,
+    );
+    assert_tab(
+      &prowlarr_data.main_tabs.tabs[5],
+      "System",
+      ActiveProwlarrBlock::System,
+      &SYSTEM_CONTEXT_CLUES,
+    );
+  }
+
+  fn assert_tab(
+    tab: &TabRoute,
+    title: &str,
+    block: ActiveProwlarrBlock,
+    contextual_help: &'static [crate::app::context_clues::ContextClue],
+  ) {
+    assert_str_eq!(tab.title, title);
+    assert_eq!(tab.route, block.into());
+    assert!(tab.contextual_help.is_some());
+    assert_eq!(tab.contextual_help.unwrap(), contextual_help);
+    assert!(tab.config.is_none());
+  }
+
+  #[test]
+  fn test_indexers_blocks_contents() {
+    assert_eq!(INDEXERS_BLOCKS.len(), 3);
+    assert!(INDEXERS_BLOCKS.contains(&ActiveProwlarrBlock::Indexers));
+    assert!(INDEXERS_BLOCKS.contains(&ActiveProwlarrBlock::DeleteIndexerPrompt));
+    assert!(INDEXERS_BLOCKS.contains(&ActiveProwlarrBlock::TestIndexer));
+  }
+
+  #[test]
+  fn test_add_indexer_blocks_contents() {
+    assert_eq!(ADD_INDEXER_BLOCKS.len(), 3);
+    assert!(ADD_INDEXER_BLOCKS.contains(&ActiveProwlarrBlock::AddIndexerFieldInput));
+    assert!(ADD_INDEXER_BLOCKS.contains(&ActiveProwlarrBlock::AddIndexerPrompt));
+    assert!(ADD_INDEXER_BLOCKS.contains(&ActiveProwlarrBlock::AddIndexerSelectImplementation));
+  }
+
+  #[test]
+  fn test_search_releases_blocks_contents() {
+    assert_eq!(SEARCH_RELEASES_BLOCKS.len(), 4);
+    assert!(SEARCH_RELEASES_BLOCKS.contains(&ActiveProwlarrBlock::SearchReleases));
+    assert!(SEARCH_RELEASES_BLOCKS.contains(&ActiveProwlarrBlock::SearchReleasesInput));
+    assert!(SEARCH_RELEASES_BLOCKS.contains(&ActiveProwlarrBlock::SearchReleasesSortPrompt));
+    assert!(SEARCH_RELEASES_BLOCKS.contains(&ActiveProwlarrBlock::GrabReleaseConfirmPrompt));
+  }
+
+  #[test]
+  fn test_applications_blocks_contents() {
+    assert_eq!(APPLICATIONS_BLOCKS.len(), 3);
+    assert!(APPLICATIONS_BLOCKS.contains(&ActiveProwlarrBlock::Applications));
+    assert!(APPLICATIONS_BLOCKS.contains(&ActiveProwlarrBlock::DeleteApplicationPrompt));
+    assert!(APPLICATIONS_BLOCKS.contains(&ActiveProwlarrBlock::SyncAppIndexersPrompt));
+  }
+
+  #[test]
+  fn test_indexer_proxies_blocks_contents() {
+    assert_eq!(INDEXER_PROXIES_BLOCKS.len(), 2);
+    assert!(INDEXER_PROXIES_BLOCKS.contains(&ActiveProwlarrBlock::IndexerProxies));
+    assert!(INDEXER_PROXIES_BLOCKS.contains(&ActiveProwlarrBlock::DeleteIndexerProxyPrompt));
+  }
+
+  #[test]
+  fn test_history_blocks_contents() {
+    assert_eq!(HISTORY_BLOCKS.len(), 7);
+    assert!(HISTORY_BLOCKS.contains(&ActiveProwlarrBlock::History));
+    assert!(HISTORY_BLOCKS.contains(&ActiveProwlarrBlock::HistoryItemDetails));
+    assert!(HISTORY_BLOCKS.contains(&ActiveProwlarrBlock::HistorySortPrompt));
+    assert!(HISTORY_BLOCKS.contains(&ActiveProwlarrBlock::SearchHistory));
+    assert!(HISTORY_BLOCKS.contains(&ActiveProwlarrBlock::SearchHistoryError));
+    assert!(HISTORY_BLOCKS.contains(&ActiveProwlarrBlock::FilterHistory));
+    assert!(HISTORY_BLOCKS.contains(&ActiveProwlarrBlock::FilterHistoryError));
+  }
+
+  #[test]
+  fn test_system_details_blocks_contents() {
+    assert_eq!(SYSTEM_DETAILS_BLOCKS.len(), 5);
+    assert!(SYSTEM_DETAILS_BLOCKS.contains(&ActiveProwlarrBlock::SystemLogs));
+    assert!(SYSTEM_DETAILS_BLOCKS.contains(&ActiveProwlarrBlock::SystemQueuedEvents));
+    assert!(SYSTEM_DETAILS_BLOCKS.contains(&ActiveProwlarrBlock::SystemTasks));
+    assert!(SYSTEM_DETAILS_BLOCKS.contains(&ActiveProwlarrBlock::SystemTaskStartConfirmPrompt));
+    assert!(SYSTEM_DETAILS_BLOCKS.contains(&ActiveProwlarrBlock::SystemUpdates));
+  }
+}
@@ -1,9 +1,10 @@
 use strum::IntoEnumIterator;
 
 use crate::models::radarr_models::{
-  Collection, Credit, MinimumAvailability, Movie, MovieHistoryItem, MovieMonitor, RadarrRelease,
+  Collection, Credit, MinimumAvailability, Movie, MovieHistoryItem, MovieMonitor,
+  RadarrManualImportItem, RadarrRelease,
 };
-use crate::models::servarr_data::modals::EditIndexerModal;
+use crate::models::servarr_data::modals::{BulkEditModal, DownloadClientModal, EditIndexerModal};
 use crate::models::servarr_data::radarr::radarr_data::RadarrData;
 use crate::models::servarr_models::{Indexer, RootFolder};
 use crate::models::stateful_list::StatefulList;
@@ -27,6 +28,15 @@ pub struct MovieDetailsModal {
   pub movie_releases: StatefulTable<RadarrRelease>,
 }
 
+#[derive(Default)]
+#[cfg_attr(test, derive(Debug))]
+pub struct ManualImportModal {
+  pub download_id: String,
+  pub movie_id: Option<i64>,
+  pub items: StatefulTable<RadarrManualImportItem>,
+  pub movie_list: StatefulList<Movie>,
+}
+
 impl From<&RadarrData<'_>> for EditIndexerModal {
   fn from(radarr_data: &RadarrData<'_>) -> EditIndexerModal {
     let mut edit_indexer_modal = EditIndexerModal::default();
@@ -97,6 +107,20 @@ impl From<&RadarrData<'_>> for EditIndexerModal {
   }
 }
 
+impl From<&RadarrData<'_>> for DownloadClientModal {
+  fn from(radarr_data: &RadarrData<'_>) -> DownloadClientModal {
+    let download_client = radarr_data.download_clients.current_selection();
+    let mut download_client_modal = DownloadClientModal::from(download_client);
+    download_client_modal.id = Some(download_client.id);
+    download_client_modal.set_field_value(
+      "tags",
+      radarr_data.tag_ids_to_display(&download_client.tags),
+    );
+
+    download_client_modal
+  }
+}
+
 #[derive(Default)]
 #[cfg_attr(test, derive(Debug))]
 pub struct EditMovieModal {
@@ -158,6 +182,20 @@ impl From<&RadarrData<'_>> for EditMovieModal {
   }
 }
 
+impl From<&RadarrData<'_>> for BulkEditModal {
+  fn from(radarr_data: &RadarrData<'_>) -> BulkEditModal {
+    BulkEditModal::new(
+      radarr_data.sorted_quality_profile_names(),
+      radarr_data
+        .root_folders
+        .items
+        .iter()
+        .map(|root_folder| root_folder.path.clone())
+        .collect(),
+    )
+  }
+}
+
 #[derive(Default)]
 #[cfg_attr(test, derive(Debug))]
 pub struct AddMovieModal {
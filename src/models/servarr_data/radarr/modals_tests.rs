@@ -1,6 +1,7 @@
 #[cfg(test)]
 mod test {
   use crate::models::radarr_models::{Collection, MinimumAvailability, Movie, MovieMonitor};
+  use crate::models::servarr_data::modals::{BULK_EDIT_NO_CHANGE, BulkEditModal};
   use crate::models::servarr_data::radarr::modals::{
     AddMovieModal, EditCollectionModal, EditIndexerModal, EditMovieModal,
   };
@@ -24,10 +25,12 @@ mod test {
       IndexerField {
         name: Some("baseUrl".to_owned()),
         value: Some(Value::String("https://test.com".to_owned())),
+        ..IndexerField::default()
       },
       IndexerField {
         name: Some("apiKey".to_owned()),
         value: Some(Value::String("1234".to_owned())),
+        ..IndexerField::default()
       },
     ];
 
@@ -35,6 +38,7 @@ mod test {
       fields.push(IndexerField {
         name: Some("seedCriteria.seedRatio".to_owned()),
         value: Some(Value::from(1.2f64)),
+        ..IndexerField::default()
       });
     }
 
@@ -77,14 +81,17 @@ mod test {
       IndexerField {
         name: Some("baseUrl".to_owned()),
         value: Some(Value::String("https://test.com".to_owned())),
+        ..IndexerField::default()
       },
       IndexerField {
         name: Some("apiKey".to_owned()),
         value: Some(Value::String("1234".to_owned())),
+        ..IndexerField::default()
       },
       IndexerField {
         name: Some("seedCriteria.seedRatio".to_owned()),
         value: None,
+        ..IndexerField::default()
       },
     ];
 
@@ -163,6 +170,39 @@ mod test {
     assert_eq!(edit_movie_modal.monitored, Some(true));
   }
 
+  #[test]
+  fn test_bulk_edit_modal_from_radarr_data() {
+    let mut radarr_data = RadarrData {
+      quality_profile_map: BiMap::from_iter([
+        (2222, "HD - 1080p".to_owned()),
+        (1111, "Any".to_owned()),
+      ]),
+      ..RadarrData::default()
+    };
+    radarr_data.root_folders.set_items(vec![RootFolder {
+      id: 1,
+      path: "/nfs".to_owned(),
+      accessible: true,
+      free_space: 219902325555200,
+      unmapped_folders: None,
+    }]);
+
+    let bulk_edit_modal = BulkEditModal::from(&radarr_data);
+
+    assert_eq!(
+      bulk_edit_modal.quality_profile_list.items,
+      vec![
+        BULK_EDIT_NO_CHANGE.to_owned(),
+        "Any".to_owned(),
+        "HD - 1080p".to_owned()
+      ]
+    );
+    assert_eq!(
+      bulk_edit_modal.root_folder_list.items,
+      vec![BULK_EDIT_NO_CHANGE.to_owned(), "/nfs".to_owned()]
+    );
+  }
+
   #[test]
   fn test_add_movie_modal_from_radarr_data() {
     let root_folder = RootFolder {
@@ -1,6 +1,8 @@
 use crate::app::context_clues::{
-  BLOCKLIST_CONTEXT_CLUES, DOWNLOADS_CONTEXT_CLUES, HISTORY_CONTEXT_CLUES, INDEXERS_CONTEXT_CLUES,
-  ROOT_FOLDERS_CONTEXT_CLUES, SYSTEM_CONTEXT_CLUES,
+  BLOCKLIST_CONTEXT_CLUES, CALENDAR_CONTEXT_CLUES, CUSTOM_FORMATS_CONTEXT_CLUES,
+  DOWNLOAD_CLIENTS_CONTEXT_CLUES, DOWNLOADS_CONTEXT_CLUES, HISTORY_CONTEXT_CLUES,
+  INDEXERS_CONTEXT_CLUES, QUALITY_PROFILES_CONTEXT_CLUES, ROOT_FOLDERS_CONTEXT_CLUES,
+  SYSTEM_WITH_BACKUPS_CONTEXT_CLUES, WANTED_CONTEXT_CLUES,
 };
 use crate::app::radarr::radarr_context_clues::{
   COLLECTIONS_CONTEXT_CLUES, LIBRARY_CONTEXT_CLUES, MANUAL_MOVIE_SEARCH_CONTEXT_CLUES,
@@ -8,13 +10,19 @@ use crate::app::radarr::radarr_context_clues::{
 };
 use crate::models::radarr_models::{
   AddMovieSearchResult, BlocklistItem, Collection, CollectionMovie, DownloadRecord,
-  IndexerSettings, Movie, RadarrHistoryItem, RadarrTask,
+  IndexerSettings, Movie, NamingExamples, RadarrHistoryItem, RadarrTask,
+};
+use crate::models::servarr_data::modals::{
+  AddIndexerModal, BulkEditModal, CustomFormatModal, DownloadClientModal, EditIndexerModal,
+  IndexerTestResultModalItem, MediaManagementFormField, MediaManagementModal, QualityProfileModal,
 };
-use crate::models::servarr_data::modals::{EditIndexerModal, IndexerTestResultModalItem};
 use crate::models::servarr_data::radarr::modals::{
-  AddMovieModal, EditCollectionModal, EditMovieModal, MovieDetailsModal,
+  AddMovieModal, EditCollectionModal, EditMovieModal, ManualImportModal, MovieDetailsModal,
+};
+use crate::models::servarr_models::{
+  Backup, CustomFormat, DiskSpace, DownloadClient, Indexer, QualityProfile, QueueEvent,
+  QueueRemovalOptions, RootFolder,
 };
-use crate::models::servarr_models::{DiskSpace, Indexer, QueueEvent, RootFolder};
 use crate::models::stateful_list::StatefulList;
 use crate::models::stateful_table::StatefulTable;
 use crate::models::{
@@ -22,23 +30,28 @@ use crate::models::{
 };
 use crate::network::radarr_network::RadarrEvent;
 use bimap::BiMap;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use itertools::Itertools;
 use serde_json::Number;
 use strum::EnumIter;
 #[cfg(test)]
 use {
-  crate::models::radarr_models::{MinimumAvailability, MovieMonitor},
+  crate::models::radarr_models::{MinimumAvailability, MovieMonitor, RadarrManualImportItem},
+  crate::models::servarr_models::ManualImportRejection,
   crate::models::stateful_table::SortOption,
   crate::network::radarr_network::radarr_network_test_utils::test_utils::root_folder,
   crate::network::radarr_network::radarr_network_test_utils::test_utils::{
     add_movie_search_result, blocklist_item, cast_credit, collection, collection_movie,
-    crew_credit, download_record, indexer, log_line, movie, movie_history_item,
-    quality_profile_map, radarr_history_item, tags_map, task, torrent_release, updates,
-    usenet_release,
+    crew_credit, download_record, indexer, log_line, manual_import_item, media_management_settings,
+    movie, movie_history_item, naming_examples, quality_profile_map, radarr_history_item, tags_map,
+    task, torrent_release, updates, usenet_release,
   },
+  crate::network::servarr_test_utils::backup,
+  crate::network::servarr_test_utils::custom_format,
   crate::network::servarr_test_utils::diskspace,
+  crate::network::servarr_test_utils::download_client,
   crate::network::servarr_test_utils::indexer_test_result,
+  crate::network::servarr_test_utils::quality_profile_details,
   crate::network::servarr_test_utils::queued_event,
   crate::sort_option,
   strum::IntoEnumIterator,
@@ -62,7 +75,22 @@ pub struct RadarrData<'a> {
   pub selected_block: BlockSelectionState<'a, ActiveRadarrBlock>,
   pub downloads: StatefulTable<DownloadRecord>,
   pub indexers: StatefulTable<Indexer>,
+  pub indexer_schema: StatefulList<Indexer>,
+  pub download_clients: StatefulTable<DownloadClient>,
+  pub download_client_schema: StatefulList<DownloadClient>,
+  pub download_client_modal: Option<DownloadClientModal>,
+  pub download_client_test_errors: Option<String>,
+  pub download_client_test_all_results: Option<StatefulTable<IndexerTestResultModalItem>>,
+  pub quality_profiles: StatefulTable<QualityProfile>,
+  pub quality_profile_modal: Option<QualityProfileModal>,
+  pub custom_formats: StatefulTable<CustomFormat>,
+  pub custom_format_modal: Option<CustomFormatModal>,
+  pub custom_format_path: Option<HorizontallyScrollableText>,
   pub blocklist: StatefulTable<BlocklistItem>,
+  pub calendar: StatefulTable<Movie>,
+  pub calendar_start: Option<NaiveDate>,
+  pub wanted_missing: StatefulTable<Movie>,
+  pub cutoff_unmet: StatefulTable<Movie>,
   pub history: StatefulTable<RadarrHistoryItem>,
   pub quality_profile_map: BiMap<i64, String>,
   pub tags_map: BiMap<i64, String>,
@@ -71,13 +99,16 @@ pub struct RadarrData<'a> {
   pub logs: StatefulList<HorizontallyScrollableText>,
   pub log_details: StatefulList<HorizontallyScrollableText>,
   pub tasks: StatefulTable<RadarrTask>,
+  pub backups: StatefulTable<Backup>,
   pub queued_events: StatefulTable<QueueEvent>,
   pub updates: ScrollableText,
   pub main_tabs: TabState,
   pub movie_info_tabs: TabState,
+  pub add_indexer_modal: Option<AddIndexerModal>,
   pub add_movie_search: Option<HorizontallyScrollableText>,
   pub add_movie_modal: Option<AddMovieModal>,
   pub add_searched_movies: Option<StatefulTable<AddMovieSearchResult>>,
+  pub bulk_edit_modal: Option<BulkEditModal>,
   pub edit_movie_modal: Option<EditMovieModal>,
   pub edit_collection_modal: Option<EditCollectionModal>,
   pub edit_indexer_modal: Option<EditIndexerModal>,
@@ -85,9 +116,13 @@ pub struct RadarrData<'a> {
   pub indexer_settings: Option<IndexerSettings>,
   pub indexer_test_errors: Option<String>,
   pub indexer_test_all_results: Option<StatefulTable<IndexerTestResultModalItem>>,
+  pub manual_import_modal: Option<ManualImportModal>,
+  pub media_management_modal: Option<MediaManagementModal>,
   pub movie_details_modal: Option<MovieDetailsModal>,
+  pub naming_examples: Option<NamingExamples>,
   pub prompt_confirm: bool,
   pub prompt_confirm_action: Option<RadarrEvent>,
+  pub queue_removal_options: QueueRemovalOptions,
   pub delete_movie_files: bool,
   pub add_list_exclusion: bool,
 }
@@ -136,7 +171,22 @@ impl<'a> Default for RadarrData<'a> {
       selected_block: BlockSelectionState::default(),
       downloads: StatefulTable::default(),
       indexers: StatefulTable::default(),
+      indexer_schema: StatefulList::default(),
+      download_clients: StatefulTable::default(),
+      download_client_schema: StatefulList::default(),
+      download_client_modal: None,
+      download_client_test_errors: None,
+      download_client_test_all_results: None,
+      quality_profiles: StatefulTable::default(),
+      quality_profile_modal: None,
+      custom_formats: StatefulTable::default(),
+      custom_format_modal: None,
+      custom_format_path: None,
       blocklist: StatefulTable::default(),
+      calendar: StatefulTable::default(),
+      calendar_start: None,
+      wanted_missing: StatefulTable::default(),
+      cutoff_unmet: StatefulTable::default(),
       history: StatefulTable::default(),
       quality_profile_map: BiMap::default(),
       tags_map: BiMap::default(),
@@ -145,11 +195,14 @@ impl<'a> Default for RadarrData<'a> {
       logs: StatefulList::default(),
       log_details: StatefulList::default(),
       tasks: StatefulTable::default(),
+      backups: StatefulTable::default(),
       queued_events: StatefulTable::default(),
       updates: ScrollableText::default(),
+      add_indexer_modal: None,
       add_movie_search: None,
       add_movie_modal: None,
       add_searched_movies: None,
+      bulk_edit_modal: None,
       edit_movie_modal: None,
       edit_collection_modal: None,
       edit_indexer_modal: None,
@@ -157,9 +210,13 @@ impl<'a> Default for RadarrData<'a> {
       indexer_settings: None,
       indexer_test_errors: None,
       indexer_test_all_results: None,
+      manual_import_modal: None,
+      media_management_modal: None,
       movie_details_modal: None,
+      naming_examples: None,
       prompt_confirm: false,
       prompt_confirm_action: None,
+      queue_removal_options: QueueRemovalOptions::default(),
       delete_movie_files: false,
       add_list_exclusion: false,
       main_tabs: TabState::new(vec![
@@ -205,10 +262,40 @@ impl<'a> Default for RadarrData<'a> {
           contextual_help: Some(&INDEXERS_CONTEXT_CLUES),
           config: None,
         },
+        TabRoute {
+          title: "Download Clients".to_string(),
+          route: ActiveRadarrBlock::DownloadClients.into(),
+          contextual_help: Some(&DOWNLOAD_CLIENTS_CONTEXT_CLUES),
+          config: None,
+        },
+        TabRoute {
+          title: "Quality Profiles".to_string(),
+          route: ActiveRadarrBlock::QualityProfiles.into(),
+          contextual_help: Some(&QUALITY_PROFILES_CONTEXT_CLUES),
+          config: None,
+        },
+        TabRoute {
+          title: "Custom Formats".to_string(),
+          route: ActiveRadarrBlock::CustomFormats.into(),
+          contextual_help: Some(&CUSTOM_FORMATS_CONTEXT_CLUES),
+          config: None,
+        },
         TabRoute {
           title: "System".to_string(),
           route: ActiveRadarrBlock::System.into(),
-          contextual_help: Some(&SYSTEM_CONTEXT_CLUES),
+          contextual_help: Some(&SYSTEM_WITH_BACKUPS_CONTEXT_CLUES),
+          config: None,
+        },
+        TabRoute {
+          title: "Calendar".to_string(),
+          route: ActiveRadarrBlock::Calendar.into(),
+          contextual_help: Some(&CALENDAR_CONTEXT_CLUES),
+          config: None,
+        },
+        TabRoute {
+          title: "Wanted".to_string(),
+          route: ActiveRadarrBlock::WantedMissing.into(),
+          contextual_help: Some(&WANTED_CONTEXT_CLUES),
           config: None,
         },
       ]),
@@ -294,6 +381,11 @@ impl RadarrData<'_> {
       .quality_profile_list
       .set_items(vec![quality_profile_name.clone()]);
 
+    let bulk_edit_modal = BulkEditModal {
+      tags: "alex".into(),
+      ..BulkEditModal::new(vec![quality_profile_name.clone()], vec![root_folder().path])
+    };
+
     let mut edit_collection_modal = EditCollectionModal {
       monitored: Some(true),
       path: "/nfs/movies".into(),
@@ -360,6 +452,25 @@ impl RadarrData<'_> {
       .movie_releases
       .sorting(vec![sort_option!(indexer_id)]);
 
+    let mut manual_import_modal = ManualImportModal {
+      download_id: "abc123".to_owned(),
+      ..ManualImportModal::default()
+    };
+    manual_import_modal.items.set_items(vec![
+      manual_import_item(),
+      RadarrManualImportItem {
+        id: 2,
+        path: "/nfs/downloads/Test/Sample.mkv".to_owned(),
+        relative_path: "Test/Sample.mkv".to_owned(),
+        movie: None,
+        rejections: vec![ManualImportRejection {
+          reason: "Sample".to_owned(),
+        }],
+        ..manual_import_item()
+      },
+    ]);
+    manual_import_modal.movie_list.set_items(vec![movie()]);
+
     let mut radarr_data = RadarrData {
       disk_space_vec: vec![diskspace()],
       version: "1.2.3.4".to_owned(),
@@ -370,6 +481,7 @@ impl RadarrData<'_> {
       add_movie_search: Some("test".into()),
       add_movie_modal: Some(add_movie_modal),
       add_searched_movies: Some(add_movie_search_result_table),
+      bulk_edit_modal: Some(bulk_edit_modal),
       edit_movie_modal: Some(edit_movie_modal),
       edit_collection_modal: Some(edit_collection_modal),
       edit_indexer_modal: Some(edit_indexer_modal),
@@ -377,6 +489,7 @@ impl RadarrData<'_> {
       indexer_settings: Some(indexer_settings),
       indexer_test_errors: Some("error".into()),
       indexer_test_all_results: Some(indexer_test_results),
+      manual_import_modal: Some(manual_import_modal),
       movie_details_modal: Some(movie_details_modal),
       delete_movie_files: true,
       ..RadarrData::default()
@@ -396,17 +509,51 @@ impl RadarrData<'_> {
     radarr_data.downloads.set_items(vec![download_record()]);
     radarr_data.blocklist.set_items(vec![blocklist_item()]);
     radarr_data.blocklist.sorting(vec![sort_option!(id)]);
+    radarr_data.calendar.set_items(vec![Movie {
+      in_cinemas: DateTime::parse_from_rfc3339("2024-02-06T00:00:00Z")
+        .ok()
+        .map(DateTime::from),
+      digital_release: DateTime::parse_from_rfc3339("2024-02-09T00:00:00Z")
+        .ok()
+        .map(DateTime::from),
+      ..movie()
+    }]);
+    radarr_data.calendar_start = NaiveDate::from_ymd_opt(2024, 2, 5);
+    radarr_data.wanted_missing.set_items(vec![movie()]);
+    radarr_data.cutoff_unmet.set_items(vec![movie()]);
     radarr_data.history.set_items(vec![radarr_history_item()]);
     radarr_data.history.sorting(vec![sort_option!(id)]);
     radarr_data.history.search = Some("Something".into());
     radarr_data.history.filter = Some("Something".into());
     radarr_data.indexers.set_items(vec![indexer()]);
+    radarr_data.indexer_schema.set_items(vec![indexer()]);
+    radarr_data
+      .download_clients
+      .set_items(vec![download_client()]);
+    radarr_data
+      .download_client_schema
+      .set_items(vec![download_client()]);
+    radarr_data.download_client_modal = Some(DownloadClientModal::from(&download_client()));
+    radarr_data
+      .quality_profiles
+      .set_items(vec![quality_profile_details()]);
+    radarr_data.quality_profile_modal = Some(QualityProfileModal::from(&quality_profile_details()));
+    radarr_data.custom_formats.set_items(vec![custom_format()]);
+    radarr_data.custom_format_modal = Some(CustomFormatModal::from(&custom_format()));
+    radarr_data.custom_format_path = Some("/tmp/x265.json".into());
+    radarr_data.add_indexer_modal = Some(AddIndexerModal::from(&indexer()));
+    radarr_data.media_management_modal = Some(MediaManagementModal::new(
+      &media_management_settings(),
+      &MEDIA_MANAGEMENT_NAMING_FIELDS,
+    ));
+    radarr_data.naming_examples = Some(naming_examples());
     radarr_data.indexers.sorting(vec![sort_option!(id)]);
     radarr_data.indexers.search = Some("Something".into());
     radarr_data.indexers.filter = Some("Something".into());
     radarr_data.logs.set_items(vec![log_line().into()]);
     radarr_data.log_details.set_items(vec![log_line().into()]);
     radarr_data.tasks.set_items(vec![task()]);
+    radarr_data.backups.set_items(vec![backup()]);
     radarr_data.queued_events.set_items(vec![queued_event()]);
 
     radarr_data
@@ -433,6 +580,16 @@ pub enum ActiveRadarrBlock {
   BlocklistClearAllItemsPrompt,
   BlocklistItemDetails,
   BlocklistSortPrompt,
+  BulkEditMoviesPrompt,
+  BulkEditMoviesConfirmPrompt,
+  BulkEditMoviesSelectApplyTags,
+  BulkEditMoviesSelectMonitored,
+  BulkEditMoviesSelectQualityProfile,
+  BulkEditMoviesSelectRootFolder,
+  BulkEditMoviesTagsInput,
+  BulkEditMoviesToggleMoveFiles,
+  Calendar,
+  CalendarAgenda,
   History,
   HistoryItemDetails,
   HistorySortPrompt,
@@ -445,8 +602,32 @@ pub enum ActiveRadarrBlock {
   CollectionDetails,
   Cast,
   Crew,
+  DeleteBackupPrompt,
   DeleteBlocklistItemPrompt,
   DeleteDownloadPrompt,
+  DeleteDownloadConfirmPrompt,
+  DeleteDownloadToggleRemoveFromClient,
+  DeleteDownloadToggleBlocklist,
+  DeleteDownloadToggleSkipRedownload,
+  DeleteDownloadToggleChangeCategory,
+  GrabDownloadPrompt,
+  AddIndexerFieldInput,
+  AddIndexerPrompt,
+  AddIndexerSelectImplementation,
+  AddDownloadClientSelectImplementation,
+  DeleteDownloadClientPrompt,
+  DownloadClientFieldInput,
+  DownloadClientPrompt,
+  DownloadClients,
+  EditQualityProfile,
+  QualityProfiles,
+  CustomFormatDetails,
+  CustomFormatFieldInput,
+  CustomFormats,
+  DeleteCustomFormatPrompt,
+  EditCustomFormatPrompt,
+  ExportCustomFormatPrompt,
+  ImportCustomFormatPrompt,
   DeleteIndexerPrompt,
   DeleteMoviePrompt,
   DeleteMovieConfirmPrompt,
@@ -495,9 +676,14 @@ pub enum ActiveRadarrBlock {
   IndexerSettingsToggleAllowHardcodedSubs,
   IndexerSettingsTogglePreferIndexerFlags,
   IndexerSettingsWhitelistedSubtitleTagsInput,
+  ManualImport,
+  ManualImportConfirmPrompt,
+  ManualImportSelectMovie,
   ManualSearch,
   ManualSearchSortPrompt,
   ManualSearchConfirmPrompt,
+  MediaManagementFieldInput,
+  MediaManagementSettingsPrompt,
   MovieDetails,
   MovieHistory,
   #[default]
@@ -505,11 +691,14 @@ pub enum ActiveRadarrBlock {
   MoviesSortPrompt,
   RootFolders,
   System,
+  SystemBackups,
   SystemLogs,
   SystemQueuedEvents,
   SystemTasks,
   SystemTaskStartConfirmPrompt,
   SystemUpdates,
+  TestDownloadClient,
+  TestAllDownloadClients,
   TestIndexer,
   TestAllIndexers,
   UpdateAndScanPrompt,
@@ -521,6 +710,10 @@ pub enum ActiveRadarrBlock {
   SearchMovie,
   SearchMovieError,
   ViewMovieOverview,
+  WantedCutoffUnmet,
+  WantedMissing,
+  WantedSearchAllPrompt,
+  WantedSearchSelectedPrompt,
 }
 
 pub static LIBRARY_BLOCKS: [ActiveRadarrBlock; 7] = [
@@ -541,16 +734,73 @@ pub static COLLECTIONS_BLOCKS: [ActiveRadarrBlock; 7] = [
   ActiveRadarrBlock::FilterCollectionsError,
   ActiveRadarrBlock::UpdateAllCollectionsPrompt,
 ];
+pub static ADD_INDEXER_BLOCKS: [ActiveRadarrBlock; 3] = [
+  ActiveRadarrBlock::AddIndexerFieldInput,
+  ActiveRadarrBlock::AddIndexerPrompt,
+  ActiveRadarrBlock::AddIndexerSelectImplementation,
+];
+
 pub static INDEXERS_BLOCKS: [ActiveRadarrBlock; 3] = [
   ActiveRadarrBlock::DeleteIndexerPrompt,
   ActiveRadarrBlock::Indexers,
   ActiveRadarrBlock::TestIndexer,
 ];
+pub static DOWNLOAD_CLIENTS_BLOCKS: [ActiveRadarrBlock; 3] = [
+  ActiveRadarrBlock::DeleteDownloadClientPrompt,
+  ActiveRadarrBlock::DownloadClients,
+  ActiveRadarrBlock::TestDownloadClient,
+];
+pub static DOWNLOAD_CLIENT_FORM_BLOCKS: [ActiveRadarrBlock; 3] = [
+  ActiveRadarrBlock::AddDownloadClientSelectImplementation,
+  ActiveRadarrBlock::DownloadClientFieldInput,
+  ActiveRadarrBlock::DownloadClientPrompt,
+];
+pub static QUALITY_PROFILES_BLOCKS: [ActiveRadarrBlock; 2] = [
+  ActiveRadarrBlock::QualityProfiles,
+  ActiveRadarrBlock::EditQualityProfile,
+];
+pub static CUSTOM_FORMATS_BLOCKS: [ActiveRadarrBlock; 5] = [
+  ActiveRadarrBlock::CustomFormats,
+  ActiveRadarrBlock::CustomFormatDetails,
+  ActiveRadarrBlock::DeleteCustomFormatPrompt,
+  ActiveRadarrBlock::ExportCustomFormatPrompt,
+  ActiveRadarrBlock::ImportCustomFormatPrompt,
+];
+pub static CUSTOM_FORMAT_FORM_BLOCKS: [ActiveRadarrBlock; 2] = [
+  ActiveRadarrBlock::EditCustomFormatPrompt,
+  ActiveRadarrBlock::CustomFormatFieldInput,
+];
 pub static ROOT_FOLDERS_BLOCKS: [ActiveRadarrBlock; 3] = [
   ActiveRadarrBlock::RootFolders,
   ActiveRadarrBlock::AddRootFolderPrompt,
   ActiveRadarrBlock::DeleteRootFolderPrompt,
 ];
+pub static MEDIA_MANAGEMENT_SETTINGS_BLOCKS: [ActiveRadarrBlock; 2] = [
+  ActiveRadarrBlock::MediaManagementSettingsPrompt,
+  ActiveRadarrBlock::MediaManagementFieldInput,
+];
+pub const MEDIA_MANAGEMENT_NAMING_FIELDS: [MediaManagementFormField; 4] = [
+  (
+    "naming.renameMovies",
+    "Rename Movies",
+    Some("The existing file name is used when renaming is disabled"),
+  ),
+  (
+    "naming.replaceIllegalCharacters",
+    "Replace Illegal Characters",
+    Some("Replace illegal characters instead of removing them"),
+  ),
+  (
+    "naming.standardMovieFormat",
+    "Standard Movie Format",
+    Some("e.g. {Movie Title} ({Release Year}) {Quality Full}"),
+  ),
+  (
+    "naming.movieFolderFormat",
+    "Movie Folder Format",
+    Some("e.g. {Movie Title} ({Release Year})"),
+  ),
+];
 pub static BLOCKLIST_BLOCKS: [ActiveRadarrBlock; 5] = [
   ActiveRadarrBlock::Blocklist,
   ActiveRadarrBlock::BlocklistItemDetails,
@@ -558,6 +808,16 @@ pub static BLOCKLIST_BLOCKS: [ActiveRadarrBlock; 5] = [
   ActiveRadarrBlock::BlocklistClearAllItemsPrompt,
   ActiveRadarrBlock::BlocklistSortPrompt,
 ];
+pub static CALENDAR_BLOCKS: [ActiveRadarrBlock; 2] = [
+  ActiveRadarrBlock::Calendar,
+  ActiveRadarrBlock::CalendarAgenda,
+];
+pub static WANTED_BLOCKS: [ActiveRadarrBlock; 4] = [
+  ActiveRadarrBlock::WantedMissing,
+  ActiveRadarrBlock::WantedCutoffUnmet,
+  ActiveRadarrBlock::WantedSearchAllPrompt,
+  ActiveRadarrBlock::WantedSearchSelectedPrompt,
+];
 pub static HISTORY_BLOCKS: [ActiveRadarrBlock; 7] = [
   ActiveRadarrBlock::History,
   ActiveRadarrBlock::HistoryItemDetails,
@@ -621,10 +881,45 @@ pub const EDIT_MOVIE_SELECTION_BLOCKS: &[&[ActiveRadarrBlock]] = &[
   &[ActiveRadarrBlock::EditMovieTagsInput],
   &[ActiveRadarrBlock::EditMovieConfirmPrompt],
 ];
-pub static DOWNLOADS_BLOCKS: [ActiveRadarrBlock; 3] = [
+pub static BULK_EDIT_MOVIES_BLOCKS: [ActiveRadarrBlock; 8] = [
+  ActiveRadarrBlock::BulkEditMoviesPrompt,
+  ActiveRadarrBlock::BulkEditMoviesConfirmPrompt,
+  ActiveRadarrBlock::BulkEditMoviesSelectApplyTags,
+  ActiveRadarrBlock::BulkEditMoviesSelectMonitored,
+  ActiveRadarrBlock::BulkEditMoviesSelectQualityProfile,
+  ActiveRadarrBlock::BulkEditMoviesSelectRootFolder,
+  ActiveRadarrBlock::BulkEditMoviesTagsInput,
+  ActiveRadarrBlock::BulkEditMoviesToggleMoveFiles,
+];
+pub const BULK_EDIT_MOVIES_SELECTION_BLOCKS: &[&[ActiveRadarrBlock]] = &[
+  &[ActiveRadarrBlock::BulkEditMoviesSelectMonitored],
+  &[ActiveRadarrBlock::BulkEditMoviesSelectQualityProfile],
+  &[ActiveRadarrBlock::BulkEditMoviesSelectRootFolder],
+  &[ActiveRadarrBlock::BulkEditMoviesToggleMoveFiles],
+  &[ActiveRadarrBlock::BulkEditMoviesTagsInput],
+  &[ActiveRadarrBlock::BulkEditMoviesSelectApplyTags],
+  &[ActiveRadarrBlock::BulkEditMoviesConfirmPrompt],
+];
+pub static DOWNLOADS_BLOCKS: [ActiveRadarrBlock; 12] = [
   ActiveRadarrBlock::Downloads,
   ActiveRadarrBlock::DeleteDownloadPrompt,
+  ActiveRadarrBlock::DeleteDownloadConfirmPrompt,
+  ActiveRadarrBlock::DeleteDownloadToggleRemoveFromClient,
+  ActiveRadarrBlock::DeleteDownloadToggleBlocklist,
+  ActiveRadarrBlock::DeleteDownloadToggleSkipRedownload,
+  ActiveRadarrBlock::DeleteDownloadToggleChangeCategory,
+  ActiveRadarrBlock::GrabDownloadPrompt,
   ActiveRadarrBlock::UpdateDownloadsPrompt,
+  ActiveRadarrBlock::ManualImport,
+  ActiveRadarrBlock::ManualImportConfirmPrompt,
+  ActiveRadarrBlock::ManualImportSelectMovie,
+];
+pub const DELETE_DOWNLOAD_SELECTION_BLOCKS: &[&[ActiveRadarrBlock]] = &[
+  &[ActiveRadarrBlock::DeleteDownloadToggleRemoveFromClient],
+  &[ActiveRadarrBlock::DeleteDownloadToggleBlocklist],
+  &[ActiveRadarrBlock::DeleteDownloadToggleSkipRedownload],
+  &[ActiveRadarrBlock::DeleteDownloadToggleChangeCategory],
+  &[ActiveRadarrBlock::DeleteDownloadConfirmPrompt],
 ];
 pub static MOVIE_DETAILS_BLOCKS: [ActiveRadarrBlock; 10] = [
   ActiveRadarrBlock::MovieDetails,
@@ -748,7 +1043,9 @@ pub const INDEXER_SETTINGS_SELECTION_BLOCKS: &[&[ActiveRadarrBlock]] = &[
     ActiveRadarrBlock::IndexerSettingsConfirmPrompt,
   ],
 ];
-pub static SYSTEM_DETAILS_BLOCKS: [ActiveRadarrBlock; 5] = [
+pub static SYSTEM_DETAILS_BLOCKS: [ActiveRadarrBlock; 7] = [
+  ActiveRadarrBlock::SystemBackups,
+  ActiveRadarrBlock::DeleteBackupPrompt,
   ActiveRadarrBlock::SystemLogs,
   ActiveRadarrBlock::SystemQueuedEvents,
   ActiveRadarrBlock::SystemTasks,
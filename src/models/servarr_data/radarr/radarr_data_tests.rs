@@ -2,13 +2,16 @@
 mod tests {
   mod radarr_data_tests {
     use crate::app::context_clues::{
-      BLOCKLIST_CONTEXT_CLUES, DOWNLOADS_CONTEXT_CLUES, HISTORY_CONTEXT_CLUES,
-      INDEXERS_CONTEXT_CLUES, ROOT_FOLDERS_CONTEXT_CLUES, SYSTEM_CONTEXT_CLUES,
+      BLOCKLIST_CONTEXT_CLUES, CALENDAR_CONTEXT_CLUES, CUSTOM_FORMATS_CONTEXT_CLUES,
+      DOWNLOAD_CLIENTS_CONTEXT_CLUES, DOWNLOADS_CONTEXT_CLUES, HISTORY_CONTEXT_CLUES,
+      INDEXERS_CONTEXT_CLUES, QUALITY_PROFILES_CONTEXT_CLUES, ROOT_FOLDERS_CONTEXT_CLUES,
+      SYSTEM_WITH_BACKUPS_CONTEXT_CLUES, WANTED_CONTEXT_CLUES,
     };
     use crate::app::radarr::radarr_context_clues::{
       COLLECTIONS_CONTEXT_CLUES, LIBRARY_CONTEXT_CLUES, MANUAL_MOVIE_SEARCH_CONTEXT_CLUES,
       MOVIE_DETAILS_CONTEXT_CLUES,
     };
+    use crate::models::servarr_models::QueueRemovalOptions;
     use bimap::BiMap;
     use chrono::{DateTime, Utc};
     use pretty_assertions::{assert_eq, assert_str_eq};
@@ -113,20 +116,35 @@ mod tests {
       assert_eq!(radarr_data.selected_block, BlockSelectionState::default());
       assert_is_empty!(radarr_data.downloads.items);
       assert_is_empty!(radarr_data.indexers.items);
+      assert_is_empty!(radarr_data.download_clients.items);
+      assert_is_empty!(radarr_data.download_client_schema.items);
+      assert_is_empty!(radarr_data.indexer_schema.items);
       assert_is_empty!(radarr_data.blocklist.items);
+      assert_is_empty!(radarr_data.calendar.items);
+      assert_none!(radarr_data.calendar_start);
+      assert_is_empty!(radarr_data.wanted_missing.items);
+      assert_is_empty!(radarr_data.cutoff_unmet.items);
       assert_is_empty!(radarr_data.history.items);
       assert_is_empty!(radarr_data.quality_profile_map);
+      assert_is_empty!(radarr_data.quality_profiles);
+      assert_none!(radarr_data.quality_profile_modal);
+      assert_is_empty!(radarr_data.custom_formats);
+      assert_none!(radarr_data.custom_format_modal);
+      assert_none!(radarr_data.custom_format_path);
       assert_is_empty!(radarr_data.tags_map);
       assert_is_empty!(radarr_data.collections.items);
       assert_is_empty!(radarr_data.collection_movies.items);
       assert_is_empty!(radarr_data.logs.items);
       assert_is_empty!(radarr_data.log_details.items);
       assert_is_empty!(radarr_data.tasks.items);
+      assert_is_empty!(radarr_data.backups.items);
       assert_is_empty!(radarr_data.queued_events.items);
       assert_is_empty!(radarr_data.updates.get_text());
       assert_none!(&radarr_data.add_movie_search);
+      assert_none!(&radarr_data.add_indexer_modal);
       assert_none!(&radarr_data.add_movie_modal);
       assert_none!(&radarr_data.add_searched_movies);
+      assert_none!(&radarr_data.bulk_edit_modal);
       assert_none!(&radarr_data.edit_movie_modal);
       assert_none!(&radarr_data.edit_collection_modal);
       assert_none!(&radarr_data.edit_root_folder);
@@ -134,13 +152,23 @@ mod tests {
       assert_none!(&radarr_data.indexer_settings);
       assert_none!(&radarr_data.indexer_test_errors);
       assert_none!(&radarr_data.indexer_test_all_results);
+      assert_none!(&radarr_data.download_client_modal);
+      assert_none!(&radarr_data.download_client_test_errors);
+      assert_none!(&radarr_data.download_client_test_all_results);
+      assert_none!(&radarr_data.manual_import_modal);
+      assert_none!(&radarr_data.media_management_modal);
       assert_none!(&radarr_data.movie_details_modal);
+      assert_none!(&radarr_data.naming_examples);
       assert_none!(&radarr_data.prompt_confirm_action);
       assert!(!radarr_data.prompt_confirm);
+      assert_eq!(
+        radarr_data.queue_removal_options,
+        QueueRemovalOptions::default()
+      );
       assert!(!radarr_data.delete_movie_files);
       assert!(!radarr_data.add_list_exclusion);
 
-      assert_eq!(radarr_data.main_tabs.tabs.len(), 8);
+      assert_eq!(radarr_data.main_tabs.tabs.len(), 13);
 
       assert_str_eq!(radarr_data.main_tabs.tabs[0].title, "Library");
       assert_eq!(
@@ -226,18 +254,78 @@ mod tests {
       );
       assert_eq!(radarr_data.main_tabs.tabs[6].config, None);
 
-      assert_str_eq!(radarr_data.main_tabs.tabs[7].title, "System");
+      assert_str_eq!(radarr_data.main_tabs.tabs[7].title, "Download Clients");
       assert_eq!(
         radarr_data.main_tabs.tabs[7].route,
-        ActiveRadarrBlock::System.into()
+        ActiveRadarrBlock::DownloadClients.into()
       );
       assert!(radarr_data.main_tabs.tabs[7].contextual_help.is_some());
       assert_eq!(
         radarr_data.main_tabs.tabs[7].contextual_help.unwrap(),
-        &SYSTEM_CONTEXT_CLUES
+        &DOWNLOAD_CLIENTS_CONTEXT_CLUES
       );
       assert_eq!(radarr_data.main_tabs.tabs[7].config, None);
 
+      assert_str_eq!(radarr_data.main_tabs.tabs[8].title, "Quality Profiles");
+      assert_eq!(
+        radarr_data.main_tabs.tabs[8].route,
+        ActiveRadarrBlock::QualityProfiles.into()
+      );
+      assert!(radarr_data.main_tabs.tabs[8].contextual_help.is_some());
+      assert_eq!(
+        radarr_data.main_tabs.tabs[8].contextual_help.unwrap(),
+        &QUALITY_PROFILES_CONTEXT_CLUES
+      );
+      assert_eq!(radarr_data.main_tabs.tabs[8].config, None);
+
+      assert_str_eq!(radarr_data.main_tabs.tabs[9].title, "Custom Formats");
+      assert_eq!(
+        radarr_data.main_tabs.tabs[9].route,
+        ActiveRadarrBlock::CustomFormats.into()
+      );
+      assert!(radarr_data.main_tabs.tabs[9].contextual_help.is_some());
+      assert_eq!(
+        radarr_data.main_tabs.tabs[9].contextual_help.unwrap(),
+        &CUSTOM_FORMATS_CONTEXT_CLUES
+      );
+      assert_eq!(radarr_data.main_tabs.tabs[9].config, None);
+
+      assert_str_eq!(radarr_data.main_tabs.tabs[10].title, "System");
+      assert_eq!(
+        radarr_data.main_tabs.tabs[10].route,
+        ActiveRadarrBlock::System.into()
+      );
+      assert!(radarr_data.main_tabs.tabs[10].contextual_help.is_some());
+      assert_eq!(
+        radarr_data.main_tabs.tabs[10].contextual_help.unwrap(),
+        &SYSTEM_WITH_BACKUPS_CONTEXT_CLUES
+      );
+      assert_eq!(radarr_data.main_tabs.tabs[10].config, None);
+
+      assert_str_eq!(radarr_data.main_tabs.tabs[11].title, "Calendar");
+      assert_eq!(
+        radarr_data.main_tabs.tabs[11].route,
+        ActiveRadarrBlock::Calendar.into()
+      );
+      assert!(radarr_data.main_tabs.tabs[11].contextual_help.is_some());
+      assert_eq!(
+        radarr_data.main_tabs.tabs[11].contextual_help.unwrap(),
+        &CALENDAR_CONTEXT_CLUES
+      );
+      assert_eq!(radarr_data.main_tabs.tabs[11].config, None);
+
+      assert_str_eq!(radarr_data.main_tabs.tabs[12].title, "Wanted");
+      assert_eq!(
+        radarr_data.main_tabs.tabs[12].route,
+        ActiveRadarrBlock::WantedMissing.into()
+      );
+      assert!(radarr_data.main_tabs.tabs[12].contextual_help.is_some());
+      assert_eq!(
+        radarr_data.main_tabs.tabs[12].contextual_help.unwrap(),
+        &WANTED_CONTEXT_CLUES
+      );
+      assert_eq!(radarr_data.main_tabs.tabs[12].config, None);
+
       assert_eq!(radarr_data.movie_info_tabs.tabs.len(), 6);
 
       assert_str_eq!(radarr_data.movie_info_tabs.tabs[0].title, "Details");
@@ -342,13 +430,17 @@ mod tests {
     use pretty_assertions::assert_eq;
 
     use crate::models::servarr_data::radarr::radarr_data::{
-      ADD_MOVIE_BLOCKS, ADD_MOVIE_SELECTION_BLOCKS, ActiveRadarrBlock, BLOCKLIST_BLOCKS,
-      COLLECTION_DETAILS_BLOCKS, COLLECTIONS_BLOCKS, DELETE_MOVIE_BLOCKS,
-      DELETE_MOVIE_SELECTION_BLOCKS, DOWNLOADS_BLOCKS, EDIT_COLLECTION_BLOCKS,
-      EDIT_COLLECTION_SELECTION_BLOCKS, EDIT_INDEXER_BLOCKS, EDIT_INDEXER_NZB_SELECTION_BLOCKS,
+      ADD_INDEXER_BLOCKS, ADD_MOVIE_BLOCKS, ADD_MOVIE_SELECTION_BLOCKS, ActiveRadarrBlock,
+      BLOCKLIST_BLOCKS, BULK_EDIT_MOVIES_BLOCKS, BULK_EDIT_MOVIES_SELECTION_BLOCKS,
+      CALENDAR_BLOCKS, COLLECTION_DETAILS_BLOCKS, COLLECTIONS_BLOCKS, CUSTOM_FORMAT_FORM_BLOCKS,
+      CUSTOM_FORMATS_BLOCKS, DELETE_DOWNLOAD_SELECTION_BLOCKS, DELETE_MOVIE_BLOCKS,
+      DELETE_MOVIE_SELECTION_BLOCKS, DOWNLOAD_CLIENT_FORM_BLOCKS, DOWNLOAD_CLIENTS_BLOCKS,
+      DOWNLOADS_BLOCKS, EDIT_COLLECTION_BLOCKS, EDIT_COLLECTION_SELECTION_BLOCKS,
+      EDIT_INDEXER_BLOCKS, EDIT_INDEXER_NZB_SELECTION_BLOCKS,
       EDIT_INDEXER_TORRENT_SELECTION_BLOCKS, EDIT_MOVIE_BLOCKS, EDIT_MOVIE_SELECTION_BLOCKS,
       HISTORY_BLOCKS, INDEXER_SETTINGS_BLOCKS, INDEXER_SETTINGS_SELECTION_BLOCKS, INDEXERS_BLOCKS,
-      LIBRARY_BLOCKS, MOVIE_DETAILS_BLOCKS, ROOT_FOLDERS_BLOCKS, SYSTEM_DETAILS_BLOCKS,
+      LIBRARY_BLOCKS, MEDIA_MANAGEMENT_SETTINGS_BLOCKS, MOVIE_DETAILS_BLOCKS,
+      QUALITY_PROFILES_BLOCKS, ROOT_FOLDERS_BLOCKS, SYSTEM_DETAILS_BLOCKS, WANTED_BLOCKS,
     };
 
     #[test]
@@ -391,6 +483,18 @@ mod tests {
       assert!(ROOT_FOLDERS_BLOCKS.contains(&ActiveRadarrBlock::DeleteRootFolderPrompt));
     }
 
+    #[test]
+    fn test_media_management_settings_blocks_contents() {
+      assert_eq!(MEDIA_MANAGEMENT_SETTINGS_BLOCKS.len(), 2);
+      assert!(
+        MEDIA_MANAGEMENT_SETTINGS_BLOCKS
+          .contains(&ActiveRadarrBlock::MediaManagementSettingsPrompt)
+      );
+      assert!(
+        MEDIA_MANAGEMENT_SETTINGS_BLOCKS.contains(&ActiveRadarrBlock::MediaManagementFieldInput)
+      );
+    }
+
     #[test]
     fn test_blocklist_blocks_contents() {
       assert_eq!(BLOCKLIST_BLOCKS.len(), 5);
@@ -413,6 +517,22 @@ mod tests {
       assert!(HISTORY_BLOCKS.contains(&ActiveRadarrBlock::SearchHistoryError));
     }
 
+    #[test]
+    fn test_calendar_blocks_contents() {
+      assert_eq!(CALENDAR_BLOCKS.len(), 2);
+      assert!(CALENDAR_BLOCKS.contains(&ActiveRadarrBlock::Calendar));
+      assert!(CALENDAR_BLOCKS.contains(&ActiveRadarrBlock::CalendarAgenda));
+    }
+
+    #[test]
+    fn test_wanted_blocks_contents() {
+      assert_eq!(WANTED_BLOCKS.len(), 4);
+      assert!(WANTED_BLOCKS.contains(&ActiveRadarrBlock::WantedMissing));
+      assert!(WANTED_BLOCKS.contains(&ActiveRadarrBlock::WantedCutoffUnmet));
+      assert!(WANTED_BLOCKS.contains(&ActiveRadarrBlock::WantedSearchAllPrompt));
+      assert!(WANTED_BLOCKS.contains(&ActiveRadarrBlock::WantedSearchSelectedPrompt));
+    }
+
     #[test]
     fn test_add_movie_blocks_contents() {
       assert_eq!(ADD_MOVIE_BLOCKS.len(), 10);
@@ -459,12 +579,63 @@ mod tests {
       assert!(EDIT_MOVIE_BLOCKS.contains(&ActiveRadarrBlock::EditMovieToggleMonitored));
     }
 
+    #[test]
+    fn test_bulk_edit_movies_blocks_contents() {
+      assert_eq!(BULK_EDIT_MOVIES_BLOCKS.len(), 8);
+      assert!(BULK_EDIT_MOVIES_BLOCKS.contains(&ActiveRadarrBlock::BulkEditMoviesPrompt));
+      assert!(BULK_EDIT_MOVIES_BLOCKS.contains(&ActiveRadarrBlock::BulkEditMoviesConfirmPrompt));
+      assert!(BULK_EDIT_MOVIES_BLOCKS.contains(&ActiveRadarrBlock::BulkEditMoviesSelectApplyTags));
+      assert!(BULK_EDIT_MOVIES_BLOCKS.contains(&ActiveRadarrBlock::BulkEditMoviesSelectMonitored));
+      assert!(
+        BULK_EDIT_MOVIES_BLOCKS.contains(&ActiveRadarrBlock::BulkEditMoviesSelectQualityProfile)
+      );
+      assert!(BULK_EDIT_MOVIES_BLOCKS.contains(&ActiveRadarrBlock::BulkEditMoviesSelectRootFolder));
+      assert!(BULK_EDIT_MOVIES_BLOCKS.contains(&ActiveRadarrBlock::BulkEditMoviesTagsInput));
+      assert!(BULK_EDIT_MOVIES_BLOCKS.contains(&ActiveRadarrBlock::BulkEditMoviesToggleMoveFiles));
+    }
+
     #[test]
     fn test_downloads_blocks_contents() {
-      assert_eq!(DOWNLOADS_BLOCKS.len(), 3);
+      assert_eq!(DOWNLOADS_BLOCKS.len(), 12);
       assert!(DOWNLOADS_BLOCKS.contains(&ActiveRadarrBlock::Downloads));
       assert!(DOWNLOADS_BLOCKS.contains(&ActiveRadarrBlock::DeleteDownloadPrompt));
+      assert!(DOWNLOADS_BLOCKS.contains(&ActiveRadarrBlock::DeleteDownloadConfirmPrompt));
+      assert!(DOWNLOADS_BLOCKS.contains(&ActiveRadarrBlock::DeleteDownloadToggleRemoveFromClient));
+      assert!(DOWNLOADS_BLOCKS.contains(&ActiveRadarrBlock::DeleteDownloadToggleBlocklist));
+      assert!(DOWNLOADS_BLOCKS.contains(&ActiveRadarrBlock::DeleteDownloadToggleSkipRedownload));
+      assert!(DOWNLOADS_BLOCKS.contains(&ActiveRadarrBlock::DeleteDownloadToggleChangeCategory));
+      assert!(DOWNLOADS_BLOCKS.contains(&ActiveRadarrBlock::GrabDownloadPrompt));
       assert!(DOWNLOADS_BLOCKS.contains(&ActiveRadarrBlock::UpdateDownloadsPrompt));
+      assert!(DOWNLOADS_BLOCKS.contains(&ActiveRadarrBlock::ManualImport));
+      assert!(DOWNLOADS_BLOCKS.contains(&ActiveRadarrBlock::ManualImportConfirmPrompt));
+      assert!(DOWNLOADS_BLOCKS.contains(&ActiveRadarrBlock::ManualImportSelectMovie));
+    }
+
+    #[test]
+    fn test_delete_download_selection_blocks_ordering() {
+      let mut delete_download_block_iter = DELETE_DOWNLOAD_SELECTION_BLOCKS.iter();
+
+      assert_eq!(
+        delete_download_block_iter.next().unwrap(),
+        &[ActiveRadarrBlock::DeleteDownloadToggleRemoveFromClient]
+      );
+      assert_eq!(
+        delete_download_block_iter.next().unwrap(),
+        &[ActiveRadarrBlock::DeleteDownloadToggleBlocklist]
+      );
+      assert_eq!(
+        delete_download_block_iter.next().unwrap(),
+        &[ActiveRadarrBlock::DeleteDownloadToggleSkipRedownload]
+      );
+      assert_eq!(
+        delete_download_block_iter.next().unwrap(),
+        &[ActiveRadarrBlock::DeleteDownloadToggleChangeCategory]
+      );
+      assert_eq!(
+        delete_download_block_iter.next().unwrap(),
+        &[ActiveRadarrBlock::DeleteDownloadConfirmPrompt]
+      );
+      assert_eq!(delete_download_block_iter.next(), None);
     }
 
     #[test]
@@ -498,6 +669,57 @@ mod tests {
       assert!(DELETE_MOVIE_BLOCKS.contains(&ActiveRadarrBlock::DeleteMovieToggleAddListExclusion));
     }
 
+    #[test]
+    fn test_add_indexer_blocks_contents() {
+      assert_eq!(ADD_INDEXER_BLOCKS.len(), 3);
+      assert!(ADD_INDEXER_BLOCKS.contains(&ActiveRadarrBlock::AddIndexerFieldInput));
+      assert!(ADD_INDEXER_BLOCKS.contains(&ActiveRadarrBlock::AddIndexerPrompt));
+      assert!(ADD_INDEXER_BLOCKS.contains(&ActiveRadarrBlock::AddIndexerSelectImplementation));
+    }
+
+    #[test]
+    fn test_download_clients_blocks_contents() {
+      assert_eq!(DOWNLOAD_CLIENTS_BLOCKS.len(), 3);
+      assert!(DOWNLOAD_CLIENTS_BLOCKS.contains(&ActiveRadarrBlock::DeleteDownloadClientPrompt));
+      assert!(DOWNLOAD_CLIENTS_BLOCKS.contains(&ActiveRadarrBlock::DownloadClients));
+      assert!(DOWNLOAD_CLIENTS_BLOCKS.contains(&ActiveRadarrBlock::TestDownloadClient));
+    }
+
+    #[test]
+    fn test_quality_profiles_blocks_contents() {
+      assert_eq!(QUALITY_PROFILES_BLOCKS.len(), 2);
+      assert!(QUALITY_PROFILES_BLOCKS.contains(&ActiveRadarrBlock::EditQualityProfile));
+      assert!(QUALITY_PROFILES_BLOCKS.contains(&ActiveRadarrBlock::QualityProfiles));
+    }
+
+    #[test]
+    fn test_custom_formats_blocks_contents() {
+      assert_eq!(CUSTOM_FORMATS_BLOCKS.len(), 5);
+      assert!(CUSTOM_FORMATS_BLOCKS.contains(&ActiveRadarrBlock::CustomFormats));
+      assert!(CUSTOM_FORMATS_BLOCKS.contains(&ActiveRadarrBlock::CustomFormatDetails));
+      assert!(CUSTOM_FORMATS_BLOCKS.contains(&ActiveRadarrBlock::DeleteCustomFormatPrompt));
+      assert!(CUSTOM_FORMATS_BLOCKS.contains(&ActiveRadarrBlock::ExportCustomFormatPrompt));
+      assert!(CUSTOM_FORMATS_BLOCKS.contains(&ActiveRadarrBlock::ImportCustomFormatPrompt));
+    }
+
+    #[test]
+    fn test_custom_format_form_blocks_contents() {
+      assert_eq!(CUSTOM_FORMAT_FORM_BLOCKS.len(), 2);
+      assert!(CUSTOM_FORMAT_FORM_BLOCKS.contains(&ActiveRadarrBlock::EditCustomFormatPrompt));
+      assert!(CUSTOM_FORMAT_FORM_BLOCKS.contains(&ActiveRadarrBlock::CustomFormatFieldInput));
+    }
+
+    #[test]
+    fn test_download_client_form_blocks_contents() {
+      assert_eq!(DOWNLOAD_CLIENT_FORM_BLOCKS.len(), 3);
+      assert!(
+        DOWNLOAD_CLIENT_FORM_BLOCKS
+          .contains(&ActiveRadarrBlock::AddDownloadClientSelectImplementation)
+      );
+      assert!(DOWNLOAD_CLIENT_FORM_BLOCKS.contains(&ActiveRadarrBlock::DownloadClientFieldInput));
+      assert!(DOWNLOAD_CLIENT_FORM_BLOCKS.contains(&ActiveRadarrBlock::DownloadClientPrompt));
+    }
+
     #[test]
     fn test_edit_indexer_blocks_contents() {
       assert_eq!(EDIT_INDEXER_BLOCKS.len(), 11);
@@ -550,7 +772,9 @@ mod tests {
 
     #[test]
     fn test_system_details_blocks_contents() {
-      assert_eq!(SYSTEM_DETAILS_BLOCKS.len(), 5);
+      assert_eq!(SYSTEM_DETAILS_BLOCKS.len(), 7);
+      assert!(SYSTEM_DETAILS_BLOCKS.contains(&ActiveRadarrBlock::SystemBackups));
+      assert!(SYSTEM_DETAILS_BLOCKS.contains(&ActiveRadarrBlock::DeleteBackupPrompt));
       assert!(SYSTEM_DETAILS_BLOCKS.contains(&ActiveRadarrBlock::SystemLogs));
       assert!(SYSTEM_DETAILS_BLOCKS.contains(&ActiveRadarrBlock::SystemQueuedEvents));
       assert!(SYSTEM_DETAILS_BLOCKS.contains(&ActiveRadarrBlock::SystemTasks));
@@ -620,6 +844,41 @@ mod tests {
       assert_eq!(edit_movie_block_iter.next(), None);
     }
 
+    #[test]
+    fn test_bulk_edit_movies_selection_blocks_ordering() {
+      let mut bulk_edit_movies_block_iter = BULK_EDIT_MOVIES_SELECTION_BLOCKS.iter();
+
+      assert_eq!(
+        bulk_edit_movies_block_iter.next().unwrap(),
+        &[ActiveRadarrBlock::BulkEditMoviesSelectMonitored]
+      );
+      assert_eq!(
+        bulk_edit_movies_block_iter.next().unwrap(),
+        &[ActiveRadarrBlock::BulkEditMoviesSelectQualityProfile]
+      );
+      assert_eq!(
+        bulk_edit_movies_block_iter.next().unwrap(),
+        &[ActiveRadarrBlock::BulkEditMoviesSelectRootFolder]
+      );
+      assert_eq!(
+        bulk_edit_movies_block_iter.next().unwrap(),
+        &[ActiveRadarrBlock::BulkEditMoviesToggleMoveFiles]
+      );
+      assert_eq!(
+        bulk_edit_movies_block_iter.next().unwrap(),
+        &[ActiveRadarrBlock::BulkEditMoviesTagsInput]
+      );
+      assert_eq!(
+        bulk_edit_movies_block_iter.next().unwrap(),
+        &[ActiveRadarrBlock::BulkEditMoviesSelectApplyTags]
+      );
+      assert_eq!(
+        bulk_edit_movies_block_iter.next().unwrap(),
+        &[ActiveRadarrBlock::BulkEditMoviesConfirmPrompt]
+      );
+      assert_eq!(bulk_edit_movies_block_iter.next(), None);
+    }
+
     #[test]
     fn test_edit_collection_selection_blocks_ordering() {
       let mut edit_collection_block_iter = EDIT_COLLECTION_SELECTION_BLOCKS.iter();
@@ -0,0 +1,2 @@
+pub mod modals;
+pub mod readarr_data;
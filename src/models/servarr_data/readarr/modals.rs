@@ -0,0 +1,122 @@
+use super::readarr_data::ReadarrData;
+use crate::models::readarr_models::Edition;
+use crate::models::stateful_table::StatefulTable;
+use crate::models::{
+  HorizontallyScrollableText,
+  readarr_models::{MonitorType, NewItemMonitorType},
+  servarr_models::RootFolder,
+  stateful_list::StatefulList,
+};
+use strum::IntoEnumIterator;
+
+#[cfg(test)]
+#[path = "modals_tests.rs"]
+mod modals_tests;
+
+#[derive(Default)]
+#[cfg_attr(test, derive(Debug))]
+pub struct AddAuthorModal {
+  pub root_folder_list: StatefulList<RootFolder>,
+  pub monitor_list: StatefulList<MonitorType>,
+  pub monitor_new_items_list: StatefulList<NewItemMonitorType>,
+  pub quality_profile_list: StatefulList<String>,
+  pub metadata_profile_list: StatefulList<String>,
+  pub tags: HorizontallyScrollableText,
+}
+
+impl From<&ReadarrData<'_>> for AddAuthorModal {
+  fn from(readarr_data: &ReadarrData<'_>) -> AddAuthorModal {
+    let mut add_author_modal = AddAuthorModal::default();
+    add_author_modal
+      .monitor_list
+      .set_items(Vec::from_iter(MonitorType::iter()));
+    add_author_modal
+      .monitor_new_items_list
+      .set_items(Vec::from_iter(NewItemMonitorType::iter()));
+    add_author_modal
+      .quality_profile_list
+      .set_items(readarr_data.sorted_quality_profile_names());
+    add_author_modal
+      .metadata_profile_list
+      .set_items(readarr_data.sorted_metadata_profile_names());
+    add_author_modal
+      .root_folder_list
+      .set_items(readarr_data.root_folders.items.to_vec());
+
+    add_author_modal
+  }
+}
+
+#[derive(Default)]
+#[cfg_attr(test, derive(Debug))]
+pub struct EditAuthorModal {
+  pub monitor_list: StatefulList<NewItemMonitorType>,
+  pub quality_profile_list: StatefulList<String>,
+  pub metadata_profile_list: StatefulList<String>,
+  pub monitored: Option<bool>,
+  pub path: HorizontallyScrollableText,
+  pub tags: HorizontallyScrollableText,
+}
+
+impl From<&ReadarrData<'_>> for EditAuthorModal {
+  fn from(readarr_data: &ReadarrData<'_>) -> EditAuthorModal {
+    let mut edit_author_modal = EditAuthorModal::default();
+    let author = readarr_data.authors.current_selection();
+
+    edit_author_modal
+      .monitor_list
+      .set_items(Vec::from_iter(NewItemMonitorType::iter()));
+    edit_author_modal.path = author.path.clone().into();
+    edit_author_modal.tags = readarr_data.tag_ids_to_display(&author.tags).into();
+    edit_author_modal.monitored = Some(author.monitored);
+
+    let monitor_index = edit_author_modal
+      .monitor_list
+      .items
+      .iter()
+      .position(|m| *m == author.monitor_new_items);
+    edit_author_modal.monitor_list.state.select(monitor_index);
+
+    edit_author_modal
+      .quality_profile_list
+      .set_items(readarr_data.sorted_quality_profile_names());
+    let quality_profile_name = readarr_data
+      .quality_profile_map
+      .get_by_left(&author.quality_profile_id)
+      .unwrap();
+    let quality_profile_index = edit_author_modal
+      .quality_profile_list
+      .items
+      .iter()
+      .position(|profile| profile == quality_profile_name);
+    edit_author_modal
+      .quality_profile_list
+      .state
+      .select(quality_profile_index);
+
+    edit_author_modal
+      .metadata_profile_list
+      .set_items(readarr_data.sorted_metadata_profile_names());
+    let metadata_profile_name = readarr_data
+      .metadata_profile_map
+      .get_by_left(&author.metadata_profile_id)
+      .unwrap();
+    let metadata_profile_index = edit_author_modal
+      .metadata_profile_list
+      .items
+      .iter()
+      .position(|profile| profile == metadata_profile_name);
+    edit_author_modal
+      .metadata_profile_list
+      .state
+      .select(metadata_profile_index);
+
+    edit_author_modal
+  }
+}
+
+#[derive(Default)]
+#[cfg_attr(test, derive(Debug))]
+pub struct BookDetailsModal {
+  pub editions: StatefulTable<Edition>,
+}
@@ -0,0 +1,111 @@
+#[cfg(test)]
+mod tests {
+  use crate::models::readarr_models::{Author, MonitorType, NewItemMonitorType};
+  use crate::models::servarr_data::readarr::modals::{AddAuthorModal, EditAuthorModal};
+  use crate::models::servarr_data::readarr::readarr_data::ReadarrData;
+  use crate::models::servarr_models::RootFolder;
+  use bimap::BiMap;
+  use pretty_assertions::{assert_eq, assert_str_eq};
+  use serde_json::Number;
+
+  #[test]
+  fn test_add_author_modal_from_readarr_data() {
+    let mut readarr_data = ReadarrData {
+      quality_profile_map: BiMap::from_iter([
+        (2i64, "eBook".to_owned()),
+        (1i64, "Standard".to_owned()),
+      ]),
+      metadata_profile_map: BiMap::from_iter([
+        (2i64, "None".to_owned()),
+        (1i64, "Standard".to_owned()),
+      ]),
+      ..ReadarrData::default()
+    };
+    let root_folder_1 = RootFolder {
+      id: 1,
+      path: "/nfs".to_owned(),
+      accessible: true,
+      free_space: 219902325555200,
+      unmapped_folders: None,
+    };
+    readarr_data.root_folders.set_items(vec![
+      root_folder_1.clone(),
+      RootFolder {
+        id: 2,
+        path: "/nfs2".to_owned(),
+        accessible: true,
+        free_space: 21990232555520,
+        unmapped_folders: None,
+      },
+    ]);
+
+    let add_author_modal = AddAuthorModal::from(&readarr_data);
+
+    assert_eq!(
+      *add_author_modal.monitor_list.current_selection(),
+      MonitorType::default()
+    );
+    assert_eq!(
+      *add_author_modal.monitor_new_items_list.current_selection(),
+      NewItemMonitorType::default()
+    );
+    assert_str_eq!(
+      add_author_modal.quality_profile_list.current_selection(),
+      "Standard"
+    );
+    assert_str_eq!(
+      add_author_modal.metadata_profile_list.current_selection(),
+      "Standard"
+    );
+    assert_eq!(
+      add_author_modal.root_folder_list.current_selection(),
+      &root_folder_1
+    );
+    assert_is_empty!(add_author_modal.tags.text);
+  }
+
+  #[test]
+  fn test_edit_author_modal_from_readarr_data() {
+    let mut readarr_data = ReadarrData {
+      quality_profile_map: BiMap::from_iter([
+        (1i64, "HD - 1080p".to_owned()),
+        (2i64, "Any".to_owned()),
+      ]),
+      metadata_profile_map: BiMap::from_iter([
+        (1i64, "Standard".to_owned()),
+        (2i64, "None".to_owned()),
+      ]),
+      tags_map: BiMap::from_iter([(1i64, "usenet".to_owned())]),
+      ..ReadarrData::default()
+    };
+    let author = Author {
+      id: 1,
+      monitored: true,
+      monitor_new_items: NewItemMonitorType::All,
+      quality_profile_id: 1,
+      metadata_profile_id: 1,
+      path: "/nfs/books/test_author".to_owned(),
+      tags: vec![Number::from(1)],
+      ..Author::default()
+    };
+    readarr_data.authors.set_items(vec![author]);
+
+    let edit_author_modal = EditAuthorModal::from(&readarr_data);
+
+    assert_some_eq_x!(&edit_author_modal.monitored, &true);
+    assert_eq!(
+      *edit_author_modal.monitor_list.current_selection(),
+      NewItemMonitorType::All
+    );
+    assert_str_eq!(
+      edit_author_modal.quality_profile_list.current_selection(),
+      "HD - 1080p"
+    );
+    assert_str_eq!(
+      edit_author_modal.metadata_profile_list.current_selection(),
+      "Standard"
+    );
+    assert_str_eq!(edit_author_modal.path.text, "/nfs/books/test_author");
+    assert_str_eq!(edit_author_modal.tags.text, "usenet");
+  }
+}
@@ -0,0 +1,382 @@
+use serde_json::Number;
+
+use super::modals::{AddAuthorModal, BookDetailsModal, EditAuthorModal};
+use crate::app::context_clues::SYSTEM_CONTEXT_CLUES;
+use crate::app::readarr::readarr_context_clues::AUTHORS_CONTEXT_CLUES;
+use crate::models::readarr_models::ReadarrTask;
+use crate::models::servarr_models::QueueEvent;
+use crate::models::stateful_list::StatefulList;
+use crate::models::{
+  BlockSelectionState, HorizontallyScrollableText, Route, ScrollableText, TabRoute, TabState,
+  readarr_models::{AddAuthorSearchResult, Author, Book},
+  servarr_models::{DiskSpace, RootFolder},
+  stateful_table::StatefulTable,
+};
+use crate::network::readarr_network::ReadarrEvent;
+use bimap::BiMap;
+use chrono::{DateTime, Utc};
+use itertools::Itertools;
+use strum::EnumIter;
+#[cfg(test)]
+use {
+  crate::models::readarr_models::{MonitorType, NewItemMonitorType},
+  crate::models::stateful_table::SortOption,
+  crate::network::readarr_network::readarr_network_test_utils::test_utils::{
+    add_author_search_result, author, book, edition, log_line, metadata_profile,
+    metadata_profile_map, quality_profile, quality_profile_map, root_folder, tags_map, task,
+    updates,
+  },
+  crate::network::servarr_test_utils::diskspace,
+  crate::network::servarr_test_utils::queued_event,
+  strum::{Display, EnumString, IntoEnumIterator},
+};
+
+#[cfg(test)]
+#[path = "readarr_data_tests.rs"]
+mod readarr_data_tests;
+
+pub struct ReadarrData<'a> {
+  pub add_author_modal: Option<AddAuthorModal>,
+  pub add_author_search: Option<HorizontallyScrollableText>,
+  pub add_import_list_exclusion: bool,
+  pub add_searched_authors: Option<StatefulTable<AddAuthorSearchResult>>,
+  pub authors: StatefulTable<Author>,
+  pub book_details_modal: Option<BookDetailsModal>,
+  pub books: StatefulTable<Book>,
+  pub delete_files: bool,
+  pub disk_space_vec: Vec<DiskSpace>,
+  pub edit_author_modal: Option<EditAuthorModal>,
+  pub logs: StatefulList<HorizontallyScrollableText>,
+  pub log_details: StatefulList<HorizontallyScrollableText>,
+  pub main_tabs: TabState,
+  pub metadata_profile_map: BiMap<i64, String>,
+  pub prompt_confirm: bool,
+  pub prompt_confirm_action: Option<ReadarrEvent>,
+  pub quality_profile_map: BiMap<i64, String>,
+  pub queued_events: StatefulTable<QueueEvent>,
+  pub root_folders: StatefulTable<RootFolder>,
+  pub selected_block: BlockSelectionState<'a, ActiveReadarrBlock>,
+  pub start_time: DateTime<Utc>,
+  pub tags_map: BiMap<i64, String>,
+  pub tasks: StatefulTable<ReadarrTask>,
+  pub updates: ScrollableText,
+  pub version: String,
+}
+
+impl ReadarrData<'_> {
+  pub fn reset_delete_preferences(&mut self) {
+    self.delete_files = false;
+    self.add_import_list_exclusion = false;
+  }
+
+  pub fn tag_ids_to_display(&self, tag_ids: &[Number]) -> String {
+    tag_ids
+      .iter()
+      .filter_map(|id| {
+        let id = id.as_i64()?;
+        self.tags_map.get_by_left(&id).cloned()
+      })
+      .collect::<Vec<String>>()
+      .join(", ")
+  }
+
+  pub fn sorted_quality_profile_names(&self) -> Vec<String> {
+    self
+      .quality_profile_map
+      .iter()
+      .sorted_by_key(|(id, _)| *id)
+      .map(|(_, name)| name)
+      .cloned()
+      .collect()
+  }
+
+  pub fn sorted_metadata_profile_names(&self) -> Vec<String> {
+    self
+      .metadata_profile_map
+      .iter()
+      .sorted_by_key(|(id, _)| *id)
+      .map(|(_, name)| name)
+      .cloned()
+      .collect()
+  }
+}
+
+impl<'a> Default for ReadarrData<'a> {
+  fn default() -> ReadarrData<'a> {
+    ReadarrData {
+      add_author_modal: None,
+      add_author_search: None,
+      add_import_list_exclusion: false,
+      add_searched_authors: None,
+      authors: StatefulTable::default(),
+      book_details_modal: None,
+      books: StatefulTable::default(),
+      delete_files: false,
+      disk_space_vec: Vec::new(),
+      edit_author_modal: None,
+      logs: StatefulList::default(),
+      log_details: StatefulList::default(),
+      metadata_profile_map: BiMap::new(),
+      prompt_confirm: false,
+      prompt_confirm_action: None,
+      quality_profile_map: BiMap::new(),
+      queued_events: StatefulTable::default(),
+      root_folders: StatefulTable::default(),
+      selected_block: BlockSelectionState::default(),
+      start_time: DateTime::default(),
+      tags_map: BiMap::new(),
+      tasks: StatefulTable::default(),
+      updates: ScrollableText::default(),
+      version: String::new(),
+      main_tabs: TabState::new(vec![
+        TabRoute {
+          title: "Library".to_string(),
+          route: ActiveReadarrBlock::Authors.into(),
+          contextual_help: Some(&AUTHORS_CONTEXT_CLUES),
+          config: None,
+        },
+        TabRoute {
+          title: "System".to_string(),
+          route: ActiveReadarrBlock::System.into(),
+          contextual_help: Some(&SYSTEM_CONTEXT_CLUES),
+          config: None,
+        },
+      ]),
+    }
+  }
+}
+
+#[cfg(test)]
+impl ReadarrData<'_> {
+  pub fn test_default_fully_populated() -> Self {
+    let mut add_author_modal = AddAuthorModal {
+      tags: "usenet, testing".into(),
+      ..AddAuthorModal::default()
+    };
+    add_author_modal
+      .monitor_list
+      .set_items(Vec::from_iter(MonitorType::iter()));
+    add_author_modal
+      .monitor_new_items_list
+      .set_items(Vec::from_iter(NewItemMonitorType::iter()));
+    add_author_modal
+      .metadata_profile_list
+      .set_items(vec![metadata_profile().name]);
+    add_author_modal
+      .quality_profile_list
+      .set_items(vec![quality_profile().name]);
+    add_author_modal
+      .root_folder_list
+      .set_items(vec![root_folder()]);
+    let mut edit_author_modal = EditAuthorModal {
+      monitored: Some(true),
+      path: "/nfs/books".into(),
+      tags: "alex".into(),
+      ..EditAuthorModal::default()
+    };
+    edit_author_modal
+      .monitor_list
+      .set_items(NewItemMonitorType::iter().collect());
+    edit_author_modal
+      .quality_profile_list
+      .set_items(vec![quality_profile().name]);
+    edit_author_modal
+      .metadata_profile_list
+      .set_items(vec![metadata_profile().name]);
+
+    let mut book_details_modal = BookDetailsModal::default();
+    book_details_modal.editions.set_items(vec![edition()]);
+    book_details_modal.editions.search = Some("edition search".into());
+
+    let mut readarr_data = ReadarrData {
+      add_author_modal: Some(add_author_modal),
+      book_details_modal: Some(book_details_modal),
+      delete_files: true,
+      disk_space_vec: vec![diskspace()],
+      edit_author_modal: Some(edit_author_modal),
+      metadata_profile_map: metadata_profile_map(),
+      quality_profile_map: quality_profile_map(),
+      start_time: DateTime::from(DateTime::parse_from_rfc3339("2023-05-20T21:29:16Z").unwrap()),
+      tags_map: tags_map(),
+      updates: updates(),
+      version: "1.2.3.4".to_owned(),
+      ..ReadarrData::default()
+    };
+    readarr_data.books.set_items(vec![book()]);
+    readarr_data.books.search = Some("book search".into());
+    readarr_data.authors.set_items(vec![author()]);
+    readarr_data.authors.sorting(vec![SortOption {
+      name: "Name",
+      cmp_fn: Some(|a: &Author, b: &Author| a.author_name.text.cmp(&b.author_name.text)),
+    }]);
+    readarr_data.authors.search = Some("author search".into());
+    readarr_data.authors.filter = Some("author filter".into());
+    readarr_data.root_folders.set_items(vec![root_folder()]);
+    readarr_data.queued_events.set_items(vec![queued_event()]);
+    readarr_data.add_author_search = Some("Test Author".into());
+    let mut add_searched_authors = StatefulTable::default();
+    add_searched_authors.set_items(vec![add_author_search_result()]);
+    readarr_data.add_searched_authors = Some(add_searched_authors);
+    readarr_data.logs.set_items(vec![log_line().into()]);
+    readarr_data.log_details.set_items(vec![log_line().into()]);
+    readarr_data.tasks.set_items(vec![task()]);
+
+    readarr_data
+  }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, EnumIter)]
+#[cfg_attr(test, derive(Display, EnumString))]
+pub enum ActiveReadarrBlock {
+  #[default]
+  Authors,
+  AuthorDetails,
+  AuthorsSortPrompt,
+  AddAuthorAlreadyInLibrary,
+  AddAuthorConfirmPrompt,
+  AddAuthorEmptySearchResults,
+  AddAuthorPrompt,
+  AddAuthorSearchInput,
+  AddAuthorSearchResults,
+  AddAuthorSelectMetadataProfile,
+  AddAuthorSelectMonitor,
+  AddAuthorSelectMonitorNewItems,
+  AddAuthorSelectQualityProfile,
+  AddAuthorSelectRootFolder,
+  AddAuthorTagsInput,
+  AutomaticallySearchAuthorPrompt,
+  AutomaticallySearchBookPrompt,
+  BookDetails,
+  DeleteAuthorPrompt,
+  DeleteAuthorConfirmPrompt,
+  DeleteAuthorToggleDeleteFile,
+  DeleteAuthorToggleAddListExclusion,
+  EditAuthorPrompt,
+  EditAuthorConfirmPrompt,
+  EditAuthorPathInput,
+  EditAuthorSelectMetadataProfile,
+  EditAuthorSelectMonitorNewItems,
+  EditAuthorSelectQualityProfile,
+  EditAuthorTagsInput,
+  EditAuthorToggleMonitored,
+  FilterAuthors,
+  FilterAuthorsError,
+  SearchAuthors,
+  SearchAuthorsError,
+  SearchBooks,
+  SearchBooksError,
+  SearchEditions,
+  SearchEditionsError,
+  System,
+  SystemLogs,
+  SystemQueuedEvents,
+  SystemTasks,
+  SystemTaskStartConfirmPrompt,
+  SystemUpdates,
+  UpdateAllAuthorsPrompt,
+  UpdateAndScanAuthorPrompt,
+}
+
+pub static LIBRARY_BLOCKS: [ActiveReadarrBlock; 7] = [
+  ActiveReadarrBlock::Authors,
+  ActiveReadarrBlock::AuthorsSortPrompt,
+  ActiveReadarrBlock::FilterAuthors,
+  ActiveReadarrBlock::FilterAuthorsError,
+  ActiveReadarrBlock::SearchAuthors,
+  ActiveReadarrBlock::SearchAuthorsError,
+  ActiveReadarrBlock::UpdateAllAuthorsPrompt,
+];
+
+pub static AUTHOR_DETAILS_BLOCKS: [ActiveReadarrBlock; 5] = [
+  ActiveReadarrBlock::AuthorDetails,
+  ActiveReadarrBlock::AutomaticallySearchAuthorPrompt,
+  ActiveReadarrBlock::SearchBooks,
+  ActiveReadarrBlock::SearchBooksError,
+  ActiveReadarrBlock::UpdateAndScanAuthorPrompt,
+];
+
+pub static BOOK_DETAILS_BLOCKS: [ActiveReadarrBlock; 4] = [
+  ActiveReadarrBlock::BookDetails,
+  ActiveReadarrBlock::AutomaticallySearchBookPrompt,
+  ActiveReadarrBlock::SearchEditions,
+  ActiveReadarrBlock::SearchEditionsError,
+];
+
+pub static ADD_AUTHOR_BLOCKS: [ActiveReadarrBlock; 12] = [
+  ActiveReadarrBlock::AddAuthorAlreadyInLibrary,
+  ActiveReadarrBlock::AddAuthorConfirmPrompt,
+  ActiveReadarrBlock::AddAuthorEmptySearchResults,
+  ActiveReadarrBlock::AddAuthorPrompt,
+  ActiveReadarrBlock::AddAuthorSearchInput,
+  ActiveReadarrBlock::AddAuthorSearchResults,
+  ActiveReadarrBlock::AddAuthorSelectMetadataProfile,
+  ActiveReadarrBlock::AddAuthorSelectMonitor,
+  ActiveReadarrBlock::AddAuthorSelectMonitorNewItems,
+  ActiveReadarrBlock::AddAuthorSelectQualityProfile,
+  ActiveReadarrBlock::AddAuthorSelectRootFolder,
+  ActiveReadarrBlock::AddAuthorTagsInput,
+];
+
+pub const ADD_AUTHOR_SELECTION_BLOCKS: &[&[ActiveReadarrBlock]] = &[
+  &[ActiveReadarrBlock::AddAuthorSelectRootFolder],
+  &[ActiveReadarrBlock::AddAuthorSelectMonitor],
+  &[ActiveReadarrBlock::AddAuthorSelectMonitorNewItems],
+  &[ActiveReadarrBlock::AddAuthorSelectQualityProfile],
+  &[ActiveReadarrBlock::AddAuthorSelectMetadataProfile],
+  &[ActiveReadarrBlock::AddAuthorTagsInput],
+  &[ActiveReadarrBlock::AddAuthorConfirmPrompt],
+];
+
+pub static DELETE_AUTHOR_BLOCKS: [ActiveReadarrBlock; 4] = [
+  ActiveReadarrBlock::DeleteAuthorPrompt,
+  ActiveReadarrBlock::DeleteAuthorConfirmPrompt,
+  ActiveReadarrBlock::DeleteAuthorToggleDeleteFile,
+  ActiveReadarrBlock::DeleteAuthorToggleAddListExclusion,
+];
+
+pub const DELETE_AUTHOR_SELECTION_BLOCKS: &[&[ActiveReadarrBlock]] = &[
+  &[ActiveReadarrBlock::DeleteAuthorToggleDeleteFile],
+  &[ActiveReadarrBlock::DeleteAuthorToggleAddListExclusion],
+  &[ActiveReadarrBlock::DeleteAuthorConfirmPrompt],
+];
+
+pub static EDIT_AUTHOR_BLOCKS: [ActiveReadarrBlock; 8] = [
+  ActiveReadarrBlock::EditAuthorPrompt,
+  ActiveReadarrBlock::EditAuthorConfirmPrompt,
+  ActiveReadarrBlock::EditAuthorPathInput,
+  ActiveReadarrBlock::EditAuthorSelectMetadataProfile,
+  ActiveReadarrBlock::EditAuthorSelectMonitorNewItems,
+  ActiveReadarrBlock::EditAuthorSelectQualityProfile,
+  ActiveReadarrBlock::EditAuthorTagsInput,
+  ActiveReadarrBlock::EditAuthorToggleMonitored,
+];
+
+pub const EDIT_AUTHOR_SELECTION_BLOCKS: &[&[ActiveReadarrBlock]] = &[
+  &[ActiveReadarrBlock::EditAuthorToggleMonitored],
+  &[ActiveReadarrBlock::EditAuthorSelectMonitorNewItems],
+  &[ActiveReadarrBlock::EditAuthorSelectQualityProfile],
+  &[ActiveReadarrBlock::EditAuthorSelectMetadataProfile],
+  &[ActiveReadarrBlock::EditAuthorPathInput],
+  &[ActiveReadarrBlock::EditAuthorTagsInput],
+  &[ActiveReadarrBlock::EditAuthorConfirmPrompt],
+];
+
+pub static SYSTEM_DETAILS_BLOCKS: [ActiveReadarrBlock; 5] = [
+  ActiveReadarrBlock::SystemLogs,
+  ActiveReadarrBlock::SystemQueuedEvents,
+  ActiveReadarrBlock::SystemTasks,
+  ActiveReadarrBlock::SystemTaskStartConfirmPrompt,
+  ActiveReadarrBlock::SystemUpdates,
+];
+
+impl From<ActiveReadarrBlock> for Route {
+  fn from(active_readarr_block: ActiveReadarrBlock) -> Route {
+    Route::Readarr(active_readarr_block, None)
+  }
+}
+
+impl From<(ActiveReadarrBlock, Option<ActiveReadarrBlock>)> for Route {
+  fn from(value: (ActiveReadarrBlock, Option<ActiveReadarrBlock>)) -> Route {
+    Route::Readarr(value.0, value.1)
+  }
+}
@@ -0,0 +1,338 @@
+#[cfg(test)]
+mod tests {
+  use crate::app::context_clues::SYSTEM_CONTEXT_CLUES;
+  use crate::app::readarr::readarr_context_clues::AUTHORS_CONTEXT_CLUES;
+  use crate::models::servarr_data::readarr::readarr_data::{
+    ADD_AUTHOR_BLOCKS, ADD_AUTHOR_SELECTION_BLOCKS, AUTHOR_DETAILS_BLOCKS, BOOK_DETAILS_BLOCKS,
+    DELETE_AUTHOR_BLOCKS, DELETE_AUTHOR_SELECTION_BLOCKS, EDIT_AUTHOR_BLOCKS,
+    EDIT_AUTHOR_SELECTION_BLOCKS, SYSTEM_DETAILS_BLOCKS,
+  };
+  use crate::models::{
+    BlockSelectionState, Route,
+    servarr_data::readarr::readarr_data::{ActiveReadarrBlock, LIBRARY_BLOCKS, ReadarrData},
+  };
+  use bimap::BiMap;
+  use chrono::{DateTime, Utc};
+  use pretty_assertions::{assert_eq, assert_str_eq};
+  use serde_json::Number;
+
+  #[test]
+  fn test_from_active_readarr_block_to_route() {
+    assert_eq!(
+      Route::from(ActiveReadarrBlock::Authors),
+      Route::Readarr(ActiveReadarrBlock::Authors, None)
+    );
+  }
+
+  #[test]
+  fn test_from_tuple_to_route_with_context() {
+    assert_eq!(
+      Route::from((
+        ActiveReadarrBlock::Authors,
+        Some(ActiveReadarrBlock::Authors)
+      )),
+      Route::Readarr(
+        ActiveReadarrBlock::Authors,
+        Some(ActiveReadarrBlock::Authors),
+      )
+    );
+  }
+
+  #[test]
+  fn test_reset_delete_preferences() {
+    let mut readarr_data = ReadarrData {
+      delete_files: true,
+      add_import_list_exclusion: true,
+      ..ReadarrData::default()
+    };
+
+    readarr_data.reset_delete_preferences();
+
+    assert!(!readarr_data.delete_files);
+    assert!(!readarr_data.add_import_list_exclusion);
+  }
+
+  #[test]
+  fn test_tag_ids_to_display() {
+    let mut tags_map = BiMap::new();
+    tags_map.insert(3, "test 3".to_owned());
+    tags_map.insert(2, "test 2".to_owned());
+    tags_map.insert(1, "test 1".to_owned());
+    let readarr_data = ReadarrData {
+      tags_map,
+      ..ReadarrData::default()
+    };
+
+    assert_str_eq!(
+      readarr_data.tag_ids_to_display(&[Number::from(1), Number::from(2)]),
+      "test 1, test 2"
+    );
+  }
+
+  #[test]
+  fn test_sorted_quality_profile_names() {
+    let mut quality_profile_map = BiMap::new();
+    quality_profile_map.insert(3, "test 1".to_owned());
+    quality_profile_map.insert(2, "test 2".to_owned());
+    quality_profile_map.insert(1, "test 3".to_owned());
+    let readarr_data = ReadarrData {
+      quality_profile_map,
+      ..ReadarrData::default()
+    };
+    let expected_quality_profile_vec = vec![
+      "test 3".to_owned(),
+      "test 2".to_owned(),
+      "test 1".to_owned(),
+    ];
+
+    assert_iter_eq!(
+      readarr_data.sorted_quality_profile_names(),
+      expected_quality_profile_vec
+    );
+  }
+
+  #[test]
+  fn test_sorted_metadata_profile_names() {
+    let mut metadata_profile_map = BiMap::new();
+    metadata_profile_map.insert(3, "test 1".to_owned());
+    metadata_profile_map.insert(2, "test 2".to_owned());
+    metadata_profile_map.insert(1, "test 3".to_owned());
+    let readarr_data = ReadarrData {
+      metadata_profile_map,
+      ..ReadarrData::default()
+    };
+    let expected_metadata_profile_vec = vec![
+      "test 3".to_owned(),
+      "test 2".to_owned(),
+      "test 1".to_owned(),
+    ];
+
+    assert_iter_eq!(
+      readarr_data.sorted_metadata_profile_names(),
+      expected_metadata_profile_vec
+    );
+  }
+
+  #[test]
+  fn test_readarr_data_default() {
+    let readarr_data = ReadarrData::default();
+
+    assert_none!(readarr_data.add_author_modal);
+    assert_none!(readarr_data.add_author_search);
+    assert!(!readarr_data.add_import_list_exclusion);
+    assert_none!(readarr_data.add_searched_authors);
+    assert_is_empty!(readarr_data.authors);
+    assert_none!(readarr_data.book_details_modal);
+    assert_is_empty!(readarr_data.books);
+    assert!(!readarr_data.delete_files);
+    assert_is_empty!(readarr_data.disk_space_vec);
+    assert_none!(readarr_data.edit_author_modal);
+    assert_is_empty!(readarr_data.logs);
+    assert_is_empty!(readarr_data.log_details);
+    assert_is_empty!(readarr_data.metadata_profile_map);
+    assert!(!readarr_data.prompt_confirm);
+    assert_none!(readarr_data.prompt_confirm_action);
+    assert_is_empty!(readarr_data.quality_profile_map);
+    assert_is_empty!(readarr_data.queued_events);
+    assert_is_empty!(readarr_data.root_folders);
+    assert_eq!(readarr_data.selected_block, BlockSelectionState::default());
+    assert_eq!(readarr_data.start_time, <DateTime<Utc>>::default());
+    assert_is_empty!(readarr_data.tags_map);
+    assert_is_empty!(readarr_data.tasks);
+    assert_is_empty!(readarr_data.updates);
+    assert_is_empty!(readarr_data.version);
+
+    assert_eq!(readarr_data.main_tabs.tabs.len(), 2);
+
+    assert_str_eq!(readarr_data.main_tabs.tabs[0].title, "Library");
+    assert_eq!(
+      readarr_data.main_tabs.tabs[0].route,
+      ActiveReadarrBlock::Authors.into()
+    );
+    assert_some_eq_x!(
+      &readarr_data.main_tabs.tabs[0].contextual_help,
+      &AUTHORS_CONTEXT_CLUES
+    );
+    assert_none!(readarr_data.main_tabs.tabs[0].config);
+
+    assert_str_eq!(readarr_data.main_tabs.tabs[1].title, "System");
+    assert_eq!(
+      readarr_data.main_tabs.tabs[1].route,
+      ActiveReadarrBlock::System.into()
+    );
+    assert_some_eq_x!(
+      &readarr_data.main_tabs.tabs[1].contextual_help,
+      &SYSTEM_CONTEXT_CLUES
+    );
+    assert_none!(readarr_data.main_tabs.tabs[1].config);
+  }
+
+  #[test]
+  fn test_library_blocks_contains_expected_blocks() {
+    assert_eq!(LIBRARY_BLOCKS.len(), 7);
+    assert!(LIBRARY_BLOCKS.contains(&ActiveReadarrBlock::Authors));
+    assert!(LIBRARY_BLOCKS.contains(&ActiveReadarrBlock::AuthorsSortPrompt));
+    assert!(LIBRARY_BLOCKS.contains(&ActiveReadarrBlock::SearchAuthors));
+    assert!(LIBRARY_BLOCKS.contains(&ActiveReadarrBlock::SearchAuthorsError));
+    assert!(LIBRARY_BLOCKS.contains(&ActiveReadarrBlock::FilterAuthors));
+    assert!(LIBRARY_BLOCKS.contains(&ActiveReadarrBlock::FilterAuthorsError));
+    assert!(LIBRARY_BLOCKS.contains(&ActiveReadarrBlock::UpdateAllAuthorsPrompt));
+  }
+
+  #[test]
+  fn test_author_details_blocks_contains_expected_blocks() {
+    assert_eq!(AUTHOR_DETAILS_BLOCKS.len(), 5);
+    assert!(AUTHOR_DETAILS_BLOCKS.contains(&ActiveReadarrBlock::AuthorDetails));
+    assert!(AUTHOR_DETAILS_BLOCKS.contains(&ActiveReadarrBlock::AutomaticallySearchAuthorPrompt));
+    assert!(AUTHOR_DETAILS_BLOCKS.contains(&ActiveReadarrBlock::SearchBooks));
+    assert!(AUTHOR_DETAILS_BLOCKS.contains(&ActiveReadarrBlock::SearchBooksError));
+    assert!(AUTHOR_DETAILS_BLOCKS.contains(&ActiveReadarrBlock::UpdateAndScanAuthorPrompt));
+  }
+
+  #[test]
+  fn test_book_details_blocks_contents() {
+    assert_eq!(BOOK_DETAILS_BLOCKS.len(), 4);
+    assert!(BOOK_DETAILS_BLOCKS.contains(&ActiveReadarrBlock::BookDetails));
+    assert!(BOOK_DETAILS_BLOCKS.contains(&ActiveReadarrBlock::AutomaticallySearchBookPrompt));
+    assert!(BOOK_DETAILS_BLOCKS.contains(&ActiveReadarrBlock::SearchEditions));
+    assert!(BOOK_DETAILS_BLOCKS.contains(&ActiveReadarrBlock::SearchEditionsError));
+  }
+
+  #[test]
+  fn test_add_author_blocks_contents() {
+    assert_eq!(ADD_AUTHOR_BLOCKS.len(), 12);
+    assert!(ADD_AUTHOR_BLOCKS.contains(&ActiveReadarrBlock::AddAuthorAlreadyInLibrary));
+    assert!(ADD_AUTHOR_BLOCKS.contains(&ActiveReadarrBlock::AddAuthorConfirmPrompt));
+    assert!(ADD_AUTHOR_BLOCKS.contains(&ActiveReadarrBlock::AddAuthorEmptySearchResults));
+    assert!(ADD_AUTHOR_BLOCKS.contains(&ActiveReadarrBlock::AddAuthorPrompt));
+    assert!(ADD_AUTHOR_BLOCKS.contains(&ActiveReadarrBlock::AddAuthorSearchInput));
+    assert!(ADD_AUTHOR_BLOCKS.contains(&ActiveReadarrBlock::AddAuthorSearchResults));
+    assert!(ADD_AUTHOR_BLOCKS.contains(&ActiveReadarrBlock::AddAuthorSelectMetadataProfile));
+    assert!(ADD_AUTHOR_BLOCKS.contains(&ActiveReadarrBlock::AddAuthorSelectMonitor));
+    assert!(ADD_AUTHOR_BLOCKS.contains(&ActiveReadarrBlock::AddAuthorSelectMonitorNewItems));
+    assert!(ADD_AUTHOR_BLOCKS.contains(&ActiveReadarrBlock::AddAuthorSelectQualityProfile));
+    assert!(ADD_AUTHOR_BLOCKS.contains(&ActiveReadarrBlock::AddAuthorSelectRootFolder));
+    assert!(ADD_AUTHOR_BLOCKS.contains(&ActiveReadarrBlock::AddAuthorTagsInput));
+  }
+
+  #[test]
+  fn test_add_author_selection_blocks_ordering() {
+    let mut add_author_block_iter = ADD_AUTHOR_SELECTION_BLOCKS.iter();
+
+    assert_eq!(
+      add_author_block_iter.next().unwrap(),
+      &[ActiveReadarrBlock::AddAuthorSelectRootFolder]
+    );
+    assert_eq!(
+      add_author_block_iter.next().unwrap(),
+      &[ActiveReadarrBlock::AddAuthorSelectMonitor]
+    );
+    assert_eq!(
+      add_author_block_iter.next().unwrap(),
+      &[ActiveReadarrBlock::AddAuthorSelectMonitorNewItems]
+    );
+    assert_eq!(
+      add_author_block_iter.next().unwrap(),
+      &[ActiveReadarrBlock::AddAuthorSelectQualityProfile]
+    );
+    assert_eq!(
+      add_author_block_iter.next().unwrap(),
+      &[ActiveReadarrBlock::AddAuthorSelectMetadataProfile]
+    );
+    assert_eq!(
+      add_author_block_iter.next().unwrap(),
+      &[ActiveReadarrBlock::AddAuthorTagsInput]
+    );
+    assert_eq!(
+      add_author_block_iter.next().unwrap(),
+      &[ActiveReadarrBlock::AddAuthorConfirmPrompt]
+    );
+    assert_none!(add_author_block_iter.next());
+  }
+
+  #[test]
+  fn test_delete_author_blocks_contents() {
+    assert_eq!(DELETE_AUTHOR_BLOCKS.len(), 4);
+    assert!(DELETE_AUTHOR_BLOCKS.contains(&ActiveReadarrBlock::DeleteAuthorPrompt));
+    assert!(DELETE_AUTHOR_BLOCKS.contains(&ActiveReadarrBlock::DeleteAuthorConfirmPrompt));
+    assert!(DELETE_AUTHOR_BLOCKS.contains(&ActiveReadarrBlock::DeleteAuthorToggleDeleteFile));
+    assert!(DELETE_AUTHOR_BLOCKS.contains(&ActiveReadarrBlock::DeleteAuthorToggleAddListExclusion));
+  }
+
+  #[test]
+  fn test_delete_author_selection_blocks_ordering() {
+    let mut delete_author_block_iter = DELETE_AUTHOR_SELECTION_BLOCKS.iter();
+
+    assert_eq!(
+      delete_author_block_iter.next().unwrap(),
+      &[ActiveReadarrBlock::DeleteAuthorToggleDeleteFile]
+    );
+    assert_eq!(
+      delete_author_block_iter.next().unwrap(),
+      &[ActiveReadarrBlock::DeleteAuthorToggleAddListExclusion]
+    );
+    assert_eq!(
+      delete_author_block_iter.next().unwrap(),
+      &[ActiveReadarrBlock::DeleteAuthorConfirmPrompt]
+    );
+    assert_none!(delete_author_block_iter.next());
+  }
+
+  #[test]
+  fn test_edit_author_blocks() {
+    assert_eq!(EDIT_AUTHOR_BLOCKS.len(), 8);
+    assert!(EDIT_AUTHOR_BLOCKS.contains(&ActiveReadarrBlock::EditAuthorPrompt));
+    assert!(EDIT_AUTHOR_BLOCKS.contains(&ActiveReadarrBlock::EditAuthorConfirmPrompt));
+    assert!(EDIT_AUTHOR_BLOCKS.contains(&ActiveReadarrBlock::EditAuthorPathInput));
+    assert!(EDIT_AUTHOR_BLOCKS.contains(&ActiveReadarrBlock::EditAuthorSelectMetadataProfile));
+    assert!(EDIT_AUTHOR_BLOCKS.contains(&ActiveReadarrBlock::EditAuthorSelectMonitorNewItems));
+    assert!(EDIT_AUTHOR_BLOCKS.contains(&ActiveReadarrBlock::EditAuthorSelectQualityProfile));
+    assert!(EDIT_AUTHOR_BLOCKS.contains(&ActiveReadarrBlock::EditAuthorTagsInput));
+    assert!(EDIT_AUTHOR_BLOCKS.contains(&ActiveReadarrBlock::EditAuthorToggleMonitored));
+  }
+
+  #[test]
+  fn test_edit_author_selection_blocks_ordering() {
+    let mut edit_author_block_iter = EDIT_AUTHOR_SELECTION_BLOCKS.iter();
+
+    assert_eq!(
+      edit_author_block_iter.next().unwrap(),
+      &[ActiveReadarrBlock::EditAuthorToggleMonitored]
+    );
+    assert_eq!(
+      edit_author_block_iter.next().unwrap(),
+      &[ActiveReadarrBlock::EditAuthorSelectMonitorNewItems]
+    );
+    assert_eq!(
+      edit_author_block_iter.next().unwrap(),
+      &[ActiveReadarrBlock::EditAuthorSelectQualityProfile]
+    );
+    assert_eq!(
+      edit_author_block_iter.next().unwrap(),
+      &[ActiveReadarrBlock::EditAuthorSelectMetadataProfile]
+    );
+    assert_eq!(
+      edit_author_block_iter.next().unwrap(),
+      &[ActiveReadarrBlock::EditAuthorPathInput]
+    );
+    assert_eq!(
+      edit_author_block_iter.next().unwrap(),
+      &[ActiveReadarrBlock::EditAuthorTagsInput]
+    );
+    assert_eq!(
+      edit_author_block_iter.next().unwrap(),
+      &[ActiveReadarrBlock::EditAuthorConfirmPrompt]
+    );
+    assert_none!(edit_author_block_iter.next());
+  }
+
+  #[test]
+  fn test_system_details_blocks_contents() {
+    assert_eq!(SYSTEM_DETAILS_BLOCKS.len(), 5);
+    assert!(SYSTEM_DETAILS_BLOCKS.contains(&ActiveReadarrBlock::SystemLogs));
+    assert!(SYSTEM_DETAILS_BLOCKS.contains(&ActiveReadarrBlock::SystemQueuedEvents));
+    assert!(SYSTEM_DETAILS_BLOCKS.contains(&ActiveReadarrBlock::SystemTasks));
+    assert!(SYSTEM_DETAILS_BLOCKS.contains(&ActiveReadarrBlock::SystemTaskStartConfirmPrompt));
+    assert!(SYSTEM_DETAILS_BLOCKS.contains(&ActiveReadarrBlock::SystemUpdates));
+  }
+}
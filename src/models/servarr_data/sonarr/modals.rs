@@ -10,9 +10,12 @@ use crate::{
   },
   models::{
     HorizontallyScrollableText, ScrollableText, TabRoute, TabState,
-    servarr_data::modals::EditIndexerModal,
+    servarr_data::modals::{BulkEditModal, DownloadClientModal, EditIndexerModal},
     servarr_models::{Indexer, RootFolder},
-    sonarr_models::{Episode, Series, SeriesMonitor, SeriesType, SonarrHistoryItem, SonarrRelease},
+    sonarr_models::{
+      Episode, Series, SeriesMonitor, SeriesType, SonarrHistoryItem, SonarrManualImportItem,
+      SonarrRelease,
+    },
     stateful_list::StatefulList,
     stateful_table::StatefulTable,
   },
@@ -130,6 +133,20 @@ impl From<&SonarrData<'_>> for EditIndexerModal {
   }
 }
 
+impl From<&SonarrData<'_>> for DownloadClientModal {
+  fn from(sonarr_data: &SonarrData<'_>) -> DownloadClientModal {
+    let download_client = sonarr_data.download_clients.current_selection();
+    let mut download_client_modal = DownloadClientModal::from(download_client);
+    download_client_modal.id = Some(download_client.id);
+    download_client_modal.set_field_value(
+      "tags",
+      sonarr_data.tag_ids_to_display(&download_client.tags),
+    );
+
+    download_client_modal
+  }
+}
+
 #[derive(Default)]
 #[cfg_attr(test, derive(Debug))]
 pub struct EditSeriesModal {
@@ -142,6 +159,20 @@ pub struct EditSeriesModal {
   pub tags: HorizontallyScrollableText,
 }
 
+impl From<&SonarrData<'_>> for BulkEditModal {
+  fn from(sonarr_data: &SonarrData<'_>) -> BulkEditModal {
+    BulkEditModal::new(
+      sonarr_data.sorted_quality_profile_names(),
+      sonarr_data
+        .root_folders
+        .items
+        .iter()
+        .map(|root_folder| root_folder.path.clone())
+        .collect(),
+    )
+  }
+}
+
 impl From<&SonarrData<'_>> for EditSeriesModal {
   fn from(sonarr_data: &SonarrData<'_>) -> EditSeriesModal {
     let mut edit_series_modal = EditSeriesModal::default();
@@ -262,6 +293,15 @@ impl Default for EpisodeDetailsModal {
   }
 }
 
+#[derive(Default)]
+#[cfg_attr(test, derive(Debug))]
+pub struct ManualImportModal {
+  pub download_id: String,
+  pub series_id: Option<i64>,
+  pub items: StatefulTable<SonarrManualImportItem>,
+  pub series_list: StatefulList<Series>,
+}
+
 #[cfg_attr(test, derive(Debug))]
 pub struct SeasonDetailsModal {
   pub episodes: StatefulTable<Episode>,
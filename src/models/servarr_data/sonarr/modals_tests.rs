@@ -23,7 +23,7 @@ mod tests {
   use crate::models::{sonarr_models::Series, stateful_table::StatefulTable};
   use serde_json::{Number, Value};
 
-  use crate::models::servarr_data::modals::EditIndexerModal;
+  use crate::models::servarr_data::modals::{BULK_EDIT_NO_CHANGE, BulkEditModal, EditIndexerModal};
 
   #[test]
   fn test_add_series_modal_from_sonarr_data() {
@@ -72,6 +72,39 @@ mod tests {
     assert!(add_series_modal.use_season_folder);
   }
 
+  #[test]
+  fn test_bulk_edit_modal_from_sonarr_data() {
+    let mut sonarr_data = SonarrData {
+      quality_profile_map: BiMap::from_iter([
+        (2222, "HD - 1080p".to_owned()),
+        (1111, "Any".to_owned()),
+      ]),
+      ..SonarrData::default()
+    };
+    sonarr_data.root_folders.set_items(vec![RootFolder {
+      id: 1,
+      path: "/nfs".to_owned(),
+      accessible: true,
+      free_space: 219902325555200,
+      unmapped_folders: None,
+    }]);
+
+    let bulk_edit_modal = BulkEditModal::from(&sonarr_data);
+
+    assert_eq!(
+      bulk_edit_modal.quality_profile_list.items,
+      vec![
+        BULK_EDIT_NO_CHANGE.to_owned(),
+        "Any".to_owned(),
+        "HD - 1080p".to_owned()
+      ]
+    );
+    assert_eq!(
+      bulk_edit_modal.root_folder_list.items,
+      vec![BULK_EDIT_NO_CHANGE.to_owned(), "/nfs".to_owned()]
+    );
+  }
+
   #[rstest]
   fn test_edit_indexer_modal_from_sonarr_data(#[values(true, false)] seed_ratio_present: bool) {
     let mut sonarr_data = SonarrData {
@@ -82,10 +115,12 @@ mod tests {
       IndexerField {
         name: Some("baseUrl".to_owned()),
         value: Some(Value::String("https://test.com".to_owned())),
+        ..IndexerField::default()
       },
       IndexerField {
         name: Some("apiKey".to_owned()),
         value: Some(Value::String("1234".to_owned())),
+        ..IndexerField::default()
       },
     ];
 
@@ -93,6 +128,7 @@ mod tests {
       fields.push(IndexerField {
         name: Some("seedCriteria.seedRatio".to_owned()),
         value: Some(Value::from(1.2f64)),
+        ..IndexerField::default()
       });
     }
 
@@ -135,14 +171,17 @@ mod tests {
       IndexerField {
         name: Some("baseUrl".to_owned()),
         value: Some(Value::String("https://test.com".to_owned())),
+        ..IndexerField::default()
       },
       IndexerField {
         name: Some("apiKey".to_owned()),
         value: Some(Value::String("1234".to_owned())),
+        ..IndexerField::default()
       },
       IndexerField {
         name: Some("seedCriteria.seedRatio".to_owned()),
         value: None,
+        ..IndexerField::default()
       },
     ];
 
@@ -1,9 +1,11 @@
-use super::modals::{AddSeriesModal, EditSeriesModal, SeasonDetailsModal};
+use super::modals::{AddSeriesModal, EditSeriesModal, ManualImportModal, SeasonDetailsModal};
 use crate::{
   app::{
     context_clues::{
-      BLOCKLIST_CONTEXT_CLUES, DOWNLOADS_CONTEXT_CLUES, HISTORY_CONTEXT_CLUES,
-      INDEXERS_CONTEXT_CLUES, ROOT_FOLDERS_CONTEXT_CLUES, SYSTEM_CONTEXT_CLUES,
+      BLOCKLIST_CONTEXT_CLUES, CALENDAR_CONTEXT_CLUES, CUSTOM_FORMATS_CONTEXT_CLUES,
+      DOWNLOAD_CLIENTS_CONTEXT_CLUES, DOWNLOADS_CONTEXT_CLUES, HISTORY_CONTEXT_CLUES,
+      INDEXERS_CONTEXT_CLUES, QUALITY_PROFILES_CONTEXT_CLUES, ROOT_FOLDERS_CONTEXT_CLUES,
+      SYSTEM_WITH_BACKUPS_CONTEXT_CLUES, WANTED_CONTEXT_CLUES,
     },
     sonarr::sonarr_context_clues::{
       SERIES_CONTEXT_CLUES, SERIES_DETAILS_CONTEXT_CLUES, SERIES_HISTORY_CONTEXT_CLUES,
@@ -11,11 +13,18 @@ use crate::{
   },
   models::{
     BlockSelectionState, HorizontallyScrollableText, Route, ScrollableText, TabRoute, TabState,
-    servarr_data::modals::{EditIndexerModal, IndexerTestResultModalItem},
-    servarr_models::{DiskSpace, Indexer, IndexerSettings, QueueEvent, RootFolder},
+    servarr_data::modals::{
+      AddIndexerModal, BulkEditModal, CustomFormatModal, DownloadClientModal, EditIndexerModal,
+      IndexerTestResultModalItem, MediaManagementFormField, MediaManagementModal,
+      QualityProfileModal,
+    },
+    servarr_models::{
+      Backup, CustomFormat, DiskSpace, DownloadClient, Indexer, IndexerSettings, QualityProfile,
+      QueueEvent, QueueRemovalOptions, RootFolder,
+    },
     sonarr_models::{
-      AddSeriesSearchResult, BlocklistItem, DownloadRecord, Season, Series, SonarrHistoryItem,
-      SonarrTask,
+      AddSeriesSearchResult, BlocklistItem, DownloadRecord, Episode, NamingExamples, Season,
+      Series, SonarrHistoryItem, SonarrTask,
     },
     stateful_list::StatefulList,
     stateful_table::StatefulTable,
@@ -23,21 +32,27 @@ use crate::{
   network::sonarr_network::SonarrEvent,
 };
 use bimap::BiMap;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use itertools::Itertools;
 use serde_json::Number;
 use strum::EnumIter;
 #[cfg(test)]
 use {
   super::modals::EpisodeDetailsModal,
-  crate::models::sonarr_models::{SeriesMonitor, SeriesType},
+  crate::models::servarr_models::ManualImportRejection,
+  crate::models::sonarr_models::{SeriesMonitor, SeriesType, SonarrManualImportItem},
   crate::models::stateful_table::SortOption,
+  crate::network::servarr_test_utils::backup,
+  crate::network::servarr_test_utils::custom_format,
   crate::network::servarr_test_utils::diskspace,
+  crate::network::servarr_test_utils::download_client,
   crate::network::servarr_test_utils::indexer_settings,
   crate::network::servarr_test_utils::indexer_test_result,
+  crate::network::servarr_test_utils::quality_profile_details,
   crate::network::servarr_test_utils::queued_event,
   crate::network::sonarr_network::sonarr_network_test_utils::test_utils::{
-    add_series_search_result, blocklist_item, download_record, indexer, log_line, root_folder,
+    add_series_search_result, blocklist_item, download_record, indexer, log_line,
+    manual_import_item, media_management_settings, naming_examples, root_folder,
     sonarr_history_item,
   },
   crate::network::sonarr_network::sonarr_network_test_utils::test_utils::{
@@ -58,11 +73,16 @@ mod sonarr_data_tests;
 pub mod sonarr_test_utils;
 
 pub struct SonarrData<'a> {
+  pub add_indexer_modal: Option<AddIndexerModal>,
   pub add_list_exclusion: bool,
   pub add_searched_series: Option<StatefulTable<AddSeriesSearchResult>>,
   pub add_series_modal: Option<AddSeriesModal>,
   pub add_series_search: Option<HorizontallyScrollableText>,
   pub blocklist: StatefulTable<BlocklistItem>,
+  pub bulk_edit_modal: Option<BulkEditModal>,
+  pub calendar: StatefulTable<Episode>,
+  pub calendar_start: Option<NaiveDate>,
+  pub cutoff_unmet: StatefulTable<Episode>,
   pub delete_series_files: bool,
   pub downloads: StatefulTable<DownloadRecord>,
   pub disk_space_vec: Vec<DiskSpace>,
@@ -71,6 +91,17 @@ pub struct SonarrData<'a> {
   pub edit_series_modal: Option<EditSeriesModal>,
   pub history: StatefulTable<SonarrHistoryItem>,
   pub indexers: StatefulTable<Indexer>,
+  pub indexer_schema: StatefulList<Indexer>,
+  pub download_clients: StatefulTable<DownloadClient>,
+  pub download_client_schema: StatefulList<DownloadClient>,
+  pub download_client_modal: Option<DownloadClientModal>,
+  pub download_client_test_errors: Option<String>,
+  pub download_client_test_all_results: Option<StatefulTable<IndexerTestResultModalItem>>,
+  pub quality_profiles: StatefulTable<QualityProfile>,
+  pub quality_profile_modal: Option<QualityProfileModal>,
+  pub custom_formats: StatefulTable<CustomFormat>,
+  pub custom_format_modal: Option<CustomFormatModal>,
+  pub custom_format_path: Option<HorizontallyScrollableText>,
   pub indexer_settings: Option<IndexerSettings>,
   pub indexer_test_all_results: Option<StatefulTable<IndexerTestResultModalItem>>,
   pub indexer_test_errors: Option<String>,
@@ -78,8 +109,12 @@ pub struct SonarrData<'a> {
   pub logs: StatefulList<HorizontallyScrollableText>,
   pub log_details: StatefulList<HorizontallyScrollableText>,
   pub main_tabs: TabState,
+  pub manual_import_modal: Option<ManualImportModal>,
+  pub media_management_modal: Option<MediaManagementModal>,
+  pub naming_examples: Option<NamingExamples>,
   pub prompt_confirm: bool,
   pub prompt_confirm_action: Option<SonarrEvent>,
+  pub queue_removal_options: QueueRemovalOptions,
   pub quality_profile_map: BiMap<i64, String>,
   pub queued_events: StatefulTable<QueueEvent>,
   pub root_folders: StatefulTable<RootFolder>,
@@ -92,8 +127,10 @@ pub struct SonarrData<'a> {
   pub start_time: DateTime<Utc>,
   pub tags_map: BiMap<i64, String>,
   pub tasks: StatefulTable<SonarrTask>,
+  pub backups: StatefulTable<Backup>,
   pub updates: ScrollableText,
   pub version: String,
+  pub wanted_missing: StatefulTable<Episode>,
 }
 
 impl SonarrData<'_> {
@@ -143,11 +180,16 @@ impl SonarrData<'_> {
 impl<'a> Default for SonarrData<'a> {
   fn default() -> SonarrData<'a> {
     SonarrData {
+      add_indexer_modal: None,
       add_list_exclusion: false,
       add_searched_series: None,
       add_series_search: None,
       add_series_modal: None,
       blocklist: StatefulTable::default(),
+      bulk_edit_modal: None,
+      calendar: StatefulTable::default(),
+      calendar_start: None,
+      cutoff_unmet: StatefulTable::default(),
       downloads: StatefulTable::default(),
       delete_series_files: false,
       disk_space_vec: Vec::new(),
@@ -156,14 +198,29 @@ impl<'a> Default for SonarrData<'a> {
       edit_series_modal: None,
       history: StatefulTable::default(),
       indexers: StatefulTable::default(),
+      indexer_schema: StatefulList::default(),
+      download_clients: StatefulTable::default(),
+      download_client_schema: StatefulList::default(),
+      download_client_modal: None,
+      download_client_test_errors: None,
+      download_client_test_all_results: None,
+      quality_profiles: StatefulTable::default(),
+      quality_profile_modal: None,
+      custom_formats: StatefulTable::default(),
+      custom_format_modal: None,
+      custom_format_path: None,
       indexer_settings: None,
       indexer_test_errors: None,
       indexer_test_all_results: None,
       language_profiles_map: BiMap::new(),
       logs: StatefulList::default(),
       log_details: StatefulList::default(),
+      manual_import_modal: None,
+      media_management_modal: None,
+      naming_examples: None,
       prompt_confirm: false,
       prompt_confirm_action: None,
+      queue_removal_options: QueueRemovalOptions::default(),
       quality_profile_map: BiMap::new(),
       queued_events: StatefulTable::default(),
       root_folders: StatefulTable::default(),
@@ -175,8 +232,10 @@ impl<'a> Default for SonarrData<'a> {
       start_time: DateTime::default(),
       tags_map: BiMap::default(),
       tasks: StatefulTable::default(),
+      backups: StatefulTable::default(),
       updates: ScrollableText::default(),
       version: String::new(),
+      wanted_missing: StatefulTable::default(),
       main_tabs: TabState::new(vec![
         TabRoute {
           title: "Library".to_string(),
@@ -214,10 +273,40 @@ impl<'a> Default for SonarrData<'a> {
           contextual_help: Some(&INDEXERS_CONTEXT_CLUES),
           config: None,
         },
+        TabRoute {
+          title: "Download Clients".to_string(),
+          route: ActiveSonarrBlock::DownloadClients.into(),
+          contextual_help: Some(&DOWNLOAD_CLIENTS_CONTEXT_CLUES),
+          config: None,
+        },
+        TabRoute {
+          title: "Quality Profiles".to_string(),
+          route: ActiveSonarrBlock::QualityProfiles.into(),
+          contextual_help: Some(&QUALITY_PROFILES_CONTEXT_CLUES),
+          config: None,
+        },
+        TabRoute {
+          title: "Custom Formats".to_string(),
+          route: ActiveSonarrBlock::CustomFormats.into(),
+          contextual_help: Some(&CUSTOM_FORMATS_CONTEXT_CLUES),
+          config: None,
+        },
         TabRoute {
           title: "System".to_string(),
           route: ActiveSonarrBlock::System.into(),
-          contextual_help: Some(&SYSTEM_CONTEXT_CLUES),
+          contextual_help: Some(&SYSTEM_WITH_BACKUPS_CONTEXT_CLUES),
+          config: None,
+        },
+        TabRoute {
+          title: "Calendar".to_string(),
+          route: ActiveSonarrBlock::Calendar.into(),
+          contextual_help: Some(&CALENDAR_CONTEXT_CLUES),
+          config: None,
+        },
+        TabRoute {
+          title: "Wanted".to_string(),
+          route: ActiveSonarrBlock::WantedMissing.into(),
+          contextual_help: Some(&WANTED_CONTEXT_CLUES),
           config: None,
         },
       ]),
@@ -297,6 +386,11 @@ impl SonarrData<'_> {
       .language_profile_list
       .set_items(vec![language_profile_name.clone()]);
 
+    let bulk_edit_modal = BulkEditModal {
+      tags: "alex".into(),
+      ..BulkEditModal::new(vec![quality_profile_name.clone()], vec![root_folder().path])
+    };
+
     let mut indexer_test_all_results = StatefulTable::default();
     indexer_test_all_results.set_items(vec![indexer_test_result()]);
 
@@ -347,11 +441,31 @@ impl SonarrData<'_> {
     series_history.search = Some("series history search".into());
     series_history.filter = Some("series history filter".into());
 
+    let mut manual_import_modal = ManualImportModal {
+      download_id: "abc123".to_owned(),
+      ..ManualImportModal::default()
+    };
+    manual_import_modal.items.set_items(vec![
+      manual_import_item(),
+      SonarrManualImportItem {
+        id: 2,
+        path: "/nfs/downloads/Test/Sample.mkv".to_owned(),
+        relative_path: "Test/Sample.mkv".to_owned(),
+        episodes: Vec::new(),
+        rejections: vec![ManualImportRejection {
+          reason: "Sample".to_owned(),
+        }],
+        ..manual_import_item()
+      },
+    ]);
+    manual_import_modal.series_list.set_items(vec![series()]);
+
     let mut sonarr_data = SonarrData {
       add_list_exclusion: true,
       add_searched_series: Some(add_searched_series),
       add_series_modal: Some(add_series_modal),
       add_series_search: Some("something".into()),
+      bulk_edit_modal: Some(bulk_edit_modal),
       delete_series_files: true,
       disk_space_vec: vec![diskspace()],
       edit_indexer_modal: Some(edit_indexer_modal),
@@ -361,6 +475,7 @@ impl SonarrData<'_> {
       indexer_test_all_results: Some(indexer_test_all_results),
       indexer_test_errors: Some("error".to_string()),
       language_profiles_map: language_profiles_map(),
+      manual_import_modal: Some(manual_import_modal),
       quality_profile_map: quality_profile_map(),
       season_details_modal: Some(season_details_modal),
       series_history: Some(series_history),
@@ -373,12 +488,36 @@ impl SonarrData<'_> {
 
     sonarr_data.blocklist.set_items(vec![blocklist_item()]);
     sonarr_data.blocklist.sorting(vec![sort_option!(id)]);
+    sonarr_data.calendar.set_items(vec![episode()]);
+    sonarr_data.calendar_start = NaiveDate::from_ymd_opt(2024, 2, 5);
+    sonarr_data.cutoff_unmet.set_items(vec![episode()]);
     sonarr_data.downloads.set_items(vec![download_record()]);
     sonarr_data.history.set_items(vec![sonarr_history_item()]);
     sonarr_data.history.sorting(vec![sort_option!(id)]);
     sonarr_data.history.search = Some("test search".into());
     sonarr_data.history.filter = Some("test filter".into());
     sonarr_data.indexers.set_items(vec![indexer()]);
+    sonarr_data.indexer_schema.set_items(vec![indexer()]);
+    sonarr_data
+      .download_clients
+      .set_items(vec![download_client()]);
+    sonarr_data
+      .download_client_schema
+      .set_items(vec![download_client()]);
+    sonarr_data.download_client_modal = Some(DownloadClientModal::from(&download_client()));
+    sonarr_data
+      .quality_profiles
+      .set_items(vec![quality_profile_details()]);
+    sonarr_data.quality_profile_modal = Some(QualityProfileModal::from(&quality_profile_details()));
+    sonarr_data.custom_formats.set_items(vec![custom_format()]);
+    sonarr_data.custom_format_modal = Some(CustomFormatModal::from(&custom_format()));
+    sonarr_data.custom_format_path = Some("/tmp/x265.json".into());
+    sonarr_data.add_indexer_modal = Some(AddIndexerModal::from(&indexer()));
+    sonarr_data.media_management_modal = Some(MediaManagementModal::new(
+      &media_management_settings(),
+      &MEDIA_MANAGEMENT_NAMING_FIELDS,
+    ));
+    sonarr_data.naming_examples = Some(naming_examples());
     sonarr_data.queued_events.set_items(vec![queued_event()]);
     sonarr_data.root_folders.set_items(vec![root_folder()]);
     sonarr_data.seasons.set_items(vec![season()]);
@@ -390,6 +529,8 @@ impl SonarrData<'_> {
     sonarr_data.logs.set_items(vec![log_line().into()]);
     sonarr_data.log_details.set_items(vec![log_line().into()]);
     sonarr_data.tasks.set_items(vec![task()]);
+    sonarr_data.backups.set_items(vec![backup()]);
+    sonarr_data.wanted_missing.set_items(vec![episode()]);
 
     sonarr_data
   }
@@ -420,9 +561,43 @@ pub enum ActiveSonarrBlock {
   BlocklistClearAllItemsPrompt,
   BlocklistItemDetails,
   BlocklistSortPrompt,
+  BulkEditSeriesPrompt,
+  BulkEditSeriesConfirmPrompt,
+  BulkEditSeriesSelectApplyTags,
+  BulkEditSeriesSelectMonitored,
+  BulkEditSeriesSelectQualityProfile,
+  BulkEditSeriesSelectRootFolder,
+  BulkEditSeriesTagsInput,
+  BulkEditSeriesToggleMoveFiles,
+  Calendar,
+  CalendarAgenda,
+  DeleteBackupPrompt,
   DeleteBlocklistItemPrompt,
   DeleteDownloadPrompt,
+  DeleteDownloadConfirmPrompt,
+  DeleteDownloadToggleRemoveFromClient,
+  DeleteDownloadToggleBlocklist,
+  DeleteDownloadToggleSkipRedownload,
+  DeleteDownloadToggleChangeCategory,
+  GrabDownloadPrompt,
   DeleteEpisodeFilePrompt,
+  AddIndexerFieldInput,
+  AddIndexerPrompt,
+  AddIndexerSelectImplementation,
+  AddDownloadClientSelectImplementation,
+  DeleteDownloadClientPrompt,
+  DownloadClientFieldInput,
+  DownloadClientPrompt,
+  DownloadClients,
+  EditQualityProfile,
+  QualityProfiles,
+  CustomFormatDetails,
+  CustomFormatFieldInput,
+  CustomFormats,
+  DeleteCustomFormatPrompt,
+  EditCustomFormatPrompt,
+  ExportCustomFormatPrompt,
+  ImportCustomFormatPrompt,
   DeleteIndexerPrompt,
   DeleteRootFolderPrompt,
   DeleteSeriesConfirmPrompt,
@@ -477,9 +652,14 @@ pub enum ActiveSonarrBlock {
   ManualEpisodeSearch,
   ManualEpisodeSearchConfirmPrompt,
   ManualEpisodeSearchSortPrompt,
+  ManualImport,
+  ManualImportConfirmPrompt,
+  ManualImportSelectSeries,
   ManualSeasonSearch,
   ManualSeasonSearchConfirmPrompt,
   ManualSeasonSearchSortPrompt,
+  MediaManagementFieldInput,
+  MediaManagementSettingsPrompt,
   RootFolders,
   SearchEpisodes,
   SearchEpisodesError,
@@ -505,16 +685,23 @@ pub enum ActiveSonarrBlock {
   SeriesHistorySortPrompt,
   SeriesSortPrompt,
   System,
+  SystemBackups,
   SystemLogs,
   SystemQueuedEvents,
   SystemTasks,
   SystemTaskStartConfirmPrompt,
   SystemUpdates,
   TestAllIndexers,
+  TestDownloadClient,
+  TestAllDownloadClients,
   TestIndexer,
   UpdateAllSeriesPrompt,
   UpdateAndScanSeriesPrompt,
   UpdateDownloadsPrompt,
+  WantedCutoffUnmet,
+  WantedMissing,
+  WantedSearchAllPrompt,
+  WantedSearchSelectedPrompt,
 }
 
 pub static LIBRARY_BLOCKS: [ActiveSonarrBlock; 7] = [
@@ -629,10 +816,47 @@ pub static EDIT_SERIES_SELECTION_BLOCKS: &[&[ActiveSonarrBlock]] = &[
   &[ActiveSonarrBlock::EditSeriesConfirmPrompt],
 ];
 
-pub static DOWNLOADS_BLOCKS: [ActiveSonarrBlock; 3] = [
+pub static BULK_EDIT_SERIES_BLOCKS: [ActiveSonarrBlock; 8] = [
+  ActiveSonarrBlock::BulkEditSeriesPrompt,
+  ActiveSonarrBlock::BulkEditSeriesConfirmPrompt,
+  ActiveSonarrBlock::BulkEditSeriesSelectApplyTags,
+  ActiveSonarrBlock::BulkEditSeriesSelectMonitored,
+  ActiveSonarrBlock::BulkEditSeriesSelectQualityProfile,
+  ActiveSonarrBlock::BulkEditSeriesSelectRootFolder,
+  ActiveSonarrBlock::BulkEditSeriesTagsInput,
+  ActiveSonarrBlock::BulkEditSeriesToggleMoveFiles,
+];
+
+pub static BULK_EDIT_SERIES_SELECTION_BLOCKS: &[&[ActiveSonarrBlock]] = &[
+  &[ActiveSonarrBlock::BulkEditSeriesSelectMonitored],
+  &[ActiveSonarrBlock::BulkEditSeriesSelectQualityProfile],
+  &[ActiveSonarrBlock::BulkEditSeriesSelectRootFolder],
+  &[ActiveSonarrBlock::BulkEditSeriesToggleMoveFiles],
+  &[ActiveSonarrBlock::BulkEditSeriesTagsInput],
+  &[ActiveSonarrBlock::BulkEditSeriesSelectApplyTags],
+  &[ActiveSonarrBlock::BulkEditSeriesConfirmPrompt],
+];
+
+pub static DOWNLOADS_BLOCKS: [ActiveSonarrBlock; 12] = [
   ActiveSonarrBlock::Downloads,
   ActiveSonarrBlock::DeleteDownloadPrompt,
+  ActiveSonarrBlock::DeleteDownloadConfirmPrompt,
+  ActiveSonarrBlock::DeleteDownloadToggleRemoveFromClient,
+  ActiveSonarrBlock::DeleteDownloadToggleBlocklist,
+  ActiveSonarrBlock::DeleteDownloadToggleSkipRedownload,
+  ActiveSonarrBlock::DeleteDownloadToggleChangeCategory,
+  ActiveSonarrBlock::GrabDownloadPrompt,
   ActiveSonarrBlock::UpdateDownloadsPrompt,
+  ActiveSonarrBlock::ManualImport,
+  ActiveSonarrBlock::ManualImportConfirmPrompt,
+  ActiveSonarrBlock::ManualImportSelectSeries,
+];
+pub const DELETE_DOWNLOAD_SELECTION_BLOCKS: &[&[ActiveSonarrBlock]] = &[
+  &[ActiveSonarrBlock::DeleteDownloadToggleRemoveFromClient],
+  &[ActiveSonarrBlock::DeleteDownloadToggleBlocklist],
+  &[ActiveSonarrBlock::DeleteDownloadToggleSkipRedownload],
+  &[ActiveSonarrBlock::DeleteDownloadToggleChangeCategory],
+  &[ActiveSonarrBlock::DeleteDownloadConfirmPrompt],
 ];
 
 pub static DELETE_SERIES_BLOCKS: [ActiveSonarrBlock; 4] = [
@@ -739,19 +963,112 @@ pub static HISTORY_BLOCKS: [ActiveSonarrBlock; 7] = [
   ActiveSonarrBlock::SearchHistoryError,
 ];
 
+pub static CALENDAR_BLOCKS: [ActiveSonarrBlock; 2] = [
+  ActiveSonarrBlock::Calendar,
+  ActiveSonarrBlock::CalendarAgenda,
+];
+
+pub static WANTED_BLOCKS: [ActiveSonarrBlock; 4] = [
+  ActiveSonarrBlock::WantedMissing,
+  ActiveSonarrBlock::WantedCutoffUnmet,
+  ActiveSonarrBlock::WantedSearchAllPrompt,
+  ActiveSonarrBlock::WantedSearchSelectedPrompt,
+];
+
 pub static ROOT_FOLDERS_BLOCKS: [ActiveSonarrBlock; 3] = [
   ActiveSonarrBlock::RootFolders,
   ActiveSonarrBlock::AddRootFolderPrompt,
   ActiveSonarrBlock::DeleteRootFolderPrompt,
 ];
 
+pub static MEDIA_MANAGEMENT_SETTINGS_BLOCKS: [ActiveSonarrBlock; 2] = [
+  ActiveSonarrBlock::MediaManagementSettingsPrompt,
+  ActiveSonarrBlock::MediaManagementFieldInput,
+];
+
+pub const MEDIA_MANAGEMENT_NAMING_FIELDS: [MediaManagementFormField; 8] = [
+  (
+    "naming.renameEpisodes",
+    "Rename Episodes",
+    Some("The existing file name is used when renaming is disabled"),
+  ),
+  (
+    "naming.replaceIllegalCharacters",
+    "Replace Illegal Characters",
+    Some("Replace illegal characters instead of removing them"),
+  ),
+  (
+    "naming.standardEpisodeFormat",
+    "Standard Episode Format",
+    Some("e.g. {Series Title} - S{season:00}E{episode:00} - {Episode Title} {Quality Full}"),
+  ),
+  (
+    "naming.dailyEpisodeFormat",
+    "Daily Episode Format",
+    Some("e.g. {Series Title} - {Air-Date} - {Episode Title} {Quality Full}"),
+  ),
+  (
+    "naming.animeEpisodeFormat",
+    "Anime Episode Format",
+    Some("e.g. {Series Title} - S{season:00}E{episode:00} - {Episode Title} {Quality Full}"),
+  ),
+  (
+    "naming.seriesFolderFormat",
+    "Series Folder Format",
+    Some("e.g. {Series Title}"),
+  ),
+  (
+    "naming.seasonFolderFormat",
+    "Season Folder Format",
+    Some("e.g. Season {season}"),
+  ),
+  (
+    "naming.specialsFolderFormat",
+    "Specials Folder Format",
+    Some("e.g. Specials"),
+  ),
+];
+
+pub static ADD_INDEXER_BLOCKS: [ActiveSonarrBlock; 3] = [
+  ActiveSonarrBlock::AddIndexerFieldInput,
+  ActiveSonarrBlock::AddIndexerPrompt,
+  ActiveSonarrBlock::AddIndexerSelectImplementation,
+];
+
 pub static INDEXERS_BLOCKS: [ActiveSonarrBlock; 3] = [
   ActiveSonarrBlock::DeleteIndexerPrompt,
   ActiveSonarrBlock::Indexers,
   ActiveSonarrBlock::TestIndexer,
 ];
+pub static DOWNLOAD_CLIENTS_BLOCKS: [ActiveSonarrBlock; 3] = [
+  ActiveSonarrBlock::DeleteDownloadClientPrompt,
+  ActiveSonarrBlock::DownloadClients,
+  ActiveSonarrBlock::TestDownloadClient,
+];
+pub static DOWNLOAD_CLIENT_FORM_BLOCKS: [ActiveSonarrBlock; 3] = [
+  ActiveSonarrBlock::AddDownloadClientSelectImplementation,
+  ActiveSonarrBlock::DownloadClientFieldInput,
+  ActiveSonarrBlock::DownloadClientPrompt,
+];
+pub static QUALITY_PROFILES_BLOCKS: [ActiveSonarrBlock; 2] = [
+  ActiveSonarrBlock::QualityProfiles,
+  ActiveSonarrBlock::EditQualityProfile,
+];
+pub static CUSTOM_FORMATS_BLOCKS: [ActiveSonarrBlock; 5] = [
+  ActiveSonarrBlock::CustomFormats,
+  ActiveSonarrBlock::CustomFormatDetails,
+  ActiveSonarrBlock::DeleteCustomFormatPrompt,
+  ActiveSonarrBlock::ExportCustomFormatPrompt,
+  ActiveSonarrBlock::ImportCustomFormatPrompt,
+];
+pub static CUSTOM_FORMAT_FORM_BLOCKS: [ActiveSonarrBlock; 2] = [
+  ActiveSonarrBlock::EditCustomFormatPrompt,
+  ActiveSonarrBlock::CustomFormatFieldInput,
+];
 
-pub static SYSTEM_DETAILS_BLOCKS: [ActiveSonarrBlock; 5] = [
+pub static SYSTEM_DETAILS_BLOCKS: [ActiveSonarrBlock; 7] = [
+  ActiveSonarrBlock::SystemBackups,
+  ActiveSonarrBlock::DeleteBackupPrompt,
   ActiveSonarrBlock::SystemLogs,
   ActiveSonarrBlock::SystemQueuedEvents,
   ActiveSonarrBlock::SystemTasks,
@@ -3,13 +3,16 @@ mod tests {
   mod sonarr_data_tests {
     use crate::app::context_clues::HISTORY_CONTEXT_CLUES;
     use crate::app::sonarr::sonarr_context_clues::SERIES_HISTORY_CONTEXT_CLUES;
+    use crate::models::servarr_models::QueueRemovalOptions;
     use crate::models::sonarr_models::{Season, SonarrHistoryItem};
     use crate::models::stateful_table::StatefulTable;
     use crate::{
       app::{
         context_clues::{
-          BLOCKLIST_CONTEXT_CLUES, DOWNLOADS_CONTEXT_CLUES, INDEXERS_CONTEXT_CLUES,
-          ROOT_FOLDERS_CONTEXT_CLUES, SYSTEM_CONTEXT_CLUES,
+          BLOCKLIST_CONTEXT_CLUES, CALENDAR_CONTEXT_CLUES, CUSTOM_FORMATS_CONTEXT_CLUES,
+          DOWNLOAD_CLIENTS_CONTEXT_CLUES, DOWNLOADS_CONTEXT_CLUES, INDEXERS_CONTEXT_CLUES,
+          QUALITY_PROFILES_CONTEXT_CLUES, ROOT_FOLDERS_CONTEXT_CLUES,
+          SYSTEM_WITH_BACKUPS_CONTEXT_CLUES, WANTED_CONTEXT_CLUES,
         },
         sonarr::sonarr_context_clues::{SERIES_CONTEXT_CLUES, SERIES_DETAILS_CONTEXT_CLUES},
       },
@@ -145,8 +148,13 @@ mod tests {
       assert!(!sonarr_data.add_list_exclusion);
       assert_none!(sonarr_data.add_searched_series);
       assert_none!(sonarr_data.add_series_search);
+      assert_none!(sonarr_data.add_indexer_modal);
       assert_none!(sonarr_data.add_series_modal);
       assert_is_empty!(sonarr_data.blocklist);
+      assert_none!(sonarr_data.bulk_edit_modal);
+      assert_is_empty!(sonarr_data.calendar);
+      assert_none!(sonarr_data.calendar_start);
+      assert_is_empty!(sonarr_data.cutoff_unmet);
       assert!(!sonarr_data.delete_series_files);
       assert_is_empty!(sonarr_data.downloads);
       assert_is_empty!(sonarr_data.disk_space_vec);
@@ -155,15 +163,32 @@ mod tests {
       assert_none!(sonarr_data.edit_series_modal);
       assert_is_empty!(sonarr_data.history);
       assert_is_empty!(sonarr_data.indexers);
+      assert_is_empty!(sonarr_data.download_clients);
+      assert_is_empty!(sonarr_data.download_client_schema);
       assert_none!(sonarr_data.indexer_settings);
       assert_none!(sonarr_data.indexer_test_errors);
       assert_none!(sonarr_data.indexer_test_all_results);
+      assert_none!(sonarr_data.download_client_modal);
+      assert_none!(sonarr_data.download_client_test_errors);
+      assert_none!(sonarr_data.download_client_test_all_results);
       assert_is_empty!(sonarr_data.language_profiles_map);
       assert_is_empty!(sonarr_data.logs);
       assert_is_empty!(sonarr_data.log_details);
+      assert_none!(sonarr_data.manual_import_modal);
+      assert_none!(sonarr_data.media_management_modal);
+      assert_none!(sonarr_data.naming_examples);
       assert!(!sonarr_data.prompt_confirm);
+      assert_eq!(
+        sonarr_data.queue_removal_options,
+        QueueRemovalOptions::default()
+      );
       assert_none!(sonarr_data.prompt_confirm_action);
       assert_is_empty!(sonarr_data.quality_profile_map);
+      assert_is_empty!(sonarr_data.quality_profiles);
+      assert_none!(sonarr_data.quality_profile_modal);
+      assert_is_empty!(sonarr_data.custom_formats);
+      assert_none!(sonarr_data.custom_format_modal);
+      assert_none!(sonarr_data.custom_format_path);
       assert_is_empty!(sonarr_data.queued_events);
       assert_is_empty!(sonarr_data.root_folders);
       assert_is_empty!(sonarr_data.seasons);
@@ -174,10 +199,12 @@ mod tests {
       assert_eq!(sonarr_data.start_time, <DateTime<Utc>>::default());
       assert_is_empty!(sonarr_data.tags_map);
       assert_is_empty!(sonarr_data.tasks);
+      assert_is_empty!(sonarr_data.backups);
       assert_is_empty!(sonarr_data.updates);
+      assert_is_empty!(sonarr_data.wanted_missing);
       assert_is_empty!(sonarr_data.version);
 
-      assert_eq!(sonarr_data.main_tabs.tabs.len(), 7);
+      assert_eq!(sonarr_data.main_tabs.tabs.len(), 12);
 
       assert_str_eq!(sonarr_data.main_tabs.tabs[0].title, "Library");
       assert_eq!(
@@ -245,17 +272,72 @@ mod tests {
       );
       assert_none!(sonarr_data.main_tabs.tabs[5].config);
 
-      assert_str_eq!(sonarr_data.main_tabs.tabs[6].title, "System");
+      assert_str_eq!(sonarr_data.main_tabs.tabs[6].title, "Download Clients");
       assert_eq!(
         sonarr_data.main_tabs.tabs[6].route,
-        ActiveSonarrBlock::System.into()
+        ActiveSonarrBlock::DownloadClients.into()
       );
       assert_some_eq_x!(
         &sonarr_data.main_tabs.tabs[6].contextual_help,
-        &SYSTEM_CONTEXT_CLUES
+        &DOWNLOAD_CLIENTS_CONTEXT_CLUES
       );
       assert_none!(sonarr_data.main_tabs.tabs[6].config);
 
+      assert_str_eq!(sonarr_data.main_tabs.tabs[7].title, "Quality Profiles");
+      assert_eq!(
+        sonarr_data.main_tabs.tabs[7].route,
+        ActiveSonarrBlock::QualityProfiles.into()
+      );
+      assert_some_eq_x!(
+        &sonarr_data.main_tabs.tabs[7].contextual_help,
+        &QUALITY_PROFILES_CONTEXT_CLUES
+      );
+      assert_none!(sonarr_data.main_tabs.tabs[7].config);
+
+      assert_str_eq!(sonarr_data.main_tabs.tabs[8].title, "Custom Formats");
+      assert_eq!(
+        sonarr_data.main_tabs.tabs[8].route,
+        ActiveSonarrBlock::CustomFormats.into()
+      );
+      assert_some_eq_x!(
+        &sonarr_data.main_tabs.tabs[8].contextual_help,
+        &CUSTOM_FORMATS_CONTEXT_CLUES
+      );
+      assert_none!(sonarr_data.main_tabs.tabs[8].config);
+
+      assert_str_eq!(sonarr_data.main_tabs.tabs[9].title, "System");
+      assert_eq!(
+        sonarr_data.main_tabs.tabs[9].route,
+        ActiveSonarrBlock::System.into()
+      );
+      assert_some_eq_x!(
+        &sonarr_data.main_tabs.tabs[9].contextual_help,
+        &SYSTEM_WITH_BACKUPS_CONTEXT_CLUES
+      );
+      assert_none!(sonarr_data.main_tabs.tabs[9].config);
+
+      assert_str_eq!(sonarr_data.main_tabs.tabs[10].title, "Calendar");
+      assert_eq!(
+        sonarr_data.main_tabs.tabs[10].route,
+        ActiveSonarrBlock::Calendar.into()
+      );
+      assert_some_eq_x!(
+        &sonarr_data.main_tabs.tabs[10].contextual_help,
+        &CALENDAR_CONTEXT_CLUES
+      );
+      assert_none!(sonarr_data.main_tabs.tabs[10].config);
+
+      assert_str_eq!(sonarr_data.main_tabs.tabs[11].title, "Wanted");
+      assert_eq!(
+        sonarr_data.main_tabs.tabs[11].route,
+        ActiveSonarrBlock::WantedMissing.into()
+      );
+      assert_some_eq_x!(
+        &sonarr_data.main_tabs.tabs[11].contextual_help,
+        &WANTED_CONTEXT_CLUES
+      );
+      assert_none!(sonarr_data.main_tabs.tabs[11].config);
+
       assert_eq!(sonarr_data.series_info_tabs.tabs.len(), 2);
 
       assert_str_eq!(sonarr_data.series_info_tabs.tabs[0].title, "Seasons");
@@ -284,12 +366,16 @@ mod tests {
 
   mod active_sonarr_block_tests {
     use crate::models::servarr_data::sonarr::sonarr_data::{
-      ADD_SERIES_BLOCKS, ADD_SERIES_SELECTION_BLOCKS, ActiveSonarrBlock, BLOCKLIST_BLOCKS,
-      DELETE_SERIES_BLOCKS, DELETE_SERIES_SELECTION_BLOCKS, DOWNLOADS_BLOCKS, EDIT_INDEXER_BLOCKS,
+      ADD_INDEXER_BLOCKS, ADD_SERIES_BLOCKS, ADD_SERIES_SELECTION_BLOCKS, ActiveSonarrBlock,
+      BLOCKLIST_BLOCKS, BULK_EDIT_SERIES_BLOCKS, BULK_EDIT_SERIES_SELECTION_BLOCKS,
+      CALENDAR_BLOCKS, CUSTOM_FORMAT_FORM_BLOCKS, CUSTOM_FORMATS_BLOCKS,
+      DELETE_DOWNLOAD_SELECTION_BLOCKS, DELETE_SERIES_BLOCKS, DELETE_SERIES_SELECTION_BLOCKS,
+      DOWNLOAD_CLIENT_FORM_BLOCKS, DOWNLOAD_CLIENTS_BLOCKS, DOWNLOADS_BLOCKS, EDIT_INDEXER_BLOCKS,
       EDIT_INDEXER_NZB_SELECTION_BLOCKS, EDIT_INDEXER_TORRENT_SELECTION_BLOCKS, EDIT_SERIES_BLOCKS,
       EDIT_SERIES_SELECTION_BLOCKS, EPISODE_DETAILS_BLOCKS, HISTORY_BLOCKS,
       INDEXER_SETTINGS_BLOCKS, INDEXER_SETTINGS_SELECTION_BLOCKS, INDEXERS_BLOCKS, LIBRARY_BLOCKS,
-      ROOT_FOLDERS_BLOCKS, SEASON_DETAILS_BLOCKS, SERIES_DETAILS_BLOCKS, SYSTEM_DETAILS_BLOCKS,
+      MEDIA_MANAGEMENT_SETTINGS_BLOCKS, QUALITY_PROFILES_BLOCKS, ROOT_FOLDERS_BLOCKS,
+      SEASON_DETAILS_BLOCKS, SERIES_DETAILS_BLOCKS, SYSTEM_DETAILS_BLOCKS, WANTED_BLOCKS,
     };
 
     #[test]
@@ -424,12 +510,98 @@ mod tests {
       assert_eq!(edit_series_block_iter.next(), None);
     }
 
+    #[test]
+    fn test_bulk_edit_series_blocks_contents() {
+      assert_eq!(BULK_EDIT_SERIES_BLOCKS.len(), 8);
+      assert!(BULK_EDIT_SERIES_BLOCKS.contains(&ActiveSonarrBlock::BulkEditSeriesPrompt));
+      assert!(BULK_EDIT_SERIES_BLOCKS.contains(&ActiveSonarrBlock::BulkEditSeriesConfirmPrompt));
+      assert!(BULK_EDIT_SERIES_BLOCKS.contains(&ActiveSonarrBlock::BulkEditSeriesSelectApplyTags));
+      assert!(BULK_EDIT_SERIES_BLOCKS.contains(&ActiveSonarrBlock::BulkEditSeriesSelectMonitored));
+      assert!(
+        BULK_EDIT_SERIES_BLOCKS.contains(&ActiveSonarrBlock::BulkEditSeriesSelectQualityProfile)
+      );
+      assert!(BULK_EDIT_SERIES_BLOCKS.contains(&ActiveSonarrBlock::BulkEditSeriesSelectRootFolder));
+      assert!(BULK_EDIT_SERIES_BLOCKS.contains(&ActiveSonarrBlock::BulkEditSeriesTagsInput));
+      assert!(BULK_EDIT_SERIES_BLOCKS.contains(&ActiveSonarrBlock::BulkEditSeriesToggleMoveFiles));
+    }
+
+    #[test]
+    fn test_bulk_edit_series_selection_blocks_ordering() {
+      let mut bulk_edit_series_block_iter = BULK_EDIT_SERIES_SELECTION_BLOCKS.iter();
+
+      assert_eq!(
+        bulk_edit_series_block_iter.next().unwrap(),
+        &[ActiveSonarrBlock::BulkEditSeriesSelectMonitored]
+      );
+      assert_eq!(
+        bulk_edit_series_block_iter.next().unwrap(),
+        &[ActiveSonarrBlock::BulkEditSeriesSelectQualityProfile]
+      );
+      assert_eq!(
+        bulk_edit_series_block_iter.next().unwrap(),
+        &[ActiveSonarrBlock::BulkEditSeriesSelectRootFolder]
+      );
+      assert_eq!(
+        bulk_edit_series_block_iter.next().unwrap(),
+        &[ActiveSonarrBlock::BulkEditSeriesToggleMoveFiles]
+      );
+      assert_eq!(
+        bulk_edit_series_block_iter.next().unwrap(),
+        &[ActiveSonarrBlock::BulkEditSeriesTagsInput]
+      );
+      assert_eq!(
+        bulk_edit_series_block_iter.next().unwrap(),
+        &[ActiveSonarrBlock::BulkEditSeriesSelectApplyTags]
+      );
+      assert_eq!(
+        bulk_edit_series_block_iter.next().unwrap(),
+        &[ActiveSonarrBlock::BulkEditSeriesConfirmPrompt]
+      );
+      assert_eq!(bulk_edit_series_block_iter.next(), None);
+    }
+
     #[test]
     fn test_downloads_blocks_contents() {
-      assert_eq!(DOWNLOADS_BLOCKS.len(), 3);
+      assert_eq!(DOWNLOADS_BLOCKS.len(), 12);
       assert!(DOWNLOADS_BLOCKS.contains(&ActiveSonarrBlock::Downloads));
       assert!(DOWNLOADS_BLOCKS.contains(&ActiveSonarrBlock::DeleteDownloadPrompt));
+      assert!(DOWNLOADS_BLOCKS.contains(&ActiveSonarrBlock::DeleteDownloadConfirmPrompt));
+      assert!(DOWNLOADS_BLOCKS.contains(&ActiveSonarrBlock::DeleteDownloadToggleRemoveFromClient));
+      assert!(DOWNLOADS_BLOCKS.contains(&ActiveSonarrBlock::DeleteDownloadToggleBlocklist));
+      assert!(DOWNLOADS_BLOCKS.contains(&ActiveSonarrBlock::DeleteDownloadToggleSkipRedownload));
+      assert!(DOWNLOADS_BLOCKS.contains(&ActiveSonarrBlock::DeleteDownloadToggleChangeCategory));
+      assert!(DOWNLOADS_BLOCKS.contains(&ActiveSonarrBlock::GrabDownloadPrompt));
       assert!(DOWNLOADS_BLOCKS.contains(&ActiveSonarrBlock::UpdateDownloadsPrompt));
+      assert!(DOWNLOADS_BLOCKS.contains(&ActiveSonarrBlock::ManualImport));
+      assert!(DOWNLOADS_BLOCKS.contains(&ActiveSonarrBlock::ManualImportConfirmPrompt));
+      assert!(DOWNLOADS_BLOCKS.contains(&ActiveSonarrBlock::ManualImportSelectSeries));
+    }
+
+    #[test]
+    fn test_delete_download_selection_blocks_ordering() {
+      let mut delete_download_block_iter = DELETE_DOWNLOAD_SELECTION_BLOCKS.iter();
+
+      assert_eq!(
+        delete_download_block_iter.next().unwrap(),
+        &[ActiveSonarrBlock::DeleteDownloadToggleRemoveFromClient]
+      );
+      assert_eq!(
+        delete_download_block_iter.next().unwrap(),
+        &[ActiveSonarrBlock::DeleteDownloadToggleBlocklist]
+      );
+      assert_eq!(
+        delete_download_block_iter.next().unwrap(),
+        &[ActiveSonarrBlock::DeleteDownloadToggleSkipRedownload]
+      );
+      assert_eq!(
+        delete_download_block_iter.next().unwrap(),
+        &[ActiveSonarrBlock::DeleteDownloadToggleChangeCategory]
+      );
+      assert_eq!(
+        delete_download_block_iter.next().unwrap(),
+        &[ActiveSonarrBlock::DeleteDownloadConfirmPrompt]
+      );
+      assert_eq!(delete_download_block_iter.next(), None);
     }
 
     #[test]
@@ -462,6 +634,57 @@ mod tests {
       assert_eq!(delete_series_block_iter.next(), None);
     }
 
+    #[test]
+    fn test_add_indexer_blocks_contents() {
+      assert_eq!(ADD_INDEXER_BLOCKS.len(), 3);
+      assert!(ADD_INDEXER_BLOCKS.contains(&ActiveSonarrBlock::AddIndexerFieldInput));
+      assert!(ADD_INDEXER_BLOCKS.contains(&ActiveSonarrBlock::AddIndexerPrompt));
+      assert!(ADD_INDEXER_BLOCKS.contains(&ActiveSonarrBlock::AddIndexerSelectImplementation));
+    }
+
+    #[test]
+    fn test_download_clients_blocks_contents() {
+      assert_eq!(DOWNLOAD_CLIENTS_BLOCKS.len(), 3);
+      assert!(DOWNLOAD_CLIENTS_BLOCKS.contains(&ActiveSonarrBlock::DeleteDownloadClientPrompt));
+      assert!(DOWNLOAD_CLIENTS_BLOCKS.contains(&ActiveSonarrBlock::DownloadClients));
+      assert!(DOWNLOAD_CLIENTS_BLOCKS.contains(&ActiveSonarrBlock::TestDownloadClient));
+    }
+
+    #[test]
+    fn test_quality_profiles_blocks_contents() {
+      assert_eq!(QUALITY_PROFILES_BLOCKS.len(), 2);
+      assert!(QUALITY_PROFILES_BLOCKS.contains(&ActiveSonarrBlock::EditQualityProfile));
+      assert!(QUALITY_PROFILES_BLOCKS.contains(&ActiveSonarrBlock::QualityProfiles));
+    }
+
+    #[test]
+    fn test_custom_formats_blocks_contents() {
+      assert_eq!(CUSTOM_FORMATS_BLOCKS.len(), 5);
+      assert!(CUSTOM_FORMATS_BLOCKS.contains(&ActiveSonarrBlock::CustomFormats));
+      assert!(CUSTOM_FORMATS_BLOCKS.contains(&ActiveSonarrBlock::CustomFormatDetails));
+      assert!(CUSTOM_FORMATS_BLOCKS.contains(&ActiveSonarrBlock::DeleteCustomFormatPrompt));
+      assert!(CUSTOM_FORMATS_BLOCKS.contains(&ActiveSonarrBlock::ExportCustomFormatPrompt));
+      assert!(CUSTOM_FORMATS_BLOCKS.contains(&ActiveSonarrBlock::ImportCustomFormatPrompt));
+    }
+
+    #[test]
+    fn test_custom_format_form_blocks_contents() {
+      assert_eq!(CUSTOM_FORMAT_FORM_BLOCKS.len(), 2);
+      assert!(CUSTOM_FORMAT_FORM_BLOCKS.contains(&ActiveSonarrBlock::EditCustomFormatPrompt));
+      assert!(CUSTOM_FORMAT_FORM_BLOCKS.contains(&ActiveSonarrBlock::CustomFormatFieldInput));
+    }
+
+    #[test]
+    fn test_download_client_form_blocks_contents() {
+      assert_eq!(DOWNLOAD_CLIENT_FORM_BLOCKS.len(), 3);
+      assert!(
+        DOWNLOAD_CLIENT_FORM_BLOCKS
+          .contains(&ActiveSonarrBlock::AddDownloadClientSelectImplementation)
+      );
+      assert!(DOWNLOAD_CLIENT_FORM_BLOCKS.contains(&ActiveSonarrBlock::DownloadClientFieldInput));
+      assert!(DOWNLOAD_CLIENT_FORM_BLOCKS.contains(&ActiveSonarrBlock::DownloadClientPrompt));
+    }
+
     #[test]
     fn test_edit_indexer_blocks_contents() {
       assert_eq!(EDIT_INDEXER_BLOCKS.len(), 11);
@@ -628,6 +851,22 @@ mod tests {
       assert!(HISTORY_BLOCKS.contains(&ActiveSonarrBlock::SearchHistoryError));
     }
 
+    #[test]
+    fn test_calendar_blocks_contents() {
+      assert_eq!(CALENDAR_BLOCKS.len(), 2);
+      assert!(CALENDAR_BLOCKS.contains(&ActiveSonarrBlock::Calendar));
+      assert!(CALENDAR_BLOCKS.contains(&ActiveSonarrBlock::CalendarAgenda));
+    }
+
+    #[test]
+    fn test_wanted_blocks_contents() {
+      assert_eq!(WANTED_BLOCKS.len(), 4);
+      assert!(WANTED_BLOCKS.contains(&ActiveSonarrBlock::WantedMissing));
+      assert!(WANTED_BLOCKS.contains(&ActiveSonarrBlock::WantedCutoffUnmet));
+      assert!(WANTED_BLOCKS.contains(&ActiveSonarrBlock::WantedSearchAllPrompt));
+      assert!(WANTED_BLOCKS.contains(&ActiveSonarrBlock::WantedSearchSelectedPrompt));
+    }
+
     #[test]
     fn test_root_folders_blocks_contents() {
       assert_eq!(ROOT_FOLDERS_BLOCKS.len(), 3);
@@ -636,6 +875,18 @@ mod tests {
       assert!(ROOT_FOLDERS_BLOCKS.contains(&ActiveSonarrBlock::DeleteRootFolderPrompt));
     }
 
+    #[test]
+    fn test_media_management_settings_blocks_contents() {
+      assert_eq!(MEDIA_MANAGEMENT_SETTINGS_BLOCKS.len(), 2);
+      assert!(
+        MEDIA_MANAGEMENT_SETTINGS_BLOCKS
+          .contains(&ActiveSonarrBlock::MediaManagementSettingsPrompt)
+      );
+      assert!(
+        MEDIA_MANAGEMENT_SETTINGS_BLOCKS.contains(&ActiveSonarrBlock::MediaManagementFieldInput)
+      );
+    }
+
     #[test]
     fn test_indexers_blocks_contents() {
       assert_eq!(INDEXERS_BLOCKS.len(), 3);
@@ -646,7 +897,9 @@ mod tests {
 
     #[test]
     fn test_system_details_blocks_contents() {
-      assert_eq!(SYSTEM_DETAILS_BLOCKS.len(), 5);
+      assert_eq!(SYSTEM_DETAILS_BLOCKS.len(), 7);
+      assert!(SYSTEM_DETAILS_BLOCKS.contains(&ActiveSonarrBlock::SystemBackups));
+      assert!(SYSTEM_DETAILS_BLOCKS.contains(&ActiveSonarrBlock::DeleteBackupPrompt));
       assert!(SYSTEM_DETAILS_BLOCKS.contains(&ActiveSonarrBlock::SystemLogs));
       assert!(SYSTEM_DETAILS_BLOCKS.contains(&ActiveSonarrBlock::SystemQueuedEvents));
       assert!(SYSTEM_DETAILS_BLOCKS.contains(&ActiveSonarrBlock::SystemTasks));
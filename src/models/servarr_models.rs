@@ -1,9 +1,12 @@
 use std::fmt::{Display, Formatter, Result};
+use std::path::PathBuf;
 
 use chrono::{DateTime, Utc};
 use clap::ValueEnum;
+use enum_display_style_derive::EnumDisplayStyle;
 use serde::{Deserialize, Serialize};
-use serde_json::{Number, Value};
+use serde_json::{Number, Value, json};
+use strum_macros::{Display, EnumIter};
 
 use super::HorizontallyScrollableText;
 
@@ -11,11 +14,64 @@ use super::HorizontallyScrollableText;
 #[path = "servarr_models_tests.rs"]
 mod servarr_models_tests;
 
+#[derive(Clone, Serialize, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct AddDownloadClientParams {
+  pub implementation: String,
+  pub name: String,
+  pub enable: bool,
+  pub priority: i64,
+  pub remove_completed_downloads: bool,
+  pub remove_failed_downloads: bool,
+  pub tags: Vec<i64>,
+  #[serde(skip_serializing, skip_deserializing)]
+  pub tag_input_string: Option<String>,
+  pub fields: Vec<(String, String)>,
+}
+
+impl Default for AddDownloadClientParams {
+  fn default() -> Self {
+    Self {
+      implementation: String::new(),
+      name: String::new(),
+      enable: true,
+      priority: 1,
+      remove_completed_downloads: true,
+      remove_failed_downloads: true,
+      tags: Vec::new(),
+      tag_input_string: None,
+      fields: Vec::new(),
+    }
+  }
+}
+
 #[derive(Default, Serialize, Debug, Clone, PartialEq, Eq)]
 pub struct AddRootFolderBody {
   pub path: String,
 }
 
+#[derive(
+  Serialize,
+  Deserialize,
+  Default,
+  PartialEq,
+  Eq,
+  Clone,
+  Copy,
+  Debug,
+  EnumIter,
+  Display,
+  EnumDisplayStyle,
+)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum ApplyTags {
+  #[default]
+  Add,
+  Remove,
+  Replace,
+}
+
 #[derive(Serialize, Deserialize, Default, PartialEq, Eq, Clone, Copy, Debug, ValueEnum)]
 #[serde(rename_all = "camelCase")]
 pub enum AuthenticationMethod {
@@ -54,6 +110,66 @@ impl Display for AuthenticationRequired {
   }
 }
 
+#[derive(Default, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Backup {
+  #[serde(deserialize_with = "super::from_i64")]
+  pub id: i64,
+  pub name: String,
+  pub path: String,
+  #[serde(rename = "type")]
+  pub backup_type: String,
+  #[serde(deserialize_with = "super::from_i64")]
+  pub size: i64,
+  pub time: DateTime<Utc>,
+}
+
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+pub struct DownloadBackupParams {
+  pub backup_path: String,
+  pub destination: PathBuf,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct QueueRemovalOptions {
+  pub remove_from_client: bool,
+  pub blocklist: bool,
+  pub skip_redownload: bool,
+  pub change_category: bool,
+}
+
+impl Default for QueueRemovalOptions {
+  fn default() -> Self {
+    QueueRemovalOptions {
+      remove_from_client: true,
+      blocklist: false,
+      skip_redownload: false,
+      change_category: false,
+    }
+  }
+}
+
+impl QueueRemovalOptions {
+  pub fn query_params(&self) -> String {
+    format!(
+      "removeFromClient={}&blocklist={}&skipRedownload={}&changeCategory={}",
+      self.remove_from_client, self.blocklist, self.skip_redownload, self.change_category
+    )
+  }
+}
+
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+pub struct DeleteDownloadParams {
+  pub id: i64,
+  pub removal_options: QueueRemovalOptions,
+}
+
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+pub struct BulkDeleteDownloadsParams {
+  pub ids: Vec<i64>,
+  pub removal_options: QueueRemovalOptions,
+}
+
 #[derive(Serialize, Deserialize, Default, PartialEq, Eq, Clone, Copy, Debug, ValueEnum)]
 #[serde(rename_all = "camelCase")]
 pub enum CertificateValidation {
@@ -74,6 +190,25 @@ impl Display for CertificateValidation {
   }
 }
 
+#[derive(Default, PartialEq, Eq, Clone, Copy, Debug, EnumIter, EnumDisplayStyle)]
+pub enum BulkEditMonitoring {
+  #[default]
+  #[display_style(name = "No Change")]
+  NoChange,
+  Monitored,
+  Unmonitored,
+}
+
+impl BulkEditMonitoring {
+  pub fn monitored(self) -> Option<bool> {
+    match self {
+      BulkEditMonitoring::NoChange => None,
+      BulkEditMonitoring::Monitored => Some(true),
+      BulkEditMonitoring::Unmonitored => Some(false),
+    }
+  }
+}
+
 #[derive(Default, Serialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct CommandBody {
@@ -105,6 +240,62 @@ pub struct IndexerSettings {
   pub rss_sync_interval: i64,
 }
 
+#[derive(Default, Deserialize, Serialize, Debug, Clone, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DownloadClient {
+  #[serde(deserialize_with = "super::from_i64")]
+  pub id: i64,
+  pub name: Option<String>,
+  pub enable: bool,
+  pub protocol: String,
+  #[serde(deserialize_with = "super::from_i64")]
+  pub priority: i64,
+  pub remove_completed_downloads: bool,
+  pub remove_failed_downloads: bool,
+  pub implementation: Option<String>,
+  pub implementation_name: Option<String>,
+  pub config_contract: Option<String>,
+  pub fields: Option<Vec<IndexerField>>,
+  pub tags: Vec<Number>,
+}
+
+impl DownloadClient {
+  /// The category that downloads are added under. Each implementation names this field
+  /// differently (e.g. `movieCategory` or `tvCategory`), so the first field ending in `Category`
+  /// is used
+  pub fn category(&self) -> Option<String> {
+    self
+      .fields
+      .iter()
+      .flatten()
+      .find(|field| {
+        field
+          .name
+          .as_deref()
+          .is_some_and(|name| name == "category" || name.ends_with("Category"))
+      })
+      .and_then(|field| field.value.as_ref())
+      .and_then(Value::as_str)
+      .map(str::to_owned)
+  }
+}
+
+#[derive(Default, Clone, Serialize, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct EditDownloadClientParams {
+  pub download_client_id: i64,
+  pub name: Option<String>,
+  pub enable: Option<bool>,
+  pub priority: Option<i64>,
+  pub remove_completed_downloads: Option<bool>,
+  pub remove_failed_downloads: Option<bool>,
+  pub tags: Option<Vec<i64>>,
+  #[serde(skip_serializing, skip_deserializing)]
+  pub tag_input_string: Option<String>,
+  pub clear_tags: bool,
+  pub fields: Vec<(String, String)>,
+}
+
 #[derive(Default, Clone, Serialize, Debug, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct EditIndexerParams {
@@ -123,6 +314,29 @@ pub struct EditIndexerParams {
   pub clear_tags: bool,
 }
 
+#[derive(Default, Clone, Serialize, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct EditQualityProfileParams {
+  pub quality_profile_id: i64,
+  pub name: Option<String>,
+  pub upgrade_allowed: Option<bool>,
+  pub cutoff: Option<String>,
+  pub min_format_score: Option<i64>,
+  pub cutoff_format_score: Option<i64>,
+  pub items: Option<Vec<QualityProfileItem>>,
+  pub allowed_qualities: Vec<String>,
+  pub disallowed_qualities: Vec<String>,
+}
+
+#[derive(Default, Clone, Serialize, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct EditCustomFormatParams {
+  pub custom_format_id: i64,
+  pub name: Option<String>,
+  pub include_custom_format_when_renaming: Option<bool>,
+  pub fields: Vec<(String, String)>,
+}
+
 #[derive(Default, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct KeybindingItem {
   pub key: String,
@@ -209,9 +423,62 @@ pub struct IndexerValidationFailure {
 }
 
 #[derive(Default, Deserialize, Serialize, Debug, Clone, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
 pub struct IndexerField {
   pub name: Option<String>,
   pub value: Option<Value>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub label: Option<String>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub help_text: Option<String>,
+  #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+  pub field_type: Option<String>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub hidden: Option<String>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub select_options: Option<Vec<IndexerFieldSelectOption>>,
+}
+
+#[derive(Default, Deserialize, Serialize, Debug, Clone, Eq, PartialEq)]
+pub struct IndexerFieldSelectOption {
+  #[serde(deserialize_with = "super::from_i64")]
+  pub value: i64,
+  pub name: String,
+}
+
+#[derive(Clone, Serialize, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct AddIndexerParams {
+  pub implementation: String,
+  pub name: String,
+  pub enable: bool,
+  pub enable_rss: bool,
+  pub enable_automatic_search: bool,
+  pub enable_interactive_search: bool,
+  pub priority: i64,
+  pub app_profile_id: Option<i64>,
+  pub tags: Vec<i64>,
+  #[serde(skip_serializing, skip_deserializing)]
+  pub tag_input_string: Option<String>,
+  pub fields: Vec<(String, String)>,
+}
+
+impl Default for AddIndexerParams {
+  fn default() -> Self {
+    Self {
+      implementation: String::new(),
+      name: String::new(),
+      enable: true,
+      enable_rss: true,
+      enable_automatic_search: true,
+      enable_interactive_search: true,
+      priority: 25,
+      app_profile_id: None,
+      tags: Vec::new(),
+      tag_input_string: None,
+      fields: Vec::new(),
+    }
+  }
 }
 
 #[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq, Eq, Ord, PartialOrd)]
@@ -221,6 +488,34 @@ pub struct Language {
   pub name: String,
 }
 
+#[derive(Default, Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct ManualImportRejection {
+  pub reason: String,
+}
+
+#[derive(Default, Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct MediaManagementConfig {
+  #[serde(deserialize_with = "super::from_i64")]
+  pub id: i64,
+  pub recycle_bin: Option<String>,
+  #[serde(deserialize_with = "super::from_i64")]
+  pub recycle_bin_cleanup_days: i64,
+  pub set_permissions_linux: bool,
+  pub chmod_folder: Option<String>,
+  pub chown_group: Option<String>,
+  pub import_extra_files: bool,
+  pub extra_file_extensions: Option<String>,
+}
+
+/// The file naming and media management settings of a Servarr, which are edited together
+#[derive(Default, Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct MediaManagementSettings<N> {
+  pub naming: N,
+  pub media_management: MediaManagementConfig,
+}
+
 #[derive(Default, Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Log {
@@ -244,10 +539,45 @@ pub struct Quality {
 }
 
 #[derive(Default, Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
 pub struct QualityProfile {
   #[serde(deserialize_with = "super::from_i64")]
   pub id: i64,
   pub name: String,
+  #[serde(default)]
+  pub upgrade_allowed: bool,
+  #[serde(default)]
+  pub cutoff: i64,
+  #[serde(default)]
+  pub items: Vec<QualityProfileItem>,
+  #[serde(default)]
+  pub min_format_score: i64,
+  #[serde(default)]
+  pub cutoff_format_score: i64,
+  #[serde(default)]
+  pub format_items: Vec<ProfileFormatItem>,
+}
+
+impl QualityProfile {
+  /// The name of the quality or quality group that the profile upgrades until
+  pub fn cutoff_name(&self) -> Option<String> {
+    self
+      .items
+      .iter()
+      .find(|item| item.item_id() == self.cutoff)
+      .map(QualityProfileItem::display_name)
+  }
+
+  /// The names of all allowed qualities and quality groups, from highest to lowest
+  pub fn allowed_qualities(&self) -> Vec<String> {
+    self
+      .items
+      .iter()
+      .rev()
+      .filter(|item| item.allowed)
+      .map(QualityProfileItem::display_name)
+      .collect()
+  }
 }
 
 impl From<(&i64, &String)> for QualityProfile {
@@ -255,10 +585,145 @@ impl From<(&i64, &String)> for QualityProfile {
     QualityProfile {
       id: *value.0,
       name: value.1.clone(),
+      ..QualityProfile::default()
     }
   }
 }
 
+/// A single quality, or a named group of qualities, in a quality profile. Groups carry their own
+/// ID and name, while single qualities are identified by their quality
+#[derive(Default, Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct QualityProfileItem {
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub id: Option<i64>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub name: Option<String>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub quality: Option<ProfileQuality>,
+  #[serde(default)]
+  pub items: Vec<QualityProfileItem>,
+  pub allowed: bool,
+}
+
+impl QualityProfileItem {
+  pub fn is_group(&self) -> bool {
+    self.quality.is_none()
+  }
+
+  /// The ID that the profile cutoff refers to: the group ID for groups and the quality ID otherwise
+  pub fn item_id(&self) -> i64 {
+    match &self.quality {
+      Some(quality) => quality.id,
+      None => self.id.unwrap_or_default(),
+    }
+  }
+
+  pub fn display_name(&self) -> String {
+    match &self.quality {
+      Some(quality) => quality.name.clone(),
+      None => self.name.clone().unwrap_or_default(),
+    }
+  }
+}
+
+#[derive(Default, Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct ProfileQuality {
+  #[serde(deserialize_with = "super::from_i64")]
+  pub id: i64,
+  pub name: String,
+}
+
+#[derive(Default, Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct ProfileFormatItem {
+  #[serde(deserialize_with = "super::from_i64")]
+  pub format: i64,
+  pub name: String,
+  #[serde(deserialize_with = "super::from_i64")]
+  pub score: i64,
+}
+
+/// A custom format, which applies to a release when the release satisfies its specifications
+#[derive(Default, Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomFormat {
+  #[serde(deserialize_with = "super::from_i64")]
+  pub id: i64,
+  pub name: String,
+  #[serde(default)]
+  pub include_custom_format_when_renaming: bool,
+  #[serde(default)]
+  pub specifications: Vec<CustomFormatSpecification>,
+}
+
+impl CustomFormat {
+  /// Converts the custom format into the JSON format used by the TRaSH guides, which leaves out
+  /// the ID and lists the values of each specification's fields in a single object
+  pub fn to_trash_json(&self) -> Value {
+    let specifications = self
+      .specifications
+      .iter()
+      .map(|specification| {
+        let fields: serde_json::Map<String, Value> = specification
+          .fields
+          .iter()
+          .filter_map(|field| {
+            Some((
+              field.name.clone()?,
+              field.value.clone().unwrap_or(Value::Null),
+            ))
+          })
+          .collect();
+
+        json!({
+          "name": specification.name,
+          "implementation": specification.implementation,
+          "negate": specification.negate,
+          "required": specification.required,
+          "fields": fields,
+        })
+      })
+      .collect::<Vec<_>>();
+
+    json!({
+      "name": self.name,
+      "includeCustomFormatWhenRenaming": self.include_custom_format_when_renaming,
+      "specifications": specifications,
+    })
+  }
+}
+
+#[derive(Default, Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomFormatSpecification {
+  pub name: String,
+  pub implementation: String,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub implementation_name: Option<String>,
+  #[serde(default)]
+  pub negate: bool,
+  #[serde(default)]
+  pub required: bool,
+  #[serde(default)]
+  pub fields: Vec<IndexerField>,
+}
+
+impl CustomFormatSpecification {
+  /// The values of the specification's fields as they're shown in the specifications table
+  pub fn field_values(&self) -> String {
+    self
+      .fields
+      .iter()
+      .filter_map(|field| match field.value.as_ref()? {
+        Value::Null => None,
+        Value::String(value) => Some(value.clone()),
+        value => Some(value.to_string()),
+      })
+      .collect::<Vec<_>>()
+      .join(", ")
+  }
+}
+
 #[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq, Eq, Ord, PartialOrd)]
 pub struct QualityWrapper {
   pub quality: Quality,
@@ -3,8 +3,12 @@ mod tests {
   use pretty_assertions::{assert_eq, assert_str_eq};
 
   use crate::models::servarr_models::{
-    AuthenticationMethod, AuthenticationRequired, CertificateValidation, Indexer, QualityProfile,
+    ApplyTags, AuthenticationMethod, AuthenticationRequired, BulkEditMonitoring,
+    CertificateValidation, CustomFormat, CustomFormatSpecification, DownloadClient, Indexer,
+    IndexerField, QualityProfile, QueueRemovalOptions,
   };
+  use crate::network::servarr_test_utils::{custom_format, quality_profile_details};
+  use serde_json::json;
 
   #[test]
   fn test_indexer_default() {
@@ -27,6 +31,79 @@ mod tests {
     assert_is_empty!(indexer.tags);
   }
 
+  #[test]
+  fn test_download_client_category() {
+    let download_client = |name: &str, value: serde_json::Value| DownloadClient {
+      fields: Some(vec![
+        IndexerField {
+          name: Some("host".to_owned()),
+          value: Some(json!("localhost")),
+          ..IndexerField::default()
+        },
+        IndexerField {
+          name: Some(name.to_owned()),
+          value: Some(value),
+          ..IndexerField::default()
+        },
+      ]),
+      ..DownloadClient::default()
+    };
+
+    assert_some_eq_x!(
+      download_client("category", json!("managarr")).category(),
+      "managarr"
+    );
+    assert_some_eq_x!(
+      download_client("movieCategory", json!("radarr")).category(),
+      "radarr"
+    );
+    assert_none!(download_client("musicCategory", json!(null)).category());
+    assert_none!(download_client("port", json!("8080")).category());
+    assert_none!(DownloadClient::default().category());
+  }
+
+  #[test]
+  fn test_queue_removal_options_default() {
+    let options = QueueRemovalOptions::default();
+
+    assert!(options.remove_from_client);
+    assert!(!options.blocklist);
+    assert!(!options.skip_redownload);
+    assert!(!options.change_category);
+  }
+
+  #[test]
+  fn test_queue_removal_options_query_params() {
+    let options = QueueRemovalOptions {
+      remove_from_client: false,
+      blocklist: true,
+      skip_redownload: true,
+      change_category: false,
+    };
+
+    assert_str_eq!(
+      options.query_params(),
+      "removeFromClient=false&blocklist=true&skipRedownload=true&changeCategory=false"
+    );
+  }
+
+  #[test]
+  fn test_apply_tags_display() {
+    assert_str_eq!(ApplyTags::Add.to_string(), "add");
+    assert_str_eq!(ApplyTags::Remove.to_string(), "remove");
+    assert_str_eq!(ApplyTags::Replace.to_string(), "replace");
+    assert_str_eq!(ApplyTags::Replace.to_display_str(), "Replace");
+    assert_eq!(json!(ApplyTags::Remove), json!("remove"));
+  }
+
+  #[test]
+  fn test_bulk_edit_monitoring_monitored() {
+    assert_none!(BulkEditMonitoring::NoChange.monitored());
+    assert_some_eq_x!(BulkEditMonitoring::Monitored.monitored(), true);
+    assert_some_eq_x!(BulkEditMonitoring::Unmonitored.monitored(), false);
+    assert_str_eq!(BulkEditMonitoring::NoChange.to_display_str(), "No Change");
+  }
+
   #[test]
   fn test_authentication_method_display() {
     assert_str_eq!(AuthenticationMethod::Basic.to_string(), "basic");
@@ -61,10 +138,148 @@ mod tests {
     let expected_quality_profile = QualityProfile {
       id: 2,
       name: "Test".to_owned(),
+      ..QualityProfile::default()
     };
 
     let quality_profile = QualityProfile::from(quality_profile_tuple);
 
     assert_eq!(expected_quality_profile, quality_profile);
   }
+
+  #[test]
+  fn test_quality_profile_cutoff_name() {
+    let mut quality_profile = quality_profile_details();
+
+    assert_some_eq_x!(quality_profile.cutoff_name(), "WEB 720p");
+
+    quality_profile.cutoff = 7;
+
+    assert_some_eq_x!(quality_profile.cutoff_name(), "Bluray-1080p");
+
+    quality_profile.cutoff = 5;
+
+    assert_none!(quality_profile.cutoff_name());
+  }
+
+  #[test]
+  fn test_quality_profile_allowed_qualities() {
+    assert_eq!(
+      quality_profile_details().allowed_qualities(),
+      vec!["Bluray-1080p".to_owned(), "WEB 720p".to_owned()]
+    );
+  }
+
+  #[test]
+  fn test_quality_profile_item_helpers() {
+    let quality_profile = quality_profile_details();
+    let quality = &quality_profile.items[0];
+    let group = &quality_profile.items[1];
+
+    assert!(!quality.is_group());
+    assert_eq!(quality.item_id(), 1);
+    assert_str_eq!(quality.display_name(), "SDTV");
+    assert!(group.is_group());
+    assert_eq!(group.item_id(), 1000);
+    assert_str_eq!(group.display_name(), "WEB 720p");
+  }
+
+  #[test]
+  fn test_quality_profile_deserialization() {
+    let quality_profile_json = json!({
+      "id": 1,
+      "name": "HD",
+      "upgradeAllowed": true,
+      "cutoff": 1000,
+      "items": [
+        { "quality": { "id": 1, "name": "SDTV" }, "items": [], "allowed": false },
+        {
+          "id": 1000,
+          "name": "WEB 720p",
+          "items": [
+            { "quality": { "id": 5, "name": "WEBDL-720p" }, "items": [], "allowed": true },
+            { "quality": { "id": 14, "name": "WEBRip-720p" }, "items": [], "allowed": true }
+          ],
+          "allowed": true
+        },
+        { "quality": { "id": 7, "name": "Bluray-1080p" }, "items": [], "allowed": true }
+      ],
+      "minFormatScore": 0,
+      "cutoffFormatScore": 100,
+      "formatItems": [{ "format": 1, "name": "x265", "score": 10 }]
+    });
+
+    let quality_profile: QualityProfile = serde_json::from_value(quality_profile_json).unwrap();
+
+    assert_eq!(quality_profile, quality_profile_details());
+  }
+
+  #[test]
+  fn test_custom_format_deserialization() {
+    let custom_format_json = json!({
+      "id": 1,
+      "name": "x265",
+      "includeCustomFormatWhenRenaming": false,
+      "specifications": [{
+        "name": "x265",
+        "implementation": "ReleaseTitleSpecification",
+        "implementationName": "Release Title",
+        "negate": false,
+        "required": true,
+        "fields": [{
+          "name": "value",
+          "label": "Regular Expression",
+          "value": "\\bx265\\b",
+          "type": "textbox"
+        }]
+      }]
+    });
+
+    let deserialized_custom_format: CustomFormat =
+      serde_json::from_value(custom_format_json).unwrap();
+
+    assert_eq!(deserialized_custom_format, custom_format());
+  }
+
+  #[test]
+  fn test_custom_format_to_trash_json() {
+    let expected_json = json!({
+      "name": "x265",
+      "includeCustomFormatWhenRenaming": false,
+      "specifications": [{
+        "name": "x265",
+        "implementation": "ReleaseTitleSpecification",
+        "negate": false,
+        "required": true,
+        "fields": { "value": "\\bx265\\b" }
+      }]
+    });
+
+    assert_eq!(custom_format().to_trash_json(), expected_json);
+  }
+
+  #[test]
+  fn test_custom_format_specification_field_values() {
+    let specification = CustomFormatSpecification {
+      fields: vec![
+        IndexerField {
+          name: Some("min".to_owned()),
+          value: Some(json!(1)),
+          ..IndexerField::default()
+        },
+        IndexerField {
+          name: Some("max".to_owned()),
+          value: Some(json!(null)),
+          ..IndexerField::default()
+        },
+        IndexerField {
+          name: Some("value".to_owned()),
+          value: Some(json!("test")),
+          ..IndexerField::default()
+        },
+      ],
+      ..CustomFormatSpecification::default()
+    };
+
+    assert_str_eq!(specification.field_values(), "1, test");
+  }
 }
@@ -16,7 +16,8 @@ use strum_macros::Display;
 use super::{
   HorizontallyScrollableText, Serdeable,
   servarr_models::{
-    DiskSpace, HostConfig, Indexer, Language, LogResponse, QualityProfile, QualityWrapper,
+    ApplyTags, Backup, CustomFormat, DiskSpace, DownloadClient, HostConfig, Indexer, Language,
+    LogResponse, ManualImportRejection, MediaManagementSettings, QualityProfile, QualityWrapper,
     QueueEvent, RootFolder, SecurityConfig, Tag, Update,
   },
 };
@@ -100,6 +101,32 @@ pub struct BlocklistResponse {
   pub records: Vec<BlocklistItem>,
 }
 
+#[derive(Default, Clone, Serialize, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct BulkDeleteSeriesParams {
+  pub series_ids: Vec<i64>,
+  pub delete_files: bool,
+  pub add_import_list_exclusion: bool,
+}
+
+#[derive(Default, Clone, Serialize, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct BulkEditSeriesParams {
+  pub series_ids: Vec<i64>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub monitored: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub quality_profile_id: Option<i64>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub root_folder_path: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub tags: Option<Vec<i64>>,
+  #[serde(skip_serializing, skip_deserializing)]
+  pub tag_input_string: Option<String>,
+  pub apply_tags: ApplyTags,
+  pub move_files: bool,
+}
+
 #[derive(Serialize, Deserialize, Debug, Default, Clone, Eq, PartialEq)]
 #[serde(rename_all = "lowercase")]
 pub struct DeleteSeriesParams {
@@ -124,10 +151,80 @@ pub struct DownloadRecord {
   #[serde(default)]
   pub indexer: String,
   pub download_client: Option<String>,
+  pub download_id: Option<String>,
+  pub estimated_completion_time: Option<DateTime<Utc>>,
+}
+
+impl DownloadRecord {
+  /// Whether this is a release held back by Sonarr (e.g. by a delay profile) that hasn't been
+  /// sent to a download client yet
+  pub fn is_pending(&self) -> bool {
+    matches!(
+      self.status,
+      DownloadStatus::Delay | DownloadStatus::DownloadClientUnavailable | DownloadStatus::Fallback
+    )
+  }
 }
 
 impl Eq for DownloadRecord {}
 
+#[derive(Default, Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SonarrManualImportItem {
+  #[serde(deserialize_with = "super::from_i64")]
+  pub id: i64,
+  pub path: String,
+  pub relative_path: String,
+  #[serde(deserialize_with = "super::from_i64")]
+  pub size: i64,
+  pub series: Option<Series>,
+  pub season_number: Option<i64>,
+  #[serde(default)]
+  pub episodes: Vec<Episode>,
+  pub quality: Value,
+  #[serde(default)]
+  pub languages: Vec<Language>,
+  pub release_group: Option<String>,
+  pub download_id: Option<String>,
+  #[serde(default)]
+  pub rejections: Vec<ManualImportRejection>,
+}
+
+impl SonarrManualImportItem {
+  pub fn import_file(&self) -> Option<SonarrManualImportFile> {
+    if !self.rejections.is_empty() || self.episodes.is_empty() {
+      return None;
+    }
+
+    let series = self.series.as_ref()?;
+    let season_number = self.season_number?;
+
+    Some(SonarrManualImportFile {
+      path: self.path.clone(),
+      series_id: series.id,
+      season_number,
+      episode_ids: self.episodes.iter().map(|episode| episode.id).collect(),
+      quality: self.quality.clone(),
+      languages: self.languages.clone(),
+      release_group: self.release_group.clone(),
+      download_id: self.download_id.clone(),
+    })
+  }
+}
+
+#[derive(Default, Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SonarrManualImportFile {
+  pub path: String,
+  pub series_id: i64,
+  pub season_number: i64,
+  pub episode_ids: Vec<i64>,
+  pub quality: Value,
+  pub languages: Vec<Language>,
+  pub release_group: Option<String>,
+  pub download_id: Option<String>,
+}
+
 #[derive(
   Serialize,
   Deserialize,
@@ -256,6 +353,32 @@ pub struct MonitorEpisodeBody {
   pub monitored: bool,
 }
 
+#[derive(Default, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct NamingConfig {
+  #[serde(deserialize_with = "super::from_i64")]
+  pub id: i64,
+  pub rename_episodes: bool,
+  pub replace_illegal_characters: bool,
+  pub standard_episode_format: Option<String>,
+  pub daily_episode_format: Option<String>,
+  pub anime_episode_format: Option<String>,
+  pub series_folder_format: Option<String>,
+  pub season_folder_format: Option<String>,
+  pub specials_folder_format: Option<String>,
+}
+
+#[derive(Default, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct NamingExamples {
+  pub single_episode_example: Option<String>,
+  pub daily_episode_example: Option<String>,
+  pub anime_episode_example: Option<String>,
+  pub series_folder_example: Option<String>,
+  pub season_folder_example: Option<String>,
+  pub specials_folder_example: Option<String>,
+}
+
 #[derive(Derivative, Serialize, Deserialize, Debug, Clone, PartialEq)]
 #[derivative(Default)]
 pub struct Rating {
@@ -536,6 +659,9 @@ pub struct SonarrRelease {
   pub languages: Option<Vec<Option<Language>>>,
   pub quality: QualityWrapper,
   pub full_season: bool,
+  pub custom_formats: Vec<CustomFormat>,
+  #[serde(deserialize_with = "super::from_i64")]
+  pub custom_format_score: i64,
 }
 
 #[derive(Default, Serialize, Debug, PartialEq, Eq, Clone)]
@@ -597,18 +723,24 @@ impl From<SonarrSerdeable> for Serdeable {
 serde_enum_from!(
   SonarrSerdeable {
     AddSeriesSearchResults(Vec<AddSeriesSearchResult>),
+    Backups(Vec<Backup>),
     BlocklistResponse(BlocklistResponse),
     DownloadsResponse(DownloadsResponse),
+    CustomFormats(Vec<CustomFormat>),
     DiskSpaces(Vec<DiskSpace>),
     Episode(Episode),
     Episodes(Vec<Episode>),
     EpisodeFiles(Vec<EpisodeFile>),
     HostConfig(HostConfig),
     IndexerSettings(IndexerSettings),
+    DownloadClients(Vec<DownloadClient>),
     Indexers(Vec<Indexer>),
     IndexerTestResults(Vec<IndexerTestResult>),
     LanguageProfiles(Vec<Language>),
     LogResponse(LogResponse),
+    MediaManagementSettings(MediaManagementSettings<NamingConfig>),
+    NamingExamples(NamingExamples),
+    QualityProfile(QualityProfile),
     QualityProfiles(Vec<QualityProfile>),
     QueueEvents(Vec<QueueEvent>),
     Releases(Vec<SonarrRelease>),
@@ -624,6 +756,8 @@ serde_enum_from!(
     Tasks(Vec<SonarrTask>),
     Updates(Vec<Update>),
     Value(Value),
+    WantedEpisodesResponse(WantedEpisodesResponse),
+    ManualImportItems(Vec<SonarrManualImportItem>),
   }
 );
 
@@ -633,3 +767,8 @@ pub struct SystemStatus {
   pub version: String,
   pub start_time: DateTime<Utc>,
 }
+
+#[derive(Default, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct WantedEpisodesResponse {
+  pub records: Vec<Episode>,
+}
@@ -6,14 +6,15 @@ mod tests {
   use crate::models::{
     Serdeable,
     servarr_models::{
-      DiskSpace, HostConfig, Indexer, IndexerSettings, IndexerTestResult, Language, Log,
-      LogResponse, QualityProfile, QueueEvent, RootFolder, SecurityConfig, Tag, Update,
+      Backup, CustomFormat, DiskSpace, HostConfig, Indexer, IndexerSettings, IndexerTestResult,
+      Language, Log, LogResponse, MediaManagementConfig, MediaManagementSettings, QualityProfile,
+      QueueEvent, RootFolder, SecurityConfig, Tag, Update,
     },
     sonarr_models::{
       AddSeriesSearchResult, BlocklistItem, BlocklistResponse, DownloadRecord, DownloadStatus,
-      DownloadsResponse, Episode, EpisodeFile, Series, SeriesMonitor, SeriesStatus, SeriesType,
-      SonarrHistoryEventType, SonarrHistoryItem, SonarrRelease, SonarrSerdeable, SonarrTask,
-      SonarrTaskName, SystemStatus,
+      DownloadsResponse, Episode, EpisodeFile, NamingConfig, NamingExamples, Series, SeriesMonitor,
+      SeriesStatus, SeriesType, SonarrHistoryEventType, SonarrHistoryItem, SonarrRelease,
+      SonarrSerdeable, SonarrTask, SonarrTaskName, SystemStatus, WantedEpisodesResponse,
     },
   };
 
@@ -118,6 +119,20 @@ mod tests {
     assert_str_eq!(SeriesType::Anime.to_display_str(), "Anime");
   }
 
+  #[test]
+  fn test_download_record_is_pending() {
+    let download_record = |status: DownloadStatus| DownloadRecord {
+      status,
+      ..DownloadRecord::default()
+    };
+
+    assert!(download_record(DownloadStatus::Delay).is_pending());
+    assert!(download_record(DownloadStatus::DownloadClientUnavailable).is_pending());
+    assert!(download_record(DownloadStatus::Fallback).is_pending());
+    assert!(!download_record(DownloadStatus::Downloading).is_pending());
+    assert!(!download_record(DownloadStatus::Completed).is_pending());
+  }
+
   #[test]
   fn test_download_status_display() {
     assert_str_eq!(DownloadStatus::Unknown.to_string(), "unknown");
@@ -424,6 +439,33 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_sonarr_serdeable_from_backups() {
+    let backups = vec![Backup {
+      id: 1,
+      ..Backup::default()
+    }];
+
+    let sonarr_serdeable: SonarrSerdeable = backups.clone().into();
+
+    assert_eq!(sonarr_serdeable, SonarrSerdeable::Backups(backups));
+  }
+
+  #[test]
+  fn test_sonarr_serdeable_from_custom_formats() {
+    let custom_formats = vec![CustomFormat {
+      id: 1,
+      ..CustomFormat::default()
+    }];
+
+    let sonarr_serdeable: SonarrSerdeable = custom_formats.clone().into();
+
+    assert_eq!(
+      sonarr_serdeable,
+      SonarrSerdeable::CustomFormats(custom_formats)
+    );
+  }
+
   #[test]
   fn test_sonarr_serdeable_from_disk_spaces() {
     let disk_spaces = vec![DiskSpace {
@@ -472,11 +514,63 @@ mod tests {
     assert_eq!(sonarr_serdeable, SonarrSerdeable::LogResponse(log_response));
   }
 
+  #[test]
+  fn test_sonarr_serdeable_from_media_management_settings() {
+    let media_management_settings = MediaManagementSettings {
+      naming: NamingConfig {
+        id: 1,
+        ..NamingConfig::default()
+      },
+      media_management: MediaManagementConfig {
+        id: 1,
+        ..MediaManagementConfig::default()
+      },
+    };
+
+    let sonarr_serdeable: SonarrSerdeable = media_management_settings.clone().into();
+
+    assert_eq!(
+      sonarr_serdeable,
+      SonarrSerdeable::MediaManagementSettings(media_management_settings)
+    );
+  }
+
+  #[test]
+  fn test_sonarr_serdeable_from_naming_examples() {
+    let naming_examples = NamingExamples {
+      single_episode_example: Some("Test".to_owned()),
+      ..NamingExamples::default()
+    };
+
+    let sonarr_serdeable: SonarrSerdeable = naming_examples.clone().into();
+
+    assert_eq!(
+      sonarr_serdeable,
+      SonarrSerdeable::NamingExamples(naming_examples)
+    );
+  }
+
+  #[test]
+  fn test_sonarr_serdeable_from_quality_profile() {
+    let quality_profile = QualityProfile {
+      id: 1,
+      ..QualityProfile::default()
+    };
+
+    let sonarr_serdeable: SonarrSerdeable = quality_profile.clone().into();
+
+    assert_eq!(
+      sonarr_serdeable,
+      SonarrSerdeable::QualityProfile(quality_profile)
+    );
+  }
+
   #[test]
   fn test_sonarr_serdeable_from_quality_profiles() {
     let quality_profiles = vec![QualityProfile {
       name: "Test Profile".to_owned(),
       id: 1,
+      ..QualityProfile::default()
     }];
 
     let sonarr_serdeable: SonarrSerdeable = quality_profiles.clone().into();
@@ -600,4 +694,20 @@ mod tests {
       SonarrSerdeable::IndexerTestResults(indexer_test_results)
     );
   }
+  #[test]
+  fn test_sonarr_serdeable_from_wanted_episodes_response() {
+    let wanted_episodes_response = WantedEpisodesResponse {
+      records: vec![Episode {
+        id: 1,
+        ..Episode::default()
+      }],
+    };
+
+    let sonarr_serdeable: SonarrSerdeable = wanted_episodes_response.clone().into();
+
+    assert_eq!(
+      sonarr_serdeable,
+      SonarrSerdeable::WantedEpisodesResponse(wanted_episodes_response)
+    );
+  }
 }
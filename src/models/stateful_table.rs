@@ -4,6 +4,7 @@ use crate::models::{
 };
 use ratatui::widgets::TableState;
 use std::cmp::Ordering;
+use std::collections::HashSet;
 use std::fmt::Debug;
 
 #[cfg(test)]
@@ -44,6 +45,9 @@ where
   pub filtered_state: Option<TableState>,
   pub sort_asc: bool,
   pub sort: Option<StatefulList<SortOption<T>>>,
+  pub selected_ids: HashSet<i64>,
+  pub selection_id_fn: Option<fn(&T) -> i64>,
+  pub visual_select_anchor: Option<usize>,
 }
 
 impl<T> Scrollable for StatefulTable<T>
@@ -150,6 +154,75 @@ where
   fn active_state(&self) -> &TableState {
     self.filtered_state.as_ref().unwrap_or(&self.state)
   }
+
+  fn visual_select_range(&self) -> Option<(usize, usize)> {
+    let anchor = self.visual_select_anchor?;
+    let current = self.active_state().selected().unwrap_or(0);
+
+    Some((anchor.min(current), anchor.max(current)))
+  }
+
+  fn visual_select_ids(&self, id_fn: fn(&T) -> i64) -> HashSet<i64> {
+    self
+      .visual_select_range()
+      .map(|(start, end)| {
+        let items = self.active_items();
+        items[start.min(items.len())..(end + 1).min(items.len())]
+          .iter()
+          .map(id_fn)
+          .collect()
+      })
+      .unwrap_or_default()
+  }
+
+  pub fn is_row_selected(&self, index: usize) -> bool {
+    let in_visual_range = self
+      .visual_select_range()
+      .is_some_and(|(start, end)| (start..=end).contains(&index));
+
+    in_visual_range
+      || self
+        .selection_id_fn
+        .zip(self.active_items().get(index))
+        .is_some_and(|(id_fn, item)| self.selected_ids.contains(&id_fn(item)))
+  }
+
+  pub fn has_multi_selection(&self) -> bool {
+    !self.selected_ids.is_empty() || self.visual_select_anchor.is_some()
+  }
+
+  /// Resolves the selected rows from the table items, in table order
+  pub fn multi_selection(&self) -> Vec<T> {
+    let Some(id_fn) = self.selection_id_fn else {
+      return Vec::new();
+    };
+    let visual_select_ids = self.visual_select_ids(id_fn);
+
+    self
+      .items
+      .iter()
+      .filter(|item| {
+        let id = id_fn(item);
+        self.selected_ids.contains(&id) || visual_select_ids.contains(&id)
+      })
+      .cloned()
+      .collect()
+  }
+
+  pub fn clear_multi_selection(&mut self) {
+    self.selected_ids.clear();
+    self.visual_select_anchor = None;
+  }
+
+  /// Ends any in-progress visual selection, keeping the rows it covered selected by id so the
+  /// selection survives the rows moving (e.g. when the items are refreshed or re-sorted)
+  fn end_visual_select(&mut self) {
+    if let Some(id_fn) = self.selection_id_fn {
+      let visual_select_ids = self.visual_select_ids(id_fn);
+      self.selected_ids.extend(visual_select_ids);
+    }
+    self.visual_select_anchor = None;
+  }
 }
 
 impl<T> StatefulTable<T>
@@ -158,7 +231,12 @@ where
 {
   pub fn set_items(&mut self, items: Vec<T>) {
     let items_len = items.len();
+    self.end_visual_select();
     self.items = items;
+    if let Some(id_fn) = self.selection_id_fn {
+      let item_ids: HashSet<i64> = self.items.iter().map(id_fn).collect();
+      self.selected_ids.retain(|id| item_ids.contains(id));
+    }
     if !self.items.is_empty() {
       let selected_row = self.state.selected().map_or(0, |i| {
         if i > 0 && i < items_len {
@@ -194,6 +272,7 @@ where
     let mut filtered_state: TableState = Default::default();
     filtered_state.select(Some(preserved_selection));
     self.filtered_state = Some(filtered_state);
+    self.visual_select_anchor = None;
   }
 
   pub fn select_index(&mut self, index: Option<usize>) {
@@ -218,6 +297,7 @@ where
   }
 
   pub fn apply_sorting_toggle(&mut self, toggle_dir: bool) {
+    self.end_visual_select();
     if let Some(sort_options) = &mut self.sort {
       if toggle_dir {
         self.sort_asc = !self.sort_asc;
@@ -267,6 +347,7 @@ where
     self.filter = None;
     self.filtered_items = None;
     self.filtered_state = None;
+    self.visual_select_anchor = None;
   }
 
   pub fn apply_search(&mut self, search_field: fn(&T) -> &str) -> bool {
@@ -299,4 +380,41 @@ where
   pub fn is_empty(&self) -> bool {
     self.items.is_empty()
   }
+
+  pub fn toggle_current_selection(&mut self, id_fn: fn(&T) -> i64) {
+    if self.active_items().is_empty() {
+      return;
+    }
+
+    self.selection_id_fn = Some(id_fn);
+    let id = id_fn(self.current_selection());
+    if !self.selected_ids.remove(&id) {
+      self.selected_ids.insert(id);
+    }
+  }
+
+  pub fn toggle_select_all(&mut self, id_fn: fn(&T) -> i64) {
+    self.selection_id_fn = Some(id_fn);
+    let active_ids: Vec<i64> = self.active_items().iter().map(id_fn).collect();
+    if active_ids.iter().all(|id| self.selected_ids.contains(id)) {
+      for id in &active_ids {
+        self.selected_ids.remove(id);
+      }
+    } else {
+      self.selected_ids.extend(active_ids);
+    }
+  }
+
+  pub fn toggle_visual_select(&mut self, id_fn: fn(&T) -> i64) {
+    if self.active_items().is_empty() {
+      return;
+    }
+
+    self.selection_id_fn = Some(id_fn);
+    if self.visual_select_anchor.is_some() {
+      self.end_visual_select();
+    } else {
+      self.visual_select_anchor = Some(self.active_state().selected().unwrap_or(0));
+    }
+  }
 }
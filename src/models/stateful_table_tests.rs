@@ -4,6 +4,7 @@ mod tests {
   use crate::models::{Paginated, Scrollable};
   use pretty_assertions::{assert_eq, assert_str_eq};
   use ratatui::widgets::TableState;
+  use std::collections::HashSet;
   use std::iter;
 
   #[test]
@@ -848,6 +849,215 @@ mod tests {
     assert_is_empty!(stateful_table);
   }
 
+  #[test]
+  fn test_stateful_table_toggle_current_selection() {
+    let mut stateful_table = create_test_selectable_stateful_table();
+
+    stateful_table.toggle_current_selection(row_id);
+
+    assert_eq!(stateful_table.selected_ids, HashSet::from([1]));
+    assert!(stateful_table.is_row_selected(0));
+    assert!(!stateful_table.is_row_selected(1));
+    assert!(stateful_table.has_multi_selection());
+
+    stateful_table.toggle_current_selection(row_id);
+
+    assert_is_empty!(stateful_table.selected_ids);
+    assert!(!stateful_table.has_multi_selection());
+  }
+
+  #[test]
+  fn test_stateful_table_toggle_current_selection_on_empty_table_performs_no_op() {
+    let mut stateful_table: StatefulTable<(i64, &str)> = StatefulTable::default();
+
+    stateful_table.toggle_current_selection(row_id);
+
+    assert_is_empty!(stateful_table.selected_ids);
+  }
+
+  #[test]
+  fn test_stateful_table_toggle_select_all() {
+    let mut stateful_table = create_test_selectable_stateful_table();
+    stateful_table.toggle_current_selection(row_id);
+
+    stateful_table.toggle_select_all(row_id);
+
+    assert_eq!(stateful_table.selected_ids, HashSet::from([1, 2, 3, 4]));
+
+    stateful_table.toggle_select_all(row_id);
+
+    assert_is_empty!(stateful_table.selected_ids);
+  }
+
+  #[test]
+  fn test_stateful_table_toggle_select_all_only_selects_filtered_items() {
+    let mut stateful_table = create_test_selectable_stateful_table();
+    stateful_table.filter = Some("i".into());
+    stateful_table.apply_filter(|(_, name)| name);
+
+    stateful_table.toggle_select_all(row_id);
+
+    assert_eq!(stateful_table.selected_ids, HashSet::from([1, 2]));
+
+    stateful_table.reset_filter();
+
+    assert!(stateful_table.is_row_selected(0));
+    assert!(stateful_table.is_row_selected(1));
+    assert!(!stateful_table.is_row_selected(2));
+    assert!(!stateful_table.is_row_selected(3));
+  }
+
+  #[test]
+  fn test_stateful_table_toggle_visual_select() {
+    let mut stateful_table = create_test_selectable_stateful_table();
+    stateful_table.scroll_down();
+
+    stateful_table.toggle_visual_select(row_id);
+    stateful_table.scroll_down();
+
+    assert_some_eq_x!(stateful_table.visual_select_anchor, 1);
+    assert!(!stateful_table.is_row_selected(0));
+    assert!(stateful_table.is_row_selected(1));
+    assert!(stateful_table.is_row_selected(2));
+    assert!(!stateful_table.is_row_selected(3));
+    assert_is_empty!(stateful_table.selected_ids);
+    assert_eq!(stateful_table.multi_selection(), vec![(2, "is"), (3, "a")]);
+
+    stateful_table.toggle_visual_select(row_id);
+
+    assert_none!(stateful_table.visual_select_anchor);
+    assert_eq!(stateful_table.selected_ids, HashSet::from([2, 3]));
+  }
+
+  #[test]
+  fn test_stateful_table_toggle_visual_select_range_above_anchor() {
+    let mut stateful_table = create_test_selectable_stateful_table();
+    stateful_table.scroll_to_bottom();
+
+    stateful_table.toggle_visual_select(row_id);
+    stateful_table.scroll_up();
+
+    assert_eq!(
+      stateful_table.multi_selection(),
+      vec![(3, "a"), (4, "test")]
+    );
+  }
+
+  #[test]
+  fn test_stateful_table_multi_selection_preserves_table_order() {
+    let mut stateful_table = create_test_selectable_stateful_table();
+    stateful_table.scroll_to_bottom();
+    stateful_table.toggle_current_selection(row_id);
+    stateful_table.scroll_to_top();
+    stateful_table.toggle_current_selection(row_id);
+
+    assert_eq!(
+      stateful_table.multi_selection(),
+      vec![(1, "this"), (4, "test")]
+    );
+  }
+
+  #[test]
+  fn test_stateful_table_set_items_drops_selected_items_that_no_longer_exist() {
+    let mut stateful_table = create_test_selectable_stateful_table();
+    stateful_table.toggle_select_all(row_id);
+
+    stateful_table.set_items(vec![(2, "is"), (5, "new")]);
+
+    assert_eq!(stateful_table.selected_ids, HashSet::from([2]));
+  }
+
+  #[test]
+  fn test_stateful_table_set_items_keeps_selected_items_that_changed() {
+    let mut stateful_table = create_test_selectable_stateful_table();
+    stateful_table.toggle_current_selection(row_id);
+
+    stateful_table.set_items(vec![(1, "that"), (2, "is")]);
+
+    assert!(stateful_table.is_row_selected(0));
+    assert_eq!(stateful_table.multi_selection(), vec![(1, "that")]);
+  }
+
+  #[test]
+  fn test_stateful_table_set_items_ends_visual_select() {
+    let mut stateful_table = create_test_selectable_stateful_table();
+    stateful_table.scroll_down();
+    stateful_table.toggle_visual_select(row_id);
+    stateful_table.scroll_down();
+
+    stateful_table.set_items(vec![
+      (5, "new"),
+      (1, "this"),
+      (2, "is"),
+      (3, "a"),
+      (4, "test"),
+    ]);
+
+    assert_none!(stateful_table.visual_select_anchor);
+    assert_eq!(stateful_table.selected_ids, HashSet::from([2, 3]));
+    assert!(!stateful_table.is_row_selected(1));
+    assert!(stateful_table.is_row_selected(2));
+    assert!(stateful_table.is_row_selected(3));
+    assert!(!stateful_table.is_row_selected(4));
+    assert_eq!(stateful_table.multi_selection(), vec![(2, "is"), (3, "a")]);
+  }
+
+  #[test]
+  fn test_stateful_table_apply_sorting_ends_visual_select() {
+    let mut stateful_table = create_test_selectable_stateful_table();
+    stateful_table.sorting(vec![SortOption {
+      name: "Test 1",
+      cmp_fn: Some(|a, b| a.0.cmp(&b.0)),
+    }]);
+    stateful_table.sort_asc = true;
+    stateful_table.set_filtered_items(vec![(1, "this"), (2, "is"), (3, "a")]);
+    stateful_table.toggle_visual_select(row_id);
+    stateful_table.scroll_down();
+
+    stateful_table.apply_sorting();
+
+    assert_none!(stateful_table.visual_select_anchor);
+    assert_eq!(stateful_table.selected_ids, HashSet::from([1, 2]));
+    assert!(!stateful_table.is_row_selected(0));
+    assert!(stateful_table.is_row_selected(1));
+    assert!(stateful_table.is_row_selected(2));
+  }
+
+  #[test]
+  fn test_stateful_table_reset_filter_clears_visual_select_anchor() {
+    let mut stateful_table = create_test_selectable_stateful_table();
+    stateful_table.set_filtered_items(vec![(1, "this"), (2, "is")]);
+    stateful_table.toggle_visual_select(row_id);
+
+    stateful_table.reset_filter();
+
+    assert_none!(stateful_table.visual_select_anchor);
+  }
+
+  #[test]
+  fn test_stateful_table_clear_multi_selection() {
+    let mut stateful_table = create_test_selectable_stateful_table();
+    stateful_table.toggle_current_selection(row_id);
+    stateful_table.toggle_visual_select(row_id);
+
+    stateful_table.clear_multi_selection();
+
+    assert_is_empty!(stateful_table.selected_ids);
+    assert_none!(stateful_table.visual_select_anchor);
+    assert!(!stateful_table.has_multi_selection());
+  }
+
+  fn row_id(row: &(i64, &str)) -> i64 {
+    row.0
+  }
+
+  fn create_test_selectable_stateful_table() -> StatefulTable<(i64, &'static str)> {
+    let mut stateful_table = StatefulTable::default();
+    stateful_table.set_items(vec![(1, "this"), (2, "is"), (3, "a"), (4, "test")]);
+
+    stateful_table
+  }
+
   fn create_test_stateful_table() -> StatefulTable<&'static str> {
     let mut stateful_table = StatefulTable::default();
     stateful_table.set_items(vec!["Test 1", "Test 2"]);
@@ -0,0 +1,253 @@
+use bimap::BiMap;
+use serde_json::{Number, Value};
+
+use crate::models::lidarr_models::Artist;
+use crate::models::radarr_models::Movie;
+use crate::models::sonarr_models::Series;
+use crate::utils::{convert_f64_to_gb, convert_runtime, convert_to_gb};
+
+#[cfg(test)]
+#[path = "table_columns_tests.rs"]
+mod table_columns_tests;
+
+/// A column that's rendered both by a TUI table and by the CLI when using `--output table` or
+/// `--output csv`. The CLI renders each cell from the JSON representation of a record.
+#[derive(Debug, Clone, Copy)]
+pub struct TableColumn {
+  pub header: &'static str,
+  pub value: fn(&Value) -> String,
+}
+
+impl TableColumn {
+  const fn new(header: &'static str, value: fn(&Value) -> String) -> Self {
+    TableColumn { header, value }
+  }
+}
+
+pub fn table_headers<const N: usize>(columns: &[TableColumn; N]) -> [&'static str; N] {
+  columns.map(|column| column.header)
+}
+
+pub const MOVIE_TABLE_HEADERS: [&str; 10] = [
+  "Title",
+  "Year",
+  "Studio",
+  "Runtime",
+  "Rating",
+  "Language",
+  "Size",
+  "Quality Profile",
+  "Monitored",
+  "Tags",
+];
+
+pub const SERIES_TABLE_HEADERS: [&str; 11] = [
+  "Title",
+  "Year",
+  "Network",
+  "Status",
+  "Rating",
+  "Type",
+  "Quality Profile",
+  "Language",
+  "Size",
+  "Monitored",
+  "Tags",
+];
+
+pub const ARTIST_TABLE_HEADERS: [&str; 10] = [
+  "Name",
+  "Type",
+  "Status",
+  "Quality Profile",
+  "Metadata Profile",
+  "Albums",
+  "Tracks",
+  "Size",
+  "Monitored",
+  "Tags",
+];
+
+/// The cells of a movie in the Radarr library table, with profile and tag IDs resolved to their
+/// names
+pub fn movie_row(
+  movie: &Movie,
+  quality_profile_map: &BiMap<i64, String>,
+  tags_map: &BiMap<i64, String>,
+) -> [String; 10] {
+  let (hours, minutes) = convert_runtime(movie.runtime);
+
+  [
+    movie.title.to_string(),
+    movie.year.to_string(),
+    movie.studio.clone().unwrap_or_default(),
+    format!("{hours}h {minutes}m"),
+    movie.certification.clone().unwrap_or_default(),
+    movie.original_language.name.to_owned(),
+    format!("{:.2} GB", convert_to_gb(movie.size_on_disk)),
+    name(quality_profile_map, movie.quality_profile_id),
+    monitored(movie.monitored),
+    tag_names(&movie.tags, tags_map),
+  ]
+}
+
+/// The cells of a series in the Sonarr library table, with profile and tag IDs resolved to their
+/// names
+pub fn series_row(
+  series: &Series,
+  quality_profile_map: &BiMap<i64, String>,
+  language_profile_map: &BiMap<i64, String>,
+  tags_map: &BiMap<i64, String>,
+) -> [String; 11] {
+  let size = series
+    .statistics
+    .as_ref()
+    .map_or(0f64, |stats| convert_to_gb(stats.size_on_disk));
+
+  [
+    series.title.to_string(),
+    series.year.to_string(),
+    series.network.clone().unwrap_or_default(),
+    series.status.to_display_str().to_owned(),
+    series.certification.clone().unwrap_or_default(),
+    series.series_type.to_display_str().to_owned(),
+    name(quality_profile_map, series.quality_profile_id),
+    name(language_profile_map, series.language_profile_id),
+    format!("{size:.2} GB"),
+    monitored(series.monitored),
+    tag_names(&series.tags, tags_map),
+  ]
+}
+
+/// The cells of an artist in the Lidarr library table, with profile and tag IDs resolved to their
+/// names
+pub fn artist_row(
+  artist: &Artist,
+  quality_profile_map: &BiMap<i64, String>,
+  metadata_profile_map: &BiMap<i64, String>,
+  tags_map: &BiMap<i64, String>,
+) -> [String; 10] {
+  let size = artist
+    .statistics
+    .as_ref()
+    .map_or(0f64, |stats| convert_to_gb(stats.size_on_disk));
+  let albums = artist
+    .statistics
+    .as_ref()
+    .map_or(0, |stats| stats.album_count);
+  let tracks = artist.statistics.as_ref().map_or(String::new(), |stats| {
+    format!("{}/{}", stats.track_file_count, stats.total_track_count)
+  });
+
+  [
+    artist.artist_name.to_string(),
+    artist.artist_type.clone().unwrap_or_default(),
+    artist.status.to_display_str().to_owned(),
+    name(quality_profile_map, artist.quality_profile_id),
+    name(metadata_profile_map, artist.metadata_profile_id),
+    albums.to_string(),
+    tracks,
+    format!("{size:.2} GB"),
+    monitored(artist.monitored),
+    tag_names(&artist.tags, tags_map),
+  ]
+}
+
+pub const DOWNLOADS_TABLE_COLUMNS: [TableColumn; 8] = [
+  TableColumn::new("Title", |download| text(&download["title"])),
+  TableColumn::new("Status", |download| text(&download["status"])),
+  TableColumn::new("Percent Complete", |download| {
+    let size = download["size"].as_f64().unwrap_or_default();
+    let sizeleft = download["sizeleft"].as_f64().unwrap_or_default();
+    let percent = if size == 0.0 {
+      0.0
+    } else {
+      1.0 - (sizeleft / size)
+    };
+
+    format!("{:.0}%", percent * 100.0)
+  }),
+  TableColumn::new("Size", |download| gigabytes(&download["size"])),
+  TableColumn::new("ETA", |download| text(&download["estimatedCompletionTime"])),
+  TableColumn::new("Output Path", |download| text(&download["outputPath"])),
+  TableColumn::new("Indexer", |download| text(&download["indexer"])),
+  TableColumn::new("Download Client", |download| {
+    text(&download["downloadClient"])
+  }),
+];
+
+pub const HISTORY_TABLE_COLUMNS: [TableColumn; 5] = [
+  TableColumn::new("Source Title", |history_item| {
+    text(&history_item["sourceTitle"])
+  }),
+  TableColumn::new("Event Type", |history_item| {
+    text(&history_item["eventType"])
+  }),
+  TableColumn::new("Language", |history_item| {
+    join(&history_item["languages"], |language| {
+      text(&language["name"])
+    })
+  }),
+  TableColumn::new("Quality", |history_item| {
+    text(&history_item["quality"]["quality"]["name"])
+  }),
+  TableColumn::new("Date", |history_item| text(&history_item["date"])),
+];
+
+pub const LIDARR_HISTORY_TABLE_COLUMNS: [TableColumn; 4] = [
+  HISTORY_TABLE_COLUMNS[0],
+  HISTORY_TABLE_COLUMNS[1],
+  HISTORY_TABLE_COLUMNS[3],
+  HISTORY_TABLE_COLUMNS[4],
+];
+
+/// Renders a JSON value as a table cell; strings are unquoted, `null`s are empty, and nested
+/// values are rendered as compact JSON
+pub fn text(value: &Value) -> String {
+  match value {
+    Value::Null => String::new(),
+    Value::String(string) => string.to_owned(),
+    _ => value.to_string(),
+  }
+}
+
+fn name(map: &BiMap<i64, String>, id: i64) -> String {
+  map.get_by_left(&id).cloned().unwrap_or_default()
+}
+
+fn monitored(monitored: bool) -> String {
+  let icon = if monitored { "🏷" } else { "" };
+  icon.to_owned()
+}
+
+fn tag_names(tags: &[Number], tags_map: &BiMap<i64, String>) -> String {
+  tags
+    .iter()
+    .filter_map(|tag_id| {
+      let id = tag_id.as_i64()?;
+      tags_map.get_by_left(&id).cloned()
+    })
+    .collect::<Vec<_>>()
+    .join(", ")
+}
+
+fn gigabytes(value: &Value) -> String {
+  format!(
+    "{:.2} GB",
+    convert_f64_to_gb(value.as_f64().unwrap_or_default())
+  )
+}
+
+fn join(value: &Value, item_text: fn(&Value) -> String) -> String {
+  value
+    .as_array()
+    .map(|items| {
+      items
+        .iter()
+        .filter(|item| !item.is_null())
+        .map(item_text)
+        .collect::<Vec<_>>()
+        .join(", ")
+    })
+    .unwrap_or_default()
+}
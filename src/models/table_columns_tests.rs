@@ -0,0 +1,249 @@
+#[cfg(test)]
+mod tests {
+  use bimap::BiMap;
+  use pretty_assertions::assert_eq;
+  use serde_json::{Number, Value, json};
+
+  use crate::models::lidarr_models::{Artist, ArtistStatistics, ArtistStatus};
+  use crate::models::radarr_models::Movie;
+  use crate::models::servarr_models::Language;
+  use crate::models::sonarr_models::{Series, SeriesStatistics, SeriesStatus, SeriesType};
+  use crate::models::table_columns::{
+    DOWNLOADS_TABLE_COLUMNS, HISTORY_TABLE_COLUMNS, LIDARR_HISTORY_TABLE_COLUMNS, TableColumn,
+    artist_row, movie_row, series_row, table_headers, text,
+  };
+
+  fn quality_profile_map() -> BiMap<i64, String> {
+    BiMap::from_iter([(2222, "HD - 1080p".to_owned())])
+  }
+
+  fn tags_map() -> BiMap<i64, String> {
+    BiMap::from_iter([(1, "alex".to_owned()), (2, "testing".to_owned())])
+  }
+
+  fn render(columns: &[TableColumn], record: Value) -> Vec<String> {
+    columns
+      .iter()
+      .map(|column| (column.value)(&record))
+      .collect()
+  }
+
+  #[test]
+  fn test_table_headers() {
+    assert_eq!(
+      table_headers(&LIDARR_HISTORY_TABLE_COLUMNS),
+      ["Source Title", "Event Type", "Quality", "Date"]
+    );
+  }
+
+  #[test]
+  fn test_text() {
+    assert_eq!(text(&Value::Null), "");
+    assert_eq!(text(&json!("test")), "test");
+    assert_eq!(text(&json!(1)), "1");
+    assert_eq!(text(&json!({ "id": 1 })), "{\"id\":1}");
+  }
+
+  #[test]
+  fn test_movie_row() {
+    let movie = Movie {
+      title: "Test".into(),
+      year: 2023,
+      studio: Some("21st Century Alex".to_owned()),
+      runtime: 120,
+      certification: Some("R".to_owned()),
+      original_language: Language {
+        id: 1,
+        name: "English".to_owned(),
+      },
+      size_on_disk: 3543348019,
+      quality_profile_id: 2222,
+      monitored: true,
+      tags: vec![Number::from(1), Number::from(3)],
+      ..Movie::default()
+    };
+
+    assert_eq!(
+      movie_row(&movie, &quality_profile_map(), &tags_map()),
+      [
+        "Test",
+        "2023",
+        "21st Century Alex",
+        "2h 0m",
+        "R",
+        "English",
+        "3.30 GB",
+        "HD - 1080p",
+        "🏷",
+        "alex"
+      ]
+    );
+  }
+
+  #[test]
+  fn test_series_row() {
+    let series = Series {
+      title: "Test".into(),
+      year: 2022,
+      network: Some("HBO".to_owned()),
+      status: SeriesStatus::Continuing,
+      certification: Some("TV-MA".to_owned()),
+      series_type: SeriesType::Standard,
+      quality_profile_id: 2222,
+      language_profile_id: 1,
+      statistics: Some(SeriesStatistics {
+        size_on_disk: 63894022699,
+        ..SeriesStatistics::default()
+      }),
+      monitored: false,
+      tags: vec![Number::from(2)],
+      ..Series::default()
+    };
+    let language_profile_map = BiMap::from_iter([(1, "English".to_owned())]);
+
+    assert_eq!(
+      series_row(
+        &series,
+        &quality_profile_map(),
+        &language_profile_map,
+        &tags_map()
+      ),
+      [
+        "Test",
+        "2022",
+        "HBO",
+        "Continuing",
+        "TV-MA",
+        "Standard",
+        "HD - 1080p",
+        "English",
+        "59.51 GB",
+        "",
+        "testing"
+      ]
+    );
+  }
+
+  #[test]
+  fn test_artist_row() {
+    let artist = Artist {
+      artist_name: "Alex".into(),
+      artist_type: Some("Person".to_owned()),
+      status: ArtistStatus::Continuing,
+      quality_profile_id: 2222,
+      metadata_profile_id: 2,
+      statistics: Some(ArtistStatistics {
+        album_count: 3,
+        track_file_count: 15,
+        total_track_count: 20,
+        size_on_disk: 1073741824,
+        ..ArtistStatistics::default()
+      }),
+      monitored: true,
+      tags: vec![Number::from(1), Number::from(2)],
+      ..Artist::default()
+    };
+    let metadata_profile_map = BiMap::from_iter([(2, "Standard".to_owned())]);
+
+    assert_eq!(
+      artist_row(
+        &artist,
+        &quality_profile_map(),
+        &metadata_profile_map,
+        &tags_map()
+      ),
+      [
+        "Alex",
+        "Person",
+        "Continuing",
+        "HD - 1080p",
+        "Standard",
+        "3",
+        "15/20",
+        "1.00 GB",
+        "🏷",
+        "alex, testing"
+      ]
+    );
+  }
+
+  #[test]
+  fn test_artist_row_without_statistics_or_lookups() {
+    let row = artist_row(
+      &Artist::default(),
+      &BiMap::new(),
+      &BiMap::new(),
+      &BiMap::new(),
+    );
+
+    assert_eq!(row[3], "");
+    assert_eq!(row[4], "");
+    assert_eq!(row[5], "0");
+    assert_eq!(row[6], "");
+    assert_eq!(row[7], "0.00 GB");
+  }
+
+  #[test]
+  fn test_downloads_table_columns() {
+    let download = json!({
+      "title": "Test Download",
+      "status": "downloading",
+      "size": 3543348019u64,
+      "sizeleft": 1771674009,
+      "estimatedCompletionTime": "2023-02-25T20:16:43Z",
+      "outputPath": "/nfs/movies/Test",
+      "indexer": "kickass torrents",
+      "downloadClient": "transmission"
+    });
+
+    assert_eq!(
+      render(&DOWNLOADS_TABLE_COLUMNS, download),
+      [
+        "Test Download",
+        "downloading",
+        "50%",
+        "3.30 GB",
+        "2023-02-25T20:16:43Z",
+        "/nfs/movies/Test",
+        "kickass torrents",
+        "transmission"
+      ]
+    );
+  }
+
+  #[test]
+  fn test_downloads_table_columns_zero_size() {
+    let row = render(
+      &DOWNLOADS_TABLE_COLUMNS,
+      json!({ "size": 0, "sizeleft": 0 }),
+    );
+
+    assert_eq!(row[2], "0%");
+  }
+
+  #[test]
+  fn test_history_table_columns() {
+    let history_item = json!({
+      "sourceTitle": "Test",
+      "eventType": "grabbed",
+      "languages": [{ "id": 1, "name": "English" }, null, { "id": 2, "name": "Spanish" }],
+      "quality": { "quality": { "name": "HD - 1080p" } },
+      "date": "2022-12-30T07:37:56Z"
+    });
+
+    assert_eq!(
+      render(&HISTORY_TABLE_COLUMNS, history_item.clone()),
+      [
+        "Test",
+        "grabbed",
+        "English, Spanish",
+        "HD - 1080p",
+        "2022-12-30T07:37:56Z"
+      ]
+    );
+    assert_eq!(
+      render(&LIDARR_HISTORY_TABLE_COLUMNS, history_item),
+      ["Test", "grabbed", "HD - 1080p", "2022-12-30T07:37:56Z"]
+    );
+  }
+}
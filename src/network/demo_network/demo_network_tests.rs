@@ -0,0 +1,825 @@
+#[cfg(test)]
+mod tests {
+  use std::sync::Arc;
+
+  use chrono::NaiveDate;
+  use pretty_assertions::{assert_eq, assert_str_eq};
+  use rstest::rstest;
+  use serde_json::{Value, json};
+  use tokio::sync::{Mutex, mpsc};
+  use tokio_util::sync::CancellationToken;
+
+  use crate::app::App;
+  use crate::models::TabRoute;
+  use crate::models::lidarr_models::{AddArtistBody, DeleteParams};
+  use crate::models::prowlarr_models::{ProwlarrSearchParams, ProwlarrTaskName};
+  use crate::models::radarr_models::{AddMovieBody, BulkEditMoviesParams, DeleteMovieParams};
+  use crate::models::readarr_models::{AddAuthorBody, DeleteParams as ReadarrDeleteParams};
+  use crate::models::servarr_data::prowlarr::prowlarr_data::ActiveProwlarrBlock;
+  use crate::models::servarr_data::readarr::readarr_data::ActiveReadarrBlock;
+  use crate::models::servarr_models::{BulkDeleteDownloadsParams, DownloadBackupParams};
+  use crate::models::sonarr_models::{AddSeriesBody, DeleteSeriesParams};
+  use crate::network::demo_network::{DemoBackend, demo_config};
+  use crate::network::lidarr_network::LidarrEvent;
+  use crate::network::prowlarr_network::ProwlarrEvent;
+  use crate::network::radarr_network::RadarrEvent;
+  use crate::network::readarr_network::ReadarrEvent;
+  use crate::network::sonarr_network::SonarrEvent;
+  use crate::network::{
+    Network, NetworkEvent, NetworkResource, NetworkTrait, QueuedNetworkEvent, RequestMethod,
+  };
+  use crate::utils::try_build_network_clients;
+
+  const RADARR_URI: &str = "http://radarr.demo/api/v3";
+
+  fn demo_app(tab_index: usize) -> Arc<Mutex<App<'static>>> {
+    let config = demo_config();
+    let mut app = App::test_default();
+    app.server_tabs.tabs[0].config = Some(config.radarr.unwrap()[0].clone());
+    app.server_tabs.tabs[1].config = Some(config.sonarr.unwrap()[0].clone());
+    app.server_tabs.tabs[2].config = Some(config.lidarr.unwrap()[0].clone());
+    app.server_tabs.tabs.push(TabRoute {
+      title: "Prowlarr".to_owned(),
+      route: ActiveProwlarrBlock::Indexers.into(),
+      contextual_help: None,
+      config: Some(config.prowlarr.unwrap()[0].clone()),
+    });
+    app.server_tabs.tabs.push(TabRoute {
+      title: "Readarr".to_owned(),
+      route: ActiveReadarrBlock::Authors.into(),
+      contextual_help: None,
+      config: Some(config.readarr.unwrap()[0].clone()),
+    });
+    for _ in 0..tab_index {
+      app.server_tabs.next();
+    }
+    app.network_clients = try_build_network_clients(&app.server_tabs.tabs).unwrap();
+
+    Arc::new(Mutex::new(app))
+  }
+
+  async fn handle_events(app: &Arc<Mutex<App<'static>>>, events: Vec<NetworkEvent>) {
+    let mut network = Network::demo(app, CancellationToken::new());
+
+    for event in events {
+      let result = network.handle_network_event(event.clone()).await;
+
+      assert!(result.is_ok(), "{event:?} failed: {result:?}");
+    }
+  }
+
+  #[test]
+  fn test_demo_config() {
+    let config = demo_config();
+
+    assert_str_eq!(
+      config.radarr.as_ref().unwrap()[0].uri.as_ref().unwrap(),
+      "http://radarr.demo"
+    );
+    assert_str_eq!(
+      config.sonarr.as_ref().unwrap()[0].uri.as_ref().unwrap(),
+      "http://sonarr.demo"
+    );
+    assert_str_eq!(
+      config.lidarr.as_ref().unwrap()[0].uri.as_ref().unwrap(),
+      "http://lidarr.demo"
+    );
+    assert_str_eq!(
+      config.prowlarr.as_ref().unwrap()[0].uri.as_ref().unwrap(),
+      "http://prowlarr.demo"
+    );
+    assert_str_eq!(
+      config.readarr.as_ref().unwrap()[0].uri.as_ref().unwrap(),
+      "http://readarr.demo"
+    );
+    assert!(config.try_validate("").is_ok());
+  }
+
+  #[tokio::test]
+  async fn test_demo_radarr_events() {
+    let app = demo_app(0);
+    let today = NaiveDate::from_ymd_opt(2025, 6, 10).unwrap();
+
+    handle_events(
+      &app,
+      vec![
+        RadarrEvent::GetMovies.into(),
+        RadarrEvent::GetMovieDetails(1).into(),
+        RadarrEvent::GetMovieCredits(1).into(),
+        RadarrEvent::GetMovieHistory(1).into(),
+        RadarrEvent::GetReleases(1).into(),
+        RadarrEvent::GetCollections.into(),
+        RadarrEvent::GetDownloads(500).into(),
+        RadarrEvent::GetHistory(500).into(),
+        RadarrEvent::GetBlocklist.into(),
+        RadarrEvent::GetCalendar(today, today).into(),
+        RadarrEvent::GetWantedMissing(500).into(),
+        RadarrEvent::GetCutoffUnmet(500).into(),
+        RadarrEvent::GetRootFolders.into(),
+        RadarrEvent::GetIndexers.into(),
+        RadarrEvent::GetAllIndexerSettings.into(),
+        RadarrEvent::GetIndexerSchema.into(),
+        RadarrEvent::GetDownloadClients.into(),
+        RadarrEvent::GetDownloadClientSchema.into(),
+        RadarrEvent::GetHostConfig.into(),
+        RadarrEvent::GetSecurityConfig.into(),
+        RadarrEvent::GetQualityProfiles.into(),
+        RadarrEvent::GetTags.into(),
+        RadarrEvent::GetDiskSpace.into(),
+        RadarrEvent::GetStatus.into(),
+        RadarrEvent::GetTasks.into(),
+        RadarrEvent::GetQueuedEvents.into(),
+        RadarrEvent::GetLogs(500).into(),
+        RadarrEvent::GetUpdates.into(),
+        RadarrEvent::GetBackups.into(),
+        RadarrEvent::HealthCheck.into(),
+        RadarrEvent::SearchNewMovie("caligari".to_owned()).into(),
+      ],
+    )
+    .await;
+
+    let app = app.lock().await;
+    let radarr_data = &app.data.radarr_data;
+    assert_eq!(radarr_data.movies.items.len(), 6);
+    assert_eq!(radarr_data.downloads.items.len(), 3);
+    assert_eq!(radarr_data.collections.items.len(), 1);
+    assert!(!radarr_data.history.items.is_empty());
+    assert!(!radarr_data.blocklist.items.is_empty());
+    assert!(!radarr_data.indexers.items.is_empty());
+    assert_eq!(radarr_data.indexer_schema.items.len(), 2);
+    assert_eq!(radarr_data.download_clients.items.len(), 2);
+    assert_eq!(radarr_data.download_client_schema.items.len(), 2);
+    assert!(!radarr_data.root_folders.items.is_empty());
+    assert!(!radarr_data.logs.items.is_empty());
+    assert!(!radarr_data.tasks.items.is_empty());
+    assert_is_empty!(app.error.text);
+  }
+
+  #[tokio::test]
+  async fn test_demo_sonarr_events() {
+    let app = demo_app(1);
+    let today = NaiveDate::from_ymd_opt(2025, 6, 10).unwrap();
+
+    handle_events(
+      &app,
+      vec![
+        SonarrEvent::ListSeries.into(),
+        SonarrEvent::GetSeriesDetails(1).into(),
+        SonarrEvent::GetSeriesHistory(1).into(),
+        SonarrEvent::GetEpisodes(1).into(),
+        SonarrEvent::GetEpisodeFiles(1).into(),
+        SonarrEvent::GetEpisodeDetails(1).into(),
+        SonarrEvent::GetEpisodeHistory(1).into(),
+        SonarrEvent::GetEpisodeReleases(1).into(),
+        SonarrEvent::GetSeasonHistory(1, 1).into(),
+        SonarrEvent::GetSeasonReleases(1, 1).into(),
+        SonarrEvent::GetDownloads(500).into(),
+        SonarrEvent::GetHistory(500).into(),
+        SonarrEvent::GetBlocklist.into(),
+        SonarrEvent::GetCalendar(today, today).into(),
+        SonarrEvent::GetWantedMissing(500).into(),
+        SonarrEvent::GetCutoffUnmet(500).into(),
+        SonarrEvent::GetRootFolders.into(),
+        SonarrEvent::GetIndexers.into(),
+        SonarrEvent::GetAllIndexerSettings.into(),
+        SonarrEvent::GetIndexerSchema.into(),
+        SonarrEvent::GetDownloadClients.into(),
+        SonarrEvent::GetDownloadClientSchema.into(),
+        SonarrEvent::GetHostConfig.into(),
+        SonarrEvent::GetSecurityConfig.into(),
+        SonarrEvent::GetQualityProfiles.into(),
+        SonarrEvent::GetLanguageProfiles.into(),
+        SonarrEvent::GetTags.into(),
+        SonarrEvent::GetDiskSpace.into(),
+        SonarrEvent::GetStatus.into(),
+        SonarrEvent::GetTasks.into(),
+        SonarrEvent::GetQueuedEvents.into(),
+        SonarrEvent::GetLogs(500).into(),
+        SonarrEvent::GetUpdates.into(),
+        SonarrEvent::GetBackups.into(),
+        SonarrEvent::HealthCheck.into(),
+        SonarrEvent::SearchNewSeries("arcade".to_owned()).into(),
+      ],
+    )
+    .await;
+
+    let app = app.lock().await;
+    let sonarr_data = &app.data.sonarr_data;
+    assert_eq!(sonarr_data.series.items.len(), 3);
+    assert_eq!(sonarr_data.downloads.items.len(), 2);
+    assert!(!sonarr_data.history.items.is_empty());
+    assert!(!sonarr_data.blocklist.items.is_empty());
+    assert!(!sonarr_data.indexers.items.is_empty());
+    assert_eq!(sonarr_data.indexer_schema.items.len(), 2);
+    assert_eq!(sonarr_data.download_clients.items.len(), 2);
+    assert_eq!(sonarr_data.download_client_schema.items.len(), 2);
+    assert!(!sonarr_data.logs.items.is_empty());
+    assert_is_empty!(app.error.text);
+  }
+
+  #[tokio::test]
+  async fn test_demo_lidarr_events() {
+    let app = demo_app(2);
+    let today = NaiveDate::from_ymd_opt(2025, 6, 10).unwrap();
+
+    handle_events(
+      &app,
+      vec![
+        LidarrEvent::ListArtists.into(),
+        LidarrEvent::GetArtistDetails(1).into(),
+        LidarrEvent::GetArtistHistory(1).into(),
+        LidarrEvent::GetAlbums(1).into(),
+        LidarrEvent::GetAlbumDetails(1).into(),
+        LidarrEvent::GetAlbumHistory(1, 1).into(),
+        LidarrEvent::GetAlbumReleases(1, 1).into(),
+        LidarrEvent::GetDiscographyReleases(1).into(),
+        LidarrEvent::GetTracks(1, 1).into(),
+        LidarrEvent::GetTrackDetails(1).into(),
+        LidarrEvent::GetTrackFiles(1).into(),
+        LidarrEvent::GetTrackHistory(1, 1, 1).into(),
+        LidarrEvent::GetDownloads(500).into(),
+        LidarrEvent::GetHistory(500).into(),
+        LidarrEvent::GetBlocklist.into(),
+        LidarrEvent::GetCalendar(today, today).into(),
+        LidarrEvent::GetWantedMissing(500).into(),
+        LidarrEvent::GetCutoffUnmet(500).into(),
+        LidarrEvent::GetRootFolders.into(),
+        LidarrEvent::GetIndexers.into(),
+        LidarrEvent::GetAllIndexerSettings.into(),
+        LidarrEvent::GetIndexerSchema.into(),
+        LidarrEvent::GetDownloadClients.into(),
+        LidarrEvent::GetDownloadClientSchema.into(),
+        LidarrEvent::GetHostConfig.into(),
+        LidarrEvent::GetSecurityConfig.into(),
+        LidarrEvent::GetQualityProfiles.into(),
+        LidarrEvent::GetMetadataProfiles.into(),
+        LidarrEvent::GetTags.into(),
+        LidarrEvent::GetDiskSpace.into(),
+        LidarrEvent::GetStatus.into(),
+        LidarrEvent::GetTasks.into(),
+        LidarrEvent::GetQueuedEvents.into(),
+        LidarrEvent::GetLogs(500).into(),
+        LidarrEvent::GetUpdates.into(),
+        LidarrEvent::GetBackups.into(),
+        LidarrEvent::HealthCheck.into(),
+        LidarrEvent::SearchNewArtist("chopin".to_owned()).into(),
+      ],
+    )
+    .await;
+
+    let app = app.lock().await;
+    let lidarr_data = &app.data.lidarr_data;
+    assert_eq!(lidarr_data.artists.items.len(), 3);
+    assert_eq!(lidarr_data.downloads.items.len(), 1);
+    assert!(!lidarr_data.history.items.is_empty());
+    assert!(!lidarr_data.indexers.items.is_empty());
+    assert_eq!(lidarr_data.indexer_schema.items.len(), 2);
+    assert_eq!(lidarr_data.download_clients.items.len(), 2);
+    assert_eq!(lidarr_data.download_client_schema.items.len(), 2);
+    assert_is_empty!(app.error.text);
+  }
+
+  #[tokio::test]
+  async fn test_demo_prowlarr_events() {
+    let app = demo_app(3);
+
+    handle_events(
+      &app,
+      vec![
+        ProwlarrEvent::GetIndexers.into(),
+        ProwlarrEvent::GetIndexerSchema.into(),
+        ProwlarrEvent::GetApplications.into(),
+        ProwlarrEvent::GetIndexerProxies.into(),
+        ProwlarrEvent::GetHistory(500).into(),
+        ProwlarrEvent::GetHostConfig.into(),
+        ProwlarrEvent::GetSecurityConfig.into(),
+        ProwlarrEvent::GetTags.into(),
+        ProwlarrEvent::GetStatus.into(),
+        ProwlarrEvent::GetTasks.into(),
+        ProwlarrEvent::GetQueuedEvents.into(),
+        ProwlarrEvent::GetLogs(500).into(),
+        ProwlarrEvent::GetUpdates.into(),
+        ProwlarrEvent::HealthCheck.into(),
+        ProwlarrEvent::SearchReleases(ProwlarrSearchParams {
+          query: "caligari".to_owned(),
+          indexer_ids: vec![1, 2],
+          categories: vec![2000],
+          limit: Some(100),
+        })
+        .into(),
+      ],
+    )
+    .await;
+
+    let app = app.lock().await;
+    let prowlarr_data = &app.data.prowlarr_data;
+    assert_eq!(prowlarr_data.indexers.items.len(), 3);
+    assert_eq!(prowlarr_data.indexer_schema.items.len(), 2);
+    assert_eq!(prowlarr_data.applications.items.len(), 3);
+    assert_eq!(prowlarr_data.indexer_proxies.items.len(), 1);
+    assert_eq!(prowlarr_data.search_results.items.len(), 2);
+    assert!(!prowlarr_data.history.items.is_empty());
+    assert!(!prowlarr_data.logs.items.is_empty());
+    assert!(!prowlarr_data.tasks.items.is_empty());
+    assert!(!prowlarr_data.queued_events.items.is_empty());
+    assert!(!prowlarr_data.tags_map.is_empty());
+    assert_str_eq!(prowlarr_data.version, "1.37.0.5076");
+    assert_is_empty!(app.error.text);
+  }
+
+  #[tokio::test]
+  async fn test_demo_readarr_events() {
+    let app = demo_app(4);
+
+    handle_events(
+      &app,
+      vec![
+        ReadarrEvent::ListAuthors.into(),
+        ReadarrEvent::GetAuthorDetails(1).into(),
+        ReadarrEvent::GetBooks(1).into(),
+        ReadarrEvent::GetBookDetails(1).into(),
+        ReadarrEvent::GetEditions(1).into(),
+        ReadarrEvent::GetIndexerSchema.into(),
+        ReadarrEvent::GetRootFolders.into(),
+        ReadarrEvent::GetHostConfig.into(),
+        ReadarrEvent::GetSecurityConfig.into(),
+        ReadarrEvent::GetQualityProfiles.into(),
+        ReadarrEvent::GetMetadataProfiles.into(),
+        ReadarrEvent::GetTags.into(),
+        ReadarrEvent::GetDiskSpace.into(),
+        ReadarrEvent::GetStatus.into(),
+        ReadarrEvent::GetTasks.into(),
+        ReadarrEvent::GetQueuedEvents.into(),
+        ReadarrEvent::GetLogs(500).into(),
+        ReadarrEvent::GetUpdates.into(),
+        ReadarrEvent::HealthCheck.into(),
+        ReadarrEvent::SearchNewAuthor("verne".to_owned()).into(),
+      ],
+    )
+    .await;
+
+    let app = app.lock().await;
+    let readarr_data = &app.data.readarr_data;
+    assert_eq!(readarr_data.authors.items.len(), 3);
+    assert_eq!(readarr_data.books.items.len(), 3);
+    assert!(!readarr_data.root_folders.items.is_empty());
+    assert!(!readarr_data.logs.items.is_empty());
+    assert!(!readarr_data.tasks.items.is_empty());
+    assert!(!readarr_data.queued_events.items.is_empty());
+    assert!(!readarr_data.tags_map.is_empty());
+    assert!(!readarr_data.quality_profile_map.is_empty());
+    assert!(!readarr_data.metadata_profile_map.is_empty());
+    assert_str_eq!(readarr_data.version, "0.4.18.2805");
+    assert_is_empty!(app.error.text);
+  }
+
+  #[tokio::test]
+  async fn test_demo_readarr_mutations() {
+    let app = demo_app(4);
+
+    handle_events(
+      &app,
+      vec![
+        ReadarrEvent::AddAuthor(AddAuthorBody {
+          foreign_author_id: "demo-author-lookup-1".to_owned(),
+          author_name: "Jules Verne".to_owned(),
+          root_folder_path: "/media/books".to_owned(),
+          quality_profile_id: 1,
+          metadata_profile_id: 1,
+          ..AddAuthorBody::default()
+        })
+        .into(),
+        ReadarrEvent::DeleteAuthor(ReadarrDeleteParams {
+          id: 1,
+          ..ReadarrDeleteParams::default()
+        })
+        .into(),
+        ReadarrEvent::ListAuthors.into(),
+      ],
+    )
+    .await;
+
+    let app = app.lock().await;
+    let authors = &app.data.readarr_data.authors.items;
+    assert_eq!(
+      authors.iter().map(|author| author.id).collect::<Vec<_>>(),
+      vec![2, 3, 4]
+    );
+    assert_str_eq!(authors[2].author_name.text, "Jules Verne");
+    assert_eq!(
+      authors[2].overview.as_deref(),
+      Some("French novelist and pioneer of adventure fiction.")
+    );
+  }
+
+  #[tokio::test]
+  async fn test_demo_prowlarr_mutations() {
+    let app = demo_app(3);
+
+    handle_events(
+      &app,
+      vec![
+        ProwlarrEvent::DeleteIndexer(3).into(),
+        ProwlarrEvent::DeleteApplication(1).into(),
+        ProwlarrEvent::DeleteIndexerProxy(1).into(),
+        ProwlarrEvent::TestIndexer(1).into(),
+        ProwlarrEvent::TestAllIndexers.into(),
+        ProwlarrEvent::StartTask(ProwlarrTaskName::ApplicationIndexerSync).into(),
+        ProwlarrEvent::AddTag("anime".to_owned()).into(),
+        ProwlarrEvent::DeleteTag(1).into(),
+        ProwlarrEvent::GetIndexers.into(),
+        ProwlarrEvent::GetApplications.into(),
+        ProwlarrEvent::GetIndexerProxies.into(),
+        ProwlarrEvent::GetTags.into(),
+      ],
+    )
+    .await;
+
+    let app = app.lock().await;
+    let prowlarr_data = &app.data.prowlarr_data;
+    assert_eq!(
+      prowlarr_data
+        .indexers
+        .items
+        .iter()
+        .map(|indexer| indexer.id)
+        .collect::<Vec<_>>(),
+      vec![1, 2]
+    );
+    assert_eq!(
+      prowlarr_data
+        .applications
+        .items
+        .iter()
+        .map(|application| application.id)
+        .collect::<Vec<_>>(),
+      vec![2, 3]
+    );
+    assert_is_empty!(prowlarr_data.indexer_proxies.items);
+    assert_eq!(
+      prowlarr_data.tags_map.get_by_left(&3),
+      Some(&"anime".to_owned())
+    );
+    assert!(prowlarr_data.tags_map.get_by_left(&1).is_none());
+  }
+
+  #[tokio::test]
+  async fn test_demo_radarr_mutations() {
+    let app = demo_app(0);
+
+    handle_events(
+      &app,
+      vec![
+        RadarrEvent::AddMovie(AddMovieBody {
+          tmdb_id: 700,
+          title: "The Cabinet of Dr. Caligari".to_owned(),
+          root_folder_path: "/media/movies".to_owned(),
+          quality_profile_id: 1,
+          monitored: true,
+          ..AddMovieBody::default()
+        })
+        .into(),
+        RadarrEvent::DeleteMovie(DeleteMovieParams {
+          id: 1,
+          ..DeleteMovieParams::default()
+        })
+        .into(),
+        RadarrEvent::ToggleMovieMonitoring(2).into(),
+        RadarrEvent::BulkEditMovies(BulkEditMoviesParams {
+          movie_ids: vec![3, 4],
+          quality_profile_id: Some(3),
+          ..BulkEditMoviesParams::default()
+        })
+        .into(),
+        RadarrEvent::BulkDeleteDownloads(BulkDeleteDownloadsParams {
+          ids: vec![1, 2],
+          ..BulkDeleteDownloadsParams::default()
+        })
+        .into(),
+        RadarrEvent::AddTag("classics".to_owned()).into(),
+        RadarrEvent::DeleteTag(1).into(),
+        RadarrEvent::GetMovies.into(),
+        RadarrEvent::GetDownloads(500).into(),
+        RadarrEvent::GetTags.into(),
+      ],
+    )
+    .await;
+
+    let app = app.lock().await;
+    let radarr_data = &app.data.radarr_data;
+    let movies = &radarr_data.movies.items;
+    assert_eq!(
+      movies.iter().map(|movie| movie.id).collect::<Vec<_>>(),
+      vec![2, 3, 4, 5, 6, 7]
+    );
+    let added_movie = movies.last().unwrap();
+    assert_str_eq!(added_movie.title.text, "The Cabinet of Dr. Caligari");
+    assert_eq!(added_movie.year, 1920);
+    assert!(!added_movie.has_file);
+    assert_eq!(movies[0].monitored, false);
+    assert_eq!(movies[1].quality_profile_id, 3);
+    assert_eq!(movies[2].quality_profile_id, 3);
+    assert_eq!(radarr_data.downloads.items.len(), 1);
+    assert_eq!(
+      radarr_data.tags_map.get_by_left(&3),
+      Some(&"classics".to_owned())
+    );
+    assert!(radarr_data.tags_map.get_by_left(&1).is_none());
+  }
+
+  #[tokio::test]
+  async fn test_demo_sonarr_mutations() {
+    let app = demo_app(1);
+
+    handle_events(
+      &app,
+      vec![
+        SonarrEvent::AddSeries(AddSeriesBody {
+          tvdb_id: 8101,
+          title: "Midnight Arcade".to_owned(),
+          root_folder_path: "/media/tv".to_owned(),
+          quality_profile_id: 1,
+          language_profile_id: 1,
+          ..AddSeriesBody::default()
+        })
+        .into(),
+        SonarrEvent::DeleteSeries(DeleteSeriesParams {
+          id: 3,
+          ..DeleteSeriesParams::default()
+        })
+        .into(),
+        SonarrEvent::ListSeries.into(),
+      ],
+    )
+    .await;
+
+    let app = app.lock().await;
+    let series = &app.data.sonarr_data.series.items;
+    assert_eq!(
+      series.iter().map(|series| series.id).collect::<Vec<_>>(),
+      vec![1, 2, 4]
+    );
+    assert_str_eq!(series[2].title.text, "Midnight Arcade");
+    assert_eq!(series[2].year, 2017);
+  }
+
+  #[tokio::test]
+  async fn test_demo_lidarr_mutations() {
+    let app = demo_app(2);
+
+    handle_events(
+      &app,
+      vec![
+        LidarrEvent::AddArtist(AddArtistBody {
+          foreign_artist_id: "demo-artist-100".to_owned(),
+          artist_name: "Frédéric Chopin".to_owned(),
+          root_folder_path: "/media/music".to_owned(),
+          quality_profile_id: 1,
+          metadata_profile_id: 1,
+          ..AddArtistBody::default()
+        })
+        .into(),
+        LidarrEvent::DeleteArtist(DeleteParams {
+          id: 1,
+          ..DeleteParams::default()
+        })
+        .into(),
+        LidarrEvent::ListArtists.into(),
+      ],
+    )
+    .await;
+
+    let app = app.lock().await;
+    let artists = &app.data.lidarr_data.artists.items;
+    assert_eq!(
+      artists.iter().map(|artist| artist.id).collect::<Vec<_>>(),
+      vec![2, 3, 4]
+    );
+    assert_str_eq!(artists[2].artist_name.text, "Frédéric Chopin");
+    assert_eq!(
+      artists[2].overview.as_deref(),
+      Some("Composer and virtuoso pianist of the Romantic period.")
+    );
+  }
+
+  #[tokio::test]
+  async fn test_demo_tabs_initial_events_resolve_to_fixtures() {
+    let (network_tx, mut network_rx) = mpsc::channel::<QueuedNetworkEvent>(500);
+    let mut app = App::new(network_tx, demo_config(), CancellationToken::new()).unwrap();
+    let demo_backend = DemoBackend::default();
+
+    for index in 0..app.server_tabs.tabs.len() {
+      app.reset();
+      let route = app.server_tabs.set_index(index).route;
+      app.pop_and_push_navigation_stack(route);
+      app.on_tick().await;
+
+      let mut dispatched_events = 0;
+      while let Ok(queued_event) = network_rx.try_recv() {
+        dispatched_events += 1;
+        let (servarr, api_version, resource) = match &queued_event.network_event {
+          NetworkEvent::Radarr(event) => ("radarr", "v3", event.resource()),
+          NetworkEvent::Sonarr(event) => ("sonarr", "v3", event.resource()),
+          NetworkEvent::Lidarr(event) => ("lidarr", "v1", event.resource()),
+          NetworkEvent::Prowlarr(event) => ("prowlarr", "v1", event.resource()),
+          NetworkEvent::Readarr(event) => ("readarr", "v1", event.resource()),
+        };
+        let uri = format!("http://{servarr}.demo/api/{api_version}{resource}");
+
+        assert!(
+          demo_backend
+            .handle(RequestMethod::Get, &uri, Value::Null)
+            .is_some(),
+          "{:?} has no demo fixture at {uri}",
+          queued_event.network_event
+        );
+      }
+      assert_ne!(dispatched_events, 0, "{route:?} dispatched no events");
+    }
+  }
+
+  #[tokio::test]
+  async fn test_demo_network_does_not_transfer_files() {
+    let app = demo_app(0);
+    let mut network = Network::demo(&app, CancellationToken::new());
+
+    let result = network
+      .handle_network_event(
+        RadarrEvent::DownloadBackup(DownloadBackupParams {
+          backup_path: "/backup/radarr_backup.zip".to_owned(),
+          destination: "radarr_backup.zip".into(),
+        })
+        .into(),
+      )
+      .await;
+
+    assert!(result.is_err());
+    assert!(!app.lock().await.error.text.is_empty());
+  }
+
+  #[rstest]
+  #[case("/movie/2", json!(2))]
+  #[case("/queue", json!(3))]
+  #[case("/credit?movieId=3", json!(3))]
+  #[case("/queue?movieId=4", json!(1))]
+  #[case("/system/status", json!(null))]
+  fn test_demo_backend_get(#[case] path: &str, #[case] expected: Value) {
+    let demo_backend = DemoBackend::default();
+
+    let response = demo_backend
+      .handle(
+        RequestMethod::Get,
+        &format!("{RADARR_URI}{path}"),
+        Value::Null,
+      )
+      .unwrap();
+
+    let actual = match response {
+      Value::Array(items) => json!(items.len()),
+      Value::Object(ref object) if object.contains_key("records") => {
+        json!(object["records"].as_array().unwrap().len())
+      }
+      Value::Object(object) => object.get("id").cloned().unwrap_or_default(),
+      _ => Value::Null,
+    };
+    assert_eq!(actual, expected);
+  }
+
+  #[rstest]
+  #[case("", 3)]
+  #[case("&categories=2040", 1)]
+  #[case("&categories=2040&categories=2000", 3)]
+  #[case("&categories=5000", 0)]
+  fn test_demo_backend_get_matches_array_fields_by_id(
+    #[case] categories: &str,
+    #[case] expected_len: usize,
+  ) {
+    let demo_backend = DemoBackend::default();
+
+    let response = demo_backend
+      .handle(
+        RequestMethod::Get,
+        &format!("http://prowlarr.demo/api/v1/search?query=caligari&type=search{categories}"),
+        Value::Null,
+      )
+      .unwrap();
+
+    assert_eq!(response.as_array().unwrap().len(), expected_len);
+  }
+
+  #[rstest]
+  fn test_demo_backend_unknown_resources(
+    #[values(
+      "http://radarr.demo/api/v3/unknown",
+      "http://radarr.demo/api/v3/movie/999",
+      "http://prowlarr.demo/api/v1/indexer/999",
+      "http://whisparr.demo/api/v3/movie",
+      "not a uri"
+    )]
+    uri: &str,
+  ) {
+    let demo_backend = DemoBackend::default();
+
+    assert!(
+      demo_backend
+        .handle(RequestMethod::Get, uri, Value::Null)
+        .is_none()
+    );
+  }
+
+  #[test]
+  fn test_demo_backend_post_acknowledges_actions() {
+    let demo_backend = DemoBackend::default();
+    let command = json!({ "name": "RssSync" });
+
+    assert_eq!(
+      demo_backend.handle(
+        RequestMethod::Post,
+        &format!("{RADARR_URI}/command"),
+        command.clone()
+      ),
+      Some(command)
+    );
+    assert!(
+      demo_backend
+        .handle(
+          RequestMethod::Post,
+          &format!("{RADARR_URI}/indexer/testall"),
+          Value::Null
+        )
+        .is_none()
+    );
+    assert_eq!(
+      demo_backend
+        .handle(
+          RequestMethod::Get,
+          &format!("{RADARR_URI}/command"),
+          Value::Null
+        )
+        .unwrap()
+        .as_array()
+        .unwrap()
+        .len(),
+      3
+    );
+  }
+
+  #[test]
+  fn test_demo_backend_put_updates_singletons() {
+    let demo_backend = DemoBackend::default();
+
+    demo_backend.handle(
+      RequestMethod::Put,
+      &format!("{RADARR_URI}/config/indexer"),
+      json!({ "rssSyncInterval": 60, "retention": null }),
+    );
+
+    let indexer_settings = demo_backend
+      .handle(
+        RequestMethod::Get,
+        &format!("{RADARR_URI}/config/indexer"),
+        Value::Null,
+      )
+      .unwrap();
+    assert_eq!(indexer_settings["rssSyncInterval"], json!(60));
+    assert_eq!(indexer_settings["retention"], json!(0));
+  }
+
+  #[test]
+  fn test_demo_backend_get_naming_examples_ignores_naming_query() {
+    let demo_backend = DemoBackend::default();
+
+    let naming_examples = demo_backend
+      .handle(
+        RequestMethod::Get,
+        &format!("{RADARR_URI}/config/naming/examples?id=1&renameMovies=false"),
+        Value::Null,
+      )
+      .unwrap();
+
+    assert_eq!(
+      naming_examples["movieFolderExample"],
+      json!("The Movie Title (2010)")
+    );
+  }
+
+  #[test]
+  fn test_demo_backend_bulk_delete() {
+    let demo_backend = DemoBackend::default();
+
+    demo_backend.handle(
+      RequestMethod::Delete,
+      &format!("{RADARR_URI}/movie/editor"),
+      json!({ "movieIds": [1, 2, 3], "deleteFiles": true }),
+    );
+
+    let movies = demo_backend
+      .handle(
+        RequestMethod::Get,
+        &format!("{RADARR_URI}/movie"),
+        Value::Null,
+      )
+      .unwrap();
+    assert_eq!(movies.as_array().unwrap().len(), 3);
+  }
+}
@@ -0,0 +1,348 @@
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
+
+use anyhow::Result;
+use itertools::Itertools;
+use log::{debug, error};
+use reqwest::Url;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value, json};
+use tokio::sync::MutexGuard;
+
+use crate::app::{App, AppConfig, ServarrConfig};
+use crate::network::servarr_error::ServarrError;
+use crate::network::{Network, RequestMethod, RequestProps};
+
+#[cfg(test)]
+#[path = "demo_network_tests.rs"]
+mod demo_network_tests;
+
+const DEMO_API_TOKEN: &str = "demo";
+
+/// The configuration used by `managarr --demo`. Each Servarr is pointed at a `<servarr>.demo` host
+/// that is never contacted, since every request is answered by the [DemoBackend] instead.
+pub fn demo_config() -> AppConfig {
+  let demo_instance = |servarr: &str| {
+    Some(vec![ServarrConfig {
+      name: Some(format!("{servarr} (demo)")),
+      uri: Some(format!("http://{}.demo", servarr.to_lowercase())),
+      api_token: Some(DEMO_API_TOKEN.to_owned()),
+      ..ServarrConfig::default()
+    }])
+  };
+
+  AppConfig {
+    radarr: demo_instance("Radarr"),
+    sonarr: demo_instance("Sonarr"),
+    lidarr: demo_instance("Lidarr"),
+    prowlarr: demo_instance("Prowlarr"),
+    readarr: demo_instance("Readarr"),
+    ..AppConfig::default()
+  }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct DemoFixtures {
+  resources: Map<String, Value>,
+  templates: Map<String, Value>,
+}
+
+/// An in-memory stand-in for the Servarr APIs, backed by the built-in fixtures. Mutations update
+/// the fixtures, so added, edited, and deleted items are reflected in subsequent requests. Clones
+/// share the same fixtures.
+#[derive(Clone, Debug)]
+pub struct DemoBackend {
+  servarrs: Arc<Mutex<HashMap<String, DemoFixtures>>>,
+}
+
+impl Default for DemoBackend {
+  fn default() -> Self {
+    let fixtures = [
+      ("radarr", include_str!("fixtures/radarr.json")),
+      ("sonarr", include_str!("fixtures/sonarr.json")),
+      ("lidarr", include_str!("fixtures/lidarr.json")),
+      ("prowlarr", include_str!("fixtures/prowlarr.json")),
+      ("readarr", include_str!("fixtures/readarr.json")),
+    ];
+
+    DemoBackend {
+      servarrs: Arc::new(Mutex::new(
+        fixtures
+          .into_iter()
+          .map(|(servarr, fixtures)| {
+            (
+              servarr.to_owned(),
+              serde_json::from_str(fixtures).expect("Demo fixtures are invalid"),
+            )
+          })
+          .collect(),
+      )),
+    }
+  }
+}
+
+impl DemoBackend {
+  /// Answers the request the same way the Servarr would, returning [None] when there is no
+  /// fixture for the requested resource
+  pub fn handle(&self, method: RequestMethod, uri: &str, body: Value) -> Option<Value> {
+    let url = Url::parse(uri).ok()?;
+    let servarr = url.host_str()?.split('.').next()?;
+    let (_, path) = url.path().strip_prefix("/api/")?.split_once('/')?;
+    let path = format!("/{path}");
+    let query: Vec<(String, String)> = url.query_pairs().into_owned().collect();
+    let mut servarrs = self
+      .servarrs
+      .lock()
+      .expect("Demo fixtures mutex is poisoned");
+    let fixtures = servarrs.get_mut(servarr)?;
+
+    match method {
+      RequestMethod::Get => fixtures.get(&path, &query),
+      RequestMethod::Post => fixtures.create(&path, body),
+      RequestMethod::Put => {
+        fixtures.update(&path, &body);
+        None
+      }
+      RequestMethod::Delete => {
+        fixtures.delete(&path, &body);
+        None
+      }
+    }
+  }
+}
+
+impl DemoFixtures {
+  fn get(&self, path: &str, query: &[(String, String)]) -> Option<Value> {
+    if let Some(resource) = self.resources.get(path) {
+      let mut resource = resource.clone();
+      if let Some(items) = items_mut(&mut resource) {
+        let filters = query
+          .iter()
+          .map(|(key, value)| (key, value))
+          .into_group_map();
+        for (key, values) in filters {
+          if items.iter().any(|item| item.get(key).is_some()) {
+            items.retain(|item| {
+              item
+                .get(key)
+                .is_some_and(|field| values.iter().any(|value| matches(field, value)))
+            });
+          }
+        }
+      }
+
+      return Some(resource);
+    }
+
+    let (parent, id) = path.rsplit_once('/')?;
+    let id: i64 = id.parse().ok()?;
+
+    items(self.resources.get(parent)?)?
+      .iter()
+      .find(|item| item["id"] == id)
+      .cloned()
+  }
+
+  /// Adds a new item to the collection at the given path, filling in anything missing from the
+  /// body using the collection's template and any matching lookup result. Requests to anything
+  /// other than a collection with a template are simply acknowledged.
+  fn create(&mut self, path: &str, body: Value) -> Option<Value> {
+    let Some(mut item) = self.templates.get(path).cloned() else {
+      return (!body.is_null()).then_some(body);
+    };
+
+    if let Some(lookup_result) = self
+      .resources
+      .get(&format!("{path}/lookup"))
+      .and_then(items)
+      .and_then(|lookup_results| {
+        lookup_results
+          .iter()
+          .find(|lookup_result| shares_id(lookup_result, &body))
+      })
+    {
+      merge(&mut item, lookup_result);
+    }
+    merge(&mut item, &body);
+
+    let items = self.resources.get_mut(path).and_then(items_mut)?;
+    let id = items
+      .iter()
+      .filter_map(|item| item["id"].as_i64())
+      .max()
+      .unwrap_or_default()
+      + 1;
+    item["id"] = json!(id);
+    items.push(item.clone());
+
+    Some(item)
+  }
+
+  fn update(&mut self, path: &str, body: &Value) {
+    if let Some(resource) = self.resources.get_mut(path) {
+      if items(resource).is_none() {
+        merge(resource, body);
+      }
+
+      return;
+    }
+
+    let Some((parent, id)) = path.rsplit_once('/') else {
+      return;
+    };
+    let Some(resource) = self.resources.get_mut(parent) else {
+      return;
+    };
+
+    match id.parse::<i64>() {
+      Ok(_) if items(resource).is_none() => merge(resource, body),
+      Ok(id) => items_mut(resource)
+        .into_iter()
+        .flatten()
+        .filter(|item| item["id"] == id)
+        .for_each(|item| merge(item, body)),
+      Err(_) => {
+        let (ids_key, ids) = bulk_ids(body);
+        let mut changes = body.clone();
+        if let Some(changes) = changes.as_object_mut() {
+          changes.remove(&ids_key);
+        }
+
+        items_mut(resource)
+          .into_iter()
+          .flatten()
+          .filter(|item| item["id"].as_i64().is_some_and(|id| ids.contains(&id)))
+          .for_each(|item| merge(item, &changes));
+      }
+    }
+  }
+
+  fn delete(&mut self, path: &str, body: &Value) {
+    let Some((parent, id)) = path.rsplit_once('/') else {
+      return;
+    };
+    let ids = match id.parse::<i64>() {
+      Ok(id) => vec![id],
+      Err(_) => bulk_ids(body).1,
+    };
+
+    if let Some(items) = self.resources.get_mut(parent).and_then(items_mut) {
+      items.retain(|item| !item["id"].as_i64().is_some_and(|id| ids.contains(&id)));
+    }
+  }
+}
+
+impl Network<'_, '_> {
+  pub(super) async fn handle_demo_request<B, R>(
+    &mut self,
+    request_props: RequestProps<B>,
+    mut app_update_fn: impl FnMut(R, MutexGuard<'_, App<'_>>),
+  ) -> Result<R>
+  where
+    B: Serialize + Default + Debug,
+    R: DeserializeOwned + Default + Clone,
+  {
+    let RequestProps {
+      uri, method, body, ..
+    } = request_props;
+    debug!("Handling demo {method:?} request to {uri} with body {body:?}");
+    let body = serde_json::to_value(body)?;
+    let response = self
+      .demo_backend
+      .as_ref()
+      .and_then(|demo_backend| demo_backend.handle(method, &uri, body));
+
+    match method {
+      RequestMethod::Get | RequestMethod::Post => {
+        let value = match response.map(serde_json::from_value::<R>).transpose() {
+          Ok(value) => value.unwrap_or_default(),
+          Err(e) => {
+            error!("Failed to parse demo response! {e:?}");
+            return self
+              .handle_servarr_error(ServarrError::Parse(format!("{e:?}")))
+              .await;
+          }
+        };
+        let app = self.app.lock().await;
+        if self.is_target_active(&app) {
+          app_update_fn(value.clone(), app);
+        }
+
+        Ok(value)
+      }
+      RequestMethod::Delete | RequestMethod::Put => Ok(R::default()),
+    }
+  }
+}
+
+fn items(resource: &Value) -> Option<&Vec<Value>> {
+  resource
+    .as_array()
+    .or_else(|| resource.get("records")?.as_array())
+}
+
+fn items_mut(resource: &mut Value) -> Option<&mut Vec<Value>> {
+  if resource.is_array() {
+    resource.as_array_mut()
+  } else {
+    resource.get_mut("records")?.as_array_mut()
+  }
+}
+
+/// Whether the field matches a query parameter; arrays match when any of their elements do, and
+/// objects are matched by their `id`
+fn matches(field: &Value, query_value: &str) -> bool {
+  match field {
+    Value::String(field) => field == query_value,
+    Value::Array(elements) => elements
+      .iter()
+      .any(|element| matches(element.get("id").unwrap_or(element), query_value)),
+    field => {
+      serde_json::from_str::<Value>(query_value).is_ok_and(|query_value| query_value == *field)
+    }
+  }
+}
+
+/// Overwrites the fields of the target with every non-null field of the source
+fn merge(target: &mut Value, source: &Value) {
+  if let (Some(target), Some(source)) = (target.as_object_mut(), source.as_object()) {
+    for (key, value) in source.iter().filter(|(_, value)| !value.is_null()) {
+      target.insert(key.clone(), value.clone());
+    }
+  }
+}
+
+/// Whether the item has the same value for any of the `*Id` fields in the body (e.g. `tmdbId`)
+fn shares_id(item: &Value, body: &Value) -> bool {
+  body.as_object().is_some_and(|body| {
+    body
+      .iter()
+      .any(|(key, value)| key.ends_with("Id") && !value.is_null() && item.get(key) == Some(value))
+  })
+}
+
+/// The ids listed in the body of a bulk request (e.g. `ids` or `movieIds`), along with the key
+/// they were found under
+fn bulk_ids(body: &Value) -> (String, Vec<i64>) {
+  body
+    .as_object()
+    .and_then(|body| {
+      body
+        .iter()
+        .find(|(key, value)| (*key == "ids" || key.ends_with("Ids")) && value.is_array())
+    })
+    .map(|(key, ids)| {
+      (
+        key.clone(),
+        ids
+          .as_array()
+          .into_iter()
+          .flatten()
+          .filter_map(Value::as_i64)
+          .collect(),
+      )
+    })
+    .unwrap_or_default()
+}
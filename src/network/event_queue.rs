@@ -0,0 +1,121 @@
+use std::collections::VecDeque;
+
+use crate::network::{NetworkEvent, NetworkResource, QueuedNetworkEvent};
+
+#[cfg(test)]
+#[path = "event_queue_tests.rs"]
+mod event_queue_tests;
+
+/// The maximum number of network events that are handled at the same time
+pub const MAX_CONCURRENT_NETWORK_EVENTS: usize = 4;
+
+/// Schedules queued network events so that independent events can be handled concurrently.
+///
+/// Events conflict when they're for the same resource (e.g. `/movie`) of the same Servarr instance
+/// and at least one of them modifies it. An event is only started once no conflicting event is running or queued
+/// ahead of it, so mutations of a resource are always applied in the order they were queued.
+/// Read-only events that are already waiting in the queue aren't queued a second time, unless a
+/// conflicting event has been queued after them.
+#[derive(Debug)]
+pub struct NetworkEventQueue {
+  max_concurrent_events: usize,
+  queued: VecDeque<QueuedNetworkEvent>,
+  running: Vec<QueuedNetworkEvent>,
+}
+
+impl NetworkEventQueue {
+  pub fn new(max_concurrent_events: usize) -> Self {
+    NetworkEventQueue {
+      max_concurrent_events,
+      queued: VecDeque::new(),
+      running: Vec::new(),
+    }
+  }
+
+  /// Queues the event, returning `false` if it was coalesced with an identical queued event.
+  ///
+  /// A read-only event is only coalesced when nothing queued after its duplicate conflicts with
+  /// it, so a refresh that's queued behind a mutation still sees the result of that mutation.
+  pub fn push(&mut self, queued_event: QueuedNetworkEvent) -> bool {
+    if queued_event.network_event.is_read_only()
+      && let Some(index) = self.queued.iter().rposition(|e| *e == queued_event)
+      && !self
+        .queued
+        .range(index + 1..)
+        .any(|other| queued_event.conflicts_with(other))
+    {
+      return false;
+    }
+
+    self.queued.push_back(queued_event);
+    true
+  }
+
+  /// Takes the next queued event that can be started, and marks it as running
+  pub fn next_ready(&mut self) -> Option<QueuedNetworkEvent> {
+    if self.running.len() >= self.max_concurrent_events {
+      return None;
+    }
+
+    let index = (0..self.queued.len()).find(|&index| {
+      let queued_event = &self.queued[index];
+      !self
+        .running
+        .iter()
+        .chain(self.queued.range(..index))
+        .any(|other| queued_event.conflicts_with(other))
+    })?;
+    let queued_event = self.queued.remove(index)?;
+    self.running.push(queued_event.clone());
+
+    Some(queued_event)
+  }
+
+  pub fn finish(&mut self, queued_event: &QueuedNetworkEvent) {
+    if let Some(index) = self.running.iter().position(|e| e == queued_event) {
+      self.running.remove(index);
+    }
+  }
+
+  /// Drops every queued event that hasn't been started yet
+  pub fn clear(&mut self) {
+    self.queued.clear();
+  }
+}
+
+impl NetworkEvent {
+  fn is_read_only(&self) -> bool {
+    match self {
+      NetworkEvent::Radarr(event) => event.is_read_only(),
+      NetworkEvent::Sonarr(event) => event.is_read_only(),
+      NetworkEvent::Lidarr(event) => event.is_read_only(),
+      NetworkEvent::Prowlarr(event) => event.is_read_only(),
+      NetworkEvent::Readarr(event) => event.is_read_only(),
+    }
+  }
+
+  /// The Servarr and the top level API resource the event is for (e.g. `/movie` for both
+  /// `/movie` and `/movie/editor`)
+  fn resource_key(&self) -> (&'static str, &'static str) {
+    let (servarr, resource) = match self {
+      NetworkEvent::Radarr(event) => ("radarr", event.resource()),
+      NetworkEvent::Sonarr(event) => ("sonarr", event.resource()),
+      NetworkEvent::Lidarr(event) => ("lidarr", event.resource()),
+      NetworkEvent::Prowlarr(event) => ("prowlarr", event.resource()),
+      NetworkEvent::Readarr(event) => ("readarr", event.resource()),
+    };
+    let resource = resource
+      .split(['?', '/'])
+      .find(|segment| !segment.is_empty());
+
+    (servarr, resource.unwrap_or_default())
+  }
+}
+
+impl QueuedNetworkEvent {
+  fn conflicts_with(&self, other: &QueuedNetworkEvent) -> bool {
+    self.instance == other.instance
+      && self.network_event.resource_key() == other.network_event.resource_key()
+      && !(self.network_event.is_read_only() && other.network_event.is_read_only())
+  }
+}
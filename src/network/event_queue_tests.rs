@@ -0,0 +1,208 @@
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+
+  use crate::models::TabRoute;
+  use crate::models::servarr_data::radarr::radarr_data::ActiveRadarrBlock;
+  use crate::models::servarr_data::sonarr::sonarr_data::ActiveSonarrBlock;
+  use crate::network::event_queue::NetworkEventQueue;
+  use crate::network::radarr_network::RadarrEvent;
+  use crate::network::sonarr_network::SonarrEvent;
+  use crate::network::{NetworkEvent, QueuedNetworkEvent};
+
+  fn drain(queue: &mut NetworkEventQueue) -> Vec<QueuedNetworkEvent> {
+    std::iter::from_fn(|| queue.next_ready()).collect()
+  }
+
+  fn queued(network_event: impl Into<NetworkEvent>) -> QueuedNetworkEvent {
+    queued_for("Test", network_event)
+  }
+
+  fn queued_for(title: &str, network_event: impl Into<NetworkEvent>) -> QueuedNetworkEvent {
+    let network_event = network_event.into();
+    let route = match network_event {
+      NetworkEvent::Sonarr(_) => ActiveSonarrBlock::Series.into(),
+      _ => ActiveRadarrBlock::Movies.into(),
+    };
+    let tab = TabRoute {
+      title: title.to_owned(),
+      route,
+      contextual_help: None,
+      config: None,
+    };
+
+    QueuedNetworkEvent {
+      instance: tab.instance(),
+      network_event,
+    }
+  }
+
+  #[test]
+  fn test_independent_events_run_concurrently() {
+    let mut queue = NetworkEventQueue::new(4);
+    queue.push(queued(RadarrEvent::GetReleases(1)));
+    queue.push(queued(RadarrEvent::GetDownloads(500)));
+    queue.push(queued(SonarrEvent::GetDownloads(500)));
+
+    assert_eq!(
+      drain(&mut queue),
+      vec![
+        queued(RadarrEvent::GetReleases(1)),
+        queued(RadarrEvent::GetDownloads(500)),
+        queued(SonarrEvent::GetDownloads(500)),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_concurrency_is_bounded() {
+    let mut queue = NetworkEventQueue::new(2);
+    queue.push(queued(RadarrEvent::GetReleases(1)));
+    queue.push(queued(RadarrEvent::GetDownloads(500)));
+    queue.push(queued(RadarrEvent::GetTags));
+
+    assert_eq!(drain(&mut queue).len(), 2);
+
+    queue.finish(&queued(RadarrEvent::GetReleases(1)));
+
+    assert_eq!(drain(&mut queue), vec![queued(RadarrEvent::GetTags)]);
+  }
+
+  #[test]
+  fn test_queued_read_only_duplicates_are_coalesced() {
+    let mut queue = NetworkEventQueue::new(4);
+
+    assert!(queue.push(queued(RadarrEvent::GetMovies)));
+    assert!(!queue.push(queued(RadarrEvent::GetMovies)));
+    assert_eq!(drain(&mut queue), vec![queued(RadarrEvent::GetMovies)]);
+
+    assert!(queue.push(queued(RadarrEvent::GetMovies)));
+  }
+
+  #[test]
+  fn test_read_only_duplicates_queued_before_a_conflicting_mutation_are_not_coalesced() {
+    let mut queue = NetworkEventQueue::new(4);
+
+    assert!(queue.push(queued(RadarrEvent::GetMovies)));
+    assert!(queue.push(queued(RadarrEvent::ToggleMovieMonitoring(1))));
+    assert!(queue.push(queued(RadarrEvent::GetMovies)));
+    assert!(!queue.push(queued(RadarrEvent::GetMovies)));
+
+    assert_eq!(drain(&mut queue), vec![queued(RadarrEvent::GetMovies)]);
+
+    queue.finish(&queued(RadarrEvent::GetMovies));
+
+    assert_eq!(
+      drain(&mut queue),
+      vec![queued(RadarrEvent::ToggleMovieMonitoring(1))]
+    );
+
+    queue.finish(&queued(RadarrEvent::ToggleMovieMonitoring(1)));
+
+    assert_eq!(drain(&mut queue), vec![queued(RadarrEvent::GetMovies)]);
+  }
+
+  #[test]
+  fn test_read_only_duplicates_are_coalesced_past_unrelated_mutations() {
+    let mut queue = NetworkEventQueue::new(4);
+
+    assert!(queue.push(queued(RadarrEvent::GetMovies)));
+    assert!(queue.push(queued(RadarrEvent::DeleteBlocklistItem(1))));
+    assert!(!queue.push(queued(RadarrEvent::GetMovies)));
+  }
+
+  #[test]
+  fn test_duplicate_mutations_are_not_coalesced() {
+    let mut queue = NetworkEventQueue::new(4);
+
+    assert!(queue.push(queued(RadarrEvent::ToggleMovieMonitoring(1))));
+    assert!(queue.push(queued(RadarrEvent::ToggleMovieMonitoring(1))));
+  }
+
+  #[test]
+  fn test_mutations_of_the_same_resource_stay_ordered() {
+    let mut queue = NetworkEventQueue::new(4);
+    queue.push(queued(RadarrEvent::ToggleMovieMonitoring(1)));
+    queue.push(queued(RadarrEvent::GetMovies));
+    queue.push(queued(RadarrEvent::BulkEditMovies(Default::default())));
+    queue.push(queued(RadarrEvent::GetDownloads(500)));
+
+    assert_eq!(
+      drain(&mut queue),
+      vec![
+        queued(RadarrEvent::ToggleMovieMonitoring(1)),
+        queued(RadarrEvent::GetDownloads(500)),
+      ]
+    );
+
+    queue.finish(&queued(RadarrEvent::ToggleMovieMonitoring(1)));
+
+    assert_eq!(drain(&mut queue), vec![queued(RadarrEvent::GetMovies)]);
+
+    queue.finish(&queued(RadarrEvent::GetMovies));
+
+    assert_eq!(
+      drain(&mut queue),
+      vec![queued(RadarrEvent::BulkEditMovies(Default::default()))]
+    );
+  }
+
+  #[test]
+  fn test_reads_of_the_same_resource_run_concurrently() {
+    let mut queue = NetworkEventQueue::new(4);
+    queue.push(queued(RadarrEvent::GetMovies));
+    queue.push(queued(RadarrEvent::GetMovieDetails(1)));
+
+    assert_eq!(drain(&mut queue).len(), 2);
+  }
+
+  #[test]
+  fn test_same_resource_of_different_servarrs_do_not_conflict() {
+    let mut queue = NetworkEventQueue::new(4);
+    queue.push(queued(RadarrEvent::DeleteTag(1)));
+    queue.push(queued(SonarrEvent::DeleteTag(1)));
+
+    assert_eq!(drain(&mut queue).len(), 2);
+  }
+
+  #[test]
+  fn test_same_resource_of_different_instances_do_not_conflict() {
+    let mut queue = NetworkEventQueue::new(4);
+    queue.push(queued_for("Radarr 1", RadarrEvent::DeleteTag(1)));
+    queue.push(queued_for("Radarr 2", RadarrEvent::DeleteTag(1)));
+
+    assert_eq!(drain(&mut queue).len(), 2);
+  }
+
+  #[test]
+  fn test_read_only_duplicates_of_different_instances_are_not_coalesced() {
+    let mut queue = NetworkEventQueue::new(4);
+
+    assert!(queue.push(queued_for("Radarr 1", RadarrEvent::GetMovies)));
+    assert!(queue.push(queued_for("Radarr 2", RadarrEvent::GetMovies)));
+    assert_eq!(
+      drain(&mut queue),
+      vec![
+        queued_for("Radarr 1", RadarrEvent::GetMovies),
+        queued_for("Radarr 2", RadarrEvent::GetMovies),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_clear_keeps_running_events() {
+    let mut queue = NetworkEventQueue::new(1);
+    queue.push(queued(RadarrEvent::EditMovie(Default::default())));
+    queue.push(queued(RadarrEvent::GetMovies));
+    let running = queue.next_ready().unwrap();
+
+    queue.clear();
+    queue.push(queued(RadarrEvent::GetTags));
+
+    assert_eq!(queue.next_ready(), None);
+
+    queue.finish(&running);
+
+    assert_eq!(drain(&mut queue), vec![queued(RadarrEvent::GetTags)]);
+  }
+}
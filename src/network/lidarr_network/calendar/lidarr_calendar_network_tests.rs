@@ -0,0 +1,46 @@
+#[cfg(test)]
+mod tests {
+  use crate::models::lidarr_models::{Album, LidarrSerdeable};
+  use crate::network::lidarr_network::LidarrEvent;
+  use crate::network::lidarr_network::lidarr_network_test_utils::test_utils::album;
+  use crate::network::network_tests::test_utils::{MockServarrApi, test_network};
+  use chrono::{DateTime, NaiveDate};
+  use pretty_assertions::assert_eq;
+  use serde_json::json;
+
+  #[tokio::test]
+  async fn test_handle_get_lidarr_calendar_event() {
+    let start = NaiveDate::from_ymd_opt(2022, 12, 26).unwrap();
+    let end = NaiveDate::from_ymd_opt(2023, 1, 23).unwrap();
+    let later_album = Album {
+      id: 2,
+      release_date: Some(DateTime::from(
+        DateTime::parse_from_rfc3339("2023-01-08T00:00:00Z").unwrap(),
+      )),
+      ..album()
+    };
+    let (mock, app, _server) = MockServarrApi::get()
+      .query("start=2022-12-26&end=2023-01-23&unmonitored=true")
+      .returns(json!([later_album, album()]))
+      .build_for(LidarrEvent::GetCalendar(start, end))
+      .await;
+    app.lock().await.server_tabs.set_index(2);
+    let mut network = test_network(&app);
+
+    let LidarrSerdeable::Albums(albums) = network
+      .handle_lidarr_event(LidarrEvent::GetCalendar(start, end))
+      .await
+      .unwrap()
+    else {
+      panic!("Expected Albums")
+    };
+
+    mock.assert_async().await;
+    assert_eq!(
+      app.lock().await.data.lidarr_data.calendar.items,
+      vec![album(), later_album.clone()]
+    );
+    assert_some_eq_x!(app.lock().await.data.lidarr_data.calendar_start, start);
+    assert_eq!(albums, vec![later_album, album()]);
+  }
+}
@@ -0,0 +1,39 @@
+use crate::models::lidarr_models::Album;
+use crate::network::lidarr_network::LidarrEvent;
+use crate::network::{Network, RequestMethod};
+use anyhow::Result;
+use chrono::NaiveDate;
+use log::info;
+
+#[cfg(test)]
+#[path = "lidarr_calendar_network_tests.rs"]
+mod lidarr_calendar_network_tests;
+
+impl Network<'_, '_> {
+  pub(in crate::network::lidarr_network) async fn get_lidarr_calendar(
+    &mut self,
+    start: NaiveDate,
+    end: NaiveDate,
+  ) -> Result<Vec<Album>> {
+    info!("Fetching Lidarr calendar from {start} to {end}");
+    let event = LidarrEvent::GetCalendar(start, end);
+
+    let request_props = self
+      .request_props_from(
+        event,
+        RequestMethod::Get,
+        None::<()>,
+        None,
+        Some(format!("start={start}&end={end}&unmonitored=true")),
+      )
+      .await;
+
+    self
+      .handle_request::<(), Vec<Album>>(request_props, |mut albums, mut app| {
+        albums.sort_by_key(|album| album.release_date);
+        app.data.lidarr_data.calendar_start = Some(start);
+        app.data.lidarr_data.calendar.set_items(albums);
+      })
+      .await
+  }
+}
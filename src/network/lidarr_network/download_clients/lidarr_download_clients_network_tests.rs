@@ -0,0 +1,726 @@
+#[cfg(test)]
+mod tests {
+  use crate::models::HorizontallyScrollableText;
+  use crate::models::lidarr_models::LidarrSerdeable;
+  use crate::models::servarr_data::modals::IndexerTestResultModalItem;
+  use crate::models::servarr_models::{
+    AddDownloadClientParams, DownloadClient, EditDownloadClientParams, IndexerTestResult,
+  };
+  use crate::network::NetworkResource;
+  use crate::network::lidarr_network::LidarrEvent;
+  use crate::network::network_tests::test_utils::{MockServarrApi, test_network};
+  use crate::network::servarr_test_utils::download_client;
+  use bimap::BiMap;
+  use mockito::Matcher;
+  use pretty_assertions::assert_eq;
+  use serde_json::json;
+
+  fn download_client_details_json() -> serde_json::Value {
+    json!({
+        "enable": true,
+        "protocol": "torrent",
+        "priority": 1,
+        "removeCompletedDownloads": true,
+        "removeFailedDownloads": true,
+        "name": "qBittorrent",
+        "fields": [
+            {
+                "name": "host",
+                "value": "localhost",
+            },
+            {
+                "name": "port",
+                "type": "number",
+                "value": 8080,
+            },
+            {
+                "name": "category",
+                "value": "managarr",
+            },
+        ],
+        "implementationName": "qBittorrent",
+        "implementation": "QBittorrent",
+        "configContract": "QBittorrentSettings",
+        "tags": [1],
+        "id": 1
+    })
+  }
+
+  #[tokio::test]
+  async fn test_handle_add_lidarr_download_client_event() {
+    let download_client_schema_json = json!([{
+        "enable": false,
+        "priority": 1,
+        "removeCompletedDownloads": false,
+        "removeFailedDownloads": false,
+        "name": "",
+        "fields": [
+            {
+                "name": "host",
+                "type": "textbox",
+                "value": "",
+            },
+            {
+                "name": "port",
+                "type": "number",
+                "value": 8080,
+            },
+        ],
+        "implementationName": "qBittorrent",
+        "implementation": "QBittorrent",
+        "tags": [],
+    }]);
+    let expected_add_download_client_body_json = json!({
+        "enable": true,
+        "priority": 2,
+        "removeCompletedDownloads": true,
+        "removeFailedDownloads": false,
+        "name": "Test Client",
+        "fields": [
+            {
+                "name": "host",
+                "type": "textbox",
+                "value": "localhost",
+            },
+            {
+                "name": "port",
+                "type": "number",
+                "value": 9091,
+            },
+        ],
+        "implementationName": "qBittorrent",
+        "implementation": "QBittorrent",
+        "tags": [1, 2],
+    });
+    let add_download_client_params = AddDownloadClientParams {
+      implementation: "QBittorrent".to_owned(),
+      name: "Test Client".to_owned(),
+      priority: 2,
+      remove_failed_downloads: false,
+      tag_input_string: Some("usenet, testing".to_owned()),
+      fields: vec![
+        ("host".to_owned(), "localhost".to_owned()),
+        ("port".to_owned(), "9091".to_owned()),
+      ],
+      ..AddDownloadClientParams::default()
+    };
+    let (async_schema_server, app, mut server) = MockServarrApi::get()
+      .returns(download_client_schema_json)
+      .build_for(LidarrEvent::GetDownloadClientSchema)
+      .await;
+    let async_test_server = server
+      .mock(
+        "POST",
+        format!(
+          "/api/v1{}/test",
+          LidarrEvent::AddDownloadClient(add_download_client_params.clone()).resource()
+        )
+        .as_str(),
+      )
+      .with_status(200)
+      .with_body("{}")
+      .match_header("X-Api-Key", "test1234")
+      .match_body(Matcher::Json(
+        expected_add_download_client_body_json.clone(),
+      ))
+      .create_async()
+      .await;
+    let async_add_server = server
+      .mock(
+        "POST",
+        format!(
+          "/api/v1{}",
+          LidarrEvent::AddDownloadClient(add_download_client_params.clone()).resource()
+        )
+        .as_str(),
+      )
+      .with_status(201)
+      .match_header("X-Api-Key", "test1234")
+      .match_body(Matcher::Json(expected_add_download_client_body_json))
+      .with_body(json!({ "id": 1 }).to_string())
+      .create_async()
+      .await;
+    app.lock().await.data.lidarr_data.tags_map =
+      BiMap::from_iter([(1, "usenet".to_owned()), (2, "testing".to_owned())]);
+    app.lock().await.server_tabs.set_index(2);
+    let mut network = test_network(&app);
+
+    let LidarrSerdeable::Value(value) = network
+      .handle_lidarr_event(LidarrEvent::AddDownloadClient(add_download_client_params))
+      .await
+      .unwrap()
+    else {
+      panic!("Expected Value")
+    };
+    async_schema_server.assert_async().await;
+    async_test_server.assert_async().await;
+    async_add_server.assert_async().await;
+    assert_eq!(value, json!({ "id": 1 }));
+  }
+
+  #[tokio::test]
+  async fn test_handle_add_lidarr_download_client_event_does_not_save_download_client_when_test_fails()
+   {
+    let download_client_schema_json = json!([{
+        "name": "",
+        "fields": [],
+        "implementationName": "qBittorrent",
+        "implementation": "QBittorrent",
+        "tags": [],
+    }]);
+    let add_download_client_params = AddDownloadClientParams {
+      implementation: "QBittorrent".to_owned(),
+      name: "Test Client".to_owned(),
+      ..AddDownloadClientParams::default()
+    };
+    let (async_schema_server, app, mut server) = MockServarrApi::get()
+      .returns(download_client_schema_json)
+      .build_for(LidarrEvent::GetDownloadClientSchema)
+      .await;
+    let async_test_server = server
+      .mock(
+        "POST",
+        format!(
+          "/api/v1{}/test",
+          LidarrEvent::AddDownloadClient(add_download_client_params.clone()).resource()
+        )
+        .as_str(),
+      )
+      .with_status(400)
+      .with_body(json!([{ "propertyName": "", "errorMessage": "test failure" }]).to_string())
+      .create_async()
+      .await;
+    let async_add_server = server
+      .mock(
+        "POST",
+        format!(
+          "/api/v1{}",
+          LidarrEvent::AddDownloadClient(add_download_client_params.clone()).resource()
+        )
+        .as_str(),
+      )
+      .expect(0)
+      .create_async()
+      .await;
+    app.lock().await.server_tabs.set_index(2);
+    let mut network = test_network(&app);
+
+    assert!(
+      network
+        .handle_lidarr_event(LidarrEvent::AddDownloadClient(add_download_client_params))
+        .await
+        .is_err()
+    );
+
+    async_schema_server.assert_async().await;
+    async_test_server.assert_async().await;
+    async_add_server.assert_async().await;
+  }
+
+  #[tokio::test]
+  async fn test_handle_add_lidarr_download_client_event_unknown_implementation() {
+    let (async_schema_server, app, _server) = MockServarrApi::get()
+      .returns(json!([{ "implementation": "Sabnzbd", "fields": [] }]))
+      .build_for(LidarrEvent::GetDownloadClientSchema)
+      .await;
+    app.lock().await.server_tabs.set_index(2);
+    let mut network = test_network(&app);
+
+    let result = network
+      .handle_lidarr_event(LidarrEvent::AddDownloadClient(AddDownloadClientParams {
+        implementation: "QBittorrent".to_owned(),
+        ..AddDownloadClientParams::default()
+      }))
+      .await;
+
+    async_schema_server.assert_async().await;
+    assert_eq!(
+      result.unwrap_err().to_string(),
+      "No download client implementation named 'QBittorrent' was found"
+    );
+  }
+
+  #[tokio::test]
+  async fn test_handle_delete_lidarr_download_client_event() {
+    let (async_server, app, _server) = MockServarrApi::delete()
+      .path("/1")
+      .build_for(LidarrEvent::DeleteDownloadClient(1))
+      .await;
+    app.lock().await.server_tabs.set_index(2);
+    let mut network = test_network(&app);
+
+    assert!(
+      network
+        .handle_lidarr_event(LidarrEvent::DeleteDownloadClient(1))
+        .await
+        .is_ok()
+    );
+
+    async_server.assert_async().await;
+  }
+
+  #[tokio::test]
+  async fn test_handle_edit_lidarr_download_client_event() {
+    let mut expected_edit_download_client_body = download_client_details_json();
+    expected_edit_download_client_body["name"] = json!("Test Client");
+    expected_edit_download_client_body["enable"] = json!(false);
+    expected_edit_download_client_body["priority"] = json!(2);
+    expected_edit_download_client_body["removeCompletedDownloads"] = json!(false);
+    expected_edit_download_client_body["removeFailedDownloads"] = json!(false);
+    expected_edit_download_client_body["tags"] = json!([1, 2]);
+    expected_edit_download_client_body["fields"][1]["value"] = json!(9091);
+    let edit_download_client_params = EditDownloadClientParams {
+      download_client_id: 1,
+      name: Some("Test Client".to_owned()),
+      enable: Some(false),
+      priority: Some(2),
+      remove_completed_downloads: Some(false),
+      remove_failed_downloads: Some(false),
+      tag_input_string: Some("usenet, testing".to_owned()),
+      fields: vec![("port".to_owned(), "9091".to_owned())],
+      ..EditDownloadClientParams::default()
+    };
+
+    let (async_details_server, app, mut server) = MockServarrApi::get()
+      .returns(download_client_details_json())
+      .path("/1")
+      .build_for(LidarrEvent::GetDownloadClients)
+      .await;
+    let async_edit_server = server
+      .mock(
+        "PUT",
+        format!(
+          "/api/v1{}/1?forceSave=true",
+          LidarrEvent::EditDownloadClient(edit_download_client_params.clone()).resource()
+        )
+        .as_str(),
+      )
+      .with_status(202)
+      .match_header("X-Api-Key", "test1234")
+      .match_body(Matcher::Json(expected_edit_download_client_body))
+      .create_async()
+      .await;
+    app.lock().await.data.lidarr_data.tags_map =
+      BiMap::from_iter([(1, "usenet".to_owned()), (2, "testing".to_owned())]);
+    app.lock().await.server_tabs.set_index(2);
+    let mut network = test_network(&app);
+
+    assert!(
+      network
+        .handle_lidarr_event(LidarrEvent::EditDownloadClient(edit_download_client_params))
+        .await
+        .is_ok()
+    );
+
+    async_details_server.assert_async().await;
+    async_edit_server.assert_async().await;
+  }
+
+  #[tokio::test]
+  async fn test_handle_edit_lidarr_download_client_event_defaults_to_previous_values() {
+    let edit_download_client_params = EditDownloadClientParams {
+      download_client_id: 1,
+      ..EditDownloadClientParams::default()
+    };
+    let (async_details_server, app, mut server) = MockServarrApi::get()
+      .returns(download_client_details_json())
+      .path("/1")
+      .build_for(LidarrEvent::GetDownloadClients)
+      .await;
+    let async_edit_server = server
+      .mock(
+        "PUT",
+        format!(
+          "/api/v1{}/1?forceSave=true",
+          LidarrEvent::EditDownloadClient(edit_download_client_params.clone()).resource()
+        )
+        .as_str(),
+      )
+      .with_status(202)
+      .match_header("X-Api-Key", "test1234")
+      .match_body(Matcher::Json(download_client_details_json()))
+      .create_async()
+      .await;
+    app.lock().await.server_tabs.set_index(2);
+    let mut network = test_network(&app);
+
+    assert!(
+      network
+        .handle_lidarr_event(LidarrEvent::EditDownloadClient(edit_download_client_params))
+        .await
+        .is_ok()
+    );
+
+    async_details_server.assert_async().await;
+    async_edit_server.assert_async().await;
+  }
+
+  #[tokio::test]
+  async fn test_handle_edit_lidarr_download_client_event_clears_tags_when_clear_tags_is_true() {
+    let mut expected_edit_download_client_body = download_client_details_json();
+    expected_edit_download_client_body["tags"] = json!([]);
+    let edit_download_client_params = EditDownloadClientParams {
+      download_client_id: 1,
+      clear_tags: true,
+      ..EditDownloadClientParams::default()
+    };
+    let (async_details_server, app, mut server) = MockServarrApi::get()
+      .returns(download_client_details_json())
+      .path("/1")
+      .build_for(LidarrEvent::GetDownloadClients)
+      .await;
+    let async_edit_server = server
+      .mock(
+        "PUT",
+        format!(
+          "/api/v1{}/1?forceSave=true",
+          LidarrEvent::EditDownloadClient(edit_download_client_params.clone()).resource()
+        )
+        .as_str(),
+      )
+      .with_status(202)
+      .match_header("X-Api-Key", "test1234")
+      .match_body(Matcher::Json(expected_edit_download_client_body))
+      .create_async()
+      .await;
+    app.lock().await.server_tabs.set_index(2);
+    let mut network = test_network(&app);
+
+    assert!(
+      network
+        .handle_lidarr_event(LidarrEvent::EditDownloadClient(edit_download_client_params))
+        .await
+        .is_ok()
+    );
+
+    async_details_server.assert_async().await;
+    async_edit_server.assert_async().await;
+  }
+
+  #[tokio::test]
+  async fn test_handle_edit_lidarr_download_client_event_unknown_field() {
+    let edit_download_client_params = EditDownloadClientParams {
+      download_client_id: 1,
+      fields: vec![("apiKey".to_owned(), "test".to_owned())],
+      ..EditDownloadClientParams::default()
+    };
+    let (async_details_server, app, mut server) = MockServarrApi::get()
+      .returns(download_client_details_json())
+      .path("/1")
+      .build_for(LidarrEvent::GetDownloadClients)
+      .await;
+    let async_edit_server = server
+      .mock(
+        "PUT",
+        format!(
+          "/api/v1{}/1?forceSave=true",
+          LidarrEvent::EditDownloadClient(edit_download_client_params.clone()).resource()
+        )
+        .as_str(),
+      )
+      .expect(0)
+      .create_async()
+      .await;
+    app.lock().await.server_tabs.set_index(2);
+    let mut network = test_network(&app);
+
+    let result = network
+      .handle_lidarr_event(LidarrEvent::EditDownloadClient(edit_download_client_params))
+      .await;
+
+    async_details_server.assert_async().await;
+    async_edit_server.assert_async().await;
+    assert_eq!(
+      result.unwrap_err().to_string(),
+      "Field 'apiKey' does not exist for the 'QBittorrent' download client implementation"
+    );
+  }
+
+  #[tokio::test]
+  async fn test_handle_get_lidarr_download_client_schema_event() {
+    let download_client_schema_json = json!([{
+        "enable": false,
+        "protocol": "torrent",
+        "priority": 1,
+        "removeCompletedDownloads": true,
+        "removeFailedDownloads": true,
+        "name": "",
+        "fields": [
+            {
+                "name": "host",
+                "label": "Host",
+                "type": "textbox",
+                "value": "localhost",
+            },
+        ],
+        "implementationName": "qBittorrent",
+        "implementation": "QBittorrent",
+        "configContract": "QBittorrentSettings",
+        "tags": [],
+        "id": 0
+    }]);
+    let response: Vec<DownloadClient> =
+      serde_json::from_value(download_client_schema_json.clone()).unwrap();
+    let (async_server, app, _server) = MockServarrApi::get()
+      .returns(download_client_schema_json)
+      .build_for(LidarrEvent::GetDownloadClientSchema)
+      .await;
+    app.lock().await.server_tabs.set_index(2);
+    let mut network = test_network(&app);
+
+    let LidarrSerdeable::DownloadClients(download_client_schema) = network
+      .handle_lidarr_event(LidarrEvent::GetDownloadClientSchema)
+      .await
+      .unwrap()
+    else {
+      panic!("Expected DownloadClients")
+    };
+    async_server.assert_async().await;
+    assert_eq!(
+      app
+        .lock()
+        .await
+        .data
+        .lidarr_data
+        .download_client_schema
+        .items,
+      response
+    );
+    assert_eq!(download_client_schema, response);
+  }
+
+  #[tokio::test]
+  async fn test_handle_get_lidarr_download_clients_event() {
+    let download_clients_response_json = json!([download_client_details_json()]);
+    let response: Vec<DownloadClient> =
+      serde_json::from_value(download_clients_response_json.clone()).unwrap();
+    let (async_server, app, _server) = MockServarrApi::get()
+      .returns(download_clients_response_json)
+      .build_for(LidarrEvent::GetDownloadClients)
+      .await;
+    app.lock().await.server_tabs.set_index(2);
+    let mut network = test_network(&app);
+
+    let LidarrSerdeable::DownloadClients(download_clients) = network
+      .handle_lidarr_event(LidarrEvent::GetDownloadClients)
+      .await
+      .unwrap()
+    else {
+      panic!("Expected DownloadClients")
+    };
+    async_server.assert_async().await;
+    assert_eq!(
+      app.lock().await.data.lidarr_data.download_clients.items,
+      vec![download_client()]
+    );
+    assert_eq!(download_clients, response);
+  }
+
+  #[tokio::test]
+  async fn test_handle_test_lidarr_download_client_event_error() {
+    let response_json = json!([
+    {
+        "isWarning": false,
+        "propertyName": "",
+        "errorMessage": "test failure",
+        "severity": "error"
+    }]);
+    let (async_details_server, app, mut server) = MockServarrApi::get()
+      .returns(download_client_details_json())
+      .path("/1")
+      .build_for(LidarrEvent::GetDownloadClients)
+      .await;
+    let async_test_server = server
+      .mock(
+        "POST",
+        format!("/api/v1{}", LidarrEvent::TestDownloadClient(1).resource()).as_str(),
+      )
+      .with_status(400)
+      .match_header("X-Api-Key", "test1234")
+      .match_body(Matcher::Json(download_client_details_json()))
+      .with_body(response_json.to_string())
+      .create_async()
+      .await;
+    app.lock().await.server_tabs.set_index(2);
+    let mut network = test_network(&app);
+
+    let LidarrSerdeable::Value(value) = network
+      .handle_lidarr_event(LidarrEvent::TestDownloadClient(1))
+      .await
+      .unwrap()
+    else {
+      panic!("Expected Value")
+    };
+    async_details_server.assert_async().await;
+    async_test_server.assert_async().await;
+    assert_eq!(
+      app
+        .lock()
+        .await
+        .data
+        .lidarr_data
+        .download_client_test_errors,
+      Some("\"test failure\"".to_owned())
+    );
+    assert_eq!(value, response_json);
+  }
+
+  #[tokio::test]
+  async fn test_handle_test_lidarr_download_client_event_success() {
+    let (async_details_server, app, mut server) = MockServarrApi::get()
+      .returns(download_client_details_json())
+      .path("/1")
+      .build_for(LidarrEvent::GetDownloadClients)
+      .await;
+    let async_test_server = server
+      .mock(
+        "POST",
+        format!("/api/v1{}", LidarrEvent::TestDownloadClient(1).resource()).as_str(),
+      )
+      .with_status(200)
+      .match_header("X-Api-Key", "test1234")
+      .match_body(Matcher::Json(download_client_details_json()))
+      .with_body("{}")
+      .create_async()
+      .await;
+    app.lock().await.server_tabs.set_index(2);
+    let mut network = test_network(&app);
+
+    let LidarrSerdeable::Value(value) = network
+      .handle_lidarr_event(LidarrEvent::TestDownloadClient(1))
+      .await
+      .unwrap()
+    else {
+      panic!("Expected Value")
+    };
+    async_details_server.assert_async().await;
+    async_test_server.assert_async().await;
+    assert_eq!(
+      app
+        .lock()
+        .await
+        .data
+        .lidarr_data
+        .download_client_test_errors,
+      Some(String::new())
+    );
+    assert_eq!(value, json!({}));
+  }
+
+  #[tokio::test]
+  async fn test_handle_test_all_lidarr_download_clients_event() {
+    let download_clients = vec![
+      DownloadClient {
+        id: 1,
+        name: Some("Test 1".to_owned()),
+        ..DownloadClient::default()
+      },
+      DownloadClient {
+        id: 2,
+        name: Some("Test 2".to_owned()),
+        ..DownloadClient::default()
+      },
+    ];
+    let download_client_test_results_modal_items = vec![
+      IndexerTestResultModalItem {
+        name: "Test 1".to_owned(),
+        is_valid: true,
+        validation_failures: HorizontallyScrollableText::default(),
+      },
+      IndexerTestResultModalItem {
+        name: "Test 2".to_owned(),
+        is_valid: false,
+        validation_failures: "Failure for field 'host': Unable to connect".into(),
+      },
+    ];
+    let response_json = json!([
+    {
+      "id": 1,
+      "isValid": true,
+      "validationFailures": []
+    },
+    {
+      "id": 2,
+      "isValid": false,
+      "validationFailures": [
+          {
+              "propertyName": "host",
+              "errorMessage": "Unable to connect",
+              "severity": "error"
+          },
+      ]
+    }]);
+    let response: Vec<IndexerTestResult> = serde_json::from_value(response_json.clone()).unwrap();
+    let (async_server, app, _server) = MockServarrApi::post()
+      .returns(response_json)
+      .status(400)
+      .build_for(LidarrEvent::TestAllDownloadClients)
+      .await;
+    app
+      .lock()
+      .await
+      .data
+      .lidarr_data
+      .download_clients
+      .set_items(download_clients);
+    app.lock().await.server_tabs.set_index(2);
+    let mut network = test_network(&app);
+
+    let LidarrSerdeable::IndexerTestResults(results) = network
+      .handle_lidarr_event(LidarrEvent::TestAllDownloadClients)
+      .await
+      .unwrap()
+    else {
+      panic!("Expected IndexerTestResults")
+    };
+    async_server.assert_async().await;
+    assert_eq!(
+      app
+        .lock()
+        .await
+        .data
+        .lidarr_data
+        .download_client_test_all_results
+        .as_ref()
+        .unwrap()
+        .items,
+      download_client_test_results_modal_items
+    );
+    assert_eq!(results, response);
+  }
+
+  #[tokio::test]
+  async fn test_handle_test_all_lidarr_download_clients_event_sets_empty_table_on_api_error() {
+    let (async_server, app, _server) = MockServarrApi::post()
+      .status(500)
+      .build_for(LidarrEvent::TestAllDownloadClients)
+      .await;
+    app.lock().await.server_tabs.set_index(2);
+    let mut network = test_network(&app);
+
+    let result = network
+      .handle_lidarr_event(LidarrEvent::TestAllDownloadClients)
+      .await;
+
+    async_server.assert_async().await;
+    assert_err!(result);
+    assert_some!(
+      &app
+        .lock()
+        .await
+        .data
+        .lidarr_data
+        .download_client_test_all_results
+    );
+    assert_is_empty!(
+      app
+        .lock()
+        .await
+        .data
+        .lidarr_data
+        .download_client_test_all_results
+        .as_ref()
+        .unwrap()
+    );
+  }
+}
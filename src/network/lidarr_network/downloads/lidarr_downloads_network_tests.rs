@@ -1,17 +1,58 @@
 #[cfg(test)]
 mod tests {
   use crate::models::lidarr_models::{DownloadsResponse, LidarrSerdeable};
+  use crate::models::servarr_data::lidarr::modals::ManualImportModal;
+  use crate::models::servarr_models::{
+    BulkDeleteDownloadsParams, DeleteDownloadParams, QueueRemovalOptions,
+  };
   use crate::network::lidarr_network::LidarrEvent;
-  use crate::network::lidarr_network::lidarr_network_test_utils::test_utils::download_record;
+  use crate::network::lidarr_network::lidarr_network_test_utils::test_utils::{
+    album, artist, download_record, manual_import_file, manual_import_item, track,
+  };
   use crate::network::network_tests::test_utils::{MockServarrApi, test_network};
   use pretty_assertions::assert_eq;
+  use rstest::rstest;
   use serde_json::json;
 
+  #[tokio::test]
+  async fn test_handle_bulk_delete_lidarr_downloads_event() {
+    let params = BulkDeleteDownloadsParams {
+      ids: vec![1, 2],
+      removal_options: QueueRemovalOptions::default(),
+    };
+    let (mock, app, _server) = MockServarrApi::delete()
+      .with_request_body(json!({ "ids": [1, 2] }))
+      .query("removeFromClient=true&blocklist=false&skipRedownload=false&changeCategory=false")
+      .build_for(LidarrEvent::BulkDeleteDownloads(params.clone()))
+      .await;
+    app.lock().await.server_tabs.set_index(2);
+    let mut network = test_network(&app);
+
+    assert!(
+      network
+        .handle_lidarr_event(LidarrEvent::BulkDeleteDownloads(params))
+        .await
+        .is_ok()
+    );
+
+    mock.assert_async().await;
+  }
+
   #[tokio::test]
   async fn test_handle_delete_lidarr_download_event() {
+    let params = DeleteDownloadParams {
+      id: 1,
+      removal_options: QueueRemovalOptions {
+        remove_from_client: false,
+        blocklist: true,
+        skip_redownload: true,
+        change_category: false,
+      },
+    };
     let (mock, app, _server) = MockServarrApi::delete()
       .path("/1")
-      .build_for(LidarrEvent::DeleteDownload(1))
+      .query("removeFromClient=false&blocklist=true&skipRedownload=true&changeCategory=false")
+      .build_for(LidarrEvent::DeleteDownload(params.clone()))
       .await;
     app
       .lock()
@@ -25,7 +66,7 @@ mod tests {
 
     assert!(
       network
-        .handle_lidarr_event(LidarrEvent::DeleteDownload(1))
+        .handle_lidarr_event(LidarrEvent::DeleteDownload(params))
         .await
         .is_ok()
     );
@@ -48,7 +89,7 @@ mod tests {
     let response: DownloadsResponse = serde_json::from_value(downloads_json.clone()).unwrap();
     let (mock, app, _server) = MockServarrApi::get()
       .returns(downloads_json)
-      .query("pageSize=500")
+      .query("pageSize=500&includeUnknownArtistItems=true")
       .build_for(LidarrEvent::GetDownloads(500))
       .await;
     app.lock().await.server_tabs.set_index(2);
@@ -68,6 +109,113 @@ mod tests {
     assert!(!app.lock().await.data.lidarr_data.downloads.is_empty());
   }
 
+  #[rstest]
+  #[case(None, "downloadId=abc123&filterExistingFiles=true")]
+  #[case(Some(1), "downloadId=abc123&filterExistingFiles=true&artistId=1")]
+  #[tokio::test]
+  async fn test_handle_get_lidarr_manual_import_event(
+    #[case] artist_id: Option<i64>,
+    #[case] expected_query: &str,
+  ) {
+    let manual_import_json = json!([{
+      "id": 1,
+      "path": "/nfs/downloads/Test/01 - Test title.flac",
+      "name": "01 - Test title",
+      "size": 3543348019u64,
+      "artist": serde_json::to_value(artist()).unwrap(),
+      "album": serde_json::to_value(album()).unwrap(),
+      "albumReleaseId": 1,
+      "tracks": [serde_json::to_value(track()).unwrap()],
+      "quality": { "quality": { "id": 6, "name": "FLAC" } },
+      "releaseGroup": "SPARKS",
+      "downloadId": "abc123",
+      "rejections": []
+    }]);
+    let (mock, app, _server) = MockServarrApi::get()
+      .returns(manual_import_json)
+      .query(expected_query)
+      .build_for(LidarrEvent::GetManualImport("abc123".to_owned(), artist_id))
+      .await;
+    app.lock().await.server_tabs.set_index(2);
+    app.lock().await.data.lidarr_data.manual_import_modal = Some(ManualImportModal::default());
+    let mut network = test_network(&app);
+
+    let result = network
+      .handle_lidarr_event(LidarrEvent::GetManualImport("abc123".to_owned(), artist_id))
+      .await;
+
+    mock.assert_async().await;
+
+    let LidarrSerdeable::ManualImportItems(items) = result.unwrap() else {
+      panic!("Expected ManualImportItems")
+    };
+    assert_eq!(
+      app
+        .lock()
+        .await
+        .data
+        .lidarr_data
+        .manual_import_modal
+        .as_ref()
+        .unwrap()
+        .items
+        .items,
+      vec![manual_import_item()]
+    );
+    assert_eq!(items, vec![manual_import_item()]);
+  }
+
+  #[tokio::test]
+  async fn test_handle_grab_lidarr_queue_item_event() {
+    let (mock, app, _server) = MockServarrApi::post()
+      .returns(json!({}))
+      .path("/1")
+      .build_for(LidarrEvent::GrabQueueItem(1))
+      .await;
+    app.lock().await.server_tabs.set_index(2);
+    let mut network = test_network(&app);
+
+    let result = network
+      .handle_lidarr_event(LidarrEvent::GrabQueueItem(1))
+      .await;
+
+    mock.assert_async().await;
+    assert_ok!(result);
+  }
+
+  #[tokio::test]
+  async fn test_handle_manual_import_lidarr_files_event() {
+    let (mock, app, _server) = MockServarrApi::post()
+      .with_request_body(json!({
+        "name": "ManualImport",
+        "importMode": "auto",
+        "files": [{
+          "path": "/nfs/downloads/Test/01 - Test title.flac",
+          "artistId": 1,
+          "albumId": 1,
+          "albumReleaseId": 1,
+          "trackIds": [1],
+          "quality": { "quality": { "id": 6, "name": "FLAC" } },
+          "releaseGroup": "SPARKS",
+          "downloadId": "abc123"
+        }]
+      }))
+      .returns(json!({}))
+      .build_for(LidarrEvent::ManualImport(vec![manual_import_file()]))
+      .await;
+    app.lock().await.server_tabs.set_index(2);
+    let mut network = test_network(&app);
+
+    assert!(
+      network
+        .handle_lidarr_event(LidarrEvent::ManualImport(vec![manual_import_file()]))
+        .await
+        .is_ok()
+    );
+
+    mock.assert_async().await;
+  }
+
   #[tokio::test]
   async fn test_handle_update_lidarr_downloads_event() {
     let (mock, app, _server) = MockServarrApi::post()
@@ -1,30 +1,63 @@
-use crate::models::lidarr_models::DownloadsResponse;
-use crate::models::servarr_models::CommandBody;
+use crate::models::lidarr_models::{
+  DownloadsResponse, LidarrManualImportFile, LidarrManualImportItem,
+};
+use crate::models::servarr_models::{BulkDeleteDownloadsParams, CommandBody, DeleteDownloadParams};
 use crate::network::lidarr_network::LidarrEvent;
 use crate::network::{Network, RequestMethod};
 use anyhow::Result;
 use log::info;
-use serde_json::Value;
+use serde_json::{Value, json};
+use urlencoding::encode;
 
 #[cfg(test)]
 #[path = "lidarr_downloads_network_tests.rs"]
 mod lidarr_downloads_network_tests;
 
 impl Network<'_, '_> {
+  pub(in crate::network::lidarr_network) async fn bulk_delete_lidarr_downloads(
+    &mut self,
+    params: BulkDeleteDownloadsParams,
+  ) -> Result<()> {
+    let event = LidarrEvent::BulkDeleteDownloads(BulkDeleteDownloadsParams::default());
+    let BulkDeleteDownloadsParams {
+      ids,
+      removal_options,
+    } = params;
+    info!("Deleting Lidarr downloads with IDs: {ids:?} with options: {removal_options:?}");
+
+    let request_props = self
+      .request_props_from(
+        event,
+        RequestMethod::Delete,
+        Some(json!({ "ids": ids })),
+        None,
+        Some(removal_options.query_params()),
+      )
+      .await;
+
+    self
+      .handle_request::<Value, ()>(request_props, |_, _| ())
+      .await
+  }
+
   pub(in crate::network::lidarr_network) async fn delete_lidarr_download(
     &mut self,
-    download_id: i64,
+    params: DeleteDownloadParams,
   ) -> Result<()> {
-    let event = LidarrEvent::DeleteDownload(download_id);
-    info!("Deleting Lidarr download for download with id: {download_id}");
+    let event = LidarrEvent::DeleteDownload(DeleteDownloadParams::default());
+    let DeleteDownloadParams {
+      id,
+      removal_options,
+    } = params;
+    info!("Deleting Lidarr download for download with id: {id} with options: {removal_options:?}");
 
     let request_props = self
       .request_props_from(
         event,
         RequestMethod::Delete,
         None::<()>,
-        Some(format!("/{download_id}")),
-        None,
+        Some(format!("/{id}")),
+        Some(removal_options.query_params()),
       )
       .await;
 
@@ -46,7 +79,7 @@ impl Network<'_, '_> {
         RequestMethod::Get,
         None::<()>,
         None,
-        Some(format!("pageSize={count}")),
+        Some(format!("pageSize={count}&includeUnknownArtistItems=true")),
       )
       .await;
 
@@ -61,6 +94,77 @@ impl Network<'_, '_> {
       .await
   }
 
+  pub(in crate::network::lidarr_network) async fn get_lidarr_manual_import(
+    &mut self,
+    download_id: String,
+    artist_id: Option<i64>,
+  ) -> Result<Vec<LidarrManualImportItem>> {
+    info!("Fetching Lidarr manual import candidates for download with id: {download_id}");
+    let event = LidarrEvent::GetManualImport(download_id.clone(), artist_id);
+    let mut params = format!(
+      "downloadId={}&filterExistingFiles=true",
+      encode(&download_id)
+    );
+    if let Some(artist_id) = artist_id {
+      params.push_str(&format!("&artistId={artist_id}"));
+    }
+
+    let request_props = self
+      .request_props_from(event, RequestMethod::Get, None::<()>, None, Some(params))
+      .await;
+
+    self
+      .handle_request::<(), Vec<LidarrManualImportItem>>(request_props, |items, mut app| {
+        if let Some(manual_import_modal) = app.data.lidarr_data.manual_import_modal.as_mut() {
+          manual_import_modal.items.set_items(items);
+        }
+      })
+      .await
+  }
+
+  pub(in crate::network::lidarr_network) async fn grab_lidarr_queue_item(
+    &mut self,
+    queue_item_id: i64,
+  ) -> Result<Value> {
+    info!("Grabbing the pending Lidarr queue item with ID: {queue_item_id}");
+    let event = LidarrEvent::GrabQueueItem(queue_item_id);
+
+    let request_props = self
+      .request_props_from(
+        event,
+        RequestMethod::Post,
+        None::<()>,
+        Some(format!("/{queue_item_id}")),
+        None,
+      )
+      .await;
+
+    self
+      .handle_request::<(), Value>(request_props, |_, _| ())
+      .await
+  }
+
+  pub(in crate::network::lidarr_network) async fn manual_import_lidarr_files(
+    &mut self,
+    files: Vec<LidarrManualImportFile>,
+  ) -> Result<Value> {
+    info!("Manually importing {} file(s) into Lidarr", files.len());
+    let event = LidarrEvent::ManualImport(Vec::new());
+    let body = json!({
+      "name": "ManualImport",
+      "importMode": "auto",
+      "files": files
+    });
+
+    let request_props = self
+      .request_props_from(event, RequestMethod::Post, Some(body), None, None)
+      .await;
+
+    self
+      .handle_request::<Value, Value>(request_props, |_, _| ())
+      .await
+  }
+
   pub(in crate::network::lidarr_network) async fn update_lidarr_downloads(
     &mut self,
   ) -> Result<Value> {
@@ -1,9 +1,10 @@
 use crate::models::servarr_data::modals::IndexerTestResultModalItem;
 use crate::models::servarr_models::{
-  EditIndexerParams, Indexer, IndexerSettings, IndexerTestResult,
+  AddIndexerParams, EditIndexerParams, Indexer, IndexerSettings, IndexerTestResult,
 };
 use crate::models::stateful_table::StatefulTable;
 use crate::network::lidarr_network::LidarrEvent;
+use crate::network::utils::indexer_body_from_schema;
 use crate::network::{Network, RequestMethod};
 use anyhow::{Context, Result};
 use log::{debug, info};
@@ -14,6 +15,68 @@ use serde_json::{Value, json};
 mod lidarr_indexers_network_tests;
 
 impl Network<'_, '_> {
+  pub(in crate::network::lidarr_network) async fn add_lidarr_indexer(
+    &mut self,
+    mut add_indexer_params: AddIndexerParams,
+  ) -> Result<Value> {
+    let schema_event = LidarrEvent::GetIndexerSchema;
+    let event = LidarrEvent::AddIndexer(AddIndexerParams::default());
+    if let Some(tag_input_str) = add_indexer_params.tag_input_string.as_ref() {
+      let tag_ids_vec = self.extract_and_add_lidarr_tag_ids_vec(tag_input_str).await;
+      add_indexer_params.tags = tag_ids_vec;
+    }
+    info!(
+      "Adding new Lidarr indexer using the '{}' implementation",
+      add_indexer_params.implementation
+    );
+
+    info!("Fetching indexer schema");
+
+    let request_props = self
+      .request_props_from(schema_event, RequestMethod::Get, None::<()>, None, None)
+      .await;
+
+    let indexer_schema = self
+      .handle_request::<(), Vec<Value>>(request_props, |_, _| ())
+      .await?;
+
+    let add_indexer_body = indexer_body_from_schema(indexer_schema, &add_indexer_params)?;
+
+    debug!("Add indexer body: {add_indexer_body:?}");
+
+    info!("Testing new indexer");
+
+    let request_props = self
+      .request_props_from(
+        event.clone(),
+        RequestMethod::Post,
+        Some(add_indexer_body.clone()),
+        Some("/test".to_owned()),
+        None,
+      )
+      .await;
+
+    self
+      .handle_request::<Value, Value>(request_props, |_, _| ())
+      .await?;
+
+    info!("Saving new indexer");
+
+    let request_props = self
+      .request_props_from(
+        event,
+        RequestMethod::Post,
+        Some(add_indexer_body),
+        None,
+        None,
+      )
+      .await;
+
+    self
+      .handle_request::<Value, Value>(request_props, |_, _| ())
+      .await
+  }
+
   pub(in crate::network::lidarr_network) async fn delete_lidarr_indexer(
     &mut self,
     indexer_id: i64,
@@ -98,18 +161,12 @@ impl Network<'_, '_> {
       )
       .await;
 
-    let mut response = String::new();
-
-    self
-      .handle_request::<(), Value>(request_props, |detailed_indexer_body, _| {
-        response = detailed_indexer_body.to_string()
-      })
+    let mut detailed_indexer_body = self
+      .handle_request::<(), Value>(request_props, |_, _| ())
       .await?;
 
     info!("Constructing edit indexer body");
 
-    let mut detailed_indexer_body: Value = serde_json::from_str(&response)?;
-
     let (
       name,
       enable_rss,
@@ -304,6 +361,27 @@ impl Network<'_, '_> {
       .await
   }
 
+  pub(in crate::network::lidarr_network) async fn get_lidarr_indexer_schema(
+    &mut self,
+  ) -> Result<Vec<Indexer>> {
+    info!("Fetching Lidarr indexer schema");
+    let event = LidarrEvent::GetIndexerSchema;
+
+    let request_props = self
+      .request_props_from(event, RequestMethod::Get, None::<()>, None, None)
+      .await;
+
+    self
+      .handle_request::<(), Vec<Indexer>>(request_props, |indexer_schema, mut app| {
+        app
+          .data
+          .lidarr_data
+          .indexer_schema
+          .set_items(indexer_schema);
+      })
+      .await
+  }
+
   pub(in crate::network::lidarr_network) async fn test_lidarr_indexer(
     &mut self,
     indexer_id: i64,
@@ -324,12 +402,8 @@ impl Network<'_, '_> {
       )
       .await;
 
-    let mut test_body: Value = Value::default();
-
-    self
-      .handle_request::<(), Value>(request_props, |detailed_indexer_body, _| {
-        test_body = detailed_indexer_body;
-      })
+    let test_body = self
+      .handle_request::<(), Value>(request_props, |_, _| ())
       .await?;
 
     info!("Testing indexer");
@@ -21,6 +21,7 @@ mod tests {
     let albums_json = json!([{
       "id": 1,
       "title": "Test Album",
+      "artistId": 1,
       "foreignAlbumId": "test-foreign-album-id",
       "monitored": true,
       "anyReleaseOk": true,
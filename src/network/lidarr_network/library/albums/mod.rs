@@ -6,7 +6,7 @@ use crate::models::servarr_data::lidarr::lidarr_data::ActiveLidarrBlock;
 use crate::network::lidarr_network::LidarrEvent;
 use crate::network::{Network, RequestMethod};
 use anyhow::Result;
-use log::{debug, info, warn};
+use log::{debug, info};
 use serde_json::{Value, json};
 
 #[cfg(test)]
@@ -188,47 +188,35 @@ impl Network<'_, '_> {
       )
       .await;
 
-    let mut response = String::new();
-
-    self
-      .handle_request::<(), Value>(request_props, |detailed_album_body, _| {
-        response = detailed_album_body.to_string()
-      })
+    let mut detailed_album_body = self
+      .handle_request::<(), Value>(request_props, |_, _| ())
       .await?;
 
     info!("Constructing toggle album monitoring body");
 
-    match serde_json::from_str::<Value>(&response) {
-      Ok(mut detailed_album_body) => {
-        let monitored = detailed_album_body
-          .get("monitored")
-          .unwrap()
-          .as_bool()
-          .unwrap();
-
-        *detailed_album_body.get_mut("monitored").unwrap() = json!(!monitored);
-
-        debug!("Toggle album monitoring body: {detailed_album_body:?}");
-
-        let request_props = self
-          .request_props_from(
-            event,
-            RequestMethod::Put,
-            Some(detailed_album_body),
-            Some(format!("/{album_id}")),
-            None,
-          )
-          .await;
-
-        self
-          .handle_request::<Value, ()>(request_props, |_, _| ())
-          .await
-      }
-      Err(_) => {
-        warn!("Request for detailed album body was interrupted");
-        Ok(())
-      }
-    }
+    let monitored = detailed_album_body
+      .get("monitored")
+      .unwrap()
+      .as_bool()
+      .unwrap();
+
+    *detailed_album_body.get_mut("monitored").unwrap() = json!(!monitored);
+
+    debug!("Toggle album monitoring body: {detailed_album_body:?}");
+
+    let request_props = self
+      .request_props_from(
+        event,
+        RequestMethod::Put,
+        Some(detailed_album_body),
+        Some(format!("/{album_id}")),
+        None,
+      )
+      .await;
+
+    self
+      .handle_request::<Value, ()>(request_props, |_, _| ())
+      .await
   }
 
   pub(in crate::network::lidarr_network) async fn trigger_automatic_album_search(
@@ -1,10 +1,12 @@
 #[cfg(test)]
 mod tests {
   use crate::models::lidarr_models::{
-    AddArtistBody, AddArtistOptions, AddArtistSearchResult, Artist, DeleteParams, EditArtistParams,
-    LidarrHistoryItem, LidarrRelease, LidarrSerdeable, MonitorType, NewItemMonitorType,
+    AddArtistBody, AddArtistOptions, AddArtistSearchResult, Artist, BulkDeleteArtistsParams,
+    BulkEditArtistsParams, DeleteParams, EditArtistParams, LidarrHistoryItem, LidarrRelease,
+    LidarrSerdeable, MonitorType, NewItemMonitorType,
   };
   use crate::models::servarr_data::lidarr::lidarr_data::ActiveLidarrBlock;
+  use crate::models::servarr_models::ApplyTags;
   use crate::models::stateful_table::SortOption;
   use crate::network::NetworkResource;
   use crate::network::lidarr_network::LidarrEvent;
@@ -54,6 +56,77 @@ mod tests {
     assert!(!app.lock().await.data.lidarr_data.artists.is_empty());
   }
 
+  #[tokio::test]
+  async fn test_handle_bulk_delete_artists_event() {
+    let bulk_delete_artists_params = BulkDeleteArtistsParams {
+      artist_ids: vec![1, 2],
+      delete_files: true,
+      add_import_list_exclusion: true,
+    };
+    let (async_server, app, _server) = MockServarrApi::delete()
+      .with_request_body(json!({
+        "artistIds": [1, 2],
+        "deleteFiles": true,
+        "addImportListExclusion": true
+      }))
+      .build_for(LidarrEvent::BulkDeleteArtists(
+        bulk_delete_artists_params.clone(),
+      ))
+      .await;
+    app.lock().await.server_tabs.set_index(2);
+    let mut network = test_network(&app);
+
+    assert!(
+      network
+        .handle_lidarr_event(LidarrEvent::BulkDeleteArtists(bulk_delete_artists_params))
+        .await
+        .is_ok()
+    );
+
+    async_server.assert_async().await;
+  }
+
+  #[tokio::test]
+  async fn test_handle_bulk_edit_artists_event() {
+    let bulk_edit_artists_params = BulkEditArtistsParams {
+      artist_ids: vec![1, 2],
+      monitored: Some(false),
+      quality_profile_id: Some(2222),
+      root_folder_path: Some("/nfs2".to_owned()),
+      tag_input_string: Some("usenet, testing".to_owned()),
+      apply_tags: ApplyTags::Add,
+      move_files: true,
+      ..BulkEditArtistsParams::default()
+    };
+    let (async_server, app, _server) = MockServarrApi::put()
+      .with_request_body(json!({
+        "artistIds": [1, 2],
+        "monitored": false,
+        "qualityProfileId": 2222,
+        "rootFolderPath": "/nfs2",
+        "tags": [1, 2],
+        "applyTags": "add",
+        "moveFiles": true
+      }))
+      .build_for(LidarrEvent::BulkEditArtists(
+        bulk_edit_artists_params.clone(),
+      ))
+      .await;
+    app.lock().await.data.lidarr_data.tags_map =
+      BiMap::from_iter([(1, "usenet".to_owned()), (2, "testing".to_owned())]);
+    app.lock().await.server_tabs.set_index(2);
+    let mut network = test_network(&app);
+
+    assert!(
+      network
+        .handle_lidarr_event(LidarrEvent::BulkEditArtists(bulk_edit_artists_params))
+        .await
+        .is_ok()
+    );
+
+    async_server.assert_async().await;
+  }
+
   #[tokio::test]
   async fn test_handle_delete_artist_event() {
     let delete_artist_params = DeleteParams {
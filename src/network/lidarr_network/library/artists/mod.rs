@@ -1,11 +1,11 @@
 use anyhow::Result;
-use log::{debug, info, warn};
+use log::{debug, info};
 use serde_json::{Value, json};
 
 use crate::models::Route;
 use crate::models::lidarr_models::{
-  AddArtistBody, AddArtistSearchResult, Artist, DeleteParams, EditArtistParams, LidarrCommandBody,
-  LidarrHistoryItem, LidarrRelease,
+  AddArtistBody, AddArtistSearchResult, Artist, BulkDeleteArtistsParams, BulkEditArtistsParams,
+  DeleteParams, EditArtistParams, LidarrCommandBody, LidarrHistoryItem, LidarrRelease,
 };
 use crate::models::servarr_data::lidarr::lidarr_data::ActiveLidarrBlock;
 use crate::models::stateful_table::StatefulTable;
@@ -18,6 +18,47 @@ use urlencoding::encode;
 mod lidarr_artists_network_tests;
 
 impl Network<'_, '_> {
+  pub(in crate::network::lidarr_network) async fn bulk_delete_artists(
+    &mut self,
+    params: BulkDeleteArtistsParams,
+  ) -> Result<()> {
+    let event = LidarrEvent::BulkDeleteArtists(BulkDeleteArtistsParams::default());
+    info!(
+      "Deleting Lidarr artists with IDs: {:?} with deleteFiles={} and addImportListExclusion={}",
+      params.artist_ids, params.delete_files, params.add_import_list_exclusion
+    );
+
+    let request_props = self
+      .request_props_from(event, RequestMethod::Delete, Some(params), None, None)
+      .await;
+
+    self
+      .handle_request::<BulkDeleteArtistsParams, ()>(request_props, |_, _| ())
+      .await
+  }
+
+  pub(in crate::network::lidarr_network) async fn bulk_edit_artists(
+    &mut self,
+    mut params: BulkEditArtistsParams,
+  ) -> Result<()> {
+    info!("Bulk editing Lidarr artists");
+    let event = LidarrEvent::BulkEditArtists(BulkEditArtistsParams::default());
+    if let Some(tag_input_str) = params.tag_input_string.as_ref() {
+      let tag_ids_vec = self.extract_and_add_lidarr_tag_ids_vec(tag_input_str).await;
+      params.tags = Some(tag_ids_vec);
+    }
+
+    debug!("Bulk edit artists body: {params:?}");
+
+    let request_props = self
+      .request_props_from(event, RequestMethod::Put, Some(params), None, None)
+      .await;
+
+    self
+      .handle_request::<BulkEditArtistsParams, ()>(request_props, |_, _| ())
+      .await
+  }
+
   pub(in crate::network::lidarr_network) async fn delete_artist(
     &mut self,
     delete_artist_params: DeleteParams,
@@ -114,47 +155,35 @@ impl Network<'_, '_> {
       )
       .await;
 
-    let mut response = String::new();
-
-    self
-      .handle_request::<(), Value>(request_props, |detailed_artist_body, _| {
-        response = detailed_artist_body.to_string()
-      })
+    let mut detailed_artist_body = self
+      .handle_request::<(), Value>(request_props, |_, _| ())
       .await?;
 
     info!("Constructing toggle artist monitoring body");
 
-    match serde_json::from_str::<Value>(&response) {
-      Ok(mut detailed_artist_body) => {
-        let monitored = detailed_artist_body
-          .get("monitored")
-          .unwrap()
-          .as_bool()
-          .unwrap();
-
-        *detailed_artist_body.get_mut("monitored").unwrap() = json!(!monitored);
-
-        debug!("Toggle artist monitoring body: {detailed_artist_body:?}");
-
-        let request_props = self
-          .request_props_from(
-            event,
-            RequestMethod::Put,
-            Some(detailed_artist_body),
-            Some(format!("/{artist_id}")),
-            None,
-          )
-          .await;
-
-        self
-          .handle_request::<Value, ()>(request_props, |_, _| ())
-          .await
-      }
-      Err(_) => {
-        warn!("Request for detailed artist body was interrupted");
-        Ok(())
-      }
-    }
+    let monitored = detailed_artist_body
+      .get("monitored")
+      .unwrap()
+      .as_bool()
+      .unwrap();
+
+    *detailed_artist_body.get_mut("monitored").unwrap() = json!(!monitored);
+
+    debug!("Toggle artist monitoring body: {detailed_artist_body:?}");
+
+    let request_props = self
+      .request_props_from(
+        event,
+        RequestMethod::Put,
+        Some(detailed_artist_body),
+        Some(format!("/{artist_id}")),
+        None,
+      )
+      .await;
+
+    self
+      .handle_request::<Value, ()>(request_props, |_, _| ())
+      .await
   }
 
   pub(in crate::network::lidarr_network) async fn update_all_artists(&mut self) -> Result<Value> {
@@ -374,17 +403,12 @@ impl Network<'_, '_> {
       )
       .await;
 
-    let mut response = String::new();
-
-    self
-      .handle_request::<(), Value>(request_props, |detailed_artist_body, _| {
-        response = detailed_artist_body.to_string()
-      })
+    let mut detailed_artist_body = self
+      .handle_request::<(), Value>(request_props, |_, _| ())
       .await?;
 
     info!("Constructing edit artist body");
 
-    let mut detailed_artist_body: Value = serde_json::from_str(&response)?;
     let (
       monitored,
       monitor_new_items,
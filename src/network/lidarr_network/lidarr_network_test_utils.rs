@@ -5,14 +5,16 @@ pub mod test_utils {
     AddArtistSearchResult, Album, AlbumStatistics, Artist, ArtistStatistics, ArtistStatus,
     AudioTags, BlocklistItem, BlocklistResponse, DownloadRecord, DownloadStatus, DownloadsResponse,
     EditArtistParams, LidarrHistoryData, LidarrHistoryEventType, LidarrHistoryItem,
-    LidarrHistoryWrapper, LidarrRelease, LidarrTask, LidarrTaskName, MediaInfo, Member,
-    MetadataProfile, NewItemMonitorType, Ratings, SystemStatus, Track, TrackFile,
+    LidarrHistoryWrapper, LidarrManualImportFile, LidarrManualImportItem, LidarrRelease,
+    LidarrTask, LidarrTaskName, MediaInfo, Member, MetadataProfile, NamingConfig, NamingExamples,
+    NewItemMonitorType, Ratings, SystemStatus, Track, TrackFile,
   };
-  use crate::models::servarr_models::IndexerSettings;
   use crate::models::servarr_models::{
     Indexer, IndexerField, Quality, QualityProfile, QualityWrapper, RootFolder, Tag,
   };
+  use crate::models::servarr_models::{IndexerSettings, MediaManagementSettings};
   use crate::models::{HorizontallyScrollableText, ScrollableText};
+  use crate::network::servarr_test_utils::media_management_config;
   use bimap::BiMap;
   use chrono::DateTime;
   use indoc::formatdoc;
@@ -60,6 +62,7 @@ pub mod test_utils {
   pub const ALBUM_JSON: &str = r#"{
       "id": 1,
       "title": "Test Album",
+			"artistId": 1,
 			"foreignAlbumId": "test-foreign-album-id",
 			"monitored": true,
 			"anyReleaseOk": true,
@@ -140,6 +143,7 @@ pub mod test_utils {
     QualityProfile {
       id: 1,
       name: "Lossless".to_owned(),
+      ..QualityProfile::default()
     }
   }
 
@@ -184,6 +188,8 @@ pub mod test_utils {
       output_path: Some(HorizontallyScrollableText::from("/nfs/music/alex/album")),
       indexer: "kickass torrents".to_owned(),
       download_client: Some("transmission".to_owned()),
+      download_id: Some("abc123".to_owned()),
+      estimated_completion_time: None,
     }
   }
 
@@ -251,6 +257,7 @@ pub mod test_utils {
     Album {
       id: 1,
       title: "Test Album".into(),
+      artist_id: 1,
       foreign_album_id: "test-foreign-album-id".to_string(),
       monitored: true,
       any_release_ok: true,
@@ -314,14 +321,17 @@ pub mod test_utils {
         IndexerField {
           name: Some("baseUrl".to_owned()),
           value: Some(json!("https://test.com")),
+          ..IndexerField::default()
         },
         IndexerField {
           name: Some("apiKey".to_owned()),
           value: Some(json!("")),
+          ..IndexerField::default()
         },
         IndexerField {
           name: Some("seedCriteria.seedRatio".to_owned()),
           value: Some(json!("1.2")),
+          ..IndexerField::default()
         },
       ]),
     }
@@ -337,6 +347,43 @@ pub mod test_utils {
     }
   }
 
+  pub fn naming_config() -> NamingConfig {
+    NamingConfig {
+      id: 1,
+      rename_tracks: true,
+      replace_illegal_characters: true,
+      standard_track_format: Some(
+        "{Album Title} ({Release Year})/{Artist Name} - {Album Title} - {track:00} - {Track Title}"
+          .to_owned(),
+      ),
+      multi_disc_track_format: Some(
+        "{Album Title} ({Release Year})/{Medium Format} {medium:00}/{Artist Name} - {Album Title} - {track:00} - {Track Title}"
+          .to_owned(),
+      ),
+      artist_folder_format: Some("{Artist Name}".to_owned()),
+    }
+  }
+
+  pub fn naming_examples() -> NamingExamples {
+    NamingExamples {
+      single_track_example: Some(
+        "The Album Title (2020)/The Artist Name - The Album Title - 01 - Track Title".to_owned(),
+      ),
+      multi_disc_track_example: Some(
+        "The Album Title (2020)/CD 01/The Artist Name - The Album Title - 01 - Track Title"
+          .to_owned(),
+      ),
+      artist_folder_example: Some("The Artist Name".to_owned()),
+    }
+  }
+
+  pub fn media_management_settings() -> MediaManagementSettings<NamingConfig> {
+    MediaManagementSettings {
+      naming: naming_config(),
+      media_management: media_management_config(),
+    }
+  }
+
   pub fn task() -> LidarrTask {
     LidarrTask {
       name: "Backup".to_owned(),
@@ -478,6 +525,36 @@ pub mod test_utils {
     }
   }
 
+  pub fn manual_import_item() -> LidarrManualImportItem {
+    LidarrManualImportItem {
+      id: 1,
+      path: "/nfs/downloads/Test/01 - Test title.flac".to_owned(),
+      name: "01 - Test title".to_owned(),
+      size: 3543348019,
+      artist: Some(artist()),
+      album: Some(album()),
+      album_release_id: Some(1),
+      tracks: vec![track()],
+      quality: json!({ "quality": { "id": 6, "name": "FLAC" } }),
+      release_group: Some("SPARKS".to_owned()),
+      download_id: Some("abc123".to_owned()),
+      rejections: Vec::new(),
+    }
+  }
+
+  pub fn manual_import_file() -> LidarrManualImportFile {
+    LidarrManualImportFile {
+      path: "/nfs/downloads/Test/01 - Test title.flac".to_owned(),
+      artist_id: 1,
+      album_id: 1,
+      album_release_id: Some(1),
+      track_ids: vec![1],
+      quality: json!({ "quality": { "id": 6, "name": "FLAC" } }),
+      release_group: Some("SPARKS".to_owned()),
+      download_id: Some("abc123".to_owned()),
+    }
+  }
+
   pub fn blocklist_item() -> BlocklistItem {
     BlocklistItem {
       id: 1,
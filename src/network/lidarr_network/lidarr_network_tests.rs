@@ -2,13 +2,19 @@
 mod tests {
   use crate::app::App;
   use crate::models::lidarr_models::{
-    AddArtistBody, DeleteParams, EditArtistParams, LidarrSerdeable, MetadataProfile,
+    AddArtistBody, BulkDeleteArtistsParams, BulkEditArtistsParams, DeleteParams, EditArtistParams,
+    LidarrSerdeable, MetadataProfile, NamingConfig,
   };
   use crate::models::servarr_data::lidarr::modals::EditArtistModal;
-  use crate::models::servarr_models::{EditIndexerParams, IndexerSettings, QualityProfile, Tag};
+  use crate::models::servarr_models::{
+    AddDownloadClientParams, AddIndexerParams, BulkDeleteDownloadsParams, DeleteDownloadParams,
+    EditDownloadClientParams, EditIndexerParams, EditQualityProfileParams, IndexerSettings,
+    MediaManagementSettings, QualityProfile, Tag,
+  };
   use crate::network::network_tests::test_utils::{MockServarrApi, test_network};
   use crate::network::{NetworkEvent, NetworkResource, lidarr_network::LidarrEvent};
   use bimap::BiMap;
+  use chrono::NaiveDate;
   use pretty_assertions::{assert_eq, assert_str_eq};
   use rstest::rstest;
   use serde_json::json;
@@ -41,9 +47,24 @@ mod tests {
     assert_str_eq!(event.resource(), "/artist");
   }
 
+  #[rstest]
+  fn test_resource_artist_editor(
+    #[values(
+      LidarrEvent::BulkDeleteArtists(BulkDeleteArtistsParams::default()),
+      LidarrEvent::BulkEditArtists(BulkEditArtistsParams::default())
+    )]
+    event: LidarrEvent,
+  ) {
+    assert_str_eq!(event.resource(), "/artist/editor");
+  }
+
   #[rstest]
   fn test_resource_downloads(
-    #[values(LidarrEvent::GetDownloads(0), LidarrEvent::DeleteDownload(0))] event: LidarrEvent,
+    #[values(
+      LidarrEvent::GetDownloads(0),
+      LidarrEvent::DeleteDownload(DeleteDownloadParams::default())
+    )]
+    event: LidarrEvent,
   ) {
     assert_str_eq!(event.resource(), "/queue");
   }
@@ -51,6 +72,7 @@ mod tests {
   #[rstest]
   fn test_resource_indexer(
     #[values(
+      LidarrEvent::AddIndexer(AddIndexerParams::default()),
       LidarrEvent::GetIndexers,
       LidarrEvent::DeleteIndexer(0),
       LidarrEvent::EditIndexer(EditIndexerParams::default())
@@ -60,6 +82,19 @@ mod tests {
     assert_str_eq!(event.resource(), "/indexer");
   }
 
+  #[rstest]
+  fn test_resource_download_client(
+    #[values(
+      LidarrEvent::AddDownloadClient(AddDownloadClientParams::default()),
+      LidarrEvent::GetDownloadClients,
+      LidarrEvent::DeleteDownloadClient(0),
+      LidarrEvent::EditDownloadClient(EditDownloadClientParams::default())
+    )]
+    event: LidarrEvent,
+  ) {
+    assert_str_eq!(event.resource(), "/downloadclient");
+  }
+
   #[rstest]
   fn test_resource_artist_history(
     #[values(
@@ -84,6 +119,17 @@ mod tests {
     assert_str_eq!(event.resource(), "/tag");
   }
 
+  #[rstest]
+  fn test_resource_media_management_settings(
+    #[values(
+      LidarrEvent::GetMediaManagementSettings,
+      LidarrEvent::EditMediaManagementSettings(MediaManagementSettings::default())
+    )]
+    event: LidarrEvent,
+  ) {
+    assert_str_eq!(event.resource(), "/config");
+  }
+
   #[rstest]
   fn test_resource_config(
     #[values(LidarrEvent::GetHostConfig, LidarrEvent::GetSecurityConfig)] event: LidarrEvent,
@@ -97,9 +143,12 @@ mod tests {
       LidarrEvent::UpdateAllArtists,
       LidarrEvent::TriggerAutomaticArtistSearch(0),
       LidarrEvent::TriggerAutomaticAlbumSearch(0),
+      LidarrEvent::TriggerCutoffUnmetAlbumSearch,
+      LidarrEvent::TriggerMissingAlbumSearch,
       LidarrEvent::UpdateAndScanArtist(0),
       LidarrEvent::UpdateDownloads,
       LidarrEvent::GetQueuedEvents,
+      LidarrEvent::ManualImport(Vec::new()),
       LidarrEvent::StartTask(Default::default())
     )]
     event: LidarrEvent,
@@ -160,14 +209,36 @@ mod tests {
 
   #[rstest]
   #[case(LidarrEvent::ClearBlocklist, "/blocklist/bulk")]
+  #[case(LidarrEvent::GetIndexerSchema, "/indexer/schema")]
+  #[case(
+    LidarrEvent::BulkDeleteDownloads(BulkDeleteDownloadsParams::default()),
+    "/queue/bulk"
+  )]
+  #[case(LidarrEvent::GrabQueueItem(1), "/queue/grab")]
   #[case(LidarrEvent::DeleteBlocklistItem(0), "/blocklist")]
   #[case(LidarrEvent::GetBlocklist, "/blocklist?page=1&pageSize=10000")]
+  #[case(
+    LidarrEvent::GetCalendar(NaiveDate::default(), NaiveDate::default()),
+    "/calendar"
+  )]
+  #[case(LidarrEvent::GetCutoffUnmet(500), "/wanted/cutoff")]
+  #[case(LidarrEvent::GetWantedMissing(500), "/wanted/missing")]
   #[case(LidarrEvent::GetDiskSpace, "/diskspace")]
   #[case(LidarrEvent::GetMetadataProfiles, "/metadataprofile")]
   #[case(LidarrEvent::GetQualityProfiles, "/qualityprofile")]
+  #[case(LidarrEvent::GetQualityProfileDetails(0), "/qualityprofile")]
+  #[case(
+    LidarrEvent::EditQualityProfile(EditQualityProfileParams::default()),
+    "/qualityprofile"
+  )]
   #[case(LidarrEvent::GetStatus, "/system/status")]
   #[case(LidarrEvent::GetTags, "/tag")]
   #[case(LidarrEvent::GetLogs(500), "/log")]
+  #[case(LidarrEvent::GetManualImport(String::new(), None), "/manualimport")]
+  #[case(
+    LidarrEvent::GetNamingExamples(NamingConfig::default()),
+    "/config/naming/examples"
+  )]
   #[case(LidarrEvent::GetTasks, "/system/task")]
   #[case(LidarrEvent::GetUpdates, "/update")]
   #[case(LidarrEvent::HealthCheck, "/health")]
@@ -175,6 +246,9 @@ mod tests {
   #[case(LidarrEvent::GetHistory(0), "/history")]
   #[case(LidarrEvent::TestIndexer(0), "/indexer/test")]
   #[case(LidarrEvent::TestAllIndexers, "/indexer/testall")]
+  #[case(LidarrEvent::GetDownloadClientSchema, "/downloadclient/schema")]
+  #[case(LidarrEvent::TestDownloadClient(0), "/downloadclient/test")]
+  #[case(LidarrEvent::TestAllDownloadClients, "/downloadclient/testall")]
   fn test_resource(#[case] event: LidarrEvent, #[case] expected_uri: &str) {
     assert_str_eq!(event.resource(), expected_uri);
   }
@@ -274,6 +348,10 @@ mod tests {
         .get_by_left(&1),
       Some(&"Lossless".to_owned())
     );
+    assert_eq!(
+      app.lock().await.data.lidarr_data.quality_profiles.items,
+      response
+    );
   }
 
   #[tokio::test]
@@ -0,0 +1,206 @@
+#[cfg(test)]
+mod tests {
+  use crate::models::lidarr_models::{LidarrSerdeable, NamingConfig};
+  use crate::models::servarr_data::lidarr::lidarr_data::MEDIA_MANAGEMENT_NAMING_FIELDS;
+  use crate::models::servarr_data::modals::MediaManagementModal;
+  use crate::network::NetworkResource;
+  use crate::network::lidarr_network::LidarrEvent;
+  use crate::network::lidarr_network::lidarr_network_test_utils::test_utils::{
+    media_management_settings, naming_config, naming_examples,
+  };
+  use crate::network::network_tests::test_utils::{MockServarrApi, test_network};
+  use mockito::Matcher;
+  use pretty_assertions::assert_eq;
+  use serde_json::{Value, json};
+
+  const NAMING_EXAMPLES_QUERY: &str = "artistFolderFormat=%7BArtist%20Name%7D&id=1&multiDiscTrackFormat=%7BAlbum%20Title%7D%20%28%7BRelease%20Year%7D%29%2F%7BMedium%20Format%7D%20%7Bmedium%3A00%7D%2F%7BArtist%20Name%7D%20-%20%7BAlbum%20Title%7D%20-%20%7Btrack%3A00%7D%20-%20%7BTrack%20Title%7D&renameTracks=true&replaceIllegalCharacters=true&standardTrackFormat=%7BAlbum%20Title%7D%20%28%7BRelease%20Year%7D%29%2F%7BArtist%20Name%7D%20-%20%7BAlbum%20Title%7D%20-%20%7Btrack%3A00%7D%20-%20%7BTrack%20Title%7D";
+
+  fn naming_config_json() -> Value {
+    json!({
+      "id": 1,
+      "renameTracks": true,
+      "replaceIllegalCharacters": true,
+      "colonReplacementFormat": 4,
+      "standardTrackFormat": "{Album Title} ({Release Year})/{Artist Name} - {Album Title} - {track:00} - {Track Title}",
+      "multiDiscTrackFormat": "{Album Title} ({Release Year})/{Medium Format} {medium:00}/{Artist Name} - {Album Title} - {track:00} - {Track Title}",
+      "artistFolderFormat": "{Artist Name}"
+    })
+  }
+
+  fn media_management_config_json() -> Value {
+    json!({
+      "id": 1,
+      "autoUnmonitorPreviouslyDownloadedTracks": false,
+      "recycleBin": "/nfs/recycle",
+      "recycleBinCleanupDays": 7,
+      "setPermissionsLinux": false,
+      "chmodFolder": "755",
+      "chownGroup": "",
+      "importExtraFiles": true,
+      "extraFileExtensions": "srt,nfo"
+    })
+  }
+
+  fn naming_examples_json() -> Value {
+    json!({
+      "singleTrackExample": "The Album Title (2020)/The Artist Name - The Album Title - 01 - Track Title",
+      "multiDiscTrackExample": "The Album Title (2020)/CD 01/The Artist Name - The Album Title - 01 - Track Title",
+      "artistFolderExample": "The Artist Name"
+    })
+  }
+
+  #[tokio::test]
+  async fn test_handle_get_lidarr_media_management_settings_event() {
+    let (async_naming_server, app, mut server) = MockServarrApi::get()
+      .returns(naming_config_json())
+      .path("/naming")
+      .build_for(LidarrEvent::GetMediaManagementSettings)
+      .await;
+    let async_media_management_server = server
+      .mock(
+        "GET",
+        format!(
+          "/api/v1{}/mediamanagement",
+          LidarrEvent::GetMediaManagementSettings.resource()
+        )
+        .as_str(),
+      )
+      .with_status(200)
+      .match_header("X-Api-Key", "test1234")
+      .with_body(media_management_config_json().to_string())
+      .create_async()
+      .await;
+    let async_examples_server = server
+      .mock(
+        "GET",
+        format!(
+          "/api/v1{}?{NAMING_EXAMPLES_QUERY}",
+          LidarrEvent::GetNamingExamples(naming_config()).resource()
+        )
+        .as_str(),
+      )
+      .with_status(200)
+      .match_header("X-Api-Key", "test1234")
+      .with_body(naming_examples_json().to_string())
+      .create_async()
+      .await;
+    app.lock().await.server_tabs.set_index(2);
+    let mut network = test_network(&app);
+
+    let LidarrSerdeable::MediaManagementSettings(settings) = network
+      .handle_lidarr_event(LidarrEvent::GetMediaManagementSettings)
+      .await
+      .unwrap()
+    else {
+      panic!("Expected MediaManagementSettings")
+    };
+    async_naming_server.assert_async().await;
+    async_media_management_server.assert_async().await;
+    async_examples_server.assert_async().await;
+    assert_eq!(settings, media_management_settings());
+    let app = app.lock().await;
+    assert_eq!(
+      app
+        .data
+        .lidarr_data
+        .media_management_modal
+        .as_ref()
+        .unwrap()
+        .fields
+        .items,
+      MediaManagementModal::new(
+        &media_management_settings(),
+        &MEDIA_MANAGEMENT_NAMING_FIELDS
+      )
+      .fields
+      .items
+    );
+    assert_eq!(
+      app.data.lidarr_data.naming_examples,
+      Some(naming_examples())
+    );
+  }
+
+  #[tokio::test]
+  async fn test_handle_get_lidarr_naming_examples_event() {
+    let (async_server, app, _server) = MockServarrApi::get()
+      .returns(naming_examples_json())
+      .query(NAMING_EXAMPLES_QUERY)
+      .build_for(LidarrEvent::GetNamingExamples(naming_config()))
+      .await;
+    app.lock().await.server_tabs.set_index(2);
+    let mut network = test_network(&app);
+
+    let LidarrSerdeable::NamingExamples(examples) = network
+      .handle_lidarr_event(LidarrEvent::GetNamingExamples(naming_config()))
+      .await
+      .unwrap()
+    else {
+      panic!("Expected NamingExamples")
+    };
+    async_server.assert_async().await;
+    assert_eq!(examples, naming_examples());
+    assert_eq!(
+      app.lock().await.data.lidarr_data.naming_examples,
+      Some(naming_examples())
+    );
+  }
+
+  #[tokio::test]
+  async fn test_handle_edit_lidarr_media_management_settings_event() {
+    let mut settings = media_management_settings();
+    settings.naming = NamingConfig {
+      rename_tracks: false,
+      artist_folder_format: Some("{Artist CleanName}".to_owned()),
+      ..naming_config()
+    };
+    settings.media_management.recycle_bin_cleanup_days = 14;
+    let mut expected_naming_body = naming_config_json();
+    expected_naming_body["renameTracks"] = json!(false);
+    expected_naming_body["artistFolderFormat"] = json!("{Artist CleanName}");
+    let mut expected_media_management_body = media_management_config_json();
+    expected_media_management_body["recycleBinCleanupDays"] = json!(14);
+    let resource = LidarrEvent::EditMediaManagementSettings(settings.clone()).resource();
+
+    let (async_naming_details_server, app, mut server) = MockServarrApi::get()
+      .returns(naming_config_json())
+      .path("/naming")
+      .build_for(LidarrEvent::EditMediaManagementSettings(settings.clone()))
+      .await;
+    let async_naming_edit_server = server
+      .mock("PUT", format!("/api/v1{resource}/naming").as_str())
+      .with_status(202)
+      .match_header("X-Api-Key", "test1234")
+      .match_body(Matcher::Json(expected_naming_body))
+      .create_async()
+      .await;
+    let async_media_management_details_server = server
+      .mock("GET", format!("/api/v1{resource}/mediamanagement").as_str())
+      .with_status(200)
+      .match_header("X-Api-Key", "test1234")
+      .with_body(media_management_config_json().to_string())
+      .create_async()
+      .await;
+    let async_media_management_edit_server = server
+      .mock("PUT", format!("/api/v1{resource}/mediamanagement").as_str())
+      .with_status(202)
+      .match_header("X-Api-Key", "test1234")
+      .match_body(Matcher::Json(expected_media_management_body))
+      .create_async()
+      .await;
+    app.lock().await.server_tabs.set_index(2);
+    let mut network = test_network(&app);
+
+    assert!(
+      network
+        .handle_lidarr_event(LidarrEvent::EditMediaManagementSettings(settings))
+        .await
+        .is_ok()
+    );
+
+    async_naming_details_server.assert_async().await;
+    async_naming_edit_server.assert_async().await;
+    async_media_management_details_server.assert_async().await;
+    async_media_management_edit_server.assert_async().await;
+  }
+}
@@ -0,0 +1,139 @@
+use crate::models::lidarr_models::{NamingConfig, NamingExamples};
+use crate::models::servarr_data::lidarr::lidarr_data::MEDIA_MANAGEMENT_NAMING_FIELDS;
+use crate::models::servarr_data::modals::MediaManagementModal;
+use crate::models::servarr_models::{MediaManagementConfig, MediaManagementSettings};
+use crate::network::lidarr_network::LidarrEvent;
+use crate::network::utils::{apply_config_edits, naming_examples_query};
+use crate::network::{Network, RequestMethod};
+use anyhow::Result;
+use log::{debug, info};
+use serde_json::Value;
+
+#[cfg(test)]
+#[path = "lidarr_media_management_network_tests.rs"]
+mod lidarr_media_management_network_tests;
+
+impl Network<'_, '_> {
+  pub(in crate::network::lidarr_network) async fn get_lidarr_media_management_settings(
+    &mut self,
+  ) -> Result<MediaManagementSettings<NamingConfig>> {
+    info!("Fetching Lidarr media management settings");
+    let event = LidarrEvent::GetMediaManagementSettings;
+
+    let request_props = self
+      .request_props_from(
+        event.clone(),
+        RequestMethod::Get,
+        None::<()>,
+        Some("/naming".to_owned()),
+        None,
+      )
+      .await;
+
+    let naming = self
+      .handle_request::<(), NamingConfig>(request_props, |_, _| ())
+      .await?;
+
+    let request_props = self
+      .request_props_from(
+        event,
+        RequestMethod::Get,
+        None::<()>,
+        Some("/mediamanagement".to_owned()),
+        None,
+      )
+      .await;
+
+    let media_management = self
+      .handle_request::<(), MediaManagementConfig>(request_props, |media_management, mut app| {
+        let settings = MediaManagementSettings {
+          naming: naming.clone(),
+          media_management,
+        };
+        app.data.lidarr_data.media_management_modal = Some(MediaManagementModal::new(
+          &settings,
+          &MEDIA_MANAGEMENT_NAMING_FIELDS,
+        ));
+      })
+      .await?;
+
+    self.get_lidarr_naming_examples(naming.clone()).await?;
+
+    Ok(MediaManagementSettings {
+      naming,
+      media_management,
+    })
+  }
+
+  pub(in crate::network::lidarr_network) async fn get_lidarr_naming_examples(
+    &mut self,
+    naming_config: NamingConfig,
+  ) -> Result<NamingExamples> {
+    info!("Fetching Lidarr naming examples");
+    let query = naming_examples_query(&naming_config)?;
+    let event = LidarrEvent::GetNamingExamples(naming_config);
+
+    let request_props = self
+      .request_props_from(event, RequestMethod::Get, None::<()>, None, Some(query))
+      .await;
+
+    self
+      .handle_request::<(), NamingExamples>(request_props, |naming_examples, mut app| {
+        app.data.lidarr_data.naming_examples = Some(naming_examples);
+      })
+      .await
+  }
+
+  pub(in crate::network::lidarr_network) async fn edit_lidarr_media_management_settings(
+    &mut self,
+    settings: MediaManagementSettings<NamingConfig>,
+  ) -> Result<()> {
+    info!("Updating Lidarr media management settings");
+    let event = LidarrEvent::EditMediaManagementSettings(MediaManagementSettings::default());
+    let MediaManagementSettings {
+      naming,
+      media_management,
+    } = settings;
+
+    for (path, config) in [
+      ("/naming", serde_json::to_value(naming)?),
+      ("/mediamanagement", serde_json::to_value(media_management)?),
+    ] {
+      info!("Fetching the current {path} config");
+
+      let request_props = self
+        .request_props_from(
+          event.clone(),
+          RequestMethod::Get,
+          None::<()>,
+          Some(path.to_owned()),
+          None,
+        )
+        .await;
+
+      let mut config_body = self
+        .handle_request::<(), Value>(request_props, |_, _| ())
+        .await?;
+
+      apply_config_edits(&mut config_body, &config)?;
+
+      debug!("Edit {path} config body: {config_body:?}");
+
+      let request_props = self
+        .request_props_from(
+          event.clone(),
+          RequestMethod::Put,
+          Some(config_body),
+          Some(path.to_owned()),
+          None,
+        )
+        .await;
+
+      self
+        .handle_request::<Value, ()>(request_props, |_, _| ())
+        .await?;
+    }
+
+    Ok(())
+  }
+}
@@ -1,21 +1,33 @@
 use anyhow::Result;
+use chrono::NaiveDate;
 use log::info;
+use std::path::PathBuf;
 
 use super::{NetworkEvent, NetworkResource};
 use crate::models::lidarr_models::{
-  AddArtistBody, AddLidarrRootFolderBody, DeleteParams, EditArtistParams,
-  LidarrReleaseDownloadBody, LidarrSerdeable, LidarrTaskName, MetadataProfile,
+  AddArtistBody, AddLidarrRootFolderBody, BulkDeleteArtistsParams, BulkEditArtistsParams,
+  DeleteParams, EditArtistParams, LidarrManualImportFile, LidarrReleaseDownloadBody,
+  LidarrSerdeable, LidarrTaskName, MetadataProfile, NamingConfig,
+};
+use crate::models::servarr_models::{
+  AddDownloadClientParams, AddIndexerParams, BulkDeleteDownloadsParams, DeleteDownloadParams,
+  DownloadBackupParams, EditDownloadClientParams, EditIndexerParams, EditQualityProfileParams,
+  IndexerSettings, MediaManagementSettings, QualityProfile, Tag,
 };
-use crate::models::servarr_models::{EditIndexerParams, IndexerSettings, QualityProfile, Tag};
 use crate::network::{Network, RequestMethod};
 
 mod blocklist;
+mod calendar;
+mod download_clients;
 mod downloads;
 mod history;
 mod indexers;
 mod library;
+mod media_management;
+mod quality_profiles;
 mod root_folders;
 mod system;
+mod wanted;
 
 #[cfg(test)]
 #[path = "lidarr_network_tests.rs"]
@@ -28,21 +40,29 @@ pub mod lidarr_network_test_utils;
 #[derive(Debug, Eq, PartialEq, Clone)]
 pub enum LidarrEvent {
   AddArtist(AddArtistBody),
+  AddDownloadClient(AddDownloadClientParams),
+  AddIndexer(AddIndexerParams),
   AddRootFolder(AddLidarrRootFolderBody),
   AddTag(String),
+  BulkDeleteDownloads(BulkDeleteDownloadsParams),
+  BulkDeleteArtists(BulkDeleteArtistsParams),
+  BulkEditArtists(BulkEditArtistsParams),
   ClearBlocklist,
   DeleteAlbum(DeleteParams),
   DeleteArtist(DeleteParams),
+  DeleteBackup(i64),
   DeleteBlocklistItem(i64),
-  DeleteDownload(i64),
+  DeleteDownload(DeleteDownloadParams),
   DeleteIndexer(i64),
   DeleteRootFolder(i64),
   DeleteTag(i64),
   DeleteTrackFile(i64),
+  DownloadBackup(DownloadBackupParams),
   DownloadRelease(LidarrReleaseDownloadBody),
   EditArtist(EditArtistParams),
   EditAllIndexerSettings(IndexerSettings),
   EditIndexer(EditIndexerParams),
+  EditMediaManagementSettings(MediaManagementSettings<NamingConfig>),
   GetAlbums(i64),
   GetAlbumDetails(i64),
   GetAlbumHistory(i64, i64),
@@ -50,17 +70,31 @@ pub enum LidarrEvent {
   GetArtistHistory(i64),
   GetAllIndexerSettings,
   GetArtistDetails(i64),
+  GetBackups,
   GetBlocklist,
+  GetCalendar(NaiveDate, NaiveDate),
+  GetCutoffUnmet(u64),
   GetDiscographyReleases(i64),
   GetDiskSpace,
   GetDownloads(u64),
   GetHistory(u64),
   GetHostConfig,
   GetIndexers,
+  GetIndexerSchema,
+  GetDownloadClients,
+  GetDownloadClientSchema,
+  DeleteDownloadClient(i64),
+  EditDownloadClient(EditDownloadClientParams),
   GetLogs(u64),
+  GetManualImport(String, Option<i64>),
+  GetMediaManagementSettings,
+  GetNamingExamples(NamingConfig),
+  GrabQueueItem(i64),
   MarkHistoryItemAsFailed(i64),
   GetMetadataProfiles,
   GetQualityProfiles,
+  GetQualityProfileDetails(i64),
+  EditQualityProfile(EditQualityProfileParams),
   GetQueuedEvents,
   GetRootFolders,
   GetSecurityConfig,
@@ -72,16 +106,23 @@ pub enum LidarrEvent {
   GetUpdates,
   GetTags,
   GetTasks,
+  GetWantedMissing(u64),
   HealthCheck,
   ListArtists,
+  ManualImport(Vec<LidarrManualImportFile>),
+  RestoreBackup(PathBuf),
   SearchNewArtist(String),
   StartTask(LidarrTaskName),
   TestIndexer(i64),
   TestAllIndexers,
+  TestDownloadClient(i64),
+  TestAllDownloadClients,
   ToggleAlbumMonitoring(i64),
   ToggleArtistMonitoring(i64),
   TriggerAutomaticArtistSearch(i64),
   TriggerAutomaticAlbumSearch(i64),
+  TriggerCutoffUnmetAlbumSearch,
+  TriggerMissingAlbumSearch,
   UpdateAllArtists,
   UpdateAndScanArtist(i64),
   UpdateDownloads,
@@ -91,6 +132,10 @@ impl NetworkResource for LidarrEvent {
   fn resource(&self) -> &'static str {
     match &self {
       LidarrEvent::AddTag(_) | LidarrEvent::DeleteTag(_) | LidarrEvent::GetTags => "/tag",
+      LidarrEvent::GetBackups | LidarrEvent::DeleteBackup(_) | LidarrEvent::DownloadBackup(_) => {
+        "/system/backup"
+      }
+      LidarrEvent::RestoreBackup(_) => "/system/backup/restore/upload",
       LidarrEvent::ClearBlocklist => "/blocklist/bulk",
       LidarrEvent::DeleteTrackFile(_) | LidarrEvent::GetTrackFiles(_) => "/trackfile",
       LidarrEvent::DeleteBlocklistItem(_) => "/blocklist",
@@ -103,6 +148,7 @@ impl NetworkResource for LidarrEvent {
       | LidarrEvent::ListArtists
       | LidarrEvent::AddArtist(_)
       | LidarrEvent::ToggleArtistMonitoring(_) => "/artist",
+      LidarrEvent::BulkDeleteArtists(_) | LidarrEvent::BulkEditArtists(_) => "/artist/editor",
       LidarrEvent::GetAlbums(_)
       | LidarrEvent::ToggleAlbumMonitoring(_)
       | LidarrEvent::GetAlbumDetails(_)
@@ -111,27 +157,51 @@ impl NetworkResource for LidarrEvent {
       | LidarrEvent::GetAlbumHistory(_, _)
       | LidarrEvent::GetTrackHistory(_, _, _) => "/history/artist",
       LidarrEvent::GetBlocklist => "/blocklist?page=1&pageSize=10000",
+      LidarrEvent::GetCalendar(_, _) => "/calendar",
+      LidarrEvent::GetCutoffUnmet(_) => "/wanted/cutoff",
+      LidarrEvent::GetWantedMissing(_) => "/wanted/missing",
       LidarrEvent::GetLogs(_) => "/log",
+      LidarrEvent::GetManualImport(_, _) => "/manualimport",
+      LidarrEvent::GetMediaManagementSettings | LidarrEvent::EditMediaManagementSettings(_) => {
+        "/config"
+      }
+      LidarrEvent::GetNamingExamples(_) => "/config/naming/examples",
       LidarrEvent::GetDiskSpace => "/diskspace",
       LidarrEvent::GetDownloads(_) | LidarrEvent::DeleteDownload(_) => "/queue",
+      LidarrEvent::BulkDeleteDownloads(_) => "/queue/bulk",
+      LidarrEvent::GrabQueueItem(_) => "/queue/grab",
       LidarrEvent::GetHistory(_) => "/history",
       LidarrEvent::MarkHistoryItemAsFailed(_) => "/history/failed",
       LidarrEvent::GetDiscographyReleases(_)
       | LidarrEvent::DownloadRelease(_)
       | LidarrEvent::GetAlbumReleases(_, _) => "/release",
       LidarrEvent::GetHostConfig | LidarrEvent::GetSecurityConfig => "/config/host",
-      LidarrEvent::GetIndexers | LidarrEvent::DeleteIndexer(_) | LidarrEvent::EditIndexer(_) => {
-        "/indexer"
-      }
+      LidarrEvent::AddIndexer(_)
+      | LidarrEvent::GetIndexers
+      | LidarrEvent::DeleteIndexer(_)
+      | LidarrEvent::EditIndexer(_) => "/indexer",
+      LidarrEvent::GetIndexerSchema => "/indexer/schema",
+      LidarrEvent::AddDownloadClient(_)
+      | LidarrEvent::GetDownloadClients
+      | LidarrEvent::DeleteDownloadClient(_)
+      | LidarrEvent::EditDownloadClient(_) => "/downloadclient",
+      LidarrEvent::GetDownloadClientSchema => "/downloadclient/schema",
+      LidarrEvent::TestDownloadClient(_) => "/downloadclient/test",
+      LidarrEvent::TestAllDownloadClients => "/downloadclient/testall",
       LidarrEvent::TriggerAutomaticArtistSearch(_)
       | LidarrEvent::UpdateAllArtists
       | LidarrEvent::UpdateAndScanArtist(_)
       | LidarrEvent::UpdateDownloads
       | LidarrEvent::GetQueuedEvents
+      | LidarrEvent::ManualImport(_)
       | LidarrEvent::StartTask(_)
-      | LidarrEvent::TriggerAutomaticAlbumSearch(_) => "/command",
+      | LidarrEvent::TriggerAutomaticAlbumSearch(_)
+      | LidarrEvent::TriggerCutoffUnmetAlbumSearch
+      | LidarrEvent::TriggerMissingAlbumSearch => "/command",
       LidarrEvent::GetMetadataProfiles => "/metadataprofile",
-      LidarrEvent::GetQualityProfiles => "/qualityprofile",
+      LidarrEvent::GetQualityProfiles
+      | LidarrEvent::GetQualityProfileDetails(_)
+      | LidarrEvent::EditQualityProfile(_) => "/qualityprofile",
       LidarrEvent::GetRootFolders
       | LidarrEvent::AddRootFolder(_)
       | LidarrEvent::DeleteRootFolder(_) => "/rootfolder",
@@ -145,6 +215,54 @@ impl NetworkResource for LidarrEvent {
       LidarrEvent::SearchNewArtist(_) => "/artist/lookup",
     }
   }
+
+  fn is_read_only(&self) -> bool {
+    matches!(
+      self,
+      LidarrEvent::GetAlbums(..)
+        | LidarrEvent::GetAlbumDetails(..)
+        | LidarrEvent::GetAlbumHistory(..)
+        | LidarrEvent::GetAlbumReleases(..)
+        | LidarrEvent::GetArtistHistory(..)
+        | LidarrEvent::GetAllIndexerSettings
+        | LidarrEvent::GetArtistDetails(..)
+        | LidarrEvent::GetBackups
+        | LidarrEvent::GetBlocklist
+        | LidarrEvent::GetCalendar(..)
+        | LidarrEvent::GetCutoffUnmet(..)
+        | LidarrEvent::GetDiscographyReleases(..)
+        | LidarrEvent::GetDiskSpace
+        | LidarrEvent::GetDownloads(..)
+        | LidarrEvent::GetHistory(..)
+        | LidarrEvent::GetHostConfig
+        | LidarrEvent::GetIndexers
+        | LidarrEvent::GetIndexerSchema
+        | LidarrEvent::GetDownloadClients
+        | LidarrEvent::GetDownloadClientSchema
+        | LidarrEvent::GetLogs(..)
+        | LidarrEvent::GetManualImport(..)
+        | LidarrEvent::GetMediaManagementSettings
+        | LidarrEvent::GetNamingExamples(..)
+        | LidarrEvent::GetMetadataProfiles
+        | LidarrEvent::GetQualityProfiles
+        | LidarrEvent::GetQualityProfileDetails(..)
+        | LidarrEvent::GetQueuedEvents
+        | LidarrEvent::GetRootFolders
+        | LidarrEvent::GetSecurityConfig
+        | LidarrEvent::GetStatus
+        | LidarrEvent::GetTrackDetails(..)
+        | LidarrEvent::GetTracks(..)
+        | LidarrEvent::GetTrackFiles(..)
+        | LidarrEvent::GetTrackHistory(..)
+        | LidarrEvent::GetUpdates
+        | LidarrEvent::GetTags
+        | LidarrEvent::GetTasks
+        | LidarrEvent::GetWantedMissing(..)
+        | LidarrEvent::HealthCheck
+        | LidarrEvent::ListArtists
+        | LidarrEvent::SearchNewArtist(..)
+    )
+  }
 }
 
 impl From<LidarrEvent> for NetworkEvent {
@@ -159,11 +277,31 @@ impl Network<'_, '_> {
     lidarr_event: LidarrEvent,
   ) -> Result<LidarrSerdeable> {
     match lidarr_event {
+      LidarrEvent::AddDownloadClient(params) => self
+        .add_lidarr_download_client(params)
+        .await
+        .map(LidarrSerdeable::from),
+      LidarrEvent::AddIndexer(params) => self
+        .add_lidarr_indexer(params)
+        .await
+        .map(LidarrSerdeable::from),
       LidarrEvent::AddTag(tag) => self.add_lidarr_tag(tag).await.map(LidarrSerdeable::from),
       LidarrEvent::AddRootFolder(path) => self
         .add_lidarr_root_folder(path)
         .await
         .map(LidarrSerdeable::from),
+      LidarrEvent::BulkDeleteDownloads(params) => self
+        .bulk_delete_lidarr_downloads(params)
+        .await
+        .map(LidarrSerdeable::from),
+      LidarrEvent::BulkDeleteArtists(params) => self
+        .bulk_delete_artists(params)
+        .await
+        .map(LidarrSerdeable::from),
+      LidarrEvent::BulkEditArtists(params) => self
+        .bulk_edit_artists(params)
+        .await
+        .map(LidarrSerdeable::from),
       LidarrEvent::ClearBlocklist => self
         .clear_lidarr_blocklist()
         .await
@@ -174,12 +312,16 @@ impl Network<'_, '_> {
       LidarrEvent::DeleteArtist(params) => {
         self.delete_artist(params).await.map(LidarrSerdeable::from)
       }
+      LidarrEvent::DeleteBackup(backup_id) => self
+        .delete_lidarr_backup(backup_id)
+        .await
+        .map(LidarrSerdeable::from),
       LidarrEvent::DeleteBlocklistItem(blocklist_item_id) => self
         .delete_lidarr_blocklist_item(blocklist_item_id)
         .await
         .map(LidarrSerdeable::from),
-      LidarrEvent::DeleteDownload(download_id) => self
-        .delete_lidarr_download(download_id)
+      LidarrEvent::DeleteDownload(params) => self
+        .delete_lidarr_download(params)
         .await
         .map(LidarrSerdeable::from),
       LidarrEvent::DeleteTrackFile(track_file_id) => self
@@ -194,6 +336,10 @@ impl Network<'_, '_> {
         .edit_lidarr_indexer(params)
         .await
         .map(LidarrSerdeable::from),
+      LidarrEvent::EditMediaManagementSettings(params) => self
+        .edit_lidarr_media_management_settings(params)
+        .await
+        .map(LidarrSerdeable::from),
       LidarrEvent::DeleteIndexer(indexer_id) => self
         .delete_lidarr_indexer(indexer_id)
         .await
@@ -206,6 +352,10 @@ impl Network<'_, '_> {
         .delete_lidarr_tag(tag_id)
         .await
         .map(LidarrSerdeable::from),
+      LidarrEvent::DownloadBackup(params) => self
+        .download_lidarr_backup(params)
+        .await
+        .map(LidarrSerdeable::from),
       LidarrEvent::DownloadRelease(lidarr_release_download_body) => self
         .download_lidarr_release(lidarr_release_download_body)
         .await
@@ -233,7 +383,20 @@ impl Network<'_, '_> {
         .get_album_releases(artist_id, album_id)
         .await
         .map(LidarrSerdeable::from),
+      LidarrEvent::GetBackups => self.get_lidarr_backups().await.map(LidarrSerdeable::from),
       LidarrEvent::GetBlocklist => self.get_lidarr_blocklist().await.map(LidarrSerdeable::from),
+      LidarrEvent::GetCalendar(start, end) => self
+        .get_lidarr_calendar(start, end)
+        .await
+        .map(LidarrSerdeable::from),
+      LidarrEvent::GetCutoffUnmet(count) => self
+        .get_lidarr_cutoff_unmet(count)
+        .await
+        .map(LidarrSerdeable::from),
+      LidarrEvent::GetWantedMissing(count) => self
+        .get_lidarr_wanted_missing(count)
+        .await
+        .map(LidarrSerdeable::from),
       LidarrEvent::GetDiscographyReleases(artist_id) => self
         .get_artist_discography_releases(artist_id)
         .await
@@ -243,7 +406,31 @@ impl Network<'_, '_> {
         .get_lidarr_downloads(count)
         .await
         .map(LidarrSerdeable::from),
+      LidarrEvent::GrabQueueItem(queue_item_id) => self
+        .grab_lidarr_queue_item(queue_item_id)
+        .await
+        .map(LidarrSerdeable::from),
       LidarrEvent::GetIndexers => self.get_lidarr_indexers().await.map(LidarrSerdeable::from),
+      LidarrEvent::GetIndexerSchema => self
+        .get_lidarr_indexer_schema()
+        .await
+        .map(LidarrSerdeable::from),
+      LidarrEvent::GetDownloadClients => self
+        .get_lidarr_download_clients()
+        .await
+        .map(LidarrSerdeable::from),
+      LidarrEvent::GetDownloadClientSchema => self
+        .get_lidarr_download_client_schema()
+        .await
+        .map(LidarrSerdeable::from),
+      LidarrEvent::DeleteDownloadClient(download_client_id) => self
+        .delete_lidarr_download_client(download_client_id)
+        .await
+        .map(LidarrSerdeable::from),
+      LidarrEvent::EditDownloadClient(params) => self
+        .edit_lidarr_download_client(params)
+        .await
+        .map(LidarrSerdeable::from),
       LidarrEvent::GetHistory(events) => self
         .get_lidarr_history(events)
         .await
@@ -256,6 +443,18 @@ impl Network<'_, '_> {
         .get_lidarr_logs(events)
         .await
         .map(LidarrSerdeable::from),
+      LidarrEvent::GetManualImport(download_id, artist_id) => self
+        .get_lidarr_manual_import(download_id, artist_id)
+        .await
+        .map(LidarrSerdeable::from),
+      LidarrEvent::GetMediaManagementSettings => self
+        .get_lidarr_media_management_settings()
+        .await
+        .map(LidarrSerdeable::from),
+      LidarrEvent::GetNamingExamples(naming_config) => self
+        .get_lidarr_naming_examples(naming_config)
+        .await
+        .map(LidarrSerdeable::from),
       LidarrEvent::MarkHistoryItemAsFailed(history_item_id) => self
         .mark_lidarr_history_item_as_failed(history_item_id)
         .await
@@ -272,6 +471,14 @@ impl Network<'_, '_> {
         .get_lidarr_quality_profiles()
         .await
         .map(LidarrSerdeable::from),
+      LidarrEvent::GetQualityProfileDetails(quality_profile_id) => self
+        .get_lidarr_quality_profile_details(quality_profile_id)
+        .await
+        .map(LidarrSerdeable::from),
+      LidarrEvent::EditQualityProfile(params) => self
+        .edit_lidarr_quality_profile(params)
+        .await
+        .map(LidarrSerdeable::from),
       LidarrEvent::GetQueuedEvents => self
         .get_queued_lidarr_events()
         .await
@@ -309,6 +516,14 @@ impl Network<'_, '_> {
         .await
         .map(LidarrSerdeable::from),
       LidarrEvent::ListArtists => self.list_artists().await.map(LidarrSerdeable::from),
+      LidarrEvent::ManualImport(files) => self
+        .manual_import_lidarr_files(files)
+        .await
+        .map(LidarrSerdeable::from),
+      LidarrEvent::RestoreBackup(file) => self
+        .restore_lidarr_backup(file)
+        .await
+        .map(LidarrSerdeable::from),
       LidarrEvent::SearchNewArtist(query) => {
         self.search_artist(query).await.map(LidarrSerdeable::from)
       }
@@ -332,6 +547,14 @@ impl Network<'_, '_> {
         .trigger_automatic_album_search(album_id)
         .await
         .map(LidarrSerdeable::from),
+      LidarrEvent::TriggerCutoffUnmetAlbumSearch => self
+        .trigger_cutoff_unmet_album_search()
+        .await
+        .map(LidarrSerdeable::from),
+      LidarrEvent::TriggerMissingAlbumSearch => self
+        .trigger_missing_album_search()
+        .await
+        .map(LidarrSerdeable::from),
       LidarrEvent::UpdateAllArtists => self.update_all_artists().await.map(LidarrSerdeable::from),
       LidarrEvent::UpdateAndScanArtist(artist_id) => self
         .update_and_scan_artist(artist_id)
@@ -347,6 +570,14 @@ impl Network<'_, '_> {
         .test_all_lidarr_indexers()
         .await
         .map(LidarrSerdeable::from),
+      LidarrEvent::TestDownloadClient(download_client_id) => self
+        .test_lidarr_download_client(download_client_id)
+        .await
+        .map(LidarrSerdeable::from),
+      LidarrEvent::TestAllDownloadClients => self
+        .test_all_lidarr_download_clients()
+        .await
+        .map(LidarrSerdeable::from),
       LidarrEvent::TestIndexer(indexer_id) => self
         .test_lidarr_indexer(indexer_id)
         .await
@@ -396,9 +627,14 @@ impl Network<'_, '_> {
     self
       .handle_request::<(), Vec<QualityProfile>>(request_props, |quality_profiles, mut app| {
         app.data.lidarr_data.quality_profile_map = quality_profiles
-          .into_iter()
-          .map(|profile| (profile.id, profile.name))
+          .iter()
+          .map(|profile| (profile.id, profile.name.clone()))
           .collect();
+        app
+          .data
+          .lidarr_data
+          .quality_profiles
+          .set_items(quality_profiles);
       })
       .await
   }
@@ -475,11 +711,14 @@ impl Network<'_, '_> {
         .collect::<Vec<&str>>()
     };
 
+    let mut added_tags = Vec::new();
     for tag in missing_tags_vec {
-      self
-        .add_lidarr_tag(tag.trim().to_owned())
-        .await
-        .expect("Unable to add tag");
+      added_tags.push(
+        self
+          .add_lidarr_tag(tag.trim().to_owned())
+          .await
+          .expect("Unable to add tag"),
+      );
     }
 
     let app = self.app.lock().await;
@@ -487,11 +726,13 @@ impl Network<'_, '_> {
       .split(',')
       .filter(|tag| !tag.is_empty())
       .map(|tag| {
-        *app
-          .data
-          .lidarr_data
-          .tags_map
-          .get_by_right(tag.to_lowercase().trim())
+        let label = tag.to_lowercase();
+        let label = label.trim();
+        added_tags
+          .iter()
+          .find(|added_tag| added_tag.label.to_lowercase() == label)
+          .map(|added_tag| added_tag.id)
+          .or_else(|| app.data.lidarr_data.tags_map.get_by_right(label).copied())
           .unwrap()
       })
       .collect()
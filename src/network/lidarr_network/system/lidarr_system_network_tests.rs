@@ -3,15 +3,171 @@ mod tests {
   use crate::models::HorizontallyScrollableText;
   use crate::models::lidarr_models::{LidarrSerdeable, LidarrTask, LidarrTaskName, SystemStatus};
   use crate::models::servarr_models::{
-    DiskSpace, HostConfig, LogResponse, QueueEvent, SecurityConfig, Update,
+    DiskSpace, DownloadBackupParams, HostConfig, LogResponse, QueueEvent, SecurityConfig, Update,
   };
   use crate::network::lidarr_network::LidarrEvent;
   use crate::network::lidarr_network::lidarr_network_test_utils::test_utils::updates;
   use crate::network::network_tests::test_utils::{MockServarrApi, test_network};
+  use crate::network::servarr_test_utils::backup;
   use chrono::DateTime;
   use pretty_assertions::assert_eq;
   use serde_json::json;
 
+  #[tokio::test]
+  async fn test_handle_delete_lidarr_backup_event() {
+    let (mock, app, _server) = MockServarrApi::delete()
+      .path("/1")
+      .build_for(LidarrEvent::DeleteBackup(1))
+      .await;
+    app.lock().await.server_tabs.set_index(2);
+    let mut network = test_network(&app);
+
+    assert!(
+      network
+        .handle_lidarr_event(LidarrEvent::DeleteBackup(1))
+        .await
+        .is_ok()
+    );
+
+    mock.assert_async().await;
+  }
+
+  #[tokio::test]
+  async fn test_handle_download_lidarr_backup_event() {
+    let destination = std::env::temp_dir().join("managarr_test_lidarr_backup.zip");
+    let params = DownloadBackupParams {
+      backup_path: "/backup/scheduled/backup.zip".to_owned(),
+      destination: destination.clone(),
+    };
+    let (_, app, mut server) = MockServarrApi::get()
+      .build_for(LidarrEvent::DownloadBackup(params.clone()))
+      .await;
+    let mock = server
+      .mock("GET", "/backup/scheduled/backup.zip")
+      .match_header("X-Api-Key", "test1234")
+      .with_body("backup contents")
+      .create_async()
+      .await;
+    app.lock().await.server_tabs.set_index(2);
+    let mut network = test_network(&app);
+
+    assert!(
+      network
+        .handle_lidarr_event(LidarrEvent::DownloadBackup(params))
+        .await
+        .is_ok()
+    );
+
+    mock.assert_async().await;
+    assert_eq!(
+      std::fs::read_to_string(&destination).unwrap(),
+      "backup contents"
+    );
+    std::fs::remove_file(destination).unwrap();
+  }
+
+  #[tokio::test]
+  async fn test_handle_download_lidarr_backup_event_error() {
+    let destination = std::env::temp_dir().join("managarr_test_lidarr_backup_error.zip");
+    let params = DownloadBackupParams {
+      backup_path: "/backup/scheduled/backup.zip".to_owned(),
+      destination: destination.clone(),
+    };
+    let (_, app, mut server) = MockServarrApi::get()
+      .build_for(LidarrEvent::DownloadBackup(params.clone()))
+      .await;
+    let mock = server
+      .mock("GET", "/backup/scheduled/backup.zip")
+      .with_status(404)
+      .create_async()
+      .await;
+    app.lock().await.server_tabs.set_index(2);
+    let mut network = test_network(&app);
+
+    assert!(
+      network
+        .handle_lidarr_event(LidarrEvent::DownloadBackup(params))
+        .await
+        .is_err()
+    );
+
+    mock.assert_async().await;
+    assert!(!destination.exists());
+    assert!(app.lock().await.error.text.contains("404"));
+  }
+
+  #[tokio::test]
+  async fn test_handle_get_lidarr_backups_event() {
+    let backups_json = json!([{
+      "id": 1,
+      "name": "radarr_backup_v5.3.6.8612_2024.01.01_00.00.00.zip",
+      "path": "/backup/scheduled/radarr_backup_v5.3.6.8612_2024.01.01_00.00.00.zip",
+      "type": "scheduled",
+      "size": 1048576,
+      "time": "2024-01-01T00:00:00Z"
+    }]);
+    let (mock, app, _server) = MockServarrApi::get()
+      .returns(backups_json)
+      .build_for(LidarrEvent::GetBackups)
+      .await;
+    app.lock().await.server_tabs.set_index(2);
+    let mut network = test_network(&app);
+
+    let LidarrSerdeable::Backups(backups) = network
+      .handle_lidarr_event(LidarrEvent::GetBackups)
+      .await
+      .unwrap()
+    else {
+      panic!("Expected Backups")
+    };
+    mock.assert_async().await;
+    assert_eq!(
+      app.lock().await.data.lidarr_data.backups.items,
+      vec![backup()]
+    );
+    assert_eq!(backups, vec![backup()]);
+  }
+
+  #[tokio::test]
+  async fn test_handle_restore_lidarr_backup_event() {
+    let file = std::env::temp_dir().join("managarr_test_lidarr_restore_backup.zip");
+    std::fs::write(&file, "backup contents").unwrap();
+    let (mock, app, _server) = MockServarrApi::post()
+      .returns(json!({ "restartRequired": true }))
+      .build_for(LidarrEvent::RestoreBackup(file.clone()))
+      .await;
+    app.lock().await.server_tabs.set_index(2);
+    let mut network = test_network(&app);
+
+    let LidarrSerdeable::Value(value) = network
+      .handle_lidarr_event(LidarrEvent::RestoreBackup(file.clone()))
+      .await
+      .unwrap()
+    else {
+      panic!("Expected Value")
+    };
+    mock.assert_async().await;
+    assert_eq!(value, json!({ "restartRequired": true }));
+    std::fs::remove_file(file).unwrap();
+  }
+
+  #[tokio::test]
+  async fn test_handle_restore_lidarr_backup_event_missing_file() {
+    let file = std::env::temp_dir().join("managarr_test_lidarr_missing_backup.zip");
+    let (mock, app, _server) = MockServarrApi::post()
+      .build_for(LidarrEvent::RestoreBackup(file.clone()))
+      .await;
+    app.lock().await.server_tabs.set_index(2);
+    let mut network = test_network(&app);
+
+    let result = network
+      .handle_lidarr_event(LidarrEvent::RestoreBackup(file))
+      .await;
+
+    assert!(!mock.matched_async().await);
+    assert!(result.is_err());
+  }
+
   #[tokio::test]
   async fn test_handle_get_diskspace_event() {
     let diskspace_json = json!([
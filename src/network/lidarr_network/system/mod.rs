@@ -1,6 +1,7 @@
 use crate::models::lidarr_models::{LidarrTask, LidarrTaskName, SystemStatus};
 use crate::models::servarr_models::{
-  CommandBody, DiskSpace, HostConfig, LogResponse, QueueEvent, SecurityConfig, Update,
+  Backup, CommandBody, DiskSpace, DownloadBackupParams, HostConfig, LogResponse, QueueEvent,
+  SecurityConfig, Update,
 };
 use crate::models::{HorizontallyScrollableText, Scrollable, ScrollableText};
 use crate::network::lidarr_network::LidarrEvent;
@@ -9,12 +10,68 @@ use anyhow::Result;
 use indoc::formatdoc;
 use log::info;
 use serde_json::Value;
+use std::path::PathBuf;
 
 #[cfg(test)]
 #[path = "lidarr_system_network_tests.rs"]
 mod lidarr_system_network_tests;
 
 impl Network<'_, '_> {
+  pub(in crate::network::lidarr_network) async fn delete_lidarr_backup(
+    &mut self,
+    backup_id: i64,
+  ) -> Result<()> {
+    info!("Deleting Lidarr backup with id: {backup_id}");
+    let event = LidarrEvent::DeleteBackup(backup_id);
+
+    let request_props = self
+      .request_props_from(
+        event,
+        RequestMethod::Delete,
+        None::<()>,
+        Some(format!("/{backup_id}")),
+        None,
+      )
+      .await;
+
+    self
+      .handle_request::<(), ()>(request_props, |_, _| ())
+      .await
+  }
+
+  pub(in crate::network::lidarr_network) async fn download_lidarr_backup(
+    &mut self,
+    params: DownloadBackupParams,
+  ) -> Result<()> {
+    info!(
+      "Downloading Lidarr backup '{}' to: {}",
+      params.backup_path,
+      params.destination.display()
+    );
+    let event = LidarrEvent::DownloadBackup(params.clone());
+
+    self
+      .download_file(event, &params.backup_path, &params.destination)
+      .await
+  }
+
+  pub(in crate::network::lidarr_network) async fn get_lidarr_backups(
+    &mut self,
+  ) -> Result<Vec<Backup>> {
+    info!("Fetching Lidarr backups");
+    let event = LidarrEvent::GetBackups;
+
+    let request_props = self
+      .request_props_from(event, RequestMethod::Get, None::<()>, None, None)
+      .await;
+
+    self
+      .handle_request::<(), Vec<Backup>>(request_props, |backups_vec, mut app| {
+        app.data.lidarr_data.backups.set_items(backups_vec);
+      })
+      .await
+  }
+
   pub(in crate::network::lidarr_network) async fn get_lidarr_host_config(
     &mut self,
   ) -> Result<HostConfig> {
@@ -254,6 +311,16 @@ impl Network<'_, '_> {
       .await
   }
 
+  pub(in crate::network::lidarr_network) async fn restore_lidarr_backup(
+    &mut self,
+    file: PathBuf,
+  ) -> Result<Value> {
+    info!("Restoring Lidarr backup from: {}", file.display());
+    let event = LidarrEvent::RestoreBackup(file.clone());
+
+    self.upload_file(event, &file).await
+  }
+
   pub(in crate::network::lidarr_network) async fn start_lidarr_task(
     &mut self,
     task: LidarrTaskName,
@@ -0,0 +1,108 @@
+#[cfg(test)]
+mod tests {
+  use crate::models::lidarr_models::{LidarrSerdeable, WantedAlbumsResponse};
+  use crate::network::lidarr_network::LidarrEvent;
+  use crate::network::lidarr_network::lidarr_network_test_utils::test_utils::album;
+  use crate::network::network_tests::test_utils::{MockServarrApi, test_network};
+  use pretty_assertions::assert_eq;
+  use serde_json::json;
+
+  const WANTED_QUERY: &str =
+    "page=1&pageSize=500&sortDirection=descending&sortKey=releaseDate&monitored=true";
+
+  #[tokio::test]
+  async fn test_handle_get_lidarr_wanted_missing_event() {
+    let expected_response = WantedAlbumsResponse {
+      records: vec![album()],
+    };
+    let (mock, app, _server) = MockServarrApi::get()
+      .query(WANTED_QUERY)
+      .returns(json!({ "records": [album()] }))
+      .build_for(LidarrEvent::GetWantedMissing(500))
+      .await;
+    app.lock().await.server_tabs.set_index(2);
+    let mut network = test_network(&app);
+
+    let LidarrSerdeable::WantedAlbumsResponse(response) = network
+      .handle_lidarr_event(LidarrEvent::GetWantedMissing(500))
+      .await
+      .unwrap()
+    else {
+      panic!("Expected WantedAlbumsResponse")
+    };
+
+    mock.assert_async().await;
+    assert_eq!(
+      app.lock().await.data.lidarr_data.wanted_missing.items,
+      vec![album()]
+    );
+    assert_eq!(response, expected_response);
+  }
+
+  #[tokio::test]
+  async fn test_handle_get_lidarr_cutoff_unmet_event() {
+    let expected_response = WantedAlbumsResponse {
+      records: vec![album()],
+    };
+    let (mock, app, _server) = MockServarrApi::get()
+      .query(WANTED_QUERY)
+      .returns(json!({ "records": [album()] }))
+      .build_for(LidarrEvent::GetCutoffUnmet(500))
+      .await;
+    app.lock().await.server_tabs.set_index(2);
+    let mut network = test_network(&app);
+
+    let LidarrSerdeable::WantedAlbumsResponse(response) = network
+      .handle_lidarr_event(LidarrEvent::GetCutoffUnmet(500))
+      .await
+      .unwrap()
+    else {
+      panic!("Expected WantedAlbumsResponse")
+    };
+
+    mock.assert_async().await;
+    assert_eq!(
+      app.lock().await.data.lidarr_data.cutoff_unmet.items,
+      vec![album()]
+    );
+    assert_eq!(response, expected_response);
+  }
+
+  #[tokio::test]
+  async fn test_handle_trigger_missing_album_search_event() {
+    let (mock, app, _server) = MockServarrApi::post()
+      .with_request_body(json!({ "name": "MissingAlbumSearch" }))
+      .returns(json!({}))
+      .build_for(LidarrEvent::TriggerMissingAlbumSearch)
+      .await;
+    app.lock().await.server_tabs.set_index(2);
+    let mut network = test_network(&app);
+
+    assert_ok!(
+      network
+        .handle_lidarr_event(LidarrEvent::TriggerMissingAlbumSearch)
+        .await
+    );
+
+    mock.assert_async().await;
+  }
+
+  #[tokio::test]
+  async fn test_handle_trigger_cutoff_unmet_album_search_event() {
+    let (mock, app, _server) = MockServarrApi::post()
+      .with_request_body(json!({ "name": "CutoffUnmetAlbumSearch" }))
+      .returns(json!({}))
+      .build_for(LidarrEvent::TriggerCutoffUnmetAlbumSearch)
+      .await;
+    app.lock().await.server_tabs.set_index(2);
+    let mut network = test_network(&app);
+
+    assert_ok!(
+      network
+        .handle_lidarr_event(LidarrEvent::TriggerCutoffUnmetAlbumSearch)
+        .await
+    );
+
+    mock.assert_async().await;
+  }
+}
@@ -0,0 +1,110 @@
+use crate::models::lidarr_models::{LidarrCommandBody, WantedAlbumsResponse};
+use crate::network::lidarr_network::LidarrEvent;
+use crate::network::{Network, RequestMethod};
+use anyhow::Result;
+use log::info;
+use serde_json::Value;
+
+#[cfg(test)]
+#[path = "lidarr_wanted_network_tests.rs"]
+mod lidarr_wanted_network_tests;
+
+impl Network<'_, '_> {
+  pub(in crate::network::lidarr_network) async fn get_lidarr_wanted_missing(
+    &mut self,
+    count: u64,
+  ) -> Result<WantedAlbumsResponse> {
+    info!("Fetching missing Lidarr albums");
+    let event = LidarrEvent::GetWantedMissing(count);
+
+    let request_props = self
+      .request_props_from(
+        event,
+        RequestMethod::Get,
+        None::<()>,
+        None,
+        Some(wanted_query_params(count)),
+      )
+      .await;
+
+    self
+      .handle_request::<(), WantedAlbumsResponse>(request_props, |wanted_response, mut app| {
+        app
+          .data
+          .lidarr_data
+          .wanted_missing
+          .set_items(wanted_response.records);
+      })
+      .await
+  }
+
+  pub(in crate::network::lidarr_network) async fn get_lidarr_cutoff_unmet(
+    &mut self,
+    count: u64,
+  ) -> Result<WantedAlbumsResponse> {
+    info!("Fetching Lidarr albums that have not met their quality cutoff");
+    let event = LidarrEvent::GetCutoffUnmet(count);
+
+    let request_props = self
+      .request_props_from(
+        event,
+        RequestMethod::Get,
+        None::<()>,
+        None,
+        Some(wanted_query_params(count)),
+      )
+      .await;
+
+    self
+      .handle_request::<(), WantedAlbumsResponse>(request_props, |wanted_response, mut app| {
+        app
+          .data
+          .lidarr_data
+          .cutoff_unmet
+          .set_items(wanted_response.records);
+      })
+      .await
+  }
+
+  pub(in crate::network::lidarr_network) async fn trigger_missing_album_search(
+    &mut self,
+  ) -> Result<Value> {
+    info!("Searching indexers for all missing albums");
+    let event = LidarrEvent::TriggerMissingAlbumSearch;
+    let body = LidarrCommandBody {
+      name: "MissingAlbumSearch".to_owned(),
+      ..LidarrCommandBody::default()
+    };
+
+    let request_props = self
+      .request_props_from(event, RequestMethod::Post, Some(body), None, None)
+      .await;
+
+    self
+      .handle_request::<LidarrCommandBody, Value>(request_props, |_, _| ())
+      .await
+  }
+
+  pub(in crate::network::lidarr_network) async fn trigger_cutoff_unmet_album_search(
+    &mut self,
+  ) -> Result<Value> {
+    info!("Searching indexers for all albums that have not met their quality cutoff");
+    let event = LidarrEvent::TriggerCutoffUnmetAlbumSearch;
+    let body = LidarrCommandBody {
+      name: "CutoffUnmetAlbumSearch".to_owned(),
+      ..LidarrCommandBody::default()
+    };
+
+    let request_props = self
+      .request_props_from(event, RequestMethod::Post, Some(body), None, None)
+      .await;
+
+    self
+      .handle_request::<LidarrCommandBody, Value>(request_props, |_, _| ())
+      .await
+  }
+}
+
+fn wanted_query_params(count: u64) -> String {
+  format!("page=1&pageSize={count}&sortDirection=descending&sortKey=releaseDate&monitored=true")
+}
@@ -1,14 +1,21 @@
+use std::collections::HashMap;
 use std::fmt::Debug;
+use std::path::Path;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::time::Duration;
 
-use anyhow::{Result, anyhow};
+use anyhow::{Context, Result, anyhow};
 use async_trait::async_trait;
 use lidarr_network::LidarrEvent;
 use log::{debug, error, warn};
-use regex::Regex;
-use reqwest::{Client, RequestBuilder};
-use serde::Serialize;
+use prowlarr_network::ProwlarrEvent;
+use readarr_network::ReadarrEvent;
+use reqwest::multipart::{Form, Part};
+use reqwest::{Client, RequestBuilder, Response, StatusCode};
 use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use sonarr_network::SonarrEvent;
 use strum_macros::Display;
 use tokio::select;
@@ -16,14 +23,24 @@ use tokio::sync::{Mutex, MutexGuard};
 use tokio_util::sync::CancellationToken;
 
 use crate::app::{App, ServarrConfig};
-use crate::models::Serdeable;
+use crate::models::{Route, Serdeable, ServarrInstance, TabRoute};
+use crate::network::demo_network::DemoBackend;
 use crate::network::radarr_network::RadarrEvent;
+use crate::network::recording::{RecordedExchange, RecordedRequest, Recording};
+use crate::network::servarr_error::ServarrError;
 #[cfg(test)]
 use mockall::automock;
 use reqwest::header::HeaderMap;
 
+pub mod demo_network;
+pub mod event_queue;
 pub mod lidarr_network;
+pub mod prowlarr_network;
 pub mod radarr_network;
+pub mod readarr_network;
+pub mod recording;
+pub mod servarr_error;
+pub mod signalr;
 pub mod sonarr_network;
 mod utils;
 
@@ -32,6 +49,9 @@ mod network_tests;
 #[cfg(test)]
 pub mod servarr_test_utils;
 
+/// Identifies each request in [App::retry_attempts] while it's being retried
+static NEXT_REQUEST_ID: AtomicU64 = AtomicU64::new(0);
+
 #[cfg_attr(test, automock)]
 #[async_trait]
 pub trait NetworkTrait {
@@ -40,6 +60,9 @@ pub trait NetworkTrait {
 
 pub trait NetworkResource {
   fn resource(&self) -> &'static str;
+
+  /// Whether handling the event only fetches data from the Servarr
+  fn is_read_only(&self) -> bool;
 }
 
 #[derive(PartialEq, Eq, Debug, Clone)]
@@ -47,18 +70,41 @@ pub enum NetworkEvent {
   Radarr(RadarrEvent),
   Sonarr(SonarrEvent),
   Lidarr(LidarrEvent),
+  Prowlarr(ProwlarrEvent),
+  Readarr(ReadarrEvent),
+}
+
+/// A network event along with the Servarr instance it's for. The instance is captured when the
+/// event is dispatched, so the event is still sent to that instance if the active tab changes
+/// while it's queued.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct QueuedNetworkEvent {
+  pub instance: ServarrInstance,
+  pub network_event: NetworkEvent,
 }
 
+/// The tab of the Servarr instance that a network sends its requests to, along with the client
+/// for that instance, as they were when the network was targeted at it
 #[derive(Clone)]
-pub struct Network<'a, 'b> {
+struct NetworkTarget {
+  tab: TabRoute,
   client: Client,
+}
+
+#[derive(Clone)]
+pub struct Network<'a, 'b> {
   pub cancellation_token: CancellationToken,
   pub app: &'a Arc<Mutex<App<'b>>>,
+  demo_backend: Option<DemoBackend>,
+  recording: Option<Recording>,
+  in_flight_events: Arc<AtomicUsize>,
+  target: Option<NetworkTarget>,
 }
 
 #[async_trait]
 impl NetworkTrait for Network<'_, '_> {
   async fn handle_network_event(&mut self, network_event: NetworkEvent) -> Result<Serdeable> {
+    self.in_flight_events.fetch_add(1, Ordering::SeqCst);
     let resp = match network_event {
       NetworkEvent::Radarr(radarr_event) => self
         .handle_radarr_event(radarr_event)
@@ -72,28 +118,105 @@ impl NetworkTrait for Network<'_, '_> {
         .handle_lidarr_event(lidarr_event)
         .await
         .map(Serdeable::from),
+      NetworkEvent::Prowlarr(prowlarr_event) => self
+        .handle_prowlarr_event(prowlarr_event)
+        .await
+        .map(Serdeable::from),
+      NetworkEvent::Readarr(readarr_event) => self
+        .handle_readarr_event(readarr_event)
+        .await
+        .map(Serdeable::from),
     };
 
     let mut app = self.app.lock().await;
-    app.is_loading = false;
+    if self.in_flight_events.fetch_sub(1, Ordering::SeqCst) == 1 {
+      app.is_loading = false;
+    }
 
     resp
   }
 }
 
 impl<'a, 'b> Network<'a, 'b> {
-  pub fn new(
-    app: &'a Arc<Mutex<App<'b>>>,
-    cancellation_token: CancellationToken,
-    client: Client,
-  ) -> Self {
+  pub fn new(app: &'a Arc<Mutex<App<'b>>>, cancellation_token: CancellationToken) -> Self {
     Network {
-      client,
       app,
       cancellation_token,
+      demo_backend: None,
+      recording: None,
+      in_flight_events: Arc::new(AtomicUsize::new(0)),
+      target: None,
+    }
+  }
+
+  /// Records every request made to the Servarrs, or replays previously recorded responses
+  /// instead of contacting them
+  pub fn with_recording(mut self, recording: Option<Recording>) -> Self {
+    self.recording = recording;
+    self
+  }
+
+  /// A network that answers every request from the built-in demo fixtures instead of contacting
+  /// the configured Servarrs
+  pub fn demo(app: &'a Arc<Mutex<App<'b>>>, cancellation_token: CancellationToken) -> Self {
+    Network {
+      demo_backend: Some(DemoBackend::default()),
+      ..Network::new(app, cancellation_token)
+    }
+  }
+
+  /// Sends the requests for the following events to the given instance instead of the active
+  /// one, using the config and client the instance has at this point
+  pub(super) async fn target(&mut self, instance: &ServarrInstance) -> Result<()> {
+    let app = self.app.lock().await;
+    let tab = app
+      .server_tabs
+      .tabs
+      .iter()
+      .find(|tab| tab.instance() == *instance)
+      .ok_or_else(|| anyhow!("{instance} is no longer configured"))?;
+    let client = app.network_clients.get(instance)?.clone();
+    self.target = Some(NetworkTarget {
+      tab: tab.clone(),
+      client,
+    });
+
+    Ok(())
+  }
+
+  /// The client for the targeted Servarr instance, or the active one if there is no target
+  async fn client(&self) -> Result<Client> {
+    if let Some(target) = &self.target {
+      return Ok(target.client.clone());
+    }
+
+    let app = self.app.lock().await;
+    let instance = app
+      .server_tabs
+      .get_active_instance()
+      .ok_or_else(|| anyhow!("No Servarr is configured"))?;
+
+    app.network_clients.get(&instance).cloned()
+  }
+
+  /// The route of the targeted Servarr instance, or the active one if there is no target
+  async fn servarr_route(&self) -> Route {
+    match &self.target {
+      Some(target) => target.tab.route,
+      None => self.app.lock().await.server_tabs.get_active_route(),
     }
   }
 
+  /// Whether the targeted Servarr instance is the active one. The responses of any other instance
+  /// aren't written to the app, since they'd overwrite the data of the active one, but they're still
+  /// returned to the caller.
+  fn is_target_active(&self, app: &App<'_>) -> bool {
+    self
+      .target
+      .as_ref()
+      .is_none_or(|target| app.server_tabs.get_active_instance() == Some(target.tab.instance()))
+  }
+
   pub(super) async fn reset_cancellation_token(&mut self) {
     self.cancellation_token = self.app.lock().await.reset_cancellation_token();
   }
@@ -101,66 +224,281 @@ impl<'a, 'b> Network<'a, 'b> {
   async fn handle_request<B, R>(
     &mut self,
     request_props: RequestProps<B>,
-    mut app_update_fn: impl FnMut(R, MutexGuard<'_, App<'_>>),
+    app_update_fn: impl FnMut(R, MutexGuard<'_, App<'_>>),
   ) -> Result<R>
   where
     B: Serialize + Default + Debug,
     R: DeserializeOwned + Default + Clone,
   {
+    if self.demo_backend.is_some() {
+      return self.handle_demo_request(request_props, app_update_fn).await;
+    }
+
     let ignore_status_code = request_props.ignore_status_code;
     let method = request_props.method;
     let request_uri = request_props.uri.clone();
-    select! {
-    _ = self.cancellation_token.cancelled() => {
-        warn!("Received Cancel request. Cancelling request to: {request_uri}");
-        Ok(R::default())
+
+    if let Some(Recording::Replay(replayer)) = &self.recording {
+      let servarr = self.servarr_route().await;
+      let Some(exchange) = replayer.replay(&servarr, method, &request_uri) else {
+        error!("No recorded response found for {method:?} request to: {request_uri}");
+        self.app.lock().await.handle_error(anyhow!(
+          "No recorded response found for {method} request to: {request_uri}"
+        ));
+        return Err(anyhow!(
+          "No recorded response found for {method} request to: {request_uri}"
+        ));
+      };
+      let status = StatusCode::from_u16(exchange.status)?;
+      let response_body = exchange.response_body.clone();
+
+      return self
+        .handle_response(
+          method,
+          status,
+          response_body,
+          ignore_status_code,
+          app_update_fn,
+        )
+        .await;
+    }
+
+    let recorded_request = if let Some(Recording::Record(_)) = &self.recording {
+      Some(RecordedRequest {
+        method,
+        uri: request_uri.clone(),
+        api_token: request_props.api_token.clone(),
+        custom_headers: request_props.custom_headers.clone(),
+        body: serde_json::to_value(&request_props.body)?,
+      })
+    } else {
+      None
+    };
+
+    let ConnectionSettings {
+      retries,
+      retry_backoff,
+      ..
+    } = request_props.connection_settings;
+    let mut request = self.call_api(request_props).await?;
+    let request_id = NEXT_REQUEST_ID.fetch_add(1, Ordering::Relaxed);
+    let mut attempt = 0;
+    let resp = loop {
+      let retry_request = request.try_clone();
+      let resp = select! {
+        _ = self.cancellation_token.cancelled() => {
+          warn!("Received Cancel request. Cancelling request to: {request_uri}");
+          self.app.lock().await.retry_attempts.remove(&request_id);
+          return Err(ServarrError::Cancelled.into());
+        }
+        resp = request.send() => resp
+      };
+
+      match retry_request {
+        Some(retry_request)
+          if method == RequestMethod::Get && attempt < retries && is_retryable(&resp) =>
+        {
+          request = retry_request;
+        }
+        _ => break resp,
       }
-    resp = self.call_api(request_props).await.send() => {
-         match resp {
-          Ok(response) => {
-            if response.status().is_success() || ignore_status_code {
-              match method {
-                RequestMethod::Get | RequestMethod::Post => {
-                  match utils::parse_response::<R>(response).await {
-                    Ok(value) => {
-                      let app = self.app.lock().await;
-                      app_update_fn(value.clone(), app);
-                      Ok(value)
-                    }
-                    Err(e) => {
-                      error!("Failed to parse response! {e:?}");
-                      self
-                        .app
-                        .lock()
-                        .await
-                        .handle_error(anyhow!("Failed to parse response! {e:?}"));
-                        Err(anyhow!("Failed to parse response! {e:?}"))
-                    }
-                  }
-                }
-                RequestMethod::Delete | RequestMethod::Put => Ok(R::default()),
+
+      attempt += 1;
+      let backoff = retry_backoff.saturating_mul(2u32.saturating_pow(u32::from(attempt - 1)));
+      warn!("Request to {request_uri} failed. Retrying ({attempt}/{retries}) in {backoff:?}");
+      self
+        .app
+        .lock()
+        .await
+        .retry_attempts
+        .insert(request_id, (attempt, retries));
+
+      select! {
+        _ = self.cancellation_token.cancelled() => {
+          warn!("Received Cancel request. Cancelling request to: {request_uri}");
+          self.app.lock().await.retry_attempts.remove(&request_id);
+          return Err(ServarrError::Cancelled.into());
+        }
+        _ = tokio::time::sleep(backoff) => ()
+      }
+    };
+
+    if attempt > 0 {
+      self.app.lock().await.retry_attempts.remove(&request_id);
+    }
+
+    match resp {
+      Ok(response) => {
+        let status = response.status();
+        let response_body = response.text().await.unwrap_or_default();
+
+        if let Some(request) = recorded_request {
+          let servarr = self.servarr_route().await;
+          let exchange = RecordedExchange::new(&servarr, request, status.as_u16(), &response_body);
+          if let Some(Recording::Record(recorder)) = self.recording.as_mut()
+            && let Err(e) = recorder.record(&exchange)
+          {
+            warn!("Failed to record the response from {request_uri}: {e:?}");
+          }
+        }
+
+        self
+          .handle_response(
+            method,
+            status,
+            response_body,
+            ignore_status_code,
+            app_update_fn,
+          )
+          .await
+      }
+      Err(e) => {
+        error!("Failed to send request. {e:?}");
+        self.handle_servarr_error(ServarrError::from(&e)).await
+      }
+    }
+  }
+
+  async fn handle_response<R>(
+    &mut self,
+    method: RequestMethod,
+    status: StatusCode,
+    response_body: String,
+    ignore_status_code: bool,
+    mut app_update_fn: impl FnMut(R, MutexGuard<'_, App<'_>>),
+  ) -> Result<R>
+  where
+    R: DeserializeOwned + Default + Clone,
+  {
+    if status.is_success() || ignore_status_code {
+      match method {
+        RequestMethod::Get | RequestMethod::Post => {
+          match utils::parse_response::<R>(&response_body) {
+            Ok(value) => {
+              let app = self.app.lock().await;
+              if self.is_target_active(&app) {
+                app_update_fn(value.clone(), app);
               }
-            } else {
-              let status = response.status();
-              let whitespace_regex = Regex::new(r"\s+")?;
-              let response_body = response.text().await.unwrap_or_default();
-              let error_body = whitespace_regex
-                .replace_all(&response_body.replace('\n', " "), " ")
-                .to_string();
-
-              error!("Request failed. Received {status} response code with body: {response_body}");
-              self.app.lock().await.handle_error(anyhow!("Request failed. Received {status} response code with body: {error_body}"));
-              Err(anyhow!("Request failed. Received {status} response code with body: {error_body}"))
+
+              Ok(value)
+            }
+            Err(e) => {
+              self
+                .handle_servarr_error(ServarrError::Parse(format!("{e:?}")))
+                .await
             }
           }
+        }
+        RequestMethod::Delete | RequestMethod::Put => Ok(R::default()),
+      }
+    } else {
+      self
+        .handle_servarr_error(ServarrError::from_response(status, &response_body))
+        .await
+    }
+  }
+
+  /// Shows the error in the UI and returns it so the CLI can exit with its exit code
+  pub(super) async fn handle_servarr_error<R>(&mut self, error: ServarrError) -> Result<R> {
+    error!("{error}");
+    self.app.lock().await.handle_error(anyhow!(error.clone()));
+    Err(error.into())
+  }
+
+  /// Downloads a file served by the Servarr outside of its API (e.g. a backup) to the given
+  /// destination. The `path` is relative to the Servarr's base URL.
+  async fn download_file<N>(
+    &mut self,
+    network_event: N,
+    path: &str,
+    destination: &Path,
+  ) -> Result<()>
+  where
+    N: Into<NetworkEvent> + NetworkResource,
+  {
+    let request_props = self
+      .request_props_from(network_event, RequestMethod::Get, None::<()>, None, None)
+      .await;
+    let base_uri = request_props
+      .uri
+      .split_once("/api/")
+      .map_or(request_props.uri.as_str(), |(base_uri, _)| base_uri);
+    let uri = format!("{base_uri}{path}");
+    let request = self
+      .client()
+      .await?
+      .get(&uri)
+      .header("X-Api-Key", request_props.api_token)
+      .headers(request_props.custom_headers);
+
+    let response = self.send_file_request(request, &uri).await?;
+    let contents = response.bytes().await?;
+    tokio::fs::write(destination, contents)
+      .await
+      .with_context(|| format!("Unable to write file '{}'", destination.display()))
+  }
+
+  /// Uploads the given file to the Servarr as `multipart/form-data` and returns the JSON response
+  async fn upload_file<N>(&mut self, network_event: N, file: &Path) -> Result<Value>
+  where
+    N: Into<NetworkEvent> + NetworkResource,
+  {
+    let contents = tokio::fs::read(file)
+      .await
+      .with_context(|| format!("Unable to read file '{}'", file.display()))?;
+    let file_name = file
+      .file_name()
+      .map(|name| name.to_string_lossy().into_owned())
+      .unwrap_or_default();
+    let request_props = self
+      .request_props_from(network_event, RequestMethod::Post, None::<()>, None, None)
+      .await;
+    let file_part = Part::bytes(contents)
+      .file_name(file_name)
+      .mime_str("application/octet-stream")?;
+    let request = self
+      .client()
+      .await?
+      .post(&request_props.uri)
+      .header("X-Api-Key", request_props.api_token)
+      .headers(request_props.custom_headers)
+      .multipart(Form::new().part("file", file_part));
+
+    let response = self.send_file_request(request, &request_props.uri).await?;
+    let response_body = response.text().await?;
+    if response_body.trim().is_empty() {
+      Ok(Value::default())
+    } else {
+      Ok(serde_json::from_str(&response_body)?)
+    }
+  }
+
+  async fn send_file_request(&mut self, request: RequestBuilder, uri: &str) -> Result<Response> {
+    debug!("Sending file request to {uri}");
+
+    if self.demo_backend.is_some() || matches!(self.recording, Some(Recording::Replay(_))) {
+      let error = anyhow!("Transferring files is not supported in demo or replay mode");
+      self.app.lock().await.handle_error(anyhow!("{error}"));
+      return Err(error);
+    }
+
+    select! {
+      _ = self.cancellation_token.cancelled() => {
+        warn!("Received Cancel request. Cancelling request to: {uri}");
+        Err(ServarrError::Cancelled.into())
+      }
+      resp = request.send() => {
+        match resp {
+          Ok(response) if response.status().is_success() => Ok(response),
+          Ok(response) => {
+            let status = response.status();
+            let response_body = response.text().await.unwrap_or_default();
+
+            self.handle_servarr_error(ServarrError::from_response(status, &response_body)).await
+          }
           Err(e) => {
             error!("Failed to send request. {e:?}");
-            self
-              .app
-              .lock()
-              .await
-              .handle_error(anyhow!("Failed to send request. {e} "));
-              Err(anyhow!("Failed to send request. {e} "))
+            self.handle_servarr_error(ServarrError::from(&e)).await
           }
         }
       }
@@ -170,43 +508,46 @@ impl<'a, 'b> Network<'a, 'b> {
   async fn call_api<T: Serialize + Default + Debug>(
     &self,
     request_props: RequestProps<T>,
-  ) -> RequestBuilder {
+  ) -> Result<RequestBuilder> {
     let RequestProps {
       uri,
       method,
       body,
       api_token,
       custom_headers,
+      connection_settings,
       ..
     } = request_props;
     debug!("Creating RequestBuilder for resource: {uri:?}");
     debug!("Sending {method:?} request to {uri} with body {body:?}");
+    let client = self.client().await?;
 
-    match method {
-      RequestMethod::Get => self
-        .client
+    let request = match method {
+      RequestMethod::Get => client
         .get(uri)
         .header("X-Api-Key", api_token)
         .headers(custom_headers),
-      RequestMethod::Post => self
-        .client
+      RequestMethod::Post => client
         .post(uri)
         .json(&body.unwrap_or_default())
         .header("X-Api-Key", api_token)
         .headers(custom_headers),
-      RequestMethod::Put => self
-        .client
+      RequestMethod::Put => client
         .put(uri)
         .json(&body.unwrap_or_default())
         .header("X-Api-Key", api_token)
         .headers(custom_headers),
-      RequestMethod::Delete => self
-        .client
+      RequestMethod::Delete => client
         .delete(uri)
         .json(&body.unwrap_or_default())
         .header("X-Api-Key", api_token)
         .headers(custom_headers),
-    }
+    };
+
+    Ok(match connection_settings.timeout {
+      Some(timeout) => request.timeout(timeout),
+      None => request,
+    })
   }
 
   async fn request_props_from<T, N>(
@@ -223,6 +564,12 @@ impl<'a, 'b> Network<'a, 'b> {
   {
     let app = self.app.lock().await;
     let resource = network_event.resource();
+    let servarr_config = match &self.target {
+      Some(target) => &target.tab.config,
+      None => app.server_tabs.get_active_config(),
+    }
+    .as_ref()
+    .expect("Servarr config is undefined");
     let ServarrConfig {
       host,
       port,
@@ -232,16 +579,14 @@ impl<'a, 'b> Network<'a, 'b> {
       ssl,
       custom_headers: custom_headers_option,
       ..
-    } = app
-      .server_tabs
-      .get_active_config()
-      .as_ref()
-      .expect("Servarr config is undefined");
+    } = servarr_config;
     let network_event_type = network_event.into();
     let (default_port, api_version) = match &network_event_type {
       NetworkEvent::Radarr(_) => (7878, "v3"),
       NetworkEvent::Sonarr(_) => (8989, "v3"),
       NetworkEvent::Lidarr(_) => (8686, "v1"),
+      NetworkEvent::Prowlarr(_) => (9696, "v1"),
+      NetworkEvent::Readarr(_) => (8787, "v1"),
     };
     let mut uri = if let Some(servarr_uri) = uri {
       format!("{servarr_uri}/api/{api_version}{resource}")
@@ -275,11 +620,24 @@ impl<'a, 'b> Network<'a, 'b> {
       api_token: api_token.as_ref().expect("API token not found").clone(),
       ignore_status_code: false,
       custom_headers,
+      connection_settings: ConnectionSettings::from(servarr_config),
+    }
+  }
+}
+
+/// Whether a failed GET request is worth retrying, i.e. the Servarr couldn't be reached, didn't
+/// respond in time, or is temporarily unavailable
+fn is_retryable(resp: &reqwest::Result<Response>) -> bool {
+  match resp {
+    Ok(response) => {
+      response.status().is_server_error() || response.status() == StatusCode::TOO_MANY_REQUESTS
     }
+    Err(e) => e.is_connect() || e.is_timeout(),
   }
 }
 
-#[derive(Clone, Copy, Debug, Display, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, Display, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
 pub enum RequestMethod {
   Get,
   Post,
@@ -287,6 +645,26 @@ pub enum RequestMethod {
   Delete,
 }
 
+/// The HTTP clients used to contact the Servarrs. Each configured instance has its own client
+/// with its own TLS and proxy settings.
+#[derive(Clone, Debug, Default)]
+pub struct NetworkClients(HashMap<ServarrInstance, Client>);
+
+impl NetworkClients {
+  pub fn get(&self, instance: &ServarrInstance) -> Result<&Client> {
+    self
+      .0
+      .get(instance)
+      .ok_or_else(|| anyhow!("No HTTP client is configured for {instance}"))
+  }
+}
+
+impl FromIterator<(ServarrInstance, Client)> for NetworkClients {
+  fn from_iter<I: IntoIterator<Item = (ServarrInstance, Client)>>(clients: I) -> Self {
+    NetworkClients(clients.into_iter().collect())
+  }
+}
+
 #[derive(Debug)]
 pub struct RequestProps<T: Serialize + Debug> {
   pub uri: String,
@@ -295,4 +673,40 @@ pub struct RequestProps<T: Serialize + Debug> {
   pub api_token: String,
   pub ignore_status_code: bool,
   pub custom_headers: HeaderMap,
+  pub connection_settings: ConnectionSettings,
+}
+
+/// How long to wait for a Servarr to respond, and how many times to retry failed GET requests.
+/// Requests wait indefinitely unless a `timeout` is configured, since some calls (e.g. interactive
+/// release searches) can take minutes on slow indexers. The delay between retries starts at the
+/// `retry_backoff` and doubles after each attempt.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ConnectionSettings {
+  pub timeout: Option<Duration>,
+  pub retries: u16,
+  pub retry_backoff: Duration,
+}
+
+impl Default for ConnectionSettings {
+  fn default() -> Self {
+    ConnectionSettings {
+      timeout: None,
+      retries: 0,
+      retry_backoff: Duration::from_millis(500),
+    }
+  }
+}
+
+impl From<&ServarrConfig> for ConnectionSettings {
+  fn from(config: &ServarrConfig) -> Self {
+    let default = ConnectionSettings::default();
+
+    ConnectionSettings {
+      timeout: config.timeout_secs.map(Duration::from_secs),
+      retries: config.retries.unwrap_or(default.retries),
+      retry_backoff: config
+        .retry_backoff
+        .map_or(default.retry_backoff, Duration::from_millis),
+    }
+  }
 }
@@ -3,6 +3,7 @@ mod tests {
   use std::fmt::Debug;
   use std::string::ToString;
   use std::sync::Arc;
+  use std::time::Duration;
 
   use mockito::{Mock, Server, ServerGuard};
   use pretty_assertions::assert_str_eq;
@@ -16,13 +17,19 @@ mod tests {
 
   use super::test_utils;
   use crate::app::{App, AppConfig, ServarrConfig};
-  use crate::models::HorizontallyScrollableText;
+  use crate::models::{HorizontallyScrollableText, TabRoute};
   use crate::network::NetworkResource;
   use crate::network::lidarr_network::LidarrEvent;
   use crate::network::network_tests::test_utils::test_network;
+  use crate::network::prowlarr_network::ProwlarrEvent;
   use crate::network::radarr_network::RadarrEvent;
+  use crate::network::readarr_network::ReadarrEvent;
+  use crate::network::servarr_error::{ServarrError, ValidationFailure};
   use crate::network::sonarr_network::SonarrEvent;
-  use crate::network::{Network, NetworkEvent, NetworkTrait, RequestMethod, RequestProps};
+  use crate::network::{
+    ConnectionSettings, Network, NetworkClients, NetworkEvent, NetworkTrait, QueuedNetworkEvent,
+    RequestMethod, RequestProps,
+  };
 
   #[tokio::test]
   async fn test_handle_network_event_radarr_event() {
@@ -71,6 +78,7 @@ mod tests {
           api_token: "test1234".to_owned(),
           ignore_status_code: false,
           custom_headers: HeaderMap::new(),
+          connection_settings: ConnectionSettings::default(),
         },
         |_, _| (),
       )
@@ -96,6 +104,7 @@ mod tests {
           api_token: "test1234".to_owned(),
           ignore_status_code: false,
           custom_headers: HeaderMap::new(),
+          connection_settings: ConnectionSettings::default(),
         },
         |response, mut app| app.error = HorizontallyScrollableText::from(response.value),
       )
@@ -130,6 +139,7 @@ mod tests {
           api_token: "test1234".to_owned(),
           ignore_status_code: true,
           custom_headers: HeaderMap::new(),
+          connection_settings: ConnectionSettings::default(),
         },
         |response, _app| test_result = response.value,
       )
@@ -150,14 +160,20 @@ mod tests {
   async fn test_handle_request_request_is_cancelled() {
     let (async_server, _, server) = mock_api(RequestMethod::Get, 200, true).await;
     let cancellation_token = CancellationToken::new();
-    let (tx, _) = mpsc::channel::<NetworkEvent>(500);
-    let app_arc = Arc::new(Mutex::new(App::new(
-      tx,
-      AppConfig::default(),
-      cancellation_token.clone(),
-    )));
+    let (tx, _) = mpsc::channel::<QueuedNetworkEvent>(500);
+    let app_arc = Arc::new(Mutex::new(
+      App::new(
+        tx,
+        AppConfig {
+          radarr: Some(vec![ServarrConfig::default()]),
+          ..AppConfig::default()
+        },
+        cancellation_token.clone(),
+      )
+      .unwrap(),
+    ));
     app_arc.lock().await.is_loading = true;
-    let mut network = Network::new(&app_arc, cancellation_token, Client::new());
+    let mut network = Network::new(&app_arc, cancellation_token);
     network.cancellation_token.cancel();
 
     let resp = network
@@ -169,6 +185,7 @@ mod tests {
           api_token: "test1234".to_owned(),
           ignore_status_code: false,
           custom_headers: HeaderMap::new(),
+          connection_settings: ConnectionSettings::default(),
         },
         |_, _| (),
       )
@@ -176,22 +193,22 @@ mod tests {
 
     assert!(!async_server.matched_async().await);
     assert!(app_arc.lock().await.error.text.is_empty());
-    assert!(resp.is_ok());
-    assert_eq!(resp.unwrap(), Test::default());
+    assert_eq!(
+      resp.unwrap_err().downcast_ref::<ServarrError>(),
+      Some(&ServarrError::Cancelled)
+    );
   }
 
   #[tokio::test]
   async fn test_reset_cancellation_token() {
     let cancellation_token = CancellationToken::new();
-    let (tx, _) = mpsc::channel::<NetworkEvent>(500);
-    let app_arc = Arc::new(Mutex::new(App::new(
-      tx,
-      AppConfig::default(),
-      cancellation_token.clone(),
-    )));
+    let (tx, _) = mpsc::channel::<QueuedNetworkEvent>(500);
+    let app_arc = Arc::new(Mutex::new(
+      App::new(tx, AppConfig::default(), cancellation_token.clone()).unwrap(),
+    ));
     app_arc.lock().await.should_refresh = false;
     app_arc.lock().await.is_loading = true;
-    let mut network = Network::new(&app_arc, cancellation_token, Client::new());
+    let mut network = Network::new(&app_arc, cancellation_token);
     network.cancellation_token.cancel();
 
     network.reset_cancellation_token().await;
@@ -223,6 +240,7 @@ mod tests {
           api_token: "test1234".to_owned(),
           ignore_status_code: false,
           custom_headers: HeaderMap::new(),
+          connection_settings: ConnectionSettings::default(),
         },
         |response, mut app| app.error = HorizontallyScrollableText::from(response.value),
       )
@@ -260,6 +278,7 @@ mod tests {
           api_token: "test1234".to_owned(),
           ignore_status_code: false,
           custom_headers: HeaderMap::new(),
+          connection_settings: ConnectionSettings::default(),
         },
         |response, mut app| app.error = HorizontallyScrollableText::from(response.value),
       )
@@ -271,14 +290,14 @@ mod tests {
         .await
         .error
         .text
-        .starts_with("Failed to send request.")
+        .starts_with("Unable to reach the Servarr.")
     );
     assert!(resp.is_err());
     assert!(
       resp
         .unwrap_err()
         .to_string()
-        .starts_with("Failed to send request.")
+        .starts_with("Unable to reach the Servarr.")
     );
   }
 
@@ -293,7 +312,7 @@ mod tests {
     )]
     request_method: RequestMethod,
   ) {
-    let (async_server, app_arc, server) = mock_api(request_method, 404, true).await;
+    let (async_server, app_arc, server) = mock_api(request_method, 500, true).await;
     let mut network = test_network(&app_arc);
 
     let resp = network
@@ -305,6 +324,7 @@ mod tests {
           api_token: "test1234".to_owned(),
           ignore_status_code: false,
           custom_headers: HeaderMap::new(),
+          connection_settings: ConnectionSettings::default(),
         },
         |response, mut app| app.error = HorizontallyScrollableText::from(response.value),
       )
@@ -313,18 +333,18 @@ mod tests {
     async_server.assert_async().await;
     assert_str_eq!(
       app_arc.lock().await.error.text,
-      r#"Request failed. Received 404 Not Found response code with body: { "value": "Test" }"#
+      r#"Request failed. Received 500 Internal Server Error response code with body: { "value": "Test" }"#
     );
     assert!(resp.is_err());
     assert_str_eq!(
       resp.unwrap_err().to_string(),
-      r#"Request failed. Received 404 Not Found response code with body: { "value": "Test" }"#
+      r#"Request failed. Received 500 Internal Server Error response code with body: { "value": "Test" }"#
     );
   }
 
   #[tokio::test]
   async fn test_handle_request_non_success_code_empty_response_body() {
-    let (async_server, app_arc, server) = mock_api(RequestMethod::Post, 404, false).await;
+    let (async_server, app_arc, server) = mock_api(RequestMethod::Post, 500, false).await;
     let mut network = test_network(&app_arc);
 
     let resp = network
@@ -336,6 +356,7 @@ mod tests {
           api_token: "test1234".to_owned(),
           ignore_status_code: false,
           custom_headers: HeaderMap::new(),
+          connection_settings: ConnectionSettings::default(),
         },
         |response, mut app| app.error = HorizontallyScrollableText::from(response.value),
       )
@@ -344,12 +365,209 @@ mod tests {
     async_server.assert_async().await;
     assert_str_eq!(
       app_arc.lock().await.error.text,
-      r#"Request failed. Received 404 Not Found response code with body: "#
+      r#"Request failed. Received 500 Internal Server Error response code with body: "#
     );
     assert!(resp.is_err());
     assert_str_eq!(
       resp.unwrap_err().to_string(),
-      r#"Request failed. Received 404 Not Found response code with body: "#
+      r#"Request failed. Received 500 Internal Server Error response code with body: "#
+    );
+  }
+
+  #[rstest]
+  #[case(RequestMethod::Get, 503, 4)]
+  #[case(RequestMethod::Get, 429, 4)]
+  #[case(RequestMethod::Get, 404, 1)]
+  #[case(RequestMethod::Post, 503, 1)]
+  #[case(RequestMethod::Delete, 503, 1)]
+  #[tokio::test]
+  async fn test_handle_request_retries_failed_get_requests(
+    #[case] request_method: RequestMethod,
+    #[case] status: usize,
+    #[case] expected_attempts: usize,
+  ) {
+    let mut server = Server::new_async().await;
+    let async_server = server
+      .mock(&request_method.to_string().to_uppercase(), "/test")
+      .with_status(status)
+      .expect(expected_attempts)
+      .create_async()
+      .await;
+    let app_arc = Arc::new(Mutex::new(App::test_default()));
+    let mut network = test_network(&app_arc);
+
+    let resp = network
+      .handle_request::<(), Test>(
+        RequestProps {
+          uri: format!("{}/test", server.url()),
+          method: request_method,
+          body: None,
+          api_token: "test1234".to_owned(),
+          ignore_status_code: false,
+          custom_headers: HeaderMap::new(),
+          connection_settings: ConnectionSettings {
+            retries: 3,
+            retry_backoff: Duration::from_millis(1),
+            ..ConnectionSettings::default()
+          },
+        },
+        |_, _| (),
+      )
+      .await;
+
+    async_server.assert_async().await;
+    assert!(resp.is_err());
+    assert_is_empty!(app_arc.lock().await.retry_attempts);
+  }
+
+  #[test]
+  fn test_network_clients_get() {
+    let app = App::test_default();
+    let radarr_instance = app.server_tabs.tabs[0].instance();
+    let sonarr_instance = app.server_tabs.tabs[1].instance();
+    let network_clients: NetworkClients = [(radarr_instance.clone(), Client::new())]
+      .into_iter()
+      .collect();
+
+    assert_ok!(network_clients.get(&radarr_instance));
+    assert_str_eq!(
+      network_clients
+        .get(&sonarr_instance)
+        .unwrap_err()
+        .to_string(),
+      "No HTTP client is configured for Sonarr"
+    );
+  }
+
+  #[tokio::test]
+  async fn test_call_api_fails_without_a_client_for_the_active_instance() {
+    let app_arc = Arc::new(Mutex::new(App::test_default()));
+    let network = Network::new(&app_arc, CancellationToken::new());
+
+    let result = network
+      .call_api(RequestProps {
+        uri: "http://localhost:7878/api/v3/test".to_owned(),
+        method: RequestMethod::Get,
+        body: None::<()>,
+        api_token: "test1234".to_owned(),
+        ignore_status_code: false,
+        custom_headers: HeaderMap::new(),
+        connection_settings: ConnectionSettings::default(),
+      })
+      .await;
+
+    assert_str_eq!(
+      result.unwrap_err().to_string(),
+      "No HTTP client is configured for Radarr"
+    );
+  }
+
+  #[tokio::test]
+  async fn test_target_fails_for_an_instance_that_is_no_longer_configured() {
+    let app = App::test_default();
+    let removed_instance = TabRoute {
+      title: "Removed".to_owned(),
+      ..app.server_tabs.tabs[0].clone()
+    }
+    .instance();
+    let app_arc = Arc::new(Mutex::new(app));
+    let mut network = Network::new(&app_arc, CancellationToken::new());
+
+    let result = network.target(&removed_instance).await;
+
+    assert_str_eq!(
+      result.unwrap_err().to_string(),
+      "Removed is no longer configured"
+    );
+  }
+
+  #[tokio::test]
+  async fn test_target_only_updates_the_app_for_the_active_instance() {
+    let mut app = App::test_default();
+    let radarr_instance = app.server_tabs.tabs[0].instance();
+    let sonarr_instance = app.server_tabs.tabs[1].instance();
+    app.network_clients = [
+      (radarr_instance.clone(), Client::new()),
+      (sonarr_instance.clone(), Client::new()),
+    ]
+    .into_iter()
+    .collect();
+    let app_arc = Arc::new(Mutex::new(app));
+    let mut network = Network::new(&app_arc, CancellationToken::new());
+
+    assert!(network.is_target_active(&*app_arc.lock().await));
+
+    network.target(&radarr_instance).await.unwrap();
+    assert!(network.is_target_active(&*app_arc.lock().await));
+
+    network.target(&sonarr_instance).await.unwrap();
+    assert!(!network.is_target_active(&*app_arc.lock().await));
+  }
+
+  #[test]
+  fn test_connection_settings_from_servarr_config() {
+    let servarr_config = ServarrConfig {
+      timeout_secs: Some(5),
+      retries: Some(3),
+      retry_backoff: Some(250),
+      ..ServarrConfig::default()
+    };
+
+    assert_eq!(
+      ConnectionSettings::from(&servarr_config),
+      ConnectionSettings {
+        timeout: Some(Duration::from_secs(5)),
+        retries: 3,
+        retry_backoff: Duration::from_millis(250),
+      }
+    );
+    assert_eq!(
+      ConnectionSettings::from(&ServarrConfig::default()),
+      ConnectionSettings::default()
+    );
+    assert_none!(ConnectionSettings::default().timeout);
+  }
+
+  #[tokio::test]
+  async fn test_handle_request_validation_failure() {
+    let mut server = Server::new_async().await;
+    let async_server = server
+      .mock("POST", "/test")
+      .with_status(400)
+      .with_body(
+        r#"[{ "propertyName": "Path", "errorMessage": "Path is already configured", "severity": "error" }]"#,
+      )
+      .create_async()
+      .await;
+    let app_arc = Arc::new(Mutex::new(App::test_default()));
+    let mut network = test_network(&app_arc);
+
+    let resp = network
+      .handle_request::<(), Test>(
+        RequestProps {
+          uri: format!("{}/test", server.url()),
+          method: RequestMethod::Post,
+          body: None,
+          api_token: "test1234".to_owned(),
+          ignore_status_code: false,
+          custom_headers: HeaderMap::new(),
+          connection_settings: ConnectionSettings::default(),
+        },
+        |_, _| (),
+      )
+      .await;
+
+    async_server.assert_async().await;
+    assert_str_eq!(
+      app_arc.lock().await.error.text,
+      "The Servarr rejected the request: Path: Path is already configured"
+    );
+    assert_eq!(
+      resp.unwrap_err().downcast_ref::<ServarrError>(),
+      Some(&ServarrError::Validation(vec![ValidationFailure {
+        property_name: "Path".to_owned(),
+        error_message: "Path is already configured".to_owned(),
+      }]))
     );
   }
 
@@ -397,8 +615,10 @@ mod tests {
         api_token: "test1234".to_owned(),
         ignore_status_code: false,
         custom_headers,
+        connection_settings: ConnectionSettings::default(),
       })
       .await
+      .unwrap()
       .send()
       .await
       .unwrap();
@@ -425,6 +645,8 @@ mod tests {
   #[case(RadarrEvent::GetMovies, "v3", 7878)]
   #[case(SonarrEvent::ListSeries, "v3", 8989)]
   #[case(LidarrEvent::ListArtists, "v1", 8686)]
+  #[case(ProwlarrEvent::GetIndexers, "v1", 9696)]
+  #[case(ReadarrEvent::ListAuthors, "v1", 8787)]
   #[tokio::test]
   async fn test_request_props_from_default_config(
     #[case] network_event: impl Into<NetworkEvent> + NetworkResource,
@@ -647,6 +869,8 @@ mod tests {
   #[case(RadarrEvent::GetMovies, "v3", 7878)]
   #[case(SonarrEvent::ListSeries, "v3", 8989)]
   #[case(LidarrEvent::ListArtists, "v1", 8686)]
+  #[case(ProwlarrEvent::GetIndexers, "v1", 9696)]
+  #[case(ReadarrEvent::ListAuthors, "v1", 8787)]
   #[tokio::test]
   async fn test_request_props_from_default_config_with_path_and_query_params(
     #[case] network_event: impl Into<NetworkEvent> + NetworkResource,
@@ -810,6 +1034,12 @@ pub(in crate::network) mod test_utils {
 
   use crate::{
     app::{App, ServarrConfig},
+    models::{
+      TabRoute,
+      servarr_data::{
+        prowlarr::prowlarr_data::ActiveProwlarrBlock, readarr::readarr_data::ActiveReadarrBlock,
+      },
+    },
     network::{Network, NetworkEvent, NetworkResource, RequestMethod},
   };
 
@@ -821,13 +1051,25 @@ pub(in crate::network) mod test_utils {
     CancellationToken::new()
   }
 
+  /// A network with a client for each of the app's tabs
   pub fn test_network<'a, 'b>(app: &'a Arc<Mutex<App<'b>>>) -> Network<'a, 'b> {
-    Network::new(app, test_cancellation_token(), test_client())
+    {
+      let mut app = app.try_lock().unwrap();
+      app.network_clients = app
+        .server_tabs
+        .tabs
+        .iter()
+        .map(|tab| (tab.instance(), test_client()))
+        .collect();
+    }
+
+    Network::new(app, test_cancellation_token())
   }
 
   pub struct MockServarrApi {
     method: RequestMethod,
     request_body: Option<Value>,
+    request_body_matcher: Option<Matcher>,
     response_body: Option<Value>,
     response_status: usize,
     path: Option<String>,
@@ -856,6 +1098,7 @@ pub(in crate::network) mod test_utils {
       Self {
         method,
         request_body: None,
+        request_body_matcher: None,
         response_body: None,
         response_status: 200,
         path: None,
@@ -868,6 +1111,11 @@ pub(in crate::network) mod test_utils {
       self
     }
 
+    pub fn with_request_body_matching(mut self, matcher: Matcher) -> Self {
+      self.request_body_matcher = Some(matcher);
+      self
+    }
+
     pub fn returns(mut self, body: Value) -> Self {
       self.response_body = Some(body);
       self
@@ -898,7 +1146,7 @@ pub(in crate::network) mod test_utils {
       let resource = network_event.resource();
       let network_event_clone: NetworkEvent = network_event.clone().into();
       let api_version = match &network_event_clone {
-        NetworkEvent::Lidarr(_) => "v1",
+        NetworkEvent::Lidarr(_) | NetworkEvent::Prowlarr(_) | NetworkEvent::Readarr(_) => "v1",
         _ => "v3",
       };
       let mut server = Server::new_async().await;
@@ -921,6 +1169,10 @@ pub(in crate::network) mod test_utils {
         mock_builder = mock_builder.match_body(Matcher::Json(body.clone()));
       }
 
+      if let Some(matcher) = &self.request_body_matcher {
+        mock_builder = mock_builder.match_body(matcher.clone());
+      }
+
       if let Some(body) = &self.response_body {
         mock_builder = mock_builder.with_body(body.to_string());
       }
@@ -946,6 +1198,18 @@ pub(in crate::network) mod test_utils {
         NetworkEvent::Radarr(_) => app.server_tabs.tabs[0].config = Some(servarr_config),
         NetworkEvent::Sonarr(_) => app.server_tabs.tabs[1].config = Some(servarr_config),
         NetworkEvent::Lidarr(_) => app.server_tabs.tabs[2].config = Some(servarr_config),
+        NetworkEvent::Prowlarr(_) => app.server_tabs.tabs.push(TabRoute {
+          title: "Prowlarr".to_owned(),
+          route: ActiveProwlarrBlock::Indexers.into(),
+          contextual_help: None,
+          config: Some(servarr_config),
+        }),
+        NetworkEvent::Readarr(_) => app.server_tabs.tabs.push(TabRoute {
+          title: "Readarr".to_owned(),
+          route: ActiveReadarrBlock::Authors.into(),
+          contextual_help: None,
+          config: Some(servarr_config),
+        }),
       }
 
       let app_arc = Arc::new(Mutex::new(app));
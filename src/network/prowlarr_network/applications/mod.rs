@@ -0,0 +1,50 @@
+use crate::models::prowlarr_models::Application;
+use crate::network::prowlarr_network::ProwlarrEvent;
+use crate::network::{Network, RequestMethod};
+use anyhow::Result;
+use log::info;
+
+#[cfg(test)]
+#[path = "prowlarr_applications_network_tests.rs"]
+mod prowlarr_applications_network_tests;
+
+impl Network<'_, '_> {
+  pub(in crate::network::prowlarr_network) async fn delete_prowlarr_application(
+    &mut self,
+    application_id: i64,
+  ) -> Result<()> {
+    let event = ProwlarrEvent::DeleteApplication(application_id);
+    info!("Deleting Prowlarr application with id: {application_id}");
+
+    let request_props = self
+      .request_props_from(
+        event,
+        RequestMethod::Delete,
+        None::<()>,
+        Some(format!("/{application_id}")),
+        None,
+      )
+      .await;
+
+    self
+      .handle_request::<(), ()>(request_props, |_, _| ())
+      .await
+  }
+
+  pub(in crate::network::prowlarr_network) async fn get_prowlarr_applications(
+    &mut self,
+  ) -> Result<Vec<Application>> {
+    info!("Fetching Prowlarr applications");
+    let event = ProwlarrEvent::GetApplications;
+
+    let request_props = self
+      .request_props_from(event, RequestMethod::Get, None::<()>, None, None)
+      .await;
+
+    self
+      .handle_request::<(), Vec<Application>>(request_props, |applications, mut app| {
+        app.data.prowlarr_data.applications.set_items(applications);
+      })
+      .await
+  }
+}
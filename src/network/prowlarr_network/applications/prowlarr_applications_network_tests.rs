@@ -0,0 +1,56 @@
+#[cfg(test)]
+mod tests {
+  use crate::models::prowlarr_models::{Application, ProwlarrSerdeable};
+  use crate::network::network_tests::test_utils::{MockServarrApi, test_network};
+  use crate::network::prowlarr_network::ProwlarrEvent;
+  use crate::network::prowlarr_network::prowlarr_network_test_utils::test_utils::{
+    APPLICATION_JSON, application,
+  };
+  use pretty_assertions::assert_eq;
+  use serde_json::Value;
+
+  #[tokio::test]
+  async fn test_handle_delete_prowlarr_application_event() {
+    let (mock, app, _server) = MockServarrApi::delete()
+      .path("/1")
+      .build_for(ProwlarrEvent::DeleteApplication(1))
+      .await;
+    app.lock().await.server_tabs.set_index(3);
+    let mut network = test_network(&app);
+
+    assert_ok!(
+      network
+        .handle_prowlarr_event(ProwlarrEvent::DeleteApplication(1))
+        .await
+    );
+
+    mock.assert_async().await;
+  }
+
+  #[tokio::test]
+  async fn test_handle_get_prowlarr_applications_event() {
+    let applications_json = Value::Array(vec![serde_json::from_str(APPLICATION_JSON).unwrap()]);
+    let response: Vec<Application> = serde_json::from_value(applications_json.clone()).unwrap();
+    let (mock, app, _server) = MockServarrApi::get()
+      .returns(applications_json)
+      .build_for(ProwlarrEvent::GetApplications)
+      .await;
+    app.lock().await.server_tabs.set_index(3);
+    let mut network = test_network(&app);
+
+    let ProwlarrSerdeable::Applications(applications) = network
+      .handle_prowlarr_event(ProwlarrEvent::GetApplications)
+      .await
+      .unwrap()
+    else {
+      panic!("Expected Applications")
+    };
+
+    mock.assert_async().await;
+    assert_eq!(
+      app.lock().await.data.prowlarr_data.applications.items,
+      vec![application()]
+    );
+    assert_eq!(applications, response);
+  }
+}
@@ -0,0 +1,40 @@
+use crate::models::Route;
+use crate::models::prowlarr_models::ProwlarrHistoryWrapper;
+use crate::models::servarr_data::prowlarr::prowlarr_data::ActiveProwlarrBlock;
+use crate::network::prowlarr_network::ProwlarrEvent;
+use crate::network::{Network, RequestMethod};
+use anyhow::Result;
+use log::info;
+
+#[cfg(test)]
+#[path = "prowlarr_history_network_tests.rs"]
+mod prowlarr_history_network_tests;
+
+impl Network<'_, '_> {
+  pub(in crate::network::prowlarr_network) async fn get_prowlarr_history(
+    &mut self,
+    events: u64,
+  ) -> Result<ProwlarrHistoryWrapper> {
+    info!("Fetching all Prowlarr history events");
+    let event = ProwlarrEvent::GetHistory(events);
+
+    let params = format!("pageSize={events}&sortDirection=descending&sortKey=date");
+    let request_props = self
+      .request_props_from(event, RequestMethod::Get, None::<()>, None, Some(params))
+      .await;
+
+    self
+      .handle_request::<(), ProwlarrHistoryWrapper>(request_props, |history_response, mut app| {
+        if !matches!(
+          app.get_current_route(),
+          Route::Prowlarr(ActiveProwlarrBlock::HistorySortPrompt, _)
+        ) {
+          let mut history_vec = history_response.records;
+          history_vec.sort_by_key(|a| a.id);
+          app.data.prowlarr_data.history.set_items(history_vec);
+          app.data.prowlarr_data.history.apply_sorting_toggle(false);
+        }
+      })
+      .await
+  }
+}
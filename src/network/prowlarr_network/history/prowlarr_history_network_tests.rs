@@ -0,0 +1,139 @@
+#[cfg(test)]
+mod tests {
+  use crate::models::prowlarr_models::{
+    ProwlarrHistoryItem, ProwlarrHistoryWrapper, ProwlarrSerdeable,
+  };
+  use crate::models::servarr_data::prowlarr::prowlarr_data::ActiveProwlarrBlock;
+  use crate::models::stateful_table::SortOption;
+  use crate::network::network_tests::test_utils::{MockServarrApi, test_network};
+  use crate::network::prowlarr_network::ProwlarrEvent;
+  use crate::network::prowlarr_network::prowlarr_network_test_utils::test_utils::prowlarr_history_item;
+  use pretty_assertions::assert_eq;
+  use rstest::rstest;
+  use serde_json::json;
+
+  #[rstest]
+  #[tokio::test]
+  async fn test_handle_get_prowlarr_history_event(#[values(true, false)] use_custom_sorting: bool) {
+    let history_json = json!({"records": [{
+      "id": 456,
+      "indexerId": 2,
+      "date": "2023-05-20T21:29:16Z",
+      "successful": true,
+      "eventType": "indexerQuery",
+      "data": {
+        "query": "test query",
+        "queryType": "search",
+        "source": "Prowlarr",
+        "elapsedTime": "1023",
+        "queryResults": "100"
+      }
+    },
+    {
+      "id": 123,
+      "indexerId": 1,
+      "date": "2023-05-20T21:29:16Z",
+      "successful": true,
+      "eventType": "indexerQuery",
+      "data": {
+        "query": "test query",
+        "queryType": "search",
+        "source": "Prowlarr",
+        "elapsedTime": "1023",
+        "queryResults": "100"
+      }
+    }]});
+    let response: ProwlarrHistoryWrapper = serde_json::from_value(history_json.clone()).unwrap();
+    let (mock, app, _server) = MockServarrApi::get()
+      .returns(history_json)
+      .query("pageSize=500&sortDirection=descending&sortKey=date")
+      .build_for(ProwlarrEvent::GetHistory(500))
+      .await;
+    let mut expected_history_items = vec![
+      ProwlarrHistoryItem {
+        id: 123,
+        indexer_id: 1,
+        ..prowlarr_history_item()
+      },
+      ProwlarrHistoryItem {
+        id: 456,
+        indexer_id: 2,
+        ..prowlarr_history_item()
+      },
+    ];
+    {
+      let mut app_mut = app.lock().await;
+      app_mut.server_tabs.set_index(3);
+      app_mut.data.prowlarr_data.history.sort_asc = true;
+    }
+    if use_custom_sorting {
+      let cmp_fn =
+        |a: &ProwlarrHistoryItem, b: &ProwlarrHistoryItem| a.indexer_id.cmp(&b.indexer_id);
+      expected_history_items.sort_by(cmp_fn);
+      let history_sort_option = SortOption {
+        name: "Indexer",
+        cmp_fn: Some(cmp_fn),
+      };
+      app
+        .lock()
+        .await
+        .data
+        .prowlarr_data
+        .history
+        .sorting(vec![history_sort_option]);
+    }
+    let mut network = test_network(&app);
+
+    let ProwlarrSerdeable::ProwlarrHistoryWrapper(history) = network
+      .handle_prowlarr_event(ProwlarrEvent::GetHistory(500))
+      .await
+      .unwrap()
+    else {
+      panic!("Expected ProwlarrHistoryWrapper")
+    };
+
+    mock.assert_async().await;
+    assert_eq!(
+      app.lock().await.data.prowlarr_data.history.items,
+      expected_history_items
+    );
+    assert!(app.lock().await.data.prowlarr_data.history.sort_asc);
+    assert_eq!(history, response);
+  }
+
+  #[tokio::test]
+  async fn test_handle_get_prowlarr_history_event_no_op_when_user_is_selecting_sort_options() {
+    let history_json = json!({"records": [{
+      "id": 123,
+      "indexerId": 1,
+      "date": "2023-05-20T21:29:16Z",
+      "successful": true,
+      "eventType": "indexerQuery",
+      "data": {}
+    }]});
+    let response: ProwlarrHistoryWrapper = serde_json::from_value(history_json.clone()).unwrap();
+    let (mock, app, _server) = MockServarrApi::get()
+      .returns(history_json)
+      .query("pageSize=500&sortDirection=descending&sortKey=date")
+      .build_for(ProwlarrEvent::GetHistory(500))
+      .await;
+    app
+      .lock()
+      .await
+      .push_navigation_stack(ActiveProwlarrBlock::HistorySortPrompt.into());
+    app.lock().await.server_tabs.set_index(3);
+    let mut network = test_network(&app);
+
+    let ProwlarrSerdeable::ProwlarrHistoryWrapper(history) = network
+      .handle_prowlarr_event(ProwlarrEvent::GetHistory(500))
+      .await
+      .unwrap()
+    else {
+      panic!("Expected ProwlarrHistoryWrapper")
+    };
+
+    mock.assert_async().await;
+    assert_is_empty!(app.lock().await.data.prowlarr_data.history);
+    assert_eq!(history, response);
+  }
+}
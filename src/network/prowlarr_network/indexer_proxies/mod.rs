@@ -0,0 +1,54 @@
+use crate::models::prowlarr_models::IndexerProxy;
+use crate::network::prowlarr_network::ProwlarrEvent;
+use crate::network::{Network, RequestMethod};
+use anyhow::Result;
+use log::info;
+
+#[cfg(test)]
+#[path = "prowlarr_indexer_proxies_network_tests.rs"]
+mod prowlarr_indexer_proxies_network_tests;
+
+impl Network<'_, '_> {
+  pub(in crate::network::prowlarr_network) async fn delete_prowlarr_indexer_proxy(
+    &mut self,
+    indexer_proxy_id: i64,
+  ) -> Result<()> {
+    let event = ProwlarrEvent::DeleteIndexerProxy(indexer_proxy_id);
+    info!("Deleting Prowlarr indexer proxy with id: {indexer_proxy_id}");
+
+    let request_props = self
+      .request_props_from(
+        event,
+        RequestMethod::Delete,
+        None::<()>,
+        Some(format!("/{indexer_proxy_id}")),
+        None,
+      )
+      .await;
+
+    self
+      .handle_request::<(), ()>(request_props, |_, _| ())
+      .await
+  }
+
+  pub(in crate::network::prowlarr_network) async fn get_prowlarr_indexer_proxies(
+    &mut self,
+  ) -> Result<Vec<IndexerProxy>> {
+    info!("Fetching Prowlarr indexer proxies");
+    let event = ProwlarrEvent::GetIndexerProxies;
+
+    let request_props = self
+      .request_props_from(event, RequestMethod::Get, None::<()>, None, None)
+      .await;
+
+    self
+      .handle_request::<(), Vec<IndexerProxy>>(request_props, |indexer_proxies, mut app| {
+        app
+          .data
+          .prowlarr_data
+          .indexer_proxies
+          .set_items(indexer_proxies);
+      })
+      .await
+  }
+}
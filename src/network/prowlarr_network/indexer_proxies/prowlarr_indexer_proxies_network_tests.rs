@@ -0,0 +1,57 @@
+#[cfg(test)]
+mod tests {
+  use crate::models::prowlarr_models::{IndexerProxy, ProwlarrSerdeable};
+  use crate::network::network_tests::test_utils::{MockServarrApi, test_network};
+  use crate::network::prowlarr_network::ProwlarrEvent;
+  use crate::network::prowlarr_network::prowlarr_network_test_utils::test_utils::{
+    INDEXER_PROXY_JSON, indexer_proxy,
+  };
+  use pretty_assertions::assert_eq;
+  use serde_json::Value;
+
+  #[tokio::test]
+  async fn test_handle_delete_prowlarr_indexer_proxy_event() {
+    let (mock, app, _server) = MockServarrApi::delete()
+      .path("/1")
+      .build_for(ProwlarrEvent::DeleteIndexerProxy(1))
+      .await;
+    app.lock().await.server_tabs.set_index(3);
+    let mut network = test_network(&app);
+
+    assert_ok!(
+      network
+        .handle_prowlarr_event(ProwlarrEvent::DeleteIndexerProxy(1))
+        .await
+    );
+
+    mock.assert_async().await;
+  }
+
+  #[tokio::test]
+  async fn test_handle_get_prowlarr_indexer_proxies_event() {
+    let indexer_proxies_json =
+      Value::Array(vec![serde_json::from_str(INDEXER_PROXY_JSON).unwrap()]);
+    let response: Vec<IndexerProxy> = serde_json::from_value(indexer_proxies_json.clone()).unwrap();
+    let (mock, app, _server) = MockServarrApi::get()
+      .returns(indexer_proxies_json)
+      .build_for(ProwlarrEvent::GetIndexerProxies)
+      .await;
+    app.lock().await.server_tabs.set_index(3);
+    let mut network = test_network(&app);
+
+    let ProwlarrSerdeable::IndexerProxies(indexer_proxies) = network
+      .handle_prowlarr_event(ProwlarrEvent::GetIndexerProxies)
+      .await
+      .unwrap()
+    else {
+      panic!("Expected IndexerProxies")
+    };
+
+    mock.assert_async().await;
+    assert_eq!(
+      app.lock().await.data.prowlarr_data.indexer_proxies.items,
+      vec![indexer_proxy()]
+    );
+    assert_eq!(indexer_proxies, response);
+  }
+}
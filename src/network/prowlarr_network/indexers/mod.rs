@@ -0,0 +1,242 @@
+use crate::models::prowlarr_models::ProwlarrIndexer;
+use crate::models::servarr_data::modals::IndexerTestResultModalItem;
+use crate::models::servarr_models::{AddIndexerParams, IndexerTestResult};
+use crate::models::stateful_table::StatefulTable;
+use crate::network::prowlarr_network::ProwlarrEvent;
+use crate::network::utils::indexer_body_from_schema;
+use crate::network::{Network, RequestMethod};
+use anyhow::Result;
+use log::{debug, info};
+use serde_json::Value;
+
+#[cfg(test)]
+#[path = "prowlarr_indexers_network_tests.rs"]
+mod prowlarr_indexers_network_tests;
+
+impl Network<'_, '_> {
+  pub(in crate::network::prowlarr_network) async fn add_prowlarr_indexer(
+    &mut self,
+    mut add_indexer_params: AddIndexerParams,
+  ) -> Result<Value> {
+    let schema_event = ProwlarrEvent::GetIndexerSchema;
+    let event = ProwlarrEvent::AddIndexer(AddIndexerParams::default());
+    if let Some(tag_input_str) = add_indexer_params.tag_input_string.as_ref() {
+      let tag_ids_vec = self
+        .extract_and_add_prowlarr_tag_ids_vec(tag_input_str)
+        .await;
+      add_indexer_params.tags = tag_ids_vec;
+    }
+    info!(
+      "Adding new Prowlarr indexer using the '{}' implementation",
+      add_indexer_params.implementation
+    );
+
+    info!("Fetching indexer schema");
+
+    let request_props = self
+      .request_props_from(schema_event, RequestMethod::Get, None::<()>, None, None)
+      .await;
+
+    let indexer_schema = self
+      .handle_request::<(), Vec<Value>>(request_props, |_, _| ())
+      .await?;
+
+    let add_indexer_body = indexer_body_from_schema(indexer_schema, &add_indexer_params)?;
+
+    debug!("Add indexer body: {add_indexer_body:?}");
+
+    info!("Testing new indexer");
+
+    let request_props = self
+      .request_props_from(
+        event.clone(),
+        RequestMethod::Post,
+        Some(add_indexer_body.clone()),
+        Some("/test".to_owned()),
+        None,
+      )
+      .await;
+
+    self
+      .handle_request::<Value, Value>(request_props, |_, _| ())
+      .await?;
+
+    info!("Saving new indexer");
+
+    let request_props = self
+      .request_props_from(
+        event,
+        RequestMethod::Post,
+        Some(add_indexer_body),
+        None,
+        None,
+      )
+      .await;
+
+    self
+      .handle_request::<Value, Value>(request_props, |_, _| ())
+      .await
+  }
+
+  pub(in crate::network::prowlarr_network) async fn delete_prowlarr_indexer(
+    &mut self,
+    indexer_id: i64,
+  ) -> Result<()> {
+    let event = ProwlarrEvent::DeleteIndexer(indexer_id);
+    info!("Deleting Prowlarr indexer with id: {indexer_id}");
+
+    let request_props = self
+      .request_props_from(
+        event,
+        RequestMethod::Delete,
+        None::<()>,
+        Some(format!("/{indexer_id}")),
+        None,
+      )
+      .await;
+
+    self
+      .handle_request::<(), ()>(request_props, |_, _| ())
+      .await
+  }
+
+  pub(in crate::network::prowlarr_network) async fn get_prowlarr_indexers(
+    &mut self,
+  ) -> Result<Vec<ProwlarrIndexer>> {
+    info!("Fetching Prowlarr indexers");
+    let event = ProwlarrEvent::GetIndexers;
+
+    let request_props = self
+      .request_props_from(event, RequestMethod::Get, None::<()>, None, None)
+      .await;
+
+    self
+      .handle_request::<(), Vec<ProwlarrIndexer>>(request_props, |indexers, mut app| {
+        app.data.prowlarr_data.indexers.set_items(indexers);
+      })
+      .await
+  }
+
+  pub(in crate::network::prowlarr_network) async fn get_prowlarr_indexer_schema(
+    &mut self,
+  ) -> Result<Vec<ProwlarrIndexer>> {
+    info!("Fetching Prowlarr indexer schema");
+    let event = ProwlarrEvent::GetIndexerSchema;
+
+    let request_props = self
+      .request_props_from(event, RequestMethod::Get, None::<()>, None, None)
+      .await;
+
+    self
+      .handle_request::<(), Vec<ProwlarrIndexer>>(request_props, |indexer_schema, mut app| {
+        app
+          .data
+          .prowlarr_data
+          .indexer_schema
+          .set_items(indexer_schema);
+      })
+      .await
+  }
+
+  pub(in crate::network::prowlarr_network) async fn test_prowlarr_indexer(
+    &mut self,
+    indexer_id: i64,
+  ) -> Result<Value> {
+    let detail_event = ProwlarrEvent::GetIndexers;
+    let event = ProwlarrEvent::TestIndexer(indexer_id);
+    info!("Testing Prowlarr indexer with ID: {indexer_id}");
+
+    info!("Fetching indexer details for indexer with ID: {indexer_id}");
+
+    let request_props = self
+      .request_props_from(
+        detail_event,
+        RequestMethod::Get,
+        None::<()>,
+        Some(format!("/{indexer_id}")),
+        None,
+      )
+      .await;
+
+    let test_body = self
+      .handle_request::<(), Value>(request_props, |_, _| ())
+      .await?;
+
+    info!("Testing indexer");
+
+    let mut request_props = self
+      .request_props_from(event, RequestMethod::Post, Some(test_body), None, None)
+      .await;
+    request_props.ignore_status_code = true;
+
+    self
+      .handle_request::<Value, Value>(request_props, |test_results, mut app| {
+        if test_results.as_object().is_none() {
+          let error_message = test_results
+            .as_array()
+            .and_then(|arr| arr.first())
+            .and_then(|item| item.get("errorMessage"))
+            .map(|msg| msg.to_string())
+            .unwrap_or_else(|| "Unknown indexer test error".to_string());
+          app.data.prowlarr_data.indexer_test_errors = Some(error_message);
+        } else {
+          app.data.prowlarr_data.indexer_test_errors = Some(String::new());
+        };
+      })
+      .await
+  }
+
+  pub(in crate::network::prowlarr_network) async fn test_all_prowlarr_indexers(
+    &mut self,
+  ) -> Result<Vec<IndexerTestResult>> {
+    info!("Testing all Prowlarr indexers");
+    let event = ProwlarrEvent::TestAllIndexers;
+
+    let mut request_props = self
+      .request_props_from(event, RequestMethod::Post, None, None, None)
+      .await;
+    request_props.ignore_status_code = true;
+
+    let result = self
+      .handle_request::<(), Vec<IndexerTestResult>>(request_props, |test_results, mut app| {
+        let mut test_all_indexer_results = StatefulTable::default();
+        let modal_test_results = test_results
+          .iter()
+          .map(|result| {
+            let validation_failures = result
+              .validation_failures
+              .iter()
+              .map(|failure| {
+                format!(
+                  "Failure for field '{}': {}",
+                  failure.property_name, failure.error_message
+                )
+              })
+              .collect::<Vec<String>>()
+              .join(", ");
+
+            IndexerTestResultModalItem {
+              name: app.data.prowlarr_data.indexer_name(result.id),
+              is_valid: result.is_valid,
+              validation_failures: validation_failures.into(),
+            }
+          })
+          .collect();
+        test_all_indexer_results.set_items(modal_test_results);
+        app.data.prowlarr_data.indexer_test_all_results = Some(test_all_indexer_results);
+      })
+      .await;
+
+    if result.is_err() {
+      self
+        .app
+        .lock()
+        .await
+        .data
+        .prowlarr_data
+        .indexer_test_all_results = Some(StatefulTable::default());
+    }
+
+    result
+  }
+}
@@ -0,0 +1,472 @@
+#[cfg(test)]
+mod tests {
+  use crate::models::HorizontallyScrollableText;
+  use crate::models::prowlarr_models::{ProwlarrIndexer, ProwlarrSerdeable};
+  use crate::models::servarr_data::modals::IndexerTestResultModalItem;
+  use crate::models::servarr_models::{AddIndexerParams, IndexerTestResult};
+  use crate::network::NetworkResource;
+  use crate::network::network_tests::test_utils::{MockServarrApi, test_network};
+  use crate::network::prowlarr_network::ProwlarrEvent;
+  use crate::network::prowlarr_network::prowlarr_network_test_utils::test_utils::{
+    INDEXER_JSON, indexer,
+  };
+  use bimap::BiMap;
+  use mockito::Matcher;
+  use pretty_assertions::assert_eq;
+  use serde_json::{Value, json};
+
+  #[tokio::test]
+  async fn test_handle_add_prowlarr_indexer_event() {
+    let indexer_schema_json = json!([
+      {
+        "enable": false,
+        "appProfileId": 0,
+        "priority": 25,
+        "name": "1337x",
+        "fields": [{ "name": "baseUrl", "type": "select", "value": "https://1337x.to/" }],
+        "implementationName": "Cardigann",
+        "implementation": "Cardigann",
+        "definitionName": "1337x",
+        "protocol": "torrent",
+        "tags": [],
+      },
+      {
+        "enable": false,
+        "appProfileId": 0,
+        "priority": 25,
+        "name": "Torznab",
+        "fields": [
+          { "name": "baseUrl", "type": "textbox", "value": "" },
+          { "name": "apiKey", "type": "textbox", "value": "" },
+        ],
+        "implementationName": "Torznab",
+        "implementation": "Torznab",
+        "definitionName": "torznab",
+        "protocol": "torrent",
+        "tags": [],
+      }
+    ]);
+    let expected_add_indexer_body_json = json!({
+      "enable": true,
+      "appProfileId": 2,
+      "priority": 1,
+      "name": "Test Indexer",
+      "fields": [
+        { "name": "baseUrl", "type": "textbox", "value": "https://test.com" },
+        { "name": "apiKey", "type": "textbox", "value": "test1234" },
+      ],
+      "implementationName": "Torznab",
+      "implementation": "Torznab",
+      "definitionName": "torznab",
+      "protocol": "torrent",
+      "tags": [1, 2],
+    });
+    let add_indexer_params = AddIndexerParams {
+      implementation: "torznab".to_owned(),
+      name: "Test Indexer".to_owned(),
+      priority: 1,
+      app_profile_id: Some(2),
+      tag_input_string: Some("usenet, testing".to_owned()),
+      fields: vec![
+        ("baseUrl".to_owned(), "https://test.com".to_owned()),
+        ("apiKey".to_owned(), "test1234".to_owned()),
+      ],
+      ..AddIndexerParams::default()
+    };
+    let (async_schema_server, app, mut server) = MockServarrApi::get()
+      .returns(indexer_schema_json)
+      .build_for(ProwlarrEvent::GetIndexerSchema)
+      .await;
+    let async_test_server = server
+      .mock(
+        "POST",
+        format!(
+          "/api/v1{}/test",
+          ProwlarrEvent::AddIndexer(add_indexer_params.clone()).resource()
+        )
+        .as_str(),
+      )
+      .with_status(200)
+      .with_body("{}")
+      .match_header("X-Api-Key", "test1234")
+      .match_body(Matcher::Json(expected_add_indexer_body_json.clone()))
+      .create_async()
+      .await;
+    let async_add_server = server
+      .mock(
+        "POST",
+        format!(
+          "/api/v1{}",
+          ProwlarrEvent::AddIndexer(add_indexer_params.clone()).resource()
+        )
+        .as_str(),
+      )
+      .with_status(201)
+      .match_header("X-Api-Key", "test1234")
+      .match_body(Matcher::Json(expected_add_indexer_body_json))
+      .with_body(json!({ "id": 1 }).to_string())
+      .create_async()
+      .await;
+    app.lock().await.server_tabs.set_index(3);
+    app.lock().await.data.prowlarr_data.tags_map =
+      BiMap::from_iter([(1, "usenet".to_owned()), (2, "testing".to_owned())]);
+    let mut network = test_network(&app);
+
+    let ProwlarrSerdeable::Value(value) = network
+      .handle_prowlarr_event(ProwlarrEvent::AddIndexer(add_indexer_params))
+      .await
+      .unwrap()
+    else {
+      panic!("Expected Value")
+    };
+    async_schema_server.assert_async().await;
+    async_test_server.assert_async().await;
+    async_add_server.assert_async().await;
+    assert_eq!(value, json!({ "id": 1 }));
+  }
+
+  #[tokio::test]
+  async fn test_handle_add_prowlarr_indexer_event_does_not_save_indexer_when_test_fails() {
+    let indexer_schema_json = json!([{
+      "name": "1337x",
+      "fields": [],
+      "implementationName": "Cardigann",
+      "implementation": "Cardigann",
+      "definitionName": "1337x",
+      "tags": [],
+    }]);
+    let add_indexer_params = AddIndexerParams {
+      implementation: "1337x".to_owned(),
+      name: "Test Indexer".to_owned(),
+      ..AddIndexerParams::default()
+    };
+    let (async_schema_server, app, mut server) = MockServarrApi::get()
+      .returns(indexer_schema_json)
+      .build_for(ProwlarrEvent::GetIndexerSchema)
+      .await;
+    let async_test_server = server
+      .mock(
+        "POST",
+        format!(
+          "/api/v1{}/test",
+          ProwlarrEvent::AddIndexer(add_indexer_params.clone()).resource()
+        )
+        .as_str(),
+      )
+      .with_status(400)
+      .with_body(json!([{ "propertyName": "", "errorMessage": "test failure" }]).to_string())
+      .create_async()
+      .await;
+    let async_add_server = server
+      .mock(
+        "POST",
+        format!(
+          "/api/v1{}",
+          ProwlarrEvent::AddIndexer(add_indexer_params.clone()).resource()
+        )
+        .as_str(),
+      )
+      .expect(0)
+      .create_async()
+      .await;
+    app.lock().await.server_tabs.set_index(3);
+    let mut network = test_network(&app);
+
+    assert!(
+      network
+        .handle_prowlarr_event(ProwlarrEvent::AddIndexer(add_indexer_params))
+        .await
+        .is_err()
+    );
+
+    async_schema_server.assert_async().await;
+    async_test_server.assert_async().await;
+    async_add_server.assert_async().await;
+  }
+
+  #[tokio::test]
+  async fn test_handle_delete_prowlarr_indexer_event() {
+    let (mock, app, _server) = MockServarrApi::delete()
+      .path("/1")
+      .build_for(ProwlarrEvent::DeleteIndexer(1))
+      .await;
+    app.lock().await.server_tabs.set_index(3);
+    let mut network = test_network(&app);
+
+    assert_ok!(
+      network
+        .handle_prowlarr_event(ProwlarrEvent::DeleteIndexer(1))
+        .await
+    );
+
+    mock.assert_async().await;
+  }
+
+  #[tokio::test]
+  async fn test_handle_get_prowlarr_indexers_event() {
+    let indexers_response_json: Value =
+      Value::Array(vec![serde_json::from_str(INDEXER_JSON).unwrap()]);
+    let response: Vec<ProwlarrIndexer> =
+      serde_json::from_value(indexers_response_json.clone()).unwrap();
+    let (mock, app, _server) = MockServarrApi::get()
+      .returns(indexers_response_json)
+      .build_for(ProwlarrEvent::GetIndexers)
+      .await;
+    app.lock().await.server_tabs.set_index(3);
+    let mut network = test_network(&app);
+
+    let ProwlarrSerdeable::Indexers(indexers) = network
+      .handle_prowlarr_event(ProwlarrEvent::GetIndexers)
+      .await
+      .unwrap()
+    else {
+      panic!("Expected Indexers")
+    };
+
+    mock.assert_async().await;
+    assert_eq!(
+      app.lock().await.data.prowlarr_data.indexers.items,
+      vec![indexer()]
+    );
+    assert_eq!(indexers, response);
+  }
+
+  #[tokio::test]
+  async fn test_handle_test_prowlarr_indexer_event_error() {
+    let indexer_details_json: Value = serde_json::from_str(INDEXER_JSON).unwrap();
+    let response_json = json!([
+    {
+        "isWarning": false,
+        "propertyName": "",
+        "errorMessage": "test failure",
+        "severity": "error"
+    }]);
+    let (async_details_server, app, mut server) = MockServarrApi::get()
+      .returns(indexer_details_json.clone())
+      .path("/1")
+      .build_for(ProwlarrEvent::GetIndexers)
+      .await;
+    let async_test_server = server
+      .mock(
+        "POST",
+        format!("/api/v1{}", ProwlarrEvent::TestIndexer(1).resource()).as_str(),
+      )
+      .with_status(400)
+      .match_header("X-Api-Key", "test1234")
+      .match_body(Matcher::Json(indexer_details_json.clone()))
+      .with_body(response_json.to_string())
+      .create_async()
+      .await;
+    app.lock().await.server_tabs.set_index(3);
+    let mut network = test_network(&app);
+
+    let ProwlarrSerdeable::Value(value) = network
+      .handle_prowlarr_event(ProwlarrEvent::TestIndexer(1))
+      .await
+      .unwrap()
+    else {
+      panic!("Expected Value")
+    };
+
+    async_details_server.assert_async().await;
+    async_test_server.assert_async().await;
+    assert_eq!(
+      app.lock().await.data.prowlarr_data.indexer_test_errors,
+      Some("\"test failure\"".to_owned())
+    );
+    assert_eq!(value, response_json);
+  }
+
+  #[tokio::test]
+  async fn test_handle_test_prowlarr_indexer_event_success() {
+    let indexer_details_json: Value = serde_json::from_str(INDEXER_JSON).unwrap();
+    let (async_details_server, app, mut server) = MockServarrApi::get()
+      .returns(indexer_details_json.clone())
+      .path("/1")
+      .build_for(ProwlarrEvent::GetIndexers)
+      .await;
+    let async_test_server = server
+      .mock(
+        "POST",
+        format!("/api/v1{}", ProwlarrEvent::TestIndexer(1).resource()).as_str(),
+      )
+      .with_status(200)
+      .match_header("X-Api-Key", "test1234")
+      .match_body(Matcher::Json(indexer_details_json.clone()))
+      .with_body("{}")
+      .create_async()
+      .await;
+    app.lock().await.server_tabs.set_index(3);
+    let mut network = test_network(&app);
+
+    let ProwlarrSerdeable::Value(value) = network
+      .handle_prowlarr_event(ProwlarrEvent::TestIndexer(1))
+      .await
+      .unwrap()
+    else {
+      panic!("Expected Value")
+    };
+
+    async_details_server.assert_async().await;
+    async_test_server.assert_async().await;
+    assert_eq!(
+      app.lock().await.data.prowlarr_data.indexer_test_errors,
+      Some(String::new())
+    );
+    assert_eq!(value, json!({}));
+  }
+
+  #[tokio::test]
+  async fn test_handle_test_all_prowlarr_indexers_event() {
+    let indexers = vec![
+      ProwlarrIndexer {
+        id: 1,
+        name: Some("Test 1".to_owned()),
+        ..ProwlarrIndexer::default()
+      },
+      ProwlarrIndexer {
+        id: 2,
+        name: Some("Test 2".to_owned()),
+        ..ProwlarrIndexer::default()
+      },
+    ];
+    let indexer_test_results_modal_items = vec![
+      IndexerTestResultModalItem {
+        name: "Test 1".to_owned(),
+        is_valid: true,
+        validation_failures: HorizontallyScrollableText::default(),
+      },
+      IndexerTestResultModalItem {
+        name: "Test 2".to_owned(),
+        is_valid: false,
+        validation_failures: "Failure for field 'test field 1': test error message".into(),
+      },
+    ];
+    let response_json = json!([
+    {
+      "id": 1,
+      "isValid": true,
+      "validationFailures": []
+    },
+    {
+      "id": 2,
+      "isValid": false,
+      "validationFailures": [
+          {
+              "propertyName": "test field 1",
+              "errorMessage": "test error message",
+              "severity": "error"
+          }
+      ]
+    }]);
+    let response: Vec<IndexerTestResult> = serde_json::from_value(response_json.clone()).unwrap();
+    let (mock, app, _server) = MockServarrApi::post()
+      .returns(response_json)
+      .status(400)
+      .build_for(ProwlarrEvent::TestAllIndexers)
+      .await;
+    app
+      .lock()
+      .await
+      .data
+      .prowlarr_data
+      .indexers
+      .set_items(indexers);
+    app.lock().await.server_tabs.set_index(3);
+    let mut network = test_network(&app);
+
+    let ProwlarrSerdeable::IndexerTestResults(results) = network
+      .handle_prowlarr_event(ProwlarrEvent::TestAllIndexers)
+      .await
+      .unwrap()
+    else {
+      panic!("Expected IndexerTestResults")
+    };
+
+    mock.assert_async().await;
+    assert_eq!(
+      app
+        .lock()
+        .await
+        .data
+        .prowlarr_data
+        .indexer_test_all_results
+        .as_ref()
+        .unwrap()
+        .items,
+      indexer_test_results_modal_items
+    );
+    assert_eq!(results, response);
+  }
+
+  #[tokio::test]
+  async fn test_handle_test_all_prowlarr_indexers_event_sets_empty_table_on_api_error() {
+    let (mock, app, _server) = MockServarrApi::post()
+      .status(500)
+      .build_for(ProwlarrEvent::TestAllIndexers)
+      .await;
+    app.lock().await.server_tabs.set_index(3);
+    let mut network = test_network(&app);
+
+    let result = network
+      .handle_prowlarr_event(ProwlarrEvent::TestAllIndexers)
+      .await;
+
+    mock.assert_async().await;
+    assert_err!(result);
+    let app = app.lock().await;
+    assert_some!(&app.data.prowlarr_data.indexer_test_all_results);
+    assert_is_empty!(
+      app
+        .data
+        .prowlarr_data
+        .indexer_test_all_results
+        .as_ref()
+        .unwrap()
+        .items
+    );
+  }
+
+  #[tokio::test]
+  async fn test_handle_get_prowlarr_indexer_schema_event() {
+    let indexer_schema_json = json!([{
+      "id": 0,
+      "name": "1337x",
+      "implementation": "Cardigann",
+      "implementationName": "Cardigann",
+      "definitionName": "1337x",
+      "configContract": "CardigannSettings",
+      "protocol": "torrent",
+      "privacy": "public",
+      "enable": false,
+      "supportsRss": true,
+      "supportsSearch": true,
+      "priority": 25,
+      "appProfileId": 0,
+      "fields": [{ "name": "baseUrl", "label": "Base Url", "type": "select" }],
+      "tags": []
+    }]);
+    let response: Vec<ProwlarrIndexer> =
+      serde_json::from_value(indexer_schema_json.clone()).unwrap();
+    let (async_server, app, _server) = MockServarrApi::get()
+      .returns(indexer_schema_json)
+      .build_for(ProwlarrEvent::GetIndexerSchema)
+      .await;
+    app.lock().await.server_tabs.set_index(3);
+    let mut network = test_network(&app);
+
+    let ProwlarrSerdeable::Indexers(indexer_schema) = network
+      .handle_prowlarr_event(ProwlarrEvent::GetIndexerSchema)
+      .await
+      .unwrap()
+    else {
+      panic!("Expected Indexers")
+    };
+    async_server.assert_async().await;
+    assert_eq!(
+      app.lock().await.data.prowlarr_data.indexer_schema.items,
+      response
+    );
+    assert_eq!(indexer_schema, response);
+  }
+}
@@ -0,0 +1,318 @@
+use anyhow::Result;
+use log::info;
+
+use super::{NetworkEvent, NetworkResource};
+use crate::models::prowlarr_models::{
+  ProwlarrReleaseDownloadBody, ProwlarrSearchParams, ProwlarrSerdeable, ProwlarrTaskName,
+};
+use crate::models::servarr_models::{AddIndexerParams, Tag};
+use crate::network::{Network, RequestMethod};
+
+mod applications;
+mod history;
+mod indexer_proxies;
+mod indexers;
+mod search;
+mod system;
+
+#[cfg(test)]
+#[path = "prowlarr_network_tests.rs"]
+mod prowlarr_network_tests;
+
+#[cfg(test)]
+#[path = "prowlarr_network_test_utils.rs"]
+pub mod prowlarr_network_test_utils;
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum ProwlarrEvent {
+  AddIndexer(AddIndexerParams),
+  AddTag(String),
+  DeleteApplication(i64),
+  DeleteIndexer(i64),
+  DeleteIndexerProxy(i64),
+  DeleteTag(i64),
+  GetApplications,
+  GetHistory(u64),
+  GetHostConfig,
+  GetIndexerProxies,
+  GetIndexers,
+  GetIndexerSchema,
+  GetLogs(u64),
+  GetQueuedEvents,
+  GetSecurityConfig,
+  GetStatus,
+  GetTags,
+  GetTasks,
+  GetUpdates,
+  GrabRelease(ProwlarrReleaseDownloadBody),
+  HealthCheck,
+  SearchReleases(ProwlarrSearchParams),
+  StartTask(ProwlarrTaskName),
+  TestIndexer(i64),
+  TestAllIndexers,
+}
+
+impl NetworkResource for ProwlarrEvent {
+  fn resource(&self) -> &'static str {
+    match &self {
+      ProwlarrEvent::AddTag(_) | ProwlarrEvent::DeleteTag(_) | ProwlarrEvent::GetTags => "/tag",
+      ProwlarrEvent::DeleteApplication(_) | ProwlarrEvent::GetApplications => "/applications",
+      ProwlarrEvent::AddIndexer(_)
+      | ProwlarrEvent::DeleteIndexer(_)
+      | ProwlarrEvent::GetIndexers => "/indexer",
+      ProwlarrEvent::GetIndexerSchema => "/indexer/schema",
+      ProwlarrEvent::DeleteIndexerProxy(_) | ProwlarrEvent::GetIndexerProxies => "/indexerproxy",
+      ProwlarrEvent::GetHistory(_) => "/history",
+      ProwlarrEvent::GetHostConfig | ProwlarrEvent::GetSecurityConfig => "/config/host",
+      ProwlarrEvent::GetLogs(_) => "/log",
+      ProwlarrEvent::GetQueuedEvents | ProwlarrEvent::StartTask(_) => "/command",
+      ProwlarrEvent::GetStatus => "/system/status",
+      ProwlarrEvent::GetTasks => "/system/task",
+      ProwlarrEvent::GetUpdates => "/update",
+      ProwlarrEvent::GrabRelease(_) | ProwlarrEvent::SearchReleases(_) => "/search",
+      ProwlarrEvent::HealthCheck => "/health",
+      ProwlarrEvent::TestIndexer(_) => "/indexer/test",
+      ProwlarrEvent::TestAllIndexers => "/indexer/testall",
+    }
+  }
+
+  fn is_read_only(&self) -> bool {
+    matches!(
+      self,
+      ProwlarrEvent::GetApplications
+        | ProwlarrEvent::GetHistory(..)
+        | ProwlarrEvent::GetHostConfig
+        | ProwlarrEvent::GetIndexerProxies
+        | ProwlarrEvent::GetIndexers
+        | ProwlarrEvent::GetIndexerSchema
+        | ProwlarrEvent::GetLogs(..)
+        | ProwlarrEvent::GetQueuedEvents
+        | ProwlarrEvent::GetSecurityConfig
+        | ProwlarrEvent::GetStatus
+        | ProwlarrEvent::GetTags
+        | ProwlarrEvent::GetTasks
+        | ProwlarrEvent::GetUpdates
+        | ProwlarrEvent::HealthCheck
+        | ProwlarrEvent::SearchReleases(..)
+    )
+  }
+}
+
+impl From<ProwlarrEvent> for NetworkEvent {
+  fn from(prowlarr_event: ProwlarrEvent) -> Self {
+    NetworkEvent::Prowlarr(prowlarr_event)
+  }
+}
+
+impl Network<'_, '_> {
+  pub async fn handle_prowlarr_event(
+    &mut self,
+    prowlarr_event: ProwlarrEvent,
+  ) -> Result<ProwlarrSerdeable> {
+    match prowlarr_event {
+      ProwlarrEvent::AddIndexer(params) => self
+        .add_prowlarr_indexer(params)
+        .await
+        .map(ProwlarrSerdeable::from),
+      ProwlarrEvent::AddTag(tag) => self
+        .add_prowlarr_tag(tag)
+        .await
+        .map(ProwlarrSerdeable::from),
+      ProwlarrEvent::DeleteApplication(application_id) => self
+        .delete_prowlarr_application(application_id)
+        .await
+        .map(ProwlarrSerdeable::from),
+      ProwlarrEvent::DeleteIndexer(indexer_id) => self
+        .delete_prowlarr_indexer(indexer_id)
+        .await
+        .map(ProwlarrSerdeable::from),
+      ProwlarrEvent::DeleteIndexerProxy(indexer_proxy_id) => self
+        .delete_prowlarr_indexer_proxy(indexer_proxy_id)
+        .await
+        .map(ProwlarrSerdeable::from),
+      ProwlarrEvent::DeleteTag(tag_id) => self
+        .delete_prowlarr_tag(tag_id)
+        .await
+        .map(ProwlarrSerdeable::from),
+      ProwlarrEvent::GetApplications => self
+        .get_prowlarr_applications()
+        .await
+        .map(ProwlarrSerdeable::from),
+      ProwlarrEvent::GetHistory(events) => self
+        .get_prowlarr_history(events)
+        .await
+        .map(ProwlarrSerdeable::from),
+      ProwlarrEvent::GetHostConfig => self
+        .get_prowlarr_host_config()
+        .await
+        .map(ProwlarrSerdeable::from),
+      ProwlarrEvent::GetIndexerProxies => self
+        .get_prowlarr_indexer_proxies()
+        .await
+        .map(ProwlarrSerdeable::from),
+      ProwlarrEvent::GetIndexers => self
+        .get_prowlarr_indexers()
+        .await
+        .map(ProwlarrSerdeable::from),
+      ProwlarrEvent::GetIndexerSchema => self
+        .get_prowlarr_indexer_schema()
+        .await
+        .map(ProwlarrSerdeable::from),
+      ProwlarrEvent::GetLogs(events) => self
+        .get_prowlarr_logs(events)
+        .await
+        .map(ProwlarrSerdeable::from),
+      ProwlarrEvent::GetQueuedEvents => self
+        .get_queued_prowlarr_events()
+        .await
+        .map(ProwlarrSerdeable::from),
+      ProwlarrEvent::GetSecurityConfig => self
+        .get_prowlarr_security_config()
+        .await
+        .map(ProwlarrSerdeable::from),
+      ProwlarrEvent::GetStatus => self
+        .get_prowlarr_status()
+        .await
+        .map(ProwlarrSerdeable::from),
+      ProwlarrEvent::GetTags => self.get_prowlarr_tags().await.map(ProwlarrSerdeable::from),
+      ProwlarrEvent::GetTasks => self.get_prowlarr_tasks().await.map(ProwlarrSerdeable::from),
+      ProwlarrEvent::GetUpdates => self
+        .get_prowlarr_updates()
+        .await
+        .map(ProwlarrSerdeable::from),
+      ProwlarrEvent::GrabRelease(body) => self
+        .grab_prowlarr_release(body)
+        .await
+        .map(ProwlarrSerdeable::from),
+      ProwlarrEvent::HealthCheck => self
+        .get_prowlarr_healthcheck()
+        .await
+        .map(ProwlarrSerdeable::from),
+      ProwlarrEvent::SearchReleases(params) => self
+        .search_prowlarr_releases(params)
+        .await
+        .map(ProwlarrSerdeable::from),
+      ProwlarrEvent::StartTask(task_name) => self
+        .start_prowlarr_task(task_name)
+        .await
+        .map(ProwlarrSerdeable::from),
+      ProwlarrEvent::TestIndexer(indexer_id) => self
+        .test_prowlarr_indexer(indexer_id)
+        .await
+        .map(ProwlarrSerdeable::from),
+      ProwlarrEvent::TestAllIndexers => self
+        .test_all_prowlarr_indexers()
+        .await
+        .map(ProwlarrSerdeable::from),
+    }
+  }
+
+  async fn get_prowlarr_healthcheck(&mut self) -> Result<()> {
+    info!("Performing Prowlarr health check");
+    let event = ProwlarrEvent::HealthCheck;
+
+    let request_props = self
+      .request_props_from(event, RequestMethod::Get, None::<()>, None, None)
+      .await;
+
+    self
+      .handle_request::<(), ()>(request_props, |_, _| ())
+      .await
+  }
+
+  async fn get_prowlarr_tags(&mut self) -> Result<Vec<Tag>> {
+    info!("Fetching Prowlarr tags");
+    let event = ProwlarrEvent::GetTags;
+
+    let request_props = self
+      .request_props_from(event, RequestMethod::Get, None::<()>, None, None)
+      .await;
+
+    self
+      .handle_request::<(), Vec<Tag>>(request_props, |tags_vec, mut app| {
+        app.data.prowlarr_data.tags_map = tags_vec
+          .into_iter()
+          .map(|tag| (tag.id, tag.label))
+          .collect();
+      })
+      .await
+  }
+
+  async fn add_prowlarr_tag(&mut self, tag: String) -> Result<Tag> {
+    info!("Adding a new Prowlarr tag");
+    let event = ProwlarrEvent::AddTag(String::new());
+
+    let request_props = self
+      .request_props_from(
+        event,
+        RequestMethod::Post,
+        Some(serde_json::json!({ "label": tag })),
+        None,
+        None,
+      )
+      .await;
+
+    self
+      .handle_request::<serde_json::Value, Tag>(request_props, |tag, mut app| {
+        app.data.prowlarr_data.tags_map.insert(tag.id, tag.label);
+      })
+      .await
+  }
+
+  async fn delete_prowlarr_tag(&mut self, id: i64) -> Result<()> {
+    info!("Deleting Prowlarr tag with ID: {id}");
+    let event = ProwlarrEvent::DeleteTag(id);
+
+    let request_props = self
+      .request_props_from(
+        event,
+        RequestMethod::Delete,
+        None::<()>,
+        Some(format!("/{id}")),
+        None,
+      )
+      .await;
+
+    self
+      .handle_request::<(), ()>(request_props, |_, _| ())
+      .await
+  }
+
+  async fn extract_and_add_prowlarr_tag_ids_vec(&mut self, edit_tags: &str) -> Vec<i64> {
+    let missing_tags_vec = {
+      let tags_map = &self.app.lock().await.data.prowlarr_data.tags_map;
+      edit_tags
+        .split(',')
+        .filter(|&tag| {
+          !tag.is_empty() && tags_map.get_by_right(tag.to_lowercase().trim()).is_none()
+        })
+        .collect::<Vec<&str>>()
+    };
+
+    let mut added_tags = Vec::new();
+    for tag in missing_tags_vec {
+      added_tags.push(
+        self
+          .add_prowlarr_tag(tag.trim().to_owned())
+          .await
+          .expect("Unable to add tag"),
+      );
+    }
+
+    let app = self.app.lock().await;
+    edit_tags
+      .split(',')
+      .filter(|tag| !tag.is_empty())
+      .map(|tag| {
+        let label = tag.to_lowercase();
+        let label = label.trim();
+        added_tags
+          .iter()
+          .find(|added_tag| added_tag.label.to_lowercase() == label)
+          .map(|added_tag| added_tag.id)
+          .or_else(|| app.data.prowlarr_data.tags_map.get_by_right(label).copied())
+          .unwrap()
+      })
+      .collect()
+  }
+}
@@ -0,0 +1,277 @@
+#[cfg(test)]
+#[allow(dead_code)]
+pub mod test_utils {
+  use crate::models::ScrollableText;
+  use crate::models::prowlarr_models::{
+    Application, ApplicationSyncLevel, IndexerPrivacy, IndexerProxy, ProwlarrHistoryData,
+    ProwlarrHistoryEventType, ProwlarrHistoryItem, ProwlarrHistoryWrapper, ProwlarrIndexer,
+    ProwlarrRelease, ProwlarrTask, ProwlarrTaskName, ReleaseCategory, SystemStatus,
+  };
+  use crate::models::servarr_models::{IndexerField, Tag};
+  use bimap::BiMap;
+  use chrono::DateTime;
+  use indoc::formatdoc;
+  use serde_json::{Number, json};
+
+  pub const INDEXER_JSON: &str = r#"{
+    "id": 1,
+    "name": "Test Indexer",
+    "implementation": "Cardigann",
+    "implementationName": "Cardigann",
+    "definitionName": "testindexer",
+    "configContract": "CardigannSettings",
+    "infoLink": "https://wiki.servarr.com/prowlarr/supported-indexers#testindexer",
+    "protocol": "torrent",
+    "privacy": "private",
+    "enable": true,
+    "supportsRss": true,
+    "supportsSearch": true,
+    "priority": 25,
+    "appProfileId": 1,
+    "added": "2023-05-20T21:29:16Z",
+    "fields": [
+      { "name": "definitionFile", "value": "testindexer" },
+      { "name": "baseUrl", "value": "https://test.com" }
+    ],
+    "tags": [1]
+  }"#;
+
+  pub const APPLICATION_JSON: &str = r#"{
+    "id": 1,
+    "name": "Sonarr",
+    "implementation": "Sonarr",
+    "implementationName": "Sonarr",
+    "configContract": "SonarrSettings",
+    "syncLevel": "fullSync",
+    "fields": [
+      { "name": "baseUrl", "value": "http://localhost:8989" }
+    ],
+    "tags": [1]
+  }"#;
+
+  pub const INDEXER_PROXY_JSON: &str = r#"{
+    "id": 1,
+    "name": "FlareSolverr",
+    "implementation": "FlareSolverr",
+    "implementationName": "FlareSolverr",
+    "configContract": "FlareSolverrSettings",
+    "fields": [
+      { "name": "host", "value": "http://localhost:8191/" }
+    ],
+    "tags": [1]
+  }"#;
+
+  pub const RELEASE_JSON: &str = r#"{
+    "guid": "1234",
+    "age": 1,
+    "title": "Test Release",
+    "indexer": "Test Indexer",
+    "indexerId": 1,
+    "size": 1234,
+    "protocol": "torrent",
+    "seeders": 10,
+    "leechers": 2,
+    "grabs": 5,
+    "categories": [{ "id": 2000, "name": "Movies" }],
+    "publishDate": "2023-05-20T21:29:16Z",
+    "infoUrl": "https://test.com/1234"
+  }"#;
+
+  pub const HISTORY_ITEM_JSON: &str = r#"{
+    "id": 1,
+    "indexerId": 1,
+    "date": "2023-05-20T21:29:16Z",
+    "successful": true,
+    "eventType": "indexerQuery",
+    "data": {
+      "query": "test query",
+      "queryType": "search",
+      "source": "Prowlarr",
+      "elapsedTime": "1023",
+      "queryResults": "100"
+    }
+  }"#;
+
+  pub fn tag() -> Tag {
+    Tag {
+      id: 1,
+      label: "alex".to_owned(),
+    }
+  }
+
+  pub fn tags_map() -> BiMap<i64, String> {
+    let tag = tag();
+    BiMap::from_iter(vec![(tag.id, tag.label)])
+  }
+
+  pub fn indexer() -> ProwlarrIndexer {
+    ProwlarrIndexer {
+      id: 1,
+      name: Some("Test Indexer".to_owned()),
+      implementation: Some("Cardigann".to_owned()),
+      implementation_name: Some("Cardigann".to_owned()),
+      definition_name: Some("testindexer".to_owned()),
+      config_contract: Some("CardigannSettings".to_owned()),
+      info_link: Some(
+        "https://wiki.servarr.com/prowlarr/supported-indexers#testindexer".to_owned(),
+      ),
+      protocol: "torrent".to_owned(),
+      privacy: IndexerPrivacy::Private,
+      enable: true,
+      supports_rss: true,
+      supports_search: true,
+      priority: 25,
+      app_profile_id: 1,
+      added: Some(DateTime::from(
+        DateTime::parse_from_rfc3339("2023-05-20T21:29:16Z").unwrap(),
+      )),
+      fields: Some(vec![
+        IndexerField {
+          name: Some("definitionFile".to_owned()),
+          value: Some(json!("testindexer")),
+          ..IndexerField::default()
+        },
+        IndexerField {
+          name: Some("baseUrl".to_owned()),
+          value: Some(json!("https://test.com")),
+          ..IndexerField::default()
+        },
+      ]),
+      tags: vec![Number::from(1)],
+    }
+  }
+
+  pub fn application() -> Application {
+    Application {
+      id: 1,
+      name: Some("Sonarr".to_owned()),
+      implementation: Some("Sonarr".to_owned()),
+      implementation_name: Some("Sonarr".to_owned()),
+      config_contract: Some("SonarrSettings".to_owned()),
+      sync_level: ApplicationSyncLevel::FullSync,
+      fields: Some(vec![IndexerField {
+        name: Some("baseUrl".to_owned()),
+        value: Some(json!("http://localhost:8989")),
+        ..IndexerField::default()
+      }]),
+      tags: vec![Number::from(1)],
+    }
+  }
+
+  pub fn indexer_proxy() -> IndexerProxy {
+    IndexerProxy {
+      id: 1,
+      name: Some("FlareSolverr".to_owned()),
+      implementation: Some("FlareSolverr".to_owned()),
+      implementation_name: Some("FlareSolverr".to_owned()),
+      config_contract: Some("FlareSolverrSettings".to_owned()),
+      fields: Some(vec![IndexerField {
+        name: Some("host".to_owned()),
+        value: Some(json!("http://localhost:8191/")),
+        ..IndexerField::default()
+      }]),
+      tags: vec![Number::from(1)],
+    }
+  }
+
+  pub fn release() -> ProwlarrRelease {
+    ProwlarrRelease {
+      guid: "1234".to_owned(),
+      age: 1,
+      title: "Test Release".into(),
+      indexer: "Test Indexer".to_owned(),
+      indexer_id: 1,
+      size: 1234,
+      protocol: "torrent".to_owned(),
+      seeders: Some(Number::from(10)),
+      leechers: Some(Number::from(2)),
+      grabs: Some(Number::from(5)),
+      categories: vec![ReleaseCategory {
+        id: 2000,
+        name: Some("Movies".to_owned()),
+      }],
+      publish_date: Some(DateTime::from(
+        DateTime::parse_from_rfc3339("2023-05-20T21:29:16Z").unwrap(),
+      )),
+      info_url: Some("https://test.com/1234".to_owned()),
+    }
+  }
+
+  pub fn prowlarr_history_data() -> ProwlarrHistoryData {
+    ProwlarrHistoryData {
+      query: Some("test query".to_owned()),
+      query_type: Some("search".to_owned()),
+      source: Some("Prowlarr".to_owned()),
+      elapsed_time: Some("1023".to_owned()),
+      query_results: Some("100".to_owned()),
+      ..ProwlarrHistoryData::default()
+    }
+  }
+
+  pub fn prowlarr_history_item() -> ProwlarrHistoryItem {
+    ProwlarrHistoryItem {
+      id: 1,
+      indexer_id: 1,
+      date: DateTime::from(DateTime::parse_from_rfc3339("2023-05-20T21:29:16Z").unwrap()),
+      successful: true,
+      event_type: ProwlarrHistoryEventType::IndexerQuery,
+      data: prowlarr_history_data(),
+    }
+  }
+
+  pub fn prowlarr_history_wrapper() -> ProwlarrHistoryWrapper {
+    ProwlarrHistoryWrapper {
+      records: vec![prowlarr_history_item()],
+    }
+  }
+
+  pub fn system_status() -> SystemStatus {
+    SystemStatus {
+      version: "1.0".to_owned(),
+      start_time: DateTime::from(DateTime::parse_from_rfc3339("2023-02-25T20:16:43Z").unwrap()),
+    }
+  }
+
+  pub fn task() -> ProwlarrTask {
+    ProwlarrTask {
+      name: "Application Indexer Sync".to_owned(),
+      task_name: ProwlarrTaskName::ApplicationIndexerSync,
+      interval: 360,
+      last_execution: DateTime::from(DateTime::parse_from_rfc3339("2023-05-20T21:29:16Z").unwrap()),
+      next_execution: DateTime::from(DateTime::parse_from_rfc3339("2023-05-21T03:29:16Z").unwrap()),
+    }
+  }
+
+  pub fn log_line() -> &'static str {
+    "2025-12-16 16:40:59 UTC|INFO|ApplicationService|Syncing indexers to Sonarr"
+  }
+
+  pub fn updates() -> ScrollableText {
+    let line_break = "-".repeat(200);
+    ScrollableText::with_string(formatdoc!(
+      "
+    The latest version of Prowlarr is already installed
+
+    4.3.2.1 - 2023-04-15 02:02:53 UTC (Currently Installed)
+    {line_break}
+    New:
+      * Cool new thing
+    Fixed:
+      * Some bugs killed
+
+
+    3.2.1.0 - 2023-04-15 02:02:53 UTC (Previously Installed)
+    {line_break}
+    New:
+      * Cool new thing (old)
+      * Other cool new thing (old)
+
+
+    2.1.0 - 2023-04-15 02:02:53 UTC
+    {line_break}
+    Fixed:
+      * Killed bug 1
+      * Fixed bug 2"
+    ))
+  }
+}
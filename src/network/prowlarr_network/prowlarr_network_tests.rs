@@ -0,0 +1,194 @@
+#[cfg(test)]
+mod tests {
+  use crate::models::prowlarr_models::{ProwlarrSerdeable, ProwlarrTaskName};
+  use crate::models::servarr_models::{AddIndexerParams, Tag};
+  use crate::network::network_tests::test_utils::{MockServarrApi, test_network};
+  use crate::network::{NetworkEvent, NetworkResource, prowlarr_network::ProwlarrEvent};
+  use pretty_assertions::{assert_eq, assert_str_eq};
+  use rstest::rstest;
+  use serde_json::json;
+
+  #[rstest]
+  fn test_resource_applications(
+    #[values(ProwlarrEvent::GetApplications, ProwlarrEvent::DeleteApplication(0))]
+    event: ProwlarrEvent,
+  ) {
+    assert_str_eq!(event.resource(), "/applications");
+  }
+
+  #[rstest]
+  fn test_resource_indexer(
+    #[values(
+      ProwlarrEvent::AddIndexer(AddIndexerParams::default()),
+      ProwlarrEvent::GetIndexers,
+      ProwlarrEvent::DeleteIndexer(0)
+    )]
+    event: ProwlarrEvent,
+  ) {
+    assert_str_eq!(event.resource(), "/indexer");
+  }
+
+  #[rstest]
+  fn test_resource_indexer_proxy(
+    #[values(ProwlarrEvent::GetIndexerProxies, ProwlarrEvent::DeleteIndexerProxy(0))]
+    event: ProwlarrEvent,
+  ) {
+    assert_str_eq!(event.resource(), "/indexerproxy");
+  }
+
+  #[rstest]
+  fn test_resource_tag(
+    #[values(
+      ProwlarrEvent::AddTag(String::new()),
+      ProwlarrEvent::DeleteTag(0),
+      ProwlarrEvent::GetTags
+    )]
+    event: ProwlarrEvent,
+  ) {
+    assert_str_eq!(event.resource(), "/tag");
+  }
+
+  #[rstest]
+  fn test_resource_config(
+    #[values(ProwlarrEvent::GetHostConfig, ProwlarrEvent::GetSecurityConfig)] event: ProwlarrEvent,
+  ) {
+    assert_str_eq!(event.resource(), "/config/host");
+  }
+
+  #[rstest]
+  fn test_resource_command(
+    #[values(
+      ProwlarrEvent::GetQueuedEvents,
+      ProwlarrEvent::StartTask(ProwlarrTaskName::default())
+    )]
+    event: ProwlarrEvent,
+  ) {
+    assert_str_eq!(event.resource(), "/command");
+  }
+
+  #[rstest]
+  fn test_resource_search(
+    #[values(
+      ProwlarrEvent::SearchReleases(Default::default()),
+      ProwlarrEvent::GrabRelease(Default::default())
+    )]
+    event: ProwlarrEvent,
+  ) {
+    assert_str_eq!(event.resource(), "/search");
+  }
+
+  #[rstest]
+  #[case(ProwlarrEvent::GetHistory(0), "/history")]
+  #[case(ProwlarrEvent::GetIndexerSchema, "/indexer/schema")]
+  #[case(ProwlarrEvent::GetLogs(500), "/log")]
+  #[case(ProwlarrEvent::GetStatus, "/system/status")]
+  #[case(ProwlarrEvent::GetTasks, "/system/task")]
+  #[case(ProwlarrEvent::GetUpdates, "/update")]
+  #[case(ProwlarrEvent::HealthCheck, "/health")]
+  #[case(ProwlarrEvent::TestIndexer(0), "/indexer/test")]
+  #[case(ProwlarrEvent::TestAllIndexers, "/indexer/testall")]
+  fn test_resource(#[case] event: ProwlarrEvent, #[case] expected_uri: &str) {
+    assert_str_eq!(event.resource(), expected_uri);
+  }
+
+  #[test]
+  fn test_from_prowlarr_event() {
+    assert_eq!(
+      NetworkEvent::Prowlarr(ProwlarrEvent::HealthCheck),
+      NetworkEvent::from(ProwlarrEvent::HealthCheck)
+    );
+  }
+
+  #[tokio::test]
+  async fn test_handle_get_prowlarr_healthcheck_event() {
+    let (mock, app, _server) = MockServarrApi::get()
+      .build_for(ProwlarrEvent::HealthCheck)
+      .await;
+    app.lock().await.server_tabs.set_index(3);
+    let mut network = test_network(&app);
+
+    let _ = network
+      .handle_prowlarr_event(ProwlarrEvent::HealthCheck)
+      .await;
+
+    mock.assert_async().await;
+  }
+
+  #[tokio::test]
+  async fn test_handle_get_prowlarr_tags_event() {
+    let tags_json = json!([{
+      "id": 1,
+      "label": "usenet"
+    }]);
+    let response: Vec<Tag> = serde_json::from_value(tags_json.clone()).unwrap();
+    let (mock, app, _server) = MockServarrApi::get()
+      .returns(tags_json)
+      .build_for(ProwlarrEvent::GetTags)
+      .await;
+    app.lock().await.server_tabs.set_index(3);
+    let mut network = test_network(&app);
+
+    let result = network.handle_prowlarr_event(ProwlarrEvent::GetTags).await;
+
+    mock.assert_async().await;
+
+    let ProwlarrSerdeable::Tags(tags) = result.unwrap() else {
+      panic!("Expected Tags");
+    };
+
+    assert_eq!(tags, response);
+    assert_eq!(
+      app.lock().await.data.prowlarr_data.tags_map.get_by_left(&1),
+      Some(&"usenet".to_owned())
+    );
+  }
+
+  #[tokio::test]
+  async fn test_handle_add_prowlarr_tag_event() {
+    let tag_json = json!({
+      "id": 1,
+      "label": "usenet"
+    });
+    let response: Tag = serde_json::from_value(tag_json.clone()).unwrap();
+    let (mock, app, _server) = MockServarrApi::post()
+      .with_request_body(json!({ "label": "usenet" }))
+      .returns(tag_json)
+      .build_for(ProwlarrEvent::AddTag("usenet".to_owned()))
+      .await;
+    app.lock().await.server_tabs.set_index(3);
+    let mut network = test_network(&app);
+
+    let result = network
+      .handle_prowlarr_event(ProwlarrEvent::AddTag("usenet".to_owned()))
+      .await;
+
+    mock.assert_async().await;
+
+    let ProwlarrSerdeable::Tag(tag) = result.unwrap() else {
+      panic!("Expected Tag");
+    };
+
+    assert_eq!(tag, response);
+    assert_eq!(
+      app.lock().await.data.prowlarr_data.tags_map.get_by_left(&1),
+      Some(&"usenet".to_owned())
+    );
+  }
+
+  #[tokio::test]
+  async fn test_handle_delete_prowlarr_tag_event() {
+    let (mock, app, _server) = MockServarrApi::delete()
+      .path("/1")
+      .build_for(ProwlarrEvent::DeleteTag(1))
+      .await;
+    app.lock().await.server_tabs.set_index(3);
+    let mut network = test_network(&app);
+
+    let result = network
+      .handle_prowlarr_event(ProwlarrEvent::DeleteTag(1))
+      .await;
+
+    mock.assert_async().await;
+    assert!(result.is_ok());
+  }
+}
@@ -0,0 +1,105 @@
+use crate::models::prowlarr_models::{
+  ProwlarrRelease, ProwlarrReleaseDownloadBody, ProwlarrSearchParams,
+};
+use crate::models::servarr_data::Notification;
+use crate::network::prowlarr_network::ProwlarrEvent;
+use crate::network::{Network, RequestMethod};
+use anyhow::Result;
+use log::info;
+use serde_json::Value;
+use urlencoding::encode;
+
+#[cfg(test)]
+#[path = "prowlarr_search_network_tests.rs"]
+mod prowlarr_search_network_tests;
+
+impl Network<'_, '_> {
+  pub(in crate::network::prowlarr_network) async fn search_prowlarr_releases(
+    &mut self,
+    params: ProwlarrSearchParams,
+  ) -> Result<Vec<ProwlarrRelease>> {
+    info!("Searching Prowlarr indexers with params: {params:?}");
+    let event = ProwlarrEvent::SearchReleases(ProwlarrSearchParams::default());
+
+    let mut query_params = vec![
+      format!("query={}", encode(&params.query)),
+      "type=search".to_owned(),
+    ];
+    query_params.extend(
+      params
+        .indexer_ids
+        .iter()
+        .map(|indexer_id| format!("indexerIds={indexer_id}")),
+    );
+    query_params.extend(
+      params
+        .categories
+        .iter()
+        .map(|category| format!("categories={category}")),
+    );
+    if let Some(limit) = params.limit {
+      query_params.push(format!("limit={limit}"));
+    }
+
+    let request_props = self
+      .request_props_from(
+        event,
+        RequestMethod::Get,
+        None::<()>,
+        None,
+        Some(query_params.join("&")),
+      )
+      .await;
+
+    self
+      .handle_request::<(), Vec<ProwlarrRelease>>(request_props, |release_vec, mut app| {
+        app.data.prowlarr_data.search_results.set_items(release_vec);
+        app
+          .data
+          .prowlarr_data
+          .search_results
+          .apply_sorting_toggle(false);
+      })
+      .await
+  }
+
+  pub(in crate::network::prowlarr_network) async fn grab_prowlarr_release(
+    &mut self,
+    prowlarr_release_download_body: ProwlarrReleaseDownloadBody,
+  ) -> Result<Value> {
+    let event = ProwlarrEvent::GrabRelease(ProwlarrReleaseDownloadBody::default());
+    info!("Grabbing Prowlarr release with params: {prowlarr_release_download_body:?}");
+
+    let request_props = self
+      .request_props_from(
+        event,
+        RequestMethod::Post,
+        Some(prowlarr_release_download_body),
+        None,
+        None,
+      )
+      .await;
+
+    let result = self
+      .handle_request::<ProwlarrReleaseDownloadBody, Value>(request_props, |_, mut app| {
+        app.notification = Some(Notification::new(
+          "Grab Result".to_owned(),
+          "Grab request sent successfully".to_owned(),
+          true,
+        ));
+      })
+      .await;
+
+    if result.is_err() {
+      let mut app = self.app.lock().await;
+      std::mem::take(&mut app.error.text);
+      app.notification = Some(Notification::new(
+        "Grab Failed".to_owned(),
+        "Grab request failed. Check the logs for more details.".to_owned(),
+        false,
+      ));
+    }
+
+    result
+  }
+}
@@ -0,0 +1,124 @@
+#[cfg(test)]
+mod tests {
+  use crate::models::prowlarr_models::{
+    ProwlarrRelease, ProwlarrReleaseDownloadBody, ProwlarrSearchParams, ProwlarrSerdeable,
+  };
+  use crate::network::network_tests::test_utils::{MockServarrApi, test_network};
+  use crate::network::prowlarr_network::ProwlarrEvent;
+  use crate::network::prowlarr_network::prowlarr_network_test_utils::test_utils::{
+    RELEASE_JSON, release,
+  };
+  use pretty_assertions::assert_eq;
+  use serde_json::{Value, json};
+
+  #[tokio::test]
+  async fn test_handle_search_prowlarr_releases_event() {
+    let releases_json = Value::Array(vec![serde_json::from_str(RELEASE_JSON).unwrap()]);
+    let response: Vec<ProwlarrRelease> = serde_json::from_value(releases_json.clone()).unwrap();
+    let params = ProwlarrSearchParams {
+      query: "test query".to_owned(),
+      ..ProwlarrSearchParams::default()
+    };
+    let (mock, app, _server) = MockServarrApi::get()
+      .returns(releases_json)
+      .query("query=test%20query&type=search")
+      .build_for(ProwlarrEvent::SearchReleases(params.clone()))
+      .await;
+    app.lock().await.server_tabs.set_index(3);
+    let mut network = test_network(&app);
+
+    let ProwlarrSerdeable::Releases(releases) = network
+      .handle_prowlarr_event(ProwlarrEvent::SearchReleases(params))
+      .await
+      .unwrap()
+    else {
+      panic!("Expected Releases")
+    };
+
+    mock.assert_async().await;
+    assert_eq!(
+      app.lock().await.data.prowlarr_data.search_results.items,
+      vec![release()]
+    );
+    assert_eq!(releases, response);
+  }
+
+  #[tokio::test]
+  async fn test_handle_search_prowlarr_releases_event_with_all_params() {
+    let params = ProwlarrSearchParams {
+      query: "test".to_owned(),
+      indexer_ids: vec![1, 2],
+      categories: vec![2000],
+      limit: Some(50),
+    };
+    let (mock, app, _server) = MockServarrApi::get()
+      .returns(json!([]))
+      .query("query=test&type=search&indexerIds=1&indexerIds=2&categories=2000&limit=50")
+      .build_for(ProwlarrEvent::SearchReleases(params.clone()))
+      .await;
+    app.lock().await.server_tabs.set_index(3);
+    let mut network = test_network(&app);
+
+    assert_ok!(
+      network
+        .handle_prowlarr_event(ProwlarrEvent::SearchReleases(params))
+        .await
+    );
+
+    mock.assert_async().await;
+  }
+
+  #[tokio::test]
+  async fn test_handle_grab_prowlarr_release_event() {
+    let body = ProwlarrReleaseDownloadBody {
+      guid: "1234".to_owned(),
+      indexer_id: 2,
+    };
+    let (mock, app, _server) = MockServarrApi::post()
+      .with_request_body(json!({
+        "guid": "1234",
+        "indexerId": 2
+      }))
+      .returns(json!({}))
+      .build_for(ProwlarrEvent::GrabRelease(body.clone()))
+      .await;
+    app.lock().await.server_tabs.set_index(3);
+    let mut network = test_network(&app);
+
+    assert_ok!(
+      network
+        .handle_prowlarr_event(ProwlarrEvent::GrabRelease(body))
+        .await
+    );
+
+    mock.assert_async().await;
+    let app = app.lock().await;
+    assert_some!(&app.notification);
+    assert!(app.notification.as_ref().unwrap().success);
+  }
+
+  #[tokio::test]
+  async fn test_handle_grab_prowlarr_release_event_failure_sets_notification() {
+    let (mock, app, _server) = MockServarrApi::post()
+      .status(500)
+      .build_for(ProwlarrEvent::GrabRelease(
+        ProwlarrReleaseDownloadBody::default(),
+      ))
+      .await;
+    app.lock().await.server_tabs.set_index(3);
+    let mut network = test_network(&app);
+
+    assert_err!(
+      network
+        .handle_prowlarr_event(ProwlarrEvent::GrabRelease(
+          ProwlarrReleaseDownloadBody::default()
+        ))
+        .await
+    );
+
+    mock.assert_async().await;
+    let app = app.lock().await;
+    assert!(app.error.text.is_empty());
+    assert!(!app.notification.as_ref().unwrap().success);
+  }
+}
@@ -0,0 +1,48 @@
+use crate::models::radarr_models::Movie;
+use crate::network::radarr_network::RadarrEvent;
+use crate::network::{Network, RequestMethod};
+use anyhow::Result;
+use chrono::NaiveDate;
+use log::info;
+
+#[cfg(test)]
+#[path = "radarr_calendar_network_tests.rs"]
+mod radarr_calendar_network_tests;
+
+impl Network<'_, '_> {
+  pub(in crate::network::radarr_network) async fn get_radarr_calendar(
+    &mut self,
+    start: NaiveDate,
+    end: NaiveDate,
+  ) -> Result<Vec<Movie>> {
+    info!("Fetching Radarr calendar from {start} to {end}");
+    let event = RadarrEvent::GetCalendar(start, end);
+
+    let request_props = self
+      .request_props_from(
+        event,
+        RequestMethod::Get,
+        None::<()>,
+        None,
+        Some(format!("start={start}&end={end}&unmonitored=true")),
+      )
+      .await;
+
+    self
+      .handle_request::<(), Vec<Movie>>(request_props, |mut movies, mut app| {
+        movies.sort_by_key(|movie| {
+          [
+            movie.in_cinemas,
+            movie.digital_release,
+            movie.physical_release,
+          ]
+          .into_iter()
+          .flatten()
+          .min()
+        });
+        app.data.radarr_data.calendar_start = Some(start);
+        app.data.radarr_data.calendar.set_items(movies);
+      })
+      .await
+  }
+}
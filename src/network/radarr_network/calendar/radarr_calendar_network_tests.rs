@@ -0,0 +1,54 @@
+#[cfg(test)]
+mod tests {
+  use crate::models::radarr_models::{Movie, RadarrSerdeable};
+  use crate::network::network_tests::test_utils::{MockServarrApi, test_network};
+  use crate::network::radarr_network::RadarrEvent;
+  use crate::network::radarr_network::radarr_network_test_utils::test_utils::movie;
+  use chrono::{DateTime, NaiveDate};
+  use pretty_assertions::assert_eq;
+  use serde_json::json;
+
+  #[tokio::test]
+  async fn test_handle_get_radarr_calendar_event() {
+    let start = NaiveDate::from_ymd_opt(2024, 2, 5).unwrap();
+    let end = NaiveDate::from_ymd_opt(2024, 3, 4).unwrap();
+    let earlier_movie = Movie {
+      in_cinemas: Some(DateTime::from(
+        DateTime::parse_from_rfc3339("2024-02-06T00:00:00Z").unwrap(),
+      )),
+      ..movie()
+    };
+    let later_movie = Movie {
+      id: 2,
+      digital_release: Some(DateTime::from(
+        DateTime::parse_from_rfc3339("2024-02-20T00:00:00Z").unwrap(),
+      )),
+      physical_release: Some(DateTime::from(
+        DateTime::parse_from_rfc3339("2024-02-13T00:00:00Z").unwrap(),
+      )),
+      ..movie()
+    };
+    let (mock, app, _server) = MockServarrApi::get()
+      .query("start=2024-02-05&end=2024-03-04&unmonitored=true")
+      .returns(json!([later_movie, earlier_movie]))
+      .build_for(RadarrEvent::GetCalendar(start, end))
+      .await;
+    let mut network = test_network(&app);
+
+    let RadarrSerdeable::Movies(movies) = network
+      .handle_radarr_event(RadarrEvent::GetCalendar(start, end))
+      .await
+      .unwrap()
+    else {
+      panic!("Expected Movies")
+    };
+
+    mock.assert_async().await;
+    assert_eq!(
+      app.lock().await.data.radarr_data.calendar.items,
+      vec![earlier_movie.clone(), later_movie.clone()]
+    );
+    assert_some_eq_x!(app.lock().await.data.radarr_data.calendar_start, start);
+    assert_eq!(movies, vec![later_movie, earlier_movie]);
+  }
+}
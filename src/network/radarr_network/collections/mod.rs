@@ -33,17 +33,12 @@ impl Network<'_, '_> {
       )
       .await;
 
-    let mut response = String::new();
-
-    self
-      .handle_request::<(), Value>(request_props, |detailed_collection_body, _| {
-        response = detailed_collection_body.to_string()
-      })
+    let mut detailed_collection_body = self
+      .handle_request::<(), Value>(request_props, |_, _| ())
       .await?;
 
     info!("Constructing edit collection body");
 
-    let mut detailed_collection_body: Value = serde_json::from_str(&response)?;
     let (monitored, minimum_availability, quality_profile_id, root_folder_path, search_on_add) = {
       let monitored = edit_collection_params.monitored.unwrap_or_else(|| {
         detailed_collection_body["monitored"]
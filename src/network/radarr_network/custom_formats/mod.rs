@@ -0,0 +1,144 @@
+use crate::models::servarr_models::{CustomFormat, EditCustomFormatParams};
+use crate::network::radarr_network::RadarrEvent;
+use crate::network::utils::{apply_custom_format_edits, custom_format_body_from_json};
+use crate::network::{Network, RequestMethod};
+use anyhow::Result;
+use log::{debug, info};
+use serde_json::Value;
+
+#[cfg(test)]
+#[path = "radarr_custom_formats_network_tests.rs"]
+mod radarr_custom_formats_network_tests;
+
+impl Network<'_, '_> {
+  pub(in crate::network::radarr_network) async fn get_radarr_custom_formats(
+    &mut self,
+  ) -> Result<Vec<CustomFormat>> {
+    info!("Fetching Radarr custom formats");
+    let event = RadarrEvent::GetCustomFormats;
+
+    let request_props = self
+      .request_props_from(event, RequestMethod::Get, None::<()>, None, None)
+      .await;
+
+    self
+      .handle_request::<(), Vec<CustomFormat>>(request_props, |custom_formats, mut app| {
+        app
+          .data
+          .radarr_data
+          .custom_formats
+          .set_items(custom_formats);
+      })
+      .await
+  }
+
+  pub(in crate::network::radarr_network) async fn delete_radarr_custom_format(
+    &mut self,
+    custom_format_id: i64,
+  ) -> Result<()> {
+    let event = RadarrEvent::DeleteCustomFormat(custom_format_id);
+    info!("Deleting Radarr custom format with id: {custom_format_id}");
+
+    let request_props = self
+      .request_props_from(
+        event,
+        RequestMethod::Delete,
+        None::<()>,
+        Some(format!("/{custom_format_id}")),
+        None,
+      )
+      .await;
+
+    self
+      .handle_request::<(), ()>(request_props, |_, _| ())
+      .await
+  }
+
+  pub(in crate::network::radarr_network) async fn edit_radarr_custom_format(
+    &mut self,
+    edit_custom_format_params: EditCustomFormatParams,
+  ) -> Result<()> {
+    let event = RadarrEvent::EditCustomFormat(EditCustomFormatParams::default());
+    let id = edit_custom_format_params.custom_format_id;
+    info!("Updating Radarr custom format with ID: {id}");
+
+    info!("Fetching custom format details for custom format with ID: {id}");
+
+    let request_props = self
+      .request_props_from(
+        event.clone(),
+        RequestMethod::Get,
+        None::<()>,
+        Some(format!("/{id}")),
+        None,
+      )
+      .await;
+
+    let mut detailed_custom_format_body = self
+      .handle_request::<(), Value>(request_props, |_, _| ())
+      .await?;
+
+    info!("Constructing edit custom format body");
+
+    apply_custom_format_edits(&mut detailed_custom_format_body, edit_custom_format_params)?;
+
+    debug!("Edit custom format body: {detailed_custom_format_body:?}");
+
+    let request_props = self
+      .request_props_from(
+        event,
+        RequestMethod::Put,
+        Some(detailed_custom_format_body),
+        Some(format!("/{id}")),
+        None,
+      )
+      .await;
+
+    self
+      .handle_request::<Value, ()>(request_props, |_, _| ())
+      .await
+  }
+
+  pub(in crate::network::radarr_network) async fn import_radarr_custom_format(
+    &mut self,
+    custom_format_json: String,
+  ) -> Result<Value> {
+    let event = RadarrEvent::ImportCustomFormat(String::new());
+    info!("Importing Radarr custom format");
+
+    let body = custom_format_body_from_json(&custom_format_json)?;
+
+    debug!("Import custom format body: {body:?}");
+
+    let request_props = self
+      .request_props_from(event, RequestMethod::Post, Some(body), None, None)
+      .await;
+
+    self
+      .handle_request::<Value, Value>(request_props, |_, _| ())
+      .await
+  }
+
+  pub(in crate::network::radarr_network) async fn export_radarr_custom_format(
+    &mut self,
+    custom_format_id: i64,
+  ) -> Result<Value> {
+    let event = RadarrEvent::ExportCustomFormat(custom_format_id);
+    info!("Exporting Radarr custom format with ID: {custom_format_id}");
+
+    let request_props = self
+      .request_props_from(
+        event,
+        RequestMethod::Get,
+        None::<()>,
+        Some(format!("/{custom_format_id}")),
+        None,
+      )
+      .await;
+
+    self
+      .handle_request::<(), CustomFormat>(request_props, |_, _| ())
+      .await
+      .map(|custom_format| custom_format.to_trash_json())
+  }
+}
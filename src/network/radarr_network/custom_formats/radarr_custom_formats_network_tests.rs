@@ -0,0 +1,250 @@
+#[cfg(test)]
+mod tests {
+  use crate::models::radarr_models::RadarrSerdeable;
+  use crate::models::servarr_models::EditCustomFormatParams;
+  use crate::network::NetworkResource;
+  use crate::network::network_tests::test_utils::{MockServarrApi, test_network};
+  use crate::network::radarr_network::RadarrEvent;
+  use crate::network::servarr_test_utils::custom_format;
+  use mockito::Matcher;
+  use pretty_assertions::assert_eq;
+  use serde_json::json;
+
+  fn custom_format_json() -> serde_json::Value {
+    json!({
+        "id": 1,
+        "name": "x265",
+        "includeCustomFormatWhenRenaming": false,
+        "specifications": [
+            {
+                "name": "x265",
+                "implementation": "ReleaseTitleSpecification",
+                "implementationName": "Release Title",
+                "negate": false,
+                "required": true,
+                "fields": [
+                    {
+                        "order": 0,
+                        "name": "value",
+                        "label": "Regular Expression",
+                        "value": "\\bx265\\b",
+                        "type": "textbox",
+                        "advanced": false
+                    }
+                ]
+            }
+        ]
+    })
+  }
+
+  #[tokio::test]
+  async fn test_handle_get_radarr_custom_formats_event() {
+    let (async_server, app, _server) = MockServarrApi::get()
+      .returns(json!([custom_format_json()]))
+      .build_for(RadarrEvent::GetCustomFormats)
+      .await;
+    let mut network = test_network(&app);
+
+    let RadarrSerdeable::CustomFormats(custom_formats) = network
+      .handle_radarr_event(RadarrEvent::GetCustomFormats)
+      .await
+      .unwrap()
+    else {
+      panic!("Expected CustomFormats")
+    };
+    async_server.assert_async().await;
+    assert_eq!(custom_formats, vec![custom_format()]);
+    assert_eq!(
+      app.lock().await.data.radarr_data.custom_formats.items,
+      vec![custom_format()]
+    );
+  }
+
+  #[tokio::test]
+  async fn test_handle_delete_radarr_custom_format_event() {
+    let (async_server, app, _server) = MockServarrApi::delete()
+      .path("/1")
+      .build_for(RadarrEvent::DeleteCustomFormat(1))
+      .await;
+    let mut network = test_network(&app);
+
+    assert!(
+      network
+        .handle_radarr_event(RadarrEvent::DeleteCustomFormat(1))
+        .await
+        .is_ok()
+    );
+
+    async_server.assert_async().await;
+  }
+
+  #[tokio::test]
+  async fn test_handle_edit_radarr_custom_format_event() {
+    let mut expected_edit_custom_format_body = custom_format_json();
+    expected_edit_custom_format_body["name"] = json!("HEVC");
+    expected_edit_custom_format_body["includeCustomFormatWhenRenaming"] = json!(true);
+    expected_edit_custom_format_body["specifications"][0]["negate"] = json!(true);
+    expected_edit_custom_format_body["specifications"][0]["fields"][0]["value"] = json!("HEVC");
+    let edit_custom_format_params = EditCustomFormatParams {
+      custom_format_id: 1,
+      name: Some("HEVC".to_owned()),
+      include_custom_format_when_renaming: Some(true),
+      fields: vec![
+        ("x265.negate".to_owned(), "true".to_owned()),
+        ("x265.value".to_owned(), "HEVC".to_owned()),
+      ],
+    };
+
+    let (async_details_server, app, mut server) = MockServarrApi::get()
+      .returns(custom_format_json())
+      .path("/1")
+      .build_for(RadarrEvent::EditCustomFormat(
+        edit_custom_format_params.clone(),
+      ))
+      .await;
+    let async_edit_server = server
+      .mock(
+        "PUT",
+        format!(
+          "/api/v3{}/1",
+          RadarrEvent::EditCustomFormat(edit_custom_format_params.clone()).resource()
+        )
+        .as_str(),
+      )
+      .with_status(202)
+      .match_header("X-Api-Key", "test1234")
+      .match_body(Matcher::Json(expected_edit_custom_format_body))
+      .create_async()
+      .await;
+    let mut network = test_network(&app);
+
+    assert!(
+      network
+        .handle_radarr_event(RadarrEvent::EditCustomFormat(edit_custom_format_params))
+        .await
+        .is_ok()
+    );
+
+    async_details_server.assert_async().await;
+    async_edit_server.assert_async().await;
+  }
+
+  #[tokio::test]
+  async fn test_handle_edit_radarr_custom_format_event_unknown_specification() {
+    let edit_custom_format_params = EditCustomFormatParams {
+      custom_format_id: 1,
+      fields: vec![("x264.value".to_owned(), "x264".to_owned())],
+      ..EditCustomFormatParams::default()
+    };
+    let (async_details_server, app, mut server) = MockServarrApi::get()
+      .returns(custom_format_json())
+      .path("/1")
+      .build_for(RadarrEvent::EditCustomFormat(
+        edit_custom_format_params.clone(),
+      ))
+      .await;
+    let async_edit_server = server
+      .mock(
+        "PUT",
+        format!(
+          "/api/v3{}/1",
+          RadarrEvent::EditCustomFormat(edit_custom_format_params.clone()).resource()
+        )
+        .as_str(),
+      )
+      .expect(0)
+      .create_async()
+      .await;
+    let mut network = test_network(&app);
+
+    let result = network
+      .handle_radarr_event(RadarrEvent::EditCustomFormat(edit_custom_format_params))
+      .await;
+
+    async_details_server.assert_async().await;
+    async_edit_server.assert_async().await;
+    assert_eq!(
+      result.unwrap_err().to_string(),
+      "Specification 'x264' does not exist in the custom format"
+    );
+  }
+
+  #[tokio::test]
+  async fn test_handle_import_radarr_custom_format_event() {
+    let trash_json = json!({
+      "trash_id": "c9eafd50846d299b862ca9bb6ea91950",
+      "name": "x265",
+      "includeCustomFormatWhenRenaming": false,
+      "specifications": [{
+        "name": "x265",
+        "implementation": "ReleaseTitleSpecification",
+        "negate": false,
+        "required": true,
+        "fields": { "value": "\\bx265\\b" }
+      }]
+    });
+    let expected_body = json!({
+      "name": "x265",
+      "includeCustomFormatWhenRenaming": false,
+      "specifications": [{
+        "name": "x265",
+        "implementation": "ReleaseTitleSpecification",
+        "negate": false,
+        "required": true,
+        "fields": [{ "name": "value", "value": "\\bx265\\b" }]
+      }]
+    });
+    let (async_server, app, _server) = MockServarrApi::post()
+      .with_request_body(expected_body)
+      .returns(custom_format_json())
+      .build_for(RadarrEvent::ImportCustomFormat(trash_json.to_string()))
+      .await;
+    let mut network = test_network(&app);
+
+    assert!(
+      network
+        .handle_radarr_event(RadarrEvent::ImportCustomFormat(trash_json.to_string()))
+        .await
+        .is_ok()
+    );
+
+    async_server.assert_async().await;
+  }
+
+  #[tokio::test]
+  async fn test_handle_import_radarr_custom_format_event_invalid_json() {
+    let (_async_server, app, _server) = MockServarrApi::post()
+      .build_for(RadarrEvent::ImportCustomFormat(String::new()))
+      .await;
+    let mut network = test_network(&app);
+
+    let result = network
+      .handle_radarr_event(RadarrEvent::ImportCustomFormat("{".to_owned()))
+      .await;
+
+    assert_eq!(
+      result.unwrap_err().to_string(),
+      "The custom format is not valid JSON"
+    );
+  }
+
+  #[tokio::test]
+  async fn test_handle_export_radarr_custom_format_event() {
+    let (async_server, app, _server) = MockServarrApi::get()
+      .returns(custom_format_json())
+      .path("/1")
+      .build_for(RadarrEvent::ExportCustomFormat(1))
+      .await;
+    let mut network = test_network(&app);
+
+    let RadarrSerdeable::Value(value) = network
+      .handle_radarr_event(RadarrEvent::ExportCustomFormat(1))
+      .await
+      .unwrap()
+    else {
+      panic!("Expected Value")
+    };
+    async_server.assert_async().await;
+    assert_eq!(value, custom_format().to_trash_json());
+  }
+}
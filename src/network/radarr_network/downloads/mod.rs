@@ -1,30 +1,63 @@
-use crate::models::radarr_models::DownloadsResponse;
-use crate::models::servarr_models::CommandBody;
+use crate::models::radarr_models::{
+  DownloadsResponse, RadarrManualImportFile, RadarrManualImportItem,
+};
+use crate::models::servarr_models::{BulkDeleteDownloadsParams, CommandBody, DeleteDownloadParams};
 use crate::network::radarr_network::RadarrEvent;
 use crate::network::{Network, RequestMethod};
 use anyhow::Result;
 use log::info;
-use serde_json::Value;
+use serde_json::{Value, json};
+use urlencoding::encode;
 
 #[cfg(test)]
 #[path = "radarr_downloads_network_tests.rs"]
 mod radarr_downloads_network_tests;
 
 impl Network<'_, '_> {
+  pub(in crate::network::radarr_network) async fn bulk_delete_radarr_downloads(
+    &mut self,
+    params: BulkDeleteDownloadsParams,
+  ) -> Result<()> {
+    let event = RadarrEvent::BulkDeleteDownloads(BulkDeleteDownloadsParams::default());
+    let BulkDeleteDownloadsParams {
+      ids,
+      removal_options,
+    } = params;
+    info!("Deleting Radarr downloads with IDs: {ids:?} with options: {removal_options:?}");
+
+    let request_props = self
+      .request_props_from(
+        event,
+        RequestMethod::Delete,
+        Some(json!({ "ids": ids })),
+        None,
+        Some(removal_options.query_params()),
+      )
+      .await;
+
+    self
+      .handle_request::<Value, ()>(request_props, |_, _| ())
+      .await
+  }
+
   pub(in crate::network::radarr_network) async fn delete_radarr_download(
     &mut self,
-    download_id: i64,
+    params: DeleteDownloadParams,
   ) -> Result<()> {
-    let event = RadarrEvent::DeleteDownload(download_id);
-    info!("Deleting Radarr download for download with id: {download_id}");
+    let event = RadarrEvent::DeleteDownload(DeleteDownloadParams::default());
+    let DeleteDownloadParams {
+      id,
+      removal_options,
+    } = params;
+    info!("Deleting Radarr download for download with id: {id} with options: {removal_options:?}");
 
     let request_props = self
       .request_props_from(
         event,
         RequestMethod::Delete,
         None::<()>,
-        Some(format!("/{download_id}")),
-        None,
+        Some(format!("/{id}")),
+        Some(removal_options.query_params()),
       )
       .await;
 
@@ -46,7 +79,7 @@ impl Network<'_, '_> {
         RequestMethod::Get,
         None::<()>,
         None,
-        Some(format!("pageSize={count}")),
+        Some(format!("pageSize={count}&includeUnknownMovieItems=true")),
       )
       .await;
 
@@ -61,6 +94,77 @@ impl Network<'_, '_> {
       .await
   }
 
+  pub(in crate::network::radarr_network) async fn get_radarr_manual_import(
+    &mut self,
+    download_id: String,
+    movie_id: Option<i64>,
+  ) -> Result<Vec<RadarrManualImportItem>> {
+    info!("Fetching Radarr manual import candidates for download with id: {download_id}");
+    let event = RadarrEvent::GetManualImport(download_id.clone(), movie_id);
+    let mut params = format!(
+      "downloadId={}&filterExistingFiles=true",
+      encode(&download_id)
+    );
+    if let Some(movie_id) = movie_id {
+      params.push_str(&format!("&movieId={movie_id}"));
+    }
+
+    let request_props = self
+      .request_props_from(event, RequestMethod::Get, None::<()>, None, Some(params))
+      .await;
+
+    self
+      .handle_request::<(), Vec<RadarrManualImportItem>>(request_props, |items, mut app| {
+        if let Some(manual_import_modal) = app.data.radarr_data.manual_import_modal.as_mut() {
+          manual_import_modal.items.set_items(items);
+        }
+      })
+      .await
+  }
+
+  pub(in crate::network::radarr_network) async fn grab_radarr_queue_item(
+    &mut self,
+    queue_item_id: i64,
+  ) -> Result<Value> {
+    info!("Grabbing the pending Radarr queue item with ID: {queue_item_id}");
+    let event = RadarrEvent::GrabQueueItem(queue_item_id);
+
+    let request_props = self
+      .request_props_from(
+        event,
+        RequestMethod::Post,
+        None::<()>,
+        Some(format!("/{queue_item_id}")),
+        None,
+      )
+      .await;
+
+    self
+      .handle_request::<(), Value>(request_props, |_, _| ())
+      .await
+  }
+
+  pub(in crate::network::radarr_network) async fn manual_import_radarr_files(
+    &mut self,
+    files: Vec<RadarrManualImportFile>,
+  ) -> Result<Value> {
+    info!("Manually importing {} file(s) into Radarr", files.len());
+    let event = RadarrEvent::ManualImport(Vec::new());
+    let body = json!({
+      "name": "ManualImport",
+      "importMode": "auto",
+      "files": files
+    });
+
+    let request_props = self
+      .request_props_from(event, RequestMethod::Post, Some(body), None, None)
+      .await;
+
+    self
+      .handle_request::<Value, Value>(request_props, |_, _| ())
+      .await
+  }
+
   pub(in crate::network::radarr_network) async fn update_radarr_downloads(
     &mut self,
   ) -> Result<Value> {
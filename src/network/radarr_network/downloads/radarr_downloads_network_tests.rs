@@ -1,22 +1,63 @@
 #[cfg(test)]
 mod tests {
   use crate::models::radarr_models::{DownloadsResponse, RadarrSerdeable};
+  use crate::models::servarr_data::radarr::modals::ManualImportModal;
+  use crate::models::servarr_models::{
+    BulkDeleteDownloadsParams, DeleteDownloadParams, QueueRemovalOptions,
+  };
   use crate::network::network_tests::test_utils::{MockServarrApi, test_network};
   use crate::network::radarr_network::RadarrEvent;
-  use crate::network::radarr_network::radarr_network_test_utils::test_utils::downloads_response;
-  use serde_json::json;
+  use crate::network::radarr_network::radarr_network_test_utils::test_utils::{
+    MOVIE_JSON, downloads_response, manual_import_file, manual_import_item,
+  };
+  use pretty_assertions::assert_eq;
+  use rstest::rstest;
+  use serde_json::{Value, json};
+
+  #[tokio::test]
+  async fn test_handle_bulk_delete_radarr_downloads_event() {
+    let params = BulkDeleteDownloadsParams {
+      ids: vec![1, 2],
+      removal_options: QueueRemovalOptions::default(),
+    };
+    let (mock, app, _server) = MockServarrApi::delete()
+      .with_request_body(json!({ "ids": [1, 2] }))
+      .query("removeFromClient=true&blocklist=false&skipRedownload=false&changeCategory=false")
+      .build_for(RadarrEvent::BulkDeleteDownloads(params.clone()))
+      .await;
+    let mut network = test_network(&app);
+
+    assert!(
+      network
+        .handle_radarr_event(RadarrEvent::BulkDeleteDownloads(params))
+        .await
+        .is_ok()
+    );
+
+    mock.assert_async().await;
+  }
 
   #[tokio::test]
   async fn test_handle_delete_radarr_download_event() {
+    let params = DeleteDownloadParams {
+      id: 1,
+      removal_options: QueueRemovalOptions {
+        remove_from_client: false,
+        blocklist: true,
+        skip_redownload: true,
+        change_category: false,
+      },
+    };
     let (mock, app, _server) = MockServarrApi::delete()
       .path("/1")
-      .build_for(RadarrEvent::DeleteDownload(1))
+      .query("removeFromClient=false&blocklist=true&skipRedownload=true&changeCategory=false")
+      .build_for(RadarrEvent::DeleteDownload(params.clone()))
       .await;
     let mut network = test_network(&app);
 
     assert!(
       network
-        .handle_radarr_event(RadarrEvent::DeleteDownload(1))
+        .handle_radarr_event(RadarrEvent::DeleteDownload(params))
         .await
         .is_ok()
     );
@@ -37,13 +78,14 @@ mod tests {
         "outputPath": "/nfs/movies/Test",
         "indexer": "kickass torrents",
         "downloadClient": "transmission",
+        "downloadId": "abc123",
       }]
     });
     let response: DownloadsResponse =
       serde_json::from_value(downloads_response_json.clone()).unwrap();
     let (mock, app, _server) = MockServarrApi::get()
       .returns(downloads_response_json)
-      .path("?pageSize=500")
+      .path("?pageSize=500&includeUnknownMovieItems=true")
       .build_for(RadarrEvent::GetDownloads(500))
       .await;
     let mut network = test_network(&app);
@@ -63,6 +105,106 @@ mod tests {
     pretty_assertions::assert_eq!(downloads, response);
   }
 
+  #[rstest]
+  #[case(None, "downloadId=abc123&filterExistingFiles=true")]
+  #[case(Some(1), "downloadId=abc123&filterExistingFiles=true&movieId=1")]
+  #[tokio::test]
+  async fn test_handle_get_radarr_manual_import_event(
+    #[case] movie_id: Option<i64>,
+    #[case] expected_query: &str,
+  ) {
+    let manual_import_json = json!([{
+      "id": 1,
+      "path": "/nfs/downloads/Test/Test.mkv",
+      "relativePath": "Test/Test.mkv",
+      "size": 3543348019u64,
+      "movie": serde_json::from_str::<Value>(MOVIE_JSON).unwrap(),
+      "quality": { "quality": { "id": 7, "name": "Bluray-1080p" } },
+      "languages": [{ "id": 1, "name": "English" }],
+      "releaseGroup": "SPARKS",
+      "downloadId": "abc123",
+      "rejections": []
+    }]);
+    let (mock, app, _server) = MockServarrApi::get()
+      .returns(manual_import_json)
+      .query(expected_query)
+      .build_for(RadarrEvent::GetManualImport("abc123".to_owned(), movie_id))
+      .await;
+    app.lock().await.data.radarr_data.manual_import_modal = Some(ManualImportModal::default());
+    let mut network = test_network(&app);
+
+    let result = network
+      .handle_radarr_event(RadarrEvent::GetManualImport("abc123".to_owned(), movie_id))
+      .await;
+
+    mock.assert_async().await;
+
+    let RadarrSerdeable::ManualImportItems(items) = result.unwrap() else {
+      panic!("Expected ManualImportItems")
+    };
+    assert_eq!(
+      app
+        .lock()
+        .await
+        .data
+        .radarr_data
+        .manual_import_modal
+        .as_ref()
+        .unwrap()
+        .items
+        .items,
+      vec![manual_import_item()]
+    );
+    assert_eq!(items, vec![manual_import_item()]);
+  }
+
+  #[tokio::test]
+  async fn test_handle_grab_radarr_queue_item_event() {
+    let (mock, app, _server) = MockServarrApi::post()
+      .returns(json!({}))
+      .path("/1")
+      .build_for(RadarrEvent::GrabQueueItem(1))
+      .await;
+    let mut network = test_network(&app);
+
+    let result = network
+      .handle_radarr_event(RadarrEvent::GrabQueueItem(1))
+      .await;
+
+    mock.assert_async().await;
+    assert_ok!(result);
+  }
+
+  #[tokio::test]
+  async fn test_handle_manual_import_radarr_files_event() {
+    let (mock, app, _server) = MockServarrApi::post()
+      .with_request_body(json!({
+        "name": "ManualImport",
+        "importMode": "auto",
+        "files": [{
+          "path": "/nfs/downloads/Test/Test.mkv",
+          "movieId": 1,
+          "quality": { "quality": { "id": 7, "name": "Bluray-1080p" } },
+          "languages": [{ "id": 1, "name": "English" }],
+          "releaseGroup": "SPARKS",
+          "downloadId": "abc123"
+        }]
+      }))
+      .returns(json!({}))
+      .build_for(RadarrEvent::ManualImport(vec![manual_import_file()]))
+      .await;
+    let mut network = test_network(&app);
+
+    assert!(
+      network
+        .handle_radarr_event(RadarrEvent::ManualImport(vec![manual_import_file()]))
+        .await
+        .is_ok()
+    );
+
+    mock.assert_async().await;
+  }
+
   #[tokio::test]
   async fn test_handle_update_radarr_downloads_event() {
     let (mock, app, _server) = MockServarrApi::post()
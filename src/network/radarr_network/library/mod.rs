@@ -1,7 +1,7 @@
 use crate::models::radarr_models::{
-  AddMovieBody, AddMovieSearchResult, Credit, CreditType, DeleteMovieParams, DownloadRecord,
-  EditMovieParams, Movie, MovieCommandBody, MovieHistoryItem, RadarrRelease,
-  RadarrReleaseDownloadBody,
+  AddMovieBody, AddMovieSearchResult, BulkDeleteMoviesParams, BulkEditMoviesParams, Credit,
+  CreditType, DeleteMovieParams, DownloadRecord, EditMovieParams, Movie, MovieCommandBody,
+  MovieHistoryItem, RadarrRelease, RadarrReleaseDownloadBody,
 };
 use crate::models::servarr_data::Notification;
 use crate::models::servarr_data::radarr::modals::MovieDetailsModal;
@@ -13,7 +13,7 @@ use crate::network::{Network, RequestMethod};
 use crate::utils::{convert_runtime, convert_to_gb};
 use anyhow::Result;
 use indoc::formatdoc;
-use log::{debug, info, warn};
+use log::{debug, info};
 use serde_json::{Value, json};
 use urlencoding::encode;
 
@@ -44,6 +44,47 @@ impl Network<'_, '_> {
       .await
   }
 
+  pub(in crate::network::radarr_network) async fn bulk_delete_movies(
+    &mut self,
+    params: BulkDeleteMoviesParams,
+  ) -> Result<()> {
+    let event = RadarrEvent::BulkDeleteMovies(BulkDeleteMoviesParams::default());
+    info!(
+      "Deleting Radarr movies with IDs: {:?} with deleteFiles={} and addImportExclusion={}",
+      params.movie_ids, params.delete_files, params.add_import_exclusion
+    );
+
+    let request_props = self
+      .request_props_from(event, RequestMethod::Delete, Some(params), None, None)
+      .await;
+
+    self
+      .handle_request::<BulkDeleteMoviesParams, ()>(request_props, |_, _| ())
+      .await
+  }
+
+  pub(in crate::network::radarr_network) async fn bulk_edit_movies(
+    &mut self,
+    mut params: BulkEditMoviesParams,
+  ) -> Result<()> {
+    info!("Bulk editing Radarr movies");
+    let event = RadarrEvent::BulkEditMovies(BulkEditMoviesParams::default());
+    if let Some(tag_input_str) = params.tag_input_string.as_ref() {
+      let tag_ids_vec = self.extract_and_add_radarr_tag_ids_vec(tag_input_str).await;
+      params.tags = Some(tag_ids_vec);
+    }
+
+    debug!("Bulk edit movies body: {params:?}");
+
+    let request_props = self
+      .request_props_from(event, RequestMethod::Put, Some(params), None, None)
+      .await;
+
+    self
+      .handle_request::<BulkEditMoviesParams, ()>(request_props, |_, _| ())
+      .await
+  }
+
   pub(in crate::network::radarr_network) async fn delete_movie(
     &mut self,
     delete_movie_params: DeleteMovieParams,
@@ -134,17 +175,12 @@ impl Network<'_, '_> {
       )
       .await;
 
-    let mut response = String::new();
-
-    self
-      .handle_request::<(), Value>(request_props, |detailed_movie_body, _| {
-        response = detailed_movie_body.to_string()
-      })
+    let mut detailed_movie_body = self
+      .handle_request::<(), Value>(request_props, |_, _| ())
       .await?;
 
     info!("Constructing edit movie body");
 
-    let mut detailed_movie_body: Value = serde_json::from_str(&response)?;
     let (monitored, minimum_availability, quality_profile_id, root_folder_path, tags) = {
       let monitored = edit_movie_params.monitored.unwrap_or(
         detailed_movie_body["monitored"]
@@ -556,47 +592,35 @@ impl Network<'_, '_> {
       )
       .await;
 
-    let mut response = String::new();
-
-    self
-      .handle_request::<(), Value>(request_props, |detailed_movie_body, _| {
-        response = detailed_movie_body.to_string()
-      })
+    let mut detailed_movie_body = self
+      .handle_request::<(), Value>(request_props, |_, _| ())
       .await?;
 
     info!("Constructing toggle movie monitoring body");
 
-    match serde_json::from_str::<Value>(&response) {
-      Ok(mut detailed_movie_body) => {
-        let monitored = detailed_movie_body
-          .get("monitored")
-          .unwrap()
-          .as_bool()
-          .unwrap();
+    let monitored = detailed_movie_body
+      .get("monitored")
+      .unwrap()
+      .as_bool()
+      .unwrap();
 
-        *detailed_movie_body.get_mut("monitored").unwrap() = json!(!monitored);
+    *detailed_movie_body.get_mut("monitored").unwrap() = json!(!monitored);
 
-        debug!("Toggle movie monitoring body: {detailed_movie_body:?}");
+    debug!("Toggle movie monitoring body: {detailed_movie_body:?}");
 
-        let request_props = self
-          .request_props_from(
-            event,
-            RequestMethod::Put,
-            Some(detailed_movie_body),
-            Some(format!("/{movie_id}")),
-            None,
-          )
-          .await;
+    let request_props = self
+      .request_props_from(
+        event,
+        RequestMethod::Put,
+        Some(detailed_movie_body),
+        Some(format!("/{movie_id}")),
+        None,
+      )
+      .await;
 
-        self
-          .handle_request::<Value, ()>(request_props, |_, _| ())
-          .await
-      }
-      Err(_) => {
-        warn!("Request for detailed movie body was interrupted");
-        Ok(())
-      }
-    }
+    self
+      .handle_request::<Value, ()>(request_props, |_, _| ())
+      .await
   }
 
   pub(in crate::network) async fn trigger_automatic_movie_search(
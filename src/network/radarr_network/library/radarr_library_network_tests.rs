@@ -1,12 +1,14 @@
 #[cfg(test)]
 mod tests {
   use crate::models::radarr_models::{
-    AddMovieBody, AddMovieOptions, Credit, DeleteMovieParams, DownloadRecord, EditMovieParams,
-    MinimumAvailability, Movie, MovieHistoryItem, MovieMonitor, RadarrReleaseDownloadBody,
+    AddMovieBody, AddMovieOptions, BulkDeleteMoviesParams, BulkEditMoviesParams, Credit,
+    DeleteMovieParams, DownloadRecord, EditMovieParams, MinimumAvailability, Movie,
+    MovieHistoryItem, MovieMonitor, RadarrReleaseDownloadBody,
   };
   use crate::models::servarr_data::Notification;
   use crate::models::servarr_data::radarr::modals::MovieDetailsModal;
   use crate::models::servarr_data::radarr::radarr_data::ActiveRadarrBlock;
+  use crate::models::servarr_models::ApplyTags;
   use crate::models::stateful_table::SortOption;
   use crate::network::NetworkResource;
   use crate::network::network_tests::test_utils::{MockServarrApi, test_network};
@@ -122,6 +124,101 @@ mod tests {
     mock.assert_async().await;
   }
 
+  #[tokio::test]
+  async fn test_handle_bulk_delete_movies_event() {
+    let bulk_delete_movies_params = BulkDeleteMoviesParams {
+      movie_ids: vec![1, 2],
+      delete_files: true,
+      add_import_exclusion: true,
+    };
+    let (async_server, app_arc, _server) = MockServarrApi::delete()
+      .with_request_body(json!({
+        "movieIds": [1, 2],
+        "deleteFiles": true,
+        "addImportExclusion": true
+      }))
+      .build_for(RadarrEvent::BulkDeleteMovies(
+        bulk_delete_movies_params.clone(),
+      ))
+      .await;
+    let mut network = test_network(&app_arc);
+
+    assert!(
+      network
+        .handle_radarr_event(RadarrEvent::BulkDeleteMovies(bulk_delete_movies_params))
+        .await
+        .is_ok()
+    );
+
+    async_server.assert_async().await;
+  }
+
+  #[tokio::test]
+  async fn test_handle_bulk_edit_movies_event() {
+    let bulk_edit_movies_params = BulkEditMoviesParams {
+      movie_ids: vec![1, 2],
+      monitored: Some(false),
+      quality_profile_id: Some(2222),
+      root_folder_path: Some("/nfs2".to_owned()),
+      tag_input_string: Some("usenet, testing".to_owned()),
+      apply_tags: ApplyTags::Replace,
+      move_files: true,
+      ..BulkEditMoviesParams::default()
+    };
+    let (async_server, app_arc, _server) = MockServarrApi::put()
+      .with_request_body(json!({
+        "movieIds": [1, 2],
+        "monitored": false,
+        "qualityProfileId": 2222,
+        "rootFolderPath": "/nfs2",
+        "tags": [1, 2],
+        "applyTags": "replace",
+        "moveFiles": true
+      }))
+      .build_for(RadarrEvent::BulkEditMovies(bulk_edit_movies_params.clone()))
+      .await;
+    app_arc.lock().await.data.radarr_data.tags_map =
+      BiMap::from_iter([(1, "usenet".to_owned()), (2, "testing".to_owned())]);
+    let mut network = test_network(&app_arc);
+
+    assert!(
+      network
+        .handle_radarr_event(RadarrEvent::BulkEditMovies(bulk_edit_movies_params))
+        .await
+        .is_ok()
+    );
+
+    async_server.assert_async().await;
+  }
+
+  #[tokio::test]
+  async fn test_handle_bulk_edit_movies_event_omits_unchanged_fields() {
+    let bulk_edit_movies_params = BulkEditMoviesParams {
+      movie_ids: vec![1],
+      monitored: Some(true),
+      ..BulkEditMoviesParams::default()
+    };
+    let (async_server, app_arc, _server) = MockServarrApi::put()
+      .with_request_body(json!({
+        "movieIds": [1],
+        "monitored": true,
+        "applyTags": "add",
+        "moveFiles": false
+      }))
+      .build_for(RadarrEvent::BulkEditMovies(bulk_edit_movies_params.clone()))
+      .await;
+    let mut network = test_network(&app_arc);
+
+    assert!(
+      network
+        .handle_radarr_event(RadarrEvent::BulkEditMovies(bulk_edit_movies_params))
+        .await
+        .is_ok()
+    );
+
+    async_server.assert_async().await;
+  }
+
   #[tokio::test]
   async fn test_handle_delete_movie_event() {
     let delete_movie_params = DeleteMovieParams {
@@ -866,7 +963,21 @@ mod tests {
       "seeders": 2,
       "leechers": 1,
       "languages": [ { "id": 1, "name": "English" } ],
-      "quality": { "quality": { "name": "HD - 1080p" }}
+      "quality": { "quality": { "name": "HD - 1080p" }},
+      "customFormats": [{
+        "id": 1,
+        "name": "x265",
+        "includeCustomFormatWhenRenaming": false,
+        "specifications": [{
+          "name": "x265",
+          "implementation": "ReleaseTitleSpecification",
+          "implementationName": "Release Title",
+          "negate": false,
+          "required": true,
+          "fields": [{ "name": "value", "label": "Regular Expression", "value": "\\bx265\\b", "type": "textbox" }]
+        }]
+      }],
+      "customFormatScore": 10
     }]);
     let (async_server, app_arc, _server) = MockServarrApi::get()
       .returns(release_json)
@@ -915,7 +1026,21 @@ mod tests {
       "seeders": 2,
       "leechers": 1,
       "languages": [ { "id": 1, "name": "English" } ],
-      "quality": { "quality": { "name": "HD - 1080p" }}
+      "quality": { "quality": { "name": "HD - 1080p" }},
+      "customFormats": [{
+        "id": 1,
+        "name": "x265",
+        "includeCustomFormatWhenRenaming": false,
+        "specifications": [{
+          "name": "x265",
+          "implementation": "ReleaseTitleSpecification",
+          "implementationName": "Release Title",
+          "negate": false,
+          "required": true,
+          "fields": [{ "name": "value", "label": "Regular Expression", "value": "\\bx265\\b", "type": "textbox" }]
+        }]
+      }],
+      "customFormatScore": 10
     }]);
     let (async_server, app_arc, _server) = MockServarrApi::get()
       .returns(release_json)
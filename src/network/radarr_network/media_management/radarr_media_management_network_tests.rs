@@ -0,0 +1,201 @@
+#[cfg(test)]
+mod tests {
+  use crate::models::radarr_models::{NamingConfig, RadarrSerdeable};
+  use crate::models::servarr_data::modals::MediaManagementModal;
+  use crate::models::servarr_data::radarr::radarr_data::MEDIA_MANAGEMENT_NAMING_FIELDS;
+  use crate::network::NetworkResource;
+  use crate::network::network_tests::test_utils::{MockServarrApi, test_network};
+  use crate::network::radarr_network::RadarrEvent;
+  use crate::network::radarr_network::radarr_network_test_utils::test_utils::{
+    media_management_settings, naming_config, naming_examples,
+  };
+  use mockito::Matcher;
+  use pretty_assertions::assert_eq;
+  use serde_json::{Value, json};
+
+  const NAMING_EXAMPLES_QUERY: &str = "id=1&movieFolderFormat=%7BMovie%20Title%7D%20%28%7BRelease%20Year%7D%29&renameMovies=true&replaceIllegalCharacters=true&standardMovieFormat=%7BMovie%20Title%7D%20%28%7BRelease%20Year%7D%29%20%7BQuality%20Full%7D";
+
+  fn naming_config_json() -> Value {
+    json!({
+      "id": 1,
+      "renameMovies": true,
+      "replaceIllegalCharacters": true,
+      "colonReplacementFormat": "delete",
+      "standardMovieFormat": "{Movie Title} ({Release Year}) {Quality Full}",
+      "movieFolderFormat": "{Movie Title} ({Release Year})"
+    })
+  }
+
+  fn media_management_config_json() -> Value {
+    json!({
+      "id": 1,
+      "autoUnmonitorPreviouslyDownloadedMovies": false,
+      "recycleBin": "/nfs/recycle",
+      "recycleBinCleanupDays": 7,
+      "setPermissionsLinux": false,
+      "chmodFolder": "755",
+      "chownGroup": "",
+      "importExtraFiles": true,
+      "extraFileExtensions": "srt,nfo"
+    })
+  }
+
+  fn naming_examples_json() -> Value {
+    json!({
+      "movieExample": "The Movie Title (2010) Bluray-1080p Proper",
+      "movieFolderExample": "The Movie Title (2010)"
+    })
+  }
+
+  #[tokio::test]
+  async fn test_handle_get_radarr_media_management_settings_event() {
+    let (async_naming_server, app, mut server) = MockServarrApi::get()
+      .returns(naming_config_json())
+      .path("/naming")
+      .build_for(RadarrEvent::GetMediaManagementSettings)
+      .await;
+    let async_media_management_server = server
+      .mock(
+        "GET",
+        format!(
+          "/api/v3{}/mediamanagement",
+          RadarrEvent::GetMediaManagementSettings.resource()
+        )
+        .as_str(),
+      )
+      .with_status(200)
+      .match_header("X-Api-Key", "test1234")
+      .with_body(media_management_config_json().to_string())
+      .create_async()
+      .await;
+    let async_examples_server = server
+      .mock(
+        "GET",
+        format!(
+          "/api/v3{}?{NAMING_EXAMPLES_QUERY}",
+          RadarrEvent::GetNamingExamples(naming_config()).resource()
+        )
+        .as_str(),
+      )
+      .with_status(200)
+      .match_header("X-Api-Key", "test1234")
+      .with_body(naming_examples_json().to_string())
+      .create_async()
+      .await;
+    let mut network = test_network(&app);
+
+    let RadarrSerdeable::MediaManagementSettings(settings) = network
+      .handle_radarr_event(RadarrEvent::GetMediaManagementSettings)
+      .await
+      .unwrap()
+    else {
+      panic!("Expected MediaManagementSettings")
+    };
+    async_naming_server.assert_async().await;
+    async_media_management_server.assert_async().await;
+    async_examples_server.assert_async().await;
+    assert_eq!(settings, media_management_settings());
+    let app = app.lock().await;
+    assert_eq!(
+      app
+        .data
+        .radarr_data
+        .media_management_modal
+        .as_ref()
+        .unwrap()
+        .fields
+        .items,
+      MediaManagementModal::new(
+        &media_management_settings(),
+        &MEDIA_MANAGEMENT_NAMING_FIELDS
+      )
+      .fields
+      .items
+    );
+    assert_eq!(
+      app.data.radarr_data.naming_examples,
+      Some(naming_examples())
+    );
+  }
+
+  #[tokio::test]
+  async fn test_handle_get_radarr_naming_examples_event() {
+    let (async_server, app, _server) = MockServarrApi::get()
+      .returns(naming_examples_json())
+      .query(NAMING_EXAMPLES_QUERY)
+      .build_for(RadarrEvent::GetNamingExamples(naming_config()))
+      .await;
+    let mut network = test_network(&app);
+
+    let RadarrSerdeable::NamingExamples(examples) = network
+      .handle_radarr_event(RadarrEvent::GetNamingExamples(naming_config()))
+      .await
+      .unwrap()
+    else {
+      panic!("Expected NamingExamples")
+    };
+    async_server.assert_async().await;
+    assert_eq!(examples, naming_examples());
+    assert_eq!(
+      app.lock().await.data.radarr_data.naming_examples,
+      Some(naming_examples())
+    );
+  }
+
+  #[tokio::test]
+  async fn test_handle_edit_radarr_media_management_settings_event() {
+    let mut settings = media_management_settings();
+    settings.naming = NamingConfig {
+      rename_movies: false,
+      movie_folder_format: Some("{Movie Title}".to_owned()),
+      ..naming_config()
+    };
+    settings.media_management.recycle_bin_cleanup_days = 14;
+    let mut expected_naming_body = naming_config_json();
+    expected_naming_body["renameMovies"] = json!(false);
+    expected_naming_body["movieFolderFormat"] = json!("{Movie Title}");
+    let mut expected_media_management_body = media_management_config_json();
+    expected_media_management_body["recycleBinCleanupDays"] = json!(14);
+    let resource = RadarrEvent::EditMediaManagementSettings(settings.clone()).resource();
+
+    let (async_naming_details_server, app, mut server) = MockServarrApi::get()
+      .returns(naming_config_json())
+      .path("/naming")
+      .build_for(RadarrEvent::EditMediaManagementSettings(settings.clone()))
+      .await;
+    let async_naming_edit_server = server
+      .mock("PUT", format!("/api/v3{resource}/naming").as_str())
+      .with_status(202)
+      .match_header("X-Api-Key", "test1234")
+      .match_body(Matcher::Json(expected_naming_body))
+      .create_async()
+      .await;
+    let async_media_management_details_server = server
+      .mock("GET", format!("/api/v3{resource}/mediamanagement").as_str())
+      .with_status(200)
+      .match_header("X-Api-Key", "test1234")
+      .with_body(media_management_config_json().to_string())
+      .create_async()
+      .await;
+    let async_media_management_edit_server = server
+      .mock("PUT", format!("/api/v3{resource}/mediamanagement").as_str())
+      .with_status(202)
+      .match_header("X-Api-Key", "test1234")
+      .match_body(Matcher::Json(expected_media_management_body))
+      .create_async()
+      .await;
+    let mut network = test_network(&app);
+
+    assert!(
+      network
+        .handle_radarr_event(RadarrEvent::EditMediaManagementSettings(settings))
+        .await
+        .is_ok()
+    );
+
+    async_naming_details_server.assert_async().await;
+    async_naming_edit_server.assert_async().await;
+    async_media_management_details_server.assert_async().await;
+    async_media_management_edit_server.assert_async().await;
+  }
+}
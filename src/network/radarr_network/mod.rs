@@ -1,26 +1,39 @@
 use anyhow::Result;
+use chrono::NaiveDate;
 use std::fmt::Debug;
+use std::path::PathBuf;
 
 use log::info;
 use serde_json::{Value, json};
 
 use crate::models::radarr_models::{
-  AddMovieBody, DeleteMovieParams, EditCollectionParams, EditMovieParams, IndexerSettings,
+  AddMovieBody, BulkDeleteMoviesParams, BulkEditMoviesParams, DeleteMovieParams,
+  EditCollectionParams, EditMovieParams, IndexerSettings, NamingConfig, RadarrManualImportFile,
   RadarrReleaseDownloadBody, RadarrSerdeable, RadarrTaskName,
 };
-use crate::models::servarr_models::{AddRootFolderBody, EditIndexerParams, QualityProfile, Tag};
+use crate::models::servarr_models::{
+  AddDownloadClientParams, AddIndexerParams, AddRootFolderBody, BulkDeleteDownloadsParams,
+  DeleteDownloadParams, DownloadBackupParams, EditCustomFormatParams, EditDownloadClientParams,
+  EditIndexerParams, EditQualityProfileParams, MediaManagementSettings, QualityProfile, Tag,
+};
 use crate::network::{Network, NetworkEvent, RequestMethod};
 
 use super::NetworkResource;
 
 mod blocklist;
+mod calendar;
 mod collections;
+mod custom_formats;
+mod download_clients;
 mod downloads;
 mod history;
 mod indexers;
 mod library;
+mod media_management;
+mod quality_profiles;
 mod root_folders;
 mod system;
+mod wanted;
 
 #[cfg(test)]
 mod radarr_network_tests;
@@ -30,29 +43,49 @@ pub mod radarr_network_test_utils;
 
 #[derive(Debug, Eq, PartialEq, Clone)]
 pub enum RadarrEvent {
+  AddDownloadClient(AddDownloadClientParams),
+  AddIndexer(AddIndexerParams),
   AddMovie(AddMovieBody),
   AddRootFolder(AddRootFolderBody),
   AddTag(String),
+  BulkDeleteDownloads(BulkDeleteDownloadsParams),
+  BulkDeleteMovies(BulkDeleteMoviesParams),
+  BulkEditMovies(BulkEditMoviesParams),
   ClearBlocklist,
+  DeleteBackup(i64),
   DeleteBlocklistItem(i64),
-  DeleteDownload(i64),
+  DeleteDownload(DeleteDownloadParams),
   DeleteIndexer(i64),
   DeleteMovie(DeleteMovieParams),
   DeleteRootFolder(i64),
   DeleteTag(i64),
+  DownloadBackup(DownloadBackupParams),
   DownloadRelease(RadarrReleaseDownloadBody),
   EditAllIndexerSettings(IndexerSettings),
   EditCollection(EditCollectionParams),
   EditIndexer(EditIndexerParams),
+  EditMediaManagementSettings(MediaManagementSettings<NamingConfig>),
   EditMovie(EditMovieParams),
+  GetBackups,
   GetBlocklist,
+  GetCalendar(NaiveDate, NaiveDate),
   GetCollections,
+  GetCutoffUnmet(u64),
   GetDownloads(u64),
   GetHistory(u64),
   GetHostConfig,
   GetIndexers,
+  GetIndexerSchema,
+  GetDownloadClients,
+  GetDownloadClientSchema,
+  DeleteDownloadClient(i64),
+  EditDownloadClient(EditDownloadClientParams),
   GetAllIndexerSettings,
   GetLogs(u64),
+  GetManualImport(String, Option<i64>),
+  GetMediaManagementSettings,
+  GetNamingExamples(NamingConfig),
+  GrabQueueItem(i64),
   MarkHistoryItemAsFailed(i64),
   GetMovieCredits(i64),
   GetMovieDetails(i64),
@@ -60,6 +93,13 @@ pub enum RadarrEvent {
   GetMovies,
   GetDiskSpace,
   GetQualityProfiles,
+  GetQualityProfileDetails(i64),
+  EditQualityProfile(EditQualityProfileParams),
+  GetCustomFormats,
+  DeleteCustomFormat(i64),
+  EditCustomFormat(EditCustomFormatParams),
+  ImportCustomFormat(String),
+  ExportCustomFormat(i64),
   GetQueuedEvents,
   GetReleases(i64),
   GetRootFolders,
@@ -68,13 +108,20 @@ pub enum RadarrEvent {
   GetTags,
   GetTasks,
   GetUpdates,
+  GetWantedMissing(u64),
   HealthCheck,
+  ManualImport(Vec<RadarrManualImportFile>),
+  RestoreBackup(PathBuf),
   SearchNewMovie(String),
   StartTask(RadarrTaskName),
   TestIndexer(i64),
   TestAllIndexers,
+  TestDownloadClient(i64),
+  TestAllDownloadClients,
   ToggleMovieMonitoring(i64),
   TriggerAutomaticSearch(i64),
+  TriggerCutoffUnmetMoviesSearch,
+  TriggerMissingMoviesSearch,
   UpdateAllMovies,
   UpdateAndScan(i64),
   UpdateCollections,
@@ -84,32 +131,62 @@ pub enum RadarrEvent {
 impl NetworkResource for RadarrEvent {
   fn resource(&self) -> &'static str {
     match &self {
+      RadarrEvent::GetBackups | RadarrEvent::DeleteBackup(_) | RadarrEvent::DownloadBackup(_) => {
+        "/system/backup"
+      }
+      RadarrEvent::RestoreBackup(_) => "/system/backup/restore/upload",
       RadarrEvent::ClearBlocklist => "/blocklist/bulk",
       RadarrEvent::DeleteBlocklistItem(_) => "/blocklist",
       RadarrEvent::GetBlocklist => "/blocklist?page=1&pageSize=10000",
+      RadarrEvent::GetCalendar(_, _) => "/calendar",
       RadarrEvent::GetCollections | RadarrEvent::EditCollection(_) => "/collection",
+      RadarrEvent::GetCutoffUnmet(_) => "/wanted/cutoff",
       RadarrEvent::GetDownloads(_) | RadarrEvent::DeleteDownload(_) => "/queue",
+      RadarrEvent::BulkDeleteDownloads(_) => "/queue/bulk",
+      RadarrEvent::GrabQueueItem(_) => "/queue/grab",
       RadarrEvent::GetHistory(_) => "/history",
       RadarrEvent::GetHostConfig | RadarrEvent::GetSecurityConfig => "/config/host",
       RadarrEvent::MarkHistoryItemAsFailed(_) => "/history/failed",
-      RadarrEvent::GetIndexers | RadarrEvent::EditIndexer(_) | RadarrEvent::DeleteIndexer(_) => {
-        "/indexer"
-      }
+      RadarrEvent::AddIndexer(_)
+      | RadarrEvent::GetIndexers
+      | RadarrEvent::DeleteIndexer(_)
+      | RadarrEvent::EditIndexer(_) => "/indexer",
+      RadarrEvent::GetIndexerSchema => "/indexer/schema",
+      RadarrEvent::AddDownloadClient(_)
+      | RadarrEvent::GetDownloadClients
+      | RadarrEvent::DeleteDownloadClient(_)
+      | RadarrEvent::EditDownloadClient(_) => "/downloadclient",
+      RadarrEvent::GetDownloadClientSchema => "/downloadclient/schema",
+      RadarrEvent::TestDownloadClient(_) => "/downloadclient/test",
+      RadarrEvent::TestAllDownloadClients => "/downloadclient/testall",
       RadarrEvent::GetAllIndexerSettings | RadarrEvent::EditAllIndexerSettings(_) => {
         "/config/indexer"
       }
       RadarrEvent::GetLogs(_) => "/log",
+      RadarrEvent::GetMediaManagementSettings | RadarrEvent::EditMediaManagementSettings(_) => {
+        "/config"
+      }
+      RadarrEvent::GetNamingExamples(_) => "/config/naming/examples",
+      RadarrEvent::GetManualImport(_, _) => "/manualimport",
       RadarrEvent::AddMovie(_)
       | RadarrEvent::EditMovie(_)
       | RadarrEvent::GetMovies
       | RadarrEvent::GetMovieDetails(_)
       | RadarrEvent::DeleteMovie(_)
       | RadarrEvent::ToggleMovieMonitoring(_) => "/movie",
+      RadarrEvent::BulkDeleteMovies(_) | RadarrEvent::BulkEditMovies(_) => "/movie/editor",
       RadarrEvent::SearchNewMovie(_) => "/movie/lookup",
       RadarrEvent::GetMovieCredits(_) => "/credit",
       RadarrEvent::GetMovieHistory(_) => "/history/movie",
       RadarrEvent::GetDiskSpace => "/diskspace",
-      RadarrEvent::GetQualityProfiles => "/qualityprofile",
+      RadarrEvent::GetQualityProfiles
+      | RadarrEvent::GetQualityProfileDetails(_)
+      | RadarrEvent::EditQualityProfile(_) => "/qualityprofile",
+      RadarrEvent::GetCustomFormats
+      | RadarrEvent::DeleteCustomFormat(_)
+      | RadarrEvent::EditCustomFormat(_)
+      | RadarrEvent::ImportCustomFormat(_)
+      | RadarrEvent::ExportCustomFormat(_) => "/customformat",
       RadarrEvent::GetReleases(_) | RadarrEvent::DownloadRelease(_) => "/release",
       RadarrEvent::AddRootFolder(_)
       | RadarrEvent::GetRootFolders
@@ -118,11 +195,15 @@ impl NetworkResource for RadarrEvent {
       RadarrEvent::GetTags | RadarrEvent::AddTag(_) | RadarrEvent::DeleteTag(_) => "/tag",
       RadarrEvent::GetTasks => "/system/task",
       RadarrEvent::GetUpdates => "/update",
+      RadarrEvent::GetWantedMissing(_) => "/wanted/missing",
       RadarrEvent::TestIndexer(_) => "/indexer/test",
       RadarrEvent::TestAllIndexers => "/indexer/testall",
       RadarrEvent::StartTask(_)
       | RadarrEvent::GetQueuedEvents
+      | RadarrEvent::ManualImport(_)
       | RadarrEvent::TriggerAutomaticSearch(_)
+      | RadarrEvent::TriggerCutoffUnmetMoviesSearch
+      | RadarrEvent::TriggerMissingMoviesSearch
       | RadarrEvent::UpdateAndScan(_)
       | RadarrEvent::UpdateAllMovies
       | RadarrEvent::UpdateDownloads
@@ -130,6 +211,48 @@ impl NetworkResource for RadarrEvent {
       RadarrEvent::HealthCheck => "/health",
     }
   }
+
+  fn is_read_only(&self) -> bool {
+    matches!(
+      self,
+      RadarrEvent::GetBackups
+        | RadarrEvent::GetBlocklist
+        | RadarrEvent::GetCalendar(..)
+        | RadarrEvent::GetCollections
+        | RadarrEvent::GetCutoffUnmet(..)
+        | RadarrEvent::GetDownloads(..)
+        | RadarrEvent::GetHistory(..)
+        | RadarrEvent::GetHostConfig
+        | RadarrEvent::GetIndexers
+        | RadarrEvent::GetIndexerSchema
+        | RadarrEvent::GetDownloadClients
+        | RadarrEvent::GetDownloadClientSchema
+        | RadarrEvent::GetAllIndexerSettings
+        | RadarrEvent::GetLogs(..)
+        | RadarrEvent::GetManualImport(..)
+        | RadarrEvent::GetMediaManagementSettings
+        | RadarrEvent::GetNamingExamples(..)
+        | RadarrEvent::GetMovieCredits(..)
+        | RadarrEvent::GetMovieDetails(..)
+        | RadarrEvent::GetMovieHistory(..)
+        | RadarrEvent::GetMovies
+        | RadarrEvent::GetDiskSpace
+        | RadarrEvent::GetQualityProfiles
+        | RadarrEvent::GetQualityProfileDetails(..)
+        | RadarrEvent::GetCustomFormats
+        | RadarrEvent::GetQueuedEvents
+        | RadarrEvent::GetReleases(..)
+        | RadarrEvent::GetRootFolders
+        | RadarrEvent::GetSecurityConfig
+        | RadarrEvent::GetStatus
+        | RadarrEvent::GetTags
+        | RadarrEvent::GetTasks
+        | RadarrEvent::GetUpdates
+        | RadarrEvent::GetWantedMissing(..)
+        | RadarrEvent::HealthCheck
+        | RadarrEvent::SearchNewMovie(..)
+    )
+  }
 }
 
 impl From<RadarrEvent> for NetworkEvent {
@@ -149,17 +272,41 @@ impl Network<'_, '_> {
         .add_radarr_root_folder(path)
         .await
         .map(RadarrSerdeable::from),
+      RadarrEvent::AddDownloadClient(params) => self
+        .add_radarr_download_client(params)
+        .await
+        .map(RadarrSerdeable::from),
+      RadarrEvent::AddIndexer(params) => self
+        .add_radarr_indexer(params)
+        .await
+        .map(RadarrSerdeable::from),
       RadarrEvent::AddTag(tag) => self.add_radarr_tag(tag).await.map(RadarrSerdeable::from),
+      RadarrEvent::BulkDeleteDownloads(params) => self
+        .bulk_delete_radarr_downloads(params)
+        .await
+        .map(RadarrSerdeable::from),
+      RadarrEvent::BulkDeleteMovies(params) => self
+        .bulk_delete_movies(params)
+        .await
+        .map(RadarrSerdeable::from),
+      RadarrEvent::BulkEditMovies(params) => self
+        .bulk_edit_movies(params)
+        .await
+        .map(RadarrSerdeable::from),
       RadarrEvent::ClearBlocklist => self
         .clear_radarr_blocklist()
         .await
         .map(RadarrSerdeable::from),
+      RadarrEvent::DeleteBackup(backup_id) => self
+        .delete_radarr_backup(backup_id)
+        .await
+        .map(RadarrSerdeable::from),
       RadarrEvent::DeleteBlocklistItem(blocklist_item_id) => self
         .delete_radarr_blocklist_item(blocklist_item_id)
         .await
         .map(RadarrSerdeable::from),
-      RadarrEvent::DeleteDownload(download_id) => self
-        .delete_radarr_download(download_id)
+      RadarrEvent::DeleteDownload(params) => self
+        .delete_radarr_download(params)
         .await
         .map(RadarrSerdeable::from),
       RadarrEvent::DeleteIndexer(indexer_id) => self
@@ -177,6 +324,10 @@ impl Network<'_, '_> {
         .delete_radarr_tag(tag_id)
         .await
         .map(RadarrSerdeable::from),
+      RadarrEvent::DownloadBackup(params) => self
+        .download_radarr_backup(params)
+        .await
+        .map(RadarrSerdeable::from),
       RadarrEvent::DownloadRelease(params) => self
         .download_radarr_release(params)
         .await
@@ -193,17 +344,34 @@ impl Network<'_, '_> {
         .edit_radarr_indexer(params)
         .await
         .map(RadarrSerdeable::from),
+      RadarrEvent::EditMediaManagementSettings(params) => self
+        .edit_radarr_media_management_settings(params)
+        .await
+        .map(RadarrSerdeable::from),
       RadarrEvent::EditMovie(params) => self.edit_movie(params).await.map(RadarrSerdeable::from),
       RadarrEvent::GetAllIndexerSettings => self
         .get_all_radarr_indexer_settings()
         .await
         .map(RadarrSerdeable::from),
+      RadarrEvent::GetBackups => self.get_radarr_backups().await.map(RadarrSerdeable::from),
       RadarrEvent::GetBlocklist => self.get_radarr_blocklist().await.map(RadarrSerdeable::from),
+      RadarrEvent::GetCalendar(start, end) => self
+        .get_radarr_calendar(start, end)
+        .await
+        .map(RadarrSerdeable::from),
       RadarrEvent::GetCollections => self.get_collections().await.map(RadarrSerdeable::from),
+      RadarrEvent::GetCutoffUnmet(count) => self
+        .get_radarr_cutoff_unmet(count)
+        .await
+        .map(RadarrSerdeable::from),
       RadarrEvent::GetDownloads(count) => self
         .get_radarr_downloads(count)
         .await
         .map(RadarrSerdeable::from),
+      RadarrEvent::GrabQueueItem(queue_item_id) => self
+        .grab_radarr_queue_item(queue_item_id)
+        .await
+        .map(RadarrSerdeable::from),
       RadarrEvent::GetHistory(events) => self
         .get_radarr_history(events)
         .await
@@ -213,10 +381,42 @@ impl Network<'_, '_> {
         .await
         .map(RadarrSerdeable::from),
       RadarrEvent::GetIndexers => self.get_radarr_indexers().await.map(RadarrSerdeable::from),
+      RadarrEvent::GetIndexerSchema => self
+        .get_radarr_indexer_schema()
+        .await
+        .map(RadarrSerdeable::from),
+      RadarrEvent::GetDownloadClients => self
+        .get_radarr_download_clients()
+        .await
+        .map(RadarrSerdeable::from),
+      RadarrEvent::GetDownloadClientSchema => self
+        .get_radarr_download_client_schema()
+        .await
+        .map(RadarrSerdeable::from),
+      RadarrEvent::DeleteDownloadClient(download_client_id) => self
+        .delete_radarr_download_client(download_client_id)
+        .await
+        .map(RadarrSerdeable::from),
+      RadarrEvent::EditDownloadClient(params) => self
+        .edit_radarr_download_client(params)
+        .await
+        .map(RadarrSerdeable::from),
       RadarrEvent::GetLogs(events) => self
         .get_radarr_logs(events)
         .await
         .map(RadarrSerdeable::from),
+      RadarrEvent::GetManualImport(download_id, movie_id) => self
+        .get_radarr_manual_import(download_id, movie_id)
+        .await
+        .map(RadarrSerdeable::from),
+      RadarrEvent::GetMediaManagementSettings => self
+        .get_radarr_media_management_settings()
+        .await
+        .map(RadarrSerdeable::from),
+      RadarrEvent::GetNamingExamples(naming_config) => self
+        .get_radarr_naming_examples(naming_config)
+        .await
+        .map(RadarrSerdeable::from),
       RadarrEvent::MarkHistoryItemAsFailed(history_item_id) => self
         .mark_radarr_history_item_as_failed(history_item_id)
         .await
@@ -238,6 +438,34 @@ impl Network<'_, '_> {
         .get_radarr_quality_profiles()
         .await
         .map(RadarrSerdeable::from),
+      RadarrEvent::GetQualityProfileDetails(quality_profile_id) => self
+        .get_radarr_quality_profile_details(quality_profile_id)
+        .await
+        .map(RadarrSerdeable::from),
+      RadarrEvent::EditQualityProfile(params) => self
+        .edit_radarr_quality_profile(params)
+        .await
+        .map(RadarrSerdeable::from),
+      RadarrEvent::GetCustomFormats => self
+        .get_radarr_custom_formats()
+        .await
+        .map(RadarrSerdeable::from),
+      RadarrEvent::DeleteCustomFormat(custom_format_id) => self
+        .delete_radarr_custom_format(custom_format_id)
+        .await
+        .map(RadarrSerdeable::from),
+      RadarrEvent::EditCustomFormat(params) => self
+        .edit_radarr_custom_format(params)
+        .await
+        .map(RadarrSerdeable::from),
+      RadarrEvent::ImportCustomFormat(custom_format_json) => self
+        .import_radarr_custom_format(custom_format_json)
+        .await
+        .map(RadarrSerdeable::from),
+      RadarrEvent::ExportCustomFormat(custom_format_id) => self
+        .export_radarr_custom_format(custom_format_id)
+        .await
+        .map(RadarrSerdeable::from),
       RadarrEvent::GetQueuedEvents => self
         .get_queued_radarr_events()
         .await
@@ -258,10 +486,22 @@ impl Network<'_, '_> {
       RadarrEvent::GetTags => self.get_radarr_tags().await.map(RadarrSerdeable::from),
       RadarrEvent::GetTasks => self.get_radarr_tasks().await.map(RadarrSerdeable::from),
       RadarrEvent::GetUpdates => self.get_radarr_updates().await.map(RadarrSerdeable::from),
+      RadarrEvent::GetWantedMissing(count) => self
+        .get_radarr_wanted_missing(count)
+        .await
+        .map(RadarrSerdeable::from),
       RadarrEvent::HealthCheck => self
         .get_radarr_healthcheck()
         .await
         .map(RadarrSerdeable::from),
+      RadarrEvent::ManualImport(files) => self
+        .manual_import_radarr_files(files)
+        .await
+        .map(RadarrSerdeable::from),
+      RadarrEvent::RestoreBackup(file) => self
+        .restore_radarr_backup(file)
+        .await
+        .map(RadarrSerdeable::from),
       RadarrEvent::SearchNewMovie(query) => {
         self.search_movie(query).await.map(RadarrSerdeable::from)
       }
@@ -277,6 +517,14 @@ impl Network<'_, '_> {
         .test_all_radarr_indexers()
         .await
         .map(RadarrSerdeable::from),
+      RadarrEvent::TestDownloadClient(download_client_id) => self
+        .test_radarr_download_client(download_client_id)
+        .await
+        .map(RadarrSerdeable::from),
+      RadarrEvent::TestAllDownloadClients => self
+        .test_all_radarr_download_clients()
+        .await
+        .map(RadarrSerdeable::from),
       RadarrEvent::ToggleMovieMonitoring(movie_id) => self
         .toggle_movie_monitoring(movie_id)
         .await
@@ -285,6 +533,14 @@ impl Network<'_, '_> {
         .trigger_automatic_movie_search(movie_id)
         .await
         .map(RadarrSerdeable::from),
+      RadarrEvent::TriggerCutoffUnmetMoviesSearch => self
+        .trigger_cutoff_unmet_movies_search()
+        .await
+        .map(RadarrSerdeable::from),
+      RadarrEvent::TriggerMissingMoviesSearch => self
+        .trigger_missing_movies_search()
+        .await
+        .map(RadarrSerdeable::from),
       RadarrEvent::UpdateAllMovies => self.update_all_movies().await.map(RadarrSerdeable::from),
       RadarrEvent::UpdateAndScan(movie_id) => self
         .update_and_scan_movie(movie_id)
@@ -368,9 +624,14 @@ impl Network<'_, '_> {
     self
       .handle_request::<(), Vec<QualityProfile>>(request_props, |quality_profiles, mut app| {
         app.data.radarr_data.quality_profile_map = quality_profiles
-          .into_iter()
-          .map(|profile| (profile.id, profile.name))
+          .iter()
+          .map(|profile| (profile.id, profile.name.clone()))
           .collect();
+        app
+          .data
+          .radarr_data
+          .quality_profiles
+          .set_items(quality_profiles);
       })
       .await
   }
@@ -407,11 +668,14 @@ impl Network<'_, '_> {
         .collect::<Vec<&str>>()
     };
 
+    let mut added_tags = Vec::new();
     for tag in missing_tags_vec {
-      self
-        .add_radarr_tag(tag.trim().to_owned())
-        .await
-        .expect("Unable to add tag");
+      added_tags.push(
+        self
+          .add_radarr_tag(tag.trim().to_owned())
+          .await
+          .expect("Unable to add tag"),
+      );
     }
 
     let app = self.app.lock().await;
@@ -419,11 +683,13 @@ impl Network<'_, '_> {
       .split(',')
       .filter(|tag| !tag.is_empty())
       .map(|tag| {
-        *app
-          .data
-          .radarr_data
-          .tags_map
-          .get_by_right(tag.to_lowercase().trim())
+        let label = tag.to_lowercase();
+        let label = label.trim();
+        added_tags
+          .iter()
+          .find(|added_tag| added_tag.label.to_lowercase() == label)
+          .map(|added_tag| added_tag.id)
+          .or_else(|| app.data.radarr_data.tags_map.get_by_right(label).copied())
           .unwrap()
       })
       .collect()
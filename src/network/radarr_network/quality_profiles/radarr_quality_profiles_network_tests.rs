@@ -0,0 +1,250 @@
+#[cfg(test)]
+mod tests {
+  use crate::models::radarr_models::RadarrSerdeable;
+  use crate::models::servarr_models::EditQualityProfileParams;
+  use crate::network::NetworkResource;
+  use crate::network::network_tests::test_utils::{MockServarrApi, test_network};
+  use crate::network::radarr_network::RadarrEvent;
+  use crate::network::servarr_test_utils::{quality_profile_details, quality_profile_item};
+  use mockito::Matcher;
+  use pretty_assertions::assert_eq;
+  use serde_json::json;
+
+  fn quality_profile_details_json() -> serde_json::Value {
+    json!({
+        "name": "HD",
+        "upgradeAllowed": true,
+        "cutoff": 1000,
+        "items": [
+            {
+                "quality": { "id": 1, "name": "SDTV", "source": "tv", "resolution": 480 },
+                "items": [],
+                "allowed": false
+            },
+            {
+                "name": "WEB 720p",
+                "items": [
+                    {
+                        "quality": { "id": 5, "name": "WEBDL-720p", "source": "webdl", "resolution": 720 },
+                        "items": [],
+                        "allowed": true
+                    },
+                    {
+                        "quality": { "id": 14, "name": "WEBRip-720p", "source": "webrip", "resolution": 720 },
+                        "items": [],
+                        "allowed": true
+                    }
+                ],
+                "allowed": true,
+                "id": 1000
+            },
+            {
+                "quality": { "id": 7, "name": "Bluray-1080p", "source": "bluray", "resolution": 1080 },
+                "items": [],
+                "allowed": true
+            }
+        ],
+        "minFormatScore": 0,
+        "cutoffFormatScore": 100,
+        "formatItems": [{ "format": 1, "name": "x265", "score": 10 }],
+        "id": 1
+    })
+  }
+
+  #[tokio::test]
+  async fn test_handle_get_radarr_quality_profile_details_event() {
+    let (async_server, app, _server) = MockServarrApi::get()
+      .returns(quality_profile_details_json())
+      .path("/1")
+      .build_for(RadarrEvent::GetQualityProfileDetails(1))
+      .await;
+    let mut network = test_network(&app);
+
+    let RadarrSerdeable::QualityProfile(quality_profile) = network
+      .handle_radarr_event(RadarrEvent::GetQualityProfileDetails(1))
+      .await
+      .unwrap()
+    else {
+      panic!("Expected QualityProfile")
+    };
+    async_server.assert_async().await;
+    assert_eq!(quality_profile, quality_profile_details());
+  }
+
+  #[tokio::test]
+  async fn test_handle_edit_radarr_quality_profile_event() {
+    let mut expected_edit_quality_profile_body = quality_profile_details_json();
+    expected_edit_quality_profile_body["name"] = json!("Test Profile");
+    expected_edit_quality_profile_body["upgradeAllowed"] = json!(false);
+    expected_edit_quality_profile_body["cutoff"] = json!(7);
+    expected_edit_quality_profile_body["minFormatScore"] = json!(10);
+    expected_edit_quality_profile_body["cutoffFormatScore"] = json!(200);
+    expected_edit_quality_profile_body["items"][0]["allowed"] = json!(true);
+    expected_edit_quality_profile_body["items"][1]["items"][1]["allowed"] = json!(false);
+    let edit_quality_profile_params = EditQualityProfileParams {
+      quality_profile_id: 1,
+      name: Some("Test Profile".to_owned()),
+      upgrade_allowed: Some(false),
+      cutoff: Some("bluray-1080p".to_owned()),
+      min_format_score: Some(10),
+      cutoff_format_score: Some(200),
+      allowed_qualities: vec!["SDTV".to_owned()],
+      disallowed_qualities: vec!["WEBRip-720p".to_owned()],
+      ..EditQualityProfileParams::default()
+    };
+
+    let (async_details_server, app, mut server) = MockServarrApi::get()
+      .returns(quality_profile_details_json())
+      .path("/1")
+      .build_for(RadarrEvent::GetQualityProfileDetails(1))
+      .await;
+    let async_edit_server = server
+      .mock(
+        "PUT",
+        format!(
+          "/api/v3{}/1",
+          RadarrEvent::EditQualityProfile(edit_quality_profile_params.clone()).resource()
+        )
+        .as_str(),
+      )
+      .with_status(202)
+      .match_header("X-Api-Key", "test1234")
+      .match_body(Matcher::Json(expected_edit_quality_profile_body))
+      .create_async()
+      .await;
+    let mut network = test_network(&app);
+
+    assert!(
+      network
+        .handle_radarr_event(RadarrEvent::EditQualityProfile(edit_quality_profile_params))
+        .await
+        .is_ok()
+    );
+
+    async_details_server.assert_async().await;
+    async_edit_server.assert_async().await;
+  }
+
+  #[tokio::test]
+  async fn test_handle_edit_radarr_quality_profile_event_defaults_to_previous_values() {
+    let edit_quality_profile_params = EditQualityProfileParams {
+      quality_profile_id: 1,
+      ..EditQualityProfileParams::default()
+    };
+    let (async_details_server, app, mut server) = MockServarrApi::get()
+      .returns(quality_profile_details_json())
+      .path("/1")
+      .build_for(RadarrEvent::GetQualityProfileDetails(1))
+      .await;
+    let async_edit_server = server
+      .mock(
+        "PUT",
+        format!(
+          "/api/v3{}/1",
+          RadarrEvent::EditQualityProfile(edit_quality_profile_params.clone()).resource()
+        )
+        .as_str(),
+      )
+      .with_status(202)
+      .match_header("X-Api-Key", "test1234")
+      .match_body(Matcher::Json(quality_profile_details_json()))
+      .create_async()
+      .await;
+    let mut network = test_network(&app);
+
+    assert!(
+      network
+        .handle_radarr_event(RadarrEvent::EditQualityProfile(edit_quality_profile_params))
+        .await
+        .is_ok()
+    );
+
+    async_details_server.assert_async().await;
+    async_edit_server.assert_async().await;
+  }
+
+  #[tokio::test]
+  async fn test_handle_edit_radarr_quality_profile_event_replaces_items() {
+    let items = vec![
+      quality_profile_item(1, "SDTV", false),
+      quality_profile_item(7, "Bluray-1080p", true),
+    ];
+    let mut expected_edit_quality_profile_body = quality_profile_details_json();
+    expected_edit_quality_profile_body["items"] = json!(items);
+    expected_edit_quality_profile_body["cutoff"] = json!(7);
+    let edit_quality_profile_params = EditQualityProfileParams {
+      quality_profile_id: 1,
+      cutoff: Some("Bluray-1080p".to_owned()),
+      items: Some(items),
+      ..EditQualityProfileParams::default()
+    };
+    let (async_details_server, app, mut server) = MockServarrApi::get()
+      .returns(quality_profile_details_json())
+      .path("/1")
+      .build_for(RadarrEvent::GetQualityProfileDetails(1))
+      .await;
+    let async_edit_server = server
+      .mock(
+        "PUT",
+        format!(
+          "/api/v3{}/1",
+          RadarrEvent::EditQualityProfile(edit_quality_profile_params.clone()).resource()
+        )
+        .as_str(),
+      )
+      .with_status(202)
+      .match_header("X-Api-Key", "test1234")
+      .match_body(Matcher::Json(expected_edit_quality_profile_body))
+      .create_async()
+      .await;
+    let mut network = test_network(&app);
+
+    assert!(
+      network
+        .handle_radarr_event(RadarrEvent::EditQualityProfile(edit_quality_profile_params))
+        .await
+        .is_ok()
+    );
+
+    async_details_server.assert_async().await;
+    async_edit_server.assert_async().await;
+  }
+
+  #[tokio::test]
+  async fn test_handle_edit_radarr_quality_profile_event_unknown_quality() {
+    let edit_quality_profile_params = EditQualityProfileParams {
+      quality_profile_id: 1,
+      allowed_qualities: vec!["Remux-2160p".to_owned()],
+      ..EditQualityProfileParams::default()
+    };
+    let (async_details_server, app, mut server) = MockServarrApi::get()
+      .returns(quality_profile_details_json())
+      .path("/1")
+      .build_for(RadarrEvent::GetQualityProfileDetails(1))
+      .await;
+    let async_edit_server = server
+      .mock(
+        "PUT",
+        format!(
+          "/api/v3{}/1",
+          RadarrEvent::EditQualityProfile(edit_quality_profile_params.clone()).resource()
+        )
+        .as_str(),
+      )
+      .expect(0)
+      .create_async()
+      .await;
+    let mut network = test_network(&app);
+
+    let result = network
+      .handle_radarr_event(RadarrEvent::EditQualityProfile(edit_quality_profile_params))
+      .await;
+
+    async_details_server.assert_async().await;
+    async_edit_server.assert_async().await;
+    assert_eq!(
+      result.unwrap_err().to_string(),
+      "Quality 'Remux-2160p' does not exist in the quality profile"
+    );
+  }
+}
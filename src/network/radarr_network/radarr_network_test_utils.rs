@@ -3,13 +3,15 @@ pub mod test_utils {
   use crate::models::radarr_models::{
     AddMovieSearchResult, BlocklistItem, BlocklistItemMovie, Collection, CollectionMovie, Credit,
     CreditType, DownloadRecord, DownloadsResponse, IndexerSettings, MediaInfo, MinimumAvailability,
-    Movie, MovieCollection, MovieFile, MovieHistoryItem, RadarrHistoryData, RadarrHistoryEventType,
-    RadarrHistoryItem, RadarrRelease, RadarrTask, RadarrTaskName, Rating, RatingsList,
+    Movie, MovieCollection, MovieFile, MovieHistoryItem, NamingConfig, NamingExamples,
+    RadarrHistoryData, RadarrHistoryEventType, RadarrHistoryItem, RadarrManualImportFile,
+    RadarrManualImportItem, RadarrRelease, RadarrTask, RadarrTaskName, Rating, RatingsList,
   };
   use crate::models::servarr_models::{
-    Indexer, IndexerField, Language, Quality, QualityWrapper, RootFolder,
+    Indexer, IndexerField, Language, MediaManagementSettings, Quality, QualityWrapper, RootFolder,
   };
   use crate::models::{HorizontallyScrollableText, ScrollableText};
+  use crate::network::servarr_test_utils::{custom_format, media_management_config};
   use bimap::BiMap;
   use chrono::DateTime;
   use indoc::formatdoc;
@@ -107,6 +109,32 @@ pub mod test_utils {
     }
   }
 
+  pub fn manual_import_item() -> RadarrManualImportItem {
+    RadarrManualImportItem {
+      id: 1,
+      path: "/nfs/downloads/Test/Test.mkv".to_owned(),
+      relative_path: "Test/Test.mkv".to_owned(),
+      size: 3543348019,
+      movie: Some(movie()),
+      quality: json!({ "quality": { "id": 7, "name": "Bluray-1080p" } }),
+      languages: vec![language()],
+      release_group: Some("SPARKS".to_owned()),
+      download_id: Some("abc123".to_owned()),
+      rejections: Vec::new(),
+    }
+  }
+
+  pub fn manual_import_file() -> RadarrManualImportFile {
+    RadarrManualImportFile {
+      path: "/nfs/downloads/Test/Test.mkv".to_owned(),
+      movie_id: 1,
+      quality: json!({ "quality": { "id": 7, "name": "Bluray-1080p" } }),
+      languages: vec![language()],
+      release_group: Some("SPARKS".to_owned()),
+      download_id: Some("abc123".to_owned()),
+    }
+  }
+
   pub fn log_line() -> &'static str {
     "2025-12-15 16:14:45 UTC|INFO|DownloadDecisionMaker|Processing 545 releases"
   }
@@ -240,6 +268,9 @@ pub mod test_utils {
       tmdb_id: 1234,
       quality_profile_id: 2222,
       minimum_availability: MinimumAvailability::Announced,
+      in_cinemas: None,
+      digital_release: None,
+      physical_release: None,
       certification: Some("R".to_owned()),
       tags: vec![Number::from(1)],
       ratings: ratings_list(),
@@ -286,6 +317,8 @@ pub mod test_utils {
       leechers: Some(Number::from(1)),
       languages: Some(vec![language()]),
       quality: quality_wrapper(),
+      custom_formats: vec![custom_format()],
+      custom_format_score: 10,
     }
   }
 
@@ -342,6 +375,8 @@ pub mod test_utils {
       output_path: Some(HorizontallyScrollableText::from("/nfs/movies/Test")),
       indexer: "kickass torrents".to_owned(),
       download_client: "transmission".to_owned(),
+      download_id: Some("abc123".to_owned()),
+      estimated_completion_time: None,
     }
   }
 
@@ -401,14 +436,17 @@ pub mod test_utils {
         IndexerField {
           name: Some("baseUrl".to_owned()),
           value: Some(json!("https://test.com")),
+          ..IndexerField::default()
         },
         IndexerField {
           name: Some("apiKey".to_owned()),
           value: Some(json!("")),
+          ..IndexerField::default()
         },
         IndexerField {
           name: Some("seedCriteria.seedRatio".to_owned()),
           value: Some(json!("1.2")),
+          ..IndexerField::default()
         },
       ]),
     }
@@ -423,6 +461,30 @@ pub mod test_utils {
     }
   }
 
+  pub fn naming_config() -> NamingConfig {
+    NamingConfig {
+      id: 1,
+      rename_movies: true,
+      replace_illegal_characters: true,
+      standard_movie_format: Some("{Movie Title} ({Release Year}) {Quality Full}".to_owned()),
+      movie_folder_format: Some("{Movie Title} ({Release Year})".to_owned()),
+    }
+  }
+
+  pub fn naming_examples() -> NamingExamples {
+    NamingExamples {
+      movie_example: Some("The Movie Title (2010) Bluray-1080p Proper".to_owned()),
+      movie_folder_example: Some("The Movie Title (2010)".to_owned()),
+    }
+  }
+
+  pub fn media_management_settings() -> MediaManagementSettings<NamingConfig> {
+    MediaManagementSettings {
+      naming: naming_config(),
+      media_management: media_management_config(),
+    }
+  }
+
   pub fn tag() -> Value {
     json!({
       "id": 3,
@@ -498,6 +560,8 @@ pub mod test_utils {
       leechers: Some(3.into()),
       languages: Some(vec![language()]),
       quality: quality_wrapper(),
+      custom_formats: Vec::new(),
+      custom_format_score: 0,
     }
   }
 
@@ -516,6 +580,8 @@ pub mod test_utils {
       leechers: None,
       languages: Some(vec![language()]),
       quality: quality_wrapper(),
+      custom_formats: Vec::new(),
+      custom_format_score: 0,
     }
   }
 }
@@ -3,15 +3,20 @@ mod test {
   use super::super::*;
   use crate::App;
   use crate::models::radarr_models::{
-    EditCollectionParams, EditMovieParams, IndexerSettings, RadarrTaskName,
+    EditCollectionParams, EditMovieParams, IndexerSettings, NamingConfig, RadarrTaskName,
   };
   use crate::models::servarr_data::radarr::modals::EditMovieModal;
-  use crate::models::servarr_models::EditIndexerParams;
+  use crate::models::servarr_models::{
+    AddDownloadClientParams, AddIndexerParams, BulkDeleteDownloadsParams, DeleteDownloadParams,
+    EditCustomFormatParams, EditDownloadClientParams, EditIndexerParams, EditQualityProfileParams,
+    MediaManagementSettings,
+  };
   use crate::network::network_tests::test_utils::{MockServarrApi, test_network};
   use crate::network::radarr_network::radarr_network_test_utils::test_utils::{
     quality_profile, tag,
   };
   use bimap::BiMap;
+  use chrono::NaiveDate;
   use pretty_assertions::{assert_eq, assert_str_eq};
   use rstest::rstest;
   use serde_json::json;
@@ -33,6 +38,33 @@ mod test {
     assert_str_eq!(event.resource(), "/movie");
   }
 
+  #[rstest]
+  fn test_is_read_only(
+    #[values(
+      RadarrEvent::GetMovies,
+      RadarrEvent::GetMovieDetails(0),
+      RadarrEvent::GetTags,
+      RadarrEvent::HealthCheck,
+      RadarrEvent::SearchNewMovie(String::new())
+    )]
+    event: RadarrEvent,
+  ) {
+    assert!(event.is_read_only());
+  }
+
+  #[rstest]
+  fn test_is_not_read_only(
+    #[values(
+      RadarrEvent::EditMovie(EditMovieParams::default()),
+      RadarrEvent::ToggleMovieMonitoring(0),
+      RadarrEvent::TriggerAutomaticSearch(0),
+      RadarrEvent::AddTag(String::new())
+    )]
+    event: RadarrEvent,
+  ) {
+    assert!(!event.is_read_only());
+  }
+
   #[rstest]
   fn test_resource_collection(
     #[values(
@@ -47,6 +79,7 @@ mod test {
   #[rstest]
   fn test_resource_indexer(
     #[values(
+      RadarrEvent::AddIndexer(AddIndexerParams::default()),
       RadarrEvent::GetIndexers,
       RadarrEvent::DeleteIndexer(0),
       RadarrEvent::EditIndexer(EditIndexerParams::default())
@@ -56,6 +89,19 @@ mod test {
     assert_str_eq!(event.resource(), "/indexer");
   }
 
+  #[rstest]
+  fn test_resource_download_client(
+    #[values(
+      RadarrEvent::AddDownloadClient(AddDownloadClientParams::default()),
+      RadarrEvent::GetDownloadClients,
+      RadarrEvent::DeleteDownloadClient(0),
+      RadarrEvent::EditDownloadClient(EditDownloadClientParams::default())
+    )]
+    event: RadarrEvent,
+  ) {
+    assert_str_eq!(event.resource(), "/downloadclient");
+  }
+
   #[rstest]
   fn test_resource_all_indexer_settings(
     #[values(
@@ -67,6 +113,17 @@ mod test {
     assert_str_eq!(event.resource(), "/config/indexer");
   }
 
+  #[rstest]
+  fn test_resource_media_management_settings(
+    #[values(
+      RadarrEvent::GetMediaManagementSettings,
+      RadarrEvent::EditMediaManagementSettings(MediaManagementSettings::default())
+    )]
+    event: RadarrEvent,
+  ) {
+    assert_str_eq!(event.resource(), "/config");
+  }
+
   #[rstest]
   fn test_resource_root_folder(
     #[values(
@@ -104,7 +161,11 @@ mod test {
 
   #[rstest]
   fn test_resource_queue(
-    #[values(RadarrEvent::GetDownloads(0), RadarrEvent::DeleteDownload(0))] event: RadarrEvent,
+    #[values(
+      RadarrEvent::GetDownloads(0),
+      RadarrEvent::DeleteDownload(DeleteDownloadParams::default())
+    )]
+    event: RadarrEvent,
   ) {
     assert_str_eq!(event.resource(), "/queue");
   }
@@ -121,7 +182,10 @@ mod test {
     #[values(
       RadarrEvent::StartTask(RadarrTaskName::default()),
       RadarrEvent::GetQueuedEvents,
+      RadarrEvent::ManualImport(Vec::new()),
       RadarrEvent::TriggerAutomaticSearch(0),
+      RadarrEvent::TriggerCutoffUnmetMoviesSearch,
+      RadarrEvent::TriggerMissingMoviesSearch,
       RadarrEvent::UpdateAndScan(0),
       RadarrEvent::UpdateAllMovies,
       RadarrEvent::UpdateDownloads,
@@ -134,21 +198,62 @@ mod test {
 
   #[rstest]
   #[case(RadarrEvent::ClearBlocklist, "/blocklist/bulk")]
+  #[case(RadarrEvent::GetIndexerSchema, "/indexer/schema")]
+  #[case(
+    RadarrEvent::BulkDeleteDownloads(BulkDeleteDownloadsParams::default()),
+    "/queue/bulk"
+  )]
+  #[case(RadarrEvent::GrabQueueItem(1), "/queue/grab")]
   #[case(RadarrEvent::DeleteBlocklistItem(1), "/blocklist")]
   #[case(RadarrEvent::GetBlocklist, "/blocklist?page=1&pageSize=10000")]
+  #[case(
+    RadarrEvent::GetCalendar(NaiveDate::default(), NaiveDate::default()),
+    "/calendar"
+  )]
+  #[case(RadarrEvent::GetCutoffUnmet(500), "/wanted/cutoff")]
+  #[case(RadarrEvent::GetWantedMissing(500), "/wanted/missing")]
   #[case(RadarrEvent::GetHistory(500), "/history")]
   #[case(RadarrEvent::GetLogs(500), "/log")]
+  #[case(
+    RadarrEvent::GetNamingExamples(NamingConfig::default()),
+    "/config/naming/examples"
+  )]
+  #[case(RadarrEvent::GetManualImport(String::new(), None), "/manualimport")]
   #[case(RadarrEvent::MarkHistoryItemAsFailed(1), "/history/failed")]
   #[case(RadarrEvent::SearchNewMovie(String::new()), "/movie/lookup")]
+  #[case(
+    RadarrEvent::BulkDeleteMovies(BulkDeleteMoviesParams::default()),
+    "/movie/editor"
+  )]
+  #[case(
+    RadarrEvent::BulkEditMovies(BulkEditMoviesParams::default()),
+    "/movie/editor"
+  )]
   #[case(RadarrEvent::GetMovieCredits(0), "/credit")]
   #[case(RadarrEvent::GetMovieHistory(0), "/history/movie")]
   #[case(RadarrEvent::GetDiskSpace, "/diskspace")]
   #[case(RadarrEvent::GetQualityProfiles, "/qualityprofile")]
+  #[case(RadarrEvent::GetQualityProfileDetails(0), "/qualityprofile")]
+  #[case(
+    RadarrEvent::EditQualityProfile(EditQualityProfileParams::default()),
+    "/qualityprofile"
+  )]
+  #[case(RadarrEvent::GetCustomFormats, "/customformat")]
+  #[case(RadarrEvent::DeleteCustomFormat(0), "/customformat")]
+  #[case(
+    RadarrEvent::EditCustomFormat(EditCustomFormatParams::default()),
+    "/customformat"
+  )]
+  #[case(RadarrEvent::ImportCustomFormat(String::new()), "/customformat")]
+  #[case(RadarrEvent::ExportCustomFormat(0), "/customformat")]
   #[case(RadarrEvent::GetStatus, "/system/status")]
   #[case(RadarrEvent::GetTasks, "/system/task")]
   #[case(RadarrEvent::GetUpdates, "/update")]
   #[case(RadarrEvent::TestIndexer(0), "/indexer/test")]
   #[case(RadarrEvent::TestAllIndexers, "/indexer/testall")]
+  #[case(RadarrEvent::GetDownloadClientSchema, "/downloadclient/schema")]
+  #[case(RadarrEvent::TestDownloadClient(0), "/downloadclient/test")]
+  #[case(RadarrEvent::TestAllDownloadClients, "/downloadclient/testall")]
   #[case(RadarrEvent::HealthCheck, "/health")]
   fn test_resource(#[case] event: RadarrEvent, #[case] expected_uri: String) {
     assert_str_eq!(event.resource(), expected_uri);
@@ -180,6 +285,7 @@ mod test {
     let expected: Vec<QualityProfile> = vec![QualityProfile {
       id: 2222,
       name: "HD - 1080p".to_owned(),
+      ..QualityProfile::default()
     }];
 
     let (mock, app, _server) = MockServarrApi::get()
@@ -200,6 +306,10 @@ mod test {
     };
 
     assert_eq!(quality_profiles, expected);
+    assert_eq!(
+      app.lock().await.data.radarr_data.quality_profiles.items,
+      expected
+    );
     assert_eq!(
       app.lock().await.data.radarr_data.quality_profile_map,
       BiMap::from_iter([(2222i64, "HD - 1080p".to_owned())])
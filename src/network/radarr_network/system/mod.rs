@@ -1,6 +1,7 @@
 use crate::models::radarr_models::{RadarrTask, RadarrTaskName, SystemStatus};
 use crate::models::servarr_models::{
-  CommandBody, DiskSpace, HostConfig, LogResponse, QueueEvent, SecurityConfig, Update,
+  Backup, CommandBody, DiskSpace, DownloadBackupParams, HostConfig, LogResponse, QueueEvent,
+  SecurityConfig, Update,
 };
 use crate::models::{HorizontallyScrollableText, Scrollable, ScrollableText};
 use crate::network::radarr_network::RadarrEvent;
@@ -9,12 +10,68 @@ use anyhow::Result;
 use indoc::formatdoc;
 use log::info;
 use serde_json::Value;
+use std::path::PathBuf;
 
 #[cfg(test)]
 #[path = "radarr_system_network_tests.rs"]
 mod radarr_system_network_tests;
 
 impl Network<'_, '_> {
+  pub(in crate::network::radarr_network) async fn delete_radarr_backup(
+    &mut self,
+    backup_id: i64,
+  ) -> Result<()> {
+    info!("Deleting Radarr backup with id: {backup_id}");
+    let event = RadarrEvent::DeleteBackup(backup_id);
+
+    let request_props = self
+      .request_props_from(
+        event,
+        RequestMethod::Delete,
+        None::<()>,
+        Some(format!("/{backup_id}")),
+        None,
+      )
+      .await;
+
+    self
+      .handle_request::<(), ()>(request_props, |_, _| ())
+      .await
+  }
+
+  pub(in crate::network::radarr_network) async fn download_radarr_backup(
+    &mut self,
+    params: DownloadBackupParams,
+  ) -> Result<()> {
+    info!(
+      "Downloading Radarr backup '{}' to: {}",
+      params.backup_path,
+      params.destination.display()
+    );
+    let event = RadarrEvent::DownloadBackup(params.clone());
+
+    self
+      .download_file(event, &params.backup_path, &params.destination)
+      .await
+  }
+
+  pub(in crate::network::radarr_network) async fn get_radarr_backups(
+    &mut self,
+  ) -> Result<Vec<Backup>> {
+    info!("Fetching Radarr backups");
+    let event = RadarrEvent::GetBackups;
+
+    let request_props = self
+      .request_props_from(event, RequestMethod::Get, None::<()>, None, None)
+      .await;
+
+    self
+      .handle_request::<(), Vec<Backup>>(request_props, |backups_vec, mut app| {
+        app.data.radarr_data.backups.set_items(backups_vec);
+      })
+      .await
+  }
+
   pub(in crate::network::radarr_network) async fn get_radarr_diskspace(
     &mut self,
   ) -> Result<Vec<DiskSpace>> {
@@ -254,6 +311,16 @@ impl Network<'_, '_> {
       .await
   }
 
+  pub(in crate::network::radarr_network) async fn restore_radarr_backup(
+    &mut self,
+    file: PathBuf,
+  ) -> Result<Value> {
+    info!("Restoring Radarr backup from: {}", file.display());
+    let event = RadarrEvent::RestoreBackup(file.clone());
+
+    self.upload_file(event, &file).await
+  }
+
   pub(in crate::network::radarr_network) async fn start_radarr_task(
     &mut self,
     task_name: RadarrTaskName,
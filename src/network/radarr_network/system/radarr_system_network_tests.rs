@@ -3,15 +3,191 @@ mod tests {
   use crate::models::HorizontallyScrollableText;
   use crate::models::radarr_models::{RadarrSerdeable, RadarrTask, RadarrTaskName, SystemStatus};
   use crate::models::servarr_models::{
-    DiskSpace, HostConfig, LogResponse, QueueEvent, SecurityConfig, Update,
+    DiskSpace, DownloadBackupParams, HostConfig, LogResponse, QueueEvent, SecurityConfig, Update,
   };
   use crate::network::network_tests::test_utils::{MockServarrApi, test_network};
   use crate::network::radarr_network::RadarrEvent;
   use crate::network::radarr_network::radarr_network_test_utils::test_utils::updates;
+  use crate::network::servarr_test_utils::backup;
   use chrono::DateTime;
+  use mockito::Matcher;
   use pretty_assertions::{assert_eq, assert_str_eq};
   use serde_json::json;
 
+  #[tokio::test]
+  async fn test_handle_delete_radarr_backup_event() {
+    let (mock, app, _server) = MockServarrApi::delete()
+      .path("/1")
+      .build_for(RadarrEvent::DeleteBackup(1))
+      .await;
+    let mut network = test_network(&app);
+
+    assert!(
+      network
+        .handle_radarr_event(RadarrEvent::DeleteBackup(1))
+        .await
+        .is_ok()
+    );
+
+    mock.assert_async().await;
+  }
+
+  #[tokio::test]
+  async fn test_handle_download_radarr_backup_event() {
+    let destination = std::env::temp_dir().join("managarr_test_radarr_backup.zip");
+    let params = DownloadBackupParams {
+      backup_path: "/backup/scheduled/backup.zip".to_owned(),
+      destination: destination.clone(),
+    };
+    let (_, app, mut server) = MockServarrApi::get()
+      .build_for(RadarrEvent::DownloadBackup(params.clone()))
+      .await;
+    let mock = server
+      .mock("GET", "/backup/scheduled/backup.zip")
+      .match_header("X-Api-Key", "test1234")
+      .with_body("backup contents")
+      .create_async()
+      .await;
+    let mut network = test_network(&app);
+
+    assert!(
+      network
+        .handle_radarr_event(RadarrEvent::DownloadBackup(params))
+        .await
+        .is_ok()
+    );
+
+    mock.assert_async().await;
+    assert_eq!(
+      std::fs::read_to_string(&destination).unwrap(),
+      "backup contents"
+    );
+    std::fs::remove_file(destination).unwrap();
+  }
+
+  #[tokio::test]
+  async fn test_handle_download_radarr_backup_event_error() {
+    let destination = std::env::temp_dir().join("managarr_test_radarr_backup_error.zip");
+    let params = DownloadBackupParams {
+      backup_path: "/backup/scheduled/backup.zip".to_owned(),
+      destination: destination.clone(),
+    };
+    let (_, app, mut server) = MockServarrApi::get()
+      .build_for(RadarrEvent::DownloadBackup(params.clone()))
+      .await;
+    let mock = server
+      .mock("GET", "/backup/scheduled/backup.zip")
+      .with_status(404)
+      .create_async()
+      .await;
+    let mut network = test_network(&app);
+
+    assert!(
+      network
+        .handle_radarr_event(RadarrEvent::DownloadBackup(params))
+        .await
+        .is_err()
+    );
+
+    mock.assert_async().await;
+    assert!(!destination.exists());
+    assert!(app.lock().await.error.text.contains("404"));
+  }
+
+  #[tokio::test]
+  async fn test_handle_get_radarr_backups_event() {
+    let backups_json = json!([{
+      "id": 1,
+      "name": "radarr_backup_v5.3.6.8612_2024.01.01_00.00.00.zip",
+      "path": "/backup/scheduled/radarr_backup_v5.3.6.8612_2024.01.01_00.00.00.zip",
+      "type": "scheduled",
+      "size": 1048576,
+      "time": "2024-01-01T00:00:00Z"
+    }]);
+    let (mock, app, _server) = MockServarrApi::get()
+      .returns(backups_json)
+      .build_for(RadarrEvent::GetBackups)
+      .await;
+    let mut network = test_network(&app);
+
+    let RadarrSerdeable::Backups(backups) = network
+      .handle_radarr_event(RadarrEvent::GetBackups)
+      .await
+      .unwrap()
+    else {
+      panic!("Expected Backups")
+    };
+    mock.assert_async().await;
+    assert_eq!(
+      app.lock().await.data.radarr_data.backups.items,
+      vec![backup()]
+    );
+    assert_eq!(backups, vec![backup()]);
+  }
+
+  #[tokio::test]
+  async fn test_handle_restore_radarr_backup_event() {
+    let file = std::env::temp_dir().join("managarr_test_radarr_restore_backup.zip");
+    std::fs::write(&file, "backup contents").unwrap();
+    let (mock, app, _server) = MockServarrApi::post()
+      .returns(json!({ "restartRequired": true }))
+      .build_for(RadarrEvent::RestoreBackup(file.clone()))
+      .await;
+    let mut network = test_network(&app);
+
+    let RadarrSerdeable::Value(value) = network
+      .handle_radarr_event(RadarrEvent::RestoreBackup(file.clone()))
+      .await
+      .unwrap()
+    else {
+      panic!("Expected Value")
+    };
+    mock.assert_async().await;
+    assert_eq!(value, json!({ "restartRequired": true }));
+    std::fs::remove_file(file).unwrap();
+  }
+
+  #[tokio::test]
+  async fn test_handle_restore_radarr_backup_event_escapes_the_file_name() {
+    let file = std::env::temp_dir().join("managarr_test_radarr_\"restore\".zip");
+    std::fs::write(&file, "backup contents").unwrap();
+    let (mock, app, _server) = MockServarrApi::post()
+      .with_request_body_matching(Matcher::AllOf(vec![
+        Matcher::Regex(
+          r#"Content-Disposition: form-data; name="file"; filename="managarr_test_radarr_\\"restore\\"\.zip"\r\n"#
+            .to_owned(),
+        ),
+        Matcher::Regex("\r\n\r\nbackup contents\r\n--".to_owned()),
+      ]))
+      .build_for(RadarrEvent::RestoreBackup(file.clone()))
+      .await;
+    let mut network = test_network(&app);
+
+    let result = network
+      .handle_radarr_event(RadarrEvent::RestoreBackup(file.clone()))
+      .await;
+
+    mock.assert_async().await;
+    assert!(result.is_ok());
+    std::fs::remove_file(file).unwrap();
+  }
+
+  #[tokio::test]
+  async fn test_handle_restore_radarr_backup_event_missing_file() {
+    let file = std::env::temp_dir().join("managarr_test_radarr_missing_backup.zip");
+    let (mock, app, _server) = MockServarrApi::post()
+      .build_for(RadarrEvent::RestoreBackup(file.clone()))
+      .await;
+    let mut network = test_network(&app);
+
+    let result = network
+      .handle_radarr_event(RadarrEvent::RestoreBackup(file))
+      .await;
+
+    assert!(!mock.matched_async().await);
+    assert!(result.is_err());
+  }
+
   #[tokio::test]
   async fn test_handle_get_radarr_diskspace_event() {
     let (mock, app, _server) = MockServarrApi::get()
@@ -0,0 +1,111 @@
+use crate::models::radarr_models::WantedMoviesResponse;
+use crate::models::servarr_models::CommandBody;
+use crate::network::radarr_network::RadarrEvent;
+use crate::network::{Network, RequestMethod};
+use anyhow::Result;
+use log::info;
+use serde_json::Value;
+
+#[cfg(test)]
+#[path = "radarr_wanted_network_tests.rs"]
+mod radarr_wanted_network_tests;
+
+impl Network<'_, '_> {
+  pub(in crate::network::radarr_network) async fn get_radarr_wanted_missing(
+    &mut self,
+    count: u64,
+  ) -> Result<WantedMoviesResponse> {
+    info!("Fetching missing Radarr movies");
+    let event = RadarrEvent::GetWantedMissing(count);
+
+    let request_props = self
+      .request_props_from(
+        event,
+        RequestMethod::Get,
+        None::<()>,
+        None,
+        Some(wanted_query_params(count)),
+      )
+      .await;
+
+    self
+      .handle_request::<(), WantedMoviesResponse>(request_props, |wanted_response, mut app| {
+        app
+          .data
+          .radarr_data
+          .wanted_missing
+          .set_items(wanted_response.records);
+      })
+      .await
+  }
+
+  pub(in crate::network::radarr_network) async fn get_radarr_cutoff_unmet(
+    &mut self,
+    count: u64,
+  ) -> Result<WantedMoviesResponse> {
+    info!("Fetching Radarr movies that have not met their quality cutoff");
+    let event = RadarrEvent::GetCutoffUnmet(count);
+
+    let request_props = self
+      .request_props_from(
+        event,
+        RequestMethod::Get,
+        None::<()>,
+        None,
+        Some(wanted_query_params(count)),
+      )
+      .await;
+
+    self
+      .handle_request::<(), WantedMoviesResponse>(request_props, |wanted_response, mut app| {
+        app
+          .data
+          .radarr_data
+          .cutoff_unmet
+          .set_items(wanted_response.records);
+      })
+      .await
+  }
+
+  pub(in crate::network::radarr_network) async fn trigger_missing_movies_search(
+    &mut self,
+  ) -> Result<Value> {
+    info!("Searching indexers for all missing movies");
+    let event = RadarrEvent::TriggerMissingMoviesSearch;
+    let body = CommandBody {
+      name: "MissingMoviesSearch".to_owned(),
+    };
+
+    let request_props = self
+      .request_props_from(event, RequestMethod::Post, Some(body), None, None)
+      .await;
+
+    self
+      .handle_request::<CommandBody, Value>(request_props, |_, _| ())
+      .await
+  }
+
+  pub(in crate::network::radarr_network) async fn trigger_cutoff_unmet_movies_search(
+    &mut self,
+  ) -> Result<Value> {
+    info!("Searching indexers for all movies that have not met their quality cutoff");
+    let event = RadarrEvent::TriggerCutoffUnmetMoviesSearch;
+    let body = CommandBody {
+      name: "CutoffUnmetMoviesSearch".to_owned(),
+    };
+
+    let request_props = self
+      .request_props_from(event, RequestMethod::Post, Some(body), None, None)
+      .await;
+
+    self
+      .handle_request::<CommandBody, Value>(request_props, |_, _| ())
+      .await
+  }
+}
+
+fn wanted_query_params(count: u64) -> String {
+  format!(
+    "page=1&pageSize={count}&sortDirection=ascending&sortKey=movieMetadata.sortTitle&monitored=true"
+  )
+}
@@ -0,0 +1,104 @@
+#[cfg(test)]
+mod tests {
+  use crate::models::radarr_models::{RadarrSerdeable, WantedMoviesResponse};
+  use crate::network::network_tests::test_utils::{MockServarrApi, test_network};
+  use crate::network::radarr_network::RadarrEvent;
+  use crate::network::radarr_network::radarr_network_test_utils::test_utils::movie;
+  use pretty_assertions::assert_eq;
+  use serde_json::json;
+
+  const WANTED_QUERY: &str =
+    "page=1&pageSize=500&sortDirection=ascending&sortKey=movieMetadata.sortTitle&monitored=true";
+
+  #[tokio::test]
+  async fn test_handle_get_radarr_wanted_missing_event() {
+    let expected_response = WantedMoviesResponse {
+      records: vec![movie()],
+    };
+    let (mock, app, _server) = MockServarrApi::get()
+      .query(WANTED_QUERY)
+      .returns(json!({ "records": [movie()] }))
+      .build_for(RadarrEvent::GetWantedMissing(500))
+      .await;
+    let mut network = test_network(&app);
+
+    let RadarrSerdeable::WantedMoviesResponse(response) = network
+      .handle_radarr_event(RadarrEvent::GetWantedMissing(500))
+      .await
+      .unwrap()
+    else {
+      panic!("Expected WantedMoviesResponse")
+    };
+
+    mock.assert_async().await;
+    assert_eq!(
+      app.lock().await.data.radarr_data.wanted_missing.items,
+      vec![movie()]
+    );
+    assert_eq!(response, expected_response);
+  }
+
+  #[tokio::test]
+  async fn test_handle_get_radarr_cutoff_unmet_event() {
+    let expected_response = WantedMoviesResponse {
+      records: vec![movie()],
+    };
+    let (mock, app, _server) = MockServarrApi::get()
+      .query(WANTED_QUERY)
+      .returns(json!({ "records": [movie()] }))
+      .build_for(RadarrEvent::GetCutoffUnmet(500))
+      .await;
+    let mut network = test_network(&app);
+
+    let RadarrSerdeable::WantedMoviesResponse(response) = network
+      .handle_radarr_event(RadarrEvent::GetCutoffUnmet(500))
+      .await
+      .unwrap()
+    else {
+      panic!("Expected WantedMoviesResponse")
+    };
+
+    mock.assert_async().await;
+    assert_eq!(
+      app.lock().await.data.radarr_data.cutoff_unmet.items,
+      vec![movie()]
+    );
+    assert_eq!(response, expected_response);
+  }
+
+  #[tokio::test]
+  async fn test_handle_trigger_missing_movies_search_event() {
+    let (mock, app, _server) = MockServarrApi::post()
+      .with_request_body(json!({ "name": "MissingMoviesSearch" }))
+      .returns(json!({}))
+      .build_for(RadarrEvent::TriggerMissingMoviesSearch)
+      .await;
+    let mut network = test_network(&app);
+
+    assert_ok!(
+      network
+        .handle_radarr_event(RadarrEvent::TriggerMissingMoviesSearch)
+        .await
+    );
+
+    mock.assert_async().await;
+  }
+
+  #[tokio::test]
+  async fn test_handle_trigger_cutoff_unmet_movies_search_event() {
+    let (mock, app, _server) = MockServarrApi::post()
+      .with_request_body(json!({ "name": "CutoffUnmetMoviesSearch" }))
+      .returns(json!({}))
+      .build_for(RadarrEvent::TriggerCutoffUnmetMoviesSearch)
+      .await;
+    let mut network = test_network(&app);
+
+    assert_ok!(
+      network
+        .handle_radarr_event(RadarrEvent::TriggerCutoffUnmetMoviesSearch)
+        .await
+    );
+
+    mock.assert_async().await;
+  }
+}
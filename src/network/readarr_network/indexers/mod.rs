@@ -0,0 +1,92 @@
+use crate::models::servarr_models::{AddIndexerParams, Indexer};
+use crate::network::readarr_network::ReadarrEvent;
+use crate::network::utils::indexer_body_from_schema;
+use crate::network::{Network, RequestMethod};
+use anyhow::Result;
+use log::{debug, info};
+use serde_json::Value;
+
+#[cfg(test)]
+#[path = "readarr_indexers_network_tests.rs"]
+mod readarr_indexers_network_tests;
+
+impl Network<'_, '_> {
+  pub(in crate::network::readarr_network) async fn add_readarr_indexer(
+    &mut self,
+    mut add_indexer_params: AddIndexerParams,
+  ) -> Result<Value> {
+    let schema_event = ReadarrEvent::GetIndexerSchema;
+    let event = ReadarrEvent::AddIndexer(AddIndexerParams::default());
+    if let Some(tag_input_str) = add_indexer_params.tag_input_string.as_ref() {
+      let tag_ids_vec = self
+        .extract_and_add_readarr_tag_ids_vec(tag_input_str)
+        .await;
+      add_indexer_params.tags = tag_ids_vec;
+    }
+    info!(
+      "Adding new Readarr indexer using the '{}' implementation",
+      add_indexer_params.implementation
+    );
+
+    info!("Fetching indexer schema");
+
+    let request_props = self
+      .request_props_from(schema_event, RequestMethod::Get, None::<()>, None, None)
+      .await;
+
+    let indexer_schema = self
+      .handle_request::<(), Vec<Value>>(request_props, |_, _| ())
+      .await?;
+
+    let add_indexer_body = indexer_body_from_schema(indexer_schema, &add_indexer_params)?;
+
+    debug!("Add indexer body: {add_indexer_body:?}");
+
+    info!("Testing new indexer");
+
+    let request_props = self
+      .request_props_from(
+        event.clone(),
+        RequestMethod::Post,
+        Some(add_indexer_body.clone()),
+        Some("/test".to_owned()),
+        None,
+      )
+      .await;
+
+    self
+      .handle_request::<Value, Value>(request_props, |_, _| ())
+      .await?;
+
+    info!("Saving new indexer");
+
+    let request_props = self
+      .request_props_from(
+        event,
+        RequestMethod::Post,
+        Some(add_indexer_body),
+        None,
+        None,
+      )
+      .await;
+
+    self
+      .handle_request::<Value, Value>(request_props, |_, _| ())
+      .await
+  }
+
+  pub(in crate::network::readarr_network) async fn get_readarr_indexer_schema(
+    &mut self,
+  ) -> Result<Vec<Indexer>> {
+    info!("Fetching Readarr indexer schema");
+    let event = ReadarrEvent::GetIndexerSchema;
+
+    let request_props = self
+      .request_props_from(event, RequestMethod::Get, None::<()>, None, None)
+      .await;
+
+    self
+      .handle_request::<(), Vec<Indexer>>(request_props, |_, _| ())
+      .await
+  }
+}
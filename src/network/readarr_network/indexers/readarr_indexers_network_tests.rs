@@ -0,0 +1,225 @@
+#[cfg(test)]
+mod tests {
+  use crate::models::readarr_models::ReadarrSerdeable;
+  use crate::models::servarr_models::{AddIndexerParams, Indexer};
+  use crate::network::NetworkResource;
+  use crate::network::network_tests::test_utils::{MockServarrApi, test_network};
+  use crate::network::readarr_network::ReadarrEvent;
+  use bimap::BiMap;
+  use mockito::Matcher;
+  use pretty_assertions::assert_eq;
+  use serde_json::json;
+
+  #[tokio::test]
+  async fn test_handle_add_readarr_indexer_event() {
+    let indexer_schema_json = json!([{
+        "enableRss": false,
+        "enableAutomaticSearch": false,
+        "enableInteractiveSearch": false,
+        "priority": 25,
+        "name": "",
+        "fields": [
+            {
+                "name": "baseUrl",
+                "type": "textbox",
+                "value": "",
+            },
+            {
+                "name": "minimumSeeders",
+                "type": "number",
+                "value": 1,
+            },
+        ],
+        "implementationName": "Torznab",
+        "implementation": "Torznab",
+        "tags": [],
+    }]);
+    let expected_add_indexer_body_json = json!({
+        "enableRss": true,
+        "enableAutomaticSearch": true,
+        "enableInteractiveSearch": false,
+        "priority": 1,
+        "name": "Test Indexer",
+        "fields": [
+            {
+                "name": "baseUrl",
+                "type": "textbox",
+                "value": "https://test.com",
+            },
+            {
+                "name": "minimumSeeders",
+                "type": "number",
+                "value": 5,
+            },
+        ],
+        "implementationName": "Torznab",
+        "implementation": "Torznab",
+        "tags": [1, 2],
+    });
+    let add_indexer_params = AddIndexerParams {
+      implementation: "Torznab".to_owned(),
+      name: "Test Indexer".to_owned(),
+      enable_interactive_search: false,
+      priority: 1,
+      tag_input_string: Some("usenet, testing".to_owned()),
+      fields: vec![
+        ("baseUrl".to_owned(), "https://test.com".to_owned()),
+        ("minimumSeeders".to_owned(), "5".to_owned()),
+      ],
+      ..AddIndexerParams::default()
+    };
+    let (async_schema_server, app, mut server) = MockServarrApi::get()
+      .returns(indexer_schema_json)
+      .build_for(ReadarrEvent::GetIndexerSchema)
+      .await;
+    let async_test_server = server
+      .mock(
+        "POST",
+        format!(
+          "/api/v1{}/test",
+          ReadarrEvent::AddIndexer(add_indexer_params.clone()).resource()
+        )
+        .as_str(),
+      )
+      .with_status(200)
+      .with_body("{}")
+      .match_header("X-Api-Key", "test1234")
+      .match_body(Matcher::Json(expected_add_indexer_body_json.clone()))
+      .create_async()
+      .await;
+    let async_add_server = server
+      .mock(
+        "POST",
+        format!(
+          "/api/v1{}",
+          ReadarrEvent::AddIndexer(add_indexer_params.clone()).resource()
+        )
+        .as_str(),
+      )
+      .with_status(201)
+      .match_header("X-Api-Key", "test1234")
+      .match_body(Matcher::Json(expected_add_indexer_body_json))
+      .with_body(json!({ "id": 1 }).to_string())
+      .create_async()
+      .await;
+    app.lock().await.server_tabs.set_index(3);
+    app.lock().await.data.readarr_data.tags_map =
+      BiMap::from_iter([(1, "usenet".to_owned()), (2, "testing".to_owned())]);
+    let mut network = test_network(&app);
+
+    let ReadarrSerdeable::Value(value) = network
+      .handle_readarr_event(ReadarrEvent::AddIndexer(add_indexer_params))
+      .await
+      .unwrap()
+    else {
+      panic!("Expected Value")
+    };
+    async_schema_server.assert_async().await;
+    async_test_server.assert_async().await;
+    async_add_server.assert_async().await;
+    assert_eq!(value, json!({ "id": 1 }));
+  }
+
+  #[tokio::test]
+  async fn test_handle_add_readarr_indexer_event_does_not_save_indexer_when_test_fails() {
+    let indexer_schema_json = json!([{
+        "name": "",
+        "fields": [],
+        "implementationName": "Torznab",
+        "implementation": "Torznab",
+        "tags": [],
+    }]);
+    let add_indexer_params = AddIndexerParams {
+      implementation: "Torznab".to_owned(),
+      name: "Test Indexer".to_owned(),
+      ..AddIndexerParams::default()
+    };
+    let (async_schema_server, app, mut server) = MockServarrApi::get()
+      .returns(indexer_schema_json)
+      .build_for(ReadarrEvent::GetIndexerSchema)
+      .await;
+    let async_test_server = server
+      .mock(
+        "POST",
+        format!(
+          "/api/v1{}/test",
+          ReadarrEvent::AddIndexer(add_indexer_params.clone()).resource()
+        )
+        .as_str(),
+      )
+      .with_status(400)
+      .with_body(json!([{ "propertyName": "", "errorMessage": "test failure" }]).to_string())
+      .create_async()
+      .await;
+    let async_add_server = server
+      .mock(
+        "POST",
+        format!(
+          "/api/v1{}",
+          ReadarrEvent::AddIndexer(add_indexer_params.clone()).resource()
+        )
+        .as_str(),
+      )
+      .expect(0)
+      .create_async()
+      .await;
+    app.lock().await.server_tabs.set_index(3);
+    let mut network = test_network(&app);
+
+    assert!(
+      network
+        .handle_readarr_event(ReadarrEvent::AddIndexer(add_indexer_params))
+        .await
+        .is_err()
+    );
+
+    async_schema_server.assert_async().await;
+    async_test_server.assert_async().await;
+    async_add_server.assert_async().await;
+  }
+
+  #[tokio::test]
+  async fn test_handle_get_readarr_indexer_schema_event() {
+    let indexer_schema_json = json!([{
+        "enableRss": false,
+        "enableAutomaticSearch": false,
+        "enableInteractiveSearch": false,
+        "supportsRss": true,
+        "supportsSearch": true,
+        "protocol": "torrent",
+        "priority": 25,
+        "downloadClientId": 0,
+        "name": "",
+        "fields": [
+            {
+                "name": "baseUrl",
+                "label": "URL",
+                "type": "textbox",
+                "value": "",
+            },
+        ],
+        "implementationName": "Torznab",
+        "implementation": "Torznab",
+        "configContract": "TorznabSettings",
+        "tags": [],
+        "id": 0
+    }]);
+    let response: Vec<Indexer> = serde_json::from_value(indexer_schema_json.clone()).unwrap();
+    let (async_server, app, _server) = MockServarrApi::get()
+      .returns(indexer_schema_json)
+      .build_for(ReadarrEvent::GetIndexerSchema)
+      .await;
+    app.lock().await.server_tabs.set_index(3);
+    let mut network = test_network(&app);
+
+    let ReadarrSerdeable::Indexers(indexer_schema) = network
+      .handle_readarr_event(ReadarrEvent::GetIndexerSchema)
+      .await
+      .unwrap()
+    else {
+      panic!("Expected Indexers")
+    };
+    async_server.assert_async().await;
+    assert_eq!(indexer_schema, response);
+  }
+}
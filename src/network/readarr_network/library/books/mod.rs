@@ -0,0 +1,162 @@
+use crate::models::readarr_models::{Book, Edition, ReadarrCommandBody};
+use crate::network::readarr_network::ReadarrEvent;
+use crate::network::{Network, RequestMethod};
+use anyhow::Result;
+use log::{debug, info};
+use serde_json::{Value, json};
+
+#[cfg(test)]
+#[path = "readarr_books_network_tests.rs"]
+mod readarr_books_network_tests;
+
+impl Network<'_, '_> {
+  pub(in crate::network::readarr_network) async fn get_books(
+    &mut self,
+    author_id: i64,
+  ) -> Result<Vec<Book>> {
+    info!("Fetching books for Readarr author with ID: {author_id}");
+    let event = ReadarrEvent::GetBooks(author_id);
+
+    let request_props = self
+      .request_props_from(
+        event,
+        RequestMethod::Get,
+        None::<()>,
+        None,
+        Some(format!("authorId={author_id}")),
+      )
+      .await;
+
+    self
+      .handle_request::<(), Vec<Book>>(request_props, |mut books_vec, mut app| {
+        books_vec.sort_by_key(|a| a.id);
+        app.data.readarr_data.books.set_items(books_vec);
+      })
+      .await
+  }
+
+  pub(in crate::network::readarr_network) async fn get_book_details(
+    &mut self,
+    book_id: i64,
+  ) -> Result<Book> {
+    info!("Fetching details for Readarr book with ID: {book_id}");
+    let event = ReadarrEvent::GetBookDetails(book_id);
+
+    let request_props = self
+      .request_props_from(
+        event,
+        RequestMethod::Get,
+        None::<()>,
+        Some(format!("/{book_id}")),
+        None,
+      )
+      .await;
+
+    self
+      .handle_request::<(), Book>(request_props, |_, _| ())
+      .await
+  }
+
+  pub(in crate::network::readarr_network) async fn toggle_book_monitoring(
+    &mut self,
+    book_id: i64,
+  ) -> Result<()> {
+    let event = ReadarrEvent::ToggleBookMonitoring(book_id);
+    info!("Toggling book monitoring for book with ID: {book_id}");
+    info!("Fetching book details for book with ID: {book_id}");
+
+    let detail_event = ReadarrEvent::GetBooks(0);
+    let request_props = self
+      .request_props_from(
+        detail_event,
+        RequestMethod::Get,
+        None::<()>,
+        Some(format!("/{book_id}")),
+        None,
+      )
+      .await;
+
+    let mut detailed_book_body = self
+      .handle_request::<(), Value>(request_props, |_, _| ())
+      .await?;
+
+    info!("Constructing toggle book monitoring body");
+
+    let monitored = detailed_book_body
+      .get("monitored")
+      .unwrap()
+      .as_bool()
+      .unwrap();
+
+    *detailed_book_body.get_mut("monitored").unwrap() = json!(!monitored);
+
+    debug!("Toggle book monitoring body: {detailed_book_body:?}");
+
+    let request_props = self
+      .request_props_from(
+        event,
+        RequestMethod::Put,
+        Some(detailed_book_body),
+        Some(format!("/{book_id}")),
+        None,
+      )
+      .await;
+
+    self
+      .handle_request::<Value, ()>(request_props, |_, _| ())
+      .await
+  }
+
+  pub(in crate::network::readarr_network) async fn trigger_automatic_book_search(
+    &mut self,
+    book_id: i64,
+  ) -> Result<Value> {
+    let event = ReadarrEvent::TriggerAutomaticBookSearch(book_id);
+    info!("Searching indexers for book with ID: {book_id}");
+
+    let body = ReadarrCommandBody {
+      name: "BookSearch".to_owned(),
+      book_ids: Some(vec![book_id]),
+      ..ReadarrCommandBody::default()
+    };
+
+    let request_props = self
+      .request_props_from(event, RequestMethod::Post, Some(body), None, None)
+      .await;
+
+    self
+      .handle_request::<ReadarrCommandBody, Value>(request_props, |_, _| ())
+      .await
+  }
+
+  pub(in crate::network::readarr_network) async fn get_editions(
+    &mut self,
+    book_id: i64,
+  ) -> Result<Vec<Edition>> {
+    info!("Fetching editions for Readarr book with ID: {book_id}");
+    let event = ReadarrEvent::GetEditions(book_id);
+
+    let request_props = self
+      .request_props_from(
+        event,
+        RequestMethod::Get,
+        None::<()>,
+        None,
+        Some(format!("bookId={book_id}")),
+      )
+      .await;
+
+    self
+      .handle_request::<(), Vec<Edition>>(request_props, |mut editions_vec, mut app| {
+        editions_vec.sort_by_key(|e| e.id);
+        app
+          .data
+          .readarr_data
+          .book_details_modal
+          .get_or_insert_default()
+          .editions
+          .set_items(editions_vec);
+      })
+      .await
+  }
+}
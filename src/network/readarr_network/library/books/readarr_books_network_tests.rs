@@ -0,0 +1,159 @@
+#[cfg(test)]
+mod tests {
+  use crate::models::readarr_models::{Book, Edition, ReadarrSerdeable};
+  use crate::network::network_tests::test_utils::{MockServarrApi, test_network};
+  use crate::network::readarr_network::ReadarrEvent;
+  use crate::network::readarr_network::readarr_network_test_utils::test_utils::{
+    BOOK_JSON, EDITION_JSON,
+  };
+  use mockito::Matcher;
+  use pretty_assertions::assert_eq;
+  use serde_json::{Value, json};
+
+  #[tokio::test]
+  async fn test_handle_get_books_event() {
+    let books_json = json!([{
+      "id": 1,
+      "title": "Test Book",
+      "authorId": 1,
+      "foreignBookId": "test-foreign-book-id",
+      "monitored": true,
+      "genres": ["fantasy"],
+      "releaseDate": "2023-01-01T00:00:00Z"
+    }]);
+    let response: Vec<Book> = serde_json::from_value(books_json.clone()).unwrap();
+    let (mock, app, _server) = MockServarrApi::get()
+      .returns(books_json)
+      .query("authorId=1")
+      .build_for(ReadarrEvent::GetBooks(1))
+      .await;
+    app.lock().await.server_tabs.set_index(3);
+    let mut network = test_network(&app);
+
+    let result = network
+      .handle_readarr_event(ReadarrEvent::GetBooks(1))
+      .await;
+
+    mock.assert_async().await;
+
+    let ReadarrSerdeable::Books(books) = result.unwrap() else {
+      panic!("Expected Books");
+    };
+
+    assert_eq!(books, response);
+    assert!(!app.lock().await.data.readarr_data.books.is_empty());
+  }
+
+  #[tokio::test]
+  async fn test_handle_toggle_book_monitoring_event() {
+    let mut expected_body: Value = serde_json::from_str(BOOK_JSON).unwrap();
+    *expected_body.get_mut("monitored").unwrap() = json!(false);
+    let (get_mock, app, mut server) = MockServarrApi::get()
+      .returns(serde_json::from_str(BOOK_JSON).unwrap())
+      .path("/1")
+      .build_for(ReadarrEvent::GetBooks(1))
+      .await;
+    let put_mock = server
+      .mock("PUT", "/api/v1/book/1")
+      .match_body(Matcher::Json(expected_body))
+      .match_header("X-Api-Key", "test1234")
+      .with_status(202)
+      .create_async()
+      .await;
+    app.lock().await.server_tabs.set_index(3);
+    let mut network = test_network(&app);
+
+    assert_ok!(
+      network
+        .handle_readarr_event(ReadarrEvent::ToggleBookMonitoring(1))
+        .await
+    );
+
+    get_mock.assert_async().await;
+    put_mock.assert_async().await;
+  }
+
+  #[tokio::test]
+  async fn test_handle_get_book_details_event() {
+    let expected_book: Book = serde_json::from_str(BOOK_JSON).unwrap();
+    let (mock, app, _server) = MockServarrApi::get()
+      .returns(serde_json::from_str(BOOK_JSON).unwrap())
+      .path("/1")
+      .build_for(ReadarrEvent::GetBookDetails(1))
+      .await;
+    app.lock().await.server_tabs.set_index(3);
+    let mut network = test_network(&app);
+
+    let result = network
+      .handle_readarr_event(ReadarrEvent::GetBookDetails(1))
+      .await;
+
+    mock.assert_async().await;
+
+    let ReadarrSerdeable::Book(book) = result.unwrap() else {
+      panic!("Expected Book");
+    };
+
+    assert_eq!(book, expected_book);
+  }
+
+  #[tokio::test]
+  async fn test_handle_trigger_automatic_book_search_event() {
+    let (mock, app, _server) = MockServarrApi::post()
+      .with_request_body(json!({
+        "name": "BookSearch",
+        "bookIds": [1]
+      }))
+      .returns(json!({}))
+      .build_for(ReadarrEvent::TriggerAutomaticBookSearch(1))
+      .await;
+    app.lock().await.server_tabs.set_index(3);
+    let mut network = test_network(&app);
+
+    assert!(
+      network
+        .handle_readarr_event(ReadarrEvent::TriggerAutomaticBookSearch(1))
+        .await
+        .is_ok()
+    );
+
+    mock.assert_async().await;
+  }
+
+  #[tokio::test]
+  async fn test_handle_get_editions_event() {
+    let editions_json = json!([serde_json::from_str::<Value>(EDITION_JSON).unwrap()]);
+    let response: Vec<Edition> = serde_json::from_value(editions_json.clone()).unwrap();
+    let (mock, app, _server) = MockServarrApi::get()
+      .returns(editions_json)
+      .query("bookId=1")
+      .build_for(ReadarrEvent::GetEditions(1))
+      .await;
+    app.lock().await.server_tabs.set_index(3);
+    let mut network = test_network(&app);
+
+    let result = network
+      .handle_readarr_event(ReadarrEvent::GetEditions(1))
+      .await;
+
+    mock.assert_async().await;
+
+    let ReadarrSerdeable::Editions(editions) = result.unwrap() else {
+      panic!("Expected Editions");
+    };
+
+    assert_eq!(editions, response);
+    assert!(
+      !app
+        .lock()
+        .await
+        .data
+        .readarr_data
+        .book_details_modal
+        .as_ref()
+        .unwrap()
+        .editions
+        .is_empty()
+    );
+  }
+}
@@ -0,0 +1,2 @@
+mod authors;
+mod books;
@@ -0,0 +1,405 @@
+use anyhow::Result;
+use log::info;
+
+use super::{NetworkEvent, NetworkResource};
+use crate::models::readarr_models::{
+  AddAuthorBody, DeleteParams, EditAuthorParams, MetadataProfile, ReadarrSerdeable, ReadarrTaskName,
+};
+use crate::models::servarr_models::{AddIndexerParams, QualityProfile, RootFolder, Tag};
+use crate::network::{Network, RequestMethod};
+
+mod indexers;
+mod library;
+mod system;
+
+#[cfg(test)]
+#[path = "readarr_network_tests.rs"]
+mod readarr_network_tests;
+
+#[cfg(test)]
+#[path = "readarr_network_test_utils.rs"]
+pub mod readarr_network_test_utils;
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum ReadarrEvent {
+  AddAuthor(AddAuthorBody),
+  AddIndexer(AddIndexerParams),
+  AddTag(String),
+  DeleteAuthor(DeleteParams),
+  DeleteTag(i64),
+  EditAuthor(EditAuthorParams),
+  GetAuthorDetails(i64),
+  GetBookDetails(i64),
+  GetBooks(i64),
+  GetDiskSpace,
+  GetEditions(i64),
+  GetHostConfig,
+  GetIndexerSchema,
+  GetLogs(u64),
+  GetMetadataProfiles,
+  GetQualityProfiles,
+  GetQueuedEvents,
+  GetRootFolders,
+  GetSecurityConfig,
+  GetStatus,
+  GetTags,
+  GetTasks,
+  GetUpdates,
+  HealthCheck,
+  ListAuthors,
+  SearchNewAuthor(String),
+  StartTask(ReadarrTaskName),
+  ToggleAuthorMonitoring(i64),
+  ToggleBookMonitoring(i64),
+  TriggerAutomaticAuthorSearch(i64),
+  TriggerAutomaticBookSearch(i64),
+  UpdateAllAuthors,
+  UpdateAndScanAuthor(i64),
+}
+
+impl NetworkResource for ReadarrEvent {
+  fn resource(&self) -> &'static str {
+    match &self {
+      ReadarrEvent::AddTag(_) | ReadarrEvent::DeleteTag(_) | ReadarrEvent::GetTags => "/tag",
+      ReadarrEvent::AddAuthor(_)
+      | ReadarrEvent::DeleteAuthor(_)
+      | ReadarrEvent::EditAuthor(_)
+      | ReadarrEvent::GetAuthorDetails(_)
+      | ReadarrEvent::ListAuthors
+      | ReadarrEvent::ToggleAuthorMonitoring(_) => "/author",
+      ReadarrEvent::GetBooks(_)
+      | ReadarrEvent::GetBookDetails(_)
+      | ReadarrEvent::ToggleBookMonitoring(_) => "/book",
+      ReadarrEvent::GetEditions(_) => "/edition",
+      ReadarrEvent::AddIndexer(_) => "/indexer",
+      ReadarrEvent::GetIndexerSchema => "/indexer/schema",
+      ReadarrEvent::GetLogs(_) => "/log",
+      ReadarrEvent::GetDiskSpace => "/diskspace",
+      ReadarrEvent::GetHostConfig | ReadarrEvent::GetSecurityConfig => "/config/host",
+      ReadarrEvent::TriggerAutomaticAuthorSearch(_)
+      | ReadarrEvent::TriggerAutomaticBookSearch(_)
+      | ReadarrEvent::UpdateAllAuthors
+      | ReadarrEvent::UpdateAndScanAuthor(_)
+      | ReadarrEvent::GetQueuedEvents
+      | ReadarrEvent::StartTask(_) => "/command",
+      ReadarrEvent::GetMetadataProfiles => "/metadataprofile",
+      ReadarrEvent::GetQualityProfiles => "/qualityprofile",
+      ReadarrEvent::GetRootFolders => "/rootfolder",
+      ReadarrEvent::GetStatus => "/system/status",
+      ReadarrEvent::GetTasks => "/system/task",
+      ReadarrEvent::GetUpdates => "/update",
+      ReadarrEvent::HealthCheck => "/health",
+      ReadarrEvent::SearchNewAuthor(_) => "/author/lookup",
+    }
+  }
+
+  fn is_read_only(&self) -> bool {
+    matches!(
+      self,
+      ReadarrEvent::GetAuthorDetails(..)
+        | ReadarrEvent::GetBookDetails(..)
+        | ReadarrEvent::GetBooks(..)
+        | ReadarrEvent::GetDiskSpace
+        | ReadarrEvent::GetEditions(..)
+        | ReadarrEvent::GetHostConfig
+        | ReadarrEvent::GetIndexerSchema
+        | ReadarrEvent::GetLogs(..)
+        | ReadarrEvent::GetMetadataProfiles
+        | ReadarrEvent::GetQualityProfiles
+        | ReadarrEvent::GetQueuedEvents
+        | ReadarrEvent::GetRootFolders
+        | ReadarrEvent::GetSecurityConfig
+        | ReadarrEvent::GetStatus
+        | ReadarrEvent::GetTags
+        | ReadarrEvent::GetTasks
+        | ReadarrEvent::GetUpdates
+        | ReadarrEvent::HealthCheck
+        | ReadarrEvent::ListAuthors
+        | ReadarrEvent::SearchNewAuthor(..)
+    )
+  }
+}
+
+impl From<ReadarrEvent> for NetworkEvent {
+  fn from(readarr_event: ReadarrEvent) -> Self {
+    NetworkEvent::Readarr(readarr_event)
+  }
+}
+
+impl Network<'_, '_> {
+  pub async fn handle_readarr_event(
+    &mut self,
+    readarr_event: ReadarrEvent,
+  ) -> Result<ReadarrSerdeable> {
+    match readarr_event {
+      ReadarrEvent::AddAuthor(body) => self.add_author(body).await.map(ReadarrSerdeable::from),
+      ReadarrEvent::AddIndexer(params) => self
+        .add_readarr_indexer(params)
+        .await
+        .map(ReadarrSerdeable::from),
+      ReadarrEvent::AddTag(tag) => self.add_readarr_tag(tag).await.map(ReadarrSerdeable::from),
+      ReadarrEvent::DeleteAuthor(params) => {
+        self.delete_author(params).await.map(ReadarrSerdeable::from)
+      }
+      ReadarrEvent::DeleteTag(tag_id) => self
+        .delete_readarr_tag(tag_id)
+        .await
+        .map(ReadarrSerdeable::from),
+      ReadarrEvent::EditAuthor(params) => {
+        self.edit_author(params).await.map(ReadarrSerdeable::from)
+      }
+      ReadarrEvent::GetAuthorDetails(author_id) => self
+        .get_author_details(author_id)
+        .await
+        .map(ReadarrSerdeable::from),
+      ReadarrEvent::GetBookDetails(book_id) => self
+        .get_book_details(book_id)
+        .await
+        .map(ReadarrSerdeable::from),
+      ReadarrEvent::GetBooks(author_id) => {
+        self.get_books(author_id).await.map(ReadarrSerdeable::from)
+      }
+      ReadarrEvent::GetDiskSpace => self
+        .get_readarr_diskspace()
+        .await
+        .map(ReadarrSerdeable::from),
+      ReadarrEvent::GetEditions(book_id) => {
+        self.get_editions(book_id).await.map(ReadarrSerdeable::from)
+      }
+      ReadarrEvent::GetHostConfig => self
+        .get_readarr_host_config()
+        .await
+        .map(ReadarrSerdeable::from),
+      ReadarrEvent::GetIndexerSchema => self
+        .get_readarr_indexer_schema()
+        .await
+        .map(ReadarrSerdeable::from),
+      ReadarrEvent::GetLogs(events) => self
+        .get_readarr_logs(events)
+        .await
+        .map(ReadarrSerdeable::from),
+      ReadarrEvent::GetMetadataProfiles => self
+        .get_readarr_metadata_profiles()
+        .await
+        .map(ReadarrSerdeable::from),
+      ReadarrEvent::GetQualityProfiles => self
+        .get_readarr_quality_profiles()
+        .await
+        .map(ReadarrSerdeable::from),
+      ReadarrEvent::GetQueuedEvents => self
+        .get_queued_readarr_events()
+        .await
+        .map(ReadarrSerdeable::from),
+      ReadarrEvent::GetRootFolders => self
+        .get_readarr_root_folders()
+        .await
+        .map(ReadarrSerdeable::from),
+      ReadarrEvent::GetSecurityConfig => self
+        .get_readarr_security_config()
+        .await
+        .map(ReadarrSerdeable::from),
+      ReadarrEvent::GetStatus => self.get_readarr_status().await.map(ReadarrSerdeable::from),
+      ReadarrEvent::GetTags => self.get_readarr_tags().await.map(ReadarrSerdeable::from),
+      ReadarrEvent::GetTasks => self.get_readarr_tasks().await.map(ReadarrSerdeable::from),
+      ReadarrEvent::GetUpdates => self.get_readarr_updates().await.map(ReadarrSerdeable::from),
+      ReadarrEvent::HealthCheck => self
+        .get_readarr_healthcheck()
+        .await
+        .map(ReadarrSerdeable::from),
+      ReadarrEvent::ListAuthors => self.list_authors().await.map(ReadarrSerdeable::from),
+      ReadarrEvent::SearchNewAuthor(query) => {
+        self.search_author(query).await.map(ReadarrSerdeable::from)
+      }
+      ReadarrEvent::StartTask(task_name) => self
+        .start_readarr_task(task_name)
+        .await
+        .map(ReadarrSerdeable::from),
+      ReadarrEvent::ToggleAuthorMonitoring(author_id) => self
+        .toggle_author_monitoring(author_id)
+        .await
+        .map(ReadarrSerdeable::from),
+      ReadarrEvent::ToggleBookMonitoring(book_id) => self
+        .toggle_book_monitoring(book_id)
+        .await
+        .map(ReadarrSerdeable::from),
+      ReadarrEvent::TriggerAutomaticAuthorSearch(author_id) => self
+        .trigger_automatic_author_search(author_id)
+        .await
+        .map(ReadarrSerdeable::from),
+      ReadarrEvent::TriggerAutomaticBookSearch(book_id) => self
+        .trigger_automatic_book_search(book_id)
+        .await
+        .map(ReadarrSerdeable::from),
+      ReadarrEvent::UpdateAllAuthors => self.update_all_authors().await.map(ReadarrSerdeable::from),
+      ReadarrEvent::UpdateAndScanAuthor(author_id) => self
+        .update_and_scan_author(author_id)
+        .await
+        .map(ReadarrSerdeable::from),
+    }
+  }
+
+  pub(in crate::network::readarr_network) async fn get_readarr_healthcheck(
+    &mut self,
+  ) -> Result<()> {
+    info!("Performing Readarr health check");
+    let event = ReadarrEvent::HealthCheck;
+
+    let request_props = self
+      .request_props_from(event, RequestMethod::Get, None::<()>, None, None)
+      .await;
+
+    self
+      .handle_request::<(), ()>(request_props, |_, _| ())
+      .await
+  }
+
+  async fn get_readarr_metadata_profiles(&mut self) -> Result<Vec<MetadataProfile>> {
+    info!("Fetching Readarr metadata profiles");
+    let event = ReadarrEvent::GetMetadataProfiles;
+
+    let request_props = self
+      .request_props_from(event, RequestMethod::Get, None::<()>, None, None)
+      .await;
+
+    self
+      .handle_request::<(), Vec<MetadataProfile>>(request_props, |metadata_profiles, mut app| {
+        app.data.readarr_data.metadata_profile_map = metadata_profiles
+          .into_iter()
+          .map(|profile| (profile.id, profile.name))
+          .collect();
+      })
+      .await
+  }
+
+  async fn get_readarr_quality_profiles(&mut self) -> Result<Vec<QualityProfile>> {
+    info!("Fetching Readarr quality profiles");
+    let event = ReadarrEvent::GetQualityProfiles;
+
+    let request_props = self
+      .request_props_from(event, RequestMethod::Get, None::<()>, None, None)
+      .await;
+
+    self
+      .handle_request::<(), Vec<QualityProfile>>(request_props, |quality_profiles, mut app| {
+        app.data.readarr_data.quality_profile_map = quality_profiles
+          .into_iter()
+          .map(|profile| (profile.id, profile.name))
+          .collect();
+      })
+      .await
+  }
+
+  async fn get_readarr_root_folders(&mut self) -> Result<Vec<RootFolder>> {
+    info!("Fetching Readarr root folders");
+    let event = ReadarrEvent::GetRootFolders;
+
+    let request_props = self
+      .request_props_from(event, RequestMethod::Get, None::<()>, None, None)
+      .await;
+
+    self
+      .handle_request::<(), Vec<RootFolder>>(request_props, |root_folders, mut app| {
+        app.data.readarr_data.root_folders.set_items(root_folders);
+      })
+      .await
+  }
+
+  async fn get_readarr_tags(&mut self) -> Result<Vec<Tag>> {
+    info!("Fetching Readarr tags");
+    let event = ReadarrEvent::GetTags;
+
+    let request_props = self
+      .request_props_from(event, RequestMethod::Get, None::<()>, None, None)
+      .await;
+
+    self
+      .handle_request::<(), Vec<Tag>>(request_props, |tags_vec, mut app| {
+        app.data.readarr_data.tags_map = tags_vec
+          .into_iter()
+          .map(|tag| (tag.id, tag.label))
+          .collect();
+      })
+      .await
+  }
+
+  async fn add_readarr_tag(&mut self, tag: String) -> Result<Tag> {
+    info!("Adding a new Readarr tag");
+    let event = ReadarrEvent::AddTag(String::new());
+
+    let request_props = self
+      .request_props_from(
+        event,
+        RequestMethod::Post,
+        Some(serde_json::json!({ "label": tag })),
+        None,
+        None,
+      )
+      .await;
+
+    self
+      .handle_request::<serde_json::Value, Tag>(request_props, |tag, mut app| {
+        app.data.readarr_data.tags_map.insert(tag.id, tag.label);
+      })
+      .await
+  }
+
+  async fn delete_readarr_tag(&mut self, id: i64) -> Result<()> {
+    info!("Deleting Readarr tag with ID: {id}");
+    let event = ReadarrEvent::DeleteTag(id);
+
+    let request_props = self
+      .request_props_from(
+        event,
+        RequestMethod::Delete,
+        None::<()>,
+        Some(format!("/{id}")),
+        None,
+      )
+      .await;
+
+    self
+      .handle_request::<(), ()>(request_props, |_, _| ())
+      .await
+  }
+
+  pub(in crate::network::readarr_network) async fn extract_and_add_readarr_tag_ids_vec(
+    &mut self,
+    edit_tags: &str,
+  ) -> Vec<i64> {
+    let missing_tags_vec = {
+      let tags_map = &self.app.lock().await.data.readarr_data.tags_map;
+      edit_tags
+        .split(',')
+        .filter(|&tag| {
+          !tag.is_empty() && tags_map.get_by_right(tag.to_lowercase().trim()).is_none()
+        })
+        .collect::<Vec<&str>>()
+    };
+
+    let mut added_tags = Vec::new();
+    for tag in missing_tags_vec {
+      added_tags.push(
+        self
+          .add_readarr_tag(tag.trim().to_owned())
+          .await
+          .expect("Unable to add tag"),
+      );
+    }
+
+    let app = self.app.lock().await;
+    edit_tags
+      .split(',')
+      .filter(|tag| !tag.is_empty())
+      .map(|tag| {
+        let label = tag.to_lowercase();
+        let label = label.trim();
+        added_tags
+          .iter()
+          .find(|added_tag| added_tag.label.to_lowercase() == label)
+          .map(|added_tag| added_tag.id)
+          .or_else(|| app.data.readarr_data.tags_map.get_by_right(label).copied())
+          .unwrap()
+      })
+      .collect()
+  }
+}
@@ -0,0 +1,301 @@
+#[cfg(test)]
+#[allow(dead_code)]
+pub mod test_utils {
+  use crate::models::ScrollableText;
+  use crate::models::readarr_models::{
+    AddAuthorSearchResult, Author, AuthorStatistics, AuthorStatus, Book, BookStatistics,
+    EditAuthorParams, Edition, MetadataProfile, NewItemMonitorType, Ratings, ReadarrTask,
+    ReadarrTaskName, SystemStatus,
+  };
+  use crate::models::servarr_models::{QualityProfile, RootFolder, Tag};
+  use bimap::BiMap;
+  use chrono::DateTime;
+  use indoc::formatdoc;
+  use serde_json::Number;
+
+  pub const ADD_AUTHOR_SEARCH_RESULT_JSON: &str = r#"{
+    "foreignAuthorId": "test-foreign-id",
+    "authorName": "Test Author",
+    "status": "continuing",
+    "overview": "some interesting description of the author",
+    "disambiguation": "American novelist",
+    "genres": ["fantasy"],
+    "ratings": { "votes": 15, "value": 8.4 }
+  }"#;
+
+  pub const AUTHOR_JSON: &str = r#"{
+    "id": 1,
+    "authorName": "Test Author",
+    "foreignAuthorId": "test-foreign-id",
+    "status": "continuing",
+    "overview": "some interesting description of the author",
+    "disambiguation": "American novelist",
+    "path": "/books/test-author",
+    "qualityProfileId": 1,
+    "metadataProfileId": 1,
+    "monitored": true,
+    "monitorNewItems": "all",
+    "genres": ["fantasy"],
+    "tags": [1],
+    "added": "2023-01-01T00:00:00Z",
+    "ratings": { "votes": 15, "value": 8.4 },
+    "statistics": {
+      "bookFileCount": 5,
+      "bookCount": 5,
+      "availableBookCount": 5,
+      "totalBookCount": 5,
+      "sizeOnDisk": 12345,
+      "percentOfBooks": 99.9
+    }
+  }"#;
+
+  pub const BOOK_JSON: &str = r#"{
+    "id": 1,
+    "title": "Test Book",
+    "authorId": 1,
+    "foreignBookId": "test-foreign-book-id",
+    "monitored": true,
+    "anyEditionOk": true,
+    "pageCount": 320,
+    "seriesTitle": "Test Series",
+    "overview": "some interesting description of the book",
+    "genres": ["fantasy"],
+    "ratings": { "votes": 15, "value": 8.4 },
+    "releaseDate": "2023-01-01T00:00:00Z",
+    "statistics": {
+      "bookFileCount": 1,
+      "bookCount": 1,
+      "totalBookCount": 1,
+      "sizeOnDisk": 1024,
+      "percentOfBooks": 99.9
+    }
+  }"#;
+
+  pub const EDITION_JSON: &str = r#"{
+    "id": 1,
+    "bookId": 1,
+    "foreignEditionId": "test-foreign-edition-id",
+    "title": "Test Book",
+    "isbn13": "9780000000001",
+    "asin": "B000000001",
+    "publisher": "Test Publisher",
+    "language": "eng",
+    "pageCount": 320,
+    "format": "Hardcover",
+    "isEbook": false,
+    "monitored": true,
+    "releaseDate": "2023-01-01T00:00:00Z"
+  }"#;
+
+  pub fn ratings() -> Ratings {
+    Ratings {
+      votes: 15,
+      value: 8.4,
+    }
+  }
+
+  pub fn author_statistics() -> AuthorStatistics {
+    AuthorStatistics {
+      book_file_count: 5,
+      book_count: 5,
+      available_book_count: 5,
+      total_book_count: 5,
+      size_on_disk: 12345,
+      percent_of_books: 99.9,
+    }
+  }
+
+  pub fn author() -> Author {
+    Author {
+      id: 1,
+      author_name: "Alex".into(),
+      foreign_author_id: "test-foreign-id".to_owned(),
+      status: AuthorStatus::Continuing,
+      overview: Some("some interesting description of the author".to_owned()),
+      disambiguation: Some("American novelist".to_owned()),
+      path: "/nfs/books/test-author".to_owned(),
+      quality_profile_id: quality_profile().id,
+      metadata_profile_id: metadata_profile().id,
+      monitored: true,
+      monitor_new_items: NewItemMonitorType::All,
+      genres: vec!["fantasy".to_owned()],
+      tags: vec![Number::from(tag().id)],
+      added: DateTime::from(DateTime::parse_from_rfc3339("2023-01-01T00:00:00Z").unwrap()),
+      ratings: Some(ratings()),
+      statistics: Some(author_statistics()),
+    }
+  }
+
+  pub fn quality_profile() -> QualityProfile {
+    QualityProfile {
+      id: 1,
+      name: "eBook".to_owned(),
+      ..QualityProfile::default()
+    }
+  }
+
+  pub fn quality_profile_map() -> BiMap<i64, String> {
+    let quality_profile = quality_profile();
+    BiMap::from_iter(vec![(quality_profile.id, quality_profile.name)])
+  }
+
+  pub fn metadata_profile() -> MetadataProfile {
+    MetadataProfile {
+      id: 1,
+      name: "Standard".to_owned(),
+    }
+  }
+
+  pub fn metadata_profile_map() -> BiMap<i64, String> {
+    let metadata_profile = metadata_profile();
+    BiMap::from_iter(vec![(metadata_profile.id, metadata_profile.name)])
+  }
+
+  pub fn tag() -> Tag {
+    Tag {
+      id: 1,
+      label: "alex".to_owned(),
+    }
+  }
+
+  pub fn tags_map() -> BiMap<i64, String> {
+    let tag = tag();
+    BiMap::from_iter(vec![(tag.id, tag.label)])
+  }
+
+  pub fn system_status() -> SystemStatus {
+    SystemStatus {
+      version: "1.0".to_owned(),
+      start_time: DateTime::from(DateTime::parse_from_rfc3339("2023-01-01T00:00:00Z").unwrap()),
+    }
+  }
+
+  pub fn root_folder() -> RootFolder {
+    RootFolder {
+      id: 1,
+      path: "/nfs".to_owned(),
+      accessible: true,
+      free_space: 219902325555200,
+      unmapped_folders: None,
+    }
+  }
+
+  pub fn edit_author_params() -> EditAuthorParams {
+    EditAuthorParams {
+      author_id: author().id,
+      monitored: Some(true),
+      monitor_new_items: Some(NewItemMonitorType::All),
+      quality_profile_id: Some(quality_profile().id),
+      metadata_profile_id: Some(metadata_profile().id),
+      root_folder_path: Some("/nfs/books/test-author".to_owned()),
+      tags: Some(vec![tag().id]),
+      tag_input_string: Some("alex".to_owned()),
+      clear_tags: false,
+    }
+  }
+
+  pub fn add_author_search_result() -> AddAuthorSearchResult {
+    AddAuthorSearchResult {
+      foreign_author_id: "test-foreign-id".to_owned(),
+      author_name: "Test Author".into(),
+      status: AuthorStatus::Continuing,
+      overview: Some("some interesting description of the author".to_owned()),
+      disambiguation: Some("American novelist".to_owned()),
+      genres: vec!["fantasy".to_owned()],
+      ratings: Some(ratings()),
+    }
+  }
+
+  pub fn book_statistics() -> BookStatistics {
+    BookStatistics {
+      book_file_count: 1,
+      book_count: 1,
+      total_book_count: 1,
+      size_on_disk: 1024,
+      percent_of_books: 99.9,
+    }
+  }
+
+  pub fn book() -> Book {
+    Book {
+      id: 1,
+      title: "Test Book".into(),
+      author_id: 1,
+      foreign_book_id: "test-foreign-book-id".to_owned(),
+      monitored: true,
+      any_edition_ok: true,
+      page_count: 320,
+      series_title: Some("Test Series".to_owned()),
+      overview: Some("some interesting description of the book".to_owned()),
+      genres: vec!["fantasy".to_owned()],
+      ratings: Some(ratings()),
+      release_date: Some(DateTime::from(
+        DateTime::parse_from_rfc3339("2023-01-01T00:00:00Z").unwrap(),
+      )),
+      statistics: Some(book_statistics()),
+    }
+  }
+
+  pub fn edition() -> Edition {
+    Edition {
+      id: 1,
+      book_id: 1,
+      foreign_edition_id: "test-foreign-edition-id".to_owned(),
+      title: "Test Book".to_owned(),
+      isbn13: Some("9780000000001".to_owned()),
+      asin: Some("B000000001".to_owned()),
+      publisher: Some("Test Publisher".to_owned()),
+      language: Some("eng".to_owned()),
+      page_count: 320,
+      format: Some("Hardcover".to_owned()),
+      is_ebook: false,
+      monitored: true,
+      release_date: Some(DateTime::from(
+        DateTime::parse_from_rfc3339("2023-01-01T00:00:00Z").unwrap(),
+      )),
+    }
+  }
+
+  pub fn task() -> ReadarrTask {
+    ReadarrTask {
+      name: "Backup".to_owned(),
+      task_name: ReadarrTaskName::Backup,
+      interval: 60,
+      last_execution: DateTime::from(DateTime::parse_from_rfc3339("2023-05-20T21:29:16Z").unwrap()),
+      next_execution: DateTime::from(DateTime::parse_from_rfc3339("2023-05-20T22:29:16Z").unwrap()),
+    }
+  }
+
+  pub fn log_line() -> &'static str {
+    "2025-12-16 16:40:59 UTC|INFO|ImportListSyncService|No list items to process"
+  }
+
+  pub fn updates() -> ScrollableText {
+    let line_break = "-".repeat(200);
+    ScrollableText::with_string(formatdoc!(
+      "
+    The latest version of Readarr is already installed
+
+    4.3.2.1 - 2023-04-15 02:02:53 UTC (Currently Installed)
+    {line_break}
+    New:
+      * Cool new thing
+    Fixed:
+      * Some bugs killed
+
+
+    3.2.1.0 - 2023-04-15 02:02:53 UTC (Previously Installed)
+    {line_break}
+    New:
+      * Cool new thing (old)
+      * Other cool new thing (old)
+
+
+    2.1.0 - 2023-04-15 02:02:53 UTC
+    {line_break}
+    Fixed:
+      * Killed bug 1
+      * Fixed bug 2"
+    ))
+  }
+}
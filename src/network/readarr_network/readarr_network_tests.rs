@@ -0,0 +1,361 @@
+#[cfg(test)]
+mod tests {
+  use crate::app::App;
+  use crate::models::readarr_models::{
+    AddAuthorBody, DeleteParams, EditAuthorParams, MetadataProfile, ReadarrSerdeable,
+  };
+  use crate::models::servarr_data::readarr::modals::EditAuthorModal;
+  use crate::models::servarr_models::{AddIndexerParams, QualityProfile, RootFolder, Tag};
+  use crate::network::network_tests::test_utils::{MockServarrApi, test_network};
+  use crate::network::readarr_network::readarr_network_test_utils::test_utils::root_folder;
+  use crate::network::{NetworkEvent, NetworkResource, readarr_network::ReadarrEvent};
+  use bimap::BiMap;
+  use pretty_assertions::{assert_eq, assert_str_eq};
+  use rstest::rstest;
+  use serde_json::json;
+  use std::sync::Arc;
+  use tokio::sync::Mutex;
+
+  #[rstest]
+  fn test_resource_author(
+    #[values(
+      ReadarrEvent::GetAuthorDetails(0),
+      ReadarrEvent::ListAuthors,
+      ReadarrEvent::AddAuthor(AddAuthorBody::default()),
+      ReadarrEvent::ToggleAuthorMonitoring(0),
+      ReadarrEvent::DeleteAuthor(DeleteParams::default()),
+      ReadarrEvent::EditAuthor(EditAuthorParams::default())
+    )]
+    event: ReadarrEvent,
+  ) {
+    assert_str_eq!(event.resource(), "/author");
+  }
+
+  #[rstest]
+  fn test_resource_tag(
+    #[values(
+      ReadarrEvent::AddTag(String::new()),
+      ReadarrEvent::DeleteTag(0),
+      ReadarrEvent::GetTags
+    )]
+    event: ReadarrEvent,
+  ) {
+    assert_str_eq!(event.resource(), "/tag");
+  }
+
+  #[rstest]
+  fn test_resource_config(
+    #[values(ReadarrEvent::GetHostConfig, ReadarrEvent::GetSecurityConfig)] event: ReadarrEvent,
+  ) {
+    assert_str_eq!(event.resource(), "/config/host");
+  }
+
+  #[rstest]
+  fn test_resource_command(
+    #[values(
+      ReadarrEvent::UpdateAllAuthors,
+      ReadarrEvent::TriggerAutomaticAuthorSearch(0),
+      ReadarrEvent::TriggerAutomaticBookSearch(0),
+      ReadarrEvent::UpdateAndScanAuthor(0),
+      ReadarrEvent::GetQueuedEvents,
+      ReadarrEvent::StartTask(Default::default())
+    )]
+    event: ReadarrEvent,
+  ) {
+    assert_str_eq!(event.resource(), "/command");
+  }
+
+  #[rstest]
+  fn test_resource_book(
+    #[values(
+      ReadarrEvent::GetBooks(0),
+      ReadarrEvent::ToggleBookMonitoring(0),
+      ReadarrEvent::GetBookDetails(0)
+    )]
+    event: ReadarrEvent,
+  ) {
+    assert_str_eq!(event.resource(), "/book");
+  }
+
+  #[rstest]
+  #[case(ReadarrEvent::GetDiskSpace, "/diskspace")]
+  #[case(ReadarrEvent::GetEditions(0), "/edition")]
+  #[case(ReadarrEvent::AddIndexer(AddIndexerParams::default()), "/indexer")]
+  #[case(ReadarrEvent::GetIndexerSchema, "/indexer/schema")]
+  #[case(ReadarrEvent::GetMetadataProfiles, "/metadataprofile")]
+  #[case(ReadarrEvent::GetQualityProfiles, "/qualityprofile")]
+  #[case(ReadarrEvent::GetRootFolders, "/rootfolder")]
+  #[case(ReadarrEvent::GetStatus, "/system/status")]
+  #[case(ReadarrEvent::GetLogs(500), "/log")]
+  #[case(ReadarrEvent::GetTasks, "/system/task")]
+  #[case(ReadarrEvent::GetUpdates, "/update")]
+  #[case(ReadarrEvent::HealthCheck, "/health")]
+  #[case(ReadarrEvent::SearchNewAuthor(String::new()), "/author/lookup")]
+  fn test_resource(#[case] event: ReadarrEvent, #[case] expected_uri: &str) {
+    assert_str_eq!(event.resource(), expected_uri);
+  }
+
+  #[test]
+  fn test_from_readarr_event() {
+    assert_eq!(
+      NetworkEvent::Readarr(ReadarrEvent::HealthCheck),
+      NetworkEvent::from(ReadarrEvent::HealthCheck)
+    );
+  }
+
+  #[tokio::test]
+  async fn test_handle_get_readarr_healthcheck_event() {
+    let (mock, app, _server) = MockServarrApi::get()
+      .build_for(ReadarrEvent::HealthCheck)
+      .await;
+    app.lock().await.server_tabs.set_index(3);
+    let mut network = test_network(&app);
+
+    let _ = network
+      .handle_readarr_event(ReadarrEvent::HealthCheck)
+      .await;
+
+    mock.assert_async().await;
+  }
+
+  #[tokio::test]
+  async fn test_handle_get_metadata_profiles_event() {
+    let metadata_profiles_json = json!([{
+      "id": 1,
+      "name": "Standard"
+    }]);
+    let response: Vec<MetadataProfile> =
+      serde_json::from_value(metadata_profiles_json.clone()).unwrap();
+    let (mock, app, _server) = MockServarrApi::get()
+      .returns(metadata_profiles_json)
+      .build_for(ReadarrEvent::GetMetadataProfiles)
+      .await;
+    app.lock().await.server_tabs.set_index(3);
+    let mut network = test_network(&app);
+
+    let result = network
+      .handle_readarr_event(ReadarrEvent::GetMetadataProfiles)
+      .await;
+
+    mock.assert_async().await;
+
+    let ReadarrSerdeable::MetadataProfiles(metadata_profiles) = result.unwrap() else {
+      panic!("Expected MetadataProfiles");
+    };
+
+    assert_eq!(metadata_profiles, response);
+    assert_eq!(
+      app
+        .lock()
+        .await
+        .data
+        .readarr_data
+        .metadata_profile_map
+        .get_by_left(&1),
+      Some(&"Standard".to_owned())
+    );
+  }
+
+  #[tokio::test]
+  async fn test_handle_get_quality_profiles_event() {
+    let quality_profiles_json = json!([{
+      "id": 1,
+      "name": "Lossless"
+    }]);
+    let response: Vec<QualityProfile> =
+      serde_json::from_value(quality_profiles_json.clone()).unwrap();
+    let (mock, app, _server) = MockServarrApi::get()
+      .returns(quality_profiles_json)
+      .build_for(ReadarrEvent::GetQualityProfiles)
+      .await;
+    app.lock().await.server_tabs.set_index(3);
+    let mut network = test_network(&app);
+
+    let result = network
+      .handle_readarr_event(ReadarrEvent::GetQualityProfiles)
+      .await;
+
+    mock.assert_async().await;
+
+    let ReadarrSerdeable::QualityProfiles(quality_profiles) = result.unwrap() else {
+      panic!("Expected QualityProfiles");
+    };
+
+    assert_eq!(quality_profiles, response);
+    assert_eq!(
+      app
+        .lock()
+        .await
+        .data
+        .readarr_data
+        .quality_profile_map
+        .get_by_left(&1),
+      Some(&"Lossless".to_owned())
+    );
+  }
+
+  #[tokio::test]
+  async fn test_handle_get_readarr_root_folders_event() {
+    let root_folder_json = json!([{
+      "id": 1,
+      "path": "/nfs",
+      "accessible": true,
+      "freeSpace": 219902325555200u64
+    }]);
+    let response: Vec<RootFolder> = serde_json::from_value(root_folder_json.clone()).unwrap();
+    let (mock, app, _server) = MockServarrApi::get()
+      .returns(root_folder_json)
+      .build_for(ReadarrEvent::GetRootFolders)
+      .await;
+    app.lock().await.server_tabs.set_index(3);
+    let mut network = test_network(&app);
+
+    let result = network
+      .handle_readarr_event(ReadarrEvent::GetRootFolders)
+      .await;
+
+    mock.assert_async().await;
+
+    let ReadarrSerdeable::RootFolders(root_folders) = result.unwrap() else {
+      panic!("Expected RootFolders");
+    };
+
+    assert_eq!(root_folders, response);
+    assert_eq!(
+      app.lock().await.data.readarr_data.root_folders.items,
+      vec![root_folder()]
+    );
+  }
+
+  #[tokio::test]
+  async fn test_handle_get_tags_event() {
+    let tags_json = json!([{
+      "id": 1,
+      "label": "usenet"
+    }]);
+    let response: Vec<Tag> = serde_json::from_value(tags_json.clone()).unwrap();
+    let (mock, app, _server) = MockServarrApi::get()
+      .returns(tags_json)
+      .build_for(ReadarrEvent::GetTags)
+      .await;
+    app.lock().await.server_tabs.set_index(3);
+    let mut network = test_network(&app);
+
+    let result = network.handle_readarr_event(ReadarrEvent::GetTags).await;
+
+    mock.assert_async().await;
+
+    let ReadarrSerdeable::Tags(tags) = result.unwrap() else {
+      panic!("Expected Tags");
+    };
+
+    assert_eq!(tags, response);
+    assert_eq!(
+      app.lock().await.data.readarr_data.tags_map.get_by_left(&1),
+      Some(&"usenet".to_owned())
+    );
+  }
+
+  #[tokio::test]
+  async fn test_handle_add_readarr_tag_event() {
+    let tag_json = json!({
+      "id": 1,
+      "label": "usenet"
+    });
+    let response: Tag = serde_json::from_value(tag_json.clone()).unwrap();
+    let (mock, app, _server) = MockServarrApi::post()
+      .with_request_body(json!({ "label": "usenet" }))
+      .returns(tag_json)
+      .build_for(ReadarrEvent::AddTag("usenet".to_owned()))
+      .await;
+    app.lock().await.server_tabs.set_index(3);
+    let mut network = test_network(&app);
+
+    let result = network
+      .handle_readarr_event(ReadarrEvent::AddTag("usenet".to_owned()))
+      .await;
+
+    mock.assert_async().await;
+
+    let ReadarrSerdeable::Tag(tag) = result.unwrap() else {
+      panic!("Expected Tag");
+    };
+
+    assert_eq!(tag, response);
+    assert_eq!(
+      app.lock().await.data.readarr_data.tags_map.get_by_left(&1),
+      Some(&"usenet".to_owned())
+    );
+  }
+
+  #[tokio::test]
+  async fn test_handle_delete_readarr_tag_event() {
+    let (mock, app, _server) = MockServarrApi::delete()
+      .path("/1")
+      .build_for(ReadarrEvent::DeleteTag(1))
+      .await;
+    app.lock().await.server_tabs.set_index(3);
+    let mut network = test_network(&app);
+
+    let result = network
+      .handle_readarr_event(ReadarrEvent::DeleteTag(1))
+      .await;
+
+    mock.assert_async().await;
+    assert!(result.is_ok());
+  }
+
+  #[tokio::test]
+  async fn test_extract_and_add_readarr_tag_ids_vec() {
+    let app_arc = Arc::new(Mutex::new(App::test_default()));
+    let tags = "    test,HI ,, usenet ";
+    {
+      let mut app = app_arc.lock().await;
+      app.data.readarr_data.tags_map = BiMap::from_iter([
+        (1, "usenet".to_owned()),
+        (2, "test".to_owned()),
+        (3, "hi".to_owned()),
+      ]);
+    }
+    let mut network = test_network(&app_arc);
+
+    assert_eq!(
+      network.extract_and_add_readarr_tag_ids_vec(tags).await,
+      vec![2, 3, 1]
+    );
+  }
+
+  #[tokio::test]
+  async fn test_extract_and_add_readarr_tag_ids_vec_add_missing_tags_first() {
+    let (mock, app, _server) = MockServarrApi::post()
+      .with_request_body(json!({ "label": "TESTING" }))
+      .returns(json!({ "id": 3, "label": "testing" }))
+      .build_for(ReadarrEvent::GetTags)
+      .await;
+    let tags = "usenet, test, TESTING";
+    {
+      let mut app_guard = app.lock().await;
+      app_guard.data.readarr_data.edit_author_modal = Some(EditAuthorModal {
+        tags: tags.into(),
+        ..EditAuthorModal::default()
+      });
+      app_guard.data.readarr_data.tags_map =
+        BiMap::from_iter([(1, "usenet".to_owned()), (2, "test".to_owned())]);
+    }
+    app.lock().await.server_tabs.set_index(3);
+    let mut network = test_network(&app);
+
+    let tag_ids_vec = network.extract_and_add_readarr_tag_ids_vec(tags).await;
+
+    mock.assert_async().await;
+    assert_eq!(tag_ids_vec, vec![1, 2, 3]);
+    assert_eq!(
+      app.lock().await.data.readarr_data.tags_map,
+      BiMap::from_iter([
+        (1, "usenet".to_owned()),
+        (2, "test".to_owned()),
+        (3, "testing".to_owned())
+      ])
+    );
+  }
+}
@@ -0,0 +1,279 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+use anyhow::{Context, Result, bail};
+use reqwest::Url;
+use reqwest::header::HeaderMap;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::app::{AppConfig, ServarrConfig};
+use crate::models::Route;
+use crate::network::RequestMethod;
+
+#[cfg(test)]
+#[path = "recording_tests.rs"]
+mod recording_tests;
+
+const REDACTED: &str = "<redacted>";
+const REPLAY_API_TOKEN: &str = "replay";
+
+/// Whether the Servarr traffic is being recorded to, or replayed from, a directory of recordings
+#[derive(Clone, Debug)]
+pub enum Recording {
+  Record(Recorder),
+  Replay(Replayer),
+}
+
+/// The parts of a request that are kept alongside its response in a [RecordedExchange]
+pub struct RecordedRequest {
+  pub method: RequestMethod,
+  pub uri: String,
+  pub api_token: String,
+  pub custom_headers: HeaderMap,
+  pub body: Value,
+}
+
+/// A single request/response pair, as it's written to disk. The API key and the values of any
+/// custom headers are redacted.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordedExchange {
+  pub servarr: String,
+  pub method: RequestMethod,
+  pub path: String,
+  pub request_headers: BTreeMap<String, String>,
+  pub request_body: Value,
+  pub status: u16,
+  pub response_body: String,
+}
+
+impl RecordedExchange {
+  pub fn new(servarr: &Route, request: RecordedRequest, status: u16, response_body: &str) -> Self {
+    let RecordedRequest {
+      method,
+      uri,
+      api_token,
+      custom_headers,
+      body,
+    } = request;
+    let redact = |text: &str| {
+      if api_token.is_empty() {
+        text.to_owned()
+      } else {
+        text.replace(&api_token, REDACTED)
+      }
+    };
+    let mut request_headers: BTreeMap<String, String> = custom_headers
+      .keys()
+      .map(|name| (name.to_string(), REDACTED.to_owned()))
+      .collect();
+    request_headers.insert("X-Api-Key".to_owned(), REDACTED.to_owned());
+
+    RecordedExchange {
+      servarr: servarr_name(servarr).to_owned(),
+      method,
+      path: redact(&api_path(&uri)),
+      request_headers,
+      request_body: serde_json::from_str(&redact(&body.to_string())).unwrap_or(body),
+      status,
+      response_body: redact(response_body),
+    }
+  }
+}
+
+/// Writes every [RecordedExchange] to its own numbered file in the recording directory
+#[derive(Clone, Debug)]
+pub struct Recorder {
+  dir: PathBuf,
+  count: Arc<AtomicUsize>,
+}
+
+impl Recorder {
+  pub fn new(dir: impl Into<PathBuf>) -> Result<Self> {
+    let dir = dir.into();
+    fs::create_dir_all(&dir)
+      .with_context(|| format!("Unable to create recording directory '{}'", dir.display()))?;
+    let existing_recordings = recording_files(&dir)?.len();
+
+    Ok(Recorder {
+      dir,
+      count: Arc::new(AtomicUsize::new(existing_recordings)),
+    })
+  }
+
+  pub fn record(&mut self, exchange: &RecordedExchange) -> Result<()> {
+    let index = self.count.fetch_add(1, Ordering::SeqCst) + 1;
+    let endpoint: String = exchange
+      .path
+      .split('?')
+      .next()
+      .unwrap_or_default()
+      .chars()
+      .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+      .collect();
+    let file = self.dir.join(format!(
+      "{index:05}_{}_{}_{}.json",
+      exchange.servarr,
+      exchange.method.to_string().to_lowercase(),
+      endpoint.trim_matches('-')
+    ));
+
+    fs::write(&file, serde_json::to_string_pretty(exchange)?)
+      .with_context(|| format!("Unable to write recording '{}'", file.display()))
+  }
+}
+
+/// Serves the responses from a directory of recordings in place of the Servarrs. Requests are
+/// matched by Servarr, method, and path; when the same request was recorded more than once, the
+/// recordings are replayed in order, and the last one is repeated once they've all been used.
+/// Clones share which recordings have already been replayed.
+#[derive(Clone, Debug)]
+pub struct Replayer {
+  exchanges: Vec<RecordedExchange>,
+  replayed: Arc<Mutex<Vec<bool>>>,
+}
+
+impl Replayer {
+  pub fn load(dir: &Path) -> Result<Self> {
+    let exchanges = recording_files(dir)?
+      .iter()
+      .map(|file| {
+        let contents = fs::read_to_string(file)
+          .with_context(|| format!("Unable to read recording '{}'", file.display()))?;
+        serde_json::from_str(&contents)
+          .with_context(|| format!("Recording '{}' is invalid", file.display()))
+      })
+      .collect::<Result<Vec<RecordedExchange>>>()?;
+
+    if exchanges.is_empty() {
+      bail!("No recordings were found in '{}'", dir.display());
+    }
+
+    Ok(Replayer {
+      replayed: Arc::new(Mutex::new(vec![false; exchanges.len()])),
+      exchanges,
+    })
+  }
+
+  /// A configuration with one instance of each Servarr that has recordings. The instances are
+  /// never contacted, since every request is answered from the recordings instead.
+  pub fn config(&self) -> AppConfig {
+    let replay_instance = |servarr: &str, title: &str| {
+      self
+        .exchanges
+        .iter()
+        .any(|exchange| exchange.servarr == servarr)
+        .then(|| {
+          vec![ServarrConfig {
+            name: Some(format!("{title} (replay)")),
+            uri: Some(format!("http://{servarr}.replay")),
+            api_token: Some(REPLAY_API_TOKEN.to_owned()),
+            ..ServarrConfig::default()
+          }]
+        })
+    };
+
+    AppConfig {
+      radarr: replay_instance("radarr", "Radarr"),
+      sonarr: replay_instance("sonarr", "Sonarr"),
+      lidarr: replay_instance("lidarr", "Lidarr"),
+      prowlarr: replay_instance("prowlarr", "Prowlarr"),
+      readarr: replay_instance("readarr", "Readarr"),
+      ..AppConfig::default()
+    }
+  }
+
+  /// The recorded response to the given request. Query parameters are ignored when there's no
+  /// recording of the exact request (e.g. calendar requests made on a different day).
+  pub fn replay(
+    &self,
+    servarr: &Route,
+    method: RequestMethod,
+    uri: &str,
+  ) -> Option<&RecordedExchange> {
+    let servarr = servarr_name(servarr);
+    let path = api_path(uri);
+    let without_query = |path: &str| path.split('?').next().unwrap_or_default().to_owned();
+    let mut replayed = self
+      .replayed
+      .lock()
+      .expect("Replayed recordings mutex is poisoned");
+
+    for exact in [true, false] {
+      let candidates: Vec<usize> = self
+        .exchanges
+        .iter()
+        .enumerate()
+        .filter(|(_, exchange)| {
+          exchange.servarr == servarr
+            && exchange.method == method
+            && if exact {
+              exchange.path == path
+            } else {
+              without_query(&exchange.path) == without_query(&path)
+            }
+        })
+        .map(|(index, _)| index)
+        .collect();
+      let next = candidates
+        .iter()
+        .copied()
+        .find(|&index| !replayed[index])
+        .or(candidates.last().copied());
+
+      if let Some(index) = next {
+        replayed[index] = true;
+        return Some(&self.exchanges[index]);
+      }
+    }
+
+    None
+  }
+}
+
+fn recording_files(dir: &Path) -> Result<Vec<PathBuf>> {
+  let mut files: Vec<PathBuf> = fs::read_dir(dir)
+    .with_context(|| format!("Unable to read recording directory '{}'", dir.display()))?
+    .filter_map(|entry| Some(entry.ok()?.path()))
+    .filter(|path| {
+      path
+        .extension()
+        .is_some_and(|extension| extension == "json")
+    })
+    .collect();
+  files.sort();
+
+  Ok(files)
+}
+
+/// The path and query of the request, starting from the `/api/` segment so recordings don't
+/// depend on the host or URL base of the Servarr they were made against
+fn api_path(uri: &str) -> String {
+  let path = Url::parse(uri).map_or_else(
+    |_| uri.to_owned(),
+    |url| match url.query() {
+      Some(query) => format!("{}?{query}", url.path()),
+      None => url.path().to_owned(),
+    },
+  );
+
+  match path.find("/api/") {
+    Some(index) => path[index..].to_owned(),
+    None => path,
+  }
+}
+
+fn servarr_name(route: &Route) -> &'static str {
+  match route {
+    Route::Radarr(_, _) => "radarr",
+    Route::Sonarr(_, _) => "sonarr",
+    Route::Lidarr(_, _) => "lidarr",
+    Route::Prowlarr(_, _) => "prowlarr",
+    Route::Readarr(_, _) => "readarr",
+    _ => "unknown",
+  }
+}
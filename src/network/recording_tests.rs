@@ -0,0 +1,268 @@
+#[cfg(test)]
+mod tests {
+  use std::collections::BTreeMap;
+  use std::fs;
+  use std::path::PathBuf;
+  use std::sync::Arc;
+
+  use pretty_assertions::{assert_eq, assert_str_eq};
+  use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+  use serde_json::json;
+  use tokio::sync::Mutex;
+
+  use crate::app::App;
+  use crate::models::Route;
+  use crate::models::servarr_data::radarr::radarr_data::ActiveRadarrBlock;
+  use crate::models::servarr_data::sonarr::sonarr_data::ActiveSonarrBlock;
+  use crate::network::network_tests::test_utils::{MockServarrApi, test_network};
+  use crate::network::radarr_network::RadarrEvent;
+  use crate::network::recording::{
+    RecordedExchange, RecordedRequest, Recorder, Recording, Replayer,
+  };
+  use crate::network::{NetworkTrait, RequestMethod};
+
+  fn recording_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("managarr_test_recording_{name}"));
+    let _ = fs::remove_dir_all(&dir);
+
+    dir
+  }
+
+  fn radarr_route() -> Route {
+    ActiveRadarrBlock::Movies.into()
+  }
+
+  fn exchange(method: RequestMethod, path: &str, response_body: &str) -> RecordedExchange {
+    RecordedExchange {
+      servarr: "radarr".to_owned(),
+      method,
+      path: path.to_owned(),
+      request_headers: BTreeMap::new(),
+      request_body: json!(null),
+      status: 200,
+      response_body: response_body.to_owned(),
+    }
+  }
+
+  fn replayer_with(name: &str, exchanges: Vec<RecordedExchange>) -> Replayer {
+    let dir = recording_dir(name);
+    let mut recorder = Recorder::new(&dir).unwrap();
+    for exchange in &exchanges {
+      recorder.record(exchange).unwrap();
+    }
+
+    Replayer::load(&dir).unwrap()
+  }
+
+  #[test]
+  fn test_recorded_exchange_redacts_api_token_and_custom_headers() {
+    let mut custom_headers = HeaderMap::new();
+    custom_headers.insert(
+      HeaderName::from_static("authorization"),
+      HeaderValue::from_static("Bearer secret"),
+    );
+    let request = RecordedRequest {
+      method: RequestMethod::Post,
+      uri: "https://htpc.local/radarr/api/v3/command?apikey=test1234".to_owned(),
+      api_token: "test1234".to_owned(),
+      custom_headers,
+      body: json!({ "name": "RssSync", "apiKey": "test1234" }),
+    };
+
+    let exchange =
+      RecordedExchange::new(&radarr_route(), request, 201, "{\"apiKey\":\"test1234\"}");
+
+    assert_eq!(
+      exchange,
+      RecordedExchange {
+        servarr: "radarr".to_owned(),
+        method: RequestMethod::Post,
+        path: "/api/v3/command?apikey=<redacted>".to_owned(),
+        request_headers: BTreeMap::from([
+          ("X-Api-Key".to_owned(), "<redacted>".to_owned()),
+          ("authorization".to_owned(), "<redacted>".to_owned()),
+        ]),
+        request_body: json!({ "name": "RssSync", "apiKey": "<redacted>" }),
+        status: 201,
+        response_body: "{\"apiKey\":\"<redacted>\"}".to_owned(),
+      }
+    );
+  }
+
+  #[test]
+  fn test_recorder_writes_numbered_recordings() {
+    let dir = recording_dir("numbered");
+    let mut recorder = Recorder::new(&dir).unwrap();
+
+    recorder
+      .record(&exchange(RequestMethod::Get, "/api/v3/movie?page=1", "[]"))
+      .unwrap();
+    Recorder::new(&dir)
+      .unwrap()
+      .record(&exchange(RequestMethod::Delete, "/api/v3/movie/1", ""))
+      .unwrap();
+
+    let mut files: Vec<String> = fs::read_dir(&dir)
+      .unwrap()
+      .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+      .collect();
+    files.sort();
+    assert_eq!(
+      files,
+      vec![
+        "00001_radarr_get_api-v3-movie.json",
+        "00002_radarr_delete_api-v3-movie-1.json",
+      ]
+    );
+  }
+
+  #[test]
+  fn test_replayer_replays_recordings_in_order() {
+    let replayer = replayer_with(
+      "in_order",
+      vec![
+        exchange(RequestMethod::Get, "/api/v3/movie", "[]"),
+        exchange(RequestMethod::Get, "/api/v3/movie", "[{\"id\":1}]"),
+      ],
+    );
+    let uri = "http://radarr.replay/api/v3/movie";
+
+    let replay = || {
+      replayer
+        .replay(&radarr_route(), RequestMethod::Get, uri)
+        .unwrap()
+        .response_body
+        .clone()
+    };
+
+    assert_str_eq!(replay(), "[]");
+    assert_str_eq!(replay(), "[{\"id\":1}]");
+    assert_str_eq!(replay(), "[{\"id\":1}]");
+  }
+
+  #[test]
+  fn test_replayer_falls_back_to_ignoring_query_parameters() {
+    let replayer = replayer_with(
+      "query",
+      vec![exchange(
+        RequestMethod::Get,
+        "/api/v3/calendar?start=2025-06-01&end=2025-06-30",
+        "[]",
+      )],
+    );
+
+    assert!(
+      replayer
+        .replay(
+          &radarr_route(),
+          RequestMethod::Get,
+          "http://radarr.replay/api/v3/calendar?start=2026-01-01&end=2026-01-31",
+        )
+        .is_some()
+    );
+  }
+
+  #[test]
+  fn test_replayer_only_matches_the_same_servarr_and_method() {
+    let replayer = replayer_with(
+      "mismatch",
+      vec![exchange(RequestMethod::Get, "/api/v3/queue", "{}")],
+    );
+    let uri = "http://radarr.replay/api/v3/queue";
+
+    assert!(
+      replayer
+        .replay(&ActiveSonarrBlock::Series.into(), RequestMethod::Get, uri)
+        .is_none()
+    );
+    assert!(
+      replayer
+        .replay(&radarr_route(), RequestMethod::Delete, uri)
+        .is_none()
+    );
+  }
+
+  #[test]
+  fn test_replayer_load_empty_directory() {
+    let dir = recording_dir("empty");
+    fs::create_dir_all(&dir).unwrap();
+
+    assert!(Replayer::load(&dir).is_err());
+  }
+
+  #[test]
+  fn test_replayer_config() {
+    let replayer = replayer_with(
+      "config",
+      vec![exchange(RequestMethod::Get, "/api/v3/movie", "[]")],
+    );
+
+    let config = replayer.config();
+
+    let radarr_config = &config.radarr.as_ref().unwrap()[0];
+    assert_str_eq!(radarr_config.name.as_ref().unwrap(), "Radarr (replay)");
+    assert_str_eq!(radarr_config.uri.as_ref().unwrap(), "http://radarr.replay");
+    assert!(config.sonarr.is_none());
+    assert!(config.lidarr.is_none());
+    assert!(config.prowlarr.is_none());
+    assert!(config.readarr.is_none());
+  }
+
+  #[tokio::test]
+  async fn test_network_replays_recorded_responses() {
+    let dir = recording_dir("network");
+    let response = json!([{ "id": 3, "label": "recorded" }]);
+    let (mock, app, _server) = MockServarrApi::get()
+      .returns(response)
+      .build_for(RadarrEvent::GetTags)
+      .await;
+    let mut network =
+      test_network(&app).with_recording(Some(Recording::Record(Recorder::new(&dir).unwrap())));
+
+    assert!(
+      network
+        .handle_network_event(RadarrEvent::GetTags.into())
+        .await
+        .is_ok()
+    );
+    mock.assert_async().await;
+
+    let replayer = Replayer::load(&dir).unwrap();
+    let mut replay_app = App::test_default();
+    replay_app.server_tabs.tabs[0].config = replayer.config().radarr.unwrap().pop();
+    let replay_app = Arc::new(Mutex::new(replay_app));
+    let mut replay_network =
+      test_network(&replay_app).with_recording(Some(Recording::Replay(replayer)));
+
+    assert!(
+      replay_network
+        .handle_network_event(RadarrEvent::GetTags.into())
+        .await
+        .is_ok()
+    );
+    assert_eq!(
+      replay_app
+        .lock()
+        .await
+        .data
+        .radarr_data
+        .tags_map
+        .get_by_left(&3),
+      Some(&"recorded".to_owned())
+    );
+    assert!(
+      replay_network
+        .handle_network_event(RadarrEvent::GetMovies.into())
+        .await
+        .is_err()
+    );
+    assert!(
+      replay_app
+        .lock()
+        .await
+        .error
+        .text
+        .starts_with("No recorded response found for Get request to:")
+    );
+  }
+}
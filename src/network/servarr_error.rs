@@ -0,0 +1,168 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::io;
+
+use itertools::Itertools;
+use regex::Regex;
+use reqwest::StatusCode;
+use serde::Deserialize;
+
+#[cfg(test)]
+#[path = "servarr_error_tests.rs"]
+mod servarr_error_tests;
+
+/// The ways a request to a Servarr can fail. The [Display] implementation is the message shown
+/// to the user, and each variant has its own CLI exit code.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ServarrError {
+  Unreachable(String),
+  Timeout,
+  Tls(String),
+  Unauthorized(StatusCode),
+  NotFound,
+  Validation(Vec<ValidationFailure>),
+  Request { status: StatusCode, body: String },
+  Parse(String),
+  Cancelled,
+}
+
+/// A single validation failure, as reported by a Servarr when it rejects a request body
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidationFailure {
+  #[serde(default)]
+  pub property_name: String,
+  pub error_message: String,
+}
+
+impl ServarrError {
+  /// Classifies a non-success response from a Servarr by its status code and body
+  pub fn from_response(status: StatusCode, body: &str) -> Self {
+    match status {
+      StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => ServarrError::Unauthorized(status),
+      StatusCode::NOT_FOUND => ServarrError::NotFound,
+      _ => match serde_json::from_str::<Vec<ValidationFailure>>(body) {
+        Ok(failures) if status.is_client_error() && !failures.is_empty() => {
+          ServarrError::Validation(failures)
+        }
+        _ => {
+          let whitespace_regex = Regex::new(r"\s+").expect("Invalid whitespace regex");
+          ServarrError::Request {
+            status,
+            body: whitespace_regex
+              .replace_all(&body.replace('\n', " "), " ")
+              .to_string(),
+          }
+        }
+      },
+    }
+  }
+
+  pub fn exit_code(&self) -> i32 {
+    match self {
+      ServarrError::Unreachable(_) => 3,
+      ServarrError::Tls(_) => 4,
+      ServarrError::Unauthorized(_) => 5,
+      ServarrError::NotFound => 6,
+      ServarrError::Validation(_) => 7,
+      ServarrError::Request { .. } => 8,
+      ServarrError::Parse(_) => 9,
+      ServarrError::Timeout => 10,
+      ServarrError::Cancelled => 130,
+    }
+  }
+}
+
+impl From<&reqwest::Error> for ServarrError {
+  fn from(error: &reqwest::Error) -> Self {
+    if error.is_timeout() {
+      return ServarrError::Timeout;
+    }
+
+    if error.is_connect()
+      && let Some(tls_error) = tls_error(error)
+    {
+      return ServarrError::Tls(tls_error.to_string());
+    }
+
+    ServarrError::Unreachable(error.to_string())
+  }
+}
+
+/// Finds the [rustls::Error] that failed the TLS handshake in the source chain of a connection
+/// error. The connectors wrap it in [io::Error]s, whose `source` skips the wrapped error, so those
+/// are unwrapped instead.
+fn tls_error(error: &reqwest::Error) -> Option<&rustls::Error> {
+  let mut source = error.source();
+  while let Some(cause) = source {
+    if let Some(tls_error) = cause.downcast_ref::<rustls::Error>() {
+      return Some(tls_error);
+    }
+    source = match cause.downcast_ref::<io::Error>() {
+      Some(io_error) => io_error
+        .get_ref()
+        .map(|inner| inner as &(dyn Error + 'static)),
+      None => cause.source(),
+    };
+  }
+
+  None
+}
+
+impl Display for ServarrError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    match self {
+      ServarrError::Unreachable(message) => write!(
+        f,
+        "Unable to reach the Servarr. Check that it's running and that its host, port, or URI in your config is correct: {message}"
+      ),
+      ServarrError::Timeout => write!(
+        f,
+        "The Servarr didn't respond in time. Check that it's running, or increase the 'timeout_secs' in your config"
+      ),
+      ServarrError::Tls(message) => write!(
+        f,
+        "Unable to establish a secure connection to the Servarr. Check the 'ssl' and 'ssl_cert_path' settings in your config: {message}"
+      ),
+      ServarrError::Unauthorized(status) => write!(
+        f,
+        "The Servarr rejected the API token ({status}). Check the 'api_token' in your config"
+      ),
+      ServarrError::NotFound => write!(
+        f,
+        "The requested resource was not found (404 Not Found). It may have been deleted, or the URL base of the Servarr may be missing from the 'uri' in your config"
+      ),
+      ServarrError::Validation(failures) => write!(
+        f,
+        "The Servarr rejected the request: {}",
+        failures.iter().map(ValidationFailure::to_string).join("; ")
+      ),
+      ServarrError::Request { status, body } => write!(
+        f,
+        "Request failed. Received {status} response code with body: {body}"
+      ),
+      ServarrError::Parse(message) => write!(f, "Failed to parse response! {message}"),
+      ServarrError::Cancelled => write!(f, "The request was cancelled"),
+    }
+  }
+}
+
+impl Error for ServarrError {}
+
+impl Display for ValidationFailure {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    if self.property_name.is_empty() {
+      write!(f, "{}", self.error_message)
+    } else {
+      write!(f, "{}: {}", self.property_name, self.error_message)
+    }
+  }
+}
+
+/// The exit code the CLI uses for the given error; [ServarrError]s each have their own, and
+/// everything else exits with `1`
+pub fn exit_code(error: &anyhow::Error) -> i32 {
+  error
+    .downcast_ref::<ServarrError>()
+    .map_or(1, ServarrError::exit_code)
+}
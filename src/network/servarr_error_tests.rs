@@ -0,0 +1,174 @@
+#[cfg(test)]
+mod tests {
+  use anyhow::{Context, anyhow};
+  use pretty_assertions::{assert_eq, assert_str_eq};
+  use reqwest::StatusCode;
+  use rstest::rstest;
+  use tokio::io::AsyncWriteExt;
+  use tokio::net::TcpListener;
+
+  use crate::network::servarr_error::{ServarrError, ValidationFailure, exit_code};
+
+  #[rstest]
+  fn test_from_response_unauthorized(
+    #[values(StatusCode::UNAUTHORIZED, StatusCode::FORBIDDEN)] status: StatusCode,
+  ) {
+    assert_eq!(
+      ServarrError::from_response(status, ""),
+      ServarrError::Unauthorized(status)
+    );
+  }
+
+  #[test]
+  fn test_from_response_not_found() {
+    assert_eq!(
+      ServarrError::from_response(StatusCode::NOT_FOUND, "Not Found"),
+      ServarrError::NotFound
+    );
+  }
+
+  #[test]
+  fn test_from_response_validation() {
+    let body = r#"[
+      { "propertyName": "RootFolderPath", "errorMessage": "Folder does not exist", "severity": "error" },
+      { "errorMessage": "Invalid request" }
+    ]"#;
+
+    let error = ServarrError::from_response(StatusCode::BAD_REQUEST, body);
+
+    assert_eq!(
+      error,
+      ServarrError::Validation(vec![
+        ValidationFailure {
+          property_name: "RootFolderPath".to_owned(),
+          error_message: "Folder does not exist".to_owned(),
+        },
+        ValidationFailure {
+          property_name: String::new(),
+          error_message: "Invalid request".to_owned(),
+        },
+      ])
+    );
+    assert_str_eq!(
+      error.to_string(),
+      "The Servarr rejected the request: RootFolderPath: Folder does not exist; Invalid request"
+    );
+  }
+
+  #[rstest]
+  #[case(StatusCode::BAD_REQUEST, "{ \"message\": \"Bad\n  request\" }")]
+  #[case(StatusCode::BAD_REQUEST, "[]")]
+  #[case(
+    StatusCode::INTERNAL_SERVER_ERROR,
+    r#"[{ "errorMessage": "Server error" }]"#
+  )]
+  fn test_from_response_request(#[case] status: StatusCode, #[case] body: &str) {
+    let error = ServarrError::from_response(status, body);
+
+    assert!(matches!(error, ServarrError::Request { status: s, .. } if s == status));
+    assert!(!error.to_string().contains('\n'));
+    assert!(error.to_string().starts_with(&format!(
+      "Request failed. Received {status} response code with body:"
+    )));
+  }
+
+  #[test]
+  fn test_unauthorized_message_mentions_api_token() {
+    assert!(
+      ServarrError::Unauthorized(StatusCode::UNAUTHORIZED)
+        .to_string()
+        .contains("'api_token'")
+    );
+  }
+
+  #[test]
+  fn test_exit_codes_are_distinct() {
+    let errors = [
+      ServarrError::Unreachable(String::new()),
+      ServarrError::Timeout,
+      ServarrError::Tls(String::new()),
+      ServarrError::Unauthorized(StatusCode::UNAUTHORIZED),
+      ServarrError::NotFound,
+      ServarrError::Validation(Vec::new()),
+      ServarrError::Request {
+        status: StatusCode::INTERNAL_SERVER_ERROR,
+        body: String::new(),
+      },
+      ServarrError::Parse(String::new()),
+      ServarrError::Cancelled,
+    ];
+    let mut exit_codes: Vec<i32> = errors.iter().map(ServarrError::exit_code).collect();
+    exit_codes.sort_unstable();
+    exit_codes.dedup();
+
+    assert_eq!(exit_codes.len(), errors.len());
+    assert!(
+      !exit_codes
+        .iter()
+        .any(|&code| code == 0 || code == 1 || code == 2)
+    );
+  }
+
+  #[test]
+  fn test_exit_code() {
+    assert_eq!(exit_code(&ServarrError::NotFound.into()), 6);
+    assert_eq!(
+      exit_code(
+        &Err::<(), _>(ServarrError::Cancelled)
+          .context("Unable to list movies")
+          .unwrap_err()
+      ),
+      130
+    );
+    assert_eq!(exit_code(&anyhow!("Some other error")), 1);
+  }
+
+  #[tokio::test]
+  async fn test_from_reqwest_error_unreachable() {
+    let error = reqwest::Client::new()
+      .get("http://127.0.0.1:1")
+      .send()
+      .await
+      .unwrap_err();
+
+    assert!(matches!(
+      ServarrError::from(&error),
+      ServarrError::Unreachable(_)
+    ));
+  }
+
+  #[tokio::test]
+  async fn test_from_reqwest_error_unreachable_ignores_tls_keywords_in_the_url() {
+    let error = reqwest::Client::new()
+      .get("http://127.0.0.1:1/ssl/certificate")
+      .send()
+      .await
+      .unwrap_err();
+
+    assert!(matches!(
+      ServarrError::from(&error),
+      ServarrError::Unreachable(_)
+    ));
+  }
+
+  #[tokio::test]
+  async fn test_from_reqwest_error_tls() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+    tokio::spawn(async move {
+      let (mut stream, _) = listener.accept().await.unwrap();
+      stream
+        .write_all(b"HTTP/1.1 400 Bad Request\r\n\r\n")
+        .await
+        .unwrap();
+    });
+
+    let error = reqwest::Client::new()
+      .get(format!("https://127.0.0.1:{port}"))
+      .send()
+      .await
+      .unwrap_err();
+
+    assert!(matches!(ServarrError::from(&error), ServarrError::Tls(_)));
+  }
+}
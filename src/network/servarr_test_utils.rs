@@ -1,6 +1,22 @@
 use crate::models::servarr_data::modals::IndexerTestResultModalItem;
-use crate::models::servarr_models::{DiskSpace, IndexerSettings, QueueEvent};
+use crate::models::servarr_models::{
+  Backup, CustomFormat, CustomFormatSpecification, DiskSpace, DownloadClient, IndexerField,
+  IndexerSettings, MediaManagementConfig, ProfileFormatItem, ProfileQuality, QualityProfile,
+  QualityProfileItem, QueueEvent,
+};
 use chrono::DateTime;
+use serde_json::{Number, json};
+
+pub fn backup() -> Backup {
+  Backup {
+    id: 1,
+    name: "radarr_backup_v5.3.6.8612_2024.01.01_00.00.00.zip".to_owned(),
+    path: "/backup/scheduled/radarr_backup_v5.3.6.8612_2024.01.01_00.00.00.zip".to_owned(),
+    backup_type: "scheduled".to_owned(),
+    size: 1048576,
+    time: DateTime::from(DateTime::parse_from_rfc3339("2024-01-01T00:00:00Z").unwrap()),
+  }
+}
 
 pub fn diskspace() -> DiskSpace {
   DiskSpace {
@@ -10,6 +26,40 @@ pub fn diskspace() -> DiskSpace {
   }
 }
 
+pub fn download_client() -> DownloadClient {
+  DownloadClient {
+    id: 1,
+    name: Some("qBittorrent".to_owned()),
+    enable: true,
+    protocol: "torrent".to_owned(),
+    priority: 1,
+    remove_completed_downloads: true,
+    remove_failed_downloads: true,
+    implementation: Some("QBittorrent".to_owned()),
+    implementation_name: Some("qBittorrent".to_owned()),
+    config_contract: Some("QBittorrentSettings".to_owned()),
+    fields: Some(vec![
+      IndexerField {
+        name: Some("host".to_owned()),
+        value: Some(json!("localhost")),
+        ..IndexerField::default()
+      },
+      IndexerField {
+        name: Some("port".to_owned()),
+        field_type: Some("number".to_owned()),
+        value: Some(json!(8080)),
+        ..IndexerField::default()
+      },
+      IndexerField {
+        name: Some("category".to_owned()),
+        value: Some(json!("managarr")),
+        ..IndexerField::default()
+      },
+    ]),
+    tags: vec![Number::from(1)],
+  }
+}
+
 pub fn indexer_settings() -> IndexerSettings {
   IndexerSettings {
     id: 1,
@@ -28,6 +78,82 @@ pub fn indexer_test_result() -> IndexerTestResultModalItem {
   }
 }
 
+pub fn custom_format() -> CustomFormat {
+  CustomFormat {
+    id: 1,
+    name: "x265".to_owned(),
+    include_custom_format_when_renaming: false,
+    specifications: vec![CustomFormatSpecification {
+      name: "x265".to_owned(),
+      implementation: "ReleaseTitleSpecification".to_owned(),
+      implementation_name: Some("Release Title".to_owned()),
+      negate: false,
+      required: true,
+      fields: vec![IndexerField {
+        name: Some("value".to_owned()),
+        value: Some(json!("\\bx265\\b")),
+        label: Some("Regular Expression".to_owned()),
+        field_type: Some("textbox".to_owned()),
+        ..IndexerField::default()
+      }],
+    }],
+  }
+}
+
+pub fn media_management_config() -> MediaManagementConfig {
+  MediaManagementConfig {
+    id: 1,
+    recycle_bin: Some("/nfs/recycle".to_owned()),
+    recycle_bin_cleanup_days: 7,
+    set_permissions_linux: false,
+    chmod_folder: Some("755".to_owned()),
+    chown_group: Some(String::new()),
+    import_extra_files: true,
+    extra_file_extensions: Some("srt,nfo".to_owned()),
+  }
+}
+
+pub fn quality_profile_details() -> QualityProfile {
+  QualityProfile {
+    id: 1,
+    name: "HD".to_owned(),
+    upgrade_allowed: true,
+    cutoff: 1000,
+    items: vec![
+      quality_profile_item(1, "SDTV", false),
+      QualityProfileItem {
+        id: Some(1000),
+        name: Some("WEB 720p".to_owned()),
+        quality: None,
+        items: vec![
+          quality_profile_item(5, "WEBDL-720p", true),
+          quality_profile_item(14, "WEBRip-720p", true),
+        ],
+        allowed: true,
+      },
+      quality_profile_item(7, "Bluray-1080p", true),
+    ],
+    min_format_score: 0,
+    cutoff_format_score: 100,
+    format_items: vec![ProfileFormatItem {
+      format: 1,
+      name: "x265".to_owned(),
+      score: 10,
+    }],
+  }
+}
+
+pub fn quality_profile_item(id: i64, name: &str, allowed: bool) -> QualityProfileItem {
+  QualityProfileItem {
+    quality: Some(ProfileQuality {
+      id,
+      name: name.to_owned(),
+    }),
+    allowed,
+    ..QualityProfileItem::default()
+  }
+}
+
 pub fn queued_event() -> QueueEvent {
   QueueEvent {
     trigger: "manual".to_string(),
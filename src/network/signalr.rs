@@ -0,0 +1,543 @@
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::fmt::Display;
+use std::future::poll_fn;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{Context, Result, anyhow, bail};
+use base64::Engine;
+use base64::prelude::BASE64_STANDARD;
+use futures_util::{SinkExt, StreamExt};
+use hyper_util::client::legacy::connect::HttpConnector;
+use hyper_util::client::legacy::connect::proxy::{SocksV5, Tunnel};
+use hyper_util::rt::TokioIo;
+use log::{debug, info, warn};
+use rustls::client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier};
+use rustls::crypto::{CryptoProvider, verify_tls12_signature, verify_tls13_signature};
+use rustls::pki_types::pem::PemObject;
+use rustls::pki_types::{CertificateDer, PrivateKeyDer, ServerName, UnixTime};
+use rustls::{DigitallySignedStruct, RootCertStore, SignatureScheme};
+use serde_json::Value;
+use tokio::net::TcpStream;
+use tokio::sync::Mutex;
+use tokio::sync::mpsc::Sender;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::tungstenite::http::{HeaderValue, Uri};
+use tokio_tungstenite::{Connector, client_async_tls_with_config};
+use tokio_util::sync::CancellationToken;
+use tower_service::Service;
+
+use crate::app::{App, ServarrConfig};
+use crate::models::{Route, ServarrInstance, TabRoute};
+use crate::network::lidarr_network::LidarrEvent;
+use crate::network::prowlarr_network::ProwlarrEvent;
+use crate::network::radarr_network::RadarrEvent;
+use crate::network::readarr_network::ReadarrEvent;
+use crate::network::sonarr_network::SonarrEvent;
+use crate::network::{NetworkEvent, QueuedNetworkEvent};
+
+#[cfg(test)]
+#[path = "signalr_tests.rs"]
+mod signalr_tests;
+
+const HUB_PATH: &str = "/signalr/messages";
+const HANDSHAKE: &str = "{\"protocol\":\"json\",\"version\":1}\u{1e}";
+const PING: &str = "{\"type\":6}\u{1e}";
+const RECORD_SEPARATOR: char = '\u{1e}';
+const RECONNECT_DELAY: Duration = Duration::from_secs(30);
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum HubMessage {
+  Invocation(String),
+  Ping,
+  Close(Option<String>),
+}
+
+/// Subscribes to the SignalR hub of a single Servarr instance and turns the messages it pushes
+/// into targeted refreshes of the affected data. While the hub is connected, the instance is
+/// polled less often than every `tick_until_poll` ticks.
+pub struct SignalRSubscriber {
+  app: Arc<Mutex<App<'static>>>,
+  network_tx: Sender<QueuedNetworkEvent>,
+  instance: ServarrInstance,
+  route: Route,
+  config: ServarrConfig,
+}
+
+impl SignalRSubscriber {
+  pub fn new(
+    app: Arc<Mutex<App<'static>>>,
+    network_tx: Sender<QueuedNetworkEvent>,
+    tab: &TabRoute,
+    config: ServarrConfig,
+  ) -> Self {
+    SignalRSubscriber {
+      app,
+      network_tx,
+      instance: tab.instance(),
+      route: tab.route,
+      config,
+    }
+  }
+
+  /// Keeps the hub connection alive, reconnecting whenever it drops, until the given token is
+  /// cancelled
+  pub async fn run(self, cancellation_token: CancellationToken) {
+    let subscription = async {
+      loop {
+        if let Err(e) = self.connect_and_listen().await {
+          warn!("SignalR hub is unavailable, falling back to polling: {e:?}");
+        }
+
+        tokio::time::sleep(RECONNECT_DELAY).await;
+      }
+    };
+
+    cancellation_token.run_until_cancelled(subscription).await;
+    info!("Servarr instance was removed or reconfigured. Stopped its SignalR subscriber");
+  }
+
+  async fn connect_and_listen(&self) -> Result<()> {
+    let url = hub_url(&self.route, &self.config)
+      .ok_or_else(|| anyhow!("Push updates are not supported for this Servarr"))?;
+    let mut request = url.as_str().into_client_request()?;
+    let headers = request.headers_mut();
+    headers.insert(
+      "X-Api-Key",
+      HeaderValue::from_str(self.config.api_token.as_deref().unwrap_or_default())?,
+    );
+    if let Some(custom_headers) = &self.config.custom_headers {
+      headers.extend(custom_headers.clone());
+    }
+    let connector = tls_connector(&self.config)?;
+
+    debug!("Connecting to the SignalR hub at {HUB_PATH}");
+    let stream = connect_stream(request.uri(), self.config.proxy.as_deref())
+      .await
+      .context("Unable to connect to the SignalR hub")?;
+    let (stream, _) = client_async_tls_with_config(request, stream, None, connector)
+      .await
+      .context("Unable to connect to the SignalR hub")?;
+    let (mut write, mut read) = stream.split();
+    write.send(Message::text(HANDSHAKE)).await?;
+    self.set_connected(true).await;
+
+    let result = async {
+      while let Some(message) = read.next().await {
+        let Message::Text(text) = message? else {
+          continue;
+        };
+
+        for hub_message in parse_hub_messages(&text) {
+          match hub_message {
+            HubMessage::Invocation(name) => self.refresh(&name).await,
+            HubMessage::Ping => write.send(Message::text(PING)).await?,
+            HubMessage::Close(Some(error)) => {
+              return Err(anyhow!("SignalR hub closed the connection: {error}"));
+            }
+            HubMessage::Close(None) => return Ok(()),
+          }
+        }
+      }
+
+      Ok(())
+    }
+    .await;
+
+    self.set_connected(false).await;
+    result
+  }
+
+  async fn refresh(&self, message_name: &str) {
+    if !self.is_active().await {
+      return;
+    }
+
+    for network_event in refresh_events(&self.route, message_name) {
+      debug!("Refreshing {network_event:?} for SignalR message: {message_name}");
+      let queued_event = QueuedNetworkEvent {
+        instance: self.instance.clone(),
+        network_event,
+      };
+      if let Err(e) = self.network_tx.send(queued_event).await {
+        warn!("Failed to send the SignalR refresh event: {e:?}");
+      }
+    }
+  }
+
+  async fn set_connected(&self, connected: bool) {
+    let mut app = self.app.lock().await;
+    if connected {
+      info!("Connected to the SignalR hub. Polling less often while push updates are received");
+      app.push_connected_instances.insert(self.instance.clone());
+    } else {
+      app.push_connected_instances.remove(&self.instance);
+      if app.server_tabs.get_active_instance().as_ref() == Some(&self.instance) {
+        app.should_refresh = true;
+      }
+    }
+  }
+
+  async fn is_active(&self) -> bool {
+    self
+      .app
+      .lock()
+      .await
+      .server_tabs
+      .get_active_instance()
+      .as_ref()
+      == Some(&self.instance)
+  }
+}
+
+struct RunningSubscriber {
+  config: ServarrConfig,
+  cancellation_token: CancellationToken,
+}
+
+/// Keeps a [SignalRSubscriber] running for every configured Servarr instance that has
+/// `push_updates` enabled
+pub struct SignalRSubscribers {
+  app: Arc<Mutex<App<'static>>>,
+  network_tx: Sender<QueuedNetworkEvent>,
+  running: HashMap<ServarrInstance, RunningSubscriber>,
+}
+
+impl SignalRSubscribers {
+  pub fn new(app: Arc<Mutex<App<'static>>>, network_tx: Sender<QueuedNetworkEvent>) -> Self {
+    SignalRSubscribers {
+      app,
+      network_tx,
+      running: HashMap::new(),
+    }
+  }
+
+  /// Starts subscribers for the newly configured instances and stops those of the instances that
+  /// were removed. Subscribers of instances whose configuration changed are restarted.
+  pub fn reconcile(&mut self, app: &mut App<'_>) {
+    let instances: HashMap<ServarrInstance, (TabRoute, ServarrConfig)> = app
+      .server_tabs
+      .tabs
+      .iter()
+      .filter_map(|tab| {
+        let config = tab.config.as_ref()?;
+        (config.push_updates == Some(true) && hub_url(&tab.route, config).is_some())
+          .then(|| (tab.instance(), (tab.clone(), config.clone())))
+      })
+      .collect();
+
+    self.running.retain(|key, subscriber| {
+      let is_unchanged = instances
+        .get(key)
+        .is_some_and(|(_, config)| *config == subscriber.config);
+      if !is_unchanged {
+        debug!("Stopping the SignalR subscriber of {key}");
+        subscriber.cancellation_token.cancel();
+        app.push_connected_instances.remove(key);
+      }
+
+      is_unchanged
+    });
+
+    for (key, (tab, config)) in instances {
+      if let Entry::Vacant(entry) = self.running.entry(key) {
+        debug!("Starting the SignalR subscriber of {}", entry.key());
+        let cancellation_token = CancellationToken::new();
+        let subscriber = SignalRSubscriber::new(
+          self.app.clone(),
+          self.network_tx.clone(),
+          &tab,
+          config.clone(),
+        );
+        tokio::spawn(subscriber.run(cancellation_token.clone()));
+        entry.insert(RunningSubscriber {
+          config,
+          cancellation_token,
+        });
+      }
+    }
+  }
+}
+
+/// The websocket URL of the SignalR hub of the given Servarr, or [None] if push updates aren't
+/// supported for it
+pub fn hub_url(route: &Route, config: &ServarrConfig) -> Option<String> {
+  let default_port = match route {
+    Route::Radarr(_, _) => 7878,
+    Route::Sonarr(_, _) => 8989,
+    Route::Lidarr(_, _) => 8686,
+    Route::Prowlarr(_, _) => 9696,
+    Route::Readarr(_, _) => 8787,
+    _ => return None,
+  };
+  let base_url = if let Some(uri) = &config.uri {
+    uri
+      .replacen("https://", "wss://", 1)
+      .replacen("http://", "ws://", 1)
+  } else {
+    let protocol = if config.ssl_cert_path.is_some() || config.ssl.unwrap_or(false) {
+      "wss"
+    } else {
+      "ws"
+    };
+    format!(
+      "{protocol}://{}:{}",
+      config.host.as_deref()?,
+      config.port.unwrap_or(default_port)
+    )
+  };
+  let api_token = config.api_token.as_deref().unwrap_or_default();
+
+  Some(format!(
+    "{base_url}{HUB_PATH}?access_token={}",
+    urlencoding::encode(api_token)
+  ))
+}
+
+/// Parses the record-separated JSON messages of the SignalR hub protocol, ignoring anything
+/// other than message invocations, pings, and close requests
+pub fn parse_hub_messages(text: &str) -> Vec<HubMessage> {
+  text
+    .split(RECORD_SEPARATOR)
+    .filter(|frame| !frame.trim().is_empty())
+    .filter_map(|frame| match serde_json::from_str::<Value>(frame) {
+      Ok(message) => Some(message),
+      Err(e) => {
+        warn!("Ignoring malformed SignalR message {frame}: {e:?}");
+        None
+      }
+    })
+    .flat_map(|message| {
+      let error = message["error"].as_str().map(str::to_owned);
+      match message["type"].as_u64() {
+        Some(1) if message["target"] == "receiveMessage" => message["arguments"]
+          .as_array()
+          .into_iter()
+          .flatten()
+          .filter_map(|argument| argument["name"].as_str())
+          .map(|name| HubMessage::Invocation(name.to_owned()))
+          .collect(),
+        Some(6) => vec![HubMessage::Ping],
+        Some(7) => vec![HubMessage::Close(error)],
+        None if error.is_some() => vec![HubMessage::Close(error)],
+        _ => Vec::new(),
+      }
+    })
+    .collect()
+}
+
+/// The network events that refresh the data affected by the SignalR message with the given name
+pub fn refresh_events(route: &Route, message_name: &str) -> Vec<NetworkEvent> {
+  match route {
+    Route::Radarr(_, _) => match message_name {
+      "queue" | "queue/details" | "queue/status" => vec![RadarrEvent::GetDownloads(500).into()],
+      "command" => vec![RadarrEvent::GetQueuedEvents.into()],
+      "movie" | "moviefile" => vec![RadarrEvent::GetMovies.into()],
+      "health" => vec![RadarrEvent::HealthCheck.into()],
+      _ => Vec::new(),
+    },
+    Route::Sonarr(_, _) => match message_name {
+      "queue" | "queue/details" | "queue/status" => vec![SonarrEvent::GetDownloads(500).into()],
+      "command" => vec![SonarrEvent::GetQueuedEvents.into()],
+      "series" | "episode" | "episodefile" => vec![SonarrEvent::ListSeries.into()],
+      "health" => vec![SonarrEvent::HealthCheck.into()],
+      _ => Vec::new(),
+    },
+    Route::Lidarr(_, _) => match message_name {
+      "queue" | "queue/details" | "queue/status" => vec![LidarrEvent::GetDownloads(500).into()],
+      "command" => vec![LidarrEvent::GetQueuedEvents.into()],
+      "artist" | "album" | "trackfile" => vec![LidarrEvent::ListArtists.into()],
+      "health" => vec![LidarrEvent::HealthCheck.into()],
+      _ => Vec::new(),
+    },
+    Route::Prowlarr(_, _) => match message_name {
+      "command" => vec![ProwlarrEvent::GetQueuedEvents.into()],
+      "indexer" | "indexerstatus" => vec![ProwlarrEvent::GetIndexers.into()],
+      "health" => vec![ProwlarrEvent::HealthCheck.into()],
+      _ => Vec::new(),
+    },
+    Route::Readarr(_, _) => match message_name {
+      "command" => vec![ReadarrEvent::GetQueuedEvents.into()],
+      "author" | "book" | "bookfile" => vec![ReadarrEvent::ListAuthors.into()],
+      "health" => vec![ReadarrEvent::HealthCheck.into()],
+      _ => Vec::new(),
+    },
+    _ => Vec::new(),
+  }
+}
+
+/// Opens the TCP connection to the host of the given hub URI, tunneling it through the given HTTP
+/// or SOCKS5 proxy
+async fn connect_stream(uri: &Uri, proxy: Option<&str>) -> Result<TokioIo<TokioIo<TcpStream>>> {
+  let host = uri
+    .host()
+    .ok_or_else(|| anyhow!("The SignalR hub URL has no host"))?;
+  let default_port = if uri.scheme_str() == Some("wss") {
+    443
+  } else {
+    80
+  };
+  let destination: Uri = format!("http://{host}:{}", uri.port_u16().unwrap_or(default_port))
+    .parse()
+    .context("Invalid SignalR hub URL")?;
+  let mut http_connector = HttpConnector::new();
+  http_connector.enforce_http(false);
+
+  let stream = match proxy {
+    None => call(http_connector, destination).await?,
+    Some(proxy) => {
+      let proxy_uri: Uri = proxy
+        .parse()
+        .with_context(|| format!("Invalid proxy '{proxy}'"))?;
+      let credentials = proxy_credentials(&proxy_uri);
+
+      match proxy_uri.scheme_str() {
+        Some("http") => {
+          let mut tunnel = Tunnel::new(proxy_uri, http_connector);
+          if let Some((username, password)) = credentials {
+            let auth = BASE64_STANDARD.encode(format!("{username}:{password}"));
+            tunnel = tunnel.with_auth(HeaderValue::from_str(&format!("Basic {auth}"))?);
+          }
+
+          call(tunnel, destination).await?
+        }
+        Some("socks5" | "socks5h") => {
+          let local_dns = proxy_uri.scheme_str() == Some("socks5");
+          let mut socks = SocksV5::new(proxy_uri, http_connector).local_dns(local_dns);
+          if let Some((username, password)) = credentials {
+            socks = socks.with_auth(username, password);
+          }
+
+          call(socks, destination).await?
+        }
+        _ => bail!("Push updates only support HTTP and SOCKS5 proxies, not '{proxy}'"),
+      }
+    }
+  };
+
+  Ok(TokioIo::new(stream))
+}
+
+async fn call<S>(mut connector: S, destination: Uri) -> Result<S::Response>
+where
+  S: Service<Uri>,
+  S::Error: Display,
+{
+  poll_fn(|cx| connector.poll_ready(cx))
+    .await
+    .map_err(|e| anyhow!("{e}"))?;
+  connector
+    .call(destination)
+    .await
+    .map_err(|e| anyhow!("{e}"))
+}
+
+/// The percent-decoded username and password in the user info of the proxy URI
+fn proxy_credentials(proxy_uri: &Uri) -> Option<(String, String)> {
+  let (user_info, _) = proxy_uri.authority()?.as_str().rsplit_once('@')?;
+  let (username, password) = user_info.split_once(':').unwrap_or((user_info, ""));
+
+  Some((
+    urlencoding::decode(username).ok()?.into_owned(),
+    urlencoding::decode(password).ok()?.into_owned(),
+  ))
+}
+
+/// Builds the TLS configuration of the hub connection from the same `ssl_cert_path`,
+/// `accept_invalid_certs`, and `client_cert_path`/`client_key_path` settings that are used for
+/// the instance's HTTP client, or [None] to use the system trust store when there are none
+fn tls_connector(config: &ServarrConfig) -> Result<Option<Connector>> {
+  let client_identity = config
+    .client_cert_path
+    .as_deref()
+    .zip(config.client_key_path.as_deref());
+  if config.ssl_cert_path.is_none()
+    && config.accept_invalid_certs != Some(true)
+    && client_identity.is_none()
+  {
+    return Ok(None);
+  }
+
+  let builder = rustls::ClientConfig::builder();
+  let builder = if config.accept_invalid_certs == Some(true) {
+    let verifier = AcceptInvalidCertificates(builder.crypto_provider().clone());
+    builder
+      .dangerous()
+      .with_custom_certificate_verifier(Arc::new(verifier))
+  } else {
+    let mut root_certs = RootCertStore::empty();
+    root_certs.add_parsable_certificates(rustls_native_certs::load_native_certs().certs);
+    if let Some(cert_path) = &config.ssl_cert_path {
+      let cert = CertificateDer::from_pem_file(cert_path)
+        .with_context(|| format!("Unable to read the SSL certificate '{cert_path}'"))?;
+      root_certs.add(cert)?;
+    }
+
+    builder.with_root_certificates(root_certs)
+  };
+  let tls_config = if let Some((cert_path, key_path)) = client_identity {
+    let certs = CertificateDer::pem_file_iter(cert_path)
+      .and_then(|certs| certs.collect::<Result<Vec<_>, _>>())
+      .with_context(|| format!("Unable to read the client certificate '{cert_path}'"))?;
+    let key = PrivateKeyDer::from_pem_file(key_path)
+      .with_context(|| format!("Unable to read the client key '{key_path}'"))?;
+
+    builder.with_client_auth_cert(certs, key)?
+  } else {
+    builder.with_no_client_auth()
+  };
+
+  Ok(Some(Connector::Rustls(Arc::new(tls_config))))
+}
+
+/// Accepts any server certificate, like the HTTP client does when `accept_invalid_certs` is set,
+/// while still checking the handshake signatures
+#[derive(Debug)]
+struct AcceptInvalidCertificates(Arc<CryptoProvider>);
+
+impl ServerCertVerifier for AcceptInvalidCertificates {
+  fn verify_server_cert(
+    &self,
+    _end_entity: &CertificateDer<'_>,
+    _intermediates: &[CertificateDer<'_>],
+    _server_name: &ServerName<'_>,
+    _ocsp_response: &[u8],
+    _now: UnixTime,
+  ) -> Result<ServerCertVerified, rustls::Error> {
+    Ok(ServerCertVerified::assertion())
+  }
+
+  fn verify_tls12_signature(
+    &self,
+    message: &[u8],
+    cert: &CertificateDer<'_>,
+    dss: &DigitallySignedStruct,
+  ) -> Result<HandshakeSignatureValid, rustls::Error> {
+    verify_tls12_signature(
+      message,
+      cert,
+      dss,
+      &self.0.signature_verification_algorithms,
+    )
+  }
+
+  fn verify_tls13_signature(
+    &self,
+    message: &[u8],
+    cert: &CertificateDer<'_>,
+    dss: &DigitallySignedStruct,
+  ) -> Result<HandshakeSignatureValid, rustls::Error> {
+    verify_tls13_signature(
+      message,
+      cert,
+      dss,
+      &self.0.signature_verification_algorithms,
+    )
+  }
+
+  fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+    self.0.signature_verification_algorithms.supported_schemes()
+  }
+}
@@ -0,0 +1,39 @@
+use crate::models::sonarr_models::Episode;
+use crate::network::sonarr_network::SonarrEvent;
+use crate::network::{Network, RequestMethod};
+use anyhow::Result;
+use chrono::NaiveDate;
+use log::info;
+
+#[cfg(test)]
+#[path = "sonarr_calendar_network_tests.rs"]
+mod sonarr_calendar_network_tests;
+
+impl Network<'_, '_> {
+  pub(in crate::network::sonarr_network) async fn get_sonarr_calendar(
+    &mut self,
+    start: NaiveDate,
+    end: NaiveDate,
+  ) -> Result<Vec<Episode>> {
+    info!("Fetching Sonarr calendar from {start} to {end}");
+    let event = SonarrEvent::GetCalendar(start, end);
+
+    let request_props = self
+      .request_props_from(
+        event,
+        RequestMethod::Get,
+        None::<()>,
+        None,
+        Some(format!("start={start}&end={end}&unmonitored=true")),
+      )
+      .await;
+
+    self
+      .handle_request::<(), Vec<Episode>>(request_props, |mut episodes, mut app| {
+        episodes.sort_by_key(|episode| episode.air_date_utc);
+        app.data.sonarr_data.calendar_start = Some(start);
+        app.data.sonarr_data.calendar.set_items(episodes);
+      })
+      .await
+  }
+}
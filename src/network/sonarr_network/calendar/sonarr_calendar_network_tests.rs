@@ -0,0 +1,46 @@
+#[cfg(test)]
+mod tests {
+  use crate::models::sonarr_models::{Episode, SonarrSerdeable};
+  use crate::network::network_tests::test_utils::{MockServarrApi, test_network};
+  use crate::network::sonarr_network::SonarrEvent;
+  use crate::network::sonarr_network::sonarr_network_test_utils::test_utils::episode;
+  use chrono::{DateTime, NaiveDate};
+  use pretty_assertions::assert_eq;
+  use serde_json::json;
+
+  #[tokio::test]
+  async fn test_handle_get_sonarr_calendar_event() {
+    let start = NaiveDate::from_ymd_opt(2024, 2, 5).unwrap();
+    let end = NaiveDate::from_ymd_opt(2024, 3, 4).unwrap();
+    let later_episode = Episode {
+      id: 2,
+      air_date_utc: Some(DateTime::from(
+        DateTime::parse_from_rfc3339("2024-02-12T07:28:45Z").unwrap(),
+      )),
+      ..episode()
+    };
+    let (mock, app, _server) = MockServarrApi::get()
+      .query("start=2024-02-05&end=2024-03-04&unmonitored=true")
+      .returns(json!([later_episode, episode()]))
+      .build_for(SonarrEvent::GetCalendar(start, end))
+      .await;
+    app.lock().await.server_tabs.next();
+    let mut network = test_network(&app);
+
+    let SonarrSerdeable::Episodes(episodes) = network
+      .handle_sonarr_event(SonarrEvent::GetCalendar(start, end))
+      .await
+      .unwrap()
+    else {
+      panic!("Expected Episodes")
+    };
+
+    mock.assert_async().await;
+    assert_eq!(
+      app.lock().await.data.sonarr_data.calendar.items,
+      vec![episode(), later_episode.clone()]
+    );
+    assert_some_eq_x!(app.lock().await.data.sonarr_data.calendar_start, start);
+    assert_eq!(episodes, vec![later_episode, episode()]);
+  }
+}
@@ -0,0 +1,347 @@
+use crate::models::servarr_data::modals::IndexerTestResultModalItem;
+use crate::models::servarr_models::{
+  AddDownloadClientParams, DownloadClient, EditDownloadClientParams, IndexerTestResult,
+};
+use crate::models::stateful_table::StatefulTable;
+use crate::network::sonarr_network::SonarrEvent;
+use crate::network::utils::{apply_schema_field_values, download_client_body_from_schema};
+use crate::network::{Network, RequestMethod};
+use anyhow::Result;
+use log::{debug, info};
+use serde_json::{Value, json};
+
+#[cfg(test)]
+#[path = "sonarr_download_clients_network_tests.rs"]
+mod sonarr_download_clients_network_tests;
+
+impl Network<'_, '_> {
+  pub(in crate::network::sonarr_network) async fn add_sonarr_download_client(
+    &mut self,
+    mut add_download_client_params: AddDownloadClientParams,
+  ) -> Result<Value> {
+    let schema_event = SonarrEvent::GetDownloadClientSchema;
+    let event = SonarrEvent::AddDownloadClient(AddDownloadClientParams::default());
+    if let Some(tag_input_str) = add_download_client_params.tag_input_string.as_ref() {
+      let tag_ids_vec = self.extract_and_add_sonarr_tag_ids_vec(tag_input_str).await;
+      add_download_client_params.tags = tag_ids_vec;
+    }
+    info!(
+      "Adding new Sonarr download client using the '{}' implementation",
+      add_download_client_params.implementation
+    );
+
+    info!("Fetching download client schema");
+
+    let request_props = self
+      .request_props_from(schema_event, RequestMethod::Get, None::<()>, None, None)
+      .await;
+
+    let download_client_schema = self
+      .handle_request::<(), Vec<Value>>(request_props, |_, _| ())
+      .await?;
+
+    let add_download_client_body =
+      download_client_body_from_schema(download_client_schema, &add_download_client_params)?;
+
+    debug!("Add download client body: {add_download_client_body:?}");
+
+    info!("Testing new download client");
+
+    let request_props = self
+      .request_props_from(
+        event.clone(),
+        RequestMethod::Post,
+        Some(add_download_client_body.clone()),
+        Some("/test".to_owned()),
+        None,
+      )
+      .await;
+
+    self
+      .handle_request::<Value, Value>(request_props, |_, _| ())
+      .await?;
+
+    info!("Saving new download client");
+
+    let request_props = self
+      .request_props_from(
+        event,
+        RequestMethod::Post,
+        Some(add_download_client_body),
+        None,
+        None,
+      )
+      .await;
+
+    self
+      .handle_request::<Value, Value>(request_props, |_, _| ())
+      .await
+  }
+
+  pub(in crate::network::sonarr_network) async fn delete_sonarr_download_client(
+    &mut self,
+    download_client_id: i64,
+  ) -> Result<()> {
+    let event = SonarrEvent::DeleteDownloadClient(download_client_id);
+    info!("Deleting Sonarr download client with id: {download_client_id}");
+
+    let request_props = self
+      .request_props_from(
+        event,
+        RequestMethod::Delete,
+        None::<()>,
+        Some(format!("/{download_client_id}")),
+        None,
+      )
+      .await;
+
+    self
+      .handle_request::<(), ()>(request_props, |_, _| ())
+      .await
+  }
+
+  pub(in crate::network::sonarr_network) async fn edit_sonarr_download_client(
+    &mut self,
+    mut edit_download_client_params: EditDownloadClientParams,
+  ) -> Result<()> {
+    let detail_event = SonarrEvent::GetDownloadClients;
+    let event = SonarrEvent::EditDownloadClient(EditDownloadClientParams::default());
+    let id = edit_download_client_params.download_client_id;
+    if let Some(tag_input_str) = edit_download_client_params.tag_input_string.as_ref() {
+      let tag_ids_vec = self.extract_and_add_sonarr_tag_ids_vec(tag_input_str).await;
+      edit_download_client_params.tags = Some(tag_ids_vec);
+    }
+    info!("Updating Sonarr download client with ID: {id}");
+
+    info!("Fetching download client details for download client with ID: {id}");
+
+    let request_props = self
+      .request_props_from(
+        detail_event,
+        RequestMethod::Get,
+        None::<()>,
+        Some(format!("/{id}")),
+        None,
+      )
+      .await;
+
+    let mut detailed_download_client_body = self
+      .handle_request::<(), Value>(request_props, |_, _| ())
+      .await?;
+
+    info!("Constructing edit download client body");
+
+    let EditDownloadClientParams {
+      name,
+      enable,
+      priority,
+      remove_completed_downloads,
+      remove_failed_downloads,
+      tags,
+      clear_tags,
+      fields,
+      ..
+    } = edit_download_client_params;
+
+    if let Some(name) = name {
+      detailed_download_client_body["name"] = json!(name);
+    }
+    if let Some(enable) = enable {
+      detailed_download_client_body["enable"] = json!(enable);
+    }
+    if let Some(priority) = priority {
+      detailed_download_client_body["priority"] = json!(priority);
+    }
+    if let Some(remove_completed_downloads) = remove_completed_downloads {
+      detailed_download_client_body["removeCompletedDownloads"] = json!(remove_completed_downloads);
+    }
+    if let Some(remove_failed_downloads) = remove_failed_downloads {
+      detailed_download_client_body["removeFailedDownloads"] = json!(remove_failed_downloads);
+    }
+    if clear_tags {
+      detailed_download_client_body["tags"] = json!([]);
+    } else if let Some(tags) = tags {
+      detailed_download_client_body["tags"] = json!(tags);
+    }
+    let implementation = detailed_download_client_body["implementation"]
+      .as_str()
+      .unwrap_or_default()
+      .to_owned();
+    apply_schema_field_values(
+      &mut detailed_download_client_body,
+      &fields,
+      &implementation,
+      "download client",
+    )?;
+
+    debug!("Edit download client body: {detailed_download_client_body:?}");
+
+    let request_props = self
+      .request_props_from(
+        event,
+        RequestMethod::Put,
+        Some(detailed_download_client_body),
+        Some(format!("/{id}")),
+        Some("forceSave=true".to_owned()),
+      )
+      .await;
+
+    self
+      .handle_request::<Value, ()>(request_props, |_, _| ())
+      .await
+  }
+
+  pub(in crate::network::sonarr_network) async fn get_sonarr_download_clients(
+    &mut self,
+  ) -> Result<Vec<DownloadClient>> {
+    info!("Fetching Sonarr download clients");
+    let event = SonarrEvent::GetDownloadClients;
+
+    let request_props = self
+      .request_props_from(event, RequestMethod::Get, None::<()>, None, None)
+      .await;
+
+    self
+      .handle_request::<(), Vec<DownloadClient>>(request_props, |download_clients, mut app| {
+        app
+          .data
+          .sonarr_data
+          .download_clients
+          .set_items(download_clients);
+      })
+      .await
+  }
+
+  pub(in crate::network::sonarr_network) async fn get_sonarr_download_client_schema(
+    &mut self,
+  ) -> Result<Vec<DownloadClient>> {
+    info!("Fetching Sonarr download client schema");
+    let event = SonarrEvent::GetDownloadClientSchema;
+
+    let request_props = self
+      .request_props_from(event, RequestMethod::Get, None::<()>, None, None)
+      .await;
+
+    self
+      .handle_request::<(), Vec<DownloadClient>>(
+        request_props,
+        |download_client_schema, mut app| {
+          app
+            .data
+            .sonarr_data
+            .download_client_schema
+            .set_items(download_client_schema);
+        },
+      )
+      .await
+  }
+
+  pub(in crate::network::sonarr_network) async fn test_sonarr_download_client(
+    &mut self,
+    download_client_id: i64,
+  ) -> Result<Value> {
+    let detail_event = SonarrEvent::GetDownloadClients;
+    let event = SonarrEvent::TestDownloadClient(download_client_id);
+    info!("Testing Sonarr download client with ID: {download_client_id}");
+
+    info!("Fetching download client details for download client with ID: {download_client_id}");
+
+    let request_props = self
+      .request_props_from(
+        detail_event,
+        RequestMethod::Get,
+        None::<()>,
+        Some(format!("/{download_client_id}")),
+        None,
+      )
+      .await;
+
+    let test_body = self
+      .handle_request::<(), Value>(request_props, |_, _| ())
+      .await?;
+
+    info!("Testing download client");
+
+    let mut request_props = self
+      .request_props_from(event, RequestMethod::Post, Some(test_body), None, None)
+      .await;
+    request_props.ignore_status_code = true;
+
+    self
+      .handle_request::<Value, Value>(request_props, |test_results, mut app| {
+        if test_results.as_object().is_none() {
+          let error_message = test_results
+            .as_array()
+            .and_then(|arr| arr.first())
+            .and_then(|item| item.get("errorMessage"))
+            .map(|msg| msg.to_string())
+            .unwrap_or_else(|| "Unknown download client test error".to_string());
+          app.data.sonarr_data.download_client_test_errors = Some(error_message);
+        } else {
+          app.data.sonarr_data.download_client_test_errors = Some(String::new());
+        };
+      })
+      .await
+  }
+
+  pub(in crate::network::sonarr_network) async fn test_all_sonarr_download_clients(
+    &mut self,
+  ) -> Result<Vec<IndexerTestResult>> {
+    info!("Testing all Sonarr download clients");
+    let event = SonarrEvent::TestAllDownloadClients;
+
+    let mut request_props = self
+      .request_props_from(event, RequestMethod::Post, None, None, None)
+      .await;
+    request_props.ignore_status_code = true;
+
+    let result = self
+      .handle_request::<(), Vec<IndexerTestResult>>(request_props, |test_results, mut app| {
+        let mut test_all_download_client_results = StatefulTable::default();
+        let download_clients = &app.data.sonarr_data.download_clients.items;
+        let modal_test_results = test_results
+          .iter()
+          .map(|result| {
+            let name = download_clients
+              .iter()
+              .find(|download_client| download_client.id == result.id)
+              .and_then(|download_client| download_client.name.clone())
+              .unwrap_or_default();
+            let validation_failures = result
+              .validation_failures
+              .iter()
+              .map(|failure| {
+                format!(
+                  "Failure for field '{}': {}",
+                  failure.property_name, failure.error_message
+                )
+              })
+              .collect::<Vec<String>>()
+              .join(", ");
+
+            IndexerTestResultModalItem {
+              name,
+              is_valid: result.is_valid,
+              validation_failures: validation_failures.into(),
+            }
+          })
+          .collect();
+        test_all_download_client_results.set_items(modal_test_results);
+        app.data.sonarr_data.download_client_test_all_results =
+          Some(test_all_download_client_results);
+      })
+      .await;
+
+    if result.is_err() {
+      self
+        .app
+        .lock()
+        .await
+        .data
+        .sonarr_data
+        .download_client_test_all_results = Some(StatefulTable::default());
+    }
+
+    result
+  }
+}
@@ -1,30 +1,63 @@
-use crate::models::servarr_models::CommandBody;
-use crate::models::sonarr_models::DownloadsResponse;
+use crate::models::servarr_models::{BulkDeleteDownloadsParams, CommandBody, DeleteDownloadParams};
+use crate::models::sonarr_models::{
+  DownloadsResponse, SonarrManualImportFile, SonarrManualImportItem,
+};
 use crate::network::sonarr_network::SonarrEvent;
 use crate::network::{Network, RequestMethod};
 use anyhow::Result;
 use log::info;
-use serde_json::Value;
+use serde_json::{Value, json};
+use urlencoding::encode;
 
 #[cfg(test)]
 #[path = "sonarr_downloads_network_tests.rs"]
 mod sonarr_downloads_network_tests;
 
 impl Network<'_, '_> {
+  pub(in crate::network::sonarr_network) async fn bulk_delete_sonarr_downloads(
+    &mut self,
+    params: BulkDeleteDownloadsParams,
+  ) -> Result<()> {
+    let event = SonarrEvent::BulkDeleteDownloads(BulkDeleteDownloadsParams::default());
+    let BulkDeleteDownloadsParams {
+      ids,
+      removal_options,
+    } = params;
+    info!("Deleting Sonarr downloads with IDs: {ids:?} with options: {removal_options:?}");
+
+    let request_props = self
+      .request_props_from(
+        event,
+        RequestMethod::Delete,
+        Some(json!({ "ids": ids })),
+        None,
+        Some(removal_options.query_params()),
+      )
+      .await;
+
+    self
+      .handle_request::<Value, ()>(request_props, |_, _| ())
+      .await
+  }
+
   pub(in crate::network::sonarr_network) async fn delete_sonarr_download(
     &mut self,
-    download_id: i64,
+    params: DeleteDownloadParams,
   ) -> Result<()> {
-    let event = SonarrEvent::DeleteDownload(download_id);
-    info!("Deleting Sonarr download for download with id: {download_id}");
+    let event = SonarrEvent::DeleteDownload(DeleteDownloadParams::default());
+    let DeleteDownloadParams {
+      id,
+      removal_options,
+    } = params;
+    info!("Deleting Sonarr download for download with id: {id} with options: {removal_options:?}");
 
     let request_props = self
       .request_props_from(
         event,
         RequestMethod::Delete,
         None::<()>,
-        Some(format!("/{download_id}")),
-        None,
+        Some(format!("/{id}")),
+        Some(removal_options.query_params()),
       )
       .await;
 
@@ -46,7 +79,7 @@ impl Network<'_, '_> {
         RequestMethod::Get,
         None::<()>,
         None,
-        Some(format!("pageSize={count}")),
+        Some(format!("pageSize={count}&includeUnknownSeriesItems=true")),
       )
       .await;
 
@@ -61,6 +94,77 @@ impl Network<'_, '_> {
       .await
   }
 
+  pub(in crate::network::sonarr_network) async fn get_sonarr_manual_import(
+    &mut self,
+    download_id: String,
+    series_id: Option<i64>,
+  ) -> Result<Vec<SonarrManualImportItem>> {
+    info!("Fetching Sonarr manual import candidates for download with id: {download_id}");
+    let event = SonarrEvent::GetManualImport(download_id.clone(), series_id);
+    let mut params = format!(
+      "downloadId={}&filterExistingFiles=true",
+      encode(&download_id)
+    );
+    if let Some(series_id) = series_id {
+      params.push_str(&format!("&seriesId={series_id}"));
+    }
+
+    let request_props = self
+      .request_props_from(event, RequestMethod::Get, None::<()>, None, Some(params))
+      .await;
+
+    self
+      .handle_request::<(), Vec<SonarrManualImportItem>>(request_props, |items, mut app| {
+        if let Some(manual_import_modal) = app.data.sonarr_data.manual_import_modal.as_mut() {
+          manual_import_modal.items.set_items(items);
+        }
+      })
+      .await
+  }
+
+  pub(in crate::network::sonarr_network) async fn grab_sonarr_queue_item(
+    &mut self,
+    queue_item_id: i64,
+  ) -> Result<Value> {
+    info!("Grabbing the pending Sonarr queue item with ID: {queue_item_id}");
+    let event = SonarrEvent::GrabQueueItem(queue_item_id);
+
+    let request_props = self
+      .request_props_from(
+        event,
+        RequestMethod::Post,
+        None::<()>,
+        Some(format!("/{queue_item_id}")),
+        None,
+      )
+      .await;
+
+    self
+      .handle_request::<(), Value>(request_props, |_, _| ())
+      .await
+  }
+
+  pub(in crate::network::sonarr_network) async fn manual_import_sonarr_files(
+    &mut self,
+    files: Vec<SonarrManualImportFile>,
+  ) -> Result<Value> {
+    info!("Manually importing {} file(s) into Sonarr", files.len());
+    let event = SonarrEvent::ManualImport(Vec::new());
+    let body = json!({
+      "name": "ManualImport",
+      "importMode": "auto",
+      "files": files
+    });
+
+    let request_props = self
+      .request_props_from(event, RequestMethod::Post, Some(body), None, None)
+      .await;
+
+    self
+      .handle_request::<Value, Value>(request_props, |_, _| ())
+      .await
+  }
+
   pub(in crate::network::sonarr_network) async fn update_sonarr_downloads(
     &mut self,
   ) -> Result<Value> {
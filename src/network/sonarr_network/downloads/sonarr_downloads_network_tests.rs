@@ -1,19 +1,59 @@
 #[cfg(test)]
 mod tests {
+  use crate::models::servarr_data::sonarr::modals::ManualImportModal;
+  use crate::models::servarr_models::{
+    BulkDeleteDownloadsParams, DeleteDownloadParams, QueueRemovalOptions,
+  };
   use crate::models::sonarr_models::{DownloadsResponse, SonarrSerdeable};
   use crate::network::network_tests::test_utils::{MockServarrApi, test_network};
   use crate::network::sonarr_network::SonarrEvent;
   use crate::network::sonarr_network::sonarr_network_test_utils::test_utils::{
-    download_record, downloads_response,
+    EPISODE_JSON, SERIES_JSON, download_record, downloads_response, manual_import_file,
+    manual_import_item,
   };
   use pretty_assertions::assert_eq;
-  use serde_json::json;
+  use rstest::rstest;
+  use serde_json::{Value, json};
+
+  #[tokio::test]
+  async fn test_handle_bulk_delete_sonarr_downloads_event() {
+    let params = BulkDeleteDownloadsParams {
+      ids: vec![1, 2],
+      removal_options: QueueRemovalOptions::default(),
+    };
+    let (mock, app, _server) = MockServarrApi::delete()
+      .with_request_body(json!({ "ids": [1, 2] }))
+      .query("removeFromClient=true&blocklist=false&skipRedownload=false&changeCategory=false")
+      .build_for(SonarrEvent::BulkDeleteDownloads(params.clone()))
+      .await;
+    app.lock().await.server_tabs.next();
+    let mut network = test_network(&app);
+
+    assert!(
+      network
+        .handle_sonarr_event(SonarrEvent::BulkDeleteDownloads(params))
+        .await
+        .is_ok()
+    );
+
+    mock.assert_async().await;
+  }
 
   #[tokio::test]
   async fn test_handle_delete_sonarr_download_event() {
+    let params = DeleteDownloadParams {
+      id: 1,
+      removal_options: QueueRemovalOptions {
+        remove_from_client: false,
+        blocklist: true,
+        skip_redownload: true,
+        change_category: false,
+      },
+    };
     let (mock, app, _server) = MockServarrApi::delete()
       .path("/1")
-      .build_for(SonarrEvent::DeleteDownload(1))
+      .query("removeFromClient=false&blocklist=true&skipRedownload=true&changeCategory=false")
+      .build_for(SonarrEvent::DeleteDownload(params.clone()))
       .await;
     app
       .lock()
@@ -27,7 +67,7 @@ mod tests {
 
     assert!(
       network
-        .handle_sonarr_event(SonarrEvent::DeleteDownload(1))
+        .handle_sonarr_event(SonarrEvent::DeleteDownload(params))
         .await
         .is_ok()
     );
@@ -48,13 +88,14 @@ mod tests {
         "outputPath": "/nfs/tv/Test show/season 1/",
         "indexer": "kickass torrents",
         "downloadClient": "transmission",
+        "downloadId": "abc123",
       }]
     });
     let response: DownloadsResponse =
       serde_json::from_value(downloads_response_json.clone()).unwrap();
     let (mock, app, _server) = MockServarrApi::get()
       .returns(downloads_response_json)
-      .query("pageSize=500")
+      .query("pageSize=500&includeUnknownSeriesItems=true")
       .build_for(SonarrEvent::GetDownloads(500))
       .await;
     app.lock().await.server_tabs.next();
@@ -75,6 +116,113 @@ mod tests {
     assert_eq!(downloads, response);
   }
 
+  #[rstest]
+  #[case(None, "downloadId=abc123&filterExistingFiles=true")]
+  #[case(Some(1), "downloadId=abc123&filterExistingFiles=true&seriesId=1")]
+  #[tokio::test]
+  async fn test_handle_get_sonarr_manual_import_event(
+    #[case] series_id: Option<i64>,
+    #[case] expected_query: &str,
+  ) {
+    let manual_import_json = json!([{
+      "id": 1,
+      "path": "/nfs/downloads/Test/Test.S01E01.mkv",
+      "relativePath": "Test/Test.S01E01.mkv",
+      "size": 3543348019u64,
+      "series": serde_json::from_str::<Value>(SERIES_JSON).unwrap(),
+      "seasonNumber": 1,
+      "episodes": [serde_json::from_str::<Value>(EPISODE_JSON).unwrap()],
+      "quality": { "quality": { "id": 7, "name": "Bluray-1080p" } },
+      "languages": [{ "id": 1, "name": "English" }],
+      "releaseGroup": "SPARKS",
+      "downloadId": "abc123",
+      "rejections": []
+    }]);
+    let (mock, app, _server) = MockServarrApi::get()
+      .returns(manual_import_json)
+      .query(expected_query)
+      .build_for(SonarrEvent::GetManualImport("abc123".to_owned(), series_id))
+      .await;
+    app.lock().await.server_tabs.next();
+    app.lock().await.data.sonarr_data.manual_import_modal = Some(ManualImportModal::default());
+    let mut network = test_network(&app);
+
+    let result = network
+      .handle_sonarr_event(SonarrEvent::GetManualImport("abc123".to_owned(), series_id))
+      .await;
+
+    mock.assert_async().await;
+
+    let SonarrSerdeable::ManualImportItems(items) = result.unwrap() else {
+      panic!("Expected ManualImportItems")
+    };
+    assert_eq!(
+      app
+        .lock()
+        .await
+        .data
+        .sonarr_data
+        .manual_import_modal
+        .as_ref()
+        .unwrap()
+        .items
+        .items,
+      vec![manual_import_item()]
+    );
+    assert_eq!(items, vec![manual_import_item()]);
+  }
+
+  #[tokio::test]
+  async fn test_handle_grab_sonarr_queue_item_event() {
+    let (mock, app, _server) = MockServarrApi::post()
+      .returns(json!({}))
+      .path("/1")
+      .build_for(SonarrEvent::GrabQueueItem(1))
+      .await;
+    app.lock().await.server_tabs.next();
+    let mut network = test_network(&app);
+
+    let result = network
+      .handle_sonarr_event(SonarrEvent::GrabQueueItem(1))
+      .await;
+
+    mock.assert_async().await;
+    assert_ok!(result);
+  }
+
+  #[tokio::test]
+  async fn test_handle_manual_import_sonarr_files_event() {
+    let (mock, app, _server) = MockServarrApi::post()
+      .with_request_body(json!({
+        "name": "ManualImport",
+        "importMode": "auto",
+        "files": [{
+          "path": "/nfs/downloads/Test/Test.S01E01.mkv",
+          "seriesId": 1,
+          "seasonNumber": 1,
+          "episodeIds": [1],
+          "quality": { "quality": { "id": 7, "name": "Bluray-1080p" } },
+          "languages": [{ "id": 1, "name": "English" }],
+          "releaseGroup": "SPARKS",
+          "downloadId": "abc123"
+        }]
+      }))
+      .returns(json!({}))
+      .build_for(SonarrEvent::ManualImport(vec![manual_import_file()]))
+      .await;
+    app.lock().await.server_tabs.next();
+    let mut network = test_network(&app);
+
+    assert!(
+      network
+        .handle_sonarr_event(SonarrEvent::ManualImport(vec![manual_import_file()]))
+        .await
+        .is_ok()
+    );
+
+    mock.assert_async().await;
+  }
+
   #[tokio::test]
   async fn test_handle_update_sonarr_downloads_event() {
     let (mock, app, _server) = MockServarrApi::post()
@@ -1,8 +1,11 @@
 use crate::models::servarr_data::modals::IndexerTestResultModalItem;
 use crate::models::servarr_models::IndexerSettings;
-use crate::models::servarr_models::{EditIndexerParams, Indexer, IndexerTestResult};
+use crate::models::servarr_models::{
+  AddIndexerParams, EditIndexerParams, Indexer, IndexerTestResult,
+};
 use crate::models::stateful_table::StatefulTable;
 use crate::network::sonarr_network::SonarrEvent;
+use crate::network::utils::indexer_body_from_schema;
 use crate::network::{Network, RequestMethod};
 use anyhow::{Context, Result};
 use log::{debug, info};
@@ -13,6 +16,68 @@ use serde_json::{Value, json};
 mod sonarr_indexers_network_tests;
 
 impl Network<'_, '_> {
+  pub(in crate::network::sonarr_network) async fn add_sonarr_indexer(
+    &mut self,
+    mut add_indexer_params: AddIndexerParams,
+  ) -> Result<Value> {
+    let schema_event = SonarrEvent::GetIndexerSchema;
+    let event = SonarrEvent::AddIndexer(AddIndexerParams::default());
+    if let Some(tag_input_str) = add_indexer_params.tag_input_string.as_ref() {
+      let tag_ids_vec = self.extract_and_add_sonarr_tag_ids_vec(tag_input_str).await;
+      add_indexer_params.tags = tag_ids_vec;
+    }
+    info!(
+      "Adding new Sonarr indexer using the '{}' implementation",
+      add_indexer_params.implementation
+    );
+
+    info!("Fetching indexer schema");
+
+    let request_props = self
+      .request_props_from(schema_event, RequestMethod::Get, None::<()>, None, None)
+      .await;
+
+    let indexer_schema = self
+      .handle_request::<(), Vec<Value>>(request_props, |_, _| ())
+      .await?;
+
+    let add_indexer_body = indexer_body_from_schema(indexer_schema, &add_indexer_params)?;
+
+    debug!("Add indexer body: {add_indexer_body:?}");
+
+    info!("Testing new indexer");
+
+    let request_props = self
+      .request_props_from(
+        event.clone(),
+        RequestMethod::Post,
+        Some(add_indexer_body.clone()),
+        Some("/test".to_owned()),
+        None,
+      )
+      .await;
+
+    self
+      .handle_request::<Value, Value>(request_props, |_, _| ())
+      .await?;
+
+    info!("Saving new indexer");
+
+    let request_props = self
+      .request_props_from(
+        event,
+        RequestMethod::Post,
+        Some(add_indexer_body),
+        None,
+        None,
+      )
+      .await;
+
+    self
+      .handle_request::<Value, Value>(request_props, |_, _| ())
+      .await
+  }
+
   pub(in crate::network::sonarr_network) async fn delete_sonarr_indexer(
     &mut self,
     indexer_id: i64,
@@ -76,18 +141,12 @@ impl Network<'_, '_> {
       )
       .await;
 
-    let mut response = String::new();
-
-    self
-      .handle_request::<(), Value>(request_props, |detailed_indexer_body, _| {
-        response = detailed_indexer_body.to_string()
-      })
+    let mut detailed_indexer_body = self
+      .handle_request::<(), Value>(request_props, |_, _| ())
       .await?;
 
     info!("Constructing edit indexer body");
 
-    let mut detailed_indexer_body: Value = serde_json::from_str(&response)?;
-
     let (
       name,
       enable_rss,
@@ -303,6 +362,27 @@ impl Network<'_, '_> {
       .await
   }
 
+  pub(in crate::network::sonarr_network) async fn get_sonarr_indexer_schema(
+    &mut self,
+  ) -> Result<Vec<Indexer>> {
+    info!("Fetching Sonarr indexer schema");
+    let event = SonarrEvent::GetIndexerSchema;
+
+    let request_props = self
+      .request_props_from(event, RequestMethod::Get, None::<()>, None, None)
+      .await;
+
+    self
+      .handle_request::<(), Vec<Indexer>>(request_props, |indexer_schema, mut app| {
+        app
+          .data
+          .sonarr_data
+          .indexer_schema
+          .set_items(indexer_schema);
+      })
+      .await
+  }
+
   pub(in crate::network::sonarr_network) async fn test_sonarr_indexer(
     &mut self,
     indexer_id: i64,
@@ -323,12 +403,8 @@ impl Network<'_, '_> {
       )
       .await;
 
-    let mut test_body: Value = Value::default();
-
-    self
-      .handle_request::<(), Value>(request_props, |detailed_indexer_body, _| {
-        test_body = detailed_indexer_body;
-      })
+    let test_body = self
+      .handle_request::<(), Value>(request_props, |_, _| ())
       .await?;
 
     info!("Testing indexer");
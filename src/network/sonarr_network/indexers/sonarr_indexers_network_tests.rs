@@ -2,7 +2,9 @@
 mod tests {
   use crate::models::HorizontallyScrollableText;
   use crate::models::servarr_data::modals::IndexerTestResultModalItem;
-  use crate::models::servarr_models::{EditIndexerParams, Indexer, IndexerTestResult};
+  use crate::models::servarr_models::{
+    AddIndexerParams, EditIndexerParams, Indexer, IndexerTestResult,
+  };
   use crate::models::sonarr_models::SonarrSerdeable;
   use crate::network::NetworkResource;
   use crate::network::network_tests::test_utils::{MockServarrApi, test_network};
@@ -14,6 +16,197 @@ mod tests {
   use pretty_assertions::assert_eq;
   use serde_json::json;
 
+  #[tokio::test]
+  async fn test_handle_add_sonarr_indexer_event() {
+    let indexer_schema_json = json!([{
+        "enableRss": false,
+        "enableAutomaticSearch": false,
+        "enableInteractiveSearch": false,
+        "priority": 25,
+        "name": "",
+        "fields": [
+            {
+                "name": "baseUrl",
+                "type": "textbox",
+                "value": "",
+            },
+            {
+                "name": "minimumSeeders",
+                "type": "number",
+                "value": 1,
+            },
+        ],
+        "implementationName": "Torznab",
+        "implementation": "Torznab",
+        "tags": [],
+    }]);
+    let expected_add_indexer_body_json = json!({
+        "enableRss": true,
+        "enableAutomaticSearch": true,
+        "enableInteractiveSearch": false,
+        "priority": 1,
+        "name": "Test Indexer",
+        "fields": [
+            {
+                "name": "baseUrl",
+                "type": "textbox",
+                "value": "https://test.com",
+            },
+            {
+                "name": "minimumSeeders",
+                "type": "number",
+                "value": 5,
+            },
+        ],
+        "implementationName": "Torznab",
+        "implementation": "Torznab",
+        "tags": [1, 2],
+    });
+    let add_indexer_params = AddIndexerParams {
+      implementation: "Torznab".to_owned(),
+      name: "Test Indexer".to_owned(),
+      enable_interactive_search: false,
+      priority: 1,
+      tag_input_string: Some("usenet, testing".to_owned()),
+      fields: vec![
+        ("baseUrl".to_owned(), "https://test.com".to_owned()),
+        ("minimumSeeders".to_owned(), "5".to_owned()),
+      ],
+      ..AddIndexerParams::default()
+    };
+    let (async_schema_server, app, mut server) = MockServarrApi::get()
+      .returns(indexer_schema_json)
+      .build_for(SonarrEvent::GetIndexerSchema)
+      .await;
+    let async_test_server = server
+      .mock(
+        "POST",
+        format!(
+          "/api/v3{}/test",
+          SonarrEvent::AddIndexer(add_indexer_params.clone()).resource()
+        )
+        .as_str(),
+      )
+      .with_status(200)
+      .with_body("{}")
+      .match_header("X-Api-Key", "test1234")
+      .match_body(Matcher::Json(expected_add_indexer_body_json.clone()))
+      .create_async()
+      .await;
+    let async_add_server = server
+      .mock(
+        "POST",
+        format!(
+          "/api/v3{}",
+          SonarrEvent::AddIndexer(add_indexer_params.clone()).resource()
+        )
+        .as_str(),
+      )
+      .with_status(201)
+      .match_header("X-Api-Key", "test1234")
+      .match_body(Matcher::Json(expected_add_indexer_body_json))
+      .with_body(json!({ "id": 1 }).to_string())
+      .create_async()
+      .await;
+    app.lock().await.data.sonarr_data.tags_map =
+      BiMap::from_iter([(1, "usenet".to_owned()), (2, "testing".to_owned())]);
+    app.lock().await.server_tabs.next();
+    let mut network = test_network(&app);
+
+    let SonarrSerdeable::Value(value) = network
+      .handle_sonarr_event(SonarrEvent::AddIndexer(add_indexer_params))
+      .await
+      .unwrap()
+    else {
+      panic!("Expected Value")
+    };
+    async_schema_server.assert_async().await;
+    async_test_server.assert_async().await;
+    async_add_server.assert_async().await;
+    assert_eq!(value, json!({ "id": 1 }));
+  }
+
+  #[tokio::test]
+  async fn test_handle_add_sonarr_indexer_event_does_not_save_indexer_when_test_fails() {
+    let indexer_schema_json = json!([{
+        "name": "",
+        "fields": [],
+        "implementationName": "Torznab",
+        "implementation": "Torznab",
+        "tags": [],
+    }]);
+    let add_indexer_params = AddIndexerParams {
+      implementation: "Torznab".to_owned(),
+      name: "Test Indexer".to_owned(),
+      ..AddIndexerParams::default()
+    };
+    let (async_schema_server, app, mut server) = MockServarrApi::get()
+      .returns(indexer_schema_json)
+      .build_for(SonarrEvent::GetIndexerSchema)
+      .await;
+    let async_test_server = server
+      .mock(
+        "POST",
+        format!(
+          "/api/v3{}/test",
+          SonarrEvent::AddIndexer(add_indexer_params.clone()).resource()
+        )
+        .as_str(),
+      )
+      .with_status(400)
+      .with_body(json!([{ "propertyName": "", "errorMessage": "test failure" }]).to_string())
+      .create_async()
+      .await;
+    let async_add_server = server
+      .mock(
+        "POST",
+        format!(
+          "/api/v3{}",
+          SonarrEvent::AddIndexer(add_indexer_params.clone()).resource()
+        )
+        .as_str(),
+      )
+      .expect(0)
+      .create_async()
+      .await;
+    app.lock().await.server_tabs.next();
+    let mut network = test_network(&app);
+
+    assert!(
+      network
+        .handle_sonarr_event(SonarrEvent::AddIndexer(add_indexer_params))
+        .await
+        .is_err()
+    );
+
+    async_schema_server.assert_async().await;
+    async_test_server.assert_async().await;
+    async_add_server.assert_async().await;
+  }
+
+  #[tokio::test]
+  async fn test_handle_add_sonarr_indexer_event_unknown_implementation() {
+    let (async_schema_server, app, _server) = MockServarrApi::get()
+      .returns(json!([{ "implementation": "Newznab", "fields": [] }]))
+      .build_for(SonarrEvent::GetIndexerSchema)
+      .await;
+    app.lock().await.server_tabs.next();
+    let mut network = test_network(&app);
+
+    let result = network
+      .handle_sonarr_event(SonarrEvent::AddIndexer(AddIndexerParams {
+        implementation: "Torznab".to_owned(),
+        ..AddIndexerParams::default()
+      }))
+      .await;
+
+    async_schema_server.assert_async().await;
+    assert_eq!(
+      result.unwrap_err().to_string(),
+      "No indexer implementation named 'Torznab' was found"
+    );
+  }
+
   #[tokio::test]
   async fn test_handle_delete_sonarr_indexer_event() {
     let (mock, app, _server) = MockServarrApi::delete()
@@ -586,6 +779,55 @@ mod tests {
     async_edit_server.assert_async().await;
   }
 
+  #[tokio::test]
+  async fn test_handle_get_sonarr_indexer_schema_event() {
+    let indexer_schema_json = json!([{
+        "enableRss": false,
+        "enableAutomaticSearch": false,
+        "enableInteractiveSearch": false,
+        "supportsRss": true,
+        "supportsSearch": true,
+        "protocol": "torrent",
+        "priority": 25,
+        "downloadClientId": 0,
+        "name": "",
+        "fields": [
+            {
+                "name": "baseUrl",
+                "label": "URL",
+                "type": "textbox",
+                "value": "",
+            },
+        ],
+        "implementationName": "Torznab",
+        "implementation": "Torznab",
+        "configContract": "TorznabSettings",
+        "tags": [],
+        "id": 0
+    }]);
+    let response: Vec<Indexer> = serde_json::from_value(indexer_schema_json.clone()).unwrap();
+    let (async_server, app, _server) = MockServarrApi::get()
+      .returns(indexer_schema_json)
+      .build_for(SonarrEvent::GetIndexerSchema)
+      .await;
+    app.lock().await.server_tabs.next();
+    let mut network = test_network(&app);
+
+    let SonarrSerdeable::Indexers(indexer_schema) = network
+      .handle_sonarr_event(SonarrEvent::GetIndexerSchema)
+      .await
+      .unwrap()
+    else {
+      panic!("Expected Indexers")
+    };
+    async_server.assert_async().await;
+    assert_eq!(
+      app.lock().await.data.sonarr_data.indexer_schema.items,
+      response
+    );
+    assert_eq!(indexer_schema, response);
+  }
+
   #[tokio::test]
   async fn test_handle_get_sonarr_indexers_event() {
     let indexers_response_json = json!([{
@@ -341,19 +341,13 @@ impl Network<'_, '_> {
         )
         .await;
 
-      let mut monitored = false;
-
       self
-        .handle_request::<(), Value>(request_props, |detailed_episode_body, _| {
-          monitored = detailed_episode_body
-            .get("monitored")
-            .unwrap()
-            .as_bool()
-            .unwrap();
-        })
-        .await?;
-
-      monitored
+        .handle_request::<(), Value>(request_props, |_, _| ())
+        .await?
+        .get("monitored")
+        .unwrap()
+        .as_bool()
+        .unwrap()
     };
 
     info!("Toggling monitoring for episode id: {episode_id}");
@@ -4,7 +4,7 @@ use crate::models::sonarr_models::{SonarrCommandBody, SonarrHistoryItem, SonarrR
 use crate::network::sonarr_network::SonarrEvent;
 use crate::network::{Network, RequestMethod};
 use anyhow::Result;
-use log::{debug, info, warn};
+use log::{debug, info};
 use serde_json::{Value, json};
 
 #[cfg(test)]
@@ -33,63 +33,51 @@ impl Network<'_, '_> {
       )
       .await;
 
-    let mut response = String::new();
-
-    self
-      .handle_request::<(), Value>(request_props, |detailed_series_body, _| {
-        response = detailed_series_body.to_string()
-      })
+    let mut detailed_series_body = self
+      .handle_request::<(), Value>(request_props, |_, _| ())
       .await?;
 
     info!("Constructing toggle season monitoring body");
 
-    match serde_json::from_str::<Value>(&response) {
-      Ok(mut detailed_series_body) => {
-        let monitored = detailed_series_body
-          .get("seasons")
-          .unwrap()
-          .as_array()
-          .unwrap()
-          .iter()
-          .find(|season| season["seasonNumber"] == season_number)
-          .unwrap()
-          .get("monitored")
-          .unwrap()
-          .as_bool()
-          .unwrap();
-
-        *detailed_series_body
-          .get_mut("seasons")
-          .unwrap()
-          .as_array_mut()
-          .unwrap()
-          .iter_mut()
-          .find(|season| season["seasonNumber"] == season_number)
-          .unwrap()
-          .get_mut("monitored")
-          .unwrap() = json!(!monitored);
-
-        debug!("Toggle season monitoring body: {detailed_series_body:?}");
-
-        let request_props = self
-          .request_props_from(
-            event,
-            RequestMethod::Put,
-            Some(detailed_series_body),
-            Some(format!("/{series_id}")),
-            None,
-          )
-          .await;
-
-        self
-          .handle_request::<Value, ()>(request_props, |_, _| ())
-          .await
-      }
-      Err(_) => {
-        warn!("Request for detailed series body was interrupted");
-        Ok(())
-      }
-    }
+    let monitored = detailed_series_body
+      .get("seasons")
+      .unwrap()
+      .as_array()
+      .unwrap()
+      .iter()
+      .find(|season| season["seasonNumber"] == season_number)
+      .unwrap()
+      .get("monitored")
+      .unwrap()
+      .as_bool()
+      .unwrap();
+
+    *detailed_series_body
+      .get_mut("seasons")
+      .unwrap()
+      .as_array_mut()
+      .unwrap()
+      .iter_mut()
+      .find(|season| season["seasonNumber"] == season_number)
+      .unwrap()
+      .get_mut("monitored")
+      .unwrap() = json!(!monitored);
+
+    debug!("Toggle season monitoring body: {detailed_series_body:?}");
+
+    let request_props = self
+      .request_props_from(
+        event,
+        RequestMethod::Put,
+        Some(detailed_series_body),
+        Some(format!("/{series_id}")),
+        None,
+      )
+      .await;
+
+    self
+      .handle_request::<Value, ()>(request_props, |_, _| ())
+      .await
   }
 
   pub(in crate::network::sonarr_network) async fn get_season_releases(
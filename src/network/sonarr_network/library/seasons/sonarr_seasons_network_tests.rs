@@ -2,6 +2,7 @@
 mod tests {
   use crate::models::servarr_data::sonarr::modals::SeasonDetailsModal;
   use crate::models::servarr_data::sonarr::sonarr_data::ActiveSonarrBlock;
+  use crate::models::servarr_models::CustomFormat;
   use crate::models::sonarr_models::{SonarrHistoryItem, SonarrRelease, SonarrSerdeable};
   use crate::network::NetworkResource;
   use crate::network::network_tests::test_utils::{MockServarrApi, test_network};
@@ -82,7 +83,9 @@ mod tests {
         "leechers": 1,
         "languages": [ { "id": 1, "name": "English" } ],
         "quality": { "quality": { "name": "Bluray-1080p" }},
-        "fullSeason": true
+        "fullSeason": true,
+        "customFormats": [{ "id": 1, "name": "x265" }],
+        "customFormatScore": 10
       },
       {
         "guid": "4567",
@@ -102,13 +105,16 @@ mod tests {
     ]);
     let expected_filtered_sonarr_release = SonarrRelease {
       full_season: true,
+      custom_formats: vec![CustomFormat {
+        id: 1,
+        name: "x265".to_owned(),
+        ..CustomFormat::default()
+      }],
+      custom_format_score: 10,
       ..torrent_release()
     };
     let expected_raw_sonarr_releases = vec![
-      SonarrRelease {
-        full_season: true,
-        ..torrent_release()
-      },
+      expected_filtered_sonarr_release.clone(),
       SonarrRelease {
         guid: "4567".to_owned(),
         ..torrent_release()
@@ -1,14 +1,14 @@
 use crate::models::Route;
 use crate::models::servarr_data::sonarr::sonarr_data::ActiveSonarrBlock;
 use crate::models::sonarr_models::{
-  AddSeriesBody, AddSeriesSearchResult, DeleteSeriesParams, EditSeriesParams, Series,
-  SonarrCommandBody, SonarrHistoryItem,
+  AddSeriesBody, AddSeriesSearchResult, BulkDeleteSeriesParams, BulkEditSeriesParams,
+  DeleteSeriesParams, EditSeriesParams, Series, SonarrCommandBody, SonarrHistoryItem,
 };
 use crate::models::stateful_table::StatefulTable;
 use crate::network::sonarr_network::SonarrEvent;
 use crate::network::{Network, RequestMethod};
 use anyhow::Result;
-use log::{debug, info, warn};
+use log::{debug, info};
 use serde_json::{Value, json};
 use urlencoding::encode;
 
@@ -45,6 +45,47 @@ impl Network<'_, '_> {
       .await
   }
 
+  pub(in crate::network::sonarr_network) async fn bulk_delete_series(
+    &mut self,
+    params: BulkDeleteSeriesParams,
+  ) -> Result<()> {
+    let event = SonarrEvent::BulkDeleteSeries(BulkDeleteSeriesParams::default());
+    info!(
+      "Deleting Sonarr series with IDs: {:?} with deleteFiles={} and addImportListExclusion={}",
+      params.series_ids, params.delete_files, params.add_import_list_exclusion
+    );
+
+    let request_props = self
+      .request_props_from(event, RequestMethod::Delete, Some(params), None, None)
+      .await;
+
+    self
+      .handle_request::<BulkDeleteSeriesParams, ()>(request_props, |_, _| ())
+      .await
+  }
+
+  pub(in crate::network::sonarr_network) async fn bulk_edit_series(
+    &mut self,
+    mut params: BulkEditSeriesParams,
+  ) -> Result<()> {
+    info!("Bulk editing Sonarr series");
+    let event = SonarrEvent::BulkEditSeries(BulkEditSeriesParams::default());
+    if let Some(tag_input_str) = params.tag_input_string.as_ref() {
+      let tag_ids_vec = self.extract_and_add_sonarr_tag_ids_vec(tag_input_str).await;
+      params.tags = Some(tag_ids_vec);
+    }
+
+    debug!("Bulk edit series body: {params:?}");
+
+    let request_props = self
+      .request_props_from(event, RequestMethod::Put, Some(params), None, None)
+      .await;
+
+    self
+      .handle_request::<BulkEditSeriesParams, ()>(request_props, |_, _| ())
+      .await
+  }
+
   pub(in crate::network::sonarr_network) async fn delete_series(
     &mut self,
     delete_series_params: DeleteSeriesParams,
@@ -101,17 +142,12 @@ impl Network<'_, '_> {
       )
       .await;
 
-    let mut response = String::new();
-
-    self
-      .handle_request::<(), Value>(request_props, |detailed_series_body, _| {
-        response = detailed_series_body.to_string()
-      })
+    let mut detailed_series_body = self
+      .handle_request::<(), Value>(request_props, |_, _| ())
       .await?;
 
     info!("Constructing edit series body");
 
-    let mut detailed_series_body: Value = serde_json::from_str(&response)?;
     let (
       monitored,
       use_season_folders,
@@ -223,47 +259,35 @@ impl Network<'_, '_> {
       )
       .await;
 
-    let mut response = String::new();
-
-    self
-      .handle_request::<(), Value>(request_props, |detailed_series_body, _| {
-        response = detailed_series_body.to_string()
-      })
+    let mut detailed_series_body = self
+      .handle_request::<(), Value>(request_props, |_, _| ())
       .await?;
 
     info!("Constructing toggle series monitoring body");
 
-    match serde_json::from_str::<Value>(&response) {
-      Ok(mut detailed_series_body) => {
-        let monitored = detailed_series_body
-          .get("monitored")
-          .unwrap()
-          .as_bool()
-          .unwrap();
-
-        *detailed_series_body.get_mut("monitored").unwrap() = json!(!monitored);
-
-        debug!("Toggle series monitoring body: {detailed_series_body:?}");
-
-        let request_props = self
-          .request_props_from(
-            event,
-            RequestMethod::Put,
-            Some(detailed_series_body),
-            Some(format!("/{series_id}")),
-            None,
-          )
-          .await;
-
-        self
-          .handle_request::<Value, ()>(request_props, |_, _| ())
-          .await
-      }
-      Err(_) => {
-        warn!("Request for detailed series body was interrupted");
-        Ok(())
-      }
-    }
+    let monitored = detailed_series_body
+      .get("monitored")
+      .unwrap()
+      .as_bool()
+      .unwrap();
+
+    *detailed_series_body.get_mut("monitored").unwrap() = json!(!monitored);
+
+    debug!("Toggle series monitoring body: {detailed_series_body:?}");
+
+    let request_props = self
+      .request_props_from(
+        event,
+        RequestMethod::Put,
+        Some(detailed_series_body),
+        Some(format!("/{series_id}")),
+        None,
+      )
+      .await;
+
+    self
+      .handle_request::<Value, ()>(request_props, |_, _| ())
+      .await
   }
 
   pub(in crate::network::sonarr_network) async fn get_series_details(
@@ -1,9 +1,11 @@
 #[cfg(test)]
 mod tests {
   use crate::models::servarr_data::sonarr::sonarr_data::ActiveSonarrBlock;
+  use crate::models::servarr_models::ApplyTags;
   use crate::models::sonarr_models::{
-    AddSeriesBody, AddSeriesOptions, DeleteSeriesParams, EditSeriesParams, Series, SeriesMonitor,
-    SeriesType, SonarrHistoryItem, SonarrSerdeable,
+    AddSeriesBody, AddSeriesOptions, BulkDeleteSeriesParams, BulkEditSeriesParams,
+    DeleteSeriesParams, EditSeriesParams, Series, SeriesMonitor, SeriesType, SonarrHistoryItem,
+    SonarrSerdeable,
   };
   use crate::models::stateful_table::{SortOption, StatefulTable};
   use crate::network::NetworkResource;
@@ -125,6 +127,75 @@ mod tests {
     async_server.assert_async().await;
   }
 
+  #[tokio::test]
+  async fn test_handle_bulk_delete_series_event() {
+    let bulk_delete_series_params = BulkDeleteSeriesParams {
+      series_ids: vec![1, 2],
+      delete_files: true,
+      add_import_list_exclusion: true,
+    };
+    let (async_server, app, _server) = MockServarrApi::delete()
+      .with_request_body(json!({
+        "seriesIds": [1, 2],
+        "deleteFiles": true,
+        "addImportListExclusion": true
+      }))
+      .build_for(SonarrEvent::BulkDeleteSeries(
+        bulk_delete_series_params.clone(),
+      ))
+      .await;
+    app.lock().await.server_tabs.next();
+    let mut network = test_network(&app);
+
+    assert!(
+      network
+        .handle_sonarr_event(SonarrEvent::BulkDeleteSeries(bulk_delete_series_params))
+        .await
+        .is_ok()
+    );
+
+    async_server.assert_async().await;
+  }
+
+  #[tokio::test]
+  async fn test_handle_bulk_edit_series_event() {
+    let bulk_edit_series_params = BulkEditSeriesParams {
+      series_ids: vec![1, 2],
+      monitored: Some(false),
+      quality_profile_id: Some(2222),
+      root_folder_path: Some("/nfs2".to_owned()),
+      tag_input_string: Some("usenet, testing".to_owned()),
+      apply_tags: ApplyTags::Remove,
+      move_files: true,
+      ..BulkEditSeriesParams::default()
+    };
+    let (async_server, app, _server) = MockServarrApi::put()
+      .with_request_body(json!({
+        "seriesIds": [1, 2],
+        "monitored": false,
+        "qualityProfileId": 2222,
+        "rootFolderPath": "/nfs2",
+        "tags": [1, 2],
+        "applyTags": "remove",
+        "moveFiles": true
+      }))
+      .build_for(SonarrEvent::BulkEditSeries(bulk_edit_series_params.clone()))
+      .await;
+    app.lock().await.data.sonarr_data.tags_map =
+      BiMap::from_iter([(1, "usenet".to_owned()), (2, "testing".to_owned())]);
+    app.lock().await.server_tabs.next();
+    let mut network = test_network(&app);
+
+    assert!(
+      network
+        .handle_sonarr_event(SonarrEvent::BulkEditSeries(bulk_edit_series_params))
+        .await
+        .is_ok()
+    );
+
+    async_server.assert_async().await;
+  }
+
   #[tokio::test]
   async fn test_handle_delete_series_event() {
     let delete_series_params = DeleteSeriesParams {
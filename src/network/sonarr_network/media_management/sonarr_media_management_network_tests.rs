@@ -0,0 +1,213 @@
+#[cfg(test)]
+mod tests {
+  use crate::models::servarr_data::modals::MediaManagementModal;
+  use crate::models::servarr_data::sonarr::sonarr_data::MEDIA_MANAGEMENT_NAMING_FIELDS;
+  use crate::models::sonarr_models::{NamingConfig, SonarrSerdeable};
+  use crate::network::NetworkResource;
+  use crate::network::network_tests::test_utils::{MockServarrApi, test_network};
+  use crate::network::sonarr_network::SonarrEvent;
+  use crate::network::sonarr_network::sonarr_network_test_utils::test_utils::{
+    media_management_settings, naming_config, naming_examples,
+  };
+  use mockito::Matcher;
+  use pretty_assertions::assert_eq;
+  use serde_json::{Value, json};
+
+  const NAMING_EXAMPLES_QUERY: &str = "animeEpisodeFormat=%7BSeries%20Title%7D%20-%20S%7Bseason%3A00%7DE%7Bepisode%3A00%7D%20-%20%7BEpisode%20Title%7D%20%7BQuality%20Full%7D&dailyEpisodeFormat=%7BSeries%20Title%7D%20-%20%7BAir-Date%7D%20-%20%7BEpisode%20Title%7D%20%7BQuality%20Full%7D&id=1&renameEpisodes=true&replaceIllegalCharacters=true&seasonFolderFormat=Season%20%7Bseason%7D&seriesFolderFormat=%7BSeries%20Title%7D&specialsFolderFormat=Specials&standardEpisodeFormat=%7BSeries%20Title%7D%20-%20S%7Bseason%3A00%7DE%7Bepisode%3A00%7D%20-%20%7BEpisode%20Title%7D%20%7BQuality%20Full%7D";
+
+  fn naming_config_json() -> Value {
+    json!({
+      "id": 1,
+      "renameEpisodes": true,
+      "replaceIllegalCharacters": true,
+      "colonReplacementFormat": 4,
+      "multiEpisodeStyle": 5,
+      "standardEpisodeFormat": "{Series Title} - S{season:00}E{episode:00} - {Episode Title} {Quality Full}",
+      "dailyEpisodeFormat": "{Series Title} - {Air-Date} - {Episode Title} {Quality Full}",
+      "animeEpisodeFormat": "{Series Title} - S{season:00}E{episode:00} - {Episode Title} {Quality Full}",
+      "seriesFolderFormat": "{Series Title}",
+      "seasonFolderFormat": "Season {season}",
+      "specialsFolderFormat": "Specials"
+    })
+  }
+
+  fn media_management_config_json() -> Value {
+    json!({
+      "id": 1,
+      "autoUnmonitorPreviouslyDownloadedEpisodes": false,
+      "recycleBin": "/nfs/recycle",
+      "recycleBinCleanupDays": 7,
+      "setPermissionsLinux": false,
+      "chmodFolder": "755",
+      "chownGroup": "",
+      "importExtraFiles": true,
+      "extraFileExtensions": "srt,nfo"
+    })
+  }
+
+  fn naming_examples_json() -> Value {
+    json!({
+      "singleEpisodeExample": "The Series Title's! (2010) - S01E01 - Episode Title (1) HDTV-720p Proper",
+      "dailyEpisodeExample": "The Series Title's! (2010) - 2013-10-30 - Episode Title (1) HDTV-720p Proper",
+      "animeEpisodeExample": "The Series Title's! (2010) - S01E01 - Episode Title (1) HDTV-720p v2",
+      "seriesFolderExample": "The Series Title's! (2010)",
+      "seasonFolderExample": "Season 1",
+      "specialsFolderExample": "Specials"
+    })
+  }
+
+  #[tokio::test]
+  async fn test_handle_get_sonarr_media_management_settings_event() {
+    let (async_naming_server, app, mut server) = MockServarrApi::get()
+      .returns(naming_config_json())
+      .path("/naming")
+      .build_for(SonarrEvent::GetMediaManagementSettings)
+      .await;
+    let async_media_management_server = server
+      .mock(
+        "GET",
+        format!(
+          "/api/v3{}/mediamanagement",
+          SonarrEvent::GetMediaManagementSettings.resource()
+        )
+        .as_str(),
+      )
+      .with_status(200)
+      .match_header("X-Api-Key", "test1234")
+      .with_body(media_management_config_json().to_string())
+      .create_async()
+      .await;
+    let async_examples_server = server
+      .mock(
+        "GET",
+        format!(
+          "/api/v3{}?{NAMING_EXAMPLES_QUERY}",
+          SonarrEvent::GetNamingExamples(naming_config()).resource()
+        )
+        .as_str(),
+      )
+      .with_status(200)
+      .match_header("X-Api-Key", "test1234")
+      .with_body(naming_examples_json().to_string())
+      .create_async()
+      .await;
+    app.lock().await.server_tabs.next();
+    let mut network = test_network(&app);
+
+    let SonarrSerdeable::MediaManagementSettings(settings) = network
+      .handle_sonarr_event(SonarrEvent::GetMediaManagementSettings)
+      .await
+      .unwrap()
+    else {
+      panic!("Expected MediaManagementSettings")
+    };
+    async_naming_server.assert_async().await;
+    async_media_management_server.assert_async().await;
+    async_examples_server.assert_async().await;
+    assert_eq!(settings, media_management_settings());
+    let app = app.lock().await;
+    assert_eq!(
+      app
+        .data
+        .sonarr_data
+        .media_management_modal
+        .as_ref()
+        .unwrap()
+        .fields
+        .items,
+      MediaManagementModal::new(
+        &media_management_settings(),
+        &MEDIA_MANAGEMENT_NAMING_FIELDS
+      )
+      .fields
+      .items
+    );
+    assert_eq!(
+      app.data.sonarr_data.naming_examples,
+      Some(naming_examples())
+    );
+  }
+
+  #[tokio::test]
+  async fn test_handle_get_sonarr_naming_examples_event() {
+    let (async_server, app, _server) = MockServarrApi::get()
+      .returns(naming_examples_json())
+      .query(NAMING_EXAMPLES_QUERY)
+      .build_for(SonarrEvent::GetNamingExamples(naming_config()))
+      .await;
+    app.lock().await.server_tabs.next();
+    let mut network = test_network(&app);
+
+    let SonarrSerdeable::NamingExamples(examples) = network
+      .handle_sonarr_event(SonarrEvent::GetNamingExamples(naming_config()))
+      .await
+      .unwrap()
+    else {
+      panic!("Expected NamingExamples")
+    };
+    async_server.assert_async().await;
+    assert_eq!(examples, naming_examples());
+    assert_eq!(
+      app.lock().await.data.sonarr_data.naming_examples,
+      Some(naming_examples())
+    );
+  }
+
+  #[tokio::test]
+  async fn test_handle_edit_sonarr_media_management_settings_event() {
+    let mut settings = media_management_settings();
+    settings.naming = NamingConfig {
+      rename_episodes: false,
+      season_folder_format: Some("Season {season:00}".to_owned()),
+      ..naming_config()
+    };
+    settings.media_management.recycle_bin_cleanup_days = 14;
+    let mut expected_naming_body = naming_config_json();
+    expected_naming_body["renameEpisodes"] = json!(false);
+    expected_naming_body["seasonFolderFormat"] = json!("Season {season:00}");
+    let mut expected_media_management_body = media_management_config_json();
+    expected_media_management_body["recycleBinCleanupDays"] = json!(14);
+    let resource = SonarrEvent::EditMediaManagementSettings(settings.clone()).resource();
+
+    let (async_naming_details_server, app, mut server) = MockServarrApi::get()
+      .returns(naming_config_json())
+      .path("/naming")
+      .build_for(SonarrEvent::EditMediaManagementSettings(settings.clone()))
+      .await;
+    let async_naming_edit_server = server
+      .mock("PUT", format!("/api/v3{resource}/naming").as_str())
+      .with_status(202)
+      .match_header("X-Api-Key", "test1234")
+      .match_body(Matcher::Json(expected_naming_body))
+      .create_async()
+      .await;
+    let async_media_management_details_server = server
+      .mock("GET", format!("/api/v3{resource}/mediamanagement").as_str())
+      .with_status(200)
+      .match_header("X-Api-Key", "test1234")
+      .with_body(media_management_config_json().to_string())
+      .create_async()
+      .await;
+    let async_media_management_edit_server = server
+      .mock("PUT", format!("/api/v3{resource}/mediamanagement").as_str())
+      .with_status(202)
+      .match_header("X-Api-Key", "test1234")
+      .match_body(Matcher::Json(expected_media_management_body))
+      .create_async()
+      .await;
+    app.lock().await.server_tabs.next();
+    let mut network = test_network(&app);
+
+    assert!(
+      network
+        .handle_sonarr_event(SonarrEvent::EditMediaManagementSettings(settings))
+        .await
+        .is_ok()
+    );
+
+    async_naming_details_server.assert_async().await;
+    async_naming_edit_server.assert_async().await;
+    async_media_management_details_server.assert_async().await;
+    async_media_management_edit_server.assert_async().await;
+  }
+}
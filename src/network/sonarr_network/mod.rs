@@ -1,15 +1,21 @@
 use anyhow::Result;
+use chrono::NaiveDate;
 use log::info;
 use serde_json::{Value, json};
+use std::path::PathBuf;
 
 use super::{Network, NetworkEvent, NetworkResource};
 use crate::{
   models::{
     servarr_models::{
-      AddRootFolderBody, EditIndexerParams, IndexerSettings, Language, QualityProfile, Tag,
+      AddDownloadClientParams, AddIndexerParams, AddRootFolderBody, BulkDeleteDownloadsParams,
+      DeleteDownloadParams, DownloadBackupParams, EditCustomFormatParams, EditDownloadClientParams,
+      EditIndexerParams, EditQualityProfileParams, IndexerSettings, Language,
+      MediaManagementSettings, QualityProfile, Tag,
     },
     sonarr_models::{
-      AddSeriesBody, DeleteSeriesParams, EditSeriesParams, SonarrReleaseDownloadBody,
+      AddSeriesBody, BulkDeleteSeriesParams, BulkEditSeriesParams, DeleteSeriesParams,
+      EditSeriesParams, NamingConfig, SonarrManualImportFile, SonarrReleaseDownloadBody,
       SonarrSerdeable, SonarrTaskName,
     },
   },
@@ -24,44 +30,76 @@ mod sonarr_network_tests;
 pub mod sonarr_network_test_utils;
 
 mod blocklist;
+mod calendar;
+mod custom_formats;
+mod download_clients;
 mod downloads;
 mod history;
 mod indexers;
 mod library;
+mod media_management;
+mod quality_profiles;
 mod root_folders;
 mod system;
+mod wanted;
 
 #[derive(Debug, Eq, PartialEq, Clone)]
 pub enum SonarrEvent {
+  AddDownloadClient(AddDownloadClientParams),
+  AddIndexer(AddIndexerParams),
   AddRootFolder(AddRootFolderBody),
   AddSeries(AddSeriesBody),
   AddTag(String),
+  BulkDeleteDownloads(BulkDeleteDownloadsParams),
+  BulkDeleteSeries(BulkDeleteSeriesParams),
+  BulkEditSeries(BulkEditSeriesParams),
   ClearBlocklist,
+  DeleteBackup(i64),
   DeleteBlocklistItem(i64),
-  DeleteDownload(i64),
+  DeleteDownload(DeleteDownloadParams),
   DeleteEpisodeFile(i64),
   DeleteIndexer(i64),
   DeleteRootFolder(i64),
   DeleteSeries(DeleteSeriesParams),
   DeleteTag(i64),
+  DownloadBackup(DownloadBackupParams),
   DownloadRelease(SonarrReleaseDownloadBody),
   EditAllIndexerSettings(IndexerSettings),
   EditIndexer(EditIndexerParams),
+  EditMediaManagementSettings(MediaManagementSettings<NamingConfig>),
   EditSeries(EditSeriesParams),
   GetAllIndexerSettings,
+  GetBackups,
   GetBlocklist,
+  GetCalendar(NaiveDate, NaiveDate),
+  GetCutoffUnmet(u64),
   GetDownloads(u64),
   GetHistory(u64),
   GetHostConfig,
   GetIndexers,
+  GetIndexerSchema,
+  GetDownloadClients,
+  GetDownloadClientSchema,
+  DeleteDownloadClient(i64),
+  EditDownloadClient(EditDownloadClientParams),
   GetEpisodeDetails(i64),
   GetEpisodes(i64),
   GetEpisodeFiles(i64),
   GetEpisodeHistory(i64),
   GetLanguageProfiles,
   GetLogs(u64),
+  GetManualImport(String, Option<i64>),
+  GetMediaManagementSettings,
+  GetNamingExamples(NamingConfig),
   GetDiskSpace,
   GetQualityProfiles,
+  GetQualityProfileDetails(i64),
+  EditQualityProfile(EditQualityProfileParams),
+  GetCustomFormats,
+  DeleteCustomFormat(i64),
+  EditCustomFormat(EditCustomFormatParams),
+  ImportCustomFormat(String),
+  ExportCustomFormat(i64),
   GetQueuedEvents,
   GetRootFolders,
   GetEpisodeReleases(i64),
@@ -74,19 +112,27 @@ pub enum SonarrEvent {
   GetUpdates,
   GetTags,
   GetTasks,
+  GetWantedMissing(u64),
   HealthCheck,
   ListSeries,
+  ManualImport(Vec<SonarrManualImportFile>),
+  GrabQueueItem(i64),
   MarkHistoryItemAsFailed(i64),
+  RestoreBackup(PathBuf),
   SearchNewSeries(String),
   StartTask(SonarrTaskName),
   TestIndexer(i64),
   TestAllIndexers,
+  TestDownloadClient(i64),
+  TestAllDownloadClients,
   ToggleSeasonMonitoring(i64, i64),
   ToggleSeriesMonitoring(i64),
   ToggleEpisodeMonitoring(i64),
   TriggerAutomaticEpisodeSearch(i64),
   TriggerAutomaticSeasonSearch(i64, i64),
   TriggerAutomaticSeriesSearch(i64),
+  TriggerCutoffUnmetEpisodeSearch,
+  TriggerMissingEpisodeSearch,
   UpdateAllSeries,
   UpdateAndScanSeries(i64),
   UpdateDownloads,
@@ -96,6 +142,10 @@ impl NetworkResource for SonarrEvent {
   fn resource(&self) -> &'static str {
     match &self {
       SonarrEvent::AddTag(_) | SonarrEvent::DeleteTag(_) | SonarrEvent::GetTags => "/tag",
+      SonarrEvent::GetBackups | SonarrEvent::DeleteBackup(_) | SonarrEvent::DownloadBackup(_) => {
+        "/system/backup"
+      }
+      SonarrEvent::RestoreBackup(_) => "/system/backup/restore/upload",
       SonarrEvent::ClearBlocklist => "/blocklist/bulk",
       SonarrEvent::DownloadRelease(_) => "/release",
       SonarrEvent::DeleteBlocklistItem(_) => "/blocklist",
@@ -104,22 +154,50 @@ impl NetworkResource for SonarrEvent {
       }
       SonarrEvent::GetEpisodeFiles(_) | SonarrEvent::DeleteEpisodeFile(_) => "/episodefile",
       SonarrEvent::GetBlocklist => "/blocklist?page=1&pageSize=10000",
+      SonarrEvent::GetCalendar(_, _) => "/calendar",
+      SonarrEvent::GetCutoffUnmet(_) => "/wanted/cutoff",
       SonarrEvent::GetDownloads(_) | SonarrEvent::DeleteDownload(_) => "/queue",
+      SonarrEvent::BulkDeleteDownloads(_) => "/queue/bulk",
+      SonarrEvent::GrabQueueItem(_) => "/queue/grab",
       SonarrEvent::GetEpisodes(_) | SonarrEvent::GetEpisodeDetails(_) => "/episode",
       SonarrEvent::GetHistory(_) | SonarrEvent::GetEpisodeHistory(_) => "/history",
       SonarrEvent::GetHostConfig | SonarrEvent::GetSecurityConfig => "/config/host",
-      SonarrEvent::GetIndexers | SonarrEvent::DeleteIndexer(_) | SonarrEvent::EditIndexer(_) => {
-        "/indexer"
-      }
+      SonarrEvent::AddIndexer(_)
+      | SonarrEvent::GetIndexers
+      | SonarrEvent::DeleteIndexer(_)
+      | SonarrEvent::EditIndexer(_) => "/indexer",
+      SonarrEvent::GetIndexerSchema => "/indexer/schema",
+      SonarrEvent::AddDownloadClient(_)
+      | SonarrEvent::GetDownloadClients
+      | SonarrEvent::DeleteDownloadClient(_)
+      | SonarrEvent::EditDownloadClient(_) => "/downloadclient",
+      SonarrEvent::GetDownloadClientSchema => "/downloadclient/schema",
+      SonarrEvent::TestDownloadClient(_) => "/downloadclient/test",
+      SonarrEvent::TestAllDownloadClients => "/downloadclient/testall",
       SonarrEvent::GetLanguageProfiles => "/language",
       SonarrEvent::GetLogs(_) => "/log",
+      SonarrEvent::GetManualImport(_, _) => "/manualimport",
+      SonarrEvent::GetMediaManagementSettings | SonarrEvent::EditMediaManagementSettings(_) => {
+        "/config"
+      }
+      SonarrEvent::GetNamingExamples(_) => "/config/naming/examples",
       SonarrEvent::GetDiskSpace => "/diskspace",
-      SonarrEvent::GetQualityProfiles => "/qualityprofile",
+      SonarrEvent::GetQualityProfiles
+      | SonarrEvent::GetQualityProfileDetails(_)
+      | SonarrEvent::EditQualityProfile(_) => "/qualityprofile",
+      SonarrEvent::GetCustomFormats
+      | SonarrEvent::DeleteCustomFormat(_)
+      | SonarrEvent::EditCustomFormat(_)
+      | SonarrEvent::ImportCustomFormat(_)
+      | SonarrEvent::ExportCustomFormat(_) => "/customformat",
       SonarrEvent::GetQueuedEvents
+      | SonarrEvent::ManualImport(_)
       | SonarrEvent::StartTask(_)
       | SonarrEvent::TriggerAutomaticSeriesSearch(_)
       | SonarrEvent::TriggerAutomaticSeasonSearch(_, _)
       | SonarrEvent::TriggerAutomaticEpisodeSearch(_)
+      | SonarrEvent::TriggerCutoffUnmetEpisodeSearch
+      | SonarrEvent::TriggerMissingEpisodeSearch
       | SonarrEvent::UpdateAllSeries
       | SonarrEvent::UpdateAndScanSeries(_)
       | SonarrEvent::UpdateDownloads => "/command",
@@ -131,6 +209,7 @@ impl NetworkResource for SonarrEvent {
       SonarrEvent::GetStatus => "/system/status",
       SonarrEvent::GetTasks => "/system/task",
       SonarrEvent::GetUpdates => "/update",
+      SonarrEvent::GetWantedMissing(_) => "/wanted/missing",
       SonarrEvent::HealthCheck => "/health",
       SonarrEvent::AddSeries(_)
       | SonarrEvent::ListSeries
@@ -139,6 +218,7 @@ impl NetworkResource for SonarrEvent {
       | SonarrEvent::EditSeries(_)
       | SonarrEvent::ToggleSeasonMonitoring(_, _)
       | SonarrEvent::ToggleSeriesMonitoring(_) => "/series",
+      SonarrEvent::BulkDeleteSeries(_) | SonarrEvent::BulkEditSeries(_) => "/series/editor",
       SonarrEvent::SearchNewSeries(_) => "/series/lookup",
       SonarrEvent::MarkHistoryItemAsFailed(_) => "/history/failed",
       SonarrEvent::TestIndexer(_) => "/indexer/test",
@@ -146,6 +226,53 @@ impl NetworkResource for SonarrEvent {
       SonarrEvent::ToggleEpisodeMonitoring(_) => "/episode/monitor",
     }
   }
+
+  fn is_read_only(&self) -> bool {
+    matches!(
+      self,
+      SonarrEvent::GetAllIndexerSettings
+        | SonarrEvent::GetBackups
+        | SonarrEvent::GetBlocklist
+        | SonarrEvent::GetCalendar(..)
+        | SonarrEvent::GetCutoffUnmet(..)
+        | SonarrEvent::GetDownloads(..)
+        | SonarrEvent::GetHistory(..)
+        | SonarrEvent::GetHostConfig
+        | SonarrEvent::GetIndexers
+        | SonarrEvent::GetIndexerSchema
+        | SonarrEvent::GetDownloadClients
+        | SonarrEvent::GetDownloadClientSchema
+        | SonarrEvent::GetEpisodeDetails(..)
+        | SonarrEvent::GetEpisodes(..)
+        | SonarrEvent::GetEpisodeFiles(..)
+        | SonarrEvent::GetEpisodeHistory(..)
+        | SonarrEvent::GetLanguageProfiles
+        | SonarrEvent::GetLogs(..)
+        | SonarrEvent::GetManualImport(..)
+        | SonarrEvent::GetMediaManagementSettings
+        | SonarrEvent::GetNamingExamples(..)
+        | SonarrEvent::GetDiskSpace
+        | SonarrEvent::GetQualityProfiles
+        | SonarrEvent::GetQualityProfileDetails(..)
+        | SonarrEvent::GetCustomFormats
+        | SonarrEvent::GetQueuedEvents
+        | SonarrEvent::GetRootFolders
+        | SonarrEvent::GetEpisodeReleases(..)
+        | SonarrEvent::GetSeasonHistory(..)
+        | SonarrEvent::GetSeasonReleases(..)
+        | SonarrEvent::GetSecurityConfig
+        | SonarrEvent::GetSeriesDetails(..)
+        | SonarrEvent::GetSeriesHistory(..)
+        | SonarrEvent::GetStatus
+        | SonarrEvent::GetUpdates
+        | SonarrEvent::GetTags
+        | SonarrEvent::GetTasks
+        | SonarrEvent::GetWantedMissing(..)
+        | SonarrEvent::HealthCheck
+        | SonarrEvent::ListSeries
+        | SonarrEvent::SearchNewSeries(..)
+    )
+  }
 }
 
 impl From<SonarrEvent> for NetworkEvent {
@@ -168,7 +295,27 @@ impl Network<'_, '_> {
         .add_sonarr_series(body)
         .await
         .map(SonarrSerdeable::from),
+      SonarrEvent::AddDownloadClient(params) => self
+        .add_sonarr_download_client(params)
+        .await
+        .map(SonarrSerdeable::from),
+      SonarrEvent::AddIndexer(params) => self
+        .add_sonarr_indexer(params)
+        .await
+        .map(SonarrSerdeable::from),
       SonarrEvent::AddTag(tag) => self.add_sonarr_tag(tag).await.map(SonarrSerdeable::from),
+      SonarrEvent::BulkDeleteDownloads(params) => self
+        .bulk_delete_sonarr_downloads(params)
+        .await
+        .map(SonarrSerdeable::from),
+      SonarrEvent::BulkDeleteSeries(params) => self
+        .bulk_delete_series(params)
+        .await
+        .map(SonarrSerdeable::from),
+      SonarrEvent::BulkEditSeries(params) => self
+        .bulk_edit_series(params)
+        .await
+        .map(SonarrSerdeable::from),
       SonarrEvent::ClearBlocklist => self
         .clear_sonarr_blocklist()
         .await
@@ -177,12 +324,16 @@ impl Network<'_, '_> {
         .get_all_sonarr_indexer_settings()
         .await
         .map(SonarrSerdeable::from),
+      SonarrEvent::DeleteBackup(backup_id) => self
+        .delete_sonarr_backup(backup_id)
+        .await
+        .map(SonarrSerdeable::from),
       SonarrEvent::DeleteBlocklistItem(blocklist_item_id) => self
         .delete_sonarr_blocklist_item(blocklist_item_id)
         .await
         .map(SonarrSerdeable::from),
-      SonarrEvent::DeleteDownload(download_id) => self
-        .delete_sonarr_download(download_id)
+      SonarrEvent::DeleteDownload(params) => self
+        .delete_sonarr_download(params)
         .await
         .map(SonarrSerdeable::from),
       SonarrEvent::DeleteEpisodeFile(episode_file_id) => self
@@ -204,6 +355,10 @@ impl Network<'_, '_> {
         .delete_sonarr_tag(tag_id)
         .await
         .map(SonarrSerdeable::from),
+      SonarrEvent::DownloadBackup(params) => self
+        .download_sonarr_backup(params)
+        .await
+        .map(SonarrSerdeable::from),
       SonarrEvent::DownloadRelease(sonarr_release_download_body) => self
         .download_sonarr_release(sonarr_release_download_body)
         .await
@@ -216,15 +371,32 @@ impl Network<'_, '_> {
         .edit_sonarr_indexer(params)
         .await
         .map(SonarrSerdeable::from),
+      SonarrEvent::EditMediaManagementSettings(params) => self
+        .edit_sonarr_media_management_settings(params)
+        .await
+        .map(SonarrSerdeable::from),
       SonarrEvent::EditSeries(params) => self
         .edit_sonarr_series(params)
         .await
         .map(SonarrSerdeable::from),
+      SonarrEvent::GetBackups => self.get_sonarr_backups().await.map(SonarrSerdeable::from),
       SonarrEvent::GetBlocklist => self.get_sonarr_blocklist().await.map(SonarrSerdeable::from),
+      SonarrEvent::GetCalendar(start, end) => self
+        .get_sonarr_calendar(start, end)
+        .await
+        .map(SonarrSerdeable::from),
+      SonarrEvent::GetCutoffUnmet(count) => self
+        .get_sonarr_cutoff_unmet(count)
+        .await
+        .map(SonarrSerdeable::from),
       SonarrEvent::GetDownloads(count) => self
         .get_sonarr_downloads(count)
         .await
         .map(SonarrSerdeable::from),
+      SonarrEvent::GrabQueueItem(queue_item_id) => self
+        .grab_sonarr_queue_item(queue_item_id)
+        .await
+        .map(SonarrSerdeable::from),
       SonarrEvent::GetEpisodes(series_id) => self
         .get_episodes(series_id)
         .await
@@ -250,6 +422,26 @@ impl Network<'_, '_> {
         .await
         .map(SonarrSerdeable::from),
       SonarrEvent::GetIndexers => self.get_sonarr_indexers().await.map(SonarrSerdeable::from),
+      SonarrEvent::GetIndexerSchema => self
+        .get_sonarr_indexer_schema()
+        .await
+        .map(SonarrSerdeable::from),
+      SonarrEvent::GetDownloadClients => self
+        .get_sonarr_download_clients()
+        .await
+        .map(SonarrSerdeable::from),
+      SonarrEvent::GetDownloadClientSchema => self
+        .get_sonarr_download_client_schema()
+        .await
+        .map(SonarrSerdeable::from),
+      SonarrEvent::DeleteDownloadClient(download_client_id) => self
+        .delete_sonarr_download_client(download_client_id)
+        .await
+        .map(SonarrSerdeable::from),
+      SonarrEvent::EditDownloadClient(params) => self
+        .edit_sonarr_download_client(params)
+        .await
+        .map(SonarrSerdeable::from),
       SonarrEvent::GetLanguageProfiles => self
         .get_sonarr_language_profiles()
         .await
@@ -258,11 +450,51 @@ impl Network<'_, '_> {
         .get_sonarr_logs(events)
         .await
         .map(SonarrSerdeable::from),
+      SonarrEvent::GetManualImport(download_id, series_id) => self
+        .get_sonarr_manual_import(download_id, series_id)
+        .await
+        .map(SonarrSerdeable::from),
+      SonarrEvent::GetMediaManagementSettings => self
+        .get_sonarr_media_management_settings()
+        .await
+        .map(SonarrSerdeable::from),
+      SonarrEvent::GetNamingExamples(naming_config) => self
+        .get_sonarr_naming_examples(naming_config)
+        .await
+        .map(SonarrSerdeable::from),
       SonarrEvent::GetDiskSpace => self.get_sonarr_diskspace().await.map(SonarrSerdeable::from),
       SonarrEvent::GetQualityProfiles => self
         .get_sonarr_quality_profiles()
         .await
         .map(SonarrSerdeable::from),
+      SonarrEvent::GetQualityProfileDetails(quality_profile_id) => self
+        .get_sonarr_quality_profile_details(quality_profile_id)
+        .await
+        .map(SonarrSerdeable::from),
+      SonarrEvent::EditQualityProfile(params) => self
+        .edit_sonarr_quality_profile(params)
+        .await
+        .map(SonarrSerdeable::from),
+      SonarrEvent::GetCustomFormats => self
+        .get_sonarr_custom_formats()
+        .await
+        .map(SonarrSerdeable::from),
+      SonarrEvent::DeleteCustomFormat(custom_format_id) => self
+        .delete_sonarr_custom_format(custom_format_id)
+        .await
+        .map(SonarrSerdeable::from),
+      SonarrEvent::EditCustomFormat(params) => self
+        .edit_sonarr_custom_format(params)
+        .await
+        .map(SonarrSerdeable::from),
+      SonarrEvent::ImportCustomFormat(custom_format_json) => self
+        .import_sonarr_custom_format(custom_format_json)
+        .await
+        .map(SonarrSerdeable::from),
+      SonarrEvent::ExportCustomFormat(custom_format_id) => self
+        .export_sonarr_custom_format(custom_format_id)
+        .await
+        .map(SonarrSerdeable::from),
       SonarrEvent::GetQueuedEvents => self
         .get_queued_sonarr_events()
         .await
@@ -299,15 +531,27 @@ impl Network<'_, '_> {
       SonarrEvent::GetTags => self.get_sonarr_tags().await.map(SonarrSerdeable::from),
       SonarrEvent::GetTasks => self.get_sonarr_tasks().await.map(SonarrSerdeable::from),
       SonarrEvent::GetUpdates => self.get_sonarr_updates().await.map(SonarrSerdeable::from),
+      SonarrEvent::GetWantedMissing(count) => self
+        .get_sonarr_wanted_missing(count)
+        .await
+        .map(SonarrSerdeable::from),
       SonarrEvent::HealthCheck => self
         .get_sonarr_healthcheck()
         .await
         .map(SonarrSerdeable::from),
       SonarrEvent::ListSeries => self.list_series().await.map(SonarrSerdeable::from),
+      SonarrEvent::ManualImport(files) => self
+        .manual_import_sonarr_files(files)
+        .await
+        .map(SonarrSerdeable::from),
       SonarrEvent::MarkHistoryItemAsFailed(history_item_id) => self
         .mark_sonarr_history_item_as_failed(history_item_id)
         .await
         .map(SonarrSerdeable::from),
+      SonarrEvent::RestoreBackup(file) => self
+        .restore_sonarr_backup(file)
+        .await
+        .map(SonarrSerdeable::from),
       SonarrEvent::SearchNewSeries(query) => self
         .search_sonarr_series(query)
         .await
@@ -324,6 +568,14 @@ impl Network<'_, '_> {
         .test_all_sonarr_indexers()
         .await
         .map(SonarrSerdeable::from),
+      SonarrEvent::TestDownloadClient(download_client_id) => self
+        .test_sonarr_download_client(download_client_id)
+        .await
+        .map(SonarrSerdeable::from),
+      SonarrEvent::TestAllDownloadClients => self
+        .test_all_sonarr_download_clients()
+        .await
+        .map(SonarrSerdeable::from),
       SonarrEvent::ToggleEpisodeMonitoring(episode_id) => self
         .toggle_sonarr_episode_monitoring(episode_id)
         .await
@@ -348,6 +600,14 @@ impl Network<'_, '_> {
         .trigger_automatic_episode_search(episode_id)
         .await
         .map(SonarrSerdeable::from),
+      SonarrEvent::TriggerCutoffUnmetEpisodeSearch => self
+        .trigger_cutoff_unmet_episode_search()
+        .await
+        .map(SonarrSerdeable::from),
+      SonarrEvent::TriggerMissingEpisodeSearch => self
+        .trigger_missing_episode_search()
+        .await
+        .map(SonarrSerdeable::from),
       SonarrEvent::UpdateAllSeries => self.update_all_series().await.map(SonarrSerdeable::from),
       SonarrEvent::UpdateAndScanSeries(series_id) => self
         .update_and_scan_series(series_id)
@@ -442,9 +702,14 @@ impl Network<'_, '_> {
     self
       .handle_request::<(), Vec<QualityProfile>>(request_props, |quality_profiles, mut app| {
         app.data.sonarr_data.quality_profile_map = quality_profiles
-          .into_iter()
-          .map(|profile| (profile.id, profile.name))
+          .iter()
+          .map(|profile| (profile.id, profile.name.clone()))
           .collect();
+        app
+          .data
+          .sonarr_data
+          .quality_profiles
+          .set_items(quality_profiles);
       })
       .await
   }
@@ -481,11 +746,14 @@ impl Network<'_, '_> {
         .collect::<Vec<&str>>()
     };
 
+    let mut added_tags = Vec::new();
     for tag in missing_tags_vec {
-      self
-        .add_sonarr_tag(tag.trim().to_owned())
-        .await
-        .expect("Unable to add tag");
+      added_tags.push(
+        self
+          .add_sonarr_tag(tag.trim().to_owned())
+          .await
+          .expect("Unable to add tag"),
+      );
     }
 
     let app = self.app.lock().await;
@@ -493,11 +761,13 @@ impl Network<'_, '_> {
       .split(',')
       .filter(|tag| !tag.is_empty())
       .map(|tag| {
-        *app
-          .data
-          .sonarr_data
-          .tags_map
-          .get_by_right(tag.to_lowercase().trim())
+        let label = tag.to_lowercase();
+        let label = label.trim();
+        added_tags
+          .iter()
+          .find(|added_tag| added_tag.label.to_lowercase() == label)
+          .map(|added_tag| added_tag.id)
+          .or_else(|| app.data.sonarr_data.tags_map.get_by_right(label).copied())
           .unwrap()
       })
       .collect()
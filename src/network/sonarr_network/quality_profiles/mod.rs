@@ -0,0 +1,86 @@
+use crate::models::servarr_models::{EditQualityProfileParams, QualityProfile};
+use crate::network::sonarr_network::SonarrEvent;
+use crate::network::utils::apply_quality_profile_edits;
+use crate::network::{Network, RequestMethod};
+use anyhow::Result;
+use log::{debug, info};
+use serde_json::Value;
+
+#[cfg(test)]
+#[path = "sonarr_quality_profiles_network_tests.rs"]
+mod sonarr_quality_profiles_network_tests;
+
+impl Network<'_, '_> {
+  pub(in crate::network::sonarr_network) async fn get_sonarr_quality_profile_details(
+    &mut self,
+    quality_profile_id: i64,
+  ) -> Result<QualityProfile> {
+    info!(
+      "Fetching Sonarr quality profile details for quality profile with ID: {quality_profile_id}"
+    );
+    let event = SonarrEvent::GetQualityProfileDetails(quality_profile_id);
+
+    let request_props = self
+      .request_props_from(
+        event,
+        RequestMethod::Get,
+        None::<()>,
+        Some(format!("/{quality_profile_id}")),
+        None,
+      )
+      .await;
+
+    self
+      .handle_request::<(), QualityProfile>(request_props, |_, _| ())
+      .await
+  }
+
+  pub(in crate::network::sonarr_network) async fn edit_sonarr_quality_profile(
+    &mut self,
+    edit_quality_profile_params: EditQualityProfileParams,
+  ) -> Result<()> {
+    let detail_event = SonarrEvent::GetQualityProfileDetails(0);
+    let event = SonarrEvent::EditQualityProfile(EditQualityProfileParams::default());
+    let id = edit_quality_profile_params.quality_profile_id;
+    info!("Updating Sonarr quality profile with ID: {id}");
+
+    info!("Fetching quality profile details for quality profile with ID: {id}");
+
+    let request_props = self
+      .request_props_from(
+        detail_event,
+        RequestMethod::Get,
+        None::<()>,
+        Some(format!("/{id}")),
+        None,
+      )
+      .await;
+
+    let mut detailed_quality_profile_body = self
+      .handle_request::<(), Value>(request_props, |_, _| ())
+      .await?;
+
+    info!("Constructing edit quality profile body");
+
+    apply_quality_profile_edits(
+      &mut detailed_quality_profile_body,
+      edit_quality_profile_params,
+    )?;
+
+    debug!("Edit quality profile body: {detailed_quality_profile_body:?}");
+
+    let request_props = self
+      .request_props_from(
+        event,
+        RequestMethod::Put,
+        Some(detailed_quality_profile_body),
+        Some(format!("/{id}")),
+        None,
+      )
+      .await;
+
+    self
+      .handle_request::<Value, ()>(request_props, |_, _| ())
+      .await
+  }
+}
@@ -1,15 +1,17 @@
 #[cfg(test)]
 pub mod test_utils {
   use crate::models::servarr_models::{
-    Indexer, IndexerField, Language, Quality, QualityWrapper, RootFolder,
+    Indexer, IndexerField, Language, MediaManagementSettings, Quality, QualityWrapper, RootFolder,
   };
   use crate::models::sonarr_models::{
     AddSeriesSearchResult, AddSeriesSearchResultStatistics, BlocklistItem, DownloadRecord,
-    DownloadStatus, DownloadsResponse, Episode, EpisodeFile, MediaInfo, Rating, Season,
-    SeasonStatistics, Series, SeriesStatistics, SeriesStatus, SeriesType, SonarrHistoryData,
-    SonarrHistoryEventType, SonarrHistoryItem, SonarrRelease, SonarrTask, SonarrTaskName,
+    DownloadStatus, DownloadsResponse, Episode, EpisodeFile, MediaInfo, NamingConfig,
+    NamingExamples, Rating, Season, SeasonStatistics, Series, SeriesStatistics, SeriesStatus,
+    SeriesType, SonarrHistoryData, SonarrHistoryEventType, SonarrHistoryItem,
+    SonarrManualImportFile, SonarrManualImportItem, SonarrRelease, SonarrTask, SonarrTaskName,
   };
   use crate::models::{HorizontallyScrollableText, ScrollableText};
+  use crate::network::servarr_test_utils::media_management_config;
   use bimap::BiMap;
   use chrono::DateTime;
   use indoc::formatdoc;
@@ -148,6 +150,8 @@ pub mod test_utils {
       )),
       indexer: "kickass torrents".to_owned(),
       download_client: Some("transmission".to_owned()),
+      download_id: Some("abc123".to_owned()),
+      estimated_completion_time: None,
     }
   }
 
@@ -236,14 +240,17 @@ pub mod test_utils {
         IndexerField {
           name: Some("baseUrl".to_owned()),
           value: Some(json!("https://test.com")),
+          ..IndexerField::default()
         },
         IndexerField {
           name: Some("apiKey".to_owned()),
           value: Some(json!("")),
+          ..IndexerField::default()
         },
         IndexerField {
           name: Some("seedCriteria.seedRatio".to_owned()),
           value: Some(json!("1.2")),
+          ..IndexerField::default()
         },
       ]),
     }
@@ -256,6 +263,36 @@ pub mod test_utils {
     }
   }
 
+  pub fn manual_import_item() -> SonarrManualImportItem {
+    SonarrManualImportItem {
+      id: 1,
+      path: "/nfs/downloads/Test/Test.S01E01.mkv".to_owned(),
+      relative_path: "Test/Test.S01E01.mkv".to_owned(),
+      size: 3543348019,
+      series: Some(series()),
+      season_number: Some(1),
+      episodes: vec![episode()],
+      quality: json!({ "quality": { "id": 7, "name": "Bluray-1080p" } }),
+      languages: vec![language()],
+      release_group: Some("SPARKS".to_owned()),
+      download_id: Some("abc123".to_owned()),
+      rejections: Vec::new(),
+    }
+  }
+
+  pub fn manual_import_file() -> SonarrManualImportFile {
+    SonarrManualImportFile {
+      path: "/nfs/downloads/Test/Test.S01E01.mkv".to_owned(),
+      series_id: 1,
+      season_number: 1,
+      episode_ids: vec![1],
+      quality: json!({ "quality": { "id": 7, "name": "Bluray-1080p" } }),
+      languages: vec![language()],
+      release_group: Some("SPARKS".to_owned()),
+      download_id: Some("abc123".to_owned()),
+    }
+  }
+
   pub fn media_info() -> MediaInfo {
     MediaInfo {
       audio_bitrate: 0,
@@ -374,6 +411,8 @@ pub mod test_utils {
       languages: Some(vec![Some(language())]),
       quality: quality_wrapper(),
       full_season: false,
+      custom_formats: Vec::new(),
+      custom_format_score: 0,
     }
   }
 
@@ -393,6 +432,8 @@ pub mod test_utils {
       languages: Some(vec![Some(language())]),
       quality: quality_wrapper(),
       full_season: false,
+      custom_formats: Vec::new(),
+      custom_format_score: 0,
     }
   }
 
@@ -406,6 +447,50 @@ pub mod test_utils {
     }
   }
 
+  pub fn naming_config() -> NamingConfig {
+    NamingConfig {
+      id: 1,
+      rename_episodes: true,
+      replace_illegal_characters: true,
+      standard_episode_format: Some(
+        "{Series Title} - S{season:00}E{episode:00} - {Episode Title} {Quality Full}".to_owned(),
+      ),
+      daily_episode_format: Some(
+        "{Series Title} - {Air-Date} - {Episode Title} {Quality Full}".to_owned(),
+      ),
+      anime_episode_format: Some(
+        "{Series Title} - S{season:00}E{episode:00} - {Episode Title} {Quality Full}".to_owned(),
+      ),
+      series_folder_format: Some("{Series Title}".to_owned()),
+      season_folder_format: Some("Season {season}".to_owned()),
+      specials_folder_format: Some("Specials".to_owned()),
+    }
+  }
+
+  pub fn naming_examples() -> NamingExamples {
+    NamingExamples {
+      single_episode_example: Some(
+        "The Series Title's! (2010) - S01E01 - Episode Title (1) HDTV-720p Proper".to_owned(),
+      ),
+      daily_episode_example: Some(
+        "The Series Title's! (2010) - 2013-10-30 - Episode Title (1) HDTV-720p Proper".to_owned(),
+      ),
+      anime_episode_example: Some(
+        "The Series Title's! (2010) - S01E01 - Episode Title (1) HDTV-720p v2".to_owned(),
+      ),
+      series_folder_example: Some("The Series Title's! (2010)".to_owned()),
+      season_folder_example: Some("Season 1".to_owned()),
+      specials_folder_example: Some("Specials".to_owned()),
+    }
+  }
+
+  pub fn media_management_settings() -> MediaManagementSettings<NamingConfig> {
+    MediaManagementSettings {
+      naming: naming_config(),
+      media_management: media_management_config(),
+    }
+  }
+
   pub fn tag() -> Value {
     json!({
       "id": 3,
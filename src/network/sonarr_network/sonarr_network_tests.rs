@@ -3,14 +3,22 @@ mod test {
   use crate::app::App;
   use crate::models::servarr_data::sonarr::modals::AddSeriesModal;
   use crate::models::servarr_models::{
-    AddRootFolderBody, EditIndexerParams, IndexerSettings, Language, QualityProfile, Tag,
+    AddDownloadClientParams, AddIndexerParams, AddRootFolderBody, BulkDeleteDownloadsParams,
+    DeleteDownloadParams, EditCustomFormatParams, EditDownloadClientParams, EditIndexerParams,
+    EditQualityProfileParams, IndexerSettings, Language, MediaManagementSettings, QualityProfile,
+    Tag,
+  };
+  use crate::models::sonarr_models::{
+    AddSeriesBody, EditSeriesParams, NamingConfig, SonarrTaskName,
+  };
+  use crate::models::sonarr_models::{
+    BulkDeleteSeriesParams, BulkEditSeriesParams, DeleteSeriesParams, SonarrSerdeable,
   };
-  use crate::models::sonarr_models::{AddSeriesBody, EditSeriesParams, SonarrTaskName};
-  use crate::models::sonarr_models::{DeleteSeriesParams, SonarrSerdeable};
   use crate::network::network_tests::test_utils::{MockServarrApi, test_network};
   use crate::network::sonarr_network::sonarr_network_test_utils::test_utils::tag;
   use crate::network::{NetworkEvent, NetworkResource, sonarr_network::SonarrEvent};
   use bimap::BiMap;
+  use chrono::NaiveDate;
   use pretty_assertions::{assert_eq, assert_str_eq};
   use rstest::rstest;
   use serde_json::json;
@@ -28,6 +36,17 @@ mod test {
     assert_str_eq!(event.resource(), "/config/indexer");
   }
 
+  #[rstest]
+  fn test_resource_media_management_settings(
+    #[values(
+      SonarrEvent::GetMediaManagementSettings,
+      SonarrEvent::EditMediaManagementSettings(MediaManagementSettings::default())
+    )]
+    event: SonarrEvent,
+  ) {
+    assert_str_eq!(event.resource(), "/config");
+  }
+
   #[rstest]
   fn test_resource_episode(
     #[values(SonarrEvent::GetEpisodes(0), SonarrEvent::GetEpisodeDetails(0))] event: SonarrEvent,
@@ -74,10 +93,13 @@ mod test {
   fn test_resource_command(
     #[values(
       SonarrEvent::GetQueuedEvents,
+      SonarrEvent::ManualImport(Vec::new()),
       SonarrEvent::StartTask(SonarrTaskName::default()),
       SonarrEvent::TriggerAutomaticEpisodeSearch(0),
       SonarrEvent::TriggerAutomaticSeasonSearch(0, 0),
       SonarrEvent::TriggerAutomaticSeriesSearch(0),
+      SonarrEvent::TriggerCutoffUnmetEpisodeSearch,
+      SonarrEvent::TriggerMissingEpisodeSearch,
       SonarrEvent::UpdateAllSeries,
       SonarrEvent::UpdateAndScanSeries(0),
       SonarrEvent::UpdateDownloads
@@ -90,6 +112,7 @@ mod test {
   #[rstest]
   fn test_resource_indexer(
     #[values(
+      SonarrEvent::AddIndexer(AddIndexerParams::default()),
       SonarrEvent::GetIndexers,
       SonarrEvent::DeleteIndexer(0),
       SonarrEvent::EditIndexer(EditIndexerParams::default())
@@ -99,6 +122,19 @@ mod test {
     assert_str_eq!(event.resource(), "/indexer");
   }
 
+  #[rstest]
+  fn test_resource_download_client(
+    #[values(
+      SonarrEvent::AddDownloadClient(AddDownloadClientParams::default()),
+      SonarrEvent::GetDownloadClients,
+      SonarrEvent::DeleteDownloadClient(0),
+      SonarrEvent::EditDownloadClient(EditDownloadClientParams::default())
+    )]
+    event: SonarrEvent,
+  ) {
+    assert_str_eq!(event.resource(), "/downloadclient");
+  }
+
   #[rstest]
   fn test_resource_history(
     #[values(SonarrEvent::GetHistory(0), SonarrEvent::GetEpisodeHistory(0))] event: SonarrEvent,
@@ -116,7 +152,11 @@ mod test {
 
   #[rstest]
   fn test_resource_queue(
-    #[values(SonarrEvent::GetDownloads(0), SonarrEvent::DeleteDownload(0))] event: SonarrEvent,
+    #[values(
+      SonarrEvent::GetDownloads(0),
+      SonarrEvent::DeleteDownload(DeleteDownloadParams::default())
+    )]
+    event: SonarrEvent,
   ) {
     assert_str_eq!(event.resource(), "/queue");
   }
@@ -154,20 +194,61 @@ mod test {
 
   #[rstest]
   #[case(SonarrEvent::ClearBlocklist, "/blocklist/bulk")]
+  #[case(SonarrEvent::GetIndexerSchema, "/indexer/schema")]
+  #[case(
+    SonarrEvent::BulkDeleteDownloads(BulkDeleteDownloadsParams::default()),
+    "/queue/bulk"
+  )]
+  #[case(SonarrEvent::GrabQueueItem(1), "/queue/grab")]
   #[case(SonarrEvent::DeleteBlocklistItem(0), "/blocklist")]
   #[case(SonarrEvent::HealthCheck, "/health")]
   #[case(SonarrEvent::GetBlocklist, "/blocklist?page=1&pageSize=10000")]
+  #[case(
+    SonarrEvent::GetCalendar(NaiveDate::default(), NaiveDate::default()),
+    "/calendar"
+  )]
+  #[case(SonarrEvent::GetCutoffUnmet(0), "/wanted/cutoff")]
+  #[case(SonarrEvent::GetWantedMissing(0), "/wanted/missing")]
   #[case(SonarrEvent::GetDiskSpace, "/diskspace")]
   #[case(SonarrEvent::GetLanguageProfiles, "/language")]
   #[case(SonarrEvent::GetLogs(500), "/log")]
+  #[case(SonarrEvent::GetManualImport(String::new(), None), "/manualimport")]
+  #[case(
+    SonarrEvent::GetNamingExamples(NamingConfig::default()),
+    "/config/naming/examples"
+  )]
   #[case(SonarrEvent::GetQualityProfiles, "/qualityprofile")]
+  #[case(SonarrEvent::GetQualityProfileDetails(0), "/qualityprofile")]
+  #[case(
+    SonarrEvent::EditQualityProfile(EditQualityProfileParams::default()),
+    "/qualityprofile"
+  )]
+  #[case(SonarrEvent::GetCustomFormats, "/customformat")]
+  #[case(SonarrEvent::DeleteCustomFormat(0), "/customformat")]
+  #[case(
+    SonarrEvent::EditCustomFormat(EditCustomFormatParams::default()),
+    "/customformat"
+  )]
+  #[case(SonarrEvent::ImportCustomFormat(String::new()), "/customformat")]
+  #[case(SonarrEvent::ExportCustomFormat(0), "/customformat")]
   #[case(SonarrEvent::GetStatus, "/system/status")]
   #[case(SonarrEvent::GetTasks, "/system/task")]
   #[case(SonarrEvent::GetUpdates, "/update")]
   #[case(SonarrEvent::MarkHistoryItemAsFailed(0), "/history/failed")]
   #[case(SonarrEvent::SearchNewSeries(String::new()), "/series/lookup")]
+  #[case(
+    SonarrEvent::BulkDeleteSeries(BulkDeleteSeriesParams::default()),
+    "/series/editor"
+  )]
+  #[case(
+    SonarrEvent::BulkEditSeries(BulkEditSeriesParams::default()),
+    "/series/editor"
+  )]
   #[case(SonarrEvent::TestIndexer(0), "/indexer/test")]
   #[case(SonarrEvent::TestAllIndexers, "/indexer/testall")]
+  #[case(SonarrEvent::GetDownloadClientSchema, "/downloadclient/schema")]
+  #[case(SonarrEvent::TestDownloadClient(0), "/downloadclient/test")]
+  #[case(SonarrEvent::TestAllDownloadClients, "/downloadclient/testall")]
   #[case(SonarrEvent::ToggleEpisodeMonitoring(0), "/episode/monitor")]
   fn test_resource(#[case] event: SonarrEvent, #[case] expected_uri: String) {
     assert_str_eq!(event.resource(), expected_uri);
@@ -314,6 +395,10 @@ mod test {
       BiMap::from_iter([(2222i64, "HD - 1080p".to_owned())])
     );
     assert_eq!(quality_profiles, response);
+    assert_eq!(
+      app.lock().await.data.sonarr_data.quality_profiles.items,
+      response
+    );
   }
 
   #[tokio::test]
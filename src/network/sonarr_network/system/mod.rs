@@ -1,5 +1,6 @@
 use crate::models::servarr_models::{
-  CommandBody, DiskSpace, HostConfig, LogResponse, QueueEvent, SecurityConfig, Update,
+  Backup, CommandBody, DiskSpace, DownloadBackupParams, HostConfig, LogResponse, QueueEvent,
+  SecurityConfig, Update,
 };
 use crate::models::sonarr_models::{SonarrTask, SonarrTaskName, SystemStatus};
 use crate::models::{HorizontallyScrollableText, Scrollable, ScrollableText};
@@ -9,12 +10,68 @@ use anyhow::Result;
 use indoc::formatdoc;
 use log::info;
 use serde_json::Value;
+use std::path::PathBuf;
 
 #[cfg(test)]
 #[path = "sonarr_system_network_tests.rs"]
 mod sonarr_system_network_tests;
 
 impl Network<'_, '_> {
+  pub(in crate::network::sonarr_network) async fn delete_sonarr_backup(
+    &mut self,
+    backup_id: i64,
+  ) -> Result<()> {
+    info!("Deleting Sonarr backup with id: {backup_id}");
+    let event = SonarrEvent::DeleteBackup(backup_id);
+
+    let request_props = self
+      .request_props_from(
+        event,
+        RequestMethod::Delete,
+        None::<()>,
+        Some(format!("/{backup_id}")),
+        None,
+      )
+      .await;
+
+    self
+      .handle_request::<(), ()>(request_props, |_, _| ())
+      .await
+  }
+
+  pub(in crate::network::sonarr_network) async fn download_sonarr_backup(
+    &mut self,
+    params: DownloadBackupParams,
+  ) -> Result<()> {
+    info!(
+      "Downloading Sonarr backup '{}' to: {}",
+      params.backup_path,
+      params.destination.display()
+    );
+    let event = SonarrEvent::DownloadBackup(params.clone());
+
+    self
+      .download_file(event, &params.backup_path, &params.destination)
+      .await
+  }
+
+  pub(in crate::network::sonarr_network) async fn get_sonarr_backups(
+    &mut self,
+  ) -> Result<Vec<Backup>> {
+    info!("Fetching Sonarr backups");
+    let event = SonarrEvent::GetBackups;
+
+    let request_props = self
+      .request_props_from(event, RequestMethod::Get, None::<()>, None, None)
+      .await;
+
+    self
+      .handle_request::<(), Vec<Backup>>(request_props, |backups_vec, mut app| {
+        app.data.sonarr_data.backups.set_items(backups_vec);
+      })
+      .await
+  }
+
   pub(in crate::network::sonarr_network) async fn get_sonarr_host_config(
     &mut self,
   ) -> Result<HostConfig> {
@@ -254,6 +311,16 @@ impl Network<'_, '_> {
       .await
   }
 
+  pub(in crate::network::sonarr_network) async fn restore_sonarr_backup(
+    &mut self,
+    file: PathBuf,
+  ) -> Result<Value> {
+    info!("Restoring Sonarr backup from: {}", file.display());
+    let event = SonarrEvent::RestoreBackup(file.clone());
+
+    self.upload_file(event, &file).await
+  }
+
   pub(in crate::network::sonarr_network) async fn start_sonarr_task(
     &mut self,
     task: SonarrTaskName,
@@ -0,0 +1,114 @@
+use crate::models::sonarr_models::{SonarrCommandBody, WantedEpisodesResponse};
+use crate::network::sonarr_network::SonarrEvent;
+use crate::network::{Network, RequestMethod};
+use anyhow::Result;
+use log::info;
+use serde_json::Value;
+
+#[cfg(test)]
+#[path = "sonarr_wanted_network_tests.rs"]
+mod sonarr_wanted_network_tests;
+
+impl Network<'_, '_> {
+  pub(in crate::network::sonarr_network) async fn get_sonarr_wanted_missing(
+    &mut self,
+    count: u64,
+  ) -> Result<WantedEpisodesResponse> {
+    info!("Fetching missing Sonarr episodes");
+    let event = SonarrEvent::GetWantedMissing(count);
+
+    let request_props = self
+      .request_props_from(
+        event,
+        RequestMethod::Get,
+        None::<()>,
+        None,
+        Some(wanted_query_params(count)),
+      )
+      .await;
+
+    self
+      .handle_request::<(), WantedEpisodesResponse>(request_props, |wanted_response, mut app| {
+        app
+          .data
+          .sonarr_data
+          .wanted_missing
+          .set_items(wanted_response.records);
+      })
+      .await
+  }
+
+  pub(in crate::network::sonarr_network) async fn get_sonarr_cutoff_unmet(
+    &mut self,
+    count: u64,
+  ) -> Result<WantedEpisodesResponse> {
+    info!("Fetching Sonarr episodes that have not met their quality cutoff");
+    let event = SonarrEvent::GetCutoffUnmet(count);
+
+    let request_props = self
+      .request_props_from(
+        event,
+        RequestMethod::Get,
+        None::<()>,
+        None,
+        Some(wanted_query_params(count)),
+      )
+      .await;
+
+    self
+      .handle_request::<(), WantedEpisodesResponse>(request_props, |wanted_response, mut app| {
+        app
+          .data
+          .sonarr_data
+          .cutoff_unmet
+          .set_items(wanted_response.records);
+      })
+      .await
+  }
+
+  pub(in crate::network::sonarr_network) async fn trigger_missing_episode_search(
+    &mut self,
+  ) -> Result<Value> {
+    info!("Searching indexers for all missing episodes");
+    let event = SonarrEvent::TriggerMissingEpisodeSearch;
+
+    let body = SonarrCommandBody {
+      name: "MissingEpisodeSearch".to_owned(),
+      ..SonarrCommandBody::default()
+    };
+
+    let request_props = self
+      .request_props_from(event, RequestMethod::Post, Some(body), None, None)
+      .await;
+
+    self
+      .handle_request::<SonarrCommandBody, Value>(request_props, |_, _| ())
+      .await
+  }
+
+  pub(in crate::network::sonarr_network) async fn trigger_cutoff_unmet_episode_search(
+    &mut self,
+  ) -> Result<Value> {
+    info!("Searching indexers for all episodes that have not met their quality cutoff");
+    let event = SonarrEvent::TriggerCutoffUnmetEpisodeSearch;
+
+    let body = SonarrCommandBody {
+      name: "CutoffUnmetEpisodeSearch".to_owned(),
+      ..SonarrCommandBody::default()
+    };
+
+    let request_props = self
+      .request_props_from(event, RequestMethod::Post, Some(body), None, None)
+      .await;
+
+    self
+      .handle_request::<SonarrCommandBody, Value>(request_props, |_, _| ())
+      .await
+  }
+}
+
+fn wanted_query_params(count: u64) -> String {
+  format!(
+    "page=1&pageSize={count}&sortDirection=descending&sortKey=episodes.airDateUtc&monitored=true"
+  )
+}
@@ -0,0 +1,108 @@
+#[cfg(test)]
+mod tests {
+  use crate::models::sonarr_models::{SonarrSerdeable, WantedEpisodesResponse};
+  use crate::network::network_tests::test_utils::{MockServarrApi, test_network};
+  use crate::network::sonarr_network::SonarrEvent;
+  use crate::network::sonarr_network::sonarr_network_test_utils::test_utils::episode;
+  use pretty_assertions::assert_eq;
+  use serde_json::json;
+
+  const WANTED_QUERY: &str =
+    "page=1&pageSize=500&sortDirection=descending&sortKey=episodes.airDateUtc&monitored=true";
+
+  #[tokio::test]
+  async fn test_handle_get_sonarr_wanted_missing_event() {
+    let expected_response = WantedEpisodesResponse {
+      records: vec![episode()],
+    };
+    let (mock, app, _server) = MockServarrApi::get()
+      .query(WANTED_QUERY)
+      .returns(json!({ "records": [episode()] }))
+      .build_for(SonarrEvent::GetWantedMissing(500))
+      .await;
+    app.lock().await.server_tabs.next();
+    let mut network = test_network(&app);
+
+    let SonarrSerdeable::WantedEpisodesResponse(response) = network
+      .handle_sonarr_event(SonarrEvent::GetWantedMissing(500))
+      .await
+      .unwrap()
+    else {
+      panic!("Expected WantedEpisodesResponse")
+    };
+
+    mock.assert_async().await;
+    assert_eq!(
+      app.lock().await.data.sonarr_data.wanted_missing.items,
+      vec![episode()]
+    );
+    assert_eq!(response, expected_response);
+  }
+
+  #[tokio::test]
+  async fn test_handle_get_sonarr_cutoff_unmet_event() {
+    let expected_response = WantedEpisodesResponse {
+      records: vec![episode()],
+    };
+    let (mock, app, _server) = MockServarrApi::get()
+      .query(WANTED_QUERY)
+      .returns(json!({ "records": [episode()] }))
+      .build_for(SonarrEvent::GetCutoffUnmet(500))
+      .await;
+    app.lock().await.server_tabs.next();
+    let mut network = test_network(&app);
+
+    let SonarrSerdeable::WantedEpisodesResponse(response) = network
+      .handle_sonarr_event(SonarrEvent::GetCutoffUnmet(500))
+      .await
+      .unwrap()
+    else {
+      panic!("Expected WantedEpisodesResponse")
+    };
+
+    mock.assert_async().await;
+    assert_eq!(
+      app.lock().await.data.sonarr_data.cutoff_unmet.items,
+      vec![episode()]
+    );
+    assert_eq!(response, expected_response);
+  }
+
+  #[tokio::test]
+  async fn test_handle_trigger_missing_episode_search_event() {
+    let (mock, app, _server) = MockServarrApi::post()
+      .with_request_body(json!({ "name": "MissingEpisodeSearch" }))
+      .returns(json!({}))
+      .build_for(SonarrEvent::TriggerMissingEpisodeSearch)
+      .await;
+    app.lock().await.server_tabs.next();
+    let mut network = test_network(&app);
+
+    assert_ok!(
+      network
+        .handle_sonarr_event(SonarrEvent::TriggerMissingEpisodeSearch)
+        .await
+    );
+
+    mock.assert_async().await;
+  }
+
+  #[tokio::test]
+  async fn test_handle_trigger_cutoff_unmet_episode_search_event() {
+    let (mock, app, _server) = MockServarrApi::post()
+      .with_request_body(json!({ "name": "CutoffUnmetEpisodeSearch" }))
+      .returns(json!({}))
+      .build_for(SonarrEvent::TriggerCutoffUnmetEpisodeSearch)
+      .await;
+    app.lock().await.server_tabs.next();
+    let mut network = test_network(&app);
+
+    assert_ok!(
+      network
+        .handle_sonarr_event(SonarrEvent::TriggerCutoffUnmetEpisodeSearch)
+        .await
+    );
+
+    mock.assert_async().await;
+  }
+}
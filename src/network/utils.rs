@@ -1,6 +1,413 @@
-use reqwest::Response;
+use anyhow::{Context, Result, anyhow};
+use itertools::Itertools;
+use serde::Serialize;
 use serde::de::DeserializeOwned;
+use serde_json::{Value, json};
+use urlencoding::encode;
 
-pub async fn parse_response<T: DeserializeOwned>(response: Response) -> Result<T, reqwest::Error> {
-  response.json::<T>().await
+use crate::models::servarr_models::{
+  AddDownloadClientParams, AddIndexerParams, EditCustomFormatParams, EditQualityProfileParams,
+};
+
+#[cfg(test)]
+#[path = "utils_tests.rs"]
+mod utils_tests;
+
+pub fn parse_response<T: DeserializeOwned>(response_body: &str) -> serde_json::Result<T> {
+  serde_json::from_str(response_body)
+}
+
+/// Builds the body for a new indexer by filling in the schema of the requested implementation
+/// with the given settings and field values.
+///
+/// The settings that differ between the Servarrs are only set when the schema has them; e.g.
+/// Prowlarr indexers are enabled as a whole and belong to an app profile, rather than being
+/// enabled separately for RSS and searches
+pub fn indexer_body_from_schema(schemas: Vec<Value>, params: &AddIndexerParams) -> Result<Value> {
+  let mut body = find_schema(schemas, &params.implementation, "indexer")?;
+
+  body["name"] = json!(params.name);
+  body["priority"] = json!(params.priority);
+  body["tags"] = json!(params.tags);
+  let optional_settings = [
+    ("enable", Some(json!(params.enable))),
+    ("enableRss", Some(json!(params.enable_rss))),
+    (
+      "enableAutomaticSearch",
+      Some(json!(params.enable_automatic_search)),
+    ),
+    (
+      "enableInteractiveSearch",
+      Some(json!(params.enable_interactive_search)),
+    ),
+    ("appProfileId", params.app_profile_id.map(|id| json!(id))),
+  ];
+  for (key, value) in optional_settings {
+    if let Some(value) = value
+      && body.get(key).is_some()
+    {
+      body[key] = value;
+    }
+  }
+  apply_schema_field_values(&mut body, &params.fields, &params.implementation, "indexer")?;
+
+  Ok(body)
+}
+
+/// Builds the body for a new download client by filling in the schema of the requested
+/// implementation with the given settings and field values
+pub fn download_client_body_from_schema(
+  schemas: Vec<Value>,
+  params: &AddDownloadClientParams,
+) -> Result<Value> {
+  let mut body = find_schema(schemas, &params.implementation, "download client")?;
+
+  body["name"] = json!(params.name);
+  body["enable"] = json!(params.enable);
+  body["priority"] = json!(params.priority);
+  body["removeCompletedDownloads"] = json!(params.remove_completed_downloads);
+  body["removeFailedDownloads"] = json!(params.remove_failed_downloads);
+  body["tags"] = json!(params.tags);
+  apply_schema_field_values(
+    &mut body,
+    &params.fields,
+    &params.implementation,
+    "download client",
+  )?;
+
+  Ok(body)
+}
+
+/// Sets the values of the given implementation specific fields in a schema-based body (e.g. an
+/// indexer or download client), parsing each value into the type its field expects
+pub fn apply_schema_field_values(
+  body: &mut Value,
+  field_values: &[(String, String)],
+  implementation: &str,
+  resource: &str,
+) -> Result<()> {
+  let fields = body["fields"]
+    .as_array_mut()
+    .with_context(|| format!("Failed to get {resource} 'fields' array"))?;
+  for (name, value) in field_values {
+    let field = fields
+      .iter_mut()
+      .find(|field| field["name"] == name.as_str())
+      .with_context(|| {
+        format!(
+          "Field '{name}' does not exist for the '{implementation}' {resource} implementation"
+        )
+      })?;
+    field["value"] = parse_schema_field_value(field, value.trim())?;
+  }
+
+  Ok(())
+}
+
+/// Applies the requested changes to the body of an existing quality profile. Qualities and the
+/// cutoff are referred to by name and resolved against the quality items of the profile
+pub fn apply_quality_profile_edits(
+  body: &mut Value,
+  params: EditQualityProfileParams,
+) -> Result<()> {
+  let EditQualityProfileParams {
+    name,
+    upgrade_allowed,
+    cutoff,
+    min_format_score,
+    cutoff_format_score,
+    items,
+    allowed_qualities,
+    disallowed_qualities,
+    ..
+  } = params;
+
+  if let Some(name) = name {
+    body["name"] = json!(name);
+  }
+  if let Some(upgrade_allowed) = upgrade_allowed {
+    body["upgradeAllowed"] = json!(upgrade_allowed);
+  }
+  if let Some(min_format_score) = min_format_score {
+    body["minFormatScore"] = json!(min_format_score);
+  }
+  if let Some(cutoff_format_score) = cutoff_format_score {
+    body["cutoffFormatScore"] = json!(cutoff_format_score);
+  }
+  if let Some(items) = items {
+    body["items"] = json!(items);
+  }
+
+  let profile_items = body["items"]
+    .as_array_mut()
+    .context("Failed to get quality profile 'items' array")?;
+  let allowed_changes = allowed_qualities
+    .iter()
+    .map(|quality| (quality, true))
+    .chain(disallowed_qualities.iter().map(|quality| (quality, false)));
+  for (quality, allowed) in allowed_changes {
+    let item = find_quality_profile_item(profile_items, quality)
+      .with_context(|| format!("Quality '{quality}' does not exist in the quality profile"))?;
+    item["allowed"] = json!(allowed);
+  }
+
+  if let Some(cutoff) = cutoff {
+    let cutoff_id = profile_items
+      .iter()
+      .find(|item| quality_profile_item_name(item).eq_ignore_ascii_case(&cutoff))
+      .map(|item| {
+        if item["quality"].is_object() {
+          item["quality"]["id"].clone()
+        } else {
+          item["id"].clone()
+        }
+      })
+      .with_context(|| {
+        format!("The cutoff '{cutoff}' is not a quality or quality group in the quality profile")
+      })?;
+    body["cutoff"] = cutoff_id;
+  }
+
+  Ok(())
+}
+
+/// Builds the body for a new custom format from its JSON definition. Both the format used by the
+/// TRaSH guides and the one exported by the Servarr web UI are supported, so the `trash_*` keys and
+/// any ID are dropped and specification fields given as a `{name: value}` object are converted
+/// into the array of fields that the API expects
+pub fn custom_format_body_from_json(custom_format_json: &str) -> Result<Value> {
+  let mut body: Value =
+    serde_json::from_str(custom_format_json).context("The custom format is not valid JSON")?;
+  let custom_format = body
+    .as_object_mut()
+    .context("The custom format must be a JSON object")?;
+  custom_format.retain(|key, _| key != "id" && !key.starts_with("trash_"));
+  if custom_format
+    .get("name")
+    .and_then(Value::as_str)
+    .is_none_or(str::is_empty)
+  {
+    return Err(anyhow!("The custom format must have a name"));
+  }
+
+  let specifications = custom_format
+    .entry("specifications")
+    .or_insert_with(|| json!([]))
+    .as_array_mut()
+    .context("The custom format 'specifications' must be an array")?;
+  for specification in specifications {
+    if let Some(fields) = specification["fields"].as_object() {
+      specification["fields"] = Value::Array(
+        fields
+          .iter()
+          .map(|(name, value)| json!({ "name": name, "value": value }))
+          .collect(),
+      );
+    }
+  }
+
+  Ok(body)
+}
+
+/// Applies the requested changes to the body of an existing custom format. Specification settings
+/// are named `<specification>.<field>`, where the field is either one of the specification's
+/// fields, or `negate` or `required`
+pub fn apply_custom_format_edits(body: &mut Value, params: EditCustomFormatParams) -> Result<()> {
+  let EditCustomFormatParams {
+    name,
+    include_custom_format_when_renaming,
+    fields,
+    ..
+  } = params;
+
+  if let Some(name) = name {
+    body["name"] = json!(name);
+  }
+  if let Some(include_custom_format_when_renaming) = include_custom_format_when_renaming {
+    body["includeCustomFormatWhenRenaming"] = json!(include_custom_format_when_renaming);
+  }
+
+  let specifications = body["specifications"]
+    .as_array_mut()
+    .context("Failed to get custom format 'specifications' array")?;
+  for (name, value) in fields {
+    let (specification_name, field_name) = name
+      .rsplit_once('.')
+      .with_context(|| format!("'{name}' must be in the form '<specification>.<field>'"))?;
+    let specification = specifications
+      .iter_mut()
+      .find(|specification| specification["name"] == specification_name)
+      .with_context(|| {
+        format!("Specification '{specification_name}' does not exist in the custom format")
+      })?;
+    let value = value.trim();
+
+    match field_name {
+      "negate" | "required" => {
+        specification[field_name] = value
+          .parse::<bool>()
+          .map(Value::Bool)
+          .map_err(|_| anyhow!("Field '{name}' expects 'true' or 'false', but got '{value}'"))?
+      }
+      _ => {
+        let implementation = specification["implementation"]
+          .as_str()
+          .unwrap_or_default()
+          .to_owned();
+        apply_schema_field_values(
+          specification,
+          &[(field_name.to_owned(), value.to_owned())],
+          &implementation,
+          "custom format specification",
+        )?
+      }
+    }
+  }
+
+  Ok(())
+}
+
+/// Overwrites the settings in the body of a config with the given ones. Any settings that aren't
+/// part of the given config are left as they are
+pub fn apply_config_edits<T: Serialize>(body: &mut Value, config: &T) -> Result<()> {
+  let body = body
+    .as_object_mut()
+    .context("The config body must be a JSON object")?;
+  let Value::Object(settings) = serde_json::to_value(config)? else {
+    return Err(anyhow!("The config settings must be a JSON object"));
+  };
+  body.extend(settings);
+
+  Ok(())
+}
+
+/// Builds the query string for the naming examples endpoint, which takes the naming config as query
+/// parameters
+pub fn naming_examples_query<T: Serialize>(naming_config: &T) -> Result<String> {
+  let Value::Object(settings) = serde_json::to_value(naming_config)? else {
+    return Err(anyhow!("The naming config must be a JSON object"));
+  };
+
+  Ok(
+    settings
+      .iter()
+      .filter_map(|(name, value)| {
+        let value = match value {
+          Value::Null => return None,
+          Value::String(value) => value.clone(),
+          value => value.to_string(),
+        };
+        Some(format!("{name}={}", encode(&value)))
+      })
+      .join("&"),
+  )
+}
+
+fn quality_profile_item_name(item: &Value) -> &str {
+  item["quality"]["name"]
+    .as_str()
+    .or_else(|| item["name"].as_str())
+    .unwrap_or_default()
+}
+
+fn find_quality_profile_item<'a>(items: &'a mut [Value], name: &str) -> Option<&'a mut Value> {
+  for item in items {
+    if quality_profile_item_name(item).eq_ignore_ascii_case(name) {
+      return Some(item);
+    }
+
+    if let Some(group_item) = item["items"]
+      .as_array_mut()
+      .and_then(|group_items| find_quality_profile_item(group_items, name))
+    {
+      return Some(group_item);
+    }
+  }
+
+  None
+}
+
+/// Finds the schema of the given implementation. Prowlarr has a schema for every indexer
+/// definition, most of which share the `Cardigann` implementation, so a schema whose
+/// `definitionName` matches is preferred over one whose `implementation` does
+fn find_schema(schemas: Vec<Value>, implementation: &str, resource: &str) -> Result<Value> {
+  let matches = |schema: &Value, key: &str| {
+    schema[key]
+      .as_str()
+      .is_some_and(|name| name.eq_ignore_ascii_case(implementation))
+  };
+  let index = schemas
+    .iter()
+    .position(|schema| matches(schema, "definitionName"))
+    .or_else(|| {
+      schemas
+        .iter()
+        .position(|schema| matches(schema, "implementation"))
+    })
+    .with_context(|| format!("No {resource} implementation named '{implementation}' was found"))?;
+
+  Ok(schemas.into_iter().nth(index).unwrap_or_default())
+}
+
+fn parse_schema_field_value(field: &Value, value: &str) -> Result<Value> {
+  let name = field["name"].as_str().unwrap_or_default();
+
+  if field["value"].is_array() || field["type"] == "tag" {
+    return Ok(Value::Array(
+      value
+        .split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .map(|item| {
+          item
+            .parse::<i64>()
+            .map_or_else(|_| json!(item), |id| json!(id))
+        })
+        .collect(),
+    ));
+  }
+
+  match field["type"].as_str() {
+    Some("number") if value.is_empty() => Ok(Value::Null),
+    Some("number") => value
+      .parse::<i64>()
+      .map(|number| json!(number))
+      .or_else(|_| value.parse::<f64>().map(|number| json!(number)))
+      .map_err(|_| anyhow!("Field '{name}' expects a number, but got '{value}'")),
+    Some("checkbox") => value
+      .parse::<bool>()
+      .map(Value::Bool)
+      .map_err(|_| anyhow!("Field '{name}' expects 'true' or 'false', but got '{value}'")),
+    Some("select") => {
+      let Some(options) = field["selectOptions"].as_array() else {
+        return Ok(
+          value
+            .parse::<i64>()
+            .map_or_else(|_| json!(value), |id| json!(id)),
+        );
+      };
+
+      options
+        .iter()
+        .find(|option| {
+          option["value"]
+            .as_i64()
+            .is_some_and(|option_value| value.parse() == Ok(option_value))
+            || option["name"]
+              .as_str()
+              .is_some_and(|option_name| option_name.eq_ignore_ascii_case(value))
+        })
+        .map(|option| option["value"].clone())
+        .with_context(|| {
+          format!(
+            "'{value}' is not a valid option for field '{name}'. Valid options are: {}",
+            options
+              .iter()
+              .map(|option| option["name"].as_str().unwrap_or_default())
+              .join(", ")
+          )
+        })
+    }
+    _ => Ok(json!(value)),
+  }
 }
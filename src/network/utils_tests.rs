@@ -0,0 +1,703 @@
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+  use rstest::rstest;
+  use serde_json::{Value, json};
+
+  use crate::models::servarr_models::{
+    AddDownloadClientParams, AddIndexerParams, EditCustomFormatParams, EditQualityProfileParams,
+  };
+  use crate::network::utils::{
+    apply_config_edits, apply_custom_format_edits, apply_quality_profile_edits,
+    apply_schema_field_values, custom_format_body_from_json, download_client_body_from_schema,
+    indexer_body_from_schema, naming_examples_query, parse_response,
+  };
+
+  fn indexer_schema_json() -> Vec<Value> {
+    vec![
+      json!({
+        "implementation": "Newznab",
+        "implementationName": "Newznab",
+        "protocol": "usenet",
+        "name": "",
+        "enableRss": false,
+        "enableAutomaticSearch": false,
+        "enableInteractiveSearch": false,
+        "priority": 25,
+        "tags": [],
+        "fields": [
+          { "name": "baseUrl", "type": "textbox", "value": "" },
+          { "name": "apiKey", "type": "textbox" }
+        ]
+      }),
+      json!({
+        "implementation": "Torznab",
+        "implementationName": "Torznab",
+        "protocol": "torrent",
+        "name": "",
+        "enableRss": false,
+        "enableAutomaticSearch": false,
+        "enableInteractiveSearch": false,
+        "priority": 25,
+        "tags": [],
+        "fields": [
+          { "name": "baseUrl", "type": "textbox", "value": "" },
+          { "name": "minimumSeeders", "type": "number", "value": 1 },
+          { "name": "seedCriteria.seedRatio", "type": "number" },
+          { "name": "rejectBlocklistedTorrentHashesWhileGrabbing", "type": "checkbox", "value": false },
+          { "name": "categories", "type": "select", "value": [] },
+          {
+            "name": "animeStandardFormatSearch",
+            "type": "select",
+            "value": 0,
+            "selectOptions": [
+              { "value": 0, "name": "Disabled" },
+              { "value": 1, "name": "Enabled" }
+            ]
+          },
+          { "name": "requiredFlags", "type": "tag" }
+        ]
+      }),
+    ]
+  }
+
+  fn add_indexer_params(fields: Vec<(&str, &str)>) -> AddIndexerParams {
+    AddIndexerParams {
+      implementation: "torznab".to_owned(),
+      name: "Test".to_owned(),
+      enable_rss: true,
+      enable_automatic_search: false,
+      enable_interactive_search: true,
+      priority: 1,
+      tags: vec![1, 2],
+      fields: fields
+        .into_iter()
+        .map(|(name, value)| (name.to_owned(), value.to_owned()))
+        .collect(),
+      ..AddIndexerParams::default()
+    }
+  }
+
+  fn field_value(body: &Value, name: &str) -> Value {
+    body["fields"]
+      .as_array()
+      .unwrap()
+      .iter()
+      .find(|field| field["name"] == name)
+      .unwrap()["value"]
+      .clone()
+  }
+
+  #[test]
+  fn test_parse_response() {
+    let response: Value = parse_response(r#"{"id": 1}"#).unwrap();
+
+    assert_eq!(response, json!({ "id": 1 }));
+  }
+
+  #[test]
+  fn test_indexer_body_from_schema_sets_general_settings() {
+    let body =
+      indexer_body_from_schema(indexer_schema_json(), &add_indexer_params(vec![])).unwrap();
+
+    assert_eq!(body["implementation"], "Torznab");
+    assert_eq!(body["name"], "Test");
+    assert_eq!(body["enableRss"], true);
+    assert_eq!(body["enableAutomaticSearch"], false);
+    assert_eq!(body["enableInteractiveSearch"], true);
+    assert_eq!(body["priority"], 1);
+    assert_eq!(body["tags"], json!([1, 2]));
+    assert_none!(body.get("enable"));
+    assert_none!(body.get("appProfileId"));
+  }
+
+  #[test]
+  fn test_indexer_body_from_schema_prowlarr_definition() {
+    let schemas = vec![
+      json!({
+        "implementation": "Cardigann",
+        "definitionName": "1337x",
+        "enable": false,
+        "appProfileId": 0,
+        "fields": []
+      }),
+      json!({
+        "implementation": "Cardigann",
+        "definitionName": "cardigann",
+        "enable": false,
+        "appProfileId": 0,
+        "fields": []
+      }),
+    ];
+    let params = AddIndexerParams {
+      implementation: "cardigann".to_owned(),
+      enable: false,
+      app_profile_id: Some(2),
+      ..add_indexer_params(vec![])
+    };
+
+    let body = indexer_body_from_schema(schemas, &params).unwrap();
+
+    assert_eq!(body["definitionName"], "cardigann");
+    assert_eq!(body["enable"], false);
+    assert_eq!(body["appProfileId"], 2);
+    assert_none!(body.get("enableRss"));
+    assert_none!(body.get("enableAutomaticSearch"));
+    assert_none!(body.get("enableInteractiveSearch"));
+  }
+
+  #[test]
+  fn test_indexer_body_from_schema_parses_field_values() {
+    let params = add_indexer_params(vec![
+      ("baseUrl", " https://test.com "),
+      ("minimumSeeders", "5"),
+      ("seedCriteria.seedRatio", "1.5"),
+      ("rejectBlocklistedTorrentHashesWhileGrabbing", "true"),
+      ("categories", "2000, 5000"),
+      ("animeStandardFormatSearch", "enabled"),
+      ("requiredFlags", "1,freeleech"),
+    ]);
+
+    let body = indexer_body_from_schema(indexer_schema_json(), &params).unwrap();
+
+    assert_eq!(field_value(&body, "baseUrl"), json!("https://test.com"));
+    assert_eq!(field_value(&body, "minimumSeeders"), json!(5));
+    assert_eq!(field_value(&body, "seedCriteria.seedRatio"), json!(1.5));
+    assert_eq!(
+      field_value(&body, "rejectBlocklistedTorrentHashesWhileGrabbing"),
+      json!(true)
+    );
+    assert_eq!(field_value(&body, "categories"), json!([2000, 5000]));
+    assert_eq!(field_value(&body, "animeStandardFormatSearch"), json!(1));
+    assert_eq!(field_value(&body, "requiredFlags"), json!([1, "freeleech"]));
+  }
+
+  #[test]
+  fn test_indexer_body_from_schema_select_option_by_value() {
+    let params = add_indexer_params(vec![("animeStandardFormatSearch", "1")]);
+
+    let body = indexer_body_from_schema(indexer_schema_json(), &params).unwrap();
+
+    assert_eq!(field_value(&body, "animeStandardFormatSearch"), json!(1));
+  }
+
+  #[test]
+  fn test_indexer_body_from_schema_empty_number_is_null() {
+    let params = add_indexer_params(vec![("minimumSeeders", "")]);
+
+    let body = indexer_body_from_schema(indexer_schema_json(), &params).unwrap();
+
+    assert_eq!(field_value(&body, "minimumSeeders"), Value::Null);
+  }
+
+  #[test]
+  fn test_indexer_body_from_schema_unknown_implementation() {
+    let params = AddIndexerParams {
+      implementation: "Test".to_owned(),
+      ..add_indexer_params(vec![])
+    };
+
+    let result = indexer_body_from_schema(indexer_schema_json(), &params);
+
+    assert_eq!(
+      result.unwrap_err().to_string(),
+      "No indexer implementation named 'Test' was found"
+    );
+  }
+
+  #[test]
+  fn test_indexer_body_from_schema_unknown_field() {
+    let params = add_indexer_params(vec![("apiKey", "test")]);
+
+    let result = indexer_body_from_schema(indexer_schema_json(), &params);
+
+    assert_eq!(
+      result.unwrap_err().to_string(),
+      "Field 'apiKey' does not exist for the 'torznab' indexer implementation"
+    );
+  }
+
+  #[test]
+  fn test_indexer_body_from_schema_invalid_number() {
+    let params = add_indexer_params(vec![("minimumSeeders", "test")]);
+
+    let result = indexer_body_from_schema(indexer_schema_json(), &params);
+
+    assert_eq!(
+      result.unwrap_err().to_string(),
+      "Field 'minimumSeeders' expects a number, but got 'test'"
+    );
+  }
+
+  #[test]
+  fn test_indexer_body_from_schema_invalid_checkbox() {
+    let params = add_indexer_params(vec![("rejectBlocklistedTorrentHashesWhileGrabbing", "yes")]);
+
+    let result = indexer_body_from_schema(indexer_schema_json(), &params);
+
+    assert_eq!(
+      result.unwrap_err().to_string(),
+      "Field 'rejectBlocklistedTorrentHashesWhileGrabbing' expects 'true' or 'false', but got 'yes'"
+    );
+  }
+
+  #[test]
+  fn test_indexer_body_from_schema_invalid_select_option() {
+    let params = add_indexer_params(vec![("animeStandardFormatSearch", "test")]);
+
+    let result = indexer_body_from_schema(indexer_schema_json(), &params);
+
+    assert_eq!(
+      result.unwrap_err().to_string(),
+      "'test' is not a valid option for field 'animeStandardFormatSearch'. Valid options are: Disabled, Enabled"
+    );
+  }
+
+  #[test]
+  fn test_download_client_body_from_schema() {
+    let schemas = vec![
+      json!({
+        "implementation": "Sabnzbd",
+        "fields": []
+      }),
+      json!({
+        "implementation": "QBittorrent",
+        "implementationName": "qBittorrent",
+        "name": "",
+        "enable": false,
+        "priority": 1,
+        "removeCompletedDownloads": false,
+        "removeFailedDownloads": false,
+        "tags": [],
+        "fields": [
+          { "name": "host", "type": "textbox", "value": "" },
+          { "name": "port", "type": "number", "value": 8080 },
+          { "name": "useSsl", "type": "checkbox", "value": false }
+        ]
+      }),
+    ];
+    let params = AddDownloadClientParams {
+      implementation: "qbittorrent".to_owned(),
+      name: "Test".to_owned(),
+      priority: 2,
+      remove_failed_downloads: false,
+      tags: vec![1],
+      fields: vec![
+        ("host".to_owned(), "localhost".to_owned()),
+        ("port".to_owned(), "9091".to_owned()),
+        ("useSsl".to_owned(), "true".to_owned()),
+      ],
+      ..AddDownloadClientParams::default()
+    };
+
+    let body = download_client_body_from_schema(schemas, &params).unwrap();
+
+    assert_eq!(body["implementation"], "QBittorrent");
+    assert_eq!(body["name"], "Test");
+    assert_eq!(body["enable"], true);
+    assert_eq!(body["priority"], 2);
+    assert_eq!(body["removeCompletedDownloads"], true);
+    assert_eq!(body["removeFailedDownloads"], false);
+    assert_eq!(body["tags"], json!([1]));
+    assert_eq!(field_value(&body, "host"), json!("localhost"));
+    assert_eq!(field_value(&body, "port"), json!(9091));
+    assert_eq!(field_value(&body, "useSsl"), json!(true));
+  }
+
+  #[test]
+  fn test_download_client_body_from_schema_unknown_implementation() {
+    let params = AddDownloadClientParams {
+      implementation: "Test".to_owned(),
+      ..AddDownloadClientParams::default()
+    };
+
+    let result = download_client_body_from_schema(indexer_schema_json(), &params);
+
+    assert_eq!(
+      result.unwrap_err().to_string(),
+      "No download client implementation named 'Test' was found"
+    );
+  }
+
+  #[test]
+  fn test_apply_schema_field_values() {
+    let mut body = json!({
+      "fields": [
+        { "name": "host", "type": "textbox", "value": "localhost" },
+        { "name": "port", "type": "number", "value": 8080 }
+      ]
+    });
+
+    apply_schema_field_values(
+      &mut body,
+      &[("port".to_owned(), " 9091 ".to_owned())],
+      "QBittorrent",
+      "download client",
+    )
+    .unwrap();
+
+    assert_eq!(field_value(&body, "host"), json!("localhost"));
+    assert_eq!(field_value(&body, "port"), json!(9091));
+  }
+
+  #[test]
+  fn test_apply_schema_field_values_unknown_field() {
+    let mut body = json!({ "fields": [] });
+
+    let result = apply_schema_field_values(
+      &mut body,
+      &[("host".to_owned(), "localhost".to_owned())],
+      "QBittorrent",
+      "download client",
+    );
+
+    assert_eq!(
+      result.unwrap_err().to_string(),
+      "Field 'host' does not exist for the 'QBittorrent' download client implementation"
+    );
+  }
+
+  #[test]
+  fn test_apply_schema_field_values_missing_fields() {
+    let mut body = json!({});
+
+    let result = apply_schema_field_values(&mut body, &[], "QBittorrent", "download client");
+
+    assert_eq!(
+      result.unwrap_err().to_string(),
+      "Failed to get download client 'fields' array"
+    );
+  }
+
+  fn quality_profile_json() -> Value {
+    json!({
+      "id": 1,
+      "name": "HD",
+      "upgradeAllowed": true,
+      "cutoff": 1000,
+      "items": [
+        { "quality": { "id": 1, "name": "SDTV" }, "items": [], "allowed": false },
+        {
+          "id": 1000,
+          "name": "WEB 720p",
+          "items": [
+            { "quality": { "id": 5, "name": "WEBDL-720p" }, "items": [], "allowed": true },
+            { "quality": { "id": 14, "name": "WEBRip-720p" }, "items": [], "allowed": true }
+          ],
+          "allowed": true
+        },
+        { "quality": { "id": 7, "name": "Bluray-1080p" }, "items": [], "allowed": true }
+      ],
+      "minFormatScore": 0,
+      "cutoffFormatScore": 100
+    })
+  }
+
+  #[test]
+  fn test_apply_quality_profile_edits() {
+    let mut body = quality_profile_json();
+    let params = EditQualityProfileParams {
+      quality_profile_id: 1,
+      name: Some("UHD".to_owned()),
+      upgrade_allowed: Some(false),
+      cutoff: Some("bluray-1080p".to_owned()),
+      min_format_score: Some(10),
+      cutoff_format_score: Some(200),
+      items: None,
+      allowed_qualities: vec!["SDTV".to_owned()],
+      disallowed_qualities: vec!["webrip-720p".to_owned()],
+    };
+
+    apply_quality_profile_edits(&mut body, params).unwrap();
+
+    assert_eq!(body["name"], json!("UHD"));
+    assert_eq!(body["upgradeAllowed"], json!(false));
+    assert_eq!(body["cutoff"], json!(7));
+    assert_eq!(body["minFormatScore"], json!(10));
+    assert_eq!(body["cutoffFormatScore"], json!(200));
+    assert_eq!(body["items"][0]["allowed"], json!(true));
+    assert_eq!(body["items"][1]["items"][0]["allowed"], json!(true));
+    assert_eq!(body["items"][1]["items"][1]["allowed"], json!(false));
+  }
+
+  #[test]
+  fn test_apply_quality_profile_edits_cutoff_to_group() {
+    let mut body = quality_profile_json();
+    body["cutoff"] = json!(7);
+    let params = EditQualityProfileParams {
+      quality_profile_id: 1,
+      cutoff: Some("WEB 720p".to_owned()),
+      ..EditQualityProfileParams::default()
+    };
+
+    apply_quality_profile_edits(&mut body, params).unwrap();
+
+    assert_eq!(body["cutoff"], json!(1000));
+  }
+
+  #[test]
+  fn test_apply_quality_profile_edits_defaults_to_previous_values() {
+    let mut body = quality_profile_json();
+
+    apply_quality_profile_edits(&mut body, EditQualityProfileParams::default()).unwrap();
+
+    assert_eq!(body, quality_profile_json());
+  }
+
+  #[test]
+  fn test_apply_quality_profile_edits_unknown_quality() {
+    let mut body = quality_profile_json();
+    let params = EditQualityProfileParams {
+      allowed_qualities: vec!["Remux-2160p".to_owned()],
+      ..EditQualityProfileParams::default()
+    };
+
+    let result = apply_quality_profile_edits(&mut body, params);
+
+    assert_eq!(
+      result.unwrap_err().to_string(),
+      "Quality 'Remux-2160p' does not exist in the quality profile"
+    );
+  }
+
+  #[test]
+  fn test_apply_quality_profile_edits_unknown_cutoff() {
+    let mut body = quality_profile_json();
+    let params = EditQualityProfileParams {
+      cutoff: Some("WEBDL-720p".to_owned()),
+      ..EditQualityProfileParams::default()
+    };
+
+    let result = apply_quality_profile_edits(&mut body, params);
+
+    assert_eq!(
+      result.unwrap_err().to_string(),
+      "The cutoff 'WEBDL-720p' is not a quality or quality group in the quality profile"
+    );
+  }
+
+  #[test]
+  fn test_apply_quality_profile_edits_missing_items() {
+    let mut body = json!({});
+
+    let result = apply_quality_profile_edits(&mut body, EditQualityProfileParams::default());
+
+    assert_eq!(
+      result.unwrap_err().to_string(),
+      "Failed to get quality profile 'items' array"
+    );
+  }
+
+  fn custom_format_json() -> Value {
+    json!({
+      "id": 1,
+      "name": "x265",
+      "includeCustomFormatWhenRenaming": false,
+      "specifications": [
+        {
+          "name": "x265",
+          "implementation": "ReleaseTitleSpecification",
+          "negate": false,
+          "required": true,
+          "fields": [{ "name": "value", "label": "Regular Expression", "value": "x265", "type": "textbox" }]
+        },
+        {
+          "name": "Size",
+          "implementation": "SizeSpecification",
+          "negate": false,
+          "required": false,
+          "fields": [
+            { "name": "min", "label": "Minimum Size", "value": 0, "type": "number" },
+            { "name": "max", "label": "Maximum Size", "value": 10, "type": "number" }
+          ]
+        }
+      ]
+    })
+  }
+
+  #[test]
+  fn test_custom_format_body_from_json_trash_format() {
+    let trash_json = json!({
+      "trash_id": "c9eafd50846d299b862ca9bb6ea91950",
+      "trash_scores": { "default": 10 },
+      "name": "x265",
+      "includeCustomFormatWhenRenaming": false,
+      "specifications": [{
+        "name": "x265",
+        "implementation": "ReleaseTitleSpecification",
+        "negate": false,
+        "required": true,
+        "fields": { "value": "x265" }
+      }]
+    });
+    let expected_body = json!({
+      "name": "x265",
+      "includeCustomFormatWhenRenaming": false,
+      "specifications": [{
+        "name": "x265",
+        "implementation": "ReleaseTitleSpecification",
+        "negate": false,
+        "required": true,
+        "fields": [{ "name": "value", "value": "x265" }]
+      }]
+    });
+
+    let body = custom_format_body_from_json(&trash_json.to_string()).unwrap();
+
+    assert_eq!(body, expected_body);
+  }
+
+  #[test]
+  fn test_custom_format_body_from_json_servarr_format() {
+    let mut expected_body = custom_format_json();
+    expected_body.as_object_mut().unwrap().remove("id");
+
+    let body = custom_format_body_from_json(&custom_format_json().to_string()).unwrap();
+
+    assert_eq!(body, expected_body);
+  }
+
+  #[test]
+  fn test_custom_format_body_from_json_defaults_specifications() {
+    let body = custom_format_body_from_json(r#"{ "name": "Test" }"#).unwrap();
+
+    assert_eq!(body, json!({ "name": "Test", "specifications": [] }));
+  }
+
+  #[rstest]
+  #[case("not json", "The custom format is not valid JSON")]
+  #[case("[]", "The custom format must be a JSON object")]
+  #[case(r#"{ "specifications": [] }"#, "The custom format must have a name")]
+  #[case(
+    r#"{ "name": "Test", "specifications": {} }"#,
+    "The custom format 'specifications' must be an array"
+  )]
+  fn test_custom_format_body_from_json_invalid(
+    #[case] custom_format_json: &str,
+    #[case] expected_error: &str,
+  ) {
+    let result = custom_format_body_from_json(custom_format_json);
+
+    assert_eq!(result.unwrap_err().to_string(), expected_error);
+  }
+
+  #[test]
+  fn test_apply_custom_format_edits() {
+    let mut body = custom_format_json();
+    let params = EditCustomFormatParams {
+      custom_format_id: 1,
+      name: Some("HEVC".to_owned()),
+      include_custom_format_when_renaming: Some(true),
+      fields: vec![
+        ("x265.value".to_owned(), "HEVC".to_owned()),
+        ("x265.negate".to_owned(), "true".to_owned()),
+        ("Size.required".to_owned(), "true".to_owned()),
+        ("Size.max".to_owned(), "20".to_owned()),
+      ],
+    };
+
+    apply_custom_format_edits(&mut body, params).unwrap();
+
+    assert_eq!(body["name"], json!("HEVC"));
+    assert_eq!(body["includeCustomFormatWhenRenaming"], json!(true));
+    assert_eq!(
+      body["specifications"][0]["fields"][0]["value"],
+      json!("HEVC")
+    );
+    assert_eq!(body["specifications"][0]["negate"], json!(true));
+    assert_eq!(body["specifications"][1]["required"], json!(true));
+    assert_eq!(body["specifications"][1]["fields"][1]["value"], json!(20));
+  }
+
+  #[test]
+  fn test_apply_custom_format_edits_defaults_to_previous_values() {
+    let mut body = custom_format_json();
+
+    apply_custom_format_edits(&mut body, EditCustomFormatParams::default()).unwrap();
+
+    assert_eq!(body, custom_format_json());
+  }
+
+  #[rstest]
+  #[case("value", "'value' must be in the form '<specification>.<field>'")]
+  #[case(
+    "x264.value",
+    "Specification 'x264' does not exist in the custom format"
+  )]
+  #[case(
+    "x265.negate",
+    "Field 'x265.negate' expects 'true' or 'false', but got 'test'"
+  )]
+  #[case(
+    "x265.test",
+    "Field 'test' does not exist for the 'ReleaseTitleSpecification' custom format specification implementation"
+  )]
+  fn test_apply_custom_format_edits_invalid_field(
+    #[case] field: &str,
+    #[case] expected_error: &str,
+  ) {
+    let mut body = custom_format_json();
+    let params = EditCustomFormatParams {
+      fields: vec![(field.to_owned(), "test".to_owned())],
+      ..EditCustomFormatParams::default()
+    };
+
+    let result = apply_custom_format_edits(&mut body, params);
+
+    assert_eq!(result.unwrap_err().to_string(), expected_error);
+  }
+
+  #[test]
+  fn test_apply_custom_format_edits_missing_specifications() {
+    let mut body = json!({});
+
+    let result = apply_custom_format_edits(&mut body, EditCustomFormatParams::default());
+
+    assert_eq!(
+      result.unwrap_err().to_string(),
+      "Failed to get custom format 'specifications' array"
+    );
+  }
+
+  #[test]
+  fn test_apply_config_edits() {
+    let mut body = json!({
+      "id": 1,
+      "recycleBin": "",
+      "autoUnmonitorPreviouslyDownloadedMovies": false
+    });
+
+    apply_config_edits(&mut body, &json!({ "id": 1, "recycleBin": "/nfs/recycle" })).unwrap();
+
+    assert_eq!(
+      body,
+      json!({
+        "id": 1,
+        "recycleBin": "/nfs/recycle",
+        "autoUnmonitorPreviouslyDownloadedMovies": false
+      })
+    );
+  }
+
+  #[test]
+  fn test_apply_config_edits_requires_an_object() {
+    let mut body = json!([]);
+
+    assert_err!(apply_config_edits(&mut body, &json!({ "id": 1 })));
+  }
+
+  #[test]
+  fn test_naming_examples_query() {
+    let naming_config = json!({
+      "id": 1,
+      "renameMovies": true,
+      "movieFolderFormat": null,
+      "standardMovieFormat": "{Movie Title} ({Release Year})"
+    });
+
+    assert_eq!(
+      naming_examples_query(&naming_config).unwrap(),
+      "id=1&renameMovies=true&standardMovieFormat=%7BMovie%20Title%7D%20%28%7BRelease%20Year%7D%29"
+    );
+  }
+}
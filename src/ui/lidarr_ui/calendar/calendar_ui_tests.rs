@@ -0,0 +1,74 @@
+#[cfg(test)]
+mod tests {
+  use strum::IntoEnumIterator;
+
+  use crate::app::App;
+  use crate::models::servarr_data::lidarr::lidarr_data::{ActiveLidarrBlock, CALENDAR_BLOCKS};
+  use crate::ui::DrawUi;
+  use crate::ui::lidarr_ui::calendar::CalendarUi;
+  use crate::ui::ui_test_utils::test_utils::render_to_string_with_app;
+
+  #[test]
+  fn test_calendar_ui_accepts() {
+    ActiveLidarrBlock::iter().for_each(|active_lidarr_block| {
+      if CALENDAR_BLOCKS.contains(&active_lidarr_block) {
+        assert!(CalendarUi::accepts(active_lidarr_block.into()));
+      } else {
+        assert!(!CalendarUi::accepts(active_lidarr_block.into()));
+      }
+    });
+  }
+
+  mod snapshot_tests {
+    use crate::ui::ui_test_utils::test_utils::TerminalSize;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_calendar_ui_renders_loading(
+      #[values(ActiveLidarrBlock::Calendar, ActiveLidarrBlock::CalendarAgenda)]
+      active_lidarr_block: ActiveLidarrBlock,
+    ) {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.push_navigation_stack(active_lidarr_block.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        CalendarUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(format!("loading_{active_lidarr_block}"), output);
+    }
+
+    #[rstest]
+    fn test_calendar_ui_renders_empty_calendar(
+      #[values(ActiveLidarrBlock::Calendar, ActiveLidarrBlock::CalendarAgenda)]
+      active_lidarr_block: ActiveLidarrBlock,
+    ) {
+      let mut app = App::test_default();
+      app.push_navigation_stack(active_lidarr_block.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        CalendarUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(format!("empty_{active_lidarr_block}"), output);
+    }
+
+    #[rstest]
+    fn test_calendar_ui_renders_calendar_tab(
+      #[values(ActiveLidarrBlock::Calendar, ActiveLidarrBlock::CalendarAgenda)]
+      active_lidarr_block: ActiveLidarrBlock,
+    ) {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(active_lidarr_block.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        CalendarUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(active_lidarr_block.to_string(), output);
+    }
+  }
+}
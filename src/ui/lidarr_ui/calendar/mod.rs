@@ -0,0 +1,148 @@
+use std::collections::HashMap;
+
+#[cfg(test)]
+use crate::ui::ui_test_utils::test_utils::Utc;
+#[cfg(not(test))]
+use chrono::Utc;
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Rect};
+use ratatui::style::Styled;
+use ratatui::text::Line;
+use ratatui::widgets::{Cell, Row};
+
+use crate::app::App;
+use crate::models::Route;
+use crate::models::lidarr_models::Album;
+use crate::models::servarr_data::lidarr::lidarr_data::{ActiveLidarrBlock, CALENDAR_BLOCKS};
+use crate::ui::DrawUi;
+use crate::ui::styles::ManagarrStyle;
+use crate::ui::utils::layout_block_top_border;
+use crate::ui::widgets::calendar_week::CalendarWeek;
+use crate::ui::widgets::managarr_table::ManagarrTable;
+use crate::utils::calendar_range;
+
+#[cfg(test)]
+#[path = "calendar_ui_tests.rs"]
+mod calendar_ui_tests;
+
+pub(super) struct CalendarUi;
+
+impl DrawUi for CalendarUi {
+  fn accepts(route: Route) -> bool {
+    if let Route::Lidarr(active_lidarr_block, _) = route {
+      return CALENDAR_BLOCKS.contains(&active_lidarr_block);
+    }
+
+    false
+  }
+
+  fn draw(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+    if let Route::Lidarr(active_lidarr_block, _) = app.get_current_route() {
+      match active_lidarr_block {
+        ActiveLidarrBlock::CalendarAgenda => draw_calendar_agenda(f, app, area),
+        _ => draw_calendar_week(f, app, area),
+      }
+    }
+  }
+}
+
+fn draw_calendar_week(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+  let artist_names = artist_names(app);
+  let entries = app
+    .data
+    .lidarr_data
+    .calendar
+    .items
+    .iter()
+    .filter_map(|album| {
+      let release_date = album.release_date?;
+      let line = Line::from(format!(
+        "{} - {}",
+        artist_names
+          .get(&album.artist_id)
+          .map_or("", String::as_str),
+        album.title.text
+      ));
+
+      Some((
+        release_date.date_naive(),
+        decorate_calendar_entry(album, line),
+      ))
+    })
+    .collect();
+  let start = app
+    .data
+    .lidarr_data
+    .calendar_start
+    .unwrap_or_else(|| calendar_range(Utc::now().date_naive()).0);
+  let calendar_week = CalendarWeek::new(start, entries).loading(app.is_loading);
+
+  f.render_widget(calendar_week, area);
+}
+
+fn draw_calendar_agenda(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+  let artist_names = artist_names(app);
+  let calendar_row_mapping = |album: &Album| {
+    let Album {
+      artist_id,
+      title,
+      album_type,
+      release_date,
+      ..
+    } = album;
+    let release_date =
+      release_date.map_or_else(String::new, |date| date.format("%Y-%m-%d").to_string());
+
+    let row = Row::new(vec![
+      Cell::from(release_date),
+      Cell::from(artist_names.get(artist_id).cloned().unwrap_or_default()),
+      Cell::from(title.text.clone()),
+      Cell::from(album_type.clone().unwrap_or_default()),
+    ]);
+
+    decorate_calendar_entry(album, row)
+  };
+  let calendar_table = ManagarrTable::new(
+    Some(&mut app.data.lidarr_data.calendar),
+    calendar_row_mapping,
+  )
+  .block(layout_block_top_border())
+  .loading(app.is_loading)
+  .headers(["Release Date", "Artist", "Album", "Type"])
+  .constraints([
+    Constraint::Percentage(15),
+    Constraint::Percentage(35),
+    Constraint::Percentage(35),
+    Constraint::Percentage(15),
+  ]);
+
+  f.render_widget(calendar_table, area);
+}
+
+fn artist_names(app: &App<'_>) -> HashMap<i64, String> {
+  app
+    .data
+    .lidarr_data
+    .artists
+    .items
+    .iter()
+    .map(|artist| (artist.id, artist.artist_name.text.clone()))
+    .collect()
+}
+
+fn decorate_calendar_entry<T: Styled<Item = T>>(album: &Album, entry: T) -> T {
+  if let Some(statistics) = album.statistics.as_ref()
+    && statistics.total_track_count > 0
+    && statistics.track_file_count >= statistics.total_track_count
+  {
+    return entry.downloaded();
+  }
+
+  if let Some(release_date) = album.release_date.as_ref()
+    && release_date > &Utc::now()
+  {
+    return entry.unreleased();
+  }
+
+  entry.missing()
+}
@@ -3,7 +3,10 @@ mod tests {
   use strum::IntoEnumIterator;
 
   use crate::app::App;
-  use crate::models::servarr_data::lidarr::lidarr_data::{ActiveLidarrBlock, DOWNLOADS_BLOCKS};
+  use crate::models::BlockSelectionState;
+  use crate::models::servarr_data::lidarr::lidarr_data::{
+    ActiveLidarrBlock, DELETE_DOWNLOAD_SELECTION_BLOCKS, DOWNLOADS_BLOCKS,
+  };
   use crate::ui::DrawUi;
   use crate::ui::lidarr_ui::downloads::DownloadsUi;
   use crate::ui::ui_test_utils::test_utils::render_to_string_with_app;
@@ -20,7 +23,10 @@ mod tests {
   }
 
   mod snapshot_tests {
-    use crate::ui::ui_test_utils::test_utils::TerminalSize;
+    use crate::models::lidarr_models::{DownloadRecord, DownloadStatus};
+    use crate::network::lidarr_network::lidarr_network_test_utils::test_utils::download_record;
+    use crate::ui::ui_test_utils::test_utils::{TerminalSize, Utc};
+    use chrono::Duration;
     use rstest::rstest;
 
     use super::*;
@@ -38,6 +44,68 @@ mod tests {
       insta::assert_snapshot!(output);
     }
 
+    #[test]
+    fn test_downloads_ui_renders_delete_download_prompt_with_multi_selection() {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(ActiveLidarrBlock::DeleteDownloadPrompt.into());
+      app.data.lidarr_data.selected_block =
+        BlockSelectionState::new(DELETE_DOWNLOAD_SELECTION_BLOCKS);
+      app
+        .data
+        .lidarr_data
+        .downloads
+        .toggle_select_all(|download| download.id);
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        DownloadsUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(output);
+    }
+
+    #[test]
+    fn test_downloads_ui_renders_with_eta() {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(ActiveLidarrBlock::Downloads.into());
+      app
+        .data
+        .lidarr_data
+        .downloads
+        .set_items(vec![DownloadRecord {
+          estimated_completion_time: Some(Utc::now() + Duration::minutes(90)),
+          ..download_record()
+        }]);
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        DownloadsUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(output);
+    }
+
+    #[rstest]
+    fn test_downloads_ui_renders_with_pending_download(
+      #[values(DownloadStatus::Delay, DownloadStatus::Fallback)] status: DownloadStatus,
+    ) {
+      let status_name = status.to_string();
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(ActiveLidarrBlock::Downloads.into());
+      app
+        .data
+        .lidarr_data
+        .downloads
+        .set_items(vec![DownloadRecord {
+          status,
+          ..download_record()
+        }]);
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        DownloadsUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(format!("pending_download_{status_name}"), output);
+    }
+
     #[test]
     fn test_downloads_ui_renders_empty_downloads() {
       let mut app = App::test_default();
@@ -55,12 +123,18 @@ mod tests {
       #[values(
         ActiveLidarrBlock::Downloads,
         ActiveLidarrBlock::DeleteDownloadPrompt,
-        ActiveLidarrBlock::UpdateDownloadsPrompt
+        ActiveLidarrBlock::GrabDownloadPrompt,
+        ActiveLidarrBlock::UpdateDownloadsPrompt,
+        ActiveLidarrBlock::ManualImport,
+        ActiveLidarrBlock::ManualImportSelectArtist,
+        ActiveLidarrBlock::ManualImportConfirmPrompt
       )]
       active_lidarr_block: ActiveLidarrBlock,
     ) {
       let mut app = App::test_default_fully_populated();
       app.push_navigation_stack(active_lidarr_block.into());
+      app.data.lidarr_data.selected_block =
+        BlockSelectionState::new(DELETE_DOWNLOAD_SELECTION_BLOCKS);
 
       let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
         DownloadsUi::draw(f, app, f.area());
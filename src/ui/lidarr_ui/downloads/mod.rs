@@ -1,18 +1,28 @@
+#[cfg(test)]
+use crate::ui::ui_test_utils::test_utils::Utc;
+#[cfg(not(test))]
+use chrono::Utc;
 use ratatui::Frame;
 use ratatui::layout::{Constraint, Rect};
-use ratatui::widgets::{Cell, Row};
+use ratatui::widgets::{Cell, ListItem, Row};
 
 use crate::app::App;
-use crate::models::lidarr_models::DownloadRecord;
+use crate::models::lidarr_models::{DownloadRecord, LidarrManualImportItem};
 use crate::models::servarr_data::lidarr::lidarr_data::{ActiveLidarrBlock, DOWNLOADS_BLOCKS};
+use crate::models::table_columns::{DOWNLOADS_TABLE_COLUMNS, table_headers};
 use crate::models::{HorizontallyScrollableText, Route};
-use crate::ui::DrawUi;
 use crate::ui::styles::ManagarrStyle;
-use crate::ui::utils::{get_width_from_percentage, layout_block_top_border};
+use crate::ui::utils::{
+  convert_to_minutes_hours_days, get_width_from_percentage, layout_block_top_border, title_block,
+};
+use crate::ui::widgets::checkbox::Checkbox;
 use crate::ui::widgets::confirmation_prompt::ConfirmationPrompt;
 use crate::ui::widgets::managarr_table::ManagarrTable;
 use crate::ui::widgets::popup::{Popup, Size};
-use crate::utils::convert_f64_to_gb;
+use crate::ui::widgets::selectable_list::SelectableList;
+use crate::ui::{DrawUi, draw_popup};
+
+use crate::utils::{convert_f64_to_gb, convert_to_gb};
 
 #[cfg(test)]
 #[path = "downloads_ui_tests.rs"]
@@ -35,12 +45,61 @@ impl DrawUi for DownloadsUi {
 
       match active_lidarr_block {
         ActiveLidarrBlock::DeleteDownloadPrompt => {
+          let selected_block = app.data.lidarr_data.selected_block.get_active_block();
+          let downloads = &app.data.lidarr_data.downloads;
+          let (title, prompt) = if downloads.has_multi_selection() {
+            (
+              "Cancel Downloads",
+              format!(
+                "Do you really want to delete the {} selected downloads?",
+                downloads.multi_selection().len()
+              ),
+            )
+          } else {
+            (
+              "Cancel Download",
+              format!(
+                "Do you really want to delete this download: \n{}?",
+                downloads.current_selection().title
+              ),
+            )
+          };
+          let removal_options = app.data.lidarr_data.queue_removal_options;
+          let checkboxes = vec![
+            Checkbox::new("Remove From Client")
+              .checked(removal_options.remove_from_client)
+              .highlighted(
+                selected_block == ActiveLidarrBlock::DeleteDownloadToggleRemoveFromClient,
+              ),
+            Checkbox::new("Add Release To Blocklist")
+              .checked(removal_options.blocklist)
+              .highlighted(selected_block == ActiveLidarrBlock::DeleteDownloadToggleBlocklist),
+            Checkbox::new("Skip Redownload")
+              .checked(removal_options.skip_redownload)
+              .highlighted(selected_block == ActiveLidarrBlock::DeleteDownloadToggleSkipRedownload),
+            Checkbox::new("Change Category")
+              .checked(removal_options.change_category)
+              .highlighted(selected_block == ActiveLidarrBlock::DeleteDownloadToggleChangeCategory),
+          ];
+          let confirmation_prompt = ConfirmationPrompt::new()
+            .title(title)
+            .prompt(&prompt)
+            .checkboxes(checkboxes)
+            .yes_no_highlighted(selected_block == ActiveLidarrBlock::DeleteDownloadConfirmPrompt)
+            .yes_no_value(app.data.lidarr_data.prompt_confirm);
+
+          f.render_widget(
+            Popup::new(confirmation_prompt).size(Size::LargePrompt),
+            f.area(),
+          );
+        }
+        ActiveLidarrBlock::GrabDownloadPrompt => {
           let prompt = format!(
-            "Do you really want to delete this download: \n{}?",
+            "Do you want to grab this pending release now: \n{}?",
             app.data.lidarr_data.downloads.current_selection().title
           );
           let confirmation_prompt = ConfirmationPrompt::new()
-            .title("Cancel Download")
+            .title("Grab Release")
             .prompt(&prompt)
             .yes_no_value(app.data.lidarr_data.prompt_confirm);
 
@@ -60,6 +119,25 @@ impl DrawUi for DownloadsUi {
             f.area(),
           );
         }
+        ActiveLidarrBlock::ManualImport => {
+          draw_popup(f, app, draw_manual_import_table, Size::Large);
+        }
+        ActiveLidarrBlock::ManualImportSelectArtist => {
+          draw_popup(f, app, draw_manual_import_table, Size::Large);
+          draw_manual_import_select_artist_popup(f, app);
+        }
+        ActiveLidarrBlock::ManualImportConfirmPrompt => {
+          draw_popup(f, app, draw_manual_import_table, Size::Large);
+          let confirmation_prompt = ConfirmationPrompt::new()
+            .title("Manual Import")
+            .prompt("Do you want to import all mapped files that have no rejections?")
+            .yes_no_value(app.data.lidarr_data.prompt_confirm);
+
+          f.render_widget(
+            Popup::new(confirmation_prompt).size(Size::MediumPrompt),
+            f.area(),
+          );
+        }
         _ => (),
       }
     }
@@ -76,17 +154,19 @@ fn draw_downloads(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
   let downloads_row_mapping = |download_record: &DownloadRecord| {
     let DownloadRecord {
       title,
+      status,
       size,
       sizeleft,
       download_client,
       indexer,
       output_path,
+      estimated_completion_time,
       ..
     } = download_record;
 
     if output_path.is_some() {
       output_path.as_ref().unwrap().scroll_left_or_reset(
-        get_width_from_percentage(area, 18),
+        get_width_from_percentage(area, 16),
         current_selection == *download_record,
         app.ui_scroll_tick_count == 0,
       );
@@ -99,10 +179,16 @@ fn draw_downloads(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
     };
     let file_size: f64 = convert_f64_to_gb(*size);
 
-    Row::new(vec![
+    let eta = estimated_completion_time
+      .map(|eta| convert_to_minutes_hours_days((eta - Utc::now()).num_minutes().max(0)))
+      .unwrap_or_default();
+
+    let row = Row::new(vec![
       Cell::from(title.to_owned()),
+      Cell::from(status.to_display_str()),
       Cell::from(format!("{:.0}%", percent * 100.0)),
       Cell::from(format!("{file_size:.2} GB")),
+      Cell::from(eta),
       Cell::from(
         output_path
           .as_ref()
@@ -116,8 +202,13 @@ fn draw_downloads(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
           .unwrap_or(&String::new())
           .to_owned(),
       ),
-    ])
-    .primary()
+    ]);
+
+    if download_record.is_pending() {
+      row.unreleased()
+    } else {
+      row.primary()
+    }
   };
   let downloads_table = ManagarrTable::new(
     Some(&mut app.data.lidarr_data.downloads),
@@ -125,22 +216,112 @@ fn draw_downloads(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
   )
   .block(layout_block_top_border())
   .loading(app.is_loading)
-  .headers([
-    "Title",
-    "Percent Complete",
-    "Size",
-    "Output Path",
-    "Indexer",
-    "Download Client",
-  ])
+  .headers(table_headers(&DOWNLOADS_TABLE_COLUMNS))
   .constraints([
-    Constraint::Percentage(30),
-    Constraint::Percentage(11),
-    Constraint::Percentage(11),
-    Constraint::Percentage(18),
-    Constraint::Percentage(17),
+    Constraint::Percentage(23),
+    Constraint::Percentage(10),
+    Constraint::Percentage(10),
+    Constraint::Percentage(8),
+    Constraint::Percentage(9),
+    Constraint::Percentage(16),
     Constraint::Percentage(13),
+    Constraint::Percentage(11),
   ]);
 
   f.render_widget(downloads_table, area);
 }
+
+fn draw_manual_import_table(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+  let block = title_block("Manual Import");
+  f.render_widget(block, area);
+  let manual_import_row_mapping = |item: &LidarrManualImportItem| {
+    let artist = item
+      .artist
+      .as_ref()
+      .map(|artist| artist.artist_name.text.clone())
+      .unwrap_or_default();
+    let album = item
+      .album
+      .as_ref()
+      .map(|album| album.title.text.clone())
+      .unwrap_or_default();
+    let tracks = item
+      .tracks
+      .iter()
+      .map(|track| track.track_number.as_str())
+      .collect::<Vec<_>>()
+      .join(", ");
+    let quality = item.quality["quality"]["name"]
+      .as_str()
+      .unwrap_or_default()
+      .to_owned();
+    let rejections = item
+      .rejections
+      .iter()
+      .map(|rejection| rejection.reason.as_str())
+      .collect::<Vec<_>>()
+      .join(", ");
+    let file_size: f64 = convert_to_gb(item.size);
+
+    let row = Row::new(vec![
+      Cell::from(item.name.to_owned()),
+      Cell::from(artist),
+      Cell::from(album),
+      Cell::from(tracks),
+      Cell::from(quality),
+      Cell::from(format!("{file_size:.2} GB")),
+      Cell::from(rejections),
+    ]);
+
+    if item.import_file().is_some() {
+      row.primary()
+    } else {
+      row.failure()
+    }
+  };
+  let manual_import_modal = app
+    .data
+    .lidarr_data
+    .manual_import_modal
+    .as_mut()
+    .map(|modal| &mut modal.items);
+  let manual_import_table = ManagarrTable::new(manual_import_modal, manual_import_row_mapping)
+    .loading(app.is_loading)
+    .margin(1)
+    .headers([
+      "Name",
+      "Artist",
+      "Album",
+      "Tracks",
+      "Quality",
+      "Size",
+      "Rejections",
+    ])
+    .constraints([
+      Constraint::Percentage(22),
+      Constraint::Percentage(15),
+      Constraint::Percentage(15),
+      Constraint::Percentage(8),
+      Constraint::Percentage(10),
+      Constraint::Percentage(8),
+      Constraint::Percentage(22),
+    ]);
+
+  f.render_widget(manual_import_table, area);
+}
+
+fn draw_manual_import_select_artist_popup(f: &mut Frame<'_>, app: &mut App<'_>) {
+  let artist_list = SelectableList::new(
+    &mut app
+      .data
+      .lidarr_data
+      .manual_import_modal
+      .as_mut()
+      .expect("manual_import_modal must exist in this context")
+      .artist_list,
+    |artist| ListItem::new(artist.artist_name.text.clone()),
+  );
+  let popup = Popup::new(artist_list).size(Size::Dropdown);
+
+  f.render_widget(popup, f.area());
+}
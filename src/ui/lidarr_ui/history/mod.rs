@@ -3,6 +3,7 @@ use crate::app::App;
 use crate::models::Route;
 use crate::models::lidarr_models::LidarrHistoryItem;
 use crate::models::servarr_data::lidarr::lidarr_data::{ActiveLidarrBlock, HISTORY_BLOCKS};
+use crate::models::table_columns::{LIDARR_HISTORY_TABLE_COLUMNS, table_headers};
 use crate::ui::DrawUi;
 use crate::ui::styles::{ManagarrStyle, secondary_style};
 use crate::ui::utils::{get_width_from_percentage, layout_block_top_border};
@@ -80,7 +81,7 @@ fn draw_history_table(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
         .search_produced_empty_results(active_lidarr_block == ActiveLidarrBlock::SearchHistoryError)
         .filtering(active_lidarr_block == ActiveLidarrBlock::FilterHistory)
         .filter_produced_empty_results(active_lidarr_block == ActiveLidarrBlock::FilterHistoryError)
-        .headers(["Source Title", "Event Type", "Quality", "Date"])
+        .headers(table_headers(&LIDARR_HISTORY_TABLE_COLUMNS))
         .constraints([
           Constraint::Percentage(50),
           Constraint::Percentage(18),
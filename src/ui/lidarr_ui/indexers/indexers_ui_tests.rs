@@ -4,7 +4,8 @@ mod tests {
 
   use crate::app::App;
   use crate::models::servarr_data::lidarr::lidarr_data::{
-    ActiveLidarrBlock, EDIT_INDEXER_BLOCKS, INDEXER_SETTINGS_BLOCKS, INDEXERS_BLOCKS,
+    ADD_INDEXER_BLOCKS, ActiveLidarrBlock, EDIT_INDEXER_BLOCKS, INDEXER_SETTINGS_BLOCKS,
+    INDEXERS_BLOCKS,
   };
   use crate::models::servarr_models::Indexer;
   use crate::ui::DrawUi;
@@ -16,6 +17,7 @@ mod tests {
     let mut indexers_blocks = Vec::new();
     indexers_blocks.extend(INDEXERS_BLOCKS);
     indexers_blocks.extend(INDEXER_SETTINGS_BLOCKS);
+    indexers_blocks.extend(ADD_INDEXER_BLOCKS);
     indexers_blocks.extend(EDIT_INDEXER_BLOCKS);
     indexers_blocks.push(ActiveLidarrBlock::TestAllIndexers);
 
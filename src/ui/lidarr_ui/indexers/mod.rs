@@ -9,6 +9,7 @@ use crate::models::Route;
 use crate::models::servarr_data::lidarr::lidarr_data::{ActiveLidarrBlock, INDEXERS_BLOCKS};
 use crate::models::servarr_models::Indexer;
 use crate::ui::DrawUi;
+use crate::ui::lidarr_ui::indexers::add_indexer_ui::AddIndexerUi;
 use crate::ui::lidarr_ui::indexers::edit_indexer_ui::EditIndexerUi;
 use crate::ui::lidarr_ui::indexers::indexer_settings_ui::IndexerSettingsUi;
 use crate::ui::lidarr_ui::indexers::test_all_indexers_ui::TestAllIndexersUi;
@@ -20,6 +21,7 @@ use crate::ui::widgets::managarr_table::ManagarrTable;
 use crate::ui::widgets::message::Message;
 use crate::ui::widgets::popup::{Popup, Size};
 
+mod add_indexer_ui;
 mod edit_indexer_ui;
 mod indexer_settings_ui;
 mod test_all_indexers_ui;
@@ -33,7 +35,8 @@ pub(super) struct IndexersUi;
 impl DrawUi for IndexersUi {
   fn accepts(route: Route) -> bool {
     if let Route::Lidarr(active_lidarr_block, _) = route {
-      return EditIndexerUi::accepts(route)
+      return AddIndexerUi::accepts(route)
+        || EditIndexerUi::accepts(route)
         || IndexerSettingsUi::accepts(route)
         || TestAllIndexersUi::accepts(route)
         || INDEXERS_BLOCKS.contains(&active_lidarr_block);
@@ -47,6 +50,7 @@ impl DrawUi for IndexersUi {
     draw_indexers(f, app, area);
 
     match route {
+      _ if AddIndexerUi::accepts(route) => AddIndexerUi::draw(f, app, area),
       _ if EditIndexerUi::accepts(route) => EditIndexerUi::draw(f, app, area),
       _ if IndexerSettingsUi::accepts(route) => IndexerSettingsUi::draw(f, app, area),
       _ if TestAllIndexersUi::accepts(route) => TestAllIndexersUi::draw(f, app, area),
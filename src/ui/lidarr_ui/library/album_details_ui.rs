@@ -2,6 +2,7 @@ use crate::app::App;
 use crate::models::Route;
 use crate::models::lidarr_models::{LidarrHistoryItem, LidarrRelease, Track};
 use crate::models::servarr_data::lidarr::lidarr_data::{ALBUM_DETAILS_BLOCKS, ActiveLidarrBlock};
+use crate::models::table_columns::{LIDARR_HISTORY_TABLE_COLUMNS, table_headers};
 use crate::ui::lidarr_ui::library::track_details_ui::TrackDetailsUi;
 use crate::ui::lidarr_ui::lidarr_ui_utils::create_history_event_details;
 use crate::ui::styles::{ManagarrStyle, secondary_style};
@@ -280,7 +281,7 @@ fn draw_album_history_table(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
           .filter_produced_empty_results(
             active_lidarr_block == ActiveLidarrBlock::FilterAlbumHistoryError,
           )
-          .headers(["Source Title", "Event Type", "Quality", "Date"])
+          .headers(table_headers(&LIDARR_HISTORY_TABLE_COLUMNS))
           .constraints([
             Constraint::Percentage(40),
             Constraint::Percentage(20),
@@ -11,6 +11,7 @@ use crate::app::App;
 use crate::models::Route;
 use crate::models::lidarr_models::{Album, LidarrHistoryItem, LidarrRelease};
 use crate::models::servarr_data::lidarr::lidarr_data::{ARTIST_DETAILS_BLOCKS, ActiveLidarrBlock};
+use crate::models::table_columns::{LIDARR_HISTORY_TABLE_COLUMNS, table_headers};
 use crate::ui::lidarr_ui::library::album_details_ui::AlbumDetailsUi;
 use crate::ui::lidarr_ui::library::delete_album_ui::DeleteAlbumUi;
 use crate::ui::lidarr_ui::lidarr_ui_utils::create_history_event_details;
@@ -402,7 +403,7 @@ fn draw_artist_history_table(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
       .filter_produced_empty_results(
         active_lidarr_block == ActiveLidarrBlock::FilterArtistHistoryError,
       )
-      .headers(["Source Title", "Event Type", "Quality", "Date"])
+      .headers(table_headers(&LIDARR_HISTORY_TABLE_COLUMNS))
       .constraints([
         Constraint::Percentage(40),
         Constraint::Percentage(20),
@@ -0,0 +1,59 @@
+#[cfg(test)]
+mod tests {
+  use strum::IntoEnumIterator;
+
+  use crate::app::App;
+  use crate::models::BlockSelectionState;
+  use crate::models::servarr_data::lidarr::lidarr_data::{
+    ActiveLidarrBlock, BULK_EDIT_ARTISTS_BLOCKS, BULK_EDIT_ARTISTS_SELECTION_BLOCKS,
+  };
+  use crate::ui::DrawUi;
+  use crate::ui::lidarr_ui::library::bulk_edit_artists_ui::BulkEditArtistsUi;
+  use crate::ui::ui_test_utils::test_utils::{TerminalSize, render_to_string_with_app};
+
+  #[test]
+  fn test_bulk_edit_artists_ui_accepts() {
+    ActiveLidarrBlock::iter().for_each(|active_lidarr_block| {
+      if BULK_EDIT_ARTISTS_BLOCKS.contains(&active_lidarr_block) {
+        assert!(BulkEditArtistsUi::accepts(active_lidarr_block.into()));
+      } else {
+        assert!(!BulkEditArtistsUi::accepts(active_lidarr_block.into()));
+      }
+    });
+  }
+
+  mod snapshot_tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    fn test_bulk_edit_artists_ui_renders_bulk_edit_artists_modal(
+      #[values(
+        ActiveLidarrBlock::BulkEditArtistsPrompt,
+        ActiveLidarrBlock::BulkEditArtistsSelectMonitored,
+        ActiveLidarrBlock::BulkEditArtistsSelectQualityProfile,
+        ActiveLidarrBlock::BulkEditArtistsSelectRootFolder,
+        ActiveLidarrBlock::BulkEditArtistsSelectApplyTags,
+        ActiveLidarrBlock::BulkEditArtistsTagsInput
+      )]
+      active_lidarr_block: ActiveLidarrBlock,
+    ) {
+      let mut app = App::test_default_fully_populated();
+      app
+        .data
+        .lidarr_data
+        .artists
+        .toggle_select_all(|artist| artist.id);
+      app.push_navigation_stack(ActiveLidarrBlock::BulkEditArtistsPrompt.into());
+      app.push_navigation_stack(active_lidarr_block.into());
+      app.data.lidarr_data.selected_block =
+        BlockSelectionState::new(BULK_EDIT_ARTISTS_SELECTION_BLOCKS);
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        BulkEditArtistsUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(active_lidarr_block.to_string(), output);
+    }
+  }
+}
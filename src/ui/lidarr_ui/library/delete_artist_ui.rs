@@ -29,16 +29,24 @@ impl DrawUi for DeleteArtistUi {
       Route::Lidarr(ActiveLidarrBlock::DeleteArtistPrompt, _)
     ) {
       let selected_block = app.data.lidarr_data.selected_block.get_active_block();
-      let prompt = format!(
-        "Do you really want to delete the artist: \n{}?",
-        app
-          .data
-          .lidarr_data
-          .artists
-          .current_selection()
-          .artist_name
-          .text
-      );
+      let artists = &app.data.lidarr_data.artists;
+      let (title, prompt) = if artists.has_multi_selection() {
+        (
+          "Delete Artists",
+          format!(
+            "Do you really want to delete the {} selected artists?",
+            artists.multi_selection().len()
+          ),
+        )
+      } else {
+        (
+          "Delete Artist",
+          format!(
+            "Do you really want to delete the artist: \n{}?",
+            artists.current_selection().artist_name.text
+          ),
+        )
+      };
       let checkboxes = vec![
         Checkbox::new("Delete Artist Files")
           .checked(app.data.lidarr_data.delete_files)
@@ -48,7 +56,7 @@ impl DrawUi for DeleteArtistUi {
           .highlighted(selected_block == ActiveLidarrBlock::DeleteArtistToggleAddListExclusion),
       ];
       let confirmation_prompt = ConfirmationPrompt::new()
-        .title("Delete Artist")
+        .title(title)
         .prompt(&prompt)
         .checkboxes(checkboxes)
         .yes_no_highlighted(selected_block == ActiveLidarrBlock::DeleteArtistConfirmPrompt)
@@ -40,5 +40,24 @@ mod tests {
 
       insta::assert_snapshot!(output);
     }
+
+    #[test]
+    fn test_delete_artist_ui_renders_bulk_delete_artists_prompt() {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(ActiveLidarrBlock::DeleteArtistPrompt.into());
+      app.data.lidarr_data.selected_block =
+        BlockSelectionState::new(DELETE_ARTIST_SELECTION_BLOCKS);
+      app
+        .data
+        .lidarr_data
+        .artists
+        .toggle_select_all(|artist| artist.id);
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        DeleteArtistUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(output);
+    }
   }
 }
@@ -5,8 +5,8 @@ mod tests {
   use crate::models::lidarr_models::{Artist, ArtistStatistics, ArtistStatus};
   use crate::models::servarr_data::lidarr::lidarr_data::{
     ADD_ARTIST_BLOCKS, ALBUM_DETAILS_BLOCKS, ARTIST_DETAILS_BLOCKS, ActiveLidarrBlock,
-    DELETE_ALBUM_BLOCKS, DELETE_ARTIST_BLOCKS, EDIT_ARTIST_BLOCKS, LIBRARY_BLOCKS,
-    TRACK_DETAILS_BLOCKS,
+    BULK_EDIT_ARTISTS_BLOCKS, DELETE_ALBUM_BLOCKS, DELETE_ARTIST_BLOCKS, EDIT_ARTIST_BLOCKS,
+    LIBRARY_BLOCKS, TRACK_DETAILS_BLOCKS,
   };
   use crate::ui::DrawUi;
   use crate::ui::lidarr_ui::library::{LibraryUi, decorate_artist_row_with_style};
@@ -21,6 +21,7 @@ mod tests {
     library_ui_blocks.extend(DELETE_ARTIST_BLOCKS);
     library_ui_blocks.extend(DELETE_ALBUM_BLOCKS);
     library_ui_blocks.extend(EDIT_ARTIST_BLOCKS);
+    library_ui_blocks.extend(BULK_EDIT_ARTISTS_BLOCKS);
     library_ui_blocks.extend(ADD_ARTIST_BLOCKS);
     library_ui_blocks.extend(ARTIST_DETAILS_BLOCKS);
     library_ui_blocks.extend(ALBUM_DETAILS_BLOCKS);
@@ -1,5 +1,6 @@
 use add_artist_ui::AddArtistUi;
 use artist_details_ui::ArtistDetailsUi;
+use bulk_edit_artists_ui::BulkEditArtistsUi;
 use delete_artist_ui::DeleteArtistUi;
 use edit_artist_ui::EditArtistUi;
 use ratatui::{
@@ -8,12 +9,12 @@ use ratatui::{
   widgets::{Cell, Row},
 };
 
+use crate::models::table_columns::{ARTIST_TABLE_HEADERS, artist_row};
 use crate::ui::widgets::managarr_table::ManagarrTable;
 use crate::ui::widgets::{
   confirmation_prompt::ConfirmationPrompt,
   popup::{Popup, Size},
 };
-use crate::utils::convert_to_gb;
 use crate::{
   app::App,
   models::{
@@ -31,6 +32,7 @@ use crate::{
 mod add_artist_ui;
 mod album_details_ui;
 mod artist_details_ui;
+mod bulk_edit_artists_ui;
 mod delete_album_ui;
 mod delete_artist_ui;
 mod edit_artist_ui;
@@ -48,6 +50,7 @@ impl DrawUi for LibraryUi {
       return AddArtistUi::accepts(route)
         || DeleteArtistUi::accepts(route)
         || EditArtistUi::accepts(route)
+        || BulkEditArtistsUi::accepts(route)
         || ArtistDetailsUi::accepts(route)
         || LIBRARY_BLOCKS.contains(&active_lidarr_block);
     }
@@ -64,6 +67,7 @@ impl DrawUi for LibraryUi {
       _ if DeleteArtistUi::accepts(route) => DeleteArtistUi::draw(f, app, area),
       _ if ArtistDetailsUi::accepts(route) => ArtistDetailsUi::draw(f, app, area),
       _ if EditArtistUi::accepts(route) => EditArtistUi::draw(f, app, area),
+      _ if BulkEditArtistsUi::accepts(route) => BulkEditArtistsUi::draw(f, app, area),
       Route::Lidarr(ActiveLidarrBlock::UpdateAllArtistsPrompt, _) => {
         let confirmation_prompt = ConfirmationPrompt::new()
           .title("Update All Artists")
@@ -98,51 +102,11 @@ fn draw_library(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
         *artist == current_selection,
         app.ui_scroll_tick_count == 0,
       );
-      let monitored = if artist.monitored { "🏷" } else { "" };
-      let artist_type = artist.artist_type.clone().unwrap_or_default();
-      let size = artist
-        .statistics
-        .as_ref()
-        .map_or(0f64, |stats| convert_to_gb(stats.size_on_disk));
-      let quality_profile = quality_profile_map
-        .get_by_left(&artist.quality_profile_id)
-        .cloned()
-        .unwrap_or_default();
-      let metadata_profile = metadata_profile_map
-        .get_by_left(&artist.metadata_profile_id)
-        .cloned()
-        .unwrap_or_default();
-      let albums = artist
-        .statistics
-        .as_ref()
-        .map_or(0, |stats| stats.album_count);
-      let tracks = artist.statistics.as_ref().map_or(String::new(), |stats| {
-        format!("{}/{}", stats.track_file_count, stats.total_track_count)
-      });
-      let tags = artist
-        .tags
-        .iter()
-        .filter_map(|tag_id| {
-          let id = tag_id.as_i64()?;
-          tags_map.get_by_left(&id).cloned()
-        })
-        .collect::<Vec<_>>()
-        .join(", ");
-
       decorate_artist_row_with_style(
         artist,
-        Row::new(vec![
-          Cell::from(artist.artist_name.to_string()),
-          Cell::from(artist_type),
-          Cell::from(artist.status.to_display_str()),
-          Cell::from(quality_profile),
-          Cell::from(metadata_profile),
-          Cell::from(albums.to_string()),
-          Cell::from(tracks),
-          Cell::from(format!("{size:.2} GB")),
-          Cell::from(monitored.to_owned()),
-          Cell::from(tags),
-        ]),
+        Row::new(
+          artist_row(artist, quality_profile_map, metadata_profile_map, tags_map).map(Cell::from),
+        ),
       )
     };
     let artists_table = ManagarrTable::new(content, artists_table_row_mapping)
@@ -153,18 +117,7 @@ fn draw_library(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
       .filtering(active_lidarr_block == ActiveLidarrBlock::FilterArtists)
       .search_produced_empty_results(active_lidarr_block == ActiveLidarrBlock::SearchArtistsError)
       .filter_produced_empty_results(active_lidarr_block == ActiveLidarrBlock::FilterArtistsError)
-      .headers([
-        "Name",
-        "Type",
-        "Status",
-        "Quality Profile",
-        "Metadata Profile",
-        "Albums",
-        "Tracks",
-        "Size",
-        "Monitored",
-        "Tags",
-      ])
+      .headers(ARTIST_TABLE_HEADERS)
       .constraints([
         Constraint::Percentage(22),
         Constraint::Percentage(8),
@@ -28,6 +28,8 @@ mod tests {
     #[case(ActiveLidarrBlock::RootFolders, 4)]
     #[case(ActiveLidarrBlock::Indexers, 5)]
     #[case(ActiveLidarrBlock::System, 6)]
+    #[case(ActiveLidarrBlock::Calendar, 7)]
+    #[case(ActiveLidarrBlock::WantedMissing, 8)]
     fn test_lidarr_ui_renders_lidarr_tabs(
       #[case] active_lidarr_block: ActiveLidarrBlock,
       #[case] index: usize,
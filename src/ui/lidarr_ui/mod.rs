@@ -23,10 +23,14 @@ use super::{
   widgets::loading_block::LoadingBlock,
 };
 use crate::ui::lidarr_ui::blocklist::BlocklistUi;
+use crate::ui::lidarr_ui::calendar::CalendarUi;
+use crate::ui::lidarr_ui::download_clients::DownloadClientsUi;
 use crate::ui::lidarr_ui::downloads::DownloadsUi;
 use crate::ui::lidarr_ui::indexers::IndexersUi;
+use crate::ui::lidarr_ui::quality_profiles::QualityProfilesUi;
 use crate::ui::lidarr_ui::root_folders::RootFoldersUi;
 use crate::ui::lidarr_ui::system::SystemUi;
+use crate::ui::lidarr_ui::wanted::WantedUi;
 use crate::ui::utils::{extract_monitored_disk_space_vec, extract_monitored_root_folders};
 use crate::{
   app::App,
@@ -41,13 +45,17 @@ use crate::{
 };
 
 mod blocklist;
+mod calendar;
+mod download_clients;
 mod downloads;
 mod history;
 mod indexers;
 mod library;
 mod lidarr_ui_utils;
+mod quality_profiles;
 mod root_folders;
 mod system;
+mod wanted;
 
 #[cfg(test)]
 #[path = "lidarr_ui_tests.rs"]
@@ -71,7 +79,11 @@ impl DrawUi for LidarrUi {
       _ if HistoryUi::accepts(route) => HistoryUi::draw(f, app, content_area),
       _ if RootFoldersUi::accepts(route) => RootFoldersUi::draw(f, app, content_area),
       _ if IndexersUi::accepts(route) => IndexersUi::draw(f, app, content_area),
+      _ if DownloadClientsUi::accepts(route) => DownloadClientsUi::draw(f, app, content_area),
+      _ if QualityProfilesUi::accepts(route) => QualityProfilesUi::draw(f, app, content_area),
       _ if SystemUi::accepts(route) => SystemUi::draw(f, app, content_area),
+      _ if CalendarUi::accepts(route) => CalendarUi::draw(f, app, content_area),
+      _ if WantedUi::accepts(route) => WantedUi::draw(f, app, content_area),
       _ => (),
     }
   }
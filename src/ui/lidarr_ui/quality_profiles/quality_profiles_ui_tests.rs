@@ -0,0 +1,78 @@
+#[cfg(test)]
+mod tests {
+  use strum::IntoEnumIterator;
+
+  use crate::app::App;
+  use crate::models::servarr_data::lidarr::lidarr_data::{
+    ActiveLidarrBlock, QUALITY_PROFILES_BLOCKS,
+  };
+  use crate::models::stateful_table::StatefulTable;
+  use crate::ui::DrawUi;
+  use crate::ui::lidarr_ui::quality_profiles::QualityProfilesUi;
+  use crate::ui::ui_test_utils::test_utils::{TerminalSize, render_to_string_with_app};
+
+  #[test]
+  fn test_quality_profiles_ui_accepts() {
+    ActiveLidarrBlock::iter().for_each(|active_lidarr_block| {
+      if QUALITY_PROFILES_BLOCKS.contains(&active_lidarr_block) {
+        assert!(QualityProfilesUi::accepts(active_lidarr_block.into()));
+      } else {
+        assert!(!QualityProfilesUi::accepts(active_lidarr_block.into()));
+      }
+    });
+  }
+
+  mod snapshot_tests {
+    use super::*;
+
+    #[test]
+    fn test_quality_profiles_ui_renders_quality_profiles_tab_loading() {
+      let mut app = App::test_default_fully_populated();
+      app.is_loading = true;
+      app.push_navigation_stack(ActiveLidarrBlock::QualityProfiles.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        QualityProfilesUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(output);
+    }
+
+    #[test]
+    fn test_quality_profiles_ui_renders_quality_profiles_tab_empty_quality_profiles() {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(ActiveLidarrBlock::QualityProfiles.into());
+      app.data.lidarr_data.quality_profiles = StatefulTable::default();
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        QualityProfilesUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(output);
+    }
+
+    #[test]
+    fn test_quality_profiles_ui_renders_quality_profiles_tab() {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(ActiveLidarrBlock::QualityProfiles.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        QualityProfilesUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(output);
+    }
+
+    #[test]
+    fn test_quality_profiles_ui_renders_edit_quality_profile_popup() {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(ActiveLidarrBlock::EditQualityProfile.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        QualityProfilesUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(output);
+    }
+  }
+}
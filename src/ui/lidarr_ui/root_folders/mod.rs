@@ -1,20 +1,30 @@
+use std::sync::atomic::Ordering;
+
 use add_root_folder_ui::AddRootFolderUi;
 use ratatui::Frame;
-use ratatui::layout::{Constraint, Rect};
-use ratatui::widgets::{Cell, Row};
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::text::Text;
+use ratatui::widgets::{Cell, ListItem, Paragraph, Row, Wrap};
 
 use crate::app::App;
 use crate::models::Route;
+use crate::models::lidarr_models::NamingExamples;
 use crate::models::servarr_data::lidarr::lidarr_data::{
-  ADD_ROOT_FOLDER_BLOCKS, ActiveLidarrBlock, ROOT_FOLDERS_BLOCKS,
+  ADD_ROOT_FOLDER_BLOCKS, ActiveLidarrBlock, MEDIA_MANAGEMENT_SETTINGS_BLOCKS, ROOT_FOLDERS_BLOCKS,
 };
 use crate::models::servarr_models::RootFolder;
-use crate::ui::DrawUi;
+use crate::render_selectable_input_box;
 use crate::ui::styles::ManagarrStyle;
-use crate::ui::utils::layout_block_top_border;
+use crate::ui::utils::{
+  layout_block_top_border, layout_paragraph_borderless, title_block, title_block_centered,
+};
 use crate::ui::widgets::confirmation_prompt::ConfirmationPrompt;
+use crate::ui::widgets::input_box::InputBox;
+use crate::ui::widgets::loading_block::LoadingBlock;
 use crate::ui::widgets::managarr_table::ManagarrTable;
 use crate::ui::widgets::popup::{Popup, Size};
+use crate::ui::widgets::selectable_list::SelectableList;
+use crate::ui::{DrawUi, draw_popup};
 use crate::utils::convert_to_gb;
 
 mod add_root_folder_ui;
@@ -29,7 +39,8 @@ impl DrawUi for RootFoldersUi {
   fn accepts(route: Route) -> bool {
     if let Route::Lidarr(active_lidarr_block, _) = route {
       return ROOT_FOLDERS_BLOCKS.contains(&active_lidarr_block)
-        || ADD_ROOT_FOLDER_BLOCKS.contains(&active_lidarr_block);
+        || ADD_ROOT_FOLDER_BLOCKS.contains(&active_lidarr_block)
+        || MEDIA_MANAGEMENT_SETTINGS_BLOCKS.contains(&active_lidarr_block);
     }
 
     false
@@ -55,6 +66,13 @@ impl DrawUi for RootFoldersUi {
           Popup::new(confirmation_prompt).size(Size::MediumPrompt),
           f.area(),
         );
+      } else if MEDIA_MANAGEMENT_SETTINGS_BLOCKS.contains(&active_lidarr_block) {
+        draw_popup(
+          f,
+          app,
+          draw_media_management_settings_prompt,
+          Size::WideLargePrompt,
+        );
       }
     }
   }
@@ -100,3 +118,75 @@ fn draw_root_folders(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
 
   f.render_widget(root_folders_table, area);
 }
+
+fn draw_media_management_settings_prompt(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+  let is_editing = app.get_current_route() == ActiveLidarrBlock::MediaManagementFieldInput.into();
+  let Some(media_management_modal) = app.data.lidarr_data.media_management_modal.as_mut() else {
+    f.render_widget(
+      LoadingBlock::new(
+        app.is_loading,
+        title_block_centered("Media Management Settings"),
+      ),
+      area,
+    );
+    return;
+  };
+  f.render_widget(title_block_centered("Media Management Settings"), area);
+
+  let [fields_area, help_area, input_area, examples_area] = Layout::vertical([
+    Constraint::Fill(1),
+    Constraint::Length(2),
+    Constraint::Length(3),
+    Constraint::Length(5),
+  ])
+  .margin(1)
+  .areas(area);
+
+  let selected_field = media_management_modal.fields.current_selection().clone();
+  let fields_list = SelectableList::new(&mut media_management_modal.fields, |field| {
+    ListItem::new(format!("{}: {}", field.label, field.display_value()))
+  });
+  f.render_widget(fields_list, fields_area);
+
+  let help_text = selected_field.help_text.clone().unwrap_or_default();
+  f.render_widget(layout_paragraph_borderless(&help_text), help_area);
+
+  let value = selected_field.display_value();
+  let input_box = InputBox::new(&value)
+    .offset(selected_field.value.offset.load(Ordering::SeqCst))
+    .label(&selected_field.label)
+    .highlighted(true)
+    .selected(is_editing);
+  render_selectable_input_box!(input_box, f, input_area);
+
+  draw_naming_examples(f, app, examples_area);
+}
+
+fn draw_naming_examples(f: &mut Frame<'_>, app: &App<'_>, area: Rect) {
+  let block = title_block("Naming Examples");
+  let Some(naming_examples) = app.data.lidarr_data.naming_examples.as_ref() else {
+    f.render_widget(LoadingBlock::new(app.is_loading, block), area);
+    return;
+  };
+
+  let NamingExamples {
+    single_track_example,
+    multi_disc_track_example,
+    artist_folder_example,
+  } = naming_examples;
+  let examples = [
+    ("Single Track", single_track_example),
+    ("Multi Disc Track", multi_disc_track_example),
+    ("Artist Folder", artist_folder_example),
+  ]
+  .into_iter()
+  .map(|(label, example)| format!("{label}: {}", example.as_deref().unwrap_or_default()))
+  .collect::<Vec<_>>()
+  .join("\n");
+  let examples_paragraph = Paragraph::new(Text::from(examples))
+    .block(block)
+    .default_color()
+    .wrap(Wrap { trim: false });
+
+  f.render_widget(examples_paragraph, area);
+}
@@ -4,7 +4,8 @@ mod tests {
 
   use crate::app::App;
   use crate::models::servarr_data::lidarr::lidarr_data::{
-    ADD_ROOT_FOLDER_BLOCKS, ActiveLidarrBlock, ROOT_FOLDERS_BLOCKS,
+    ADD_ROOT_FOLDER_BLOCKS, ActiveLidarrBlock, MEDIA_MANAGEMENT_SETTINGS_BLOCKS,
+    ROOT_FOLDERS_BLOCKS,
   };
   use crate::ui::DrawUi;
   use crate::ui::lidarr_ui::root_folders::RootFoldersUi;
@@ -15,6 +16,7 @@ mod tests {
     ActiveLidarrBlock::iter().for_each(|active_lidarr_block| {
       if ROOT_FOLDERS_BLOCKS.contains(&active_lidarr_block)
         || ADD_ROOT_FOLDER_BLOCKS.contains(&active_lidarr_block)
+        || MEDIA_MANAGEMENT_SETTINGS_BLOCKS.contains(&active_lidarr_block)
       {
         assert!(RootFoldersUi::accepts(active_lidarr_block.into()));
       } else {
@@ -59,7 +61,9 @@ mod tests {
     fn test_root_folders_ui_renders_root_folders_tab(
       #[values(
         ActiveLidarrBlock::RootFolders,
-        ActiveLidarrBlock::DeleteRootFolderPrompt
+        ActiveLidarrBlock::DeleteRootFolderPrompt,
+        ActiveLidarrBlock::MediaManagementSettingsPrompt,
+        ActiveLidarrBlock::MediaManagementFieldInput
       )]
       active_lidarr_block: ActiveLidarrBlock,
     ) {
@@ -73,6 +77,35 @@ mod tests {
       insta::assert_snapshot!(active_lidarr_block.to_string(), output);
     }
 
+    #[test]
+    fn test_root_folders_ui_renders_media_management_settings_loading() {
+      let mut app = App::test_default_fully_populated();
+      app.is_loading = true;
+      app.data.lidarr_data.media_management_modal = None;
+      app.data.lidarr_data.naming_examples = None;
+      app.push_navigation_stack(ActiveLidarrBlock::MediaManagementSettingsPrompt.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        RootFoldersUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(output);
+    }
+
+    #[test]
+    fn test_root_folders_ui_renders_media_management_settings_naming_examples_loading() {
+      let mut app = App::test_default_fully_populated();
+      app.is_loading = true;
+      app.data.lidarr_data.naming_examples = None;
+      app.push_navigation_stack(ActiveLidarrBlock::MediaManagementSettingsPrompt.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        RootFoldersUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(output);
+    }
+
     #[rstest]
     fn test_root_folders_ui_renders_add_root_folders_popup_over_root_folders_table(
       #[values(
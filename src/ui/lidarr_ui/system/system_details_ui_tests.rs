@@ -31,6 +31,8 @@ mod tests {
     #[rstest]
     fn test_system_details_ui_popups(
       #[values(
+        ActiveLidarrBlock::SystemBackups,
+        ActiveLidarrBlock::DeleteBackupPrompt,
         ActiveLidarrBlock::SystemLogs,
         ActiveLidarrBlock::SystemQueuedEvents,
         ActiveLidarrBlock::SystemTasks,
@@ -52,6 +54,7 @@ mod tests {
     #[rstest]
     fn test_system_details_ui_loading(
       #[values(
+        ActiveLidarrBlock::SystemBackups,
         ActiveLidarrBlock::SystemLogs,
         ActiveLidarrBlock::SystemQueuedEvents,
         ActiveLidarrBlock::SystemTasks,
@@ -87,6 +90,7 @@ mod tests {
     #[rstest]
     fn test_system_details_ui_popups_empty(
       #[values(
+        ActiveLidarrBlock::SystemBackups,
         ActiveLidarrBlock::SystemLogs,
         ActiveLidarrBlock::SystemQueuedEvents,
         ActiveLidarrBlock::SystemTasks
@@ -0,0 +1,143 @@
+use std::collections::HashMap;
+
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Rect};
+use ratatui::widgets::{Cell, Row};
+
+use crate::app::App;
+use crate::models::Route;
+use crate::models::lidarr_models::Album;
+use crate::models::servarr_data::lidarr::lidarr_data::{ActiveLidarrBlock, WANTED_BLOCKS};
+use crate::ui::DrawUi;
+use crate::ui::styles::ManagarrStyle;
+use crate::ui::utils::{layout_block_top_border_with_title, title_style};
+use crate::ui::widgets::confirmation_prompt::ConfirmationPrompt;
+use crate::ui::widgets::managarr_table::ManagarrTable;
+use crate::ui::widgets::popup::{Popup, Size};
+
+#[cfg(test)]
+#[path = "wanted_ui_tests.rs"]
+mod wanted_ui_tests;
+
+pub(super) struct WantedUi;
+
+impl DrawUi for WantedUi {
+  fn accepts(route: Route) -> bool {
+    if let Route::Lidarr(active_lidarr_block, _) = route {
+      return WANTED_BLOCKS.contains(&active_lidarr_block);
+    }
+
+    false
+  }
+
+  fn draw(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+    if let Route::Lidarr(active_lidarr_block, context) = app.get_current_route() {
+      let table_block = match active_lidarr_block {
+        ActiveLidarrBlock::WantedSearchAllPrompt
+        | ActiveLidarrBlock::WantedSearchSelectedPrompt => {
+          context.unwrap_or(ActiveLidarrBlock::WantedMissing)
+        }
+        _ => active_lidarr_block,
+      };
+      draw_wanted_table(f, app, area, table_block);
+
+      match active_lidarr_block {
+        ActiveLidarrBlock::WantedSearchSelectedPrompt => {
+          let album = if table_block == ActiveLidarrBlock::WantedCutoffUnmet {
+            app.data.lidarr_data.cutoff_unmet.current_selection()
+          } else {
+            app.data.lidarr_data.wanted_missing.current_selection()
+          };
+          let prompt = format!(
+            "Do you want to trigger an automatic search for: \n{}?",
+            album.title.text
+          );
+          let confirmation_prompt = ConfirmationPrompt::new()
+            .title("Search Selected Album")
+            .prompt(&prompt)
+            .yes_no_value(app.data.lidarr_data.prompt_confirm);
+
+          f.render_widget(
+            Popup::new(confirmation_prompt).size(Size::MediumPrompt),
+            f.area(),
+          );
+        }
+        ActiveLidarrBlock::WantedSearchAllPrompt => {
+          let prompt = if table_block == ActiveLidarrBlock::WantedCutoffUnmet {
+            "Do you want to trigger an automatic search for all albums that have not met their quality cutoff?"
+          } else {
+            "Do you want to trigger an automatic search for all missing albums?"
+          };
+          let confirmation_prompt = ConfirmationPrompt::new()
+            .title("Search All Wanted Albums")
+            .prompt(prompt)
+            .yes_no_value(app.data.lidarr_data.prompt_confirm);
+
+          f.render_widget(
+            Popup::new(confirmation_prompt).size(Size::MediumPrompt),
+            f.area(),
+          );
+        }
+        _ => (),
+      }
+    }
+  }
+}
+
+fn draw_wanted_table(
+  f: &mut Frame<'_>,
+  app: &mut App<'_>,
+  area: Rect,
+  table_block: ActiveLidarrBlock,
+) {
+  let artist_names: HashMap<i64, String> = app
+    .data
+    .lidarr_data
+    .artists
+    .items
+    .iter()
+    .map(|artist| (artist.id, artist.artist_name.text.clone()))
+    .collect();
+  let is_cutoff_unmet = table_block == ActiveLidarrBlock::WantedCutoffUnmet;
+  let wanted_row_mapping = |album: &Album| {
+    let Album {
+      artist_id,
+      title,
+      album_type,
+      release_date,
+      ..
+    } = album;
+    let release_date =
+      release_date.map_or_else(String::new, |date| date.format("%Y-%m-%d").to_string());
+
+    let row = Row::new(vec![
+      Cell::from(artist_names.get(artist_id).cloned().unwrap_or_default()),
+      Cell::from(title.text.clone()),
+      Cell::from(album_type.clone().unwrap_or_default()),
+      Cell::from(release_date),
+    ]);
+
+    if is_cutoff_unmet {
+      row.warning()
+    } else {
+      row.missing()
+    }
+  };
+  let (title, wanted_table) = if is_cutoff_unmet {
+    ("Cutoff Unmet", &mut app.data.lidarr_data.cutoff_unmet)
+  } else {
+    ("Missing", &mut app.data.lidarr_data.wanted_missing)
+  };
+  let wanted_table = ManagarrTable::new(Some(wanted_table), wanted_row_mapping)
+    .block(layout_block_top_border_with_title(title_style(title)))
+    .loading(app.is_loading)
+    .headers(["Artist", "Album", "Type", "Release Date"])
+    .constraints([
+      Constraint::Percentage(35),
+      Constraint::Percentage(35),
+      Constraint::Percentage(15),
+      Constraint::Percentage(15),
+    ]);
+
+  f.render_widget(wanted_table, area);
+}
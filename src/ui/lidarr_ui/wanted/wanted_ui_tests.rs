@@ -0,0 +1,95 @@
+#[cfg(test)]
+mod tests {
+  use strum::IntoEnumIterator;
+
+  use crate::app::App;
+  use crate::models::servarr_data::lidarr::lidarr_data::{ActiveLidarrBlock, WANTED_BLOCKS};
+  use crate::ui::DrawUi;
+  use crate::ui::lidarr_ui::wanted::WantedUi;
+  use crate::ui::ui_test_utils::test_utils::render_to_string_with_app;
+
+  #[test]
+  fn test_wanted_ui_accepts() {
+    ActiveLidarrBlock::iter().for_each(|active_lidarr_block| {
+      if WANTED_BLOCKS.contains(&active_lidarr_block) {
+        assert!(WantedUi::accepts(active_lidarr_block.into()));
+      } else {
+        assert!(!WantedUi::accepts(active_lidarr_block.into()));
+      }
+    });
+  }
+
+  mod snapshot_tests {
+    use crate::ui::ui_test_utils::test_utils::TerminalSize;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_wanted_ui_renders_loading(
+      #[values(ActiveLidarrBlock::WantedMissing, ActiveLidarrBlock::WantedCutoffUnmet)]
+      active_lidarr_block: ActiveLidarrBlock,
+    ) {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.push_navigation_stack(active_lidarr_block.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        WantedUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(format!("loading_{active_lidarr_block}"), output);
+    }
+
+    #[rstest]
+    fn test_wanted_ui_renders_empty(
+      #[values(ActiveLidarrBlock::WantedMissing, ActiveLidarrBlock::WantedCutoffUnmet)]
+      active_lidarr_block: ActiveLidarrBlock,
+    ) {
+      let mut app = App::test_default();
+      app.push_navigation_stack(active_lidarr_block.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        WantedUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(format!("empty_{active_lidarr_block}"), output);
+    }
+
+    #[rstest]
+    fn test_wanted_ui_renders_wanted_tab(
+      #[values(ActiveLidarrBlock::WantedMissing, ActiveLidarrBlock::WantedCutoffUnmet)]
+      active_lidarr_block: ActiveLidarrBlock,
+    ) {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(active_lidarr_block.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        WantedUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(active_lidarr_block.to_string(), output);
+    }
+
+    #[rstest]
+    fn test_wanted_ui_renders_search_prompts(
+      #[values(ActiveLidarrBlock::WantedMissing, ActiveLidarrBlock::WantedCutoffUnmet)]
+      table_block: ActiveLidarrBlock,
+      #[values(
+        ActiveLidarrBlock::WantedSearchAllPrompt,
+        ActiveLidarrBlock::WantedSearchSelectedPrompt
+      )]
+      prompt_block: ActiveLidarrBlock,
+    ) {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(table_block.into());
+      app.push_navigation_stack((prompt_block, Some(table_block)).into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        WantedUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(format!("{prompt_block}_{table_block}"), output);
+    }
+  }
+}
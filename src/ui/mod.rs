@@ -2,6 +2,7 @@ use std::cell::Cell;
 use std::sync::atomic::Ordering;
 
 use lidarr_ui::LidarrUi;
+use prowlarr_ui::ProwlarrUi;
 use ratatui::Frame;
 use ratatui::layout::{Constraint, Flex, Layout, Rect};
 use ratatui::style::Stylize;
@@ -10,6 +11,7 @@ use ratatui::widgets::Paragraph;
 use ratatui::widgets::Tabs;
 use ratatui::widgets::Wrap;
 use ratatui::widgets::{Clear, Row};
+use readarr_ui::ReadarrUi;
 use sonarr_ui::SonarrUi;
 use utils::layout_block;
 
@@ -31,7 +33,9 @@ use crate::ui::widgets::popup::{Popup, Size};
 
 mod builtin_themes;
 mod lidarr_ui;
+mod prowlarr_ui;
 mod radarr_ui;
+mod readarr_ui;
 mod sonarr_ui;
 mod styles;
 pub mod theme;
@@ -94,6 +98,14 @@ pub fn ui(f: &mut Frame<'_>, app: &mut App<'_>) {
       LidarrUi::draw_context_row(f, app, context_area);
       LidarrUi::draw(f, app, table_area);
     }
+    route if ProwlarrUi::accepts(route) => {
+      ProwlarrUi::draw_context_row(f, app, context_area);
+      ProwlarrUi::draw(f, app, table_area);
+    }
+    route if ReadarrUi::accepts(route) => {
+      ReadarrUi::draw_context_row(f, app, context_area);
+      ReadarrUi::draw(f, app, table_area);
+    }
     _ => (),
   }
 
@@ -113,7 +125,16 @@ fn draw_header_row(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
     .flex(Flex::SpaceBetween)
     .margin(1)
     .areas(area);
-  let help_text = Text::from("<?> to open help".help());
+  let mut help_line = Line::from("<?> to open help".help());
+  let retrying = match app.retry_attempts.values().collect::<Vec<_>>().as_slice() {
+    [] => None,
+    [(attempt, retries)] => Some(format!("retrying ({attempt}/{retries}) | ")),
+    retry_attempts => Some(format!("retrying {} requests | ", retry_attempts.len())),
+  };
+  if let Some(retrying) = retrying {
+    help_line.spans.insert(0, retrying.warning());
+  }
+  let help_text = Text::from(help_line);
 
   let titles = app
     .server_tabs
@@ -0,0 +1,74 @@
+#[cfg(test)]
+mod tests {
+  use strum::IntoEnumIterator;
+
+  use crate::app::App;
+  use crate::models::servarr_data::prowlarr::prowlarr_data::{
+    APPLICATIONS_BLOCKS, ActiveProwlarrBlock,
+  };
+  use crate::ui::DrawUi;
+  use crate::ui::prowlarr_ui::applications::ApplicationsUi;
+  use crate::ui::ui_test_utils::test_utils::render_to_string_with_app;
+
+  #[test]
+  fn test_applications_ui_accepts() {
+    ActiveProwlarrBlock::iter().for_each(|active_prowlarr_block| {
+      if APPLICATIONS_BLOCKS.contains(&active_prowlarr_block) {
+        assert!(ApplicationsUi::accepts(active_prowlarr_block.into()));
+      } else {
+        assert!(!ApplicationsUi::accepts(active_prowlarr_block.into()));
+      }
+    });
+  }
+
+  mod snapshot_tests {
+    use crate::ui::ui_test_utils::test_utils::TerminalSize;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[test]
+    fn test_applications_ui_renders_loading() {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.push_navigation_stack(ActiveProwlarrBlock::Applications.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        ApplicationsUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(output);
+    }
+
+    #[test]
+    fn test_applications_ui_renders_empty() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveProwlarrBlock::Applications.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        ApplicationsUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(output);
+    }
+
+    #[rstest]
+    fn test_applications_ui_renders(
+      #[values(
+        ActiveProwlarrBlock::Applications,
+        ActiveProwlarrBlock::DeleteApplicationPrompt,
+        ActiveProwlarrBlock::SyncAppIndexersPrompt
+      )]
+      active_prowlarr_block: ActiveProwlarrBlock,
+    ) {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(active_prowlarr_block.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        ApplicationsUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(format!("applications_tab_{active_prowlarr_block}"), output);
+    }
+  }
+}
@@ -0,0 +1,124 @@
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Rect};
+use ratatui::widgets::{Cell, Row};
+
+use crate::app::App;
+use crate::models::Route;
+use crate::models::prowlarr_models::Application;
+use crate::models::servarr_data::prowlarr::prowlarr_data::{
+  APPLICATIONS_BLOCKS, ActiveProwlarrBlock,
+};
+use crate::ui::DrawUi;
+use crate::ui::styles::ManagarrStyle;
+use crate::ui::utils::layout_block_top_border;
+use crate::ui::widgets::confirmation_prompt::ConfirmationPrompt;
+use crate::ui::widgets::managarr_table::ManagarrTable;
+use crate::ui::widgets::popup::{Popup, Size};
+
+#[cfg(test)]
+#[path = "applications_ui_tests.rs"]
+mod applications_ui_tests;
+
+pub(super) struct ApplicationsUi;
+
+impl DrawUi for ApplicationsUi {
+  fn accepts(route: Route) -> bool {
+    if let Route::Prowlarr(active_prowlarr_block, _) = route {
+      return APPLICATIONS_BLOCKS.contains(&active_prowlarr_block);
+    }
+
+    false
+  }
+
+  fn draw(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+    if let Route::Prowlarr(active_prowlarr_block, _) = app.get_current_route() {
+      draw_applications(f, app, area);
+
+      match active_prowlarr_block {
+        ActiveProwlarrBlock::DeleteApplicationPrompt => {
+          let prompt = format!(
+            "Do you really want to delete this application: \n{}?",
+            app
+              .data
+              .prowlarr_data
+              .applications
+              .current_selection()
+              .name
+              .clone()
+              .unwrap_or_default()
+          );
+          let confirmation_prompt = ConfirmationPrompt::new()
+            .title("Delete Application")
+            .prompt(&prompt)
+            .yes_no_value(app.data.prowlarr_data.prompt_confirm);
+
+          f.render_widget(
+            Popup::new(confirmation_prompt).size(Size::MediumPrompt),
+            f.area(),
+          );
+        }
+        ActiveProwlarrBlock::SyncAppIndexersPrompt => {
+          let confirmation_prompt = ConfirmationPrompt::new()
+            .title("Sync App Indexers")
+            .prompt("Do you want to sync your indexers to all of your applications?")
+            .yes_no_value(app.data.prowlarr_data.prompt_confirm);
+
+          f.render_widget(
+            Popup::new(confirmation_prompt).size(Size::MediumPrompt),
+            f.area(),
+          );
+        }
+        _ => (),
+      }
+    }
+  }
+}
+
+fn draw_applications(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+  let applications_row_mapping = |application: &'_ Application| {
+    let Application {
+      name,
+      implementation_name,
+      sync_level,
+      tags,
+      ..
+    } = application;
+    let empty_tag = String::new();
+    let tags: String = tags
+      .iter()
+      .map(|tag_id| {
+        app
+          .data
+          .prowlarr_data
+          .tags_map
+          .get_by_left(&tag_id.as_i64().unwrap())
+          .unwrap_or(&empty_tag)
+          .clone()
+      })
+      .collect::<Vec<String>>()
+      .join(", ");
+
+    Row::new(vec![
+      Cell::from(name.clone().unwrap_or_default()),
+      Cell::from(implementation_name.clone().unwrap_or_default()),
+      Cell::from(sync_level.to_display_str()),
+      Cell::from(tags),
+    ])
+    .primary()
+  };
+  let applications_table = ManagarrTable::new(
+    Some(&mut app.data.prowlarr_data.applications),
+    applications_row_mapping,
+  )
+  .block(layout_block_top_border())
+  .loading(app.is_loading)
+  .headers(["Name", "Application", "Sync Level", "Tags"])
+  .constraints([
+    Constraint::Percentage(30),
+    Constraint::Percentage(20),
+    Constraint::Percentage(20),
+    Constraint::Percentage(30),
+  ]);
+
+  f.render_widget(applications_table, area);
+}
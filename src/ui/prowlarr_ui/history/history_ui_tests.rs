@@ -0,0 +1,82 @@
+#[cfg(test)]
+mod tests {
+  use strum::IntoEnumIterator;
+
+  use crate::app::App;
+  use crate::models::servarr_data::prowlarr::prowlarr_data::{ActiveProwlarrBlock, HISTORY_BLOCKS};
+  use crate::ui::DrawUi;
+  use crate::ui::prowlarr_ui::history::HistoryUi;
+  use crate::ui::ui_test_utils::test_utils::render_to_string_with_app;
+
+  #[test]
+  fn test_history_ui_accepts() {
+    ActiveProwlarrBlock::iter().for_each(|active_prowlarr_block| {
+      if HISTORY_BLOCKS.contains(&active_prowlarr_block) {
+        assert!(HistoryUi::accepts(active_prowlarr_block.into()));
+      } else {
+        assert!(!HistoryUi::accepts(active_prowlarr_block.into()));
+      }
+    });
+  }
+
+  mod snapshot_tests {
+    use crate::ui::ui_test_utils::test_utils::TerminalSize;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[test]
+    fn test_history_ui_renders_loading() {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.push_navigation_stack(ActiveProwlarrBlock::History.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        HistoryUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(output);
+    }
+
+    #[rstest]
+    fn test_history_ui_renders_empty(
+      #[values(ActiveProwlarrBlock::History, ActiveProwlarrBlock::HistoryItemDetails)]
+      active_prowlarr_block: ActiveProwlarrBlock,
+    ) {
+      let mut app = App::test_default();
+      app.push_navigation_stack(active_prowlarr_block.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        HistoryUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(
+        format!("loading_history_tab_{active_prowlarr_block}"),
+        output
+      );
+    }
+
+    #[rstest]
+    fn test_history_ui_renders(
+      #[values(
+        ActiveProwlarrBlock::History,
+        ActiveProwlarrBlock::HistoryItemDetails,
+        ActiveProwlarrBlock::HistorySortPrompt,
+        ActiveProwlarrBlock::FilterHistory,
+        ActiveProwlarrBlock::FilterHistoryError,
+        ActiveProwlarrBlock::SearchHistory,
+        ActiveProwlarrBlock::SearchHistoryError
+      )]
+      active_prowlarr_block: ActiveProwlarrBlock,
+    ) {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(active_prowlarr_block.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        HistoryUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(format!("history_tab_{active_prowlarr_block}"), output);
+    }
+  }
+}
@@ -0,0 +1,179 @@
+use crate::app::App;
+use crate::models::Route;
+use crate::models::prowlarr_models::{ProwlarrHistoryData, ProwlarrHistoryItem};
+use crate::models::servarr_data::prowlarr::prowlarr_data::{ActiveProwlarrBlock, HISTORY_BLOCKS};
+use crate::ui::DrawUi;
+use crate::ui::styles::{ManagarrStyle, secondary_style};
+use crate::ui::utils::layout_block_top_border;
+use crate::ui::widgets::managarr_table::ManagarrTable;
+use crate::ui::widgets::message::Message;
+use crate::ui::widgets::popup::{Popup, Size};
+use ratatui::Frame;
+use ratatui::layout::{Alignment, Rect};
+use ratatui::prelude::Constraint;
+use ratatui::text::{Line, Text};
+use ratatui::widgets::{Cell, Row};
+
+#[cfg(test)]
+#[path = "history_ui_tests.rs"]
+mod history_ui_tests;
+
+pub(super) struct HistoryUi;
+
+impl DrawUi for HistoryUi {
+  fn accepts(route: Route) -> bool {
+    if let Route::Prowlarr(active_prowlarr_block, _) = route {
+      return HISTORY_BLOCKS.contains(&active_prowlarr_block);
+    }
+
+    false
+  }
+
+  fn draw(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+    if let Route::Prowlarr(active_prowlarr_block, _) = app.get_current_route() {
+      draw_history_table(f, app, area);
+
+      if active_prowlarr_block == ActiveProwlarrBlock::HistoryItemDetails {
+        draw_history_item_details_popup(f, app);
+      }
+    }
+  }
+}
+
+fn draw_history_table(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+  if let Route::Prowlarr(active_prowlarr_block, _) = app.get_current_route() {
+    let indexers = &app.data.prowlarr_data.indexers.items;
+    let history_row_mapping = |history_item: &ProwlarrHistoryItem| {
+      let ProwlarrHistoryItem {
+        indexer_id,
+        date,
+        successful,
+        event_type,
+        data,
+        ..
+      } = history_item;
+      let indexer_name = indexers
+        .iter()
+        .find(|indexer| indexer.id == *indexer_id)
+        .and_then(|indexer| indexer.name.clone())
+        .unwrap_or_default();
+      let row = Row::new(vec![
+        Cell::from(indexer_name),
+        Cell::from(data.query.clone().unwrap_or_default()),
+        Cell::from(event_type.clone().to_display_str()),
+        Cell::from(date.to_string()),
+      ]);
+
+      if *successful {
+        row.primary()
+      } else {
+        row.failure()
+      }
+    };
+    let history_table = ManagarrTable::new(
+      Some(&mut app.data.prowlarr_data.history),
+      history_row_mapping,
+    )
+    .block(layout_block_top_border())
+    .loading(app.is_loading)
+    .sorting(active_prowlarr_block == ActiveProwlarrBlock::HistorySortPrompt)
+    .searching(active_prowlarr_block == ActiveProwlarrBlock::SearchHistory)
+    .search_produced_empty_results(active_prowlarr_block == ActiveProwlarrBlock::SearchHistoryError)
+    .filtering(active_prowlarr_block == ActiveProwlarrBlock::FilterHistory)
+    .filter_produced_empty_results(active_prowlarr_block == ActiveProwlarrBlock::FilterHistoryError)
+    .headers(["Indexer", "Query", "Event Type", "Date"])
+    .constraints([
+      Constraint::Percentage(22),
+      Constraint::Percentage(42),
+      Constraint::Percentage(13),
+      Constraint::Percentage(23),
+    ]);
+
+    if [
+      ActiveProwlarrBlock::SearchHistory,
+      ActiveProwlarrBlock::FilterHistory,
+    ]
+    .contains(&active_prowlarr_block)
+    {
+      history_table.show_cursor(f, area);
+    }
+
+    f.render_widget(history_table, area);
+  }
+}
+
+fn draw_history_item_details_popup(f: &mut Frame<'_>, app: &mut App<'_>) {
+  let current_selection = if app.data.prowlarr_data.history.items.is_empty() {
+    ProwlarrHistoryItem::default()
+  } else {
+    app.data.prowlarr_data.history.current_selection().clone()
+  };
+  let indexer_name = app
+    .data
+    .prowlarr_data
+    .indexer_name(current_selection.indexer_id);
+
+  let text = Text::from(create_history_event_details(
+    current_selection,
+    indexer_name,
+  ));
+
+  let message = Message::new(text)
+    .title("Details")
+    .style(secondary_style())
+    .alignment(Alignment::Left);
+
+  f.render_widget(Popup::new(message).size(Size::NarrowLongMessage), f.area());
+}
+
+fn create_history_event_details(
+  history_item: ProwlarrHistoryItem,
+  indexer_name: String,
+) -> Vec<Line<'static>> {
+  let ProwlarrHistoryItem {
+    date,
+    successful,
+    event_type,
+    data,
+    ..
+  } = history_item;
+  let ProwlarrHistoryData {
+    query,
+    query_type,
+    categories,
+    source,
+    host,
+    grab_title,
+    elapsed_time,
+    query_results,
+    url,
+  } = data;
+  let optional_lines = [
+    ("Query", query),
+    ("Query Type", query_type),
+    ("Categories", categories),
+    ("Results", query_results),
+    ("Grab Title", grab_title),
+    ("Url", url),
+    ("Source", source),
+    ("Host", host),
+    (
+      "Elapsed Time",
+      elapsed_time.map(|elapsed| format!("{elapsed}ms")),
+    ),
+  ];
+
+  let mut lines = vec![
+    Line::from(format!("Indexer: {indexer_name}")),
+    Line::from(format!("Event Type: {}", event_type.to_display_str())),
+    Line::from(format!("Successful: {successful}")),
+    Line::from(format!("Date: {date}")),
+  ];
+  lines.extend(
+    optional_lines
+      .into_iter()
+      .filter_map(|(label, value)| value.map(|value| Line::from(format!("{label}: {value}")))),
+  );
+
+  lines
+}
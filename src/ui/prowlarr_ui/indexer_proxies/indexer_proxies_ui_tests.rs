@@ -0,0 +1,76 @@
+#[cfg(test)]
+mod tests {
+  use strum::IntoEnumIterator;
+
+  use crate::app::App;
+  use crate::models::servarr_data::prowlarr::prowlarr_data::{
+    ActiveProwlarrBlock, INDEXER_PROXIES_BLOCKS,
+  };
+  use crate::ui::DrawUi;
+  use crate::ui::prowlarr_ui::indexer_proxies::IndexerProxiesUi;
+  use crate::ui::ui_test_utils::test_utils::render_to_string_with_app;
+
+  #[test]
+  fn test_indexer_proxies_ui_accepts() {
+    ActiveProwlarrBlock::iter().for_each(|active_prowlarr_block| {
+      if INDEXER_PROXIES_BLOCKS.contains(&active_prowlarr_block) {
+        assert!(IndexerProxiesUi::accepts(active_prowlarr_block.into()));
+      } else {
+        assert!(!IndexerProxiesUi::accepts(active_prowlarr_block.into()));
+      }
+    });
+  }
+
+  mod snapshot_tests {
+    use crate::ui::ui_test_utils::test_utils::TerminalSize;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[test]
+    fn test_indexer_proxies_ui_renders_loading() {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.push_navigation_stack(ActiveProwlarrBlock::IndexerProxies.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        IndexerProxiesUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(output);
+    }
+
+    #[test]
+    fn test_indexer_proxies_ui_renders_empty() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveProwlarrBlock::IndexerProxies.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        IndexerProxiesUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(output);
+    }
+
+    #[rstest]
+    fn test_indexer_proxies_ui_renders(
+      #[values(
+        ActiveProwlarrBlock::IndexerProxies,
+        ActiveProwlarrBlock::DeleteIndexerProxyPrompt
+      )]
+      active_prowlarr_block: ActiveProwlarrBlock,
+    ) {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(active_prowlarr_block.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        IndexerProxiesUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(
+        format!("indexer_proxies_tab_{active_prowlarr_block}"),
+        output
+      );
+    }
+  }
+}
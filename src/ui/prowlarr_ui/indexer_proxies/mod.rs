@@ -0,0 +1,107 @@
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Rect};
+use ratatui::widgets::{Cell, Row};
+
+use crate::app::App;
+use crate::models::Route;
+use crate::models::prowlarr_models::IndexerProxy;
+use crate::models::servarr_data::prowlarr::prowlarr_data::{
+  ActiveProwlarrBlock, INDEXER_PROXIES_BLOCKS,
+};
+use crate::ui::DrawUi;
+use crate::ui::styles::ManagarrStyle;
+use crate::ui::utils::layout_block_top_border;
+use crate::ui::widgets::confirmation_prompt::ConfirmationPrompt;
+use crate::ui::widgets::managarr_table::ManagarrTable;
+use crate::ui::widgets::popup::{Popup, Size};
+
+#[cfg(test)]
+#[path = "indexer_proxies_ui_tests.rs"]
+mod indexer_proxies_ui_tests;
+
+pub(super) struct IndexerProxiesUi;
+
+impl DrawUi for IndexerProxiesUi {
+  fn accepts(route: Route) -> bool {
+    if let Route::Prowlarr(active_prowlarr_block, _) = route {
+      return INDEXER_PROXIES_BLOCKS.contains(&active_prowlarr_block);
+    }
+
+    false
+  }
+
+  fn draw(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+    if let Route::Prowlarr(active_prowlarr_block, _) = app.get_current_route() {
+      draw_indexer_proxies(f, app, area);
+
+      if active_prowlarr_block == ActiveProwlarrBlock::DeleteIndexerProxyPrompt {
+        let prompt = format!(
+          "Do you really want to delete this indexer proxy: \n{}?",
+          app
+            .data
+            .prowlarr_data
+            .indexer_proxies
+            .current_selection()
+            .name
+            .clone()
+            .unwrap_or_default()
+        );
+        let confirmation_prompt = ConfirmationPrompt::new()
+          .title("Delete Indexer Proxy")
+          .prompt(&prompt)
+          .yes_no_value(app.data.prowlarr_data.prompt_confirm);
+
+        f.render_widget(
+          Popup::new(confirmation_prompt).size(Size::MediumPrompt),
+          f.area(),
+        );
+      }
+    }
+  }
+}
+
+fn draw_indexer_proxies(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+  let indexer_proxies_row_mapping = |indexer_proxy: &'_ IndexerProxy| {
+    let IndexerProxy {
+      name,
+      implementation_name,
+      tags,
+      ..
+    } = indexer_proxy;
+    let empty_tag = String::new();
+    let tags: String = tags
+      .iter()
+      .map(|tag_id| {
+        app
+          .data
+          .prowlarr_data
+          .tags_map
+          .get_by_left(&tag_id.as_i64().unwrap())
+          .unwrap_or(&empty_tag)
+          .clone()
+      })
+      .collect::<Vec<String>>()
+      .join(", ");
+
+    Row::new(vec![
+      Cell::from(name.clone().unwrap_or_default()),
+      Cell::from(implementation_name.clone().unwrap_or_default()),
+      Cell::from(tags),
+    ])
+    .primary()
+  };
+  let indexer_proxies_table = ManagarrTable::new(
+    Some(&mut app.data.prowlarr_data.indexer_proxies),
+    indexer_proxies_row_mapping,
+  )
+  .block(layout_block_top_border())
+  .loading(app.is_loading)
+  .headers(["Name", "Type", "Tags"])
+  .constraints([
+    Constraint::Percentage(40),
+    Constraint::Percentage(25),
+    Constraint::Percentage(35),
+  ]);
+
+  f.render_widget(indexer_proxies_table, area);
+}
@@ -0,0 +1,77 @@
+#[cfg(test)]
+mod tests {
+  use strum::IntoEnumIterator;
+
+  use crate::app::App;
+  use crate::models::servarr_data::prowlarr::prowlarr_data::{
+    ADD_INDEXER_BLOCKS, ActiveProwlarrBlock,
+  };
+  use crate::ui::DrawUi;
+  use crate::ui::prowlarr_ui::indexers::add_indexer_ui::AddIndexerUi;
+  use crate::ui::ui_test_utils::test_utils::{TerminalSize, render_to_string_with_app};
+
+  #[test]
+  fn test_add_indexer_ui_accepts() {
+    ActiveProwlarrBlock::iter().for_each(|active_prowlarr_block| {
+      if ADD_INDEXER_BLOCKS.contains(&active_prowlarr_block) {
+        assert!(AddIndexerUi::accepts(active_prowlarr_block.into()));
+      } else {
+        assert!(!AddIndexerUi::accepts(active_prowlarr_block.into()));
+      }
+    });
+  }
+
+  mod snapshot_tests {
+    use super::*;
+
+    #[test]
+    fn test_add_indexer_ui_renders_select_implementation_loading() {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.push_navigation_stack(ActiveProwlarrBlock::AddIndexerSelectImplementation.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        AddIndexerUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(output);
+    }
+
+    #[test]
+    fn test_add_indexer_ui_renders_select_implementation() {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(ActiveProwlarrBlock::AddIndexerSelectImplementation.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        AddIndexerUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(output);
+    }
+
+    #[test]
+    fn test_add_indexer_ui_renders_add_indexer_prompt() {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(ActiveProwlarrBlock::AddIndexerPrompt.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        AddIndexerUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(output);
+    }
+
+    #[test]
+    fn test_add_indexer_ui_renders_add_indexer_field_input() {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(ActiveProwlarrBlock::AddIndexerPrompt.into());
+      app.push_navigation_stack(ActiveProwlarrBlock::AddIndexerFieldInput.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        AddIndexerUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(output);
+    }
+  }
+}
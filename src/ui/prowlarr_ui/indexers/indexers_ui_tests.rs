@@ -0,0 +1,104 @@
+#[cfg(test)]
+mod tests {
+  use strum::IntoEnumIterator;
+
+  use crate::app::App;
+  use crate::models::servarr_data::prowlarr::prowlarr_data::{
+    ADD_INDEXER_BLOCKS, ActiveProwlarrBlock, INDEXERS_BLOCKS,
+  };
+  use crate::ui::DrawUi;
+  use crate::ui::prowlarr_ui::indexers::IndexersUi;
+  use crate::ui::ui_test_utils::test_utils::render_to_string_with_app;
+
+  #[test]
+  fn test_indexers_ui_accepts() {
+    let mut indexers_blocks = Vec::new();
+    indexers_blocks.extend(INDEXERS_BLOCKS);
+    indexers_blocks.extend(ADD_INDEXER_BLOCKS);
+    indexers_blocks.push(ActiveProwlarrBlock::TestAllIndexers);
+
+    ActiveProwlarrBlock::iter().for_each(|active_prowlarr_block| {
+      if indexers_blocks.contains(&active_prowlarr_block) {
+        assert!(IndexersUi::accepts(active_prowlarr_block.into()));
+      } else {
+        assert!(!IndexersUi::accepts(active_prowlarr_block.into()));
+      }
+    });
+  }
+
+  mod snapshot_tests {
+    use crate::ui::ui_test_utils::test_utils::TerminalSize;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[test]
+    fn test_indexers_ui_renders_loading() {
+      let mut app = App::test_default_fully_populated();
+      app.is_loading = true;
+      app.push_navigation_stack(ActiveProwlarrBlock::Indexers.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        IndexersUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(output);
+    }
+
+    #[test]
+    fn test_indexers_ui_renders_loading_test_results_when_indexer_test_errors_is_none() {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(ActiveProwlarrBlock::TestIndexer.into());
+      app.data.prowlarr_data.indexer_test_errors = None;
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        IndexersUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(output);
+    }
+
+    #[test]
+    fn test_indexers_ui_renders_empty_indexers() {
+      let mut app = App::test_default();
+      app.push_navigation_stack(ActiveProwlarrBlock::Indexers.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        IndexersUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(output);
+    }
+
+    #[rstest]
+    fn test_indexers_ui_renders(
+      #[values(
+        ActiveProwlarrBlock::DeleteIndexerPrompt,
+        ActiveProwlarrBlock::Indexers,
+        ActiveProwlarrBlock::TestIndexer
+      )]
+      active_prowlarr_block: ActiveProwlarrBlock,
+    ) {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(active_prowlarr_block.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        IndexersUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(format!("indexers_ui_{active_prowlarr_block}"), output);
+    }
+
+    #[test]
+    fn test_indexers_ui_renders_test_all_over_indexers() {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(ActiveProwlarrBlock::TestAllIndexers.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        IndexersUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(output);
+    }
+  }
+}
@@ -0,0 +1,166 @@
+use crate::ui::styles::success_style;
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Rect};
+use ratatui::text::Text;
+use ratatui::widgets::{Cell, Row};
+
+use crate::app::App;
+use crate::models::Route;
+use crate::models::prowlarr_models::ProwlarrIndexer;
+use crate::models::servarr_data::prowlarr::prowlarr_data::{ActiveProwlarrBlock, INDEXERS_BLOCKS};
+use crate::ui::DrawUi;
+use crate::ui::prowlarr_ui::indexers::add_indexer_ui::AddIndexerUi;
+use crate::ui::prowlarr_ui::indexers::test_all_indexers_ui::TestAllIndexersUi;
+use crate::ui::styles::ManagarrStyle;
+use crate::ui::utils::{layout_block_top_border, title_block};
+use crate::ui::widgets::confirmation_prompt::ConfirmationPrompt;
+use crate::ui::widgets::loading_block::LoadingBlock;
+use crate::ui::widgets::managarr_table::ManagarrTable;
+use crate::ui::widgets::message::Message;
+use crate::ui::widgets::popup::{Popup, Size};
+
+mod add_indexer_ui;
+mod test_all_indexers_ui;
+
+#[cfg(test)]
+#[path = "indexers_ui_tests.rs"]
+mod indexers_ui_tests;
+
+pub(super) struct IndexersUi;
+
+impl DrawUi for IndexersUi {
+  fn accepts(route: Route) -> bool {
+    if let Route::Prowlarr(active_prowlarr_block, _) = route {
+      return AddIndexerUi::accepts(route)
+        || TestAllIndexersUi::accepts(route)
+        || INDEXERS_BLOCKS.contains(&active_prowlarr_block);
+    }
+
+    false
+  }
+
+  fn draw(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+    let route = app.get_current_route();
+    draw_indexers(f, app, area);
+
+    match route {
+      _ if AddIndexerUi::accepts(route) => AddIndexerUi::draw(f, app, area),
+      _ if TestAllIndexersUi::accepts(route) => TestAllIndexersUi::draw(f, app, area),
+      Route::Prowlarr(active_prowlarr_block, _) => match active_prowlarr_block {
+        ActiveProwlarrBlock::TestIndexer => {
+          if let Some(result) = app
+            .data
+            .prowlarr_data
+            .indexer_test_errors
+            .as_ref()
+            .filter(|_| !app.is_loading)
+          {
+            let popup = if !result.is_empty() {
+              Popup::new(Message::new(result.clone())).size(Size::LargeMessage)
+            } else {
+              let message = Message::new("Indexer test succeeded!")
+                .title("Success")
+                .style(success_style().bold());
+              Popup::new(message).size(Size::Message)
+            };
+
+            f.render_widget(popup, f.area());
+          } else {
+            let loading_popup = Popup::new(LoadingBlock::new(
+              app.is_loading || app.data.prowlarr_data.indexer_test_errors.is_none(),
+              title_block("Testing Indexer"),
+            ))
+            .size(Size::LargeMessage);
+            f.render_widget(loading_popup, f.area());
+          }
+        }
+        ActiveProwlarrBlock::DeleteIndexerPrompt => {
+          let prompt = format!(
+            "Do you really want to delete this indexer: \n{}?",
+            app
+              .data
+              .prowlarr_data
+              .indexers
+              .current_selection()
+              .name
+              .clone()
+              .unwrap_or_default()
+          );
+          let confirmation_prompt = ConfirmationPrompt::new()
+            .title("Delete Indexer")
+            .prompt(&prompt)
+            .yes_no_value(app.data.prowlarr_data.prompt_confirm);
+
+          f.render_widget(
+            Popup::new(confirmation_prompt).size(Size::MediumPrompt),
+            f.area(),
+          );
+        }
+        _ => (),
+      },
+      _ => (),
+    }
+  }
+}
+
+fn draw_indexers(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+  let indexers_row_mapping = |indexer: &'_ ProwlarrIndexer| {
+    let ProwlarrIndexer {
+      name,
+      protocol,
+      privacy,
+      enable,
+      priority,
+      tags,
+      ..
+    } = indexer;
+    let status = if *enable {
+      Text::from("Enabled").success()
+    } else {
+      Text::from("Disabled").failure()
+    };
+    let empty_tag = String::new();
+    let tags: String = tags
+      .iter()
+      .map(|tag_id| {
+        app
+          .data
+          .prowlarr_data
+          .tags_map
+          .get_by_left(&tag_id.as_i64().unwrap())
+          .unwrap_or(&empty_tag)
+          .clone()
+      })
+      .collect::<Vec<String>>()
+      .join(", ");
+
+    Row::new(vec![
+      Cell::from(name.clone().unwrap_or_default()),
+      Cell::from(protocol.clone()),
+      Cell::from(privacy.to_display_str()),
+      Cell::from(priority.to_string()),
+      Cell::from(status),
+      Cell::from(tags),
+    ])
+    .primary()
+  };
+  let indexers_table = ManagarrTable::new(
+    Some(&mut app.data.prowlarr_data.indexers),
+    indexers_row_mapping,
+  )
+  .block(layout_block_top_border())
+  .loading(app.is_loading)
+  .headers([
+    "Indexer", "Protocol", "Privacy", "Priority", "Status", "Tags",
+  ])
+  .constraints([
+    Constraint::Percentage(27),
+    Constraint::Percentage(12),
+    Constraint::Percentage(13),
+    Constraint::Percentage(10),
+    Constraint::Percentage(13),
+    Constraint::Percentage(25),
+  ]);
+
+  f.render_widget(indexers_table, area);
+}
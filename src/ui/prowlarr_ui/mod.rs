@@ -0,0 +1,145 @@
+#[cfg(test)]
+use crate::ui::ui_test_utils::test_utils::Utc;
+use applications::ApplicationsUi;
+use chrono::Duration;
+#[cfg(not(test))]
+use chrono::Utc;
+use history::HistoryUi;
+use indexer_proxies::IndexerProxiesUi;
+use indexers::IndexersUi;
+use ratatui::{
+  Frame,
+  layout::{Constraint, Layout, Rect},
+  style::Stylize,
+  text::Text,
+  widgets::Paragraph,
+};
+use search::SearchReleasesUi;
+use system::SystemUi;
+
+use super::{
+  DrawUi, draw_tabs,
+  styles::ManagarrStyle,
+  utils::{borderless_block, layout_block, title_block},
+  widgets::loading_block::LoadingBlock,
+};
+use crate::{
+  app::App,
+  logos::PROWLARR_LOGO,
+  models::{Route, servarr_data::prowlarr::prowlarr_data::ProwlarrData},
+};
+
+mod applications;
+mod history;
+mod indexer_proxies;
+mod indexers;
+mod search;
+mod system;
+
+#[cfg(test)]
+#[path = "prowlarr_ui_tests.rs"]
+mod prowlarr_ui_tests;
+
+pub(super) struct ProwlarrUi;
+
+impl DrawUi for ProwlarrUi {
+  fn accepts(route: Route) -> bool {
+    matches!(route, Route::Prowlarr(_, _))
+  }
+
+  fn draw(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+    let content_area = draw_tabs(f, area, "Indexers", &app.data.prowlarr_data.main_tabs);
+    let route = app.get_current_route();
+
+    match route {
+      _ if IndexersUi::accepts(route) => IndexersUi::draw(f, app, content_area),
+      _ if SearchReleasesUi::accepts(route) => SearchReleasesUi::draw(f, app, content_area),
+      _ if ApplicationsUi::accepts(route) => ApplicationsUi::draw(f, app, content_area),
+      _ if IndexerProxiesUi::accepts(route) => IndexerProxiesUi::draw(f, app, content_area),
+      _ if HistoryUi::accepts(route) => HistoryUi::draw(f, app, content_area),
+      _ if SystemUi::accepts(route) => SystemUi::draw(f, app, content_area),
+      _ => (),
+    }
+  }
+
+  fn draw_context_row(f: &mut Frame<'_>, app: &App<'_>, area: Rect) {
+    let [stats_area, logo_area] =
+      Layout::horizontal([Constraint::Fill(0), Constraint::Length(20)]).areas(area);
+
+    draw_stats_context(f, app, stats_area);
+    draw_prowlarr_logo(f, logo_area);
+  }
+}
+
+fn draw_stats_context(f: &mut Frame<'_>, app: &App<'_>, area: Rect) {
+  let block = title_block("Stats");
+
+  if !app.data.prowlarr_data.version.is_empty() {
+    f.render_widget(block, area);
+    let ProwlarrData {
+      version,
+      start_time,
+      indexers,
+      applications,
+      ..
+    } = &app.data.prowlarr_data;
+
+    let [version_area, uptime_area, indexers_area, applications_area] =
+      Layout::vertical([Constraint::Length(1); 4])
+        .margin(1)
+        .areas(area);
+
+    let version_paragraph = Paragraph::new(Text::from(format!("Prowlarr Version:  {version}")))
+      .block(borderless_block())
+      .bold();
+
+    let uptime = Utc::now() - start_time.to_owned();
+    let days = uptime.num_days();
+    let day_difference = uptime - Duration::days(days);
+    let hours = day_difference.num_hours();
+    let hour_difference = day_difference - Duration::hours(hours);
+    let minutes = hour_difference.num_minutes();
+    let seconds = (hour_difference - Duration::minutes(minutes)).num_seconds();
+
+    let uptime_paragraph = Paragraph::new(Text::from(format!(
+      "Uptime: {days}d {hours:0width$}:{minutes:0width$}:{seconds:0width$}",
+      width = 2
+    )))
+    .block(borderless_block())
+    .bold();
+
+    let enabled_indexers = indexers
+      .items
+      .iter()
+      .filter(|indexer| indexer.enable)
+      .count();
+    let indexers_paragraph = Paragraph::new(Text::from(format!(
+      "Indexers: {enabled_indexers} enabled / {} total",
+      indexers.items.len()
+    )))
+    .block(borderless_block())
+    .default_color();
+    let applications_paragraph = Paragraph::new(Text::from(format!(
+      "Applications: {}",
+      applications.items.len()
+    )))
+    .block(borderless_block())
+    .default_color();
+
+    f.render_widget(version_paragraph, version_area);
+    f.render_widget(uptime_paragraph, uptime_area);
+    f.render_widget(indexers_paragraph, indexers_area);
+    f.render_widget(applications_paragraph, applications_area);
+  } else {
+    f.render_widget(LoadingBlock::new(app.is_loading, block), area);
+  }
+}
+
+fn draw_prowlarr_logo(f: &mut Frame<'_>, area: Rect) {
+  let logo_text = Text::from(PROWLARR_LOGO);
+  let logo = Paragraph::new(logo_text)
+    .light_red()
+    .block(layout_block().default_color())
+    .centered();
+  f.render_widget(logo, area);
+}
@@ -0,0 +1,45 @@
+#[cfg(test)]
+mod tests {
+  use strum::IntoEnumIterator;
+
+  use crate::models::Route;
+  use crate::models::servarr_data::prowlarr::prowlarr_data::ActiveProwlarrBlock;
+  use crate::ui::DrawUi;
+  use crate::ui::prowlarr_ui::ProwlarrUi;
+
+  #[test]
+  fn test_prowlarr_ui_accepts() {
+    for prowlarr_block in ActiveProwlarrBlock::iter() {
+      assert!(ProwlarrUi::accepts(Route::Prowlarr(prowlarr_block, None)));
+    }
+  }
+
+  mod snapshot_tests {
+    use super::*;
+    use crate::app::App;
+    use crate::ui::ui_test_utils::test_utils::{TerminalSize, render_to_string_with_app};
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(ActiveProwlarrBlock::Indexers, 0)]
+    #[case(ActiveProwlarrBlock::SearchReleases, 1)]
+    #[case(ActiveProwlarrBlock::Applications, 2)]
+    #[case(ActiveProwlarrBlock::IndexerProxies, 3)]
+    #[case(ActiveProwlarrBlock::History, 4)]
+    #[case(ActiveProwlarrBlock::System, 5)]
+    fn test_prowlarr_ui_renders_prowlarr_tabs(
+      #[case] active_prowlarr_block: ActiveProwlarrBlock,
+      #[case] index: usize,
+    ) {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(active_prowlarr_block.into());
+      app.data.prowlarr_data.main_tabs.set_index(index);
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        ProwlarrUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(format!("prowlarr_tabs_{active_prowlarr_block}"), output);
+    }
+  }
+}
@@ -0,0 +1,200 @@
+use std::sync::atomic::Ordering;
+
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::text::Text;
+use ratatui::widgets::{Cell, Row};
+use serde_json::Number;
+
+use crate::app::App;
+use crate::models::Route;
+use crate::models::prowlarr_models::ProwlarrRelease;
+use crate::models::servarr_data::prowlarr::prowlarr_data::{
+  ActiveProwlarrBlock, SEARCH_RELEASES_BLOCKS,
+};
+use crate::ui::DrawUi;
+use crate::ui::styles::ManagarrStyle;
+use crate::ui::utils::{
+  decorate_peer_style, get_width_from_percentage, layout_block_top_border, title_block_centered,
+};
+use crate::ui::widgets::confirmation_prompt::ConfirmationPrompt;
+use crate::ui::widgets::input_box::InputBox;
+use crate::ui::widgets::managarr_table::ManagarrTable;
+use crate::ui::widgets::popup::{Popup, Size};
+use crate::utils::convert_to_gb;
+
+#[cfg(test)]
+#[path = "search_releases_ui_tests.rs"]
+mod search_releases_ui_tests;
+
+pub(super) struct SearchReleasesUi;
+
+impl DrawUi for SearchReleasesUi {
+  fn accepts(route: Route) -> bool {
+    if let Route::Prowlarr(active_prowlarr_block, _) = route {
+      return SEARCH_RELEASES_BLOCKS.contains(&active_prowlarr_block);
+    }
+
+    false
+  }
+
+  fn draw(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+    if let Route::Prowlarr(active_prowlarr_block, _) = app.get_current_route() {
+      let [search_box_area, results_area] =
+        Layout::vertical([Constraint::Length(3), Constraint::Fill(0)]).areas(area);
+
+      draw_search_box(f, app, search_box_area);
+      draw_search_results(f, app, results_area);
+
+      if active_prowlarr_block == ActiveProwlarrBlock::GrabReleaseConfirmPrompt {
+        draw_grab_release_confirm_prompt(f, app);
+      }
+    }
+  }
+}
+
+fn draw_search_box(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+  let (block_content, offset) = app
+    .data
+    .prowlarr_data
+    .search_query
+    .as_ref()
+    .map_or((String::new(), 0), |query| {
+      (query.text.clone(), query.offset.load(Ordering::SeqCst))
+    });
+  let search_box = InputBox::new(&block_content)
+    .offset(offset)
+    .block(title_block_centered("Search Indexers"));
+
+  if app.get_current_route() == ActiveProwlarrBlock::SearchReleasesInput.into() {
+    search_box.show_cursor(f, area);
+  }
+
+  f.render_widget(search_box, area);
+}
+
+fn draw_search_results(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+  let current_selection = if app.data.prowlarr_data.search_results.items.is_empty() {
+    ProwlarrRelease::default()
+  } else {
+    app
+      .data
+      .prowlarr_data
+      .search_results
+      .current_selection()
+      .clone()
+  };
+  let active_prowlarr_block =
+    if let Route::Prowlarr(active_prowlarr_block, _) = app.get_current_route() {
+      active_prowlarr_block
+    } else {
+      ActiveProwlarrBlock::SearchReleases
+    };
+  let is_searching = app.data.prowlarr_data.search_query.is_some();
+
+  let release_row_mapping = |release: &ProwlarrRelease| {
+    let ProwlarrRelease {
+      protocol,
+      age,
+      title,
+      indexer,
+      size,
+      seeders,
+      leechers,
+      categories,
+      ..
+    } = release;
+
+    let age = format!("{age} days");
+    title.scroll_left_or_reset(
+      get_width_from_percentage(area, 40),
+      current_selection == *release
+        && active_prowlarr_block != ActiveProwlarrBlock::GrabReleaseConfirmPrompt,
+      app.ui_scroll_tick_count == 0,
+    );
+    let size = convert_to_gb(*size);
+    let peers = if seeders.is_none() || leechers.is_none() {
+      Text::from("")
+    } else {
+      let seeders = seeders
+        .clone()
+        .unwrap_or(Number::from(0u64))
+        .as_u64()
+        .unwrap_or_default();
+      let leechers = leechers
+        .clone()
+        .unwrap_or(Number::from(0u64))
+        .as_u64()
+        .unwrap_or_default();
+
+      decorate_peer_style(
+        seeders,
+        leechers,
+        Text::from(format!("{seeders} / {leechers}")),
+      )
+    };
+    let categories = categories
+      .iter()
+      .filter_map(|category| category.name.clone())
+      .collect::<Vec<String>>()
+      .join(", ");
+
+    Row::new(vec![
+      Cell::from(protocol.clone()),
+      Cell::from(age),
+      Cell::from(title.to_string()),
+      Cell::from(indexer.clone()),
+      Cell::from(format!("{size:.1} GB")),
+      Cell::from(peers),
+      Cell::from(categories),
+    ])
+    .primary()
+  };
+  let release_table = ManagarrTable::new(
+    Some(&mut app.data.prowlarr_data.search_results),
+    release_row_mapping,
+  )
+  .block(layout_block_top_border())
+  .loading(app.is_loading && is_searching)
+  .sorting(active_prowlarr_block == ActiveProwlarrBlock::SearchReleasesSortPrompt)
+  .headers([
+    "Source",
+    "Age",
+    "Title",
+    "Indexer",
+    "Size",
+    "Peers",
+    "Categories",
+  ])
+  .constraints([
+    Constraint::Length(9),
+    Constraint::Length(10),
+    Constraint::Percentage(40),
+    Constraint::Percentage(15),
+    Constraint::Length(12),
+    Constraint::Length(12),
+    Constraint::Percentage(20),
+  ]);
+
+  f.render_widget(release_table, area);
+}
+
+fn draw_grab_release_confirm_prompt(f: &mut Frame<'_>, app: &mut App<'_>) {
+  let title = &app
+    .data
+    .prowlarr_data
+    .search_results
+    .current_selection()
+    .title
+    .text;
+  let prompt = format!("Do you want to download the following release: \n{title}?");
+  let confirmation_prompt = ConfirmationPrompt::new()
+    .title("Download Release")
+    .prompt(&prompt)
+    .yes_no_value(app.data.prowlarr_data.prompt_confirm);
+
+  f.render_widget(
+    Popup::new(confirmation_prompt).size(Size::MediumPrompt),
+    f.area(),
+  );
+}
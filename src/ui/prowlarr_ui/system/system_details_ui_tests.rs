@@ -0,0 +1,106 @@
+#[cfg(test)]
+mod tests {
+  use strum::IntoEnumIterator;
+
+  use crate::app::App;
+  use crate::models::servarr_data::prowlarr::prowlarr_data::{
+    ActiveProwlarrBlock, SYSTEM_DETAILS_BLOCKS,
+  };
+
+  use crate::ui::DrawUi;
+  use crate::ui::prowlarr_ui::system::system_details_ui::SystemDetailsUi;
+  use crate::ui::ui_test_utils::test_utils::render_to_string_with_app;
+
+  #[test]
+  fn test_system_details_ui_accepts() {
+    ActiveProwlarrBlock::iter().for_each(|active_prowlarr_block| {
+      if SYSTEM_DETAILS_BLOCKS.contains(&active_prowlarr_block) {
+        assert!(SystemDetailsUi::accepts(active_prowlarr_block.into()));
+      } else {
+        assert!(!SystemDetailsUi::accepts(active_prowlarr_block.into()));
+      }
+    });
+  }
+
+  mod snapshot_tests {
+    use super::*;
+    use crate::models::ScrollableText;
+    use crate::ui::ui_test_utils::test_utils::TerminalSize;
+    use rstest::rstest;
+
+    #[rstest]
+    fn test_system_details_ui_popups(
+      #[values(
+        ActiveProwlarrBlock::SystemLogs,
+        ActiveProwlarrBlock::SystemQueuedEvents,
+        ActiveProwlarrBlock::SystemTasks,
+        ActiveProwlarrBlock::SystemTaskStartConfirmPrompt,
+        ActiveProwlarrBlock::SystemUpdates
+      )]
+      active_prowlarr_block: ActiveProwlarrBlock,
+    ) {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(active_prowlarr_block.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        SystemDetailsUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(format!("popup_{active_prowlarr_block}"), output);
+    }
+
+    #[rstest]
+    fn test_system_details_ui_loading(
+      #[values(
+        ActiveProwlarrBlock::SystemLogs,
+        ActiveProwlarrBlock::SystemQueuedEvents,
+        ActiveProwlarrBlock::SystemTasks,
+        ActiveProwlarrBlock::SystemUpdates
+      )]
+      active_prowlarr_block: ActiveProwlarrBlock,
+    ) {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.push_navigation_stack(active_prowlarr_block.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        SystemDetailsUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(format!("loading_{active_prowlarr_block}"), output);
+    }
+
+    #[test]
+    fn test_system_details_ui_updates_popup_loading_when_empty() {
+      let mut app = App::test_default_fully_populated();
+      app.is_loading = true;
+      app.push_navigation_stack(ActiveProwlarrBlock::SystemUpdates.into());
+      app.data.prowlarr_data.updates = ScrollableText::with_string("".to_string());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        SystemDetailsUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(output);
+    }
+
+    #[rstest]
+    fn test_system_details_ui_popups_empty(
+      #[values(
+        ActiveProwlarrBlock::SystemLogs,
+        ActiveProwlarrBlock::SystemQueuedEvents,
+        ActiveProwlarrBlock::SystemTasks
+      )]
+      active_prowlarr_block: ActiveProwlarrBlock,
+    ) {
+      let mut app = App::test_default();
+      app.push_navigation_stack(active_prowlarr_block.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        SystemDetailsUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(format!("empty_{active_prowlarr_block}"), output);
+    }
+  }
+}
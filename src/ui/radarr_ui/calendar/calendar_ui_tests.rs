@@ -0,0 +1,74 @@
+#[cfg(test)]
+mod tests {
+  use strum::IntoEnumIterator;
+
+  use crate::app::App;
+  use crate::models::servarr_data::radarr::radarr_data::{ActiveRadarrBlock, CALENDAR_BLOCKS};
+  use crate::ui::DrawUi;
+  use crate::ui::radarr_ui::calendar::CalendarUi;
+  use crate::ui::ui_test_utils::test_utils::render_to_string_with_app;
+
+  #[test]
+  fn test_calendar_ui_accepts() {
+    ActiveRadarrBlock::iter().for_each(|active_radarr_block| {
+      if CALENDAR_BLOCKS.contains(&active_radarr_block) {
+        assert!(CalendarUi::accepts(active_radarr_block.into()));
+      } else {
+        assert!(!CalendarUi::accepts(active_radarr_block.into()));
+      }
+    });
+  }
+
+  mod snapshot_tests {
+    use crate::ui::ui_test_utils::test_utils::TerminalSize;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_calendar_ui_renders_loading(
+      #[values(ActiveRadarrBlock::Calendar, ActiveRadarrBlock::CalendarAgenda)]
+      active_radarr_block: ActiveRadarrBlock,
+    ) {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.push_navigation_stack(active_radarr_block.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        CalendarUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(format!("loading_{active_radarr_block}"), output);
+    }
+
+    #[rstest]
+    fn test_calendar_ui_renders_empty_calendar(
+      #[values(ActiveRadarrBlock::Calendar, ActiveRadarrBlock::CalendarAgenda)]
+      active_radarr_block: ActiveRadarrBlock,
+    ) {
+      let mut app = App::test_default();
+      app.push_navigation_stack(active_radarr_block.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        CalendarUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(format!("empty_{active_radarr_block}"), output);
+    }
+
+    #[rstest]
+    fn test_calendar_ui_renders_calendar_tab(
+      #[values(ActiveRadarrBlock::Calendar, ActiveRadarrBlock::CalendarAgenda)]
+      active_radarr_block: ActiveRadarrBlock,
+    ) {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(active_radarr_block.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        CalendarUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(active_radarr_block.to_string(), output);
+    }
+  }
+}
@@ -0,0 +1,150 @@
+#[cfg(test)]
+use crate::ui::ui_test_utils::test_utils::Utc;
+use chrono::DateTime;
+#[cfg(not(test))]
+use chrono::Utc;
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Rect};
+use ratatui::style::Styled;
+use ratatui::text::Line;
+use ratatui::widgets::{Cell, Row};
+
+use crate::app::App;
+use crate::models::Route;
+use crate::models::radarr_models::Movie;
+use crate::models::servarr_data::radarr::radarr_data::{ActiveRadarrBlock, CALENDAR_BLOCKS};
+use crate::ui::DrawUi;
+use crate::ui::styles::ManagarrStyle;
+use crate::ui::utils::layout_block_top_border;
+use crate::ui::widgets::calendar_week::CalendarWeek;
+use crate::ui::widgets::managarr_table::ManagarrTable;
+use crate::utils::calendar_range;
+
+#[cfg(test)]
+#[path = "calendar_ui_tests.rs"]
+mod calendar_ui_tests;
+
+pub(super) struct CalendarUi;
+
+impl DrawUi for CalendarUi {
+  fn accepts(route: Route) -> bool {
+    let Route::Radarr(active_radarr_block, _) = route else {
+      return false;
+    };
+    CALENDAR_BLOCKS.contains(&active_radarr_block)
+  }
+
+  fn draw(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+    if let Route::Radarr(active_radarr_block, _) = app.get_current_route() {
+      match active_radarr_block {
+        ActiveRadarrBlock::CalendarAgenda => draw_calendar_agenda(f, app, area),
+        _ => draw_calendar_week(f, app, area),
+      }
+    }
+  }
+}
+
+fn draw_calendar_week(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+  let entries = app
+    .data
+    .radarr_data
+    .calendar
+    .items
+    .iter()
+    .flat_map(|movie| {
+      [
+        ("In Cinemas", movie.in_cinemas),
+        ("Digital", movie.digital_release),
+        ("Physical", movie.physical_release),
+      ]
+      .into_iter()
+      .filter_map(move |(release_type, release_date)| {
+        let release_date = release_date?;
+        let line = Line::from(format!("{} ({release_type})", movie.title.text));
+
+        Some((
+          release_date.date_naive(),
+          decorate_calendar_entry(movie, Some(release_date), line),
+        ))
+      })
+    })
+    .collect();
+  let start = app
+    .data
+    .radarr_data
+    .calendar_start
+    .unwrap_or_else(|| calendar_range(Utc::now().date_naive()).0);
+  let calendar_week = CalendarWeek::new(start, entries).loading(app.is_loading);
+
+  f.render_widget(calendar_week, area);
+}
+
+fn draw_calendar_agenda(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+  let calendar_row_mapping = |movie: &Movie| {
+    let Movie {
+      title,
+      year,
+      in_cinemas,
+      digital_release,
+      physical_release,
+      ..
+    } = movie;
+    let format_date = |date: &Option<DateTime<chrono::Utc>>| {
+      date.map_or_else(String::new, |date| date.format("%Y-%m-%d").to_string())
+    };
+    let earliest_release = [*in_cinemas, *digital_release, *physical_release]
+      .into_iter()
+      .flatten()
+      .min();
+
+    let row = Row::new(vec![
+      Cell::from(title.text.clone()),
+      Cell::from(year.to_string()),
+      Cell::from(format_date(in_cinemas)),
+      Cell::from(format_date(digital_release)),
+      Cell::from(format_date(physical_release)),
+    ]);
+
+    decorate_calendar_entry(movie, earliest_release, row)
+  };
+  let calendar_table = ManagarrTable::new(
+    Some(&mut app.data.radarr_data.calendar),
+    calendar_row_mapping,
+  )
+  .block(layout_block_top_border())
+  .loading(app.is_loading)
+  .headers([
+    "Title",
+    "Year",
+    "In Cinemas",
+    "Digital Release",
+    "Physical Release",
+  ])
+  .constraints([
+    Constraint::Percentage(40),
+    Constraint::Percentage(6),
+    Constraint::Percentage(18),
+    Constraint::Percentage(18),
+    Constraint::Percentage(18),
+  ]);
+
+  f.render_widget(calendar_table, area);
+}
+
+fn decorate_calendar_entry<T: Styled<Item = T>>(
+  movie: &Movie,
+  release_date: Option<DateTime<chrono::Utc>>,
+  entry: T,
+) -> T {
+  if movie.has_file {
+    return entry.downloaded();
+  }
+
+  if let Some(release_date) = release_date
+    && release_date > Utc::now()
+  {
+    return entry.unreleased();
+  }
+
+  entry.missing()
+}
@@ -0,0 +1,82 @@
+#[cfg(test)]
+mod tests {
+  use strum::IntoEnumIterator;
+
+  use crate::app::App;
+  use crate::models::servarr_data::radarr::radarr_data::{
+    ActiveRadarrBlock, CUSTOM_FORMAT_FORM_BLOCKS, CUSTOM_FORMATS_BLOCKS,
+  };
+  use crate::models::stateful_table::StatefulTable;
+  use crate::ui::DrawUi;
+  use crate::ui::radarr_ui::custom_formats::CustomFormatsUi;
+  use crate::ui::ui_test_utils::test_utils::{TerminalSize, render_to_string_with_app};
+
+  #[test]
+  fn test_custom_formats_ui_accepts() {
+    let mut custom_formats_blocks = Vec::new();
+    custom_formats_blocks.extend(CUSTOM_FORMATS_BLOCKS);
+    custom_formats_blocks.extend(CUSTOM_FORMAT_FORM_BLOCKS);
+
+    ActiveRadarrBlock::iter().for_each(|active_radarr_block| {
+      if custom_formats_blocks.contains(&active_radarr_block) {
+        assert!(CustomFormatsUi::accepts(active_radarr_block.into()));
+      } else {
+        assert!(!CustomFormatsUi::accepts(active_radarr_block.into()));
+      }
+    });
+  }
+
+  mod snapshot_tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[test]
+    fn test_custom_formats_ui_renders_custom_formats_tab_loading() {
+      let mut app = App::test_default_fully_populated();
+      app.is_loading = true;
+      app.push_navigation_stack(ActiveRadarrBlock::CustomFormats.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        CustomFormatsUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(output);
+    }
+
+    #[test]
+    fn test_custom_formats_ui_renders_custom_formats_tab_empty_custom_formats() {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(ActiveRadarrBlock::CustomFormats.into());
+      app.data.radarr_data.custom_formats = StatefulTable::default();
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        CustomFormatsUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(output);
+    }
+
+    #[rstest]
+    fn test_custom_formats_ui_renders(
+      #[values(
+        ActiveRadarrBlock::CustomFormats,
+        ActiveRadarrBlock::CustomFormatDetails,
+        ActiveRadarrBlock::DeleteCustomFormatPrompt,
+        ActiveRadarrBlock::ImportCustomFormatPrompt,
+        ActiveRadarrBlock::ExportCustomFormatPrompt,
+        ActiveRadarrBlock::EditCustomFormatPrompt,
+        ActiveRadarrBlock::CustomFormatFieldInput
+      )]
+      active_radarr_block: ActiveRadarrBlock,
+    ) {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(active_radarr_block.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        CustomFormatsUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(format!("custom_formats_ui_{active_radarr_block}"), output);
+    }
+  }
+}
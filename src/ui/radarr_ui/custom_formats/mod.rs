@@ -0,0 +1,201 @@
+use std::sync::atomic::Ordering;
+
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::widgets::{Cell, ListItem, Row};
+
+use crate::app::App;
+use crate::models::Route;
+use crate::models::servarr_data::radarr::radarr_data::{
+  ActiveRadarrBlock, CUSTOM_FORMAT_FORM_BLOCKS, CUSTOM_FORMATS_BLOCKS,
+};
+use crate::models::servarr_models::{CustomFormat, CustomFormatSpecification};
+use crate::models::stateful_table::StatefulTable;
+use crate::render_selectable_input_box;
+use crate::ui::styles::ManagarrStyle;
+use crate::ui::utils::{
+  layout_block_top_border, layout_paragraph_borderless, title_block, title_block_centered,
+};
+use crate::ui::widgets::confirmation_prompt::ConfirmationPrompt;
+use crate::ui::widgets::input_box::InputBox;
+use crate::ui::widgets::loading_block::LoadingBlock;
+use crate::ui::widgets::managarr_table::ManagarrTable;
+use crate::ui::widgets::popup::{Popup, Size};
+use crate::ui::widgets::selectable_list::SelectableList;
+use crate::ui::{DrawUi, draw_input_box_popup, draw_popup};
+
+#[cfg(test)]
+#[path = "custom_formats_ui_tests.rs"]
+mod custom_formats_ui_tests;
+
+pub(super) struct CustomFormatsUi;
+
+impl DrawUi for CustomFormatsUi {
+  fn accepts(route: Route) -> bool {
+    if let Route::Radarr(active_radarr_block, _) = route {
+      return CUSTOM_FORMATS_BLOCKS.contains(&active_radarr_block)
+        || CUSTOM_FORMAT_FORM_BLOCKS.contains(&active_radarr_block);
+    }
+
+    false
+  }
+
+  fn draw(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+    draw_custom_formats(f, app, area);
+
+    if let Route::Radarr(active_radarr_block, _) = app.get_current_route() {
+      match active_radarr_block {
+        ActiveRadarrBlock::CustomFormatDetails => draw_custom_format_details_popup(f, app),
+        ActiveRadarrBlock::DeleteCustomFormatPrompt => {
+          let prompt = format!(
+            "Do you really want to delete this custom format: \n{}?",
+            app.data.radarr_data.custom_formats.current_selection().name
+          );
+          let confirmation_prompt = ConfirmationPrompt::new()
+            .title("Delete Custom Format")
+            .prompt(&prompt)
+            .yes_no_value(app.data.radarr_data.prompt_confirm);
+
+          f.render_widget(
+            Popup::new(confirmation_prompt).size(Size::MediumPrompt),
+            f.area(),
+          );
+        }
+        ActiveRadarrBlock::ImportCustomFormatPrompt => draw_popup(
+          f,
+          app,
+          |f, app, area| draw_custom_format_path_prompt(f, app, area, "Import Custom Format From"),
+          Size::InputBox,
+        ),
+        ActiveRadarrBlock::ExportCustomFormatPrompt => draw_popup(
+          f,
+          app,
+          |f, app, area| draw_custom_format_path_prompt(f, app, area, "Export Custom Format To"),
+          Size::InputBox,
+        ),
+        _ if CUSTOM_FORMAT_FORM_BLOCKS.contains(&active_radarr_block) => draw_popup(
+          f,
+          app,
+          draw_edit_custom_format_prompt,
+          Size::WideLargePrompt,
+        ),
+        _ => (),
+      }
+    }
+  }
+}
+
+fn draw_custom_formats(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+  let custom_formats_row_mapping = |custom_format: &'_ CustomFormat| {
+    let include_when_renaming = if custom_format.include_custom_format_when_renaming {
+      "Yes"
+    } else {
+      "No"
+    };
+
+    Row::new(vec![
+      Cell::from(custom_format.name.clone()),
+      Cell::from(include_when_renaming),
+      Cell::from(custom_format.specifications.len().to_string()),
+    ])
+    .primary()
+  };
+  let custom_formats_table = ManagarrTable::new(
+    Some(&mut app.data.radarr_data.custom_formats),
+    custom_formats_row_mapping,
+  )
+  .block(layout_block_top_border())
+  .loading(app.is_loading)
+  .headers(["Custom Format", "Include When Renaming", "Specifications"])
+  .constraints([
+    Constraint::Percentage(50),
+    Constraint::Percentage(25),
+    Constraint::Percentage(25),
+  ]);
+
+  f.render_widget(custom_formats_table, area);
+}
+
+fn draw_custom_format_details_popup(f: &mut Frame<'_>, app: &mut App<'_>) {
+  let custom_format = app.data.radarr_data.custom_formats.current_selection();
+  let title = format!("Specifications - {}", custom_format.name);
+  let mut specifications = StatefulTable::default();
+  specifications.set_items(custom_format.specifications.clone());
+
+  let specifications_row_mapping = |specification: &'_ CustomFormatSpecification| {
+    let yes_no = |value: bool| if value { "Yes" } else { "No" };
+
+    Row::new(vec![
+      Cell::from(specification.name.clone()),
+      Cell::from(
+        specification
+          .implementation_name
+          .clone()
+          .unwrap_or_else(|| specification.implementation.clone()),
+      ),
+      Cell::from(yes_no(specification.negate)),
+      Cell::from(yes_no(specification.required)),
+      Cell::from(specification.field_values()),
+    ])
+    .primary()
+  };
+  let specifications_table =
+    ManagarrTable::new(Some(&mut specifications), specifications_row_mapping)
+      .block(title_block(&title))
+      .headers(["Name", "Implementation", "Negate", "Required", "Values"])
+      .constraints([
+        Constraint::Percentage(20),
+        Constraint::Percentage(20),
+        Constraint::Percentage(10),
+        Constraint::Percentage(10),
+        Constraint::Percentage(40),
+      ]);
+
+  f.render_widget(Popup::new(specifications_table).size(Size::Large), f.area());
+}
+
+fn draw_custom_format_path_prompt(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect, title: &str) {
+  draw_input_box_popup(
+    f,
+    area,
+    title,
+    app.data.radarr_data.custom_format_path.as_ref().unwrap(),
+  );
+}
+
+fn draw_edit_custom_format_prompt(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+  let is_editing = app.get_current_route() == ActiveRadarrBlock::CustomFormatFieldInput.into();
+  let Some(custom_format_modal) = app.data.radarr_data.custom_format_modal.as_mut() else {
+    f.render_widget(
+      LoadingBlock::new(app.is_loading, title_block_centered("Edit Custom Format")),
+      area,
+    );
+    return;
+  };
+  f.render_widget(title_block_centered("Edit Custom Format"), area);
+
+  let [fields_area, help_area, input_area] = Layout::vertical([
+    Constraint::Fill(1),
+    Constraint::Length(2),
+    Constraint::Length(3),
+  ])
+  .margin(1)
+  .areas(area);
+
+  let selected_field = custom_format_modal.fields.current_selection().clone();
+  let fields_list = SelectableList::new(&mut custom_format_modal.fields, |field| {
+    ListItem::new(format!("{}: {}", field.label, field.display_value()))
+  });
+  f.render_widget(fields_list, fields_area);
+
+  let help_text = selected_field.help_text.clone().unwrap_or_default();
+  f.render_widget(layout_paragraph_borderless(&help_text), help_area);
+
+  let value = selected_field.display_value();
+  let input_box = InputBox::new(&value)
+    .offset(selected_field.value.offset.load(Ordering::SeqCst))
+    .label(&selected_field.label)
+    .highlighted(true)
+    .selected(is_editing);
+  render_selectable_input_box!(input_box, f, input_area);
+}
@@ -0,0 +1,96 @@
+#[cfg(test)]
+mod tests {
+  use strum::IntoEnumIterator;
+
+  use crate::app::App;
+  use crate::models::servarr_data::radarr::radarr_data::{
+    ActiveRadarrBlock, DOWNLOAD_CLIENT_FORM_BLOCKS,
+  };
+  use crate::ui::DrawUi;
+  use crate::ui::radarr_ui::download_clients::download_client_form_ui::DownloadClientFormUi;
+  use crate::ui::ui_test_utils::test_utils::{TerminalSize, render_to_string_with_app};
+
+  #[test]
+  fn test_download_client_form_ui_accepts() {
+    ActiveRadarrBlock::iter().for_each(|active_radarr_block| {
+      if DOWNLOAD_CLIENT_FORM_BLOCKS.contains(&active_radarr_block) {
+        assert!(DownloadClientFormUi::accepts(active_radarr_block.into()));
+      } else {
+        assert!(!DownloadClientFormUi::accepts(active_radarr_block.into()));
+      }
+    });
+  }
+
+  mod snapshot_tests {
+    use super::*;
+
+    #[test]
+    fn test_download_client_form_ui_renders_select_implementation_loading() {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.push_navigation_stack(ActiveRadarrBlock::AddDownloadClientSelectImplementation.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        DownloadClientFormUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(output);
+    }
+
+    #[test]
+    fn test_download_client_form_ui_renders_select_implementation() {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(ActiveRadarrBlock::AddDownloadClientSelectImplementation.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        DownloadClientFormUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(output);
+    }
+
+    #[test]
+    fn test_download_client_form_ui_renders_add_download_client_prompt() {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(ActiveRadarrBlock::DownloadClientPrompt.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        DownloadClientFormUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(output);
+    }
+
+    #[test]
+    fn test_download_client_form_ui_renders_edit_download_client_prompt() {
+      let mut app = App::test_default_fully_populated();
+      app
+        .data
+        .radarr_data
+        .download_client_modal
+        .as_mut()
+        .unwrap()
+        .id = Some(1);
+      app.push_navigation_stack(ActiveRadarrBlock::DownloadClientPrompt.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        DownloadClientFormUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(output);
+    }
+
+    #[test]
+    fn test_download_client_form_ui_renders_download_client_field_input() {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(ActiveRadarrBlock::DownloadClientPrompt.into());
+      app.push_navigation_stack(ActiveRadarrBlock::DownloadClientFieldInput.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        DownloadClientFormUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(output);
+    }
+  }
+}
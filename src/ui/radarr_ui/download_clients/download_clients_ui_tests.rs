@@ -0,0 +1,124 @@
+#[cfg(test)]
+mod tests {
+  use strum::IntoEnumIterator;
+
+  use crate::app::App;
+  use crate::models::servarr_data::radarr::radarr_data::{
+    ActiveRadarrBlock, DOWNLOAD_CLIENT_FORM_BLOCKS, DOWNLOAD_CLIENTS_BLOCKS,
+  };
+  use crate::models::stateful_table::StatefulTable;
+  use crate::ui::DrawUi;
+  use crate::ui::radarr_ui::download_clients::DownloadClientsUi;
+  use crate::ui::ui_test_utils::test_utils::{TerminalSize, render_to_string_with_app};
+
+  #[test]
+  fn test_download_clients_ui_accepts() {
+    let mut download_clients_blocks = Vec::new();
+    download_clients_blocks.extend(DOWNLOAD_CLIENTS_BLOCKS);
+    download_clients_blocks.extend(DOWNLOAD_CLIENT_FORM_BLOCKS);
+    download_clients_blocks.push(ActiveRadarrBlock::TestAllDownloadClients);
+
+    ActiveRadarrBlock::iter().for_each(|active_radarr_block| {
+      if download_clients_blocks.contains(&active_radarr_block) {
+        assert!(DownloadClientsUi::accepts(active_radarr_block.into()));
+      } else {
+        assert!(!DownloadClientsUi::accepts(active_radarr_block.into()));
+      }
+    });
+  }
+
+  mod snapshot_tests {
+    use super::*;
+
+    #[test]
+    fn test_download_clients_ui_renders_download_clients_tab_loading() {
+      let mut app = App::test_default_fully_populated();
+      app.is_loading = true;
+      app.push_navigation_stack(ActiveRadarrBlock::DownloadClients.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        DownloadClientsUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(output);
+    }
+
+    #[test]
+    fn test_download_clients_ui_renders_download_clients_tab_empty_download_clients() {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(ActiveRadarrBlock::DownloadClients.into());
+      app.data.radarr_data.download_clients = StatefulTable::default();
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        DownloadClientsUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(output);
+    }
+
+    #[test]
+    fn test_download_clients_ui_renders_download_clients_tab() {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(ActiveRadarrBlock::DownloadClients.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        DownloadClientsUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(output);
+    }
+
+    #[test]
+    fn test_download_clients_ui_renders_test_download_client_loading() {
+      let mut app = App::test_default_fully_populated();
+      app.data.radarr_data.download_client_test_errors = None;
+      app.push_navigation_stack(ActiveRadarrBlock::TestDownloadClient.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        DownloadClientsUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(output);
+    }
+
+    #[test]
+    fn test_download_clients_ui_renders_test_download_client_success() {
+      let mut app = App::test_default_fully_populated();
+      app.data.radarr_data.download_client_test_errors = Some(String::new());
+      app.push_navigation_stack(ActiveRadarrBlock::TestDownloadClient.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        DownloadClientsUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(output);
+    }
+
+    #[test]
+    fn test_download_clients_ui_renders_test_download_client_error() {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(ActiveRadarrBlock::TestDownloadClient.into());
+
+      app.data.radarr_data.download_client_test_errors =
+        Some("Unable to connect to qBittorrent".to_owned());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        DownloadClientsUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(output);
+    }
+
+    #[test]
+    fn test_download_clients_ui_renders_delete_download_client_prompt() {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(ActiveRadarrBlock::DeleteDownloadClientPrompt.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        DownloadClientsUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(output);
+    }
+  }
+}
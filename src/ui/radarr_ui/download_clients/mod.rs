@@ -0,0 +1,164 @@
+use crate::ui::styles::success_style;
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Rect};
+use ratatui::text::Text;
+use ratatui::widgets::{Cell, Row};
+
+use crate::app::App;
+use crate::models::Route;
+use crate::models::servarr_data::radarr::radarr_data::{
+  ActiveRadarrBlock, DOWNLOAD_CLIENTS_BLOCKS,
+};
+use crate::models::servarr_models::DownloadClient;
+use crate::ui::DrawUi;
+use crate::ui::radarr_ui::download_clients::download_client_form_ui::DownloadClientFormUi;
+use crate::ui::radarr_ui::download_clients::test_all_download_clients_ui::TestAllDownloadClientsUi;
+use crate::ui::styles::ManagarrStyle;
+use crate::ui::utils::{layout_block_top_border, title_block};
+use crate::ui::widgets::confirmation_prompt::ConfirmationPrompt;
+use crate::ui::widgets::loading_block::LoadingBlock;
+use crate::ui::widgets::managarr_table::ManagarrTable;
+use crate::ui::widgets::message::Message;
+use crate::ui::widgets::popup::{Popup, Size};
+
+mod download_client_form_ui;
+mod test_all_download_clients_ui;
+
+#[cfg(test)]
+#[path = "download_clients_ui_tests.rs"]
+mod download_clients_ui_tests;
+
+pub(super) struct DownloadClientsUi;
+
+impl DrawUi for DownloadClientsUi {
+  fn accepts(route: Route) -> bool {
+    if let Route::Radarr(active_radarr_block, _) = route {
+      return DownloadClientFormUi::accepts(route)
+        || TestAllDownloadClientsUi::accepts(route)
+        || DOWNLOAD_CLIENTS_BLOCKS.contains(&active_radarr_block);
+    }
+
+    false
+  }
+
+  fn draw(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+    let route = app.get_current_route();
+    draw_download_clients(f, app, area);
+
+    match route {
+      _ if DownloadClientFormUi::accepts(route) => DownloadClientFormUi::draw(f, app, area),
+      _ if TestAllDownloadClientsUi::accepts(route) => TestAllDownloadClientsUi::draw(f, app, area),
+      Route::Radarr(active_radarr_block, _) => match active_radarr_block {
+        ActiveRadarrBlock::TestDownloadClient => {
+          if let Some(result) = app
+            .data
+            .radarr_data
+            .download_client_test_errors
+            .as_ref()
+            .filter(|_| !app.is_loading)
+          {
+            let popup = if !result.is_empty() {
+              Popup::new(Message::new(result.clone())).size(Size::LargeMessage)
+            } else {
+              let message = Message::new("Download client test succeeded!")
+                .title("Success")
+                .style(success_style().bold());
+              Popup::new(message).size(Size::Message)
+            };
+
+            f.render_widget(popup, f.area());
+          } else {
+            let loading_popup = Popup::new(LoadingBlock::new(
+              app.is_loading || app.data.radarr_data.download_client_test_errors.is_none(),
+              title_block("Testing Download Client"),
+            ))
+            .size(Size::LargeMessage);
+            f.render_widget(loading_popup, f.area());
+          }
+        }
+        ActiveRadarrBlock::DeleteDownloadClientPrompt => {
+          let prompt = format!(
+            "Do you really want to delete this download client: \n{}?",
+            app
+              .data
+              .radarr_data
+              .download_clients
+              .current_selection()
+              .name
+              .clone()
+              .unwrap_or_default()
+          );
+          let confirmation_prompt = ConfirmationPrompt::new()
+            .title("Delete Download Client")
+            .prompt(&prompt)
+            .yes_no_value(app.data.radarr_data.prompt_confirm);
+
+          f.render_widget(
+            Popup::new(confirmation_prompt).size(Size::MediumPrompt),
+            f.area(),
+          );
+        }
+        _ => (),
+      },
+      _ => (),
+    }
+  }
+}
+
+fn draw_download_clients(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+  let tags_map = &app.data.radarr_data.tags_map;
+  let download_clients_row_mapping = |download_client: &'_ DownloadClient| {
+    let DownloadClient {
+      name,
+      enable,
+      priority,
+      protocol,
+      tags,
+      ..
+    } = download_client;
+    let enabled = if *enable {
+      Text::from("Enabled").success()
+    } else {
+      Text::from("Disabled").failure()
+    };
+    let tags: String = tags
+      .iter()
+      .filter_map(|tag_id| tags_map.get_by_left(&tag_id.as_i64()?).cloned())
+      .collect::<Vec<String>>()
+      .join(", ");
+
+    Row::new(vec![
+      Cell::from(name.clone().unwrap_or_default()),
+      Cell::from(enabled),
+      Cell::from(priority.to_string()),
+      Cell::from(protocol.clone()),
+      Cell::from(download_client.category().unwrap_or_default()),
+      Cell::from(tags),
+    ])
+    .primary()
+  };
+  let download_clients_table = ManagarrTable::new(
+    Some(&mut app.data.radarr_data.download_clients),
+    download_clients_row_mapping,
+  )
+  .block(layout_block_top_border())
+  .loading(app.is_loading)
+  .headers([
+    "Download Client",
+    "Status",
+    "Priority",
+    "Protocol",
+    "Category",
+    "Tags",
+  ])
+  .constraints([
+    Constraint::Percentage(25),
+    Constraint::Percentage(12),
+    Constraint::Percentage(12),
+    Constraint::Percentage(12),
+    Constraint::Percentage(16),
+    Constraint::Percentage(23),
+  ]);
+
+  f.render_widget(download_clients_table, area);
+}
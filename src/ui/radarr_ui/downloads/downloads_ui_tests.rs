@@ -3,7 +3,10 @@ mod tests {
   use strum::IntoEnumIterator;
 
   use crate::app::App;
-  use crate::models::servarr_data::radarr::radarr_data::{ActiveRadarrBlock, DOWNLOADS_BLOCKS};
+  use crate::models::BlockSelectionState;
+  use crate::models::servarr_data::radarr::radarr_data::{
+    ActiveRadarrBlock, DELETE_DOWNLOAD_SELECTION_BLOCKS, DOWNLOADS_BLOCKS,
+  };
   use crate::ui::DrawUi;
   use crate::ui::radarr_ui::downloads::DownloadsUi;
   use crate::ui::ui_test_utils::test_utils::{TerminalSize, render_to_string_with_app};
@@ -21,6 +24,10 @@ mod tests {
 
   mod snapshot_tests {
     use super::*;
+    use crate::models::radarr_models::DownloadRecord;
+    use crate::network::radarr_network::radarr_network_test_utils::test_utils::download_record;
+    use crate::ui::ui_test_utils::test_utils::Utc;
+    use chrono::Duration;
     use rstest::rstest;
 
     #[test]
@@ -41,12 +48,18 @@ mod tests {
       #[values(
         ActiveRadarrBlock::Downloads,
         ActiveRadarrBlock::DeleteDownloadPrompt,
-        ActiveRadarrBlock::UpdateDownloadsPrompt
+        ActiveRadarrBlock::GrabDownloadPrompt,
+        ActiveRadarrBlock::UpdateDownloadsPrompt,
+        ActiveRadarrBlock::ManualImport,
+        ActiveRadarrBlock::ManualImportSelectMovie,
+        ActiveRadarrBlock::ManualImportConfirmPrompt
       )]
       active_radarr_block: ActiveRadarrBlock,
     ) {
       let mut app = App::test_default_fully_populated();
       app.push_navigation_stack(active_radarr_block.into());
+      app.data.radarr_data.selected_block =
+        BlockSelectionState::new(DELETE_DOWNLOAD_SELECTION_BLOCKS);
 
       let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
         DownloadsUi::draw(f, app, f.area());
@@ -55,6 +68,68 @@ mod tests {
       insta::assert_snapshot!(active_radarr_block.to_string(), output);
     }
 
+    #[test]
+    fn test_downloads_ui_renders_delete_download_prompt_with_multi_selection() {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(ActiveRadarrBlock::DeleteDownloadPrompt.into());
+      app.data.radarr_data.selected_block =
+        BlockSelectionState::new(DELETE_DOWNLOAD_SELECTION_BLOCKS);
+      app
+        .data
+        .radarr_data
+        .downloads
+        .toggle_select_all(|download| download.id);
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        DownloadsUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(output);
+    }
+
+    #[test]
+    fn test_radarr_ui_renders_downloads_tab_with_eta() {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(ActiveRadarrBlock::Downloads.into());
+      app
+        .data
+        .radarr_data
+        .downloads
+        .set_items(vec![DownloadRecord {
+          estimated_completion_time: Some(Utc::now() + Duration::minutes(90)),
+          ..download_record()
+        }]);
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        DownloadsUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(output);
+    }
+
+    #[rstest]
+    fn test_radarr_ui_renders_downloads_tab_with_pending_download(
+      #[values("delay".to_owned(), "fallback".to_owned())] status: String,
+    ) {
+      let status_name = status.to_string();
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(ActiveRadarrBlock::Downloads.into());
+      app
+        .data
+        .radarr_data
+        .downloads
+        .set_items(vec![DownloadRecord {
+          status,
+          ..download_record()
+        }]);
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        DownloadsUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(format!("pending_download_{status_name}"), output);
+    }
+
     #[test]
     fn test_radarr_ui_renders_downloads_tab_empty() {
       let mut app = App::test_default();
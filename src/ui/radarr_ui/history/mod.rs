@@ -2,6 +2,7 @@ use crate::app::App;
 use crate::models::Route;
 use crate::models::radarr_models::RadarrHistoryItem;
 use crate::models::servarr_data::radarr::radarr_data::{ActiveRadarrBlock, HISTORY_BLOCKS};
+use crate::models::table_columns::{HISTORY_TABLE_COLUMNS, table_headers};
 use crate::ui::DrawUi;
 use crate::ui::styles::{ManagarrStyle, secondary_style};
 use crate::ui::utils::{get_width_from_percentage, layout_block_top_border};
@@ -88,7 +89,7 @@ fn draw_history_table(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
         .search_produced_empty_results(active_radarr_block == ActiveRadarrBlock::SearchHistoryError)
         .filtering(active_radarr_block == ActiveRadarrBlock::FilterHistory)
         .filter_produced_empty_results(active_radarr_block == ActiveRadarrBlock::FilterHistoryError)
-        .headers(["Source Title", "Event Type", "Language", "Quality", "Date"])
+        .headers(table_headers(&HISTORY_TABLE_COLUMNS))
         .constraints([
           Constraint::Percentage(40),
           Constraint::Percentage(15),
@@ -0,0 +1,107 @@
+use std::sync::atomic::Ordering;
+
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::widgets::ListItem;
+
+use crate::app::App;
+use crate::models::Route;
+use crate::models::servarr_data::radarr::radarr_data::{ADD_INDEXER_BLOCKS, ActiveRadarrBlock};
+use crate::models::servarr_models::Indexer;
+use crate::render_selectable_input_box;
+use crate::ui::utils::{layout_paragraph_borderless, title_block, title_block_centered};
+use crate::ui::widgets::input_box::InputBox;
+use crate::ui::widgets::loading_block::LoadingBlock;
+use crate::ui::widgets::popup::{Popup, Size};
+use crate::ui::widgets::selectable_list::SelectableList;
+use crate::ui::{DrawUi, draw_popup};
+
+#[cfg(test)]
+#[path = "add_indexer_ui_tests.rs"]
+mod add_indexer_ui_tests;
+
+pub(super) struct AddIndexerUi;
+
+impl DrawUi for AddIndexerUi {
+  fn accepts(route: Route) -> bool {
+    if let Route::Radarr(active_radarr_block, _) = route {
+      return ADD_INDEXER_BLOCKS.contains(&active_radarr_block);
+    }
+
+    false
+  }
+
+  fn draw(f: &mut Frame<'_>, app: &mut App<'_>, _area: Rect) {
+    if let Route::Radarr(active_radarr_block, _) = app.get_current_route() {
+      match active_radarr_block {
+        ActiveRadarrBlock::AddIndexerSelectImplementation => {
+          draw_select_indexer_implementation_popup(f, app)
+        }
+        _ => draw_popup(f, app, draw_add_indexer_prompt, Size::WideLargePrompt),
+      }
+    }
+  }
+}
+
+fn draw_select_indexer_implementation_popup(f: &mut Frame<'_>, app: &mut App<'_>) {
+  let block = title_block("Select Indexer Implementation");
+
+  if app.data.radarr_data.indexer_schema.is_empty() {
+    let loading_popup = Popup::new(LoadingBlock::new(app.is_loading, block)).size(Size::Dropdown);
+    f.render_widget(loading_popup, f.area());
+  } else {
+    let implementation_list = SelectableList::new(
+      &mut app.data.radarr_data.indexer_schema,
+      |schema: &Indexer| {
+        ListItem::new(format!(
+          "{} ({})",
+          schema.implementation_name.clone().unwrap_or_default(),
+          schema.protocol
+        ))
+      },
+    )
+    .block(block);
+    let popup = Popup::new(implementation_list).size(Size::Dropdown);
+
+    f.render_widget(popup, f.area());
+  }
+}
+
+fn draw_add_indexer_prompt(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+  let is_editing = app.get_current_route() == ActiveRadarrBlock::AddIndexerFieldInput.into();
+  let Some(add_indexer_modal) = app.data.radarr_data.add_indexer_modal.as_mut() else {
+    f.render_widget(
+      LoadingBlock::new(app.is_loading, title_block_centered("Add Indexer")),
+      area,
+    );
+    return;
+  };
+
+  let title = format!("Add Indexer - {}", add_indexer_modal.implementation_name);
+  f.render_widget(title_block_centered(&title), area);
+
+  let [fields_area, help_area, input_area] = Layout::vertical([
+    Constraint::Fill(1),
+    Constraint::Length(2),
+    Constraint::Length(3),
+  ])
+  .margin(1)
+  .areas(area);
+
+  let selected_field = add_indexer_modal.fields.current_selection().clone();
+  let fields_list = SelectableList::new(&mut add_indexer_modal.fields, |field| {
+    ListItem::new(format!("{}: {}", field.label, field.display_value()))
+  });
+  f.render_widget(fields_list, fields_area);
+
+  let help_text = selected_field.help_text.clone().unwrap_or_default();
+  f.render_widget(layout_paragraph_borderless(&help_text), help_area);
+
+  let value = selected_field.display_value();
+  let input_box = InputBox::new(&value)
+    .offset(selected_field.value.offset.load(Ordering::SeqCst))
+    .label(&selected_field.label)
+    .highlighted(true)
+    .selected(is_editing);
+  render_selectable_input_box!(input_box, f, input_area);
+}
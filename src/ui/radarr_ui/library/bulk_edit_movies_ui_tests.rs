@@ -0,0 +1,59 @@
+#[cfg(test)]
+mod tests {
+  use strum::IntoEnumIterator;
+
+  use crate::app::App;
+  use crate::models::BlockSelectionState;
+  use crate::models::servarr_data::radarr::radarr_data::{
+    ActiveRadarrBlock, BULK_EDIT_MOVIES_BLOCKS, BULK_EDIT_MOVIES_SELECTION_BLOCKS,
+  };
+  use crate::ui::DrawUi;
+  use crate::ui::radarr_ui::library::bulk_edit_movies_ui::BulkEditMoviesUi;
+  use crate::ui::ui_test_utils::test_utils::{TerminalSize, render_to_string_with_app};
+
+  #[test]
+  fn test_bulk_edit_movies_ui_accepts() {
+    ActiveRadarrBlock::iter().for_each(|active_radarr_block| {
+      if BULK_EDIT_MOVIES_BLOCKS.contains(&active_radarr_block) {
+        assert!(BulkEditMoviesUi::accepts(active_radarr_block.into()));
+      } else {
+        assert!(!BulkEditMoviesUi::accepts(active_radarr_block.into()));
+      }
+    });
+  }
+
+  mod snapshot_tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    fn test_bulk_edit_movies_ui_renders_bulk_edit_movies_modal(
+      #[values(
+        ActiveRadarrBlock::BulkEditMoviesPrompt,
+        ActiveRadarrBlock::BulkEditMoviesSelectMonitored,
+        ActiveRadarrBlock::BulkEditMoviesSelectQualityProfile,
+        ActiveRadarrBlock::BulkEditMoviesSelectRootFolder,
+        ActiveRadarrBlock::BulkEditMoviesSelectApplyTags,
+        ActiveRadarrBlock::BulkEditMoviesTagsInput
+      )]
+      active_radarr_block: ActiveRadarrBlock,
+    ) {
+      let mut app = App::test_default_fully_populated();
+      app
+        .data
+        .radarr_data
+        .movies
+        .toggle_select_all(|movie| movie.id);
+      app.push_navigation_stack(ActiveRadarrBlock::BulkEditMoviesPrompt.into());
+      app.push_navigation_stack(active_radarr_block.into());
+      app.data.radarr_data.selected_block =
+        BlockSelectionState::new(BULK_EDIT_MOVIES_SELECTION_BLOCKS);
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        BulkEditMoviesUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(active_radarr_block.to_string(), output);
+    }
+  }
+}
@@ -29,10 +29,24 @@ impl DrawUi for DeleteMovieUi {
       Route::Radarr(ActiveRadarrBlock::DeleteMoviePrompt, _)
     ) {
       let selected_block = app.data.radarr_data.selected_block.get_active_block();
-      let prompt = format!(
-        "Do you really want to delete: \n{}?",
-        app.data.radarr_data.movies.current_selection().title.text
-      );
+      let movies = &app.data.radarr_data.movies;
+      let (title, prompt) = if movies.has_multi_selection() {
+        (
+          "Delete Movies",
+          format!(
+            "Do you really want to delete the {} selected movies?",
+            movies.multi_selection().len()
+          ),
+        )
+      } else {
+        (
+          "Delete Movie",
+          format!(
+            "Do you really want to delete: \n{}?",
+            movies.current_selection().title.text
+          ),
+        )
+      };
       let checkboxes = vec![
         Checkbox::new("Delete Movie File")
           .checked(app.data.radarr_data.delete_movie_files)
@@ -42,7 +56,7 @@ impl DrawUi for DeleteMovieUi {
           .highlighted(selected_block == ActiveRadarrBlock::DeleteMovieToggleAddListExclusion),
       ];
       let confirmation_prompt = ConfirmationPrompt::new()
-        .title("Delete Movie")
+        .title(title)
         .prompt(&prompt)
         .checkboxes(checkboxes)
         .yes_no_highlighted(selected_block == ActiveRadarrBlock::DeleteMovieConfirmPrompt)
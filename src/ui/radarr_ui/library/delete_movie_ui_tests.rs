@@ -37,5 +37,23 @@ mod tests {
 
       insta::assert_snapshot!(output);
     }
+
+    #[test]
+    fn test_delete_movie_ui_renders_bulk_delete_movies_prompt() {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(ActiveRadarrBlock::DeleteMoviePrompt.into());
+      app.data.radarr_data.selected_block = BlockSelectionState::new(DELETE_MOVIE_SELECTION_BLOCKS);
+      app
+        .data
+        .radarr_data
+        .movies
+        .toggle_select_all(|movie| movie.id);
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        DeleteMovieUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(output);
+    }
   }
 }
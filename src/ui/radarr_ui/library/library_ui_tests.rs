@@ -4,8 +4,8 @@ mod tests {
 
   use crate::app::App;
   use crate::models::servarr_data::radarr::radarr_data::{
-    ADD_MOVIE_BLOCKS, ActiveRadarrBlock, DELETE_MOVIE_BLOCKS, EDIT_MOVIE_BLOCKS, LIBRARY_BLOCKS,
-    MOVIE_DETAILS_BLOCKS,
+    ADD_MOVIE_BLOCKS, ActiveRadarrBlock, BULK_EDIT_MOVIES_BLOCKS, DELETE_MOVIE_BLOCKS,
+    EDIT_MOVIE_BLOCKS, LIBRARY_BLOCKS, MOVIE_DETAILS_BLOCKS,
   };
   use crate::ui::DrawUi;
   use crate::ui::radarr_ui::library::LibraryUi;
@@ -18,6 +18,7 @@ mod tests {
     library_ui_blocks.extend(MOVIE_DETAILS_BLOCKS);
     library_ui_blocks.extend(ADD_MOVIE_BLOCKS);
     library_ui_blocks.extend(EDIT_MOVIE_BLOCKS);
+    library_ui_blocks.extend(BULK_EDIT_MOVIES_BLOCKS);
     library_ui_blocks.extend(DELETE_MOVIE_BLOCKS);
 
     ActiveRadarrBlock::iter().for_each(|active_radarr_block| {
@@ -6,9 +6,11 @@ use crate::app::App;
 use crate::models::Route;
 use crate::models::radarr_models::Movie;
 use crate::models::servarr_data::radarr::radarr_data::{ActiveRadarrBlock, LIBRARY_BLOCKS};
+use crate::models::table_columns::{MOVIE_TABLE_HEADERS, movie_row};
 use crate::ui::DrawUi;
 use crate::ui::radarr_ui::decorate_with_row_style;
 use crate::ui::radarr_ui::library::add_movie_ui::AddMovieUi;
+use crate::ui::radarr_ui::library::bulk_edit_movies_ui::BulkEditMoviesUi;
 use crate::ui::radarr_ui::library::delete_movie_ui::DeleteMovieUi;
 use crate::ui::radarr_ui::library::edit_movie_ui::EditMovieUi;
 use crate::ui::radarr_ui::library::movie_details_ui::MovieDetailsUi;
@@ -16,9 +18,9 @@ use crate::ui::utils::{get_width_from_percentage, layout_block_top_border};
 use crate::ui::widgets::confirmation_prompt::ConfirmationPrompt;
 use crate::ui::widgets::managarr_table::ManagarrTable;
 use crate::ui::widgets::popup::{Popup, Size};
-use crate::utils::{convert_runtime, convert_to_gb};
 
 mod add_movie_ui;
+mod bulk_edit_movies_ui;
 mod delete_movie_ui;
 mod edit_movie_ui;
 mod movie_details_ui;
@@ -35,6 +37,7 @@ impl DrawUi for LibraryUi {
       return MovieDetailsUi::accepts(route)
         || AddMovieUi::accepts(route)
         || EditMovieUi::accepts(route)
+        || BulkEditMoviesUi::accepts(route)
         || DeleteMovieUi::accepts(route)
         || LIBRARY_BLOCKS.contains(&active_radarr_block);
     }
@@ -56,6 +59,7 @@ impl DrawUi for LibraryUi {
         _ if MovieDetailsUi::accepts(route) => MovieDetailsUi::draw(f, app, area),
         _ if AddMovieUi::accepts(route) => AddMovieUi::draw(f, app, area),
         _ if EditMovieUi::accepts(route) => EditMovieUi::draw(f, app, area),
+        _ if BulkEditMoviesUi::accepts(route) => BulkEditMoviesUi::draw(f, app, area),
         _ if DeleteMovieUi::accepts(route) => DeleteMovieUi::draw(f, app, area),
         Route::Radarr(ActiveRadarrBlock::UpdateAllMoviesPrompt, _) => {
           let confirmation_prompt = ConfirmationPrompt::new()
@@ -92,40 +96,10 @@ fn draw_library(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
         *movie == current_selection,
         app.ui_scroll_tick_count == 0,
       );
-      let monitored = if movie.monitored { "🏷" } else { "" };
-      let studio = movie.studio.clone().unwrap_or_default();
-      let (hours, minutes) = convert_runtime(movie.runtime);
-      let file_size: f64 = convert_to_gb(movie.size_on_disk);
-      let certification = movie.certification.clone().unwrap_or_default();
-      let quality_profile = quality_profile_map
-        .get_by_left(&movie.quality_profile_id)
-        .expect("Quality profile ID must exist in quality_profile_map")
-        .to_owned();
-      let tags = movie
-        .tags
-        .iter()
-        .filter_map(|tag_id| {
-          let id = tag_id.as_i64()?;
-          tags_map.get_by_left(&id).cloned()
-        })
-        .collect::<Vec<_>>()
-        .join(", ");
-
       decorate_with_row_style(
         downloads_vec,
         movie,
-        Row::new(vec![
-          Cell::from(movie.title.to_string()),
-          Cell::from(movie.year.to_string()),
-          Cell::from(studio),
-          Cell::from(format!("{hours}h {minutes}m")),
-          Cell::from(certification),
-          Cell::from(movie.original_language.name.to_owned()),
-          Cell::from(format!("{file_size:.2} GB")),
-          Cell::from(quality_profile),
-          Cell::from(monitored.to_owned()),
-          Cell::from(tags),
-        ]),
+        Row::new(movie_row(movie, quality_profile_map, tags_map).map(Cell::from)),
       )
     };
     let library_table = ManagarrTable::new(content, library_table_row_mapping)
@@ -136,18 +110,7 @@ fn draw_library(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
       .search_produced_empty_results(active_radarr_block == ActiveRadarrBlock::SearchMovieError)
       .filtering(active_radarr_block == ActiveRadarrBlock::FilterMovies)
       .filter_produced_empty_results(active_radarr_block == ActiveRadarrBlock::FilterMoviesError)
-      .headers([
-        "Title",
-        "Year",
-        "Studio",
-        "Runtime",
-        "Rating",
-        "Language",
-        "Size",
-        "Quality Profile",
-        "Monitored",
-        "Tags",
-      ])
+      .headers(MOVIE_TABLE_HEADERS)
       .constraints([
         Constraint::Percentage(27),
         Constraint::Percentage(4),
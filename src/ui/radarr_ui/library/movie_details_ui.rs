@@ -18,8 +18,8 @@ use crate::models::servarr_data::radarr::modals::MovieDetailsModal;
 use crate::models::servarr_data::radarr::radarr_data::{ActiveRadarrBlock, MOVIE_DETAILS_BLOCKS};
 use crate::ui::styles::ManagarrStyle;
 use crate::ui::utils::{
-  borderless_block, decorate_peer_style, get_width_from_percentage, layout_block_bottom_border,
-  layout_block_top_border,
+  borderless_block, custom_format_score_lines, decorate_peer_style, get_width_from_percentage,
+  layout_block_bottom_border, layout_block_top_border,
 };
 use crate::ui::widgets::confirmation_prompt::ConfirmationPrompt;
 use crate::ui::widgets::loading_block::LoadingBlock;
@@ -395,6 +395,7 @@ fn draw_movie_releases(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
         leechers,
         languages,
         quality,
+        custom_format_score,
         ..
       } = release;
       let age = format!("{age} days");
@@ -444,6 +445,7 @@ fn draw_movie_releases(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
         Cell::from(peers),
         Cell::from(language),
         Cell::from(quality),
+        Cell::from(custom_format_score.to_string()),
       ])
       .primary()
     };
@@ -453,6 +455,7 @@ fn draw_movie_releases(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
       .sorting(active_radarr_block == ActiveRadarrBlock::ManualSearchSortPrompt)
       .headers([
         "Source", "Age", "⛔", "Title", "Indexer", "Size", "Peers", "Language", "Quality",
+        "CF Score",
       ])
       .constraints([
         Constraint::Length(9),
@@ -464,6 +467,7 @@ fn draw_movie_releases(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
         Constraint::Length(12),
         Constraint::Percentage(7),
         Constraint::Percentage(10),
+        Constraint::Length(9),
       ]);
 
     f.render_widget(releases_table, area);
@@ -496,38 +500,67 @@ fn draw_manual_search_confirm_prompt(f: &mut Frame<'_>, app: &mut App<'_>) {
     )
   };
 
+  let quality_profile_id = app
+    .data
+    .radarr_data
+    .movies
+    .current_selection()
+    .quality_profile_id;
+  let quality_profile = app
+    .data
+    .radarr_data
+    .quality_profiles
+    .items
+    .iter()
+    .find(|quality_profile| quality_profile.id == quality_profile_id);
+  let mut lines_vec = Vec::new();
+
   if current_selection.rejected {
-    let mut lines_vec = vec![Line::from("Rejection reasons: ".primary().bold())];
-    let mut rejections_spans = current_selection
-      .rejections
-      .clone()
-      .unwrap_or_default()
-      .iter()
-      .map(|item| Line::from(format!("• {item}").primary().bold()))
-      .collect::<Vec<Line<'_>>>();
-    lines_vec.append(&mut rejections_spans);
+    lines_vec.push(Line::from("Rejection reasons: ".primary().bold()));
+    lines_vec.extend(
+      current_selection
+        .rejections
+        .clone()
+        .unwrap_or_default()
+        .iter()
+        .map(|item| Line::from(format!("• {item}").primary().bold())),
+    );
+  }
 
-    let content_paragraph = Paragraph::new(lines_vec)
-      .block(borderless_block())
-      .wrap(Wrap { trim: false })
-      .left_aligned();
+  let custom_format_lines = custom_format_score_lines(
+    &current_selection.custom_formats,
+    current_selection.custom_format_score,
+    quality_profile,
+  );
+  if !custom_format_lines.is_empty() {
+    if !lines_vec.is_empty() {
+      lines_vec.push(Line::default());
+    }
+    lines_vec.extend(custom_format_lines);
+  }
+
+  if lines_vec.is_empty() {
     let confirmation_prompt = ConfirmationPrompt::new()
       .title(title)
       .prompt(&prompt)
-      .content(content_paragraph)
       .yes_no_value(app.data.radarr_data.prompt_confirm);
 
-    f.render_widget(Popup::new(confirmation_prompt).size(Size::Small), f.area());
+    f.render_widget(
+      Popup::new(confirmation_prompt).size(Size::MediumPrompt),
+      f.area(),
+    );
   } else {
+    let content_paragraph = Paragraph::new(lines_vec)
+      .block(borderless_block())
+      .wrap(Wrap { trim: false })
+      .left_aligned();
     let confirmation_prompt = ConfirmationPrompt::new()
       .title(title)
       .prompt(&prompt)
+      .content(content_paragraph)
       .yes_no_value(app.data.radarr_data.prompt_confirm);
 
-    f.render_widget(
-      Popup::new(confirmation_prompt).size(Size::MediumPrompt),
-      f.area(),
-    );
+    f.render_widget(Popup::new(confirmation_prompt).size(Size::Small), f.area());
   }
 }
 
@@ -7,13 +7,18 @@ use crate::models::servarr_models::{DiskSpace, RootFolder};
 use crate::ui::DrawUi;
 use crate::ui::draw_tabs;
 use crate::ui::radarr_ui::blocklist::BlocklistUi;
+use crate::ui::radarr_ui::calendar::CalendarUi;
 use crate::ui::radarr_ui::collections::CollectionsUi;
+use crate::ui::radarr_ui::custom_formats::CustomFormatsUi;
+use crate::ui::radarr_ui::download_clients::DownloadClientsUi;
 use crate::ui::radarr_ui::downloads::DownloadsUi;
 use crate::ui::radarr_ui::history::HistoryUi;
 use crate::ui::radarr_ui::indexers::IndexersUi;
 use crate::ui::radarr_ui::library::LibraryUi;
+use crate::ui::radarr_ui::quality_profiles::QualityProfilesUi;
 use crate::ui::radarr_ui::root_folders::RootFoldersUi;
 use crate::ui::radarr_ui::system::SystemUi;
+use crate::ui::radarr_ui::wanted::WantedUi;
 use crate::ui::styles::ManagarrStyle;
 #[cfg(test)]
 use crate::ui::ui_test_utils::test_utils::Utc;
@@ -34,16 +39,21 @@ use ratatui::widgets::{Paragraph, Row};
 use std::{cmp, iter};
 
 mod blocklist;
+mod calendar;
 mod collections;
+mod custom_formats;
+mod download_clients;
 mod downloads;
 mod history;
 mod indexers;
 mod library;
+mod quality_profiles;
 #[cfg(test)]
 mod radarr_ui_tests;
 mod radarr_ui_utils;
 mod root_folders;
 mod system;
+mod wanted;
 
 pub(super) struct RadarrUi;
 
@@ -61,10 +71,15 @@ impl DrawUi for RadarrUi {
       _ if CollectionsUi::accepts(route) => CollectionsUi::draw(f, app, content_area),
       _ if DownloadsUi::accepts(route) => DownloadsUi::draw(f, app, content_area),
       _ if IndexersUi::accepts(route) => IndexersUi::draw(f, app, content_area),
+      _ if DownloadClientsUi::accepts(route) => DownloadClientsUi::draw(f, app, content_area),
+      _ if QualityProfilesUi::accepts(route) => QualityProfilesUi::draw(f, app, content_area),
+      _ if CustomFormatsUi::accepts(route) => CustomFormatsUi::draw(f, app, content_area),
       _ if RootFoldersUi::accepts(route) => RootFoldersUi::draw(f, app, content_area),
       _ if SystemUi::accepts(route) => SystemUi::draw(f, app, content_area),
       _ if BlocklistUi::accepts(route) => BlocklistUi::draw(f, app, content_area),
       _ if HistoryUi::accepts(route) => HistoryUi::draw(f, app, content_area),
+      _ if CalendarUi::accepts(route) => CalendarUi::draw(f, app, content_area),
+      _ if WantedUi::accepts(route) => WantedUi::draw(f, app, content_area),
       _ => (),
     }
   }
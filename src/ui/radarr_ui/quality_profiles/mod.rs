@@ -0,0 +1,131 @@
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::widgets::{Cell, ListItem, Row};
+
+use crate::app::App;
+use crate::models::Route;
+use crate::models::servarr_data::radarr::radarr_data::{
+  ActiveRadarrBlock, QUALITY_PROFILES_BLOCKS,
+};
+use crate::models::servarr_models::{QualityProfile, QualityProfileItem};
+use crate::ui::styles::ManagarrStyle;
+use crate::ui::utils::{
+  layout_block_top_border, layout_paragraph_borderless, title_block_centered,
+};
+use crate::ui::widgets::loading_block::LoadingBlock;
+use crate::ui::widgets::managarr_table::ManagarrTable;
+use crate::ui::widgets::popup::Size;
+use crate::ui::widgets::selectable_list::SelectableList;
+use crate::ui::{DrawUi, draw_popup};
+
+#[cfg(test)]
+#[path = "quality_profiles_ui_tests.rs"]
+mod quality_profiles_ui_tests;
+
+pub(super) struct QualityProfilesUi;
+
+impl DrawUi for QualityProfilesUi {
+  fn accepts(route: Route) -> bool {
+    if let Route::Radarr(active_radarr_block, _) = route {
+      return QUALITY_PROFILES_BLOCKS.contains(&active_radarr_block);
+    }
+
+    false
+  }
+
+  fn draw(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+    draw_quality_profiles(f, app, area);
+
+    if app.get_current_route() == ActiveRadarrBlock::EditQualityProfile.into() {
+      draw_popup(f, app, draw_edit_quality_profile_popup, Size::LargePrompt);
+    }
+  }
+}
+
+fn draw_quality_profiles(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+  let quality_profiles_row_mapping = |quality_profile: &'_ QualityProfile| {
+    let upgrades_allowed = if quality_profile.upgrade_allowed {
+      "Yes"
+    } else {
+      "No"
+    };
+
+    Row::new(vec![
+      Cell::from(quality_profile.name.clone()),
+      Cell::from(upgrades_allowed),
+      Cell::from(quality_profile.cutoff_name().unwrap_or_default()),
+      Cell::from(quality_profile.min_format_score.to_string()),
+      Cell::from(quality_profile.allowed_qualities().join(", ")),
+    ])
+    .primary()
+  };
+  let quality_profiles_table = ManagarrTable::new(
+    Some(&mut app.data.radarr_data.quality_profiles),
+    quality_profiles_row_mapping,
+  )
+  .block(layout_block_top_border())
+  .loading(app.is_loading)
+  .headers([
+    "Quality Profile",
+    "Upgrades Allowed",
+    "Cutoff",
+    "Min Format Score",
+    "Allowed Qualities",
+  ])
+  .constraints([
+    Constraint::Percentage(20),
+    Constraint::Percentage(12),
+    Constraint::Percentage(15),
+    Constraint::Percentage(12),
+    Constraint::Percentage(41),
+  ]);
+
+  f.render_widget(quality_profiles_table, area);
+}
+
+fn draw_edit_quality_profile_popup(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+  let Some(quality_profile_modal) = app.data.radarr_data.quality_profile_modal.as_mut() else {
+    f.render_widget(
+      LoadingBlock::new(app.is_loading, title_block_centered("Edit Quality Profile")),
+      area,
+    );
+    return;
+  };
+
+  let title = format!("Edit Quality Profile - {}", quality_profile_modal.name);
+  f.render_widget(title_block_centered(&title), area);
+
+  let [items_area, cutoff_area] = Layout::vertical([Constraint::Fill(1), Constraint::Length(1)])
+    .margin(1)
+    .areas(area);
+
+  let cutoff = quality_profile_modal.cutoff;
+  let cutoff_text = format!(
+    "Upgrade Until: {}",
+    quality_profile_modal.cutoff_name().unwrap_or_default()
+  );
+  let items_list = SelectableList::new(
+    &mut quality_profile_modal.items,
+    |item: &QualityProfileItem| {
+      let checkbox = if item.allowed { "[✔]" } else { "[ ]" };
+      let mut label = format!("{checkbox} {}", item.display_name());
+      if item.is_group() {
+        let qualities = item
+          .items
+          .iter()
+          .rev()
+          .map(QualityProfileItem::display_name)
+          .collect::<Vec<String>>()
+          .join(", ");
+        label.push_str(&format!(" ({qualities})"));
+      }
+      if item.item_id() == cutoff {
+        label.push_str(" - cutoff");
+      }
+
+      ListItem::new(label)
+    },
+  );
+  f.render_widget(items_list, items_area);
+  f.render_widget(layout_paragraph_borderless(&cutoff_text), cutoff_area);
+}
@@ -33,6 +33,8 @@ mod tests {
     #[case(ActiveRadarrBlock::RootFolders, 5)]
     #[case(ActiveRadarrBlock::Indexers, 6)]
     #[case(ActiveRadarrBlock::System, 7)]
+    #[case(ActiveRadarrBlock::Calendar, 8)]
+    #[case(ActiveRadarrBlock::WantedMissing, 9)]
     fn test_radarr_ui_renders_radarr_tabs(
       #[case] active_radarr_block: ActiveRadarrBlock,
       #[case] index: usize,
@@ -1,16 +1,28 @@
+use std::sync::atomic::Ordering;
+
 use ratatui::Frame;
-use ratatui::layout::{Constraint, Rect};
-use ratatui::widgets::{Cell, Row};
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::text::Text;
+use ratatui::widgets::{Cell, ListItem, Paragraph, Row, Wrap};
 
 use crate::app::App;
 use crate::models::Route;
-use crate::models::servarr_data::radarr::radarr_data::{ActiveRadarrBlock, ROOT_FOLDERS_BLOCKS};
+use crate::models::radarr_models::NamingExamples;
+use crate::models::servarr_data::radarr::radarr_data::{
+  ActiveRadarrBlock, MEDIA_MANAGEMENT_SETTINGS_BLOCKS, ROOT_FOLDERS_BLOCKS,
+};
 use crate::models::servarr_models::RootFolder;
+use crate::render_selectable_input_box;
 use crate::ui::styles::ManagarrStyle;
-use crate::ui::utils::layout_block_top_border;
+use crate::ui::utils::{
+  layout_block_top_border, layout_paragraph_borderless, title_block, title_block_centered,
+};
 use crate::ui::widgets::confirmation_prompt::ConfirmationPrompt;
+use crate::ui::widgets::input_box::InputBox;
+use crate::ui::widgets::loading_block::LoadingBlock;
 use crate::ui::widgets::managarr_table::ManagarrTable;
 use crate::ui::widgets::popup::{Popup, Size};
+use crate::ui::widgets::selectable_list::SelectableList;
 use crate::ui::{DrawUi, draw_input_box_popup, draw_popup};
 use crate::utils::convert_to_gb;
 
@@ -26,6 +38,7 @@ impl DrawUi for RootFoldersUi {
       return false;
     };
     ROOT_FOLDERS_BLOCKS.contains(&active_radarr_block)
+      || MEDIA_MANAGEMENT_SETTINGS_BLOCKS.contains(&active_radarr_block)
   }
 
   fn draw(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
@@ -51,6 +64,12 @@ impl DrawUi for RootFoldersUi {
             f.area(),
           );
         }
+        _ if MEDIA_MANAGEMENT_SETTINGS_BLOCKS.contains(&active_radarr_block) => draw_popup(
+          f,
+          app,
+          draw_media_management_settings_prompt,
+          Size::WideLargePrompt,
+        ),
         _ => (),
       }
     }
@@ -106,3 +125,73 @@ fn draw_add_root_folder_prompt_box(f: &mut Frame<'_>, app: &mut App<'_>, area: R
     app.data.radarr_data.edit_root_folder.as_ref().unwrap(),
   );
 }
+
+fn draw_media_management_settings_prompt(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+  let is_editing = app.get_current_route() == ActiveRadarrBlock::MediaManagementFieldInput.into();
+  let Some(media_management_modal) = app.data.radarr_data.media_management_modal.as_mut() else {
+    f.render_widget(
+      LoadingBlock::new(
+        app.is_loading,
+        title_block_centered("Media Management Settings"),
+      ),
+      area,
+    );
+    return;
+  };
+  f.render_widget(title_block_centered("Media Management Settings"), area);
+
+  let [fields_area, help_area, input_area, examples_area] = Layout::vertical([
+    Constraint::Fill(1),
+    Constraint::Length(2),
+    Constraint::Length(3),
+    Constraint::Length(4),
+  ])
+  .margin(1)
+  .areas(area);
+
+  let selected_field = media_management_modal.fields.current_selection().clone();
+  let fields_list = SelectableList::new(&mut media_management_modal.fields, |field| {
+    ListItem::new(format!("{}: {}", field.label, field.display_value()))
+  });
+  f.render_widget(fields_list, fields_area);
+
+  let help_text = selected_field.help_text.clone().unwrap_or_default();
+  f.render_widget(layout_paragraph_borderless(&help_text), help_area);
+
+  let value = selected_field.display_value();
+  let input_box = InputBox::new(&value)
+    .offset(selected_field.value.offset.load(Ordering::SeqCst))
+    .label(&selected_field.label)
+    .highlighted(true)
+    .selected(is_editing);
+  render_selectable_input_box!(input_box, f, input_area);
+
+  draw_naming_examples(f, app, examples_area);
+}
+
+fn draw_naming_examples(f: &mut Frame<'_>, app: &App<'_>, area: Rect) {
+  let block = title_block("Naming Examples");
+  let Some(naming_examples) = app.data.radarr_data.naming_examples.as_ref() else {
+    f.render_widget(LoadingBlock::new(app.is_loading, block), area);
+    return;
+  };
+
+  let NamingExamples {
+    movie_example,
+    movie_folder_example,
+  } = naming_examples;
+  let examples = [
+    ("Movie", movie_example),
+    ("Movie Folder", movie_folder_example),
+  ]
+  .into_iter()
+  .map(|(label, example)| format!("{label}: {}", example.as_deref().unwrap_or_default()))
+  .collect::<Vec<_>>()
+  .join("\n");
+  let examples_paragraph = Paragraph::new(Text::from(examples))
+    .block(block)
+    .default_color()
+    .wrap(Wrap { trim: false });
+
+  f.render_widget(examples_paragraph, area);
+}
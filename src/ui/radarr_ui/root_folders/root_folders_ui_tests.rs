@@ -3,7 +3,9 @@ mod tests {
   use strum::IntoEnumIterator;
 
   use crate::app::App;
-  use crate::models::servarr_data::radarr::radarr_data::{ActiveRadarrBlock, ROOT_FOLDERS_BLOCKS};
+  use crate::models::servarr_data::radarr::radarr_data::{
+    ActiveRadarrBlock, MEDIA_MANAGEMENT_SETTINGS_BLOCKS, ROOT_FOLDERS_BLOCKS,
+  };
   use crate::models::stateful_table::StatefulTable;
   use crate::ui::DrawUi;
   use crate::ui::radarr_ui::root_folders::RootFoldersUi;
@@ -11,8 +13,12 @@ mod tests {
 
   #[test]
   fn test_root_folders_ui_accepts() {
+    let mut root_folders_ui_blocks = Vec::new();
+    root_folders_ui_blocks.extend(ROOT_FOLDERS_BLOCKS);
+    root_folders_ui_blocks.extend(MEDIA_MANAGEMENT_SETTINGS_BLOCKS);
+
     ActiveRadarrBlock::iter().for_each(|active_radarr_block| {
-      if ROOT_FOLDERS_BLOCKS.contains(&active_radarr_block) {
+      if root_folders_ui_blocks.contains(&active_radarr_block) {
         assert!(RootFoldersUi::accepts(active_radarr_block.into()));
       } else {
         assert!(!RootFoldersUi::accepts(active_radarr_block.into()));
@@ -55,7 +61,9 @@ mod tests {
       #[values(
         ActiveRadarrBlock::RootFolders,
         ActiveRadarrBlock::AddRootFolderPrompt,
-        ActiveRadarrBlock::DeleteRootFolderPrompt
+        ActiveRadarrBlock::DeleteRootFolderPrompt,
+        ActiveRadarrBlock::MediaManagementSettingsPrompt,
+        ActiveRadarrBlock::MediaManagementFieldInput
       )]
       active_radarr_block: ActiveRadarrBlock,
     ) {
@@ -68,5 +76,34 @@ mod tests {
 
       insta::assert_snapshot!(active_radarr_block.to_string(), output);
     }
+
+    #[test]
+    fn test_root_folders_ui_renders_media_management_settings_loading() {
+      let mut app = App::test_default_fully_populated();
+      app.is_loading = true;
+      app.data.radarr_data.media_management_modal = None;
+      app.data.radarr_data.naming_examples = None;
+      app.push_navigation_stack(ActiveRadarrBlock::MediaManagementSettingsPrompt.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        RootFoldersUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(output);
+    }
+
+    #[test]
+    fn test_root_folders_ui_renders_media_management_settings_naming_examples_loading() {
+      let mut app = App::test_default_fully_populated();
+      app.is_loading = true;
+      app.data.radarr_data.naming_examples = None;
+      app.push_navigation_stack(ActiveRadarrBlock::MediaManagementSettingsPrompt.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        RootFoldersUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(output);
+    }
   }
 }
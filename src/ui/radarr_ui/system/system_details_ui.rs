@@ -1,5 +1,5 @@
 use ratatui::Frame;
-use ratatui::layout::Rect;
+use ratatui::layout::{Constraint, Rect};
 use ratatui::text::{Span, Text};
 use ratatui::widgets::{Cell, ListItem, Paragraph, Row};
 
@@ -7,6 +7,7 @@ use crate::app::App;
 use crate::models::Route;
 use crate::models::radarr_models::RadarrTask;
 use crate::models::servarr_data::radarr::radarr_data::{ActiveRadarrBlock, SYSTEM_DETAILS_BLOCKS};
+use crate::models::servarr_models::Backup;
 use crate::ui::radarr_ui::system::{
   TASK_TABLE_CONSTRAINTS, TASK_TABLE_HEADERS, draw_queued_events, extract_task_props,
 };
@@ -39,6 +40,9 @@ impl DrawUi for SystemDetailsUi {
         ActiveRadarrBlock::SystemLogs => {
           draw_logs_popup(f, app);
         }
+        ActiveRadarrBlock::SystemBackups | ActiveRadarrBlock::DeleteBackupPrompt => {
+          draw_popup(f, app, draw_backups_popup, Size::Large)
+        }
         ActiveRadarrBlock::SystemTasks | ActiveRadarrBlock::SystemTaskStartConfirmPrompt => {
           draw_popup(f, app, draw_tasks_popup, Size::Large)
         }
@@ -80,6 +84,53 @@ fn draw_logs_popup(f: &mut Frame<'_>, app: &mut App<'_>) {
   f.render_widget(popup, f.area());
 }
 
+fn draw_backups_popup(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+  let backups_row_mapping = |backup: &Backup| {
+    let size = backup.size as f64 / 1024f64.powi(2);
+
+    Row::new(vec![
+      Cell::from(backup.name.clone()),
+      Cell::from(backup.backup_type.clone()),
+      Cell::from(format!("{size:.2} MB")),
+      Cell::from(backup.time.to_string()),
+    ])
+    .primary()
+  };
+  let backups_table =
+    ManagarrTable::new(Some(&mut app.data.radarr_data.backups), backups_row_mapping)
+      .loading(app.is_loading)
+      .margin(1)
+      .headers(["Name", "Type", "Size", "Time"])
+      .constraints([
+        Constraint::Percentage(55),
+        Constraint::Percentage(12),
+        Constraint::Percentage(12),
+        Constraint::Percentage(21),
+      ]);
+
+  f.render_widget(title_block("Backups"), area);
+  f.render_widget(backups_table, area);
+
+  if matches!(
+    app.get_current_route(),
+    Route::Radarr(ActiveRadarrBlock::DeleteBackupPrompt, _)
+  ) {
+    let prompt = format!(
+      "Do you really want to delete this backup: \n{}?",
+      app.data.radarr_data.backups.current_selection().name
+    );
+    let confirmation_prompt = ConfirmationPrompt::new()
+      .title("Delete Backup")
+      .prompt(&prompt)
+      .yes_no_value(app.data.radarr_data.prompt_confirm);
+
+    f.render_widget(
+      Popup::new(confirmation_prompt).size(Size::MediumPrompt),
+      f.area(),
+    );
+  }
+}
+
 fn draw_tasks_popup(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
   let tasks_row_mapping = |task: &RadarrTask| {
     let task_props = extract_task_props(task);
@@ -29,6 +29,8 @@ mod tests {
     #[rstest]
     fn test_system_details_ui_renders_popups(
       #[values(
+        ActiveRadarrBlock::SystemBackups,
+        ActiveRadarrBlock::DeleteBackupPrompt,
         ActiveRadarrBlock::SystemLogs,
         ActiveRadarrBlock::SystemQueuedEvents,
         ActiveRadarrBlock::SystemTasks,
@@ -47,6 +49,19 @@ mod tests {
       insta::assert_snapshot!(active_radarr_block.to_string(), output);
     }
 
+    #[test]
+    fn test_system_details_ui_renders_backups_loading() {
+      let mut app = App::test_default_fully_populated();
+      app.is_loading = true;
+      app.push_navigation_stack(ActiveRadarrBlock::SystemBackups.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        SystemDetailsUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(output);
+    }
+
     #[test]
     fn test_system_details_ui_renders_tasks_loading() {
       let mut app = App::test_default_fully_populated();
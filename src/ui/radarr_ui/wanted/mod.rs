@@ -0,0 +1,138 @@
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Rect};
+use ratatui::widgets::{Cell, Row};
+
+use crate::app::App;
+use crate::models::Route;
+use crate::models::radarr_models::Movie;
+use crate::models::servarr_data::radarr::radarr_data::{ActiveRadarrBlock, WANTED_BLOCKS};
+use crate::ui::DrawUi;
+use crate::ui::styles::ManagarrStyle;
+use crate::ui::utils::{layout_block_top_border_with_title, title_style};
+use crate::ui::widgets::confirmation_prompt::ConfirmationPrompt;
+use crate::ui::widgets::managarr_table::ManagarrTable;
+use crate::ui::widgets::popup::{Popup, Size};
+
+#[cfg(test)]
+#[path = "wanted_ui_tests.rs"]
+mod wanted_ui_tests;
+
+pub(super) struct WantedUi;
+
+impl DrawUi for WantedUi {
+  fn accepts(route: Route) -> bool {
+    if let Route::Radarr(active_radarr_block, _) = route {
+      return WANTED_BLOCKS.contains(&active_radarr_block);
+    }
+
+    false
+  }
+
+  fn draw(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+    if let Route::Radarr(active_radarr_block, context) = app.get_current_route() {
+      let table_block = match active_radarr_block {
+        ActiveRadarrBlock::WantedSearchAllPrompt
+        | ActiveRadarrBlock::WantedSearchSelectedPrompt => {
+          context.unwrap_or(ActiveRadarrBlock::WantedMissing)
+        }
+        _ => active_radarr_block,
+      };
+      draw_wanted_table(f, app, area, table_block);
+
+      match active_radarr_block {
+        ActiveRadarrBlock::WantedSearchSelectedPrompt => {
+          let movie = if table_block == ActiveRadarrBlock::WantedCutoffUnmet {
+            app.data.radarr_data.cutoff_unmet.current_selection()
+          } else {
+            app.data.radarr_data.wanted_missing.current_selection()
+          };
+          let prompt = format!(
+            "Do you want to trigger an automatic search for: \n{}?",
+            movie.title.text
+          );
+          let confirmation_prompt = ConfirmationPrompt::new()
+            .title("Search Selected Movie")
+            .prompt(&prompt)
+            .yes_no_value(app.data.radarr_data.prompt_confirm);
+
+          f.render_widget(
+            Popup::new(confirmation_prompt).size(Size::MediumPrompt),
+            f.area(),
+          );
+        }
+        ActiveRadarrBlock::WantedSearchAllPrompt => {
+          let prompt = if table_block == ActiveRadarrBlock::WantedCutoffUnmet {
+            "Do you want to trigger an automatic search for all movies that have not met their quality cutoff?"
+          } else {
+            "Do you want to trigger an automatic search for all missing movies?"
+          };
+          let confirmation_prompt = ConfirmationPrompt::new()
+            .title("Search All Wanted Movies")
+            .prompt(prompt)
+            .yes_no_value(app.data.radarr_data.prompt_confirm);
+
+          f.render_widget(
+            Popup::new(confirmation_prompt).size(Size::MediumPrompt),
+            f.area(),
+          );
+        }
+        _ => (),
+      }
+    }
+  }
+}
+
+fn draw_wanted_table(
+  f: &mut Frame<'_>,
+  app: &mut App<'_>,
+  area: Rect,
+  table_block: ActiveRadarrBlock,
+) {
+  let quality_profile_map = &app.data.radarr_data.quality_profile_map;
+  let is_cutoff_unmet = table_block == ActiveRadarrBlock::WantedCutoffUnmet;
+  let wanted_row_mapping = |movie: &Movie| {
+    let Movie {
+      title,
+      year,
+      quality_profile_id,
+      digital_release,
+      ..
+    } = movie;
+    let quality_profile = quality_profile_map
+      .get_by_left(quality_profile_id)
+      .cloned()
+      .unwrap_or_default();
+    let digital_release =
+      digital_release.map_or_else(String::new, |date| date.format("%Y-%m-%d").to_string());
+
+    let row = Row::new(vec![
+      Cell::from(title.text.clone()),
+      Cell::from(year.to_string()),
+      Cell::from(quality_profile),
+      Cell::from(digital_release),
+    ]);
+
+    if is_cutoff_unmet {
+      row.warning()
+    } else {
+      row.missing()
+    }
+  };
+  let (title, wanted_table) = if is_cutoff_unmet {
+    ("Cutoff Unmet", &mut app.data.radarr_data.cutoff_unmet)
+  } else {
+    ("Missing", &mut app.data.radarr_data.wanted_missing)
+  };
+  let wanted_table = ManagarrTable::new(Some(wanted_table), wanted_row_mapping)
+    .block(layout_block_top_border_with_title(title_style(title)))
+    .loading(app.is_loading)
+    .headers(["Title", "Year", "Quality Profile", "Digital Release"])
+    .constraints([
+      Constraint::Percentage(45),
+      Constraint::Percentage(10),
+      Constraint::Percentage(25),
+      Constraint::Percentage(20),
+    ]);
+
+  f.render_widget(wanted_table, area);
+}
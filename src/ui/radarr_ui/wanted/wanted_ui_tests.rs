@@ -0,0 +1,95 @@
+#[cfg(test)]
+mod tests {
+  use strum::IntoEnumIterator;
+
+  use crate::app::App;
+  use crate::models::servarr_data::radarr::radarr_data::{ActiveRadarrBlock, WANTED_BLOCKS};
+  use crate::ui::DrawUi;
+  use crate::ui::radarr_ui::wanted::WantedUi;
+  use crate::ui::ui_test_utils::test_utils::render_to_string_with_app;
+
+  #[test]
+  fn test_wanted_ui_accepts() {
+    ActiveRadarrBlock::iter().for_each(|active_radarr_block| {
+      if WANTED_BLOCKS.contains(&active_radarr_block) {
+        assert!(WantedUi::accepts(active_radarr_block.into()));
+      } else {
+        assert!(!WantedUi::accepts(active_radarr_block.into()));
+      }
+    });
+  }
+
+  mod snapshot_tests {
+    use crate::ui::ui_test_utils::test_utils::TerminalSize;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_wanted_ui_renders_loading(
+      #[values(ActiveRadarrBlock::WantedMissing, ActiveRadarrBlock::WantedCutoffUnmet)]
+      active_radarr_block: ActiveRadarrBlock,
+    ) {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.push_navigation_stack(active_radarr_block.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        WantedUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(format!("loading_{active_radarr_block}"), output);
+    }
+
+    #[rstest]
+    fn test_wanted_ui_renders_empty(
+      #[values(ActiveRadarrBlock::WantedMissing, ActiveRadarrBlock::WantedCutoffUnmet)]
+      active_radarr_block: ActiveRadarrBlock,
+    ) {
+      let mut app = App::test_default();
+      app.push_navigation_stack(active_radarr_block.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        WantedUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(format!("empty_{active_radarr_block}"), output);
+    }
+
+    #[rstest]
+    fn test_wanted_ui_renders_wanted_tab(
+      #[values(ActiveRadarrBlock::WantedMissing, ActiveRadarrBlock::WantedCutoffUnmet)]
+      active_radarr_block: ActiveRadarrBlock,
+    ) {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(active_radarr_block.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        WantedUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(active_radarr_block.to_string(), output);
+    }
+
+    #[rstest]
+    fn test_wanted_ui_renders_search_prompts(
+      #[values(ActiveRadarrBlock::WantedMissing, ActiveRadarrBlock::WantedCutoffUnmet)]
+      table_block: ActiveRadarrBlock,
+      #[values(
+        ActiveRadarrBlock::WantedSearchAllPrompt,
+        ActiveRadarrBlock::WantedSearchSelectedPrompt
+      )]
+      prompt_block: ActiveRadarrBlock,
+    ) {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(table_block.into());
+      app.push_navigation_stack((prompt_block, Some(table_block)).into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        WantedUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(format!("{prompt_block}_{table_block}"), output);
+    }
+  }
+}
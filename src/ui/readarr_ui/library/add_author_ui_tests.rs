@@ -0,0 +1,124 @@
+#[cfg(test)]
+mod tests {
+  use strum::IntoEnumIterator;
+
+  use crate::models::servarr_data::readarr::readarr_data::{ADD_AUTHOR_BLOCKS, ActiveReadarrBlock};
+  use crate::ui::DrawUi;
+  use crate::ui::readarr_ui::library::add_author_ui::AddAuthorUi;
+
+  #[test]
+  fn test_add_author_ui_accepts() {
+    ActiveReadarrBlock::iter().for_each(|active_readarr_block| {
+      if ADD_AUTHOR_BLOCKS.contains(&active_readarr_block) {
+        assert!(AddAuthorUi::accepts(active_readarr_block.into()));
+      } else {
+        assert!(!AddAuthorUi::accepts(active_readarr_block.into()));
+      }
+    });
+  }
+
+  mod snapshot_tests {
+    use super::*;
+    use crate::app::App;
+    use crate::models::servarr_data::readarr::readarr_data::ADD_AUTHOR_SELECTION_BLOCKS;
+    use crate::models::{BlockSelectionState, HorizontallyScrollableText};
+    use crate::ui::ui_test_utils::test_utils::{TerminalSize, render_to_string_with_app};
+    use rstest::rstest;
+
+    #[test]
+    fn test_add_author_ui_renders_loading_for_search() {
+      let mut app = App::test_default_fully_populated();
+      app.data.readarr_data.add_author_search = Some(HorizontallyScrollableText::default());
+      app.data.readarr_data.add_searched_authors = None;
+      app.push_navigation_stack(ActiveReadarrBlock::AddAuthorSearchResults.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        AddAuthorUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(output);
+    }
+
+    #[rstest]
+    fn test_add_author_ui_renders(
+      #[values(
+        ActiveReadarrBlock::AddAuthorSearchInput,
+        ActiveReadarrBlock::AddAuthorSearchResults,
+        ActiveReadarrBlock::AddAuthorEmptySearchResults
+      )]
+      active_readarr_block: ActiveReadarrBlock,
+    ) {
+      let mut app = App::test_default_fully_populated();
+      app.data.readarr_data.add_author_search = Some("Test Author".into());
+      app.push_navigation_stack(active_readarr_block.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        AddAuthorUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(format!("add_author_ui_{active_readarr_block}"), output);
+    }
+
+    #[rstest]
+    #[case(ActiveReadarrBlock::AddAuthorPrompt)]
+    #[case(ActiveReadarrBlock::AddAuthorConfirmPrompt)]
+    #[case(ActiveReadarrBlock::AddAuthorSelectMonitor)]
+    #[case(ActiveReadarrBlock::AddAuthorSelectMonitorNewItems)]
+    #[case(ActiveReadarrBlock::AddAuthorSelectQualityProfile)]
+    #[case(ActiveReadarrBlock::AddAuthorSelectMetadataProfile)]
+    #[case(ActiveReadarrBlock::AddAuthorSelectRootFolder)]
+    #[case(ActiveReadarrBlock::AddAuthorTagsInput)]
+    fn test_add_author_modal_ui_renders(#[case] active_readarr_block: ActiveReadarrBlock) {
+      use crate::models::readarr_models::{MonitorType, NewItemMonitorType};
+      use crate::models::servarr_data::readarr::modals::AddAuthorModal;
+      use crate::models::servarr_models::RootFolder;
+      use strum::IntoEnumIterator;
+
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(active_readarr_block.into());
+      app.data.readarr_data.selected_block = BlockSelectionState::new(ADD_AUTHOR_SELECTION_BLOCKS);
+
+      let mut add_author_modal = AddAuthorModal {
+        tags: "test".into(),
+        ..AddAuthorModal::default()
+      };
+      add_author_modal
+        .monitor_list
+        .set_items(Vec::from_iter(MonitorType::iter()));
+      add_author_modal
+        .monitor_new_items_list
+        .set_items(Vec::from_iter(NewItemMonitorType::iter()));
+      add_author_modal
+        .quality_profile_list
+        .set_items(vec!["Any".to_owned()]);
+      add_author_modal
+        .metadata_profile_list
+        .set_items(vec!["Standard".to_owned()]);
+      add_author_modal
+        .root_folder_list
+        .set_items(vec![RootFolder {
+          path: "/nfs/music".to_owned(),
+          ..RootFolder::default()
+        }]);
+      app.data.readarr_data.add_author_modal = Some(add_author_modal);
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        AddAuthorUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(format!("add_author_modal_{active_readarr_block}"), output);
+    }
+
+    #[test]
+    fn test_add_author_already_in_library_ui_renders() {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(ActiveReadarrBlock::AddAuthorAlreadyInLibrary.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        AddAuthorUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(output);
+    }
+  }
+}
@@ -0,0 +1,314 @@
+use chrono::Utc;
+use deunicode::deunicode;
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::Stylize;
+use ratatui::text::Line;
+use ratatui::widgets::{Cell, Paragraph, Row, Wrap};
+use regex::Regex;
+
+use crate::app::App;
+use crate::models::Route;
+use crate::models::readarr_models::Book;
+use crate::models::servarr_data::readarr::readarr_data::{
+  AUTHOR_DETAILS_BLOCKS, ActiveReadarrBlock,
+};
+use crate::ui::readarr_ui::library::book_details_ui::BookDetailsUi;
+use crate::ui::styles::ManagarrStyle;
+use crate::ui::utils::{
+  borderless_block, get_width_from_percentage, layout_block_top_border, title_block,
+};
+use crate::ui::widgets::confirmation_prompt::ConfirmationPrompt;
+use crate::ui::widgets::managarr_table::ManagarrTable;
+use crate::ui::widgets::popup::{Popup, Size};
+use crate::ui::{DrawUi, draw_popup};
+use crate::utils::convert_to_gb;
+
+#[cfg(test)]
+#[path = "author_details_ui_tests.rs"]
+mod author_details_ui_tests;
+
+pub(super) struct AuthorDetailsUi;
+
+impl DrawUi for AuthorDetailsUi {
+  fn accepts(route: Route) -> bool {
+    let Route::Readarr(active_readarr_block, _) = route else {
+      return false;
+    };
+    BookDetailsUi::accepts(route) || AUTHOR_DETAILS_BLOCKS.contains(&active_readarr_block)
+  }
+
+  fn draw(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+    let route = app.get_current_route();
+    if let Route::Readarr(active_readarr_block, _) = route {
+      let draw_author_details_popup = |f: &mut Frame<'_>, app: &mut App<'_>, popup_area: Rect| {
+        f.render_widget(
+          title_block(
+            &app
+              .data
+              .readarr_data
+              .authors
+              .current_selection()
+              .author_name
+              .text,
+          ),
+          popup_area,
+        );
+        let [description_area, books_area] =
+          Layout::vertical([Constraint::Length(12), Constraint::Fill(0)])
+            .margin(1)
+            .areas(popup_area);
+        draw_author_description(f, app, description_area);
+        draw_books_table(f, app, books_area);
+
+        match active_readarr_block {
+          ActiveReadarrBlock::AutomaticallySearchAuthorPrompt => {
+            let prompt = format!(
+              "Do you want to trigger an automatic search of your indexers for all monitored book(s) for the author: {}?",
+              app
+                .data
+                .readarr_data
+                .authors
+                .current_selection()
+                .author_name
+            );
+            let confirmation_prompt = ConfirmationPrompt::new()
+              .title("Automatic Author Search")
+              .prompt(&prompt)
+              .yes_no_value(app.data.readarr_data.prompt_confirm);
+
+            f.render_widget(
+              Popup::new(confirmation_prompt).size(Size::MediumPrompt),
+              f.area(),
+            );
+          }
+          ActiveReadarrBlock::UpdateAndScanAuthorPrompt => {
+            let prompt = format!(
+              "Do you want to trigger an update and disk scan for the author: {}?",
+              app
+                .data
+                .readarr_data
+                .authors
+                .current_selection()
+                .author_name
+            );
+            let confirmation_prompt = ConfirmationPrompt::new()
+              .title("Update and Scan")
+              .prompt(&prompt)
+              .yes_no_value(app.data.readarr_data.prompt_confirm);
+
+            f.render_widget(
+              Popup::new(confirmation_prompt).size(Size::MediumPrompt),
+              f.area(),
+            );
+          }
+          _ => (),
+        }
+      };
+
+      draw_popup(f, app, draw_author_details_popup, Size::XXLarge);
+
+      if BookDetailsUi::accepts(route) {
+        BookDetailsUi::draw(f, app, area);
+      }
+    }
+  }
+}
+
+fn draw_author_description(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+  let current_selection = app.data.readarr_data.authors.current_selection();
+  let monitored = if current_selection.monitored {
+    "Yes"
+  } else {
+    "No"
+  };
+  let quality_profile = app
+    .data
+    .readarr_data
+    .quality_profile_map
+    .get_by_left(&current_selection.quality_profile_id)
+    .cloned()
+    .unwrap_or_default();
+  let metadata_profile = app
+    .data
+    .readarr_data
+    .metadata_profile_map
+    .get_by_left(&current_selection.metadata_profile_id)
+    .cloned()
+    .unwrap_or_default();
+  let overview = Regex::new(r"[\r\n\t]")
+    .unwrap()
+    .replace_all(
+      &deunicode(
+        current_selection
+          .overview
+          .as_ref()
+          .unwrap_or(&String::new()),
+      ),
+      "",
+    )
+    .to_string();
+
+  let mut author_description = vec![
+    Line::from(vec![
+      "Author: ".primary().bold(),
+      current_selection.author_name.text.clone().primary().bold(),
+    ]),
+    Line::from(vec![
+      "Overview: ".primary().bold(),
+      overview.default_color(),
+    ]),
+    Line::from(vec![
+      "Disambiguation: ".primary().bold(),
+      current_selection
+        .disambiguation
+        .clone()
+        .unwrap_or_default()
+        .default_color(),
+    ]),
+    Line::from(vec![
+      "Status: ".primary().bold(),
+      current_selection.status.to_display_str().default_color(),
+    ]),
+    Line::from(vec![
+      "Genres: ".primary().bold(),
+      current_selection.genres.join(", ").default_color(),
+    ]),
+    Line::from(vec![
+      "Rating: ".primary().bold(),
+      current_selection
+        .ratings
+        .as_ref()
+        .map_or_else(
+          || "N/A".to_owned(),
+          |r| format!("{}%", (r.value * 10.0) as i32),
+        )
+        .default_color(),
+    ]),
+    Line::from(vec![
+      "Path: ".primary().bold(),
+      current_selection.path.clone().default_color(),
+    ]),
+    Line::from(vec![
+      "Quality Profile: ".primary().bold(),
+      quality_profile.default_color(),
+    ]),
+    Line::from(vec![
+      "Metadata Profile: ".primary().bold(),
+      metadata_profile.default_color(),
+    ]),
+    Line::from(vec![
+      "Monitored: ".primary().bold(),
+      monitored.default_color(),
+    ]),
+  ];
+
+  if let Some(stats) = current_selection.statistics.as_ref() {
+    let size = convert_to_gb(stats.size_on_disk);
+    author_description.extend(vec![
+      Line::from(vec![
+        "Books: ".primary().bold(),
+        format!("{}/{}", stats.book_file_count, stats.book_count).default_color(),
+      ]),
+      Line::from(vec![
+        "Size on Disk: ".primary().bold(),
+        format!("{size:.2} GB").default_color(),
+      ]),
+    ]);
+  }
+
+  let description_paragraph = Paragraph::new(author_description)
+    .block(borderless_block())
+    .wrap(Wrap { trim: true });
+  f.render_widget(description_paragraph, area);
+}
+
+fn draw_books_table(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+  if let Route::Readarr(active_readarr_block, _) = app.get_current_route() {
+    let current_selection = if app.data.readarr_data.books.is_empty() {
+      Book::default()
+    } else {
+      app.data.readarr_data.books.current_selection().clone()
+    };
+    let content = Some(&mut app.data.readarr_data.books);
+    let book_row_mapping = |book: &Book| {
+      book.title.scroll_left_or_reset(
+        get_width_from_percentage(area, 33),
+        *book == current_selection,
+        app.ui_scroll_tick_count == 0,
+      );
+      let monitored = if book.monitored { "🏷" } else { "" };
+      let series = book.series_title.clone().unwrap_or_default();
+      let release_date = book
+        .release_date
+        .map_or_else(|| "N/A".to_owned(), |d| d.format("%Y-%m-%d").to_string());
+      let book_files = book.statistics.as_ref().map_or_else(
+        || "0/0".to_owned(),
+        |s| format!("{}/{}", s.book_file_count, s.book_count),
+      );
+      let size = book
+        .statistics
+        .as_ref()
+        .map_or(0f64, |s| convert_to_gb(s.size_on_disk));
+
+      let row = Row::new(vec![
+        Cell::from(monitored.to_owned()),
+        Cell::from(book.title.to_string()),
+        Cell::from(series),
+        Cell::from(book.page_count.to_string()),
+        Cell::from(book_files),
+        Cell::from(release_date),
+        Cell::from(format!("{size:.2} GB")),
+      ]);
+
+      if !book.monitored {
+        row.unmonitored()
+      } else if let Some(stats) = book.statistics.as_ref() {
+        if stats.book_file_count == stats.book_count && stats.book_count > 0 {
+          row.downloaded()
+        } else if let Some(release_date) = book.release_date.as_ref() {
+          if release_date > &Utc::now() {
+            row.unreleased()
+          } else {
+            row.missing()
+          }
+        } else {
+          row.missing()
+        }
+      } else {
+        row.indeterminate()
+      }
+    };
+
+    let is_searching = active_readarr_block == ActiveReadarrBlock::SearchBooks;
+    let book_table = ManagarrTable::new(content, book_row_mapping)
+      .block(layout_block_top_border())
+      .loading(app.is_loading)
+      .searching(is_searching)
+      .search_produced_empty_results(active_readarr_block == ActiveReadarrBlock::SearchBooksError)
+      .headers([
+        "Monitored",
+        "Title",
+        "Series",
+        "Pages",
+        "Files",
+        "Release Date",
+        "Size",
+      ])
+      .constraints([
+        Constraint::Percentage(7),
+        Constraint::Percentage(33),
+        Constraint::Percentage(20),
+        Constraint::Percentage(7),
+        Constraint::Percentage(7),
+        Constraint::Percentage(13),
+        Constraint::Percentage(13),
+      ]);
+
+    if is_searching {
+      book_table.show_cursor(f, area);
+    }
+
+    f.render_widget(book_table, area);
+  }
+}
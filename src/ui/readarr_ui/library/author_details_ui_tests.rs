@@ -0,0 +1,95 @@
+#[cfg(test)]
+mod tests {
+  use strum::IntoEnumIterator;
+
+  use crate::models::servarr_data::readarr::readarr_data::{
+    AUTHOR_DETAILS_BLOCKS, ActiveReadarrBlock, BOOK_DETAILS_BLOCKS,
+  };
+  use crate::ui::DrawUi;
+  use crate::ui::readarr_ui::library::author_details_ui::AuthorDetailsUi;
+
+  #[test]
+  fn test_author_details_ui_accepts() {
+    let mut blocks = AUTHOR_DETAILS_BLOCKS.clone().to_vec();
+    blocks.extend(BOOK_DETAILS_BLOCKS);
+
+    ActiveReadarrBlock::iter().for_each(|active_readarr_block| {
+      if blocks.contains(&active_readarr_block) {
+        assert!(AuthorDetailsUi::accepts(active_readarr_block.into()));
+      } else {
+        assert!(!AuthorDetailsUi::accepts(active_readarr_block.into()));
+      }
+    });
+  }
+
+  mod snapshot_tests {
+    use rstest::rstest;
+
+    use crate::app::App;
+    use crate::models::servarr_data::readarr::readarr_data::ActiveReadarrBlock;
+    use crate::models::stateful_table::StatefulTable;
+    use crate::ui::DrawUi;
+    use crate::ui::readarr_ui::library::author_details_ui::AuthorDetailsUi;
+    use crate::ui::ui_test_utils::test_utils::{TerminalSize, render_to_string_with_app};
+
+    #[rstest]
+    fn test_author_details_ui_renders(
+      #[values(
+        ActiveReadarrBlock::AuthorDetails,
+        ActiveReadarrBlock::SearchBooks,
+        ActiveReadarrBlock::SearchBooksError,
+        ActiveReadarrBlock::UpdateAndScanAuthorPrompt,
+        ActiveReadarrBlock::AutomaticallySearchAuthorPrompt
+      )]
+      active_readarr_block: ActiveReadarrBlock,
+    ) {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(active_readarr_block.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        AuthorDetailsUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(format!("author_details_{active_readarr_block}"), output);
+    }
+
+    #[test]
+    fn test_author_details_ui_renders_author_details_loading() {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(ActiveReadarrBlock::AuthorDetails.into());
+      app.is_loading = true;
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        AuthorDetailsUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(output);
+    }
+
+    #[test]
+    fn test_author_details_ui_renders_author_details_empty() {
+      let mut app = App::test_default_fully_populated();
+      app.data.readarr_data.books = StatefulTable::default();
+      app.push_navigation_stack(ActiveReadarrBlock::AuthorDetails.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        AuthorDetailsUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(output);
+    }
+
+    #[test]
+    fn test_author_details_ui_renders_book_details_over_author_details() {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(ActiveReadarrBlock::AuthorDetails.into());
+      app.push_navigation_stack(ActiveReadarrBlock::BookDetails.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        AuthorDetailsUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(output);
+    }
+  }
+}
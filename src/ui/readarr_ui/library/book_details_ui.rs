@@ -0,0 +1,139 @@
+use crate::app::App;
+use crate::models::Route;
+use crate::models::readarr_models::Edition;
+use crate::models::servarr_data::readarr::readarr_data::{ActiveReadarrBlock, BOOK_DETAILS_BLOCKS};
+use crate::ui::styles::ManagarrStyle;
+use crate::ui::utils::title_block;
+use crate::ui::widgets::confirmation_prompt::ConfirmationPrompt;
+use crate::ui::widgets::managarr_table::ManagarrTable;
+use crate::ui::widgets::popup::{Popup, Size};
+use crate::ui::{DrawUi, draw_popup};
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Rect};
+use ratatui::widgets::{Cell, Row};
+
+#[cfg(test)]
+#[path = "book_details_ui_tests.rs"]
+mod book_details_ui_tests;
+
+pub(super) struct BookDetailsUi;
+
+impl DrawUi for BookDetailsUi {
+  fn accepts(route: Route) -> bool {
+    let Route::Readarr(active_readarr_block, _) = route else {
+      return false;
+    };
+    BOOK_DETAILS_BLOCKS.contains(&active_readarr_block)
+  }
+
+  fn draw(f: &mut Frame<'_>, app: &mut App<'_>, _area: Rect) {
+    if app.data.readarr_data.book_details_modal.is_some()
+      && let Route::Readarr(active_readarr_block, _) = app.get_current_route()
+    {
+      let draw_book_details_popup = |f: &mut Frame<'_>, app: &mut App<'_>, popup_area: Rect| {
+        draw_editions_table(f, app, popup_area);
+
+        if active_readarr_block == ActiveReadarrBlock::AutomaticallySearchBookPrompt {
+          let prompt = format!(
+            "Do you want to trigger an automatic search of your indexers for the book: {}?",
+            app.data.readarr_data.books.current_selection().title.text
+          );
+          let confirmation_prompt = ConfirmationPrompt::new()
+            .title("Automatic Book Search")
+            .prompt(&prompt)
+            .yes_no_value(app.data.readarr_data.prompt_confirm);
+
+          f.render_widget(
+            Popup::new(confirmation_prompt).size(Size::MediumPrompt),
+            f.area(),
+          );
+        }
+      };
+
+      draw_popup(f, app, draw_book_details_popup, Size::XLarge);
+    }
+  }
+}
+
+fn draw_editions_table(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+  if let Route::Readarr(active_readarr_block, _) = app.get_current_route() {
+    let title = format!(
+      "{} Editions",
+      app.data.readarr_data.books.current_selection().title.text
+    );
+    let content = Some(
+      &mut app
+        .data
+        .readarr_data
+        .book_details_modal
+        .as_mut()
+        .expect("Book details modal is unpopulated")
+        .editions,
+    );
+
+    let edition_row_mapping = |edition: &Edition| {
+      let Edition {
+        title,
+        format,
+        publisher,
+        language,
+        page_count,
+        isbn13,
+        asin,
+        monitored,
+        ..
+      } = edition;
+      let monitored_str = if *monitored { "🏷" } else { "" };
+      let identifier = isbn13.clone().or_else(|| asin.clone()).unwrap_or_default();
+
+      let row = Row::new(vec![
+        Cell::from(monitored_str),
+        Cell::from(title.clone()),
+        Cell::from(format.clone().unwrap_or_default()),
+        Cell::from(publisher.clone().unwrap_or_default()),
+        Cell::from(language.clone().unwrap_or_default()),
+        Cell::from(page_count.to_string()),
+        Cell::from(identifier),
+      ]);
+
+      if *monitored {
+        row.primary()
+      } else {
+        row.unmonitored()
+      }
+    };
+
+    let is_searching = active_readarr_block == ActiveReadarrBlock::SearchEditions;
+    let editions_table = ManagarrTable::new(content, edition_row_mapping)
+      .block(title_block(&title))
+      .loading(app.is_loading)
+      .searching(is_searching)
+      .search_produced_empty_results(
+        active_readarr_block == ActiveReadarrBlock::SearchEditionsError,
+      )
+      .headers([
+        "Monitored",
+        "Title",
+        "Format",
+        "Publisher",
+        "Language",
+        "Pages",
+        "ISBN/ASIN",
+      ])
+      .constraints([
+        Constraint::Percentage(8),
+        Constraint::Percentage(30),
+        Constraint::Percentage(10),
+        Constraint::Percentage(18),
+        Constraint::Percentage(10),
+        Constraint::Percentage(7),
+        Constraint::Percentage(17),
+      ]);
+
+    if is_searching {
+      editions_table.show_cursor(f, area);
+    }
+
+    f.render_widget(editions_table, area);
+  }
+}
@@ -0,0 +1,80 @@
+#[cfg(test)]
+mod tests {
+  use strum::IntoEnumIterator;
+
+  use crate::app::App;
+  use crate::models::servarr_data::readarr::readarr_data::{
+    ActiveReadarrBlock, BOOK_DETAILS_BLOCKS,
+  };
+  use crate::ui::DrawUi;
+  use crate::ui::readarr_ui::library::book_details_ui::BookDetailsUi;
+  use crate::ui::ui_test_utils::test_utils::render_to_string_with_app;
+
+  #[test]
+  fn test_book_details_ui_accepts() {
+    ActiveReadarrBlock::iter().for_each(|active_readarr_block| {
+      if BOOK_DETAILS_BLOCKS.contains(&active_readarr_block) {
+        assert!(BookDetailsUi::accepts(active_readarr_block.into()));
+      } else {
+        assert!(!BookDetailsUi::accepts(active_readarr_block.into()));
+      }
+    });
+  }
+
+  mod snapshot_tests {
+    use crate::models::servarr_data::readarr::modals::BookDetailsModal;
+    use crate::ui::ui_test_utils::test_utils::TerminalSize;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_book_details_ui_renders(
+      #[values(
+        ActiveReadarrBlock::BookDetails,
+        ActiveReadarrBlock::SearchEditions,
+        ActiveReadarrBlock::SearchEditionsError,
+        ActiveReadarrBlock::AutomaticallySearchBookPrompt
+      )]
+      active_readarr_block: ActiveReadarrBlock,
+    ) {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(active_readarr_block.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        BookDetailsUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(
+        format!("book_details_renders_{active_readarr_block}"),
+        output
+      );
+    }
+
+    #[test]
+    fn test_book_details_ui_renders_loading() {
+      let mut app = App::test_default_fully_populated();
+      app.is_loading = true;
+      app.push_navigation_stack(ActiveReadarrBlock::BookDetails.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        BookDetailsUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(output);
+    }
+
+    #[test]
+    fn test_book_details_ui_renders_empty() {
+      let mut app = App::test_default_fully_populated();
+      app.data.readarr_data.book_details_modal = Some(BookDetailsModal::default());
+      app.push_navigation_stack(ActiveReadarrBlock::BookDetails.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        BookDetailsUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(output);
+    }
+  }
+}
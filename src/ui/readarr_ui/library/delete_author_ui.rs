@@ -0,0 +1,65 @@
+use ratatui::Frame;
+use ratatui::layout::Rect;
+
+use crate::app::App;
+use crate::models::Route;
+use crate::models::servarr_data::readarr::readarr_data::{
+  ActiveReadarrBlock, DELETE_AUTHOR_BLOCKS,
+};
+use crate::ui::DrawUi;
+use crate::ui::widgets::checkbox::Checkbox;
+use crate::ui::widgets::confirmation_prompt::ConfirmationPrompt;
+use crate::ui::widgets::popup::{Popup, Size};
+
+#[cfg(test)]
+#[path = "delete_author_ui_tests.rs"]
+mod delete_author_ui_tests;
+
+pub(in crate::ui::readarr_ui) struct DeleteAuthorUi;
+
+impl DrawUi for DeleteAuthorUi {
+  fn accepts(route: Route) -> bool {
+    let Route::Readarr(active_readarr_block, _) = route else {
+      return false;
+    };
+    DELETE_AUTHOR_BLOCKS.contains(&active_readarr_block)
+  }
+
+  fn draw(f: &mut Frame<'_>, app: &mut App<'_>, _area: Rect) {
+    if matches!(
+      app.get_current_route(),
+      Route::Readarr(ActiveReadarrBlock::DeleteAuthorPrompt, _)
+    ) {
+      let selected_block = app.data.readarr_data.selected_block.get_active_block();
+      let prompt = format!(
+        "Do you really want to delete the author: \n{}?",
+        app
+          .data
+          .readarr_data
+          .authors
+          .current_selection()
+          .author_name
+          .text
+      );
+      let checkboxes = vec![
+        Checkbox::new("Delete Author Files")
+          .checked(app.data.readarr_data.delete_files)
+          .highlighted(selected_block == ActiveReadarrBlock::DeleteAuthorToggleDeleteFile),
+        Checkbox::new("Add List Exclusion")
+          .checked(app.data.readarr_data.add_import_list_exclusion)
+          .highlighted(selected_block == ActiveReadarrBlock::DeleteAuthorToggleAddListExclusion),
+      ];
+      let confirmation_prompt = ConfirmationPrompt::new()
+        .title("Delete Author")
+        .prompt(&prompt)
+        .checkboxes(checkboxes)
+        .yes_no_highlighted(selected_block == ActiveReadarrBlock::DeleteAuthorConfirmPrompt)
+        .yes_no_value(app.data.readarr_data.prompt_confirm);
+
+      f.render_widget(
+        Popup::new(confirmation_prompt).size(Size::MediumPrompt),
+        f.area(),
+      );
+    }
+  }
+}
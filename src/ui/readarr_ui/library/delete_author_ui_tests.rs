@@ -0,0 +1,44 @@
+#[cfg(test)]
+mod tests {
+  use strum::IntoEnumIterator;
+
+  use crate::app::App;
+  use crate::models::BlockSelectionState;
+  use crate::models::servarr_data::readarr::readarr_data::{
+    ActiveReadarrBlock, DELETE_AUTHOR_BLOCKS, DELETE_AUTHOR_SELECTION_BLOCKS,
+  };
+  use crate::ui::DrawUi;
+  use crate::ui::readarr_ui::library::delete_author_ui::DeleteAuthorUi;
+  use crate::ui::ui_test_utils::test_utils::render_to_string_with_app;
+
+  #[test]
+  fn test_delete_author_ui_accepts() {
+    ActiveReadarrBlock::iter().for_each(|active_readarr_block| {
+      if DELETE_AUTHOR_BLOCKS.contains(&active_readarr_block) {
+        assert!(DeleteAuthorUi::accepts(active_readarr_block.into()));
+      } else {
+        assert!(!DeleteAuthorUi::accepts(active_readarr_block.into()));
+      }
+    });
+  }
+
+  mod snapshot_tests {
+    use crate::ui::ui_test_utils::test_utils::TerminalSize;
+
+    use super::*;
+
+    #[test]
+    fn test_delete_author_ui_renders_delete_author() {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(ActiveReadarrBlock::DeleteAuthorPrompt.into());
+      app.data.readarr_data.selected_block =
+        BlockSelectionState::new(DELETE_AUTHOR_SELECTION_BLOCKS);
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        DeleteAuthorUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(output);
+    }
+  }
+}
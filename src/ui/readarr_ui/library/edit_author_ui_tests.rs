@@ -0,0 +1,49 @@
+#[cfg(test)]
+mod tests {
+  use strum::IntoEnumIterator;
+
+  use crate::app::App;
+  use crate::models::BlockSelectionState;
+  use crate::models::servarr_data::readarr::readarr_data::{
+    ActiveReadarrBlock, EDIT_AUTHOR_BLOCKS, EDIT_AUTHOR_SELECTION_BLOCKS,
+  };
+  use crate::ui::DrawUi;
+  use crate::ui::readarr_ui::library::edit_author_ui::EditAuthorUi;
+  use crate::ui::ui_test_utils::test_utils::render_to_string_with_app;
+
+  #[test]
+  fn test_edit_author_ui_accepts() {
+    ActiveReadarrBlock::iter().for_each(|active_readarr_block| {
+      if EDIT_AUTHOR_BLOCKS.contains(&active_readarr_block) {
+        assert!(EditAuthorUi::accepts(active_readarr_block.into()));
+      } else {
+        assert!(!EditAuthorUi::accepts(active_readarr_block.into()));
+      }
+    });
+  }
+
+  mod snapshot_tests {
+    use crate::ui::ui_test_utils::test_utils::TerminalSize;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(ActiveReadarrBlock::EditAuthorPrompt)]
+    #[case(ActiveReadarrBlock::EditAuthorConfirmPrompt)]
+    #[case(ActiveReadarrBlock::EditAuthorSelectMetadataProfile)]
+    #[case(ActiveReadarrBlock::EditAuthorSelectMonitorNewItems)]
+    #[case(ActiveReadarrBlock::EditAuthorSelectQualityProfile)]
+    fn test_edit_author_ui_renders(#[case] active_readarr_block: ActiveReadarrBlock) {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(active_readarr_block.into());
+      app.data.readarr_data.selected_block = BlockSelectionState::new(EDIT_AUTHOR_SELECTION_BLOCKS);
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        EditAuthorUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(format!("edit_author_{active_readarr_block}"), output);
+    }
+  }
+}
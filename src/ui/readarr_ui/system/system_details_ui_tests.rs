@@ -0,0 +1,106 @@
+#[cfg(test)]
+mod tests {
+  use strum::IntoEnumIterator;
+
+  use crate::app::App;
+  use crate::models::servarr_data::readarr::readarr_data::{
+    ActiveReadarrBlock, SYSTEM_DETAILS_BLOCKS,
+  };
+
+  use crate::ui::DrawUi;
+  use crate::ui::readarr_ui::system::system_details_ui::SystemDetailsUi;
+  use crate::ui::ui_test_utils::test_utils::render_to_string_with_app;
+
+  #[test]
+  fn test_system_details_ui_accepts() {
+    ActiveReadarrBlock::iter().for_each(|active_readarr_block| {
+      if SYSTEM_DETAILS_BLOCKS.contains(&active_readarr_block) {
+        assert!(SystemDetailsUi::accepts(active_readarr_block.into()));
+      } else {
+        assert!(!SystemDetailsUi::accepts(active_readarr_block.into()));
+      }
+    });
+  }
+
+  mod snapshot_tests {
+    use super::*;
+    use crate::models::ScrollableText;
+    use crate::ui::ui_test_utils::test_utils::TerminalSize;
+    use rstest::rstest;
+
+    #[rstest]
+    fn test_system_details_ui_popups(
+      #[values(
+        ActiveReadarrBlock::SystemLogs,
+        ActiveReadarrBlock::SystemQueuedEvents,
+        ActiveReadarrBlock::SystemTasks,
+        ActiveReadarrBlock::SystemTaskStartConfirmPrompt,
+        ActiveReadarrBlock::SystemUpdates
+      )]
+      active_readarr_block: ActiveReadarrBlock,
+    ) {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(active_readarr_block.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        SystemDetailsUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(format!("popup_{active_readarr_block}"), output);
+    }
+
+    #[rstest]
+    fn test_system_details_ui_loading(
+      #[values(
+        ActiveReadarrBlock::SystemLogs,
+        ActiveReadarrBlock::SystemQueuedEvents,
+        ActiveReadarrBlock::SystemTasks,
+        ActiveReadarrBlock::SystemUpdates
+      )]
+      active_readarr_block: ActiveReadarrBlock,
+    ) {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.push_navigation_stack(active_readarr_block.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        SystemDetailsUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(format!("loading_{active_readarr_block}"), output);
+    }
+
+    #[test]
+    fn test_system_details_ui_updates_popup_loading_when_empty() {
+      let mut app = App::test_default_fully_populated();
+      app.is_loading = true;
+      app.push_navigation_stack(ActiveReadarrBlock::SystemUpdates.into());
+      app.data.readarr_data.updates = ScrollableText::with_string("".to_string());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        SystemDetailsUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(output);
+    }
+
+    #[rstest]
+    fn test_system_details_ui_popups_empty(
+      #[values(
+        ActiveReadarrBlock::SystemLogs,
+        ActiveReadarrBlock::SystemQueuedEvents,
+        ActiveReadarrBlock::SystemTasks
+      )]
+      active_readarr_block: ActiveReadarrBlock,
+    ) {
+      let mut app = App::test_default();
+      app.push_navigation_stack(active_readarr_block.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        SystemDetailsUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(format!("empty_{active_readarr_block}"), output);
+    }
+  }
+}
@@ -0,0 +1,74 @@
+#[cfg(test)]
+mod tests {
+  use strum::IntoEnumIterator;
+
+  use crate::app::App;
+  use crate::models::servarr_data::sonarr::sonarr_data::{ActiveSonarrBlock, CALENDAR_BLOCKS};
+  use crate::ui::DrawUi;
+  use crate::ui::sonarr_ui::calendar::CalendarUi;
+  use crate::ui::ui_test_utils::test_utils::render_to_string_with_app;
+
+  #[test]
+  fn test_calendar_ui_accepts() {
+    ActiveSonarrBlock::iter().for_each(|active_sonarr_block| {
+      if CALENDAR_BLOCKS.contains(&active_sonarr_block) {
+        assert!(CalendarUi::accepts(active_sonarr_block.into()));
+      } else {
+        assert!(!CalendarUi::accepts(active_sonarr_block.into()));
+      }
+    });
+  }
+
+  mod snapshot_tests {
+    use crate::ui::ui_test_utils::test_utils::TerminalSize;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_calendar_ui_renders_loading(
+      #[values(ActiveSonarrBlock::Calendar, ActiveSonarrBlock::CalendarAgenda)]
+      active_sonarr_block: ActiveSonarrBlock,
+    ) {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.push_navigation_stack(active_sonarr_block.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        CalendarUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(format!("loading_{active_sonarr_block}"), output);
+    }
+
+    #[rstest]
+    fn test_calendar_ui_renders_empty_calendar(
+      #[values(ActiveSonarrBlock::Calendar, ActiveSonarrBlock::CalendarAgenda)]
+      active_sonarr_block: ActiveSonarrBlock,
+    ) {
+      let mut app = App::test_default();
+      app.push_navigation_stack(active_sonarr_block.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        CalendarUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(format!("empty_{active_sonarr_block}"), output);
+    }
+
+    #[rstest]
+    fn test_calendar_ui_renders_calendar_tab(
+      #[values(ActiveSonarrBlock::Calendar, ActiveSonarrBlock::CalendarAgenda)]
+      active_sonarr_block: ActiveSonarrBlock,
+    ) {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(active_sonarr_block.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        CalendarUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(active_sonarr_block.to_string(), output);
+    }
+  }
+}
@@ -0,0 +1,153 @@
+use std::collections::HashMap;
+
+#[cfg(test)]
+use crate::ui::ui_test_utils::test_utils::Utc;
+#[cfg(not(test))]
+use chrono::Utc;
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Rect};
+use ratatui::style::Styled;
+use ratatui::text::Line;
+use ratatui::widgets::{Cell, Row};
+
+use crate::app::App;
+use crate::models::Route;
+use crate::models::servarr_data::sonarr::sonarr_data::{ActiveSonarrBlock, CALENDAR_BLOCKS};
+use crate::models::sonarr_models::Episode;
+use crate::ui::DrawUi;
+use crate::ui::styles::ManagarrStyle;
+use crate::ui::utils::layout_block_top_border;
+use crate::ui::widgets::calendar_week::CalendarWeek;
+use crate::ui::widgets::managarr_table::ManagarrTable;
+use crate::utils::calendar_range;
+
+#[cfg(test)]
+#[path = "calendar_ui_tests.rs"]
+mod calendar_ui_tests;
+
+pub(super) struct CalendarUi;
+
+impl DrawUi for CalendarUi {
+  fn accepts(route: Route) -> bool {
+    if let Route::Sonarr(active_sonarr_block, _) = route {
+      return CALENDAR_BLOCKS.contains(&active_sonarr_block);
+    }
+
+    false
+  }
+
+  fn draw(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+    if let Route::Sonarr(active_sonarr_block, _) = app.get_current_route() {
+      match active_sonarr_block {
+        ActiveSonarrBlock::CalendarAgenda => draw_calendar_agenda(f, app, area),
+        _ => draw_calendar_week(f, app, area),
+      }
+    }
+  }
+}
+
+fn draw_calendar_week(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+  let series_titles = series_titles(app);
+  let entries = app
+    .data
+    .sonarr_data
+    .calendar
+    .items
+    .iter()
+    .filter_map(|episode| {
+      let air_date = episode.air_date_utc?;
+      let line = Line::from(format!(
+        "{} {} {}",
+        air_date.format("%H:%M"),
+        series_titles
+          .get(&episode.series_id)
+          .map_or("", String::as_str),
+        episode_number(episode)
+      ));
+
+      Some((
+        air_date.date_naive(),
+        decorate_calendar_entry(episode, line),
+      ))
+    })
+    .collect();
+  let start = app
+    .data
+    .sonarr_data
+    .calendar_start
+    .unwrap_or_else(|| calendar_range(Utc::now().date_naive()).0);
+  let calendar_week = CalendarWeek::new(start, entries).loading(app.is_loading);
+
+  f.render_widget(calendar_week, area);
+}
+
+fn draw_calendar_agenda(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+  let series_titles = series_titles(app);
+  let calendar_row_mapping = |episode: &Episode| {
+    let Episode {
+      series_id,
+      title,
+      air_date_utc,
+      ..
+    } = episode;
+    let air_date = air_date_utc.map_or_else(String::new, |date| {
+      date.format("%Y-%m-%d %H:%M").to_string()
+    });
+
+    let row = Row::new(vec![
+      Cell::from(air_date),
+      Cell::from(series_titles.get(series_id).cloned().unwrap_or_default()),
+      Cell::from(episode_number(episode)),
+      Cell::from(title.clone()),
+    ]);
+
+    decorate_calendar_entry(episode, row)
+  };
+  let calendar_table = ManagarrTable::new(
+    Some(&mut app.data.sonarr_data.calendar),
+    calendar_row_mapping,
+  )
+  .block(layout_block_top_border())
+  .loading(app.is_loading)
+  .headers(["Air Date", "Series", "Episode", "Title"])
+  .constraints([
+    Constraint::Percentage(20),
+    Constraint::Percentage(35),
+    Constraint::Percentage(10),
+    Constraint::Percentage(35),
+  ]);
+
+  f.render_widget(calendar_table, area);
+}
+
+fn series_titles(app: &App<'_>) -> HashMap<i64, String> {
+  app
+    .data
+    .sonarr_data
+    .series
+    .items
+    .iter()
+    .map(|series| (series.id, series.title.text.clone()))
+    .collect()
+}
+
+fn episode_number(episode: &Episode) -> String {
+  format!(
+    "S{:02}E{:02}",
+    episode.season_number, episode.episode_number
+  )
+}
+
+fn decorate_calendar_entry<T: Styled<Item = T>>(episode: &Episode, entry: T) -> T {
+  if episode.has_file {
+    return entry.downloaded();
+  }
+
+  if let Some(air_date) = episode.air_date_utc.as_ref()
+    && air_date > &Utc::now()
+  {
+    return entry.unreleased();
+  }
+
+  entry.missing()
+}
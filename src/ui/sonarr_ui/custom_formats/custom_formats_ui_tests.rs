@@ -0,0 +1,82 @@
+#[cfg(test)]
+mod tests {
+  use strum::IntoEnumIterator;
+
+  use crate::app::App;
+  use crate::models::servarr_data::sonarr::sonarr_data::{
+    ActiveSonarrBlock, CUSTOM_FORMAT_FORM_BLOCKS, CUSTOM_FORMATS_BLOCKS,
+  };
+  use crate::models::stateful_table::StatefulTable;
+  use crate::ui::DrawUi;
+  use crate::ui::sonarr_ui::custom_formats::CustomFormatsUi;
+  use crate::ui::ui_test_utils::test_utils::{TerminalSize, render_to_string_with_app};
+
+  #[test]
+  fn test_custom_formats_ui_accepts() {
+    let mut custom_formats_blocks = Vec::new();
+    custom_formats_blocks.extend(CUSTOM_FORMATS_BLOCKS);
+    custom_formats_blocks.extend(CUSTOM_FORMAT_FORM_BLOCKS);
+
+    ActiveSonarrBlock::iter().for_each(|active_sonarr_block| {
+      if custom_formats_blocks.contains(&active_sonarr_block) {
+        assert!(CustomFormatsUi::accepts(active_sonarr_block.into()));
+      } else {
+        assert!(!CustomFormatsUi::accepts(active_sonarr_block.into()));
+      }
+    });
+  }
+
+  mod snapshot_tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[test]
+    fn test_custom_formats_ui_renders_custom_formats_tab_loading() {
+      let mut app = App::test_default_fully_populated();
+      app.is_loading = true;
+      app.push_navigation_stack(ActiveSonarrBlock::CustomFormats.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        CustomFormatsUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(output);
+    }
+
+    #[test]
+    fn test_custom_formats_ui_renders_custom_formats_tab_empty_custom_formats() {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(ActiveSonarrBlock::CustomFormats.into());
+      app.data.sonarr_data.custom_formats = StatefulTable::default();
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        CustomFormatsUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(output);
+    }
+
+    #[rstest]
+    fn test_custom_formats_ui_renders(
+      #[values(
+        ActiveSonarrBlock::CustomFormats,
+        ActiveSonarrBlock::CustomFormatDetails,
+        ActiveSonarrBlock::DeleteCustomFormatPrompt,
+        ActiveSonarrBlock::ImportCustomFormatPrompt,
+        ActiveSonarrBlock::ExportCustomFormatPrompt,
+        ActiveSonarrBlock::EditCustomFormatPrompt,
+        ActiveSonarrBlock::CustomFormatFieldInput
+      )]
+      active_sonarr_block: ActiveSonarrBlock,
+    ) {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(active_sonarr_block.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        CustomFormatsUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(format!("custom_formats_ui_{active_sonarr_block}"), output);
+    }
+  }
+}
@@ -3,6 +3,7 @@ use crate::models::Route;
 use crate::models::servarr_data::sonarr::sonarr_data::{ActiveSonarrBlock, HISTORY_BLOCKS};
 use crate::models::servarr_models::Language;
 use crate::models::sonarr_models::SonarrHistoryItem;
+use crate::models::table_columns::{HISTORY_TABLE_COLUMNS, table_headers};
 use crate::ui::DrawUi;
 use crate::ui::sonarr_ui::sonarr_ui_utils::create_history_event_details;
 use crate::ui::styles::{ManagarrStyle, secondary_style};
@@ -94,7 +95,7 @@ fn draw_history_table(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
         .search_produced_empty_results(active_sonarr_block == ActiveSonarrBlock::SearchHistoryError)
         .filtering(active_sonarr_block == ActiveSonarrBlock::FilterHistory)
         .filter_produced_empty_results(active_sonarr_block == ActiveSonarrBlock::FilterHistoryError)
-        .headers(["Source Title", "Event Type", "Language", "Quality", "Date"])
+        .headers(table_headers(&HISTORY_TABLE_COLUMNS))
         .constraints([
           Constraint::Percentage(40),
           Constraint::Percentage(15),
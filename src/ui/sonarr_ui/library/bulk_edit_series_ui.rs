@@ -0,0 +1,190 @@
+use std::sync::atomic::Ordering;
+
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Rect};
+use ratatui::prelude::Layout;
+use ratatui::widgets::ListItem;
+
+use crate::app::App;
+use crate::models::Route;
+use crate::models::servarr_data::modals::BulkEditModal;
+use crate::models::servarr_data::sonarr::sonarr_data::{
+  ActiveSonarrBlock, BULK_EDIT_SERIES_BLOCKS,
+};
+use crate::render_selectable_input_box;
+use crate::ui::utils::title_block_centered;
+use crate::ui::widgets::button::Button;
+use crate::ui::widgets::checkbox::Checkbox;
+use crate::ui::widgets::input_box::InputBox;
+use crate::ui::widgets::popup::{Popup, Size};
+use crate::ui::widgets::selectable_list::SelectableList;
+use crate::ui::{DrawUi, draw_popup};
+
+#[cfg(test)]
+#[path = "bulk_edit_series_ui_tests.rs"]
+mod bulk_edit_series_ui_tests;
+
+pub(super) struct BulkEditSeriesUi;
+
+impl DrawUi for BulkEditSeriesUi {
+  fn accepts(route: Route) -> bool {
+    let Route::Sonarr(active_sonarr_block, _) = route else {
+      return false;
+    };
+    BULK_EDIT_SERIES_BLOCKS.contains(&active_sonarr_block)
+  }
+
+  fn draw(f: &mut Frame<'_>, app: &mut App<'_>, _area: Rect) {
+    if let Route::Sonarr(active_sonarr_block, _) = app.get_current_route() {
+      draw_popup(
+        f,
+        app,
+        draw_bulk_edit_series_confirmation_prompt,
+        Size::Medium,
+      );
+
+      let bulk_edit_modal = app
+        .data
+        .sonarr_data
+        .bulk_edit_modal
+        .as_mut()
+        .expect("bulk_edit_modal must exist in this context");
+      match active_sonarr_block {
+        ActiveSonarrBlock::BulkEditSeriesSelectMonitored => {
+          let monitored_list =
+            SelectableList::new(&mut bulk_edit_modal.monitored_list, |monitored| {
+              ListItem::new(monitored.to_display_str())
+            });
+          f.render_widget(Popup::new(monitored_list).size(Size::Dropdown), f.area());
+        }
+        ActiveSonarrBlock::BulkEditSeriesSelectQualityProfile => {
+          let quality_profile_list = SelectableList::new(
+            &mut bulk_edit_modal.quality_profile_list,
+            |quality_profile| ListItem::new(quality_profile.clone()),
+          );
+          f.render_widget(
+            Popup::new(quality_profile_list).size(Size::Dropdown),
+            f.area(),
+          );
+        }
+        ActiveSonarrBlock::BulkEditSeriesSelectRootFolder => {
+          let root_folder_list =
+            SelectableList::new(&mut bulk_edit_modal.root_folder_list, |root_folder| {
+              ListItem::new(root_folder.clone())
+            });
+          f.render_widget(Popup::new(root_folder_list).size(Size::Dropdown), f.area());
+        }
+        ActiveSonarrBlock::BulkEditSeriesSelectApplyTags => {
+          let apply_tags_list =
+            SelectableList::new(&mut bulk_edit_modal.apply_tags_list, |apply_tags| {
+              ListItem::new(apply_tags.to_display_str())
+            });
+          f.render_widget(Popup::new(apply_tags_list).size(Size::Dropdown), f.area());
+        }
+        _ => (),
+      }
+    }
+  }
+}
+
+fn draw_bulk_edit_series_confirmation_prompt(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+  let title = format!(
+    "Edit {} Series",
+    app.data.sonarr_data.series.multi_selection().len()
+  );
+  f.render_widget(title_block_centered(&title), area);
+  let yes_no_value = app.data.sonarr_data.prompt_confirm;
+  let selected_block = app.data.sonarr_data.selected_block.get_active_block();
+  let highlight_yes_no = selected_block == ActiveSonarrBlock::BulkEditSeriesConfirmPrompt;
+  let BulkEditModal {
+    monitored_list,
+    quality_profile_list,
+    root_folder_list,
+    tags,
+    apply_tags_list,
+    move_files,
+  } = app
+    .data
+    .sonarr_data
+    .bulk_edit_modal
+    .as_ref()
+    .expect("bulk_edit_modal must exist in this context");
+
+  let [
+    monitored_area,
+    quality_profile_area,
+    root_folder_area,
+    move_files_area,
+    tags_area,
+    apply_tags_area,
+    _,
+    buttons_area,
+  ] = Layout::vertical([
+    Constraint::Length(3),
+    Constraint::Length(3),
+    Constraint::Length(3),
+    Constraint::Length(3),
+    Constraint::Length(3),
+    Constraint::Length(3),
+    Constraint::Fill(1),
+    Constraint::Length(3),
+  ])
+  .margin(1)
+  .areas(area);
+  let [save_area, cancel_area] =
+    Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)])
+      .areas(buttons_area);
+
+  let monitored_drop_down_button = Button::default()
+    .title(monitored_list.current_selection().to_display_str())
+    .label("Monitored")
+    .icon("▼")
+    .selected(selected_block == ActiveSonarrBlock::BulkEditSeriesSelectMonitored);
+  let quality_profile_drop_down_button = Button::default()
+    .title(quality_profile_list.current_selection())
+    .label("Quality Profile")
+    .icon("▼")
+    .selected(selected_block == ActiveSonarrBlock::BulkEditSeriesSelectQualityProfile);
+  let root_folder_drop_down_button = Button::default()
+    .title(root_folder_list.current_selection())
+    .label("Root Folder")
+    .icon("▼")
+    .selected(selected_block == ActiveSonarrBlock::BulkEditSeriesSelectRootFolder);
+  let move_files_checkbox = Checkbox::new("Move Files")
+    .checked(move_files.unwrap_or_default())
+    .highlighted(selected_block == ActiveSonarrBlock::BulkEditSeriesToggleMoveFiles);
+  let apply_tags_drop_down_button = Button::default()
+    .title(apply_tags_list.current_selection().to_display_str())
+    .label("Apply Tags")
+    .icon("▼")
+    .selected(selected_block == ActiveSonarrBlock::BulkEditSeriesSelectApplyTags);
+
+  if let Route::Sonarr(active_sonarr_block, _) = app.get_current_route() {
+    let tags_input_box = InputBox::new(&tags.text)
+      .offset(tags.offset.load(Ordering::SeqCst))
+      .label("Tags")
+      .highlighted(selected_block == ActiveSonarrBlock::BulkEditSeriesTagsInput)
+      .selected(active_sonarr_block == ActiveSonarrBlock::BulkEditSeriesTagsInput);
+
+    if active_sonarr_block == ActiveSonarrBlock::BulkEditSeriesTagsInput {
+      tags_input_box.show_cursor(f, tags_area);
+    }
+
+    render_selectable_input_box!(tags_input_box, f, tags_area);
+  }
+
+  let save_button = Button::default()
+    .title("Save")
+    .selected(yes_no_value && highlight_yes_no);
+  let cancel_button = Button::default()
+    .title("Cancel")
+    .selected(!yes_no_value && highlight_yes_no);
+
+  f.render_widget(monitored_drop_down_button, monitored_area);
+  f.render_widget(quality_profile_drop_down_button, quality_profile_area);
+  f.render_widget(root_folder_drop_down_button, root_folder_area);
+  f.render_widget(move_files_checkbox, move_files_area);
+  f.render_widget(apply_tags_drop_down_button, apply_tags_area);
+  f.render_widget(save_button, save_area);
+  f.render_widget(cancel_button, cancel_area);
+}
@@ -0,0 +1,59 @@
+#[cfg(test)]
+mod tests {
+  use strum::IntoEnumIterator;
+
+  use crate::app::App;
+  use crate::models::BlockSelectionState;
+  use crate::models::servarr_data::sonarr::sonarr_data::{
+    ActiveSonarrBlock, BULK_EDIT_SERIES_BLOCKS, BULK_EDIT_SERIES_SELECTION_BLOCKS,
+  };
+  use crate::ui::DrawUi;
+  use crate::ui::sonarr_ui::library::bulk_edit_series_ui::BulkEditSeriesUi;
+  use crate::ui::ui_test_utils::test_utils::{TerminalSize, render_to_string_with_app};
+
+  #[test]
+  fn test_bulk_edit_series_ui_accepts() {
+    ActiveSonarrBlock::iter().for_each(|active_sonarr_block| {
+      if BULK_EDIT_SERIES_BLOCKS.contains(&active_sonarr_block) {
+        assert!(BulkEditSeriesUi::accepts(active_sonarr_block.into()));
+      } else {
+        assert!(!BulkEditSeriesUi::accepts(active_sonarr_block.into()));
+      }
+    });
+  }
+
+  mod snapshot_tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    fn test_bulk_edit_series_ui_renders_bulk_edit_series_modal(
+      #[values(
+        ActiveSonarrBlock::BulkEditSeriesPrompt,
+        ActiveSonarrBlock::BulkEditSeriesSelectMonitored,
+        ActiveSonarrBlock::BulkEditSeriesSelectQualityProfile,
+        ActiveSonarrBlock::BulkEditSeriesSelectRootFolder,
+        ActiveSonarrBlock::BulkEditSeriesSelectApplyTags,
+        ActiveSonarrBlock::BulkEditSeriesTagsInput
+      )]
+      active_sonarr_block: ActiveSonarrBlock,
+    ) {
+      let mut app = App::test_default_fully_populated();
+      app
+        .data
+        .sonarr_data
+        .series
+        .toggle_select_all(|series| series.id);
+      app.push_navigation_stack(ActiveSonarrBlock::BulkEditSeriesPrompt.into());
+      app.push_navigation_stack(active_sonarr_block.into());
+      app.data.sonarr_data.selected_block =
+        BlockSelectionState::new(BULK_EDIT_SERIES_SELECTION_BLOCKS);
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        BulkEditSeriesUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(active_sonarr_block.to_string(), output);
+    }
+  }
+}
@@ -29,10 +29,18 @@ impl DrawUi for DeleteSeriesUi {
       Route::Sonarr(ActiveSonarrBlock::DeleteSeriesPrompt, _)
     ) {
       let selected_block = app.data.sonarr_data.selected_block.get_active_block();
-      let prompt = format!(
-        "Do you really want to delete the series: \n{}?",
-        app.data.sonarr_data.series.current_selection().title.text
-      );
+      let series = &app.data.sonarr_data.series;
+      let prompt = if series.has_multi_selection() {
+        format!(
+          "Do you really want to delete the {} selected series?",
+          series.multi_selection().len()
+        )
+      } else {
+        format!(
+          "Do you really want to delete the series: \n{}?",
+          series.current_selection().title.text
+        )
+      };
       let checkboxes = vec![
         Checkbox::new("Delete Series File")
           .checked(app.data.sonarr_data.delete_series_files)
@@ -5,6 +5,7 @@ use crate::models::servarr_models::Language;
 use crate::models::sonarr_models::{
   DownloadRecord, DownloadStatus, Episode, SonarrHistoryItem, SonarrRelease,
 };
+use crate::models::table_columns::{HISTORY_TABLE_COLUMNS, table_headers};
 use crate::ui::sonarr_ui::sonarr_ui_utils::create_history_event_details;
 use crate::ui::styles::ManagarrStyle;
 use crate::ui::styles::{
@@ -12,8 +13,8 @@ use crate::ui::styles::{
   unmonitored_missing_style, unmonitored_style, unreleased_style,
 };
 use crate::ui::utils::{
-  borderless_block, decorate_peer_style, get_width_from_percentage, layout_block_bottom_border,
-  layout_block_top_border,
+  borderless_block, custom_format_score_lines, decorate_peer_style, get_width_from_percentage,
+  layout_block_bottom_border, layout_block_top_border,
 };
 use crate::ui::widgets::confirmation_prompt::ConfirmationPrompt;
 use crate::ui::widgets::loading_block::LoadingBlock;
@@ -317,7 +318,7 @@ fn draw_episode_history_table(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect)
           ManagarrTable::new(Some(&mut episode_history_table), history_row_mapping)
             .block(layout_block_top_border())
             .loading(app.is_loading)
-            .headers(["Source Title", "Event Type", "Language", "Quality", "Date"])
+            .headers(table_headers(&HISTORY_TABLE_COLUMNS))
             .constraints([
               Constraint::Percentage(40),
               Constraint::Percentage(15),
@@ -406,6 +407,7 @@ fn draw_episode_releases(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
               leechers,
               languages,
               quality,
+              custom_format_score,
               ..
             } = release;
 
@@ -460,6 +462,7 @@ fn draw_episode_releases(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
               Cell::from(peers),
               Cell::from(language),
               Cell::from(quality),
+              Cell::from(custom_format_score.to_string()),
             ])
             .primary()
           };
@@ -482,6 +485,7 @@ fn draw_episode_releases(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
           .sorting(active_sonarr_block == ActiveSonarrBlock::ManualEpisodeSearchSortPrompt)
           .headers([
             "Source", "Age", "⛔", "Title", "Indexer", "Size", "Peers", "Language", "Quality",
+            "CF Score",
           ])
           .constraints([
             Constraint::Length(9),
@@ -493,6 +497,7 @@ fn draw_episode_releases(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
             Constraint::Length(12),
             Constraint::Percentage(7),
             Constraint::Percentage(10),
+            Constraint::Length(9),
           ]);
 
           f.render_widget(release_table, area);
@@ -546,38 +551,67 @@ fn draw_manual_episode_search_confirm_prompt(f: &mut Frame<'_>, app: &mut App<'_
     )
   };
 
+  let quality_profile_id = app
+    .data
+    .sonarr_data
+    .series
+    .current_selection()
+    .quality_profile_id;
+  let quality_profile = app
+    .data
+    .sonarr_data
+    .quality_profiles
+    .items
+    .iter()
+    .find(|quality_profile| quality_profile.id == quality_profile_id);
+  let mut lines_vec = Vec::new();
+
   if current_selection.rejected {
-    let mut lines_vec = vec![Line::from("Rejection reasons: ".primary().bold())];
-    let mut rejections_spans = current_selection
-      .rejections
-      .clone()
-      .unwrap_or_default()
-      .iter()
-      .map(|item| Line::from(format!("• {item}").primary().bold()))
-      .collect::<Vec<Line<'_>>>();
-    lines_vec.append(&mut rejections_spans);
+    lines_vec.push(Line::from("Rejection reasons: ".primary().bold()));
+    lines_vec.extend(
+      current_selection
+        .rejections
+        .clone()
+        .unwrap_or_default()
+        .iter()
+        .map(|item| Line::from(format!("• {item}").primary().bold())),
+    );
+  }
 
-    let content_paragraph = Paragraph::new(lines_vec)
-      .block(borderless_block())
-      .wrap(Wrap { trim: false })
-      .left_aligned();
+  let custom_format_lines = custom_format_score_lines(
+    &current_selection.custom_formats,
+    current_selection.custom_format_score,
+    quality_profile,
+  );
+  if !custom_format_lines.is_empty() {
+    if !lines_vec.is_empty() {
+      lines_vec.push(Line::default());
+    }
+    lines_vec.extend(custom_format_lines);
+  }
+
+  if lines_vec.is_empty() {
     let confirmation_prompt = ConfirmationPrompt::new()
       .title(title)
       .prompt(&prompt)
-      .content(content_paragraph)
       .yes_no_value(app.data.sonarr_data.prompt_confirm);
 
-    f.render_widget(Popup::new(confirmation_prompt).size(Size::Small), f.area());
+    f.render_widget(
+      Popup::new(confirmation_prompt).size(Size::MediumPrompt),
+      f.area(),
+    );
   } else {
+    let content_paragraph = Paragraph::new(lines_vec)
+      .block(borderless_block())
+      .wrap(Wrap { trim: false })
+      .left_aligned();
     let confirmation_prompt = ConfirmationPrompt::new()
       .title(title)
       .prompt(&prompt)
+      .content(content_paragraph)
       .yes_no_value(app.data.sonarr_data.prompt_confirm);
 
-    f.render_widget(
-      Popup::new(confirmation_prompt).size(Size::MediumPrompt),
-      f.area(),
-    );
+    f.render_widget(Popup::new(confirmation_prompt).size(Size::Small), f.area());
   }
 }
 
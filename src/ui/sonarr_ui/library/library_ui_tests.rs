@@ -1,8 +1,8 @@
 #[cfg(test)]
 mod tests {
   use crate::models::servarr_data::sonarr::sonarr_data::{
-    ADD_SERIES_BLOCKS, ActiveSonarrBlock, DELETE_SERIES_BLOCKS, EDIT_SERIES_BLOCKS,
-    EPISODE_DETAILS_BLOCKS, SEASON_DETAILS_BLOCKS, SERIES_DETAILS_BLOCKS,
+    ADD_SERIES_BLOCKS, ActiveSonarrBlock, BULK_EDIT_SERIES_BLOCKS, DELETE_SERIES_BLOCKS,
+    EDIT_SERIES_BLOCKS, EPISODE_DETAILS_BLOCKS, SEASON_DETAILS_BLOCKS, SERIES_DETAILS_BLOCKS,
   };
   use crate::models::{
     servarr_data::sonarr::sonarr_data::LIBRARY_BLOCKS, sonarr_models::SeriesStatus,
@@ -26,6 +26,7 @@ mod tests {
     library_ui_blocks.extend(ADD_SERIES_BLOCKS);
     library_ui_blocks.extend(DELETE_SERIES_BLOCKS);
     library_ui_blocks.extend(EDIT_SERIES_BLOCKS);
+    library_ui_blocks.extend(BULK_EDIT_SERIES_BLOCKS);
     library_ui_blocks.extend(SERIES_DETAILS_BLOCKS);
     library_ui_blocks.extend(SEASON_DETAILS_BLOCKS);
     library_ui_blocks.extend(EPISODE_DETAILS_BLOCKS);
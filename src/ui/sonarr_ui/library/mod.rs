@@ -1,4 +1,5 @@
 use add_series_ui::AddSeriesUi;
+use bulk_edit_series_ui::BulkEditSeriesUi;
 use delete_series_ui::DeleteSeriesUi;
 use edit_series_ui::EditSeriesUi;
 use ratatui::{
@@ -8,11 +9,11 @@ use ratatui::{
 };
 use series_details_ui::SeriesDetailsUi;
 
+use crate::models::table_columns::{SERIES_TABLE_HEADERS, series_row};
 use crate::ui::widgets::{
   confirmation_prompt::ConfirmationPrompt,
   popup::{Popup, Size},
 };
-use crate::utils::convert_to_gb;
 use crate::{
   app::App,
   models::{
@@ -29,6 +30,7 @@ use crate::{
 };
 
 mod add_series_ui;
+mod bulk_edit_series_ui;
 mod delete_series_ui;
 mod edit_series_ui;
 mod series_details_ui;
@@ -47,6 +49,7 @@ impl DrawUi for LibraryUi {
       return AddSeriesUi::accepts(route)
         || DeleteSeriesUi::accepts(route)
         || EditSeriesUi::accepts(route)
+        || BulkEditSeriesUi::accepts(route)
         || SeriesDetailsUi::accepts(route)
         || LIBRARY_BLOCKS.contains(&active_sonarr_block);
     }
@@ -62,6 +65,7 @@ impl DrawUi for LibraryUi {
       _ if AddSeriesUi::accepts(route) => AddSeriesUi::draw(f, app, area),
       _ if DeleteSeriesUi::accepts(route) => DeleteSeriesUi::draw(f, app, area),
       _ if EditSeriesUi::accepts(route) => EditSeriesUi::draw(f, app, area),
+      _ if BulkEditSeriesUi::accepts(route) => BulkEditSeriesUi::draw(f, app, area),
       _ if SeriesDetailsUi::accepts(route) => SeriesDetailsUi::draw(f, app, area),
       Route::Sonarr(ActiveSonarrBlock::UpdateAllSeriesPrompt, _) => {
         let confirmation_prompt = ConfirmationPrompt::new()
@@ -97,46 +101,11 @@ fn draw_library(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
         *series == current_selection,
         app.ui_scroll_tick_count == 0,
       );
-      let monitored = if series.monitored { "🏷" } else { "" };
-      let certification = series.certification.clone().unwrap_or_default();
-      let network = series.network.clone().unwrap_or_default();
-      let size = series
-        .statistics
-        .as_ref()
-        .map_or(0f64, |stats| convert_to_gb(stats.size_on_disk));
-      let quality_profile = quality_profile_map
-        .get_by_left(&series.quality_profile_id)
-        .expect("Quality profile ID must exist in quality_profile_map")
-        .to_owned();
-      let language_profile = language_profile_map
-        .get_by_left(&series.language_profile_id)
-        .expect("Language profile ID must exist in language_profile_map")
-        .to_owned();
-      let tags = series
-        .tags
-        .iter()
-        .filter_map(|tag_id| {
-          let id = tag_id.as_i64()?;
-          tags_map.get_by_left(&id).cloned()
-        })
-        .collect::<Vec<_>>()
-        .join(", ");
-
       decorate_series_row_with_style(
         series,
-        Row::new(vec![
-          Cell::from(series.title.to_string()),
-          Cell::from(series.year.to_string()),
-          Cell::from(network),
-          Cell::from(series.status.to_display_str()),
-          Cell::from(certification),
-          Cell::from(series.series_type.to_display_str()),
-          Cell::from(quality_profile),
-          Cell::from(language_profile),
-          Cell::from(format!("{size:.2} GB")),
-          Cell::from(monitored.to_owned()),
-          Cell::from(tags),
-        ]),
+        Row::new(
+          series_row(series, quality_profile_map, language_profile_map, tags_map).map(Cell::from),
+        ),
       )
     };
     let series_table = ManagarrTable::new(content, series_table_row_mapping)
@@ -147,19 +116,7 @@ fn draw_library(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
       .filtering(active_sonarr_block == ActiveSonarrBlock::FilterSeries)
       .search_produced_empty_results(active_sonarr_block == ActiveSonarrBlock::SearchSeriesError)
       .filter_produced_empty_results(active_sonarr_block == ActiveSonarrBlock::FilterSeriesError)
-      .headers([
-        "Title",
-        "Year",
-        "Network",
-        "Status",
-        "Rating",
-        "Type",
-        "Quality Profile",
-        "Language",
-        "Size",
-        "Monitored",
-        "Tags",
-      ])
+      .headers(SERIES_TABLE_HEADERS)
       .constraints([
         Constraint::Percentage(20),
         Constraint::Percentage(4),
@@ -1,3 +1,4 @@
+use crate::models::table_columns::{HISTORY_TABLE_COLUMNS, table_headers};
 use crate::ui::styles::secondary_style;
 use chrono::Utc;
 use deunicode::deunicode;
@@ -357,7 +358,7 @@ fn draw_series_history_table(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
             .filter_produced_empty_results(
               active_sonarr_block == ActiveSonarrBlock::FilterSeriesHistoryError,
             )
-            .headers(["Source Title", "Event Type", "Language", "Quality", "Date"])
+            .headers(table_headers(&HISTORY_TABLE_COLUMNS))
             .constraints([
               Constraint::Percentage(40),
               Constraint::Percentage(15),
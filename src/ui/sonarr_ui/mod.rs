@@ -1,13 +1,17 @@
 #[cfg(test)]
 use crate::ui::ui_test_utils::test_utils::Utc;
 use blocklist::BlocklistUi;
+use calendar::CalendarUi;
 use chrono::Duration;
 #[cfg(not(test))]
 use chrono::Utc;
+use custom_formats::CustomFormatsUi;
+use download_clients::DownloadClientsUi;
 use downloads::DownloadsUi;
 use history::HistoryUi;
 use indexers::IndexersUi;
 use library::LibraryUi;
+use quality_profiles::QualityProfilesUi;
 use ratatui::{
   Frame,
   layout::{Constraint, Layout, Rect},
@@ -18,6 +22,7 @@ use ratatui::{
 use root_folders::RootFoldersUi;
 use std::{cmp, iter};
 use system::SystemUi;
+use wanted::WantedUi;
 
 use super::{
   DrawUi, draw_tabs,
@@ -41,13 +46,18 @@ use crate::{
 };
 
 mod blocklist;
+mod calendar;
+mod custom_formats;
+mod download_clients;
 mod downloads;
 mod history;
 mod indexers;
 mod library;
+mod quality_profiles;
 mod root_folders;
 mod sonarr_ui_utils;
 mod system;
+mod wanted;
 
 #[cfg(test)]
 #[path = "sonarr_ui_tests.rs"]
@@ -71,7 +81,12 @@ impl DrawUi for SonarrUi {
       _ if HistoryUi::accepts(route) => HistoryUi::draw(f, app, content_area),
       _ if RootFoldersUi::accepts(route) => RootFoldersUi::draw(f, app, content_area),
       _ if IndexersUi::accepts(route) => IndexersUi::draw(f, app, content_area),
+      _ if DownloadClientsUi::accepts(route) => DownloadClientsUi::draw(f, app, content_area),
+      _ if QualityProfilesUi::accepts(route) => QualityProfilesUi::draw(f, app, content_area),
+      _ if CustomFormatsUi::accepts(route) => CustomFormatsUi::draw(f, app, content_area),
       _ if SystemUi::accepts(route) => SystemUi::draw(f, app, content_area),
+      _ if CalendarUi::accepts(route) => CalendarUi::draw(f, app, content_area),
+      _ if WantedUi::accepts(route) => WantedUi::draw(f, app, content_area),
       _ => (),
     }
   }
@@ -3,15 +3,21 @@ mod tests {
   use strum::IntoEnumIterator;
 
   use crate::app::App;
-  use crate::models::servarr_data::sonarr::sonarr_data::{ActiveSonarrBlock, ROOT_FOLDERS_BLOCKS};
+  use crate::models::servarr_data::sonarr::sonarr_data::{
+    ActiveSonarrBlock, MEDIA_MANAGEMENT_SETTINGS_BLOCKS, ROOT_FOLDERS_BLOCKS,
+  };
   use crate::ui::DrawUi;
   use crate::ui::sonarr_ui::root_folders::RootFoldersUi;
   use crate::ui::ui_test_utils::test_utils::render_to_string_with_app;
 
   #[test]
   fn test_root_folders_ui_accepts() {
+    let mut root_folders_ui_blocks = Vec::new();
+    root_folders_ui_blocks.extend(ROOT_FOLDERS_BLOCKS);
+    root_folders_ui_blocks.extend(MEDIA_MANAGEMENT_SETTINGS_BLOCKS);
+
     ActiveSonarrBlock::iter().for_each(|active_sonarr_block| {
-      if ROOT_FOLDERS_BLOCKS.contains(&active_sonarr_block) {
+      if root_folders_ui_blocks.contains(&active_sonarr_block) {
         assert!(RootFoldersUi::accepts(active_sonarr_block.into()));
       } else {
         assert!(!RootFoldersUi::accepts(active_sonarr_block.into()));
@@ -55,7 +61,9 @@ mod tests {
       #[values(
         ActiveSonarrBlock::RootFolders,
         ActiveSonarrBlock::AddRootFolderPrompt,
-        ActiveSonarrBlock::DeleteRootFolderPrompt
+        ActiveSonarrBlock::DeleteRootFolderPrompt,
+        ActiveSonarrBlock::MediaManagementSettingsPrompt,
+        ActiveSonarrBlock::MediaManagementFieldInput
       )]
       active_sonarr_block: ActiveSonarrBlock,
     ) {
@@ -68,5 +76,34 @@ mod tests {
 
       insta::assert_snapshot!(active_sonarr_block.to_string(), output);
     }
+
+    #[test]
+    fn test_root_folders_ui_renders_media_management_settings_loading() {
+      let mut app = App::test_default_fully_populated();
+      app.is_loading = true;
+      app.data.sonarr_data.media_management_modal = None;
+      app.data.sonarr_data.naming_examples = None;
+      app.push_navigation_stack(ActiveSonarrBlock::MediaManagementSettingsPrompt.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        RootFoldersUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(output);
+    }
+
+    #[test]
+    fn test_root_folders_ui_renders_media_management_settings_naming_examples_loading() {
+      let mut app = App::test_default_fully_populated();
+      app.is_loading = true;
+      app.data.sonarr_data.naming_examples = None;
+      app.push_navigation_stack(ActiveSonarrBlock::MediaManagementSettingsPrompt.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        RootFoldersUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(output);
+    }
   }
 }
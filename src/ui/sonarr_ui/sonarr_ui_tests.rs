@@ -29,6 +29,8 @@ mod tests {
     #[case(ActiveSonarrBlock::RootFolders, 4)]
     #[case(ActiveSonarrBlock::Indexers, 5)]
     #[case(ActiveSonarrBlock::System, 6)]
+    #[case(ActiveSonarrBlock::Calendar, 7)]
+    #[case(ActiveSonarrBlock::WantedMissing, 8)]
     fn test_sonarr_ui_renders_sonarr_tabs(
       #[case] active_sonarr_block: ActiveSonarrBlock,
       #[case] index: usize,
@@ -31,6 +31,8 @@ mod tests {
     #[rstest]
     fn test_system_details_ui_popups(
       #[values(
+        ActiveSonarrBlock::SystemBackups,
+        ActiveSonarrBlock::DeleteBackupPrompt,
         ActiveSonarrBlock::SystemLogs,
         ActiveSonarrBlock::SystemQueuedEvents,
         ActiveSonarrBlock::SystemTasks,
@@ -52,6 +54,7 @@ mod tests {
     #[rstest]
     fn test_system_details_ui_loading(
       #[values(
+        ActiveSonarrBlock::SystemBackups,
         ActiveSonarrBlock::SystemLogs,
         ActiveSonarrBlock::SystemQueuedEvents,
         ActiveSonarrBlock::SystemTasks,
@@ -87,6 +90,7 @@ mod tests {
     #[rstest]
     fn test_system_details_ui_popups_empty(
       #[values(
+        ActiveSonarrBlock::SystemBackups,
         ActiveSonarrBlock::SystemLogs,
         ActiveSonarrBlock::SystemQueuedEvents,
         ActiveSonarrBlock::SystemTasks
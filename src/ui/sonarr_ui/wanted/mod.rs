@@ -0,0 +1,151 @@
+use std::collections::HashMap;
+
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Rect};
+use ratatui::widgets::{Cell, Row};
+
+use crate::app::App;
+use crate::models::Route;
+use crate::models::servarr_data::sonarr::sonarr_data::{ActiveSonarrBlock, WANTED_BLOCKS};
+use crate::models::sonarr_models::Episode;
+use crate::ui::DrawUi;
+use crate::ui::styles::ManagarrStyle;
+use crate::ui::utils::{layout_block_top_border_with_title, title_style};
+use crate::ui::widgets::confirmation_prompt::ConfirmationPrompt;
+use crate::ui::widgets::managarr_table::ManagarrTable;
+use crate::ui::widgets::popup::{Popup, Size};
+
+#[cfg(test)]
+#[path = "wanted_ui_tests.rs"]
+mod wanted_ui_tests;
+
+pub(super) struct WantedUi;
+
+impl DrawUi for WantedUi {
+  fn accepts(route: Route) -> bool {
+    if let Route::Sonarr(active_sonarr_block, _) = route {
+      return WANTED_BLOCKS.contains(&active_sonarr_block);
+    }
+
+    false
+  }
+
+  fn draw(f: &mut Frame<'_>, app: &mut App<'_>, area: Rect) {
+    if let Route::Sonarr(active_sonarr_block, context) = app.get_current_route() {
+      let table_block = match active_sonarr_block {
+        ActiveSonarrBlock::WantedSearchAllPrompt
+        | ActiveSonarrBlock::WantedSearchSelectedPrompt => {
+          context.unwrap_or(ActiveSonarrBlock::WantedMissing)
+        }
+        _ => active_sonarr_block,
+      };
+      draw_wanted_table(f, app, area, table_block);
+
+      match active_sonarr_block {
+        ActiveSonarrBlock::WantedSearchSelectedPrompt => {
+          let episode = if table_block == ActiveSonarrBlock::WantedCutoffUnmet {
+            app.data.sonarr_data.cutoff_unmet.current_selection()
+          } else {
+            app.data.sonarr_data.wanted_missing.current_selection()
+          };
+          let prompt = format!(
+            "Do you want to trigger an automatic search for: \n{} {}?",
+            episode_number(episode),
+            episode.title
+          );
+          let confirmation_prompt = ConfirmationPrompt::new()
+            .title("Search Selected Episode")
+            .prompt(&prompt)
+            .yes_no_value(app.data.sonarr_data.prompt_confirm);
+
+          f.render_widget(
+            Popup::new(confirmation_prompt).size(Size::MediumPrompt),
+            f.area(),
+          );
+        }
+        ActiveSonarrBlock::WantedSearchAllPrompt => {
+          let prompt = if table_block == ActiveSonarrBlock::WantedCutoffUnmet {
+            "Do you want to trigger an automatic search for all episodes that have not met their quality cutoff?"
+          } else {
+            "Do you want to trigger an automatic search for all missing episodes?"
+          };
+          let confirmation_prompt = ConfirmationPrompt::new()
+            .title("Search All Wanted Episodes")
+            .prompt(prompt)
+            .yes_no_value(app.data.sonarr_data.prompt_confirm);
+
+          f.render_widget(
+            Popup::new(confirmation_prompt).size(Size::MediumPrompt),
+            f.area(),
+          );
+        }
+        _ => (),
+      }
+    }
+  }
+}
+
+fn draw_wanted_table(
+  f: &mut Frame<'_>,
+  app: &mut App<'_>,
+  area: Rect,
+  table_block: ActiveSonarrBlock,
+) {
+  let series_titles: HashMap<i64, String> = app
+    .data
+    .sonarr_data
+    .series
+    .items
+    .iter()
+    .map(|series| (series.id, series.title.text.clone()))
+    .collect();
+  let is_cutoff_unmet = table_block == ActiveSonarrBlock::WantedCutoffUnmet;
+  let wanted_row_mapping = |episode: &Episode| {
+    let Episode {
+      series_id,
+      title,
+      air_date_utc,
+      ..
+    } = episode;
+    let air_date = air_date_utc.map_or_else(String::new, |date| {
+      date.format("%Y-%m-%d %H:%M").to_string()
+    });
+
+    let row = Row::new(vec![
+      Cell::from(series_titles.get(series_id).cloned().unwrap_or_default()),
+      Cell::from(episode_number(episode)),
+      Cell::from(title.clone()),
+      Cell::from(air_date),
+    ]);
+
+    if is_cutoff_unmet {
+      row.warning()
+    } else {
+      row.missing()
+    }
+  };
+  let (title, wanted_table) = if is_cutoff_unmet {
+    ("Cutoff Unmet", &mut app.data.sonarr_data.cutoff_unmet)
+  } else {
+    ("Missing", &mut app.data.sonarr_data.wanted_missing)
+  };
+  let wanted_table = ManagarrTable::new(Some(wanted_table), wanted_row_mapping)
+    .block(layout_block_top_border_with_title(title_style(title)))
+    .loading(app.is_loading)
+    .headers(["Series", "Episode", "Title", "Air Date"])
+    .constraints([
+      Constraint::Percentage(35),
+      Constraint::Percentage(10),
+      Constraint::Percentage(35),
+      Constraint::Percentage(20),
+    ]);
+
+  f.render_widget(wanted_table, area);
+}
+
+fn episode_number(episode: &Episode) -> String {
+  format!(
+    "S{:02}E{:02}",
+    episode.season_number, episode.episode_number
+  )
+}
@@ -0,0 +1,95 @@
+#[cfg(test)]
+mod tests {
+  use strum::IntoEnumIterator;
+
+  use crate::app::App;
+  use crate::models::servarr_data::sonarr::sonarr_data::{ActiveSonarrBlock, WANTED_BLOCKS};
+  use crate::ui::DrawUi;
+  use crate::ui::sonarr_ui::wanted::WantedUi;
+  use crate::ui::ui_test_utils::test_utils::render_to_string_with_app;
+
+  #[test]
+  fn test_wanted_ui_accepts() {
+    ActiveSonarrBlock::iter().for_each(|active_sonarr_block| {
+      if WANTED_BLOCKS.contains(&active_sonarr_block) {
+        assert!(WantedUi::accepts(active_sonarr_block.into()));
+      } else {
+        assert!(!WantedUi::accepts(active_sonarr_block.into()));
+      }
+    });
+  }
+
+  mod snapshot_tests {
+    use crate::ui::ui_test_utils::test_utils::TerminalSize;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_wanted_ui_renders_loading(
+      #[values(ActiveSonarrBlock::WantedMissing, ActiveSonarrBlock::WantedCutoffUnmet)]
+      active_sonarr_block: ActiveSonarrBlock,
+    ) {
+      let mut app = App::test_default();
+      app.is_loading = true;
+      app.push_navigation_stack(active_sonarr_block.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        WantedUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(format!("loading_{active_sonarr_block}"), output);
+    }
+
+    #[rstest]
+    fn test_wanted_ui_renders_empty(
+      #[values(ActiveSonarrBlock::WantedMissing, ActiveSonarrBlock::WantedCutoffUnmet)]
+      active_sonarr_block: ActiveSonarrBlock,
+    ) {
+      let mut app = App::test_default();
+      app.push_navigation_stack(active_sonarr_block.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        WantedUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(format!("empty_{active_sonarr_block}"), output);
+    }
+
+    #[rstest]
+    fn test_wanted_ui_renders_wanted_tab(
+      #[values(ActiveSonarrBlock::WantedMissing, ActiveSonarrBlock::WantedCutoffUnmet)]
+      active_sonarr_block: ActiveSonarrBlock,
+    ) {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(active_sonarr_block.into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        WantedUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(active_sonarr_block.to_string(), output);
+    }
+
+    #[rstest]
+    fn test_wanted_ui_renders_search_prompts(
+      #[values(ActiveSonarrBlock::WantedMissing, ActiveSonarrBlock::WantedCutoffUnmet)]
+      table_block: ActiveSonarrBlock,
+      #[values(
+        ActiveSonarrBlock::WantedSearchAllPrompt,
+        ActiveSonarrBlock::WantedSearchSelectedPrompt
+      )]
+      prompt_block: ActiveSonarrBlock,
+    ) {
+      let mut app = App::test_default_fully_populated();
+      app.push_navigation_stack(table_block.into());
+      app.push_navigation_stack((prompt_block, Some(table_block)).into());
+
+      let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+        WantedUi::draw(f, app, f.area());
+      });
+
+      insta::assert_snapshot!(format!("{prompt_block}_{table_block}"), output);
+    }
+  }
+}
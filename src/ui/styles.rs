@@ -100,6 +100,10 @@ pub fn highlight_style() -> Style {
   Style::new().reversed()
 }
 
+pub fn multi_selection_style() -> Style {
+  Style::new().bold().underlined()
+}
+
 pub fn missing_style() -> Style {
   THEME.with(|theme| {
     Style::new().fg(
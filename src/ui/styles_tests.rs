@@ -2,9 +2,9 @@
 mod test {
   use crate::ui::styles::{
     awaiting_import_style, default_style, downloaded_style, downloading_style, failure_style,
-    help_style, highlight_style, indeterminate_style, missing_style, primary_style,
-    secondary_style, success_style, system_function_style, unmonitored_missing_style,
-    unmonitored_style, unreleased_style, warning_style,
+    help_style, highlight_style, indeterminate_style, missing_style, multi_selection_style,
+    primary_style, secondary_style, success_style, system_function_style,
+    unmonitored_missing_style, unmonitored_style, unreleased_style, warning_style,
   };
   use pretty_assertions::assert_eq;
   use ratatui::prelude::Modifier;
@@ -59,6 +59,14 @@ mod test {
     );
   }
 
+  #[test]
+  fn test_style_multi_selection() {
+    assert_eq!(
+      multi_selection_style(),
+      Style::new().add_modifier(Modifier::BOLD | Modifier::UNDERLINED)
+    );
+  }
+
   #[test]
   fn test_style_missing() {
     assert_eq!(missing_style(), Style::new().red());
@@ -34,6 +34,33 @@ mod snapshot_tests {
     insta::assert_snapshot!(output);
   }
 
+  #[test]
+  fn test_radarr_ui_renders_library_tab_while_retrying() {
+    let mut app = App::test_default_fully_populated();
+    app.retry_attempts.insert(0, (2, 3));
+    app.push_navigation_stack(ActiveRadarrBlock::default().into());
+
+    let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+      ui(f, app);
+    });
+
+    insta::assert_snapshot!(output);
+  }
+
+  #[test]
+  fn test_radarr_ui_renders_library_tab_while_retrying_multiple_requests() {
+    let mut app = App::test_default_fully_populated();
+    app.retry_attempts.insert(0, (2, 3));
+    app.retry_attempts.insert(1, (1, 3));
+    app.push_navigation_stack(ActiveRadarrBlock::default().into());
+
+    let output = render_to_string_with_app(TerminalSize::Large, &mut app, |f, app| {
+      ui(f, app);
+    });
+
+    insta::assert_snapshot!(output);
+  }
+
   #[test]
   fn test_radarr_ui_renders_library_tab_error_popup() {
     let mut app = App::test_default_fully_populated();
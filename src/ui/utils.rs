@@ -1,5 +1,5 @@
 use crate::app::App;
-use crate::models::servarr_models::{DiskSpace, RootFolder};
+use crate::models::servarr_models::{CustomFormat, DiskSpace, QualityProfile, RootFolder};
 use crate::ui::THEME;
 use crate::ui::styles::{
   ManagarrStyle, default_style, failure_style, primary_style, secondary_style,
@@ -184,6 +184,41 @@ pub(super) fn decorate_peer_style(seeders: u64, leechers: u64, text: Text<'_>) -
   }
 }
 
+/// Lists the custom formats a release matched, along with the score the quality profile assigns to
+/// each of them
+pub(super) fn custom_format_score_lines(
+  custom_formats: &[CustomFormat],
+  custom_format_score: i64,
+  quality_profile: Option<&QualityProfile>,
+) -> Vec<Line<'static>> {
+  if custom_formats.is_empty() {
+    return Vec::new();
+  }
+
+  let mut lines = vec![Line::from(
+    format!("Custom formats (score {custom_format_score}): ")
+      .primary()
+      .bold(),
+  )];
+  lines.extend(custom_formats.iter().map(|custom_format| {
+    let score = quality_profile.and_then(|profile| {
+      profile
+        .format_items
+        .iter()
+        .find(|format_item| format_item.format == custom_format.id)
+        .map(|format_item| format_item.score)
+    });
+    let line = match score {
+      Some(score) => format!("• {}: {score:+}", custom_format.name),
+      None => format!("• {}", custom_format.name),
+    };
+
+    Line::from(line.primary().bold())
+  }));
+
+  lines
+}
+
 pub(super) fn extract_monitored_root_folders(
   app: &App<'_>,
   root_folders: Vec<RootFolder>,
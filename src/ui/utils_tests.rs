@@ -1,19 +1,21 @@
 #[cfg(test)]
 mod test {
   use crate::app::{App, ServarrConfig};
-  use crate::models::servarr_models::{DiskSpace, RootFolder};
+  use crate::models::servarr_models::{
+    CustomFormat, DiskSpace, ProfileFormatItem, QualityProfile, RootFolder,
+  };
   use crate::ui::styles::{ManagarrStyle, default_style, failure_style, secondary_style};
   use crate::ui::utils::{
-    borderless_block, centered_rect, convert_to_minutes_hours_days, decorate_peer_style,
-    extract_monitored_disk_space_vec, extract_monitored_root_folders, get_width_from_percentage,
-    layout_block, layout_block_bottom_border, layout_block_top_border,
+    borderless_block, centered_rect, convert_to_minutes_hours_days, custom_format_score_lines,
+    decorate_peer_style, extract_monitored_disk_space_vec, extract_monitored_root_folders,
+    get_width_from_percentage, layout_block, layout_block_bottom_border, layout_block_top_border,
     layout_block_top_border_with_title, layout_block_with_title, logo_block, style_block_highlight,
     style_log_list_item, title_block, title_block_centered, title_style, unstyled_title_block,
   };
   use pretty_assertions::{assert_eq, assert_str_eq};
   use ratatui::layout::{Alignment, Rect};
   use ratatui::style::{Color, Modifier, Style, Stylize};
-  use ratatui::text::{Span, Text};
+  use ratatui::text::{Line, Span, Text};
   use ratatui::widgets::{Block, BorderType, Borders, ListItem};
   use rstest::rstest;
 
@@ -562,6 +564,47 @@ mod test {
     assert_eq!(monitored_disk_space, disk_spaces);
   }
 
+  #[test]
+  fn test_custom_format_score_lines() {
+    let quality_profile = QualityProfile {
+      id: 1,
+      format_items: vec![ProfileFormatItem {
+        format: 1,
+        name: "x265".to_owned(),
+        score: 10,
+      }],
+      ..QualityProfile::default()
+    };
+    let custom_formats = vec![
+      CustomFormat {
+        id: 1,
+        name: "x265".to_owned(),
+        ..CustomFormat::default()
+      },
+      CustomFormat {
+        id: 2,
+        name: "HDR".to_owned(),
+        ..CustomFormat::default()
+      },
+    ];
+
+    let lines = custom_format_score_lines(&custom_formats, 10, Some(&quality_profile));
+
+    assert_eq!(
+      lines,
+      vec![
+        Line::from("Custom formats (score 10): ".primary().bold()),
+        Line::from("• x265: +10".primary().bold()),
+        Line::from("• HDR".primary().bold()),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_custom_format_score_lines_empty_custom_formats() {
+    assert_is_empty!(custom_format_score_lines(&[], 0, None));
+  }
+
   enum PeerStyle {
     Failure,
     Warning,
@@ -0,0 +1,73 @@
+use crate::ui::utils::{layout_block_top_border, title_block};
+use crate::ui::widgets::loading_block::LoadingBlock;
+use chrono::{Days, NaiveDate};
+use derive_setters::Setters;
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::text::Line;
+use ratatui::widgets::{Paragraph, Widget, Wrap};
+
+#[cfg(test)]
+#[path = "calendar_week_tests.rs"]
+mod calendar_week_tests;
+
+const DAYS_IN_WEEK: usize = 7;
+
+#[derive(Setters)]
+pub struct CalendarWeek<'a> {
+  #[setters(skip)]
+  start: NaiveDate,
+  #[setters(skip)]
+  entries: Vec<(NaiveDate, Line<'a>)>,
+  #[setters(rename = "loading")]
+  is_loading: bool,
+}
+
+impl<'a> CalendarWeek<'a> {
+  pub fn new(start: NaiveDate, entries: Vec<(NaiveDate, Line<'a>)>) -> Self {
+    CalendarWeek {
+      start,
+      entries,
+      is_loading: false,
+    }
+  }
+
+  fn day_index(&self, date: NaiveDate) -> Option<usize> {
+    let offset = usize::try_from((date - self.start).num_days()).ok()?;
+
+    (offset < DAYS_IN_WEEK).then_some(offset)
+  }
+
+  fn render_calendar_week(self, area: Rect, buf: &mut Buffer) {
+    if self.is_loading {
+      LoadingBlock::new(true, layout_block_top_border()).render(area, buf);
+      return;
+    }
+
+    let mut days: Vec<Vec<Line<'a>>> = vec![Vec::new(); DAYS_IN_WEEK];
+    for (date, line) in self.entries.iter().cloned() {
+      if let Some(index) = self.day_index(date) {
+        days[index].push(line);
+      }
+    }
+
+    let day_areas =
+      Layout::horizontal([Constraint::Ratio(1, DAYS_IN_WEEK as u32); DAYS_IN_WEEK]).split(area);
+
+    for (offset, (lines, day_area)) in days.into_iter().zip(day_areas.iter()).enumerate() {
+      let date = self.start + Days::new(offset as u64);
+      let title = date.format("%a %b %d").to_string();
+
+      Paragraph::new(lines)
+        .block(title_block(&title))
+        .wrap(Wrap { trim: true })
+        .render(*day_area, buf);
+    }
+  }
+}
+
+impl Widget for CalendarWeek<'_> {
+  fn render(self, area: Rect, buf: &mut Buffer) {
+    self.render_calendar_week(area, buf);
+  }
+}
@@ -0,0 +1,39 @@
+#[cfg(test)]
+mod tests {
+  use crate::ui::widgets::calendar_week::CalendarWeek;
+  use chrono::NaiveDate;
+  use pretty_assertions::assert_eq;
+  use ratatui::text::Line;
+
+  #[test]
+  fn test_calendar_week_new() {
+    let start = NaiveDate::from_ymd_opt(2023, 5, 15).unwrap();
+    let entries = vec![(start, Line::from("test"))];
+
+    let calendar_week = CalendarWeek::new(start, entries.clone());
+
+    assert_eq!(calendar_week.start, start);
+    assert_eq!(calendar_week.entries, entries);
+    assert!(!calendar_week.is_loading);
+  }
+
+  #[test]
+  fn test_calendar_week_day_index() {
+    let start = NaiveDate::from_ymd_opt(2023, 5, 15).unwrap();
+    let calendar_week = CalendarWeek::new(start, Vec::new());
+
+    assert_eq!(calendar_week.day_index(start), Some(0));
+    assert_eq!(
+      calendar_week.day_index(NaiveDate::from_ymd_opt(2023, 5, 21).unwrap()),
+      Some(6)
+    );
+    assert_eq!(
+      calendar_week.day_index(NaiveDate::from_ymd_opt(2023, 5, 22).unwrap()),
+      None
+    );
+    assert_eq!(
+      calendar_week.day_index(NaiveDate::from_ymd_opt(2023, 5, 14).unwrap()),
+      None
+    );
+  }
+}
@@ -3,7 +3,7 @@ use super::message::Message;
 use super::popup::Size;
 use crate::models::stateful_table::StatefulTable;
 use crate::ui::HIGHLIGHT_SYMBOL;
-use crate::ui::styles::{ManagarrStyle, highlight_style};
+use crate::ui::styles::{ManagarrStyle, highlight_style, multi_selection_style};
 use crate::ui::utils::{borderless_block, centered_rect, title_block_centered};
 use crate::ui::widgets::loading_block::LoadingBlock;
 use crate::ui::widgets::popup::Popup;
@@ -13,6 +13,7 @@ use ratatui::Frame;
 use ratatui::buffer::Buffer;
 use ratatui::layout::{Constraint, Layout, Position, Rect};
 use ratatui::prelude::{Stylize, Text};
+use ratatui::style::Styled;
 use ratatui::widgets::{Block, ListItem, Row, StatefulWidget, Table, Widget, WidgetRef};
 use std::fmt::Debug;
 use std::sync::atomic::Ordering;
@@ -125,13 +126,30 @@ where
     if let Some(content) = self.content
       && !self.is_loading
     {
+      let selected_rows = (0..content
+        .filtered_items
+        .as_ref()
+        .map_or(content.items.len(), |items| items.len()))
+        .map(|index| content.is_row_selected(index))
+        .collect::<Vec<bool>>();
       let (table_contents, table_state) = if let Some(items) = &content.filtered_items {
         (items, content.filtered_state.as_mut().unwrap())
       } else {
         (&content.items, &mut content.state)
       };
       if !table_contents.is_empty() {
-        let rows = table_contents.iter().map(&self.row_mapper);
+        let rows = table_contents
+          .iter()
+          .map(&self.row_mapper)
+          .zip(selected_rows)
+          .map(|(row, is_selected)| {
+            if is_selected {
+              let style = Styled::style(&row).patch(multi_selection_style());
+              row.set_style(style)
+            } else {
+              row
+            }
+          });
 
         let headers = Row::new(table_headers)
           .default_color()
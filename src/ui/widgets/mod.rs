@@ -1,4 +1,5 @@
 pub(super) mod button;
+pub(super) mod calendar_week;
 pub(super) mod checkbox;
 pub(super) mod confirmation_prompt;
 pub(super) mod input_box;
@@ -7,9 +7,10 @@ use std::time::Duration;
 
 use anyhow::Result;
 use anyhow::{Context, anyhow};
+use chrono::{Days, NaiveDate, Weekday};
 use colored::Colorize;
 use indicatif::{ProgressBar, ProgressStyle};
-use log::{LevelFilter, error};
+use log::LevelFilter;
 use log4rs::append::rolling_file::RollingFileAppender;
 use log4rs::append::rolling_file::policy::compound::CompoundPolicy;
 use log4rs::append::rolling_file::policy::compound::roll::fixed_window::FixedWindowRoller;
@@ -17,13 +18,19 @@ use log4rs::append::rolling_file::policy::compound::trigger::size::SizeTrigger;
 use log4rs::config::{Appender, Root};
 use log4rs::encode::pattern::PatternEncoder;
 use regex::Regex;
-use reqwest::{Certificate, Client};
+use reqwest::{Certificate, Client, ClientBuilder, Identity, Proxy};
 use tokio::sync::Mutex;
 use tokio_util::sync::CancellationToken;
 
-use crate::app::{App, AppConfig, log_and_print_error};
+use crate::app::{
+  App, AppConfig, ConfigValidationError, ConfigValidationErrors, ServarrConfig, log_and_print_error,
+};
+use crate::cli::output::OutputOptions;
 use crate::cli::{self, Command};
-use crate::network::Network;
+use crate::models::TabRoute;
+use crate::network::recording::Recording;
+use crate::network::servarr_error;
+use crate::network::{Network, NetworkClients};
 use crate::ui::theme::ThemeDefinitionsWrapper;
 
 #[cfg(test)]
@@ -94,6 +101,12 @@ pub fn convert_runtime(runtime: i64) -> (i64, i64) {
   (hours, minutes)
 }
 
+pub fn calendar_range(today: NaiveDate) -> (NaiveDate, NaiveDate) {
+  let start = today.week(Weekday::Mon).first_day();
+
+  (start, start + Days::new(28))
+}
+
 pub async fn tail_logs(no_color: bool) -> Result<()> {
   let re = Regex::new(r"^(?P<timestamp>\d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}\.\d{3})\s+<(?P<opid>[^\s>]+)>\s+\[(?P<level>[A-Z]+)]\s+(?P<logger>[^:]+):(?P<line>\d+)\s+-\s+(?P<message>.*)$").unwrap();
   let file_path = get_log_path();
@@ -194,6 +207,49 @@ pub(super) fn load_config(path: &str) -> Result<AppConfig> {
   }
 }
 
+pub(super) fn validate_config_file(path: &str) -> Result<(), ConfigValidationErrors> {
+  let config_error = |message: String| {
+    ConfigValidationErrors(vec![ConfigValidationError {
+      instance: None,
+      field: String::new(),
+      message,
+    }])
+  };
+  let contents = fs::read_to_string(path)
+    .map_err(|e| config_error(format!("Unable to open config file '{path}': {e}")))?;
+  let mut config: AppConfig = serde_yaml::from_str(&contents)
+    .map_err(|e| config_error(format!("Config file at '{path}' is invalid: {e}")))?;
+
+  config.try_validate(path)?;
+  config.try_post_process_initialization()
+}
+
+pub(super) fn config_validation_report(
+  path: &str,
+  result: &Result<(), ConfigValidationErrors>,
+  json: bool,
+) -> String {
+  let errors = result.as_ref().err().cloned().unwrap_or_default();
+
+  if json {
+    let report = serde_json::json!({
+      "configFile": path,
+      "valid": result.is_ok(),
+      "errors": errors,
+    });
+
+    return serde_json::to_string_pretty(&report).expect("Unable to serialize validation report");
+  }
+
+  match result {
+    Ok(()) => format!("The configuration file '{path}' is valid"),
+    Err(errors) => format!(
+      "The configuration file '{path}' has {} error(s):\n{errors}",
+      errors.0.len()
+    ),
+  }
+}
+
 pub(super) fn load_theme_config(path: &str) -> Result<ThemeDefinitionsWrapper> {
   match File::open(path).map_err(|e| anyhow!(e)) {
     Ok(file) => {
@@ -208,58 +264,74 @@ pub(super) fn load_theme_config(path: &str) -> Result<ThemeDefinitionsWrapper> {
   }
 }
 
-pub(super) fn build_network_client(config: &AppConfig) -> Client {
-  let mut client_builder = Client::builder()
-    .pool_max_idle_per_host(10)
-    .http2_keep_alive_interval(Duration::from_secs(5))
-    .tcp_keepalive(Duration::from_secs(5));
+/// Builds the client for each of the given Servarr tabs, keyed by the instance it's for
+pub(super) fn try_build_network_clients(tabs: &[TabRoute]) -> Result<NetworkClients> {
+  tabs
+    .iter()
+    .filter_map(|tab| Some((tab, tab.config.as_ref()?)))
+    .map(|(tab, servarr_config)| {
+      let client = try_build_servarr_client(servarr_config, &tab.title)?;
+      Ok((tab.instance(), client))
+    })
+    .collect()
+}
 
-  if let Some(radarr_configs) = &config.radarr {
-    for radarr_config in radarr_configs {
-      if let Some(cert_path) = &radarr_config.ssl_cert_path {
-        let cert = create_cert(cert_path, "Radarr");
-        client_builder = client_builder.add_root_certificate(cert);
-      }
-    }
+fn try_build_servarr_client(config: &ServarrConfig, instance: &str) -> Result<Client> {
+  let mut client_builder = client_builder();
+
+  if let Some(cert_path) = &config.ssl_cert_path {
+    let cert = create_cert(cert_path, instance)?;
+    client_builder = client_builder.add_root_certificate(cert);
   }
 
-  if let Some(sonarr_configs) = &config.sonarr {
-    for sonarr_config in sonarr_configs {
-      if let Some(cert_path) = &sonarr_config.ssl_cert_path {
-        let cert = create_cert(cert_path, "Sonarr");
-        client_builder = client_builder.add_root_certificate(cert);
-      }
-    }
+  if config.accept_invalid_certs == Some(true) {
+    client_builder = client_builder.tls_danger_accept_invalid_certs(true);
   }
 
-  match client_builder.build() {
-    Ok(client) => client,
-    Err(e) => {
-      error!("Unable to create reqwest client: {e}");
-      eprintln!("error: {}", e.to_string().red());
-      process::exit(1);
-    }
+  if let (Some(cert_path), Some(key_path)) = (&config.client_cert_path, &config.client_key_path) {
+    let identity = create_identity(cert_path, key_path, instance)?;
+    client_builder = client_builder.identity(identity);
   }
-}
 
-pub(super) fn create_cert(cert_path: &String, servarr_name: &str) -> Certificate {
-  match fs::read(cert_path) {
-    Ok(cert) => match Certificate::from_pem(&cert) {
-      Ok(certificate) => certificate,
-      Err(_) => {
-        log_and_print_error(format!(
-          "Unable to read the specified {servarr_name} SSL certificate"
-        ));
-        process::exit(1);
-      }
-    },
-    Err(_) => {
-      log_and_print_error(format!(
-        "Unable to open specified {servarr_name} SSL certificate"
-      ));
-      process::exit(1);
-    }
+  if let Some(proxy) = &config.proxy {
+    let proxy =
+      Proxy::all(proxy).map_err(|e| anyhow!("Invalid {instance} proxy '{proxy}': {e}"))?;
+    client_builder = client_builder.proxy(proxy);
   }
+
+  client_builder
+    .build()
+    .map_err(|e| anyhow!("Unable to create reqwest client for {instance}: {e}"))
+}
+
+fn client_builder() -> ClientBuilder {
+  Client::builder()
+    .pool_max_idle_per_host(10)
+    .http2_keep_alive_interval(Duration::from_secs(5))
+    .tcp_keepalive(Duration::from_secs(5))
+}
+
+pub(super) fn create_cert(cert_path: &String, servarr_name: &str) -> Result<Certificate> {
+  let cert = fs::read(cert_path)
+    .map_err(|_| anyhow!("Unable to open specified {servarr_name} SSL certificate"))?;
+
+  Certificate::from_pem(&cert)
+    .map_err(|_| anyhow!("Unable to read the specified {servarr_name} SSL certificate"))
+}
+
+/// Combines the PEM encoded client certificate and private key into the identity used for mTLS
+fn create_identity(cert_path: &String, key_path: &String, servarr_name: &str) -> Result<Identity> {
+  let mut pem = fs::read(cert_path)
+    .map_err(|_| anyhow!("Unable to open specified {servarr_name} client certificate"))?;
+  pem.push(b'\n');
+  pem.extend(
+    fs::read(key_path)
+      .map_err(|_| anyhow!("Unable to open specified {servarr_name} client key"))?,
+  );
+
+  Identity::from_pem(&pem).map_err(|e| {
+    anyhow!("Unable to read the specified {servarr_name} client certificate and key: {e}")
+  })
 }
 
 pub(super) fn render_spinner() -> ProgressBar {
@@ -281,10 +353,11 @@ pub(super) fn render_spinner() -> ProgressBar {
 
 pub(super) async fn start_cli_with_spinner(
   config: AppConfig,
-  reqwest_client: Client,
   cancellation_token: CancellationToken,
   app: Arc<Mutex<App<'_>>>,
   command: Command,
+  output_options: OutputOptions,
+  recording: Option<Recording>,
 ) {
   config.verify_config_present_for_cli(&command);
   {
@@ -294,8 +367,8 @@ pub(super) async fn start_cli_with_spinner(
   }
   let pb = render_spinner();
   let app_nw = Arc::clone(&app);
-  let mut network = Network::new(&app_nw, cancellation_token, reqwest_client);
-  match cli::handle_command(&app, command, &mut network).await {
+  let mut network = Network::new(&app_nw, cancellation_token).with_recording(recording);
+  match cli::handle_command(&app, command, &mut network, &output_options).await {
     Ok(output) => {
       pb.finish();
       println!("{output}");
@@ -303,17 +376,18 @@ pub(super) async fn start_cli_with_spinner(
     Err(e) => {
       pb.finish();
       eprintln!("error: {}", e.to_string().red());
-      process::exit(1);
+      process::exit(servarr_error::exit_code(&e));
     }
   }
 }
 
 pub(super) async fn start_cli_no_spinner(
   config: AppConfig,
-  reqwest_client: Client,
   cancellation_token: CancellationToken,
   app: Arc<Mutex<App<'_>>>,
   command: Command,
+  output_options: OutputOptions,
+  recording: Option<Recording>,
 ) {
   config.verify_config_present_for_cli(&command);
   {
@@ -322,14 +396,14 @@ pub(super) async fn start_cli_no_spinner(
     select_cli_configuration(&mut app, &config, &command, None);
   }
   let app_nw = Arc::clone(&app);
-  let mut network = Network::new(&app_nw, cancellation_token, reqwest_client);
-  match cli::handle_command(&app, command, &mut network).await {
+  let mut network = Network::new(&app_nw, cancellation_token).with_recording(recording);
+  match cli::handle_command(&app, command, &mut network, &output_options).await {
     Ok(output) => {
       println!("{output}");
     }
     Err(e) => {
       eprintln!("error: {}", e.to_string().red());
-      process::exit(1);
+      process::exit(servarr_error::exit_code(&e));
     }
   }
 }
@@ -365,6 +439,23 @@ pub fn select_cli_configuration(
           config.lidarr.as_ref().expect("Lidarr config must exist")[0].clone();
         app.server_tabs.select_tab_by_config(&default_lidarr_config);
       }
+      Command::Prowlarr(_) => {
+        let default_prowlarr_config = config
+          .prowlarr
+          .as_ref()
+          .expect("Prowlarr config must exist")[0]
+          .clone();
+        app
+          .server_tabs
+          .select_tab_by_config(&default_prowlarr_config);
+      }
+      Command::Readarr(_) => {
+        let default_readarr_config =
+          config.readarr.as_ref().expect("Readarr config must exist")[0].clone();
+        app
+          .server_tabs
+          .select_tab_by_config(&default_readarr_config);
+      }
       _ => (),
     }
   }
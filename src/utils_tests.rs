@@ -3,9 +3,21 @@ mod tests {
   use std::fs::{self, File};
   use std::io::{BufRead, BufReader, Seek, SeekFrom, Write};
 
-  use pretty_assertions::assert_eq;
+  use indoc::indoc;
+  use pretty_assertions::{assert_eq, assert_str_eq};
+  use serde_json::{Value, json};
 
-  use crate::utils::{convert_f64_to_gb, convert_runtime, convert_to_gb, was_log_rotated};
+  use chrono::NaiveDate;
+
+  use crate::app::{ConfigValidationError, ConfigValidationErrors, ServarrConfig};
+  use crate::models::servarr_data::lidarr::lidarr_data::ActiveLidarrBlock;
+  use crate::models::servarr_data::radarr::radarr_data::ActiveRadarrBlock;
+  use crate::models::servarr_data::sonarr::sonarr_data::ActiveSonarrBlock;
+  use crate::models::{Route, TabRoute};
+  use crate::utils::{
+    calendar_range, config_validation_report, convert_f64_to_gb, convert_runtime, convert_to_gb,
+    try_build_network_clients, validate_config_file, was_log_rotated,
+  };
 
   #[test]
   fn test_convert_to_gb() {
@@ -27,6 +39,14 @@ mod tests {
     assert_eq!(minutes, 34);
   }
 
+  #[test]
+  fn test_calendar_range() {
+    let (start, end) = calendar_range(NaiveDate::from_ymd_opt(2023, 5, 20).unwrap());
+
+    assert_eq!(start, NaiveDate::from_ymd_opt(2023, 5, 15).unwrap());
+    assert_eq!(end, NaiveDate::from_ymd_opt(2023, 6, 12).unwrap());
+  }
+
   #[test]
   fn test_was_log_rotated_returns_false_when_file_has_not_rotated() {
     let path = std::env::temp_dir().join("managarr_test_no_rotation.log");
@@ -77,4 +97,235 @@ mod tests {
 
     fs::remove_file(&path).unwrap();
   }
+
+  #[test]
+  fn test_try_build_network_clients() {
+    let tabs = vec![
+      test_tab_route(
+        "Radarr 1",
+        ActiveRadarrBlock::Movies.into(),
+        Some(ServarrConfig {
+          proxy: Some("http://proxy.local:3128".to_owned()),
+          ..ServarrConfig::default()
+        }),
+      ),
+      test_tab_route(
+        "Lidarr 1",
+        ActiveLidarrBlock::Artists.into(),
+        Some(ServarrConfig {
+          accept_invalid_certs: Some(true),
+          proxy: Some("socks5://proxy.local:1080".to_owned()),
+          ..ServarrConfig::default()
+        }),
+      ),
+      test_tab_route("Sonarr 1", ActiveSonarrBlock::Series.into(), None),
+    ];
+
+    let network_clients = try_build_network_clients(&tabs).unwrap();
+
+    assert_ok!(network_clients.get(&tabs[0].instance()));
+    assert_ok!(network_clients.get(&tabs[1].instance()));
+    assert_err!(network_clients.get(&tabs[2].instance()));
+  }
+
+  #[test]
+  fn test_try_build_network_clients_uses_the_ssl_cert_path_of_every_servarr() {
+    let tabs = vec![test_tab_route(
+      "Lidarr Test",
+      ActiveLidarrBlock::Artists.into(),
+      Some(ServarrConfig {
+        name: Some("Lidarr Test".to_owned()),
+        ssl_cert_path: Some("/nonexistent/lidarr.crt".to_owned()),
+        ..ServarrConfig::default()
+      }),
+    )];
+
+    assert_str_eq!(
+      try_build_network_clients(&tabs).unwrap_err().to_string(),
+      "Unable to open specified Lidarr Test SSL certificate"
+    );
+  }
+
+  #[test]
+  fn test_try_build_network_clients_invalid_client_certificate() {
+    let cert_path = std::env::temp_dir().join("managarr_test_invalid_client.crt");
+    fs::write(&cert_path, "not a certificate").unwrap();
+    let tabs = vec![test_tab_route(
+      "Sonarr 1",
+      ActiveSonarrBlock::Series.into(),
+      Some(ServarrConfig {
+        client_cert_path: Some(cert_path.to_string_lossy().into_owned()),
+        client_key_path: Some(cert_path.to_string_lossy().into_owned()),
+        ..ServarrConfig::default()
+      }),
+    )];
+
+    assert!(
+      try_build_network_clients(&tabs)
+        .unwrap_err()
+        .to_string()
+        .starts_with("Unable to read the specified Sonarr 1 client certificate and key")
+    );
+
+    fs::remove_file(&cert_path).unwrap();
+  }
+
+  fn test_tab_route(title: &str, route: Route, config: Option<ServarrConfig>) -> TabRoute {
+    TabRoute {
+      title: title.to_owned(),
+      route,
+      contextual_help: None,
+      config,
+    }
+  }
+
+  #[test]
+  fn test_validate_config_file() {
+    let path = std::env::temp_dir().join("managarr_test_validate_config_file.yml");
+    fs::write(
+      &path,
+      indoc! {"
+        radarr:
+          - host: 192.168.0.78
+            api_token: someApiToken1234567890
+      "},
+    )
+    .unwrap();
+
+    assert_ok!(validate_config_file(path.to_str().unwrap()));
+
+    fs::remove_file(&path).unwrap();
+  }
+
+  #[test]
+  fn test_validate_config_file_collects_errors() {
+    let path = std::env::temp_dir().join("managarr_test_validate_config_file_errors.yml");
+    fs::write(
+      &path,
+      indoc! {"
+        radarr:
+          - host: 192.168.0.78
+            port: hi
+            api_token: someApiToken1234567890
+        sonarr:
+          - name: Anime
+            host: 192.168.0.89
+      "},
+    )
+    .unwrap();
+    let expected_errors = ConfigValidationErrors(vec![
+      ConfigValidationError {
+        instance: Some("Radarr 1".to_owned()),
+        field: "radarr[0].port".to_owned(),
+        message: "Invalid value 'hi': invalid digit found in string".to_owned(),
+      },
+      ConfigValidationError {
+        instance: Some("Anime".to_owned()),
+        field: "sonarr[0].api_token".to_owned(),
+        message: "'api_token' or 'api_token_file' is required for configuration".to_owned(),
+      },
+    ]);
+
+    assert_eq!(
+      validate_config_file(path.to_str().unwrap()),
+      Err(expected_errors)
+    );
+
+    fs::remove_file(&path).unwrap();
+  }
+
+  #[test]
+  fn test_validate_config_file_invalid_yaml() {
+    let path = std::env::temp_dir().join("managarr_test_validate_config_file_invalid.yml");
+    fs::write(&path, "radarr: [").unwrap();
+
+    let result = validate_config_file(path.to_str().unwrap());
+
+    assert_err!(&result);
+    let errors = result.unwrap_err().0;
+    assert_eq!(errors.len(), 1);
+    assert_none!(errors[0].instance);
+    assert!(
+      errors[0]
+        .message
+        .starts_with(&format!("Config file at '{}' is invalid", path.display()))
+    );
+
+    fs::remove_file(&path).unwrap();
+  }
+
+  #[test]
+  fn test_validate_config_file_missing_file() {
+    let result = validate_config_file("/path/to/nonexistent/config.yml");
+
+    assert_err!(&result);
+    assert!(
+      result.unwrap_err().0[0]
+        .message
+        .starts_with("Unable to open config file '/path/to/nonexistent/config.yml'")
+    );
+  }
+
+  #[test]
+  fn test_config_validation_report() {
+    let result = Err(ConfigValidationErrors(vec![ConfigValidationError {
+      instance: Some("Radarr 1".to_owned()),
+      field: "radarr[0].host".to_owned(),
+      message: "'host' or 'uri' is required for configuration".to_owned(),
+    }]));
+
+    assert_str_eq!(
+      config_validation_report("/path/to/config.yml", &Ok(()), false),
+      "The configuration file '/path/to/config.yml' is valid"
+    );
+    assert_str_eq!(
+      config_validation_report("/path/to/config.yml", &result, false),
+      indoc! {"
+        The configuration file '/path/to/config.yml' has 1 error(s):
+        radarr[0].host (Radarr 1): 'host' or 'uri' is required for configuration"}
+    );
+  }
+
+  #[test]
+  fn test_config_validation_report_json() {
+    let result = Err(ConfigValidationErrors(vec![ConfigValidationError {
+      instance: Some("Radarr 1".to_owned()),
+      field: "radarr[0].host".to_owned(),
+      message: "'host' or 'uri' is required for configuration".to_owned(),
+    }]));
+
+    let report: Value = serde_json::from_str(&config_validation_report(
+      "/path/to/config.yml",
+      &result,
+      true,
+    ))
+    .unwrap();
+    let valid_report: Value = serde_json::from_str(&config_validation_report(
+      "/path/to/config.yml",
+      &Ok(()),
+      true,
+    ))
+    .unwrap();
+
+    assert_eq!(
+      report,
+      json!({
+        "configFile": "/path/to/config.yml",
+        "valid": false,
+        "errors": [{
+          "instance": "Radarr 1",
+          "field": "radarr[0].host",
+          "message": "'host' or 'uri' is required for configuration"
+        }]
+      })
+    );
+    assert_eq!(
+      valid_report,
+      json!({
+        "configFile": "/path/to/config.yml",
+        "valid": true,
+        "errors": []
+      })
+    );
+  }
 }